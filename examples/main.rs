@@ -2,6 +2,7 @@
 extern crate log;
 
 use rust_tdlib::{client::Client, types::*};
+use std::env;
 
 #[tokio::main]
 async fn main() {
@@ -9,8 +10,8 @@ async fn main() {
     let tdlib_parameters = TdlibParameters::builder()
         .database_directory("tdlib")
         .use_test_dc(false)
-        .api_id(env!("API_ID").parse::<i64>().unwrap())
-        .api_hash(env!("API_HASH"))
+        .api_id(env::var("API_ID").unwrap().parse::<i32>().unwrap())
+        .api_hash(env::var("API_HASH").unwrap())
         .system_language_code("en")
         .device_model("Desktop")
         .system_version("Unknown")
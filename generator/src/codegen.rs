@@ -0,0 +1,1437 @@
+//! Rust code generation for parsed schema.
+//!
+//! Generated code is not formatted, run `rustfmt` on generated files.
+use crate::tl::{Definition, Param, Schema};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::PathBuf;
+
+/// Generated file, path is relative to rust-tdlib crate root.
+#[derive(Debug, Clone)]
+pub struct GeneratedFile {
+    pub path: PathBuf,
+    pub content: String,
+}
+
+impl GeneratedFile {
+    fn new(path: &str, content: String) -> Self {
+        Self {
+            path: PathBuf::from(path),
+            content,
+        }
+    }
+}
+
+/// Generates `src/types` module and `Api` methods
+pub fn generate(schema: &Schema, tdlib_version: &str) -> Vec<GeneratedFile> {
+    Generator::new(schema).generate(tdlib_version)
+}
+
+/// `chatTypePrivate` -> `ChatTypePrivate`
+pub fn pascal_case(name: &str) -> String {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// `ChatTypePrivate` or `chatTypePrivate` -> `chat_type_private`
+pub fn snake_case(name: &str) -> String {
+    let mut result = String::with_capacity(name.len() + 8);
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 {
+                result.push('_');
+            }
+            result.extend(c.to_lowercase());
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+// Rust representation of a parameter
+struct Field {
+    name: String,
+    // `name` is a Rust keyword
+    renamed: Option<String>,
+    description: String,
+    type_: String,
+    kind: FieldKind,
+    optional: bool,
+    // must be set before `try_build`
+    required: bool,
+    // serde module for int64 and bytes, which TDlib represents as strings
+    serde_with: Option<&'static str>,
+    boxed: bool,
+    // identifier type from `ID_TYPES`, setter accepts anything convertible to it
+    id: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum FieldKind {
+    // numbers and booleans
+    Copy,
+    String,
+    Bytes,
+    Vec,
+    Object,
+}
+
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "break", "const", "continue", "crate", "else", "enum", "extern", "false", "fn", "for",
+    "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return",
+    "self", "static", "struct", "super", "trait", "true", "type", "unsafe", "use", "where",
+    "while", "async", "await", "dyn", "abstract", "become", "box", "do", "final", "macro",
+    "override", "priv", "typeof", "unsized", "virtual", "yield", "try",
+];
+
+struct Generator<'a> {
+    schema: &'a Schema,
+    // definitions and types with floating point fields, directly or nested, can't be `Eq` and `Hash`
+    floats: BTreeSet<&'a str>,
+    // default constructor of every class, the first one which has finite default value
+    defaults: BTreeMap<&'a str, &'a str>,
+}
+
+impl<'a> Generator<'a> {
+    fn new(schema: &'a Schema) -> Self {
+        let mut floats = BTreeSet::new();
+        loop {
+            let mut changed = false;
+            for definition in schema.types.iter().chain(schema.functions.iter()) {
+                if floats.contains(definition.name.as_str()) {
+                    continue;
+                }
+                let has_float = definition.params.iter().any(|p| {
+                    let type_ = vector_item(&p.type_).unwrap_or(&p.type_);
+                    type_ == "double" || floats.contains(type_)
+                });
+                if has_float {
+                    floats.insert(definition.name.as_str());
+                    if schema.types.contains(definition) {
+                        floats.insert(definition.result.as_str());
+                    }
+                    changed = true;
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+        let defaults = default_constructors(schema);
+        Self {
+            schema,
+            floats,
+            defaults,
+        }
+    }
+
+    fn derives(&self, name: &str) -> &'static str {
+        if self.floats.contains(name) {
+            "PartialEq"
+        } else {
+            "PartialEq, Eq, Hash"
+        }
+    }
+
+    fn generate(&self, tdlib_version: &str) -> Vec<GeneratedFile> {
+        let mut files = Vec::new();
+        let mut modules = BTreeSet::new();
+
+        for class in self.schema.classes.iter() {
+            let module = snake_case(&class.name);
+            files.push(GeneratedFile::new(
+                &format!("src/types/{}.rs", module),
+                self.class_file(&class.name, &class.description),
+            ));
+            modules.insert(module);
+        }
+        for definition in self.schema.types.iter() {
+            if self.schema.is_class(&definition.result) {
+                continue;
+            }
+            let module = snake_case(&definition.name);
+            files.push(GeneratedFile::new(
+                &format!("src/types/{}.rs", module),
+                self.types_file(&[definition], false),
+            ));
+            modules.insert(module);
+        }
+        let functions: Vec<&Definition> = sorted(self.schema.functions.iter().collect());
+        files.push(GeneratedFile::new(
+            "src/types/functions.rs",
+            self.types_file(&functions, true),
+        ));
+        modules.insert("functions".to_string());
+
+        files.push(GeneratedFile::new(
+            "src/types/mod.rs",
+            self.types_module(&modules, tdlib_version),
+        ));
+        files.push(GeneratedFile::new(
+            "src/types/_td_type.rs",
+            self.td_type_file(),
+        ));
+        files.push(GeneratedFile::new(
+            "src/client/api/methods.rs",
+            self.api_methods_file(&functions),
+        ));
+        files
+    }
+
+    fn types_module(&self, modules: &BTreeSet<String>, tdlib_version: &str) -> String {
+        let mut out = String::new();
+        out.push_str(
+            r#"//! Module provides all TDlib types.
+//! For types details you can see [TDlib API Scheme](https://github.com/tdlib/td/blob/master/td/generate/scheme/td_api.tl)
+pub use self::_common::{RFunction, RObject};
+"#,
+        );
+        out.push_str(&format!(
+            "pub use self::_ids::{{{}}};\n",
+            ID_TYPES
+                .iter()
+                .map(|(id, _, _, _)| *id)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+        out.push_str(
+            r#"pub use self::_td_type::TdType;
+
+"#,
+        );
+        out.push_str(&format!(
+            "/// TDlib version, which types are generated for.\npub const TDLIB_VERSION: &str = \"{}\";\n\n",
+            tdlib_version
+        ));
+        out.push_str(
+            r#"#[allow(dead_code, unused_imports)]
+pub(crate) use self::_common::{from_json, TdHeader};
+
+#[macro_use]
+mod _common;
+mod _display;
+mod _ids;
+mod _td_type;
+
+"#,
+        );
+        for module in modules {
+            out.push_str(&format!("pub use self::{}::*;\n", module));
+        }
+        out.push('\n');
+        for module in modules {
+            out.push_str(&format!("mod {};\n", module));
+        }
+        out
+    }
+
+    // abstract class and its constructors
+    fn class_file(&self, class: &str, description: &str) -> String {
+        let constructors: Vec<&Definition> = sorted(
+            self.schema
+                .types
+                .iter()
+                .filter(|t| t.result == class)
+                .collect(),
+        );
+        let variants: Vec<(String, String, &Definition)> = constructors
+            .iter()
+            .map(|d| (variant_name(class, &d.name), pascal_case(&d.name), *d))
+            .collect();
+
+        let mut out = String::new();
+        out.push_str(FILE_HEADER);
+        out.push_str("\nuse serde::ser::{Serialize, Serializer};\nuse std::fmt::Debug;\n\n");
+        out.push_str(&format!(
+            "/// TRAIT | {description}\npub trait TD{class}: Debug + RObject {{}}\n\n",
+            description = description,
+            class = class
+        ));
+
+        // deserialized by `@type` tag, constructors serialize their tags themselves
+        out.push_str(&format!(
+            "/// {}\n#[derive(Debug, Clone, {}, Deserialize)]\n#[serde(tag = \"@type\")]\npub enum {} {{\n",
+            description,
+            self.derives(class),
+            class
+        ));
+        for (variant, type_name, definition) in variants.iter() {
+            out.push_str(&format!(
+                "    /// {}\n    #[serde(rename = \"{}\")]\n    {}({}),\n",
+                definition.description, definition.name, variant, type_name
+            ));
+        }
+        out.push_str("}\n\n");
+
+        let default = self
+            .defaults
+            .get(class)
+            .unwrap_or_else(|| panic!("{} has no constructor with finite default", class));
+        out.push_str(&format!(
+            r#"/// Default value is default of [{type_name}], builders still require the field to be set
+impl Default for {class} {{
+    fn default() -> Self {{
+        {class}::{variant}({type_name}::default())
+    }}
+}}
+
+impl Serialize for {class} {{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {{
+        match self {{
+"#,
+            class = class,
+            variant = variant_name(class, default),
+            type_name = pascal_case(default)
+        ));
+        for (variant, _, _) in variants.iter() {
+            out.push_str(&format!(
+                "            {}::{}(t) => t.serialize(serializer),\n",
+                class, variant
+            ));
+        }
+        out.push_str("        }\n    }\n}\n\n");
+
+        out.push_str(&format!(
+            "impl RObject for {class} {{\n    #[doc(hidden)]\n    fn td_name(&self) -> &'static str {{\n        match self {{\n",
+            class = class
+        ));
+        for (variant, _, _) in variants.iter() {
+            out.push_str(&format!(
+                "            {}::{}(t) => t.td_name(),\n",
+                class, variant
+            ));
+        }
+        out.push_str(
+            "        }\n    }\n    #[doc(hidden)]\n    fn extra(&self) -> Option<String> {\n        match self {\n",
+        );
+        for (variant, _, _) in variants.iter() {
+            out.push_str(&format!(
+                "            {}::{}(t) => t.extra(),\n",
+                class, variant
+            ));
+        }
+        out.push_str(&format!(
+            r#"        }}
+    }}
+    fn to_json(&self) -> RTDResult<String> {{
+        Ok(serde_json::to_string(self)?)
+    }}
+}}
+
+impl {class} {{
+    pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {{
+        Ok(serde_json::from_str(json.as_ref())?)
+    }}
+}}
+
+impl AsRef<{class}> for {class} {{
+    fn as_ref(&self) -> &{class} {{
+        self
+    }}
+}}
+"#,
+            class = class
+        ));
+
+        for constructor in constructors.iter() {
+            out.push('\n');
+            out.push_str(&self.struct_definition(constructor, Some(class), false));
+        }
+
+        let samples: Vec<String> = constructors
+            .iter()
+            .map(|c| {
+                format!(
+                    "{}::{}({})",
+                    class,
+                    variant_name(class, &c.name),
+                    self.sample_object(c, 0)
+                )
+            })
+            .collect();
+        out.push_str(&round_trip_test(&samples));
+        out
+    }
+
+    // concrete types or functions
+    fn types_file(&self, definitions: &[&Definition], functions: bool) -> String {
+        let mut out = String::new();
+        out.push_str(FILE_HEADER);
+        for definition in definitions {
+            // function is not a constructor of its result class
+            let class = if !functions && self.schema.is_class(&definition.result) {
+                Some(definition.result.as_str())
+            } else {
+                None
+            };
+            out.push('\n');
+            out.push_str(&self.struct_definition(definition, class, functions));
+        }
+
+        let samples: Vec<String> = definitions
+            .iter()
+            .map(|d| self.sample_object(d, 0))
+            .collect();
+        out.push_str(&round_trip_test(&samples));
+        out
+    }
+
+    // builder expression of sample object for round-trip tests, only required fields are set
+    fn sample_object(&self, definition: &Definition, depth: usize) -> String {
+        if depth > MAX_SAMPLE_DEPTH {
+            panic!("sample of {} is too deep", definition.name);
+        }
+        let owner = if self.schema.is_class(&definition.result) {
+            definition.result.clone()
+        } else {
+            pascal_case(&definition.name)
+        };
+        let mut out = format!("{}::builder()", pascal_case(&definition.name));
+        for param in definition.params.iter() {
+            let field = self.field(definition, param, &owner);
+            if field.optional {
+                continue;
+            }
+            let value = if field.type_.starts_with("Vec<Option<") {
+                "vec![]".to_string()
+            } else if field.id && field.kind == FieldKind::Vec {
+                // identifiers are not sampled as integers
+                "vec![Default::default()]".to_string()
+            } else {
+                self.sample(&param.type_, depth + 1)
+            };
+            out.push_str(&format!(".{}({})", field.name, value));
+        }
+        out.push_str(".build()");
+        out
+    }
+
+    // boundary values for numbers, classes are sampled by their simplest constructor
+    fn sample(&self, type_: &str, depth: usize) -> String {
+        if let Some(item) = vector_item(type_) {
+            return match item {
+                "int32" | "int53" | "int64" | "double" | "Bool" => {
+                    format!("vec![{}]", self.sample(item, depth))
+                }
+                "string" => "vec![\"text\".to_string()]".to_string(),
+                "bytes" => "vec![b\"\\x00\\x01\\xfe\\xff\".to_vec()]".to_string(),
+                _ => "vec![]".to_string(),
+            };
+        }
+        match type_ {
+            "int32" => "i32::MAX".to_string(),
+            // 2^53 - 1
+            "int53" => "9007199254740991i64".to_string(),
+            "int64" => "i64::MAX".to_string(),
+            "double" => "1.5".to_string(),
+            "Bool" => "true".to_string(),
+            "string" => "\"text\"".to_string(),
+            "bytes" => "b\"\\x00\\x01\\xfe\\xff\"".to_string(),
+            class if self.schema.is_class(class) => {
+                let constructor = self
+                    .schema
+                    .types
+                    .iter()
+                    .filter(|t| t.result == class)
+                    .min_by_key(|t| {
+                        let objects = t
+                            .params
+                            .iter()
+                            .filter(|p| self.schema.types.iter().any(|o| o.result == p.type_))
+                            .count();
+                        (objects, t.name.clone())
+                    })
+                    .unwrap_or_else(|| panic!("class {} has no constructors", class));
+                format!(
+                    "{}::{}({})",
+                    class,
+                    variant_name(class, &constructor.name),
+                    self.sample_object(constructor, depth)
+                )
+            }
+            name => {
+                let definition = self
+                    .schema
+                    .types
+                    .iter()
+                    .find(|t| t.result == name)
+                    .unwrap_or_else(|| panic!("unknown type {}", name));
+                self.sample_object(definition, depth)
+            }
+        }
+    }
+
+    // `PartialEq` (and `Eq`, `Hash` without floats) of object fields, `@extra` is skipped,
+    // so received responses are equal to the same objects received as updates
+    fn comparisons(&self, td_name: &str, name: &str, fields: &[Field]) -> String {
+        let eq = if fields.is_empty() {
+            "true".to_string()
+        } else {
+            fields
+                .iter()
+                .map(|f| format!("self.{0} == other.{0}", f.name))
+                .collect::<Vec<_>>()
+                .join(" && ")
+        };
+        let mut out = format!(
+            "impl PartialEq for {} {{\n    fn eq(&self, {}other: &Self) -> bool {{\n        {}\n    }}\n}}\n\n",
+            name,
+            if fields.is_empty() { "_" } else { "" },
+            eq
+        );
+        if self.floats.contains(td_name) {
+            return out;
+        }
+        let hash: String = fields
+            .iter()
+            .map(|f| format!("        std::hash::Hash::hash(&self.{}, state);\n", f.name))
+            .collect();
+        out.push_str(&format!(
+            "impl Eq for {0} {{}}\n\nimpl std::hash::Hash for {0} {{\n    fn hash<H: std::hash::Hasher>(&self, {1}state: &mut H) {{\n{2}    }}\n}}\n\n",
+            name,
+            if fields.is_empty() { "_" } else { "" },
+            hash
+        ));
+        out
+    }
+
+    fn struct_definition(
+        &self,
+        definition: &Definition,
+        class: Option<&str>,
+        function: bool,
+    ) -> String {
+        let name = pascal_case(&definition.name);
+        let builder = format!("RTD{}Builder", name);
+        let owner = class.map(|c| c.to_string()).unwrap_or_else(|| name.clone());
+        let fields: Vec<Field> = definition
+            .params
+            .iter()
+            .map(|p| self.field(definition, p, &owner))
+            .collect();
+
+        let mut out = String::new();
+        out.push_str(&format!(
+            r#"/// {description}
+#[derive({derives}Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "{td_name}")]
+pub struct {name} {{
+"#,
+            description = definition.description,
+            // objects implement comparisons without `@extra`
+            derives = if function {
+                format!(
+                    "Debug, Clone, Default, {}, ",
+                    self.derives(&definition.name)
+                )
+            } else {
+                "Debug, Clone, Default, ".to_string()
+            },
+            td_name = definition.name,
+            name = name
+        ));
+        // `@extra` of functions is assigned by `Api` when request is sent
+        if !function {
+            out.push_str(
+                r#"    #[doc(hidden)]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
+    extra: Option<String>,
+"#,
+            );
+        }
+        for field in fields.iter() {
+            out.push_str(&format!("    /// {}\n", field.description));
+            if let Some(renamed) = &field.renamed {
+                out.push_str(&format!(
+                    "    #[serde(rename(serialize = \"{0}\", deserialize = \"{0}\"))]\n",
+                    renamed
+                ));
+            }
+            if let Some(with) = field.serde_with {
+                out.push_str(&format!(
+                    "    #[serde(with = \"super::_common::{}\")]\n",
+                    with
+                ));
+            }
+            out.push_str(&format!("    {}: {},\n", field.name, field.type_));
+        }
+        out.push_str("}\n\n");
+
+        out.push_str(&format!(
+            r#"impl RObject for {name} {{
+    #[doc(hidden)]
+    fn td_name(&self) -> &'static str {{
+        "{td_name}"
+    }}
+    #[doc(hidden)]
+    fn extra(&self) -> Option<String> {{
+        {extra}
+    }}
+    fn to_json(&self) -> RTDResult<String> {{
+        Ok(serde_json::to_string(self)?)
+    }}
+}}
+
+"#,
+            name = name,
+            td_name = definition.name,
+            extra = if function {
+                "None"
+            } else {
+                "self.extra.clone()"
+            }
+        ));
+        if !function {
+            out.push_str(&self.comparisons(&definition.name, &name, &fields));
+        }
+        if let Some(class) = class {
+            out.push_str(&format!("impl TD{} for {} {{}}\n\n", class, name));
+        }
+        if function {
+            out.push_str(&format!(
+                "impl RFunction for {} {{\n    type Response = {};\n}}\n\n",
+                name,
+                pascal_case(&definition.result)
+            ));
+        }
+
+        out.push_str(&format!(
+            r#"impl {name} {{
+    pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {{
+        Ok(serde_json::from_str(json.as_ref())?)
+    }}
+    pub fn builder() -> {builder} {{
+        {builder} {{
+            inner: {name}::default(),
+            missing: vec![{missing}],
+        }}
+    }}
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> {builder} {{
+        {builder} {{
+            inner: self.clone(),
+            missing: vec![],
+        }}
+    }}
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> {builder} {{
+        {builder} {{
+            inner: self,
+            missing: vec![],
+        }}
+    }}
+"#,
+            name = name,
+            builder = builder,
+            missing = fields
+                .iter()
+                .filter(|f| f.required)
+                .map(|f| format!("\"{}\"", f.name))
+                .collect::<Vec<String>>()
+                .join(", ")
+        ));
+        for field in fields.iter() {
+            if field.kind == FieldKind::Bytes && !field.optional {
+                out.push_str(&format!(
+                    "\n    pub fn {name}(&self) -> &[u8] {{\n        &self.{name}\n    }}\n",
+                    name = field.name
+                ));
+                continue;
+            }
+            let reference = if field.kind == FieldKind::Copy && !field.optional {
+                ""
+            } else {
+                "&"
+            };
+            out.push_str(&format!(
+                "\n    pub fn {name}(&self) -> {reference}{type_} {{\n        {reference}self.{name}\n    }}\n",
+                name = field.name,
+                reference = reference,
+                type_ = field.type_
+            ));
+        }
+        // consuming getters move data out without cloning
+        for field in fields
+            .iter()
+            .filter(|f| f.kind != FieldKind::Copy || f.optional)
+        {
+            let (type_, value) = match (field.boxed, field.optional) {
+                (true, false) => (
+                    boxed_inner(&field.type_).to_string(),
+                    format!("*self.{}", field.name),
+                ),
+                (true, true) => (
+                    format!("Option<{}>", boxed_inner(option_inner(&field.type_))),
+                    format!("self.{}.map(|v| *v)", field.name),
+                ),
+                _ => (field.type_.clone(), format!("self.{}", field.name)),
+            };
+            out.push_str(&format!(
+                "\n    pub fn into_{method}(self) -> {type_} {{\n        {value}\n    }}\n",
+                method = field.renamed.as_ref().unwrap_or(&field.name),
+                type_ = type_,
+                value = value
+            ));
+        }
+        for field in fields.iter() {
+            out.push('\n');
+            out.push_str(&with_setter(field));
+        }
+        out.push_str("}\n\n");
+
+        out.push_str(&format!(
+            r#"#[doc(hidden)]
+pub struct {builder} {{
+    inner: {name},
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
+}}
+
+impl {builder} {{
+    /// Builds the object, fields which are not set have default values
+    pub fn build(&self) -> {name} {{
+        self.inner.clone()
+    }}
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<{name}> {{
+        if !self.missing.is_empty() {{
+            return Err(RTDError::MissingFields("{name}", self.missing.clone()));
+        }}
+        Ok(self.inner.clone())
+    }}
+"#,
+            name = name,
+            builder = builder
+        ));
+        for field in fields.iter() {
+            out.push('\n');
+            out.push_str(&builder_setter(field));
+        }
+        out.push_str("}\n\n");
+
+        out.push_str(&format!(
+            r#"impl AsRef<{name}> for {name} {{
+    fn as_ref(&self) -> &{name} {{
+        self
+    }}
+}}
+
+impl AsRef<{name}> for {builder} {{
+    fn as_ref(&self) -> &{name} {{
+        &self.inner
+    }}
+}}
+"#,
+            name = name,
+            builder = builder
+        ));
+        out
+    }
+
+    fn field(&self, definition: &Definition, param: &Param, owner: &str) -> Field {
+        let id = id_type(&definition.name, param);
+        let optional = param.description.contains("may be null");
+        let (name, renamed) = if RUST_KEYWORDS.contains(&param.name.as_str()) {
+            (format!("{}_", param.name), Some(param.name.clone()))
+        } else {
+            (param.name.clone(), None)
+        };
+        let mut boxed = false;
+        let (type_, kind) = match vector_item(&param.type_) {
+            Some(item) => {
+                let item = id
+                    .map(|id| id.to_string())
+                    .unwrap_or_else(|| rust_type(item));
+                if optional {
+                    (format!("Vec<Option<{}>>", item), FieldKind::Vec)
+                } else {
+                    (format!("Vec<{}>", item), FieldKind::Vec)
+                }
+            }
+            None => {
+                let kind = match param.type_.as_str() {
+                    "int32" | "int53" | "int64" | "double" | "Bool" => FieldKind::Copy,
+                    "string" => FieldKind::String,
+                    "bytes" => FieldKind::Bytes,
+                    _ => FieldKind::Object,
+                };
+                let mut type_ = id
+                    .map(|id| id.to_string())
+                    .unwrap_or_else(|| rust_type(&param.type_));
+                // recursive types
+                if type_ == owner {
+                    type_ = format!("Box<{}>", type_);
+                    boxed = true;
+                }
+                if optional {
+                    type_ = format!("Option<{}>", type_);
+                }
+                (type_, kind)
+            }
+        };
+        Field {
+            name,
+            renamed,
+            description: param.description.clone(),
+            type_,
+            kind,
+            optional: optional && kind != FieldKind::Vec,
+            required: is_required(param),
+            serde_with: match param.type_.as_str() {
+                "int64" => Some("int64"),
+                "vector<int64>" => Some("int64_vec"),
+                "bytes" => Some("bytes"),
+                "vector<bytes>" => Some("bytes_vec"),
+                _ => None,
+            },
+            boxed,
+            id: id.is_some(),
+        }
+    }
+
+    // `TdType` is a result of received json deserialization: updates and function results
+    fn td_type_file(&self) -> String {
+        let mut updates: Vec<&Definition> = self
+            .schema
+            .types
+            .iter()
+            .filter(|d| d.result == "Update")
+            .collect();
+        updates.sort_by_key(|d| pascal_case(&d.name));
+
+        let results: BTreeSet<&str> = self
+            .schema
+            .functions
+            .iter()
+            .map(|f| f.result.as_str())
+            .collect();
+        let classes: Vec<&str> = results
+            .iter()
+            .filter(|r| self.schema.is_class(r))
+            .copied()
+            .collect();
+        // concrete result type and its td name
+        let mut concrete: Vec<(String, &str)> = results
+            .iter()
+            .filter(|r| !self.schema.is_class(r))
+            .map(|r| {
+                let td_name = self
+                    .schema
+                    .types
+                    .iter()
+                    .find(|t| t.result == *r)
+                    .map(|t| t.name.as_str())
+                    .unwrap_or(r);
+                (pascal_case(r), td_name)
+            })
+            .collect();
+        concrete.sort();
+
+        let mut out = String::new();
+        out.push_str(
+            "//! [TdType](crate::types::TdType) and implementations of class traits for references.\nuse crate::types::*;\nuse serde::de::{Deserialize, Deserializer};\n\n",
+        );
+        let mut class_names: Vec<&str> = self
+            .schema
+            .classes
+            .iter()
+            .map(|c| c.name.as_str())
+            .collect();
+        class_names.sort_unstable();
+        for class in class_names {
+            let generic = class.to_uppercase();
+            out.push_str(&format!(
+                "impl<'a, {generic}: TD{class}> TD{class} for &'a {generic} {{}}\nimpl<'a, {generic}: TD{class}> TD{class} for &'a mut {generic} {{}}\n\n",
+                generic = generic,
+                class = class
+            ));
+        }
+
+        let has_float = updates
+            .iter()
+            .any(|u| self.floats.contains(u.name.as_str()))
+            || results.iter().any(|r| self.floats.contains(r));
+        out.push_str(&format!(
+            "#[derive(Debug, Clone, {})]\npub enum TdType {{\n",
+            if has_float {
+                "PartialEq"
+            } else {
+                "PartialEq, Eq, Hash"
+            }
+        ));
+        for update in updates.iter() {
+            let name = pascal_case(&update.name);
+            out.push_str(&format!("    {0}({0}),\n", name));
+        }
+        out.push('\n');
+        for class in classes.iter() {
+            out.push_str(&format!("    {0}({0}),\n", class));
+        }
+        for (name, _) in concrete.iter() {
+            out.push_str(&format!("    {0}({0}),\n", name));
+        }
+        out.push_str(
+            r#"}
+impl<'de> Deserialize<'de> for TdType {
+    fn deserialize<D>(deserializer: D) -> Result<TdType, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use serde::de::Error;
+        rtd_enum_deserialize!(
+             TdType,
+"#,
+        );
+        for update in updates.iter() {
+            out.push_str(&format!(
+                "         ({}, {});\n",
+                update.name,
+                pascal_case(&update.name)
+            ));
+        }
+        out.push('\n');
+        for class in classes.iter() {
+            out.push_str(&format!("         ({0}, {0});\n", class));
+        }
+        for (name, td_name) in concrete.iter() {
+            out.push_str(&format!("         ({}, {});\n", td_name, name));
+        }
+        out.push_str("\n        )(deserializer)\n    }\n}\n");
+        out
+    }
+
+    fn api_methods_file(&self, functions: &[&Definition]) -> String {
+        let mut out = String::new();
+        out.push_str(
+            r#"use crate::{
+    client::api::{Api, TdLibClient},
+    errors::RTDResult,
+    types::*,
+};
+
+/// TDLib high-level API methods.
+/// Methods documentation can be found in https://core.telegram.org/tdlib/docs/td__api_8h.html
+impl<S> Api<S>
+where
+    S: TdLibClient + Clone,
+{
+"#,
+        );
+        for (i, function) in functions.iter().enumerate() {
+            if i > 0 {
+                out.push('\n');
+            }
+            out.push_str(&format!(
+                r#"    /// {description}
+    pub async fn {method}<C: AsRef<{type_}>>(&self, {method}: C) -> RTDResult<{result}> {{
+        self.send({method}.as_ref()).await
+    }}
+"#,
+                description = function.description,
+                method = snake_case(&function.name),
+                type_ = pascal_case(&function.name),
+                result = pascal_case(&function.result)
+            ));
+        }
+        out.push_str("}\n");
+        out
+    }
+}
+
+const FILE_HEADER: &str = "use crate::errors::*;\nuse crate::types::*;\n";
+
+// limits nesting of sample objects, recursive types must have non-recursive constructor
+const MAX_SAMPLE_DEPTH: usize = 16;
+
+// identifier types of `types::_ids`: type name, TL type, field name and definitions with own `id` field;
+// fields named like `chat_id`, `sender_user_id` or `member_user_ids` with matching TL type use them.
+// Call identifier isn't `CallId`, TDlib `callId` object already has the name
+const ID_TYPES: &[(&str, &str, &str, &[&str])] = &[
+    ("BasicGroupId", "int32", "basic_group_id", &["basicGroup"]),
+    ("CallIdentifier", "int32", "call_id", &["call", "callId"]),
+    ("ChatId", "int53", "chat_id", &["chat"]),
+    ("FileId", "int32", "file_id", &["file", "inputFileId"]),
+    ("MessageId", "int53", "message_id", &["message"]),
+    ("SecretChatId", "int32", "secret_chat_id", &["secretChat"]),
+    ("SupergroupId", "int32", "supergroup_id", &["supergroup"]),
+    ("UserId", "int32", "user_id", &["user"]),
+];
+
+fn id_type(definition: &str, param: &Param) -> Option<&'static str> {
+    let type_ = vector_item(&param.type_).unwrap_or(&param.type_);
+    let name = if param.name.ends_with("_ids") {
+        param.name.trim_end_matches('s')
+    } else {
+        param.name.as_str()
+    };
+    ID_TYPES
+        .iter()
+        .find(|(_, tl_type, field, definitions)| {
+            *tl_type == type_
+                && (name == *field
+                    || name.ends_with(&format!("_{}", field))
+                    || (name == "id" && definitions.contains(&definition)))
+        })
+        .map(|(id, _, _, _)| *id)
+}
+
+fn round_trip_test(samples: &[String]) -> String {
+    if samples.is_empty() {
+        return String::new();
+    }
+    let mut out = String::from(
+        "\n#[cfg(test)]\nmod tests {\n    use super::*;\n    use crate::types::_common::assert_round_trip;\n\n    #[test]\n    fn test_round_trip() {\n",
+    );
+    for sample in samples {
+        out.push_str(&format!("        assert_round_trip({});\n", sample));
+    }
+    out.push_str("    }\n}\n");
+    out
+}
+
+// constructors of classes used by `Default`: default of a constructor must not contain
+// default of its own class, otherwise e.g. `RichText` would be `RichTextAnchor` infinitely nested.
+// Class gets its default in the round, in which one of its constructors becomes finite
+fn default_constructors(schema: &Schema) -> BTreeMap<&str, &str> {
+    let mut finite: BTreeSet<&str> = BTreeSet::new();
+    let mut defaults = BTreeMap::new();
+    loop {
+        let found: Vec<&Definition> = sorted(schema.types.iter().collect())
+            .into_iter()
+            .filter(|d| !finite.contains(d.name.as_str()))
+            .filter(|d| {
+                d.params.iter().all(|p| {
+                    p.description.contains("may be null")
+                        || vector_item(&p.type_).is_some()
+                        || matches!(
+                            p.type_.as_str(),
+                            "int32" | "int53" | "int64" | "double" | "Bool" | "string" | "bytes"
+                        )
+                        || finite.contains(p.type_.as_str())
+                })
+            })
+            .collect();
+        if found.is_empty() {
+            break;
+        }
+        for definition in found {
+            finite.insert(definition.name.as_str());
+            if schema.is_class(&definition.result) {
+                defaults
+                    .entry(definition.result.as_str())
+                    .or_insert_with(|| definition.name.as_str());
+            }
+        }
+        finite.extend(defaults.keys());
+    }
+    defaults
+}
+
+fn sorted(mut definitions: Vec<&Definition>) -> Vec<&Definition> {
+    definitions.sort_by(|a, b| a.name.cmp(&b.name));
+    definitions
+}
+
+// `chatTypePrivate` of `ChatType` -> `Private`, constructor name is kept if it doesn't start with class name
+fn variant_name(class: &str, name: &str) -> String {
+    let name = pascal_case(name);
+    match name.strip_prefix(class) {
+        Some(rest) if rest.starts_with(|c: char| c.is_uppercase()) => rest.to_string(),
+        _ => name,
+    }
+}
+
+fn vector_item(type_: &str) -> Option<&str> {
+    type_
+        .strip_prefix("vector<")
+        .and_then(|t| t.strip_suffix('>'))
+}
+
+fn rust_type(type_: &str) -> String {
+    if let Some(item) = vector_item(type_) {
+        return format!("Vec<{}>", rust_type(item));
+    }
+    match type_ {
+        "int32" => "i32".to_string(),
+        "int53" | "int64" => "i64".to_string(),
+        "double" => "f32".to_string(),
+        "string" => "String".to_string(),
+        "bytes" => "Vec<u8>".to_string(),
+        "Bool" => "bool".to_string(),
+        class => pascal_case(class),
+    }
+}
+
+// generic parameters, argument type and assigned value of a setter
+fn setter_argument(field: &Field) -> (String, String, String) {
+    match (field.kind, field.optional) {
+        (FieldKind::Copy, false) if field.id => (
+            format!("<T: Into<{}>>", field.type_),
+            "T".to_string(),
+            format!("{}.into()", field.name),
+        ),
+        (FieldKind::Copy, true) if field.id => (
+            format!("<T: Into<{}>>", option_inner(&field.type_)),
+            "T".to_string(),
+            format!("Some({}.into())", field.name),
+        ),
+        (FieldKind::Copy, false) | (FieldKind::Vec, _) => {
+            (String::new(), field.type_.clone(), field.name.clone())
+        }
+        (FieldKind::Copy, true) => {
+            let inner = option_inner(&field.type_);
+            (
+                String::new(),
+                inner.to_string(),
+                format!("Some({})", field.name),
+            )
+        }
+        (FieldKind::String, false) => (
+            "<T: AsRef<str>>".to_string(),
+            "T".to_string(),
+            format!("{}.as_ref().to_string()", field.name),
+        ),
+        (FieldKind::Bytes, false) => (
+            "<T: AsRef<[u8]>>".to_string(),
+            "T".to_string(),
+            format!("{}.as_ref().to_vec()", field.name),
+        ),
+        (FieldKind::Bytes, true) => (
+            "<T: AsRef<[u8]>>".to_string(),
+            "T".to_string(),
+            format!("Some({}.as_ref().to_vec())", field.name),
+        ),
+        (FieldKind::String, true) => (
+            "<T: AsRef<str>>".to_string(),
+            "T".to_string(),
+            format!("Some({}.as_ref().to_string())", field.name),
+        ),
+        (FieldKind::Object, false) if field.boxed => (
+            format!("<T: AsRef<{}>>", boxed_inner(&field.type_)),
+            "T".to_string(),
+            format!("{}.as_ref().clone()", field.name),
+        ),
+        (FieldKind::Object, false) => (
+            format!("<T: AsRef<{}>>", field.type_),
+            "T".to_string(),
+            format!("{}.as_ref().clone()", field.name),
+        ),
+        (FieldKind::Object, true) if field.boxed => (
+            format!("<T: AsRef<{}>>", boxed_inner(option_inner(&field.type_))),
+            "T".to_string(),
+            format!("Some(Box::new({}.as_ref().clone()))", field.name),
+        ),
+        (FieldKind::Object, true) => (
+            format!("<T: AsRef<{}>>", option_inner(&field.type_)),
+            "T".to_string(),
+            format!("Some({}.as_ref().clone())", field.name),
+        ),
+    }
+}
+
+fn builder_setter(field: &Field) -> String {
+    let (generic, argument_type, value) = setter_argument(field);
+    let set_required = if field.required {
+        format!(
+            "        self.missing.retain(|f| *f != \"{}\");\n",
+            field.name
+        )
+    } else {
+        String::new()
+    };
+    format!(
+        "    pub fn {name}{generic}(&mut self, {name}: {argument_type}) -> &mut Self {{\n        {deref}self.inner.{name} = {value};\n{set_required}        self\n    }}\n",
+        name = field.name,
+        generic = generic,
+        argument_type = argument_type,
+        value = value,
+        // boxed value is replaced in place
+        deref = if field.boxed && !field.optional { "*" } else { "" },
+        set_required = set_required
+    )
+}
+
+// setter of the object itself, renamed keyword fields use TDlib name: `with_type`
+fn with_setter(field: &Field) -> String {
+    let (generic, argument_type, value) = setter_argument(field);
+    format!(
+        "    pub fn with_{method}{generic}(mut self, {name}: {argument_type}) -> Self {{\n        {deref}self.{name} = {value};\n        self\n    }}\n",
+        method = field.renamed.as_ref().unwrap_or(&field.name),
+        name = field.name,
+        generic = generic,
+        argument_type = argument_type,
+        value = value,
+        deref = if field.boxed && !field.optional { "*" } else { "" },
+    )
+}
+
+// Documentation phrases of parameters which can be omitted: null, empty or zero values are allowed
+const OPTIONAL_MARKERS: &[&str] = &[
+    "null",
+    "optional",
+    "may be empty",
+    "can be empty",
+    "if empty",
+    "if non-empty",
+    "empty on",
+    "empty string",
+    "or 0",
+    "use 0",
+    "pass 0",
+    "specify 0",
+    "may be 0",
+    "0 if",
+    "0 for",
+    "if not 0",
+    "0-",
+    "zero",
+    "for bots only",
+    "if set",
+    "if specified",
+    "if known",
+    "if needed",
+    "by default",
+];
+
+// TL doesn't distinguish optional parameters, so parameter is required unless it's a flag,
+// its documentation allows to omit it or it's a markup or options, which TDLib fills with defaults
+fn is_required(param: &Param) -> bool {
+    let description = param.description.to_lowercase();
+    param.type_ != "Bool"
+        && param.type_ != "ReplyMarkup"
+        && param.type_ != "SendMessageOptions"
+        && !OPTIONAL_MARKERS.iter().any(|m| description.contains(m))
+}
+
+fn boxed_inner(type_: &str) -> &str {
+    type_
+        .strip_prefix("Box<")
+        .and_then(|t| t.strip_suffix('>'))
+        .unwrap_or(type_)
+}
+
+fn option_inner(type_: &str) -> &str {
+    type_
+        .strip_prefix("Option<")
+        .and_then(|t| t.strip_suffix('>'))
+        .unwrap_or(type_)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tl::{parse, TEST_SCHEMA};
+    use std::path::Path;
+
+    fn file<'a>(files: &'a [GeneratedFile], path: &str) -> &'a str {
+        &files
+            .iter()
+            .find(|f| f.path == Path::new(path))
+            .unwrap_or_else(|| panic!("{} not generated", path))
+            .content
+    }
+
+    #[test]
+    fn test_derives() {
+        let schema = parse(
+            r#"
+//@description A point on the map @latitude Latitude @longitude Longitude
+location latitude:double longitude:double = Location;
+
+//@description A venue @location Venue location @title Venue name
+venue location:location title:string = Venue;
+
+//@description A message @venues Venues
+message venues:vector<venue> = Message;
+
+//@description A user @id User identifier
+user id:int32 = User;
+"#,
+        )
+        .unwrap();
+        let generator = Generator::new(&schema);
+        assert_eq!(generator.derives("venue"), "PartialEq");
+        assert_eq!(generator.derives("message"), "PartialEq");
+        assert_eq!(generator.derives("user"), "PartialEq, Eq, Hash");
+    }
+
+    #[test]
+    fn test_default_constructors() {
+        let schema = parse(
+            r#"
+//@class RichText @description Describes a text object inside an instant-view web page
+
+//@description An anchor @text Text @name Anchor name
+richTextAnchor text:RichText name:string = RichText;
+
+//@description A bold rich text @text Text
+richTextBold text:RichText = RichText;
+
+//@description A plain text @text Text
+richTextPlain text:string = RichText;
+
+//@description A caption @text Block text @credit Block credit (like HTML tag <cite>)
+pageBlockCaption text:RichText credit:RichText = PageBlockCaption;
+
+//@class ChatType @description Describes the type of a chat
+
+//@description An ordinary chat with a user @user_id User identifier
+chatTypePrivate user_id:int32 = ChatType;
+
+//@description A basic group @basic_group_id Basic group identifier
+chatTypeBasicGroup basic_group_id:int32 = ChatType;
+"#,
+        )
+        .unwrap();
+        let defaults = default_constructors(&schema);
+        assert_eq!(defaults.get("RichText"), Some(&"richTextPlain"));
+        assert_eq!(defaults.get("ChatType"), Some(&"chatTypeBasicGroup"));
+        assert_eq!(defaults.len(), 2);
+    }
+
+    #[test]
+    fn test_names() {
+        assert_eq!(pascal_case("tMeUrls"), "TMeUrls");
+        assert_eq!(snake_case("TMeUrls"), "t_me_urls");
+        assert_eq!(snake_case("getChatHistory"), "get_chat_history");
+        assert_eq!(variant_name("ChatType", "chatTypePrivate"), "Private");
+        assert_eq!(variant_name("RichText", "richTexts"), "RichTexts");
+        assert_eq!(
+            variant_name("AuthorizationState", "getAuthorizationState"),
+            "GetAuthorizationState"
+        );
+        assert_eq!(rust_type("vector<vector<int53>>"), "Vec<Vec<i64>>");
+        assert_eq!(rust_type("int32"), "i32");
+        assert_eq!(rust_type("vector<bytes>"), "Vec<Vec<u8>>");
+    }
+
+    #[test]
+    fn test_id_type() {
+        let param = |name: &str, type_: &str| Param {
+            name: name.to_string(),
+            type_: type_.to_string(),
+            description: String::new(),
+        };
+        assert_eq!(
+            id_type("call", &param("id", "int32")),
+            Some("CallIdentifier")
+        );
+        assert_eq!(
+            id_type("callId", &param("id", "int32")),
+            Some("CallIdentifier")
+        );
+        assert_eq!(
+            id_type("acceptCall", &param("call_id", "int32")),
+            Some("CallIdentifier")
+        );
+        assert_eq!(id_type("call", &param("user_id", "int32")), Some("UserId"));
+        assert_eq!(
+            id_type("addChatMembers", &param("user_ids", "vector<int32>")),
+            Some("UserId")
+        );
+        assert_eq!(id_type("chat", &param("id", "int53")), Some("ChatId"));
+        assert_eq!(id_type("chatPhoto", &param("id", "int64")), None);
+    }
+
+    #[test]
+    fn test_generate() {
+        let files = generate(&parse(TEST_SCHEMA).unwrap(), "1.6");
+        let paths: Vec<String> = files
+            .iter()
+            .map(|f| f.path.to_string_lossy().to_string())
+            .collect();
+        assert_eq!(
+            paths,
+            vec![
+                "src/types/chat_type.rs",
+                "src/types/error.rs",
+                "src/types/chat.rs",
+                "src/types/functions.rs",
+                "src/types/mod.rs",
+                "src/types/_td_type.rs",
+                "src/client/api/methods.rs",
+            ]
+        );
+
+        let chat_type = file(&files, "src/types/chat_type.rs");
+        assert!(chat_type.contains("pub trait TDChatType: Debug + RObject {}"));
+        assert!(chat_type.contains("#[serde(tag = \"@type\")]\npub enum ChatType {"));
+        assert!(chat_type.contains(
+            "    /// An ordinary chat with a user\n    #[serde(rename = \"chatTypePrivate\")]\n    Private(ChatTypePrivate),\n"
+        ));
+        assert!(
+            chat_type.contains("            ChatType::Private(t) => t.serialize(serializer),\n")
+        );
+        assert!(chat_type.contains("impl TDChatType for ChatTypeSupergroup {}"));
+        assert!(!chat_type.contains("_Default"));
+        assert!(chat_type.contains(
+            "    fn default() -> Self {\n        ChatType::Private(ChatTypePrivate::default())\n    }"
+        ));
+
+        let chat = file(&files, "src/types/chat.rs");
+        assert!(chat.contains("    #[serde(rename(serialize = \"type\", deserialize = \"type\"))]\n    type_: ChatType,"));
+        assert!(chat.contains("    #[serde(with = \"super::_common::int64\")]\n    order: i64,"));
+        assert!(chat.contains("    description: Option<String>,"));
+        assert!(chat.contains(
+            "#[derive(Debug, Clone, Default, Serialize, Deserialize)]\n#[serde(tag = \"@type\", rename = \"chat\")]\npub struct Chat {"
+        ));
+        assert!(chat.contains("impl PartialEq for Chat {\n    fn eq(&self, other: &Self) -> bool {\n        self.id == other.id && self.type_ == other.type_"));
+        assert!(chat.contains("impl Eq for Chat {}"));
+        assert!(chat.contains("        std::hash::Hash::hash(&self.id, state);\n"));
+        assert!(!chat.contains("other.extra"));
+        assert!(!chat.contains("self.extra, state"));
+        assert!(!chat.contains("td_name: String"));
+        assert!(chat.contains("pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self"));
+        assert!(chat.contains("pub fn id(&self) -> ChatId {\n        self.id\n    }"));
+        assert!(chat.contains("pub fn id<T: Into<ChatId>>(&mut self, id: T) -> &mut Self"));
+        assert!(chat.contains("missing: vec![\"id\", \"type_\", \"title\", \"order\"],"));
+        assert!(chat.contains(
+            "self.inner.title = title.as_ref().to_string();\n        self.missing.retain(|f| *f != \"title\");\n"
+        ));
+        assert!(chat.contains("pub fn try_build(&self) -> RTDResult<Chat> {"));
+        assert!(chat.contains("pub fn to_builder(&self) -> RTDChatBuilder {"));
+        assert!(chat.contains(
+            "pub fn with_title<T: AsRef<str>>(mut self, title: T) -> Self {\n        self.title = title.as_ref().to_string();\n        self\n    }"
+        ));
+        assert!(chat.contains("pub fn with_type<T: AsRef<ChatType>>(mut self, type_: T) -> Self {"));
+        assert!(chat.contains(
+            "pub fn into_description(self) -> Option<String> {\n        self.description\n    }"
+        ));
+        assert!(chat.contains("pub fn into_type(self) -> ChatType {"));
+        assert!(!chat.contains("pub fn into_order("));
+        assert!(chat.contains(
+            "assert_round_trip(Chat::builder().id(9007199254740991i64).type_(ChatType::Private(ChatTypePrivate::builder().user_id(i32::MAX).build())).title(\"text\").order(i64::MAX).build());"
+        ));
+
+        let functions = file(&files, "src/types/functions.rs");
+        assert!(functions.contains("impl RFunction for GetChat {\n    type Response = Chat;\n}"));
+        assert!(!functions.contains("extra: Option<String>"));
+        assert!(functions.contains("fn extra(&self) -> Option<String> {\n        None\n    }"));
+        assert!(chat.contains("    extra: Option<String>,\n"));
+
+        let module = file(&files, "src/types/mod.rs");
+        assert!(module.contains("pub const TDLIB_VERSION: &str = \"1.6\";"));
+        assert!(module.contains("pub use self::chat_type::*;"));
+        assert!(module.contains("mod functions;"));
+        assert!(module.contains("mod _display;"));
+        assert!(module.contains("pub use self::_ids::{BasicGroupId, CallIdentifier, ChatId,"));
+
+        let td_type = file(&files, "src/types/_td_type.rs");
+        assert!(td_type.contains("    Chat(Chat),\n}"));
+        assert!(td_type.contains("         (chat, Chat);"));
+
+        let methods = file(&files, "src/client/api/methods.rs");
+        assert!(methods.contains(
+            "    /// Returns information about a chat by its identifier\n    pub async fn get_chat<C: AsRef<GetChat>>(&self, get_chat: C) -> RTDResult<Chat> {\n        self.send(get_chat.as_ref()).await\n    }"
+        ));
+    }
+}
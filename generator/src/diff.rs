@@ -0,0 +1,219 @@
+//! Comparison of two schema versions.
+use crate::tl::{Definition, Schema};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Differences between two schemas, names are sorted.
+#[derive(Debug, Default, PartialEq)]
+pub struct SchemaDiff {
+    pub added_classes: Vec<String>,
+    pub removed_classes: Vec<String>,
+    pub added_types: Vec<String>,
+    pub removed_types: Vec<String>,
+    /// Types with changed parameters or result
+    pub changed_types: Vec<DefinitionChange>,
+    pub added_functions: Vec<String>,
+    pub removed_functions: Vec<String>,
+    pub changed_functions: Vec<DefinitionChange>,
+}
+
+/// Changes of type constructor or function with the same name.
+#[derive(Debug, Default, PartialEq)]
+pub struct DefinitionChange {
+    pub name: String,
+    /// `name:type` of added parameters
+    pub added_params: Vec<String>,
+    /// `name:type` of removed parameters
+    pub removed_params: Vec<String>,
+    /// Old and new result type, if changed
+    pub result: Option<(String, String)>,
+}
+
+impl SchemaDiff {
+    pub fn is_empty(&self) -> bool {
+        *self == SchemaDiff::default()
+    }
+}
+
+pub fn diff(old: &Schema, new: &Schema) -> SchemaDiff {
+    let old_classes: Vec<&str> = old.classes.iter().map(|c| c.name.as_str()).collect();
+    let new_classes: Vec<&str> = new.classes.iter().map(|c| c.name.as_str()).collect();
+    let (added_classes, removed_classes) = added_removed(&old_classes, &new_classes);
+    let (added_types, removed_types, changed_types) = diff_definitions(&old.types, &new.types);
+    let (added_functions, removed_functions, changed_functions) =
+        diff_definitions(&old.functions, &new.functions);
+    SchemaDiff {
+        added_classes,
+        removed_classes,
+        added_types,
+        removed_types,
+        changed_types,
+        added_functions,
+        removed_functions,
+        changed_functions,
+    }
+}
+
+fn added_removed(old: &[&str], new: &[&str]) -> (Vec<String>, Vec<String>) {
+    let mut added: Vec<String> = new
+        .iter()
+        .filter(|n| !old.contains(n))
+        .map(|n| n.to_string())
+        .collect();
+    let mut removed: Vec<String> = old
+        .iter()
+        .filter(|o| !new.contains(o))
+        .map(|o| o.to_string())
+        .collect();
+    added.sort();
+    removed.sort();
+    (added, removed)
+}
+
+fn diff_definitions(
+    old: &[Definition],
+    new: &[Definition],
+) -> (Vec<String>, Vec<String>, Vec<DefinitionChange>) {
+    let old: BTreeMap<&str, &Definition> = old.iter().map(|d| (d.name.as_str(), d)).collect();
+    let new: BTreeMap<&str, &Definition> = new.iter().map(|d| (d.name.as_str(), d)).collect();
+    let old_names: Vec<&str> = old.keys().copied().collect();
+    let new_names: Vec<&str> = new.keys().copied().collect();
+    let (added, removed) = added_removed(&old_names, &new_names);
+
+    let mut changed = Vec::new();
+    for (name, old_definition) in old.iter() {
+        let new_definition = match new.get(name) {
+            Some(new_definition) => new_definition,
+            None => continue,
+        };
+        let old_params: Vec<String> = old_definition
+            .params
+            .iter()
+            .map(|p| format!("{}:{}", p.name, p.type_))
+            .collect();
+        let new_params: Vec<String> = new_definition
+            .params
+            .iter()
+            .map(|p| format!("{}:{}", p.name, p.type_))
+            .collect();
+        let change = DefinitionChange {
+            name: name.to_string(),
+            added_params: new_params
+                .iter()
+                .filter(|p| !old_params.contains(p))
+                .cloned()
+                .collect(),
+            removed_params: old_params
+                .iter()
+                .filter(|p| !new_params.contains(p))
+                .cloned()
+                .collect(),
+            result: if old_definition.result != new_definition.result {
+                Some((old_definition.result.clone(), new_definition.result.clone()))
+            } else {
+                None
+            },
+        };
+        if !change.added_params.is_empty()
+            || !change.removed_params.is_empty()
+            || change.result.is_some()
+        {
+            changed.push(change);
+        }
+    }
+    (added, removed, changed)
+}
+
+impl fmt::Display for SchemaDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "schemas are equal");
+        }
+        write_names(f, "added classes", &self.added_classes)?;
+        write_names(f, "removed classes", &self.removed_classes)?;
+        write_names(f, "added types", &self.added_types)?;
+        write_names(f, "removed types", &self.removed_types)?;
+        write_changes(f, "changed types", &self.changed_types)?;
+        write_names(f, "added functions", &self.added_functions)?;
+        write_names(f, "removed functions", &self.removed_functions)?;
+        write_changes(f, "changed functions", &self.changed_functions)
+    }
+}
+
+fn write_names(f: &mut fmt::Formatter<'_>, title: &str, names: &[String]) -> fmt::Result {
+    if names.is_empty() {
+        return Ok(());
+    }
+    writeln!(f, "{} ({}):", title, names.len())?;
+    for name in names {
+        writeln!(f, "  {}", name)?;
+    }
+    Ok(())
+}
+
+fn write_changes(
+    f: &mut fmt::Formatter<'_>,
+    title: &str,
+    changes: &[DefinitionChange],
+) -> fmt::Result {
+    if changes.is_empty() {
+        return Ok(());
+    }
+    writeln!(f, "{} ({}):", title, changes.len())?;
+    for change in changes {
+        writeln!(f, "  {}", change.name)?;
+        for param in change.added_params.iter() {
+            writeln!(f, "    + {}", param)?;
+        }
+        for param in change.removed_params.iter() {
+            writeln!(f, "    - {}", param)?;
+        }
+        if let Some((old, new)) = &change.result {
+            writeln!(f, "    = {} -> {}", old, new)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tl::{parse, TEST_SCHEMA};
+
+    #[test]
+    fn test_diff() {
+        let old = parse(TEST_SCHEMA).unwrap();
+        assert!(diff(&old, &old).is_empty());
+
+        let new_schema = TEST_SCHEMA
+            .replace(
+                "order:int64 description:string = Chat;",
+                "positions:vector<chatPosition> description:string = Chat;",
+            )
+            .replace("chatTypeSupergroup supergroup_id:int32 is_channel:Bool = ChatType;", "")
+            .replace(
+                "---functions---",
+                "//@description Describes a position of a chat in a chat list @order A parameter used to determine order of the chat in the chat list\nchatPosition order:int64 = ChatPosition;\n\n---functions---",
+            );
+        let new = parse(&new_schema).unwrap();
+        let schema_diff = diff(&old, &new);
+        assert_eq!(
+            schema_diff,
+            SchemaDiff {
+                added_types: vec!["chatPosition".to_string()],
+                removed_types: vec!["chatTypeSupergroup".to_string()],
+                changed_types: vec![DefinitionChange {
+                    name: "chat".to_string(),
+                    added_params: vec!["positions:vector<chatPosition>".to_string()],
+                    removed_params: vec!["order:int64".to_string()],
+                    result: None,
+                }],
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            schema_diff.to_string(),
+            "added types (1):\n  chatPosition\nremoved types (1):\n  chatTypeSupergroup\nchanged types (1):\n  chat\n    + positions:vector<chatPosition>\n    - order:int64\n"
+        );
+    }
+}
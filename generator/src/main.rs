@@ -0,0 +1,98 @@
+//! Generates rust-tdlib types and API methods from TDlib `td_api.tl` schema,
+//! compares schema versions.
+//!
+//! `td_api.tl` of required TDlib version can be found in TDlib repository: `td/generate/scheme/td_api.tl`.
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{exit, Command};
+
+mod codegen;
+mod diff;
+mod tl;
+
+const USAGE: &str = "Usage:
+    rtd-generator generate <td_api.tl> --tdlib-version <version> [--out <rust-tdlib dir>] [--no-fmt]
+        writes src/types/*.rs and src/client/api/methods.rs, formats them with rustfmt
+    rtd-generator diff <old td_api.tl> <new td_api.tl>
+        prints added, removed and changed classes, types and functions";
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let result = match args.first().map(|a| a.as_str()) {
+        Some("generate") => generate(&args[1..]),
+        Some("diff") => diff(&args[1..]),
+        _ => Err(USAGE.to_string()),
+    };
+    if let Err(e) = result {
+        eprintln!("{}", e);
+        exit(1);
+    }
+}
+
+fn generate(args: &[String]) -> Result<(), String> {
+    let mut schema_path = None;
+    let mut tdlib_version = None;
+    let mut out = PathBuf::from(".");
+    let mut format = true;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--tdlib-version" => tdlib_version = args.next().cloned(),
+            "--out" => out = PathBuf::from(args.next().ok_or(USAGE)?),
+            "--no-fmt" => format = false,
+            path if schema_path.is_none() && !path.starts_with("--") => {
+                schema_path = Some(path.to_string())
+            }
+            _ => return Err(USAGE.to_string()),
+        }
+    }
+    let schema_path = schema_path.ok_or(USAGE)?;
+    let tdlib_version = tdlib_version.ok_or(USAGE)?;
+
+    let schema = read_schema(&schema_path)?;
+    let files = codegen::generate(&schema, &tdlib_version);
+    let mut written = Vec::with_capacity(files.len());
+    for file in files {
+        let path = out.join(&file.path);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| format!("can't create {:?}: {}", dir, e))?;
+        }
+        fs::write(&path, file.content).map_err(|e| format!("can't write {:?}: {}", path, e))?;
+        written.push(path);
+    }
+    if format {
+        rustfmt(&written)?;
+    }
+    println!("{} files generated", written.len());
+    Ok(())
+}
+
+fn rustfmt(files: &[PathBuf]) -> Result<(), String> {
+    let status = Command::new("rustfmt")
+        .arg("--edition")
+        .arg("2018")
+        .args(files)
+        .status()
+        .map_err(|e| format!("can't run rustfmt: {}; use --no-fmt to skip formatting", e))?;
+    if !status.success() {
+        return Err(format!("rustfmt failed: {}", status));
+    }
+    Ok(())
+}
+
+fn diff(args: &[String]) -> Result<(), String> {
+    match args {
+        [old, new] => {
+            let schema_diff = diff::diff(&read_schema(old)?, &read_schema(new)?);
+            print!("{}", schema_diff);
+            Ok(())
+        }
+        _ => Err(USAGE.to_string()),
+    }
+}
+
+fn read_schema<P: AsRef<Path>>(path: P) -> Result<tl::Schema, String> {
+    let path = path.as_ref();
+    let source = fs::read_to_string(path).map_err(|e| format!("can't read {:?}: {}", path, e))?;
+    tl::parse(&source).map_err(|e| format!("can't parse {:?}: {}", path, e))
+}
@@ -0,0 +1,295 @@
+//! Parser of TDlib TL schema (`td_api.tl`).
+//!
+//! Only the subset of TL used by TDlib JSON interface is supported: documentation comments,
+//! type constructors and functions. Builtin types declared at the beginning of the schema are skipped.
+const BUILTIN_TYPES: &[&str] = &[
+    "double",
+    "string",
+    "int32",
+    "int53",
+    "int64",
+    "bytes",
+    "boolFalse",
+    "boolTrue",
+    "vector",
+];
+
+/// Parameter of a type constructor or a function.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Param {
+    pub name: String,
+    /// TL type, e.g. `int53`, `vector<string>` or `ChatType`
+    pub type_: String,
+    pub description: String,
+}
+
+/// Type constructor or function.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Definition {
+    /// TL name, e.g. `chatTypePrivate` or `getChat`
+    pub name: String,
+    pub description: String,
+    pub params: Vec<Param>,
+    /// Type of constructed object or function result, e.g. `ChatType` or `Chat`
+    pub result: String,
+}
+
+/// Abstract class, implemented by several type constructors.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Class {
+    pub name: String,
+    pub description: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Schema {
+    pub classes: Vec<Class>,
+    pub types: Vec<Definition>,
+    pub functions: Vec<Definition>,
+}
+
+impl Schema {
+    pub fn is_class(&self, name: &str) -> bool {
+        self.classes.iter().any(|c| c.name == name)
+    }
+}
+
+/// Parses content of `td_api.tl`
+pub fn parse(source: &str) -> Result<Schema, String> {
+    let mut schema = Schema::default();
+    let mut is_function = false;
+    // documentation of next definition or class, `//@` and `//-` prefixes removed
+    let mut comment = String::new();
+
+    for (number, line) in source.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            flush_class(&mut schema, &mut comment)?;
+            continue;
+        }
+        if line == "---functions---" {
+            flush_class(&mut schema, &mut comment)?;
+            is_function = true;
+            continue;
+        }
+        if line == "---types---" {
+            continue;
+        }
+        if let Some(doc) = line.strip_prefix("//") {
+            let doc = doc.strip_prefix('-').unwrap_or(doc).trim();
+            if doc.starts_with("@description") && comment.trim_start().starts_with("@class") {
+                flush_class(&mut schema, &mut comment)?;
+            }
+            comment.push(' ');
+            comment.push_str(doc);
+            continue;
+        }
+        flush_class(&mut schema, &mut comment)?;
+
+        let definition = parse_definition(line, &comment)
+            .map_err(|e| format!("line {}: {}: {}", number + 1, e, line))?;
+        comment.clear();
+        let definition = match definition {
+            Some(definition) => definition,
+            None => continue,
+        };
+        if is_function {
+            schema.functions.push(definition)
+        } else {
+            schema.types.push(definition)
+        }
+    }
+    flush_class(&mut schema, &mut comment)?;
+    Ok(schema)
+}
+
+fn flush_class(schema: &mut Schema, comment: &mut String) -> Result<(), String> {
+    if !comment.trim_start().starts_with("@class") {
+        return Ok(());
+    }
+    let tags = split_tags(comment, &["class", "description"]);
+    let name = tag(&tags, "class")
+        .filter(|name| !name.is_empty())
+        .ok_or_else(|| format!("class without name: {}", comment.trim()))?;
+    schema.classes.push(Class {
+        name: name.to_string(),
+        description: tag(&tags, "description").unwrap_or_default().to_string(),
+    });
+    comment.clear();
+    Ok(())
+}
+
+fn parse_definition(line: &str, comment: &str) -> Result<Option<Definition>, String> {
+    let line = line
+        .strip_suffix(';')
+        .ok_or_else(|| "definition must end with `;`".to_string())?;
+    let mut parts = line.splitn(2, '=');
+    let (left, result) = match (parts.next(), parts.next()) {
+        (Some(left), Some(result)) => (left.trim(), result.trim()),
+        _ => return Err("definition must contain `=`".to_string()),
+    };
+    let mut tokens = left.split_whitespace();
+    let name = tokens
+        .next()
+        .ok_or_else(|| "definition without name".to_string())?;
+    if BUILTIN_TYPES.contains(&name) {
+        return Ok(None);
+    }
+
+    let mut params = Vec::new();
+    for token in tokens {
+        let mut param = token.splitn(2, ':');
+        match (param.next(), param.next()) {
+            (Some(name), Some(type_)) if !name.is_empty() && !type_.is_empty() => {
+                params.push(Param {
+                    name: name.to_string(),
+                    type_: type_.to_string(),
+                    description: String::new(),
+                })
+            }
+            _ => return Err(format!("invalid parameter `{}`", token)),
+        }
+    }
+
+    // parameter `description` is documented with `@param_description`, because `@description` describes definition
+    let mut known_tags = vec!["description", "param_description"];
+    known_tags.extend(params.iter().map(|p| p.name.as_str()));
+    let tags = split_tags(comment, &known_tags);
+    for param in params.iter_mut() {
+        let tag_name = match param.name.as_str() {
+            "description" => "param_description",
+            name => name,
+        };
+        param.description = tag(&tags, tag_name).unwrap_or_default().to_string();
+    }
+
+    Ok(Some(Definition {
+        name: name.to_string(),
+        description: tag(&tags, "description").unwrap_or_default().to_string(),
+        params,
+        result: result.to_string(),
+    }))
+}
+
+// splits `@tag value @other_tag value` documentation, unknown `@words` are treated as part of value
+fn split_tags<'a>(comment: &'a str, known_tags: &[&str]) -> Vec<(&'a str, &'a str)> {
+    // tag name and range of its value
+    let mut tags: Vec<(&str, usize, usize)> = Vec::new();
+    let mut position = 0;
+    for word in comment.split_whitespace() {
+        let start = position + comment[position..].find(word).unwrap_or(0);
+        position = start + word.len();
+        match word.strip_prefix('@') {
+            Some(name) if known_tags.contains(&name) => {
+                if let Some((_, _, end)) = tags.last_mut() {
+                    *end = start;
+                }
+                tags.push((name, position, comment.len()));
+            }
+            _ => {}
+        }
+    }
+    tags.into_iter()
+        .map(|(name, start, end)| (name, comment[start..end].trim()))
+        .collect()
+}
+
+fn tag<'a>(tags: &[(&str, &'a str)], name: &str) -> Option<&'a str> {
+    tags.iter()
+        .find(|(tag, _)| *tag == name)
+        .map(|(_, value)| *value)
+}
+
+#[cfg(test)]
+pub(crate) const TEST_SCHEMA: &str = r#"
+double ? = Double;
+string ? = String;
+
+int32 = Int32;
+int53 = Int53;
+int64 = Int64;
+bytes = Bytes;
+
+boolFalse = Bool;
+boolTrue = Bool;
+
+vector {t:Type} # [ t ] = Vector t;
+
+
+//@description An object of this type can be returned on every function call, in case of an error
+//@code Error code; subject to future changes. If the error code is 406, the error message must not be processed in any way and must not be displayed to the user @message Error message; subject to future changes
+error code:int32 message:string = Error;
+
+//@class ChatType @description Describes the type of a chat
+
+//@description An ordinary chat with a user @user_id User identifier
+chatTypePrivate user_id:int32 = ChatType;
+
+//@description A supergroup (i.e. a chat with up to GetOption("supergroup_max_size") other users), or channel (with unlimited members) @supergroup_id Supergroup or channel identifier @is_channel True, if the supergroup is a channel
+chatTypeSupergroup supergroup_id:int32 is_channel:Bool = ChatType;
+
+//@description A chat @id Chat unique identifier @type Type of the chat @title Chat title
+//@order Descending parameter by which chats are sorted in the main chat list. If the order number of two chats is the same,
+//-they must be sorted in descending order by ID
+//@param_description Chat description; may be null
+chat id:int53 type:ChatType title:string order:int64 description:string = Chat;
+
+---functions---
+
+//@description Returns information about a chat by its identifier @chat_id Chat identifier
+getChat chat_id:int53 = Chat;
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let schema = parse(TEST_SCHEMA).unwrap();
+        assert_eq!(
+            schema.classes,
+            vec![Class {
+                name: "ChatType".to_string(),
+                description: "Describes the type of a chat".to_string()
+            }]
+        );
+        let names: Vec<&str> = schema.types.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec!["error", "chatTypePrivate", "chatTypeSupergroup", "chat"]
+        );
+
+        let chat = &schema.types[3];
+        assert_eq!(chat.description, "A chat");
+        assert_eq!(chat.result, "Chat");
+        assert_eq!(chat.params.len(), 5);
+        assert_eq!(chat.params[1].type_, "ChatType");
+        assert_eq!(
+            chat.params[3].description,
+            "Descending parameter by which chats are sorted in the main chat list. If the order number of two chats is the same, they must be sorted in descending order by ID"
+        );
+        assert_eq!(chat.params[4].name, "description");
+        assert_eq!(chat.params[4].description, "Chat description; may be null");
+
+        let supergroup = &schema.types[2];
+        assert!(supergroup
+            .description
+            .contains("GetOption(\"supergroup_max_size\")"));
+        assert_eq!(
+            supergroup.params[1].description,
+            "True, if the supergroup is a channel"
+        );
+
+        assert_eq!(schema.functions.len(), 1);
+        assert_eq!(schema.functions[0].name, "getChat");
+        assert_eq!(schema.functions[0].params[0].description, "Chat identifier");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse("//@description Broken\nbroken id:int32 = Broken").is_err());
+        assert!(parse("//@description Broken\nbroken id = Broken;").is_err());
+        assert!(parse("//@class @description No name\n").is_err());
+    }
+}
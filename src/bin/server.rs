@@ -0,0 +1,145 @@
+//! Exposes local TDlib instance on a socket, so it can be used with `rust_tdlib::client::remote::RemoteApi`.
+//! Every accepted connection gets its own TDlib instance.
+//!
+//! `RTD_SERVER_ADDR=127.0.0.1:7878 cargo run --bin rust-tdlib-server`,
+//! `RTD_SERVER_WS_ADDR=127.0.0.1:7879 cargo run --bin rust-tdlib-server`
+//! or `RTD_SERVER_UNIX_SOCKET=/tmp/tdlib.sock cargo run --bin rust-tdlib-server`
+//!
+//! If `RTD_SERVER_SECRET` is set, clients must send it before any request, otherwise connection is closed.
+//! TCP and WebSocket servers refuse to listen on non-loopback addresses without it.
+#[macro_use]
+extern crate log;
+
+use rust_tdlib::client::{
+    api::RawApi,
+    remote::{accept_auth, accept_websocket, serve_connection},
+    Tdlib,
+};
+use std::env;
+use std::io::{self, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::Arc;
+use std::thread;
+
+const DEFAULT_ADDR: &str = "127.0.0.1:7878";
+const READ_UPDATES_TIMEOUT: f64 = 1.0;
+
+fn serve<R, W>(reader: R, mut writer: W, secret: Option<Arc<String>>)
+where
+    R: Read + Send + 'static,
+    W: Write + Send + 'static,
+{
+    thread::spawn(move || {
+        let mut reader = BufReader::new(reader);
+        // TDlib instance is created only for authenticated clients
+        if let Some(secret) = secret {
+            if let Err(e) = accept_auth(&mut reader, &mut writer, &secret) {
+                warn!("connection rejected: {}", e);
+                return;
+            }
+        }
+        match serve_connection(
+            RawApi::new(Tdlib::new()),
+            reader,
+            writer,
+            READ_UPDATES_TIMEOUT,
+        ) {
+            Ok(_) => info!("connection closed"),
+            Err(e) => error!("connection closed with error: {}", e),
+        }
+    });
+}
+
+#[cfg(unix)]
+fn serve_unix(path: &str, secret: Option<Arc<String>>) -> io::Result<()> {
+    let listener = std::os::unix::net::UnixListener::bind(path)?;
+    info!("listening on {}", path);
+    for stream in listener.incoming() {
+        // failed connection must not stop the server
+        match stream.and_then(|stream| Ok((stream.try_clone()?, stream))) {
+            Ok((reader, writer)) => serve(reader, writer, secret.clone()),
+            Err(e) => warn!("can't accept connection: {}", e),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn serve_unix(_path: &str, _secret: Option<Arc<String>>) -> io::Result<()> {
+    Err(io::Error::new(
+        io::ErrorKind::Other,
+        "unix sockets not supported on this platform",
+    ))
+}
+
+// anyone who can connect gets TDlib instance, so other hosts must know the secret
+fn bind(addr: &str, secret: &Option<Arc<String>>) -> io::Result<TcpListener> {
+    if secret.is_none() && !addr.to_socket_addrs()?.all(|a| a.ip().is_loopback()) {
+        return Err(io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            format!(
+                "refusing to listen on non-loopback address {} without RTD_SERVER_SECRET",
+                addr
+            ),
+        ));
+    }
+    let listener = TcpListener::bind(addr)?;
+    info!("listening on {}", addr);
+    Ok(listener)
+}
+
+// failed connection must not stop the server, so errors are only logged
+fn accept(stream: io::Result<TcpStream>) -> Option<TcpStream> {
+    let accepted = stream.and_then(|stream| {
+        info!("accepted connection from {}", stream.peer_addr()?);
+        stream.set_nodelay(true)?;
+        Ok(stream)
+    });
+    match accepted {
+        Ok(stream) => Some(stream),
+        Err(e) => {
+            warn!("can't accept connection: {}", e);
+            None
+        }
+    }
+}
+
+fn serve_tcp(addr: &str, secret: Option<Arc<String>>) -> io::Result<()> {
+    for stream in bind(addr, &secret)?.incoming().filter_map(accept) {
+        match stream.try_clone() {
+            Ok(reader) => serve(reader, stream, secret.clone()),
+            Err(e) => warn!("can't accept connection: {}", e),
+        }
+    }
+    Ok(())
+}
+
+fn serve_websocket(addr: &str, secret: Option<Arc<String>>) -> io::Result<()> {
+    for stream in bind(addr, &secret)?.incoming().filter_map(accept) {
+        let secret = secret.clone();
+        // handshake of one client must not block others
+        thread::spawn(move || match accept_websocket(stream) {
+            Ok((reader, writer)) => serve(reader, writer, secret),
+            Err(e) => warn!("websocket handshake failed: {}", e),
+        });
+    }
+    Ok(())
+}
+
+fn main() -> io::Result<()> {
+    env_logger::init();
+    let secret = env::var("RTD_SERVER_SECRET")
+        .ok()
+        .filter(|secret| !secret.is_empty())
+        .map(Arc::new);
+    if let Ok(path) = env::var("RTD_SERVER_UNIX_SOCKET") {
+        return serve_unix(&path, secret);
+    }
+    if let Ok(addr) = env::var("RTD_SERVER_WS_ADDR") {
+        return serve_websocket(&addr, secret);
+    }
+    serve_tcp(
+        &env::var("RTD_SERVER_ADDR").unwrap_or_else(|_| DEFAULT_ADDR.to_string()),
+        secret,
+    )
+}
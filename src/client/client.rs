@@ -1,30 +1,46 @@
 //! Handlers for all incoming data
 use super::observer::OBSERVER;
 use async_trait::async_trait;
+#[cfg(feature = "client")]
 use rtdlib_sys::Tdlib;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
-use super::api::{Api, RawApi, TdLibClient};
+#[cfg(feature = "client")]
+use super::api::RawApi;
+use super::api::{Api, TdLibClient};
+use super::database_key::DatabaseKeyProvider;
+#[cfg(feature = "dynamic-tdjson")]
+use super::tdjson::{DynamicApi, TdJson};
 use crate::types::{
-    AuthorizationState, AuthorizationStateWaitOtherDeviceConfirmation,
-    AuthorizationStateWaitPhoneNumber, AuthorizationStateWaitRegistration, RegisterUser,
+    AcceptTermsOfService, AuthenticationCodeType, AuthorizationState,
+    AuthorizationStateWaitOtherDeviceConfirmation, AuthorizationStateWaitPhoneNumber,
+    AuthorizationStateWaitRegistration, LogOut, RecoverAuthenticationPassword, RegisterUser,
+    RequestAuthenticationPasswordRecovery, ResendAuthenticationCode, TermsOfService,
+    UpdateTermsOfService,
 };
 use crate::{
     errors::{RTDError, RTDResult},
-    types::from_json,
     types::TdType,
+    types::{from_json, TdHeader},
     types::{
         AuthorizationStateWaitCode, AuthorizationStateWaitEncryptionKey,
         AuthorizationStateWaitPassword, CheckAuthenticationCode, CheckAuthenticationPassword,
-        CheckDatabaseEncryptionKey, SetAuthenticationPhoneNumber, SetTdlibParameters,
-        TdlibParameters, UpdateAuthorizationState,
+        CheckDatabaseEncryptionKey, SetAuthenticationPhoneNumber, SetDatabaseEncryptionKey,
+        SetTdlibParameters, TdlibParameters, UpdateAuthorizationState,
     },
 };
 use std::io;
 use std::sync::atomic::{AtomicBool, Ordering};
-use tokio::{sync::mpsc, task::JoinHandle};
+use std::thread;
+use std::time::Duration;
+use tokio::{
+    sync::{mpsc, Notify},
+    task::{self, JoinHandle},
+    time::{delay_until, Instant},
+};
 
 const CLOSED_CHANNEL_ERROR: RTDError = RTDError::Internal("channel closed");
+const RECEIVED_JSON_CHANNEL_SIZE: usize = 100;
 
 /// `AuthStateHandler` trait provides methods that returns data, required for authentication
 ///It allows you to handle particular "auth states", such as [WaitPassword](crate::types::AuthorizationStateWaitPassword), [WaitPhoneNumber](crate::types::AuthorizationStateWaitPhoneNumber) and so on.
@@ -35,15 +51,29 @@ pub trait AuthStateHandler {
         &self,
         wait_device_confirmation: &AuthorizationStateWaitOtherDeviceConfirmation,
     );
-    /// Returns wait code
-    async fn handle_wait_code(&self, wait_code: &AuthorizationStateWaitCode) -> String;
-    /// Returns database encryption key
+    /// Returns wait code or asks to resend it or to cancel login.
+    /// Delivery type of the sent code and of the next one available with [code_info](crate::types::AuthorizationStateWaitCode::code_info)
+    async fn handle_wait_code(&self, wait_code: &AuthorizationStateWaitCode) -> WaitCodeAction;
+    /// Returns base64-encoded database encryption key, other values are rejected with [InvalidParameters](crate::errors::RTDError::InvalidParameters)
     async fn handle_encryption_key(
         &self,
         wait_encryption_key: &AuthorizationStateWaitEncryptionKey,
     ) -> String;
-    /// Returns password
-    async fn handle_wait_password(&self, wait_password: &AuthorizationStateWaitPassword) -> String;
+    /// Returns password or asks for password recovery.
+    /// Password hint available with [password_hint](crate::types::AuthorizationStateWaitPassword::password_hint)
+    async fn handle_wait_password(
+        &self,
+        wait_password: &AuthorizationStateWaitPassword,
+    ) -> WaitPasswordAction;
+    /// Returns password recovery code, sent to email address matching [recovery_email_address_pattern](crate::types::AuthorizationStateWaitPassword::recovery_email_address_pattern).
+    /// Called after recovery was requested, asked again if code is invalid.
+    /// `None` means that password will be entered instead, it's returned by default
+    async fn handle_password_recovery_code(
+        &self,
+        _wait_password: &AuthorizationStateWaitPassword,
+    ) -> Option<String> {
+        None
+    }
     /// Returns phone number
     async fn handle_wait_phone_number(
         &self,
@@ -54,6 +84,47 @@ pub trait AuthStateHandler {
         &self,
         wait_registration: &AuthorizationStateWaitRegistration,
     ) -> (String, String);
+    /// Returns `true` if user accepts [TermsOfService](crate::types::TermsOfService).
+    /// Called before registration and every time [UpdateTermsOfService](crate::types::UpdateTermsOfService) received.
+    /// Implementation have to check [min_user_age](crate::types::TermsOfService::min_user_age) by itself.
+    /// Declines by default, so registration fails and the account is deleted after declined update,
+    /// see [handle_terms_of_service_declined](crate::client::AuthStateHandler::handle_terms_of_service_declined)
+    async fn handle_terms_of_service(&self, _terms_of_service: &TermsOfService) -> bool {
+        warn!("terms of service declined, AuthStateHandler::handle_terms_of_service isn't implemented");
+        false
+    }
+    /// Called when terms of service from [UpdateTermsOfService](crate::types::UpdateTermsOfService) declined.
+    /// Telegram will delete the account after a while, it can be deleted immediately with
+    /// [delete_account](crate::client::api::Api::delete_account) with reason "Decline ToS update".
+    /// Logs a warning by default
+    async fn handle_terms_of_service_declined(&self, update: &UpdateTermsOfService) {
+        warn!(
+            "terms of service {} declined, account will be deleted by Telegram after a while",
+            update.terms_of_service_id()
+        );
+    }
+}
+
+/// Action to perform on [WaitCode](crate::types::AuthorizationStateWaitCode) state
+#[derive(Debug, Clone)]
+pub enum WaitCodeAction {
+    /// Check received authentication code
+    Code(String),
+    /// Resend authentication code with [next_type](crate::types::AuthenticationCodeInfo::next_type).
+    /// Code will be requested only when [timeout](crate::types::AuthenticationCodeInfo::timeout) expires
+    Resend,
+    /// Cancel login. Client will be closed
+    Cancel,
+}
+
+/// Action to perform on [WaitPassword](crate::types::AuthorizationStateWaitPassword) state
+#[derive(Debug, Clone)]
+pub enum WaitPasswordAction {
+    /// Check entered password
+    Password(String),
+    /// Send password recovery code to the recovery email address.
+    /// Has no effect if [has_recovery_email_address](crate::types::AuthorizationStateWaitPassword::has_recovery_email_address) is false
+    Recover,
 }
 
 #[derive(Debug, Clone)]
@@ -82,13 +153,46 @@ impl ConsoleAuthStateHandler {
         Self
     }
 
-    fn wait_input() -> String {
-        let mut input = String::new();
-        match io::stdin().read_line(&mut input) {
-            Ok(_) => input.trim().to_string(),
+    // stdin is read in blocking thread, so runtime isn't blocked while waiting for input,
+    // e.g. when terms of service update received after authorization
+    async fn wait_input() -> String {
+        let input = task::spawn_blocking(|| {
+            let mut input = String::new();
+            io::stdin().read_line(&mut input).map(|_| input)
+        })
+        .await
+        .expect("stdin reading task failed");
+        match input {
+            Ok(input) => input.trim().to_string(),
             Err(e) => panic!("Can not get input value: {:?}", e),
         }
     }
+
+    fn code_type_description(code_type: &AuthenticationCodeType) -> String {
+        match code_type {
+            AuthenticationCodeType::Call(_) => "phone call".to_string(),
+            AuthenticationCodeType::FlashCall(flash_call) => {
+                format!("flash call from number {}", flash_call.pattern())
+            }
+            AuthenticationCodeType::Sms(_) => "SMS".to_string(),
+            AuthenticationCodeType::TelegramMessage(_) => "Telegram message".to_string(),
+        }
+    }
+
+    async fn wait_confirmation() -> bool {
+        loop {
+            eprintln!("type \"y\" to confirm or \"n\" to decline");
+            match ConsoleAuthStateHandler::wait_input()
+                .await
+                .to_lowercase()
+                .as_str()
+            {
+                "y" | "yes" => return true,
+                "n" | "no" => return false,
+                _ => {}
+            }
+        }
+    }
 }
 
 #[async_trait]
@@ -103,25 +207,70 @@ impl AuthStateHandler for ConsoleAuthStateHandler {
         );
     }
 
-    async fn handle_wait_code(&self, _wait_code: &AuthorizationStateWaitCode) -> String {
-        eprintln!("wait for auth code");
-        ConsoleAuthStateHandler::wait_input()
+    async fn handle_wait_code(&self, wait_code: &AuthorizationStateWaitCode) -> WaitCodeAction {
+        let code_info = wait_code.code_info();
+        eprintln!(
+            "wait for auth code, sent via {}",
+            ConsoleAuthStateHandler::code_type_description(code_info.type_())
+        );
+        if let Some(next_type) = code_info.next_type() {
+            eprintln!(
+                "type \"resend\" to receive code via {} in {} seconds",
+                ConsoleAuthStateHandler::code_type_description(next_type),
+                code_info.timeout()
+            );
+        }
+        eprintln!("type \"cancel\" to cancel login");
+        let input = ConsoleAuthStateHandler::wait_input().await;
+        match input.as_str() {
+            "resend" => WaitCodeAction::Resend,
+            "cancel" => WaitCodeAction::Cancel,
+            _ => WaitCodeAction::Code(input),
+        }
     }
 
     async fn handle_encryption_key(
         &self,
         _wait_encryption_key: &AuthorizationStateWaitEncryptionKey,
     ) -> String {
-        eprintln!("wait for encryption key");
-        ConsoleAuthStateHandler::wait_input()
+        eprintln!("wait for database encryption passphrase");
+        base64::encode(ConsoleAuthStateHandler::wait_input().await)
     }
 
     async fn handle_wait_password(
         &self,
-        _wait_password: &AuthorizationStateWaitPassword,
-    ) -> String {
+        wait_password: &AuthorizationStateWaitPassword,
+    ) -> WaitPasswordAction {
         eprintln!("wait for password");
-        ConsoleAuthStateHandler::wait_input()
+        if !wait_password.password_hint().is_empty() {
+            eprintln!("password hint: {}", wait_password.password_hint());
+        }
+        if wait_password.has_recovery_email_address() {
+            eprintln!("leave it empty if you forgot password");
+        }
+        let input = ConsoleAuthStateHandler::wait_input().await;
+        // password can't be empty
+        if input.is_empty() && wait_password.has_recovery_email_address() {
+            WaitPasswordAction::Recover
+        } else {
+            WaitPasswordAction::Password(input)
+        }
+    }
+
+    async fn handle_password_recovery_code(
+        &self,
+        wait_password: &AuthorizationStateWaitPassword,
+    ) -> Option<String> {
+        eprintln!(
+            "wait for password recovery code, sent to {}, leave it empty to enter password",
+            wait_password.recovery_email_address_pattern()
+        );
+        let input = ConsoleAuthStateHandler::wait_input().await;
+        if input.is_empty() {
+            None
+        } else {
+            Some(input)
+        }
     }
 
     async fn handle_wait_phone_number(
@@ -129,7 +278,7 @@ impl AuthStateHandler for ConsoleAuthStateHandler {
         _wait_phone_number: &AuthorizationStateWaitPhoneNumber,
     ) -> String {
         eprintln!("wait for phone number");
-        ConsoleAuthStateHandler::wait_input()
+        ConsoleAuthStateHandler::wait_input().await
     }
 
     async fn handle_wait_registration(
@@ -138,10 +287,10 @@ impl AuthStateHandler for ConsoleAuthStateHandler {
     ) -> (String, String) {
         loop {
             eprintln!("waits for first_name and second_name separated by comma");
-            let input: String = ConsoleAuthStateHandler::wait_input();
-            let found: Vec<&str> = input.splitn(2, |c| c == ',').collect();
+            let input: String = ConsoleAuthStateHandler::wait_input().await;
+            let found: Vec<&str> = input.splitn(2, ',').collect();
             if let 2 = found.len() {
-                let f = found.get(0).unwrap().trim();
+                let f = found.first().unwrap().trim();
                 let s = found.get(1).unwrap().trim();
                 if !f.is_empty() && !s.is_empty() {
                     return (f.to_string(), s.to_string());
@@ -149,6 +298,26 @@ impl AuthStateHandler for ConsoleAuthStateHandler {
             }
         }
     }
+
+    async fn handle_terms_of_service(&self, terms_of_service: &TermsOfService) -> bool {
+        eprintln!("terms of service:\n{}", terms_of_service.text().text());
+        eprintln!("do you accept terms of service?");
+        if !ConsoleAuthStateHandler::wait_confirmation().await {
+            return false;
+        }
+        if terms_of_service.min_user_age() > 0 {
+            eprintln!(
+                "are you at least {} years old?",
+                terms_of_service.min_user_age()
+            );
+            return ConsoleAuthStateHandler::wait_confirmation().await;
+        }
+        true
+    }
+
+    async fn handle_terms_of_service_declined(&self, _update: &UpdateTermsOfService) {
+        eprintln!("terms of service declined, your account will be deleted after a while");
+    }
 }
 
 #[derive(Debug)]
@@ -161,9 +330,9 @@ where
     updates_sender: Option<mpsc::Sender<TdType>>,
     tdlib_parameters: Option<TdlibParameters>,
     auth_state_handler: A,
-    tdlib: Tdlib,
     tdlib_log_file_path: Option<String>,
     tdlib_log_max_file_size: Option<i64>,
+    database_key_provider: Option<Arc<dyn DatabaseKeyProvider>>,
 }
 
 impl Default for ClientBuilder<ConsoleAuthStateHandler> {
@@ -176,8 +345,8 @@ impl Default for ClientBuilder<ConsoleAuthStateHandler> {
             updates_sender: None,
             tdlib_parameters: None,
             auth_state_handler: ConsoleAuthStateHandler::new(),
-            tdlib: Tdlib::new(),
             tdlib_log_max_file_size: None,
+            database_key_provider: None,
         }
     }
 }
@@ -207,6 +376,9 @@ where
         self
     }
 
+    /// Timeout of a single TDlib receive call, in seconds.
+    /// Updates are received in a dedicated thread, so timeout doesn't affect client stop latency,
+    /// but the task returned by [start](crate::client::Client::start) joins the thread, so it finishes within the timeout after stop.
     pub fn with_read_updates_timeout(mut self, read_updates_timeout: f64) -> Self {
         self.read_updates_timeout = read_updates_timeout;
         self
@@ -224,6 +396,16 @@ where
         self
     }
 
+    /// [DatabaseKeyProvider](crate::client::database_key::DatabaseKeyProvider) provides local database encryption key.
+    /// If set, it's used instead of [handle_encryption_key](crate::client::client::AuthStateHandler::handle_encryption_key).
+    pub fn with_database_key_provider<P>(mut self, database_key_provider: P) -> Self
+    where
+        P: DatabaseKeyProvider + 'static,
+    {
+        self.database_key_provider = Some(Arc::new(database_key_provider));
+        self
+    }
+
     /// [AuthStateHandler](crate::client::client::AuthStateHandler) allows you to handle particular "auth states", such as [WaitPassword](crate::types::AuthorizationStateWaitPassword), [WaitPhoneNumber](crate::types::AuthorizationStateWaitPhoneNumber) and so on.
     /// See [AuthorizationState](crate::types::AuthorizationState).
     pub fn with_auth_state_handler<N>(self, auth_state_handler: N) -> ClientBuilder<N>
@@ -236,33 +418,68 @@ where
             read_updates_timeout: self.read_updates_timeout,
             updates_sender: self.updates_sender,
             tdlib_parameters: self.tdlib_parameters,
-            tdlib: self.tdlib,
             tdlib_log_file_path: self.tdlib_log_file_path,
             tdlib_log_max_file_size: self.tdlib_log_max_file_size,
+            database_key_provider: self.database_key_provider,
         }
     }
 
+    /// Builds client with local TDlib instance.
+    #[cfg(feature = "client")]
     pub fn build(self) -> RTDResult<Client<A, RawApi>> {
         if self.tdlib_parameters.is_none() {
             return Err(RTDError::InvalidParameters("tdlib_parameters not set"));
         };
 
-        if self.tdlib_log_max_file_size.is_some() {
-            Tdlib::set_log_max_file_size(self.tdlib_log_max_file_size.unwrap());
+        if let Some(tdlib_log_max_file_size) = self.tdlib_log_max_file_size {
+            Tdlib::set_log_max_file_size(tdlib_log_max_file_size);
         }
 
-        if self.tdlib_log_file_path.is_some() {
-            Tdlib::set_log_file_path(Some(self.tdlib_log_file_path.unwrap().as_str()));
+        if let Some(tdlib_log_file_path) = &self.tdlib_log_file_path {
+            Tdlib::set_log_file_path(Some(tdlib_log_file_path.as_str()));
         }
 
         Tdlib::set_log_verbosity_level(self.tdlib_verbosity_level)
             .map_err(|e| RTDError::TdlibError(e.to_string()))?;
+        self.build_with_tdlib_client(RawApi::new(Tdlib::new()))
+    }
+
+    /// Builds client with new TDlib instance of dynamically loaded libtdjson.
+    #[cfg(feature = "dynamic-tdjson")]
+    pub fn build_with_tdjson(self, tdjson: &Arc<TdJson>) -> RTDResult<Client<A, DynamicApi>> {
+        if self.tdlib_parameters.is_none() {
+            return Err(RTDError::InvalidParameters("tdlib_parameters not set"));
+        };
+
+        if let Some(tdlib_log_max_file_size) = self.tdlib_log_max_file_size {
+            tdjson.set_log_max_file_size(tdlib_log_max_file_size);
+        }
+
+        if let Some(tdlib_log_file_path) = &self.tdlib_log_file_path {
+            tdjson.set_log_file_path(tdlib_log_file_path)?;
+        }
+
+        tdjson.set_log_verbosity_level(self.tdlib_verbosity_level)?;
+        self.build_with_tdlib_client(tdjson.create_client())
+    }
+
+    /// Builds client, which interacts with TDlib through specified [TdLibClient](crate::client::api::TdLibClient),
+    /// e.g. with [RemoteApi](crate::client::remote::RemoteApi).
+    /// TDlib log settings are not applied in this case.
+    pub fn build_with_tdlib_client<S>(self, tdlib_client: S) -> RTDResult<Client<A, S>>
+    where
+        S: TdLibClient + Send + Sync + Clone + 'static,
+    {
+        if self.tdlib_parameters.is_none() {
+            return Err(RTDError::InvalidParameters("tdlib_parameters not set"));
+        };
         let client = Client::new(
-            RawApi::new(self.tdlib),
+            tdlib_client,
             self.auth_state_handler,
             self.tdlib_parameters.unwrap(),
             self.updates_sender,
             self.read_updates_timeout,
+            self.database_key_provider,
         );
         Ok(client)
     }
@@ -277,14 +494,18 @@ where
     S: TdLibClient + Send + Sync + Clone + 'static,
 {
     stop_flag: Arc<AtomicBool>,
+    stop_signal: Arc<Notify>,
     is_started: bool,
     api: Api<S>,
     updates_sender: Option<mpsc::Sender<TdType>>,
     auth_state_handler: Arc<A>,
     tdlib_parameters: Arc<TdlibParameters>,
     read_updates_timeout: f64,
+    database_key_provider: Option<Arc<dyn DatabaseKeyProvider>>,
+    receive_thread: Arc<Mutex<Option<thread::JoinHandle<()>>>>,
 }
 
+#[cfg(feature = "client")]
 impl Client<ConsoleAuthStateHandler, RawApi> {
     pub fn builder() -> ClientBuilder<ConsoleAuthStateHandler> {
         ClientBuilder::default()
@@ -309,19 +530,40 @@ where
         tdlib_parameters: TdlibParameters,
         updates_sender: Option<mpsc::Sender<TdType>>,
         read_updates_timeout: f64,
+        database_key_provider: Option<Arc<dyn DatabaseKeyProvider>>,
     ) -> Self {
         let stop_flag = Arc::new(AtomicBool::new(false));
         Self {
             stop_flag,
+            stop_signal: Arc::new(Notify::new()),
             read_updates_timeout,
             updates_sender,
             is_started: false,
             tdlib_parameters: Arc::new(tdlib_parameters),
             api: Api::new(api),
             auth_state_handler: Arc::new(auth_state_handler),
+            database_key_provider,
+            receive_thread: Arc::new(Mutex::new(None)),
         }
     }
 
+    /// Changes local database encryption key to the key returned by `database_key_provider`.
+    /// Don't forget to use the same provider next time you build the client.
+    pub async fn rotate_database_key<P: DatabaseKeyProvider + ?Sized>(
+        &self,
+        database_key_provider: &P,
+    ) -> RTDResult<()> {
+        let new_key = database_key_provider.database_key().await?;
+        self.api
+            .set_database_encryption_key(
+                SetDatabaseEncryptionKey::builder()
+                    .new_encryption_key(decode_database_key(&new_key)?)
+                    .build(),
+            )
+            .await?;
+        Ok(())
+    }
+
     pub fn set_updates_sender(&mut self, updates_sender: mpsc::Sender<TdType>) -> RTDResult<()> {
         match self.is_started {
             true => Err(RTDError::InvalidParameters(
@@ -342,7 +584,7 @@ where
         let (client_state_sx, mut client_state_rx) = mpsc::channel::<ClientState>(2);
         let (auth_sx, auth_rx) = mpsc::channel::<UpdateAuthorizationState>(10);
 
-        let updates_handle = self.init_updates_task(auth_sx);
+        let updates_handle = self.init_updates_task(auth_sx)?;
         let auth_handle = self.init_auth_task(client_state_sx, auth_rx);
 
         // wait until ClientState::Opened received
@@ -355,6 +597,8 @@ where
         }
 
         let stop = self.stop_flag.clone();
+        let stop_signal = self.stop_signal.clone();
+        let receive_thread = self.receive_thread.clone();
 
         Ok(tokio::spawn(async move {
             let res_state = tokio::select! {
@@ -373,6 +617,8 @@ where
                 },
             };
             stop.store(true, Ordering::Release);
+            stop_signal.notify();
+            join_receive_thread(&receive_thread).await;
             res_state
         }))
     }
@@ -380,7 +626,33 @@ where
     /// Stops the client.
     /// You may want to await JoinHandle retrieved with `client.start().await` after stopping the client.
     pub fn stop(&self) {
-        self.stop_flag.store(true, Ordering::Release)
+        self.stop_flag.store(true, Ordering::Release);
+        self.stop_signal.notify();
+    }
+
+    // Spawns long-lived thread which receives raw json from TDlib until client stopped or returned receiver dropped.
+    // Thread is joined by the task returned from `start`
+    fn init_receive_thread(&self) -> RTDResult<mpsc::Receiver<String>> {
+        let (mut json_sx, json_rx) = mpsc::channel::<String>(RECEIVED_JSON_CHANNEL_SIZE);
+        let raw_api = self.api.raw_api().clone();
+        let stop_flag = self.stop_flag.clone();
+        let recv_timeout = self.read_updates_timeout;
+
+        let handle = thread::Builder::new()
+            .name("tdlib-receiver".to_string())
+            .spawn(move || {
+                while !stop_flag.load(Ordering::Acquire) {
+                    if let Some(json) = raw_api.receive(recv_timeout) {
+                        if futures::executor::block_on(json_sx.send(json)).is_err() {
+                            trace!("received json channel closed");
+                            break;
+                        }
+                    }
+                }
+                trace!("receive thread stopped");
+            })?;
+        *self.receive_thread.lock().unwrap() = Some(handle);
+        Ok(json_rx)
     }
 
     // pub(crate) is just for unit-tests
@@ -388,52 +660,102 @@ where
     pub(crate) fn init_updates_task(
         &self,
         mut auth_sx: mpsc::Sender<UpdateAuthorizationState>,
-    ) -> JoinHandle<RTDResult<()>> {
+    ) -> RTDResult<JoinHandle<RTDResult<()>>> {
         let api = self.api.clone();
         let stop_flag = self.stop_flag.clone();
+        let stop_signal = self.stop_signal.clone();
         let mut updates_sender = self.updates_sender.clone();
-        let recv_timeout = self.read_updates_timeout;
+        let auth_state_handler = self.auth_state_handler.clone();
+        let mut json_rx = self.init_receive_thread()?;
 
-        tokio::spawn(async move {
-            let current = tokio::runtime::Handle::try_current().unwrap();
+        Ok(tokio::spawn(async move {
             while !stop_flag.load(Ordering::Acquire) {
-                let rec_api = api.raw_api().clone();
-                if let Some(json) = current
-                    .spawn_blocking(move || rec_api.receive(recv_timeout))
-                    .await
-                    .unwrap()
-                {
-                    trace!("received json from tdlib: {}", json);
-                    match from_json::<TdType>(&json) {
-                        Ok(t) => match OBSERVER.notify(t) {
-                            None => {}
-                            Some(t) => match t {
-                                TdType::UpdateAuthorizationState(auth_state) => {
-                                    trace!("auth state send: {:?}", auth_state);
-                                    auth_sx
-                                        .send(auth_state)
-                                        .await
-                                        .map_err(|_| CLOSED_CHANNEL_ERROR)?;
-                                    trace!("auth state sent");
+                let received = tokio::select! {
+                    _ = stop_signal.notified() => None,
+                    json = json_rx.recv() => json,
+                };
+                let json = match received {
+                    Some(json) => json,
+                    None => break,
+                };
+                trace!("received json from tdlib: {}", json);
+                // only `@type` and `@extra` needed to route received json,
+                // so it'll be completely deserialized only when it's really required
+                let header = match from_json::<TdHeader>(&json) {
+                    Ok(header) => header,
+                    Err(e) => {
+                        error!("can't deserialize received json: {}", e);
+                        continue;
+                    }
+                };
+                let json = match OBSERVER.notify(header.extra(), json) {
+                    None => continue,
+                    Some(json) => json,
+                };
+                match header.td_type() {
+                    "updateAuthorizationState" => {
+                        let auth_state = match from_json::<UpdateAuthorizationState>(&json) {
+                            Ok(auth_state) => auth_state,
+                            Err(e) => {
+                                error!("can't deserialize authorization state: {}", e);
+                                continue;
+                            }
+                        };
+                        trace!("auth state send: {:?}", auth_state);
+                        auth_sx
+                            .send(auth_state)
+                            .await
+                            .map_err(|_| CLOSED_CHANNEL_ERROR)?;
+                        trace!("auth state sent");
+                    }
+                    "updateTermsOfService" => {
+                        let terms_of_service = match from_json::<UpdateTermsOfService>(&json) {
+                            Ok(terms_of_service) => terms_of_service,
+                            Err(e) => {
+                                error!("can't deserialize terms of service update: {}", e);
+                                continue;
+                            }
+                        };
+                        let tos_api = api.clone();
+                        let tos_handler = auth_state_handler.clone();
+                        let tos_update = terms_of_service.clone();
+                        tokio::spawn(async move {
+                            if let Err(e) =
+                                handle_terms_of_service_update(tos_api, tos_handler, tos_update)
+                                    .await
+                            {
+                                error!("can't handle terms of service: {}", e);
+                            }
+                        });
+                        if let Some(sender) = &mut updates_sender {
+                            trace!("update send: {:?}", terms_of_service);
+                            sender
+                                .send(TdType::UpdateTermsOfService(terms_of_service))
+                                .await
+                                .map_err(|_| CLOSED_CHANNEL_ERROR)?;
+                            trace!("update sent");
+                        }
+                    }
+                    _ => match &mut updates_sender {
+                        None => {}
+                        Some(sender) => {
+                            // unknown or changed updates are skipped
+                            let t = match from_json::<TdType>(&json) {
+                                Ok(t) => t,
+                                Err(e) => {
+                                    error!("can't deserialize update {}: {}", header.td_type(), e);
+                                    continue;
                                 }
-                                _ => match &mut updates_sender {
-                                    None => {}
-                                    Some(sender) => {
-                                        trace!("update send: {:?}", t);
-                                        sender.send(t).await.map_err(|_| CLOSED_CHANNEL_ERROR)?;
-                                        trace!("update sent");
-                                    }
-                                },
-                            },
-                        },
-                        Err(e) => {
-                            panic!("{}", e)
+                            };
+                            trace!("update send: {:?}", t);
+                            sender.send(t).await.map_err(|_| CLOSED_CHANNEL_ERROR)?;
+                            trace!("update sent");
                         }
-                    };
-                }
+                    },
+                };
             }
             Ok(())
-        })
+        }))
     }
 
     // created task handles [UpdateAuthorizationState][crate::types::UpdateAuthorizationState] and sends it to particular methods of specified [AuthStateHandler](crate::client::client::AuthStateHandler)
@@ -445,6 +767,7 @@ where
         let auth_api = self.api.clone();
         let auth_state_handler = self.auth_state_handler.clone();
         let tdlib_params = self.tdlib_parameters.clone();
+        let database_key_provider = self.database_key_provider.clone();
         tokio::spawn(async move {
             while let Some(auth_state) = auth_rx.recv().await {
                 trace!("received new auth state: {:?}", auth_state);
@@ -454,6 +777,7 @@ where
                     auth_state,
                     client_state_sx.clone(),
                     tdlib_params.clone(),
+                    database_key_provider.clone(),
                 )
                 .await?;
             }
@@ -462,15 +786,15 @@ where
     }
 }
 
-async fn handle_auth_state<A: AuthStateHandler, S: TdLibClient + Clone>(
+async fn handle_auth_state<A: AuthStateHandler + Sync, S: TdLibClient + Clone>(
     api: &Api<S>,
     auth_state_handler: Arc<A>,
     state: UpdateAuthorizationState,
     mut client_state_sx: mpsc::Sender<ClientState>,
     tdlib_parameters: Arc<TdlibParameters>,
+    database_key_provider: Option<Arc<dyn DatabaseKeyProvider>>,
 ) -> RTDResult<()> {
     match state.authorization_state() {
-        AuthorizationState::_Default(_) => Ok(()),
         AuthorizationState::Closed(_) => {
             client_state_sx
                 .send(ClientState::Closed)
@@ -489,19 +813,51 @@ async fn handle_auth_state<A: AuthStateHandler, S: TdLibClient + Clone>(
             Ok(())
         }
         AuthorizationState::WaitCode(wait_code) => {
-            let code = auth_state_handler.handle_wait_code(wait_code).await;
-            api.check_authentication_code(CheckAuthenticationCode::builder().code(code).build())
-                .await?;
-            Ok(())
+            let received_at = Instant::now();
+            loop {
+                match auth_state_handler.handle_wait_code(wait_code).await {
+                    WaitCodeAction::Code(code) => {
+                        api.check_authentication_code(
+                            CheckAuthenticationCode::builder().code(code).build(),
+                        )
+                        .await?;
+                        return Ok(());
+                    }
+                    WaitCodeAction::Resend => {
+                        let code_info = wait_code.code_info();
+                        if code_info.next_type().is_none() {
+                            warn!("authentication code can't be resent, next code type unknown");
+                            continue;
+                        }
+                        let timeout = Duration::from_secs(code_info.timeout().max(0) as u64);
+                        trace!("waiting {:?} before resending code", timeout);
+                        delay_until(received_at + timeout).await;
+                        api.resend_authentication_code(ResendAuthenticationCode::builder().build())
+                            .await?;
+                        trace!("authentication code resent");
+                        return Ok(());
+                    }
+                    WaitCodeAction::Cancel => {
+                        trace!("login cancelled");
+                        api.log_out(LogOut::builder().build()).await?;
+                        return Ok(());
+                    }
+                }
+            }
         }
         AuthorizationState::WaitEncryptionKey(wait_encryption_key) => {
-            let key = auth_state_handler
-                .handle_encryption_key(wait_encryption_key)
-                .await;
+            let key = match database_key_provider {
+                Some(provider) => provider.database_key().await?,
+                None => {
+                    auth_state_handler
+                        .handle_encryption_key(wait_encryption_key)
+                        .await
+                }
+            };
             trace!("checking encryption key");
             api.check_database_encryption_key(
                 CheckDatabaseEncryptionKey::builder()
-                    .encryption_key(key)
+                    .encryption_key(decode_database_key(&key)?)
                     .build(),
             )
             .await?;
@@ -517,16 +873,63 @@ async fn handle_auth_state<A: AuthStateHandler, S: TdLibClient + Clone>(
             Ok(())
         }
         AuthorizationState::WaitPassword(wait_password) => {
-            let password = auth_state_handler.handle_wait_password(wait_password).await;
-            trace!("checking password");
-            api.check_authentication_password(
-                CheckAuthenticationPassword::builder()
-                    .password(password)
-                    .build(),
-            )
-            .await?;
-            trace!("password checked");
-            Ok(())
+            loop {
+                // non-empty pattern means that recovery code has been already sent
+                if !wait_password.recovery_email_address_pattern().is_empty() {
+                    if let Some(recovery_code) = auth_state_handler
+                        .handle_password_recovery_code(wait_password)
+                        .await
+                    {
+                        trace!("checking recovery code");
+                        let recovered = api
+                            .recover_authentication_password(
+                                RecoverAuthenticationPassword::builder()
+                                    .recovery_code(recovery_code)
+                                    .build(),
+                            )
+                            .await;
+                        match recovered {
+                            Ok(_) => {
+                                trace!("recovery code checked");
+                                return Ok(());
+                            }
+                            // TDLib keeps waiting for password or recovery code
+                            Err(RTDError::TdlibError(e)) => {
+                                warn!("recovery code check failed: {}", e);
+                                continue;
+                            }
+                            Err(e) => return Err(e),
+                        }
+                    }
+                }
+                match auth_state_handler.handle_wait_password(wait_password).await {
+                    WaitPasswordAction::Password(password) => {
+                        trace!("checking password");
+                        api.check_authentication_password(
+                            CheckAuthenticationPassword::builder()
+                                .password(password)
+                                .build(),
+                        )
+                        .await?;
+                        trace!("password checked");
+                        return Ok(());
+                    }
+                    WaitPasswordAction::Recover => {
+                        if !wait_password.has_recovery_email_address() {
+                            warn!("password can't be recovered, recovery email address not set");
+                            continue;
+                        }
+                        trace!("requesting password recovery");
+                        // TDLib sends new WaitPassword state with recovery email address pattern
+                        api.request_authentication_password_recovery(
+                            RequestAuthenticationPasswordRecovery::builder().build(),
+                        )
+                        .await?;
+                        trace!("password recovery requested");
+                        return Ok(());
+                    }
+                }
+            }
         }
         AuthorizationState::WaitPhoneNumber(wait_phone_number) => {
             let phone_number = auth_state_handler
@@ -542,6 +945,19 @@ async fn handle_auth_state<A: AuthStateHandler, S: TdLibClient + Clone>(
         }
         AuthorizationState::WaitRegistration(wait_registration) => {
             trace!("handling wait registration");
+            if !auth_state_handler
+                .handle_terms_of_service(wait_registration.terms_of_service())
+                .await
+            {
+                trace!("terms of service declined");
+                client_state_sx
+                    .send(ClientState::Error(
+                        "terms of service declined, registration impossible".to_string(),
+                    ))
+                    .await
+                    .map_err(|_| CLOSED_CHANNEL_ERROR)?;
+                return Ok(());
+            }
             let (first_name, last_name) = auth_state_handler
                 .handle_wait_registration(wait_registration)
                 .await;
@@ -562,17 +978,89 @@ async fn handle_auth_state<A: AuthStateHandler, S: TdLibClient + Clone>(
             .await?;
             Ok(())
         }
-        AuthorizationState::GetAuthorizationState(_) => Err(RTDError::Internal(
-            "retrieved GetAuthorizationState update but observer not found any subscriber",
-        )),
     }
 }
 
+// waits until receive thread finishes current TDlib receive call
+async fn join_receive_thread(receive_thread: &Mutex<Option<thread::JoinHandle<()>>>) {
+    let handle = receive_thread.lock().unwrap().take();
+    if let Some(handle) = handle {
+        match task::spawn_blocking(move || handle.join()).await {
+            Ok(Ok(_)) => trace!("receive thread joined"),
+            _ => error!("receive thread panicked"),
+        }
+    }
+}
+
+// database encryption keys are returned base64-encoded by handlers and providers
+fn decode_database_key(key: &str) -> RTDResult<Vec<u8>> {
+    base64::decode(key)
+        .map_err(|_| RTDError::InvalidParameters("database encryption key must be base64-encoded"))
+}
+
+// handles terms of service, received after authorization, with specified [AuthStateHandler](crate::client::client::AuthStateHandler)
+async fn handle_terms_of_service_update<A: AuthStateHandler + Sync, S: TdLibClient + Clone>(
+    api: Api<S>,
+    auth_state_handler: Arc<A>,
+    update: UpdateTermsOfService,
+) -> RTDResult<()> {
+    trace!("handling terms of service update");
+    if auth_state_handler
+        .handle_terms_of_service(update.terms_of_service())
+        .await
+    {
+        api.accept_terms_of_service(
+            AcceptTermsOfService::builder()
+                .terms_of_service_id(update.terms_of_service_id())
+                .build(),
+        )
+        .await?;
+        trace!("terms of service accepted");
+    } else {
+        trace!("terms of service declined");
+        auth_state_handler
+            .handle_terms_of_service_declined(&update)
+            .await;
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::client::{AuthStateHandler, Client, ClientBuilder};
+    use super::{handle_auth_state, join_receive_thread, ClientState};
+    use crate::client::api::TdLibClient;
+    use crate::client::testing::{MockApi, MockRule};
+    #[cfg(feature = "client")]
+    use crate::client::ClientBuilder;
+    use crate::client::{AuthStateHandler, Client, WaitCodeAction, WaitPasswordAction};
+    use crate::errors::RTDResult;
     use crate::types::*;
     use async_trait::async_trait;
+    use serde_json::json;
+    use std::collections::VecDeque;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Mutex;
+    use std::time::{Duration, Instant};
+    use tokio::sync::mpsc;
+    use tokio::time::{delay_for, timeout};
+
+    #[derive(Clone)]
+    struct SilentRawApi;
+
+    impl TdLibClient for SilentRawApi {
+        fn send(&self, _request: &str) -> RTDResult<()> {
+            Ok(())
+        }
+
+        fn receive(&self, timeout: f64) -> Option<String> {
+            std::thread::sleep(Duration::from_secs_f64(timeout));
+            None
+        }
+
+        fn execute(&self, _request: &str) -> RTDResult<Option<String>> {
+            unimplemented!()
+        }
+    }
 
     struct DummyStateHandler;
     #[async_trait]
@@ -583,14 +1071,17 @@ mod tests {
         ) {
             unimplemented!()
         }
-        async fn handle_wait_code(&self, _: &AuthorizationStateWaitCode) -> String {
+        async fn handle_wait_code(&self, _: &AuthorizationStateWaitCode) -> WaitCodeAction {
             unimplemented!()
         }
 
         async fn handle_encryption_key(&self, _: &AuthorizationStateWaitEncryptionKey) -> String {
             unimplemented!()
         }
-        async fn handle_wait_password(&self, _: &AuthorizationStateWaitPassword) -> String {
+        async fn handle_wait_password(
+            &self,
+            _: &AuthorizationStateWaitPassword,
+        ) -> WaitPasswordAction {
             unimplemented!()
         }
         async fn handle_wait_phone_number(&self, _: &AuthorizationStateWaitPhoneNumber) -> String {
@@ -602,9 +1093,382 @@ mod tests {
         ) -> (String, String) {
             unimplemented!()
         }
+        async fn handle_terms_of_service(&self, _: &TermsOfService) -> bool {
+            unimplemented!()
+        }
+    }
+
+    // answers with scripted values, panics on unexpected calls
+    #[derive(Default)]
+    struct ScriptedStateHandler {
+        code_actions: Mutex<VecDeque<WaitCodeAction>>,
+        password_actions: Mutex<VecDeque<WaitPasswordAction>>,
+        recovery_codes: Mutex<VecDeque<Option<String>>>,
+        accept_terms_of_service: bool,
+        terms_of_service_asked: AtomicBool,
+        terms_of_service_declined: AtomicBool,
+    }
+
+    #[async_trait]
+    impl AuthStateHandler for ScriptedStateHandler {
+        async fn handle_other_device_confirmation(
+            &self,
+            _: &AuthorizationStateWaitOtherDeviceConfirmation,
+        ) {
+            unimplemented!()
+        }
+        async fn handle_wait_code(&self, _: &AuthorizationStateWaitCode) -> WaitCodeAction {
+            self.code_actions
+                .lock()
+                .unwrap()
+                .pop_front()
+                .expect("unexpected wait code call")
+        }
+        async fn handle_encryption_key(&self, _: &AuthorizationStateWaitEncryptionKey) -> String {
+            unimplemented!()
+        }
+        async fn handle_wait_password(
+            &self,
+            _: &AuthorizationStateWaitPassword,
+        ) -> WaitPasswordAction {
+            self.password_actions
+                .lock()
+                .unwrap()
+                .pop_front()
+                .expect("unexpected wait password call")
+        }
+        async fn handle_password_recovery_code(
+            &self,
+            _: &AuthorizationStateWaitPassword,
+        ) -> Option<String> {
+            self.recovery_codes
+                .lock()
+                .unwrap()
+                .pop_front()
+                .expect("unexpected recovery code call")
+        }
+        async fn handle_wait_phone_number(&self, _: &AuthorizationStateWaitPhoneNumber) -> String {
+            unimplemented!()
+        }
+        async fn handle_wait_registration(
+            &self,
+            _: &AuthorizationStateWaitRegistration,
+        ) -> (String, String) {
+            ("John".to_string(), "Doe".to_string())
+        }
+        async fn handle_terms_of_service(&self, _: &TermsOfService) -> bool {
+            self.terms_of_service_asked.store(true, Ordering::Release);
+            self.accept_terms_of_service
+        }
+        async fn handle_terms_of_service_declined(&self, _: &UpdateTermsOfService) {
+            self.terms_of_service_declined
+                .store(true, Ordering::Release);
+        }
+    }
+
+    // client with running updates task, so responses of `mock` are routed to api calls
+    fn mocked_client<A>(mock: &MockApi, auth_state_handler: A) -> Client<A, MockApi>
+    where
+        A: AuthStateHandler + Send + Sync + 'static,
+    {
+        let client = Client::new(
+            mock.clone(),
+            auth_state_handler,
+            TdlibParameters::builder().build(),
+            None,
+            0.01,
+            None,
+        );
+        let (sx, _rx) = mpsc::channel::<UpdateAuthorizationState>(10);
+        client.init_updates_task(sx).unwrap();
+        client
+    }
+
+    // handles auth state, returns result and client state, sent by handler
+    async fn handle_state<A>(
+        client: &Client<A, MockApi>,
+        state: AuthorizationState,
+    ) -> (RTDResult<()>, Option<ClientState>)
+    where
+        A: AuthStateHandler + Send + Sync + 'static,
+    {
+        let (client_state_sx, mut client_state_rx) = mpsc::channel::<ClientState>(2);
+        let result = handle_auth_state(
+            client.api(),
+            client.auth_state_handler.clone(),
+            UpdateAuthorizationState::builder()
+                .authorization_state(state)
+                .build(),
+            client_state_sx,
+            client.tdlib_parameters.clone(),
+            None,
+        )
+        .await;
+        (result, client_state_rx.try_recv().ok())
+    }
+
+    async fn wait_for<F: Fn() -> bool>(condition: F) {
+        let deadline = Instant::now() + Duration::from_secs(1);
+        while !condition() {
+            assert!(Instant::now() < deadline, "condition not met within 1 s");
+            delay_for(Duration::from_millis(10)).await;
+        }
+    }
+
+    fn code_handler(code_actions: Vec<WaitCodeAction>) -> ScriptedStateHandler {
+        ScriptedStateHandler {
+            code_actions: Mutex::new(code_actions.into()),
+            ..Default::default()
+        }
+    }
+
+    fn wait_code(timeout: i32, next_type: Option<AuthenticationCodeType>) -> AuthorizationState {
+        let mut code_info = AuthenticationCodeInfo::builder();
+        code_info
+            .type_(AuthenticationCodeType::Sms(
+                AuthenticationCodeTypeSms::builder().build(),
+            ))
+            .timeout(timeout);
+        if let Some(next_type) = next_type {
+            code_info.next_type(next_type);
+        }
+        AuthorizationState::WaitCode(
+            AuthorizationStateWaitCode::builder()
+                .code_info(code_info.build())
+                .build(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_wait_code_resend() {
+        let mock = MockApi::new();
+        mock.add_rule(MockRule::new("resendAuthenticationCode").respond(json!({"@type": "ok"})));
+        let client = mocked_client(&mock, code_handler(vec![WaitCodeAction::Resend]));
+        let next_type = AuthenticationCodeType::Call(AuthenticationCodeTypeCall::builder().build());
+        let started = Instant::now();
+        let (result, _) = handle_state(&client, wait_code(1, Some(next_type))).await;
+        result.unwrap();
+        assert!(started.elapsed() >= Duration::from_secs(1));
+        assert_eq!(mock.sent_requests_of("resendAuthenticationCode").len(), 1);
+        client.stop();
+    }
+
+    #[tokio::test]
+    async fn test_wait_code_resend_without_next_type() {
+        let mock = MockApi::new();
+        mock.add_rule(MockRule::new("checkAuthenticationCode").respond(json!({"@type": "ok"})));
+        let client = mocked_client(
+            &mock,
+            code_handler(vec![
+                WaitCodeAction::Resend,
+                WaitCodeAction::Code("12345".to_string()),
+            ]),
+        );
+        let (result, _) = handle_state(&client, wait_code(0, None)).await;
+        result.unwrap();
+        assert!(mock.sent_requests_of("resendAuthenticationCode").is_empty());
+        let checked = mock.sent_requests_of("checkAuthenticationCode");
+        assert_eq!(checked[0]["code"], "12345");
+        client.stop();
+    }
+
+    #[tokio::test]
+    async fn test_wait_code_cancel() {
+        let mock = MockApi::new();
+        mock.add_rule(MockRule::new("logOut").respond(json!({"@type": "ok"})));
+        let client = mocked_client(&mock, code_handler(vec![WaitCodeAction::Cancel]));
+        let (result, _) = handle_state(&client, wait_code(0, None)).await;
+        result.unwrap();
+        mock.assert_sent("logOut");
+        assert!(mock.sent_requests_of("checkAuthenticationCode").is_empty());
+        client.stop();
+    }
+
+    fn password_handler(
+        password_actions: Vec<WaitPasswordAction>,
+        recovery_codes: Vec<Option<&str>>,
+    ) -> ScriptedStateHandler {
+        ScriptedStateHandler {
+            password_actions: Mutex::new(password_actions.into()),
+            recovery_codes: Mutex::new(
+                recovery_codes
+                    .into_iter()
+                    .map(|code| code.map(|c| c.to_string()))
+                    .collect(),
+            ),
+            ..Default::default()
+        }
+    }
+
+    fn wait_password(recovery_email_address_pattern: &str) -> AuthorizationState {
+        AuthorizationState::WaitPassword(
+            AuthorizationStateWaitPassword::builder()
+                .has_recovery_email_address(true)
+                .recovery_email_address_pattern(recovery_email_address_pattern)
+                .build(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_wait_password_recovery() {
+        let mock = MockApi::new();
+        mock.add_rule(
+            MockRule::new("requestAuthenticationPasswordRecovery").respond(json!({"@type": "ok"})),
+        )
+        .add_rule(
+            MockRule::new("recoverAuthenticationPassword")
+                .matching(|request| request["recovery_code"] == "valid")
+                .respond(json!({"@type": "ok"})),
+        )
+        .add_rule(
+            MockRule::new("recoverAuthenticationPassword")
+                .respond_error(400, "PASSWORD_RECOVERY_CODE_INVALID"),
+        );
+        let client = mocked_client(
+            &mock,
+            password_handler(
+                vec![WaitPasswordAction::Recover],
+                vec![Some("invalid"), Some("valid")],
+            ),
+        );
+
+        let (result, _) = handle_state(&client, wait_password("")).await;
+        result.unwrap();
+        mock.assert_sent("requestAuthenticationPasswordRecovery");
+
+        // TDLib sends WaitPassword with pattern after recovery requested
+        let (result, _) = handle_state(&client, wait_password("a***@example.com")).await;
+        result.unwrap();
+        let recovered = mock.sent_requests_of("recoverAuthenticationPassword");
+        assert_eq!(recovered.len(), 2);
+        assert_eq!(recovered[1]["recovery_code"], "valid");
+        assert!(mock
+            .sent_requests_of("checkAuthenticationPassword")
+            .is_empty());
+        client.stop();
+    }
+
+    #[tokio::test]
+    async fn test_wait_password_after_recovery_requested() {
+        let mock = MockApi::new();
+        mock.add_rule(MockRule::new("checkAuthenticationPassword").respond(json!({"@type": "ok"})));
+        let client = mocked_client(
+            &mock,
+            password_handler(
+                vec![WaitPasswordAction::Password("secret".to_string())],
+                vec![None],
+            ),
+        );
+        let (result, _) = handle_state(&client, wait_password("a***@example.com")).await;
+        result.unwrap();
+        let checked = mock.sent_requests_of("checkAuthenticationPassword");
+        assert_eq!(checked[0]["password"], "secret");
+        assert!(mock
+            .sent_requests_of("recoverAuthenticationPassword")
+            .is_empty());
+        client.stop();
+    }
+
+    #[tokio::test]
+    async fn test_wait_password_without_recovery_email() {
+        let mock = MockApi::new();
+        mock.add_rule(MockRule::new("checkAuthenticationPassword").respond(json!({"@type": "ok"})));
+        let client = mocked_client(
+            &mock,
+            password_handler(
+                vec![
+                    WaitPasswordAction::Recover,
+                    WaitPasswordAction::Password("secret".to_string()),
+                ],
+                vec![],
+            ),
+        );
+        let state = AuthorizationState::WaitPassword(
+            AuthorizationStateWaitPassword::builder()
+                .has_recovery_email_address(false)
+                .build(),
+        );
+        let (result, _) = handle_state(&client, state).await;
+        result.unwrap();
+        assert!(mock
+            .sent_requests_of("requestAuthenticationPasswordRecovery")
+            .is_empty());
+        assert!(mock
+            .sent_requests_of("recoverAuthenticationPassword")
+            .is_empty());
+        mock.assert_sent("checkAuthenticationPassword");
+        client.stop();
+    }
+
+    #[tokio::test]
+    async fn test_terms_of_service_update() {
+        for accept in &[true, false] {
+            let mock = MockApi::new();
+            mock.add_rule(MockRule::new("acceptTermsOfService").respond(json!({"@type": "ok"})));
+            let client = mocked_client(
+                &mock,
+                ScriptedStateHandler {
+                    accept_terms_of_service: *accept,
+                    ..Default::default()
+                },
+            );
+            mock.push_update(
+                &UpdateTermsOfService::builder()
+                    .terms_of_service_id("tos-1")
+                    .terms_of_service(TermsOfService::builder().build())
+                    .build(),
+            )
+            .unwrap();
+            let handler = client.auth_state_handler.clone();
+            wait_for(|| handler.terms_of_service_asked.load(Ordering::Acquire)).await;
+            if *accept {
+                wait_for(|| !mock.sent_requests_of("acceptTermsOfService").is_empty()).await;
+                let accepted = mock.sent_requests_of("acceptTermsOfService");
+                assert_eq!(accepted[0]["terms_of_service_id"], "tos-1");
+            } else {
+                wait_for(|| handler.terms_of_service_declined.load(Ordering::Acquire)).await;
+                assert!(mock.sent_requests_of("acceptTermsOfService").is_empty());
+            }
+            client.stop();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_registration_terms_of_service() {
+        let wait_registration = AuthorizationState::WaitRegistration(
+            AuthorizationStateWaitRegistration::builder()
+                .terms_of_service(TermsOfService::builder().min_user_age(18).build())
+                .build(),
+        );
+
+        let mock = MockApi::new();
+        mock.add_rule(MockRule::new("registerUser").respond(json!({"@type": "ok"})));
+        let client = mocked_client(
+            &mock,
+            ScriptedStateHandler {
+                accept_terms_of_service: true,
+                ..Default::default()
+            },
+        );
+        let (result, state) = handle_state(&client, wait_registration.clone()).await;
+        result.unwrap();
+        assert!(state.is_none());
+        let registered = mock.sent_requests_of("registerUser");
+        assert_eq!(registered[0]["first_name"], "John");
+        assert_eq!(registered[0]["last_name"], "Doe");
+        client.stop();
+
+        let mock = MockApi::new();
+        let client = mocked_client(&mock, ScriptedStateHandler::default());
+        let (result, state) = handle_state(&client, wait_registration).await;
+        result.unwrap();
+        assert!(matches!(state, Some(ClientState::Error(_))));
+        assert!(mock.sent_requests_of("registerUser").is_empty());
+        client.stop();
     }
 
     #[test]
+    #[cfg(feature = "client")]
     fn test_builder_auth_state_handler() {
         Client::builder()
             .with_tdlib_parameters(TdlibParameters::builder().build())
@@ -623,6 +1487,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "client")]
     fn test_builder_no_params() {
         let result = Client::builder().build();
 
@@ -635,4 +1500,79 @@ mod tests {
             .build()
             .unwrap();
     }
+
+    #[tokio::test]
+    async fn test_stop_does_not_wait_receive_timeout() {
+        let client = Client::new(
+            SilentRawApi,
+            DummyStateHandler {},
+            TdlibParameters::builder().build(),
+            None,
+            30.0,
+            None,
+        );
+        let (sx, _rx) = mpsc::channel::<UpdateAuthorizationState>(10);
+        let updates_handle = client.init_updates_task(sx).unwrap();
+        client.stop();
+        match timeout(Duration::from_secs(1), updates_handle).await {
+            Err(_) => panic!("updates task not stopped within 1 s"),
+            Ok(result) => result.unwrap().unwrap(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_receive_thread_joined() {
+        let client = Client::new(
+            SilentRawApi,
+            DummyStateHandler {},
+            TdlibParameters::builder().build(),
+            None,
+            0.05,
+            None,
+        );
+        let (sx, _rx) = mpsc::channel::<UpdateAuthorizationState>(10);
+        client.init_updates_task(sx).unwrap();
+        assert!(client.receive_thread.lock().unwrap().is_some());
+        client.stop();
+        timeout(
+            Duration::from_secs(1),
+            join_receive_thread(&client.receive_thread),
+        )
+        .await
+        .expect("receive thread not joined within 1 s");
+        assert!(client.receive_thread.lock().unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_invalid_updates_skipped() {
+        let mock = MockApi::new();
+        let (updates_sx, mut updates_rx) = mpsc::channel::<TdType>(10);
+        let client = Client::new(
+            mock.clone(),
+            DummyStateHandler {},
+            TdlibParameters::builder().build(),
+            Some(updates_sx),
+            0.01,
+            None,
+        );
+        let (sx, _rx) = mpsc::channel::<UpdateAuthorizationState>(10);
+        let updates_handle = client.init_updates_task(sx).unwrap();
+        mock.push_json("not a json")
+            .push_json(r#"{"@type":"updateFromNewerTdlib","value":1}"#)
+            .push_json(r#"{"@type":"updateAuthorizationState","authorization_state":{}}"#)
+            .push_update(
+                &UpdateConnectionState::builder()
+                    .state(ConnectionState::Ready(
+                        ConnectionStateReady::builder().build(),
+                    ))
+                    .build(),
+            )
+            .unwrap();
+        match timeout(Duration::from_secs(1), updates_rx.recv()).await {
+            Ok(Some(TdType::UpdateConnectionState(_))) => {}
+            received => panic!("unexpected update: {:?}", received),
+        }
+        client.stop();
+        updates_handle.await.unwrap().unwrap();
+    }
 }
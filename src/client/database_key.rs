@@ -0,0 +1,197 @@
+//! Providers of TDlib local database encryption key.
+//! Allows to open encrypted database without interactive input.
+use crate::errors::{RTDError, RTDResult};
+use async_trait::async_trait;
+use hmac::Hmac;
+use sha2::Sha256;
+use std::env;
+use std::fmt::Debug;
+use std::fs;
+use std::path::PathBuf;
+use tokio::task;
+
+/// Environment variable used by [EnvDatabaseKeyProvider](crate::client::database_key::EnvDatabaseKeyProvider) by default.
+pub const DEFAULT_DATABASE_KEY_ENV: &str = "TDLIB_DATABASE_KEY";
+
+const DERIVED_KEY_LENGTH: usize = 32;
+const DEFAULT_DERIVATION_ITERATIONS: u32 = 100_000;
+
+/// `DatabaseKeyProvider` trait provides encryption key for TDlib local database.
+/// Key is used on [WaitEncryptionKey](crate::types::AuthorizationStateWaitEncryptionKey) state instead of [handle_encryption_key](crate::client::AuthStateHandler::handle_encryption_key).
+/// Returned value is passed to TDlib as is, so it must be base64-encoded.
+#[async_trait]
+pub trait DatabaseKeyProvider: Debug + Send + Sync {
+    /// Returns database encryption key
+    async fn database_key(&self) -> RTDResult<String>;
+}
+
+/// Reads database encryption key from environment variable.
+#[derive(Debug, Clone)]
+pub struct EnvDatabaseKeyProvider {
+    var_name: String,
+}
+
+impl Default for EnvDatabaseKeyProvider {
+    /// Reads key from `TDLIB_DATABASE_KEY` environment variable
+    fn default() -> Self {
+        Self::new(DEFAULT_DATABASE_KEY_ENV)
+    }
+}
+
+impl EnvDatabaseKeyProvider {
+    pub fn new<S: AsRef<str>>(var_name: S) -> Self {
+        Self {
+            var_name: var_name.as_ref().to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl DatabaseKeyProvider for EnvDatabaseKeyProvider {
+    async fn database_key(&self) -> RTDResult<String> {
+        env::var(&self.var_name).map_err(|e| RTDError::EnvVar(self.var_name.clone(), e))
+    }
+}
+
+/// Reads database encryption key from file. Leading and trailing whitespaces are ignored.
+#[derive(Debug, Clone)]
+pub struct FileDatabaseKeyProvider {
+    path: PathBuf,
+}
+
+impl FileDatabaseKeyProvider {
+    pub fn new<P: Into<PathBuf>>(path: P) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+#[async_trait]
+impl DatabaseKeyProvider for FileDatabaseKeyProvider {
+    async fn database_key(&self) -> RTDResult<String> {
+        let path = self.path.clone();
+        let key = task::spawn_blocking(move || fs::read_to_string(path))
+            .await
+            .map_err(|_| RTDError::Internal("database key reading task failed"))??;
+        Ok(key.trim().to_string())
+    }
+}
+
+/// Derives database encryption key from passphrase with PBKDF2-HMAC-SHA256.
+/// Same passphrase, salt and iterations produce same key on every platform.
+#[derive(Clone)]
+pub struct PassphraseDatabaseKeyProvider {
+    passphrase: String,
+    salt: String,
+    iterations: u32,
+}
+
+impl Debug for PassphraseDatabaseKeyProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // passphrase must not be leaked to logs
+        f.debug_struct("PassphraseDatabaseKeyProvider")
+            .field("salt", &self.salt)
+            .field("iterations", &self.iterations)
+            .finish()
+    }
+}
+
+impl PassphraseDatabaseKeyProvider {
+    /// Creates provider with 100000 PBKDF2 iterations
+    pub fn new<P: AsRef<str>, S: AsRef<str>>(passphrase: P, salt: S) -> Self {
+        Self {
+            passphrase: passphrase.as_ref().to_string(),
+            salt: salt.as_ref().to_string(),
+            iterations: DEFAULT_DERIVATION_ITERATIONS,
+        }
+    }
+
+    pub fn with_iterations(mut self, iterations: u32) -> Self {
+        self.iterations = iterations;
+        self
+    }
+
+    /// Returns base64-encoded derived key
+    pub fn derive_key(&self) -> String {
+        let mut key = [0u8; DERIVED_KEY_LENGTH];
+        pbkdf2::pbkdf2::<Hmac<Sha256>>(
+            self.passphrase.as_bytes(),
+            self.salt.as_bytes(),
+            self.iterations,
+            &mut key,
+        );
+        base64::encode(key)
+    }
+}
+
+#[async_trait]
+impl DatabaseKeyProvider for PassphraseDatabaseKeyProvider {
+    async fn database_key(&self) -> RTDResult<String> {
+        if self.iterations == 0 {
+            return Err(RTDError::InvalidParameters(
+                "key derivation iterations must be positive",
+            ));
+        }
+        // derivation is slow by design, it must not block runtime
+        let provider = self.clone();
+        task::spawn_blocking(move || provider.derive_key())
+            .await
+            .map_err(|_| RTDError::Internal("database key derivation task failed"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::database_key::{
+        DatabaseKeyProvider, EnvDatabaseKeyProvider, FileDatabaseKeyProvider,
+        PassphraseDatabaseKeyProvider,
+    };
+
+    #[tokio::test]
+    async fn test_passphrase_key_derivation() {
+        // RFC 7914 PBKDF2-HMAC-SHA256 test vector
+        let provider = PassphraseDatabaseKeyProvider::new("passwd", "salt").with_iterations(1);
+        assert_eq!(
+            provider.database_key().await.unwrap(),
+            base64::encode([
+                0x55, 0xac, 0x04, 0x6e, 0x56, 0xe3, 0x08, 0x9f, 0xec, 0x16, 0x91, 0xc2, 0x25, 0x44,
+                0xb6, 0x05, 0xf9, 0x41, 0x85, 0x21, 0x6d, 0xde, 0x04, 0x65, 0xe6, 0x8b, 0x9d, 0x57,
+                0xc2, 0x0d, 0xac, 0xbc
+            ])
+        );
+        assert!(PassphraseDatabaseKeyProvider::new("passwd", "salt")
+            .with_iterations(0)
+            .database_key()
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_env_and_file_keys() {
+        std::env::set_var("RUST_TDLIB_TEST_DATABASE_KEY", "ZW52LWtleQ==");
+        assert_eq!(
+            EnvDatabaseKeyProvider::new("RUST_TDLIB_TEST_DATABASE_KEY")
+                .database_key()
+                .await
+                .unwrap(),
+            "ZW52LWtleQ=="
+        );
+        match EnvDatabaseKeyProvider::new("RUST_TDLIB_TEST_MISSING_KEY")
+            .database_key()
+            .await
+        {
+            Err(e) => assert!(e.to_string().contains("RUST_TDLIB_TEST_MISSING_KEY")),
+            Ok(_) => panic!("key read from missing environment variable"),
+        }
+
+        let path = std::env::temp_dir().join("rust_tdlib_test_database_key");
+        std::fs::write(&path, "ZmlsZS1rZXk=\n").unwrap();
+        assert_eq!(
+            FileDatabaseKeyProvider::new(&path)
+                .database_key()
+                .await
+                .unwrap(),
+            "ZmlsZS1rZXk="
+        );
+        std::fs::remove_file(&path).unwrap();
+    }
+}
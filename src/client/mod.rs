@@ -7,7 +7,27 @@ pub mod api;
 #[allow(clippy::module_inception)]
 /// Handlers for all incoming data
 pub mod client;
+/// Database encryption key providers.
+pub mod database_key;
 pub mod errors;
+/// Record-and-replay TDlib clients.
+pub mod recording;
+/// TDlib access over a socket.
+pub mod remote;
+/// Runtime loading of libtdjson.
+#[cfg(feature = "dynamic-tdjson")]
+pub mod tdjson;
+/// TDlib replacements for tests.
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 
-pub use client::{AuthStateHandler, Client, ClientBuilder, ConsoleAuthStateHandler};
+pub use client::{
+    AuthStateHandler, Client, ClientBuilder, ConsoleAuthStateHandler, WaitCodeAction,
+    WaitPasswordAction,
+};
+pub use database_key::{
+    DatabaseKeyProvider, EnvDatabaseKeyProvider, FileDatabaseKeyProvider,
+    PassphraseDatabaseKeyProvider,
+};
+#[cfg(feature = "client")]
 pub use rtdlib_sys::Tdlib;
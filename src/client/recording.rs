@@ -0,0 +1,408 @@
+//! Record-and-replay [TdLibClient](crate::client::api::TdLibClient) implementations.
+//!
+//! [RecordingApi](crate::client::recording::RecordingApi) wraps any `TdLibClient` and writes every sent request and every received json to a file.
+//! [ReplayApi](crate::client::recording::ReplayApi) feeds recorded session back, so [Client](crate::client::Client) logic can be tested offline.
+//!
+//! Session file contains one [RecordEntry](crate::client::recording::RecordEntry) json per line.
+use crate::{
+    client::api::TdLibClient,
+    errors::{RTDError, RTDResult},
+};
+use serde_json::Value;
+use std::collections::{HashMap, VecDeque};
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Single event of recorded session.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum RecordedEvent {
+    /// Request sent to TDlib
+    Sent { json: String },
+    /// Json received from TDlib
+    Received { json: String },
+    /// Synchronously executed request and its result
+    Executed {
+        request: String,
+        response: Option<String>,
+    },
+}
+
+/// Recorded event with its time.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordEntry {
+    /// Milliseconds since UNIX epoch
+    timestamp_ms: u64,
+    #[serde(flatten)]
+    event: RecordedEvent,
+}
+
+impl RecordEntry {
+    pub fn new(event: RecordedEvent) -> Self {
+        let timestamp_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or_default();
+        Self {
+            timestamp_ms,
+            event,
+        }
+    }
+
+    pub fn timestamp_ms(&self) -> u64 {
+        self.timestamp_ms
+    }
+
+    pub fn event(&self) -> &RecordedEvent {
+        &self.event
+    }
+}
+
+/// Reads recorded session from file.
+pub fn read_session<P: AsRef<Path>>(path: P) -> RTDResult<Vec<RecordEntry>> {
+    let mut entries = Vec::new();
+    for line in BufReader::new(File::open(path)?).lines() {
+        let line = line?;
+        if !line.trim().is_empty() {
+            entries.push(serde_json::from_str(&line)?);
+        }
+    }
+    Ok(entries)
+}
+
+/// [TdLibClient](crate::client::api::TdLibClient) wrapper, which records all interactions with wrapped client to a file.
+#[derive(Clone, Debug)]
+pub struct RecordingApi<S>
+where
+    S: TdLibClient + Clone,
+{
+    inner: S,
+    writer: Arc<Mutex<BufWriter<File>>>,
+}
+
+impl<S> RecordingApi<S>
+where
+    S: TdLibClient + Clone,
+{
+    /// Creates (or truncates) session file at `path`
+    pub fn new<P: AsRef<Path>>(inner: S, path: P) -> RTDResult<Self> {
+        Ok(Self {
+            inner,
+            writer: Arc::new(Mutex::new(BufWriter::new(File::create(path)?))),
+        })
+    }
+
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    fn record(&self, event: RecordedEvent) -> RTDResult<()> {
+        let mut line = serde_json::to_string(&RecordEntry::new(event))?;
+        line.push('\n');
+        let mut writer = self
+            .writer
+            .lock()
+            .map_err(|_| RTDError::Internal("recording writer poisoned"))?;
+        writer.write_all(line.as_bytes())?;
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+impl<S> TdLibClient for RecordingApi<S>
+where
+    S: TdLibClient + Clone,
+{
+    fn send(&self, request: &str) -> RTDResult<()> {
+        self.record(RecordedEvent::Sent {
+            json: request.to_string(),
+        })?;
+        self.inner.send(request)
+    }
+
+    fn receive(&self, timeout: f64) -> Option<String> {
+        let json = self.inner.receive(timeout)?;
+        if let Err(e) = self.record(RecordedEvent::Received { json: json.clone() }) {
+            error!("can't record received json: {}", e);
+        }
+        Some(json)
+    }
+
+    fn execute(&self, request: &str) -> RTDResult<Option<String>> {
+        let response = self.inner.execute(request)?;
+        self.record(RecordedEvent::Executed {
+            request: request.to_string(),
+            response: response.clone(),
+        })?;
+        Ok(response)
+    }
+}
+
+#[derive(Debug, Default)]
+struct ReplayState {
+    entries: VecDeque<RecordEntry>,
+    to_receive: VecDeque<String>,
+    // recorded `@extra` -> `@extra` of replayed request
+    extras: HashMap<String, String>,
+}
+
+impl ReplayState {
+    // moves all received jsons up to the next request into receive queue
+    fn release_received(&mut self) {
+        while let Some(entry) = self.entries.front() {
+            let json = match &entry.event {
+                RecordedEvent::Received { json } => json.clone(),
+                _ => break,
+            };
+            self.entries.pop_front();
+            self.to_receive.push_back(self.remap_extra(json));
+        }
+    }
+
+    fn remap_extra(&self, json: String) -> String {
+        let mut value = match serde_json::from_str::<Value>(&json) {
+            Ok(value) => value,
+            Err(_) => return json,
+        };
+        let remapped = match value.get("@extra").and_then(|extra| extra.as_str()) {
+            Some(extra) => match self.extras.get(extra) {
+                Some(remapped) => remapped.clone(),
+                None => return json,
+            },
+            None => return json,
+        };
+        value["@extra"] = Value::String(remapped);
+        value.to_string()
+    }
+
+    // returns recorded request matched with `request`
+    fn expect_request(&mut self, request: &str, executed: bool) -> RTDResult<RecordEntry> {
+        let entry = match self.entries.pop_front() {
+            Some(entry) => entry,
+            None => {
+                error!("unexpected request, recorded session finished: {}", request);
+                return Err(RTDError::Internal("recorded session finished"));
+            }
+        };
+        let recorded = match (&entry.event, executed) {
+            (RecordedEvent::Sent { json }, false) => json,
+            (RecordedEvent::Executed { request, .. }, true) => request,
+            _ => {
+                error!(
+                    "unexpected request {}, recorded event: {:?}",
+                    request, entry.event
+                );
+                return Err(RTDError::Internal("request doesn't match recorded session"));
+            }
+        };
+        let (recorded, actual) = (parse_request(recorded)?, parse_request(request)?);
+        if without_extra(recorded.clone()) != without_extra(actual.clone()) {
+            error!("unexpected request {}, recorded: {}", actual, recorded);
+            return Err(RTDError::Internal("request doesn't match recorded session"));
+        }
+        if let (Some(recorded), Some(actual)) = (
+            recorded.get("@extra").and_then(|v| v.as_str()),
+            actual.get("@extra").and_then(|v| v.as_str()),
+        ) {
+            self.extras.insert(recorded.to_string(), actual.to_string());
+        }
+        Ok(entry)
+    }
+}
+
+fn parse_request(json: &str) -> RTDResult<Value> {
+    Ok(serde_json::from_str(json)?)
+}
+
+// `@extra` values differ between sessions, so they're ignored on comparison
+fn without_extra(value: Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .filter(|(k, _)| k != "@extra")
+                .map(|(k, v)| (k, without_extra(v)))
+                .collect(),
+        ),
+        Value::Array(items) => Value::Array(items.into_iter().map(without_extra).collect()),
+        value => value,
+    }
+}
+
+/// [TdLibClient](crate::client::api::TdLibClient), which replays session recorded with [RecordingApi](crate::client::recording::RecordingApi).
+///
+/// Every sent request must match next recorded request (`@extra` values are ignored), otherwise `send` returns an error.
+/// Jsons, recorded after a request, become available for receiving when the request sent. Their `@extra` remapped to the `@extra` of the replayed request.
+#[derive(Clone, Debug)]
+pub struct ReplayApi {
+    state: Arc<(Mutex<ReplayState>, Condvar)>,
+}
+
+impl ReplayApi {
+    pub fn new(entries: Vec<RecordEntry>) -> Self {
+        let mut state = ReplayState {
+            entries: entries.into(),
+            ..Default::default()
+        };
+        state.release_received();
+        Self {
+            state: Arc::new((Mutex::new(state), Condvar::new())),
+        }
+    }
+
+    /// Loads recorded session from file
+    pub fn open<P: AsRef<Path>>(path: P) -> RTDResult<Self> {
+        Ok(Self::new(read_session(path)?))
+    }
+
+    /// Returns number of recorded requests, which were not sent yet
+    pub fn remaining_requests(&self) -> usize {
+        let state = self.state.0.lock().unwrap();
+        state
+            .entries
+            .iter()
+            .filter(|entry| !matches!(entry.event, RecordedEvent::Received { .. }))
+            .count()
+    }
+
+    /// Returns `true` if all recorded requests sent and all recorded jsons received
+    pub fn is_finished(&self) -> bool {
+        let state = self.state.0.lock().unwrap();
+        state.entries.is_empty() && state.to_receive.is_empty()
+    }
+
+    fn lock(&self) -> RTDResult<std::sync::MutexGuard<'_, ReplayState>> {
+        self.state
+            .0
+            .lock()
+            .map_err(|_| RTDError::Internal("replay state poisoned"))
+    }
+}
+
+impl TdLibClient for ReplayApi {
+    fn send(&self, request: &str) -> RTDResult<()> {
+        let mut state = self.lock()?;
+        state.expect_request(request, false)?;
+        state.release_received();
+        self.state.1.notify_all();
+        Ok(())
+    }
+
+    fn receive(&self, timeout: f64) -> Option<String> {
+        let timeout = Duration::from_secs_f64(timeout.max(0.0));
+        let state = self.state.0.lock().ok()?;
+        let (mut state, _) = self
+            .state
+            .1
+            .wait_timeout_while(state, timeout, |state| state.to_receive.is_empty())
+            .ok()?;
+        state.to_receive.pop_front()
+    }
+
+    fn execute(&self, request: &str) -> RTDResult<Option<String>> {
+        let mut state = self.lock()?;
+        let entry = state.expect_request(request, true)?;
+        state.release_received();
+        self.state.1.notify_all();
+        match entry.event {
+            RecordedEvent::Executed { response, .. } => Ok(response),
+            _ => Err(RTDError::Internal("request doesn't match recorded session")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::api::TdLibClient;
+    use crate::client::recording::{
+        read_session, RecordEntry, RecordedEvent, RecordingApi, ReplayApi,
+    };
+    use crate::client::{ClientBuilder, ConsoleAuthStateHandler};
+    use crate::types::{
+        GetMe, RObject, SearchPublicChats, TdlibParameters, UpdateAuthorizationState,
+    };
+    use std::time::Duration;
+    use tokio::time::timeout;
+
+    fn recorded_search(query: &str) -> Vec<RecordEntry> {
+        vec![
+            RecordEntry::new(RecordedEvent::Sent {
+                json: format!(
+                    r#"{{"@type":"searchPublicChats","@extra":"recorded","query":"{}"}}"#,
+                    query
+                ),
+            }),
+            RecordEntry::new(RecordedEvent::Received {
+                json: r#"{"@type":"chats","chat_ids":[1,2,3],"@extra":"recorded"}"#.to_string(),
+            }),
+        ]
+    }
+
+    async fn search<S: TdLibClient + Send + Sync + Clone + 'static>(api: S, query: &str) {
+        let client = ClientBuilder::default()
+            .with_tdlib_parameters(TdlibParameters::builder().build())
+            .with_auth_state_handler(ConsoleAuthStateHandler::new())
+            .with_read_updates_timeout(0.1)
+            .build_with_tdlib_client(api)
+            .unwrap();
+        let (sx, _rx) = tokio::sync::mpsc::channel::<UpdateAuthorizationState>(10);
+        let _updates_handle = client.init_updates_task(sx).unwrap();
+        match timeout(
+            Duration::from_secs(5),
+            client
+                .api()
+                .search_public_chats(SearchPublicChats::builder().query(query).build()),
+        )
+        .await
+        {
+            Err(_) => panic!("did not receive response within 5 s"),
+            Ok(Err(e)) => panic!("{}", e),
+            Ok(Ok(result)) => assert_eq!(result.chat_ids(), &vec![1, 2, 3]),
+        }
+        client.stop();
+    }
+
+    #[tokio::test]
+    async fn test_record_and_replay() {
+        let path = std::env::temp_dir().join("rust_tdlib_test_recorded_session");
+
+        let recording = RecordingApi::new(ReplayApi::new(recorded_search("rust")), &path).unwrap();
+        search(recording.clone(), "rust").await;
+        assert!(recording.inner().is_finished());
+
+        let recorded = read_session(&path).unwrap();
+        assert_eq!(recorded.len(), 2);
+        assert!(matches!(recorded[0].event(), RecordedEvent::Sent { .. }));
+        assert!(matches!(
+            recorded[1].event(),
+            RecordedEvent::Received { .. }
+        ));
+
+        let replay = ReplayApi::open(&path).unwrap();
+        assert_eq!(replay.remaining_requests(), 1);
+        search(replay.clone(), "rust").await;
+        assert!(replay.is_finished());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_replay_unexpected_request() {
+        let replay = ReplayApi::new(recorded_search("rust"));
+        assert!(replay
+            .send(&GetMe::builder().build().to_json().unwrap())
+            .is_err());
+
+        let replay = ReplayApi::new(recorded_search("rust"));
+        assert!(replay
+            .send(
+                &SearchPublicChats::builder()
+                    .query("go")
+                    .build()
+                    .to_json()
+                    .unwrap()
+            )
+            .is_err());
+    }
+}
@@ -0,0 +1,490 @@
+//! Remote TDlib access over a socket.
+//!
+//! [RemoteApi](crate::client::remote::RemoteApi) implements [TdLibClient](crate::client::api::TdLibClient),
+//! so [Client](crate::client::Client) and [Api](crate::client::api::Api) can work with TDlib, started on another host.
+//! TDlib side is served by [serve_connection](crate::client::remote::serve_connection), see `rust-tdlib-server` binary.
+//!
+//! Protocol is newline-delimited json: every line is a single [RemoteFrame](crate::client::remote::RemoteFrame).
+//! Over WebSocket (`websocket` feature) every frame is a single text message instead.
+//! If server requires shared secret, client sends it in the first [Auth](crate::client::remote::RemoteFrame::Auth) frame,
+//! which is checked with [accept_auth](crate::client::remote::accept_auth).
+#[cfg(feature = "websocket")]
+mod websocket;
+
+#[cfg(feature = "websocket")]
+pub use websocket::{WebSocketReader, WebSocketWriter};
+
+use crate::{
+    client::api::TdLibClient,
+    errors::{RTDError, RTDResult},
+};
+use std::fmt;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+#[cfg(unix)]
+use std::path::Path;
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    mpsc, Arc, Mutex,
+};
+use std::thread;
+use std::time::Duration;
+
+/// Single message of remote protocol.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum RemoteFrame {
+    /// Shared secret, the first frame sent by client to server, which requires it
+    Auth { secret: String },
+    /// Server accepted `Auth` frame
+    Authenticated,
+    /// Request, which must be sent to TDlib with `td_json_client_send`
+    Send { request: String },
+    /// Request, which must be executed synchronously with `td_json_client_execute`
+    Execute { request: String },
+    /// Json, received from TDlib
+    Received { json: String },
+    /// Result of `Execute` request
+    Executed { json: Option<String> },
+}
+
+impl fmt::Debug for RemoteFrame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            // secret must not be leaked to logs
+            RemoteFrame::Auth { .. } => f.debug_struct("Auth").finish(),
+            RemoteFrame::Authenticated => f.write_str("Authenticated"),
+            RemoteFrame::Send { request } => {
+                f.debug_struct("Send").field("request", request).finish()
+            }
+            RemoteFrame::Execute { request } => {
+                f.debug_struct("Execute").field("request", request).finish()
+            }
+            RemoteFrame::Received { json } => {
+                f.debug_struct("Received").field("json", json).finish()
+            }
+            RemoteFrame::Executed { json } => {
+                f.debug_struct("Executed").field("json", json).finish()
+            }
+        }
+    }
+}
+
+impl RemoteFrame {
+    // reads single frame, returns `None` if connection closed
+    fn read_from<R: BufRead + ?Sized>(reader: &mut R) -> RTDResult<Option<Self>> {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+        Ok(Some(serde_json::from_str(&line)?))
+    }
+
+    fn write_to<W: Write + ?Sized>(&self, writer: &mut W) -> RTDResult<()> {
+        let mut line = serde_json::to_string(self)?;
+        line.push('\n');
+        writer.write_all(line.as_bytes())?;
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+struct RemoteConnection {
+    writer: Mutex<Box<dyn Write + Send>>,
+    received: Mutex<mpsc::Receiver<String>>,
+    executed: Mutex<mpsc::Receiver<Option<String>>>,
+}
+
+impl fmt::Debug for RemoteConnection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RemoteConnection").finish()
+    }
+}
+
+/// [TdLibClient](crate::client::api::TdLibClient), which interacts with remote TDlib over a socket.
+#[derive(Clone, Debug)]
+pub struct RemoteApi {
+    connection: Arc<RemoteConnection>,
+}
+
+impl RemoteApi {
+    /// Connects to TDlib server over TCP.
+    /// `secret` must be specified if server requires it
+    pub fn connect_tcp<A: ToSocketAddrs>(addr: A, secret: Option<&str>) -> RTDResult<Self> {
+        let stream = TcpStream::connect(addr)?;
+        stream.set_nodelay(true)?;
+        let reader = stream.try_clone()?;
+        Self::from_streams(reader, stream, secret)
+    }
+
+    /// Connects to TDlib server over Unix socket.
+    /// `secret` must be specified if server requires it
+    #[cfg(unix)]
+    pub fn connect_unix<P: AsRef<Path>>(path: P, secret: Option<&str>) -> RTDResult<Self> {
+        let stream = std::os::unix::net::UnixStream::connect(path)?;
+        let reader = stream.try_clone()?;
+        Self::from_streams(reader, stream, secret)
+    }
+
+    /// Connects to TDlib server over WebSocket, e.g. `ws://127.0.0.1:7879`. TLS is not supported.
+    /// `secret` must be specified if server requires it
+    #[cfg(feature = "websocket")]
+    pub fn connect_websocket(url: &str, secret: Option<&str>) -> RTDResult<Self> {
+        let (reader, writer) = websocket::connect(url)?;
+        Self::from_streams(reader, writer, secret)
+    }
+
+    /// Creates client from any connected stream halves, sends `secret` first if specified
+    pub fn from_streams<R, W>(reader: R, mut writer: W, secret: Option<&str>) -> RTDResult<Self>
+    where
+        R: Read + Send + 'static,
+        W: Write + Send + 'static,
+    {
+        let mut reader = BufReader::new(reader);
+        if let Some(secret) = secret {
+            RemoteFrame::Auth {
+                secret: secret.to_string(),
+            }
+            .write_to(&mut writer)?;
+            match RemoteFrame::read_from(&mut reader)? {
+                Some(RemoteFrame::Authenticated) => trace!("authenticated by remote tdlib"),
+                _ => return Err(RTDError::InvalidParameters("remote tdlib rejected secret")),
+            }
+        }
+        let (received_sx, received_rx) = mpsc::channel();
+        let (executed_sx, executed_rx) = mpsc::channel();
+        thread::Builder::new()
+            .name("tdlib-remote-reader".to_string())
+            .spawn(move || {
+                for line in reader.lines() {
+                    let frame = match line.map_err(RTDError::from).and_then(|line| {
+                        serde_json::from_str::<RemoteFrame>(&line).map_err(RTDError::from)
+                    }) {
+                        Ok(frame) => frame,
+                        Err(e) => {
+                            error!("can't read frame from remote tdlib: {}", e);
+                            break;
+                        }
+                    };
+                    let sent = match frame {
+                        RemoteFrame::Received { json } => received_sx.send(json).is_ok(),
+                        RemoteFrame::Executed { json } => executed_sx.send(json).is_ok(),
+                        frame => {
+                            warn!("unexpected frame received from remote tdlib: {:?}", frame);
+                            true
+                        }
+                    };
+                    if !sent {
+                        break;
+                    }
+                }
+                trace!("remote tdlib connection closed");
+            })?;
+        Ok(Self {
+            connection: Arc::new(RemoteConnection {
+                writer: Mutex::new(Box::new(writer)),
+                received: Mutex::new(received_rx),
+                executed: Mutex::new(executed_rx),
+            }),
+        })
+    }
+
+    fn write_frame(&self, frame: &RemoteFrame) -> RTDResult<()> {
+        let mut writer = self
+            .connection
+            .writer
+            .lock()
+            .map_err(|_| RTDError::Internal("remote connection writer poisoned"))?;
+        frame.write_to(&mut *writer)
+    }
+}
+
+impl TdLibClient for RemoteApi {
+    fn send(&self, request: &str) -> RTDResult<()> {
+        self.write_frame(&RemoteFrame::Send {
+            request: request.to_string(),
+        })
+    }
+
+    fn receive(&self, timeout: f64) -> Option<String> {
+        let timeout = Duration::from_secs_f64(timeout.max(0.0));
+        let received = self.connection.received.lock().ok()?;
+        match received.recv_timeout(timeout) {
+            Ok(json) => Some(json),
+            Err(mpsc::RecvTimeoutError::Timeout) => None,
+            Err(mpsc::RecvTimeoutError::Disconnected) => {
+                // nothing will be received anymore, don't let the caller spin
+                thread::sleep(timeout);
+                None
+            }
+        }
+    }
+
+    fn execute(&self, request: &str) -> RTDResult<Option<String>> {
+        // lock held until response received, so responses can't be mixed up
+        let executed = self
+            .connection
+            .executed
+            .lock()
+            .map_err(|_| RTDError::Internal("remote connection poisoned"))?;
+        self.write_frame(&RemoteFrame::Execute {
+            request: request.to_string(),
+        })?;
+        executed
+            .recv()
+            .map_err(|_| RTDError::Internal("remote connection closed"))
+    }
+}
+
+/// Checks shared secret, which remote client sends in the first [Auth](crate::client::remote::RemoteFrame::Auth) frame, and confirms it.
+/// Connection must be closed if error returned, otherwise pass the same `reader` to [serve_connection](crate::client::remote::serve_connection).
+pub fn accept_auth<R, W>(reader: &mut R, writer: &mut W, secret: &str) -> RTDResult<()>
+where
+    R: BufRead + ?Sized,
+    W: Write + ?Sized,
+{
+    match RemoteFrame::read_from(reader)? {
+        Some(RemoteFrame::Auth { secret: received }) if secrets_equal(&received, secret) => {
+            RemoteFrame::Authenticated.write_to(writer)
+        }
+        _ => Err(RTDError::InvalidParameters(
+            "remote client sent invalid secret",
+        )),
+    }
+}
+
+// compares all bytes, so time of comparison doesn't tell how many leading bytes match
+fn secrets_equal(received: &str, expected: &str) -> bool {
+    received.len() == expected.len()
+        && received
+            .bytes()
+            .zip(expected.bytes())
+            .fold(0, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+/// Performs server side of WebSocket handshake on connection, accepted with [TcpListener](std::net::TcpListener).
+/// Returned halves are passed to [accept_auth](crate::client::remote::accept_auth) and [serve_connection](crate::client::remote::serve_connection).
+#[cfg(feature = "websocket")]
+pub fn accept_websocket(stream: TcpStream) -> RTDResult<(WebSocketReader, WebSocketWriter)> {
+    websocket::accept(stream)
+}
+
+/// Serves single remote client connection with specified [TdLibClient](crate::client::api::TdLibClient).
+/// Returns when remote client closes the connection.
+pub fn serve_connection<S, R, W>(
+    tdlib_client: S,
+    reader: R,
+    writer: W,
+    read_updates_timeout: f64,
+) -> RTDResult<()>
+where
+    S: TdLibClient + Clone + Send + 'static,
+    R: Read,
+    W: Write + Send + 'static,
+{
+    let writer = Arc::new(Mutex::new(writer));
+    let closed = Arc::new(AtomicBool::new(false));
+
+    let receive_client = tdlib_client.clone();
+    let receive_writer = writer.clone();
+    let receive_closed = closed.clone();
+    let receive_handle = thread::Builder::new()
+        .name("tdlib-remote-server".to_string())
+        .spawn(move || {
+            while !receive_closed.load(Ordering::Acquire) {
+                if let Some(json) = receive_client.receive(read_updates_timeout) {
+                    let written = match receive_writer.lock() {
+                        Ok(mut writer) => RemoteFrame::Received { json }.write_to(&mut *writer),
+                        Err(_) => Err(RTDError::Internal("remote connection writer poisoned")),
+                    };
+                    if let Err(e) = written {
+                        error!("can't send received json to remote client: {}", e);
+                        break;
+                    }
+                }
+            }
+        })?;
+
+    let result = serve_requests(&tdlib_client, reader, &writer);
+    closed.store(true, Ordering::Release);
+    if receive_handle.join().is_err() {
+        error!("remote server receive thread panicked");
+    }
+    result
+}
+
+fn serve_requests<S, R, W>(tdlib_client: &S, reader: R, writer: &Arc<Mutex<W>>) -> RTDResult<()>
+where
+    S: TdLibClient,
+    R: Read,
+    W: Write,
+{
+    for line in BufReader::new(reader).lines() {
+        match serde_json::from_str::<RemoteFrame>(&line?)? {
+            RemoteFrame::Send { request } => tdlib_client.send(&request)?,
+            // secret is not required or already checked with `accept_auth`
+            RemoteFrame::Auth { .. } => {
+                let mut writer = writer
+                    .lock()
+                    .map_err(|_| RTDError::Internal("remote connection writer poisoned"))?;
+                RemoteFrame::Authenticated.write_to(&mut *writer)?;
+            }
+            RemoteFrame::Execute { request } => {
+                let json = tdlib_client.execute(&request)?;
+                let mut writer = writer
+                    .lock()
+                    .map_err(|_| RTDError::Internal("remote connection writer poisoned"))?;
+                RemoteFrame::Executed { json }.write_to(&mut *writer)?;
+            }
+            frame => warn!("unexpected frame received from remote client: {:?}", frame),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::api::TdLibClient;
+    use crate::client::remote::{accept_auth, serve_connection, RemoteApi};
+    use crate::client::testing::{MockApi, MockRule};
+    use crate::client::{ClientBuilder, ConsoleAuthStateHandler};
+    use crate::types::{RObject, SearchPublicChats, TdlibParameters, UpdateAuthorizationState};
+    use std::io::{BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::time::Duration;
+    use tokio::time::timeout;
+
+    #[tokio::test]
+    async fn test_tcp_round_trip() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let reader = stream.try_clone().unwrap();
+            let mock = MockApi::new();
+            mock.add_rule(
+                MockRule::new("searchPublicChats")
+                    .respond(serde_json::json!({"@type": "chats", "chat_ids": [1, 2, 3]})),
+            );
+            serve_connection(mock, reader, stream, 0.1).unwrap();
+        });
+
+        let remote_api = RemoteApi::connect_tcp(addr, None).unwrap();
+        let executed = remote_api
+            .execute(
+                &SearchPublicChats::builder()
+                    .query("rust")
+                    .build()
+                    .to_json()
+                    .unwrap(),
+            )
+            .unwrap()
+            .unwrap();
+        assert!(executed.contains("\"chat_ids\":[1,2,3]"));
+
+        let client = ClientBuilder::default()
+            .with_tdlib_parameters(TdlibParameters::builder().build())
+            .with_auth_state_handler(ConsoleAuthStateHandler::new())
+            .with_read_updates_timeout(0.1)
+            .build_with_tdlib_client(remote_api)
+            .unwrap();
+        let (sx, _rx) = tokio::sync::mpsc::channel::<UpdateAuthorizationState>(10);
+        let _updates_handle = client.init_updates_task(sx).unwrap();
+        match timeout(
+            Duration::from_secs(5),
+            client
+                .api()
+                .search_public_chats(SearchPublicChats::builder().build()),
+        )
+        .await
+        {
+            Err(_) => panic!("did not receive response within 5 s"),
+            Ok(Err(e)) => panic!("{}", e),
+            Ok(Ok(result)) => assert_eq!(result.chat_ids(), &vec![1, 2, 3]),
+        }
+        client.stop();
+    }
+
+    fn search_mock() -> MockApi {
+        let mock = MockApi::new();
+        mock.add_rule(
+            MockRule::new("searchPublicChats")
+                .respond(serde_json::json!({"@type": "chats", "chat_ids": [1, 2, 3]})),
+        );
+        mock
+    }
+
+    // checks secret like `rust-tdlib-server` does, then serves connection with mock
+    fn serve_with_secret<R, W>(reader: R, mut writer: W, secret: &str)
+    where
+        R: Read,
+        W: Write + Send + 'static,
+    {
+        let mut reader = BufReader::new(reader);
+        if accept_auth(&mut reader, &mut writer, secret).is_ok() {
+            serve_connection(search_mock(), reader, writer, 0.1).unwrap();
+        }
+    }
+
+    fn search(remote_api: &RemoteApi) -> String {
+        remote_api
+            .execute(&SearchPublicChats::builder().build().to_json().unwrap())
+            .unwrap()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_tcp_secret() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let stream = stream.unwrap();
+                let reader = stream.try_clone().unwrap();
+                std::thread::spawn(move || serve_with_secret(reader, stream, "secret"));
+            }
+        });
+
+        let remote_api = RemoteApi::connect_tcp(addr, Some("secret")).unwrap();
+        assert!(search(&remote_api).contains("\"chat_ids\":[1,2,3]"));
+        assert!(RemoteApi::connect_tcp(addr, Some("wrong")).is_err());
+        assert!(RemoteApi::connect_tcp(addr, Some("secret!")).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "websocket")]
+    fn test_websocket_round_trip() {
+        use crate::client::remote::accept_websocket;
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let (reader, writer) = accept_websocket(stream).unwrap();
+            serve_with_secret(reader, writer, "secret");
+        });
+
+        let remote_api =
+            RemoteApi::connect_websocket(&format!("ws://{}", addr), Some("secret")).unwrap();
+        assert!(search(&remote_api).contains("\"chat_ids\":[1,2,3]"));
+        remote_api
+            .send(
+                &SearchPublicChats::builder()
+                    .query("rust")
+                    .build()
+                    .to_json()
+                    .unwrap(),
+            )
+            .unwrap();
+        let received = remote_api.receive(5.).unwrap();
+        assert!(received.contains("\"chat_ids\":[1,2,3]"));
+
+        // frames are written as soon as they're sent, not between reads
+        let started = std::time::Instant::now();
+        for _ in 0..50 {
+            search(&remote_api);
+        }
+        assert!(started.elapsed() < Duration::from_millis(250));
+    }
+}
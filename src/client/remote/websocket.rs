@@ -0,0 +1,190 @@
+//! WebSocket transport of remote protocol: every [RemoteFrame](crate::client::remote::RemoteFrame) is a single text message.
+//!
+//! Socket is shared by newline-delimited reader and writer halves, so the same protocol code serves TCP,
+//! Unix socket and WebSocket connections. Reader waits for incoming data without locking the socket,
+//! so writes are never delayed by reading.
+use crate::errors::{RTDError, RTDResult};
+use std::collections::VecDeque;
+use std::fmt;
+use std::io::{self, Read, Write};
+use std::net::TcpStream;
+use std::sync::{Arc, Mutex};
+use tungstenite::{client::IntoClientRequest, Message, WebSocket};
+
+type SharedSocket = Arc<Mutex<WebSocket<TcpStream>>>;
+
+/// Reading half of WebSocket connection, yields received messages as newline-delimited frames.
+pub struct WebSocketReader {
+    socket: SharedSocket,
+    // clone of socket stream, used to wait for incoming data
+    stream: TcpStream,
+    messages: VecDeque<Vec<u8>>,
+    pending: Vec<u8>,
+    position: usize,
+    closed: bool,
+}
+
+impl fmt::Debug for WebSocketReader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WebSocketReader").finish()
+    }
+}
+
+impl WebSocketReader {
+    // blocks until data arrives, then reads all available messages
+    fn receive(&mut self) -> io::Result<()> {
+        let mut byte = [0u8; 1];
+        if self.stream.peek(&mut byte)? == 0 {
+            self.closed = true;
+            return Ok(());
+        }
+        let mut socket = self.socket.lock().unwrap();
+        socket.get_ref().set_nonblocking(true)?;
+        let received = read_available(&mut socket, &mut self.messages);
+        socket.get_ref().set_nonblocking(false)?;
+        self.closed = !received?;
+        Ok(())
+    }
+}
+
+impl Read for WebSocketReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while self.position == self.pending.len() {
+            match self.messages.pop_front() {
+                Some(message) => {
+                    self.pending = message;
+                    self.position = 0;
+                }
+                // connection closed
+                None if self.closed => return Ok(0),
+                None => self.receive()?,
+            }
+        }
+        let read = (&self.pending[self.position..]).read(buf)?;
+        self.position += read;
+        Ok(read)
+    }
+}
+
+/// Writing half of WebSocket connection, sends every written line as a text message.
+/// Connection is closed when writer is dropped.
+pub struct WebSocketWriter {
+    socket: SharedSocket,
+    line: Vec<u8>,
+}
+
+impl fmt::Debug for WebSocketWriter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WebSocketWriter").finish()
+    }
+}
+
+impl Write for WebSocketWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.line.extend_from_slice(buf);
+        while let Some(end) = self.line.iter().position(|b| *b == b'\n') {
+            let mut line: Vec<u8> = self.line.drain(..=end).collect();
+            line.pop();
+            let text = String::from_utf8(line)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+            self.socket
+                .lock()
+                .unwrap()
+                .write_message(Message::Text(text))
+                .map_err(|e| match e {
+                    tungstenite::Error::Io(e) => e,
+                    e => io::Error::other(e.to_string()),
+                })?;
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Drop for WebSocketWriter {
+    fn drop(&mut self) {
+        if let Ok(mut socket) = self.socket.lock() {
+            // peer may be already gone
+            let _ = socket.close(None);
+            let _ = socket.write_pending();
+        }
+    }
+}
+
+/// Connects to `ws://` url
+pub(super) fn connect(url: &str) -> RTDResult<(WebSocketReader, WebSocketWriter)> {
+    let request = url.into_client_request().map_err(io_error)?;
+    if request.uri().scheme_str() != Some("ws") {
+        return Err(RTDError::InvalidParameters(
+            "only ws:// urls are supported by remote websocket transport",
+        ));
+    }
+    let host = request
+        .uri()
+        .host()
+        .ok_or(RTDError::InvalidParameters("websocket url without host"))?
+        .to_string();
+    let port = request.uri().port_u16().unwrap_or(80);
+    let stream = TcpStream::connect((host.as_str(), port))?;
+    stream.set_nodelay(true)?;
+    let (socket, _) = tungstenite::client(request, stream).map_err(io_error)?;
+    split(socket)
+}
+
+/// Performs server side of WebSocket handshake on accepted connection
+pub(super) fn accept(stream: TcpStream) -> RTDResult<(WebSocketReader, WebSocketWriter)> {
+    stream.set_nodelay(true)?;
+    let socket = tungstenite::accept(stream).map_err(io_error)?;
+    split(socket)
+}
+
+fn split(socket: WebSocket<TcpStream>) -> RTDResult<(WebSocketReader, WebSocketWriter)> {
+    let stream = socket.get_ref().try_clone()?;
+    let socket = Arc::new(Mutex::new(socket));
+    Ok((
+        WebSocketReader {
+            socket: socket.clone(),
+            stream,
+            messages: VecDeque::new(),
+            pending: Vec::new(),
+            position: 0,
+            closed: false,
+        },
+        WebSocketWriter {
+            socket,
+            line: Vec::new(),
+        },
+    ))
+}
+
+// reads messages until socket would block, returns `false` if connection closed
+fn read_available(
+    socket: &mut WebSocket<TcpStream>,
+    messages: &mut VecDeque<Vec<u8>>,
+) -> io::Result<bool> {
+    loop {
+        let mut frame = match socket.read_message() {
+            Ok(Message::Text(text)) => text.into_bytes(),
+            Ok(Message::Binary(data)) => data,
+            // pings are answered and close handshake is completed by tungstenite
+            Ok(Message::Ping(_)) | Ok(Message::Pong(_)) | Ok(Message::Close(_)) => continue,
+            Err(tungstenite::Error::Io(e)) if e.kind() == io::ErrorKind::WouldBlock => {
+                return Ok(true)
+            }
+            Err(tungstenite::Error::ConnectionClosed) | Err(tungstenite::Error::AlreadyClosed) => {
+                return Ok(false)
+            }
+            Err(tungstenite::Error::Io(e)) => return Err(e),
+            Err(e) => return Err(io::Error::other(e.to_string())),
+        };
+        frame.push(b'\n');
+        messages.push_back(frame);
+    }
+}
+
+fn io_error<E: fmt::Display>(e: E) -> RTDError {
+    RTDError::Io(io::Error::other(e.to_string()))
+}
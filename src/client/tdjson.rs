@@ -0,0 +1,362 @@
+//! Runtime loading of TDlib JSON interface library, available with `dynamic-tdjson` feature.
+//!
+//! Unlike [RawApi](crate::client::api::RawApi), libtdjson is not linked at build time,
+//! so single binary can work with different TDlib builds.
+//! ```no_run
+//! use rust_tdlib::client::{tdjson::TdJson, ClientBuilder};
+//! use rust_tdlib::types::TdlibParameters;
+//!
+//! // library path taken from `TDJSON_PATH` environment variable
+//! let tdjson = TdJson::load_default().unwrap();
+//! let client = ClientBuilder::default()
+//!     .with_tdlib_parameters(TdlibParameters::builder().build())
+//!     .build_with_tdjson(&tdjson)
+//!     .unwrap();
+//! ```
+use crate::{
+    client::api::TdLibClient,
+    errors::{RTDError, RTDResult},
+    types::TDLIB_VERSION,
+};
+use libloading::Library;
+use std::ffi::{CStr, CString, OsStr};
+use std::os::raw::{c_char, c_double, c_int, c_long, c_void};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Environment variable with path to libtdjson, used by [TdJson::load_default](crate::client::tdjson::TdJson::load_default).
+pub const TDJSON_PATH_ENV: &str = "TDJSON_PATH";
+
+#[cfg(target_os = "macos")]
+const DEFAULT_LIBRARY_NAME: &str = "libtdjson.dylib";
+#[cfg(target_os = "windows")]
+const DEFAULT_LIBRARY_NAME: &str = "tdjson.dll";
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+const DEFAULT_LIBRARY_NAME: &str = "libtdjson.so";
+
+const GET_VERSION_REQUEST: &str = r#"{"@type":"getOption","name":"version","@extra":"version"}"#;
+const VERSION_TIMEOUT: Duration = Duration::from_secs(10);
+
+type TdJsonClient = *mut c_void;
+
+// symbols of libtdjson, valid while library loaded
+struct TdJsonFunctions {
+    client_create: unsafe extern "C" fn() -> TdJsonClient,
+    client_send: unsafe extern "C" fn(TdJsonClient, *const c_char),
+    client_receive: unsafe extern "C" fn(TdJsonClient, c_double) -> *const c_char,
+    client_execute: unsafe extern "C" fn(TdJsonClient, *const c_char) -> *const c_char,
+    client_destroy: unsafe extern "C" fn(TdJsonClient),
+    // logging functions are absent in latest TDlib versions
+    set_log_verbosity_level: Option<unsafe extern "C" fn(c_int)>,
+    set_log_file_path: Option<unsafe extern "C" fn(*const c_char) -> c_int>,
+    set_log_max_file_size: Option<unsafe extern "C" fn(c_long)>,
+}
+
+/// Loaded libtdjson.
+pub struct TdJson {
+    functions: TdJsonFunctions,
+    path: String,
+    // must be dropped after all functions usages
+    _library: Library,
+}
+
+impl std::fmt::Debug for TdJson {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TdJson").field("path", &self.path).finish()
+    }
+}
+
+impl TdJson {
+    /// Loads library from `TDJSON_PATH` environment variable, or from system library path if variable not set
+    pub fn load_default() -> RTDResult<Arc<Self>> {
+        match std::env::var_os(TDJSON_PATH_ENV) {
+            Some(path) => Self::load(path),
+            None => Self::load(DEFAULT_LIBRARY_NAME),
+        }
+    }
+
+    /// Loads library from specified path and checks that its version matches [TDLIB_VERSION](crate::types::TDLIB_VERSION).
+    /// Returns an error if version doesn't match or can't be determined, see [version](crate::client::tdjson::TdJson::version)
+    pub fn load<P: AsRef<OsStr>>(path: P) -> RTDResult<Arc<Self>> {
+        let tdjson = Self::load_unchecked(path)?;
+        tdjson.check_version(TDLIB_VERSION)?;
+        Ok(tdjson)
+    }
+
+    /// Loads library from specified path without version check
+    pub fn load_unchecked<P: AsRef<OsStr>>(path: P) -> RTDResult<Arc<Self>> {
+        let path_str = path.as_ref().to_string_lossy().to_string();
+        // library initialization routines are trusted, it's TDlib
+        let library = unsafe { Library::new(path.as_ref()) }.map_err(|e| {
+            RTDError::LibraryLoading(format!(
+                "can't load libtdjson from {}: {}; set {} environment variable to the library path",
+                path_str, e, TDJSON_PATH_ENV
+            ))
+        })?;
+        let functions = unsafe {
+            TdJsonFunctions {
+                client_create: required_symbol(&library, &path_str, "td_json_client_create")?,
+                client_send: required_symbol(&library, &path_str, "td_json_client_send")?,
+                client_receive: required_symbol(&library, &path_str, "td_json_client_receive")?,
+                client_execute: required_symbol(&library, &path_str, "td_json_client_execute")?,
+                client_destroy: required_symbol(&library, &path_str, "td_json_client_destroy")?,
+                set_log_verbosity_level: optional_symbol(&library, "td_set_log_verbosity_level"),
+                set_log_file_path: optional_symbol(&library, "td_set_log_file_path"),
+                set_log_max_file_size: optional_symbol(&library, "td_set_log_max_file_size"),
+            }
+        };
+        Ok(Arc::new(Self {
+            functions,
+            path: path_str,
+            _library: library,
+        }))
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Returns TDlib version.
+    /// Newer libraries return it synchronously, older ones, e.g. TDlib 1.6, can't execute `getOption`,
+    /// so temporary TDlib instance is created and version received from its `updateOption`.
+    /// Returns `None` if version isn't received within 10 seconds
+    pub fn version(&self) -> Option<String> {
+        self.execute(std::ptr::null_mut(), GET_VERSION_REQUEST)
+            .and_then(|response| version_from(&response))
+            .or_else(|| self.receive_version())
+    }
+
+    /// Returns an error if library version doesn't start with `expected` or can't be determined
+    pub fn check_version(&self, expected: &str) -> RTDResult<()> {
+        match self.version() {
+            Some(version) if version.starts_with(expected) => Ok(()),
+            Some(version) => Err(RTDError::LibraryLoading(format!(
+                "libtdjson from {} has version {}, but version {} is required",
+                self.path, version, expected
+            ))),
+            None => Err(RTDError::LibraryLoading(format!(
+                "can't get version of libtdjson from {}, version {} is required; use TdJson::load_unchecked to skip the check",
+                self.path, expected
+            ))),
+        }
+    }
+
+    // TDlib sends `updateOption` with version after instance creation and answers `getOption` request
+    fn receive_version(&self) -> Option<String> {
+        let instance = unsafe { (self.functions.client_create)() };
+        let request = CString::new(GET_VERSION_REQUEST).ok()?;
+        unsafe { (self.functions.client_send)(instance, request.as_ptr()) };
+        let deadline = Instant::now() + VERSION_TIMEOUT;
+        let mut version = None;
+        while version.is_none() {
+            let now = Instant::now();
+            if now >= deadline {
+                break;
+            }
+            let timeout = (deadline - now).as_secs_f64();
+            let received =
+                copy_response(unsafe { (self.functions.client_receive)(instance, timeout) });
+            version = received.and_then(|received| version_from(&received));
+        }
+        unsafe { (self.functions.client_destroy)(instance) };
+        version
+    }
+
+    pub fn set_log_verbosity_level(&self, level: i32) -> RTDResult<()> {
+        if !(0..=1024).contains(&level) {
+            return Err(RTDError::InvalidParameters(
+                "log verbosity level should be in range 0..=1024",
+            ));
+        }
+        match self.functions.set_log_verbosity_level {
+            Some(set_log_verbosity_level) => unsafe { set_log_verbosity_level(level) },
+            None => warn!("td_set_log_verbosity_level not supported by {}", self.path),
+        }
+        Ok(())
+    }
+
+    pub fn set_log_file_path(&self, path: &str) -> RTDResult<()> {
+        let path = CString::new(path)
+            .map_err(|_| RTDError::InvalidParameters("log file path contains nul byte"))?;
+        match self.functions.set_log_file_path {
+            Some(set_log_file_path) => match unsafe { set_log_file_path(path.as_ptr()) } {
+                1 => Ok(()),
+                _ => Err(RTDError::TdlibError(
+                    "can't set TDlib log file path".to_string(),
+                )),
+            },
+            None => {
+                warn!("td_set_log_file_path not supported by {}", self.path);
+                Ok(())
+            }
+        }
+    }
+
+    pub fn set_log_max_file_size(&self, size: i64) {
+        match self.functions.set_log_max_file_size {
+            Some(set_log_max_file_size) => unsafe { set_log_max_file_size(size as c_long) },
+            None => warn!("td_set_log_max_file_size not supported by {}", self.path),
+        }
+    }
+
+    /// Creates new TDlib instance
+    pub fn create_client(self: &Arc<Self>) -> DynamicApi {
+        let instance = unsafe { (self.functions.client_create)() };
+        DynamicApi {
+            instance: Arc::new(DynamicInstance {
+                tdjson: self.clone(),
+                instance,
+            }),
+        }
+    }
+
+    fn execute(&self, instance: TdJsonClient, request: &str) -> Option<String> {
+        let request = CString::new(request).ok()?;
+        let response = unsafe { (self.functions.client_execute)(instance, request.as_ptr()) };
+        copy_response(response)
+    }
+}
+
+// version from `getOption` response or `updateOption` update
+fn version_from(json: &str) -> Option<String> {
+    let json: serde_json::Value = serde_json::from_str(json).ok()?;
+    let value = match json["@type"].as_str() {
+        Some("optionValueString") => &json,
+        Some("updateOption") if json["name"] == "version" => &json["value"],
+        _ => return None,
+    };
+    match value["@type"].as_str() {
+        Some("optionValueString") => value["value"].as_str().map(|v| v.to_string()),
+        _ => None,
+    }
+}
+
+unsafe fn required_symbol<T: Copy>(library: &Library, path: &str, name: &str) -> RTDResult<T> {
+    let symbol = format!("{}\0", name);
+    match library.get::<T>(symbol.as_bytes()) {
+        Ok(symbol) => Ok(*symbol),
+        Err(e) => Err(RTDError::LibraryLoading(format!(
+            "{} not found in {}: {}; probably it's not libtdjson or its version is not supported",
+            name, path, e
+        ))),
+    }
+}
+
+unsafe fn optional_symbol<T: Copy>(library: &Library, name: &str) -> Option<T> {
+    let symbol = format!("{}\0", name);
+    library
+        .get::<T>(symbol.as_bytes())
+        .ok()
+        .map(|symbol| *symbol)
+}
+
+// TDlib owns returned string until next call
+fn copy_response(response: *const c_char) -> Option<String> {
+    if response.is_null() {
+        return None;
+    }
+    let response = unsafe { CStr::from_ptr(response) };
+    Some(response.to_string_lossy().to_string())
+}
+
+struct DynamicInstance {
+    tdjson: Arc<TdJson>,
+    instance: TdJsonClient,
+}
+
+// TDlib instance may be used from any thread
+unsafe impl Send for DynamicInstance {}
+unsafe impl Sync for DynamicInstance {}
+
+impl Drop for DynamicInstance {
+    fn drop(&mut self) {
+        unsafe { (self.tdjson.functions.client_destroy)(self.instance) }
+    }
+}
+
+/// [TdLibClient](crate::client::api::TdLibClient), backed by TDlib instance of dynamically loaded libtdjson.
+/// Created with [TdJson::create_client](crate::client::tdjson::TdJson::create_client).
+#[derive(Clone)]
+pub struct DynamicApi {
+    instance: Arc<DynamicInstance>,
+}
+
+impl std::fmt::Debug for DynamicApi {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DynamicApi")
+            .field("tdjson", &self.instance.tdjson)
+            .finish()
+    }
+}
+
+impl TdLibClient for DynamicApi {
+    fn send(&self, request: &str) -> RTDResult<()> {
+        let request = CString::new(request)
+            .map_err(|_| RTDError::InvalidParameters("request contains nul byte"))?;
+        let functions = &self.instance.tdjson.functions;
+        unsafe { (functions.client_send)(self.instance.instance, request.as_ptr()) };
+        Ok(())
+    }
+
+    fn receive(&self, timeout: f64) -> Option<String> {
+        let functions = &self.instance.tdjson.functions;
+        copy_response(unsafe { (functions.client_receive)(self.instance.instance, timeout) })
+    }
+
+    fn execute(&self, request: &str) -> RTDResult<Option<String>> {
+        if request.contains('\0') {
+            return Err(RTDError::InvalidParameters("request contains nul byte"));
+        }
+        Ok(self
+            .instance
+            .tdjson
+            .execute(self.instance.instance, request))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::tdjson::{version_from, TdJson};
+    use crate::errors::RTDError;
+
+    #[test]
+    fn test_version_from() {
+        assert_eq!(
+            version_from(r#"{"@type":"optionValueString","value":"1.7.0","@extra":"version"}"#),
+            Some("1.7.0".to_string())
+        );
+        assert_eq!(
+            version_from(
+                r#"{"@type":"updateOption","name":"version","value":{"@type":"optionValueString","value":"1.6.0"}}"#
+            ),
+            Some("1.6.0".to_string())
+        );
+        assert_eq!(
+            version_from(
+                r#"{"@type":"updateOption","name":"utc_time","value":{"@type":"optionValueInteger","value":1}}"#
+            ),
+            None
+        );
+        assert_eq!(
+            version_from(
+                r#"{"@type":"error","code":400,"message":"Function can't be executed synchronously"}"#
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_load_errors() {
+        match TdJson::load("/nonexistent/libtdjson.so") {
+            Err(RTDError::LibraryLoading(e)) => assert!(e.contains("/nonexistent/libtdjson.so")),
+            r => panic!("unexpected result: {:?}", r),
+        }
+
+        // any library without TDlib symbols
+        #[cfg(target_os = "linux")]
+        match TdJson::load("libm.so.6") {
+            Err(RTDError::LibraryLoading(e)) => assert!(e.contains("td_json_client_create")),
+            r => panic!("unexpected result: {:?}", r),
+        }
+    }
+}
@@ -0,0 +1,933 @@
+//! In-memory emulation of TDlib.
+use crate::{
+    client::{api::TdLibClient, testing::ReceiveQueue},
+    errors::{RTDError, RTDResult},
+    types::{Ok as TdOk, *},
+};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const DEFAULT_AUTH_CODE: &str = "12345";
+const DEFAULT_PHONE_NUMBER: &str = "+10000000000";
+// TDlib message identifiers of server messages are multiples of 2^20
+const MESSAGE_ID_STEP: i64 = 1 << 20;
+const DOWNLOAD_PROGRESS_STEPS: i32 = 4;
+
+#[derive(Debug)]
+struct FakeState {
+    authorization_state: AuthorizationState,
+    skip_authentication: bool,
+    auth_code: String,
+    password: Option<String>,
+    me: User,
+    users: BTreeMap<UserId, User>,
+    chats: Vec<Chat>,
+    // messages of every chat, oldest first
+    messages: HashMap<ChatId, Vec<Message>>,
+    files: BTreeMap<FileId, File>,
+    last_message_id: i64,
+    sent: Vec<Value>,
+}
+
+impl FakeState {
+    fn is_authorized(&self) -> bool {
+        matches!(self.authorization_state, AuthorizationState::Ready(_))
+    }
+
+    fn chat(&self, chat_id: ChatId) -> Option<&Chat> {
+        self.chats.iter().find(|chat| chat.id() == chat_id)
+    }
+
+    fn next_message_id(&mut self) -> i64 {
+        self.last_message_id += MESSAGE_ID_STEP;
+        self.last_message_id
+    }
+
+    // returns response and updates for the request
+    fn handle(&mut self, request: &Value) -> RTDResult<(Value, Vec<Value>)> {
+        let function = request["@type"].as_str().unwrap_or_default();
+        let mut updates = Vec::new();
+        let response = match function {
+            "getAuthorizationState" => to_value(&self.authorization_state)?,
+            "setTdlibParameters" => {
+                self.expect_state(function, |state| {
+                    matches!(state, AuthorizationState::WaitTdlibParameters(_))
+                })?;
+                updates.push(self.set_authorization_state(
+                    AuthorizationState::WaitEncryptionKey(
+                        AuthorizationStateWaitEncryptionKey::builder().build(),
+                    ),
+                )?);
+                ok()?
+            }
+            "checkDatabaseEncryptionKey" => {
+                self.expect_state(function, |state| {
+                    matches!(state, AuthorizationState::WaitEncryptionKey(_))
+                })?;
+                if self.skip_authentication {
+                    updates.extend(self.authorize()?);
+                } else {
+                    updates.push(self.set_authorization_state(
+                        AuthorizationState::WaitPhoneNumber(
+                            AuthorizationStateWaitPhoneNumber::builder().build(),
+                        ),
+                    )?);
+                }
+                ok()?
+            }
+            "setAuthenticationPhoneNumber" => {
+                self.expect_state(function, |state| {
+                    matches!(state, AuthorizationState::WaitPhoneNumber(_))
+                })?;
+                let code_info = AuthenticationCodeInfo::builder()
+                    .phone_number(request["phone_number"].as_str().unwrap_or_default())
+                    .type_(AuthenticationCodeType::Sms(
+                        AuthenticationCodeTypeSms::builder()
+                            .length(self.auth_code.len() as i32)
+                            .build(),
+                    ))
+                    .build();
+                updates.push(
+                    self.set_authorization_state(AuthorizationState::WaitCode(
+                        AuthorizationStateWaitCode::builder()
+                            .code_info(code_info)
+                            .build(),
+                    ))?,
+                );
+                ok()?
+            }
+            "checkAuthenticationCode" => {
+                self.expect_state(function, |state| {
+                    matches!(state, AuthorizationState::WaitCode(_))
+                })?;
+                if request["code"].as_str() != Some(self.auth_code.as_str()) {
+                    return Ok((error(400, "PHONE_CODE_INVALID"), updates));
+                }
+                if self.password.is_some() {
+                    updates.push(self.set_authorization_state(
+                        AuthorizationState::WaitPassword(
+                            AuthorizationStateWaitPassword::builder().build(),
+                        ),
+                    )?);
+                } else {
+                    updates.extend(self.authorize()?);
+                }
+                ok()?
+            }
+            "checkAuthenticationPassword" => {
+                self.expect_state(function, |state| {
+                    matches!(state, AuthorizationState::WaitPassword(_))
+                })?;
+                if request["password"].as_str() != self.password.as_deref() {
+                    return Ok((error(400, "PASSWORD_HASH_INVALID"), updates));
+                }
+                updates.extend(self.authorize()?);
+                ok()?
+            }
+            "logOut" => {
+                updates.push(self.set_authorization_state(AuthorizationState::LoggingOut(
+                    AuthorizationStateLoggingOut::builder().build(),
+                ))?);
+                updates.push(self.set_authorization_state(AuthorizationState::Closed(
+                    AuthorizationStateClosed::builder().build(),
+                ))?);
+                ok()?
+            }
+            "close" => {
+                updates.push(self.set_authorization_state(AuthorizationState::Closing(
+                    AuthorizationStateClosing::builder().build(),
+                ))?);
+                updates.push(self.set_authorization_state(AuthorizationState::Closed(
+                    AuthorizationStateClosed::builder().build(),
+                ))?);
+                ok()?
+            }
+            _ if !self.is_authorized() => error(401, "Unauthorized"),
+            "getMe" => to_value(&self.me)?,
+            "getUser" => match self.users.get(&id(request, "user_id")) {
+                Some(user) => to_value(user)?,
+                None => error(404, "User not found"),
+            },
+            "getChat" => match self.chat(id(request, "chat_id")) {
+                Some(chat) => to_value(chat)?,
+                None => error(400, "Chat not found"),
+            },
+            "getChats" => {
+                // chat list is ordered by descending order and identifier, page starts after the offset chat
+                let offset = (
+                    int(request, "offset_order"),
+                    id::<ChatId>(request, "offset_chat_id"),
+                );
+                let mut chats: Vec<&Chat> = self
+                    .chats
+                    .iter()
+                    .filter(|chat| (chat.order(), chat.id()) < offset)
+                    .collect();
+                chats.sort_by_key(|chat| Reverse((chat.order(), chat.id())));
+                let chat_ids: Vec<ChatId> = chats
+                    .iter()
+                    .map(|chat| chat.id())
+                    .take(int(request, "limit").max(0) as usize)
+                    .collect();
+                to_value(&Chats::builder().chat_ids(chat_ids).build())?
+            }
+            "sendMessage" => {
+                let chat_id = id(request, "chat_id");
+                if self.chat(chat_id).is_none() {
+                    return Ok((error(400, "Chat not found"), updates));
+                }
+                let content = &request["input_message_content"];
+                if content["@type"] != "inputMessageText" {
+                    return Ok((error(400, "Message content is not supported"), updates));
+                }
+                let text = content["text"]["text"].as_str().unwrap_or_default();
+                let message_id = self.next_message_id();
+                // temporary identifier of not yet sent message
+                let pending = message(message_id + 1, chat_id, self.me.id(), true, true, text);
+                let sent = message(message_id, chat_id, self.me.id(), true, false, text);
+                updates.push(update(
+                    &UpdateNewMessage::builder().message(&pending).build(),
+                )?);
+                updates.push(update(
+                    &UpdateMessageSendSucceeded::builder()
+                        .message(&sent)
+                        .old_message_id(pending.id())
+                        .build(),
+                )?);
+                self.messages.entry(chat_id).or_default().push(sent);
+                to_value(&pending)?
+            }
+            "getChatHistory" => {
+                let from_message_id = int(request, "from_message_id");
+                let messages: Vec<&Message> = self
+                    .messages
+                    .get(&id(request, "chat_id"))
+                    .map(|messages| messages.iter().rev().collect())
+                    .unwrap_or_default();
+                // `from_message_id` is included, negative offset returns newer messages
+                let start = messages
+                    .iter()
+                    .position(|message| from_message_id == 0 || message.id() <= from_message_id)
+                    .unwrap_or(messages.len()) as i64
+                    + int(request, "offset");
+                let messages: Vec<Option<Message>> = messages
+                    .into_iter()
+                    .skip(start.max(0) as usize)
+                    .take(int(request, "limit").max(0) as usize)
+                    .map(|message| Some(message.clone()))
+                    .collect();
+                to_value(
+                    &Messages::builder()
+                        .total_count(messages.len() as i32)
+                        .messages(messages)
+                        .build(),
+                )?
+            }
+            "getFile" => match self.files.get(&id(request, "file_id")) {
+                Some(file) => to_value(file)?,
+                None => error(404, "File not found"),
+            },
+            "downloadFile" => {
+                let file = match self.files.get(&id(request, "file_id")) {
+                    Some(file) => file.clone(),
+                    None => return Ok((error(404, "File not found"), updates)),
+                };
+                if file.local().is_downloading_completed() {
+                    return Ok((to_value(&file)?, updates));
+                }
+                for step in 1..DOWNLOAD_PROGRESS_STEPS {
+                    let progress = file_with_progress(
+                        file.id(),
+                        file.size(),
+                        file.size() * step / DOWNLOAD_PROGRESS_STEPS,
+                    );
+                    updates.push(update(&UpdateFile::builder().file(progress).build())?);
+                }
+                let downloaded = file_with_progress(file.id(), file.size(), file.size());
+                updates.push(update(&UpdateFile::builder().file(&downloaded).build())?);
+                self.files.insert(file.id(), downloaded.clone());
+                if request["synchronous"].as_bool().unwrap_or_default() {
+                    to_value(&downloaded)?
+                } else {
+                    to_value(&file_with_progress(file.id(), file.size(), 0))?
+                }
+            }
+            _ => {
+                warn!("fake telegram doesn't support request: {}", request);
+                error(400, format!("Method {} is not supported", function))
+            }
+        };
+        Ok((response, updates))
+    }
+
+    fn expect_state<F: Fn(&AuthorizationState) -> bool>(
+        &self,
+        function: &str,
+        expected: F,
+    ) -> RTDResult<()> {
+        if expected(&self.authorization_state) {
+            return Ok(());
+        }
+        warn!(
+            "unexpected {} in authorization state {:?}",
+            function, self.authorization_state
+        );
+        Err(RTDError::InvalidParameters(
+            "request unexpected in current authorization state",
+        ))
+    }
+
+    fn set_authorization_state(&mut self, state: AuthorizationState) -> RTDResult<Value> {
+        self.authorization_state = state;
+        update(
+            &UpdateAuthorizationState::builder()
+                .authorization_state(&self.authorization_state)
+                .build(),
+        )
+    }
+
+    // returns `Ready` state and all known users and chats
+    fn authorize(&mut self) -> RTDResult<Vec<Value>> {
+        let mut updates = vec![self.set_authorization_state(AuthorizationState::Ready(
+            AuthorizationStateReady::builder().build(),
+        ))?];
+        for user in self.users.values() {
+            updates.push(update(&UpdateUser::builder().user(user).build())?);
+        }
+        for chat in self.chats.iter() {
+            updates.push(update(&UpdateNewChat::builder().chat(chat).build())?);
+        }
+        Ok(updates)
+    }
+}
+
+fn to_value<T: RObject>(object: &T) -> RTDResult<Value> {
+    Ok(serde_json::from_str(&object.to_json()?)?)
+}
+
+// updates have no `@extra`
+fn update<T: RObject>(update: &T) -> RTDResult<Value> {
+    let mut value = to_value(update)?;
+    if let Some(object) = value.as_object_mut() {
+        object.remove("@extra");
+    }
+    Ok(value)
+}
+
+fn ok() -> RTDResult<Value> {
+    to_value(&TdOk::builder().build())
+}
+
+fn error<S: AsRef<str>>(code: i64, message: S) -> Value {
+    serde_json::json!({
+        "@type": "error",
+        "code": code,
+        "message": message.as_ref(),
+    })
+}
+
+// int64 values may be sent as strings
+fn int(request: &Value, field: &str) -> i64 {
+    let value = &request[field];
+    value
+        .as_i64()
+        .or_else(|| value.as_str().and_then(|value| value.parse().ok()))
+        .unwrap_or_default()
+}
+
+// identifiers are numbers or their typed wrappers
+fn id<T: DeserializeOwned + Default>(request: &Value, field: &str) -> T {
+    serde_json::from_value(request[field].clone()).unwrap_or_default()
+}
+
+fn now() -> i32 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i32)
+        .unwrap_or_default()
+}
+
+fn user<I: Into<UserId>>(id: I, first_name: &str, last_name: &str, phone_number: &str) -> User {
+    User::builder()
+        .id(id)
+        .first_name(first_name)
+        .last_name(last_name)
+        .phone_number(phone_number)
+        .status(UserStatus::Empty(UserStatusEmpty::builder().build()))
+        .have_access(true)
+        .type_(UserType::Regular(UserTypeRegular::builder().build()))
+        .build()
+}
+
+fn chat<I: Into<ChatId>>(id: I, title: &str, type_: ChatType) -> Chat {
+    Chat::builder()
+        .id(id)
+        .type_(type_)
+        .title(title)
+        .permissions(
+            ChatPermissions::builder()
+                .can_send_messages(true)
+                .can_send_media_messages(true)
+                .build(),
+        )
+        .notification_settings(ChatNotificationSettings::builder().build())
+        .build()
+}
+
+fn message(
+    id: i64,
+    chat_id: ChatId,
+    sender_user_id: UserId,
+    is_outgoing: bool,
+    is_pending: bool,
+    text: &str,
+) -> Message {
+    let mut builder = Message::builder();
+    builder
+        .id(id)
+        .chat_id(chat_id)
+        .sender_user_id(sender_user_id)
+        .is_outgoing(is_outgoing)
+        .can_be_deleted_only_for_self(true)
+        .date(now())
+        .content(MessageContent::MessageText(
+            MessageText::builder()
+                .text(FormattedText::builder().text(text).build())
+                .build(),
+        ));
+    if is_pending {
+        builder.sending_state(MessageSendingState::Pending(
+            MessageSendingStatePending::builder().build(),
+        ));
+    }
+    builder.build()
+}
+
+fn file_with_progress<I: Into<FileId>>(id: I, size: i32, downloaded_size: i32) -> File {
+    let id = id.into();
+    let is_completed = downloaded_size >= size;
+    let path = if is_completed {
+        format!("/fake/files/{}", id)
+    } else {
+        String::new()
+    };
+    File::builder()
+        .id(id)
+        .size(size)
+        .expected_size(size)
+        .local(
+            LocalFile::builder()
+                .path(path)
+                .can_be_downloaded(true)
+                .is_downloading_active(!is_completed && downloaded_size > 0)
+                .is_downloading_completed(is_completed)
+                .downloaded_prefix_size(downloaded_size)
+                .downloaded_size(downloaded_size)
+                .build(),
+        )
+        .remote(
+            RemoteFile::builder()
+                .id(format!("remote_{}", id))
+                .unique_id(format!("unique_{}", id))
+                .is_uploading_completed(true)
+                .uploaded_size(size)
+                .build(),
+        )
+        .build()
+}
+
+/// In-memory [TdLibClient](crate::client::api::TdLibClient), which emulates subset of TDlib behavior.
+///
+/// Supported requests: authorization flow (phone number, code, password), `getMe`, `getUser`, `getChat`, `getChats` (newly added chats first),
+/// `sendMessage` with text content (followed by `updateNewMessage` and `updateMessageSendSucceeded`),
+/// `getChatHistory`, `getFile` and `downloadFile` (followed by `updateFile` with download progress).
+/// Other requests are answered with TDlib error.
+/// ```
+/// use rust_tdlib::client::testing::FakeTelegram;
+///
+/// let telegram = FakeTelegram::new().with_auth_code("11111");
+/// let friend = telegram.add_user(2, "Friend", "");
+/// let chat = telegram.add_private_chat(friend.id()).unwrap();
+/// telegram.receive_message(chat.id(), friend.id(), "hello").unwrap();
+/// ```
+/// Pass it to [ClientBuilder::build_with_tdlib_client](crate::client::ClientBuilder::build_with_tdlib_client),
+/// and your [AuthStateHandler](crate::client::AuthStateHandler) has to provide the code.
+#[derive(Clone, Debug)]
+pub struct FakeTelegram {
+    state: Arc<Mutex<FakeState>>,
+    to_receive: Arc<ReceiveQueue>,
+}
+
+impl Default for FakeTelegram {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FakeTelegram {
+    /// Creates unauthorized fake with current user `+10000000000` and authentication code `12345`
+    pub fn new() -> Self {
+        let me = user(1, "Test", "User", DEFAULT_PHONE_NUMBER);
+        let mut users = BTreeMap::new();
+        users.insert(me.id(), me.clone());
+        let telegram = Self {
+            state: Arc::new(Mutex::new(FakeState {
+                authorization_state: AuthorizationState::WaitTdlibParameters(
+                    AuthorizationStateWaitTdlibParameters::builder().build(),
+                ),
+                skip_authentication: false,
+                auth_code: DEFAULT_AUTH_CODE.to_string(),
+                password: None,
+                me,
+                users,
+                chats: Vec::new(),
+                messages: HashMap::new(),
+                files: BTreeMap::new(),
+                last_message_id: 0,
+                sent: Vec::new(),
+            })),
+            to_receive: Arc::new(ReceiveQueue::default()),
+        };
+        let state = telegram.lock().authorization_state.clone();
+        telegram.push_update(
+            &UpdateAuthorizationState::builder()
+                .authorization_state(state)
+                .build(),
+        );
+        telegram
+    }
+
+    /// Sets code, expected by `checkAuthenticationCode`
+    pub fn with_auth_code<S: AsRef<str>>(self, code: S) -> Self {
+        self.lock().auth_code = code.as_ref().to_string();
+        self
+    }
+
+    /// Enables two-step verification with specified password
+    pub fn with_password<S: AsRef<str>>(self, password: S) -> Self {
+        self.lock().password = Some(password.as_ref().to_string());
+        self
+    }
+
+    /// Sets current user
+    pub fn with_me<I: Into<UserId>, S: AsRef<str>>(
+        self,
+        id: I,
+        first_name: S,
+        last_name: S,
+    ) -> Self {
+        let id = id.into();
+        {
+            let mut state = self.lock();
+            let phone_number = state.me.phone_number().clone();
+            let me = user(id, first_name.as_ref(), last_name.as_ref(), &phone_number);
+            let old_id = state.me.id();
+            state.users.remove(&old_id);
+            state.users.insert(id, me.clone());
+            state.me = me;
+        }
+        self
+    }
+
+    /// Authorization becomes ready right after database encryption key check, phone number and code are not requested
+    pub fn authorized(self) -> Self {
+        self.lock().skip_authentication = true;
+        self
+    }
+
+    pub fn me(&self) -> User {
+        self.lock().me.clone()
+    }
+
+    pub fn authorization_state(&self) -> AuthorizationState {
+        self.lock().authorization_state.clone()
+    }
+
+    /// Adds user, `updateUser` is sent when authorized
+    pub fn add_user<I: Into<UserId>, S: AsRef<str>>(
+        &self,
+        id: I,
+        first_name: S,
+        last_name: S,
+    ) -> User {
+        let id = id.into();
+        let user = user(id, first_name.as_ref(), last_name.as_ref(), "");
+        let mut state = self.lock();
+        state.users.insert(id, user.clone());
+        if state.is_authorized() {
+            self.push_update(&UpdateUser::builder().user(&user).build());
+        }
+        user
+    }
+
+    /// Adds private chat with known user, chat identifier is equal to user identifier
+    pub fn add_private_chat<I: Into<UserId>>(&self, user_id: I) -> RTDResult<Chat> {
+        let user_id = user_id.into();
+        let title = match self.lock().users.get(&user_id) {
+            Some(user) => format!("{} {}", user.first_name(), user.last_name())
+                .trim()
+                .to_string(),
+            None => return Err(RTDError::InvalidParameters("user not found")),
+        };
+        Ok(self.add_chat(chat(
+            user_id,
+            &title,
+            ChatType::Private(ChatTypePrivate::builder().user_id(user_id).build()),
+        )))
+    }
+
+    /// Adds basic group chat, chat identifier is negated basic group identifier like in TDlib
+    pub fn add_group_chat<S: AsRef<str>>(&self, id: i32, title: S) -> Chat {
+        self.add_chat(chat(
+            -i64::from(id),
+            title.as_ref(),
+            ChatType::BasicGroup(ChatTypeBasicGroup::builder().basic_group_id(id).build()),
+        ))
+    }
+
+    /// Adds remote file of specified size, which can be downloaded
+    pub fn add_file(&self, size: i32) -> File {
+        let mut state = self.lock();
+        // files are never removed
+        let file = file_with_progress(state.files.len() as i32 + 1, size, 0);
+        state.files.insert(file.id(), file.clone());
+        file
+    }
+
+    /// Emulates incoming message, `updateNewMessage` is sent
+    pub fn receive_message<C: Into<ChatId>, U: Into<UserId>, S: AsRef<str>>(
+        &self,
+        chat_id: C,
+        sender_user_id: U,
+        text: S,
+    ) -> RTDResult<Message> {
+        let chat_id = chat_id.into();
+        let mut state = self.lock();
+        if state.chat(chat_id).is_none() {
+            return Err(RTDError::InvalidParameters("chat not found"));
+        }
+        let message_id = state.next_message_id();
+        let message = message(
+            message_id,
+            chat_id,
+            sender_user_id.into(),
+            false,
+            false,
+            text.as_ref(),
+        );
+        state
+            .messages
+            .entry(chat_id)
+            .or_default()
+            .push(message.clone());
+        self.push_update(&UpdateNewMessage::builder().message(&message).build());
+        Ok(message)
+    }
+
+    /// Returns messages of the chat, oldest first
+    pub fn chat_history<C: Into<ChatId>>(&self, chat_id: C) -> Vec<Message> {
+        self.lock()
+            .messages
+            .get(&chat_id.into())
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Returns all requests, sent or executed so far
+    pub fn sent_requests(&self) -> Vec<Value> {
+        self.lock().sent.clone()
+    }
+
+    /// Pushes arbitrary update
+    pub fn push_update<T: RObject>(&self, object: &T) {
+        match update(object) {
+            Ok(value) => self.to_receive.push(value.to_string()),
+            Err(e) => error!("can't serialize fake update: {}", e),
+        }
+    }
+
+    fn add_chat(&self, chat: Chat) -> Chat {
+        let mut state = self.lock();
+        state.chats.retain(|known| known.id() != chat.id());
+        // new chats are on top of the chat list
+        let order = state
+            .chats
+            .iter()
+            .map(|chat| chat.order())
+            .max()
+            .unwrap_or(0)
+            + 1;
+        let chat = chat.with_order(order);
+        state.chats.push(chat.clone());
+        if state.is_authorized() {
+            self.push_update(&UpdateNewChat::builder().chat(&chat).build());
+        }
+        chat
+    }
+
+    fn lock(&self) -> MutexGuard<'_, FakeState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn handle_request(&self, request: &str) -> RTDResult<(Value, Vec<Value>)> {
+        let request: Value = serde_json::from_str(request)?;
+        let mut state = self.lock();
+        state.sent.push(request.clone());
+        let (mut response, updates) = match state.handle(&request) {
+            Err(RTDError::InvalidParameters(message)) => (error(400, message), Vec::new()),
+            result => result?,
+        };
+        if let (Some(response), Some(extra)) = (response.as_object_mut(), request.get("@extra")) {
+            response.insert("@extra".to_string(), extra.clone());
+        }
+        Ok((response, updates))
+    }
+}
+
+impl TdLibClient for FakeTelegram {
+    fn send(&self, request: &str) -> RTDResult<()> {
+        let (response, updates) = self.handle_request(request)?;
+        self.to_receive.push(response.to_string());
+        for update in updates {
+            self.to_receive.push(update.to_string());
+        }
+        Ok(())
+    }
+
+    fn receive(&self, timeout: f64) -> Option<String> {
+        self.to_receive.receive(timeout)
+    }
+
+    fn execute(&self, request: &str) -> RTDResult<Option<String>> {
+        let (response, updates) = self.handle_request(request)?;
+        for update in updates {
+            self.to_receive.push(update.to_string());
+        }
+        Ok(Some(response.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::testing::FakeTelegram;
+    use crate::client::{
+        AuthStateHandler, Client, ClientBuilder, WaitCodeAction, WaitPasswordAction,
+    };
+    use crate::types::*;
+    use async_trait::async_trait;
+    use std::time::Duration;
+    use tokio::time::timeout;
+
+    // answers as user with phone number and code, expected by fake
+    #[derive(Clone, Debug)]
+    struct FakeUser;
+
+    #[async_trait]
+    impl AuthStateHandler for FakeUser {
+        async fn handle_other_device_confirmation(
+            &self,
+            _: &AuthorizationStateWaitOtherDeviceConfirmation,
+        ) {
+            unimplemented!()
+        }
+
+        async fn handle_wait_code(&self, _: &AuthorizationStateWaitCode) -> WaitCodeAction {
+            WaitCodeAction::Code("12345".to_string())
+        }
+
+        async fn handle_encryption_key(&self, _: &AuthorizationStateWaitEncryptionKey) -> String {
+            String::new()
+        }
+
+        async fn handle_wait_password(
+            &self,
+            _: &AuthorizationStateWaitPassword,
+        ) -> WaitPasswordAction {
+            WaitPasswordAction::Password("secret".to_string())
+        }
+
+        async fn handle_wait_phone_number(&self, _: &AuthorizationStateWaitPhoneNumber) -> String {
+            "+10000000000".to_string()
+        }
+
+        async fn handle_wait_registration(
+            &self,
+            _: &AuthorizationStateWaitRegistration,
+        ) -> (String, String) {
+            unimplemented!()
+        }
+
+        async fn handle_terms_of_service(&self, _: &TermsOfService) -> bool {
+            true
+        }
+    }
+
+    async fn start_client(
+        telegram: FakeTelegram,
+    ) -> (
+        Client<FakeUser, FakeTelegram>,
+        tokio::sync::mpsc::Receiver<TdType>,
+    ) {
+        let (sx, rx) = tokio::sync::mpsc::channel::<TdType>(100);
+        let mut client = ClientBuilder::default()
+            .with_tdlib_parameters(TdlibParameters::builder().build())
+            .with_auth_state_handler(FakeUser)
+            .with_updates_sender(sx)
+            .with_read_updates_timeout(0.1)
+            .build_with_tdlib_client(telegram)
+            .unwrap();
+        timeout(Duration::from_secs(5), client.start())
+            .await
+            .expect("client not authorized within 5 s")
+            .unwrap();
+        (client, rx)
+    }
+
+    #[tokio::test]
+    async fn test_authorization_and_messages() {
+        let telegram = FakeTelegram::new().with_password("secret");
+        let friend = telegram.add_user(2, "Friend", "");
+        let chat = telegram.add_private_chat(friend.id()).unwrap();
+        let (client, _rx) = start_client(telegram.clone()).await;
+        assert!(matches!(
+            telegram.authorization_state(),
+            AuthorizationState::Ready(_)
+        ));
+
+        let api = client.api();
+        let me = api.get_me(GetMe::builder().build()).await.unwrap();
+        assert_eq!(me.id(), 1);
+        let user = api
+            .get_user(GetUser::builder().user_id(friend.id()).build())
+            .await
+            .unwrap();
+        assert_eq!(user.first_name(), "Friend");
+        let chats = api
+            .get_chats(GetChats::builder().offset_order(i64::MAX).limit(10).build())
+            .await
+            .unwrap();
+        assert_eq!(chats.chat_ids(), &vec![chat.id()]);
+
+        telegram
+            .receive_message(chat.id(), friend.id(), "hello")
+            .unwrap();
+        let sent = api
+            .send_message(
+                SendMessage::builder()
+                    .chat_id(chat.id())
+                    .input_message_content(InputMessageContent::InputMessageText(
+                        InputMessageText::builder()
+                            .text(FormattedText::builder().text("hi").build())
+                            .build(),
+                    ))
+                    .build(),
+            )
+            .await
+            .unwrap();
+        assert!(sent.sending_state().is_some());
+
+        let history = api
+            .get_chat_history(
+                GetChatHistory::builder()
+                    .chat_id(chat.id())
+                    .limit(10)
+                    .build(),
+            )
+            .await
+            .unwrap();
+        let texts: Vec<String> = history
+            .messages()
+            .iter()
+            .flatten()
+            .map(|message| match message.content() {
+                MessageContent::MessageText(text) => text.text().text().clone(),
+                _ => panic!("unexpected content"),
+            })
+            .collect();
+        assert_eq!(texts, vec!["hi".to_string(), "hello".to_string()]);
+        client.stop();
+    }
+
+    #[tokio::test]
+    async fn test_updates_and_download() {
+        let telegram = FakeTelegram::new().authorized();
+        let file = telegram.add_file(1000);
+        let (client, mut rx) = start_client(telegram.clone()).await;
+
+        let downloaded = client
+            .api()
+            .download_file(
+                DownloadFile::builder()
+                    .file_id(file.id())
+                    .priority(1)
+                    .synchronous(true)
+                    .build(),
+            )
+            .await
+            .unwrap();
+        assert!(downloaded.local().is_downloading_completed());
+        assert_eq!(
+            telegram.sent_requests().last().unwrap()["@type"],
+            "downloadFile"
+        );
+
+        let mut progress = Vec::new();
+        while progress.last() != Some(&1000) {
+            match timeout(Duration::from_secs(5), rx.recv()).await {
+                Ok(Some(TdType::UpdateFile(update))) => {
+                    progress.push(update.file().local().downloaded_size())
+                }
+                Ok(Some(_)) => {}
+                _ => panic!("file download not completed, progress: {:?}", progress),
+            }
+        }
+        assert_eq!(progress, vec![250, 500, 750, 1000]);
+        client.stop();
+    }
+
+    #[tokio::test]
+    async fn test_chats_pagination() {
+        let telegram = FakeTelegram::new().authorized();
+        let added: Vec<ChatId> = (1..=5)
+            .map(|id| telegram.add_group_chat(id, "group").id())
+            .collect();
+        let (client, _rx) = start_client(telegram).await;
+
+        let mut listed = Vec::new();
+        let (mut offset_order, mut offset_chat_id) = (i64::MAX, ChatId::default());
+        for _ in 0..added.len() + 1 {
+            let chats = client
+                .api()
+                .get_chats(
+                    GetChats::builder()
+                        .offset_order(offset_order)
+                        .offset_chat_id(offset_chat_id)
+                        .limit(2)
+                        .build(),
+                )
+                .await
+                .unwrap();
+            let last_chat_id = match chats.chat_ids().last() {
+                Some(chat_id) => *chat_id,
+                None => break,
+            };
+            listed.extend_from_slice(chats.chat_ids());
+            let last_chat = client
+                .api()
+                .get_chat(GetChat::builder().chat_id(last_chat_id).build())
+                .await
+                .unwrap();
+            offset_order = last_chat.order();
+            offset_chat_id = last_chat_id;
+        }
+        // newest chats first
+        assert_eq!(listed, added.into_iter().rev().collect::<Vec<_>>());
+        client.stop();
+    }
+}
@@ -0,0 +1,352 @@
+//! Scriptable [TdLibClient](crate::client::api::TdLibClient) for tests.
+//!
+//! [MockApi](crate::client::testing::MockApi) answers requests according to registered [MockRule](crate::client::testing::MockRule)s,
+//! allows to push arbitrary updates and stores all sent requests, so they can be checked later.
+//! ```
+//! use rust_tdlib::client::testing::{MockApi, MockRule};
+//! use serde_json::json;
+//!
+//! let mock = MockApi::new();
+//! mock.add_rule(
+//!     MockRule::new("searchPublicChats")
+//!         .matching(|request| request["query"] == "rust")
+//!         .respond(json!({"@type": "chats", "chat_ids": [1, 2, 3]})),
+//! );
+//! mock.add_rule(MockRule::new("getMe").respond_error(401, "Unauthorized"));
+//! mock.push_json(r#"{"@type":"updateConnectionState","state":{"@type":"connectionStateReady"}}"#);
+//! ```
+use crate::{
+    client::{api::TdLibClient, testing::ReceiveQueue},
+    errors::{RTDError, RTDResult},
+    types::RObject,
+};
+use serde_json::Value;
+use std::fmt;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+type Matcher = Arc<dyn Fn(&Value) -> bool + Send + Sync>;
+type Responder = Arc<dyn Fn(&Value) -> Value + Send + Sync>;
+
+/// Describes how [MockApi](crate::client::testing::MockApi) answers particular request.
+#[derive(Clone)]
+pub struct MockRule {
+    function: String,
+    matcher: Option<Matcher>,
+    responder: Option<Responder>,
+    updates: Vec<Value>,
+    delay: Duration,
+    times: Option<usize>,
+    fail_send: bool,
+}
+
+impl fmt::Debug for MockRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MockRule")
+            .field("function", &self.function)
+            .field("updates", &self.updates)
+            .field("delay", &self.delay)
+            .field("times", &self.times)
+            .field("fail_send", &self.fail_send)
+            .finish()
+    }
+}
+
+impl MockRule {
+    /// Creates rule for requests with specified `@type`, e.g. `getMe`
+    pub fn new<S: AsRef<str>>(function: S) -> Self {
+        Self {
+            function: function.as_ref().to_string(),
+            matcher: None,
+            responder: None,
+            updates: Vec::new(),
+            delay: Duration::default(),
+            times: None,
+            fail_send: false,
+        }
+    }
+
+    /// Rule applied only to requests, for which `matcher` returns `true`
+    pub fn matching<F: Fn(&Value) -> bool + Send + Sync + 'static>(mut self, matcher: F) -> Self {
+        self.matcher = Some(Arc::new(matcher));
+        self
+    }
+
+    /// Responds with specified json. `@extra` of the request is added automatically
+    pub fn respond(self, response: Value) -> Self {
+        self.respond_with(move |_| response.clone())
+    }
+
+    /// Responds with json, built from the request. `@extra` of the request is added automatically
+    pub fn respond_with<F: Fn(&Value) -> Value + Send + Sync + 'static>(
+        mut self,
+        responder: F,
+    ) -> Self {
+        self.responder = Some(Arc::new(responder));
+        self
+    }
+
+    /// Responds with TDlib [Error](crate::types::Error)
+    pub fn respond_error<S: AsRef<str>>(self, code: i64, message: S) -> Self {
+        let message = message.as_ref().to_string();
+        self.respond(serde_json::json!({
+            "@type": "error",
+            "code": code,
+            "message": message,
+        }))
+    }
+
+    /// Pushes specified update after the response
+    pub fn then_update(mut self, update: Value) -> Self {
+        self.updates.push(update);
+        self
+    }
+
+    /// Response and updates become available for receiving after `delay`
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Rule applied at most `times` times
+    pub fn times(mut self, times: usize) -> Self {
+        self.times = Some(times);
+        self
+    }
+
+    /// `send` returns an error instead of responding
+    pub fn fail_send(mut self) -> Self {
+        self.fail_send = true;
+        self
+    }
+
+    fn matches(&self, function: &str, request: &Value) -> bool {
+        self.function == function
+            && self.times != Some(0)
+            && self.matcher.as_ref().map(|m| m(request)).unwrap_or(true)
+    }
+
+    fn response(&self, request: &Value) -> Option<Value> {
+        let mut response = self.responder.as_ref()?(request);
+        if let (Some(response), Some(extra)) = (response.as_object_mut(), request.get("@extra")) {
+            response.insert("@extra".to_string(), extra.clone());
+        }
+        Some(response)
+    }
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+    rules: Vec<MockRule>,
+    sent: Vec<Value>,
+}
+
+impl MockState {
+    // returns response and updates for the request
+    fn apply_rules(&mut self, request: &Value) -> RTDResult<(Duration, Vec<Value>)> {
+        let function = request["@type"].as_str().unwrap_or_default().to_string();
+        let rule = match self
+            .rules
+            .iter_mut()
+            .find(|rule| rule.matches(&function, request))
+        {
+            Some(rule) => rule,
+            None => {
+                warn!("no mock rule for request: {}", request);
+                let mut error = serde_json::json!({
+                    "@type": "error",
+                    "code": 404,
+                    "message": format!("no mock rule for {}", function),
+                });
+                if let Some(extra) = request.get("@extra") {
+                    error["@extra"] = extra.clone();
+                }
+                return Ok((Duration::default(), vec![error]));
+            }
+        };
+        if let Some(times) = rule.times.as_mut() {
+            *times -= 1;
+        }
+        if rule.fail_send {
+            return Err(RTDError::Internal("mocked send failure"));
+        }
+        let mut jsons: Vec<Value> = rule.response(request).into_iter().collect();
+        jsons.extend(rule.updates.iter().cloned());
+        Ok((rule.delay, jsons))
+    }
+}
+
+/// Scriptable [TdLibClient](crate::client::api::TdLibClient) implementation.
+///
+/// Requests without matching [MockRule](crate::client::testing::MockRule) are answered with TDlib error with code 404.
+/// Rules are checked in order of addition.
+#[derive(Clone, Debug, Default)]
+pub struct MockApi {
+    state: Arc<Mutex<MockState>>,
+    to_receive: Arc<ReceiveQueue>,
+}
+
+impl MockApi {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_rule(&self, rule: MockRule) -> &Self {
+        self.lock().rules.push(rule);
+        self
+    }
+
+    /// Pushes json, which will be received by the client
+    pub fn push_json<S: Into<String>>(&self, json: S) -> &Self {
+        self.push_json_delayed(json, Duration::default())
+    }
+
+    /// Pushes json, which will be received by the client after `delay`
+    pub fn push_json_delayed<S: Into<String>>(&self, json: S, delay: Duration) -> &Self {
+        self.to_receive.push_at(Instant::now() + delay, json.into());
+        self
+    }
+
+    /// Pushes update, which will be received by the client
+    pub fn push_update<T: RObject>(&self, update: &T) -> RTDResult<&Self> {
+        Ok(self.push_json(update.to_json()?))
+    }
+
+    /// Returns all requests, sent or executed so far
+    pub fn sent_requests(&self) -> Vec<Value> {
+        self.lock().sent.clone()
+    }
+
+    /// Returns sent requests with specified `@type`
+    pub fn sent_requests_of<S: AsRef<str>>(&self, function: S) -> Vec<Value> {
+        self.lock()
+            .sent
+            .iter()
+            .filter(|request| request["@type"] == function.as_ref())
+            .cloned()
+            .collect()
+    }
+
+    /// Panics if request with specified `@type` was not sent
+    pub fn assert_sent<S: AsRef<str>>(&self, function: S) {
+        if self.sent_requests_of(function.as_ref()).is_empty() {
+            panic!(
+                "request {} was not sent, sent requests: {:?}",
+                function.as_ref(),
+                self.sent_requests()
+            );
+        }
+    }
+
+    pub fn clear_sent_requests(&self) {
+        self.lock().sent.clear();
+    }
+
+    fn lock(&self) -> MutexGuard<'_, MockState> {
+        // test failure in one thread should not hide state from others
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn handle_request(&self, request: &str) -> RTDResult<(Duration, Vec<Value>)> {
+        let request: Value = serde_json::from_str(request)?;
+        let mut state = self.lock();
+        state.sent.push(request.clone());
+        state.apply_rules(&request)
+    }
+}
+
+impl TdLibClient for MockApi {
+    fn send(&self, request: &str) -> RTDResult<()> {
+        let (delay, jsons) = self.handle_request(request)?;
+        let at = Instant::now() + delay;
+        for json in jsons {
+            self.to_receive.push_at(at, json.to_string());
+        }
+        Ok(())
+    }
+
+    fn receive(&self, timeout: f64) -> Option<String> {
+        self.to_receive.receive(timeout)
+    }
+
+    /// Returns response immediately, updates are pushed to receive queue
+    fn execute(&self, request: &str) -> RTDResult<Option<String>> {
+        let (delay, mut jsons) = self.handle_request(request)?;
+        if jsons.is_empty() {
+            return Ok(None);
+        }
+        let response = jsons.remove(0);
+        let at = Instant::now() + delay;
+        for json in jsons {
+            self.to_receive.push_at(at, json.to_string());
+        }
+        Ok(Some(response.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::api::TdLibClient;
+    use crate::client::testing::mock::{MockApi, MockRule};
+    use crate::types::{GetMe, RObject, SearchPublicChats};
+    use serde_json::{json, Value};
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn test_mock_rules() {
+        let mock = MockApi::new();
+        mock.add_rule(
+            MockRule::new("searchPublicChats")
+                .matching(|request| request["query"] == "rust")
+                .respond(json!({"@type": "chats", "chat_ids": [1]}))
+                .then_update(json!({"@type": "updateOption", "name": "test"})),
+        )
+        .add_rule(
+            MockRule::new("searchPublicChats")
+                .times(1)
+                .respond_error(400, "bad query"),
+        )
+        .add_rule(MockRule::new("getMe").fail_send());
+
+        let request = json!({"@type": "searchPublicChats", "@extra": "1:1", "query": "rust"});
+        mock.send(&request.to_string()).unwrap();
+        let response: Value = serde_json::from_str(&mock.receive(0.).unwrap()).unwrap();
+        assert_eq!(response["@type"], "chats");
+        assert_eq!(response["@extra"], "1:1");
+        assert!(mock.receive(0.).unwrap().contains("updateOption"));
+
+        let request = SearchPublicChats::builder().query("go").build();
+        mock.send(&request.to_json().unwrap()).unwrap();
+        assert!(mock.receive(0.).unwrap().contains("bad query"));
+        mock.send(&request.to_json().unwrap()).unwrap();
+        assert!(mock.receive(0.).unwrap().contains("no mock rule"));
+
+        let get_me = GetMe::builder().build().to_json().unwrap();
+        assert!(mock.send(&get_me).is_err());
+        assert_eq!(mock.sent_requests().len(), 4);
+        assert_eq!(mock.sent_requests_of("searchPublicChats").len(), 3);
+        mock.assert_sent("getMe");
+        assert!(mock.receive(0.).is_none());
+    }
+
+    #[test]
+    fn test_mock_delays() {
+        let mock = MockApi::new();
+        mock.add_rule(
+            MockRule::new("getMe")
+                .delay(Duration::from_millis(100))
+                .respond(json!({"@type": "user", "id": 1})),
+        );
+        mock.push_json_delayed(r#"{"@type":"updateOption"}"#, Duration::from_millis(300));
+        let started = Instant::now();
+        mock.send(&GetMe::builder().build().to_json().unwrap())
+            .unwrap();
+        assert!(mock.receive(0.05).is_none());
+        assert!(mock.receive(1.).unwrap().contains("user"));
+        assert!(started.elapsed() >= Duration::from_millis(100));
+        assert!(mock.receive(1.).unwrap().contains("updateOption"));
+        assert!(started.elapsed() >= Duration::from_millis(300));
+    }
+}
@@ -0,0 +1,65 @@
+//! [TdLibClient](crate::client::api::TdLibClient) implementations for tests, available with `testing` feature.
+//!
+//! [MockApi](crate::client::testing::MockApi) answers requests according to scripted rules,
+//! [FakeTelegram](crate::client::testing::FakeTelegram) emulates subset of TDlib behavior in memory.
+use std::collections::VecDeque;
+use std::sync::{Condvar, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+mod fake;
+mod mock;
+
+pub use fake::FakeTelegram;
+pub use mock::{MockApi, MockRule};
+
+// jsons waiting to be received, sorted by time when json becomes available
+#[derive(Debug, Default)]
+struct ReceiveQueue {
+    jsons: Mutex<VecDeque<(Instant, String)>>,
+    pushed: Condvar,
+}
+
+impl ReceiveQueue {
+    fn lock(&self) -> MutexGuard<'_, VecDeque<(Instant, String)>> {
+        // test failure in one thread should not hide queue from others
+        self.jsons
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn push(&self, json: String) {
+        self.push_at(Instant::now(), json)
+    }
+
+    fn push_at(&self, at: Instant, json: String) {
+        let mut jsons = self.lock();
+        let position = jsons
+            .iter()
+            .position(|(ready_at, _)| *ready_at > at)
+            .unwrap_or(jsons.len());
+        jsons.insert(position, (at, json));
+        self.pushed.notify_all();
+    }
+
+    fn receive(&self, timeout: f64) -> Option<String> {
+        let deadline = Instant::now() + Duration::from_secs_f64(timeout.max(0.0));
+        let mut jsons = self.lock();
+        loop {
+            let now = Instant::now();
+            let ready_at = jsons.front().map(|(ready_at, _)| *ready_at);
+            match ready_at {
+                Some(ready_at) if ready_at <= now => {
+                    return jsons.pop_front().map(|(_, json)| json)
+                }
+                _ if now >= deadline => return None,
+                _ => {}
+            }
+            let wait_until = ready_at.map_or(deadline, |ready_at| ready_at.min(deadline));
+            jsons = self
+                .pushed
+                .wait_timeout(jsons, wait_until - now)
+                .map(|(jsons, _)| jsons)
+                .unwrap_or_else(|poisoned| poisoned.into_inner().0);
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use std::{error, fmt, io};
+use std::{env, error, fmt, io};
 
 #[derive(Debug)]
 pub enum RTDError {
@@ -7,6 +7,11 @@ pub enum RTDError {
     TdlibError(String),
     Internal(&'static str),
     InvalidParameters(&'static str),
+    LibraryLoading(String),
+    /// Object name and its required fields, which are not set in builder
+    MissingFields(&'static str, Vec<&'static str>),
+    /// Name of environment variable, which can't be read
+    EnvVar(String, env::VarError),
 }
 
 pub type RTDResult<T, E = RTDError> = Result<T, E>;
@@ -29,6 +34,20 @@ impl fmt::Display for RTDError {
             RTDError::InvalidParameters(err) => {
                 write!(f, "{}", err)
             }
+            RTDError::LibraryLoading(err) => {
+                write!(f, "{}", err)
+            }
+            RTDError::MissingFields(name, fields) => {
+                write!(
+                    f,
+                    "{} required fields are not set: {}",
+                    name,
+                    fields.join(", ")
+                )
+            }
+            RTDError::EnvVar(name, err) => {
+                write!(f, "{}: {}", name, err)
+            }
         }
     }
 }
@@ -41,6 +60,9 @@ impl error::Error for RTDError {
             RTDError::Internal(_) => None,
             RTDError::TdlibError(_) => None,
             RTDError::InvalidParameters(_) => None,
+            RTDError::LibraryLoading(_) => None,
+            RTDError::MissingFields(_, _) => None,
+            RTDError::EnvVar(_, ref err) => Some(err),
         }
     }
 }
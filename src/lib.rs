@@ -7,46 +7,63 @@
 //! 3. use them as parameters of an [ClientBuilder](crate::client::ClientBuilder).
 //! 4. [start](crate::client::Client::start) the client.
 //! 5. write your own code to interact with Telegram.
-//! ```
+//! ```no_run
+//! # #[cfg(feature = "client")]
 //! use rust_tdlib::{types::{TdlibParameters, GetMe}, client::Client};
+//! # #[cfg(feature = "client")]
 //! #[tokio::main]
 //! async fn main() {
-//!     let tdlib_params = TdlibParameters::builder().api_id(env!("API_ID").parse::<i64>().unwrap()).api_hash(env!("API_HASH")).build();
-//!     let client = rust_tdlib::client::Client::builder().with_tdlib_parameters(tdlib_params).build();
+//!     let api_id = std::env::var("API_ID").unwrap().parse::<i32>().unwrap();
+//!     let api_hash = std::env::var("API_HASH").unwrap();
+//!     let tdlib_params = TdlibParameters::builder().api_id(api_id).api_hash(api_hash).build();
+//!     let mut client = rust_tdlib::client::Client::builder().with_tdlib_parameters(tdlib_params).build().unwrap();
 //!     client.start().await.unwrap();
 //!     let me = client.api().get_me(GetMe::builder().build()).await.unwrap();
 //!     eprintln!("{:?}", me);
 //! }
+//! # #[cfg(not(feature = "client"))]
+//! # fn main() {}
 //! ```
 //!
 //! You can read all updates, received from Telegram server, such as: new messages, chats updates, new chats, user updates and so on. All updates varians declared within [Update](crate::types::Update).
-//! ```
+//! ```no_run
+//! # #[cfg(feature = "client")]
 //! use rust_tdlib::{types::{TdlibParameters, GetMe, TdType}, client::Client};
+//! # #[cfg(feature = "client")]
 //! #[tokio::main]
 //! async fn main() {
-//!     let tdlib_params = TdlibParameters::builder().api_id(env!("API_ID").parse::<i64>().unwrap()).api_hash(env!("API_HASH")).build();
+//!     let api_id = std::env::var("API_ID").unwrap().parse::<i32>().unwrap();
+//!     let api_hash = std::env::var("API_HASH").unwrap();
+//!     let tdlib_params = TdlibParameters::builder().api_id(api_id).api_hash(api_hash).build();
 //!     let (sender, mut receiver) = tokio::sync::mpsc::channel::<TdType>(10);
-//!     let client = rust_tdlib::client::Client::builder().with_updates_sender(sender).with_tdlib_parameters(tdlib_params).build();
+//!     let mut client = rust_tdlib::client::Client::builder().with_updates_sender(sender).with_tdlib_parameters(tdlib_params).build().unwrap();
 //!     client.start().await.unwrap();
-//!     if let Some(message) = receiver.recv().await.unwrap() {
+//!     if let Some(message) = receiver.recv().await {
 //!         eprintln!("updates handler received {:?}", message);
 //!     }
 //! }
+//! # #[cfg(not(feature = "client"))]
+//! # fn main() {}
 //! ```
-#![allow(clippy::large_enum_variant, clippy::borrowed_box)]
+#![allow(
+    clippy::large_enum_variant,
+    clippy::borrowed_box,
+    clippy::field_reassign_with_default,
+    clippy::needless_lifetimes
+)]
 
 #[macro_use]
 extern crate serde_derive;
 
-#[cfg(feature = "client")]
+#[cfg(feature = "client-core")]
 #[macro_use]
 extern crate log;
 
-#[cfg(feature = "client")]
+#[cfg(feature = "client-core")]
 #[macro_use]
 extern crate lazy_static;
 
-#[cfg(feature = "client")]
+#[cfg(feature = "client-core")]
 pub mod client;
 
 pub mod errors;
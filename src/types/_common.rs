@@ -3,9 +3,10 @@ use std::{
     str::FromStr,
 };
 
-use serde::de::{Deserialize, Deserializer};
+use serde::de::{Deserialize, DeserializeOwned, Deserializer};
+use serde::Serialize;
 
-use crate::{errors::*, types::*};
+use crate::errors::*;
 use serde::de;
 
 macro_rules! rtd_enum_deserialize {
@@ -20,18 +21,19 @@ macro_rules! rtd_enum_deserialize {
         None => return Err(D::Error::unknown_field(stringify!($type_name), &[stringify!("{} is not the correct type", $type_name)])) // &format!("{} is not the correct type", stringify!($field))[..]
       };
       // get `@type` value, detect specific types
+      // `@type` copied, so `rtd_trait_value` can be moved into concrete type without cloning
       let rtd_trait_type = match rtd_trait_map.get("@type") {
         // the `t` variable type is `serde_json::Value`, convert `t` to str
         Some(t) => match t.as_str() {
-          Some(s) => s,
+          Some(s) => s.to_string(),
           None => return Err(D::Error::unknown_field(stringify!("{} -> @type", $field), &[stringify!("{} -> @type is not the correct type", $type_name)])) // &format!("{} -> @type is not the correct type", stringify!($field))[..]
         },
         None => return Err(D::Error::missing_field(stringify!("{} -> @type", $field)))
       };
 
-      let obj = match rtd_trait_type {
+      let obj = match rtd_trait_type.as_str() {
         $(
-          stringify!($td_name) => $type_name::$enum_item(match serde_json::from_value(rtd_trait_value.clone()) {
+          stringify!($td_name) => $type_name::$enum_item(match serde_json::from_value(rtd_trait_value) {
             Ok(t) => t,
             Err(_e) => return Err(D::Error::unknown_field(stringify!("{} can't deserialize to {}::{}", $td_name, $type_name, $enum_item, _e), &[stringify!("{:?}", _e)]))
           }),
@@ -51,6 +53,27 @@ where
     Ok(serde_json::from_str(json)?)
 }
 
+/// `@type` and `@extra` fields of received TDlib object.
+/// Allows to route received object without deserializing all its fields.
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct TdHeader {
+    #[serde(rename(deserialize = "@type"))]
+    td_type: String,
+    #[serde(rename(deserialize = "@extra"), default)]
+    extra: Option<String>,
+}
+
+#[allow(dead_code)]
+impl TdHeader {
+    pub fn td_type(&self) -> &str {
+        &self.td_type
+    }
+
+    pub fn extra(&self) -> Option<&str> {
+        self.extra.as_deref()
+    }
+}
+
 /// All tdlib type abstract class defined the same behavior
 pub trait RObject: Debug {
     #[doc(hidden)]
@@ -61,7 +84,11 @@ pub trait RObject: Debug {
     fn to_json(&self) -> RTDResult<String>;
 }
 
-pub trait RFunction: Debug + RObject {}
+/// TDlib function, which can be sent with [Api::send](crate::client::api::Api::send)
+pub trait RFunction: Debug + RObject + Serialize {
+    /// Type of successful function result
+    type Response: RObject + DeserializeOwned;
+}
 
 impl<'a, RObj: RObject> RObject for &'a RObj {
     fn td_name(&self) -> &'static str {
@@ -87,746 +114,116 @@ impl<'a, RObj: RObject> RObject for &'a mut RObj {
     }
 }
 
-impl<'a, Fnc: RFunction> RFunction for &'a Fnc {}
-impl<'a, Fnc: RFunction> RFunction for &'a mut Fnc {}
-
-impl<'a, AUTHENTICATIONCODETYPE: TDAuthenticationCodeType> TDAuthenticationCodeType
-    for &'a AUTHENTICATIONCODETYPE
-{
-}
-impl<'a, AUTHENTICATIONCODETYPE: TDAuthenticationCodeType> TDAuthenticationCodeType
-    for &'a mut AUTHENTICATIONCODETYPE
-{
-}
-
-impl<'a, AUTHORIZATIONSTATE: TDAuthorizationState> TDAuthorizationState for &'a AUTHORIZATIONSTATE {}
-impl<'a, AUTHORIZATIONSTATE: TDAuthorizationState> TDAuthorizationState
-    for &'a mut AUTHORIZATIONSTATE
-{
-}
-
-impl<'a, BACKGROUNDFILL: TDBackgroundFill> TDBackgroundFill for &'a BACKGROUNDFILL {}
-impl<'a, BACKGROUNDFILL: TDBackgroundFill> TDBackgroundFill for &'a mut BACKGROUNDFILL {}
-
-impl<'a, BACKGROUNDTYPE: TDBackgroundType> TDBackgroundType for &'a BACKGROUNDTYPE {}
-impl<'a, BACKGROUNDTYPE: TDBackgroundType> TDBackgroundType for &'a mut BACKGROUNDTYPE {}
-
-impl<'a, CALLDISCARDREASON: TDCallDiscardReason> TDCallDiscardReason for &'a CALLDISCARDREASON {}
-impl<'a, CALLDISCARDREASON: TDCallDiscardReason> TDCallDiscardReason for &'a mut CALLDISCARDREASON {}
-
-impl<'a, CALLPROBLEM: TDCallProblem> TDCallProblem for &'a CALLPROBLEM {}
-impl<'a, CALLPROBLEM: TDCallProblem> TDCallProblem for &'a mut CALLPROBLEM {}
-
-impl<'a, CALLSTATE: TDCallState> TDCallState for &'a CALLSTATE {}
-impl<'a, CALLSTATE: TDCallState> TDCallState for &'a mut CALLSTATE {}
-
-impl<'a, CALLBACKQUERYPAYLOAD: TDCallbackQueryPayload> TDCallbackQueryPayload
-    for &'a CALLBACKQUERYPAYLOAD
-{
-}
-impl<'a, CALLBACKQUERYPAYLOAD: TDCallbackQueryPayload> TDCallbackQueryPayload
-    for &'a mut CALLBACKQUERYPAYLOAD
-{
-}
-
-impl<'a, CANTRANSFEROWNERSHIPRESULT: TDCanTransferOwnershipResult> TDCanTransferOwnershipResult
-    for &'a CANTRANSFEROWNERSHIPRESULT
-{
-}
-impl<'a, CANTRANSFEROWNERSHIPRESULT: TDCanTransferOwnershipResult> TDCanTransferOwnershipResult
-    for &'a mut CANTRANSFEROWNERSHIPRESULT
-{
-}
-
-impl<'a, CHATACTION: TDChatAction> TDChatAction for &'a CHATACTION {}
-impl<'a, CHATACTION: TDChatAction> TDChatAction for &'a mut CHATACTION {}
-
-impl<'a, CHATACTIONBAR: TDChatActionBar> TDChatActionBar for &'a CHATACTIONBAR {}
-impl<'a, CHATACTIONBAR: TDChatActionBar> TDChatActionBar for &'a mut CHATACTIONBAR {}
-
-impl<'a, CHATEVENTACTION: TDChatEventAction> TDChatEventAction for &'a CHATEVENTACTION {}
-impl<'a, CHATEVENTACTION: TDChatEventAction> TDChatEventAction for &'a mut CHATEVENTACTION {}
-
-impl<'a, CHATLIST: TDChatList> TDChatList for &'a CHATLIST {}
-impl<'a, CHATLIST: TDChatList> TDChatList for &'a mut CHATLIST {}
-
-impl<'a, CHATMEMBERSTATUS: TDChatMemberStatus> TDChatMemberStatus for &'a CHATMEMBERSTATUS {}
-impl<'a, CHATMEMBERSTATUS: TDChatMemberStatus> TDChatMemberStatus for &'a mut CHATMEMBERSTATUS {}
-
-impl<'a, CHATMEMBERSFILTER: TDChatMembersFilter> TDChatMembersFilter for &'a CHATMEMBERSFILTER {}
-impl<'a, CHATMEMBERSFILTER: TDChatMembersFilter> TDChatMembersFilter for &'a mut CHATMEMBERSFILTER {}
-
-impl<'a, CHATREPORTREASON: TDChatReportReason> TDChatReportReason for &'a CHATREPORTREASON {}
-impl<'a, CHATREPORTREASON: TDChatReportReason> TDChatReportReason for &'a mut CHATREPORTREASON {}
-
-impl<'a, CHATTYPE: TDChatType> TDChatType for &'a CHATTYPE {}
-impl<'a, CHATTYPE: TDChatType> TDChatType for &'a mut CHATTYPE {}
-
-impl<'a, CHECKCHATUSERNAMERESULT: TDCheckChatUsernameResult> TDCheckChatUsernameResult
-    for &'a CHECKCHATUSERNAMERESULT
-{
-}
-impl<'a, CHECKCHATUSERNAMERESULT: TDCheckChatUsernameResult> TDCheckChatUsernameResult
-    for &'a mut CHECKCHATUSERNAMERESULT
-{
-}
-
-impl<'a, CONNECTIONSTATE: TDConnectionState> TDConnectionState for &'a CONNECTIONSTATE {}
-impl<'a, CONNECTIONSTATE: TDConnectionState> TDConnectionState for &'a mut CONNECTIONSTATE {}
-
-impl<'a, DEVICETOKEN: TDDeviceToken> TDDeviceToken for &'a DEVICETOKEN {}
-impl<'a, DEVICETOKEN: TDDeviceToken> TDDeviceToken for &'a mut DEVICETOKEN {}
-
-impl<'a, FILETYPE: TDFileType> TDFileType for &'a FILETYPE {}
-impl<'a, FILETYPE: TDFileType> TDFileType for &'a mut FILETYPE {}
-
-impl<'a, INLINEKEYBOARDBUTTONTYPE: TDInlineKeyboardButtonType> TDInlineKeyboardButtonType
-    for &'a INLINEKEYBOARDBUTTONTYPE
-{
-}
-impl<'a, INLINEKEYBOARDBUTTONTYPE: TDInlineKeyboardButtonType> TDInlineKeyboardButtonType
-    for &'a mut INLINEKEYBOARDBUTTONTYPE
-{
-}
-
-impl<'a, INLINEQUERYRESULT: TDInlineQueryResult> TDInlineQueryResult for &'a INLINEQUERYRESULT {}
-impl<'a, INLINEQUERYRESULT: TDInlineQueryResult> TDInlineQueryResult for &'a mut INLINEQUERYRESULT {}
-
-impl<'a, INPUTBACKGROUND: TDInputBackground> TDInputBackground for &'a INPUTBACKGROUND {}
-impl<'a, INPUTBACKGROUND: TDInputBackground> TDInputBackground for &'a mut INPUTBACKGROUND {}
-
-impl<'a, INPUTCREDENTIALS: TDInputCredentials> TDInputCredentials for &'a INPUTCREDENTIALS {}
-impl<'a, INPUTCREDENTIALS: TDInputCredentials> TDInputCredentials for &'a mut INPUTCREDENTIALS {}
-
-impl<'a, INPUTFILE: TDInputFile> TDInputFile for &'a INPUTFILE {}
-impl<'a, INPUTFILE: TDInputFile> TDInputFile for &'a mut INPUTFILE {}
-
-impl<'a, INPUTINLINEQUERYRESULT: TDInputInlineQueryResult> TDInputInlineQueryResult
-    for &'a INPUTINLINEQUERYRESULT
-{
-}
-impl<'a, INPUTINLINEQUERYRESULT: TDInputInlineQueryResult> TDInputInlineQueryResult
-    for &'a mut INPUTINLINEQUERYRESULT
-{
-}
-
-impl<'a, INPUTMESSAGECONTENT: TDInputMessageContent> TDInputMessageContent
-    for &'a INPUTMESSAGECONTENT
-{
-}
-impl<'a, INPUTMESSAGECONTENT: TDInputMessageContent> TDInputMessageContent
-    for &'a mut INPUTMESSAGECONTENT
-{
-}
-
-impl<'a, INPUTPASSPORTELEMENT: TDInputPassportElement> TDInputPassportElement
-    for &'a INPUTPASSPORTELEMENT
-{
-}
-impl<'a, INPUTPASSPORTELEMENT: TDInputPassportElement> TDInputPassportElement
-    for &'a mut INPUTPASSPORTELEMENT
-{
+impl<'a, Fnc: RFunction> RFunction for &'a Fnc {
+    type Response = Fnc::Response;
 }
 
-impl<'a, INPUTPASSPORTELEMENTERRORSOURCE: TDInputPassportElementErrorSource>
-    TDInputPassportElementErrorSource for &'a INPUTPASSPORTELEMENTERRORSOURCE
-{
-}
-impl<'a, INPUTPASSPORTELEMENTERRORSOURCE: TDInputPassportElementErrorSource>
-    TDInputPassportElementErrorSource for &'a mut INPUTPASSPORTELEMENTERRORSOURCE
-{
-}
-
-impl<'a, JSONVALUE: TDJsonValue> TDJsonValue for &'a JSONVALUE {}
-impl<'a, JSONVALUE: TDJsonValue> TDJsonValue for &'a mut JSONVALUE {}
-
-impl<'a, KEYBOARDBUTTONTYPE: TDKeyboardButtonType> TDKeyboardButtonType for &'a KEYBOARDBUTTONTYPE {}
-impl<'a, KEYBOARDBUTTONTYPE: TDKeyboardButtonType> TDKeyboardButtonType
-    for &'a mut KEYBOARDBUTTONTYPE
-{
-}
-
-impl<'a, LANGUAGEPACKSTRINGVALUE: TDLanguagePackStringValue> TDLanguagePackStringValue
-    for &'a LANGUAGEPACKSTRINGVALUE
-{
-}
-impl<'a, LANGUAGEPACKSTRINGVALUE: TDLanguagePackStringValue> TDLanguagePackStringValue
-    for &'a mut LANGUAGEPACKSTRINGVALUE
-{
-}
-
-impl<'a, LOGSTREAM: TDLogStream> TDLogStream for &'a LOGSTREAM {}
-impl<'a, LOGSTREAM: TDLogStream> TDLogStream for &'a mut LOGSTREAM {}
-
-impl<'a, LOGINURLINFO: TDLoginUrlInfo> TDLoginUrlInfo for &'a LOGINURLINFO {}
-impl<'a, LOGINURLINFO: TDLoginUrlInfo> TDLoginUrlInfo for &'a mut LOGINURLINFO {}
-
-impl<'a, MASKPOINT: TDMaskPoint> TDMaskPoint for &'a MASKPOINT {}
-impl<'a, MASKPOINT: TDMaskPoint> TDMaskPoint for &'a mut MASKPOINT {}
-
-impl<'a, MESSAGECONTENT: TDMessageContent> TDMessageContent for &'a MESSAGECONTENT {}
-impl<'a, MESSAGECONTENT: TDMessageContent> TDMessageContent for &'a mut MESSAGECONTENT {}
-
-impl<'a, MESSAGEFORWARDORIGIN: TDMessageForwardOrigin> TDMessageForwardOrigin
-    for &'a MESSAGEFORWARDORIGIN
-{
-}
-impl<'a, MESSAGEFORWARDORIGIN: TDMessageForwardOrigin> TDMessageForwardOrigin
-    for &'a mut MESSAGEFORWARDORIGIN
-{
-}
-
-impl<'a, MESSAGESCHEDULINGSTATE: TDMessageSchedulingState> TDMessageSchedulingState
-    for &'a MESSAGESCHEDULINGSTATE
-{
-}
-impl<'a, MESSAGESCHEDULINGSTATE: TDMessageSchedulingState> TDMessageSchedulingState
-    for &'a mut MESSAGESCHEDULINGSTATE
-{
-}
-
-impl<'a, MESSAGESENDINGSTATE: TDMessageSendingState> TDMessageSendingState
-    for &'a MESSAGESENDINGSTATE
-{
-}
-impl<'a, MESSAGESENDINGSTATE: TDMessageSendingState> TDMessageSendingState
-    for &'a mut MESSAGESENDINGSTATE
-{
+impl<'a, Fnc: RFunction> RFunction for &'a mut Fnc {
+    type Response = Fnc::Response;
 }
 
-impl<'a, NETWORKSTATISTICSENTRY: TDNetworkStatisticsEntry> TDNetworkStatisticsEntry
-    for &'a NETWORKSTATISTICSENTRY
-{
-}
-impl<'a, NETWORKSTATISTICSENTRY: TDNetworkStatisticsEntry> TDNetworkStatisticsEntry
-    for &'a mut NETWORKSTATISTICSENTRY
-{
-}
-
-impl<'a, NETWORKTYPE: TDNetworkType> TDNetworkType for &'a NETWORKTYPE {}
-impl<'a, NETWORKTYPE: TDNetworkType> TDNetworkType for &'a mut NETWORKTYPE {}
-
-impl<'a, NOTIFICATIONGROUPTYPE: TDNotificationGroupType> TDNotificationGroupType
-    for &'a NOTIFICATIONGROUPTYPE
-{
-}
-impl<'a, NOTIFICATIONGROUPTYPE: TDNotificationGroupType> TDNotificationGroupType
-    for &'a mut NOTIFICATIONGROUPTYPE
-{
-}
-
-impl<'a, NOTIFICATIONSETTINGSSCOPE: TDNotificationSettingsScope> TDNotificationSettingsScope
-    for &'a NOTIFICATIONSETTINGSSCOPE
-{
-}
-impl<'a, NOTIFICATIONSETTINGSSCOPE: TDNotificationSettingsScope> TDNotificationSettingsScope
-    for &'a mut NOTIFICATIONSETTINGSSCOPE
-{
-}
-
-impl<'a, NOTIFICATIONTYPE: TDNotificationType> TDNotificationType for &'a NOTIFICATIONTYPE {}
-impl<'a, NOTIFICATIONTYPE: TDNotificationType> TDNotificationType for &'a mut NOTIFICATIONTYPE {}
-
-impl<'a, OPTIONVALUE: TDOptionValue> TDOptionValue for &'a OPTIONVALUE {}
-impl<'a, OPTIONVALUE: TDOptionValue> TDOptionValue for &'a mut OPTIONVALUE {}
-
-impl<'a, PAGEBLOCK: TDPageBlock> TDPageBlock for &'a PAGEBLOCK {}
-impl<'a, PAGEBLOCK: TDPageBlock> TDPageBlock for &'a mut PAGEBLOCK {}
-
-impl<'a, PAGEBLOCKHORIZONTALALIGNMENT: TDPageBlockHorizontalAlignment>
-    TDPageBlockHorizontalAlignment for &'a PAGEBLOCKHORIZONTALALIGNMENT
-{
-}
-impl<'a, PAGEBLOCKHORIZONTALALIGNMENT: TDPageBlockHorizontalAlignment>
-    TDPageBlockHorizontalAlignment for &'a mut PAGEBLOCKHORIZONTALALIGNMENT
-{
-}
-
-impl<'a, PAGEBLOCKVERTICALALIGNMENT: TDPageBlockVerticalAlignment> TDPageBlockVerticalAlignment
-    for &'a PAGEBLOCKVERTICALALIGNMENT
-{
-}
-impl<'a, PAGEBLOCKVERTICALALIGNMENT: TDPageBlockVerticalAlignment> TDPageBlockVerticalAlignment
-    for &'a mut PAGEBLOCKVERTICALALIGNMENT
+fn number_from_string<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: FromStr,
+    T::Err: Display,
+    D: Deserializer<'de>,
 {
+    // TDlib sends int64 as strings, but numbers are accepted too
+    let s = match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::String(s) => s,
+        serde_json::Value::Number(n) => n.to_string(),
+        v => {
+            return Err(de::Error::custom(format!(
+                "expected number or string, got {}",
+                v
+            )))
+        }
+    };
+    T::from_str(&s).map_err(de::Error::custom)
 }
 
-impl<'a, PASSPORTELEMENT: TDPassportElement> TDPassportElement for &'a PASSPORTELEMENT {}
-impl<'a, PASSPORTELEMENT: TDPassportElement> TDPassportElement for &'a mut PASSPORTELEMENT {}
+/// TL int64, serialized as string, because it can't be represented by json number precisely
+pub(super) mod int64 {
+    use serde::{Deserializer, Serializer};
 
-impl<'a, PASSPORTELEMENTERRORSOURCE: TDPassportElementErrorSource> TDPassportElementErrorSource
-    for &'a PASSPORTELEMENTERRORSOURCE
-{
-}
-impl<'a, PASSPORTELEMENTERRORSOURCE: TDPassportElementErrorSource> TDPassportElementErrorSource
-    for &'a mut PASSPORTELEMENTERRORSOURCE
-{
-}
+    pub fn serialize<S: Serializer>(value: &i64, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(value)
+    }
 
-impl<'a, PASSPORTELEMENTTYPE: TDPassportElementType> TDPassportElementType
-    for &'a PASSPORTELEMENTTYPE
-{
-}
-impl<'a, PASSPORTELEMENTTYPE: TDPassportElementType> TDPassportElementType
-    for &'a mut PASSPORTELEMENTTYPE
-{
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<i64, D::Error> {
+        super::number_from_string(deserializer)
+    }
 }
 
-impl<'a, POLLTYPE: TDPollType> TDPollType for &'a POLLTYPE {}
-impl<'a, POLLTYPE: TDPollType> TDPollType for &'a mut POLLTYPE {}
+/// Vector of TL int64, serialized as strings
+pub(super) mod int64_vec {
+    use serde::{Deserialize, Deserializer, Serializer};
 
-impl<'a, PROXYTYPE: TDProxyType> TDProxyType for &'a PROXYTYPE {}
-impl<'a, PROXYTYPE: TDProxyType> TDProxyType for &'a mut PROXYTYPE {}
+    #[derive(Deserialize)]
+    struct Int64(#[serde(with = "super::int64")] i64);
 
-impl<'a, PUBLICCHATTYPE: TDPublicChatType> TDPublicChatType for &'a PUBLICCHATTYPE {}
-impl<'a, PUBLICCHATTYPE: TDPublicChatType> TDPublicChatType for &'a mut PUBLICCHATTYPE {}
+    pub fn serialize<S: Serializer>(value: &[i64], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(value.iter().map(|v| v.to_string()))
+    }
 
-impl<'a, PUSHMESSAGECONTENT: TDPushMessageContent> TDPushMessageContent for &'a PUSHMESSAGECONTENT {}
-impl<'a, PUSHMESSAGECONTENT: TDPushMessageContent> TDPushMessageContent
-    for &'a mut PUSHMESSAGECONTENT
-{
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<i64>, D::Error> {
+        let values = Vec::<Int64>::deserialize(deserializer)?;
+        Ok(values.into_iter().map(|v| v.0).collect())
+    }
 }
 
-impl<'a, REPLYMARKUP: TDReplyMarkup> TDReplyMarkup for &'a REPLYMARKUP {}
-impl<'a, REPLYMARKUP: TDReplyMarkup> TDReplyMarkup for &'a mut REPLYMARKUP {}
-
-impl<'a, RICHTEXT: TDRichText> TDRichText for &'a RICHTEXT {}
-impl<'a, RICHTEXT: TDRichText> TDRichText for &'a mut RICHTEXT {}
-
-impl<'a, SEARCHMESSAGESFILTER: TDSearchMessagesFilter> TDSearchMessagesFilter
-    for &'a SEARCHMESSAGESFILTER
-{
-}
-impl<'a, SEARCHMESSAGESFILTER: TDSearchMessagesFilter> TDSearchMessagesFilter
-    for &'a mut SEARCHMESSAGESFILTER
-{
-}
+/// TL bytes, serialized as base64 string
+pub(super) mod bytes {
+    use serde::{de, Deserialize, Deserializer, Serializer};
 
-impl<'a, SECRETCHATSTATE: TDSecretChatState> TDSecretChatState for &'a SECRETCHATSTATE {}
-impl<'a, SECRETCHATSTATE: TDSecretChatState> TDSecretChatState for &'a mut SECRETCHATSTATE {}
+    pub fn serialize<S: Serializer>(value: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&base64::encode(value))
+    }
 
-impl<'a, SUPERGROUPMEMBERSFILTER: TDSupergroupMembersFilter> TDSupergroupMembersFilter
-    for &'a SUPERGROUPMEMBERSFILTER
-{
-}
-impl<'a, SUPERGROUPMEMBERSFILTER: TDSupergroupMembersFilter> TDSupergroupMembersFilter
-    for &'a mut SUPERGROUPMEMBERSFILTER
-{
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let encoded = String::deserialize(deserializer)?;
+        base64::decode(&encoded).map_err(de::Error::custom)
+    }
 }
 
-impl<'a, TMEURLTYPE: TDTMeUrlType> TDTMeUrlType for &'a TMEURLTYPE {}
-impl<'a, TMEURLTYPE: TDTMeUrlType> TDTMeUrlType for &'a mut TMEURLTYPE {}
-
-impl<'a, TEXTENTITYTYPE: TDTextEntityType> TDTextEntityType for &'a TEXTENTITYTYPE {}
-impl<'a, TEXTENTITYTYPE: TDTextEntityType> TDTextEntityType for &'a mut TEXTENTITYTYPE {}
+/// Vector of TL bytes, serialized as base64 strings
+pub(super) mod bytes_vec {
+    use serde::{Deserialize, Deserializer, Serializer};
 
-impl<'a, TEXTPARSEMODE: TDTextParseMode> TDTextParseMode for &'a TEXTPARSEMODE {}
-impl<'a, TEXTPARSEMODE: TDTextParseMode> TDTextParseMode for &'a mut TEXTPARSEMODE {}
+    #[derive(Deserialize)]
+    struct Bytes(#[serde(with = "super::bytes")] Vec<u8>);
 
-impl<'a, TOPCHATCATEGORY: TDTopChatCategory> TDTopChatCategory for &'a TOPCHATCATEGORY {}
-impl<'a, TOPCHATCATEGORY: TDTopChatCategory> TDTopChatCategory for &'a mut TOPCHATCATEGORY {}
-
-impl<'a, UPDATE: TDUpdate> TDUpdate for &'a UPDATE {}
-impl<'a, UPDATE: TDUpdate> TDUpdate for &'a mut UPDATE {}
-
-impl<'a, USERPRIVACYSETTING: TDUserPrivacySetting> TDUserPrivacySetting for &'a USERPRIVACYSETTING {}
-impl<'a, USERPRIVACYSETTING: TDUserPrivacySetting> TDUserPrivacySetting
-    for &'a mut USERPRIVACYSETTING
-{
-}
+    pub fn serialize<S: Serializer>(value: &[Vec<u8>], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(value.iter().map(base64::encode))
+    }
 
-impl<'a, USERPRIVACYSETTINGRULE: TDUserPrivacySettingRule> TDUserPrivacySettingRule
-    for &'a USERPRIVACYSETTINGRULE
-{
-}
-impl<'a, USERPRIVACYSETTINGRULE: TDUserPrivacySettingRule> TDUserPrivacySettingRule
-    for &'a mut USERPRIVACYSETTINGRULE
-{
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<Vec<u8>>, D::Error> {
+        let values = Vec::<Bytes>::deserialize(deserializer)?;
+        Ok(values.into_iter().map(|v| v.0).collect())
+    }
 }
 
-impl<'a, USERSTATUS: TDUserStatus> TDUserStatus for &'a USERSTATUS {}
-impl<'a, USERSTATUS: TDUserStatus> TDUserStatus for &'a mut USERSTATUS {}
-
-impl<'a, USERTYPE: TDUserType> TDUserType for &'a USERTYPE {}
-impl<'a, USERTYPE: TDUserType> TDUserType for &'a mut USERTYPE {}
-
-#[derive(Debug, Clone)]
-pub enum TdType {
-    TestUseUpdate(TestUseUpdate),
-    UpdateActiveNotifications(UpdateActiveNotifications),
-    UpdateAuthorizationState(UpdateAuthorizationState),
-    UpdateBasicGroup(UpdateBasicGroup),
-    UpdateBasicGroupFullInfo(UpdateBasicGroupFullInfo),
-    UpdateCall(UpdateCall),
-    UpdateChatActionBar(UpdateChatActionBar),
-    UpdateChatChatList(UpdateChatChatList),
-    UpdateChatDefaultDisableNotification(UpdateChatDefaultDisableNotification),
-    UpdateChatDraftMessage(UpdateChatDraftMessage),
-    UpdateChatHasScheduledMessages(UpdateChatHasScheduledMessages),
-    UpdateChatIsMarkedAsUnread(UpdateChatIsMarkedAsUnread),
-    UpdateChatIsPinned(UpdateChatIsPinned),
-    UpdateChatIsSponsored(UpdateChatIsSponsored),
-    UpdateChatLastMessage(UpdateChatLastMessage),
-    UpdateChatNotificationSettings(UpdateChatNotificationSettings),
-    UpdateChatOnlineMemberCount(UpdateChatOnlineMemberCount),
-    UpdateChatOrder(UpdateChatOrder),
-    UpdateChatPermissions(UpdateChatPermissions),
-    UpdateChatPhoto(UpdateChatPhoto),
-    UpdateChatPinnedMessage(UpdateChatPinnedMessage),
-    UpdateChatReadInbox(UpdateChatReadInbox),
-    UpdateChatReadOutbox(UpdateChatReadOutbox),
-    UpdateChatReplyMarkup(UpdateChatReplyMarkup),
-    UpdateChatTitle(UpdateChatTitle),
-    UpdateChatUnreadMentionCount(UpdateChatUnreadMentionCount),
-    UpdateConnectionState(UpdateConnectionState),
-    UpdateDeleteMessages(UpdateDeleteMessages),
-    UpdateFavoriteStickers(UpdateFavoriteStickers),
-    UpdateFile(UpdateFile),
-    UpdateFileGenerationStart(UpdateFileGenerationStart),
-    UpdateFileGenerationStop(UpdateFileGenerationStop),
-    UpdateHavePendingNotifications(UpdateHavePendingNotifications),
-    UpdateInstalledStickerSets(UpdateInstalledStickerSets),
-    UpdateLanguagePackStrings(UpdateLanguagePackStrings),
-    UpdateMessageContent(UpdateMessageContent),
-    UpdateMessageContentOpened(UpdateMessageContentOpened),
-    UpdateMessageEdited(UpdateMessageEdited),
-    UpdateMessageLiveLocationViewed(UpdateMessageLiveLocationViewed),
-    UpdateMessageMentionRead(UpdateMessageMentionRead),
-    UpdateMessageSendAcknowledged(UpdateMessageSendAcknowledged),
-    UpdateMessageSendFailed(UpdateMessageSendFailed),
-    UpdateMessageSendSucceeded(UpdateMessageSendSucceeded),
-    UpdateMessageViews(UpdateMessageViews),
-    UpdateNewCallbackQuery(UpdateNewCallbackQuery),
-    UpdateNewChat(UpdateNewChat),
-    UpdateNewChosenInlineResult(UpdateNewChosenInlineResult),
-    UpdateNewCustomEvent(UpdateNewCustomEvent),
-    UpdateNewCustomQuery(UpdateNewCustomQuery),
-    UpdateNewInlineCallbackQuery(UpdateNewInlineCallbackQuery),
-    UpdateNewInlineQuery(UpdateNewInlineQuery),
-    UpdateNewMessage(UpdateNewMessage),
-    UpdateNewPreCheckoutQuery(UpdateNewPreCheckoutQuery),
-    UpdateNewShippingQuery(UpdateNewShippingQuery),
-    UpdateNotification(UpdateNotification),
-    UpdateNotificationGroup(UpdateNotificationGroup),
-    UpdateOption(UpdateOption),
-    UpdatePoll(UpdatePoll),
-    UpdatePollAnswer(UpdatePollAnswer),
-    UpdateRecentStickers(UpdateRecentStickers),
-    UpdateSavedAnimations(UpdateSavedAnimations),
-    UpdateScopeNotificationSettings(UpdateScopeNotificationSettings),
-    UpdateSecretChat(UpdateSecretChat),
-    UpdateSelectedBackground(UpdateSelectedBackground),
-    UpdateServiceNotification(UpdateServiceNotification),
-    UpdateSupergroup(UpdateSupergroup),
-    UpdateSupergroupFullInfo(UpdateSupergroupFullInfo),
-    UpdateTermsOfService(UpdateTermsOfService),
-    UpdateTrendingStickerSets(UpdateTrendingStickerSets),
-    UpdateUnreadChatCount(UpdateUnreadChatCount),
-    UpdateUnreadMessageCount(UpdateUnreadMessageCount),
-    UpdateUser(UpdateUser),
-    UpdateUserChatAction(UpdateUserChatAction),
-    UpdateUserFullInfo(UpdateUserFullInfo),
-    UpdateUserPrivacySettingRules(UpdateUserPrivacySettingRules),
-    UpdateUserStatus(UpdateUserStatus),
-    UpdateUsersNearby(UpdateUsersNearby),
-
-    AuthorizationState(AuthorizationState),
-    CanTransferOwnershipResult(CanTransferOwnershipResult),
-    CheckChatUsernameResult(CheckChatUsernameResult),
-    JsonValue(JsonValue),
-    LanguagePackStringValue(LanguagePackStringValue),
-    LogStream(LogStream),
-    LoginUrlInfo(LoginUrlInfo),
-    OptionValue(OptionValue),
-    PassportElement(PassportElement),
-    Update(Update),
-    AccountTtl(AccountTtl),
-    Animations(Animations),
-    AuthenticationCodeInfo(AuthenticationCodeInfo),
-    AutoDownloadSettingsPresets(AutoDownloadSettingsPresets),
-    Background(Background),
-    Backgrounds(Backgrounds),
-    BasicGroup(BasicGroup),
-    BasicGroupFullInfo(BasicGroupFullInfo),
-    CallId(CallId),
-    CallbackQueryAnswer(CallbackQueryAnswer),
-    Chat(Chat),
-    ChatAdministrators(ChatAdministrators),
-    ChatEvents(ChatEvents),
-    ChatInviteLink(ChatInviteLink),
-    ChatInviteLinkInfo(ChatInviteLinkInfo),
-    ChatMember(ChatMember),
-    ChatMembers(ChatMembers),
-    Chats(Chats),
-    ChatsNearby(ChatsNearby),
-    ConnectedWebsites(ConnectedWebsites),
-    Count(Count),
-    CustomRequestResult(CustomRequestResult),
-    DatabaseStatistics(DatabaseStatistics),
-    DeepLinkInfo(DeepLinkInfo),
-    EmailAddressAuthenticationCodeInfo(EmailAddressAuthenticationCodeInfo),
-    Emojis(Emojis),
-    Error(Error),
-    File(File),
-    FilePart(FilePart),
-    FormattedText(FormattedText),
-    FoundMessages(FoundMessages),
-    GameHighScores(GameHighScores),
-    Hashtags(Hashtags),
-    HttpUrl(HttpUrl),
-    ImportedContacts(ImportedContacts),
-    InlineQueryResults(InlineQueryResults),
-    LanguagePackInfo(LanguagePackInfo),
-    LanguagePackStrings(LanguagePackStrings),
-    LocalizationTargetInfo(LocalizationTargetInfo),
-    LogTags(LogTags),
-    LogVerbosityLevel(LogVerbosityLevel),
-    Message(Message),
-    MessageLinkInfo(MessageLinkInfo),
-    Messages(Messages),
-    NetworkStatistics(NetworkStatistics),
-    Ok(Ok),
-    OrderInfo(OrderInfo),
-    PassportAuthorizationForm(PassportAuthorizationForm),
-    PassportElements(PassportElements),
-    PassportElementsWithErrors(PassportElementsWithErrors),
-    PasswordState(PasswordState),
-    PaymentForm(PaymentForm),
-    PaymentReceipt(PaymentReceipt),
-    PaymentResult(PaymentResult),
-    Proxies(Proxies),
-    Proxy(Proxy),
-    PublicMessageLink(PublicMessageLink),
-    PushReceiverId(PushReceiverId),
-    RecoveryEmailAddress(RecoveryEmailAddress),
-    ScopeNotificationSettings(ScopeNotificationSettings),
-    Seconds(Seconds),
-    SecretChat(SecretChat),
-    Session(Session),
-    Sessions(Sessions),
-    StickerSet(StickerSet),
-    StickerSets(StickerSets),
-    Stickers(Stickers),
-    StorageStatistics(StorageStatistics),
-    StorageStatisticsFast(StorageStatisticsFast),
-    Supergroup(Supergroup),
-    SupergroupFullInfo(SupergroupFullInfo),
-    TMeUrls(TMeUrls),
-    TemporaryPasswordState(TemporaryPasswordState),
-    TestBytes(TestBytes),
-    TestInt(TestInt),
-    TestString(TestString),
-    TestVectorInt(TestVectorInt),
-    TestVectorIntObject(TestVectorIntObject),
-    TestVectorString(TestVectorString),
-    TestVectorStringObject(TestVectorStringObject),
-    Text(Text),
-    TextEntities(TextEntities),
-    Updates(Updates),
-    User(User),
-    UserFullInfo(UserFullInfo),
-    UserPrivacySettingRules(UserPrivacySettingRules),
-    UserProfilePhotos(UserProfilePhotos),
-    Users(Users),
-    ValidatedOrderInfo(ValidatedOrderInfo),
-    WebPage(WebPage),
-    WebPageInstantView(WebPageInstantView),
-}
-impl<'de> Deserialize<'de> for TdType {
-    fn deserialize<D>(deserializer: D) -> Result<TdType, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-             TdType,
-         (testUseUpdate, TestUseUpdate);
-         (updateActiveNotifications, UpdateActiveNotifications);
-         (updateAuthorizationState, UpdateAuthorizationState);
-         (updateBasicGroup, UpdateBasicGroup);
-         (updateBasicGroupFullInfo, UpdateBasicGroupFullInfo);
-         (updateCall, UpdateCall);
-         (updateChatActionBar, UpdateChatActionBar);
-         (updateChatChatList, UpdateChatChatList);
-         (updateChatDefaultDisableNotification, UpdateChatDefaultDisableNotification);
-         (updateChatDraftMessage, UpdateChatDraftMessage);
-         (updateChatHasScheduledMessages, UpdateChatHasScheduledMessages);
-         (updateChatIsMarkedAsUnread, UpdateChatIsMarkedAsUnread);
-         (updateChatIsPinned, UpdateChatIsPinned);
-         (updateChatIsSponsored, UpdateChatIsSponsored);
-         (updateChatLastMessage, UpdateChatLastMessage);
-         (updateChatNotificationSettings, UpdateChatNotificationSettings);
-         (updateChatOnlineMemberCount, UpdateChatOnlineMemberCount);
-         (updateChatOrder, UpdateChatOrder);
-         (updateChatPermissions, UpdateChatPermissions);
-         (updateChatPhoto, UpdateChatPhoto);
-         (updateChatPinnedMessage, UpdateChatPinnedMessage);
-         (updateChatReadInbox, UpdateChatReadInbox);
-         (updateChatReadOutbox, UpdateChatReadOutbox);
-         (updateChatReplyMarkup, UpdateChatReplyMarkup);
-         (updateChatTitle, UpdateChatTitle);
-         (updateChatUnreadMentionCount, UpdateChatUnreadMentionCount);
-         (updateConnectionState, UpdateConnectionState);
-         (updateDeleteMessages, UpdateDeleteMessages);
-         (updateFavoriteStickers, UpdateFavoriteStickers);
-         (updateFile, UpdateFile);
-         (updateFileGenerationStart, UpdateFileGenerationStart);
-         (updateFileGenerationStop, UpdateFileGenerationStop);
-         (updateHavePendingNotifications, UpdateHavePendingNotifications);
-         (updateInstalledStickerSets, UpdateInstalledStickerSets);
-         (updateLanguagePackStrings, UpdateLanguagePackStrings);
-         (updateMessageContent, UpdateMessageContent);
-         (updateMessageContentOpened, UpdateMessageContentOpened);
-         (updateMessageEdited, UpdateMessageEdited);
-         (updateMessageLiveLocationViewed, UpdateMessageLiveLocationViewed);
-         (updateMessageMentionRead, UpdateMessageMentionRead);
-         (updateMessageSendAcknowledged, UpdateMessageSendAcknowledged);
-         (updateMessageSendFailed, UpdateMessageSendFailed);
-         (updateMessageSendSucceeded, UpdateMessageSendSucceeded);
-         (updateMessageViews, UpdateMessageViews);
-         (updateNewCallbackQuery, UpdateNewCallbackQuery);
-         (updateNewChat, UpdateNewChat);
-         (updateNewChosenInlineResult, UpdateNewChosenInlineResult);
-         (updateNewCustomEvent, UpdateNewCustomEvent);
-         (updateNewCustomQuery, UpdateNewCustomQuery);
-         (updateNewInlineCallbackQuery, UpdateNewInlineCallbackQuery);
-         (updateNewInlineQuery, UpdateNewInlineQuery);
-         (updateNewMessage, UpdateNewMessage);
-         (updateNewPreCheckoutQuery, UpdateNewPreCheckoutQuery);
-         (updateNewShippingQuery, UpdateNewShippingQuery);
-         (updateNotification, UpdateNotification);
-         (updateNotificationGroup, UpdateNotificationGroup);
-         (updateOption, UpdateOption);
-         (updatePoll, UpdatePoll);
-         (updatePollAnswer, UpdatePollAnswer);
-         (updateRecentStickers, UpdateRecentStickers);
-         (updateSavedAnimations, UpdateSavedAnimations);
-         (updateScopeNotificationSettings, UpdateScopeNotificationSettings);
-         (updateSecretChat, UpdateSecretChat);
-         (updateSelectedBackground, UpdateSelectedBackground);
-         (updateServiceNotification, UpdateServiceNotification);
-         (updateSupergroup, UpdateSupergroup);
-         (updateSupergroupFullInfo, UpdateSupergroupFullInfo);
-         (updateTermsOfService, UpdateTermsOfService);
-         (updateTrendingStickerSets, UpdateTrendingStickerSets);
-         (updateUnreadChatCount, UpdateUnreadChatCount);
-         (updateUnreadMessageCount, UpdateUnreadMessageCount);
-         (updateUser, UpdateUser);
-         (updateUserChatAction, UpdateUserChatAction);
-         (updateUserFullInfo, UpdateUserFullInfo);
-         (updateUserPrivacySettingRules, UpdateUserPrivacySettingRules);
-         (updateUserStatus, UpdateUserStatus);
-         (updateUsersNearby, UpdateUsersNearby);
-
-         (AuthorizationState, AuthorizationState);
-         (CanTransferOwnershipResult, CanTransferOwnershipResult);
-         (CheckChatUsernameResult, CheckChatUsernameResult);
-         (JsonValue, JsonValue);
-         (LanguagePackStringValue, LanguagePackStringValue);
-         (LogStream, LogStream);
-         (LoginUrlInfo, LoginUrlInfo);
-         (OptionValue, OptionValue);
-         (PassportElement, PassportElement);
-         (Update, Update);
-         (accountTtl, AccountTtl);
-         (animations, Animations);
-         (authenticationCodeInfo, AuthenticationCodeInfo);
-         (autoDownloadSettingsPresets, AutoDownloadSettingsPresets);
-         (background, Background);
-         (backgrounds, Backgrounds);
-         (basicGroup, BasicGroup);
-         (basicGroupFullInfo, BasicGroupFullInfo);
-         (callId, CallId);
-         (callbackQueryAnswer, CallbackQueryAnswer);
-         (chat, Chat);
-         (chatAdministrators, ChatAdministrators);
-         (chatEvents, ChatEvents);
-         (chatInviteLink, ChatInviteLink);
-         (chatInviteLinkInfo, ChatInviteLinkInfo);
-         (chatMember, ChatMember);
-         (chatMembers, ChatMembers);
-         (chats, Chats);
-         (chatsNearby, ChatsNearby);
-         (connectedWebsites, ConnectedWebsites);
-         (count, Count);
-         (customRequestResult, CustomRequestResult);
-         (databaseStatistics, DatabaseStatistics);
-         (deepLinkInfo, DeepLinkInfo);
-         (emailAddressAuthenticationCodeInfo, EmailAddressAuthenticationCodeInfo);
-         (emojis, Emojis);
-         (error, Error);
-         (file, File);
-         (filePart, FilePart);
-         (formattedText, FormattedText);
-         (foundMessages, FoundMessages);
-         (gameHighScores, GameHighScores);
-         (hashtags, Hashtags);
-         (httpUrl, HttpUrl);
-         (importedContacts, ImportedContacts);
-         (inlineQueryResults, InlineQueryResults);
-         (languagePackInfo, LanguagePackInfo);
-         (languagePackStrings, LanguagePackStrings);
-         (localizationTargetInfo, LocalizationTargetInfo);
-         (logTags, LogTags);
-         (logVerbosityLevel, LogVerbosityLevel);
-         (message, Message);
-         (messageLinkInfo, MessageLinkInfo);
-         (messages, Messages);
-         (networkStatistics, NetworkStatistics);
-         (ok, Ok);
-         (orderInfo, OrderInfo);
-         (passportAuthorizationForm, PassportAuthorizationForm);
-         (passportElements, PassportElements);
-         (passportElementsWithErrors, PassportElementsWithErrors);
-         (passwordState, PasswordState);
-         (paymentForm, PaymentForm);
-         (paymentReceipt, PaymentReceipt);
-         (paymentResult, PaymentResult);
-         (proxies, Proxies);
-         (proxy, Proxy);
-         (publicMessageLink, PublicMessageLink);
-         (pushReceiverId, PushReceiverId);
-         (recoveryEmailAddress, RecoveryEmailAddress);
-         (scopeNotificationSettings, ScopeNotificationSettings);
-         (seconds, Seconds);
-         (secretChat, SecretChat);
-         (session, Session);
-         (sessions, Sessions);
-         (stickerSet, StickerSet);
-         (stickerSets, StickerSets);
-         (stickers, Stickers);
-         (storageStatistics, StorageStatistics);
-         (storageStatisticsFast, StorageStatisticsFast);
-         (supergroup, Supergroup);
-         (supergroupFullInfo, SupergroupFullInfo);
-         (tMeUrls, TMeUrls);
-         (temporaryPasswordState, TemporaryPasswordState);
-         (testBytes, TestBytes);
-         (testInt, TestInt);
-         (testString, TestString);
-         (testVectorInt, TestVectorInt);
-         (testVectorIntObject, TestVectorIntObject);
-         (testVectorString, TestVectorString);
-         (testVectorStringObject, TestVectorStringObject);
-         (text, Text);
-         (textEntities, TextEntities);
-         (updates, Updates);
-         (user, User);
-         (userFullInfo, UserFullInfo);
-         (userPrivacySettingRules, UserPrivacySettingRules);
-         (userProfilePhotos, UserProfilePhotos);
-         (users, Users);
-         (validatedOrderInfo, ValidatedOrderInfo);
-         (webPage, WebPage);
-         (webPageInstantView, WebPageInstantView);
-
-        )(deserializer)
-    }
+/// Checks that object is deserialized from its own json without changes
+#[cfg(test)]
+pub(super) fn assert_round_trip<T: RObject + serde::de::DeserializeOwned>(value: T) {
+    let json = value.to_json().unwrap();
+    let parsed: T =
+        serde_json::from_str(&json).unwrap_or_else(|e| panic!("can't deserialize {}: {}", json, e));
+    assert_eq!(parsed.to_json().unwrap(), json);
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::types::{from_json, TdType, UpdateAuthorizationState};
+    use crate::errors::RTDError;
+    use crate::types::{
+        from_json, Chat, ChatPermissions, ChatType, ChatTypePrivate, FilePart, FormattedText,
+        GetMe, InputMessageContent, InputMessageText, Location, MessageContent, MessageLocation,
+        Messages, PushReceiverId, RObject, RichText, SendMessage, TdHeader, TdType,
+        UpdateAuthorizationState, UpdateInstalledStickerSets, User,
+    };
+    use std::collections::HashSet;
 
     #[test]
     fn test_deserialize_enum() {
@@ -851,14 +248,164 @@ mod tests {
             }
         };
     }
-}
 
-pub(super) fn number_from_string<'de, T, D>(deserializer: D) -> Result<T, D::Error>
-where
-    T: FromStr,
-    T::Err: Display,
-    D: Deserializer<'de>,
-{
-    let s = String::deserialize(deserializer)?;
-    T::from_str(&s).map_err(de::Error::custom)
+    #[test]
+    fn test_deserialize_header() {
+        let header =
+            from_json::<TdHeader>(r#"{"@type":"chats","chat_ids":[1,2,3],"@extra":"4d2a1b"}"#)
+                .unwrap();
+        assert_eq!(header.td_type(), "chats");
+        assert_eq!(header.extra(), Some("4d2a1b"));
+
+        let header = from_json::<TdHeader>(
+            r#"{"@type":"updateAuthorizationState","authorization_state":{"@type":"authorizationStateWaitTdlibParameters"}}"#,
+        )
+        .unwrap();
+        assert_eq!(header.td_type(), "updateAuthorizationState");
+        assert_eq!(header.extra(), None);
+    }
+
+    #[test]
+    fn test_try_build() {
+        match SendMessage::builder().reply_to_message_id(1).try_build() {
+            Err(RTDError::MissingFields(name, fields)) => {
+                assert_eq!(name, "SendMessage");
+                assert_eq!(fields, vec!["chat_id", "input_message_content"]);
+            }
+            r => panic!("required fields not checked: {:?}", r),
+        };
+
+        let content = InputMessageContent::InputMessageText(
+            InputMessageText::builder()
+                .text(FormattedText::builder().text("text").build())
+                .build(),
+        );
+        let message = SendMessage::builder()
+            .chat_id(1)
+            .input_message_content(content)
+            .try_build()
+            .unwrap();
+        assert_eq!(message.chat_id(), 1);
+    }
+
+    #[test]
+    fn test_int64() {
+        let from_string =
+            PushReceiverId::from_json(r#"{"@type":"pushReceiverId","id":"9223372036854775807"}"#)
+                .unwrap();
+        assert_eq!(from_string.id(), i64::MAX);
+        let from_number =
+            PushReceiverId::from_json(r#"{"@type":"pushReceiverId","id":-42}"#).unwrap();
+        assert_eq!(from_number.id(), -42);
+        assert!(from_number.to_json().unwrap().contains(r#""id":"-42""#));
+
+        let update = UpdateInstalledStickerSets::from_json(
+            r#"{"@type":"updateInstalledStickerSets","is_masks":false,"sticker_set_ids":["1",2]}"#,
+        )
+        .unwrap();
+        assert_eq!(update.sticker_set_ids(), &vec![1, 2]);
+        assert!(update
+            .to_json()
+            .unwrap()
+            .contains(r#""sticker_set_ids":["1","2"]"#));
+    }
+
+    #[test]
+    fn test_bytes() {
+        let part = FilePart::from_json(r#"{"@type":"filePart","data":"AAH+/w=="}"#).unwrap();
+        assert_eq!(part.data(), &[0, 1, 254, 255]);
+        assert!(part.to_json().unwrap().contains(r#""data":"AAH+/w==""#));
+        assert!(FilePart::from_json(r#"{"@type":"filePart","data":"not base64"}"#).is_err());
+
+        let part = FilePart::builder().data(b"rust").build();
+        assert!(part.to_json().unwrap().contains(r#""data":"cnVzdA==""#));
+    }
+
+    #[test]
+    fn test_eq() {
+        let json = r#"{"@type":"messageLocation","location":{"@type":"location","latitude":1.5,"longitude":2.5},"live_period":0,"expires_in":0}"#;
+        let content = from_json::<MessageContent>(json).unwrap();
+        assert_eq!(content, from_json::<MessageContent>(json).unwrap());
+        let moved = MessageContent::MessageLocation(
+            MessageLocation::builder()
+                .location(Location::builder().latitude(1.5).longitude(3.5).build())
+                .build(),
+        );
+        assert_ne!(content, moved);
+
+        let users: HashSet<User> = [1, 2, 1]
+            .iter()
+            .map(|id| User::builder().id(*id).first_name("user").build())
+            .collect();
+        assert_eq!(users.len(), 2);
+
+        // response with `@extra` is equal to the same object received as update
+        let response =
+            ChatTypePrivate::from_json(r#"{"@type":"chatTypePrivate","@extra":"1:1","user_id":1}"#)
+                .unwrap();
+        let update =
+            ChatTypePrivate::from_json(r#"{"@type":"chatTypePrivate","user_id":1}"#).unwrap();
+        assert!(response.extra().is_some());
+        assert_eq!(response, update);
+        let types: HashSet<ChatTypePrivate> = vec![response, update].into_iter().collect();
+        assert_eq!(types.len(), 1);
+    }
+
+    #[test]
+    fn test_type_tag() {
+        assert_eq!(GetMe::default().to_json().unwrap(), r#"{"@type":"getMe"}"#);
+
+        let chat_type = ChatType::Private(ChatTypePrivate::default());
+        assert_eq!(
+            chat_type.to_json().unwrap(),
+            r#"{"@type":"chatTypePrivate","user_id":0}"#
+        );
+        assert_eq!(
+            from_json::<ChatType>(&chat_type.to_json().unwrap()).unwrap(),
+            chat_type
+        );
+        assert!(from_json::<ChatType>(r#"{"@type":"chatTypeUnknown"}"#).is_err());
+
+        // default of a class is its real constructor, not `null`
+        let chat = Chat::default().to_json().unwrap();
+        assert!(chat.contains(r#""type":{"@type":"chatTypeBasicGroup","basic_group_id":0}"#));
+        assert_eq!(Chat::from_json(&chat).unwrap(), Chat::default());
+        let rich_text = RichText::default();
+        assert_eq!(
+            from_json::<RichText>(&rich_text.to_json().unwrap()).unwrap(),
+            rich_text
+        );
+    }
+
+    #[test]
+    fn test_modify() {
+        let permissions = ChatPermissions::builder()
+            .can_send_messages(true)
+            .can_send_polls(true)
+            .build();
+        let restricted = permissions.clone().with_can_send_polls(false);
+        assert!(restricted.can_send_messages());
+        assert!(!restricted.can_send_polls());
+        assert_ne!(restricted, permissions);
+
+        let mut builder = restricted.to_builder();
+        assert_eq!(builder.try_build().unwrap(), restricted);
+        assert_eq!(builder.can_send_polls(true).build(), permissions);
+        assert!(!restricted.into_builder().build().can_send_polls());
+    }
+
+    #[test]
+    fn test_into() {
+        let chat = Chat::builder().title("Rust").build();
+        let title = chat.title().as_ptr();
+        let moved = chat.into_title();
+        assert_eq!(moved, "Rust");
+        assert_eq!(moved.as_ptr(), title);
+
+        let messages =
+            from_json::<Messages>(r#"{"@type":"messages","total_count":1,"messages":[null]}"#)
+                .unwrap();
+        assert_eq!(messages.total_count(), 1);
+        assert_eq!(messages.into_messages(), vec![None]);
+    }
 }
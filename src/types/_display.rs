@@ -0,0 +1,451 @@
+//! Short human-readable representation of messages, chats, users and files for logs and notifications.
+//!
+//! ```
+//! use rust_tdlib::types::{FormattedText, Message, MessageContent, MessageText};
+//!
+//! let message = Message::builder()
+//!     .date(1601553600)
+//!     .sender_user_id(5)
+//!     .chat_id(7)
+//!     .content(MessageContent::MessageText(
+//!         MessageText::builder()
+//!             .text(FormattedText::builder().text("hello").build())
+//!             .build(),
+//!     ))
+//!     .build();
+//! assert_eq!(message.to_string(), "[2020-10-01 12:00] user 5 in chat 7: hello");
+//! ```
+use crate::types::*;
+use std::fmt;
+
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+// `2020-10-01 12:00` in UTC
+fn write_date(f: &mut fmt::Formatter, timestamp: i32) -> fmt::Result {
+    let timestamp = i64::from(timestamp);
+    let seconds = timestamp.rem_euclid(SECONDS_PER_DAY);
+    // civil date from days since 1970-01-01, http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let days = timestamp.div_euclid(SECONDS_PER_DAY) + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    write!(
+        f,
+        "{:04}-{:02}-{:02} {:02}:{:02}",
+        year,
+        month,
+        day,
+        seconds / 3600,
+        seconds % 3600 / 60
+    )
+}
+
+// ` 'caption'`, nothing for empty caption
+fn write_caption(f: &mut fmt::Formatter, caption: &FormattedText) -> fmt::Result {
+    if caption.text().is_empty() {
+        Ok(())
+    } else {
+        write!(f, " '{}'", caption.text())
+    }
+}
+
+fn write_user_ids(f: &mut fmt::Formatter, user_ids: &[UserId]) -> fmt::Result {
+    for (i, user_id) in user_ids.iter().enumerate() {
+        if i > 0 {
+            f.write_str(", ")?;
+        }
+        write!(f, "{}", user_id)?;
+    }
+    Ok(())
+}
+
+impl MessageContent {
+    /// True for service messages, e.g. pinned message or new chat members, which are rendered as actions of the sender
+    pub fn is_service(&self) -> bool {
+        matches!(
+            self,
+            MessageContent::MessageBasicGroupChatCreate(_)
+                | MessageContent::MessageChatAddMembers(_)
+                | MessageContent::MessageChatChangePhoto(_)
+                | MessageContent::MessageChatChangeTitle(_)
+                | MessageContent::MessageChatDeleteMember(_)
+                | MessageContent::MessageChatDeletePhoto(_)
+                | MessageContent::MessageChatJoinByLink(_)
+                | MessageContent::MessageChatSetTtl(_)
+                | MessageContent::MessageChatUpgradeFrom(_)
+                | MessageContent::MessageChatUpgradeTo(_)
+                | MessageContent::MessageContactRegistered(_)
+                | MessageContent::MessageCustomServiceAction(_)
+                | MessageContent::MessageGameScore(_)
+                | MessageContent::MessagePassportDataReceived(_)
+                | MessageContent::MessagePassportDataSent(_)
+                | MessageContent::MessagePaymentSuccessful(_)
+                | MessageContent::MessagePaymentSuccessfulBot(_)
+                | MessageContent::MessagePinMessage(_)
+                | MessageContent::MessageScreenshotTaken(_)
+                | MessageContent::MessageSupergroupChatCreate(_)
+                | MessageContent::MessageWebsiteConnected(_)
+        )
+    }
+}
+
+/// Text of text messages, kind and caption of media, action of service messages: `photo (1280x720) 'caption'`, `pinned a message`
+impl fmt::Display for MessageContent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MessageContent::MessageText(t) => f.write_str(t.text().text()),
+            MessageContent::MessageAnimation(t) => {
+                let animation = t.animation();
+                write!(
+                    f,
+                    "animation ({}x{})",
+                    animation.width(),
+                    animation.height()
+                )?;
+                write_caption(f, t.caption())
+            }
+            MessageContent::MessageAudio(t) => {
+                let audio = t.audio();
+                match (audio.performer().is_empty(), audio.title().is_empty()) {
+                    (false, false) => {
+                        write!(f, "audio '{} - {}'", audio.performer(), audio.title())
+                    }
+                    (true, false) => write!(f, "audio '{}'", audio.title()),
+                    _ => write!(f, "audio '{}'", audio.file_name()),
+                }?;
+                write_caption(f, t.caption())
+            }
+            MessageContent::MessageDocument(t) => {
+                write!(f, "document '{}'", t.document().file_name())?;
+                write_caption(f, t.caption())
+            }
+            MessageContent::MessagePhoto(t) => {
+                f.write_str("photo")?;
+                let largest = t
+                    .photo()
+                    .sizes()
+                    .iter()
+                    .max_by_key(|size| i64::from(size.width()) * i64::from(size.height()));
+                if let Some(size) = largest {
+                    write!(f, " ({}x{})", size.width(), size.height())?;
+                }
+                write_caption(f, t.caption())
+            }
+            MessageContent::MessageSticker(t) => write!(f, "sticker {}", t.sticker().emoji()),
+            MessageContent::MessageVideo(t) => {
+                let video = t.video();
+                write!(
+                    f,
+                    "video ({}x{}, {} s)",
+                    video.width(),
+                    video.height(),
+                    video.duration()
+                )?;
+                write_caption(f, t.caption())
+            }
+            MessageContent::MessageVideoNote(t) => {
+                write!(f, "video message ({} s)", t.video_note().duration())
+            }
+            MessageContent::MessageVoiceNote(t) => {
+                write!(f, "voice message ({} s)", t.voice_note().duration())?;
+                write_caption(f, t.caption())
+            }
+            MessageContent::MessageLocation(t) => write!(
+                f,
+                "location ({}, {})",
+                t.location().latitude(),
+                t.location().longitude()
+            ),
+            MessageContent::MessageVenue(t) => {
+                write!(f, "venue '{}', {}", t.venue().title(), t.venue().address())
+            }
+            MessageContent::MessageContact(t) => {
+                let contact = t.contact();
+                let name = format!("{} {}", contact.first_name(), contact.last_name());
+                write!(f, "contact '{}', {}", name.trim(), contact.phone_number())
+            }
+            MessageContent::MessagePoll(t) => write!(f, "poll '{}'", t.poll().question()),
+            MessageContent::MessageGame(t) => write!(f, "game '{}'", t.game().title()),
+            MessageContent::MessageInvoice(t) => write!(f, "invoice '{}'", t.title()),
+            MessageContent::MessageCall(t) => write!(f, "call ({} s)", t.duration()),
+            MessageContent::MessageExpiredPhoto(_) => f.write_str("expired photo"),
+            MessageContent::MessageExpiredVideo(_) => f.write_str("expired video"),
+            MessageContent::MessageUnsupported(_) => f.write_str("unsupported message"),
+
+            MessageContent::MessageBasicGroupChatCreate(t) => {
+                write!(f, "created the group '{}'", t.title())
+            }
+            MessageContent::MessageSupergroupChatCreate(t) => {
+                write!(f, "created the supergroup '{}'", t.title())
+            }
+            MessageContent::MessageChatAddMembers(t) => {
+                f.write_str(if t.member_user_ids().len() == 1 {
+                    "added user "
+                } else {
+                    "added users "
+                })?;
+                write_user_ids(f, t.member_user_ids())
+            }
+            MessageContent::MessageChatJoinByLink(_) => {
+                f.write_str("joined the chat via invite link")
+            }
+            MessageContent::MessageChatDeleteMember(t) => write!(f, "removed user {}", t.user_id()),
+            MessageContent::MessageChatChangeTitle(t) => {
+                write!(f, "changed the chat title to '{}'", t.title())
+            }
+            MessageContent::MessageChatChangePhoto(_) => f.write_str("changed the chat photo"),
+            MessageContent::MessageChatDeletePhoto(_) => f.write_str("removed the chat photo"),
+            MessageContent::MessageChatUpgradeTo(_) => {
+                f.write_str("upgraded the group to a supergroup")
+            }
+            MessageContent::MessageChatUpgradeFrom(t) => {
+                write!(f, "created the supergroup '{}' from a group", t.title())
+            }
+            MessageContent::MessageChatSetTtl(t) if t.ttl() == 0 => {
+                f.write_str("disabled the self-destruct timer")
+            }
+            MessageContent::MessageChatSetTtl(t) => {
+                write!(f, "set the self-destruct timer to {} s", t.ttl())
+            }
+            MessageContent::MessagePinMessage(_) => f.write_str("pinned a message"),
+            MessageContent::MessageScreenshotTaken(_) => f.write_str("took a screenshot"),
+            MessageContent::MessageCustomServiceAction(t) => f.write_str(t.text()),
+            MessageContent::MessageGameScore(t) => write!(f, "scored {} in a game", t.score()),
+            MessageContent::MessagePaymentSuccessful(_)
+            | MessageContent::MessagePaymentSuccessfulBot(_) => f.write_str("made a payment"),
+            MessageContent::MessageContactRegistered(_) => f.write_str("joined Telegram"),
+            MessageContent::MessageWebsiteConnected(t) => {
+                write!(f, "logged in to {}", t.domain_name())
+            }
+            MessageContent::MessagePassportDataSent(_)
+            | MessageContent::MessagePassportDataReceived(_) => {
+                f.write_str("shared Telegram Passport data")
+            }
+        }
+    }
+}
+
+// message with optionally known sender and chat
+struct MessageSummary<'a> {
+    message: &'a Message,
+    sender: Option<&'a User>,
+    chat: Option<&'a Chat>,
+}
+
+impl<'a> fmt::Display for MessageSummary<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let message = self.message;
+        f.write_str("[")?;
+        write_date(f, message.date())?;
+        f.write_str("] ")?;
+        // channel posts have no sender user
+        if message.sender_user_id() != 0 {
+            match self.sender {
+                Some(sender) => write!(f, "{} in ", sender)?,
+                None => write!(f, "user {} in ", message.sender_user_id())?,
+            }
+        }
+        match self.chat {
+            Some(chat) => write!(f, "{}", chat)?,
+            None => write!(f, "chat {}", message.chat_id())?,
+        }
+        match message.content() {
+            MessageContent::MessageChatDeleteMember(t)
+                if t.user_id() == message.sender_user_id() =>
+            {
+                f.write_str(" left the chat")
+            }
+            content if content.is_service() => write!(f, " {}", content),
+            content => write!(f, ": {}", content),
+        }
+    }
+}
+
+impl Message {
+    /// Date, sender, chat and content of the message with names of sender and chat, if they are known:
+    /// `[2020-10-01 12:00] Alice in Rust Chat: photo (1280x720) 'caption'`, `[2020-10-01 12:05] Bob in Rust Chat pinned a message`.
+    /// Date is in UTC
+    pub fn summary(&self, sender: Option<&User>, chat: Option<&Chat>) -> String {
+        MessageSummary {
+            message: self,
+            sender,
+            chat,
+        }
+        .to_string()
+    }
+}
+
+/// [Message::summary](crate::types::Message::summary) with identifiers of sender and chat
+impl fmt::Display for Message {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        MessageSummary {
+            message: self,
+            sender: None,
+            chat: None,
+        }
+        .fmt(f)
+    }
+}
+
+/// Chat title
+impl fmt::Display for Chat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.title().is_empty() {
+            write!(f, "chat {}", self.id())
+        } else {
+            f.write_str(self.title())
+        }
+    }
+}
+
+/// Full name of the user, username if name is empty
+impl fmt::Display for User {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = format!("{} {}", self.first_name(), self.last_name());
+        let name = name.trim();
+        if !name.is_empty() {
+            f.write_str(name)
+        } else if !self.username().is_empty() {
+            write!(f, "@{}", self.username())
+        } else {
+            write!(f, "user {}", self.id())
+        }
+    }
+}
+
+/// Identifier, size and download state: `file 5 (1000 bytes) at /path`, `file 5 (250/1000 bytes downloaded)`
+impl fmt::Display for File {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let size = if self.size() > 0 {
+            self.size()
+        } else {
+            self.expected_size()
+        };
+        let local = self.local();
+        if local.is_downloading_completed() {
+            write!(f, "file {} ({} bytes) at {}", self.id(), size, local.path())
+        } else if local.is_downloading_active() {
+            write!(
+                f,
+                "file {} ({}/{} bytes downloaded)",
+                self.id(),
+                local.downloaded_size(),
+                size
+            )
+        } else {
+            write!(f, "file {} ({} bytes)", self.id(), size)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::*;
+
+    fn message(content: MessageContent) -> Message {
+        Message::builder()
+            .date(1601553600)
+            .sender_user_id(5)
+            .chat_id(-7)
+            .content(content)
+            .build()
+    }
+
+    #[test]
+    fn test_message() {
+        let photo = MessageContent::MessagePhoto(
+            MessagePhoto::builder()
+                .photo(
+                    Photo::builder()
+                        .sizes(vec![
+                            PhotoSize::builder().width(320).height(180).build(),
+                            PhotoSize::builder().width(1280).height(720).build(),
+                        ])
+                        .build(),
+                )
+                .caption(FormattedText::builder().text("caption").build())
+                .build(),
+        );
+        let alice = User::builder().id(5).first_name("Alice").build();
+        let chat = Chat::builder().id(-7).title("Rust Chat").build();
+        assert_eq!(
+            message(photo.clone()).summary(Some(&alice), Some(&chat)),
+            "[2020-10-01 12:00] Alice in Rust Chat: photo (1280x720) 'caption'"
+        );
+        assert_eq!(
+            message(photo).to_string(),
+            "[2020-10-01 12:00] user 5 in chat -7: photo (1280x720) 'caption'"
+        );
+
+        let pin = MessageContent::MessagePinMessage(MessagePinMessage::builder().build());
+        assert_eq!(
+            message(pin).summary(Some(&alice), Some(&chat)),
+            "[2020-10-01 12:00] Alice in Rust Chat pinned a message"
+        );
+        let leave = MessageContent::MessageChatDeleteMember(
+            MessageChatDeleteMember::builder().user_id(5).build(),
+        );
+        assert_eq!(
+            message(leave).summary(None, Some(&chat)),
+            "[2020-10-01 12:00] user 5 in Rust Chat left the chat"
+        );
+    }
+
+    #[test]
+    fn test_content() {
+        let add: MessageContent =
+            serde_json::from_str(r#"{"@type":"messageChatAddMembers","member_user_ids":[1,2]}"#)
+                .unwrap();
+        assert_eq!(add.to_string(), "added users 1, 2");
+        assert!(add.is_service());
+
+        let text = MessageContent::MessageText(
+            MessageText::builder()
+                .text(FormattedText::builder().text("hello").build())
+                .build(),
+        );
+        assert_eq!(text.to_string(), "hello");
+        assert!(!text.is_service());
+    }
+
+    #[test]
+    fn test_user_and_file() {
+        assert_eq!(
+            User::builder().id(1).first_name("Bob").build().to_string(),
+            "Bob"
+        );
+        assert_eq!(
+            User::builder().id(1).username("bob").build().to_string(),
+            "@bob"
+        );
+        let file = File::builder()
+            .id(3)
+            .size(1000)
+            .local(
+                LocalFile::builder()
+                    .is_downloading_active(true)
+                    .downloaded_size(250)
+                    .build(),
+            )
+            .build();
+        assert_eq!(file.to_string(), "file 3 (250/1000 bytes downloaded)");
+    }
+
+    #[test]
+    fn test_date() {
+        let message = Message::builder().date(0).build();
+        assert!(message.to_string().starts_with("[1970-01-01 00:00]"));
+        let message = Message::builder().date(951_825_600).build();
+        assert!(message.to_string().starts_with("[2000-02-29 12:00]"));
+    }
+}
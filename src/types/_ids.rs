@@ -0,0 +1,176 @@
+//! Identifiers of TDlib objects.
+//!
+//! Identifiers are distinct types serialized as plain numbers, which convert into each other where
+//! TDlib relates them: user, basic group, supergroup and secret chat identifiers map to identifiers
+//! of their chats.
+use std::{cmp::Ordering, fmt};
+
+macro_rules! rtd_id {
+    ($(#[$doc:meta] $name:ident($type_:ty);)*) => {$(
+        #[$doc]
+        #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+        #[serde(transparent)]
+        pub struct $name(pub $type_);
+
+        impl From<$type_> for $name {
+            fn from(id: $type_) -> Self {
+                $name(id)
+            }
+        }
+
+        impl From<$name> for $type_ {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+
+        impl PartialEq<$type_> for $name {
+            fn eq(&self, other: &$type_) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl PartialOrd<$type_> for $name {
+            fn partial_cmp(&self, other: &$type_) -> Option<Ordering> {
+                self.0.partial_cmp(other)
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt::Display::fmt(&self.0, f)
+            }
+        }
+    )*};
+}
+
+rtd_id! {
+    /// Basic group identifier
+    BasicGroupId(i32);
+    /// Call identifier, TDlib `CallId` object wraps it
+    CallIdentifier(i32);
+    /// Chat identifier
+    ChatId(i64);
+    /// File identifier
+    FileId(i32);
+    /// Message identifier
+    MessageId(i64);
+    /// Secret chat identifier
+    SecretChatId(i32);
+    /// Supergroup or channel identifier
+    SupergroupId(i32);
+    /// User identifier
+    UserId(i32);
+}
+
+// chat identifiers of supergroups are below it, TDlib `ZERO_CHANNEL_ID`
+const ZERO_SUPERGROUP_CHAT_ID: i64 = -1_000_000_000_000;
+// chat identifiers of secret chats are around it, TDlib `ZERO_SECRET_ID`
+const ZERO_SECRET_CHAT_ID: i64 = -2_000_000_000_000;
+
+impl From<UserId> for ChatId {
+    /// Identifier of private chat with the user
+    fn from(id: UserId) -> Self {
+        ChatId(i64::from(id.0))
+    }
+}
+
+impl From<BasicGroupId> for ChatId {
+    /// Identifier of the basic group chat
+    fn from(id: BasicGroupId) -> Self {
+        ChatId(-i64::from(id.0))
+    }
+}
+
+impl From<SupergroupId> for ChatId {
+    /// Identifier of the supergroup or channel chat
+    fn from(id: SupergroupId) -> Self {
+        ChatId(ZERO_SUPERGROUP_CHAT_ID - i64::from(id.0))
+    }
+}
+
+impl From<SecretChatId> for ChatId {
+    /// Identifier of the secret chat
+    fn from(id: SecretChatId) -> Self {
+        ChatId(ZERO_SECRET_CHAT_ID + i64::from(id.0))
+    }
+}
+
+impl ChatId {
+    /// Identifier of the user, if this is a private chat
+    pub fn user_id(self) -> Option<UserId> {
+        if self.0 > 0 && self.0 <= i64::from(i32::MAX) {
+            Some(UserId(self.0 as i32))
+        } else {
+            None
+        }
+    }
+
+    /// Identifier of the basic group, if this is a basic group chat
+    pub fn basic_group_id(self) -> Option<BasicGroupId> {
+        if self.0 < 0 && self.0 >= -i64::from(i32::MAX) {
+            Some(BasicGroupId(-self.0 as i32))
+        } else {
+            None
+        }
+    }
+
+    /// Identifier of the supergroup, if this is a supergroup or channel chat
+    pub fn supergroup_id(self) -> Option<SupergroupId> {
+        let id = ZERO_SUPERGROUP_CHAT_ID - self.0;
+        if id > 0 && id <= i64::from(i32::MAX) {
+            Some(SupergroupId(id as i32))
+        } else {
+            None
+        }
+    }
+
+    /// Identifier of the secret chat, if this is a secret chat
+    pub fn secret_chat_id(self) -> Option<SecretChatId> {
+        let id = self.0 - ZERO_SECRET_CHAT_ID;
+        if id >= i64::from(i32::MIN) && id <= i64::from(i32::MAX) {
+            Some(SecretChatId(id as i32))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chat_id_conversions() {
+        let chat_id = ChatId::from(UserId(777));
+        assert_eq!(chat_id, 777);
+        assert_eq!(chat_id.user_id(), Some(UserId(777)));
+        assert_eq!(chat_id.basic_group_id(), None);
+
+        let chat_id = ChatId::from(BasicGroupId(42));
+        assert_eq!(chat_id, -42);
+        assert_eq!(chat_id.basic_group_id(), Some(BasicGroupId(42)));
+        assert_eq!(chat_id.user_id(), None);
+
+        let chat_id = ChatId::from(SupergroupId(1_234_567));
+        assert_eq!(chat_id, -1_000_001_234_567);
+        assert_eq!(chat_id.supergroup_id(), Some(SupergroupId(1_234_567)));
+        assert_eq!(chat_id.basic_group_id(), None);
+
+        for secret_chat_id in &[-5, 5] {
+            let chat_id = ChatId::from(SecretChatId(*secret_chat_id));
+            assert_eq!(
+                chat_id.secret_chat_id(),
+                Some(SecretChatId(*secret_chat_id))
+            );
+            assert_eq!(chat_id.supergroup_id(), None);
+        }
+    }
+
+    #[test]
+    fn test_serialization() {
+        let chat_id: ChatId = serde_json::from_str("-1000000000042").unwrap();
+        assert_eq!(chat_id.supergroup_id(), Some(SupergroupId(42)));
+        assert_eq!(serde_json::to_string(&UserId(1)).unwrap(), "1");
+    }
+}
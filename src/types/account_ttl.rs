@@ -1,18 +1,18 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains information about the period of inactivity after which the current user's account will automatically be deleted
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "accountTtl")]
 pub struct AccountTtl {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Number of days of inactivity before the account will be flagged for deletion; should range from 30-366 days
-    days: i64,
+    days: i32,
 }
 
 impl RObject for AccountTtl {
@@ -29,33 +29,76 @@ impl RObject for AccountTtl {
     }
 }
 
+impl PartialEq for AccountTtl {
+    fn eq(&self, other: &Self) -> bool {
+        self.days == other.days
+    }
+}
+
+impl Eq for AccountTtl {}
+
+impl std::hash::Hash for AccountTtl {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.days, state);
+    }
+}
+
 impl AccountTtl {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDAccountTtlBuilder {
-        let mut inner = AccountTtl::default();
-        inner.td_name = "accountTtl".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDAccountTtlBuilder { inner }
+        RTDAccountTtlBuilder {
+            inner: AccountTtl::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDAccountTtlBuilder {
+        RTDAccountTtlBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDAccountTtlBuilder {
+        RTDAccountTtlBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn days(&self) -> i64 {
+    pub fn days(&self) -> i32 {
         self.days
     }
+
+    pub fn with_days(mut self, days: i32) -> Self {
+        self.days = days;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDAccountTtlBuilder {
     inner: AccountTtl,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDAccountTtlBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> AccountTtl {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<AccountTtl> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("AccountTtl", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn days(&mut self, days: i64) -> &mut Self {
+    pub fn days(&mut self, days: i32) -> &mut Self {
         self.inner.days = days;
         self
     }
@@ -72,3 +115,14 @@ impl AsRef<AccountTtl> for RTDAccountTtlBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(AccountTtl::builder().days(i32::MAX).build());
+    }
+}
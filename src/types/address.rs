@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Describes an address
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "address")]
 pub struct Address {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// A two-letter ISO 3166-1 alpha-2 country code
     country_code: String,
@@ -39,15 +39,60 @@ impl RObject for Address {
     }
 }
 
+impl PartialEq for Address {
+    fn eq(&self, other: &Self) -> bool {
+        self.country_code == other.country_code
+            && self.state == other.state
+            && self.city == other.city
+            && self.street_line1 == other.street_line1
+            && self.street_line2 == other.street_line2
+            && self.postal_code == other.postal_code
+    }
+}
+
+impl Eq for Address {}
+
+impl std::hash::Hash for Address {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.country_code, state);
+        std::hash::Hash::hash(&self.state, state);
+        std::hash::Hash::hash(&self.city, state);
+        std::hash::Hash::hash(&self.street_line1, state);
+        std::hash::Hash::hash(&self.street_line2, state);
+        std::hash::Hash::hash(&self.postal_code, state);
+    }
+}
+
 impl Address {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDAddressBuilder {
-        let mut inner = Address::default();
-        inner.td_name = "address".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDAddressBuilder { inner }
+        RTDAddressBuilder {
+            inner: Address::default(),
+            missing: vec![
+                "country_code",
+                "state",
+                "city",
+                "street_line1",
+                "street_line2",
+                "postal_code",
+            ],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDAddressBuilder {
+        RTDAddressBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDAddressBuilder {
+        RTDAddressBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn country_code(&self) -> &String {
@@ -73,45 +118,115 @@ impl Address {
     pub fn postal_code(&self) -> &String {
         &self.postal_code
     }
+
+    pub fn into_country_code(self) -> String {
+        self.country_code
+    }
+
+    pub fn into_state(self) -> String {
+        self.state
+    }
+
+    pub fn into_city(self) -> String {
+        self.city
+    }
+
+    pub fn into_street_line1(self) -> String {
+        self.street_line1
+    }
+
+    pub fn into_street_line2(self) -> String {
+        self.street_line2
+    }
+
+    pub fn into_postal_code(self) -> String {
+        self.postal_code
+    }
+
+    pub fn with_country_code<T: AsRef<str>>(mut self, country_code: T) -> Self {
+        self.country_code = country_code.as_ref().to_string();
+        self
+    }
+
+    pub fn with_state<T: AsRef<str>>(mut self, state: T) -> Self {
+        self.state = state.as_ref().to_string();
+        self
+    }
+
+    pub fn with_city<T: AsRef<str>>(mut self, city: T) -> Self {
+        self.city = city.as_ref().to_string();
+        self
+    }
+
+    pub fn with_street_line1<T: AsRef<str>>(mut self, street_line1: T) -> Self {
+        self.street_line1 = street_line1.as_ref().to_string();
+        self
+    }
+
+    pub fn with_street_line2<T: AsRef<str>>(mut self, street_line2: T) -> Self {
+        self.street_line2 = street_line2.as_ref().to_string();
+        self
+    }
+
+    pub fn with_postal_code<T: AsRef<str>>(mut self, postal_code: T) -> Self {
+        self.postal_code = postal_code.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDAddressBuilder {
     inner: Address,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDAddressBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> Address {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<Address> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("Address", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn country_code<T: AsRef<str>>(&mut self, country_code: T) -> &mut Self {
         self.inner.country_code = country_code.as_ref().to_string();
+        self.missing.retain(|f| *f != "country_code");
         self
     }
 
     pub fn state<T: AsRef<str>>(&mut self, state: T) -> &mut Self {
         self.inner.state = state.as_ref().to_string();
+        self.missing.retain(|f| *f != "state");
         self
     }
 
     pub fn city<T: AsRef<str>>(&mut self, city: T) -> &mut Self {
         self.inner.city = city.as_ref().to_string();
+        self.missing.retain(|f| *f != "city");
         self
     }
 
     pub fn street_line1<T: AsRef<str>>(&mut self, street_line1: T) -> &mut Self {
         self.inner.street_line1 = street_line1.as_ref().to_string();
+        self.missing.retain(|f| *f != "street_line1");
         self
     }
 
     pub fn street_line2<T: AsRef<str>>(&mut self, street_line2: T) -> &mut Self {
         self.inner.street_line2 = street_line2.as_ref().to_string();
+        self.missing.retain(|f| *f != "street_line2");
         self
     }
 
     pub fn postal_code<T: AsRef<str>>(&mut self, postal_code: T) -> &mut Self {
         self.inner.postal_code = postal_code.as_ref().to_string();
+        self.missing.retain(|f| *f != "postal_code");
         self
     }
 }
@@ -127,3 +242,23 @@ impl AsRef<Address> for RTDAddressBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            Address::builder()
+                .country_code("text")
+                .state("text")
+                .city("text")
+                .street_line1("text")
+                .street_line2("text")
+                .postal_code("text")
+                .build(),
+        );
+    }
+}
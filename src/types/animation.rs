@@ -1,22 +1,22 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Describes an animation file. The animation must be encoded in GIF or MPEG4 format
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "animation")]
 pub struct Animation {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Duration of the animation, in seconds; as defined by the sender
-    duration: i64,
+    duration: i32,
     /// Width of the animation
-    width: i64,
+    width: i32,
     /// Height of the animation
-    height: i64,
+    height: i32,
     /// Original name of the file; as defined by the sender
     file_name: String,
     /// MIME type of the file, usually "image/gif" or "video/mp4"
@@ -43,26 +43,75 @@ impl RObject for Animation {
     }
 }
 
+impl PartialEq for Animation {
+    fn eq(&self, other: &Self) -> bool {
+        self.duration == other.duration
+            && self.width == other.width
+            && self.height == other.height
+            && self.file_name == other.file_name
+            && self.mime_type == other.mime_type
+            && self.minithumbnail == other.minithumbnail
+            && self.thumbnail == other.thumbnail
+            && self.animation == other.animation
+    }
+}
+
+impl Eq for Animation {}
+
+impl std::hash::Hash for Animation {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.duration, state);
+        std::hash::Hash::hash(&self.width, state);
+        std::hash::Hash::hash(&self.height, state);
+        std::hash::Hash::hash(&self.file_name, state);
+        std::hash::Hash::hash(&self.mime_type, state);
+        std::hash::Hash::hash(&self.minithumbnail, state);
+        std::hash::Hash::hash(&self.thumbnail, state);
+        std::hash::Hash::hash(&self.animation, state);
+    }
+}
+
 impl Animation {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDAnimationBuilder {
-        let mut inner = Animation::default();
-        inner.td_name = "animation".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDAnimationBuilder { inner }
+        RTDAnimationBuilder {
+            inner: Animation::default(),
+            missing: vec![
+                "duration",
+                "width",
+                "height",
+                "file_name",
+                "mime_type",
+                "animation",
+            ],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDAnimationBuilder {
+        RTDAnimationBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDAnimationBuilder {
+        RTDAnimationBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn duration(&self) -> i64 {
+    pub fn duration(&self) -> i32 {
         self.duration
     }
 
-    pub fn width(&self) -> i64 {
+    pub fn width(&self) -> i32 {
         self.width
     }
 
-    pub fn height(&self) -> i64 {
+    pub fn height(&self) -> i32 {
         self.height
     }
 
@@ -85,40 +134,115 @@ impl Animation {
     pub fn animation(&self) -> &File {
         &self.animation
     }
+
+    pub fn into_file_name(self) -> String {
+        self.file_name
+    }
+
+    pub fn into_mime_type(self) -> String {
+        self.mime_type
+    }
+
+    pub fn into_minithumbnail(self) -> Option<Minithumbnail> {
+        self.minithumbnail
+    }
+
+    pub fn into_thumbnail(self) -> Option<PhotoSize> {
+        self.thumbnail
+    }
+
+    pub fn into_animation(self) -> File {
+        self.animation
+    }
+
+    pub fn with_duration(mut self, duration: i32) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    pub fn with_width(mut self, width: i32) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn with_height(mut self, height: i32) -> Self {
+        self.height = height;
+        self
+    }
+
+    pub fn with_file_name<T: AsRef<str>>(mut self, file_name: T) -> Self {
+        self.file_name = file_name.as_ref().to_string();
+        self
+    }
+
+    pub fn with_mime_type<T: AsRef<str>>(mut self, mime_type: T) -> Self {
+        self.mime_type = mime_type.as_ref().to_string();
+        self
+    }
+
+    pub fn with_minithumbnail<T: AsRef<Minithumbnail>>(mut self, minithumbnail: T) -> Self {
+        self.minithumbnail = Some(minithumbnail.as_ref().clone());
+        self
+    }
+
+    pub fn with_thumbnail<T: AsRef<PhotoSize>>(mut self, thumbnail: T) -> Self {
+        self.thumbnail = Some(thumbnail.as_ref().clone());
+        self
+    }
+
+    pub fn with_animation<T: AsRef<File>>(mut self, animation: T) -> Self {
+        self.animation = animation.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDAnimationBuilder {
     inner: Animation,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDAnimationBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> Animation {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<Animation> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("Animation", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn duration(&mut self, duration: i64) -> &mut Self {
+    pub fn duration(&mut self, duration: i32) -> &mut Self {
         self.inner.duration = duration;
+        self.missing.retain(|f| *f != "duration");
         self
     }
 
-    pub fn width(&mut self, width: i64) -> &mut Self {
+    pub fn width(&mut self, width: i32) -> &mut Self {
         self.inner.width = width;
+        self.missing.retain(|f| *f != "width");
         self
     }
 
-    pub fn height(&mut self, height: i64) -> &mut Self {
+    pub fn height(&mut self, height: i32) -> &mut Self {
         self.inner.height = height;
+        self.missing.retain(|f| *f != "height");
         self
     }
 
     pub fn file_name<T: AsRef<str>>(&mut self, file_name: T) -> &mut Self {
         self.inner.file_name = file_name.as_ref().to_string();
+        self.missing.retain(|f| *f != "file_name");
         self
     }
 
     pub fn mime_type<T: AsRef<str>>(&mut self, mime_type: T) -> &mut Self {
         self.inner.mime_type = mime_type.as_ref().to_string();
+        self.missing.retain(|f| *f != "mime_type");
         self
     }
 
@@ -134,6 +258,7 @@ impl RTDAnimationBuilder {
 
     pub fn animation<T: AsRef<File>>(&mut self, animation: T) -> &mut Self {
         self.inner.animation = animation.as_ref().clone();
+        self.missing.retain(|f| *f != "animation");
         self
     }
 }
@@ -149,3 +274,50 @@ impl AsRef<Animation> for RTDAnimationBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            Animation::builder()
+                .duration(i32::MAX)
+                .width(i32::MAX)
+                .height(i32::MAX)
+                .file_name("text")
+                .mime_type("text")
+                .animation(
+                    File::builder()
+                        .id(i32::MAX)
+                        .size(i32::MAX)
+                        .expected_size(i32::MAX)
+                        .local(
+                            LocalFile::builder()
+                                .path("text")
+                                .can_be_downloaded(true)
+                                .can_be_deleted(true)
+                                .is_downloading_active(true)
+                                .is_downloading_completed(true)
+                                .download_offset(i32::MAX)
+                                .downloaded_prefix_size(i32::MAX)
+                                .downloaded_size(i32::MAX)
+                                .build(),
+                        )
+                        .remote(
+                            RemoteFile::builder()
+                                .id("text")
+                                .unique_id("text")
+                                .is_uploading_active(true)
+                                .is_uploading_completed(true)
+                                .uploaded_size(i32::MAX)
+                                .build(),
+                        )
+                        .build(),
+                )
+                .build(),
+        );
+    }
+}
@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Represents a list of animations
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "animations")]
 pub struct Animations {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// List of animations
     animations: Vec<Animation>,
@@ -29,34 +29,82 @@ impl RObject for Animations {
     }
 }
 
+impl PartialEq for Animations {
+    fn eq(&self, other: &Self) -> bool {
+        self.animations == other.animations
+    }
+}
+
+impl Eq for Animations {}
+
+impl std::hash::Hash for Animations {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.animations, state);
+    }
+}
+
 impl Animations {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDAnimationsBuilder {
-        let mut inner = Animations::default();
-        inner.td_name = "animations".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDAnimationsBuilder { inner }
+        RTDAnimationsBuilder {
+            inner: Animations::default(),
+            missing: vec!["animations"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDAnimationsBuilder {
+        RTDAnimationsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDAnimationsBuilder {
+        RTDAnimationsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn animations(&self) -> &Vec<Animation> {
         &self.animations
     }
+
+    pub fn into_animations(self) -> Vec<Animation> {
+        self.animations
+    }
+
+    pub fn with_animations(mut self, animations: Vec<Animation>) -> Self {
+        self.animations = animations;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDAnimationsBuilder {
     inner: Animations,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDAnimationsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> Animations {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<Animations> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("Animations", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn animations(&mut self, animations: Vec<Animation>) -> &mut Self {
         self.inner.animations = animations;
+        self.missing.retain(|f| *f != "animations");
         self
     }
 }
@@ -72,3 +120,14 @@ impl AsRef<Animations> for RTDAnimationsBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(Animations::builder().animations(vec![]).build());
+    }
+}
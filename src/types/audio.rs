@@ -1,18 +1,18 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Describes an audio file. Audio is usually in MP3 or M4A format
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "audio")]
 pub struct Audio {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Duration of the audio, in seconds; as defined by the sender
-    duration: i64,
+    duration: i32,
     /// Title of the audio; as defined by the sender
     title: String,
     /// Performer of the audio; as defined by the sender
@@ -43,18 +43,67 @@ impl RObject for Audio {
     }
 }
 
+impl PartialEq for Audio {
+    fn eq(&self, other: &Self) -> bool {
+        self.duration == other.duration
+            && self.title == other.title
+            && self.performer == other.performer
+            && self.file_name == other.file_name
+            && self.mime_type == other.mime_type
+            && self.album_cover_minithumbnail == other.album_cover_minithumbnail
+            && self.album_cover_thumbnail == other.album_cover_thumbnail
+            && self.audio == other.audio
+    }
+}
+
+impl Eq for Audio {}
+
+impl std::hash::Hash for Audio {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.duration, state);
+        std::hash::Hash::hash(&self.title, state);
+        std::hash::Hash::hash(&self.performer, state);
+        std::hash::Hash::hash(&self.file_name, state);
+        std::hash::Hash::hash(&self.mime_type, state);
+        std::hash::Hash::hash(&self.album_cover_minithumbnail, state);
+        std::hash::Hash::hash(&self.album_cover_thumbnail, state);
+        std::hash::Hash::hash(&self.audio, state);
+    }
+}
+
 impl Audio {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDAudioBuilder {
-        let mut inner = Audio::default();
-        inner.td_name = "audio".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDAudioBuilder { inner }
+        RTDAudioBuilder {
+            inner: Audio::default(),
+            missing: vec![
+                "duration",
+                "title",
+                "performer",
+                "file_name",
+                "mime_type",
+                "audio",
+            ],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDAudioBuilder {
+        RTDAudioBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDAudioBuilder {
+        RTDAudioBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn duration(&self) -> i64 {
+    pub fn duration(&self) -> i32 {
         self.duration
     }
 
@@ -85,40 +134,129 @@ impl Audio {
     pub fn audio(&self) -> &File {
         &self.audio
     }
+
+    pub fn into_title(self) -> String {
+        self.title
+    }
+
+    pub fn into_performer(self) -> String {
+        self.performer
+    }
+
+    pub fn into_file_name(self) -> String {
+        self.file_name
+    }
+
+    pub fn into_mime_type(self) -> String {
+        self.mime_type
+    }
+
+    pub fn into_album_cover_minithumbnail(self) -> Option<Minithumbnail> {
+        self.album_cover_minithumbnail
+    }
+
+    pub fn into_album_cover_thumbnail(self) -> Option<PhotoSize> {
+        self.album_cover_thumbnail
+    }
+
+    pub fn into_audio(self) -> File {
+        self.audio
+    }
+
+    pub fn with_duration(mut self, duration: i32) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    pub fn with_title<T: AsRef<str>>(mut self, title: T) -> Self {
+        self.title = title.as_ref().to_string();
+        self
+    }
+
+    pub fn with_performer<T: AsRef<str>>(mut self, performer: T) -> Self {
+        self.performer = performer.as_ref().to_string();
+        self
+    }
+
+    pub fn with_file_name<T: AsRef<str>>(mut self, file_name: T) -> Self {
+        self.file_name = file_name.as_ref().to_string();
+        self
+    }
+
+    pub fn with_mime_type<T: AsRef<str>>(mut self, mime_type: T) -> Self {
+        self.mime_type = mime_type.as_ref().to_string();
+        self
+    }
+
+    pub fn with_album_cover_minithumbnail<T: AsRef<Minithumbnail>>(
+        mut self,
+        album_cover_minithumbnail: T,
+    ) -> Self {
+        self.album_cover_minithumbnail = Some(album_cover_minithumbnail.as_ref().clone());
+        self
+    }
+
+    pub fn with_album_cover_thumbnail<T: AsRef<PhotoSize>>(
+        mut self,
+        album_cover_thumbnail: T,
+    ) -> Self {
+        self.album_cover_thumbnail = Some(album_cover_thumbnail.as_ref().clone());
+        self
+    }
+
+    pub fn with_audio<T: AsRef<File>>(mut self, audio: T) -> Self {
+        self.audio = audio.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDAudioBuilder {
     inner: Audio,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDAudioBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> Audio {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<Audio> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("Audio", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn duration(&mut self, duration: i64) -> &mut Self {
+    pub fn duration(&mut self, duration: i32) -> &mut Self {
         self.inner.duration = duration;
+        self.missing.retain(|f| *f != "duration");
         self
     }
 
     pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
         self.inner.title = title.as_ref().to_string();
+        self.missing.retain(|f| *f != "title");
         self
     }
 
     pub fn performer<T: AsRef<str>>(&mut self, performer: T) -> &mut Self {
         self.inner.performer = performer.as_ref().to_string();
+        self.missing.retain(|f| *f != "performer");
         self
     }
 
     pub fn file_name<T: AsRef<str>>(&mut self, file_name: T) -> &mut Self {
         self.inner.file_name = file_name.as_ref().to_string();
+        self.missing.retain(|f| *f != "file_name");
         self
     }
 
     pub fn mime_type<T: AsRef<str>>(&mut self, mime_type: T) -> &mut Self {
         self.inner.mime_type = mime_type.as_ref().to_string();
+        self.missing.retain(|f| *f != "mime_type");
         self
     }
 
@@ -140,6 +278,7 @@ impl RTDAudioBuilder {
 
     pub fn audio<T: AsRef<File>>(&mut self, audio: T) -> &mut Self {
         self.inner.audio = audio.as_ref().clone();
+        self.missing.retain(|f| *f != "audio");
         self
     }
 }
@@ -155,3 +294,50 @@ impl AsRef<Audio> for RTDAudioBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            Audio::builder()
+                .duration(i32::MAX)
+                .title("text")
+                .performer("text")
+                .file_name("text")
+                .mime_type("text")
+                .audio(
+                    File::builder()
+                        .id(i32::MAX)
+                        .size(i32::MAX)
+                        .expected_size(i32::MAX)
+                        .local(
+                            LocalFile::builder()
+                                .path("text")
+                                .can_be_downloaded(true)
+                                .can_be_deleted(true)
+                                .is_downloading_active(true)
+                                .is_downloading_completed(true)
+                                .download_offset(i32::MAX)
+                                .downloaded_prefix_size(i32::MAX)
+                                .downloaded_size(i32::MAX)
+                                .build(),
+                        )
+                        .remote(
+                            RemoteFile::builder()
+                                .id("text")
+                                .unique_id("text")
+                                .is_uploading_active(true)
+                                .is_uploading_completed(true)
+                                .uploaded_size(i32::MAX)
+                                .build(),
+                        )
+                        .build(),
+                )
+                .build(),
+        );
+    }
+}
@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Information about the authentication code that was sent
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "authenticationCodeInfo")]
 pub struct AuthenticationCodeInfo {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// A phone number that is being authenticated
     phone_number: String,
@@ -19,7 +19,7 @@ pub struct AuthenticationCodeInfo {
     /// Describes the way the next code will be sent to the user; may be null
     next_type: Option<AuthenticationCodeType>,
     /// Timeout before the code should be re-sent, in seconds
-    timeout: i64,
+    timeout: i32,
 }
 
 impl RObject for AuthenticationCodeInfo {
@@ -36,15 +36,49 @@ impl RObject for AuthenticationCodeInfo {
     }
 }
 
+impl PartialEq for AuthenticationCodeInfo {
+    fn eq(&self, other: &Self) -> bool {
+        self.phone_number == other.phone_number
+            && self.type_ == other.type_
+            && self.next_type == other.next_type
+            && self.timeout == other.timeout
+    }
+}
+
+impl Eq for AuthenticationCodeInfo {}
+
+impl std::hash::Hash for AuthenticationCodeInfo {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.phone_number, state);
+        std::hash::Hash::hash(&self.type_, state);
+        std::hash::Hash::hash(&self.next_type, state);
+        std::hash::Hash::hash(&self.timeout, state);
+    }
+}
+
 impl AuthenticationCodeInfo {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDAuthenticationCodeInfoBuilder {
-        let mut inner = AuthenticationCodeInfo::default();
-        inner.td_name = "authenticationCodeInfo".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDAuthenticationCodeInfoBuilder { inner }
+        RTDAuthenticationCodeInfoBuilder {
+            inner: AuthenticationCodeInfo::default(),
+            missing: vec!["phone_number", "type_", "timeout"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDAuthenticationCodeInfoBuilder {
+        RTDAuthenticationCodeInfoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDAuthenticationCodeInfoBuilder {
+        RTDAuthenticationCodeInfoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn phone_number(&self) -> &String {
@@ -59,28 +93,75 @@ impl AuthenticationCodeInfo {
         &self.next_type
     }
 
-    pub fn timeout(&self) -> i64 {
+    pub fn timeout(&self) -> i32 {
         self.timeout
     }
+
+    pub fn into_phone_number(self) -> String {
+        self.phone_number
+    }
+
+    pub fn into_type(self) -> AuthenticationCodeType {
+        self.type_
+    }
+
+    pub fn into_next_type(self) -> Option<AuthenticationCodeType> {
+        self.next_type
+    }
+
+    pub fn with_phone_number<T: AsRef<str>>(mut self, phone_number: T) -> Self {
+        self.phone_number = phone_number.as_ref().to_string();
+        self
+    }
+
+    pub fn with_type<T: AsRef<AuthenticationCodeType>>(mut self, type_: T) -> Self {
+        self.type_ = type_.as_ref().clone();
+        self
+    }
+
+    pub fn with_next_type<T: AsRef<AuthenticationCodeType>>(mut self, next_type: T) -> Self {
+        self.next_type = Some(next_type.as_ref().clone());
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: i32) -> Self {
+        self.timeout = timeout;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDAuthenticationCodeInfoBuilder {
     inner: AuthenticationCodeInfo,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDAuthenticationCodeInfoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> AuthenticationCodeInfo {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<AuthenticationCodeInfo> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "AuthenticationCodeInfo",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn phone_number<T: AsRef<str>>(&mut self, phone_number: T) -> &mut Self {
         self.inner.phone_number = phone_number.as_ref().to_string();
+        self.missing.retain(|f| *f != "phone_number");
         self
     }
 
     pub fn type_<T: AsRef<AuthenticationCodeType>>(&mut self, type_: T) -> &mut Self {
         self.inner.type_ = type_.as_ref().clone();
+        self.missing.retain(|f| *f != "type_");
         self
     }
 
@@ -89,8 +170,9 @@ impl RTDAuthenticationCodeInfoBuilder {
         self
     }
 
-    pub fn timeout(&mut self, timeout: i64) -> &mut Self {
+    pub fn timeout(&mut self, timeout: i32) -> &mut Self {
         self.inner.timeout = timeout;
+        self.missing.retain(|f| *f != "timeout");
         self
     }
 }
@@ -106,3 +188,24 @@ impl AsRef<AuthenticationCodeInfo> for RTDAuthenticationCodeInfoBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            AuthenticationCodeInfo::builder()
+                .phone_number("text")
+                .type_(AuthenticationCodeType::Call(
+                    AuthenticationCodeTypeCall::builder()
+                        .length(i32::MAX)
+                        .build(),
+                ))
+                .timeout(i32::MAX)
+                .build(),
+        );
+    }
+}
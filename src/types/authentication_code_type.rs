@@ -1,49 +1,48 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Provides information about the method by which an authentication code is delivered to the user
 pub trait TDAuthenticationCodeType: Debug + RObject {}
 
 /// Provides information about the method by which an authentication code is delivered to the user
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum AuthenticationCodeType {
-    #[doc(hidden)]
-    _Default(()),
     /// An authentication code is delivered via a phone call to the specified phone number
+    #[serde(rename = "authenticationCodeTypeCall")]
     Call(AuthenticationCodeTypeCall),
     /// An authentication code is delivered by an immediately cancelled call to the specified phone number. The number from which the call was made is the code
+    #[serde(rename = "authenticationCodeTypeFlashCall")]
     FlashCall(AuthenticationCodeTypeFlashCall),
     /// An authentication code is delivered via an SMS message to the specified phone number
+    #[serde(rename = "authenticationCodeTypeSms")]
     Sms(AuthenticationCodeTypeSms),
     /// An authentication code is delivered via a private Telegram message, which can be viewed in another client
+    #[serde(rename = "authenticationCodeTypeTelegramMessage")]
     TelegramMessage(AuthenticationCodeTypeTelegramMessage),
 }
 
+/// Default value is default of [AuthenticationCodeTypeCall], builders still require the field to be set
 impl Default for AuthenticationCodeType {
     fn default() -> Self {
-        AuthenticationCodeType::_Default(())
+        AuthenticationCodeType::Call(AuthenticationCodeTypeCall::default())
     }
 }
 
-impl<'de> Deserialize<'de> for AuthenticationCodeType {
-    fn deserialize<D>(deserializer: D) -> Result<AuthenticationCodeType, D::Error>
+impl Serialize for AuthenticationCodeType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          AuthenticationCodeType,
-          (authenticationCodeTypeCall, Call);
-          (authenticationCodeTypeFlashCall, FlashCall);
-          (authenticationCodeTypeSms, Sms);
-          (authenticationCodeTypeTelegramMessage, TelegramMessage);
-
-        )(deserializer)
+        match self {
+            AuthenticationCodeType::Call(t) => t.serialize(serializer),
+            AuthenticationCodeType::FlashCall(t) => t.serialize(serializer),
+            AuthenticationCodeType::Sms(t) => t.serialize(serializer),
+            AuthenticationCodeType::TelegramMessage(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -55,8 +54,6 @@ impl RObject for AuthenticationCodeType {
             AuthenticationCodeType::FlashCall(t) => t.td_name(),
             AuthenticationCodeType::Sms(t) => t.td_name(),
             AuthenticationCodeType::TelegramMessage(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -66,8 +63,6 @@ impl RObject for AuthenticationCodeType {
             AuthenticationCodeType::FlashCall(t) => t.extra(),
             AuthenticationCodeType::Sms(t) => t.extra(),
             AuthenticationCodeType::TelegramMessage(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -79,10 +74,6 @@ impl AuthenticationCodeType {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, AuthenticationCodeType::_Default(_))
-    }
 }
 
 impl AsRef<AuthenticationCodeType> for AuthenticationCodeType {
@@ -93,15 +84,16 @@ impl AsRef<AuthenticationCodeType> for AuthenticationCodeType {
 
 /// An authentication code is delivered via a phone call to the specified phone number
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "authenticationCodeTypeCall")]
 pub struct AuthenticationCodeTypeCall {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Length of the code
-    length: i64,
+    length: i32,
 }
 
 impl RObject for AuthenticationCodeTypeCall {
@@ -118,6 +110,20 @@ impl RObject for AuthenticationCodeTypeCall {
     }
 }
 
+impl PartialEq for AuthenticationCodeTypeCall {
+    fn eq(&self, other: &Self) -> bool {
+        self.length == other.length
+    }
+}
+
+impl Eq for AuthenticationCodeTypeCall {}
+
+impl std::hash::Hash for AuthenticationCodeTypeCall {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.length, state);
+    }
+}
+
 impl TDAuthenticationCodeType for AuthenticationCodeTypeCall {}
 
 impl AuthenticationCodeTypeCall {
@@ -125,29 +131,62 @@ impl AuthenticationCodeTypeCall {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDAuthenticationCodeTypeCallBuilder {
-        let mut inner = AuthenticationCodeTypeCall::default();
-        inner.td_name = "authenticationCodeTypeCall".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDAuthenticationCodeTypeCallBuilder { inner }
+        RTDAuthenticationCodeTypeCallBuilder {
+            inner: AuthenticationCodeTypeCall::default(),
+            missing: vec!["length"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDAuthenticationCodeTypeCallBuilder {
+        RTDAuthenticationCodeTypeCallBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDAuthenticationCodeTypeCallBuilder {
+        RTDAuthenticationCodeTypeCallBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn length(&self) -> i64 {
+    pub fn length(&self) -> i32 {
         self.length
     }
+
+    pub fn with_length(mut self, length: i32) -> Self {
+        self.length = length;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDAuthenticationCodeTypeCallBuilder {
     inner: AuthenticationCodeTypeCall,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDAuthenticationCodeTypeCallBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> AuthenticationCodeTypeCall {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<AuthenticationCodeTypeCall> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "AuthenticationCodeTypeCall",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn length(&mut self, length: i64) -> &mut Self {
+    pub fn length(&mut self, length: i32) -> &mut Self {
         self.inner.length = length;
+        self.missing.retain(|f| *f != "length");
         self
     }
 }
@@ -166,12 +205,13 @@ impl AsRef<AuthenticationCodeTypeCall> for RTDAuthenticationCodeTypeCallBuilder
 
 /// An authentication code is delivered by an immediately cancelled call to the specified phone number. The number from which the call was made is the code
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "authenticationCodeTypeFlashCall")]
 pub struct AuthenticationCodeTypeFlashCall {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Pattern of the phone number from which the call will be made
     pattern: String,
@@ -191,6 +231,20 @@ impl RObject for AuthenticationCodeTypeFlashCall {
     }
 }
 
+impl PartialEq for AuthenticationCodeTypeFlashCall {
+    fn eq(&self, other: &Self) -> bool {
+        self.pattern == other.pattern
+    }
+}
+
+impl Eq for AuthenticationCodeTypeFlashCall {}
+
+impl std::hash::Hash for AuthenticationCodeTypeFlashCall {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.pattern, state);
+    }
+}
+
 impl TDAuthenticationCodeType for AuthenticationCodeTypeFlashCall {}
 
 impl AuthenticationCodeTypeFlashCall {
@@ -198,29 +252,66 @@ impl AuthenticationCodeTypeFlashCall {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDAuthenticationCodeTypeFlashCallBuilder {
-        let mut inner = AuthenticationCodeTypeFlashCall::default();
-        inner.td_name = "authenticationCodeTypeFlashCall".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDAuthenticationCodeTypeFlashCallBuilder { inner }
+        RTDAuthenticationCodeTypeFlashCallBuilder {
+            inner: AuthenticationCodeTypeFlashCall::default(),
+            missing: vec!["pattern"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDAuthenticationCodeTypeFlashCallBuilder {
+        RTDAuthenticationCodeTypeFlashCallBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDAuthenticationCodeTypeFlashCallBuilder {
+        RTDAuthenticationCodeTypeFlashCallBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn pattern(&self) -> &String {
         &self.pattern
     }
+
+    pub fn into_pattern(self) -> String {
+        self.pattern
+    }
+
+    pub fn with_pattern<T: AsRef<str>>(mut self, pattern: T) -> Self {
+        self.pattern = pattern.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDAuthenticationCodeTypeFlashCallBuilder {
     inner: AuthenticationCodeTypeFlashCall,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDAuthenticationCodeTypeFlashCallBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> AuthenticationCodeTypeFlashCall {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<AuthenticationCodeTypeFlashCall> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "AuthenticationCodeTypeFlashCall",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn pattern<T: AsRef<str>>(&mut self, pattern: T) -> &mut Self {
         self.inner.pattern = pattern.as_ref().to_string();
+        self.missing.retain(|f| *f != "pattern");
         self
     }
 }
@@ -239,15 +330,16 @@ impl AsRef<AuthenticationCodeTypeFlashCall> for RTDAuthenticationCodeTypeFlashCa
 
 /// An authentication code is delivered via an SMS message to the specified phone number
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "authenticationCodeTypeSms")]
 pub struct AuthenticationCodeTypeSms {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Length of the code
-    length: i64,
+    length: i32,
 }
 
 impl RObject for AuthenticationCodeTypeSms {
@@ -264,6 +356,20 @@ impl RObject for AuthenticationCodeTypeSms {
     }
 }
 
+impl PartialEq for AuthenticationCodeTypeSms {
+    fn eq(&self, other: &Self) -> bool {
+        self.length == other.length
+    }
+}
+
+impl Eq for AuthenticationCodeTypeSms {}
+
+impl std::hash::Hash for AuthenticationCodeTypeSms {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.length, state);
+    }
+}
+
 impl TDAuthenticationCodeType for AuthenticationCodeTypeSms {}
 
 impl AuthenticationCodeTypeSms {
@@ -271,29 +377,62 @@ impl AuthenticationCodeTypeSms {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDAuthenticationCodeTypeSmsBuilder {
-        let mut inner = AuthenticationCodeTypeSms::default();
-        inner.td_name = "authenticationCodeTypeSms".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDAuthenticationCodeTypeSmsBuilder { inner }
+        RTDAuthenticationCodeTypeSmsBuilder {
+            inner: AuthenticationCodeTypeSms::default(),
+            missing: vec!["length"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDAuthenticationCodeTypeSmsBuilder {
+        RTDAuthenticationCodeTypeSmsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDAuthenticationCodeTypeSmsBuilder {
+        RTDAuthenticationCodeTypeSmsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn length(&self) -> i64 {
+    pub fn length(&self) -> i32 {
         self.length
     }
+
+    pub fn with_length(mut self, length: i32) -> Self {
+        self.length = length;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDAuthenticationCodeTypeSmsBuilder {
     inner: AuthenticationCodeTypeSms,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDAuthenticationCodeTypeSmsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> AuthenticationCodeTypeSms {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<AuthenticationCodeTypeSms> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "AuthenticationCodeTypeSms",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn length(&mut self, length: i64) -> &mut Self {
+    pub fn length(&mut self, length: i32) -> &mut Self {
         self.inner.length = length;
+        self.missing.retain(|f| *f != "length");
         self
     }
 }
@@ -312,15 +451,16 @@ impl AsRef<AuthenticationCodeTypeSms> for RTDAuthenticationCodeTypeSmsBuilder {
 
 /// An authentication code is delivered via a private Telegram message, which can be viewed in another client
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "authenticationCodeTypeTelegramMessage")]
 pub struct AuthenticationCodeTypeTelegramMessage {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Length of the code
-    length: i64,
+    length: i32,
 }
 
 impl RObject for AuthenticationCodeTypeTelegramMessage {
@@ -337,6 +477,20 @@ impl RObject for AuthenticationCodeTypeTelegramMessage {
     }
 }
 
+impl PartialEq for AuthenticationCodeTypeTelegramMessage {
+    fn eq(&self, other: &Self) -> bool {
+        self.length == other.length
+    }
+}
+
+impl Eq for AuthenticationCodeTypeTelegramMessage {}
+
+impl std::hash::Hash for AuthenticationCodeTypeTelegramMessage {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.length, state);
+    }
+}
+
 impl TDAuthenticationCodeType for AuthenticationCodeTypeTelegramMessage {}
 
 impl AuthenticationCodeTypeTelegramMessage {
@@ -344,29 +498,62 @@ impl AuthenticationCodeTypeTelegramMessage {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDAuthenticationCodeTypeTelegramMessageBuilder {
-        let mut inner = AuthenticationCodeTypeTelegramMessage::default();
-        inner.td_name = "authenticationCodeTypeTelegramMessage".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDAuthenticationCodeTypeTelegramMessageBuilder { inner }
+        RTDAuthenticationCodeTypeTelegramMessageBuilder {
+            inner: AuthenticationCodeTypeTelegramMessage::default(),
+            missing: vec!["length"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDAuthenticationCodeTypeTelegramMessageBuilder {
+        RTDAuthenticationCodeTypeTelegramMessageBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDAuthenticationCodeTypeTelegramMessageBuilder {
+        RTDAuthenticationCodeTypeTelegramMessageBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn length(&self) -> i64 {
+    pub fn length(&self) -> i32 {
         self.length
     }
+
+    pub fn with_length(mut self, length: i32) -> Self {
+        self.length = length;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDAuthenticationCodeTypeTelegramMessageBuilder {
     inner: AuthenticationCodeTypeTelegramMessage,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDAuthenticationCodeTypeTelegramMessageBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> AuthenticationCodeTypeTelegramMessage {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<AuthenticationCodeTypeTelegramMessage> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "AuthenticationCodeTypeTelegramMessage",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn length(&mut self, length: i64) -> &mut Self {
+    pub fn length(&mut self, length: i32) -> &mut Self {
         self.inner.length = length;
+        self.missing.retain(|f| *f != "length");
         self
     }
 }
@@ -384,3 +571,33 @@ impl AsRef<AuthenticationCodeTypeTelegramMessage>
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(AuthenticationCodeType::Call(
+            AuthenticationCodeTypeCall::builder()
+                .length(i32::MAX)
+                .build(),
+        ));
+        assert_round_trip(AuthenticationCodeType::FlashCall(
+            AuthenticationCodeTypeFlashCall::builder()
+                .pattern("text")
+                .build(),
+        ));
+        assert_round_trip(AuthenticationCodeType::Sms(
+            AuthenticationCodeTypeSms::builder()
+                .length(i32::MAX)
+                .build(),
+        ));
+        assert_round_trip(AuthenticationCodeType::TelegramMessage(
+            AuthenticationCodeTypeTelegramMessage::builder()
+                .length(i32::MAX)
+                .build(),
+        ));
+    }
+}
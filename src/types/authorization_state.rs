@@ -1,73 +1,76 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Represents the current authorization state of the client
 pub trait TDAuthorizationState: Debug + RObject {}
 
 /// Represents the current authorization state of the client
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum AuthorizationState {
-    #[doc(hidden)]
-    _Default(()),
     /// TDLib client is in its final state. All databases are closed and all resources are released. No other updates will be received after this. All queries will be responded to with error code 500. To continue working, one should create a new instance of the TDLib client
+    #[serde(rename = "authorizationStateClosed")]
     Closed(AuthorizationStateClosed),
     /// TDLib is closing, all subsequent queries will be answered with the error 500. Note that closing TDLib can take a while. All resources will be freed only after authorizationStateClosed has been received
+    #[serde(rename = "authorizationStateClosing")]
     Closing(AuthorizationStateClosing),
     /// The user is currently logging out
+    #[serde(rename = "authorizationStateLoggingOut")]
     LoggingOut(AuthorizationStateLoggingOut),
     /// The user has been successfully authorized. TDLib is now ready to answer queries
+    #[serde(rename = "authorizationStateReady")]
     Ready(AuthorizationStateReady),
     /// TDLib needs the user's authentication code to authorize
+    #[serde(rename = "authorizationStateWaitCode")]
     WaitCode(AuthorizationStateWaitCode),
     /// TDLib needs an encryption key to decrypt the local database
+    #[serde(rename = "authorizationStateWaitEncryptionKey")]
     WaitEncryptionKey(AuthorizationStateWaitEncryptionKey),
     /// The user needs to confirm authorization on another logged in device by scanning a QR code with the provided link
+    #[serde(rename = "authorizationStateWaitOtherDeviceConfirmation")]
     WaitOtherDeviceConfirmation(AuthorizationStateWaitOtherDeviceConfirmation),
     /// The user has been authorized, but needs to enter a password to start using the application
+    #[serde(rename = "authorizationStateWaitPassword")]
     WaitPassword(AuthorizationStateWaitPassword),
     /// TDLib needs the user's phone number to authorize. Call `setAuthenticationPhoneNumber` to provide the phone number, or use `requestQrCodeAuthentication`, or `checkAuthenticationBotToken` for other authentication options
+    #[serde(rename = "authorizationStateWaitPhoneNumber")]
     WaitPhoneNumber(AuthorizationStateWaitPhoneNumber),
     /// The user is unregistered and need to accept terms of service and enter their first name and last name to finish registration
+    #[serde(rename = "authorizationStateWaitRegistration")]
     WaitRegistration(AuthorizationStateWaitRegistration),
     /// TDLib needs TdlibParameters for initialization
+    #[serde(rename = "authorizationStateWaitTdlibParameters")]
     WaitTdlibParameters(AuthorizationStateWaitTdlibParameters),
-    /// Returns the current authorization state; this is an offline request. For informational purposes only. Use updateAuthorizationState instead to maintain the current authorization state
-    GetAuthorizationState(GetAuthorizationState),
 }
 
+/// Default value is default of [AuthorizationStateClosed], builders still require the field to be set
 impl Default for AuthorizationState {
     fn default() -> Self {
-        AuthorizationState::_Default(())
+        AuthorizationState::Closed(AuthorizationStateClosed::default())
     }
 }
 
-impl<'de> Deserialize<'de> for AuthorizationState {
-    fn deserialize<D>(deserializer: D) -> Result<AuthorizationState, D::Error>
+impl Serialize for AuthorizationState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          AuthorizationState,
-          (authorizationStateClosed, Closed);
-          (authorizationStateClosing, Closing);
-          (authorizationStateLoggingOut, LoggingOut);
-          (authorizationStateReady, Ready);
-          (authorizationStateWaitCode, WaitCode);
-          (authorizationStateWaitEncryptionKey, WaitEncryptionKey);
-          (authorizationStateWaitOtherDeviceConfirmation, WaitOtherDeviceConfirmation);
-          (authorizationStateWaitPassword, WaitPassword);
-          (authorizationStateWaitPhoneNumber, WaitPhoneNumber);
-          (authorizationStateWaitRegistration, WaitRegistration);
-          (authorizationStateWaitTdlibParameters, WaitTdlibParameters);
-          (getAuthorizationState, GetAuthorizationState);
-
-        )(deserializer)
+        match self {
+            AuthorizationState::Closed(t) => t.serialize(serializer),
+            AuthorizationState::Closing(t) => t.serialize(serializer),
+            AuthorizationState::LoggingOut(t) => t.serialize(serializer),
+            AuthorizationState::Ready(t) => t.serialize(serializer),
+            AuthorizationState::WaitCode(t) => t.serialize(serializer),
+            AuthorizationState::WaitEncryptionKey(t) => t.serialize(serializer),
+            AuthorizationState::WaitOtherDeviceConfirmation(t) => t.serialize(serializer),
+            AuthorizationState::WaitPassword(t) => t.serialize(serializer),
+            AuthorizationState::WaitPhoneNumber(t) => t.serialize(serializer),
+            AuthorizationState::WaitRegistration(t) => t.serialize(serializer),
+            AuthorizationState::WaitTdlibParameters(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -86,9 +89,6 @@ impl RObject for AuthorizationState {
             AuthorizationState::WaitPhoneNumber(t) => t.td_name(),
             AuthorizationState::WaitRegistration(t) => t.td_name(),
             AuthorizationState::WaitTdlibParameters(t) => t.td_name(),
-            AuthorizationState::GetAuthorizationState(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -105,9 +105,6 @@ impl RObject for AuthorizationState {
             AuthorizationState::WaitPhoneNumber(t) => t.extra(),
             AuthorizationState::WaitRegistration(t) => t.extra(),
             AuthorizationState::WaitTdlibParameters(t) => t.extra(),
-            AuthorizationState::GetAuthorizationState(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -119,10 +116,6 @@ impl AuthorizationState {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, AuthorizationState::_Default(_))
-    }
 }
 
 impl AsRef<AuthorizationState> for AuthorizationState {
@@ -133,12 +126,13 @@ impl AsRef<AuthorizationState> for AuthorizationState {
 
 /// TDLib client is in its final state. All databases are closed and all resources are released. No other updates will be received after this. All queries will be responded to with error code 500. To continue working, one should create a new instance of the TDLib client
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "authorizationStateClosed")]
 pub struct AuthorizationStateClosed {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -156,6 +150,18 @@ impl RObject for AuthorizationStateClosed {
     }
 }
 
+impl PartialEq for AuthorizationStateClosed {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for AuthorizationStateClosed {}
+
+impl std::hash::Hash for AuthorizationStateClosed {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDAuthorizationState for AuthorizationStateClosed {}
 
 impl AuthorizationStateClosed {
@@ -163,22 +169,49 @@ impl AuthorizationStateClosed {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDAuthorizationStateClosedBuilder {
-        let mut inner = AuthorizationStateClosed::default();
-        inner.td_name = "authorizationStateClosed".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDAuthorizationStateClosedBuilder { inner }
+        RTDAuthorizationStateClosedBuilder {
+            inner: AuthorizationStateClosed::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDAuthorizationStateClosedBuilder {
+        RTDAuthorizationStateClosedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDAuthorizationStateClosedBuilder {
+        RTDAuthorizationStateClosedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDAuthorizationStateClosedBuilder {
     inner: AuthorizationStateClosed,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDAuthorizationStateClosedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> AuthorizationStateClosed {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<AuthorizationStateClosed> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "AuthorizationStateClosed",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<AuthorizationStateClosed> for AuthorizationStateClosed {
@@ -195,12 +228,13 @@ impl AsRef<AuthorizationStateClosed> for RTDAuthorizationStateClosedBuilder {
 
 /// TDLib is closing, all subsequent queries will be answered with the error 500. Note that closing TDLib can take a while. All resources will be freed only after authorizationStateClosed has been received
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "authorizationStateClosing")]
 pub struct AuthorizationStateClosing {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -218,6 +252,18 @@ impl RObject for AuthorizationStateClosing {
     }
 }
 
+impl PartialEq for AuthorizationStateClosing {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for AuthorizationStateClosing {}
+
+impl std::hash::Hash for AuthorizationStateClosing {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDAuthorizationState for AuthorizationStateClosing {}
 
 impl AuthorizationStateClosing {
@@ -225,22 +271,49 @@ impl AuthorizationStateClosing {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDAuthorizationStateClosingBuilder {
-        let mut inner = AuthorizationStateClosing::default();
-        inner.td_name = "authorizationStateClosing".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDAuthorizationStateClosingBuilder { inner }
+        RTDAuthorizationStateClosingBuilder {
+            inner: AuthorizationStateClosing::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDAuthorizationStateClosingBuilder {
+        RTDAuthorizationStateClosingBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDAuthorizationStateClosingBuilder {
+        RTDAuthorizationStateClosingBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDAuthorizationStateClosingBuilder {
     inner: AuthorizationStateClosing,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDAuthorizationStateClosingBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> AuthorizationStateClosing {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<AuthorizationStateClosing> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "AuthorizationStateClosing",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<AuthorizationStateClosing> for AuthorizationStateClosing {
@@ -257,12 +330,13 @@ impl AsRef<AuthorizationStateClosing> for RTDAuthorizationStateClosingBuilder {
 
 /// The user is currently logging out
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "authorizationStateLoggingOut")]
 pub struct AuthorizationStateLoggingOut {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -280,6 +354,18 @@ impl RObject for AuthorizationStateLoggingOut {
     }
 }
 
+impl PartialEq for AuthorizationStateLoggingOut {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for AuthorizationStateLoggingOut {}
+
+impl std::hash::Hash for AuthorizationStateLoggingOut {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDAuthorizationState for AuthorizationStateLoggingOut {}
 
 impl AuthorizationStateLoggingOut {
@@ -287,22 +373,49 @@ impl AuthorizationStateLoggingOut {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDAuthorizationStateLoggingOutBuilder {
-        let mut inner = AuthorizationStateLoggingOut::default();
-        inner.td_name = "authorizationStateLoggingOut".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDAuthorizationStateLoggingOutBuilder { inner }
+        RTDAuthorizationStateLoggingOutBuilder {
+            inner: AuthorizationStateLoggingOut::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDAuthorizationStateLoggingOutBuilder {
+        RTDAuthorizationStateLoggingOutBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDAuthorizationStateLoggingOutBuilder {
+        RTDAuthorizationStateLoggingOutBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDAuthorizationStateLoggingOutBuilder {
     inner: AuthorizationStateLoggingOut,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDAuthorizationStateLoggingOutBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> AuthorizationStateLoggingOut {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<AuthorizationStateLoggingOut> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "AuthorizationStateLoggingOut",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<AuthorizationStateLoggingOut> for AuthorizationStateLoggingOut {
@@ -319,12 +432,13 @@ impl AsRef<AuthorizationStateLoggingOut> for RTDAuthorizationStateLoggingOutBuil
 
 /// The user has been successfully authorized. TDLib is now ready to answer queries
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "authorizationStateReady")]
 pub struct AuthorizationStateReady {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -342,6 +456,18 @@ impl RObject for AuthorizationStateReady {
     }
 }
 
+impl PartialEq for AuthorizationStateReady {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for AuthorizationStateReady {}
+
+impl std::hash::Hash for AuthorizationStateReady {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDAuthorizationState for AuthorizationStateReady {}
 
 impl AuthorizationStateReady {
@@ -349,22 +475,49 @@ impl AuthorizationStateReady {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDAuthorizationStateReadyBuilder {
-        let mut inner = AuthorizationStateReady::default();
-        inner.td_name = "authorizationStateReady".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDAuthorizationStateReadyBuilder { inner }
+        RTDAuthorizationStateReadyBuilder {
+            inner: AuthorizationStateReady::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDAuthorizationStateReadyBuilder {
+        RTDAuthorizationStateReadyBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDAuthorizationStateReadyBuilder {
+        RTDAuthorizationStateReadyBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDAuthorizationStateReadyBuilder {
     inner: AuthorizationStateReady,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDAuthorizationStateReadyBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> AuthorizationStateReady {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<AuthorizationStateReady> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "AuthorizationStateReady",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<AuthorizationStateReady> for AuthorizationStateReady {
@@ -381,12 +534,13 @@ impl AsRef<AuthorizationStateReady> for RTDAuthorizationStateReadyBuilder {
 
 /// TDLib needs the user's authentication code to authorize
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "authorizationStateWaitCode")]
 pub struct AuthorizationStateWaitCode {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Information about the authorization code that was sent
     code_info: AuthenticationCodeInfo,
@@ -406,6 +560,20 @@ impl RObject for AuthorizationStateWaitCode {
     }
 }
 
+impl PartialEq for AuthorizationStateWaitCode {
+    fn eq(&self, other: &Self) -> bool {
+        self.code_info == other.code_info
+    }
+}
+
+impl Eq for AuthorizationStateWaitCode {}
+
+impl std::hash::Hash for AuthorizationStateWaitCode {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.code_info, state);
+    }
+}
+
 impl TDAuthorizationState for AuthorizationStateWaitCode {}
 
 impl AuthorizationStateWaitCode {
@@ -413,29 +581,66 @@ impl AuthorizationStateWaitCode {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDAuthorizationStateWaitCodeBuilder {
-        let mut inner = AuthorizationStateWaitCode::default();
-        inner.td_name = "authorizationStateWaitCode".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDAuthorizationStateWaitCodeBuilder { inner }
+        RTDAuthorizationStateWaitCodeBuilder {
+            inner: AuthorizationStateWaitCode::default(),
+            missing: vec!["code_info"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDAuthorizationStateWaitCodeBuilder {
+        RTDAuthorizationStateWaitCodeBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDAuthorizationStateWaitCodeBuilder {
+        RTDAuthorizationStateWaitCodeBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn code_info(&self) -> &AuthenticationCodeInfo {
         &self.code_info
     }
+
+    pub fn into_code_info(self) -> AuthenticationCodeInfo {
+        self.code_info
+    }
+
+    pub fn with_code_info<T: AsRef<AuthenticationCodeInfo>>(mut self, code_info: T) -> Self {
+        self.code_info = code_info.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDAuthorizationStateWaitCodeBuilder {
     inner: AuthorizationStateWaitCode,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDAuthorizationStateWaitCodeBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> AuthorizationStateWaitCode {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<AuthorizationStateWaitCode> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "AuthorizationStateWaitCode",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn code_info<T: AsRef<AuthenticationCodeInfo>>(&mut self, code_info: T) -> &mut Self {
         self.inner.code_info = code_info.as_ref().clone();
+        self.missing.retain(|f| *f != "code_info");
         self
     }
 }
@@ -454,12 +659,13 @@ impl AsRef<AuthorizationStateWaitCode> for RTDAuthorizationStateWaitCodeBuilder
 
 /// TDLib needs an encryption key to decrypt the local database
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "authorizationStateWaitEncryptionKey")]
 pub struct AuthorizationStateWaitEncryptionKey {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// True, if the database is currently encrypted
     is_encrypted: bool,
@@ -479,6 +685,20 @@ impl RObject for AuthorizationStateWaitEncryptionKey {
     }
 }
 
+impl PartialEq for AuthorizationStateWaitEncryptionKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.is_encrypted == other.is_encrypted
+    }
+}
+
+impl Eq for AuthorizationStateWaitEncryptionKey {}
+
+impl std::hash::Hash for AuthorizationStateWaitEncryptionKey {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.is_encrypted, state);
+    }
+}
+
 impl TDAuthorizationState for AuthorizationStateWaitEncryptionKey {}
 
 impl AuthorizationStateWaitEncryptionKey {
@@ -486,26 +706,58 @@ impl AuthorizationStateWaitEncryptionKey {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDAuthorizationStateWaitEncryptionKeyBuilder {
-        let mut inner = AuthorizationStateWaitEncryptionKey::default();
-        inner.td_name = "authorizationStateWaitEncryptionKey".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDAuthorizationStateWaitEncryptionKeyBuilder { inner }
+        RTDAuthorizationStateWaitEncryptionKeyBuilder {
+            inner: AuthorizationStateWaitEncryptionKey::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDAuthorizationStateWaitEncryptionKeyBuilder {
+        RTDAuthorizationStateWaitEncryptionKeyBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDAuthorizationStateWaitEncryptionKeyBuilder {
+        RTDAuthorizationStateWaitEncryptionKeyBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn is_encrypted(&self) -> bool {
         self.is_encrypted
     }
+
+    pub fn with_is_encrypted(mut self, is_encrypted: bool) -> Self {
+        self.is_encrypted = is_encrypted;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDAuthorizationStateWaitEncryptionKeyBuilder {
     inner: AuthorizationStateWaitEncryptionKey,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDAuthorizationStateWaitEncryptionKeyBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> AuthorizationStateWaitEncryptionKey {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<AuthorizationStateWaitEncryptionKey> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "AuthorizationStateWaitEncryptionKey",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn is_encrypted(&mut self, is_encrypted: bool) -> &mut Self {
         self.inner.is_encrypted = is_encrypted;
@@ -527,12 +779,16 @@ impl AsRef<AuthorizationStateWaitEncryptionKey> for RTDAuthorizationStateWaitEnc
 
 /// The user needs to confirm authorization on another logged in device by scanning a QR code with the provided link
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(
+    tag = "@type",
+    rename = "authorizationStateWaitOtherDeviceConfirmation"
+)]
 pub struct AuthorizationStateWaitOtherDeviceConfirmation {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// A tg:// URL for the QR code. The link will be updated frequently
     link: String,
@@ -552,6 +808,20 @@ impl RObject for AuthorizationStateWaitOtherDeviceConfirmation {
     }
 }
 
+impl PartialEq for AuthorizationStateWaitOtherDeviceConfirmation {
+    fn eq(&self, other: &Self) -> bool {
+        self.link == other.link
+    }
+}
+
+impl Eq for AuthorizationStateWaitOtherDeviceConfirmation {}
+
+impl std::hash::Hash for AuthorizationStateWaitOtherDeviceConfirmation {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.link, state);
+    }
+}
+
 impl TDAuthorizationState for AuthorizationStateWaitOtherDeviceConfirmation {}
 
 impl AuthorizationStateWaitOtherDeviceConfirmation {
@@ -559,29 +829,66 @@ impl AuthorizationStateWaitOtherDeviceConfirmation {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDAuthorizationStateWaitOtherDeviceConfirmationBuilder {
-        let mut inner = AuthorizationStateWaitOtherDeviceConfirmation::default();
-        inner.td_name = "authorizationStateWaitOtherDeviceConfirmation".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDAuthorizationStateWaitOtherDeviceConfirmationBuilder { inner }
+        RTDAuthorizationStateWaitOtherDeviceConfirmationBuilder {
+            inner: AuthorizationStateWaitOtherDeviceConfirmation::default(),
+            missing: vec!["link"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDAuthorizationStateWaitOtherDeviceConfirmationBuilder {
+        RTDAuthorizationStateWaitOtherDeviceConfirmationBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDAuthorizationStateWaitOtherDeviceConfirmationBuilder {
+        RTDAuthorizationStateWaitOtherDeviceConfirmationBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn link(&self) -> &String {
         &self.link
     }
+
+    pub fn into_link(self) -> String {
+        self.link
+    }
+
+    pub fn with_link<T: AsRef<str>>(mut self, link: T) -> Self {
+        self.link = link.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDAuthorizationStateWaitOtherDeviceConfirmationBuilder {
     inner: AuthorizationStateWaitOtherDeviceConfirmation,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDAuthorizationStateWaitOtherDeviceConfirmationBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> AuthorizationStateWaitOtherDeviceConfirmation {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<AuthorizationStateWaitOtherDeviceConfirmation> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "AuthorizationStateWaitOtherDeviceConfirmation",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn link<T: AsRef<str>>(&mut self, link: T) -> &mut Self {
         self.inner.link = link.as_ref().to_string();
+        self.missing.retain(|f| *f != "link");
         self
     }
 }
@@ -604,12 +911,13 @@ impl AsRef<AuthorizationStateWaitOtherDeviceConfirmation>
 
 /// The user has been authorized, but needs to enter a password to start using the application
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "authorizationStateWaitPassword")]
 pub struct AuthorizationStateWaitPassword {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Hint for the password; may be empty
     password_hint: String,
@@ -633,6 +941,24 @@ impl RObject for AuthorizationStateWaitPassword {
     }
 }
 
+impl PartialEq for AuthorizationStateWaitPassword {
+    fn eq(&self, other: &Self) -> bool {
+        self.password_hint == other.password_hint
+            && self.has_recovery_email_address == other.has_recovery_email_address
+            && self.recovery_email_address_pattern == other.recovery_email_address_pattern
+    }
+}
+
+impl Eq for AuthorizationStateWaitPassword {}
+
+impl std::hash::Hash for AuthorizationStateWaitPassword {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.password_hint, state);
+        std::hash::Hash::hash(&self.has_recovery_email_address, state);
+        std::hash::Hash::hash(&self.recovery_email_address_pattern, state);
+    }
+}
+
 impl TDAuthorizationState for AuthorizationStateWaitPassword {}
 
 impl AuthorizationStateWaitPassword {
@@ -640,10 +966,24 @@ impl AuthorizationStateWaitPassword {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDAuthorizationStateWaitPasswordBuilder {
-        let mut inner = AuthorizationStateWaitPassword::default();
-        inner.td_name = "authorizationStateWaitPassword".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDAuthorizationStateWaitPasswordBuilder { inner }
+        RTDAuthorizationStateWaitPasswordBuilder {
+            inner: AuthorizationStateWaitPassword::default(),
+            missing: vec!["recovery_email_address_pattern"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDAuthorizationStateWaitPasswordBuilder {
+        RTDAuthorizationStateWaitPasswordBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDAuthorizationStateWaitPasswordBuilder {
+        RTDAuthorizationStateWaitPasswordBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn password_hint(&self) -> &String {
@@ -657,17 +997,56 @@ impl AuthorizationStateWaitPassword {
     pub fn recovery_email_address_pattern(&self) -> &String {
         &self.recovery_email_address_pattern
     }
+
+    pub fn into_password_hint(self) -> String {
+        self.password_hint
+    }
+
+    pub fn into_recovery_email_address_pattern(self) -> String {
+        self.recovery_email_address_pattern
+    }
+
+    pub fn with_password_hint<T: AsRef<str>>(mut self, password_hint: T) -> Self {
+        self.password_hint = password_hint.as_ref().to_string();
+        self
+    }
+
+    pub fn with_has_recovery_email_address(mut self, has_recovery_email_address: bool) -> Self {
+        self.has_recovery_email_address = has_recovery_email_address;
+        self
+    }
+
+    pub fn with_recovery_email_address_pattern<T: AsRef<str>>(
+        mut self,
+        recovery_email_address_pattern: T,
+    ) -> Self {
+        self.recovery_email_address_pattern = recovery_email_address_pattern.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDAuthorizationStateWaitPasswordBuilder {
     inner: AuthorizationStateWaitPassword,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDAuthorizationStateWaitPasswordBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> AuthorizationStateWaitPassword {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<AuthorizationStateWaitPassword> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "AuthorizationStateWaitPassword",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn password_hint<T: AsRef<str>>(&mut self, password_hint: T) -> &mut Self {
         self.inner.password_hint = password_hint.as_ref().to_string();
@@ -685,6 +1064,8 @@ impl RTDAuthorizationStateWaitPasswordBuilder {
     ) -> &mut Self {
         self.inner.recovery_email_address_pattern =
             recovery_email_address_pattern.as_ref().to_string();
+        self.missing
+            .retain(|f| *f != "recovery_email_address_pattern");
         self
     }
 }
@@ -703,12 +1084,13 @@ impl AsRef<AuthorizationStateWaitPassword> for RTDAuthorizationStateWaitPassword
 
 /// TDLib needs the user's phone number to authorize. Call `setAuthenticationPhoneNumber` to provide the phone number, or use `requestQrCodeAuthentication`, or `checkAuthenticationBotToken` for other authentication options
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "authorizationStateWaitPhoneNumber")]
 pub struct AuthorizationStateWaitPhoneNumber {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -726,6 +1108,18 @@ impl RObject for AuthorizationStateWaitPhoneNumber {
     }
 }
 
+impl PartialEq for AuthorizationStateWaitPhoneNumber {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for AuthorizationStateWaitPhoneNumber {}
+
+impl std::hash::Hash for AuthorizationStateWaitPhoneNumber {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDAuthorizationState for AuthorizationStateWaitPhoneNumber {}
 
 impl AuthorizationStateWaitPhoneNumber {
@@ -733,22 +1127,49 @@ impl AuthorizationStateWaitPhoneNumber {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDAuthorizationStateWaitPhoneNumberBuilder {
-        let mut inner = AuthorizationStateWaitPhoneNumber::default();
-        inner.td_name = "authorizationStateWaitPhoneNumber".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDAuthorizationStateWaitPhoneNumberBuilder { inner }
+        RTDAuthorizationStateWaitPhoneNumberBuilder {
+            inner: AuthorizationStateWaitPhoneNumber::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDAuthorizationStateWaitPhoneNumberBuilder {
+        RTDAuthorizationStateWaitPhoneNumberBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDAuthorizationStateWaitPhoneNumberBuilder {
+        RTDAuthorizationStateWaitPhoneNumberBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDAuthorizationStateWaitPhoneNumberBuilder {
     inner: AuthorizationStateWaitPhoneNumber,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDAuthorizationStateWaitPhoneNumberBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> AuthorizationStateWaitPhoneNumber {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<AuthorizationStateWaitPhoneNumber> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "AuthorizationStateWaitPhoneNumber",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<AuthorizationStateWaitPhoneNumber> for AuthorizationStateWaitPhoneNumber {
@@ -765,12 +1186,13 @@ impl AsRef<AuthorizationStateWaitPhoneNumber> for RTDAuthorizationStateWaitPhone
 
 /// The user is unregistered and need to accept terms of service and enter their first name and last name to finish registration
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "authorizationStateWaitRegistration")]
 pub struct AuthorizationStateWaitRegistration {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Telegram terms of service
     terms_of_service: TermsOfService,
@@ -790,6 +1212,20 @@ impl RObject for AuthorizationStateWaitRegistration {
     }
 }
 
+impl PartialEq for AuthorizationStateWaitRegistration {
+    fn eq(&self, other: &Self) -> bool {
+        self.terms_of_service == other.terms_of_service
+    }
+}
+
+impl Eq for AuthorizationStateWaitRegistration {}
+
+impl std::hash::Hash for AuthorizationStateWaitRegistration {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.terms_of_service, state);
+    }
+}
+
 impl TDAuthorizationState for AuthorizationStateWaitRegistration {}
 
 impl AuthorizationStateWaitRegistration {
@@ -797,29 +1233,66 @@ impl AuthorizationStateWaitRegistration {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDAuthorizationStateWaitRegistrationBuilder {
-        let mut inner = AuthorizationStateWaitRegistration::default();
-        inner.td_name = "authorizationStateWaitRegistration".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDAuthorizationStateWaitRegistrationBuilder { inner }
+        RTDAuthorizationStateWaitRegistrationBuilder {
+            inner: AuthorizationStateWaitRegistration::default(),
+            missing: vec!["terms_of_service"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDAuthorizationStateWaitRegistrationBuilder {
+        RTDAuthorizationStateWaitRegistrationBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDAuthorizationStateWaitRegistrationBuilder {
+        RTDAuthorizationStateWaitRegistrationBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn terms_of_service(&self) -> &TermsOfService {
         &self.terms_of_service
     }
+
+    pub fn into_terms_of_service(self) -> TermsOfService {
+        self.terms_of_service
+    }
+
+    pub fn with_terms_of_service<T: AsRef<TermsOfService>>(mut self, terms_of_service: T) -> Self {
+        self.terms_of_service = terms_of_service.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDAuthorizationStateWaitRegistrationBuilder {
     inner: AuthorizationStateWaitRegistration,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDAuthorizationStateWaitRegistrationBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> AuthorizationStateWaitRegistration {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<AuthorizationStateWaitRegistration> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "AuthorizationStateWaitRegistration",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn terms_of_service<T: AsRef<TermsOfService>>(&mut self, terms_of_service: T) -> &mut Self {
         self.inner.terms_of_service = terms_of_service.as_ref().clone();
+        self.missing.retain(|f| *f != "terms_of_service");
         self
     }
 }
@@ -838,12 +1311,13 @@ impl AsRef<AuthorizationStateWaitRegistration> for RTDAuthorizationStateWaitRegi
 
 /// TDLib needs TdlibParameters for initialization
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "authorizationStateWaitTdlibParameters")]
 pub struct AuthorizationStateWaitTdlibParameters {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -861,6 +1335,18 @@ impl RObject for AuthorizationStateWaitTdlibParameters {
     }
 }
 
+impl PartialEq for AuthorizationStateWaitTdlibParameters {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for AuthorizationStateWaitTdlibParameters {}
+
+impl std::hash::Hash for AuthorizationStateWaitTdlibParameters {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDAuthorizationState for AuthorizationStateWaitTdlibParameters {}
 
 impl AuthorizationStateWaitTdlibParameters {
@@ -868,22 +1354,49 @@ impl AuthorizationStateWaitTdlibParameters {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDAuthorizationStateWaitTdlibParametersBuilder {
-        let mut inner = AuthorizationStateWaitTdlibParameters::default();
-        inner.td_name = "authorizationStateWaitTdlibParameters".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDAuthorizationStateWaitTdlibParametersBuilder { inner }
+        RTDAuthorizationStateWaitTdlibParametersBuilder {
+            inner: AuthorizationStateWaitTdlibParameters::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDAuthorizationStateWaitTdlibParametersBuilder {
+        RTDAuthorizationStateWaitTdlibParametersBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDAuthorizationStateWaitTdlibParametersBuilder {
+        RTDAuthorizationStateWaitTdlibParametersBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDAuthorizationStateWaitTdlibParametersBuilder {
     inner: AuthorizationStateWaitTdlibParameters,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDAuthorizationStateWaitTdlibParametersBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> AuthorizationStateWaitTdlibParameters {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<AuthorizationStateWaitTdlibParameters> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "AuthorizationStateWaitTdlibParameters",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<AuthorizationStateWaitTdlibParameters> for AuthorizationStateWaitTdlibParameters {
@@ -899,3 +1412,79 @@ impl AsRef<AuthorizationStateWaitTdlibParameters>
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(AuthorizationState::Closed(
+            AuthorizationStateClosed::builder().build(),
+        ));
+        assert_round_trip(AuthorizationState::Closing(
+            AuthorizationStateClosing::builder().build(),
+        ));
+        assert_round_trip(AuthorizationState::LoggingOut(
+            AuthorizationStateLoggingOut::builder().build(),
+        ));
+        assert_round_trip(AuthorizationState::Ready(
+            AuthorizationStateReady::builder().build(),
+        ));
+        assert_round_trip(AuthorizationState::WaitCode(
+            AuthorizationStateWaitCode::builder()
+                .code_info(
+                    AuthenticationCodeInfo::builder()
+                        .phone_number("text")
+                        .type_(AuthenticationCodeType::Call(
+                            AuthenticationCodeTypeCall::builder()
+                                .length(i32::MAX)
+                                .build(),
+                        ))
+                        .timeout(i32::MAX)
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(AuthorizationState::WaitEncryptionKey(
+            AuthorizationStateWaitEncryptionKey::builder()
+                .is_encrypted(true)
+                .build(),
+        ));
+        assert_round_trip(AuthorizationState::WaitOtherDeviceConfirmation(
+            AuthorizationStateWaitOtherDeviceConfirmation::builder()
+                .link("text")
+                .build(),
+        ));
+        assert_round_trip(AuthorizationState::WaitPassword(
+            AuthorizationStateWaitPassword::builder()
+                .password_hint("text")
+                .has_recovery_email_address(true)
+                .recovery_email_address_pattern("text")
+                .build(),
+        ));
+        assert_round_trip(AuthorizationState::WaitPhoneNumber(
+            AuthorizationStateWaitPhoneNumber::builder().build(),
+        ));
+        assert_round_trip(AuthorizationState::WaitRegistration(
+            AuthorizationStateWaitRegistration::builder()
+                .terms_of_service(
+                    TermsOfService::builder()
+                        .text(
+                            FormattedText::builder()
+                                .text("text")
+                                .entities(vec![])
+                                .build(),
+                        )
+                        .min_user_age(i32::MAX)
+                        .show_popup(true)
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(AuthorizationState::WaitTdlibParameters(
+            AuthorizationStateWaitTdlibParameters::builder().build(),
+        ));
+    }
+}
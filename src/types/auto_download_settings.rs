@@ -1,26 +1,26 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains auto-download settings
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "autoDownloadSettings")]
 pub struct AutoDownloadSettings {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// True, if the auto-download is enabled
     is_auto_download_enabled: bool,
     /// The maximum size of a photo file to be auto-downloaded
-    max_photo_file_size: i64,
+    max_photo_file_size: i32,
     /// The maximum size of a video file to be auto-downloaded
     max_video_file_size: i64,
     /// The maximum size of other file types to be auto-downloaded
     max_other_file_size: i64,
     /// The maximum suggested bitrate for uploaded videos
-    video_upload_bitrate: i64,
+    video_upload_bitrate: i32,
     /// True, if the beginning of videos needs to be preloaded for instant playback
     preload_large_videos: bool,
     /// True, if the next audio track needs to be preloaded while the user is listening to an audio file
@@ -43,22 +43,69 @@ impl RObject for AutoDownloadSettings {
     }
 }
 
+impl PartialEq for AutoDownloadSettings {
+    fn eq(&self, other: &Self) -> bool {
+        self.is_auto_download_enabled == other.is_auto_download_enabled
+            && self.max_photo_file_size == other.max_photo_file_size
+            && self.max_video_file_size == other.max_video_file_size
+            && self.max_other_file_size == other.max_other_file_size
+            && self.video_upload_bitrate == other.video_upload_bitrate
+            && self.preload_large_videos == other.preload_large_videos
+            && self.preload_next_audio == other.preload_next_audio
+            && self.use_less_data_for_calls == other.use_less_data_for_calls
+    }
+}
+
+impl Eq for AutoDownloadSettings {}
+
+impl std::hash::Hash for AutoDownloadSettings {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.is_auto_download_enabled, state);
+        std::hash::Hash::hash(&self.max_photo_file_size, state);
+        std::hash::Hash::hash(&self.max_video_file_size, state);
+        std::hash::Hash::hash(&self.max_other_file_size, state);
+        std::hash::Hash::hash(&self.video_upload_bitrate, state);
+        std::hash::Hash::hash(&self.preload_large_videos, state);
+        std::hash::Hash::hash(&self.preload_next_audio, state);
+        std::hash::Hash::hash(&self.use_less_data_for_calls, state);
+    }
+}
+
 impl AutoDownloadSettings {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDAutoDownloadSettingsBuilder {
-        let mut inner = AutoDownloadSettings::default();
-        inner.td_name = "autoDownloadSettings".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDAutoDownloadSettingsBuilder { inner }
+        RTDAutoDownloadSettingsBuilder {
+            inner: AutoDownloadSettings::default(),
+            missing: vec![
+                "max_photo_file_size",
+                "max_video_file_size",
+                "max_other_file_size",
+                "video_upload_bitrate",
+            ],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDAutoDownloadSettingsBuilder {
+        RTDAutoDownloadSettingsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDAutoDownloadSettingsBuilder {
+        RTDAutoDownloadSettingsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn is_auto_download_enabled(&self) -> bool {
         self.is_auto_download_enabled
     }
 
-    pub fn max_photo_file_size(&self) -> i64 {
+    pub fn max_photo_file_size(&self) -> i32 {
         self.max_photo_file_size
     }
 
@@ -70,7 +117,7 @@ impl AutoDownloadSettings {
         self.max_other_file_size
     }
 
-    pub fn video_upload_bitrate(&self) -> i64 {
+    pub fn video_upload_bitrate(&self) -> i32 {
         self.video_upload_bitrate
     }
 
@@ -85,40 +132,97 @@ impl AutoDownloadSettings {
     pub fn use_less_data_for_calls(&self) -> bool {
         self.use_less_data_for_calls
     }
+
+    pub fn with_is_auto_download_enabled(mut self, is_auto_download_enabled: bool) -> Self {
+        self.is_auto_download_enabled = is_auto_download_enabled;
+        self
+    }
+
+    pub fn with_max_photo_file_size(mut self, max_photo_file_size: i32) -> Self {
+        self.max_photo_file_size = max_photo_file_size;
+        self
+    }
+
+    pub fn with_max_video_file_size(mut self, max_video_file_size: i64) -> Self {
+        self.max_video_file_size = max_video_file_size;
+        self
+    }
+
+    pub fn with_max_other_file_size(mut self, max_other_file_size: i64) -> Self {
+        self.max_other_file_size = max_other_file_size;
+        self
+    }
+
+    pub fn with_video_upload_bitrate(mut self, video_upload_bitrate: i32) -> Self {
+        self.video_upload_bitrate = video_upload_bitrate;
+        self
+    }
+
+    pub fn with_preload_large_videos(mut self, preload_large_videos: bool) -> Self {
+        self.preload_large_videos = preload_large_videos;
+        self
+    }
+
+    pub fn with_preload_next_audio(mut self, preload_next_audio: bool) -> Self {
+        self.preload_next_audio = preload_next_audio;
+        self
+    }
+
+    pub fn with_use_less_data_for_calls(mut self, use_less_data_for_calls: bool) -> Self {
+        self.use_less_data_for_calls = use_less_data_for_calls;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDAutoDownloadSettingsBuilder {
     inner: AutoDownloadSettings,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDAutoDownloadSettingsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> AutoDownloadSettings {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<AutoDownloadSettings> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "AutoDownloadSettings",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn is_auto_download_enabled(&mut self, is_auto_download_enabled: bool) -> &mut Self {
         self.inner.is_auto_download_enabled = is_auto_download_enabled;
         self
     }
 
-    pub fn max_photo_file_size(&mut self, max_photo_file_size: i64) -> &mut Self {
+    pub fn max_photo_file_size(&mut self, max_photo_file_size: i32) -> &mut Self {
         self.inner.max_photo_file_size = max_photo_file_size;
+        self.missing.retain(|f| *f != "max_photo_file_size");
         self
     }
 
     pub fn max_video_file_size(&mut self, max_video_file_size: i64) -> &mut Self {
         self.inner.max_video_file_size = max_video_file_size;
+        self.missing.retain(|f| *f != "max_video_file_size");
         self
     }
 
     pub fn max_other_file_size(&mut self, max_other_file_size: i64) -> &mut Self {
         self.inner.max_other_file_size = max_other_file_size;
+        self.missing.retain(|f| *f != "max_other_file_size");
         self
     }
 
-    pub fn video_upload_bitrate(&mut self, video_upload_bitrate: i64) -> &mut Self {
+    pub fn video_upload_bitrate(&mut self, video_upload_bitrate: i32) -> &mut Self {
         self.inner.video_upload_bitrate = video_upload_bitrate;
+        self.missing.retain(|f| *f != "video_upload_bitrate");
         self
     }
 
@@ -149,3 +253,25 @@ impl AsRef<AutoDownloadSettings> for RTDAutoDownloadSettingsBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            AutoDownloadSettings::builder()
+                .is_auto_download_enabled(true)
+                .max_photo_file_size(i32::MAX)
+                .max_video_file_size(9007199254740991i64)
+                .max_other_file_size(9007199254740991i64)
+                .video_upload_bitrate(i32::MAX)
+                .preload_large_videos(true)
+                .preload_next_audio(true)
+                .use_less_data_for_calls(true)
+                .build(),
+        );
+    }
+}
@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains auto-download settings presets for the user
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "autoDownloadSettingsPresets")]
 pub struct AutoDownloadSettingsPresets {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Preset with lowest settings; supposed to be used by default when roaming
     low: AutoDownloadSettings,
@@ -33,15 +33,45 @@ impl RObject for AutoDownloadSettingsPresets {
     }
 }
 
+impl PartialEq for AutoDownloadSettingsPresets {
+    fn eq(&self, other: &Self) -> bool {
+        self.low == other.low && self.medium == other.medium && self.high == other.high
+    }
+}
+
+impl Eq for AutoDownloadSettingsPresets {}
+
+impl std::hash::Hash for AutoDownloadSettingsPresets {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.low, state);
+        std::hash::Hash::hash(&self.medium, state);
+        std::hash::Hash::hash(&self.high, state);
+    }
+}
+
 impl AutoDownloadSettingsPresets {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDAutoDownloadSettingsPresetsBuilder {
-        let mut inner = AutoDownloadSettingsPresets::default();
-        inner.td_name = "autoDownloadSettingsPresets".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDAutoDownloadSettingsPresetsBuilder { inner }
+        RTDAutoDownloadSettingsPresetsBuilder {
+            inner: AutoDownloadSettingsPresets::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDAutoDownloadSettingsPresetsBuilder {
+        RTDAutoDownloadSettingsPresetsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDAutoDownloadSettingsPresetsBuilder {
+        RTDAutoDownloadSettingsPresetsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn low(&self) -> &AutoDownloadSettings {
@@ -55,17 +85,57 @@ impl AutoDownloadSettingsPresets {
     pub fn high(&self) -> &AutoDownloadSettings {
         &self.high
     }
+
+    pub fn into_low(self) -> AutoDownloadSettings {
+        self.low
+    }
+
+    pub fn into_medium(self) -> AutoDownloadSettings {
+        self.medium
+    }
+
+    pub fn into_high(self) -> AutoDownloadSettings {
+        self.high
+    }
+
+    pub fn with_low<T: AsRef<AutoDownloadSettings>>(mut self, low: T) -> Self {
+        self.low = low.as_ref().clone();
+        self
+    }
+
+    pub fn with_medium<T: AsRef<AutoDownloadSettings>>(mut self, medium: T) -> Self {
+        self.medium = medium.as_ref().clone();
+        self
+    }
+
+    pub fn with_high<T: AsRef<AutoDownloadSettings>>(mut self, high: T) -> Self {
+        self.high = high.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDAutoDownloadSettingsPresetsBuilder {
     inner: AutoDownloadSettingsPresets,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDAutoDownloadSettingsPresetsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> AutoDownloadSettingsPresets {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<AutoDownloadSettingsPresets> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "AutoDownloadSettingsPresets",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn low<T: AsRef<AutoDownloadSettings>>(&mut self, low: T) -> &mut Self {
         self.inner.low = low.as_ref().clone();
@@ -94,3 +164,53 @@ impl AsRef<AutoDownloadSettingsPresets> for RTDAutoDownloadSettingsPresetsBuilde
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            AutoDownloadSettingsPresets::builder()
+                .low(
+                    AutoDownloadSettings::builder()
+                        .is_auto_download_enabled(true)
+                        .max_photo_file_size(i32::MAX)
+                        .max_video_file_size(9007199254740991i64)
+                        .max_other_file_size(9007199254740991i64)
+                        .video_upload_bitrate(i32::MAX)
+                        .preload_large_videos(true)
+                        .preload_next_audio(true)
+                        .use_less_data_for_calls(true)
+                        .build(),
+                )
+                .medium(
+                    AutoDownloadSettings::builder()
+                        .is_auto_download_enabled(true)
+                        .max_photo_file_size(i32::MAX)
+                        .max_video_file_size(9007199254740991i64)
+                        .max_other_file_size(9007199254740991i64)
+                        .video_upload_bitrate(i32::MAX)
+                        .preload_large_videos(true)
+                        .preload_next_audio(true)
+                        .use_less_data_for_calls(true)
+                        .build(),
+                )
+                .high(
+                    AutoDownloadSettings::builder()
+                        .is_auto_download_enabled(true)
+                        .max_photo_file_size(i32::MAX)
+                        .max_video_file_size(9007199254740991i64)
+                        .max_other_file_size(9007199254740991i64)
+                        .video_upload_bitrate(i32::MAX)
+                        .preload_large_videos(true)
+                        .preload_next_audio(true)
+                        .use_less_data_for_calls(true)
+                        .build(),
+                )
+                .build(),
+        );
+    }
+}
@@ -1,18 +1,19 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Describes a chat background
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "background")]
 pub struct Background {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Unique background identifier
-    id: isize,
+    #[serde(with = "super::_common::int64")]
+    id: i64,
     /// True, if this is one of default backgrounds
     is_default: bool,
     /// True, if the background is dark and is recommended to be used with dark theme
@@ -40,18 +41,56 @@ impl RObject for Background {
     }
 }
 
+impl PartialEq for Background {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.is_default == other.is_default
+            && self.is_dark == other.is_dark
+            && self.name == other.name
+            && self.document == other.document
+            && self.type_ == other.type_
+    }
+}
+
+impl Eq for Background {}
+
+impl std::hash::Hash for Background {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.id, state);
+        std::hash::Hash::hash(&self.is_default, state);
+        std::hash::Hash::hash(&self.is_dark, state);
+        std::hash::Hash::hash(&self.name, state);
+        std::hash::Hash::hash(&self.document, state);
+        std::hash::Hash::hash(&self.type_, state);
+    }
+}
+
 impl Background {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDBackgroundBuilder {
-        let mut inner = Background::default();
-        inner.td_name = "background".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDBackgroundBuilder { inner }
-    }
-
-    pub fn id(&self) -> isize {
+        RTDBackgroundBuilder {
+            inner: Background::default(),
+            missing: vec!["id", "name", "type_"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDBackgroundBuilder {
+        RTDBackgroundBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDBackgroundBuilder {
+        RTDBackgroundBuilder {
+            inner: self,
+            missing: vec![],
+        }
+    }
+
+    pub fn id(&self) -> i64 {
         self.id
     }
 
@@ -74,20 +113,73 @@ impl Background {
     pub fn type_(&self) -> &BackgroundType {
         &self.type_
     }
+
+    pub fn into_name(self) -> String {
+        self.name
+    }
+
+    pub fn into_document(self) -> Option<Document> {
+        self.document
+    }
+
+    pub fn into_type(self) -> BackgroundType {
+        self.type_
+    }
+
+    pub fn with_id(mut self, id: i64) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn with_is_default(mut self, is_default: bool) -> Self {
+        self.is_default = is_default;
+        self
+    }
+
+    pub fn with_is_dark(mut self, is_dark: bool) -> Self {
+        self.is_dark = is_dark;
+        self
+    }
+
+    pub fn with_name<T: AsRef<str>>(mut self, name: T) -> Self {
+        self.name = name.as_ref().to_string();
+        self
+    }
+
+    pub fn with_document<T: AsRef<Document>>(mut self, document: T) -> Self {
+        self.document = Some(document.as_ref().clone());
+        self
+    }
+
+    pub fn with_type<T: AsRef<BackgroundType>>(mut self, type_: T) -> Self {
+        self.type_ = type_.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDBackgroundBuilder {
     inner: Background,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDBackgroundBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> Background {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<Background> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("Background", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn id(&mut self, id: isize) -> &mut Self {
+    pub fn id(&mut self, id: i64) -> &mut Self {
         self.inner.id = id;
+        self.missing.retain(|f| *f != "id");
         self
     }
 
@@ -103,6 +195,7 @@ impl RTDBackgroundBuilder {
 
     pub fn name<T: AsRef<str>>(&mut self, name: T) -> &mut Self {
         self.inner.name = name.as_ref().to_string();
+        self.missing.retain(|f| *f != "name");
         self
     }
 
@@ -113,6 +206,7 @@ impl RTDBackgroundBuilder {
 
     pub fn type_<T: AsRef<BackgroundType>>(&mut self, type_: T) -> &mut Self {
         self.inner.type_ = type_.as_ref().clone();
+        self.missing.retain(|f| *f != "type_");
         self
     }
 }
@@ -128,3 +222,27 @@ impl AsRef<Background> for RTDBackgroundBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            Background::builder()
+                .id(i64::MAX)
+                .is_default(true)
+                .is_dark(true)
+                .name("text")
+                .type_(BackgroundType::Wallpaper(
+                    BackgroundTypeWallpaper::builder()
+                        .is_blurred(true)
+                        .is_moving(true)
+                        .build(),
+                ))
+                .build(),
+        );
+    }
+}
@@ -1,43 +1,40 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Describes a fill of a background
 pub trait TDBackgroundFill: Debug + RObject {}
 
 /// Describes a fill of a background
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum BackgroundFill {
-    #[doc(hidden)]
-    _Default(()),
     /// Describes a gradient fill of a background
+    #[serde(rename = "backgroundFillGradient")]
     Gradient(BackgroundFillGradient),
     /// Describes a solid fill of a background
+    #[serde(rename = "backgroundFillSolid")]
     Solid(BackgroundFillSolid),
 }
 
+/// Default value is default of [BackgroundFillGradient], builders still require the field to be set
 impl Default for BackgroundFill {
     fn default() -> Self {
-        BackgroundFill::_Default(())
+        BackgroundFill::Gradient(BackgroundFillGradient::default())
     }
 }
 
-impl<'de> Deserialize<'de> for BackgroundFill {
-    fn deserialize<D>(deserializer: D) -> Result<BackgroundFill, D::Error>
+impl Serialize for BackgroundFill {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          BackgroundFill,
-          (backgroundFillGradient, Gradient);
-          (backgroundFillSolid, Solid);
-
-        )(deserializer)
+        match self {
+            BackgroundFill::Gradient(t) => t.serialize(serializer),
+            BackgroundFill::Solid(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -47,8 +44,6 @@ impl RObject for BackgroundFill {
         match self {
             BackgroundFill::Gradient(t) => t.td_name(),
             BackgroundFill::Solid(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -56,8 +51,6 @@ impl RObject for BackgroundFill {
         match self {
             BackgroundFill::Gradient(t) => t.extra(),
             BackgroundFill::Solid(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -69,10 +62,6 @@ impl BackgroundFill {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, BackgroundFill::_Default(_))
-    }
 }
 
 impl AsRef<BackgroundFill> for BackgroundFill {
@@ -83,19 +72,20 @@ impl AsRef<BackgroundFill> for BackgroundFill {
 
 /// Describes a gradient fill of a background
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "backgroundFillGradient")]
 pub struct BackgroundFillGradient {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// A top color of the background in the RGB24 format
-    top_color: i64,
+    top_color: i32,
     /// A bottom color of the background in the RGB24 format
-    bottom_color: i64,
+    bottom_color: i32,
     /// Clockwise rotation angle of the gradient, in degrees; 0-359. Should be always divisible by 45
-    rotation_angle: i64,
+    rotation_angle: i32,
 }
 
 impl RObject for BackgroundFillGradient {
@@ -112,6 +102,24 @@ impl RObject for BackgroundFillGradient {
     }
 }
 
+impl PartialEq for BackgroundFillGradient {
+    fn eq(&self, other: &Self) -> bool {
+        self.top_color == other.top_color
+            && self.bottom_color == other.bottom_color
+            && self.rotation_angle == other.rotation_angle
+    }
+}
+
+impl Eq for BackgroundFillGradient {}
+
+impl std::hash::Hash for BackgroundFillGradient {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.top_color, state);
+        std::hash::Hash::hash(&self.bottom_color, state);
+        std::hash::Hash::hash(&self.rotation_angle, state);
+    }
+}
+
 impl TDBackgroundFill for BackgroundFillGradient {}
 
 impl BackgroundFillGradient {
@@ -119,46 +127,90 @@ impl BackgroundFillGradient {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDBackgroundFillGradientBuilder {
-        let mut inner = BackgroundFillGradient::default();
-        inner.td_name = "backgroundFillGradient".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDBackgroundFillGradientBuilder { inner }
+        RTDBackgroundFillGradientBuilder {
+            inner: BackgroundFillGradient::default(),
+            missing: vec!["top_color", "bottom_color"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDBackgroundFillGradientBuilder {
+        RTDBackgroundFillGradientBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDBackgroundFillGradientBuilder {
+        RTDBackgroundFillGradientBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn top_color(&self) -> i64 {
+    pub fn top_color(&self) -> i32 {
         self.top_color
     }
 
-    pub fn bottom_color(&self) -> i64 {
+    pub fn bottom_color(&self) -> i32 {
         self.bottom_color
     }
 
-    pub fn rotation_angle(&self) -> i64 {
+    pub fn rotation_angle(&self) -> i32 {
         self.rotation_angle
     }
+
+    pub fn with_top_color(mut self, top_color: i32) -> Self {
+        self.top_color = top_color;
+        self
+    }
+
+    pub fn with_bottom_color(mut self, bottom_color: i32) -> Self {
+        self.bottom_color = bottom_color;
+        self
+    }
+
+    pub fn with_rotation_angle(mut self, rotation_angle: i32) -> Self {
+        self.rotation_angle = rotation_angle;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDBackgroundFillGradientBuilder {
     inner: BackgroundFillGradient,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDBackgroundFillGradientBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> BackgroundFillGradient {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<BackgroundFillGradient> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "BackgroundFillGradient",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn top_color(&mut self, top_color: i64) -> &mut Self {
+    pub fn top_color(&mut self, top_color: i32) -> &mut Self {
         self.inner.top_color = top_color;
+        self.missing.retain(|f| *f != "top_color");
         self
     }
 
-    pub fn bottom_color(&mut self, bottom_color: i64) -> &mut Self {
+    pub fn bottom_color(&mut self, bottom_color: i32) -> &mut Self {
         self.inner.bottom_color = bottom_color;
+        self.missing.retain(|f| *f != "bottom_color");
         self
     }
 
-    pub fn rotation_angle(&mut self, rotation_angle: i64) -> &mut Self {
+    pub fn rotation_angle(&mut self, rotation_angle: i32) -> &mut Self {
         self.inner.rotation_angle = rotation_angle;
         self
     }
@@ -178,15 +230,16 @@ impl AsRef<BackgroundFillGradient> for RTDBackgroundFillGradientBuilder {
 
 /// Describes a solid fill of a background
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "backgroundFillSolid")]
 pub struct BackgroundFillSolid {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// A color of the background in the RGB24 format
-    color: i64,
+    color: i32,
 }
 
 impl RObject for BackgroundFillSolid {
@@ -203,6 +256,20 @@ impl RObject for BackgroundFillSolid {
     }
 }
 
+impl PartialEq for BackgroundFillSolid {
+    fn eq(&self, other: &Self) -> bool {
+        self.color == other.color
+    }
+}
+
+impl Eq for BackgroundFillSolid {}
+
+impl std::hash::Hash for BackgroundFillSolid {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.color, state);
+    }
+}
+
 impl TDBackgroundFill for BackgroundFillSolid {}
 
 impl BackgroundFillSolid {
@@ -210,29 +277,62 @@ impl BackgroundFillSolid {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDBackgroundFillSolidBuilder {
-        let mut inner = BackgroundFillSolid::default();
-        inner.td_name = "backgroundFillSolid".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDBackgroundFillSolidBuilder { inner }
+        RTDBackgroundFillSolidBuilder {
+            inner: BackgroundFillSolid::default(),
+            missing: vec!["color"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDBackgroundFillSolidBuilder {
+        RTDBackgroundFillSolidBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDBackgroundFillSolidBuilder {
+        RTDBackgroundFillSolidBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn color(&self) -> i64 {
+    pub fn color(&self) -> i32 {
         self.color
     }
+
+    pub fn with_color(mut self, color: i32) -> Self {
+        self.color = color;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDBackgroundFillSolidBuilder {
     inner: BackgroundFillSolid,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDBackgroundFillSolidBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> BackgroundFillSolid {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<BackgroundFillSolid> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "BackgroundFillSolid",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn color(&mut self, color: i64) -> &mut Self {
+    pub fn color(&mut self, color: i32) -> &mut Self {
         self.inner.color = color;
+        self.missing.retain(|f| *f != "color");
         self
     }
 }
@@ -248,3 +348,23 @@ impl AsRef<BackgroundFillSolid> for RTDBackgroundFillSolidBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(BackgroundFill::Gradient(
+            BackgroundFillGradient::builder()
+                .top_color(i32::MAX)
+                .bottom_color(i32::MAX)
+                .rotation_angle(i32::MAX)
+                .build(),
+        ));
+        assert_round_trip(BackgroundFill::Solid(
+            BackgroundFillSolid::builder().color(i32::MAX).build(),
+        ));
+    }
+}
@@ -1,46 +1,44 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Describes the type of a background
 pub trait TDBackgroundType: Debug + RObject {}
 
 /// Describes the type of a background
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum BackgroundType {
-    #[doc(hidden)]
-    _Default(()),
     /// A filled background
+    #[serde(rename = "backgroundTypeFill")]
     Fill(BackgroundTypeFill),
     /// A PNG or TGV (gzipped subset of SVG with MIME type "application/x-tgwallpattern") pattern to be combined with the background fill chosen by the user
+    #[serde(rename = "backgroundTypePattern")]
     Pattern(BackgroundTypePattern),
     /// A wallpaper in JPEG format
+    #[serde(rename = "backgroundTypeWallpaper")]
     Wallpaper(BackgroundTypeWallpaper),
 }
 
+/// Default value is default of [BackgroundTypeWallpaper], builders still require the field to be set
 impl Default for BackgroundType {
     fn default() -> Self {
-        BackgroundType::_Default(())
+        BackgroundType::Wallpaper(BackgroundTypeWallpaper::default())
     }
 }
 
-impl<'de> Deserialize<'de> for BackgroundType {
-    fn deserialize<D>(deserializer: D) -> Result<BackgroundType, D::Error>
+impl Serialize for BackgroundType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          BackgroundType,
-          (backgroundTypeFill, Fill);
-          (backgroundTypePattern, Pattern);
-          (backgroundTypeWallpaper, Wallpaper);
-
-        )(deserializer)
+        match self {
+            BackgroundType::Fill(t) => t.serialize(serializer),
+            BackgroundType::Pattern(t) => t.serialize(serializer),
+            BackgroundType::Wallpaper(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -51,8 +49,6 @@ impl RObject for BackgroundType {
             BackgroundType::Fill(t) => t.td_name(),
             BackgroundType::Pattern(t) => t.td_name(),
             BackgroundType::Wallpaper(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -61,8 +57,6 @@ impl RObject for BackgroundType {
             BackgroundType::Fill(t) => t.extra(),
             BackgroundType::Pattern(t) => t.extra(),
             BackgroundType::Wallpaper(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -74,10 +68,6 @@ impl BackgroundType {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, BackgroundType::_Default(_))
-    }
 }
 
 impl AsRef<BackgroundType> for BackgroundType {
@@ -88,12 +78,13 @@ impl AsRef<BackgroundType> for BackgroundType {
 
 /// A filled background
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "backgroundTypeFill")]
 pub struct BackgroundTypeFill {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Description of the background fill
     fill: BackgroundFill,
@@ -113,6 +104,20 @@ impl RObject for BackgroundTypeFill {
     }
 }
 
+impl PartialEq for BackgroundTypeFill {
+    fn eq(&self, other: &Self) -> bool {
+        self.fill == other.fill
+    }
+}
+
+impl Eq for BackgroundTypeFill {}
+
+impl std::hash::Hash for BackgroundTypeFill {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.fill, state);
+    }
+}
+
 impl TDBackgroundType for BackgroundTypeFill {}
 
 impl BackgroundTypeFill {
@@ -120,29 +125,66 @@ impl BackgroundTypeFill {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDBackgroundTypeFillBuilder {
-        let mut inner = BackgroundTypeFill::default();
-        inner.td_name = "backgroundTypeFill".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDBackgroundTypeFillBuilder { inner }
+        RTDBackgroundTypeFillBuilder {
+            inner: BackgroundTypeFill::default(),
+            missing: vec!["fill"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDBackgroundTypeFillBuilder {
+        RTDBackgroundTypeFillBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDBackgroundTypeFillBuilder {
+        RTDBackgroundTypeFillBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn fill(&self) -> &BackgroundFill {
         &self.fill
     }
+
+    pub fn into_fill(self) -> BackgroundFill {
+        self.fill
+    }
+
+    pub fn with_fill<T: AsRef<BackgroundFill>>(mut self, fill: T) -> Self {
+        self.fill = fill.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDBackgroundTypeFillBuilder {
     inner: BackgroundTypeFill,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDBackgroundTypeFillBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> BackgroundTypeFill {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<BackgroundTypeFill> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "BackgroundTypeFill",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn fill<T: AsRef<BackgroundFill>>(&mut self, fill: T) -> &mut Self {
         self.inner.fill = fill.as_ref().clone();
+        self.missing.retain(|f| *f != "fill");
         self
     }
 }
@@ -161,17 +203,18 @@ impl AsRef<BackgroundTypeFill> for RTDBackgroundTypeFillBuilder {
 
 /// A PNG or TGV (gzipped subset of SVG with MIME type "application/x-tgwallpattern") pattern to be combined with the background fill chosen by the user
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "backgroundTypePattern")]
 pub struct BackgroundTypePattern {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Description of the background fill
     fill: BackgroundFill,
     /// Intensity of the pattern when it is shown above the filled background, 0-100
-    intensity: i64,
+    intensity: i32,
     /// True, if the background needs to be slightly moved when device is tilted
     is_moving: bool,
 }
@@ -190,6 +233,24 @@ impl RObject for BackgroundTypePattern {
     }
 }
 
+impl PartialEq for BackgroundTypePattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.fill == other.fill
+            && self.intensity == other.intensity
+            && self.is_moving == other.is_moving
+    }
+}
+
+impl Eq for BackgroundTypePattern {}
+
+impl std::hash::Hash for BackgroundTypePattern {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.fill, state);
+        std::hash::Hash::hash(&self.intensity, state);
+        std::hash::Hash::hash(&self.is_moving, state);
+    }
+}
+
 impl TDBackgroundType for BackgroundTypePattern {}
 
 impl BackgroundTypePattern {
@@ -197,41 +258,88 @@ impl BackgroundTypePattern {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDBackgroundTypePatternBuilder {
-        let mut inner = BackgroundTypePattern::default();
-        inner.td_name = "backgroundTypePattern".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDBackgroundTypePatternBuilder { inner }
+        RTDBackgroundTypePatternBuilder {
+            inner: BackgroundTypePattern::default(),
+            missing: vec!["fill"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDBackgroundTypePatternBuilder {
+        RTDBackgroundTypePatternBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDBackgroundTypePatternBuilder {
+        RTDBackgroundTypePatternBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn fill(&self) -> &BackgroundFill {
         &self.fill
     }
 
-    pub fn intensity(&self) -> i64 {
+    pub fn intensity(&self) -> i32 {
         self.intensity
     }
 
     pub fn is_moving(&self) -> bool {
         self.is_moving
     }
+
+    pub fn into_fill(self) -> BackgroundFill {
+        self.fill
+    }
+
+    pub fn with_fill<T: AsRef<BackgroundFill>>(mut self, fill: T) -> Self {
+        self.fill = fill.as_ref().clone();
+        self
+    }
+
+    pub fn with_intensity(mut self, intensity: i32) -> Self {
+        self.intensity = intensity;
+        self
+    }
+
+    pub fn with_is_moving(mut self, is_moving: bool) -> Self {
+        self.is_moving = is_moving;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDBackgroundTypePatternBuilder {
     inner: BackgroundTypePattern,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDBackgroundTypePatternBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> BackgroundTypePattern {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<BackgroundTypePattern> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "BackgroundTypePattern",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn fill<T: AsRef<BackgroundFill>>(&mut self, fill: T) -> &mut Self {
         self.inner.fill = fill.as_ref().clone();
+        self.missing.retain(|f| *f != "fill");
         self
     }
 
-    pub fn intensity(&mut self, intensity: i64) -> &mut Self {
+    pub fn intensity(&mut self, intensity: i32) -> &mut Self {
         self.inner.intensity = intensity;
         self
     }
@@ -256,12 +364,13 @@ impl AsRef<BackgroundTypePattern> for RTDBackgroundTypePatternBuilder {
 
 /// A wallpaper in JPEG format
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "backgroundTypeWallpaper")]
 pub struct BackgroundTypeWallpaper {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// True, if the wallpaper must be downscaled to fit in 450x450 square and then box-blurred with radius 12
     is_blurred: bool,
@@ -283,6 +392,21 @@ impl RObject for BackgroundTypeWallpaper {
     }
 }
 
+impl PartialEq for BackgroundTypeWallpaper {
+    fn eq(&self, other: &Self) -> bool {
+        self.is_blurred == other.is_blurred && self.is_moving == other.is_moving
+    }
+}
+
+impl Eq for BackgroundTypeWallpaper {}
+
+impl std::hash::Hash for BackgroundTypeWallpaper {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.is_blurred, state);
+        std::hash::Hash::hash(&self.is_moving, state);
+    }
+}
+
 impl TDBackgroundType for BackgroundTypeWallpaper {}
 
 impl BackgroundTypeWallpaper {
@@ -290,10 +414,24 @@ impl BackgroundTypeWallpaper {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDBackgroundTypeWallpaperBuilder {
-        let mut inner = BackgroundTypeWallpaper::default();
-        inner.td_name = "backgroundTypeWallpaper".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDBackgroundTypeWallpaperBuilder { inner }
+        RTDBackgroundTypeWallpaperBuilder {
+            inner: BackgroundTypeWallpaper::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDBackgroundTypeWallpaperBuilder {
+        RTDBackgroundTypeWallpaperBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDBackgroundTypeWallpaperBuilder {
+        RTDBackgroundTypeWallpaperBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn is_blurred(&self) -> bool {
@@ -303,17 +441,40 @@ impl BackgroundTypeWallpaper {
     pub fn is_moving(&self) -> bool {
         self.is_moving
     }
+
+    pub fn with_is_blurred(mut self, is_blurred: bool) -> Self {
+        self.is_blurred = is_blurred;
+        self
+    }
+
+    pub fn with_is_moving(mut self, is_moving: bool) -> Self {
+        self.is_moving = is_moving;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDBackgroundTypeWallpaperBuilder {
     inner: BackgroundTypeWallpaper,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDBackgroundTypeWallpaperBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> BackgroundTypeWallpaper {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<BackgroundTypeWallpaper> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "BackgroundTypeWallpaper",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn is_blurred(&mut self, is_blurred: bool) -> &mut Self {
         self.inner.is_blurred = is_blurred;
@@ -337,3 +498,43 @@ impl AsRef<BackgroundTypeWallpaper> for RTDBackgroundTypeWallpaperBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(BackgroundType::Fill(
+            BackgroundTypeFill::builder()
+                .fill(BackgroundFill::Gradient(
+                    BackgroundFillGradient::builder()
+                        .top_color(i32::MAX)
+                        .bottom_color(i32::MAX)
+                        .rotation_angle(i32::MAX)
+                        .build(),
+                ))
+                .build(),
+        ));
+        assert_round_trip(BackgroundType::Pattern(
+            BackgroundTypePattern::builder()
+                .fill(BackgroundFill::Gradient(
+                    BackgroundFillGradient::builder()
+                        .top_color(i32::MAX)
+                        .bottom_color(i32::MAX)
+                        .rotation_angle(i32::MAX)
+                        .build(),
+                ))
+                .intensity(i32::MAX)
+                .is_moving(true)
+                .build(),
+        ));
+        assert_round_trip(BackgroundType::Wallpaper(
+            BackgroundTypeWallpaper::builder()
+                .is_blurred(true)
+                .is_moving(true)
+                .build(),
+        ));
+    }
+}
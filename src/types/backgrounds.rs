@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains a list of backgrounds
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "backgrounds")]
 pub struct Backgrounds {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// A list of backgrounds
     backgrounds: Vec<Background>,
@@ -29,34 +29,82 @@ impl RObject for Backgrounds {
     }
 }
 
+impl PartialEq for Backgrounds {
+    fn eq(&self, other: &Self) -> bool {
+        self.backgrounds == other.backgrounds
+    }
+}
+
+impl Eq for Backgrounds {}
+
+impl std::hash::Hash for Backgrounds {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.backgrounds, state);
+    }
+}
+
 impl Backgrounds {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDBackgroundsBuilder {
-        let mut inner = Backgrounds::default();
-        inner.td_name = "backgrounds".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDBackgroundsBuilder { inner }
+        RTDBackgroundsBuilder {
+            inner: Backgrounds::default(),
+            missing: vec!["backgrounds"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDBackgroundsBuilder {
+        RTDBackgroundsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDBackgroundsBuilder {
+        RTDBackgroundsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn backgrounds(&self) -> &Vec<Background> {
         &self.backgrounds
     }
+
+    pub fn into_backgrounds(self) -> Vec<Background> {
+        self.backgrounds
+    }
+
+    pub fn with_backgrounds(mut self, backgrounds: Vec<Background>) -> Self {
+        self.backgrounds = backgrounds;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDBackgroundsBuilder {
     inner: Backgrounds,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDBackgroundsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> Backgrounds {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<Backgrounds> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("Backgrounds", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn backgrounds(&mut self, backgrounds: Vec<Background>) -> &mut Self {
         self.inner.backgrounds = backgrounds;
+        self.missing.retain(|f| *f != "backgrounds");
         self
     }
 }
@@ -72,3 +120,14 @@ impl AsRef<Backgrounds> for RTDBackgroundsBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(Backgrounds::builder().backgrounds(vec![]).build());
+    }
+}
@@ -1,26 +1,26 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Represents a basic group of 0-200 users (must be upgraded to a supergroup to accommodate more than 200 users)
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "basicGroup")]
 pub struct BasicGroup {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Group identifier
-    id: i64,
+    id: BasicGroupId,
     /// Number of members in the group
-    member_count: i64,
+    member_count: i32,
     /// Status of the current user in the group
     status: ChatMemberStatus,
     /// True, if the group is active
     is_active: bool,
     /// Identifier of the supergroup to which this group was upgraded; 0 if none
-    upgraded_to_supergroup_id: i64,
+    upgraded_to_supergroup_id: SupergroupId,
 }
 
 impl RObject for BasicGroup {
@@ -37,22 +37,58 @@ impl RObject for BasicGroup {
     }
 }
 
+impl PartialEq for BasicGroup {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.member_count == other.member_count
+            && self.status == other.status
+            && self.is_active == other.is_active
+            && self.upgraded_to_supergroup_id == other.upgraded_to_supergroup_id
+    }
+}
+
+impl Eq for BasicGroup {}
+
+impl std::hash::Hash for BasicGroup {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.id, state);
+        std::hash::Hash::hash(&self.member_count, state);
+        std::hash::Hash::hash(&self.status, state);
+        std::hash::Hash::hash(&self.is_active, state);
+        std::hash::Hash::hash(&self.upgraded_to_supergroup_id, state);
+    }
+}
+
 impl BasicGroup {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDBasicGroupBuilder {
-        let mut inner = BasicGroup::default();
-        inner.td_name = "basicGroup".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDBasicGroupBuilder { inner }
-    }
-
-    pub fn id(&self) -> i64 {
+        RTDBasicGroupBuilder {
+            inner: BasicGroup::default(),
+            missing: vec!["id", "member_count", "status"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDBasicGroupBuilder {
+        RTDBasicGroupBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDBasicGroupBuilder {
+        RTDBasicGroupBuilder {
+            inner: self,
+            missing: vec![],
+        }
+    }
+
+    pub fn id(&self) -> BasicGroupId {
         self.id
     }
 
-    pub fn member_count(&self) -> i64 {
+    pub fn member_count(&self) -> i32 {
         self.member_count
     }
 
@@ -64,33 +100,78 @@ impl BasicGroup {
         self.is_active
     }
 
-    pub fn upgraded_to_supergroup_id(&self) -> i64 {
+    pub fn upgraded_to_supergroup_id(&self) -> SupergroupId {
         self.upgraded_to_supergroup_id
     }
+
+    pub fn into_status(self) -> ChatMemberStatus {
+        self.status
+    }
+
+    pub fn with_id<T: Into<BasicGroupId>>(mut self, id: T) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    pub fn with_member_count(mut self, member_count: i32) -> Self {
+        self.member_count = member_count;
+        self
+    }
+
+    pub fn with_status<T: AsRef<ChatMemberStatus>>(mut self, status: T) -> Self {
+        self.status = status.as_ref().clone();
+        self
+    }
+
+    pub fn with_is_active(mut self, is_active: bool) -> Self {
+        self.is_active = is_active;
+        self
+    }
+
+    pub fn with_upgraded_to_supergroup_id<T: Into<SupergroupId>>(
+        mut self,
+        upgraded_to_supergroup_id: T,
+    ) -> Self {
+        self.upgraded_to_supergroup_id = upgraded_to_supergroup_id.into();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDBasicGroupBuilder {
     inner: BasicGroup,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDBasicGroupBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> BasicGroup {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<BasicGroup> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("BasicGroup", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn id(&mut self, id: i64) -> &mut Self {
-        self.inner.id = id;
+    pub fn id<T: Into<BasicGroupId>>(&mut self, id: T) -> &mut Self {
+        self.inner.id = id.into();
+        self.missing.retain(|f| *f != "id");
         self
     }
 
-    pub fn member_count(&mut self, member_count: i64) -> &mut Self {
+    pub fn member_count(&mut self, member_count: i32) -> &mut Self {
         self.inner.member_count = member_count;
+        self.missing.retain(|f| *f != "member_count");
         self
     }
 
     pub fn status<T: AsRef<ChatMemberStatus>>(&mut self, status: T) -> &mut Self {
         self.inner.status = status.as_ref().clone();
+        self.missing.retain(|f| *f != "status");
         self
     }
 
@@ -99,8 +180,11 @@ impl RTDBasicGroupBuilder {
         self
     }
 
-    pub fn upgraded_to_supergroup_id(&mut self, upgraded_to_supergroup_id: i64) -> &mut Self {
-        self.inner.upgraded_to_supergroup_id = upgraded_to_supergroup_id;
+    pub fn upgraded_to_supergroup_id<T: Into<SupergroupId>>(
+        &mut self,
+        upgraded_to_supergroup_id: T,
+    ) -> &mut Self {
+        self.inner.upgraded_to_supergroup_id = upgraded_to_supergroup_id.into();
         self
     }
 }
@@ -116,3 +200,35 @@ impl AsRef<BasicGroup> for RTDBasicGroupBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            BasicGroup::builder()
+                .id(i32::MAX)
+                .member_count(i32::MAX)
+                .status(ChatMemberStatus::Administrator(
+                    ChatMemberStatusAdministrator::builder()
+                        .custom_title("text")
+                        .can_be_edited(true)
+                        .can_change_info(true)
+                        .can_post_messages(true)
+                        .can_edit_messages(true)
+                        .can_delete_messages(true)
+                        .can_invite_users(true)
+                        .can_restrict_members(true)
+                        .can_pin_messages(true)
+                        .can_promote_members(true)
+                        .build(),
+                ))
+                .is_active(true)
+                .upgraded_to_supergroup_id(i32::MAX)
+                .build(),
+        );
+    }
+}
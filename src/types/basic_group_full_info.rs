@@ -1,20 +1,20 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains full information about a basic group
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "basicGroupFullInfo")]
 pub struct BasicGroupFullInfo {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Contains full information about a basic group
     description: String,
     /// User identifier of the creator of the group; 0 if unknown
-    creator_user_id: i64,
+    creator_user_id: UserId,
     /// Group members
     members: Vec<ChatMember>,
     /// Invite link for this group; available only after it has been generated at least once and only for the group creator
@@ -35,22 +35,56 @@ impl RObject for BasicGroupFullInfo {
     }
 }
 
+impl PartialEq for BasicGroupFullInfo {
+    fn eq(&self, other: &Self) -> bool {
+        self.description == other.description
+            && self.creator_user_id == other.creator_user_id
+            && self.members == other.members
+            && self.invite_link == other.invite_link
+    }
+}
+
+impl Eq for BasicGroupFullInfo {}
+
+impl std::hash::Hash for BasicGroupFullInfo {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.description, state);
+        std::hash::Hash::hash(&self.creator_user_id, state);
+        std::hash::Hash::hash(&self.members, state);
+        std::hash::Hash::hash(&self.invite_link, state);
+    }
+}
+
 impl BasicGroupFullInfo {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDBasicGroupFullInfoBuilder {
-        let mut inner = BasicGroupFullInfo::default();
-        inner.td_name = "basicGroupFullInfo".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDBasicGroupFullInfoBuilder { inner }
+        RTDBasicGroupFullInfoBuilder {
+            inner: BasicGroupFullInfo::default(),
+            missing: vec!["description", "members", "invite_link"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDBasicGroupFullInfoBuilder {
+        RTDBasicGroupFullInfoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDBasicGroupFullInfoBuilder {
+        RTDBasicGroupFullInfoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn description(&self) -> &String {
         &self.description
     }
 
-    pub fn creator_user_id(&self) -> i64 {
+    pub fn creator_user_id(&self) -> UserId {
         self.creator_user_id
     }
 
@@ -61,35 +95,83 @@ impl BasicGroupFullInfo {
     pub fn invite_link(&self) -> &String {
         &self.invite_link
     }
+
+    pub fn into_description(self) -> String {
+        self.description
+    }
+
+    pub fn into_members(self) -> Vec<ChatMember> {
+        self.members
+    }
+
+    pub fn into_invite_link(self) -> String {
+        self.invite_link
+    }
+
+    pub fn with_description<T: AsRef<str>>(mut self, description: T) -> Self {
+        self.description = description.as_ref().to_string();
+        self
+    }
+
+    pub fn with_creator_user_id<T: Into<UserId>>(mut self, creator_user_id: T) -> Self {
+        self.creator_user_id = creator_user_id.into();
+        self
+    }
+
+    pub fn with_members(mut self, members: Vec<ChatMember>) -> Self {
+        self.members = members;
+        self
+    }
+
+    pub fn with_invite_link<T: AsRef<str>>(mut self, invite_link: T) -> Self {
+        self.invite_link = invite_link.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDBasicGroupFullInfoBuilder {
     inner: BasicGroupFullInfo,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDBasicGroupFullInfoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> BasicGroupFullInfo {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<BasicGroupFullInfo> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "BasicGroupFullInfo",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn description<T: AsRef<str>>(&mut self, description: T) -> &mut Self {
         self.inner.description = description.as_ref().to_string();
+        self.missing.retain(|f| *f != "description");
         self
     }
 
-    pub fn creator_user_id(&mut self, creator_user_id: i64) -> &mut Self {
-        self.inner.creator_user_id = creator_user_id;
+    pub fn creator_user_id<T: Into<UserId>>(&mut self, creator_user_id: T) -> &mut Self {
+        self.inner.creator_user_id = creator_user_id.into();
         self
     }
 
     pub fn members(&mut self, members: Vec<ChatMember>) -> &mut Self {
         self.inner.members = members;
+        self.missing.retain(|f| *f != "members");
         self
     }
 
     pub fn invite_link<T: AsRef<str>>(&mut self, invite_link: T) -> &mut Self {
         self.inner.invite_link = invite_link.as_ref().to_string();
+        self.missing.retain(|f| *f != "invite_link");
         self
     }
 }
@@ -105,3 +187,21 @@ impl AsRef<BasicGroupFullInfo> for RTDBasicGroupFullInfoBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            BasicGroupFullInfo::builder()
+                .description("text")
+                .creator_user_id(i32::MAX)
+                .members(vec![])
+                .invite_link("text")
+                .build(),
+        );
+    }
+}
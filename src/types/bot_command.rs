@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Represents commands supported by a bot
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "botCommand")]
 pub struct BotCommand {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Text of the bot command
     command: String,
@@ -31,15 +31,44 @@ impl RObject for BotCommand {
     }
 }
 
+impl PartialEq for BotCommand {
+    fn eq(&self, other: &Self) -> bool {
+        self.command == other.command && self.description == other.description
+    }
+}
+
+impl Eq for BotCommand {}
+
+impl std::hash::Hash for BotCommand {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.command, state);
+        std::hash::Hash::hash(&self.description, state);
+    }
+}
+
 impl BotCommand {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDBotCommandBuilder {
-        let mut inner = BotCommand::default();
-        inner.td_name = "botCommand".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDBotCommandBuilder { inner }
+        RTDBotCommandBuilder {
+            inner: BotCommand::default(),
+            missing: vec!["command", "description"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDBotCommandBuilder {
+        RTDBotCommandBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDBotCommandBuilder {
+        RTDBotCommandBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn command(&self) -> &String {
@@ -49,25 +78,55 @@ impl BotCommand {
     pub fn description(&self) -> &String {
         &self.description
     }
+
+    pub fn into_command(self) -> String {
+        self.command
+    }
+
+    pub fn into_description(self) -> String {
+        self.description
+    }
+
+    pub fn with_command<T: AsRef<str>>(mut self, command: T) -> Self {
+        self.command = command.as_ref().to_string();
+        self
+    }
+
+    pub fn with_description<T: AsRef<str>>(mut self, description: T) -> Self {
+        self.description = description.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDBotCommandBuilder {
     inner: BotCommand,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDBotCommandBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> BotCommand {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<BotCommand> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("BotCommand", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn command<T: AsRef<str>>(&mut self, command: T) -> &mut Self {
         self.inner.command = command.as_ref().to_string();
+        self.missing.retain(|f| *f != "command");
         self
     }
 
     pub fn description<T: AsRef<str>>(&mut self, description: T) -> &mut Self {
         self.inner.description = description.as_ref().to_string();
+        self.missing.retain(|f| *f != "description");
         self
     }
 }
@@ -83,3 +142,19 @@ impl AsRef<BotCommand> for RTDBotCommandBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            BotCommand::builder()
+                .command("text")
+                .description("text")
+                .build(),
+        );
+    }
+}
@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Provides information about a bot and its supported commands
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "botInfo")]
 pub struct BotInfo {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Provides information about a bot and its supported commands
     description: String,
@@ -31,15 +31,44 @@ impl RObject for BotInfo {
     }
 }
 
+impl PartialEq for BotInfo {
+    fn eq(&self, other: &Self) -> bool {
+        self.description == other.description && self.commands == other.commands
+    }
+}
+
+impl Eq for BotInfo {}
+
+impl std::hash::Hash for BotInfo {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.description, state);
+        std::hash::Hash::hash(&self.commands, state);
+    }
+}
+
 impl BotInfo {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDBotInfoBuilder {
-        let mut inner = BotInfo::default();
-        inner.td_name = "botInfo".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDBotInfoBuilder { inner }
+        RTDBotInfoBuilder {
+            inner: BotInfo::default(),
+            missing: vec!["description", "commands"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDBotInfoBuilder {
+        RTDBotInfoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDBotInfoBuilder {
+        RTDBotInfoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn description(&self) -> &String {
@@ -49,25 +78,55 @@ impl BotInfo {
     pub fn commands(&self) -> &Vec<BotCommand> {
         &self.commands
     }
+
+    pub fn into_description(self) -> String {
+        self.description
+    }
+
+    pub fn into_commands(self) -> Vec<BotCommand> {
+        self.commands
+    }
+
+    pub fn with_description<T: AsRef<str>>(mut self, description: T) -> Self {
+        self.description = description.as_ref().to_string();
+        self
+    }
+
+    pub fn with_commands(mut self, commands: Vec<BotCommand>) -> Self {
+        self.commands = commands;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDBotInfoBuilder {
     inner: BotInfo,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDBotInfoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> BotInfo {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<BotInfo> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("BotInfo", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn description<T: AsRef<str>>(&mut self, description: T) -> &mut Self {
         self.inner.description = description.as_ref().to_string();
+        self.missing.retain(|f| *f != "description");
         self
     }
 
     pub fn commands(&mut self, commands: Vec<BotCommand>) -> &mut Self {
         self.inner.commands = commands;
+        self.missing.retain(|f| *f != "commands");
         self
     }
 }
@@ -83,3 +142,19 @@ impl AsRef<BotInfo> for RTDBotInfoBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            BotInfo::builder()
+                .description("text")
+                .commands(vec![])
+                .build(),
+        );
+    }
+}
@@ -1,20 +1,20 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Describes a call
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "call")]
 pub struct Call {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Call identifier, not persistent
-    id: i64,
+    id: CallIdentifier,
     /// Peer user identifier
-    user_id: i64,
+    user_id: UserId,
     /// True, if the call is outgoing
     is_outgoing: bool,
     /// Call state
@@ -35,22 +35,56 @@ impl RObject for Call {
     }
 }
 
+impl PartialEq for Call {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.user_id == other.user_id
+            && self.is_outgoing == other.is_outgoing
+            && self.state == other.state
+    }
+}
+
+impl Eq for Call {}
+
+impl std::hash::Hash for Call {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.id, state);
+        std::hash::Hash::hash(&self.user_id, state);
+        std::hash::Hash::hash(&self.is_outgoing, state);
+        std::hash::Hash::hash(&self.state, state);
+    }
+}
+
 impl Call {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDCallBuilder {
-        let mut inner = Call::default();
-        inner.td_name = "call".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDCallBuilder { inner }
+        RTDCallBuilder {
+            inner: Call::default(),
+            missing: vec!["id", "user_id", "state"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDCallBuilder {
+        RTDCallBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDCallBuilder {
+        RTDCallBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn id(&self) -> i64 {
+    pub fn id(&self) -> CallIdentifier {
         self.id
     }
 
-    pub fn user_id(&self) -> i64 {
+    pub fn user_id(&self) -> UserId {
         self.user_id
     }
 
@@ -61,25 +95,61 @@ impl Call {
     pub fn state(&self) -> &CallState {
         &self.state
     }
+
+    pub fn into_state(self) -> CallState {
+        self.state
+    }
+
+    pub fn with_id<T: Into<CallIdentifier>>(mut self, id: T) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    pub fn with_user_id<T: Into<UserId>>(mut self, user_id: T) -> Self {
+        self.user_id = user_id.into();
+        self
+    }
+
+    pub fn with_is_outgoing(mut self, is_outgoing: bool) -> Self {
+        self.is_outgoing = is_outgoing;
+        self
+    }
+
+    pub fn with_state<T: AsRef<CallState>>(mut self, state: T) -> Self {
+        self.state = state.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDCallBuilder {
     inner: Call,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDCallBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> Call {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<Call> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("Call", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn id(&mut self, id: i64) -> &mut Self {
-        self.inner.id = id;
+    pub fn id<T: Into<CallIdentifier>>(&mut self, id: T) -> &mut Self {
+        self.inner.id = id.into();
+        self.missing.retain(|f| *f != "id");
         self
     }
 
-    pub fn user_id(&mut self, user_id: i64) -> &mut Self {
-        self.inner.user_id = user_id;
+    pub fn user_id<T: Into<UserId>>(&mut self, user_id: T) -> &mut Self {
+        self.inner.user_id = user_id.into();
+        self.missing.retain(|f| *f != "user_id");
         self
     }
 
@@ -90,6 +160,7 @@ impl RTDCallBuilder {
 
     pub fn state<T: AsRef<CallState>>(&mut self, state: T) -> &mut Self {
         self.inner.state = state.as_ref().clone();
+        self.missing.retain(|f| *f != "state");
         self
     }
 }
@@ -105,3 +176,23 @@ impl AsRef<Call> for RTDCallBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            Call::builder()
+                .id(i32::MAX)
+                .user_id(i32::MAX)
+                .is_outgoing(true)
+                .state(CallState::ExchangingKeys(
+                    CallStateExchangingKeys::builder().build(),
+                ))
+                .build(),
+        );
+    }
+}
@@ -1,26 +1,28 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Describes the address of UDP reflectors
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "callConnection")]
 pub struct CallConnection {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Reflector identifier
-    id: isize,
+    #[serde(with = "super::_common::int64")]
+    id: i64,
     /// IPv4 reflector address
     ip: String,
     /// IPv6 reflector address
     ipv6: String,
     /// Reflector port number
-    port: i64,
+    port: i32,
     /// Connection peer tag
-    peer_tag: String,
+    #[serde(with = "super::_common::bytes")]
+    peer_tag: Vec<u8>,
 }
 
 impl RObject for CallConnection {
@@ -37,18 +39,54 @@ impl RObject for CallConnection {
     }
 }
 
+impl PartialEq for CallConnection {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.ip == other.ip
+            && self.ipv6 == other.ipv6
+            && self.port == other.port
+            && self.peer_tag == other.peer_tag
+    }
+}
+
+impl Eq for CallConnection {}
+
+impl std::hash::Hash for CallConnection {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.id, state);
+        std::hash::Hash::hash(&self.ip, state);
+        std::hash::Hash::hash(&self.ipv6, state);
+        std::hash::Hash::hash(&self.port, state);
+        std::hash::Hash::hash(&self.peer_tag, state);
+    }
+}
+
 impl CallConnection {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDCallConnectionBuilder {
-        let mut inner = CallConnection::default();
-        inner.td_name = "callConnection".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDCallConnectionBuilder { inner }
-    }
-
-    pub fn id(&self) -> isize {
+        RTDCallConnectionBuilder {
+            inner: CallConnection::default(),
+            missing: vec!["id", "ip", "ipv6", "port", "peer_tag"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDCallConnectionBuilder {
+        RTDCallConnectionBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDCallConnectionBuilder {
+        RTDCallConnectionBuilder {
+            inner: self,
+            missing: vec![],
+        }
+    }
+
+    pub fn id(&self) -> i64 {
         self.id
     }
 
@@ -60,47 +98,102 @@ impl CallConnection {
         &self.ipv6
     }
 
-    pub fn port(&self) -> i64 {
+    pub fn port(&self) -> i32 {
         self.port
     }
 
-    pub fn peer_tag(&self) -> &String {
+    pub fn peer_tag(&self) -> &[u8] {
         &self.peer_tag
     }
+
+    pub fn into_ip(self) -> String {
+        self.ip
+    }
+
+    pub fn into_ipv6(self) -> String {
+        self.ipv6
+    }
+
+    pub fn into_peer_tag(self) -> Vec<u8> {
+        self.peer_tag
+    }
+
+    pub fn with_id(mut self, id: i64) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn with_ip<T: AsRef<str>>(mut self, ip: T) -> Self {
+        self.ip = ip.as_ref().to_string();
+        self
+    }
+
+    pub fn with_ipv6<T: AsRef<str>>(mut self, ipv6: T) -> Self {
+        self.ipv6 = ipv6.as_ref().to_string();
+        self
+    }
+
+    pub fn with_port(mut self, port: i32) -> Self {
+        self.port = port;
+        self
+    }
+
+    pub fn with_peer_tag<T: AsRef<[u8]>>(mut self, peer_tag: T) -> Self {
+        self.peer_tag = peer_tag.as_ref().to_vec();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDCallConnectionBuilder {
     inner: CallConnection,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDCallConnectionBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> CallConnection {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<CallConnection> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "CallConnection",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn id(&mut self, id: isize) -> &mut Self {
+    pub fn id(&mut self, id: i64) -> &mut Self {
         self.inner.id = id;
+        self.missing.retain(|f| *f != "id");
         self
     }
 
     pub fn ip<T: AsRef<str>>(&mut self, ip: T) -> &mut Self {
         self.inner.ip = ip.as_ref().to_string();
+        self.missing.retain(|f| *f != "ip");
         self
     }
 
     pub fn ipv6<T: AsRef<str>>(&mut self, ipv6: T) -> &mut Self {
         self.inner.ipv6 = ipv6.as_ref().to_string();
+        self.missing.retain(|f| *f != "ipv6");
         self
     }
 
-    pub fn port(&mut self, port: i64) -> &mut Self {
+    pub fn port(&mut self, port: i32) -> &mut Self {
         self.inner.port = port;
+        self.missing.retain(|f| *f != "port");
         self
     }
 
-    pub fn peer_tag<T: AsRef<str>>(&mut self, peer_tag: T) -> &mut Self {
-        self.inner.peer_tag = peer_tag.as_ref().to_string();
+    pub fn peer_tag<T: AsRef<[u8]>>(&mut self, peer_tag: T) -> &mut Self {
+        self.inner.peer_tag = peer_tag.as_ref().to_vec();
+        self.missing.retain(|f| *f != "peer_tag");
         self
     }
 }
@@ -116,3 +209,22 @@ impl AsRef<CallConnection> for RTDCallConnectionBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            CallConnection::builder()
+                .id(i64::MAX)
+                .ip("text")
+                .ipv6("text")
+                .port(i32::MAX)
+                .peer_tag(b"\x00\x01\xfe\xff")
+                .build(),
+        );
+    }
+}
@@ -1,52 +1,52 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Describes the reason why a call was discarded
 pub trait TDCallDiscardReason: Debug + RObject {}
 
 /// Describes the reason why a call was discarded
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum CallDiscardReason {
-    #[doc(hidden)]
-    _Default(()),
     /// The call was ended before the conversation started. It was declined by the other party
+    #[serde(rename = "callDiscardReasonDeclined")]
     Declined(CallDiscardReasonDeclined),
     /// The call was ended during the conversation because the users were disconnected
+    #[serde(rename = "callDiscardReasonDisconnected")]
     Disconnected(CallDiscardReasonDisconnected),
     /// The call wasn't discarded, or the reason is unknown
+    #[serde(rename = "callDiscardReasonEmpty")]
     Empty(CallDiscardReasonEmpty),
     /// The call was ended because one of the parties hung up
+    #[serde(rename = "callDiscardReasonHungUp")]
     HungUp(CallDiscardReasonHungUp),
     /// The call was ended before the conversation started. It was cancelled by the caller or missed by the other party
+    #[serde(rename = "callDiscardReasonMissed")]
     Missed(CallDiscardReasonMissed),
 }
 
+/// Default value is default of [CallDiscardReasonDeclined], builders still require the field to be set
 impl Default for CallDiscardReason {
     fn default() -> Self {
-        CallDiscardReason::_Default(())
+        CallDiscardReason::Declined(CallDiscardReasonDeclined::default())
     }
 }
 
-impl<'de> Deserialize<'de> for CallDiscardReason {
-    fn deserialize<D>(deserializer: D) -> Result<CallDiscardReason, D::Error>
+impl Serialize for CallDiscardReason {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          CallDiscardReason,
-          (callDiscardReasonDeclined, Declined);
-          (callDiscardReasonDisconnected, Disconnected);
-          (callDiscardReasonEmpty, Empty);
-          (callDiscardReasonHungUp, HungUp);
-          (callDiscardReasonMissed, Missed);
-
-        )(deserializer)
+        match self {
+            CallDiscardReason::Declined(t) => t.serialize(serializer),
+            CallDiscardReason::Disconnected(t) => t.serialize(serializer),
+            CallDiscardReason::Empty(t) => t.serialize(serializer),
+            CallDiscardReason::HungUp(t) => t.serialize(serializer),
+            CallDiscardReason::Missed(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -59,8 +59,6 @@ impl RObject for CallDiscardReason {
             CallDiscardReason::Empty(t) => t.td_name(),
             CallDiscardReason::HungUp(t) => t.td_name(),
             CallDiscardReason::Missed(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -71,8 +69,6 @@ impl RObject for CallDiscardReason {
             CallDiscardReason::Empty(t) => t.extra(),
             CallDiscardReason::HungUp(t) => t.extra(),
             CallDiscardReason::Missed(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -84,10 +80,6 @@ impl CallDiscardReason {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, CallDiscardReason::_Default(_))
-    }
 }
 
 impl AsRef<CallDiscardReason> for CallDiscardReason {
@@ -98,12 +90,13 @@ impl AsRef<CallDiscardReason> for CallDiscardReason {
 
 /// The call was ended before the conversation started. It was declined by the other party
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "callDiscardReasonDeclined")]
 pub struct CallDiscardReasonDeclined {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -121,6 +114,18 @@ impl RObject for CallDiscardReasonDeclined {
     }
 }
 
+impl PartialEq for CallDiscardReasonDeclined {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for CallDiscardReasonDeclined {}
+
+impl std::hash::Hash for CallDiscardReasonDeclined {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDCallDiscardReason for CallDiscardReasonDeclined {}
 
 impl CallDiscardReasonDeclined {
@@ -128,22 +133,49 @@ impl CallDiscardReasonDeclined {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDCallDiscardReasonDeclinedBuilder {
-        let mut inner = CallDiscardReasonDeclined::default();
-        inner.td_name = "callDiscardReasonDeclined".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDCallDiscardReasonDeclinedBuilder { inner }
+        RTDCallDiscardReasonDeclinedBuilder {
+            inner: CallDiscardReasonDeclined::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDCallDiscardReasonDeclinedBuilder {
+        RTDCallDiscardReasonDeclinedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDCallDiscardReasonDeclinedBuilder {
+        RTDCallDiscardReasonDeclinedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDCallDiscardReasonDeclinedBuilder {
     inner: CallDiscardReasonDeclined,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDCallDiscardReasonDeclinedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> CallDiscardReasonDeclined {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<CallDiscardReasonDeclined> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "CallDiscardReasonDeclined",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<CallDiscardReasonDeclined> for CallDiscardReasonDeclined {
@@ -160,12 +192,13 @@ impl AsRef<CallDiscardReasonDeclined> for RTDCallDiscardReasonDeclinedBuilder {
 
 /// The call was ended during the conversation because the users were disconnected
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "callDiscardReasonDisconnected")]
 pub struct CallDiscardReasonDisconnected {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -183,6 +216,18 @@ impl RObject for CallDiscardReasonDisconnected {
     }
 }
 
+impl PartialEq for CallDiscardReasonDisconnected {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for CallDiscardReasonDisconnected {}
+
+impl std::hash::Hash for CallDiscardReasonDisconnected {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDCallDiscardReason for CallDiscardReasonDisconnected {}
 
 impl CallDiscardReasonDisconnected {
@@ -190,22 +235,49 @@ impl CallDiscardReasonDisconnected {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDCallDiscardReasonDisconnectedBuilder {
-        let mut inner = CallDiscardReasonDisconnected::default();
-        inner.td_name = "callDiscardReasonDisconnected".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDCallDiscardReasonDisconnectedBuilder { inner }
+        RTDCallDiscardReasonDisconnectedBuilder {
+            inner: CallDiscardReasonDisconnected::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDCallDiscardReasonDisconnectedBuilder {
+        RTDCallDiscardReasonDisconnectedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDCallDiscardReasonDisconnectedBuilder {
+        RTDCallDiscardReasonDisconnectedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDCallDiscardReasonDisconnectedBuilder {
     inner: CallDiscardReasonDisconnected,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDCallDiscardReasonDisconnectedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> CallDiscardReasonDisconnected {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<CallDiscardReasonDisconnected> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "CallDiscardReasonDisconnected",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<CallDiscardReasonDisconnected> for CallDiscardReasonDisconnected {
@@ -222,12 +294,13 @@ impl AsRef<CallDiscardReasonDisconnected> for RTDCallDiscardReasonDisconnectedBu
 
 /// The call wasn't discarded, or the reason is unknown
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "callDiscardReasonEmpty")]
 pub struct CallDiscardReasonEmpty {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -245,6 +318,18 @@ impl RObject for CallDiscardReasonEmpty {
     }
 }
 
+impl PartialEq for CallDiscardReasonEmpty {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for CallDiscardReasonEmpty {}
+
+impl std::hash::Hash for CallDiscardReasonEmpty {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDCallDiscardReason for CallDiscardReasonEmpty {}
 
 impl CallDiscardReasonEmpty {
@@ -252,22 +337,49 @@ impl CallDiscardReasonEmpty {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDCallDiscardReasonEmptyBuilder {
-        let mut inner = CallDiscardReasonEmpty::default();
-        inner.td_name = "callDiscardReasonEmpty".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDCallDiscardReasonEmptyBuilder { inner }
+        RTDCallDiscardReasonEmptyBuilder {
+            inner: CallDiscardReasonEmpty::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDCallDiscardReasonEmptyBuilder {
+        RTDCallDiscardReasonEmptyBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDCallDiscardReasonEmptyBuilder {
+        RTDCallDiscardReasonEmptyBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDCallDiscardReasonEmptyBuilder {
     inner: CallDiscardReasonEmpty,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDCallDiscardReasonEmptyBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> CallDiscardReasonEmpty {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<CallDiscardReasonEmpty> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "CallDiscardReasonEmpty",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<CallDiscardReasonEmpty> for CallDiscardReasonEmpty {
@@ -284,12 +396,13 @@ impl AsRef<CallDiscardReasonEmpty> for RTDCallDiscardReasonEmptyBuilder {
 
 /// The call was ended because one of the parties hung up
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "callDiscardReasonHungUp")]
 pub struct CallDiscardReasonHungUp {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -307,6 +420,18 @@ impl RObject for CallDiscardReasonHungUp {
     }
 }
 
+impl PartialEq for CallDiscardReasonHungUp {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for CallDiscardReasonHungUp {}
+
+impl std::hash::Hash for CallDiscardReasonHungUp {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDCallDiscardReason for CallDiscardReasonHungUp {}
 
 impl CallDiscardReasonHungUp {
@@ -314,22 +439,49 @@ impl CallDiscardReasonHungUp {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDCallDiscardReasonHungUpBuilder {
-        let mut inner = CallDiscardReasonHungUp::default();
-        inner.td_name = "callDiscardReasonHungUp".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDCallDiscardReasonHungUpBuilder { inner }
+        RTDCallDiscardReasonHungUpBuilder {
+            inner: CallDiscardReasonHungUp::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDCallDiscardReasonHungUpBuilder {
+        RTDCallDiscardReasonHungUpBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDCallDiscardReasonHungUpBuilder {
+        RTDCallDiscardReasonHungUpBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDCallDiscardReasonHungUpBuilder {
     inner: CallDiscardReasonHungUp,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDCallDiscardReasonHungUpBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> CallDiscardReasonHungUp {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<CallDiscardReasonHungUp> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "CallDiscardReasonHungUp",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<CallDiscardReasonHungUp> for CallDiscardReasonHungUp {
@@ -346,12 +498,13 @@ impl AsRef<CallDiscardReasonHungUp> for RTDCallDiscardReasonHungUpBuilder {
 
 /// The call was ended before the conversation started. It was cancelled by the caller or missed by the other party
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "callDiscardReasonMissed")]
 pub struct CallDiscardReasonMissed {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -369,6 +522,18 @@ impl RObject for CallDiscardReasonMissed {
     }
 }
 
+impl PartialEq for CallDiscardReasonMissed {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for CallDiscardReasonMissed {}
+
+impl std::hash::Hash for CallDiscardReasonMissed {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDCallDiscardReason for CallDiscardReasonMissed {}
 
 impl CallDiscardReasonMissed {
@@ -376,22 +541,49 @@ impl CallDiscardReasonMissed {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDCallDiscardReasonMissedBuilder {
-        let mut inner = CallDiscardReasonMissed::default();
-        inner.td_name = "callDiscardReasonMissed".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDCallDiscardReasonMissedBuilder { inner }
+        RTDCallDiscardReasonMissedBuilder {
+            inner: CallDiscardReasonMissed::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDCallDiscardReasonMissedBuilder {
+        RTDCallDiscardReasonMissedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDCallDiscardReasonMissedBuilder {
+        RTDCallDiscardReasonMissedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDCallDiscardReasonMissedBuilder {
     inner: CallDiscardReasonMissed,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDCallDiscardReasonMissedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> CallDiscardReasonMissed {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<CallDiscardReasonMissed> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "CallDiscardReasonMissed",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<CallDiscardReasonMissed> for CallDiscardReasonMissed {
@@ -405,3 +597,28 @@ impl AsRef<CallDiscardReasonMissed> for RTDCallDiscardReasonMissedBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(CallDiscardReason::Declined(
+            CallDiscardReasonDeclined::builder().build(),
+        ));
+        assert_round_trip(CallDiscardReason::Disconnected(
+            CallDiscardReasonDisconnected::builder().build(),
+        ));
+        assert_round_trip(CallDiscardReason::Empty(
+            CallDiscardReasonEmpty::builder().build(),
+        ));
+        assert_round_trip(CallDiscardReason::HungUp(
+            CallDiscardReasonHungUp::builder().build(),
+        ));
+        assert_round_trip(CallDiscardReason::Missed(
+            CallDiscardReasonMissed::builder().build(),
+        ));
+    }
+}
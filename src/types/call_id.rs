@@ -1,18 +1,18 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains the call identifier
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "callId")]
 pub struct CallId {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Call identifier
-    id: i64,
+    id: CallIdentifier,
 }
 
 impl RObject for CallId {
@@ -29,34 +29,78 @@ impl RObject for CallId {
     }
 }
 
+impl PartialEq for CallId {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for CallId {}
+
+impl std::hash::Hash for CallId {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.id, state);
+    }
+}
+
 impl CallId {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDCallIdBuilder {
-        let mut inner = CallId::default();
-        inner.td_name = "callId".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDCallIdBuilder { inner }
+        RTDCallIdBuilder {
+            inner: CallId::default(),
+            missing: vec!["id"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDCallIdBuilder {
+        RTDCallIdBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDCallIdBuilder {
+        RTDCallIdBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn id(&self) -> i64 {
+    pub fn id(&self) -> CallIdentifier {
         self.id
     }
+
+    pub fn with_id<T: Into<CallIdentifier>>(mut self, id: T) -> Self {
+        self.id = id.into();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDCallIdBuilder {
     inner: CallId,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDCallIdBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> CallId {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<CallId> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("CallId", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn id(&mut self, id: i64) -> &mut Self {
-        self.inner.id = id;
+    pub fn id<T: Into<CallIdentifier>>(&mut self, id: T) -> &mut Self {
+        self.inner.id = id.into();
+        self.missing.retain(|f| *f != "id");
         self
     }
 }
@@ -72,3 +116,14 @@ impl AsRef<CallId> for RTDCallIdBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(CallId::builder().id(i32::MAX).build());
+    }
+}
@@ -1,58 +1,60 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Describes the exact type of a problem with a call
 pub trait TDCallProblem: Debug + RObject {}
 
 /// Describes the exact type of a problem with a call
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum CallProblem {
-    #[doc(hidden)]
-    _Default(()),
     /// The speech was distorted
+    #[serde(rename = "callProblemDistortedSpeech")]
     DistortedSpeech(CallProblemDistortedSpeech),
     /// The call ended unexpectedly
+    #[serde(rename = "callProblemDropped")]
     Dropped(CallProblemDropped),
     /// The user heard their own voice
+    #[serde(rename = "callProblemEcho")]
     Echo(CallProblemEcho),
     /// The other side kept disappearing
+    #[serde(rename = "callProblemInterruptions")]
     Interruptions(CallProblemInterruptions),
     /// The user heard background noise
+    #[serde(rename = "callProblemNoise")]
     Noise(CallProblemNoise),
     /// The user couldn't hear the other side
+    #[serde(rename = "callProblemSilentLocal")]
     SilentLocal(CallProblemSilentLocal),
     /// The other side couldn't hear the user
+    #[serde(rename = "callProblemSilentRemote")]
     SilentRemote(CallProblemSilentRemote),
 }
 
+/// Default value is default of [CallProblemDistortedSpeech], builders still require the field to be set
 impl Default for CallProblem {
     fn default() -> Self {
-        CallProblem::_Default(())
+        CallProblem::DistortedSpeech(CallProblemDistortedSpeech::default())
     }
 }
 
-impl<'de> Deserialize<'de> for CallProblem {
-    fn deserialize<D>(deserializer: D) -> Result<CallProblem, D::Error>
+impl Serialize for CallProblem {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          CallProblem,
-          (callProblemDistortedSpeech, DistortedSpeech);
-          (callProblemDropped, Dropped);
-          (callProblemEcho, Echo);
-          (callProblemInterruptions, Interruptions);
-          (callProblemNoise, Noise);
-          (callProblemSilentLocal, SilentLocal);
-          (callProblemSilentRemote, SilentRemote);
-
-        )(deserializer)
+        match self {
+            CallProblem::DistortedSpeech(t) => t.serialize(serializer),
+            CallProblem::Dropped(t) => t.serialize(serializer),
+            CallProblem::Echo(t) => t.serialize(serializer),
+            CallProblem::Interruptions(t) => t.serialize(serializer),
+            CallProblem::Noise(t) => t.serialize(serializer),
+            CallProblem::SilentLocal(t) => t.serialize(serializer),
+            CallProblem::SilentRemote(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -67,8 +69,6 @@ impl RObject for CallProblem {
             CallProblem::Noise(t) => t.td_name(),
             CallProblem::SilentLocal(t) => t.td_name(),
             CallProblem::SilentRemote(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -81,8 +81,6 @@ impl RObject for CallProblem {
             CallProblem::Noise(t) => t.extra(),
             CallProblem::SilentLocal(t) => t.extra(),
             CallProblem::SilentRemote(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -94,10 +92,6 @@ impl CallProblem {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, CallProblem::_Default(_))
-    }
 }
 
 impl AsRef<CallProblem> for CallProblem {
@@ -108,12 +102,13 @@ impl AsRef<CallProblem> for CallProblem {
 
 /// The speech was distorted
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "callProblemDistortedSpeech")]
 pub struct CallProblemDistortedSpeech {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -131,6 +126,18 @@ impl RObject for CallProblemDistortedSpeech {
     }
 }
 
+impl PartialEq for CallProblemDistortedSpeech {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for CallProblemDistortedSpeech {}
+
+impl std::hash::Hash for CallProblemDistortedSpeech {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDCallProblem for CallProblemDistortedSpeech {}
 
 impl CallProblemDistortedSpeech {
@@ -138,22 +145,49 @@ impl CallProblemDistortedSpeech {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDCallProblemDistortedSpeechBuilder {
-        let mut inner = CallProblemDistortedSpeech::default();
-        inner.td_name = "callProblemDistortedSpeech".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDCallProblemDistortedSpeechBuilder { inner }
+        RTDCallProblemDistortedSpeechBuilder {
+            inner: CallProblemDistortedSpeech::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDCallProblemDistortedSpeechBuilder {
+        RTDCallProblemDistortedSpeechBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDCallProblemDistortedSpeechBuilder {
+        RTDCallProblemDistortedSpeechBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDCallProblemDistortedSpeechBuilder {
     inner: CallProblemDistortedSpeech,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDCallProblemDistortedSpeechBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> CallProblemDistortedSpeech {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<CallProblemDistortedSpeech> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "CallProblemDistortedSpeech",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<CallProblemDistortedSpeech> for CallProblemDistortedSpeech {
@@ -170,12 +204,13 @@ impl AsRef<CallProblemDistortedSpeech> for RTDCallProblemDistortedSpeechBuilder
 
 /// The call ended unexpectedly
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "callProblemDropped")]
 pub struct CallProblemDropped {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -193,6 +228,18 @@ impl RObject for CallProblemDropped {
     }
 }
 
+impl PartialEq for CallProblemDropped {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for CallProblemDropped {}
+
+impl std::hash::Hash for CallProblemDropped {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDCallProblem for CallProblemDropped {}
 
 impl CallProblemDropped {
@@ -200,22 +247,49 @@ impl CallProblemDropped {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDCallProblemDroppedBuilder {
-        let mut inner = CallProblemDropped::default();
-        inner.td_name = "callProblemDropped".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDCallProblemDroppedBuilder { inner }
+        RTDCallProblemDroppedBuilder {
+            inner: CallProblemDropped::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDCallProblemDroppedBuilder {
+        RTDCallProblemDroppedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDCallProblemDroppedBuilder {
+        RTDCallProblemDroppedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDCallProblemDroppedBuilder {
     inner: CallProblemDropped,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDCallProblemDroppedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> CallProblemDropped {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<CallProblemDropped> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "CallProblemDropped",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<CallProblemDropped> for CallProblemDropped {
@@ -232,12 +306,13 @@ impl AsRef<CallProblemDropped> for RTDCallProblemDroppedBuilder {
 
 /// The user heard their own voice
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "callProblemEcho")]
 pub struct CallProblemEcho {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -255,6 +330,18 @@ impl RObject for CallProblemEcho {
     }
 }
 
+impl PartialEq for CallProblemEcho {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for CallProblemEcho {}
+
+impl std::hash::Hash for CallProblemEcho {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDCallProblem for CallProblemEcho {}
 
 impl CallProblemEcho {
@@ -262,22 +349,49 @@ impl CallProblemEcho {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDCallProblemEchoBuilder {
-        let mut inner = CallProblemEcho::default();
-        inner.td_name = "callProblemEcho".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDCallProblemEchoBuilder { inner }
+        RTDCallProblemEchoBuilder {
+            inner: CallProblemEcho::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDCallProblemEchoBuilder {
+        RTDCallProblemEchoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDCallProblemEchoBuilder {
+        RTDCallProblemEchoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDCallProblemEchoBuilder {
     inner: CallProblemEcho,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDCallProblemEchoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> CallProblemEcho {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<CallProblemEcho> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "CallProblemEcho",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<CallProblemEcho> for CallProblemEcho {
@@ -294,12 +408,13 @@ impl AsRef<CallProblemEcho> for RTDCallProblemEchoBuilder {
 
 /// The other side kept disappearing
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "callProblemInterruptions")]
 pub struct CallProblemInterruptions {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -317,6 +432,18 @@ impl RObject for CallProblemInterruptions {
     }
 }
 
+impl PartialEq for CallProblemInterruptions {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for CallProblemInterruptions {}
+
+impl std::hash::Hash for CallProblemInterruptions {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDCallProblem for CallProblemInterruptions {}
 
 impl CallProblemInterruptions {
@@ -324,22 +451,49 @@ impl CallProblemInterruptions {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDCallProblemInterruptionsBuilder {
-        let mut inner = CallProblemInterruptions::default();
-        inner.td_name = "callProblemInterruptions".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDCallProblemInterruptionsBuilder { inner }
+        RTDCallProblemInterruptionsBuilder {
+            inner: CallProblemInterruptions::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDCallProblemInterruptionsBuilder {
+        RTDCallProblemInterruptionsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDCallProblemInterruptionsBuilder {
+        RTDCallProblemInterruptionsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDCallProblemInterruptionsBuilder {
     inner: CallProblemInterruptions,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDCallProblemInterruptionsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> CallProblemInterruptions {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<CallProblemInterruptions> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "CallProblemInterruptions",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<CallProblemInterruptions> for CallProblemInterruptions {
@@ -356,12 +510,13 @@ impl AsRef<CallProblemInterruptions> for RTDCallProblemInterruptionsBuilder {
 
 /// The user heard background noise
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "callProblemNoise")]
 pub struct CallProblemNoise {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -379,6 +534,18 @@ impl RObject for CallProblemNoise {
     }
 }
 
+impl PartialEq for CallProblemNoise {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for CallProblemNoise {}
+
+impl std::hash::Hash for CallProblemNoise {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDCallProblem for CallProblemNoise {}
 
 impl CallProblemNoise {
@@ -386,22 +553,49 @@ impl CallProblemNoise {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDCallProblemNoiseBuilder {
-        let mut inner = CallProblemNoise::default();
-        inner.td_name = "callProblemNoise".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDCallProblemNoiseBuilder { inner }
+        RTDCallProblemNoiseBuilder {
+            inner: CallProblemNoise::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDCallProblemNoiseBuilder {
+        RTDCallProblemNoiseBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDCallProblemNoiseBuilder {
+        RTDCallProblemNoiseBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDCallProblemNoiseBuilder {
     inner: CallProblemNoise,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDCallProblemNoiseBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> CallProblemNoise {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<CallProblemNoise> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "CallProblemNoise",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<CallProblemNoise> for CallProblemNoise {
@@ -418,12 +612,13 @@ impl AsRef<CallProblemNoise> for RTDCallProblemNoiseBuilder {
 
 /// The user couldn't hear the other side
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "callProblemSilentLocal")]
 pub struct CallProblemSilentLocal {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -441,6 +636,18 @@ impl RObject for CallProblemSilentLocal {
     }
 }
 
+impl PartialEq for CallProblemSilentLocal {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for CallProblemSilentLocal {}
+
+impl std::hash::Hash for CallProblemSilentLocal {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDCallProblem for CallProblemSilentLocal {}
 
 impl CallProblemSilentLocal {
@@ -448,22 +655,49 @@ impl CallProblemSilentLocal {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDCallProblemSilentLocalBuilder {
-        let mut inner = CallProblemSilentLocal::default();
-        inner.td_name = "callProblemSilentLocal".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDCallProblemSilentLocalBuilder { inner }
+        RTDCallProblemSilentLocalBuilder {
+            inner: CallProblemSilentLocal::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDCallProblemSilentLocalBuilder {
+        RTDCallProblemSilentLocalBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDCallProblemSilentLocalBuilder {
+        RTDCallProblemSilentLocalBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDCallProblemSilentLocalBuilder {
     inner: CallProblemSilentLocal,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDCallProblemSilentLocalBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> CallProblemSilentLocal {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<CallProblemSilentLocal> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "CallProblemSilentLocal",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<CallProblemSilentLocal> for CallProblemSilentLocal {
@@ -480,12 +714,13 @@ impl AsRef<CallProblemSilentLocal> for RTDCallProblemSilentLocalBuilder {
 
 /// The other side couldn't hear the user
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "callProblemSilentRemote")]
 pub struct CallProblemSilentRemote {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -503,6 +738,18 @@ impl RObject for CallProblemSilentRemote {
     }
 }
 
+impl PartialEq for CallProblemSilentRemote {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for CallProblemSilentRemote {}
+
+impl std::hash::Hash for CallProblemSilentRemote {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDCallProblem for CallProblemSilentRemote {}
 
 impl CallProblemSilentRemote {
@@ -510,22 +757,49 @@ impl CallProblemSilentRemote {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDCallProblemSilentRemoteBuilder {
-        let mut inner = CallProblemSilentRemote::default();
-        inner.td_name = "callProblemSilentRemote".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDCallProblemSilentRemoteBuilder { inner }
+        RTDCallProblemSilentRemoteBuilder {
+            inner: CallProblemSilentRemote::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDCallProblemSilentRemoteBuilder {
+        RTDCallProblemSilentRemoteBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDCallProblemSilentRemoteBuilder {
+        RTDCallProblemSilentRemoteBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDCallProblemSilentRemoteBuilder {
     inner: CallProblemSilentRemote,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDCallProblemSilentRemoteBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> CallProblemSilentRemote {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<CallProblemSilentRemote> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "CallProblemSilentRemote",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<CallProblemSilentRemote> for CallProblemSilentRemote {
@@ -539,3 +813,28 @@ impl AsRef<CallProblemSilentRemote> for RTDCallProblemSilentRemoteBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(CallProblem::DistortedSpeech(
+            CallProblemDistortedSpeech::builder().build(),
+        ));
+        assert_round_trip(CallProblem::Dropped(CallProblemDropped::builder().build()));
+        assert_round_trip(CallProblem::Echo(CallProblemEcho::builder().build()));
+        assert_round_trip(CallProblem::Interruptions(
+            CallProblemInterruptions::builder().build(),
+        ));
+        assert_round_trip(CallProblem::Noise(CallProblemNoise::builder().build()));
+        assert_round_trip(CallProblem::SilentLocal(
+            CallProblemSilentLocal::builder().build(),
+        ));
+        assert_round_trip(CallProblem::SilentRemote(
+            CallProblemSilentRemote::builder().build(),
+        ));
+    }
+}
@@ -1,24 +1,24 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Specifies the supported call protocols
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "callProtocol")]
 pub struct CallProtocol {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// True, if UDP peer-to-peer connections are supported
     udp_p2p: bool,
     /// True, if connection through UDP reflectors is supported
     udp_reflector: bool,
     /// The minimum supported API layer; use 65
-    min_layer: i64,
+    min_layer: i32,
     /// The maximum supported API layer; use 65
-    max_layer: i64,
+    max_layer: i32,
 }
 
 impl RObject for CallProtocol {
@@ -35,15 +35,49 @@ impl RObject for CallProtocol {
     }
 }
 
+impl PartialEq for CallProtocol {
+    fn eq(&self, other: &Self) -> bool {
+        self.udp_p2p == other.udp_p2p
+            && self.udp_reflector == other.udp_reflector
+            && self.min_layer == other.min_layer
+            && self.max_layer == other.max_layer
+    }
+}
+
+impl Eq for CallProtocol {}
+
+impl std::hash::Hash for CallProtocol {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.udp_p2p, state);
+        std::hash::Hash::hash(&self.udp_reflector, state);
+        std::hash::Hash::hash(&self.min_layer, state);
+        std::hash::Hash::hash(&self.max_layer, state);
+    }
+}
+
 impl CallProtocol {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDCallProtocolBuilder {
-        let mut inner = CallProtocol::default();
-        inner.td_name = "callProtocol".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDCallProtocolBuilder { inner }
+        RTDCallProtocolBuilder {
+            inner: CallProtocol::default(),
+            missing: vec!["min_layer", "max_layer"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDCallProtocolBuilder {
+        RTDCallProtocolBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDCallProtocolBuilder {
+        RTDCallProtocolBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn udp_p2p(&self) -> bool {
@@ -54,24 +88,57 @@ impl CallProtocol {
         self.udp_reflector
     }
 
-    pub fn min_layer(&self) -> i64 {
+    pub fn min_layer(&self) -> i32 {
         self.min_layer
     }
 
-    pub fn max_layer(&self) -> i64 {
+    pub fn max_layer(&self) -> i32 {
         self.max_layer
     }
+
+    pub fn with_udp_p2p(mut self, udp_p2p: bool) -> Self {
+        self.udp_p2p = udp_p2p;
+        self
+    }
+
+    pub fn with_udp_reflector(mut self, udp_reflector: bool) -> Self {
+        self.udp_reflector = udp_reflector;
+        self
+    }
+
+    pub fn with_min_layer(mut self, min_layer: i32) -> Self {
+        self.min_layer = min_layer;
+        self
+    }
+
+    pub fn with_max_layer(mut self, max_layer: i32) -> Self {
+        self.max_layer = max_layer;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDCallProtocolBuilder {
     inner: CallProtocol,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDCallProtocolBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> CallProtocol {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<CallProtocol> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "CallProtocol",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn udp_p2p(&mut self, udp_p2p: bool) -> &mut Self {
         self.inner.udp_p2p = udp_p2p;
@@ -83,13 +150,15 @@ impl RTDCallProtocolBuilder {
         self
     }
 
-    pub fn min_layer(&mut self, min_layer: i64) -> &mut Self {
+    pub fn min_layer(&mut self, min_layer: i32) -> &mut Self {
         self.inner.min_layer = min_layer;
+        self.missing.retain(|f| *f != "min_layer");
         self
     }
 
-    pub fn max_layer(&mut self, max_layer: i64) -> &mut Self {
+    pub fn max_layer(&mut self, max_layer: i32) -> &mut Self {
         self.inner.max_layer = max_layer;
+        self.missing.retain(|f| *f != "max_layer");
         self
     }
 }
@@ -105,3 +174,21 @@ impl AsRef<CallProtocol> for RTDCallProtocolBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            CallProtocol::builder()
+                .udp_p2p(true)
+                .udp_reflector(true)
+                .min_layer(i32::MAX)
+                .max_layer(i32::MAX)
+                .build(),
+        );
+    }
+}
@@ -1,55 +1,56 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Describes the current call state
 pub trait TDCallState: Debug + RObject {}
 
 /// Describes the current call state
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum CallState {
-    #[doc(hidden)]
-    _Default(()),
     /// The call has ended successfully
+    #[serde(rename = "callStateDiscarded")]
     Discarded(CallStateDiscarded),
     /// The call has ended with an error
+    #[serde(rename = "callStateError")]
     Error(CallStateError),
     /// The call has been answered and encryption keys are being exchanged
+    #[serde(rename = "callStateExchangingKeys")]
     ExchangingKeys(CallStateExchangingKeys),
     /// The call is hanging up after discardCall has been called
+    #[serde(rename = "callStateHangingUp")]
     HangingUp(CallStateHangingUp),
     /// The call is pending, waiting to be accepted by a user
+    #[serde(rename = "callStatePending")]
     Pending(CallStatePending),
     /// The call is ready to use
+    #[serde(rename = "callStateReady")]
     Ready(CallStateReady),
 }
 
+/// Default value is default of [CallStateExchangingKeys], builders still require the field to be set
 impl Default for CallState {
     fn default() -> Self {
-        CallState::_Default(())
+        CallState::ExchangingKeys(CallStateExchangingKeys::default())
     }
 }
 
-impl<'de> Deserialize<'de> for CallState {
-    fn deserialize<D>(deserializer: D) -> Result<CallState, D::Error>
+impl Serialize for CallState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          CallState,
-          (callStateDiscarded, Discarded);
-          (callStateError, Error);
-          (callStateExchangingKeys, ExchangingKeys);
-          (callStateHangingUp, HangingUp);
-          (callStatePending, Pending);
-          (callStateReady, Ready);
-
-        )(deserializer)
+        match self {
+            CallState::Discarded(t) => t.serialize(serializer),
+            CallState::Error(t) => t.serialize(serializer),
+            CallState::ExchangingKeys(t) => t.serialize(serializer),
+            CallState::HangingUp(t) => t.serialize(serializer),
+            CallState::Pending(t) => t.serialize(serializer),
+            CallState::Ready(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -63,8 +64,6 @@ impl RObject for CallState {
             CallState::HangingUp(t) => t.td_name(),
             CallState::Pending(t) => t.td_name(),
             CallState::Ready(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -76,8 +75,6 @@ impl RObject for CallState {
             CallState::HangingUp(t) => t.extra(),
             CallState::Pending(t) => t.extra(),
             CallState::Ready(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -89,10 +86,6 @@ impl CallState {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, CallState::_Default(_))
-    }
 }
 
 impl AsRef<CallState> for CallState {
@@ -103,12 +96,13 @@ impl AsRef<CallState> for CallState {
 
 /// The call has ended successfully
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "callStateDiscarded")]
 pub struct CallStateDiscarded {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The reason, why the call has ended
     reason: CallDiscardReason,
@@ -132,6 +126,24 @@ impl RObject for CallStateDiscarded {
     }
 }
 
+impl PartialEq for CallStateDiscarded {
+    fn eq(&self, other: &Self) -> bool {
+        self.reason == other.reason
+            && self.need_rating == other.need_rating
+            && self.need_debug_information == other.need_debug_information
+    }
+}
+
+impl Eq for CallStateDiscarded {}
+
+impl std::hash::Hash for CallStateDiscarded {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.reason, state);
+        std::hash::Hash::hash(&self.need_rating, state);
+        std::hash::Hash::hash(&self.need_debug_information, state);
+    }
+}
+
 impl TDCallState for CallStateDiscarded {}
 
 impl CallStateDiscarded {
@@ -139,10 +151,24 @@ impl CallStateDiscarded {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDCallStateDiscardedBuilder {
-        let mut inner = CallStateDiscarded::default();
-        inner.td_name = "callStateDiscarded".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDCallStateDiscardedBuilder { inner }
+        RTDCallStateDiscardedBuilder {
+            inner: CallStateDiscarded::default(),
+            missing: vec!["reason"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDCallStateDiscardedBuilder {
+        RTDCallStateDiscardedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDCallStateDiscardedBuilder {
+        RTDCallStateDiscardedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn reason(&self) -> &CallDiscardReason {
@@ -156,20 +182,53 @@ impl CallStateDiscarded {
     pub fn need_debug_information(&self) -> bool {
         self.need_debug_information
     }
+
+    pub fn into_reason(self) -> CallDiscardReason {
+        self.reason
+    }
+
+    pub fn with_reason<T: AsRef<CallDiscardReason>>(mut self, reason: T) -> Self {
+        self.reason = reason.as_ref().clone();
+        self
+    }
+
+    pub fn with_need_rating(mut self, need_rating: bool) -> Self {
+        self.need_rating = need_rating;
+        self
+    }
+
+    pub fn with_need_debug_information(mut self, need_debug_information: bool) -> Self {
+        self.need_debug_information = need_debug_information;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDCallStateDiscardedBuilder {
     inner: CallStateDiscarded,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDCallStateDiscardedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> CallStateDiscarded {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<CallStateDiscarded> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "CallStateDiscarded",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn reason<T: AsRef<CallDiscardReason>>(&mut self, reason: T) -> &mut Self {
         self.inner.reason = reason.as_ref().clone();
+        self.missing.retain(|f| *f != "reason");
         self
     }
 
@@ -198,12 +257,13 @@ impl AsRef<CallStateDiscarded> for RTDCallStateDiscardedBuilder {
 
 /// The call has ended with an error
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "callStateError")]
 pub struct CallStateError {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Error. An error with the code 4005000 will be returned if an outgoing call is missed because of an expired timeout
     error: Error,
@@ -223,6 +283,20 @@ impl RObject for CallStateError {
     }
 }
 
+impl PartialEq for CallStateError {
+    fn eq(&self, other: &Self) -> bool {
+        self.error == other.error
+    }
+}
+
+impl Eq for CallStateError {}
+
+impl std::hash::Hash for CallStateError {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.error, state);
+    }
+}
+
 impl TDCallState for CallStateError {}
 
 impl CallStateError {
@@ -230,29 +304,66 @@ impl CallStateError {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDCallStateErrorBuilder {
-        let mut inner = CallStateError::default();
-        inner.td_name = "callStateError".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDCallStateErrorBuilder { inner }
+        RTDCallStateErrorBuilder {
+            inner: CallStateError::default(),
+            missing: vec!["error"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDCallStateErrorBuilder {
+        RTDCallStateErrorBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDCallStateErrorBuilder {
+        RTDCallStateErrorBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn error(&self) -> &Error {
         &self.error
     }
+
+    pub fn into_error(self) -> Error {
+        self.error
+    }
+
+    pub fn with_error<T: AsRef<Error>>(mut self, error: T) -> Self {
+        self.error = error.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDCallStateErrorBuilder {
     inner: CallStateError,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDCallStateErrorBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> CallStateError {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<CallStateError> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "CallStateError",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn error<T: AsRef<Error>>(&mut self, error: T) -> &mut Self {
         self.inner.error = error.as_ref().clone();
+        self.missing.retain(|f| *f != "error");
         self
     }
 }
@@ -271,12 +382,13 @@ impl AsRef<CallStateError> for RTDCallStateErrorBuilder {
 
 /// The call has been answered and encryption keys are being exchanged
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "callStateExchangingKeys")]
 pub struct CallStateExchangingKeys {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -294,6 +406,18 @@ impl RObject for CallStateExchangingKeys {
     }
 }
 
+impl PartialEq for CallStateExchangingKeys {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for CallStateExchangingKeys {}
+
+impl std::hash::Hash for CallStateExchangingKeys {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDCallState for CallStateExchangingKeys {}
 
 impl CallStateExchangingKeys {
@@ -301,22 +425,49 @@ impl CallStateExchangingKeys {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDCallStateExchangingKeysBuilder {
-        let mut inner = CallStateExchangingKeys::default();
-        inner.td_name = "callStateExchangingKeys".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDCallStateExchangingKeysBuilder { inner }
+        RTDCallStateExchangingKeysBuilder {
+            inner: CallStateExchangingKeys::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDCallStateExchangingKeysBuilder {
+        RTDCallStateExchangingKeysBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDCallStateExchangingKeysBuilder {
+        RTDCallStateExchangingKeysBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDCallStateExchangingKeysBuilder {
     inner: CallStateExchangingKeys,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDCallStateExchangingKeysBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> CallStateExchangingKeys {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<CallStateExchangingKeys> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "CallStateExchangingKeys",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<CallStateExchangingKeys> for CallStateExchangingKeys {
@@ -333,12 +484,13 @@ impl AsRef<CallStateExchangingKeys> for RTDCallStateExchangingKeysBuilder {
 
 /// The call is hanging up after discardCall has been called
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "callStateHangingUp")]
 pub struct CallStateHangingUp {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -356,6 +508,18 @@ impl RObject for CallStateHangingUp {
     }
 }
 
+impl PartialEq for CallStateHangingUp {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for CallStateHangingUp {}
+
+impl std::hash::Hash for CallStateHangingUp {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDCallState for CallStateHangingUp {}
 
 impl CallStateHangingUp {
@@ -363,22 +527,49 @@ impl CallStateHangingUp {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDCallStateHangingUpBuilder {
-        let mut inner = CallStateHangingUp::default();
-        inner.td_name = "callStateHangingUp".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDCallStateHangingUpBuilder { inner }
+        RTDCallStateHangingUpBuilder {
+            inner: CallStateHangingUp::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDCallStateHangingUpBuilder {
+        RTDCallStateHangingUpBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDCallStateHangingUpBuilder {
+        RTDCallStateHangingUpBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDCallStateHangingUpBuilder {
     inner: CallStateHangingUp,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDCallStateHangingUpBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> CallStateHangingUp {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<CallStateHangingUp> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "CallStateHangingUp",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<CallStateHangingUp> for CallStateHangingUp {
@@ -395,12 +586,13 @@ impl AsRef<CallStateHangingUp> for RTDCallStateHangingUpBuilder {
 
 /// The call is pending, waiting to be accepted by a user
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "callStatePending")]
 pub struct CallStatePending {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// True, if the call has already been created by the server
     is_created: bool,
@@ -422,6 +614,21 @@ impl RObject for CallStatePending {
     }
 }
 
+impl PartialEq for CallStatePending {
+    fn eq(&self, other: &Self) -> bool {
+        self.is_created == other.is_created && self.is_received == other.is_received
+    }
+}
+
+impl Eq for CallStatePending {}
+
+impl std::hash::Hash for CallStatePending {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.is_created, state);
+        std::hash::Hash::hash(&self.is_received, state);
+    }
+}
+
 impl TDCallState for CallStatePending {}
 
 impl CallStatePending {
@@ -429,10 +636,24 @@ impl CallStatePending {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDCallStatePendingBuilder {
-        let mut inner = CallStatePending::default();
-        inner.td_name = "callStatePending".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDCallStatePendingBuilder { inner }
+        RTDCallStatePendingBuilder {
+            inner: CallStatePending::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDCallStatePendingBuilder {
+        RTDCallStatePendingBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDCallStatePendingBuilder {
+        RTDCallStatePendingBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn is_created(&self) -> bool {
@@ -442,17 +663,40 @@ impl CallStatePending {
     pub fn is_received(&self) -> bool {
         self.is_received
     }
+
+    pub fn with_is_created(mut self, is_created: bool) -> Self {
+        self.is_created = is_created;
+        self
+    }
+
+    pub fn with_is_received(mut self, is_received: bool) -> Self {
+        self.is_received = is_received;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDCallStatePendingBuilder {
     inner: CallStatePending,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDCallStatePendingBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> CallStatePending {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<CallStatePending> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "CallStatePending",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn is_created(&mut self, is_created: bool) -> &mut Self {
         self.inner.is_created = is_created;
@@ -479,12 +723,13 @@ impl AsRef<CallStatePending> for RTDCallStatePendingBuilder {
 
 /// The call is ready to use
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "callStateReady")]
 pub struct CallStateReady {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Call protocols supported by the peer
     protocol: CallProtocol,
@@ -493,7 +738,8 @@ pub struct CallStateReady {
     /// A JSON-encoded call config
     config: String,
     /// Call encryption key
-    encryption_key: String,
+    #[serde(with = "super::_common::bytes")]
+    encryption_key: Vec<u8>,
     /// Encryption key emojis fingerprint
     emojis: Vec<String>,
     /// True, if peer-to-peer connection is allowed by users privacy settings
@@ -514,6 +760,30 @@ impl RObject for CallStateReady {
     }
 }
 
+impl PartialEq for CallStateReady {
+    fn eq(&self, other: &Self) -> bool {
+        self.protocol == other.protocol
+            && self.connections == other.connections
+            && self.config == other.config
+            && self.encryption_key == other.encryption_key
+            && self.emojis == other.emojis
+            && self.allow_p2p == other.allow_p2p
+    }
+}
+
+impl Eq for CallStateReady {}
+
+impl std::hash::Hash for CallStateReady {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.protocol, state);
+        std::hash::Hash::hash(&self.connections, state);
+        std::hash::Hash::hash(&self.config, state);
+        std::hash::Hash::hash(&self.encryption_key, state);
+        std::hash::Hash::hash(&self.emojis, state);
+        std::hash::Hash::hash(&self.allow_p2p, state);
+    }
+}
+
 impl TDCallState for CallStateReady {}
 
 impl CallStateReady {
@@ -521,10 +791,30 @@ impl CallStateReady {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDCallStateReadyBuilder {
-        let mut inner = CallStateReady::default();
-        inner.td_name = "callStateReady".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDCallStateReadyBuilder { inner }
+        RTDCallStateReadyBuilder {
+            inner: CallStateReady::default(),
+            missing: vec![
+                "protocol",
+                "connections",
+                "config",
+                "encryption_key",
+                "emojis",
+            ],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDCallStateReadyBuilder {
+        RTDCallStateReadyBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDCallStateReadyBuilder {
+        RTDCallStateReadyBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn protocol(&self) -> &CallProtocol {
@@ -539,7 +829,7 @@ impl CallStateReady {
         &self.config
     }
 
-    pub fn encryption_key(&self) -> &String {
+    pub fn encryption_key(&self) -> &[u8] {
         &self.encryption_key
     }
 
@@ -550,40 +840,108 @@ impl CallStateReady {
     pub fn allow_p2p(&self) -> bool {
         self.allow_p2p
     }
+
+    pub fn into_protocol(self) -> CallProtocol {
+        self.protocol
+    }
+
+    pub fn into_connections(self) -> Vec<CallConnection> {
+        self.connections
+    }
+
+    pub fn into_config(self) -> String {
+        self.config
+    }
+
+    pub fn into_encryption_key(self) -> Vec<u8> {
+        self.encryption_key
+    }
+
+    pub fn into_emojis(self) -> Vec<String> {
+        self.emojis
+    }
+
+    pub fn with_protocol<T: AsRef<CallProtocol>>(mut self, protocol: T) -> Self {
+        self.protocol = protocol.as_ref().clone();
+        self
+    }
+
+    pub fn with_connections(mut self, connections: Vec<CallConnection>) -> Self {
+        self.connections = connections;
+        self
+    }
+
+    pub fn with_config<T: AsRef<str>>(mut self, config: T) -> Self {
+        self.config = config.as_ref().to_string();
+        self
+    }
+
+    pub fn with_encryption_key<T: AsRef<[u8]>>(mut self, encryption_key: T) -> Self {
+        self.encryption_key = encryption_key.as_ref().to_vec();
+        self
+    }
+
+    pub fn with_emojis(mut self, emojis: Vec<String>) -> Self {
+        self.emojis = emojis;
+        self
+    }
+
+    pub fn with_allow_p2p(mut self, allow_p2p: bool) -> Self {
+        self.allow_p2p = allow_p2p;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDCallStateReadyBuilder {
     inner: CallStateReady,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDCallStateReadyBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> CallStateReady {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<CallStateReady> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "CallStateReady",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn protocol<T: AsRef<CallProtocol>>(&mut self, protocol: T) -> &mut Self {
         self.inner.protocol = protocol.as_ref().clone();
+        self.missing.retain(|f| *f != "protocol");
         self
     }
 
     pub fn connections(&mut self, connections: Vec<CallConnection>) -> &mut Self {
         self.inner.connections = connections;
+        self.missing.retain(|f| *f != "connections");
         self
     }
 
     pub fn config<T: AsRef<str>>(&mut self, config: T) -> &mut Self {
         self.inner.config = config.as_ref().to_string();
+        self.missing.retain(|f| *f != "config");
         self
     }
 
-    pub fn encryption_key<T: AsRef<str>>(&mut self, encryption_key: T) -> &mut Self {
-        self.inner.encryption_key = encryption_key.as_ref().to_string();
+    pub fn encryption_key<T: AsRef<[u8]>>(&mut self, encryption_key: T) -> &mut Self {
+        self.inner.encryption_key = encryption_key.as_ref().to_vec();
+        self.missing.retain(|f| *f != "encryption_key");
         self
     }
 
     pub fn emojis(&mut self, emojis: Vec<String>) -> &mut Self {
         self.inner.emojis = emojis;
+        self.missing.retain(|f| *f != "emojis");
         self
     }
 
@@ -604,3 +962,54 @@ impl AsRef<CallStateReady> for RTDCallStateReadyBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(CallState::Discarded(
+            CallStateDiscarded::builder()
+                .reason(CallDiscardReason::Declined(
+                    CallDiscardReasonDeclined::builder().build(),
+                ))
+                .need_rating(true)
+                .need_debug_information(true)
+                .build(),
+        ));
+        assert_round_trip(CallState::Error(
+            CallStateError::builder()
+                .error(Error::builder().code(i32::MAX).message("text").build())
+                .build(),
+        ));
+        assert_round_trip(CallState::ExchangingKeys(
+            CallStateExchangingKeys::builder().build(),
+        ));
+        assert_round_trip(CallState::HangingUp(CallStateHangingUp::builder().build()));
+        assert_round_trip(CallState::Pending(
+            CallStatePending::builder()
+                .is_created(true)
+                .is_received(true)
+                .build(),
+        ));
+        assert_round_trip(CallState::Ready(
+            CallStateReady::builder()
+                .protocol(
+                    CallProtocol::builder()
+                        .udp_p2p(true)
+                        .udp_reflector(true)
+                        .min_layer(i32::MAX)
+                        .max_layer(i32::MAX)
+                        .build(),
+                )
+                .connections(vec![])
+                .config("text")
+                .encryption_key(b"\x00\x01\xfe\xff")
+                .emojis(vec!["text".to_string()])
+                .allow_p2p(true)
+                .build(),
+        ));
+    }
+}
@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains a bot's answer to a callback query
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "callbackQueryAnswer")]
 pub struct CallbackQueryAnswer {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Text of the answer
     text: String,
@@ -33,15 +33,45 @@ impl RObject for CallbackQueryAnswer {
     }
 }
 
+impl PartialEq for CallbackQueryAnswer {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text && self.show_alert == other.show_alert && self.url == other.url
+    }
+}
+
+impl Eq for CallbackQueryAnswer {}
+
+impl std::hash::Hash for CallbackQueryAnswer {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.text, state);
+        std::hash::Hash::hash(&self.show_alert, state);
+        std::hash::Hash::hash(&self.url, state);
+    }
+}
+
 impl CallbackQueryAnswer {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDCallbackQueryAnswerBuilder {
-        let mut inner = CallbackQueryAnswer::default();
-        inner.td_name = "callbackQueryAnswer".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDCallbackQueryAnswerBuilder { inner }
+        RTDCallbackQueryAnswerBuilder {
+            inner: CallbackQueryAnswer::default(),
+            missing: vec!["text", "url"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDCallbackQueryAnswerBuilder {
+        RTDCallbackQueryAnswerBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDCallbackQueryAnswerBuilder {
+        RTDCallbackQueryAnswerBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn text(&self) -> &String {
@@ -55,20 +85,57 @@ impl CallbackQueryAnswer {
     pub fn url(&self) -> &String {
         &self.url
     }
+
+    pub fn into_text(self) -> String {
+        self.text
+    }
+
+    pub fn into_url(self) -> String {
+        self.url
+    }
+
+    pub fn with_text<T: AsRef<str>>(mut self, text: T) -> Self {
+        self.text = text.as_ref().to_string();
+        self
+    }
+
+    pub fn with_show_alert(mut self, show_alert: bool) -> Self {
+        self.show_alert = show_alert;
+        self
+    }
+
+    pub fn with_url<T: AsRef<str>>(mut self, url: T) -> Self {
+        self.url = url.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDCallbackQueryAnswerBuilder {
     inner: CallbackQueryAnswer,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDCallbackQueryAnswerBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> CallbackQueryAnswer {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<CallbackQueryAnswer> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "CallbackQueryAnswer",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn text<T: AsRef<str>>(&mut self, text: T) -> &mut Self {
         self.inner.text = text.as_ref().to_string();
+        self.missing.retain(|f| *f != "text");
         self
     }
 
@@ -79,6 +146,7 @@ impl RTDCallbackQueryAnswerBuilder {
 
     pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
         self.inner.url = url.as_ref().to_string();
+        self.missing.retain(|f| *f != "url");
         self
     }
 }
@@ -94,3 +162,20 @@ impl AsRef<CallbackQueryAnswer> for RTDCallbackQueryAnswerBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            CallbackQueryAnswer::builder()
+                .text("text")
+                .show_alert(true)
+                .url("text")
+                .build(),
+        );
+    }
+}
@@ -1,43 +1,40 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Represents a payload of a callback query
 pub trait TDCallbackQueryPayload: Debug + RObject {}
 
 /// Represents a payload of a callback query
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum CallbackQueryPayload {
-    #[doc(hidden)]
-    _Default(()),
     /// The payload from a general callback button
+    #[serde(rename = "callbackQueryPayloadData")]
     Data(CallbackQueryPayloadData),
     /// The payload from a game callback button
+    #[serde(rename = "callbackQueryPayloadGame")]
     Game(CallbackQueryPayloadGame),
 }
 
+/// Default value is default of [CallbackQueryPayloadData], builders still require the field to be set
 impl Default for CallbackQueryPayload {
     fn default() -> Self {
-        CallbackQueryPayload::_Default(())
+        CallbackQueryPayload::Data(CallbackQueryPayloadData::default())
     }
 }
 
-impl<'de> Deserialize<'de> for CallbackQueryPayload {
-    fn deserialize<D>(deserializer: D) -> Result<CallbackQueryPayload, D::Error>
+impl Serialize for CallbackQueryPayload {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          CallbackQueryPayload,
-          (callbackQueryPayloadData, Data);
-          (callbackQueryPayloadGame, Game);
-
-        )(deserializer)
+        match self {
+            CallbackQueryPayload::Data(t) => t.serialize(serializer),
+            CallbackQueryPayload::Game(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -47,8 +44,6 @@ impl RObject for CallbackQueryPayload {
         match self {
             CallbackQueryPayload::Data(t) => t.td_name(),
             CallbackQueryPayload::Game(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -56,8 +51,6 @@ impl RObject for CallbackQueryPayload {
         match self {
             CallbackQueryPayload::Data(t) => t.extra(),
             CallbackQueryPayload::Game(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -69,10 +62,6 @@ impl CallbackQueryPayload {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, CallbackQueryPayload::_Default(_))
-    }
 }
 
 impl AsRef<CallbackQueryPayload> for CallbackQueryPayload {
@@ -83,15 +72,17 @@ impl AsRef<CallbackQueryPayload> for CallbackQueryPayload {
 
 /// The payload from a general callback button
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "callbackQueryPayloadData")]
 pub struct CallbackQueryPayloadData {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Data that was attached to the callback button
-    data: String,
+    #[serde(with = "super::_common::bytes")]
+    data: Vec<u8>,
 }
 
 impl RObject for CallbackQueryPayloadData {
@@ -108,6 +99,20 @@ impl RObject for CallbackQueryPayloadData {
     }
 }
 
+impl PartialEq for CallbackQueryPayloadData {
+    fn eq(&self, other: &Self) -> bool {
+        self.data == other.data
+    }
+}
+
+impl Eq for CallbackQueryPayloadData {}
+
+impl std::hash::Hash for CallbackQueryPayloadData {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.data, state);
+    }
+}
+
 impl TDCallbackQueryPayload for CallbackQueryPayloadData {}
 
 impl CallbackQueryPayloadData {
@@ -115,29 +120,66 @@ impl CallbackQueryPayloadData {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDCallbackQueryPayloadDataBuilder {
-        let mut inner = CallbackQueryPayloadData::default();
-        inner.td_name = "callbackQueryPayloadData".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDCallbackQueryPayloadDataBuilder { inner }
+        RTDCallbackQueryPayloadDataBuilder {
+            inner: CallbackQueryPayloadData::default(),
+            missing: vec!["data"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDCallbackQueryPayloadDataBuilder {
+        RTDCallbackQueryPayloadDataBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDCallbackQueryPayloadDataBuilder {
+        RTDCallbackQueryPayloadDataBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn data(&self) -> &String {
+    pub fn data(&self) -> &[u8] {
         &self.data
     }
+
+    pub fn into_data(self) -> Vec<u8> {
+        self.data
+    }
+
+    pub fn with_data<T: AsRef<[u8]>>(mut self, data: T) -> Self {
+        self.data = data.as_ref().to_vec();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDCallbackQueryPayloadDataBuilder {
     inner: CallbackQueryPayloadData,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDCallbackQueryPayloadDataBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> CallbackQueryPayloadData {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<CallbackQueryPayloadData> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "CallbackQueryPayloadData",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn data<T: AsRef<str>>(&mut self, data: T) -> &mut Self {
-        self.inner.data = data.as_ref().to_string();
+    pub fn data<T: AsRef<[u8]>>(&mut self, data: T) -> &mut Self {
+        self.inner.data = data.as_ref().to_vec();
+        self.missing.retain(|f| *f != "data");
         self
     }
 }
@@ -156,12 +198,13 @@ impl AsRef<CallbackQueryPayloadData> for RTDCallbackQueryPayloadDataBuilder {
 
 /// The payload from a game callback button
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "callbackQueryPayloadGame")]
 pub struct CallbackQueryPayloadGame {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// A short name of the game that was attached to the callback button
     game_short_name: String,
@@ -181,6 +224,20 @@ impl RObject for CallbackQueryPayloadGame {
     }
 }
 
+impl PartialEq for CallbackQueryPayloadGame {
+    fn eq(&self, other: &Self) -> bool {
+        self.game_short_name == other.game_short_name
+    }
+}
+
+impl Eq for CallbackQueryPayloadGame {}
+
+impl std::hash::Hash for CallbackQueryPayloadGame {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.game_short_name, state);
+    }
+}
+
 impl TDCallbackQueryPayload for CallbackQueryPayloadGame {}
 
 impl CallbackQueryPayloadGame {
@@ -188,29 +245,66 @@ impl CallbackQueryPayloadGame {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDCallbackQueryPayloadGameBuilder {
-        let mut inner = CallbackQueryPayloadGame::default();
-        inner.td_name = "callbackQueryPayloadGame".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDCallbackQueryPayloadGameBuilder { inner }
+        RTDCallbackQueryPayloadGameBuilder {
+            inner: CallbackQueryPayloadGame::default(),
+            missing: vec!["game_short_name"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDCallbackQueryPayloadGameBuilder {
+        RTDCallbackQueryPayloadGameBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDCallbackQueryPayloadGameBuilder {
+        RTDCallbackQueryPayloadGameBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn game_short_name(&self) -> &String {
         &self.game_short_name
     }
+
+    pub fn into_game_short_name(self) -> String {
+        self.game_short_name
+    }
+
+    pub fn with_game_short_name<T: AsRef<str>>(mut self, game_short_name: T) -> Self {
+        self.game_short_name = game_short_name.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDCallbackQueryPayloadGameBuilder {
     inner: CallbackQueryPayloadGame,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDCallbackQueryPayloadGameBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> CallbackQueryPayloadGame {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<CallbackQueryPayloadGame> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "CallbackQueryPayloadGame",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn game_short_name<T: AsRef<str>>(&mut self, game_short_name: T) -> &mut Self {
         self.inner.game_short_name = game_short_name.as_ref().to_string();
+        self.missing.retain(|f| *f != "game_short_name");
         self
     }
 }
@@ -226,3 +320,23 @@ impl AsRef<CallbackQueryPayloadGame> for RTDCallbackQueryPayloadGameBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(CallbackQueryPayload::Data(
+            CallbackQueryPayloadData::builder()
+                .data(b"\x00\x01\xfe\xff")
+                .build(),
+        ));
+        assert_round_trip(CallbackQueryPayload::Game(
+            CallbackQueryPayloadGame::builder()
+                .game_short_name("text")
+                .build(),
+        ));
+    }
+}
@@ -1,52 +1,48 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Represents result of checking whether the current session can be used to transfer a chat ownership to another user
 pub trait TDCanTransferOwnershipResult: Debug + RObject {}
 
 /// Represents result of checking whether the current session can be used to transfer a chat ownership to another user
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum CanTransferOwnershipResult {
-    #[doc(hidden)]
-    _Default(()),
-    /// Checks whether the current session can be used to transfer a chat ownership to another user
-    CanTransferOwnership(CanTransferOwnership),
     /// The session can be used
+    #[serde(rename = "canTransferOwnershipResultOk")]
     Ok(CanTransferOwnershipResultOk),
     /// The 2-step verification needs to be enabled first
+    #[serde(rename = "canTransferOwnershipResultPasswordNeeded")]
     PasswordNeeded(CanTransferOwnershipResultPasswordNeeded),
     /// The 2-step verification was enabled recently, user needs to wait
+    #[serde(rename = "canTransferOwnershipResultPasswordTooFresh")]
     PasswordTooFresh(CanTransferOwnershipResultPasswordTooFresh),
     /// The session was created recently, user needs to wait
+    #[serde(rename = "canTransferOwnershipResultSessionTooFresh")]
     SessionTooFresh(CanTransferOwnershipResultSessionTooFresh),
 }
 
+/// Default value is default of [CanTransferOwnershipResultOk], builders still require the field to be set
 impl Default for CanTransferOwnershipResult {
     fn default() -> Self {
-        CanTransferOwnershipResult::_Default(())
+        CanTransferOwnershipResult::Ok(CanTransferOwnershipResultOk::default())
     }
 }
 
-impl<'de> Deserialize<'de> for CanTransferOwnershipResult {
-    fn deserialize<D>(deserializer: D) -> Result<CanTransferOwnershipResult, D::Error>
+impl Serialize for CanTransferOwnershipResult {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          CanTransferOwnershipResult,
-          (canTransferOwnership, CanTransferOwnership);
-          (canTransferOwnershipResultOk, Ok);
-          (canTransferOwnershipResultPasswordNeeded, PasswordNeeded);
-          (canTransferOwnershipResultPasswordTooFresh, PasswordTooFresh);
-          (canTransferOwnershipResultSessionTooFresh, SessionTooFresh);
-
-        )(deserializer)
+        match self {
+            CanTransferOwnershipResult::Ok(t) => t.serialize(serializer),
+            CanTransferOwnershipResult::PasswordNeeded(t) => t.serialize(serializer),
+            CanTransferOwnershipResult::PasswordTooFresh(t) => t.serialize(serializer),
+            CanTransferOwnershipResult::SessionTooFresh(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -54,25 +50,19 @@ impl RObject for CanTransferOwnershipResult {
     #[doc(hidden)]
     fn td_name(&self) -> &'static str {
         match self {
-            CanTransferOwnershipResult::CanTransferOwnership(t) => t.td_name(),
             CanTransferOwnershipResult::Ok(t) => t.td_name(),
             CanTransferOwnershipResult::PasswordNeeded(t) => t.td_name(),
             CanTransferOwnershipResult::PasswordTooFresh(t) => t.td_name(),
             CanTransferOwnershipResult::SessionTooFresh(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
     fn extra(&self) -> Option<String> {
         match self {
-            CanTransferOwnershipResult::CanTransferOwnership(t) => t.extra(),
             CanTransferOwnershipResult::Ok(t) => t.extra(),
             CanTransferOwnershipResult::PasswordNeeded(t) => t.extra(),
             CanTransferOwnershipResult::PasswordTooFresh(t) => t.extra(),
             CanTransferOwnershipResult::SessionTooFresh(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -84,10 +74,6 @@ impl CanTransferOwnershipResult {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, CanTransferOwnershipResult::_Default(_))
-    }
 }
 
 impl AsRef<CanTransferOwnershipResult> for CanTransferOwnershipResult {
@@ -98,12 +84,13 @@ impl AsRef<CanTransferOwnershipResult> for CanTransferOwnershipResult {
 
 /// The session can be used
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "canTransferOwnershipResultOk")]
 pub struct CanTransferOwnershipResultOk {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -121,6 +108,18 @@ impl RObject for CanTransferOwnershipResultOk {
     }
 }
 
+impl PartialEq for CanTransferOwnershipResultOk {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for CanTransferOwnershipResultOk {}
+
+impl std::hash::Hash for CanTransferOwnershipResultOk {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDCanTransferOwnershipResult for CanTransferOwnershipResultOk {}
 
 impl CanTransferOwnershipResultOk {
@@ -128,22 +127,49 @@ impl CanTransferOwnershipResultOk {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDCanTransferOwnershipResultOkBuilder {
-        let mut inner = CanTransferOwnershipResultOk::default();
-        inner.td_name = "canTransferOwnershipResultOk".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDCanTransferOwnershipResultOkBuilder { inner }
+        RTDCanTransferOwnershipResultOkBuilder {
+            inner: CanTransferOwnershipResultOk::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDCanTransferOwnershipResultOkBuilder {
+        RTDCanTransferOwnershipResultOkBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDCanTransferOwnershipResultOkBuilder {
+        RTDCanTransferOwnershipResultOkBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDCanTransferOwnershipResultOkBuilder {
     inner: CanTransferOwnershipResultOk,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDCanTransferOwnershipResultOkBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> CanTransferOwnershipResultOk {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<CanTransferOwnershipResultOk> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "CanTransferOwnershipResultOk",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<CanTransferOwnershipResultOk> for CanTransferOwnershipResultOk {
@@ -160,12 +186,13 @@ impl AsRef<CanTransferOwnershipResultOk> for RTDCanTransferOwnershipResultOkBuil
 
 /// The 2-step verification needs to be enabled first
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "canTransferOwnershipResultPasswordNeeded")]
 pub struct CanTransferOwnershipResultPasswordNeeded {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -183,6 +210,18 @@ impl RObject for CanTransferOwnershipResultPasswordNeeded {
     }
 }
 
+impl PartialEq for CanTransferOwnershipResultPasswordNeeded {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for CanTransferOwnershipResultPasswordNeeded {}
+
+impl std::hash::Hash for CanTransferOwnershipResultPasswordNeeded {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDCanTransferOwnershipResult for CanTransferOwnershipResultPasswordNeeded {}
 
 impl CanTransferOwnershipResultPasswordNeeded {
@@ -190,22 +229,49 @@ impl CanTransferOwnershipResultPasswordNeeded {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDCanTransferOwnershipResultPasswordNeededBuilder {
-        let mut inner = CanTransferOwnershipResultPasswordNeeded::default();
-        inner.td_name = "canTransferOwnershipResultPasswordNeeded".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDCanTransferOwnershipResultPasswordNeededBuilder { inner }
+        RTDCanTransferOwnershipResultPasswordNeededBuilder {
+            inner: CanTransferOwnershipResultPasswordNeeded::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDCanTransferOwnershipResultPasswordNeededBuilder {
+        RTDCanTransferOwnershipResultPasswordNeededBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDCanTransferOwnershipResultPasswordNeededBuilder {
+        RTDCanTransferOwnershipResultPasswordNeededBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDCanTransferOwnershipResultPasswordNeededBuilder {
     inner: CanTransferOwnershipResultPasswordNeeded,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDCanTransferOwnershipResultPasswordNeededBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> CanTransferOwnershipResultPasswordNeeded {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<CanTransferOwnershipResultPasswordNeeded> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "CanTransferOwnershipResultPasswordNeeded",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<CanTransferOwnershipResultPasswordNeeded> for CanTransferOwnershipResultPasswordNeeded {
@@ -224,15 +290,16 @@ impl AsRef<CanTransferOwnershipResultPasswordNeeded>
 
 /// The 2-step verification was enabled recently, user needs to wait
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "canTransferOwnershipResultPasswordTooFresh")]
 pub struct CanTransferOwnershipResultPasswordTooFresh {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Time left before the session can be used to transfer ownership of a chat, in seconds
-    retry_after: i64,
+    retry_after: i32,
 }
 
 impl RObject for CanTransferOwnershipResultPasswordTooFresh {
@@ -249,6 +316,20 @@ impl RObject for CanTransferOwnershipResultPasswordTooFresh {
     }
 }
 
+impl PartialEq for CanTransferOwnershipResultPasswordTooFresh {
+    fn eq(&self, other: &Self) -> bool {
+        self.retry_after == other.retry_after
+    }
+}
+
+impl Eq for CanTransferOwnershipResultPasswordTooFresh {}
+
+impl std::hash::Hash for CanTransferOwnershipResultPasswordTooFresh {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.retry_after, state);
+    }
+}
+
 impl TDCanTransferOwnershipResult for CanTransferOwnershipResultPasswordTooFresh {}
 
 impl CanTransferOwnershipResultPasswordTooFresh {
@@ -256,29 +337,62 @@ impl CanTransferOwnershipResultPasswordTooFresh {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDCanTransferOwnershipResultPasswordTooFreshBuilder {
-        let mut inner = CanTransferOwnershipResultPasswordTooFresh::default();
-        inner.td_name = "canTransferOwnershipResultPasswordTooFresh".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDCanTransferOwnershipResultPasswordTooFreshBuilder { inner }
+        RTDCanTransferOwnershipResultPasswordTooFreshBuilder {
+            inner: CanTransferOwnershipResultPasswordTooFresh::default(),
+            missing: vec!["retry_after"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDCanTransferOwnershipResultPasswordTooFreshBuilder {
+        RTDCanTransferOwnershipResultPasswordTooFreshBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDCanTransferOwnershipResultPasswordTooFreshBuilder {
+        RTDCanTransferOwnershipResultPasswordTooFreshBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn retry_after(&self) -> i64 {
+    pub fn retry_after(&self) -> i32 {
         self.retry_after
     }
+
+    pub fn with_retry_after(mut self, retry_after: i32) -> Self {
+        self.retry_after = retry_after;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDCanTransferOwnershipResultPasswordTooFreshBuilder {
     inner: CanTransferOwnershipResultPasswordTooFresh,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDCanTransferOwnershipResultPasswordTooFreshBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> CanTransferOwnershipResultPasswordTooFresh {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<CanTransferOwnershipResultPasswordTooFresh> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "CanTransferOwnershipResultPasswordTooFresh",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn retry_after(&mut self, retry_after: i64) -> &mut Self {
+    pub fn retry_after(&mut self, retry_after: i32) -> &mut Self {
         self.inner.retry_after = retry_after;
+        self.missing.retain(|f| *f != "retry_after");
         self
     }
 }
@@ -301,15 +415,16 @@ impl AsRef<CanTransferOwnershipResultPasswordTooFresh>
 
 /// The session was created recently, user needs to wait
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "canTransferOwnershipResultSessionTooFresh")]
 pub struct CanTransferOwnershipResultSessionTooFresh {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Time left before the session can be used to transfer ownership of a chat, in seconds
-    retry_after: i64,
+    retry_after: i32,
 }
 
 impl RObject for CanTransferOwnershipResultSessionTooFresh {
@@ -326,6 +441,20 @@ impl RObject for CanTransferOwnershipResultSessionTooFresh {
     }
 }
 
+impl PartialEq for CanTransferOwnershipResultSessionTooFresh {
+    fn eq(&self, other: &Self) -> bool {
+        self.retry_after == other.retry_after
+    }
+}
+
+impl Eq for CanTransferOwnershipResultSessionTooFresh {}
+
+impl std::hash::Hash for CanTransferOwnershipResultSessionTooFresh {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.retry_after, state);
+    }
+}
+
 impl TDCanTransferOwnershipResult for CanTransferOwnershipResultSessionTooFresh {}
 
 impl CanTransferOwnershipResultSessionTooFresh {
@@ -333,29 +462,62 @@ impl CanTransferOwnershipResultSessionTooFresh {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDCanTransferOwnershipResultSessionTooFreshBuilder {
-        let mut inner = CanTransferOwnershipResultSessionTooFresh::default();
-        inner.td_name = "canTransferOwnershipResultSessionTooFresh".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDCanTransferOwnershipResultSessionTooFreshBuilder { inner }
+        RTDCanTransferOwnershipResultSessionTooFreshBuilder {
+            inner: CanTransferOwnershipResultSessionTooFresh::default(),
+            missing: vec!["retry_after"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDCanTransferOwnershipResultSessionTooFreshBuilder {
+        RTDCanTransferOwnershipResultSessionTooFreshBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDCanTransferOwnershipResultSessionTooFreshBuilder {
+        RTDCanTransferOwnershipResultSessionTooFreshBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn retry_after(&self) -> i64 {
+    pub fn retry_after(&self) -> i32 {
         self.retry_after
     }
+
+    pub fn with_retry_after(mut self, retry_after: i32) -> Self {
+        self.retry_after = retry_after;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDCanTransferOwnershipResultSessionTooFreshBuilder {
     inner: CanTransferOwnershipResultSessionTooFresh,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDCanTransferOwnershipResultSessionTooFreshBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> CanTransferOwnershipResultSessionTooFresh {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<CanTransferOwnershipResultSessionTooFresh> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "CanTransferOwnershipResultSessionTooFresh",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn retry_after(&mut self, retry_after: i64) -> &mut Self {
+    pub fn retry_after(&mut self, retry_after: i32) -> &mut Self {
         self.inner.retry_after = retry_after;
+        self.missing.retain(|f| *f != "retry_after");
         self
     }
 }
@@ -375,3 +537,29 @@ impl AsRef<CanTransferOwnershipResultSessionTooFresh>
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(CanTransferOwnershipResult::Ok(
+            CanTransferOwnershipResultOk::builder().build(),
+        ));
+        assert_round_trip(CanTransferOwnershipResult::PasswordNeeded(
+            CanTransferOwnershipResultPasswordNeeded::builder().build(),
+        ));
+        assert_round_trip(CanTransferOwnershipResult::PasswordTooFresh(
+            CanTransferOwnershipResultPasswordTooFresh::builder()
+                .retry_after(i32::MAX)
+                .build(),
+        ));
+        assert_round_trip(CanTransferOwnershipResult::SessionTooFresh(
+            CanTransferOwnershipResultSessionTooFresh::builder()
+                .retry_after(i32::MAX)
+                .build(),
+        ));
+    }
+}
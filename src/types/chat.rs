@@ -1,18 +1,18 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// A chat. (Can be a private chat, basic group, supergroup, or secret chat)
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chat")]
 pub struct Chat {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Chat unique identifier
-    id: i64,
+    id: ChatId,
     /// Type of the chat
     #[serde(rename(serialize = "type", deserialize = "type"))]
     type_: ChatType,
@@ -27,8 +27,8 @@ pub struct Chat {
     /// Last message in the chat; may be null
     last_message: Option<Message>,
     /// Descending parameter by which chats are sorted in the main chat list. If the order number of two chats is the same, they must be sorted in descending order by ID. If 0, the position of the chat in the list is undetermined
-    #[serde(deserialize_with = "super::_common::number_from_string")]
-    order: isize,
+    #[serde(with = "super::_common::int64")]
+    order: i64,
     /// True, if the chat is pinned
     is_pinned: bool,
     /// True, if the chat is marked as unread
@@ -46,21 +46,21 @@ pub struct Chat {
     /// Default value of the disable_notification parameter, used when a message is sent to the chat
     default_disable_notification: bool,
     /// Number of unread messages in the chat
-    unread_count: i64,
+    unread_count: i32,
     /// Identifier of the last read incoming message
-    last_read_inbox_message_id: i64,
+    last_read_inbox_message_id: MessageId,
     /// Identifier of the last read outgoing message
-    last_read_outbox_message_id: i64,
+    last_read_outbox_message_id: MessageId,
     /// Number of unread messages with a mention/reply in the chat
-    unread_mention_count: i64,
+    unread_mention_count: i32,
     /// Notification settings for this chat
     notification_settings: ChatNotificationSettings,
     /// Describes actions which should be possible to do through a chat action bar; may be null
     action_bar: Option<ChatActionBar>,
     /// Identifier of the pinned message in the chat; 0 if none
-    pinned_message_id: i64,
+    pinned_message_id: MessageId,
     /// Identifier of the message from which reply markup needs to be used; 0 if there is no default custom reply markup in the chat
-    reply_markup_message_id: i64,
+    reply_markup_message_id: MessageId,
     /// A draft of a message in the chat; may be null
     draft_message: Option<DraftMessage>,
     /// Contains client-specific data associated with the chat. (For example, the chat position or local chat notification settings can be stored here.) Persistent if the message database is used
@@ -81,18 +81,75 @@ impl RObject for Chat {
     }
 }
 
+impl PartialEq for Chat {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.type_ == other.type_
+            && self.chat_list == other.chat_list
+            && self.title == other.title
+            && self.photo == other.photo
+            && self.permissions == other.permissions
+            && self.last_message == other.last_message
+            && self.order == other.order
+            && self.is_pinned == other.is_pinned
+            && self.is_marked_as_unread == other.is_marked_as_unread
+            && self.is_sponsored == other.is_sponsored
+            && self.has_scheduled_messages == other.has_scheduled_messages
+            && self.can_be_deleted_only_for_self == other.can_be_deleted_only_for_self
+            && self.can_be_deleted_for_all_users == other.can_be_deleted_for_all_users
+            && self.can_be_reported == other.can_be_reported
+            && self.default_disable_notification == other.default_disable_notification
+            && self.unread_count == other.unread_count
+            && self.last_read_inbox_message_id == other.last_read_inbox_message_id
+            && self.last_read_outbox_message_id == other.last_read_outbox_message_id
+            && self.unread_mention_count == other.unread_mention_count
+            && self.notification_settings == other.notification_settings
+            && self.action_bar == other.action_bar
+            && self.pinned_message_id == other.pinned_message_id
+            && self.reply_markup_message_id == other.reply_markup_message_id
+            && self.draft_message == other.draft_message
+            && self.client_data == other.client_data
+    }
+}
+
 impl Chat {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatBuilder {
-        let mut inner = Chat::default();
-        inner.td_name = "chat".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatBuilder { inner }
-    }
-
-    pub fn id(&self) -> i64 {
+        RTDChatBuilder {
+            inner: Chat::default(),
+            missing: vec![
+                "id",
+                "type_",
+                "title",
+                "permissions",
+                "order",
+                "unread_count",
+                "last_read_inbox_message_id",
+                "last_read_outbox_message_id",
+                "unread_mention_count",
+                "notification_settings",
+                "client_data",
+            ],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatBuilder {
+        RTDChatBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatBuilder {
+        RTDChatBuilder {
+            inner: self,
+            missing: vec![],
+        }
+    }
+
+    pub fn id(&self) -> ChatId {
         self.id
     }
 
@@ -120,7 +177,7 @@ impl Chat {
         &self.last_message
     }
 
-    pub fn order(&self) -> isize {
+    pub fn order(&self) -> i64 {
         self.order
     }
 
@@ -156,19 +213,19 @@ impl Chat {
         self.default_disable_notification
     }
 
-    pub fn unread_count(&self) -> i64 {
+    pub fn unread_count(&self) -> i32 {
         self.unread_count
     }
 
-    pub fn last_read_inbox_message_id(&self) -> i64 {
+    pub fn last_read_inbox_message_id(&self) -> MessageId {
         self.last_read_inbox_message_id
     }
 
-    pub fn last_read_outbox_message_id(&self) -> i64 {
+    pub fn last_read_outbox_message_id(&self) -> MessageId {
         self.last_read_outbox_message_id
     }
 
-    pub fn unread_mention_count(&self) -> i64 {
+    pub fn unread_mention_count(&self) -> i32 {
         self.unread_mention_count
     }
 
@@ -180,11 +237,11 @@ impl Chat {
         &self.action_bar
     }
 
-    pub fn pinned_message_id(&self) -> i64 {
+    pub fn pinned_message_id(&self) -> MessageId {
         self.pinned_message_id
     }
 
-    pub fn reply_markup_message_id(&self) -> i64 {
+    pub fn reply_markup_message_id(&self) -> MessageId {
         self.reply_markup_message_id
     }
 
@@ -195,25 +252,219 @@ impl Chat {
     pub fn client_data(&self) -> &String {
         &self.client_data
     }
+
+    pub fn into_type(self) -> ChatType {
+        self.type_
+    }
+
+    pub fn into_chat_list(self) -> Option<ChatList> {
+        self.chat_list
+    }
+
+    pub fn into_title(self) -> String {
+        self.title
+    }
+
+    pub fn into_photo(self) -> Option<ChatPhoto> {
+        self.photo
+    }
+
+    pub fn into_permissions(self) -> ChatPermissions {
+        self.permissions
+    }
+
+    pub fn into_last_message(self) -> Option<Message> {
+        self.last_message
+    }
+
+    pub fn into_notification_settings(self) -> ChatNotificationSettings {
+        self.notification_settings
+    }
+
+    pub fn into_action_bar(self) -> Option<ChatActionBar> {
+        self.action_bar
+    }
+
+    pub fn into_draft_message(self) -> Option<DraftMessage> {
+        self.draft_message
+    }
+
+    pub fn into_client_data(self) -> String {
+        self.client_data
+    }
+
+    pub fn with_id<T: Into<ChatId>>(mut self, id: T) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    pub fn with_type<T: AsRef<ChatType>>(mut self, type_: T) -> Self {
+        self.type_ = type_.as_ref().clone();
+        self
+    }
+
+    pub fn with_chat_list<T: AsRef<ChatList>>(mut self, chat_list: T) -> Self {
+        self.chat_list = Some(chat_list.as_ref().clone());
+        self
+    }
+
+    pub fn with_title<T: AsRef<str>>(mut self, title: T) -> Self {
+        self.title = title.as_ref().to_string();
+        self
+    }
+
+    pub fn with_photo<T: AsRef<ChatPhoto>>(mut self, photo: T) -> Self {
+        self.photo = Some(photo.as_ref().clone());
+        self
+    }
+
+    pub fn with_permissions<T: AsRef<ChatPermissions>>(mut self, permissions: T) -> Self {
+        self.permissions = permissions.as_ref().clone();
+        self
+    }
+
+    pub fn with_last_message<T: AsRef<Message>>(mut self, last_message: T) -> Self {
+        self.last_message = Some(last_message.as_ref().clone());
+        self
+    }
+
+    pub fn with_order(mut self, order: i64) -> Self {
+        self.order = order;
+        self
+    }
+
+    pub fn with_is_pinned(mut self, is_pinned: bool) -> Self {
+        self.is_pinned = is_pinned;
+        self
+    }
+
+    pub fn with_is_marked_as_unread(mut self, is_marked_as_unread: bool) -> Self {
+        self.is_marked_as_unread = is_marked_as_unread;
+        self
+    }
+
+    pub fn with_is_sponsored(mut self, is_sponsored: bool) -> Self {
+        self.is_sponsored = is_sponsored;
+        self
+    }
+
+    pub fn with_has_scheduled_messages(mut self, has_scheduled_messages: bool) -> Self {
+        self.has_scheduled_messages = has_scheduled_messages;
+        self
+    }
+
+    pub fn with_can_be_deleted_only_for_self(mut self, can_be_deleted_only_for_self: bool) -> Self {
+        self.can_be_deleted_only_for_self = can_be_deleted_only_for_self;
+        self
+    }
+
+    pub fn with_can_be_deleted_for_all_users(mut self, can_be_deleted_for_all_users: bool) -> Self {
+        self.can_be_deleted_for_all_users = can_be_deleted_for_all_users;
+        self
+    }
+
+    pub fn with_can_be_reported(mut self, can_be_reported: bool) -> Self {
+        self.can_be_reported = can_be_reported;
+        self
+    }
+
+    pub fn with_default_disable_notification(mut self, default_disable_notification: bool) -> Self {
+        self.default_disable_notification = default_disable_notification;
+        self
+    }
+
+    pub fn with_unread_count(mut self, unread_count: i32) -> Self {
+        self.unread_count = unread_count;
+        self
+    }
+
+    pub fn with_last_read_inbox_message_id<T: Into<MessageId>>(
+        mut self,
+        last_read_inbox_message_id: T,
+    ) -> Self {
+        self.last_read_inbox_message_id = last_read_inbox_message_id.into();
+        self
+    }
+
+    pub fn with_last_read_outbox_message_id<T: Into<MessageId>>(
+        mut self,
+        last_read_outbox_message_id: T,
+    ) -> Self {
+        self.last_read_outbox_message_id = last_read_outbox_message_id.into();
+        self
+    }
+
+    pub fn with_unread_mention_count(mut self, unread_mention_count: i32) -> Self {
+        self.unread_mention_count = unread_mention_count;
+        self
+    }
+
+    pub fn with_notification_settings<T: AsRef<ChatNotificationSettings>>(
+        mut self,
+        notification_settings: T,
+    ) -> Self {
+        self.notification_settings = notification_settings.as_ref().clone();
+        self
+    }
+
+    pub fn with_action_bar<T: AsRef<ChatActionBar>>(mut self, action_bar: T) -> Self {
+        self.action_bar = Some(action_bar.as_ref().clone());
+        self
+    }
+
+    pub fn with_pinned_message_id<T: Into<MessageId>>(mut self, pinned_message_id: T) -> Self {
+        self.pinned_message_id = pinned_message_id.into();
+        self
+    }
+
+    pub fn with_reply_markup_message_id<T: Into<MessageId>>(
+        mut self,
+        reply_markup_message_id: T,
+    ) -> Self {
+        self.reply_markup_message_id = reply_markup_message_id.into();
+        self
+    }
+
+    pub fn with_draft_message<T: AsRef<DraftMessage>>(mut self, draft_message: T) -> Self {
+        self.draft_message = Some(draft_message.as_ref().clone());
+        self
+    }
+
+    pub fn with_client_data<T: AsRef<str>>(mut self, client_data: T) -> Self {
+        self.client_data = client_data.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDChatBuilder {
     inner: Chat,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> Chat {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<Chat> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("Chat", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn id(&mut self, id: i64) -> &mut Self {
-        self.inner.id = id;
+    pub fn id<T: Into<ChatId>>(&mut self, id: T) -> &mut Self {
+        self.inner.id = id.into();
+        self.missing.retain(|f| *f != "id");
         self
     }
 
     pub fn type_<T: AsRef<ChatType>>(&mut self, type_: T) -> &mut Self {
         self.inner.type_ = type_.as_ref().clone();
+        self.missing.retain(|f| *f != "type_");
         self
     }
 
@@ -224,6 +475,7 @@ impl RTDChatBuilder {
 
     pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
         self.inner.title = title.as_ref().to_string();
+        self.missing.retain(|f| *f != "title");
         self
     }
 
@@ -234,6 +486,7 @@ impl RTDChatBuilder {
 
     pub fn permissions<T: AsRef<ChatPermissions>>(&mut self, permissions: T) -> &mut Self {
         self.inner.permissions = permissions.as_ref().clone();
+        self.missing.retain(|f| *f != "permissions");
         self
     }
 
@@ -242,8 +495,9 @@ impl RTDChatBuilder {
         self
     }
 
-    pub fn order(&mut self, order: isize) -> &mut Self {
+    pub fn order(&mut self, order: i64) -> &mut Self {
         self.inner.order = order;
+        self.missing.retain(|f| *f != "order");
         self
     }
 
@@ -296,23 +550,33 @@ impl RTDChatBuilder {
         self
     }
 
-    pub fn unread_count(&mut self, unread_count: i64) -> &mut Self {
+    pub fn unread_count(&mut self, unread_count: i32) -> &mut Self {
         self.inner.unread_count = unread_count;
+        self.missing.retain(|f| *f != "unread_count");
         self
     }
 
-    pub fn last_read_inbox_message_id(&mut self, last_read_inbox_message_id: i64) -> &mut Self {
-        self.inner.last_read_inbox_message_id = last_read_inbox_message_id;
+    pub fn last_read_inbox_message_id<T: Into<MessageId>>(
+        &mut self,
+        last_read_inbox_message_id: T,
+    ) -> &mut Self {
+        self.inner.last_read_inbox_message_id = last_read_inbox_message_id.into();
+        self.missing.retain(|f| *f != "last_read_inbox_message_id");
         self
     }
 
-    pub fn last_read_outbox_message_id(&mut self, last_read_outbox_message_id: i64) -> &mut Self {
-        self.inner.last_read_outbox_message_id = last_read_outbox_message_id;
+    pub fn last_read_outbox_message_id<T: Into<MessageId>>(
+        &mut self,
+        last_read_outbox_message_id: T,
+    ) -> &mut Self {
+        self.inner.last_read_outbox_message_id = last_read_outbox_message_id.into();
+        self.missing.retain(|f| *f != "last_read_outbox_message_id");
         self
     }
 
-    pub fn unread_mention_count(&mut self, unread_mention_count: i64) -> &mut Self {
+    pub fn unread_mention_count(&mut self, unread_mention_count: i32) -> &mut Self {
         self.inner.unread_mention_count = unread_mention_count;
+        self.missing.retain(|f| *f != "unread_mention_count");
         self
     }
 
@@ -321,6 +585,7 @@ impl RTDChatBuilder {
         notification_settings: T,
     ) -> &mut Self {
         self.inner.notification_settings = notification_settings.as_ref().clone();
+        self.missing.retain(|f| *f != "notification_settings");
         self
     }
 
@@ -329,13 +594,16 @@ impl RTDChatBuilder {
         self
     }
 
-    pub fn pinned_message_id(&mut self, pinned_message_id: i64) -> &mut Self {
-        self.inner.pinned_message_id = pinned_message_id;
+    pub fn pinned_message_id<T: Into<MessageId>>(&mut self, pinned_message_id: T) -> &mut Self {
+        self.inner.pinned_message_id = pinned_message_id.into();
         self
     }
 
-    pub fn reply_markup_message_id(&mut self, reply_markup_message_id: i64) -> &mut Self {
-        self.inner.reply_markup_message_id = reply_markup_message_id;
+    pub fn reply_markup_message_id<T: Into<MessageId>>(
+        &mut self,
+        reply_markup_message_id: T,
+    ) -> &mut Self {
+        self.inner.reply_markup_message_id = reply_markup_message_id.into();
         self
     }
 
@@ -346,6 +614,7 @@ impl RTDChatBuilder {
 
     pub fn client_data<T: AsRef<str>>(&mut self, client_data: T) -> &mut Self {
         self.inner.client_data = client_data.as_ref().to_string();
+        self.missing.retain(|f| *f != "client_data");
         self
     }
 }
@@ -361,3 +630,66 @@ impl AsRef<Chat> for RTDChatBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            Chat::builder()
+                .id(9007199254740991i64)
+                .type_(ChatType::BasicGroup(
+                    ChatTypeBasicGroup::builder()
+                        .basic_group_id(i32::MAX)
+                        .build(),
+                ))
+                .title("text")
+                .permissions(
+                    ChatPermissions::builder()
+                        .can_send_messages(true)
+                        .can_send_media_messages(true)
+                        .can_send_polls(true)
+                        .can_send_other_messages(true)
+                        .can_add_web_page_previews(true)
+                        .can_change_info(true)
+                        .can_invite_users(true)
+                        .can_pin_messages(true)
+                        .build(),
+                )
+                .order(i64::MAX)
+                .is_pinned(true)
+                .is_marked_as_unread(true)
+                .is_sponsored(true)
+                .has_scheduled_messages(true)
+                .can_be_deleted_only_for_self(true)
+                .can_be_deleted_for_all_users(true)
+                .can_be_reported(true)
+                .default_disable_notification(true)
+                .unread_count(i32::MAX)
+                .last_read_inbox_message_id(9007199254740991i64)
+                .last_read_outbox_message_id(9007199254740991i64)
+                .unread_mention_count(i32::MAX)
+                .notification_settings(
+                    ChatNotificationSettings::builder()
+                        .use_default_mute_for(true)
+                        .mute_for(i32::MAX)
+                        .use_default_sound(true)
+                        .sound("text")
+                        .use_default_show_preview(true)
+                        .show_preview(true)
+                        .use_default_disable_pinned_message_notifications(true)
+                        .disable_pinned_message_notifications(true)
+                        .use_default_disable_mention_notifications(true)
+                        .disable_mention_notifications(true)
+                        .build(),
+                )
+                .pinned_message_id(9007199254740991i64)
+                .reply_markup_message_id(9007199254740991i64)
+                .client_data("text")
+                .build(),
+        );
+    }
+}
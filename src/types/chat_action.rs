@@ -1,76 +1,84 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Describes the different types of activity in a chat
 pub trait TDChatAction: Debug + RObject {}
 
 /// Describes the different types of activity in a chat
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum ChatAction {
-    #[doc(hidden)]
-    _Default(()),
     /// The user has cancelled the previous action
+    #[serde(rename = "chatActionCancel")]
     Cancel(ChatActionCancel),
     /// The user is picking a contact to send
+    #[serde(rename = "chatActionChoosingContact")]
     ChoosingContact(ChatActionChoosingContact),
     /// The user is picking a location or venue to send
+    #[serde(rename = "chatActionChoosingLocation")]
     ChoosingLocation(ChatActionChoosingLocation),
     /// The user is recording a video
+    #[serde(rename = "chatActionRecordingVideo")]
     RecordingVideo(ChatActionRecordingVideo),
     /// The user is recording a video note
+    #[serde(rename = "chatActionRecordingVideoNote")]
     RecordingVideoNote(ChatActionRecordingVideoNote),
     /// The user is recording a voice note
+    #[serde(rename = "chatActionRecordingVoiceNote")]
     RecordingVoiceNote(ChatActionRecordingVoiceNote),
     /// The user has started to play a game
+    #[serde(rename = "chatActionStartPlayingGame")]
     StartPlayingGame(ChatActionStartPlayingGame),
     /// The user is typing a message
+    #[serde(rename = "chatActionTyping")]
     Typing(ChatActionTyping),
     /// The user is uploading a document
+    #[serde(rename = "chatActionUploadingDocument")]
     UploadingDocument(ChatActionUploadingDocument),
     /// The user is uploading a photo
+    #[serde(rename = "chatActionUploadingPhoto")]
     UploadingPhoto(ChatActionUploadingPhoto),
     /// The user is uploading a video
+    #[serde(rename = "chatActionUploadingVideo")]
     UploadingVideo(ChatActionUploadingVideo),
     /// The user is uploading a video note
+    #[serde(rename = "chatActionUploadingVideoNote")]
     UploadingVideoNote(ChatActionUploadingVideoNote),
     /// The user is uploading a voice note
+    #[serde(rename = "chatActionUploadingVoiceNote")]
     UploadingVoiceNote(ChatActionUploadingVoiceNote),
 }
 
+/// Default value is default of [ChatActionCancel], builders still require the field to be set
 impl Default for ChatAction {
     fn default() -> Self {
-        ChatAction::_Default(())
+        ChatAction::Cancel(ChatActionCancel::default())
     }
 }
 
-impl<'de> Deserialize<'de> for ChatAction {
-    fn deserialize<D>(deserializer: D) -> Result<ChatAction, D::Error>
+impl Serialize for ChatAction {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          ChatAction,
-          (chatActionCancel, Cancel);
-          (chatActionChoosingContact, ChoosingContact);
-          (chatActionChoosingLocation, ChoosingLocation);
-          (chatActionRecordingVideo, RecordingVideo);
-          (chatActionRecordingVideoNote, RecordingVideoNote);
-          (chatActionRecordingVoiceNote, RecordingVoiceNote);
-          (chatActionStartPlayingGame, StartPlayingGame);
-          (chatActionTyping, Typing);
-          (chatActionUploadingDocument, UploadingDocument);
-          (chatActionUploadingPhoto, UploadingPhoto);
-          (chatActionUploadingVideo, UploadingVideo);
-          (chatActionUploadingVideoNote, UploadingVideoNote);
-          (chatActionUploadingVoiceNote, UploadingVoiceNote);
-
-        )(deserializer)
+        match self {
+            ChatAction::Cancel(t) => t.serialize(serializer),
+            ChatAction::ChoosingContact(t) => t.serialize(serializer),
+            ChatAction::ChoosingLocation(t) => t.serialize(serializer),
+            ChatAction::RecordingVideo(t) => t.serialize(serializer),
+            ChatAction::RecordingVideoNote(t) => t.serialize(serializer),
+            ChatAction::RecordingVoiceNote(t) => t.serialize(serializer),
+            ChatAction::StartPlayingGame(t) => t.serialize(serializer),
+            ChatAction::Typing(t) => t.serialize(serializer),
+            ChatAction::UploadingDocument(t) => t.serialize(serializer),
+            ChatAction::UploadingPhoto(t) => t.serialize(serializer),
+            ChatAction::UploadingVideo(t) => t.serialize(serializer),
+            ChatAction::UploadingVideoNote(t) => t.serialize(serializer),
+            ChatAction::UploadingVoiceNote(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -91,8 +99,6 @@ impl RObject for ChatAction {
             ChatAction::UploadingVideo(t) => t.td_name(),
             ChatAction::UploadingVideoNote(t) => t.td_name(),
             ChatAction::UploadingVoiceNote(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -111,8 +117,6 @@ impl RObject for ChatAction {
             ChatAction::UploadingVideo(t) => t.extra(),
             ChatAction::UploadingVideoNote(t) => t.extra(),
             ChatAction::UploadingVoiceNote(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -124,10 +128,6 @@ impl ChatAction {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, ChatAction::_Default(_))
-    }
 }
 
 impl AsRef<ChatAction> for ChatAction {
@@ -138,12 +138,13 @@ impl AsRef<ChatAction> for ChatAction {
 
 /// The user has cancelled the previous action
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatActionCancel")]
 pub struct ChatActionCancel {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -161,6 +162,18 @@ impl RObject for ChatActionCancel {
     }
 }
 
+impl PartialEq for ChatActionCancel {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ChatActionCancel {}
+
+impl std::hash::Hash for ChatActionCancel {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDChatAction for ChatActionCancel {}
 
 impl ChatActionCancel {
@@ -168,22 +181,49 @@ impl ChatActionCancel {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatActionCancelBuilder {
-        let mut inner = ChatActionCancel::default();
-        inner.td_name = "chatActionCancel".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatActionCancelBuilder { inner }
+        RTDChatActionCancelBuilder {
+            inner: ChatActionCancel::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatActionCancelBuilder {
+        RTDChatActionCancelBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatActionCancelBuilder {
+        RTDChatActionCancelBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDChatActionCancelBuilder {
     inner: ChatActionCancel,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatActionCancelBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatActionCancel {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatActionCancel> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatActionCancel",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<ChatActionCancel> for ChatActionCancel {
@@ -200,12 +240,13 @@ impl AsRef<ChatActionCancel> for RTDChatActionCancelBuilder {
 
 /// The user is picking a contact to send
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatActionChoosingContact")]
 pub struct ChatActionChoosingContact {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -223,6 +264,18 @@ impl RObject for ChatActionChoosingContact {
     }
 }
 
+impl PartialEq for ChatActionChoosingContact {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ChatActionChoosingContact {}
+
+impl std::hash::Hash for ChatActionChoosingContact {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDChatAction for ChatActionChoosingContact {}
 
 impl ChatActionChoosingContact {
@@ -230,22 +283,49 @@ impl ChatActionChoosingContact {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatActionChoosingContactBuilder {
-        let mut inner = ChatActionChoosingContact::default();
-        inner.td_name = "chatActionChoosingContact".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatActionChoosingContactBuilder { inner }
+        RTDChatActionChoosingContactBuilder {
+            inner: ChatActionChoosingContact::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatActionChoosingContactBuilder {
+        RTDChatActionChoosingContactBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatActionChoosingContactBuilder {
+        RTDChatActionChoosingContactBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDChatActionChoosingContactBuilder {
     inner: ChatActionChoosingContact,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatActionChoosingContactBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatActionChoosingContact {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatActionChoosingContact> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatActionChoosingContact",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<ChatActionChoosingContact> for ChatActionChoosingContact {
@@ -262,12 +342,13 @@ impl AsRef<ChatActionChoosingContact> for RTDChatActionChoosingContactBuilder {
 
 /// The user is picking a location or venue to send
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatActionChoosingLocation")]
 pub struct ChatActionChoosingLocation {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -285,6 +366,18 @@ impl RObject for ChatActionChoosingLocation {
     }
 }
 
+impl PartialEq for ChatActionChoosingLocation {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ChatActionChoosingLocation {}
+
+impl std::hash::Hash for ChatActionChoosingLocation {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDChatAction for ChatActionChoosingLocation {}
 
 impl ChatActionChoosingLocation {
@@ -292,22 +385,49 @@ impl ChatActionChoosingLocation {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatActionChoosingLocationBuilder {
-        let mut inner = ChatActionChoosingLocation::default();
-        inner.td_name = "chatActionChoosingLocation".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatActionChoosingLocationBuilder { inner }
+        RTDChatActionChoosingLocationBuilder {
+            inner: ChatActionChoosingLocation::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatActionChoosingLocationBuilder {
+        RTDChatActionChoosingLocationBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatActionChoosingLocationBuilder {
+        RTDChatActionChoosingLocationBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDChatActionChoosingLocationBuilder {
     inner: ChatActionChoosingLocation,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatActionChoosingLocationBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatActionChoosingLocation {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatActionChoosingLocation> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatActionChoosingLocation",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<ChatActionChoosingLocation> for ChatActionChoosingLocation {
@@ -324,12 +444,13 @@ impl AsRef<ChatActionChoosingLocation> for RTDChatActionChoosingLocationBuilder
 
 /// The user is recording a video
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatActionRecordingVideo")]
 pub struct ChatActionRecordingVideo {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -347,6 +468,18 @@ impl RObject for ChatActionRecordingVideo {
     }
 }
 
+impl PartialEq for ChatActionRecordingVideo {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ChatActionRecordingVideo {}
+
+impl std::hash::Hash for ChatActionRecordingVideo {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDChatAction for ChatActionRecordingVideo {}
 
 impl ChatActionRecordingVideo {
@@ -354,22 +487,49 @@ impl ChatActionRecordingVideo {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatActionRecordingVideoBuilder {
-        let mut inner = ChatActionRecordingVideo::default();
-        inner.td_name = "chatActionRecordingVideo".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatActionRecordingVideoBuilder { inner }
+        RTDChatActionRecordingVideoBuilder {
+            inner: ChatActionRecordingVideo::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatActionRecordingVideoBuilder {
+        RTDChatActionRecordingVideoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatActionRecordingVideoBuilder {
+        RTDChatActionRecordingVideoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDChatActionRecordingVideoBuilder {
     inner: ChatActionRecordingVideo,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatActionRecordingVideoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatActionRecordingVideo {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatActionRecordingVideo> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatActionRecordingVideo",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<ChatActionRecordingVideo> for ChatActionRecordingVideo {
@@ -386,12 +546,13 @@ impl AsRef<ChatActionRecordingVideo> for RTDChatActionRecordingVideoBuilder {
 
 /// The user is recording a video note
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatActionRecordingVideoNote")]
 pub struct ChatActionRecordingVideoNote {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -409,6 +570,18 @@ impl RObject for ChatActionRecordingVideoNote {
     }
 }
 
+impl PartialEq for ChatActionRecordingVideoNote {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ChatActionRecordingVideoNote {}
+
+impl std::hash::Hash for ChatActionRecordingVideoNote {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDChatAction for ChatActionRecordingVideoNote {}
 
 impl ChatActionRecordingVideoNote {
@@ -416,22 +589,49 @@ impl ChatActionRecordingVideoNote {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatActionRecordingVideoNoteBuilder {
-        let mut inner = ChatActionRecordingVideoNote::default();
-        inner.td_name = "chatActionRecordingVideoNote".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatActionRecordingVideoNoteBuilder { inner }
+        RTDChatActionRecordingVideoNoteBuilder {
+            inner: ChatActionRecordingVideoNote::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatActionRecordingVideoNoteBuilder {
+        RTDChatActionRecordingVideoNoteBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatActionRecordingVideoNoteBuilder {
+        RTDChatActionRecordingVideoNoteBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDChatActionRecordingVideoNoteBuilder {
     inner: ChatActionRecordingVideoNote,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatActionRecordingVideoNoteBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatActionRecordingVideoNote {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatActionRecordingVideoNote> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatActionRecordingVideoNote",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<ChatActionRecordingVideoNote> for ChatActionRecordingVideoNote {
@@ -448,12 +648,13 @@ impl AsRef<ChatActionRecordingVideoNote> for RTDChatActionRecordingVideoNoteBuil
 
 /// The user is recording a voice note
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatActionRecordingVoiceNote")]
 pub struct ChatActionRecordingVoiceNote {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -471,6 +672,18 @@ impl RObject for ChatActionRecordingVoiceNote {
     }
 }
 
+impl PartialEq for ChatActionRecordingVoiceNote {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ChatActionRecordingVoiceNote {}
+
+impl std::hash::Hash for ChatActionRecordingVoiceNote {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDChatAction for ChatActionRecordingVoiceNote {}
 
 impl ChatActionRecordingVoiceNote {
@@ -478,22 +691,49 @@ impl ChatActionRecordingVoiceNote {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatActionRecordingVoiceNoteBuilder {
-        let mut inner = ChatActionRecordingVoiceNote::default();
-        inner.td_name = "chatActionRecordingVoiceNote".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatActionRecordingVoiceNoteBuilder { inner }
+        RTDChatActionRecordingVoiceNoteBuilder {
+            inner: ChatActionRecordingVoiceNote::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatActionRecordingVoiceNoteBuilder {
+        RTDChatActionRecordingVoiceNoteBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatActionRecordingVoiceNoteBuilder {
+        RTDChatActionRecordingVoiceNoteBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDChatActionRecordingVoiceNoteBuilder {
     inner: ChatActionRecordingVoiceNote,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatActionRecordingVoiceNoteBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatActionRecordingVoiceNote {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatActionRecordingVoiceNote> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatActionRecordingVoiceNote",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<ChatActionRecordingVoiceNote> for ChatActionRecordingVoiceNote {
@@ -510,12 +750,13 @@ impl AsRef<ChatActionRecordingVoiceNote> for RTDChatActionRecordingVoiceNoteBuil
 
 /// The user has started to play a game
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatActionStartPlayingGame")]
 pub struct ChatActionStartPlayingGame {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -533,6 +774,18 @@ impl RObject for ChatActionStartPlayingGame {
     }
 }
 
+impl PartialEq for ChatActionStartPlayingGame {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ChatActionStartPlayingGame {}
+
+impl std::hash::Hash for ChatActionStartPlayingGame {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDChatAction for ChatActionStartPlayingGame {}
 
 impl ChatActionStartPlayingGame {
@@ -540,22 +793,49 @@ impl ChatActionStartPlayingGame {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatActionStartPlayingGameBuilder {
-        let mut inner = ChatActionStartPlayingGame::default();
-        inner.td_name = "chatActionStartPlayingGame".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatActionStartPlayingGameBuilder { inner }
+        RTDChatActionStartPlayingGameBuilder {
+            inner: ChatActionStartPlayingGame::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatActionStartPlayingGameBuilder {
+        RTDChatActionStartPlayingGameBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatActionStartPlayingGameBuilder {
+        RTDChatActionStartPlayingGameBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDChatActionStartPlayingGameBuilder {
     inner: ChatActionStartPlayingGame,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatActionStartPlayingGameBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatActionStartPlayingGame {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatActionStartPlayingGame> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatActionStartPlayingGame",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<ChatActionStartPlayingGame> for ChatActionStartPlayingGame {
@@ -572,12 +852,13 @@ impl AsRef<ChatActionStartPlayingGame> for RTDChatActionStartPlayingGameBuilder
 
 /// The user is typing a message
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatActionTyping")]
 pub struct ChatActionTyping {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -595,6 +876,18 @@ impl RObject for ChatActionTyping {
     }
 }
 
+impl PartialEq for ChatActionTyping {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ChatActionTyping {}
+
+impl std::hash::Hash for ChatActionTyping {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDChatAction for ChatActionTyping {}
 
 impl ChatActionTyping {
@@ -602,22 +895,49 @@ impl ChatActionTyping {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatActionTypingBuilder {
-        let mut inner = ChatActionTyping::default();
-        inner.td_name = "chatActionTyping".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatActionTypingBuilder { inner }
+        RTDChatActionTypingBuilder {
+            inner: ChatActionTyping::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatActionTypingBuilder {
+        RTDChatActionTypingBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatActionTypingBuilder {
+        RTDChatActionTypingBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDChatActionTypingBuilder {
     inner: ChatActionTyping,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatActionTypingBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatActionTyping {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatActionTyping> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatActionTyping",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<ChatActionTyping> for ChatActionTyping {
@@ -634,15 +954,16 @@ impl AsRef<ChatActionTyping> for RTDChatActionTypingBuilder {
 
 /// The user is uploading a document
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatActionUploadingDocument")]
 pub struct ChatActionUploadingDocument {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Upload progress, as a percentage
-    progress: i64,
+    progress: i32,
 }
 
 impl RObject for ChatActionUploadingDocument {
@@ -659,6 +980,20 @@ impl RObject for ChatActionUploadingDocument {
     }
 }
 
+impl PartialEq for ChatActionUploadingDocument {
+    fn eq(&self, other: &Self) -> bool {
+        self.progress == other.progress
+    }
+}
+
+impl Eq for ChatActionUploadingDocument {}
+
+impl std::hash::Hash for ChatActionUploadingDocument {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.progress, state);
+    }
+}
+
 impl TDChatAction for ChatActionUploadingDocument {}
 
 impl ChatActionUploadingDocument {
@@ -666,29 +1001,62 @@ impl ChatActionUploadingDocument {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatActionUploadingDocumentBuilder {
-        let mut inner = ChatActionUploadingDocument::default();
-        inner.td_name = "chatActionUploadingDocument".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatActionUploadingDocumentBuilder { inner }
+        RTDChatActionUploadingDocumentBuilder {
+            inner: ChatActionUploadingDocument::default(),
+            missing: vec!["progress"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatActionUploadingDocumentBuilder {
+        RTDChatActionUploadingDocumentBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatActionUploadingDocumentBuilder {
+        RTDChatActionUploadingDocumentBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn progress(&self) -> i64 {
+    pub fn progress(&self) -> i32 {
         self.progress
     }
+
+    pub fn with_progress(mut self, progress: i32) -> Self {
+        self.progress = progress;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDChatActionUploadingDocumentBuilder {
     inner: ChatActionUploadingDocument,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatActionUploadingDocumentBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatActionUploadingDocument {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatActionUploadingDocument> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatActionUploadingDocument",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn progress(&mut self, progress: i64) -> &mut Self {
+    pub fn progress(&mut self, progress: i32) -> &mut Self {
         self.inner.progress = progress;
+        self.missing.retain(|f| *f != "progress");
         self
     }
 }
@@ -707,15 +1075,16 @@ impl AsRef<ChatActionUploadingDocument> for RTDChatActionUploadingDocumentBuilde
 
 /// The user is uploading a photo
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatActionUploadingPhoto")]
 pub struct ChatActionUploadingPhoto {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Upload progress, as a percentage
-    progress: i64,
+    progress: i32,
 }
 
 impl RObject for ChatActionUploadingPhoto {
@@ -732,6 +1101,20 @@ impl RObject for ChatActionUploadingPhoto {
     }
 }
 
+impl PartialEq for ChatActionUploadingPhoto {
+    fn eq(&self, other: &Self) -> bool {
+        self.progress == other.progress
+    }
+}
+
+impl Eq for ChatActionUploadingPhoto {}
+
+impl std::hash::Hash for ChatActionUploadingPhoto {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.progress, state);
+    }
+}
+
 impl TDChatAction for ChatActionUploadingPhoto {}
 
 impl ChatActionUploadingPhoto {
@@ -739,29 +1122,62 @@ impl ChatActionUploadingPhoto {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatActionUploadingPhotoBuilder {
-        let mut inner = ChatActionUploadingPhoto::default();
-        inner.td_name = "chatActionUploadingPhoto".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatActionUploadingPhotoBuilder { inner }
+        RTDChatActionUploadingPhotoBuilder {
+            inner: ChatActionUploadingPhoto::default(),
+            missing: vec!["progress"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatActionUploadingPhotoBuilder {
+        RTDChatActionUploadingPhotoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatActionUploadingPhotoBuilder {
+        RTDChatActionUploadingPhotoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn progress(&self) -> i64 {
+    pub fn progress(&self) -> i32 {
         self.progress
     }
+
+    pub fn with_progress(mut self, progress: i32) -> Self {
+        self.progress = progress;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDChatActionUploadingPhotoBuilder {
     inner: ChatActionUploadingPhoto,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatActionUploadingPhotoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatActionUploadingPhoto {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatActionUploadingPhoto> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatActionUploadingPhoto",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn progress(&mut self, progress: i64) -> &mut Self {
+    pub fn progress(&mut self, progress: i32) -> &mut Self {
         self.inner.progress = progress;
+        self.missing.retain(|f| *f != "progress");
         self
     }
 }
@@ -780,15 +1196,16 @@ impl AsRef<ChatActionUploadingPhoto> for RTDChatActionUploadingPhotoBuilder {
 
 /// The user is uploading a video
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatActionUploadingVideo")]
 pub struct ChatActionUploadingVideo {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Upload progress, as a percentage
-    progress: i64,
+    progress: i32,
 }
 
 impl RObject for ChatActionUploadingVideo {
@@ -805,6 +1222,20 @@ impl RObject for ChatActionUploadingVideo {
     }
 }
 
+impl PartialEq for ChatActionUploadingVideo {
+    fn eq(&self, other: &Self) -> bool {
+        self.progress == other.progress
+    }
+}
+
+impl Eq for ChatActionUploadingVideo {}
+
+impl std::hash::Hash for ChatActionUploadingVideo {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.progress, state);
+    }
+}
+
 impl TDChatAction for ChatActionUploadingVideo {}
 
 impl ChatActionUploadingVideo {
@@ -812,29 +1243,62 @@ impl ChatActionUploadingVideo {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatActionUploadingVideoBuilder {
-        let mut inner = ChatActionUploadingVideo::default();
-        inner.td_name = "chatActionUploadingVideo".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatActionUploadingVideoBuilder { inner }
+        RTDChatActionUploadingVideoBuilder {
+            inner: ChatActionUploadingVideo::default(),
+            missing: vec!["progress"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatActionUploadingVideoBuilder {
+        RTDChatActionUploadingVideoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatActionUploadingVideoBuilder {
+        RTDChatActionUploadingVideoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn progress(&self) -> i64 {
+    pub fn progress(&self) -> i32 {
         self.progress
     }
+
+    pub fn with_progress(mut self, progress: i32) -> Self {
+        self.progress = progress;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDChatActionUploadingVideoBuilder {
     inner: ChatActionUploadingVideo,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatActionUploadingVideoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatActionUploadingVideo {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatActionUploadingVideo> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatActionUploadingVideo",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn progress(&mut self, progress: i64) -> &mut Self {
+    pub fn progress(&mut self, progress: i32) -> &mut Self {
         self.inner.progress = progress;
+        self.missing.retain(|f| *f != "progress");
         self
     }
 }
@@ -853,15 +1317,16 @@ impl AsRef<ChatActionUploadingVideo> for RTDChatActionUploadingVideoBuilder {
 
 /// The user is uploading a video note
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatActionUploadingVideoNote")]
 pub struct ChatActionUploadingVideoNote {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Upload progress, as a percentage
-    progress: i64,
+    progress: i32,
 }
 
 impl RObject for ChatActionUploadingVideoNote {
@@ -878,6 +1343,20 @@ impl RObject for ChatActionUploadingVideoNote {
     }
 }
 
+impl PartialEq for ChatActionUploadingVideoNote {
+    fn eq(&self, other: &Self) -> bool {
+        self.progress == other.progress
+    }
+}
+
+impl Eq for ChatActionUploadingVideoNote {}
+
+impl std::hash::Hash for ChatActionUploadingVideoNote {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.progress, state);
+    }
+}
+
 impl TDChatAction for ChatActionUploadingVideoNote {}
 
 impl ChatActionUploadingVideoNote {
@@ -885,29 +1364,62 @@ impl ChatActionUploadingVideoNote {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatActionUploadingVideoNoteBuilder {
-        let mut inner = ChatActionUploadingVideoNote::default();
-        inner.td_name = "chatActionUploadingVideoNote".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatActionUploadingVideoNoteBuilder { inner }
+        RTDChatActionUploadingVideoNoteBuilder {
+            inner: ChatActionUploadingVideoNote::default(),
+            missing: vec!["progress"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatActionUploadingVideoNoteBuilder {
+        RTDChatActionUploadingVideoNoteBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatActionUploadingVideoNoteBuilder {
+        RTDChatActionUploadingVideoNoteBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn progress(&self) -> i64 {
+    pub fn progress(&self) -> i32 {
         self.progress
     }
+
+    pub fn with_progress(mut self, progress: i32) -> Self {
+        self.progress = progress;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDChatActionUploadingVideoNoteBuilder {
     inner: ChatActionUploadingVideoNote,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatActionUploadingVideoNoteBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatActionUploadingVideoNote {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatActionUploadingVideoNote> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatActionUploadingVideoNote",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn progress(&mut self, progress: i64) -> &mut Self {
+    pub fn progress(&mut self, progress: i32) -> &mut Self {
         self.inner.progress = progress;
+        self.missing.retain(|f| *f != "progress");
         self
     }
 }
@@ -926,15 +1438,16 @@ impl AsRef<ChatActionUploadingVideoNote> for RTDChatActionUploadingVideoNoteBuil
 
 /// The user is uploading a voice note
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatActionUploadingVoiceNote")]
 pub struct ChatActionUploadingVoiceNote {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Upload progress, as a percentage
-    progress: i64,
+    progress: i32,
 }
 
 impl RObject for ChatActionUploadingVoiceNote {
@@ -951,6 +1464,20 @@ impl RObject for ChatActionUploadingVoiceNote {
     }
 }
 
+impl PartialEq for ChatActionUploadingVoiceNote {
+    fn eq(&self, other: &Self) -> bool {
+        self.progress == other.progress
+    }
+}
+
+impl Eq for ChatActionUploadingVoiceNote {}
+
+impl std::hash::Hash for ChatActionUploadingVoiceNote {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.progress, state);
+    }
+}
+
 impl TDChatAction for ChatActionUploadingVoiceNote {}
 
 impl ChatActionUploadingVoiceNote {
@@ -958,29 +1485,62 @@ impl ChatActionUploadingVoiceNote {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatActionUploadingVoiceNoteBuilder {
-        let mut inner = ChatActionUploadingVoiceNote::default();
-        inner.td_name = "chatActionUploadingVoiceNote".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatActionUploadingVoiceNoteBuilder { inner }
+        RTDChatActionUploadingVoiceNoteBuilder {
+            inner: ChatActionUploadingVoiceNote::default(),
+            missing: vec!["progress"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatActionUploadingVoiceNoteBuilder {
+        RTDChatActionUploadingVoiceNoteBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatActionUploadingVoiceNoteBuilder {
+        RTDChatActionUploadingVoiceNoteBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn progress(&self) -> i64 {
+    pub fn progress(&self) -> i32 {
         self.progress
     }
+
+    pub fn with_progress(mut self, progress: i32) -> Self {
+        self.progress = progress;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDChatActionUploadingVoiceNoteBuilder {
     inner: ChatActionUploadingVoiceNote,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatActionUploadingVoiceNoteBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatActionUploadingVoiceNote {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatActionUploadingVoiceNote> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatActionUploadingVoiceNote",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn progress(&mut self, progress: i64) -> &mut Self {
+    pub fn progress(&mut self, progress: i32) -> &mut Self {
         self.inner.progress = progress;
+        self.missing.retain(|f| *f != "progress");
         self
     }
 }
@@ -996,3 +1556,58 @@ impl AsRef<ChatActionUploadingVoiceNote> for RTDChatActionUploadingVoiceNoteBuil
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(ChatAction::Cancel(ChatActionCancel::builder().build()));
+        assert_round_trip(ChatAction::ChoosingContact(
+            ChatActionChoosingContact::builder().build(),
+        ));
+        assert_round_trip(ChatAction::ChoosingLocation(
+            ChatActionChoosingLocation::builder().build(),
+        ));
+        assert_round_trip(ChatAction::RecordingVideo(
+            ChatActionRecordingVideo::builder().build(),
+        ));
+        assert_round_trip(ChatAction::RecordingVideoNote(
+            ChatActionRecordingVideoNote::builder().build(),
+        ));
+        assert_round_trip(ChatAction::RecordingVoiceNote(
+            ChatActionRecordingVoiceNote::builder().build(),
+        ));
+        assert_round_trip(ChatAction::StartPlayingGame(
+            ChatActionStartPlayingGame::builder().build(),
+        ));
+        assert_round_trip(ChatAction::Typing(ChatActionTyping::builder().build()));
+        assert_round_trip(ChatAction::UploadingDocument(
+            ChatActionUploadingDocument::builder()
+                .progress(i32::MAX)
+                .build(),
+        ));
+        assert_round_trip(ChatAction::UploadingPhoto(
+            ChatActionUploadingPhoto::builder()
+                .progress(i32::MAX)
+                .build(),
+        ));
+        assert_round_trip(ChatAction::UploadingVideo(
+            ChatActionUploadingVideo::builder()
+                .progress(i32::MAX)
+                .build(),
+        ));
+        assert_round_trip(ChatAction::UploadingVideoNote(
+            ChatActionUploadingVideoNote::builder()
+                .progress(i32::MAX)
+                .build(),
+        ));
+        assert_round_trip(ChatAction::UploadingVoiceNote(
+            ChatActionUploadingVoiceNote::builder()
+                .progress(i32::MAX)
+                .build(),
+        ));
+    }
+}
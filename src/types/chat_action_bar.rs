@@ -1,52 +1,52 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Describes actions which should be possible to do through a chat action bar
 pub trait TDChatActionBar: Debug + RObject {}
 
 /// Describes actions which should be possible to do through a chat action bar
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum ChatActionBar {
-    #[doc(hidden)]
-    _Default(()),
     /// The chat is a private or secret chat and the other user can be added to the contact list using the method addContact
+    #[serde(rename = "chatActionBarAddContact")]
     AddContact(ChatActionBarAddContact),
     /// The chat is a private or secret chat, which can be reported using the method reportChat, or the other user can be added to the contact list using the method addContact, or the other user can be blocked using the method blockUser
+    #[serde(rename = "chatActionBarReportAddBlock")]
     ReportAddBlock(ChatActionBarReportAddBlock),
     /// The chat can be reported as spam using the method reportChat with the reason chatReportReasonSpam
+    #[serde(rename = "chatActionBarReportSpam")]
     ReportSpam(ChatActionBarReportSpam),
     /// The chat is a location-based supergroup, which can be reported as having unrelated location using the method reportChat with the reason chatReportReasonUnrelatedLocation
+    #[serde(rename = "chatActionBarReportUnrelatedLocation")]
     ReportUnrelatedLocation(ChatActionBarReportUnrelatedLocation),
     /// The chat is a private or secret chat with a mutual contact and the user's phone number can be shared with the other user using the method sharePhoneNumber
+    #[serde(rename = "chatActionBarSharePhoneNumber")]
     SharePhoneNumber(ChatActionBarSharePhoneNumber),
 }
 
+/// Default value is default of [ChatActionBarAddContact], builders still require the field to be set
 impl Default for ChatActionBar {
     fn default() -> Self {
-        ChatActionBar::_Default(())
+        ChatActionBar::AddContact(ChatActionBarAddContact::default())
     }
 }
 
-impl<'de> Deserialize<'de> for ChatActionBar {
-    fn deserialize<D>(deserializer: D) -> Result<ChatActionBar, D::Error>
+impl Serialize for ChatActionBar {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          ChatActionBar,
-          (chatActionBarAddContact, AddContact);
-          (chatActionBarReportAddBlock, ReportAddBlock);
-          (chatActionBarReportSpam, ReportSpam);
-          (chatActionBarReportUnrelatedLocation, ReportUnrelatedLocation);
-          (chatActionBarSharePhoneNumber, SharePhoneNumber);
-
-        )(deserializer)
+        match self {
+            ChatActionBar::AddContact(t) => t.serialize(serializer),
+            ChatActionBar::ReportAddBlock(t) => t.serialize(serializer),
+            ChatActionBar::ReportSpam(t) => t.serialize(serializer),
+            ChatActionBar::ReportUnrelatedLocation(t) => t.serialize(serializer),
+            ChatActionBar::SharePhoneNumber(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -59,8 +59,6 @@ impl RObject for ChatActionBar {
             ChatActionBar::ReportSpam(t) => t.td_name(),
             ChatActionBar::ReportUnrelatedLocation(t) => t.td_name(),
             ChatActionBar::SharePhoneNumber(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -71,8 +69,6 @@ impl RObject for ChatActionBar {
             ChatActionBar::ReportSpam(t) => t.extra(),
             ChatActionBar::ReportUnrelatedLocation(t) => t.extra(),
             ChatActionBar::SharePhoneNumber(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -84,10 +80,6 @@ impl ChatActionBar {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, ChatActionBar::_Default(_))
-    }
 }
 
 impl AsRef<ChatActionBar> for ChatActionBar {
@@ -98,12 +90,13 @@ impl AsRef<ChatActionBar> for ChatActionBar {
 
 /// The chat is a private or secret chat and the other user can be added to the contact list using the method addContact
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatActionBarAddContact")]
 pub struct ChatActionBarAddContact {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -121,6 +114,18 @@ impl RObject for ChatActionBarAddContact {
     }
 }
 
+impl PartialEq for ChatActionBarAddContact {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ChatActionBarAddContact {}
+
+impl std::hash::Hash for ChatActionBarAddContact {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDChatActionBar for ChatActionBarAddContact {}
 
 impl ChatActionBarAddContact {
@@ -128,22 +133,49 @@ impl ChatActionBarAddContact {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatActionBarAddContactBuilder {
-        let mut inner = ChatActionBarAddContact::default();
-        inner.td_name = "chatActionBarAddContact".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatActionBarAddContactBuilder { inner }
+        RTDChatActionBarAddContactBuilder {
+            inner: ChatActionBarAddContact::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatActionBarAddContactBuilder {
+        RTDChatActionBarAddContactBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatActionBarAddContactBuilder {
+        RTDChatActionBarAddContactBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDChatActionBarAddContactBuilder {
     inner: ChatActionBarAddContact,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatActionBarAddContactBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatActionBarAddContact {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatActionBarAddContact> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatActionBarAddContact",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<ChatActionBarAddContact> for ChatActionBarAddContact {
@@ -160,12 +192,13 @@ impl AsRef<ChatActionBarAddContact> for RTDChatActionBarAddContactBuilder {
 
 /// The chat is a private or secret chat, which can be reported using the method reportChat, or the other user can be added to the contact list using the method addContact, or the other user can be blocked using the method blockUser
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatActionBarReportAddBlock")]
 pub struct ChatActionBarReportAddBlock {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -183,6 +216,18 @@ impl RObject for ChatActionBarReportAddBlock {
     }
 }
 
+impl PartialEq for ChatActionBarReportAddBlock {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ChatActionBarReportAddBlock {}
+
+impl std::hash::Hash for ChatActionBarReportAddBlock {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDChatActionBar for ChatActionBarReportAddBlock {}
 
 impl ChatActionBarReportAddBlock {
@@ -190,22 +235,49 @@ impl ChatActionBarReportAddBlock {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatActionBarReportAddBlockBuilder {
-        let mut inner = ChatActionBarReportAddBlock::default();
-        inner.td_name = "chatActionBarReportAddBlock".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatActionBarReportAddBlockBuilder { inner }
+        RTDChatActionBarReportAddBlockBuilder {
+            inner: ChatActionBarReportAddBlock::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatActionBarReportAddBlockBuilder {
+        RTDChatActionBarReportAddBlockBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatActionBarReportAddBlockBuilder {
+        RTDChatActionBarReportAddBlockBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDChatActionBarReportAddBlockBuilder {
     inner: ChatActionBarReportAddBlock,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatActionBarReportAddBlockBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatActionBarReportAddBlock {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatActionBarReportAddBlock> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatActionBarReportAddBlock",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<ChatActionBarReportAddBlock> for ChatActionBarReportAddBlock {
@@ -222,12 +294,13 @@ impl AsRef<ChatActionBarReportAddBlock> for RTDChatActionBarReportAddBlockBuilde
 
 /// The chat can be reported as spam using the method reportChat with the reason chatReportReasonSpam
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatActionBarReportSpam")]
 pub struct ChatActionBarReportSpam {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -245,6 +318,18 @@ impl RObject for ChatActionBarReportSpam {
     }
 }
 
+impl PartialEq for ChatActionBarReportSpam {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ChatActionBarReportSpam {}
+
+impl std::hash::Hash for ChatActionBarReportSpam {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDChatActionBar for ChatActionBarReportSpam {}
 
 impl ChatActionBarReportSpam {
@@ -252,22 +337,49 @@ impl ChatActionBarReportSpam {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatActionBarReportSpamBuilder {
-        let mut inner = ChatActionBarReportSpam::default();
-        inner.td_name = "chatActionBarReportSpam".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatActionBarReportSpamBuilder { inner }
+        RTDChatActionBarReportSpamBuilder {
+            inner: ChatActionBarReportSpam::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatActionBarReportSpamBuilder {
+        RTDChatActionBarReportSpamBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatActionBarReportSpamBuilder {
+        RTDChatActionBarReportSpamBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDChatActionBarReportSpamBuilder {
     inner: ChatActionBarReportSpam,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatActionBarReportSpamBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatActionBarReportSpam {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatActionBarReportSpam> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatActionBarReportSpam",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<ChatActionBarReportSpam> for ChatActionBarReportSpam {
@@ -284,12 +396,13 @@ impl AsRef<ChatActionBarReportSpam> for RTDChatActionBarReportSpamBuilder {
 
 /// The chat is a location-based supergroup, which can be reported as having unrelated location using the method reportChat with the reason chatReportReasonUnrelatedLocation
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatActionBarReportUnrelatedLocation")]
 pub struct ChatActionBarReportUnrelatedLocation {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -307,6 +420,18 @@ impl RObject for ChatActionBarReportUnrelatedLocation {
     }
 }
 
+impl PartialEq for ChatActionBarReportUnrelatedLocation {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ChatActionBarReportUnrelatedLocation {}
+
+impl std::hash::Hash for ChatActionBarReportUnrelatedLocation {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDChatActionBar for ChatActionBarReportUnrelatedLocation {}
 
 impl ChatActionBarReportUnrelatedLocation {
@@ -314,22 +439,49 @@ impl ChatActionBarReportUnrelatedLocation {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatActionBarReportUnrelatedLocationBuilder {
-        let mut inner = ChatActionBarReportUnrelatedLocation::default();
-        inner.td_name = "chatActionBarReportUnrelatedLocation".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatActionBarReportUnrelatedLocationBuilder { inner }
+        RTDChatActionBarReportUnrelatedLocationBuilder {
+            inner: ChatActionBarReportUnrelatedLocation::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatActionBarReportUnrelatedLocationBuilder {
+        RTDChatActionBarReportUnrelatedLocationBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatActionBarReportUnrelatedLocationBuilder {
+        RTDChatActionBarReportUnrelatedLocationBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDChatActionBarReportUnrelatedLocationBuilder {
     inner: ChatActionBarReportUnrelatedLocation,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatActionBarReportUnrelatedLocationBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatActionBarReportUnrelatedLocation {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatActionBarReportUnrelatedLocation> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatActionBarReportUnrelatedLocation",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<ChatActionBarReportUnrelatedLocation> for ChatActionBarReportUnrelatedLocation {
@@ -348,12 +500,13 @@ impl AsRef<ChatActionBarReportUnrelatedLocation>
 
 /// The chat is a private or secret chat with a mutual contact and the user's phone number can be shared with the other user using the method sharePhoneNumber
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatActionBarSharePhoneNumber")]
 pub struct ChatActionBarSharePhoneNumber {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -371,6 +524,18 @@ impl RObject for ChatActionBarSharePhoneNumber {
     }
 }
 
+impl PartialEq for ChatActionBarSharePhoneNumber {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ChatActionBarSharePhoneNumber {}
+
+impl std::hash::Hash for ChatActionBarSharePhoneNumber {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDChatActionBar for ChatActionBarSharePhoneNumber {}
 
 impl ChatActionBarSharePhoneNumber {
@@ -378,22 +543,49 @@ impl ChatActionBarSharePhoneNumber {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatActionBarSharePhoneNumberBuilder {
-        let mut inner = ChatActionBarSharePhoneNumber::default();
-        inner.td_name = "chatActionBarSharePhoneNumber".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatActionBarSharePhoneNumberBuilder { inner }
+        RTDChatActionBarSharePhoneNumberBuilder {
+            inner: ChatActionBarSharePhoneNumber::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatActionBarSharePhoneNumberBuilder {
+        RTDChatActionBarSharePhoneNumberBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatActionBarSharePhoneNumberBuilder {
+        RTDChatActionBarSharePhoneNumberBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDChatActionBarSharePhoneNumberBuilder {
     inner: ChatActionBarSharePhoneNumber,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatActionBarSharePhoneNumberBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatActionBarSharePhoneNumber {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatActionBarSharePhoneNumber> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatActionBarSharePhoneNumber",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<ChatActionBarSharePhoneNumber> for ChatActionBarSharePhoneNumber {
@@ -407,3 +599,28 @@ impl AsRef<ChatActionBarSharePhoneNumber> for RTDChatActionBarSharePhoneNumberBu
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(ChatActionBar::AddContact(
+            ChatActionBarAddContact::builder().build(),
+        ));
+        assert_round_trip(ChatActionBar::ReportAddBlock(
+            ChatActionBarReportAddBlock::builder().build(),
+        ));
+        assert_round_trip(ChatActionBar::ReportSpam(
+            ChatActionBarReportSpam::builder().build(),
+        ));
+        assert_round_trip(ChatActionBar::ReportUnrelatedLocation(
+            ChatActionBarReportUnrelatedLocation::builder().build(),
+        ));
+        assert_round_trip(ChatActionBar::SharePhoneNumber(
+            ChatActionBarSharePhoneNumber::builder().build(),
+        ));
+    }
+}
@@ -1,18 +1,18 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains information about a chat administrator
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatAdministrator")]
 pub struct ChatAdministrator {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// User identifier of the administrator
-    user_id: i64,
+    user_id: UserId,
     /// Custom title of the administrator
     custom_title: String,
     /// True, if the user is the owner of the chat
@@ -33,18 +33,50 @@ impl RObject for ChatAdministrator {
     }
 }
 
+impl PartialEq for ChatAdministrator {
+    fn eq(&self, other: &Self) -> bool {
+        self.user_id == other.user_id
+            && self.custom_title == other.custom_title
+            && self.is_owner == other.is_owner
+    }
+}
+
+impl Eq for ChatAdministrator {}
+
+impl std::hash::Hash for ChatAdministrator {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.user_id, state);
+        std::hash::Hash::hash(&self.custom_title, state);
+        std::hash::Hash::hash(&self.is_owner, state);
+    }
+}
+
 impl ChatAdministrator {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatAdministratorBuilder {
-        let mut inner = ChatAdministrator::default();
-        inner.td_name = "chatAdministrator".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatAdministratorBuilder { inner }
+        RTDChatAdministratorBuilder {
+            inner: ChatAdministrator::default(),
+            missing: vec!["user_id", "custom_title"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatAdministratorBuilder {
+        RTDChatAdministratorBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatAdministratorBuilder {
+        RTDChatAdministratorBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn user_id(&self) -> i64 {
+    pub fn user_id(&self) -> UserId {
         self.user_id
     }
 
@@ -55,25 +87,59 @@ impl ChatAdministrator {
     pub fn is_owner(&self) -> bool {
         self.is_owner
     }
+
+    pub fn into_custom_title(self) -> String {
+        self.custom_title
+    }
+
+    pub fn with_user_id<T: Into<UserId>>(mut self, user_id: T) -> Self {
+        self.user_id = user_id.into();
+        self
+    }
+
+    pub fn with_custom_title<T: AsRef<str>>(mut self, custom_title: T) -> Self {
+        self.custom_title = custom_title.as_ref().to_string();
+        self
+    }
+
+    pub fn with_is_owner(mut self, is_owner: bool) -> Self {
+        self.is_owner = is_owner;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDChatAdministratorBuilder {
     inner: ChatAdministrator,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatAdministratorBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatAdministrator {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatAdministrator> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatAdministrator",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn user_id(&mut self, user_id: i64) -> &mut Self {
-        self.inner.user_id = user_id;
+    pub fn user_id<T: Into<UserId>>(&mut self, user_id: T) -> &mut Self {
+        self.inner.user_id = user_id.into();
+        self.missing.retain(|f| *f != "user_id");
         self
     }
 
     pub fn custom_title<T: AsRef<str>>(&mut self, custom_title: T) -> &mut Self {
         self.inner.custom_title = custom_title.as_ref().to_string();
+        self.missing.retain(|f| *f != "custom_title");
         self
     }
 
@@ -94,3 +160,20 @@ impl AsRef<ChatAdministrator> for RTDChatAdministratorBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            ChatAdministrator::builder()
+                .user_id(i32::MAX)
+                .custom_title("text")
+                .is_owner(true)
+                .build(),
+        );
+    }
+}
@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Represents a list of chat administrators
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatAdministrators")]
 pub struct ChatAdministrators {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// A list of chat administrators
     administrators: Vec<ChatAdministrator>,
@@ -29,34 +29,85 @@ impl RObject for ChatAdministrators {
     }
 }
 
+impl PartialEq for ChatAdministrators {
+    fn eq(&self, other: &Self) -> bool {
+        self.administrators == other.administrators
+    }
+}
+
+impl Eq for ChatAdministrators {}
+
+impl std::hash::Hash for ChatAdministrators {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.administrators, state);
+    }
+}
+
 impl ChatAdministrators {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatAdministratorsBuilder {
-        let mut inner = ChatAdministrators::default();
-        inner.td_name = "chatAdministrators".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatAdministratorsBuilder { inner }
+        RTDChatAdministratorsBuilder {
+            inner: ChatAdministrators::default(),
+            missing: vec!["administrators"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatAdministratorsBuilder {
+        RTDChatAdministratorsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatAdministratorsBuilder {
+        RTDChatAdministratorsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn administrators(&self) -> &Vec<ChatAdministrator> {
         &self.administrators
     }
+
+    pub fn into_administrators(self) -> Vec<ChatAdministrator> {
+        self.administrators
+    }
+
+    pub fn with_administrators(mut self, administrators: Vec<ChatAdministrator>) -> Self {
+        self.administrators = administrators;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDChatAdministratorsBuilder {
     inner: ChatAdministrators,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatAdministratorsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatAdministrators {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatAdministrators> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatAdministrators",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn administrators(&mut self, administrators: Vec<ChatAdministrator>) -> &mut Self {
         self.inner.administrators = administrators;
+        self.missing.retain(|f| *f != "administrators");
         self
     }
 }
@@ -72,3 +123,14 @@ impl AsRef<ChatAdministrators> for RTDChatAdministratorsBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(ChatAdministrators::builder().administrators(vec![]).build());
+    }
+}
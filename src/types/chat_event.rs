@@ -1,22 +1,23 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Represents a chat event
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatEvent")]
 pub struct ChatEvent {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Chat event identifier
-    id: isize,
+    #[serde(with = "super::_common::int64")]
+    id: i64,
     /// Point in time (Unix timestamp) when the event happened
-    date: i64,
+    date: i32,
     /// Identifier of the user who performed the action that triggered the event
-    user_id: i64,
+    user_id: UserId,
     /// Action performed by the user
     action: ChatEventAction,
 }
@@ -35,61 +36,122 @@ impl RObject for ChatEvent {
     }
 }
 
+impl PartialEq for ChatEvent {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.date == other.date
+            && self.user_id == other.user_id
+            && self.action == other.action
+    }
+}
+
 impl ChatEvent {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatEventBuilder {
-        let mut inner = ChatEvent::default();
-        inner.td_name = "chatEvent".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatEventBuilder { inner }
+        RTDChatEventBuilder {
+            inner: ChatEvent::default(),
+            missing: vec!["id", "date", "user_id", "action"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatEventBuilder {
+        RTDChatEventBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatEventBuilder {
+        RTDChatEventBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn id(&self) -> isize {
+    pub fn id(&self) -> i64 {
         self.id
     }
 
-    pub fn date(&self) -> i64 {
+    pub fn date(&self) -> i32 {
         self.date
     }
 
-    pub fn user_id(&self) -> i64 {
+    pub fn user_id(&self) -> UserId {
         self.user_id
     }
 
     pub fn action(&self) -> &ChatEventAction {
         &self.action
     }
+
+    pub fn into_action(self) -> ChatEventAction {
+        self.action
+    }
+
+    pub fn with_id(mut self, id: i64) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn with_date(mut self, date: i32) -> Self {
+        self.date = date;
+        self
+    }
+
+    pub fn with_user_id<T: Into<UserId>>(mut self, user_id: T) -> Self {
+        self.user_id = user_id.into();
+        self
+    }
+
+    pub fn with_action<T: AsRef<ChatEventAction>>(mut self, action: T) -> Self {
+        self.action = action.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDChatEventBuilder {
     inner: ChatEvent,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatEventBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatEvent {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatEvent> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("ChatEvent", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn id(&mut self, id: isize) -> &mut Self {
+    pub fn id(&mut self, id: i64) -> &mut Self {
         self.inner.id = id;
+        self.missing.retain(|f| *f != "id");
         self
     }
 
-    pub fn date(&mut self, date: i64) -> &mut Self {
+    pub fn date(&mut self, date: i32) -> &mut Self {
         self.inner.date = date;
+        self.missing.retain(|f| *f != "date");
         self
     }
 
-    pub fn user_id(&mut self, user_id: i64) -> &mut Self {
-        self.inner.user_id = user_id;
+    pub fn user_id<T: Into<UserId>>(&mut self, user_id: T) -> &mut Self {
+        self.inner.user_id = user_id.into();
+        self.missing.retain(|f| *f != "user_id");
         self
     }
 
     pub fn action<T: AsRef<ChatEventAction>>(&mut self, action: T) -> &mut Self {
         self.inner.action = action.as_ref().clone();
+        self.missing.retain(|f| *f != "action");
         self
     }
 }
@@ -105,3 +167,26 @@ impl AsRef<ChatEvent> for RTDChatEventBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            ChatEvent::builder()
+                .id(i64::MAX)
+                .date(i32::MAX)
+                .user_id(i32::MAX)
+                .action(ChatEventAction::ChatEventDescriptionChanged(
+                    ChatEventDescriptionChanged::builder()
+                        .old_description("text")
+                        .new_description("text")
+                        .build(),
+                ))
+                .build(),
+        );
+    }
+}
@@ -1,103 +1,120 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Represents a chat event
 pub trait TDChatEventAction: Debug + RObject {}
 
 /// Represents a chat event
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "@type")]
 pub enum ChatEventAction {
-    #[doc(hidden)]
-    _Default(()),
     /// The chat description was changed
+    #[serde(rename = "chatEventDescriptionChanged")]
     ChatEventDescriptionChanged(ChatEventDescriptionChanged),
     /// The can_invite_users permission of a supergroup chat was toggled
+    #[serde(rename = "chatEventInvitesToggled")]
     ChatEventInvitesToggled(ChatEventInvitesToggled),
     /// The is_all_history_available setting of a supergroup was toggled
+    #[serde(rename = "chatEventIsAllHistoryAvailableToggled")]
     ChatEventIsAllHistoryAvailableToggled(ChatEventIsAllHistoryAvailableToggled),
     /// The linked chat of a supergroup was changed
+    #[serde(rename = "chatEventLinkedChatChanged")]
     ChatEventLinkedChatChanged(ChatEventLinkedChatChanged),
     /// The supergroup location was changed
+    #[serde(rename = "chatEventLocationChanged")]
     ChatEventLocationChanged(ChatEventLocationChanged),
     /// A new chat member was invited
+    #[serde(rename = "chatEventMemberInvited")]
     ChatEventMemberInvited(ChatEventMemberInvited),
     /// A new member joined the chat
+    #[serde(rename = "chatEventMemberJoined")]
     ChatEventMemberJoined(ChatEventMemberJoined),
     /// A member left the chat
+    #[serde(rename = "chatEventMemberLeft")]
     ChatEventMemberLeft(ChatEventMemberLeft),
     /// A chat member has gained/lost administrator status, or the list of their administrator privileges has changed
+    #[serde(rename = "chatEventMemberPromoted")]
     ChatEventMemberPromoted(ChatEventMemberPromoted),
     /// A chat member was restricted/unrestricted or banned/unbanned, or the list of their restrictions has changed
+    #[serde(rename = "chatEventMemberRestricted")]
     ChatEventMemberRestricted(ChatEventMemberRestricted),
     /// A message was deleted
+    #[serde(rename = "chatEventMessageDeleted")]
     ChatEventMessageDeleted(ChatEventMessageDeleted),
     /// A message was edited
+    #[serde(rename = "chatEventMessageEdited")]
     ChatEventMessageEdited(ChatEventMessageEdited),
     /// A message was pinned
+    #[serde(rename = "chatEventMessagePinned")]
     ChatEventMessagePinned(ChatEventMessagePinned),
     /// A message was unpinned
+    #[serde(rename = "chatEventMessageUnpinned")]
     ChatEventMessageUnpinned(ChatEventMessageUnpinned),
     /// The chat permissions was changed
+    #[serde(rename = "chatEventPermissionsChanged")]
     ChatEventPermissionsChanged(ChatEventPermissionsChanged),
     /// The chat photo was changed
+    #[serde(rename = "chatEventPhotoChanged")]
     ChatEventPhotoChanged(ChatEventPhotoChanged),
     /// A poll in a message was stopped
+    #[serde(rename = "chatEventPollStopped")]
     ChatEventPollStopped(ChatEventPollStopped),
     /// The sign_messages setting of a channel was toggled
+    #[serde(rename = "chatEventSignMessagesToggled")]
     ChatEventSignMessagesToggled(ChatEventSignMessagesToggled),
     /// The slow_mode_delay setting of a supergroup was changed
+    #[serde(rename = "chatEventSlowModeDelayChanged")]
     ChatEventSlowModeDelayChanged(ChatEventSlowModeDelayChanged),
     /// The supergroup sticker set was changed
+    #[serde(rename = "chatEventStickerSetChanged")]
     ChatEventStickerSetChanged(ChatEventStickerSetChanged),
     /// The chat title was changed
+    #[serde(rename = "chatEventTitleChanged")]
     ChatEventTitleChanged(ChatEventTitleChanged),
     /// The chat username was changed
+    #[serde(rename = "chatEventUsernameChanged")]
     ChatEventUsernameChanged(ChatEventUsernameChanged),
 }
 
+/// Default value is default of [ChatEventDescriptionChanged], builders still require the field to be set
 impl Default for ChatEventAction {
     fn default() -> Self {
-        ChatEventAction::_Default(())
+        ChatEventAction::ChatEventDescriptionChanged(ChatEventDescriptionChanged::default())
     }
 }
 
-impl<'de> Deserialize<'de> for ChatEventAction {
-    fn deserialize<D>(deserializer: D) -> Result<ChatEventAction, D::Error>
+impl Serialize for ChatEventAction {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          ChatEventAction,
-          (chatEventDescriptionChanged, ChatEventDescriptionChanged);
-          (chatEventInvitesToggled, ChatEventInvitesToggled);
-          (chatEventIsAllHistoryAvailableToggled, ChatEventIsAllHistoryAvailableToggled);
-          (chatEventLinkedChatChanged, ChatEventLinkedChatChanged);
-          (chatEventLocationChanged, ChatEventLocationChanged);
-          (chatEventMemberInvited, ChatEventMemberInvited);
-          (chatEventMemberJoined, ChatEventMemberJoined);
-          (chatEventMemberLeft, ChatEventMemberLeft);
-          (chatEventMemberPromoted, ChatEventMemberPromoted);
-          (chatEventMemberRestricted, ChatEventMemberRestricted);
-          (chatEventMessageDeleted, ChatEventMessageDeleted);
-          (chatEventMessageEdited, ChatEventMessageEdited);
-          (chatEventMessagePinned, ChatEventMessagePinned);
-          (chatEventMessageUnpinned, ChatEventMessageUnpinned);
-          (chatEventPermissionsChanged, ChatEventPermissionsChanged);
-          (chatEventPhotoChanged, ChatEventPhotoChanged);
-          (chatEventPollStopped, ChatEventPollStopped);
-          (chatEventSignMessagesToggled, ChatEventSignMessagesToggled);
-          (chatEventSlowModeDelayChanged, ChatEventSlowModeDelayChanged);
-          (chatEventStickerSetChanged, ChatEventStickerSetChanged);
-          (chatEventTitleChanged, ChatEventTitleChanged);
-          (chatEventUsernameChanged, ChatEventUsernameChanged);
-
-        )(deserializer)
+        match self {
+            ChatEventAction::ChatEventDescriptionChanged(t) => t.serialize(serializer),
+            ChatEventAction::ChatEventInvitesToggled(t) => t.serialize(serializer),
+            ChatEventAction::ChatEventIsAllHistoryAvailableToggled(t) => t.serialize(serializer),
+            ChatEventAction::ChatEventLinkedChatChanged(t) => t.serialize(serializer),
+            ChatEventAction::ChatEventLocationChanged(t) => t.serialize(serializer),
+            ChatEventAction::ChatEventMemberInvited(t) => t.serialize(serializer),
+            ChatEventAction::ChatEventMemberJoined(t) => t.serialize(serializer),
+            ChatEventAction::ChatEventMemberLeft(t) => t.serialize(serializer),
+            ChatEventAction::ChatEventMemberPromoted(t) => t.serialize(serializer),
+            ChatEventAction::ChatEventMemberRestricted(t) => t.serialize(serializer),
+            ChatEventAction::ChatEventMessageDeleted(t) => t.serialize(serializer),
+            ChatEventAction::ChatEventMessageEdited(t) => t.serialize(serializer),
+            ChatEventAction::ChatEventMessagePinned(t) => t.serialize(serializer),
+            ChatEventAction::ChatEventMessageUnpinned(t) => t.serialize(serializer),
+            ChatEventAction::ChatEventPermissionsChanged(t) => t.serialize(serializer),
+            ChatEventAction::ChatEventPhotoChanged(t) => t.serialize(serializer),
+            ChatEventAction::ChatEventPollStopped(t) => t.serialize(serializer),
+            ChatEventAction::ChatEventSignMessagesToggled(t) => t.serialize(serializer),
+            ChatEventAction::ChatEventSlowModeDelayChanged(t) => t.serialize(serializer),
+            ChatEventAction::ChatEventStickerSetChanged(t) => t.serialize(serializer),
+            ChatEventAction::ChatEventTitleChanged(t) => t.serialize(serializer),
+            ChatEventAction::ChatEventUsernameChanged(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -127,8 +144,6 @@ impl RObject for ChatEventAction {
             ChatEventAction::ChatEventStickerSetChanged(t) => t.td_name(),
             ChatEventAction::ChatEventTitleChanged(t) => t.td_name(),
             ChatEventAction::ChatEventUsernameChanged(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -156,8 +171,6 @@ impl RObject for ChatEventAction {
             ChatEventAction::ChatEventStickerSetChanged(t) => t.extra(),
             ChatEventAction::ChatEventTitleChanged(t) => t.extra(),
             ChatEventAction::ChatEventUsernameChanged(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -169,10 +182,6 @@ impl ChatEventAction {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, ChatEventAction::_Default(_))
-    }
 }
 
 impl AsRef<ChatEventAction> for ChatEventAction {
@@ -183,12 +192,13 @@ impl AsRef<ChatEventAction> for ChatEventAction {
 
 /// The chat description was changed
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatEventDescriptionChanged")]
 pub struct ChatEventDescriptionChanged {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Previous chat description
     old_description: String,
@@ -210,6 +220,22 @@ impl RObject for ChatEventDescriptionChanged {
     }
 }
 
+impl PartialEq for ChatEventDescriptionChanged {
+    fn eq(&self, other: &Self) -> bool {
+        self.old_description == other.old_description
+            && self.new_description == other.new_description
+    }
+}
+
+impl Eq for ChatEventDescriptionChanged {}
+
+impl std::hash::Hash for ChatEventDescriptionChanged {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.old_description, state);
+        std::hash::Hash::hash(&self.new_description, state);
+    }
+}
+
 impl TDChatEventAction for ChatEventDescriptionChanged {}
 
 impl ChatEventDescriptionChanged {
@@ -217,10 +243,24 @@ impl ChatEventDescriptionChanged {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatEventDescriptionChangedBuilder {
-        let mut inner = ChatEventDescriptionChanged::default();
-        inner.td_name = "chatEventDescriptionChanged".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatEventDescriptionChangedBuilder { inner }
+        RTDChatEventDescriptionChangedBuilder {
+            inner: ChatEventDescriptionChanged::default(),
+            missing: vec!["old_description", "new_description"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatEventDescriptionChangedBuilder {
+        RTDChatEventDescriptionChangedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatEventDescriptionChangedBuilder {
+        RTDChatEventDescriptionChangedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn old_description(&self) -> &String {
@@ -230,25 +270,58 @@ impl ChatEventDescriptionChanged {
     pub fn new_description(&self) -> &String {
         &self.new_description
     }
+
+    pub fn into_old_description(self) -> String {
+        self.old_description
+    }
+
+    pub fn into_new_description(self) -> String {
+        self.new_description
+    }
+
+    pub fn with_old_description<T: AsRef<str>>(mut self, old_description: T) -> Self {
+        self.old_description = old_description.as_ref().to_string();
+        self
+    }
+
+    pub fn with_new_description<T: AsRef<str>>(mut self, new_description: T) -> Self {
+        self.new_description = new_description.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDChatEventDescriptionChangedBuilder {
     inner: ChatEventDescriptionChanged,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatEventDescriptionChangedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatEventDescriptionChanged {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatEventDescriptionChanged> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatEventDescriptionChanged",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn old_description<T: AsRef<str>>(&mut self, old_description: T) -> &mut Self {
         self.inner.old_description = old_description.as_ref().to_string();
+        self.missing.retain(|f| *f != "old_description");
         self
     }
 
     pub fn new_description<T: AsRef<str>>(&mut self, new_description: T) -> &mut Self {
         self.inner.new_description = new_description.as_ref().to_string();
+        self.missing.retain(|f| *f != "new_description");
         self
     }
 }
@@ -267,12 +340,13 @@ impl AsRef<ChatEventDescriptionChanged> for RTDChatEventDescriptionChangedBuilde
 
 /// The can_invite_users permission of a supergroup chat was toggled
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatEventInvitesToggled")]
 pub struct ChatEventInvitesToggled {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// New value of can_invite_users permission
     can_invite_users: bool,
@@ -292,6 +366,20 @@ impl RObject for ChatEventInvitesToggled {
     }
 }
 
+impl PartialEq for ChatEventInvitesToggled {
+    fn eq(&self, other: &Self) -> bool {
+        self.can_invite_users == other.can_invite_users
+    }
+}
+
+impl Eq for ChatEventInvitesToggled {}
+
+impl std::hash::Hash for ChatEventInvitesToggled {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.can_invite_users, state);
+    }
+}
+
 impl TDChatEventAction for ChatEventInvitesToggled {}
 
 impl ChatEventInvitesToggled {
@@ -299,26 +387,58 @@ impl ChatEventInvitesToggled {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatEventInvitesToggledBuilder {
-        let mut inner = ChatEventInvitesToggled::default();
-        inner.td_name = "chatEventInvitesToggled".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatEventInvitesToggledBuilder { inner }
+        RTDChatEventInvitesToggledBuilder {
+            inner: ChatEventInvitesToggled::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatEventInvitesToggledBuilder {
+        RTDChatEventInvitesToggledBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatEventInvitesToggledBuilder {
+        RTDChatEventInvitesToggledBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn can_invite_users(&self) -> bool {
         self.can_invite_users
     }
+
+    pub fn with_can_invite_users(mut self, can_invite_users: bool) -> Self {
+        self.can_invite_users = can_invite_users;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDChatEventInvitesToggledBuilder {
     inner: ChatEventInvitesToggled,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatEventInvitesToggledBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatEventInvitesToggled {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatEventInvitesToggled> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatEventInvitesToggled",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn can_invite_users(&mut self, can_invite_users: bool) -> &mut Self {
         self.inner.can_invite_users = can_invite_users;
@@ -340,12 +460,13 @@ impl AsRef<ChatEventInvitesToggled> for RTDChatEventInvitesToggledBuilder {
 
 /// The is_all_history_available setting of a supergroup was toggled
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatEventIsAllHistoryAvailableToggled")]
 pub struct ChatEventIsAllHistoryAvailableToggled {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// New value of is_all_history_available
     is_all_history_available: bool,
@@ -365,6 +486,20 @@ impl RObject for ChatEventIsAllHistoryAvailableToggled {
     }
 }
 
+impl PartialEq for ChatEventIsAllHistoryAvailableToggled {
+    fn eq(&self, other: &Self) -> bool {
+        self.is_all_history_available == other.is_all_history_available
+    }
+}
+
+impl Eq for ChatEventIsAllHistoryAvailableToggled {}
+
+impl std::hash::Hash for ChatEventIsAllHistoryAvailableToggled {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.is_all_history_available, state);
+    }
+}
+
 impl TDChatEventAction for ChatEventIsAllHistoryAvailableToggled {}
 
 impl ChatEventIsAllHistoryAvailableToggled {
@@ -372,26 +507,58 @@ impl ChatEventIsAllHistoryAvailableToggled {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatEventIsAllHistoryAvailableToggledBuilder {
-        let mut inner = ChatEventIsAllHistoryAvailableToggled::default();
-        inner.td_name = "chatEventIsAllHistoryAvailableToggled".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatEventIsAllHistoryAvailableToggledBuilder { inner }
+        RTDChatEventIsAllHistoryAvailableToggledBuilder {
+            inner: ChatEventIsAllHistoryAvailableToggled::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatEventIsAllHistoryAvailableToggledBuilder {
+        RTDChatEventIsAllHistoryAvailableToggledBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatEventIsAllHistoryAvailableToggledBuilder {
+        RTDChatEventIsAllHistoryAvailableToggledBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn is_all_history_available(&self) -> bool {
         self.is_all_history_available
     }
+
+    pub fn with_is_all_history_available(mut self, is_all_history_available: bool) -> Self {
+        self.is_all_history_available = is_all_history_available;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDChatEventIsAllHistoryAvailableToggledBuilder {
     inner: ChatEventIsAllHistoryAvailableToggled,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatEventIsAllHistoryAvailableToggledBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatEventIsAllHistoryAvailableToggled {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatEventIsAllHistoryAvailableToggled> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatEventIsAllHistoryAvailableToggled",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn is_all_history_available(&mut self, is_all_history_available: bool) -> &mut Self {
         self.inner.is_all_history_available = is_all_history_available;
@@ -415,17 +582,18 @@ impl AsRef<ChatEventIsAllHistoryAvailableToggled>
 
 /// The linked chat of a supergroup was changed
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatEventLinkedChatChanged")]
 pub struct ChatEventLinkedChatChanged {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Previous supergroup linked chat identifier
-    old_linked_chat_id: i64,
+    old_linked_chat_id: ChatId,
     /// New supergroup linked chat identifier
-    new_linked_chat_id: i64,
+    new_linked_chat_id: ChatId,
 }
 
 impl RObject for ChatEventLinkedChatChanged {
@@ -442,6 +610,22 @@ impl RObject for ChatEventLinkedChatChanged {
     }
 }
 
+impl PartialEq for ChatEventLinkedChatChanged {
+    fn eq(&self, other: &Self) -> bool {
+        self.old_linked_chat_id == other.old_linked_chat_id
+            && self.new_linked_chat_id == other.new_linked_chat_id
+    }
+}
+
+impl Eq for ChatEventLinkedChatChanged {}
+
+impl std::hash::Hash for ChatEventLinkedChatChanged {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.old_linked_chat_id, state);
+        std::hash::Hash::hash(&self.new_linked_chat_id, state);
+    }
+}
+
 impl TDChatEventAction for ChatEventLinkedChatChanged {}
 
 impl ChatEventLinkedChatChanged {
@@ -449,38 +633,77 @@ impl ChatEventLinkedChatChanged {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatEventLinkedChatChangedBuilder {
-        let mut inner = ChatEventLinkedChatChanged::default();
-        inner.td_name = "chatEventLinkedChatChanged".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatEventLinkedChatChangedBuilder { inner }
+        RTDChatEventLinkedChatChangedBuilder {
+            inner: ChatEventLinkedChatChanged::default(),
+            missing: vec!["old_linked_chat_id", "new_linked_chat_id"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatEventLinkedChatChangedBuilder {
+        RTDChatEventLinkedChatChangedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatEventLinkedChatChangedBuilder {
+        RTDChatEventLinkedChatChangedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn old_linked_chat_id(&self) -> i64 {
+    pub fn old_linked_chat_id(&self) -> ChatId {
         self.old_linked_chat_id
     }
 
-    pub fn new_linked_chat_id(&self) -> i64 {
+    pub fn new_linked_chat_id(&self) -> ChatId {
         self.new_linked_chat_id
     }
+
+    pub fn with_old_linked_chat_id<T: Into<ChatId>>(mut self, old_linked_chat_id: T) -> Self {
+        self.old_linked_chat_id = old_linked_chat_id.into();
+        self
+    }
+
+    pub fn with_new_linked_chat_id<T: Into<ChatId>>(mut self, new_linked_chat_id: T) -> Self {
+        self.new_linked_chat_id = new_linked_chat_id.into();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDChatEventLinkedChatChangedBuilder {
     inner: ChatEventLinkedChatChanged,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatEventLinkedChatChangedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatEventLinkedChatChanged {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatEventLinkedChatChanged> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatEventLinkedChatChanged",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn old_linked_chat_id(&mut self, old_linked_chat_id: i64) -> &mut Self {
-        self.inner.old_linked_chat_id = old_linked_chat_id;
+    pub fn old_linked_chat_id<T: Into<ChatId>>(&mut self, old_linked_chat_id: T) -> &mut Self {
+        self.inner.old_linked_chat_id = old_linked_chat_id.into();
+        self.missing.retain(|f| *f != "old_linked_chat_id");
         self
     }
 
-    pub fn new_linked_chat_id(&mut self, new_linked_chat_id: i64) -> &mut Self {
-        self.inner.new_linked_chat_id = new_linked_chat_id;
+    pub fn new_linked_chat_id<T: Into<ChatId>>(&mut self, new_linked_chat_id: T) -> &mut Self {
+        self.inner.new_linked_chat_id = new_linked_chat_id.into();
+        self.missing.retain(|f| *f != "new_linked_chat_id");
         self
     }
 }
@@ -499,12 +722,13 @@ impl AsRef<ChatEventLinkedChatChanged> for RTDChatEventLinkedChatChangedBuilder
 
 /// The supergroup location was changed
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatEventLocationChanged")]
 pub struct ChatEventLocationChanged {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Previous location; may be null
     old_location: Option<ChatLocation>,
@@ -526,6 +750,12 @@ impl RObject for ChatEventLocationChanged {
     }
 }
 
+impl PartialEq for ChatEventLocationChanged {
+    fn eq(&self, other: &Self) -> bool {
+        self.old_location == other.old_location && self.new_location == other.new_location
+    }
+}
+
 impl TDChatEventAction for ChatEventLocationChanged {}
 
 impl ChatEventLocationChanged {
@@ -533,10 +763,24 @@ impl ChatEventLocationChanged {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatEventLocationChangedBuilder {
-        let mut inner = ChatEventLocationChanged::default();
-        inner.td_name = "chatEventLocationChanged".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatEventLocationChangedBuilder { inner }
+        RTDChatEventLocationChangedBuilder {
+            inner: ChatEventLocationChanged::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatEventLocationChangedBuilder {
+        RTDChatEventLocationChangedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatEventLocationChangedBuilder {
+        RTDChatEventLocationChangedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn old_location(&self) -> &Option<ChatLocation> {
@@ -546,17 +790,48 @@ impl ChatEventLocationChanged {
     pub fn new_location(&self) -> &Option<ChatLocation> {
         &self.new_location
     }
+
+    pub fn into_old_location(self) -> Option<ChatLocation> {
+        self.old_location
+    }
+
+    pub fn into_new_location(self) -> Option<ChatLocation> {
+        self.new_location
+    }
+
+    pub fn with_old_location<T: AsRef<ChatLocation>>(mut self, old_location: T) -> Self {
+        self.old_location = Some(old_location.as_ref().clone());
+        self
+    }
+
+    pub fn with_new_location<T: AsRef<ChatLocation>>(mut self, new_location: T) -> Self {
+        self.new_location = Some(new_location.as_ref().clone());
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDChatEventLocationChangedBuilder {
     inner: ChatEventLocationChanged,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatEventLocationChangedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatEventLocationChanged {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatEventLocationChanged> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatEventLocationChanged",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn old_location<T: AsRef<ChatLocation>>(&mut self, old_location: T) -> &mut Self {
         self.inner.old_location = Some(old_location.as_ref().clone());
@@ -583,15 +858,16 @@ impl AsRef<ChatEventLocationChanged> for RTDChatEventLocationChangedBuilder {
 
 /// A new chat member was invited
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatEventMemberInvited")]
 pub struct ChatEventMemberInvited {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// New member user identifier
-    user_id: i64,
+    user_id: UserId,
     /// New member status
     status: ChatMemberStatus,
 }
@@ -610,6 +886,21 @@ impl RObject for ChatEventMemberInvited {
     }
 }
 
+impl PartialEq for ChatEventMemberInvited {
+    fn eq(&self, other: &Self) -> bool {
+        self.user_id == other.user_id && self.status == other.status
+    }
+}
+
+impl Eq for ChatEventMemberInvited {}
+
+impl std::hash::Hash for ChatEventMemberInvited {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.user_id, state);
+        std::hash::Hash::hash(&self.status, state);
+    }
+}
+
 impl TDChatEventAction for ChatEventMemberInvited {}
 
 impl ChatEventMemberInvited {
@@ -617,38 +908,81 @@ impl ChatEventMemberInvited {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatEventMemberInvitedBuilder {
-        let mut inner = ChatEventMemberInvited::default();
-        inner.td_name = "chatEventMemberInvited".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatEventMemberInvitedBuilder { inner }
+        RTDChatEventMemberInvitedBuilder {
+            inner: ChatEventMemberInvited::default(),
+            missing: vec!["user_id", "status"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatEventMemberInvitedBuilder {
+        RTDChatEventMemberInvitedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatEventMemberInvitedBuilder {
+        RTDChatEventMemberInvitedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn user_id(&self) -> i64 {
+    pub fn user_id(&self) -> UserId {
         self.user_id
     }
 
     pub fn status(&self) -> &ChatMemberStatus {
         &self.status
     }
+
+    pub fn into_status(self) -> ChatMemberStatus {
+        self.status
+    }
+
+    pub fn with_user_id<T: Into<UserId>>(mut self, user_id: T) -> Self {
+        self.user_id = user_id.into();
+        self
+    }
+
+    pub fn with_status<T: AsRef<ChatMemberStatus>>(mut self, status: T) -> Self {
+        self.status = status.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDChatEventMemberInvitedBuilder {
     inner: ChatEventMemberInvited,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatEventMemberInvitedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatEventMemberInvited {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatEventMemberInvited> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatEventMemberInvited",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn user_id(&mut self, user_id: i64) -> &mut Self {
-        self.inner.user_id = user_id;
+    pub fn user_id<T: Into<UserId>>(&mut self, user_id: T) -> &mut Self {
+        self.inner.user_id = user_id.into();
+        self.missing.retain(|f| *f != "user_id");
         self
     }
 
     pub fn status<T: AsRef<ChatMemberStatus>>(&mut self, status: T) -> &mut Self {
         self.inner.status = status.as_ref().clone();
+        self.missing.retain(|f| *f != "status");
         self
     }
 }
@@ -667,12 +1001,13 @@ impl AsRef<ChatEventMemberInvited> for RTDChatEventMemberInvitedBuilder {
 
 /// A new member joined the chat
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatEventMemberJoined")]
 pub struct ChatEventMemberJoined {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -690,6 +1025,18 @@ impl RObject for ChatEventMemberJoined {
     }
 }
 
+impl PartialEq for ChatEventMemberJoined {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ChatEventMemberJoined {}
+
+impl std::hash::Hash for ChatEventMemberJoined {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDChatEventAction for ChatEventMemberJoined {}
 
 impl ChatEventMemberJoined {
@@ -697,22 +1044,49 @@ impl ChatEventMemberJoined {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatEventMemberJoinedBuilder {
-        let mut inner = ChatEventMemberJoined::default();
-        inner.td_name = "chatEventMemberJoined".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatEventMemberJoinedBuilder { inner }
+        RTDChatEventMemberJoinedBuilder {
+            inner: ChatEventMemberJoined::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatEventMemberJoinedBuilder {
+        RTDChatEventMemberJoinedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatEventMemberJoinedBuilder {
+        RTDChatEventMemberJoinedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDChatEventMemberJoinedBuilder {
     inner: ChatEventMemberJoined,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatEventMemberJoinedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatEventMemberJoined {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatEventMemberJoined> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatEventMemberJoined",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<ChatEventMemberJoined> for ChatEventMemberJoined {
@@ -729,12 +1103,13 @@ impl AsRef<ChatEventMemberJoined> for RTDChatEventMemberJoinedBuilder {
 
 /// A member left the chat
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatEventMemberLeft")]
 pub struct ChatEventMemberLeft {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -752,6 +1127,18 @@ impl RObject for ChatEventMemberLeft {
     }
 }
 
+impl PartialEq for ChatEventMemberLeft {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ChatEventMemberLeft {}
+
+impl std::hash::Hash for ChatEventMemberLeft {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDChatEventAction for ChatEventMemberLeft {}
 
 impl ChatEventMemberLeft {
@@ -759,22 +1146,49 @@ impl ChatEventMemberLeft {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatEventMemberLeftBuilder {
-        let mut inner = ChatEventMemberLeft::default();
-        inner.td_name = "chatEventMemberLeft".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatEventMemberLeftBuilder { inner }
+        RTDChatEventMemberLeftBuilder {
+            inner: ChatEventMemberLeft::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatEventMemberLeftBuilder {
+        RTDChatEventMemberLeftBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatEventMemberLeftBuilder {
+        RTDChatEventMemberLeftBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDChatEventMemberLeftBuilder {
     inner: ChatEventMemberLeft,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatEventMemberLeftBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatEventMemberLeft {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatEventMemberLeft> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatEventMemberLeft",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<ChatEventMemberLeft> for ChatEventMemberLeft {
@@ -791,15 +1205,16 @@ impl AsRef<ChatEventMemberLeft> for RTDChatEventMemberLeftBuilder {
 
 /// A chat member has gained/lost administrator status, or the list of their administrator privileges has changed
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatEventMemberPromoted")]
 pub struct ChatEventMemberPromoted {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Chat member user identifier
-    user_id: i64,
+    user_id: UserId,
     /// Previous status of the chat member
     old_status: ChatMemberStatus,
     /// New status of the chat member
@@ -820,6 +1235,24 @@ impl RObject for ChatEventMemberPromoted {
     }
 }
 
+impl PartialEq for ChatEventMemberPromoted {
+    fn eq(&self, other: &Self) -> bool {
+        self.user_id == other.user_id
+            && self.old_status == other.old_status
+            && self.new_status == other.new_status
+    }
+}
+
+impl Eq for ChatEventMemberPromoted {}
+
+impl std::hash::Hash for ChatEventMemberPromoted {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.user_id, state);
+        std::hash::Hash::hash(&self.old_status, state);
+        std::hash::Hash::hash(&self.new_status, state);
+    }
+}
+
 impl TDChatEventAction for ChatEventMemberPromoted {}
 
 impl ChatEventMemberPromoted {
@@ -827,13 +1260,27 @@ impl ChatEventMemberPromoted {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatEventMemberPromotedBuilder {
-        let mut inner = ChatEventMemberPromoted::default();
-        inner.td_name = "chatEventMemberPromoted".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatEventMemberPromotedBuilder { inner }
+        RTDChatEventMemberPromotedBuilder {
+            inner: ChatEventMemberPromoted::default(),
+            missing: vec!["user_id", "old_status", "new_status"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatEventMemberPromotedBuilder {
+        RTDChatEventMemberPromotedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatEventMemberPromotedBuilder {
+        RTDChatEventMemberPromotedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn user_id(&self) -> i64 {
+    pub fn user_id(&self) -> UserId {
         self.user_id
     }
 
@@ -844,30 +1291,69 @@ impl ChatEventMemberPromoted {
     pub fn new_status(&self) -> &ChatMemberStatus {
         &self.new_status
     }
+
+    pub fn into_old_status(self) -> ChatMemberStatus {
+        self.old_status
+    }
+
+    pub fn into_new_status(self) -> ChatMemberStatus {
+        self.new_status
+    }
+
+    pub fn with_user_id<T: Into<UserId>>(mut self, user_id: T) -> Self {
+        self.user_id = user_id.into();
+        self
+    }
+
+    pub fn with_old_status<T: AsRef<ChatMemberStatus>>(mut self, old_status: T) -> Self {
+        self.old_status = old_status.as_ref().clone();
+        self
+    }
+
+    pub fn with_new_status<T: AsRef<ChatMemberStatus>>(mut self, new_status: T) -> Self {
+        self.new_status = new_status.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDChatEventMemberPromotedBuilder {
     inner: ChatEventMemberPromoted,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatEventMemberPromotedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatEventMemberPromoted {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatEventMemberPromoted> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatEventMemberPromoted",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn user_id(&mut self, user_id: i64) -> &mut Self {
-        self.inner.user_id = user_id;
+    pub fn user_id<T: Into<UserId>>(&mut self, user_id: T) -> &mut Self {
+        self.inner.user_id = user_id.into();
+        self.missing.retain(|f| *f != "user_id");
         self
     }
 
     pub fn old_status<T: AsRef<ChatMemberStatus>>(&mut self, old_status: T) -> &mut Self {
         self.inner.old_status = old_status.as_ref().clone();
+        self.missing.retain(|f| *f != "old_status");
         self
     }
 
     pub fn new_status<T: AsRef<ChatMemberStatus>>(&mut self, new_status: T) -> &mut Self {
         self.inner.new_status = new_status.as_ref().clone();
+        self.missing.retain(|f| *f != "new_status");
         self
     }
 }
@@ -886,15 +1372,16 @@ impl AsRef<ChatEventMemberPromoted> for RTDChatEventMemberPromotedBuilder {
 
 /// A chat member was restricted/unrestricted or banned/unbanned, or the list of their restrictions has changed
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatEventMemberRestricted")]
 pub struct ChatEventMemberRestricted {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Chat member user identifier
-    user_id: i64,
+    user_id: UserId,
     /// Previous status of the chat member
     old_status: ChatMemberStatus,
     /// New status of the chat member
@@ -915,6 +1402,24 @@ impl RObject for ChatEventMemberRestricted {
     }
 }
 
+impl PartialEq for ChatEventMemberRestricted {
+    fn eq(&self, other: &Self) -> bool {
+        self.user_id == other.user_id
+            && self.old_status == other.old_status
+            && self.new_status == other.new_status
+    }
+}
+
+impl Eq for ChatEventMemberRestricted {}
+
+impl std::hash::Hash for ChatEventMemberRestricted {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.user_id, state);
+        std::hash::Hash::hash(&self.old_status, state);
+        std::hash::Hash::hash(&self.new_status, state);
+    }
+}
+
 impl TDChatEventAction for ChatEventMemberRestricted {}
 
 impl ChatEventMemberRestricted {
@@ -922,13 +1427,27 @@ impl ChatEventMemberRestricted {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatEventMemberRestrictedBuilder {
-        let mut inner = ChatEventMemberRestricted::default();
-        inner.td_name = "chatEventMemberRestricted".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatEventMemberRestrictedBuilder { inner }
+        RTDChatEventMemberRestrictedBuilder {
+            inner: ChatEventMemberRestricted::default(),
+            missing: vec!["user_id", "old_status", "new_status"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatEventMemberRestrictedBuilder {
+        RTDChatEventMemberRestrictedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatEventMemberRestrictedBuilder {
+        RTDChatEventMemberRestrictedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn user_id(&self) -> i64 {
+    pub fn user_id(&self) -> UserId {
         self.user_id
     }
 
@@ -939,30 +1458,69 @@ impl ChatEventMemberRestricted {
     pub fn new_status(&self) -> &ChatMemberStatus {
         &self.new_status
     }
+
+    pub fn into_old_status(self) -> ChatMemberStatus {
+        self.old_status
+    }
+
+    pub fn into_new_status(self) -> ChatMemberStatus {
+        self.new_status
+    }
+
+    pub fn with_user_id<T: Into<UserId>>(mut self, user_id: T) -> Self {
+        self.user_id = user_id.into();
+        self
+    }
+
+    pub fn with_old_status<T: AsRef<ChatMemberStatus>>(mut self, old_status: T) -> Self {
+        self.old_status = old_status.as_ref().clone();
+        self
+    }
+
+    pub fn with_new_status<T: AsRef<ChatMemberStatus>>(mut self, new_status: T) -> Self {
+        self.new_status = new_status.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDChatEventMemberRestrictedBuilder {
     inner: ChatEventMemberRestricted,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatEventMemberRestrictedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatEventMemberRestricted {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatEventMemberRestricted> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatEventMemberRestricted",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn user_id(&mut self, user_id: i64) -> &mut Self {
-        self.inner.user_id = user_id;
+    pub fn user_id<T: Into<UserId>>(&mut self, user_id: T) -> &mut Self {
+        self.inner.user_id = user_id.into();
+        self.missing.retain(|f| *f != "user_id");
         self
     }
 
     pub fn old_status<T: AsRef<ChatMemberStatus>>(&mut self, old_status: T) -> &mut Self {
         self.inner.old_status = old_status.as_ref().clone();
+        self.missing.retain(|f| *f != "old_status");
         self
     }
 
     pub fn new_status<T: AsRef<ChatMemberStatus>>(&mut self, new_status: T) -> &mut Self {
         self.inner.new_status = new_status.as_ref().clone();
+        self.missing.retain(|f| *f != "new_status");
         self
     }
 }
@@ -981,12 +1539,13 @@ impl AsRef<ChatEventMemberRestricted> for RTDChatEventMemberRestrictedBuilder {
 
 /// A message was deleted
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatEventMessageDeleted")]
 pub struct ChatEventMessageDeleted {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Deleted message
     message: Message,
@@ -1006,6 +1565,12 @@ impl RObject for ChatEventMessageDeleted {
     }
 }
 
+impl PartialEq for ChatEventMessageDeleted {
+    fn eq(&self, other: &Self) -> bool {
+        self.message == other.message
+    }
+}
+
 impl TDChatEventAction for ChatEventMessageDeleted {}
 
 impl ChatEventMessageDeleted {
@@ -1013,29 +1578,66 @@ impl ChatEventMessageDeleted {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatEventMessageDeletedBuilder {
-        let mut inner = ChatEventMessageDeleted::default();
-        inner.td_name = "chatEventMessageDeleted".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatEventMessageDeletedBuilder { inner }
+        RTDChatEventMessageDeletedBuilder {
+            inner: ChatEventMessageDeleted::default(),
+            missing: vec!["message"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatEventMessageDeletedBuilder {
+        RTDChatEventMessageDeletedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatEventMessageDeletedBuilder {
+        RTDChatEventMessageDeletedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn message(&self) -> &Message {
         &self.message
     }
+
+    pub fn into_message(self) -> Message {
+        self.message
+    }
+
+    pub fn with_message<T: AsRef<Message>>(mut self, message: T) -> Self {
+        self.message = message.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDChatEventMessageDeletedBuilder {
     inner: ChatEventMessageDeleted,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatEventMessageDeletedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatEventMessageDeleted {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatEventMessageDeleted> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatEventMessageDeleted",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn message<T: AsRef<Message>>(&mut self, message: T) -> &mut Self {
         self.inner.message = message.as_ref().clone();
+        self.missing.retain(|f| *f != "message");
         self
     }
 }
@@ -1054,12 +1656,13 @@ impl AsRef<ChatEventMessageDeleted> for RTDChatEventMessageDeletedBuilder {
 
 /// A message was edited
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatEventMessageEdited")]
 pub struct ChatEventMessageEdited {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The original message before the edit
     old_message: Message,
@@ -1081,6 +1684,12 @@ impl RObject for ChatEventMessageEdited {
     }
 }
 
+impl PartialEq for ChatEventMessageEdited {
+    fn eq(&self, other: &Self) -> bool {
+        self.old_message == other.old_message && self.new_message == other.new_message
+    }
+}
+
 impl TDChatEventAction for ChatEventMessageEdited {}
 
 impl ChatEventMessageEdited {
@@ -1088,10 +1697,24 @@ impl ChatEventMessageEdited {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatEventMessageEditedBuilder {
-        let mut inner = ChatEventMessageEdited::default();
-        inner.td_name = "chatEventMessageEdited".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatEventMessageEditedBuilder { inner }
+        RTDChatEventMessageEditedBuilder {
+            inner: ChatEventMessageEdited::default(),
+            missing: vec!["old_message", "new_message"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatEventMessageEditedBuilder {
+        RTDChatEventMessageEditedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatEventMessageEditedBuilder {
+        RTDChatEventMessageEditedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn old_message(&self) -> &Message {
@@ -1101,25 +1724,58 @@ impl ChatEventMessageEdited {
     pub fn new_message(&self) -> &Message {
         &self.new_message
     }
+
+    pub fn into_old_message(self) -> Message {
+        self.old_message
+    }
+
+    pub fn into_new_message(self) -> Message {
+        self.new_message
+    }
+
+    pub fn with_old_message<T: AsRef<Message>>(mut self, old_message: T) -> Self {
+        self.old_message = old_message.as_ref().clone();
+        self
+    }
+
+    pub fn with_new_message<T: AsRef<Message>>(mut self, new_message: T) -> Self {
+        self.new_message = new_message.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDChatEventMessageEditedBuilder {
     inner: ChatEventMessageEdited,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatEventMessageEditedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatEventMessageEdited {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatEventMessageEdited> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatEventMessageEdited",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn old_message<T: AsRef<Message>>(&mut self, old_message: T) -> &mut Self {
         self.inner.old_message = old_message.as_ref().clone();
+        self.missing.retain(|f| *f != "old_message");
         self
     }
 
     pub fn new_message<T: AsRef<Message>>(&mut self, new_message: T) -> &mut Self {
         self.inner.new_message = new_message.as_ref().clone();
+        self.missing.retain(|f| *f != "new_message");
         self
     }
 }
@@ -1138,12 +1794,13 @@ impl AsRef<ChatEventMessageEdited> for RTDChatEventMessageEditedBuilder {
 
 /// A message was pinned
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatEventMessagePinned")]
 pub struct ChatEventMessagePinned {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Pinned message
     message: Message,
@@ -1163,6 +1820,12 @@ impl RObject for ChatEventMessagePinned {
     }
 }
 
+impl PartialEq for ChatEventMessagePinned {
+    fn eq(&self, other: &Self) -> bool {
+        self.message == other.message
+    }
+}
+
 impl TDChatEventAction for ChatEventMessagePinned {}
 
 impl ChatEventMessagePinned {
@@ -1170,29 +1833,66 @@ impl ChatEventMessagePinned {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatEventMessagePinnedBuilder {
-        let mut inner = ChatEventMessagePinned::default();
-        inner.td_name = "chatEventMessagePinned".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatEventMessagePinnedBuilder { inner }
+        RTDChatEventMessagePinnedBuilder {
+            inner: ChatEventMessagePinned::default(),
+            missing: vec!["message"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatEventMessagePinnedBuilder {
+        RTDChatEventMessagePinnedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatEventMessagePinnedBuilder {
+        RTDChatEventMessagePinnedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn message(&self) -> &Message {
         &self.message
     }
+
+    pub fn into_message(self) -> Message {
+        self.message
+    }
+
+    pub fn with_message<T: AsRef<Message>>(mut self, message: T) -> Self {
+        self.message = message.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDChatEventMessagePinnedBuilder {
     inner: ChatEventMessagePinned,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatEventMessagePinnedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatEventMessagePinned {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatEventMessagePinned> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatEventMessagePinned",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn message<T: AsRef<Message>>(&mut self, message: T) -> &mut Self {
         self.inner.message = message.as_ref().clone();
+        self.missing.retain(|f| *f != "message");
         self
     }
 }
@@ -1211,12 +1911,13 @@ impl AsRef<ChatEventMessagePinned> for RTDChatEventMessagePinnedBuilder {
 
 /// A message was unpinned
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatEventMessageUnpinned")]
 pub struct ChatEventMessageUnpinned {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -1234,6 +1935,18 @@ impl RObject for ChatEventMessageUnpinned {
     }
 }
 
+impl PartialEq for ChatEventMessageUnpinned {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ChatEventMessageUnpinned {}
+
+impl std::hash::Hash for ChatEventMessageUnpinned {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDChatEventAction for ChatEventMessageUnpinned {}
 
 impl ChatEventMessageUnpinned {
@@ -1241,22 +1954,49 @@ impl ChatEventMessageUnpinned {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatEventMessageUnpinnedBuilder {
-        let mut inner = ChatEventMessageUnpinned::default();
-        inner.td_name = "chatEventMessageUnpinned".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatEventMessageUnpinnedBuilder { inner }
+        RTDChatEventMessageUnpinnedBuilder {
+            inner: ChatEventMessageUnpinned::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatEventMessageUnpinnedBuilder {
+        RTDChatEventMessageUnpinnedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatEventMessageUnpinnedBuilder {
+        RTDChatEventMessageUnpinnedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDChatEventMessageUnpinnedBuilder {
     inner: ChatEventMessageUnpinned,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatEventMessageUnpinnedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatEventMessageUnpinned {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatEventMessageUnpinned> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatEventMessageUnpinned",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<ChatEventMessageUnpinned> for ChatEventMessageUnpinned {
@@ -1273,12 +2013,13 @@ impl AsRef<ChatEventMessageUnpinned> for RTDChatEventMessageUnpinnedBuilder {
 
 /// The chat permissions was changed
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatEventPermissionsChanged")]
 pub struct ChatEventPermissionsChanged {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Previous chat permissions
     old_permissions: ChatPermissions,
@@ -1300,6 +2041,22 @@ impl RObject for ChatEventPermissionsChanged {
     }
 }
 
+impl PartialEq for ChatEventPermissionsChanged {
+    fn eq(&self, other: &Self) -> bool {
+        self.old_permissions == other.old_permissions
+            && self.new_permissions == other.new_permissions
+    }
+}
+
+impl Eq for ChatEventPermissionsChanged {}
+
+impl std::hash::Hash for ChatEventPermissionsChanged {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.old_permissions, state);
+        std::hash::Hash::hash(&self.new_permissions, state);
+    }
+}
+
 impl TDChatEventAction for ChatEventPermissionsChanged {}
 
 impl ChatEventPermissionsChanged {
@@ -1307,10 +2064,24 @@ impl ChatEventPermissionsChanged {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatEventPermissionsChangedBuilder {
-        let mut inner = ChatEventPermissionsChanged::default();
-        inner.td_name = "chatEventPermissionsChanged".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatEventPermissionsChangedBuilder { inner }
+        RTDChatEventPermissionsChangedBuilder {
+            inner: ChatEventPermissionsChanged::default(),
+            missing: vec!["old_permissions", "new_permissions"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatEventPermissionsChangedBuilder {
+        RTDChatEventPermissionsChangedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatEventPermissionsChangedBuilder {
+        RTDChatEventPermissionsChangedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn old_permissions(&self) -> &ChatPermissions {
@@ -1320,25 +2091,58 @@ impl ChatEventPermissionsChanged {
     pub fn new_permissions(&self) -> &ChatPermissions {
         &self.new_permissions
     }
+
+    pub fn into_old_permissions(self) -> ChatPermissions {
+        self.old_permissions
+    }
+
+    pub fn into_new_permissions(self) -> ChatPermissions {
+        self.new_permissions
+    }
+
+    pub fn with_old_permissions<T: AsRef<ChatPermissions>>(mut self, old_permissions: T) -> Self {
+        self.old_permissions = old_permissions.as_ref().clone();
+        self
+    }
+
+    pub fn with_new_permissions<T: AsRef<ChatPermissions>>(mut self, new_permissions: T) -> Self {
+        self.new_permissions = new_permissions.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDChatEventPermissionsChangedBuilder {
     inner: ChatEventPermissionsChanged,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatEventPermissionsChangedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatEventPermissionsChanged {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatEventPermissionsChanged> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatEventPermissionsChanged",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn old_permissions<T: AsRef<ChatPermissions>>(&mut self, old_permissions: T) -> &mut Self {
         self.inner.old_permissions = old_permissions.as_ref().clone();
+        self.missing.retain(|f| *f != "old_permissions");
         self
     }
 
     pub fn new_permissions<T: AsRef<ChatPermissions>>(&mut self, new_permissions: T) -> &mut Self {
         self.inner.new_permissions = new_permissions.as_ref().clone();
+        self.missing.retain(|f| *f != "new_permissions");
         self
     }
 }
@@ -1357,12 +2161,13 @@ impl AsRef<ChatEventPermissionsChanged> for RTDChatEventPermissionsChangedBuilde
 
 /// The chat photo was changed
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatEventPhotoChanged")]
 pub struct ChatEventPhotoChanged {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Previous chat photo value; may be null
     old_photo: Option<Photo>,
@@ -1384,6 +2189,21 @@ impl RObject for ChatEventPhotoChanged {
     }
 }
 
+impl PartialEq for ChatEventPhotoChanged {
+    fn eq(&self, other: &Self) -> bool {
+        self.old_photo == other.old_photo && self.new_photo == other.new_photo
+    }
+}
+
+impl Eq for ChatEventPhotoChanged {}
+
+impl std::hash::Hash for ChatEventPhotoChanged {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.old_photo, state);
+        std::hash::Hash::hash(&self.new_photo, state);
+    }
+}
+
 impl TDChatEventAction for ChatEventPhotoChanged {}
 
 impl ChatEventPhotoChanged {
@@ -1391,10 +2211,24 @@ impl ChatEventPhotoChanged {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatEventPhotoChangedBuilder {
-        let mut inner = ChatEventPhotoChanged::default();
-        inner.td_name = "chatEventPhotoChanged".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatEventPhotoChangedBuilder { inner }
+        RTDChatEventPhotoChangedBuilder {
+            inner: ChatEventPhotoChanged::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatEventPhotoChangedBuilder {
+        RTDChatEventPhotoChangedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatEventPhotoChangedBuilder {
+        RTDChatEventPhotoChangedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn old_photo(&self) -> &Option<Photo> {
@@ -1404,17 +2238,48 @@ impl ChatEventPhotoChanged {
     pub fn new_photo(&self) -> &Option<Photo> {
         &self.new_photo
     }
+
+    pub fn into_old_photo(self) -> Option<Photo> {
+        self.old_photo
+    }
+
+    pub fn into_new_photo(self) -> Option<Photo> {
+        self.new_photo
+    }
+
+    pub fn with_old_photo<T: AsRef<Photo>>(mut self, old_photo: T) -> Self {
+        self.old_photo = Some(old_photo.as_ref().clone());
+        self
+    }
+
+    pub fn with_new_photo<T: AsRef<Photo>>(mut self, new_photo: T) -> Self {
+        self.new_photo = Some(new_photo.as_ref().clone());
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDChatEventPhotoChangedBuilder {
     inner: ChatEventPhotoChanged,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatEventPhotoChangedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatEventPhotoChanged {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatEventPhotoChanged> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatEventPhotoChanged",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn old_photo<T: AsRef<Photo>>(&mut self, old_photo: T) -> &mut Self {
         self.inner.old_photo = Some(old_photo.as_ref().clone());
@@ -1441,12 +2306,13 @@ impl AsRef<ChatEventPhotoChanged> for RTDChatEventPhotoChangedBuilder {
 
 /// A poll in a message was stopped
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatEventPollStopped")]
 pub struct ChatEventPollStopped {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The message with the poll
     message: Message,
@@ -1466,6 +2332,12 @@ impl RObject for ChatEventPollStopped {
     }
 }
 
+impl PartialEq for ChatEventPollStopped {
+    fn eq(&self, other: &Self) -> bool {
+        self.message == other.message
+    }
+}
+
 impl TDChatEventAction for ChatEventPollStopped {}
 
 impl ChatEventPollStopped {
@@ -1473,29 +2345,66 @@ impl ChatEventPollStopped {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatEventPollStoppedBuilder {
-        let mut inner = ChatEventPollStopped::default();
-        inner.td_name = "chatEventPollStopped".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatEventPollStoppedBuilder { inner }
+        RTDChatEventPollStoppedBuilder {
+            inner: ChatEventPollStopped::default(),
+            missing: vec!["message"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatEventPollStoppedBuilder {
+        RTDChatEventPollStoppedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatEventPollStoppedBuilder {
+        RTDChatEventPollStoppedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn message(&self) -> &Message {
         &self.message
     }
+
+    pub fn into_message(self) -> Message {
+        self.message
+    }
+
+    pub fn with_message<T: AsRef<Message>>(mut self, message: T) -> Self {
+        self.message = message.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDChatEventPollStoppedBuilder {
     inner: ChatEventPollStopped,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatEventPollStoppedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatEventPollStopped {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatEventPollStopped> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatEventPollStopped",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn message<T: AsRef<Message>>(&mut self, message: T) -> &mut Self {
         self.inner.message = message.as_ref().clone();
+        self.missing.retain(|f| *f != "message");
         self
     }
 }
@@ -1514,12 +2423,13 @@ impl AsRef<ChatEventPollStopped> for RTDChatEventPollStoppedBuilder {
 
 /// The sign_messages setting of a channel was toggled
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatEventSignMessagesToggled")]
 pub struct ChatEventSignMessagesToggled {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// New value of sign_messages
     sign_messages: bool,
@@ -1539,6 +2449,20 @@ impl RObject for ChatEventSignMessagesToggled {
     }
 }
 
+impl PartialEq for ChatEventSignMessagesToggled {
+    fn eq(&self, other: &Self) -> bool {
+        self.sign_messages == other.sign_messages
+    }
+}
+
+impl Eq for ChatEventSignMessagesToggled {}
+
+impl std::hash::Hash for ChatEventSignMessagesToggled {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.sign_messages, state);
+    }
+}
+
 impl TDChatEventAction for ChatEventSignMessagesToggled {}
 
 impl ChatEventSignMessagesToggled {
@@ -1546,26 +2470,58 @@ impl ChatEventSignMessagesToggled {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatEventSignMessagesToggledBuilder {
-        let mut inner = ChatEventSignMessagesToggled::default();
-        inner.td_name = "chatEventSignMessagesToggled".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatEventSignMessagesToggledBuilder { inner }
+        RTDChatEventSignMessagesToggledBuilder {
+            inner: ChatEventSignMessagesToggled::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatEventSignMessagesToggledBuilder {
+        RTDChatEventSignMessagesToggledBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatEventSignMessagesToggledBuilder {
+        RTDChatEventSignMessagesToggledBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn sign_messages(&self) -> bool {
         self.sign_messages
     }
+
+    pub fn with_sign_messages(mut self, sign_messages: bool) -> Self {
+        self.sign_messages = sign_messages;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDChatEventSignMessagesToggledBuilder {
     inner: ChatEventSignMessagesToggled,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatEventSignMessagesToggledBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatEventSignMessagesToggled {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatEventSignMessagesToggled> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatEventSignMessagesToggled",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn sign_messages(&mut self, sign_messages: bool) -> &mut Self {
         self.inner.sign_messages = sign_messages;
@@ -1587,17 +2543,18 @@ impl AsRef<ChatEventSignMessagesToggled> for RTDChatEventSignMessagesToggledBuil
 
 /// The slow_mode_delay setting of a supergroup was changed
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatEventSlowModeDelayChanged")]
 pub struct ChatEventSlowModeDelayChanged {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Previous value of slow_mode_delay
-    old_slow_mode_delay: i64,
+    old_slow_mode_delay: i32,
     /// New value of slow_mode_delay
-    new_slow_mode_delay: i64,
+    new_slow_mode_delay: i32,
 }
 
 impl RObject for ChatEventSlowModeDelayChanged {
@@ -1614,6 +2571,22 @@ impl RObject for ChatEventSlowModeDelayChanged {
     }
 }
 
+impl PartialEq for ChatEventSlowModeDelayChanged {
+    fn eq(&self, other: &Self) -> bool {
+        self.old_slow_mode_delay == other.old_slow_mode_delay
+            && self.new_slow_mode_delay == other.new_slow_mode_delay
+    }
+}
+
+impl Eq for ChatEventSlowModeDelayChanged {}
+
+impl std::hash::Hash for ChatEventSlowModeDelayChanged {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.old_slow_mode_delay, state);
+        std::hash::Hash::hash(&self.new_slow_mode_delay, state);
+    }
+}
+
 impl TDChatEventAction for ChatEventSlowModeDelayChanged {}
 
 impl ChatEventSlowModeDelayChanged {
@@ -1621,38 +2594,77 @@ impl ChatEventSlowModeDelayChanged {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatEventSlowModeDelayChangedBuilder {
-        let mut inner = ChatEventSlowModeDelayChanged::default();
-        inner.td_name = "chatEventSlowModeDelayChanged".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatEventSlowModeDelayChangedBuilder { inner }
+        RTDChatEventSlowModeDelayChangedBuilder {
+            inner: ChatEventSlowModeDelayChanged::default(),
+            missing: vec!["old_slow_mode_delay", "new_slow_mode_delay"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatEventSlowModeDelayChangedBuilder {
+        RTDChatEventSlowModeDelayChangedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatEventSlowModeDelayChangedBuilder {
+        RTDChatEventSlowModeDelayChangedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn old_slow_mode_delay(&self) -> i64 {
+    pub fn old_slow_mode_delay(&self) -> i32 {
         self.old_slow_mode_delay
     }
 
-    pub fn new_slow_mode_delay(&self) -> i64 {
+    pub fn new_slow_mode_delay(&self) -> i32 {
         self.new_slow_mode_delay
     }
+
+    pub fn with_old_slow_mode_delay(mut self, old_slow_mode_delay: i32) -> Self {
+        self.old_slow_mode_delay = old_slow_mode_delay;
+        self
+    }
+
+    pub fn with_new_slow_mode_delay(mut self, new_slow_mode_delay: i32) -> Self {
+        self.new_slow_mode_delay = new_slow_mode_delay;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDChatEventSlowModeDelayChangedBuilder {
     inner: ChatEventSlowModeDelayChanged,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatEventSlowModeDelayChangedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatEventSlowModeDelayChanged {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatEventSlowModeDelayChanged> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatEventSlowModeDelayChanged",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn old_slow_mode_delay(&mut self, old_slow_mode_delay: i64) -> &mut Self {
+    pub fn old_slow_mode_delay(&mut self, old_slow_mode_delay: i32) -> &mut Self {
         self.inner.old_slow_mode_delay = old_slow_mode_delay;
+        self.missing.retain(|f| *f != "old_slow_mode_delay");
         self
     }
 
-    pub fn new_slow_mode_delay(&mut self, new_slow_mode_delay: i64) -> &mut Self {
+    pub fn new_slow_mode_delay(&mut self, new_slow_mode_delay: i32) -> &mut Self {
         self.inner.new_slow_mode_delay = new_slow_mode_delay;
+        self.missing.retain(|f| *f != "new_slow_mode_delay");
         self
     }
 }
@@ -1671,17 +2683,20 @@ impl AsRef<ChatEventSlowModeDelayChanged> for RTDChatEventSlowModeDelayChangedBu
 
 /// The supergroup sticker set was changed
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatEventStickerSetChanged")]
 pub struct ChatEventStickerSetChanged {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Previous identifier of the chat sticker set; 0 if none
-    old_sticker_set_id: isize,
+    #[serde(with = "super::_common::int64")]
+    old_sticker_set_id: i64,
     /// New identifier of the chat sticker set; 0 if none
-    new_sticker_set_id: isize,
+    #[serde(with = "super::_common::int64")]
+    new_sticker_set_id: i64,
 }
 
 impl RObject for ChatEventStickerSetChanged {
@@ -1698,6 +2713,22 @@ impl RObject for ChatEventStickerSetChanged {
     }
 }
 
+impl PartialEq for ChatEventStickerSetChanged {
+    fn eq(&self, other: &Self) -> bool {
+        self.old_sticker_set_id == other.old_sticker_set_id
+            && self.new_sticker_set_id == other.new_sticker_set_id
+    }
+}
+
+impl Eq for ChatEventStickerSetChanged {}
+
+impl std::hash::Hash for ChatEventStickerSetChanged {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.old_sticker_set_id, state);
+        std::hash::Hash::hash(&self.new_sticker_set_id, state);
+    }
+}
+
 impl TDChatEventAction for ChatEventStickerSetChanged {}
 
 impl ChatEventStickerSetChanged {
@@ -1705,37 +2736,74 @@ impl ChatEventStickerSetChanged {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatEventStickerSetChangedBuilder {
-        let mut inner = ChatEventStickerSetChanged::default();
-        inner.td_name = "chatEventStickerSetChanged".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatEventStickerSetChangedBuilder { inner }
+        RTDChatEventStickerSetChangedBuilder {
+            inner: ChatEventStickerSetChanged::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatEventStickerSetChangedBuilder {
+        RTDChatEventStickerSetChangedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatEventStickerSetChangedBuilder {
+        RTDChatEventStickerSetChangedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn old_sticker_set_id(&self) -> isize {
+    pub fn old_sticker_set_id(&self) -> i64 {
         self.old_sticker_set_id
     }
 
-    pub fn new_sticker_set_id(&self) -> isize {
+    pub fn new_sticker_set_id(&self) -> i64 {
         self.new_sticker_set_id
     }
+
+    pub fn with_old_sticker_set_id(mut self, old_sticker_set_id: i64) -> Self {
+        self.old_sticker_set_id = old_sticker_set_id;
+        self
+    }
+
+    pub fn with_new_sticker_set_id(mut self, new_sticker_set_id: i64) -> Self {
+        self.new_sticker_set_id = new_sticker_set_id;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDChatEventStickerSetChangedBuilder {
     inner: ChatEventStickerSetChanged,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatEventStickerSetChangedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatEventStickerSetChanged {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatEventStickerSetChanged> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatEventStickerSetChanged",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn old_sticker_set_id(&mut self, old_sticker_set_id: isize) -> &mut Self {
+    pub fn old_sticker_set_id(&mut self, old_sticker_set_id: i64) -> &mut Self {
         self.inner.old_sticker_set_id = old_sticker_set_id;
         self
     }
 
-    pub fn new_sticker_set_id(&mut self, new_sticker_set_id: isize) -> &mut Self {
+    pub fn new_sticker_set_id(&mut self, new_sticker_set_id: i64) -> &mut Self {
         self.inner.new_sticker_set_id = new_sticker_set_id;
         self
     }
@@ -1755,12 +2823,13 @@ impl AsRef<ChatEventStickerSetChanged> for RTDChatEventStickerSetChangedBuilder
 
 /// The chat title was changed
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatEventTitleChanged")]
 pub struct ChatEventTitleChanged {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Previous chat title
     old_title: String,
@@ -1782,6 +2851,21 @@ impl RObject for ChatEventTitleChanged {
     }
 }
 
+impl PartialEq for ChatEventTitleChanged {
+    fn eq(&self, other: &Self) -> bool {
+        self.old_title == other.old_title && self.new_title == other.new_title
+    }
+}
+
+impl Eq for ChatEventTitleChanged {}
+
+impl std::hash::Hash for ChatEventTitleChanged {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.old_title, state);
+        std::hash::Hash::hash(&self.new_title, state);
+    }
+}
+
 impl TDChatEventAction for ChatEventTitleChanged {}
 
 impl ChatEventTitleChanged {
@@ -1789,10 +2873,24 @@ impl ChatEventTitleChanged {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatEventTitleChangedBuilder {
-        let mut inner = ChatEventTitleChanged::default();
-        inner.td_name = "chatEventTitleChanged".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatEventTitleChangedBuilder { inner }
+        RTDChatEventTitleChangedBuilder {
+            inner: ChatEventTitleChanged::default(),
+            missing: vec!["old_title", "new_title"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatEventTitleChangedBuilder {
+        RTDChatEventTitleChangedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatEventTitleChangedBuilder {
+        RTDChatEventTitleChangedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn old_title(&self) -> &String {
@@ -1802,25 +2900,58 @@ impl ChatEventTitleChanged {
     pub fn new_title(&self) -> &String {
         &self.new_title
     }
+
+    pub fn into_old_title(self) -> String {
+        self.old_title
+    }
+
+    pub fn into_new_title(self) -> String {
+        self.new_title
+    }
+
+    pub fn with_old_title<T: AsRef<str>>(mut self, old_title: T) -> Self {
+        self.old_title = old_title.as_ref().to_string();
+        self
+    }
+
+    pub fn with_new_title<T: AsRef<str>>(mut self, new_title: T) -> Self {
+        self.new_title = new_title.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDChatEventTitleChangedBuilder {
     inner: ChatEventTitleChanged,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatEventTitleChangedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatEventTitleChanged {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatEventTitleChanged> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatEventTitleChanged",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn old_title<T: AsRef<str>>(&mut self, old_title: T) -> &mut Self {
         self.inner.old_title = old_title.as_ref().to_string();
+        self.missing.retain(|f| *f != "old_title");
         self
     }
 
     pub fn new_title<T: AsRef<str>>(&mut self, new_title: T) -> &mut Self {
         self.inner.new_title = new_title.as_ref().to_string();
+        self.missing.retain(|f| *f != "new_title");
         self
     }
 }
@@ -1839,12 +2970,13 @@ impl AsRef<ChatEventTitleChanged> for RTDChatEventTitleChangedBuilder {
 
 /// The chat username was changed
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatEventUsernameChanged")]
 pub struct ChatEventUsernameChanged {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Previous chat username
     old_username: String,
@@ -1866,6 +2998,21 @@ impl RObject for ChatEventUsernameChanged {
     }
 }
 
+impl PartialEq for ChatEventUsernameChanged {
+    fn eq(&self, other: &Self) -> bool {
+        self.old_username == other.old_username && self.new_username == other.new_username
+    }
+}
+
+impl Eq for ChatEventUsernameChanged {}
+
+impl std::hash::Hash for ChatEventUsernameChanged {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.old_username, state);
+        std::hash::Hash::hash(&self.new_username, state);
+    }
+}
+
 impl TDChatEventAction for ChatEventUsernameChanged {}
 
 impl ChatEventUsernameChanged {
@@ -1873,10 +3020,24 @@ impl ChatEventUsernameChanged {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatEventUsernameChangedBuilder {
-        let mut inner = ChatEventUsernameChanged::default();
-        inner.td_name = "chatEventUsernameChanged".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatEventUsernameChangedBuilder { inner }
+        RTDChatEventUsernameChangedBuilder {
+            inner: ChatEventUsernameChanged::default(),
+            missing: vec!["old_username", "new_username"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatEventUsernameChangedBuilder {
+        RTDChatEventUsernameChangedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatEventUsernameChangedBuilder {
+        RTDChatEventUsernameChangedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn old_username(&self) -> &String {
@@ -1886,25 +3047,58 @@ impl ChatEventUsernameChanged {
     pub fn new_username(&self) -> &String {
         &self.new_username
     }
+
+    pub fn into_old_username(self) -> String {
+        self.old_username
+    }
+
+    pub fn into_new_username(self) -> String {
+        self.new_username
+    }
+
+    pub fn with_old_username<T: AsRef<str>>(mut self, old_username: T) -> Self {
+        self.old_username = old_username.as_ref().to_string();
+        self
+    }
+
+    pub fn with_new_username<T: AsRef<str>>(mut self, new_username: T) -> Self {
+        self.new_username = new_username.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDChatEventUsernameChangedBuilder {
     inner: ChatEventUsernameChanged,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatEventUsernameChangedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatEventUsernameChanged {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatEventUsernameChanged> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatEventUsernameChanged",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn old_username<T: AsRef<str>>(&mut self, old_username: T) -> &mut Self {
         self.inner.old_username = old_username.as_ref().to_string();
+        self.missing.retain(|f| *f != "old_username");
         self
     }
 
     pub fn new_username<T: AsRef<str>>(&mut self, new_username: T) -> &mut Self {
         self.inner.new_username = new_username.as_ref().to_string();
+        self.missing.retain(|f| *f != "new_username");
         self
     }
 }
@@ -1920,3 +3114,358 @@ impl AsRef<ChatEventUsernameChanged> for RTDChatEventUsernameChangedBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(ChatEventAction::ChatEventDescriptionChanged(
+            ChatEventDescriptionChanged::builder()
+                .old_description("text")
+                .new_description("text")
+                .build(),
+        ));
+        assert_round_trip(ChatEventAction::ChatEventInvitesToggled(
+            ChatEventInvitesToggled::builder()
+                .can_invite_users(true)
+                .build(),
+        ));
+        assert_round_trip(ChatEventAction::ChatEventIsAllHistoryAvailableToggled(
+            ChatEventIsAllHistoryAvailableToggled::builder()
+                .is_all_history_available(true)
+                .build(),
+        ));
+        assert_round_trip(ChatEventAction::ChatEventLinkedChatChanged(
+            ChatEventLinkedChatChanged::builder()
+                .old_linked_chat_id(9007199254740991i64)
+                .new_linked_chat_id(9007199254740991i64)
+                .build(),
+        ));
+        assert_round_trip(ChatEventAction::ChatEventLocationChanged(
+            ChatEventLocationChanged::builder().build(),
+        ));
+        assert_round_trip(ChatEventAction::ChatEventMemberInvited(
+            ChatEventMemberInvited::builder()
+                .user_id(i32::MAX)
+                .status(ChatMemberStatus::Administrator(
+                    ChatMemberStatusAdministrator::builder()
+                        .custom_title("text")
+                        .can_be_edited(true)
+                        .can_change_info(true)
+                        .can_post_messages(true)
+                        .can_edit_messages(true)
+                        .can_delete_messages(true)
+                        .can_invite_users(true)
+                        .can_restrict_members(true)
+                        .can_pin_messages(true)
+                        .can_promote_members(true)
+                        .build(),
+                ))
+                .build(),
+        ));
+        assert_round_trip(ChatEventAction::ChatEventMemberJoined(
+            ChatEventMemberJoined::builder().build(),
+        ));
+        assert_round_trip(ChatEventAction::ChatEventMemberLeft(
+            ChatEventMemberLeft::builder().build(),
+        ));
+        assert_round_trip(ChatEventAction::ChatEventMemberPromoted(
+            ChatEventMemberPromoted::builder()
+                .user_id(i32::MAX)
+                .old_status(ChatMemberStatus::Administrator(
+                    ChatMemberStatusAdministrator::builder()
+                        .custom_title("text")
+                        .can_be_edited(true)
+                        .can_change_info(true)
+                        .can_post_messages(true)
+                        .can_edit_messages(true)
+                        .can_delete_messages(true)
+                        .can_invite_users(true)
+                        .can_restrict_members(true)
+                        .can_pin_messages(true)
+                        .can_promote_members(true)
+                        .build(),
+                ))
+                .new_status(ChatMemberStatus::Administrator(
+                    ChatMemberStatusAdministrator::builder()
+                        .custom_title("text")
+                        .can_be_edited(true)
+                        .can_change_info(true)
+                        .can_post_messages(true)
+                        .can_edit_messages(true)
+                        .can_delete_messages(true)
+                        .can_invite_users(true)
+                        .can_restrict_members(true)
+                        .can_pin_messages(true)
+                        .can_promote_members(true)
+                        .build(),
+                ))
+                .build(),
+        ));
+        assert_round_trip(ChatEventAction::ChatEventMemberRestricted(
+            ChatEventMemberRestricted::builder()
+                .user_id(i32::MAX)
+                .old_status(ChatMemberStatus::Administrator(
+                    ChatMemberStatusAdministrator::builder()
+                        .custom_title("text")
+                        .can_be_edited(true)
+                        .can_change_info(true)
+                        .can_post_messages(true)
+                        .can_edit_messages(true)
+                        .can_delete_messages(true)
+                        .can_invite_users(true)
+                        .can_restrict_members(true)
+                        .can_pin_messages(true)
+                        .can_promote_members(true)
+                        .build(),
+                ))
+                .new_status(ChatMemberStatus::Administrator(
+                    ChatMemberStatusAdministrator::builder()
+                        .custom_title("text")
+                        .can_be_edited(true)
+                        .can_change_info(true)
+                        .can_post_messages(true)
+                        .can_edit_messages(true)
+                        .can_delete_messages(true)
+                        .can_invite_users(true)
+                        .can_restrict_members(true)
+                        .can_pin_messages(true)
+                        .can_promote_members(true)
+                        .build(),
+                ))
+                .build(),
+        ));
+        assert_round_trip(ChatEventAction::ChatEventMessageDeleted(
+            ChatEventMessageDeleted::builder()
+                .message(
+                    Message::builder()
+                        .id(9007199254740991i64)
+                        .sender_user_id(i32::MAX)
+                        .chat_id(9007199254740991i64)
+                        .is_outgoing(true)
+                        .can_be_edited(true)
+                        .can_be_forwarded(true)
+                        .can_be_deleted_only_for_self(true)
+                        .can_be_deleted_for_all_users(true)
+                        .is_channel_post(true)
+                        .contains_unread_mention(true)
+                        .date(i32::MAX)
+                        .edit_date(i32::MAX)
+                        .reply_to_message_id(9007199254740991i64)
+                        .ttl(i32::MAX)
+                        .ttl_expires_in(1.5)
+                        .via_bot_user_id(i32::MAX)
+                        .author_signature("text")
+                        .views(i32::MAX)
+                        .media_album_id(i64::MAX)
+                        .restriction_reason("text")
+                        .content(MessageContent::MessageBasicGroupChatCreate(
+                            MessageBasicGroupChatCreate::builder()
+                                .title("text")
+                                .member_user_ids(vec![Default::default()])
+                                .build(),
+                        ))
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(ChatEventAction::ChatEventMessageEdited(
+            ChatEventMessageEdited::builder()
+                .old_message(
+                    Message::builder()
+                        .id(9007199254740991i64)
+                        .sender_user_id(i32::MAX)
+                        .chat_id(9007199254740991i64)
+                        .is_outgoing(true)
+                        .can_be_edited(true)
+                        .can_be_forwarded(true)
+                        .can_be_deleted_only_for_self(true)
+                        .can_be_deleted_for_all_users(true)
+                        .is_channel_post(true)
+                        .contains_unread_mention(true)
+                        .date(i32::MAX)
+                        .edit_date(i32::MAX)
+                        .reply_to_message_id(9007199254740991i64)
+                        .ttl(i32::MAX)
+                        .ttl_expires_in(1.5)
+                        .via_bot_user_id(i32::MAX)
+                        .author_signature("text")
+                        .views(i32::MAX)
+                        .media_album_id(i64::MAX)
+                        .restriction_reason("text")
+                        .content(MessageContent::MessageBasicGroupChatCreate(
+                            MessageBasicGroupChatCreate::builder()
+                                .title("text")
+                                .member_user_ids(vec![Default::default()])
+                                .build(),
+                        ))
+                        .build(),
+                )
+                .new_message(
+                    Message::builder()
+                        .id(9007199254740991i64)
+                        .sender_user_id(i32::MAX)
+                        .chat_id(9007199254740991i64)
+                        .is_outgoing(true)
+                        .can_be_edited(true)
+                        .can_be_forwarded(true)
+                        .can_be_deleted_only_for_self(true)
+                        .can_be_deleted_for_all_users(true)
+                        .is_channel_post(true)
+                        .contains_unread_mention(true)
+                        .date(i32::MAX)
+                        .edit_date(i32::MAX)
+                        .reply_to_message_id(9007199254740991i64)
+                        .ttl(i32::MAX)
+                        .ttl_expires_in(1.5)
+                        .via_bot_user_id(i32::MAX)
+                        .author_signature("text")
+                        .views(i32::MAX)
+                        .media_album_id(i64::MAX)
+                        .restriction_reason("text")
+                        .content(MessageContent::MessageBasicGroupChatCreate(
+                            MessageBasicGroupChatCreate::builder()
+                                .title("text")
+                                .member_user_ids(vec![Default::default()])
+                                .build(),
+                        ))
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(ChatEventAction::ChatEventMessagePinned(
+            ChatEventMessagePinned::builder()
+                .message(
+                    Message::builder()
+                        .id(9007199254740991i64)
+                        .sender_user_id(i32::MAX)
+                        .chat_id(9007199254740991i64)
+                        .is_outgoing(true)
+                        .can_be_edited(true)
+                        .can_be_forwarded(true)
+                        .can_be_deleted_only_for_self(true)
+                        .can_be_deleted_for_all_users(true)
+                        .is_channel_post(true)
+                        .contains_unread_mention(true)
+                        .date(i32::MAX)
+                        .edit_date(i32::MAX)
+                        .reply_to_message_id(9007199254740991i64)
+                        .ttl(i32::MAX)
+                        .ttl_expires_in(1.5)
+                        .via_bot_user_id(i32::MAX)
+                        .author_signature("text")
+                        .views(i32::MAX)
+                        .media_album_id(i64::MAX)
+                        .restriction_reason("text")
+                        .content(MessageContent::MessageBasicGroupChatCreate(
+                            MessageBasicGroupChatCreate::builder()
+                                .title("text")
+                                .member_user_ids(vec![Default::default()])
+                                .build(),
+                        ))
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(ChatEventAction::ChatEventMessageUnpinned(
+            ChatEventMessageUnpinned::builder().build(),
+        ));
+        assert_round_trip(ChatEventAction::ChatEventPermissionsChanged(
+            ChatEventPermissionsChanged::builder()
+                .old_permissions(
+                    ChatPermissions::builder()
+                        .can_send_messages(true)
+                        .can_send_media_messages(true)
+                        .can_send_polls(true)
+                        .can_send_other_messages(true)
+                        .can_add_web_page_previews(true)
+                        .can_change_info(true)
+                        .can_invite_users(true)
+                        .can_pin_messages(true)
+                        .build(),
+                )
+                .new_permissions(
+                    ChatPermissions::builder()
+                        .can_send_messages(true)
+                        .can_send_media_messages(true)
+                        .can_send_polls(true)
+                        .can_send_other_messages(true)
+                        .can_add_web_page_previews(true)
+                        .can_change_info(true)
+                        .can_invite_users(true)
+                        .can_pin_messages(true)
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(ChatEventAction::ChatEventPhotoChanged(
+            ChatEventPhotoChanged::builder().build(),
+        ));
+        assert_round_trip(ChatEventAction::ChatEventPollStopped(
+            ChatEventPollStopped::builder()
+                .message(
+                    Message::builder()
+                        .id(9007199254740991i64)
+                        .sender_user_id(i32::MAX)
+                        .chat_id(9007199254740991i64)
+                        .is_outgoing(true)
+                        .can_be_edited(true)
+                        .can_be_forwarded(true)
+                        .can_be_deleted_only_for_self(true)
+                        .can_be_deleted_for_all_users(true)
+                        .is_channel_post(true)
+                        .contains_unread_mention(true)
+                        .date(i32::MAX)
+                        .edit_date(i32::MAX)
+                        .reply_to_message_id(9007199254740991i64)
+                        .ttl(i32::MAX)
+                        .ttl_expires_in(1.5)
+                        .via_bot_user_id(i32::MAX)
+                        .author_signature("text")
+                        .views(i32::MAX)
+                        .media_album_id(i64::MAX)
+                        .restriction_reason("text")
+                        .content(MessageContent::MessageBasicGroupChatCreate(
+                            MessageBasicGroupChatCreate::builder()
+                                .title("text")
+                                .member_user_ids(vec![Default::default()])
+                                .build(),
+                        ))
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(ChatEventAction::ChatEventSignMessagesToggled(
+            ChatEventSignMessagesToggled::builder()
+                .sign_messages(true)
+                .build(),
+        ));
+        assert_round_trip(ChatEventAction::ChatEventSlowModeDelayChanged(
+            ChatEventSlowModeDelayChanged::builder()
+                .old_slow_mode_delay(i32::MAX)
+                .new_slow_mode_delay(i32::MAX)
+                .build(),
+        ));
+        assert_round_trip(ChatEventAction::ChatEventStickerSetChanged(
+            ChatEventStickerSetChanged::builder()
+                .old_sticker_set_id(i64::MAX)
+                .new_sticker_set_id(i64::MAX)
+                .build(),
+        ));
+        assert_round_trip(ChatEventAction::ChatEventTitleChanged(
+            ChatEventTitleChanged::builder()
+                .old_title("text")
+                .new_title("text")
+                .build(),
+        ));
+        assert_round_trip(ChatEventAction::ChatEventUsernameChanged(
+            ChatEventUsernameChanged::builder()
+                .old_username("text")
+                .new_username("text")
+                .build(),
+        ));
+    }
+}
@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Represents a set of filters used to obtain a chat event log
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatEventLogFilters")]
 pub struct ChatEventLogFilters {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// True, if message edits should be returned
     message_edits: bool,
@@ -47,15 +47,61 @@ impl RObject for ChatEventLogFilters {
     }
 }
 
+impl PartialEq for ChatEventLogFilters {
+    fn eq(&self, other: &Self) -> bool {
+        self.message_edits == other.message_edits
+            && self.message_deletions == other.message_deletions
+            && self.message_pins == other.message_pins
+            && self.member_joins == other.member_joins
+            && self.member_leaves == other.member_leaves
+            && self.member_invites == other.member_invites
+            && self.member_promotions == other.member_promotions
+            && self.member_restrictions == other.member_restrictions
+            && self.info_changes == other.info_changes
+            && self.setting_changes == other.setting_changes
+    }
+}
+
+impl Eq for ChatEventLogFilters {}
+
+impl std::hash::Hash for ChatEventLogFilters {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.message_edits, state);
+        std::hash::Hash::hash(&self.message_deletions, state);
+        std::hash::Hash::hash(&self.message_pins, state);
+        std::hash::Hash::hash(&self.member_joins, state);
+        std::hash::Hash::hash(&self.member_leaves, state);
+        std::hash::Hash::hash(&self.member_invites, state);
+        std::hash::Hash::hash(&self.member_promotions, state);
+        std::hash::Hash::hash(&self.member_restrictions, state);
+        std::hash::Hash::hash(&self.info_changes, state);
+        std::hash::Hash::hash(&self.setting_changes, state);
+    }
+}
+
 impl ChatEventLogFilters {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatEventLogFiltersBuilder {
-        let mut inner = ChatEventLogFilters::default();
-        inner.td_name = "chatEventLogFilters".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatEventLogFiltersBuilder { inner }
+        RTDChatEventLogFiltersBuilder {
+            inner: ChatEventLogFilters::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatEventLogFiltersBuilder {
+        RTDChatEventLogFiltersBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatEventLogFiltersBuilder {
+        RTDChatEventLogFiltersBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn message_edits(&self) -> bool {
@@ -97,17 +143,80 @@ impl ChatEventLogFilters {
     pub fn setting_changes(&self) -> bool {
         self.setting_changes
     }
+
+    pub fn with_message_edits(mut self, message_edits: bool) -> Self {
+        self.message_edits = message_edits;
+        self
+    }
+
+    pub fn with_message_deletions(mut self, message_deletions: bool) -> Self {
+        self.message_deletions = message_deletions;
+        self
+    }
+
+    pub fn with_message_pins(mut self, message_pins: bool) -> Self {
+        self.message_pins = message_pins;
+        self
+    }
+
+    pub fn with_member_joins(mut self, member_joins: bool) -> Self {
+        self.member_joins = member_joins;
+        self
+    }
+
+    pub fn with_member_leaves(mut self, member_leaves: bool) -> Self {
+        self.member_leaves = member_leaves;
+        self
+    }
+
+    pub fn with_member_invites(mut self, member_invites: bool) -> Self {
+        self.member_invites = member_invites;
+        self
+    }
+
+    pub fn with_member_promotions(mut self, member_promotions: bool) -> Self {
+        self.member_promotions = member_promotions;
+        self
+    }
+
+    pub fn with_member_restrictions(mut self, member_restrictions: bool) -> Self {
+        self.member_restrictions = member_restrictions;
+        self
+    }
+
+    pub fn with_info_changes(mut self, info_changes: bool) -> Self {
+        self.info_changes = info_changes;
+        self
+    }
+
+    pub fn with_setting_changes(mut self, setting_changes: bool) -> Self {
+        self.setting_changes = setting_changes;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDChatEventLogFiltersBuilder {
     inner: ChatEventLogFilters,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatEventLogFiltersBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatEventLogFilters {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatEventLogFilters> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatEventLogFilters",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn message_edits(&mut self, message_edits: bool) -> &mut Self {
         self.inner.message_edits = message_edits;
@@ -171,3 +280,27 @@ impl AsRef<ChatEventLogFilters> for RTDChatEventLogFiltersBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            ChatEventLogFilters::builder()
+                .message_edits(true)
+                .message_deletions(true)
+                .message_pins(true)
+                .member_joins(true)
+                .member_leaves(true)
+                .member_invites(true)
+                .member_promotions(true)
+                .member_restrictions(true)
+                .info_changes(true)
+                .setting_changes(true)
+                .build(),
+        );
+    }
+}
@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains a list of chat events
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatEvents")]
 pub struct ChatEvents {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// List of events
     events: Vec<ChatEvent>,
@@ -29,34 +29,74 @@ impl RObject for ChatEvents {
     }
 }
 
+impl PartialEq for ChatEvents {
+    fn eq(&self, other: &Self) -> bool {
+        self.events == other.events
+    }
+}
+
 impl ChatEvents {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatEventsBuilder {
-        let mut inner = ChatEvents::default();
-        inner.td_name = "chatEvents".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatEventsBuilder { inner }
+        RTDChatEventsBuilder {
+            inner: ChatEvents::default(),
+            missing: vec!["events"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatEventsBuilder {
+        RTDChatEventsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatEventsBuilder {
+        RTDChatEventsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn events(&self) -> &Vec<ChatEvent> {
         &self.events
     }
+
+    pub fn into_events(self) -> Vec<ChatEvent> {
+        self.events
+    }
+
+    pub fn with_events(mut self, events: Vec<ChatEvent>) -> Self {
+        self.events = events;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDChatEventsBuilder {
     inner: ChatEvents,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatEventsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatEvents {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatEvents> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("ChatEvents", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn events(&mut self, events: Vec<ChatEvent>) -> &mut Self {
         self.inner.events = events;
+        self.missing.retain(|f| *f != "events");
         self
     }
 }
@@ -72,3 +112,14 @@ impl AsRef<ChatEvents> for RTDChatEventsBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(ChatEvents::builder().events(vec![]).build());
+    }
+}
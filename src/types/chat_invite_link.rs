@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains a chat invite link
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatInviteLink")]
 pub struct ChatInviteLink {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Chat invite link
     invite_link: String,
@@ -29,34 +29,85 @@ impl RObject for ChatInviteLink {
     }
 }
 
+impl PartialEq for ChatInviteLink {
+    fn eq(&self, other: &Self) -> bool {
+        self.invite_link == other.invite_link
+    }
+}
+
+impl Eq for ChatInviteLink {}
+
+impl std::hash::Hash for ChatInviteLink {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.invite_link, state);
+    }
+}
+
 impl ChatInviteLink {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatInviteLinkBuilder {
-        let mut inner = ChatInviteLink::default();
-        inner.td_name = "chatInviteLink".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatInviteLinkBuilder { inner }
+        RTDChatInviteLinkBuilder {
+            inner: ChatInviteLink::default(),
+            missing: vec!["invite_link"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatInviteLinkBuilder {
+        RTDChatInviteLinkBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatInviteLinkBuilder {
+        RTDChatInviteLinkBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn invite_link(&self) -> &String {
         &self.invite_link
     }
+
+    pub fn into_invite_link(self) -> String {
+        self.invite_link
+    }
+
+    pub fn with_invite_link<T: AsRef<str>>(mut self, invite_link: T) -> Self {
+        self.invite_link = invite_link.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDChatInviteLinkBuilder {
     inner: ChatInviteLink,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatInviteLinkBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatInviteLink {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatInviteLink> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatInviteLink",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn invite_link<T: AsRef<str>>(&mut self, invite_link: T) -> &mut Self {
         self.inner.invite_link = invite_link.as_ref().to_string();
+        self.missing.retain(|f| *f != "invite_link");
         self
     }
 }
@@ -72,3 +123,14 @@ impl AsRef<ChatInviteLink> for RTDChatInviteLinkBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(ChatInviteLink::builder().invite_link("text").build());
+    }
+}
@@ -1,18 +1,18 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains information about a chat invite link
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatInviteLinkInfo")]
 pub struct ChatInviteLinkInfo {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Chat identifier of the invite link; 0 if the user is not a member of this chat
-    chat_id: i64,
+    chat_id: ChatId,
     /// Contains information about the type of the chat
     #[serde(rename(serialize = "type", deserialize = "type"))]
     type_: ChatType,
@@ -21,9 +21,9 @@ pub struct ChatInviteLinkInfo {
     /// Chat photo; may be null
     photo: Option<ChatPhoto>,
     /// Number of members
-    member_count: i64,
+    member_count: i32,
     /// User identifiers of some chat members that may be known to the current user
-    member_user_ids: Vec<i64>,
+    member_user_ids: Vec<UserId>,
     /// True, if the chat is a public supergroup or channel, i.e. it has a username or it is a location-based supergroup
     is_public: bool,
 }
@@ -42,18 +42,58 @@ impl RObject for ChatInviteLinkInfo {
     }
 }
 
+impl PartialEq for ChatInviteLinkInfo {
+    fn eq(&self, other: &Self) -> bool {
+        self.chat_id == other.chat_id
+            && self.type_ == other.type_
+            && self.title == other.title
+            && self.photo == other.photo
+            && self.member_count == other.member_count
+            && self.member_user_ids == other.member_user_ids
+            && self.is_public == other.is_public
+    }
+}
+
+impl Eq for ChatInviteLinkInfo {}
+
+impl std::hash::Hash for ChatInviteLinkInfo {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.chat_id, state);
+        std::hash::Hash::hash(&self.type_, state);
+        std::hash::Hash::hash(&self.title, state);
+        std::hash::Hash::hash(&self.photo, state);
+        std::hash::Hash::hash(&self.member_count, state);
+        std::hash::Hash::hash(&self.member_user_ids, state);
+        std::hash::Hash::hash(&self.is_public, state);
+    }
+}
+
 impl ChatInviteLinkInfo {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatInviteLinkInfoBuilder {
-        let mut inner = ChatInviteLinkInfo::default();
-        inner.td_name = "chatInviteLinkInfo".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatInviteLinkInfoBuilder { inner }
+        RTDChatInviteLinkInfoBuilder {
+            inner: ChatInviteLinkInfo::default(),
+            missing: vec!["type_", "title", "member_count", "member_user_ids"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatInviteLinkInfoBuilder {
+        RTDChatInviteLinkInfoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatInviteLinkInfoBuilder {
+        RTDChatInviteLinkInfoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn chat_id(&self) -> i64 {
+    pub fn chat_id(&self) -> ChatId {
         self.chat_id
     }
 
@@ -69,41 +109,107 @@ impl ChatInviteLinkInfo {
         &self.photo
     }
 
-    pub fn member_count(&self) -> i64 {
+    pub fn member_count(&self) -> i32 {
         self.member_count
     }
 
-    pub fn member_user_ids(&self) -> &Vec<i64> {
+    pub fn member_user_ids(&self) -> &Vec<UserId> {
         &self.member_user_ids
     }
 
     pub fn is_public(&self) -> bool {
         self.is_public
     }
+
+    pub fn into_type(self) -> ChatType {
+        self.type_
+    }
+
+    pub fn into_title(self) -> String {
+        self.title
+    }
+
+    pub fn into_photo(self) -> Option<ChatPhoto> {
+        self.photo
+    }
+
+    pub fn into_member_user_ids(self) -> Vec<UserId> {
+        self.member_user_ids
+    }
+
+    pub fn with_chat_id<T: Into<ChatId>>(mut self, chat_id: T) -> Self {
+        self.chat_id = chat_id.into();
+        self
+    }
+
+    pub fn with_type<T: AsRef<ChatType>>(mut self, type_: T) -> Self {
+        self.type_ = type_.as_ref().clone();
+        self
+    }
+
+    pub fn with_title<T: AsRef<str>>(mut self, title: T) -> Self {
+        self.title = title.as_ref().to_string();
+        self
+    }
+
+    pub fn with_photo<T: AsRef<ChatPhoto>>(mut self, photo: T) -> Self {
+        self.photo = Some(photo.as_ref().clone());
+        self
+    }
+
+    pub fn with_member_count(mut self, member_count: i32) -> Self {
+        self.member_count = member_count;
+        self
+    }
+
+    pub fn with_member_user_ids(mut self, member_user_ids: Vec<UserId>) -> Self {
+        self.member_user_ids = member_user_ids;
+        self
+    }
+
+    pub fn with_is_public(mut self, is_public: bool) -> Self {
+        self.is_public = is_public;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDChatInviteLinkInfoBuilder {
     inner: ChatInviteLinkInfo,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatInviteLinkInfoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatInviteLinkInfo {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatInviteLinkInfo> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatInviteLinkInfo",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
-        self.inner.chat_id = chat_id;
+    pub fn chat_id<T: Into<ChatId>>(&mut self, chat_id: T) -> &mut Self {
+        self.inner.chat_id = chat_id.into();
         self
     }
 
     pub fn type_<T: AsRef<ChatType>>(&mut self, type_: T) -> &mut Self {
         self.inner.type_ = type_.as_ref().clone();
+        self.missing.retain(|f| *f != "type_");
         self
     }
 
     pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
         self.inner.title = title.as_ref().to_string();
+        self.missing.retain(|f| *f != "title");
         self
     }
 
@@ -112,13 +218,15 @@ impl RTDChatInviteLinkInfoBuilder {
         self
     }
 
-    pub fn member_count(&mut self, member_count: i64) -> &mut Self {
+    pub fn member_count(&mut self, member_count: i32) -> &mut Self {
         self.inner.member_count = member_count;
+        self.missing.retain(|f| *f != "member_count");
         self
     }
 
-    pub fn member_user_ids(&mut self, member_user_ids: Vec<i64>) -> &mut Self {
+    pub fn member_user_ids(&mut self, member_user_ids: Vec<UserId>) -> &mut Self {
         self.inner.member_user_ids = member_user_ids;
+        self.missing.retain(|f| *f != "member_user_ids");
         self
     }
 
@@ -139,3 +247,27 @@ impl AsRef<ChatInviteLinkInfo> for RTDChatInviteLinkInfoBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            ChatInviteLinkInfo::builder()
+                .chat_id(9007199254740991i64)
+                .type_(ChatType::BasicGroup(
+                    ChatTypeBasicGroup::builder()
+                        .basic_group_id(i32::MAX)
+                        .build(),
+                ))
+                .title("text")
+                .member_count(i32::MAX)
+                .member_user_ids(vec![Default::default()])
+                .is_public(true)
+                .build(),
+        );
+    }
+}
@@ -1,43 +1,40 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Describes a list of chats
 pub trait TDChatList: Debug + RObject {}
 
 /// Describes a list of chats
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum ChatList {
-    #[doc(hidden)]
-    _Default(()),
     /// A list of chats usually located at the top of the main chat list. Unmuted chats are automatically moved from the Archive to the Main chat list when a new message arrives
+    #[serde(rename = "chatListArchive")]
     Archive(ChatListArchive),
     /// A main list of chats
+    #[serde(rename = "chatListMain")]
     Main(ChatListMain),
 }
 
+/// Default value is default of [ChatListArchive], builders still require the field to be set
 impl Default for ChatList {
     fn default() -> Self {
-        ChatList::_Default(())
+        ChatList::Archive(ChatListArchive::default())
     }
 }
 
-impl<'de> Deserialize<'de> for ChatList {
-    fn deserialize<D>(deserializer: D) -> Result<ChatList, D::Error>
+impl Serialize for ChatList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          ChatList,
-          (chatListArchive, Archive);
-          (chatListMain, Main);
-
-        )(deserializer)
+        match self {
+            ChatList::Archive(t) => t.serialize(serializer),
+            ChatList::Main(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -47,8 +44,6 @@ impl RObject for ChatList {
         match self {
             ChatList::Archive(t) => t.td_name(),
             ChatList::Main(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -56,8 +51,6 @@ impl RObject for ChatList {
         match self {
             ChatList::Archive(t) => t.extra(),
             ChatList::Main(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -69,10 +62,6 @@ impl ChatList {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, ChatList::_Default(_))
-    }
 }
 
 impl AsRef<ChatList> for ChatList {
@@ -83,12 +72,13 @@ impl AsRef<ChatList> for ChatList {
 
 /// A list of chats usually located at the top of the main chat list. Unmuted chats are automatically moved from the Archive to the Main chat list when a new message arrives
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatListArchive")]
 pub struct ChatListArchive {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -106,6 +96,18 @@ impl RObject for ChatListArchive {
     }
 }
 
+impl PartialEq for ChatListArchive {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ChatListArchive {}
+
+impl std::hash::Hash for ChatListArchive {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDChatList for ChatListArchive {}
 
 impl ChatListArchive {
@@ -113,22 +115,49 @@ impl ChatListArchive {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatListArchiveBuilder {
-        let mut inner = ChatListArchive::default();
-        inner.td_name = "chatListArchive".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatListArchiveBuilder { inner }
+        RTDChatListArchiveBuilder {
+            inner: ChatListArchive::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatListArchiveBuilder {
+        RTDChatListArchiveBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatListArchiveBuilder {
+        RTDChatListArchiveBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDChatListArchiveBuilder {
     inner: ChatListArchive,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatListArchiveBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatListArchive {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatListArchive> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatListArchive",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<ChatListArchive> for ChatListArchive {
@@ -145,12 +174,13 @@ impl AsRef<ChatListArchive> for RTDChatListArchiveBuilder {
 
 /// A main list of chats
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatListMain")]
 pub struct ChatListMain {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -168,6 +198,18 @@ impl RObject for ChatListMain {
     }
 }
 
+impl PartialEq for ChatListMain {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ChatListMain {}
+
+impl std::hash::Hash for ChatListMain {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDChatList for ChatListMain {}
 
 impl ChatListMain {
@@ -175,22 +217,49 @@ impl ChatListMain {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatListMainBuilder {
-        let mut inner = ChatListMain::default();
-        inner.td_name = "chatListMain".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatListMainBuilder { inner }
+        RTDChatListMainBuilder {
+            inner: ChatListMain::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatListMainBuilder {
+        RTDChatListMainBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatListMainBuilder {
+        RTDChatListMainBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDChatListMainBuilder {
     inner: ChatListMain,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatListMainBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatListMain {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatListMain> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatListMain",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<ChatListMain> for ChatListMain {
@@ -204,3 +273,15 @@ impl AsRef<ChatListMain> for RTDChatListMainBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(ChatList::Archive(ChatListArchive::builder().build()));
+        assert_round_trip(ChatList::Main(ChatListMain::builder().build()));
+    }
+}
@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Represents a location to which a chat is connected
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatLocation")]
 pub struct ChatLocation {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The location
     location: Location,
@@ -31,15 +31,35 @@ impl RObject for ChatLocation {
     }
 }
 
+impl PartialEq for ChatLocation {
+    fn eq(&self, other: &Self) -> bool {
+        self.location == other.location && self.address == other.address
+    }
+}
+
 impl ChatLocation {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatLocationBuilder {
-        let mut inner = ChatLocation::default();
-        inner.td_name = "chatLocation".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatLocationBuilder { inner }
+        RTDChatLocationBuilder {
+            inner: ChatLocation::default(),
+            missing: vec!["location", "address"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatLocationBuilder {
+        RTDChatLocationBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatLocationBuilder {
+        RTDChatLocationBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn location(&self) -> &Location {
@@ -49,25 +69,58 @@ impl ChatLocation {
     pub fn address(&self) -> &String {
         &self.address
     }
+
+    pub fn into_location(self) -> Location {
+        self.location
+    }
+
+    pub fn into_address(self) -> String {
+        self.address
+    }
+
+    pub fn with_location<T: AsRef<Location>>(mut self, location: T) -> Self {
+        self.location = location.as_ref().clone();
+        self
+    }
+
+    pub fn with_address<T: AsRef<str>>(mut self, address: T) -> Self {
+        self.address = address.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDChatLocationBuilder {
     inner: ChatLocation,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatLocationBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatLocation {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatLocation> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatLocation",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn location<T: AsRef<Location>>(&mut self, location: T) -> &mut Self {
         self.inner.location = location.as_ref().clone();
+        self.missing.retain(|f| *f != "location");
         self
     }
 
     pub fn address<T: AsRef<str>>(&mut self, address: T) -> &mut Self {
         self.inner.address = address.as_ref().to_string();
+        self.missing.retain(|f| *f != "address");
         self
     }
 }
@@ -83,3 +136,19 @@ impl AsRef<ChatLocation> for RTDChatLocationBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            ChatLocation::builder()
+                .location(Location::builder().latitude(1.5).longitude(1.5).build())
+                .address("text")
+                .build(),
+        );
+    }
+}
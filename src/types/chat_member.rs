@@ -1,22 +1,22 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// A user with information about joining/leaving a chat
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatMember")]
 pub struct ChatMember {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// User identifier of the chat member
-    user_id: i64,
+    user_id: UserId,
     /// Identifier of a user that invited/promoted/banned this member in the chat; 0 if unknown
-    inviter_user_id: i64,
+    inviter_user_id: UserId,
     /// Point in time (Unix timestamp) when the user joined a chat
-    joined_chat_date: i64,
+    joined_chat_date: i32,
     /// Status of the member in the chat
     status: ChatMemberStatus,
     /// If the user is a bot, information about the bot; may be null. Can be null even for a bot if the bot is not a chat member
@@ -37,26 +37,62 @@ impl RObject for ChatMember {
     }
 }
 
+impl PartialEq for ChatMember {
+    fn eq(&self, other: &Self) -> bool {
+        self.user_id == other.user_id
+            && self.inviter_user_id == other.inviter_user_id
+            && self.joined_chat_date == other.joined_chat_date
+            && self.status == other.status
+            && self.bot_info == other.bot_info
+    }
+}
+
+impl Eq for ChatMember {}
+
+impl std::hash::Hash for ChatMember {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.user_id, state);
+        std::hash::Hash::hash(&self.inviter_user_id, state);
+        std::hash::Hash::hash(&self.joined_chat_date, state);
+        std::hash::Hash::hash(&self.status, state);
+        std::hash::Hash::hash(&self.bot_info, state);
+    }
+}
+
 impl ChatMember {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatMemberBuilder {
-        let mut inner = ChatMember::default();
-        inner.td_name = "chatMember".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatMemberBuilder { inner }
-    }
-
-    pub fn user_id(&self) -> i64 {
+        RTDChatMemberBuilder {
+            inner: ChatMember::default(),
+            missing: vec!["user_id", "joined_chat_date", "status"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatMemberBuilder {
+        RTDChatMemberBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatMemberBuilder {
+        RTDChatMemberBuilder {
+            inner: self,
+            missing: vec![],
+        }
+    }
+
+    pub fn user_id(&self) -> UserId {
         self.user_id
     }
 
-    pub fn inviter_user_id(&self) -> i64 {
+    pub fn inviter_user_id(&self) -> UserId {
         self.inviter_user_id
     }
 
-    pub fn joined_chat_date(&self) -> i64 {
+    pub fn joined_chat_date(&self) -> i32 {
         self.joined_chat_date
     }
 
@@ -67,35 +103,81 @@ impl ChatMember {
     pub fn bot_info(&self) -> &Option<BotInfo> {
         &self.bot_info
     }
+
+    pub fn into_status(self) -> ChatMemberStatus {
+        self.status
+    }
+
+    pub fn into_bot_info(self) -> Option<BotInfo> {
+        self.bot_info
+    }
+
+    pub fn with_user_id<T: Into<UserId>>(mut self, user_id: T) -> Self {
+        self.user_id = user_id.into();
+        self
+    }
+
+    pub fn with_inviter_user_id<T: Into<UserId>>(mut self, inviter_user_id: T) -> Self {
+        self.inviter_user_id = inviter_user_id.into();
+        self
+    }
+
+    pub fn with_joined_chat_date(mut self, joined_chat_date: i32) -> Self {
+        self.joined_chat_date = joined_chat_date;
+        self
+    }
+
+    pub fn with_status<T: AsRef<ChatMemberStatus>>(mut self, status: T) -> Self {
+        self.status = status.as_ref().clone();
+        self
+    }
+
+    pub fn with_bot_info<T: AsRef<BotInfo>>(mut self, bot_info: T) -> Self {
+        self.bot_info = Some(bot_info.as_ref().clone());
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDChatMemberBuilder {
     inner: ChatMember,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatMemberBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatMember {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatMember> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("ChatMember", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn user_id(&mut self, user_id: i64) -> &mut Self {
-        self.inner.user_id = user_id;
+    pub fn user_id<T: Into<UserId>>(&mut self, user_id: T) -> &mut Self {
+        self.inner.user_id = user_id.into();
+        self.missing.retain(|f| *f != "user_id");
         self
     }
 
-    pub fn inviter_user_id(&mut self, inviter_user_id: i64) -> &mut Self {
-        self.inner.inviter_user_id = inviter_user_id;
+    pub fn inviter_user_id<T: Into<UserId>>(&mut self, inviter_user_id: T) -> &mut Self {
+        self.inner.inviter_user_id = inviter_user_id.into();
         self
     }
 
-    pub fn joined_chat_date(&mut self, joined_chat_date: i64) -> &mut Self {
+    pub fn joined_chat_date(&mut self, joined_chat_date: i32) -> &mut Self {
         self.inner.joined_chat_date = joined_chat_date;
+        self.missing.retain(|f| *f != "joined_chat_date");
         self
     }
 
     pub fn status<T: AsRef<ChatMemberStatus>>(&mut self, status: T) -> &mut Self {
         self.inner.status = status.as_ref().clone();
+        self.missing.retain(|f| *f != "status");
         self
     }
 
@@ -116,3 +198,34 @@ impl AsRef<ChatMember> for RTDChatMemberBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            ChatMember::builder()
+                .user_id(i32::MAX)
+                .inviter_user_id(i32::MAX)
+                .joined_chat_date(i32::MAX)
+                .status(ChatMemberStatus::Administrator(
+                    ChatMemberStatusAdministrator::builder()
+                        .custom_title("text")
+                        .can_be_edited(true)
+                        .can_change_info(true)
+                        .can_post_messages(true)
+                        .can_edit_messages(true)
+                        .can_delete_messages(true)
+                        .can_invite_users(true)
+                        .can_restrict_members(true)
+                        .can_pin_messages(true)
+                        .can_promote_members(true)
+                        .build(),
+                ))
+                .build(),
+        );
+    }
+}
@@ -1,55 +1,56 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Provides information about the status of a member in a chat
 pub trait TDChatMemberStatus: Debug + RObject {}
 
 /// Provides information about the status of a member in a chat
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum ChatMemberStatus {
-    #[doc(hidden)]
-    _Default(()),
     /// The user is a member of a chat and has some additional privileges. In basic groups, administrators can edit and delete messages sent by others, add new members, and ban unprivileged members. In supergroups and channels, there are more detailed options for administrator privileges
+    #[serde(rename = "chatMemberStatusAdministrator")]
     Administrator(ChatMemberStatusAdministrator),
     /// The user was banned (and hence is not a member of the chat). Implies the user can't return to the chat or view messages
+    #[serde(rename = "chatMemberStatusBanned")]
     Banned(ChatMemberStatusBanned),
     /// The user is the owner of a chat and has all the administrator privileges
+    #[serde(rename = "chatMemberStatusCreator")]
     Creator(ChatMemberStatusCreator),
     /// The user is not a chat member
+    #[serde(rename = "chatMemberStatusLeft")]
     Left(ChatMemberStatusLeft),
     /// The user is a member of a chat, without any additional privileges or restrictions
+    #[serde(rename = "chatMemberStatusMember")]
     Member(ChatMemberStatusMember),
     /// The user is under certain restrictions in the chat. Not supported in basic groups and channels
+    #[serde(rename = "chatMemberStatusRestricted")]
     Restricted(ChatMemberStatusRestricted),
 }
 
+/// Default value is default of [ChatMemberStatusAdministrator], builders still require the field to be set
 impl Default for ChatMemberStatus {
     fn default() -> Self {
-        ChatMemberStatus::_Default(())
+        ChatMemberStatus::Administrator(ChatMemberStatusAdministrator::default())
     }
 }
 
-impl<'de> Deserialize<'de> for ChatMemberStatus {
-    fn deserialize<D>(deserializer: D) -> Result<ChatMemberStatus, D::Error>
+impl Serialize for ChatMemberStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          ChatMemberStatus,
-          (chatMemberStatusAdministrator, Administrator);
-          (chatMemberStatusBanned, Banned);
-          (chatMemberStatusCreator, Creator);
-          (chatMemberStatusLeft, Left);
-          (chatMemberStatusMember, Member);
-          (chatMemberStatusRestricted, Restricted);
-
-        )(deserializer)
+        match self {
+            ChatMemberStatus::Administrator(t) => t.serialize(serializer),
+            ChatMemberStatus::Banned(t) => t.serialize(serializer),
+            ChatMemberStatus::Creator(t) => t.serialize(serializer),
+            ChatMemberStatus::Left(t) => t.serialize(serializer),
+            ChatMemberStatus::Member(t) => t.serialize(serializer),
+            ChatMemberStatus::Restricted(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -63,8 +64,6 @@ impl RObject for ChatMemberStatus {
             ChatMemberStatus::Left(t) => t.td_name(),
             ChatMemberStatus::Member(t) => t.td_name(),
             ChatMemberStatus::Restricted(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -76,8 +75,6 @@ impl RObject for ChatMemberStatus {
             ChatMemberStatus::Left(t) => t.extra(),
             ChatMemberStatus::Member(t) => t.extra(),
             ChatMemberStatus::Restricted(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -89,10 +86,6 @@ impl ChatMemberStatus {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, ChatMemberStatus::_Default(_))
-    }
 }
 
 impl AsRef<ChatMemberStatus> for ChatMemberStatus {
@@ -103,12 +96,13 @@ impl AsRef<ChatMemberStatus> for ChatMemberStatus {
 
 /// The user is a member of a chat and has some additional privileges. In basic groups, administrators can edit and delete messages sent by others, add new members, and ban unprivileged members. In supergroups and channels, there are more detailed options for administrator privileges
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatMemberStatusAdministrator")]
 pub struct ChatMemberStatusAdministrator {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// A custom title of the administrator; 0-16 characters without emojis; applicable to supergroups only
     custom_title: String,
@@ -146,6 +140,38 @@ impl RObject for ChatMemberStatusAdministrator {
     }
 }
 
+impl PartialEq for ChatMemberStatusAdministrator {
+    fn eq(&self, other: &Self) -> bool {
+        self.custom_title == other.custom_title
+            && self.can_be_edited == other.can_be_edited
+            && self.can_change_info == other.can_change_info
+            && self.can_post_messages == other.can_post_messages
+            && self.can_edit_messages == other.can_edit_messages
+            && self.can_delete_messages == other.can_delete_messages
+            && self.can_invite_users == other.can_invite_users
+            && self.can_restrict_members == other.can_restrict_members
+            && self.can_pin_messages == other.can_pin_messages
+            && self.can_promote_members == other.can_promote_members
+    }
+}
+
+impl Eq for ChatMemberStatusAdministrator {}
+
+impl std::hash::Hash for ChatMemberStatusAdministrator {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.custom_title, state);
+        std::hash::Hash::hash(&self.can_be_edited, state);
+        std::hash::Hash::hash(&self.can_change_info, state);
+        std::hash::Hash::hash(&self.can_post_messages, state);
+        std::hash::Hash::hash(&self.can_edit_messages, state);
+        std::hash::Hash::hash(&self.can_delete_messages, state);
+        std::hash::Hash::hash(&self.can_invite_users, state);
+        std::hash::Hash::hash(&self.can_restrict_members, state);
+        std::hash::Hash::hash(&self.can_pin_messages, state);
+        std::hash::Hash::hash(&self.can_promote_members, state);
+    }
+}
+
 impl TDChatMemberStatus for ChatMemberStatusAdministrator {}
 
 impl ChatMemberStatusAdministrator {
@@ -153,10 +179,24 @@ impl ChatMemberStatusAdministrator {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatMemberStatusAdministratorBuilder {
-        let mut inner = ChatMemberStatusAdministrator::default();
-        inner.td_name = "chatMemberStatusAdministrator".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatMemberStatusAdministratorBuilder { inner }
+        RTDChatMemberStatusAdministratorBuilder {
+            inner: ChatMemberStatusAdministrator::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatMemberStatusAdministratorBuilder {
+        RTDChatMemberStatusAdministratorBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatMemberStatusAdministratorBuilder {
+        RTDChatMemberStatusAdministratorBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn custom_title(&self) -> &String {
@@ -198,17 +238,84 @@ impl ChatMemberStatusAdministrator {
     pub fn can_promote_members(&self) -> bool {
         self.can_promote_members
     }
+
+    pub fn into_custom_title(self) -> String {
+        self.custom_title
+    }
+
+    pub fn with_custom_title<T: AsRef<str>>(mut self, custom_title: T) -> Self {
+        self.custom_title = custom_title.as_ref().to_string();
+        self
+    }
+
+    pub fn with_can_be_edited(mut self, can_be_edited: bool) -> Self {
+        self.can_be_edited = can_be_edited;
+        self
+    }
+
+    pub fn with_can_change_info(mut self, can_change_info: bool) -> Self {
+        self.can_change_info = can_change_info;
+        self
+    }
+
+    pub fn with_can_post_messages(mut self, can_post_messages: bool) -> Self {
+        self.can_post_messages = can_post_messages;
+        self
+    }
+
+    pub fn with_can_edit_messages(mut self, can_edit_messages: bool) -> Self {
+        self.can_edit_messages = can_edit_messages;
+        self
+    }
+
+    pub fn with_can_delete_messages(mut self, can_delete_messages: bool) -> Self {
+        self.can_delete_messages = can_delete_messages;
+        self
+    }
+
+    pub fn with_can_invite_users(mut self, can_invite_users: bool) -> Self {
+        self.can_invite_users = can_invite_users;
+        self
+    }
+
+    pub fn with_can_restrict_members(mut self, can_restrict_members: bool) -> Self {
+        self.can_restrict_members = can_restrict_members;
+        self
+    }
+
+    pub fn with_can_pin_messages(mut self, can_pin_messages: bool) -> Self {
+        self.can_pin_messages = can_pin_messages;
+        self
+    }
+
+    pub fn with_can_promote_members(mut self, can_promote_members: bool) -> Self {
+        self.can_promote_members = can_promote_members;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDChatMemberStatusAdministratorBuilder {
     inner: ChatMemberStatusAdministrator,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatMemberStatusAdministratorBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatMemberStatusAdministrator {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatMemberStatusAdministrator> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatMemberStatusAdministrator",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn custom_title<T: AsRef<str>>(&mut self, custom_title: T) -> &mut Self {
         self.inner.custom_title = custom_title.as_ref().to_string();
@@ -275,15 +382,16 @@ impl AsRef<ChatMemberStatusAdministrator> for RTDChatMemberStatusAdministratorBu
 
 /// The user was banned (and hence is not a member of the chat). Implies the user can't return to the chat or view messages
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatMemberStatusBanned")]
 pub struct ChatMemberStatusBanned {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Point in time (Unix timestamp) when the user will be unbanned; 0 if never. If the user is banned for more than 366 days or for less than 30 seconds from the current time, the user is considered to be banned forever
-    banned_until_date: i64,
+    banned_until_date: i32,
 }
 
 impl RObject for ChatMemberStatusBanned {
@@ -300,6 +408,20 @@ impl RObject for ChatMemberStatusBanned {
     }
 }
 
+impl PartialEq for ChatMemberStatusBanned {
+    fn eq(&self, other: &Self) -> bool {
+        self.banned_until_date == other.banned_until_date
+    }
+}
+
+impl Eq for ChatMemberStatusBanned {}
+
+impl std::hash::Hash for ChatMemberStatusBanned {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.banned_until_date, state);
+    }
+}
+
 impl TDChatMemberStatus for ChatMemberStatusBanned {}
 
 impl ChatMemberStatusBanned {
@@ -307,28 +429,60 @@ impl ChatMemberStatusBanned {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatMemberStatusBannedBuilder {
-        let mut inner = ChatMemberStatusBanned::default();
-        inner.td_name = "chatMemberStatusBanned".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatMemberStatusBannedBuilder { inner }
+        RTDChatMemberStatusBannedBuilder {
+            inner: ChatMemberStatusBanned::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatMemberStatusBannedBuilder {
+        RTDChatMemberStatusBannedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatMemberStatusBannedBuilder {
+        RTDChatMemberStatusBannedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn banned_until_date(&self) -> i64 {
+    pub fn banned_until_date(&self) -> i32 {
         self.banned_until_date
     }
+
+    pub fn with_banned_until_date(mut self, banned_until_date: i32) -> Self {
+        self.banned_until_date = banned_until_date;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDChatMemberStatusBannedBuilder {
     inner: ChatMemberStatusBanned,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatMemberStatusBannedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatMemberStatusBanned {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatMemberStatusBanned> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatMemberStatusBanned",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn banned_until_date(&mut self, banned_until_date: i64) -> &mut Self {
+    pub fn banned_until_date(&mut self, banned_until_date: i32) -> &mut Self {
         self.inner.banned_until_date = banned_until_date;
         self
     }
@@ -348,12 +502,13 @@ impl AsRef<ChatMemberStatusBanned> for RTDChatMemberStatusBannedBuilder {
 
 /// The user is the owner of a chat and has all the administrator privileges
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatMemberStatusCreator")]
 pub struct ChatMemberStatusCreator {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// A custom title of the owner; 0-16 characters without emojis; applicable to supergroups only
     custom_title: String,
@@ -375,6 +530,21 @@ impl RObject for ChatMemberStatusCreator {
     }
 }
 
+impl PartialEq for ChatMemberStatusCreator {
+    fn eq(&self, other: &Self) -> bool {
+        self.custom_title == other.custom_title && self.is_member == other.is_member
+    }
+}
+
+impl Eq for ChatMemberStatusCreator {}
+
+impl std::hash::Hash for ChatMemberStatusCreator {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.custom_title, state);
+        std::hash::Hash::hash(&self.is_member, state);
+    }
+}
+
 impl TDChatMemberStatus for ChatMemberStatusCreator {}
 
 impl ChatMemberStatusCreator {
@@ -382,10 +552,24 @@ impl ChatMemberStatusCreator {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatMemberStatusCreatorBuilder {
-        let mut inner = ChatMemberStatusCreator::default();
-        inner.td_name = "chatMemberStatusCreator".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatMemberStatusCreatorBuilder { inner }
+        RTDChatMemberStatusCreatorBuilder {
+            inner: ChatMemberStatusCreator::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatMemberStatusCreatorBuilder {
+        RTDChatMemberStatusCreatorBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatMemberStatusCreatorBuilder {
+        RTDChatMemberStatusCreatorBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn custom_title(&self) -> &String {
@@ -395,17 +579,44 @@ impl ChatMemberStatusCreator {
     pub fn is_member(&self) -> bool {
         self.is_member
     }
+
+    pub fn into_custom_title(self) -> String {
+        self.custom_title
+    }
+
+    pub fn with_custom_title<T: AsRef<str>>(mut self, custom_title: T) -> Self {
+        self.custom_title = custom_title.as_ref().to_string();
+        self
+    }
+
+    pub fn with_is_member(mut self, is_member: bool) -> Self {
+        self.is_member = is_member;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDChatMemberStatusCreatorBuilder {
     inner: ChatMemberStatusCreator,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatMemberStatusCreatorBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatMemberStatusCreator {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatMemberStatusCreator> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatMemberStatusCreator",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn custom_title<T: AsRef<str>>(&mut self, custom_title: T) -> &mut Self {
         self.inner.custom_title = custom_title.as_ref().to_string();
@@ -432,12 +643,13 @@ impl AsRef<ChatMemberStatusCreator> for RTDChatMemberStatusCreatorBuilder {
 
 /// The user is not a chat member
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatMemberStatusLeft")]
 pub struct ChatMemberStatusLeft {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -455,6 +667,18 @@ impl RObject for ChatMemberStatusLeft {
     }
 }
 
+impl PartialEq for ChatMemberStatusLeft {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ChatMemberStatusLeft {}
+
+impl std::hash::Hash for ChatMemberStatusLeft {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDChatMemberStatus for ChatMemberStatusLeft {}
 
 impl ChatMemberStatusLeft {
@@ -462,22 +686,49 @@ impl ChatMemberStatusLeft {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatMemberStatusLeftBuilder {
-        let mut inner = ChatMemberStatusLeft::default();
-        inner.td_name = "chatMemberStatusLeft".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatMemberStatusLeftBuilder { inner }
+        RTDChatMemberStatusLeftBuilder {
+            inner: ChatMemberStatusLeft::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatMemberStatusLeftBuilder {
+        RTDChatMemberStatusLeftBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatMemberStatusLeftBuilder {
+        RTDChatMemberStatusLeftBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDChatMemberStatusLeftBuilder {
     inner: ChatMemberStatusLeft,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatMemberStatusLeftBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatMemberStatusLeft {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatMemberStatusLeft> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatMemberStatusLeft",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<ChatMemberStatusLeft> for ChatMemberStatusLeft {
@@ -494,12 +745,13 @@ impl AsRef<ChatMemberStatusLeft> for RTDChatMemberStatusLeftBuilder {
 
 /// The user is a member of a chat, without any additional privileges or restrictions
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatMemberStatusMember")]
 pub struct ChatMemberStatusMember {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -517,6 +769,18 @@ impl RObject for ChatMemberStatusMember {
     }
 }
 
+impl PartialEq for ChatMemberStatusMember {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ChatMemberStatusMember {}
+
+impl std::hash::Hash for ChatMemberStatusMember {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDChatMemberStatus for ChatMemberStatusMember {}
 
 impl ChatMemberStatusMember {
@@ -524,22 +788,49 @@ impl ChatMemberStatusMember {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatMemberStatusMemberBuilder {
-        let mut inner = ChatMemberStatusMember::default();
-        inner.td_name = "chatMemberStatusMember".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatMemberStatusMemberBuilder { inner }
+        RTDChatMemberStatusMemberBuilder {
+            inner: ChatMemberStatusMember::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatMemberStatusMemberBuilder {
+        RTDChatMemberStatusMemberBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatMemberStatusMemberBuilder {
+        RTDChatMemberStatusMemberBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDChatMemberStatusMemberBuilder {
     inner: ChatMemberStatusMember,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatMemberStatusMemberBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatMemberStatusMember {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatMemberStatusMember> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatMemberStatusMember",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<ChatMemberStatusMember> for ChatMemberStatusMember {
@@ -556,17 +847,18 @@ impl AsRef<ChatMemberStatusMember> for RTDChatMemberStatusMemberBuilder {
 
 /// The user is under certain restrictions in the chat. Not supported in basic groups and channels
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatMemberStatusRestricted")]
 pub struct ChatMemberStatusRestricted {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// True, if the user is a member of the chat
     is_member: bool,
     /// Point in time (Unix timestamp) when restrictions will be lifted from the user; 0 if never. If the user is restricted for more than 366 days or for less than 30 seconds from the current time, the user is considered to be restricted forever
-    restricted_until_date: i64,
+    restricted_until_date: i32,
     /// User permissions in the chat
     permissions: ChatPermissions,
 }
@@ -585,6 +877,24 @@ impl RObject for ChatMemberStatusRestricted {
     }
 }
 
+impl PartialEq for ChatMemberStatusRestricted {
+    fn eq(&self, other: &Self) -> bool {
+        self.is_member == other.is_member
+            && self.restricted_until_date == other.restricted_until_date
+            && self.permissions == other.permissions
+    }
+}
+
+impl Eq for ChatMemberStatusRestricted {}
+
+impl std::hash::Hash for ChatMemberStatusRestricted {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.is_member, state);
+        std::hash::Hash::hash(&self.restricted_until_date, state);
+        std::hash::Hash::hash(&self.permissions, state);
+    }
+}
+
 impl TDChatMemberStatus for ChatMemberStatusRestricted {}
 
 impl ChatMemberStatusRestricted {
@@ -592,47 +902,94 @@ impl ChatMemberStatusRestricted {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatMemberStatusRestrictedBuilder {
-        let mut inner = ChatMemberStatusRestricted::default();
-        inner.td_name = "chatMemberStatusRestricted".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatMemberStatusRestrictedBuilder { inner }
+        RTDChatMemberStatusRestrictedBuilder {
+            inner: ChatMemberStatusRestricted::default(),
+            missing: vec!["permissions"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatMemberStatusRestrictedBuilder {
+        RTDChatMemberStatusRestrictedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatMemberStatusRestrictedBuilder {
+        RTDChatMemberStatusRestrictedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn is_member(&self) -> bool {
         self.is_member
     }
 
-    pub fn restricted_until_date(&self) -> i64 {
+    pub fn restricted_until_date(&self) -> i32 {
         self.restricted_until_date
     }
 
     pub fn permissions(&self) -> &ChatPermissions {
         &self.permissions
     }
+
+    pub fn into_permissions(self) -> ChatPermissions {
+        self.permissions
+    }
+
+    pub fn with_is_member(mut self, is_member: bool) -> Self {
+        self.is_member = is_member;
+        self
+    }
+
+    pub fn with_restricted_until_date(mut self, restricted_until_date: i32) -> Self {
+        self.restricted_until_date = restricted_until_date;
+        self
+    }
+
+    pub fn with_permissions<T: AsRef<ChatPermissions>>(mut self, permissions: T) -> Self {
+        self.permissions = permissions.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDChatMemberStatusRestrictedBuilder {
     inner: ChatMemberStatusRestricted,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatMemberStatusRestrictedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatMemberStatusRestricted {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatMemberStatusRestricted> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatMemberStatusRestricted",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn is_member(&mut self, is_member: bool) -> &mut Self {
         self.inner.is_member = is_member;
         self
     }
 
-    pub fn restricted_until_date(&mut self, restricted_until_date: i64) -> &mut Self {
+    pub fn restricted_until_date(&mut self, restricted_until_date: i32) -> &mut Self {
         self.inner.restricted_until_date = restricted_until_date;
         self
     }
 
     pub fn permissions<T: AsRef<ChatPermissions>>(&mut self, permissions: T) -> &mut Self {
         self.inner.permissions = permissions.as_ref().clone();
+        self.missing.retain(|f| *f != "permissions");
         self
     }
 }
@@ -648,3 +1005,62 @@ impl AsRef<ChatMemberStatusRestricted> for RTDChatMemberStatusRestrictedBuilder
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(ChatMemberStatus::Administrator(
+            ChatMemberStatusAdministrator::builder()
+                .custom_title("text")
+                .can_be_edited(true)
+                .can_change_info(true)
+                .can_post_messages(true)
+                .can_edit_messages(true)
+                .can_delete_messages(true)
+                .can_invite_users(true)
+                .can_restrict_members(true)
+                .can_pin_messages(true)
+                .can_promote_members(true)
+                .build(),
+        ));
+        assert_round_trip(ChatMemberStatus::Banned(
+            ChatMemberStatusBanned::builder()
+                .banned_until_date(i32::MAX)
+                .build(),
+        ));
+        assert_round_trip(ChatMemberStatus::Creator(
+            ChatMemberStatusCreator::builder()
+                .custom_title("text")
+                .is_member(true)
+                .build(),
+        ));
+        assert_round_trip(ChatMemberStatus::Left(
+            ChatMemberStatusLeft::builder().build(),
+        ));
+        assert_round_trip(ChatMemberStatus::Member(
+            ChatMemberStatusMember::builder().build(),
+        ));
+        assert_round_trip(ChatMemberStatus::Restricted(
+            ChatMemberStatusRestricted::builder()
+                .is_member(true)
+                .restricted_until_date(i32::MAX)
+                .permissions(
+                    ChatPermissions::builder()
+                        .can_send_messages(true)
+                        .can_send_media_messages(true)
+                        .can_send_polls(true)
+                        .can_send_other_messages(true)
+                        .can_add_web_page_previews(true)
+                        .can_change_info(true)
+                        .can_invite_users(true)
+                        .can_pin_messages(true)
+                        .build(),
+                )
+                .build(),
+        ));
+    }
+}
@@ -1,18 +1,18 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains a list of chat members
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatMembers")]
 pub struct ChatMembers {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Approximate total count of chat members found
-    total_count: i64,
+    total_count: i32,
     /// A list of chat members
     members: Vec<ChatMember>,
 }
@@ -31,43 +31,98 @@ impl RObject for ChatMembers {
     }
 }
 
+impl PartialEq for ChatMembers {
+    fn eq(&self, other: &Self) -> bool {
+        self.total_count == other.total_count && self.members == other.members
+    }
+}
+
+impl Eq for ChatMembers {}
+
+impl std::hash::Hash for ChatMembers {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.total_count, state);
+        std::hash::Hash::hash(&self.members, state);
+    }
+}
+
 impl ChatMembers {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatMembersBuilder {
-        let mut inner = ChatMembers::default();
-        inner.td_name = "chatMembers".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatMembersBuilder { inner }
+        RTDChatMembersBuilder {
+            inner: ChatMembers::default(),
+            missing: vec!["total_count", "members"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatMembersBuilder {
+        RTDChatMembersBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatMembersBuilder {
+        RTDChatMembersBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn total_count(&self) -> i64 {
+    pub fn total_count(&self) -> i32 {
         self.total_count
     }
 
     pub fn members(&self) -> &Vec<ChatMember> {
         &self.members
     }
+
+    pub fn into_members(self) -> Vec<ChatMember> {
+        self.members
+    }
+
+    pub fn with_total_count(mut self, total_count: i32) -> Self {
+        self.total_count = total_count;
+        self
+    }
+
+    pub fn with_members(mut self, members: Vec<ChatMember>) -> Self {
+        self.members = members;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDChatMembersBuilder {
     inner: ChatMembers,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatMembersBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatMembers {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatMembers> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("ChatMembers", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn total_count(&mut self, total_count: i64) -> &mut Self {
+    pub fn total_count(&mut self, total_count: i32) -> &mut Self {
         self.inner.total_count = total_count;
+        self.missing.retain(|f| *f != "total_count");
         self
     }
 
     pub fn members(&mut self, members: Vec<ChatMember>) -> &mut Self {
         self.inner.members = members;
+        self.missing.retain(|f| *f != "members");
         self
     }
 }
@@ -83,3 +138,19 @@ impl AsRef<ChatMembers> for RTDChatMembersBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            ChatMembers::builder()
+                .total_count(i32::MAX)
+                .members(vec![])
+                .build(),
+        );
+    }
+}
@@ -1,55 +1,56 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Specifies the kind of chat members to return in searchChatMembers
 pub trait TDChatMembersFilter: Debug + RObject {}
 
 /// Specifies the kind of chat members to return in searchChatMembers
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum ChatMembersFilter {
-    #[doc(hidden)]
-    _Default(()),
     /// Returns the owner and administrators
+    #[serde(rename = "chatMembersFilterAdministrators")]
     Administrators(ChatMembersFilterAdministrators),
     /// Returns users banned from the chat; can be used only by administrators in a supergroup or in a channel
+    #[serde(rename = "chatMembersFilterBanned")]
     Banned(ChatMembersFilterBanned),
     /// Returns bot members of the chat
+    #[serde(rename = "chatMembersFilterBots")]
     Bots(ChatMembersFilterBots),
     /// Returns contacts of the user
+    #[serde(rename = "chatMembersFilterContacts")]
     Contacts(ChatMembersFilterContacts),
     /// Returns all chat members, including restricted chat members
+    #[serde(rename = "chatMembersFilterMembers")]
     Members(ChatMembersFilterMembers),
     /// Returns users under certain restrictions in the chat; can be used only by administrators in a supergroup
+    #[serde(rename = "chatMembersFilterRestricted")]
     Restricted(ChatMembersFilterRestricted),
 }
 
+/// Default value is default of [ChatMembersFilterAdministrators], builders still require the field to be set
 impl Default for ChatMembersFilter {
     fn default() -> Self {
-        ChatMembersFilter::_Default(())
+        ChatMembersFilter::Administrators(ChatMembersFilterAdministrators::default())
     }
 }
 
-impl<'de> Deserialize<'de> for ChatMembersFilter {
-    fn deserialize<D>(deserializer: D) -> Result<ChatMembersFilter, D::Error>
+impl Serialize for ChatMembersFilter {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          ChatMembersFilter,
-          (chatMembersFilterAdministrators, Administrators);
-          (chatMembersFilterBanned, Banned);
-          (chatMembersFilterBots, Bots);
-          (chatMembersFilterContacts, Contacts);
-          (chatMembersFilterMembers, Members);
-          (chatMembersFilterRestricted, Restricted);
-
-        )(deserializer)
+        match self {
+            ChatMembersFilter::Administrators(t) => t.serialize(serializer),
+            ChatMembersFilter::Banned(t) => t.serialize(serializer),
+            ChatMembersFilter::Bots(t) => t.serialize(serializer),
+            ChatMembersFilter::Contacts(t) => t.serialize(serializer),
+            ChatMembersFilter::Members(t) => t.serialize(serializer),
+            ChatMembersFilter::Restricted(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -63,8 +64,6 @@ impl RObject for ChatMembersFilter {
             ChatMembersFilter::Contacts(t) => t.td_name(),
             ChatMembersFilter::Members(t) => t.td_name(),
             ChatMembersFilter::Restricted(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -76,8 +75,6 @@ impl RObject for ChatMembersFilter {
             ChatMembersFilter::Contacts(t) => t.extra(),
             ChatMembersFilter::Members(t) => t.extra(),
             ChatMembersFilter::Restricted(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -89,10 +86,6 @@ impl ChatMembersFilter {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, ChatMembersFilter::_Default(_))
-    }
 }
 
 impl AsRef<ChatMembersFilter> for ChatMembersFilter {
@@ -103,12 +96,13 @@ impl AsRef<ChatMembersFilter> for ChatMembersFilter {
 
 /// Returns the owner and administrators
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatMembersFilterAdministrators")]
 pub struct ChatMembersFilterAdministrators {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -126,6 +120,18 @@ impl RObject for ChatMembersFilterAdministrators {
     }
 }
 
+impl PartialEq for ChatMembersFilterAdministrators {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ChatMembersFilterAdministrators {}
+
+impl std::hash::Hash for ChatMembersFilterAdministrators {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDChatMembersFilter for ChatMembersFilterAdministrators {}
 
 impl ChatMembersFilterAdministrators {
@@ -133,22 +139,49 @@ impl ChatMembersFilterAdministrators {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatMembersFilterAdministratorsBuilder {
-        let mut inner = ChatMembersFilterAdministrators::default();
-        inner.td_name = "chatMembersFilterAdministrators".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatMembersFilterAdministratorsBuilder { inner }
+        RTDChatMembersFilterAdministratorsBuilder {
+            inner: ChatMembersFilterAdministrators::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatMembersFilterAdministratorsBuilder {
+        RTDChatMembersFilterAdministratorsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatMembersFilterAdministratorsBuilder {
+        RTDChatMembersFilterAdministratorsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDChatMembersFilterAdministratorsBuilder {
     inner: ChatMembersFilterAdministrators,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatMembersFilterAdministratorsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatMembersFilterAdministrators {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatMembersFilterAdministrators> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatMembersFilterAdministrators",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<ChatMembersFilterAdministrators> for ChatMembersFilterAdministrators {
@@ -165,12 +198,13 @@ impl AsRef<ChatMembersFilterAdministrators> for RTDChatMembersFilterAdministrato
 
 /// Returns users banned from the chat; can be used only by administrators in a supergroup or in a channel
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatMembersFilterBanned")]
 pub struct ChatMembersFilterBanned {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -188,6 +222,18 @@ impl RObject for ChatMembersFilterBanned {
     }
 }
 
+impl PartialEq for ChatMembersFilterBanned {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ChatMembersFilterBanned {}
+
+impl std::hash::Hash for ChatMembersFilterBanned {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDChatMembersFilter for ChatMembersFilterBanned {}
 
 impl ChatMembersFilterBanned {
@@ -195,22 +241,49 @@ impl ChatMembersFilterBanned {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatMembersFilterBannedBuilder {
-        let mut inner = ChatMembersFilterBanned::default();
-        inner.td_name = "chatMembersFilterBanned".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatMembersFilterBannedBuilder { inner }
+        RTDChatMembersFilterBannedBuilder {
+            inner: ChatMembersFilterBanned::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatMembersFilterBannedBuilder {
+        RTDChatMembersFilterBannedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatMembersFilterBannedBuilder {
+        RTDChatMembersFilterBannedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDChatMembersFilterBannedBuilder {
     inner: ChatMembersFilterBanned,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatMembersFilterBannedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatMembersFilterBanned {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatMembersFilterBanned> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatMembersFilterBanned",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<ChatMembersFilterBanned> for ChatMembersFilterBanned {
@@ -227,12 +300,13 @@ impl AsRef<ChatMembersFilterBanned> for RTDChatMembersFilterBannedBuilder {
 
 /// Returns bot members of the chat
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatMembersFilterBots")]
 pub struct ChatMembersFilterBots {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -250,6 +324,18 @@ impl RObject for ChatMembersFilterBots {
     }
 }
 
+impl PartialEq for ChatMembersFilterBots {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ChatMembersFilterBots {}
+
+impl std::hash::Hash for ChatMembersFilterBots {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDChatMembersFilter for ChatMembersFilterBots {}
 
 impl ChatMembersFilterBots {
@@ -257,22 +343,49 @@ impl ChatMembersFilterBots {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatMembersFilterBotsBuilder {
-        let mut inner = ChatMembersFilterBots::default();
-        inner.td_name = "chatMembersFilterBots".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatMembersFilterBotsBuilder { inner }
+        RTDChatMembersFilterBotsBuilder {
+            inner: ChatMembersFilterBots::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatMembersFilterBotsBuilder {
+        RTDChatMembersFilterBotsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatMembersFilterBotsBuilder {
+        RTDChatMembersFilterBotsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDChatMembersFilterBotsBuilder {
     inner: ChatMembersFilterBots,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatMembersFilterBotsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatMembersFilterBots {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatMembersFilterBots> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatMembersFilterBots",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<ChatMembersFilterBots> for ChatMembersFilterBots {
@@ -289,12 +402,13 @@ impl AsRef<ChatMembersFilterBots> for RTDChatMembersFilterBotsBuilder {
 
 /// Returns contacts of the user
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatMembersFilterContacts")]
 pub struct ChatMembersFilterContacts {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -312,6 +426,18 @@ impl RObject for ChatMembersFilterContacts {
     }
 }
 
+impl PartialEq for ChatMembersFilterContacts {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ChatMembersFilterContacts {}
+
+impl std::hash::Hash for ChatMembersFilterContacts {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDChatMembersFilter for ChatMembersFilterContacts {}
 
 impl ChatMembersFilterContacts {
@@ -319,22 +445,49 @@ impl ChatMembersFilterContacts {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatMembersFilterContactsBuilder {
-        let mut inner = ChatMembersFilterContacts::default();
-        inner.td_name = "chatMembersFilterContacts".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatMembersFilterContactsBuilder { inner }
+        RTDChatMembersFilterContactsBuilder {
+            inner: ChatMembersFilterContacts::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatMembersFilterContactsBuilder {
+        RTDChatMembersFilterContactsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatMembersFilterContactsBuilder {
+        RTDChatMembersFilterContactsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDChatMembersFilterContactsBuilder {
     inner: ChatMembersFilterContacts,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatMembersFilterContactsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatMembersFilterContacts {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatMembersFilterContacts> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatMembersFilterContacts",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<ChatMembersFilterContacts> for ChatMembersFilterContacts {
@@ -351,12 +504,13 @@ impl AsRef<ChatMembersFilterContacts> for RTDChatMembersFilterContactsBuilder {
 
 /// Returns all chat members, including restricted chat members
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatMembersFilterMembers")]
 pub struct ChatMembersFilterMembers {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -374,6 +528,18 @@ impl RObject for ChatMembersFilterMembers {
     }
 }
 
+impl PartialEq for ChatMembersFilterMembers {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ChatMembersFilterMembers {}
+
+impl std::hash::Hash for ChatMembersFilterMembers {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDChatMembersFilter for ChatMembersFilterMembers {}
 
 impl ChatMembersFilterMembers {
@@ -381,22 +547,49 @@ impl ChatMembersFilterMembers {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatMembersFilterMembersBuilder {
-        let mut inner = ChatMembersFilterMembers::default();
-        inner.td_name = "chatMembersFilterMembers".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatMembersFilterMembersBuilder { inner }
+        RTDChatMembersFilterMembersBuilder {
+            inner: ChatMembersFilterMembers::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatMembersFilterMembersBuilder {
+        RTDChatMembersFilterMembersBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatMembersFilterMembersBuilder {
+        RTDChatMembersFilterMembersBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDChatMembersFilterMembersBuilder {
     inner: ChatMembersFilterMembers,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatMembersFilterMembersBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatMembersFilterMembers {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatMembersFilterMembers> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatMembersFilterMembers",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<ChatMembersFilterMembers> for ChatMembersFilterMembers {
@@ -413,12 +606,13 @@ impl AsRef<ChatMembersFilterMembers> for RTDChatMembersFilterMembersBuilder {
 
 /// Returns users under certain restrictions in the chat; can be used only by administrators in a supergroup
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatMembersFilterRestricted")]
 pub struct ChatMembersFilterRestricted {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -436,6 +630,18 @@ impl RObject for ChatMembersFilterRestricted {
     }
 }
 
+impl PartialEq for ChatMembersFilterRestricted {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ChatMembersFilterRestricted {}
+
+impl std::hash::Hash for ChatMembersFilterRestricted {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDChatMembersFilter for ChatMembersFilterRestricted {}
 
 impl ChatMembersFilterRestricted {
@@ -443,22 +649,49 @@ impl ChatMembersFilterRestricted {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatMembersFilterRestrictedBuilder {
-        let mut inner = ChatMembersFilterRestricted::default();
-        inner.td_name = "chatMembersFilterRestricted".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatMembersFilterRestrictedBuilder { inner }
+        RTDChatMembersFilterRestrictedBuilder {
+            inner: ChatMembersFilterRestricted::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatMembersFilterRestrictedBuilder {
+        RTDChatMembersFilterRestrictedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatMembersFilterRestrictedBuilder {
+        RTDChatMembersFilterRestrictedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDChatMembersFilterRestrictedBuilder {
     inner: ChatMembersFilterRestricted,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatMembersFilterRestrictedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatMembersFilterRestricted {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatMembersFilterRestricted> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatMembersFilterRestricted",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<ChatMembersFilterRestricted> for ChatMembersFilterRestricted {
@@ -472,3 +705,31 @@ impl AsRef<ChatMembersFilterRestricted> for RTDChatMembersFilterRestrictedBuilde
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(ChatMembersFilter::Administrators(
+            ChatMembersFilterAdministrators::builder().build(),
+        ));
+        assert_round_trip(ChatMembersFilter::Banned(
+            ChatMembersFilterBanned::builder().build(),
+        ));
+        assert_round_trip(ChatMembersFilter::Bots(
+            ChatMembersFilterBots::builder().build(),
+        ));
+        assert_round_trip(ChatMembersFilter::Contacts(
+            ChatMembersFilterContacts::builder().build(),
+        ));
+        assert_round_trip(ChatMembersFilter::Members(
+            ChatMembersFilterMembers::builder().build(),
+        ));
+        assert_round_trip(ChatMembersFilter::Restricted(
+            ChatMembersFilterRestricted::builder().build(),
+        ));
+    }
+}
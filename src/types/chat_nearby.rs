@@ -1,20 +1,20 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Describes a chat located nearby
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatNearby")]
 pub struct ChatNearby {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Chat identifier
-    chat_id: i64,
+    chat_id: ChatId,
     /// Distance to the chat location in meters
-    distance: i64,
+    distance: i32,
 }
 
 impl RObject for ChatNearby {
@@ -31,43 +31,94 @@ impl RObject for ChatNearby {
     }
 }
 
+impl PartialEq for ChatNearby {
+    fn eq(&self, other: &Self) -> bool {
+        self.chat_id == other.chat_id && self.distance == other.distance
+    }
+}
+
+impl Eq for ChatNearby {}
+
+impl std::hash::Hash for ChatNearby {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.chat_id, state);
+        std::hash::Hash::hash(&self.distance, state);
+    }
+}
+
 impl ChatNearby {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatNearbyBuilder {
-        let mut inner = ChatNearby::default();
-        inner.td_name = "chatNearby".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatNearbyBuilder { inner }
+        RTDChatNearbyBuilder {
+            inner: ChatNearby::default(),
+            missing: vec!["chat_id", "distance"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatNearbyBuilder {
+        RTDChatNearbyBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatNearbyBuilder {
+        RTDChatNearbyBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn chat_id(&self) -> i64 {
+    pub fn chat_id(&self) -> ChatId {
         self.chat_id
     }
 
-    pub fn distance(&self) -> i64 {
+    pub fn distance(&self) -> i32 {
         self.distance
     }
+
+    pub fn with_chat_id<T: Into<ChatId>>(mut self, chat_id: T) -> Self {
+        self.chat_id = chat_id.into();
+        self
+    }
+
+    pub fn with_distance(mut self, distance: i32) -> Self {
+        self.distance = distance;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDChatNearbyBuilder {
     inner: ChatNearby,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatNearbyBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatNearby {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatNearby> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("ChatNearby", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
-        self.inner.chat_id = chat_id;
+    pub fn chat_id<T: Into<ChatId>>(&mut self, chat_id: T) -> &mut Self {
+        self.inner.chat_id = chat_id.into();
+        self.missing.retain(|f| *f != "chat_id");
         self
     }
 
-    pub fn distance(&mut self, distance: i64) -> &mut Self {
+    pub fn distance(&mut self, distance: i32) -> &mut Self {
         self.inner.distance = distance;
+        self.missing.retain(|f| *f != "distance");
         self
     }
 }
@@ -83,3 +134,19 @@ impl AsRef<ChatNearby> for RTDChatNearbyBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            ChatNearby::builder()
+                .chat_id(9007199254740991i64)
+                .distance(i32::MAX)
+                .build(),
+        );
+    }
+}
@@ -1,20 +1,20 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains information about notification settings for a chat
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatNotificationSettings")]
 pub struct ChatNotificationSettings {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// If true, mute_for is ignored and the value for the relevant type of chat is used instead
     use_default_mute_for: bool,
     /// Time left before notifications will be unmuted, in seconds
-    mute_for: i64,
+    mute_for: i32,
     /// If true, sound is ignored and the value for the relevant type of chat is used instead
     use_default_sound: bool,
     /// The name of an audio file to be used for notification sounds; only applies to iOS applications
@@ -47,22 +47,74 @@ impl RObject for ChatNotificationSettings {
     }
 }
 
+impl PartialEq for ChatNotificationSettings {
+    fn eq(&self, other: &Self) -> bool {
+        self.use_default_mute_for == other.use_default_mute_for
+            && self.mute_for == other.mute_for
+            && self.use_default_sound == other.use_default_sound
+            && self.sound == other.sound
+            && self.use_default_show_preview == other.use_default_show_preview
+            && self.show_preview == other.show_preview
+            && self.use_default_disable_pinned_message_notifications
+                == other.use_default_disable_pinned_message_notifications
+            && self.disable_pinned_message_notifications
+                == other.disable_pinned_message_notifications
+            && self.use_default_disable_mention_notifications
+                == other.use_default_disable_mention_notifications
+            && self.disable_mention_notifications == other.disable_mention_notifications
+    }
+}
+
+impl Eq for ChatNotificationSettings {}
+
+impl std::hash::Hash for ChatNotificationSettings {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.use_default_mute_for, state);
+        std::hash::Hash::hash(&self.mute_for, state);
+        std::hash::Hash::hash(&self.use_default_sound, state);
+        std::hash::Hash::hash(&self.sound, state);
+        std::hash::Hash::hash(&self.use_default_show_preview, state);
+        std::hash::Hash::hash(&self.show_preview, state);
+        std::hash::Hash::hash(
+            &self.use_default_disable_pinned_message_notifications,
+            state,
+        );
+        std::hash::Hash::hash(&self.disable_pinned_message_notifications, state);
+        std::hash::Hash::hash(&self.use_default_disable_mention_notifications, state);
+        std::hash::Hash::hash(&self.disable_mention_notifications, state);
+    }
+}
+
 impl ChatNotificationSettings {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatNotificationSettingsBuilder {
-        let mut inner = ChatNotificationSettings::default();
-        inner.td_name = "chatNotificationSettings".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatNotificationSettingsBuilder { inner }
+        RTDChatNotificationSettingsBuilder {
+            inner: ChatNotificationSettings::default(),
+            missing: vec!["mute_for", "sound"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatNotificationSettingsBuilder {
+        RTDChatNotificationSettingsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatNotificationSettingsBuilder {
+        RTDChatNotificationSettingsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn use_default_mute_for(&self) -> bool {
         self.use_default_mute_for
     }
 
-    pub fn mute_for(&self) -> i64 {
+    pub fn mute_for(&self) -> i32 {
         self.mute_for
     }
 
@@ -97,25 +149,106 @@ impl ChatNotificationSettings {
     pub fn disable_mention_notifications(&self) -> bool {
         self.disable_mention_notifications
     }
+
+    pub fn into_sound(self) -> String {
+        self.sound
+    }
+
+    pub fn with_use_default_mute_for(mut self, use_default_mute_for: bool) -> Self {
+        self.use_default_mute_for = use_default_mute_for;
+        self
+    }
+
+    pub fn with_mute_for(mut self, mute_for: i32) -> Self {
+        self.mute_for = mute_for;
+        self
+    }
+
+    pub fn with_use_default_sound(mut self, use_default_sound: bool) -> Self {
+        self.use_default_sound = use_default_sound;
+        self
+    }
+
+    pub fn with_sound<T: AsRef<str>>(mut self, sound: T) -> Self {
+        self.sound = sound.as_ref().to_string();
+        self
+    }
+
+    pub fn with_use_default_show_preview(mut self, use_default_show_preview: bool) -> Self {
+        self.use_default_show_preview = use_default_show_preview;
+        self
+    }
+
+    pub fn with_show_preview(mut self, show_preview: bool) -> Self {
+        self.show_preview = show_preview;
+        self
+    }
+
+    pub fn with_use_default_disable_pinned_message_notifications(
+        mut self,
+        use_default_disable_pinned_message_notifications: bool,
+    ) -> Self {
+        self.use_default_disable_pinned_message_notifications =
+            use_default_disable_pinned_message_notifications;
+        self
+    }
+
+    pub fn with_disable_pinned_message_notifications(
+        mut self,
+        disable_pinned_message_notifications: bool,
+    ) -> Self {
+        self.disable_pinned_message_notifications = disable_pinned_message_notifications;
+        self
+    }
+
+    pub fn with_use_default_disable_mention_notifications(
+        mut self,
+        use_default_disable_mention_notifications: bool,
+    ) -> Self {
+        self.use_default_disable_mention_notifications = use_default_disable_mention_notifications;
+        self
+    }
+
+    pub fn with_disable_mention_notifications(
+        mut self,
+        disable_mention_notifications: bool,
+    ) -> Self {
+        self.disable_mention_notifications = disable_mention_notifications;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDChatNotificationSettingsBuilder {
     inner: ChatNotificationSettings,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatNotificationSettingsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatNotificationSettings {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatNotificationSettings> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatNotificationSettings",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn use_default_mute_for(&mut self, use_default_mute_for: bool) -> &mut Self {
         self.inner.use_default_mute_for = use_default_mute_for;
         self
     }
 
-    pub fn mute_for(&mut self, mute_for: i64) -> &mut Self {
+    pub fn mute_for(&mut self, mute_for: i32) -> &mut Self {
         self.inner.mute_for = mute_for;
+        self.missing.retain(|f| *f != "mute_for");
         self
     }
 
@@ -126,6 +259,7 @@ impl RTDChatNotificationSettingsBuilder {
 
     pub fn sound<T: AsRef<str>>(&mut self, sound: T) -> &mut Self {
         self.inner.sound = sound.as_ref().to_string();
+        self.missing.retain(|f| *f != "sound");
         self
     }
 
@@ -185,3 +319,27 @@ impl AsRef<ChatNotificationSettings> for RTDChatNotificationSettingsBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            ChatNotificationSettings::builder()
+                .use_default_mute_for(true)
+                .mute_for(i32::MAX)
+                .use_default_sound(true)
+                .sound("text")
+                .use_default_show_preview(true)
+                .show_preview(true)
+                .use_default_disable_pinned_message_notifications(true)
+                .disable_pinned_message_notifications(true)
+                .use_default_disable_mention_notifications(true)
+                .disable_mention_notifications(true)
+                .build(),
+        );
+    }
+}
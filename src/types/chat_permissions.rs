@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Describes actions that a user is allowed to take in a chat
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatPermissions")]
 pub struct ChatPermissions {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// True, if the user can send text messages, contacts, locations, and venues
     can_send_messages: bool,
@@ -43,15 +43,57 @@ impl RObject for ChatPermissions {
     }
 }
 
+impl PartialEq for ChatPermissions {
+    fn eq(&self, other: &Self) -> bool {
+        self.can_send_messages == other.can_send_messages
+            && self.can_send_media_messages == other.can_send_media_messages
+            && self.can_send_polls == other.can_send_polls
+            && self.can_send_other_messages == other.can_send_other_messages
+            && self.can_add_web_page_previews == other.can_add_web_page_previews
+            && self.can_change_info == other.can_change_info
+            && self.can_invite_users == other.can_invite_users
+            && self.can_pin_messages == other.can_pin_messages
+    }
+}
+
+impl Eq for ChatPermissions {}
+
+impl std::hash::Hash for ChatPermissions {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.can_send_messages, state);
+        std::hash::Hash::hash(&self.can_send_media_messages, state);
+        std::hash::Hash::hash(&self.can_send_polls, state);
+        std::hash::Hash::hash(&self.can_send_other_messages, state);
+        std::hash::Hash::hash(&self.can_add_web_page_previews, state);
+        std::hash::Hash::hash(&self.can_change_info, state);
+        std::hash::Hash::hash(&self.can_invite_users, state);
+        std::hash::Hash::hash(&self.can_pin_messages, state);
+    }
+}
+
 impl ChatPermissions {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatPermissionsBuilder {
-        let mut inner = ChatPermissions::default();
-        inner.td_name = "chatPermissions".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatPermissionsBuilder { inner }
+        RTDChatPermissionsBuilder {
+            inner: ChatPermissions::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatPermissionsBuilder {
+        RTDChatPermissionsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatPermissionsBuilder {
+        RTDChatPermissionsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn can_send_messages(&self) -> bool {
@@ -85,17 +127,70 @@ impl ChatPermissions {
     pub fn can_pin_messages(&self) -> bool {
         self.can_pin_messages
     }
+
+    pub fn with_can_send_messages(mut self, can_send_messages: bool) -> Self {
+        self.can_send_messages = can_send_messages;
+        self
+    }
+
+    pub fn with_can_send_media_messages(mut self, can_send_media_messages: bool) -> Self {
+        self.can_send_media_messages = can_send_media_messages;
+        self
+    }
+
+    pub fn with_can_send_polls(mut self, can_send_polls: bool) -> Self {
+        self.can_send_polls = can_send_polls;
+        self
+    }
+
+    pub fn with_can_send_other_messages(mut self, can_send_other_messages: bool) -> Self {
+        self.can_send_other_messages = can_send_other_messages;
+        self
+    }
+
+    pub fn with_can_add_web_page_previews(mut self, can_add_web_page_previews: bool) -> Self {
+        self.can_add_web_page_previews = can_add_web_page_previews;
+        self
+    }
+
+    pub fn with_can_change_info(mut self, can_change_info: bool) -> Self {
+        self.can_change_info = can_change_info;
+        self
+    }
+
+    pub fn with_can_invite_users(mut self, can_invite_users: bool) -> Self {
+        self.can_invite_users = can_invite_users;
+        self
+    }
+
+    pub fn with_can_pin_messages(mut self, can_pin_messages: bool) -> Self {
+        self.can_pin_messages = can_pin_messages;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDChatPermissionsBuilder {
     inner: ChatPermissions,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatPermissionsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatPermissions {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatPermissions> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatPermissions",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn can_send_messages(&mut self, can_send_messages: bool) -> &mut Self {
         self.inner.can_send_messages = can_send_messages;
@@ -149,3 +244,25 @@ impl AsRef<ChatPermissions> for RTDChatPermissionsBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            ChatPermissions::builder()
+                .can_send_messages(true)
+                .can_send_media_messages(true)
+                .can_send_polls(true)
+                .can_send_other_messages(true)
+                .can_add_web_page_previews(true)
+                .can_change_info(true)
+                .can_invite_users(true)
+                .can_pin_messages(true)
+                .build(),
+        );
+    }
+}
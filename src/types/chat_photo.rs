@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Describes the photo of a chat
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatPhoto")]
 pub struct ChatPhoto {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// A small (160x160) chat photo. The file can be downloaded only before the photo is changed
     small: File,
@@ -31,15 +31,44 @@ impl RObject for ChatPhoto {
     }
 }
 
+impl PartialEq for ChatPhoto {
+    fn eq(&self, other: &Self) -> bool {
+        self.small == other.small && self.big == other.big
+    }
+}
+
+impl Eq for ChatPhoto {}
+
+impl std::hash::Hash for ChatPhoto {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.small, state);
+        std::hash::Hash::hash(&self.big, state);
+    }
+}
+
 impl ChatPhoto {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatPhotoBuilder {
-        let mut inner = ChatPhoto::default();
-        inner.td_name = "chatPhoto".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatPhotoBuilder { inner }
+        RTDChatPhotoBuilder {
+            inner: ChatPhoto::default(),
+            missing: vec!["small", "big"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatPhotoBuilder {
+        RTDChatPhotoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatPhotoBuilder {
+        RTDChatPhotoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn small(&self) -> &File {
@@ -49,25 +78,55 @@ impl ChatPhoto {
     pub fn big(&self) -> &File {
         &self.big
     }
+
+    pub fn into_small(self) -> File {
+        self.small
+    }
+
+    pub fn into_big(self) -> File {
+        self.big
+    }
+
+    pub fn with_small<T: AsRef<File>>(mut self, small: T) -> Self {
+        self.small = small.as_ref().clone();
+        self
+    }
+
+    pub fn with_big<T: AsRef<File>>(mut self, big: T) -> Self {
+        self.big = big.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDChatPhotoBuilder {
     inner: ChatPhoto,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatPhotoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatPhoto {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatPhoto> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("ChatPhoto", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn small<T: AsRef<File>>(&mut self, small: T) -> &mut Self {
         self.inner.small = small.as_ref().clone();
+        self.missing.retain(|f| *f != "small");
         self
     }
 
     pub fn big<T: AsRef<File>>(&mut self, big: T) -> &mut Self {
         self.inner.big = big.as_ref().clone();
+        self.missing.retain(|f| *f != "big");
         self
     }
 }
@@ -83,3 +142,73 @@ impl AsRef<ChatPhoto> for RTDChatPhotoBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            ChatPhoto::builder()
+                .small(
+                    File::builder()
+                        .id(i32::MAX)
+                        .size(i32::MAX)
+                        .expected_size(i32::MAX)
+                        .local(
+                            LocalFile::builder()
+                                .path("text")
+                                .can_be_downloaded(true)
+                                .can_be_deleted(true)
+                                .is_downloading_active(true)
+                                .is_downloading_completed(true)
+                                .download_offset(i32::MAX)
+                                .downloaded_prefix_size(i32::MAX)
+                                .downloaded_size(i32::MAX)
+                                .build(),
+                        )
+                        .remote(
+                            RemoteFile::builder()
+                                .id("text")
+                                .unique_id("text")
+                                .is_uploading_active(true)
+                                .is_uploading_completed(true)
+                                .uploaded_size(i32::MAX)
+                                .build(),
+                        )
+                        .build(),
+                )
+                .big(
+                    File::builder()
+                        .id(i32::MAX)
+                        .size(i32::MAX)
+                        .expected_size(i32::MAX)
+                        .local(
+                            LocalFile::builder()
+                                .path("text")
+                                .can_be_downloaded(true)
+                                .can_be_deleted(true)
+                                .is_downloading_active(true)
+                                .is_downloading_completed(true)
+                                .download_offset(i32::MAX)
+                                .downloaded_prefix_size(i32::MAX)
+                                .downloaded_size(i32::MAX)
+                                .build(),
+                        )
+                        .remote(
+                            RemoteFile::builder()
+                                .id("text")
+                                .unique_id("text")
+                                .is_uploading_active(true)
+                                .is_uploading_completed(true)
+                                .uploaded_size(i32::MAX)
+                                .build(),
+                        )
+                        .build(),
+                )
+                .build(),
+        );
+    }
+}
@@ -1,58 +1,60 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Describes the reason why a chat is reported
 pub trait TDChatReportReason: Debug + RObject {}
 
 /// Describes the reason why a chat is reported
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum ChatReportReason {
-    #[doc(hidden)]
-    _Default(()),
     /// The chat has child abuse related content
+    #[serde(rename = "chatReportReasonChildAbuse")]
     ChildAbuse(ChatReportReasonChildAbuse),
     /// The chat contains copyrighted content
+    #[serde(rename = "chatReportReasonCopyright")]
     Copyright(ChatReportReasonCopyright),
     /// A custom reason provided by the user
+    #[serde(rename = "chatReportReasonCustom")]
     Custom(ChatReportReasonCustom),
     /// The chat contains pornographic messages
+    #[serde(rename = "chatReportReasonPornography")]
     Pornography(ChatReportReasonPornography),
     /// The chat contains spam messages
+    #[serde(rename = "chatReportReasonSpam")]
     Spam(ChatReportReasonSpam),
     /// The location-based chat is unrelated to its stated location
+    #[serde(rename = "chatReportReasonUnrelatedLocation")]
     UnrelatedLocation(ChatReportReasonUnrelatedLocation),
     /// The chat promotes violence
+    #[serde(rename = "chatReportReasonViolence")]
     Violence(ChatReportReasonViolence),
 }
 
+/// Default value is default of [ChatReportReasonChildAbuse], builders still require the field to be set
 impl Default for ChatReportReason {
     fn default() -> Self {
-        ChatReportReason::_Default(())
+        ChatReportReason::ChildAbuse(ChatReportReasonChildAbuse::default())
     }
 }
 
-impl<'de> Deserialize<'de> for ChatReportReason {
-    fn deserialize<D>(deserializer: D) -> Result<ChatReportReason, D::Error>
+impl Serialize for ChatReportReason {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          ChatReportReason,
-          (chatReportReasonChildAbuse, ChildAbuse);
-          (chatReportReasonCopyright, Copyright);
-          (chatReportReasonCustom, Custom);
-          (chatReportReasonPornography, Pornography);
-          (chatReportReasonSpam, Spam);
-          (chatReportReasonUnrelatedLocation, UnrelatedLocation);
-          (chatReportReasonViolence, Violence);
-
-        )(deserializer)
+        match self {
+            ChatReportReason::ChildAbuse(t) => t.serialize(serializer),
+            ChatReportReason::Copyright(t) => t.serialize(serializer),
+            ChatReportReason::Custom(t) => t.serialize(serializer),
+            ChatReportReason::Pornography(t) => t.serialize(serializer),
+            ChatReportReason::Spam(t) => t.serialize(serializer),
+            ChatReportReason::UnrelatedLocation(t) => t.serialize(serializer),
+            ChatReportReason::Violence(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -67,8 +69,6 @@ impl RObject for ChatReportReason {
             ChatReportReason::Spam(t) => t.td_name(),
             ChatReportReason::UnrelatedLocation(t) => t.td_name(),
             ChatReportReason::Violence(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -81,8 +81,6 @@ impl RObject for ChatReportReason {
             ChatReportReason::Spam(t) => t.extra(),
             ChatReportReason::UnrelatedLocation(t) => t.extra(),
             ChatReportReason::Violence(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -94,10 +92,6 @@ impl ChatReportReason {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, ChatReportReason::_Default(_))
-    }
 }
 
 impl AsRef<ChatReportReason> for ChatReportReason {
@@ -108,12 +102,13 @@ impl AsRef<ChatReportReason> for ChatReportReason {
 
 /// The chat has child abuse related content
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatReportReasonChildAbuse")]
 pub struct ChatReportReasonChildAbuse {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -131,6 +126,18 @@ impl RObject for ChatReportReasonChildAbuse {
     }
 }
 
+impl PartialEq for ChatReportReasonChildAbuse {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ChatReportReasonChildAbuse {}
+
+impl std::hash::Hash for ChatReportReasonChildAbuse {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDChatReportReason for ChatReportReasonChildAbuse {}
 
 impl ChatReportReasonChildAbuse {
@@ -138,22 +145,49 @@ impl ChatReportReasonChildAbuse {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatReportReasonChildAbuseBuilder {
-        let mut inner = ChatReportReasonChildAbuse::default();
-        inner.td_name = "chatReportReasonChildAbuse".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatReportReasonChildAbuseBuilder { inner }
+        RTDChatReportReasonChildAbuseBuilder {
+            inner: ChatReportReasonChildAbuse::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatReportReasonChildAbuseBuilder {
+        RTDChatReportReasonChildAbuseBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatReportReasonChildAbuseBuilder {
+        RTDChatReportReasonChildAbuseBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDChatReportReasonChildAbuseBuilder {
     inner: ChatReportReasonChildAbuse,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatReportReasonChildAbuseBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatReportReasonChildAbuse {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatReportReasonChildAbuse> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatReportReasonChildAbuse",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<ChatReportReasonChildAbuse> for ChatReportReasonChildAbuse {
@@ -170,12 +204,13 @@ impl AsRef<ChatReportReasonChildAbuse> for RTDChatReportReasonChildAbuseBuilder
 
 /// The chat contains copyrighted content
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatReportReasonCopyright")]
 pub struct ChatReportReasonCopyright {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -193,6 +228,18 @@ impl RObject for ChatReportReasonCopyright {
     }
 }
 
+impl PartialEq for ChatReportReasonCopyright {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ChatReportReasonCopyright {}
+
+impl std::hash::Hash for ChatReportReasonCopyright {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDChatReportReason for ChatReportReasonCopyright {}
 
 impl ChatReportReasonCopyright {
@@ -200,22 +247,49 @@ impl ChatReportReasonCopyright {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatReportReasonCopyrightBuilder {
-        let mut inner = ChatReportReasonCopyright::default();
-        inner.td_name = "chatReportReasonCopyright".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatReportReasonCopyrightBuilder { inner }
+        RTDChatReportReasonCopyrightBuilder {
+            inner: ChatReportReasonCopyright::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatReportReasonCopyrightBuilder {
+        RTDChatReportReasonCopyrightBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatReportReasonCopyrightBuilder {
+        RTDChatReportReasonCopyrightBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDChatReportReasonCopyrightBuilder {
     inner: ChatReportReasonCopyright,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatReportReasonCopyrightBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatReportReasonCopyright {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatReportReasonCopyright> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatReportReasonCopyright",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<ChatReportReasonCopyright> for ChatReportReasonCopyright {
@@ -232,12 +306,13 @@ impl AsRef<ChatReportReasonCopyright> for RTDChatReportReasonCopyrightBuilder {
 
 /// A custom reason provided by the user
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatReportReasonCustom")]
 pub struct ChatReportReasonCustom {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Report text
     text: String,
@@ -257,6 +332,20 @@ impl RObject for ChatReportReasonCustom {
     }
 }
 
+impl PartialEq for ChatReportReasonCustom {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text
+    }
+}
+
+impl Eq for ChatReportReasonCustom {}
+
+impl std::hash::Hash for ChatReportReasonCustom {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.text, state);
+    }
+}
+
 impl TDChatReportReason for ChatReportReasonCustom {}
 
 impl ChatReportReasonCustom {
@@ -264,29 +353,66 @@ impl ChatReportReasonCustom {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatReportReasonCustomBuilder {
-        let mut inner = ChatReportReasonCustom::default();
-        inner.td_name = "chatReportReasonCustom".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatReportReasonCustomBuilder { inner }
+        RTDChatReportReasonCustomBuilder {
+            inner: ChatReportReasonCustom::default(),
+            missing: vec!["text"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatReportReasonCustomBuilder {
+        RTDChatReportReasonCustomBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatReportReasonCustomBuilder {
+        RTDChatReportReasonCustomBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn text(&self) -> &String {
         &self.text
     }
+
+    pub fn into_text(self) -> String {
+        self.text
+    }
+
+    pub fn with_text<T: AsRef<str>>(mut self, text: T) -> Self {
+        self.text = text.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDChatReportReasonCustomBuilder {
     inner: ChatReportReasonCustom,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatReportReasonCustomBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatReportReasonCustom {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatReportReasonCustom> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatReportReasonCustom",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn text<T: AsRef<str>>(&mut self, text: T) -> &mut Self {
         self.inner.text = text.as_ref().to_string();
+        self.missing.retain(|f| *f != "text");
         self
     }
 }
@@ -305,12 +431,13 @@ impl AsRef<ChatReportReasonCustom> for RTDChatReportReasonCustomBuilder {
 
 /// The chat contains pornographic messages
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatReportReasonPornography")]
 pub struct ChatReportReasonPornography {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -328,6 +455,18 @@ impl RObject for ChatReportReasonPornography {
     }
 }
 
+impl PartialEq for ChatReportReasonPornography {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ChatReportReasonPornography {}
+
+impl std::hash::Hash for ChatReportReasonPornography {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDChatReportReason for ChatReportReasonPornography {}
 
 impl ChatReportReasonPornography {
@@ -335,22 +474,49 @@ impl ChatReportReasonPornography {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatReportReasonPornographyBuilder {
-        let mut inner = ChatReportReasonPornography::default();
-        inner.td_name = "chatReportReasonPornography".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatReportReasonPornographyBuilder { inner }
+        RTDChatReportReasonPornographyBuilder {
+            inner: ChatReportReasonPornography::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatReportReasonPornographyBuilder {
+        RTDChatReportReasonPornographyBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatReportReasonPornographyBuilder {
+        RTDChatReportReasonPornographyBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDChatReportReasonPornographyBuilder {
     inner: ChatReportReasonPornography,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatReportReasonPornographyBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatReportReasonPornography {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatReportReasonPornography> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatReportReasonPornography",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<ChatReportReasonPornography> for ChatReportReasonPornography {
@@ -367,12 +533,13 @@ impl AsRef<ChatReportReasonPornography> for RTDChatReportReasonPornographyBuilde
 
 /// The chat contains spam messages
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatReportReasonSpam")]
 pub struct ChatReportReasonSpam {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -390,6 +557,18 @@ impl RObject for ChatReportReasonSpam {
     }
 }
 
+impl PartialEq for ChatReportReasonSpam {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ChatReportReasonSpam {}
+
+impl std::hash::Hash for ChatReportReasonSpam {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDChatReportReason for ChatReportReasonSpam {}
 
 impl ChatReportReasonSpam {
@@ -397,22 +576,49 @@ impl ChatReportReasonSpam {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatReportReasonSpamBuilder {
-        let mut inner = ChatReportReasonSpam::default();
-        inner.td_name = "chatReportReasonSpam".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatReportReasonSpamBuilder { inner }
+        RTDChatReportReasonSpamBuilder {
+            inner: ChatReportReasonSpam::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatReportReasonSpamBuilder {
+        RTDChatReportReasonSpamBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatReportReasonSpamBuilder {
+        RTDChatReportReasonSpamBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDChatReportReasonSpamBuilder {
     inner: ChatReportReasonSpam,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatReportReasonSpamBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatReportReasonSpam {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatReportReasonSpam> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatReportReasonSpam",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<ChatReportReasonSpam> for ChatReportReasonSpam {
@@ -429,12 +635,13 @@ impl AsRef<ChatReportReasonSpam> for RTDChatReportReasonSpamBuilder {
 
 /// The location-based chat is unrelated to its stated location
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatReportReasonUnrelatedLocation")]
 pub struct ChatReportReasonUnrelatedLocation {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -452,6 +659,18 @@ impl RObject for ChatReportReasonUnrelatedLocation {
     }
 }
 
+impl PartialEq for ChatReportReasonUnrelatedLocation {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ChatReportReasonUnrelatedLocation {}
+
+impl std::hash::Hash for ChatReportReasonUnrelatedLocation {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDChatReportReason for ChatReportReasonUnrelatedLocation {}
 
 impl ChatReportReasonUnrelatedLocation {
@@ -459,22 +678,49 @@ impl ChatReportReasonUnrelatedLocation {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatReportReasonUnrelatedLocationBuilder {
-        let mut inner = ChatReportReasonUnrelatedLocation::default();
-        inner.td_name = "chatReportReasonUnrelatedLocation".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatReportReasonUnrelatedLocationBuilder { inner }
+        RTDChatReportReasonUnrelatedLocationBuilder {
+            inner: ChatReportReasonUnrelatedLocation::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatReportReasonUnrelatedLocationBuilder {
+        RTDChatReportReasonUnrelatedLocationBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatReportReasonUnrelatedLocationBuilder {
+        RTDChatReportReasonUnrelatedLocationBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDChatReportReasonUnrelatedLocationBuilder {
     inner: ChatReportReasonUnrelatedLocation,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatReportReasonUnrelatedLocationBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatReportReasonUnrelatedLocation {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatReportReasonUnrelatedLocation> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatReportReasonUnrelatedLocation",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<ChatReportReasonUnrelatedLocation> for ChatReportReasonUnrelatedLocation {
@@ -491,12 +737,13 @@ impl AsRef<ChatReportReasonUnrelatedLocation> for RTDChatReportReasonUnrelatedLo
 
 /// The chat promotes violence
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatReportReasonViolence")]
 pub struct ChatReportReasonViolence {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -514,6 +761,18 @@ impl RObject for ChatReportReasonViolence {
     }
 }
 
+impl PartialEq for ChatReportReasonViolence {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ChatReportReasonViolence {}
+
+impl std::hash::Hash for ChatReportReasonViolence {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDChatReportReason for ChatReportReasonViolence {}
 
 impl ChatReportReasonViolence {
@@ -521,22 +780,49 @@ impl ChatReportReasonViolence {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatReportReasonViolenceBuilder {
-        let mut inner = ChatReportReasonViolence::default();
-        inner.td_name = "chatReportReasonViolence".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatReportReasonViolenceBuilder { inner }
+        RTDChatReportReasonViolenceBuilder {
+            inner: ChatReportReasonViolence::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatReportReasonViolenceBuilder {
+        RTDChatReportReasonViolenceBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatReportReasonViolenceBuilder {
+        RTDChatReportReasonViolenceBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDChatReportReasonViolenceBuilder {
     inner: ChatReportReasonViolence,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatReportReasonViolenceBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatReportReasonViolence {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatReportReasonViolence> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatReportReasonViolence",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<ChatReportReasonViolence> for ChatReportReasonViolence {
@@ -550,3 +836,34 @@ impl AsRef<ChatReportReasonViolence> for RTDChatReportReasonViolenceBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(ChatReportReason::ChildAbuse(
+            ChatReportReasonChildAbuse::builder().build(),
+        ));
+        assert_round_trip(ChatReportReason::Copyright(
+            ChatReportReasonCopyright::builder().build(),
+        ));
+        assert_round_trip(ChatReportReason::Custom(
+            ChatReportReasonCustom::builder().text("text").build(),
+        ));
+        assert_round_trip(ChatReportReason::Pornography(
+            ChatReportReasonPornography::builder().build(),
+        ));
+        assert_round_trip(ChatReportReason::Spam(
+            ChatReportReasonSpam::builder().build(),
+        ));
+        assert_round_trip(ChatReportReason::UnrelatedLocation(
+            ChatReportReasonUnrelatedLocation::builder().build(),
+        ));
+        assert_round_trip(ChatReportReason::Violence(
+            ChatReportReasonViolence::builder().build(),
+        ));
+    }
+}
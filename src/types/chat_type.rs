@@ -1,49 +1,48 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Describes the type of a chat
 pub trait TDChatType: Debug + RObject {}
 
 /// Describes the type of a chat
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum ChatType {
-    #[doc(hidden)]
-    _Default(()),
     /// A basic group (i.e., a chat with 0-200 other users)
+    #[serde(rename = "chatTypeBasicGroup")]
     BasicGroup(ChatTypeBasicGroup),
     /// An ordinary chat with a user
+    #[serde(rename = "chatTypePrivate")]
     Private(ChatTypePrivate),
     /// A secret chat with a user
+    #[serde(rename = "chatTypeSecret")]
     Secret(ChatTypeSecret),
     /// A supergroup (i.e. a chat with up to GetOption("supergroup_max_size") other users), or channel (with unlimited members)
+    #[serde(rename = "chatTypeSupergroup")]
     Supergroup(ChatTypeSupergroup),
 }
 
+/// Default value is default of [ChatTypeBasicGroup], builders still require the field to be set
 impl Default for ChatType {
     fn default() -> Self {
-        ChatType::_Default(())
+        ChatType::BasicGroup(ChatTypeBasicGroup::default())
     }
 }
 
-impl<'de> Deserialize<'de> for ChatType {
-    fn deserialize<D>(deserializer: D) -> Result<ChatType, D::Error>
+impl Serialize for ChatType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          ChatType,
-          (chatTypeBasicGroup, BasicGroup);
-          (chatTypePrivate, Private);
-          (chatTypeSecret, Secret);
-          (chatTypeSupergroup, Supergroup);
-
-        )(deserializer)
+        match self {
+            ChatType::BasicGroup(t) => t.serialize(serializer),
+            ChatType::Private(t) => t.serialize(serializer),
+            ChatType::Secret(t) => t.serialize(serializer),
+            ChatType::Supergroup(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -55,8 +54,6 @@ impl RObject for ChatType {
             ChatType::Private(t) => t.td_name(),
             ChatType::Secret(t) => t.td_name(),
             ChatType::Supergroup(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -66,8 +63,6 @@ impl RObject for ChatType {
             ChatType::Private(t) => t.extra(),
             ChatType::Secret(t) => t.extra(),
             ChatType::Supergroup(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -79,10 +74,6 @@ impl ChatType {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, ChatType::_Default(_))
-    }
 }
 
 impl AsRef<ChatType> for ChatType {
@@ -93,15 +84,16 @@ impl AsRef<ChatType> for ChatType {
 
 /// A basic group (i.e., a chat with 0-200 other users)
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatTypeBasicGroup")]
 pub struct ChatTypeBasicGroup {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Basic group identifier
-    basic_group_id: i64,
+    basic_group_id: BasicGroupId,
 }
 
 impl RObject for ChatTypeBasicGroup {
@@ -118,6 +110,20 @@ impl RObject for ChatTypeBasicGroup {
     }
 }
 
+impl PartialEq for ChatTypeBasicGroup {
+    fn eq(&self, other: &Self) -> bool {
+        self.basic_group_id == other.basic_group_id
+    }
+}
+
+impl Eq for ChatTypeBasicGroup {}
+
+impl std::hash::Hash for ChatTypeBasicGroup {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.basic_group_id, state);
+    }
+}
+
 impl TDChatType for ChatTypeBasicGroup {}
 
 impl ChatTypeBasicGroup {
@@ -125,29 +131,62 @@ impl ChatTypeBasicGroup {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatTypeBasicGroupBuilder {
-        let mut inner = ChatTypeBasicGroup::default();
-        inner.td_name = "chatTypeBasicGroup".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatTypeBasicGroupBuilder { inner }
+        RTDChatTypeBasicGroupBuilder {
+            inner: ChatTypeBasicGroup::default(),
+            missing: vec!["basic_group_id"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatTypeBasicGroupBuilder {
+        RTDChatTypeBasicGroupBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatTypeBasicGroupBuilder {
+        RTDChatTypeBasicGroupBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn basic_group_id(&self) -> i64 {
+    pub fn basic_group_id(&self) -> BasicGroupId {
         self.basic_group_id
     }
+
+    pub fn with_basic_group_id<T: Into<BasicGroupId>>(mut self, basic_group_id: T) -> Self {
+        self.basic_group_id = basic_group_id.into();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDChatTypeBasicGroupBuilder {
     inner: ChatTypeBasicGroup,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatTypeBasicGroupBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatTypeBasicGroup {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatTypeBasicGroup> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatTypeBasicGroup",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn basic_group_id(&mut self, basic_group_id: i64) -> &mut Self {
-        self.inner.basic_group_id = basic_group_id;
+    pub fn basic_group_id<T: Into<BasicGroupId>>(&mut self, basic_group_id: T) -> &mut Self {
+        self.inner.basic_group_id = basic_group_id.into();
+        self.missing.retain(|f| *f != "basic_group_id");
         self
     }
 }
@@ -166,15 +205,16 @@ impl AsRef<ChatTypeBasicGroup> for RTDChatTypeBasicGroupBuilder {
 
 /// An ordinary chat with a user
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatTypePrivate")]
 pub struct ChatTypePrivate {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// User identifier
-    user_id: i64,
+    user_id: UserId,
 }
 
 impl RObject for ChatTypePrivate {
@@ -191,6 +231,20 @@ impl RObject for ChatTypePrivate {
     }
 }
 
+impl PartialEq for ChatTypePrivate {
+    fn eq(&self, other: &Self) -> bool {
+        self.user_id == other.user_id
+    }
+}
+
+impl Eq for ChatTypePrivate {}
+
+impl std::hash::Hash for ChatTypePrivate {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.user_id, state);
+    }
+}
+
 impl TDChatType for ChatTypePrivate {}
 
 impl ChatTypePrivate {
@@ -198,29 +252,62 @@ impl ChatTypePrivate {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatTypePrivateBuilder {
-        let mut inner = ChatTypePrivate::default();
-        inner.td_name = "chatTypePrivate".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatTypePrivateBuilder { inner }
+        RTDChatTypePrivateBuilder {
+            inner: ChatTypePrivate::default(),
+            missing: vec!["user_id"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatTypePrivateBuilder {
+        RTDChatTypePrivateBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatTypePrivateBuilder {
+        RTDChatTypePrivateBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn user_id(&self) -> i64 {
+    pub fn user_id(&self) -> UserId {
         self.user_id
     }
+
+    pub fn with_user_id<T: Into<UserId>>(mut self, user_id: T) -> Self {
+        self.user_id = user_id.into();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDChatTypePrivateBuilder {
     inner: ChatTypePrivate,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatTypePrivateBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatTypePrivate {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatTypePrivate> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatTypePrivate",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn user_id(&mut self, user_id: i64) -> &mut Self {
-        self.inner.user_id = user_id;
+    pub fn user_id<T: Into<UserId>>(&mut self, user_id: T) -> &mut Self {
+        self.inner.user_id = user_id.into();
+        self.missing.retain(|f| *f != "user_id");
         self
     }
 }
@@ -239,17 +326,18 @@ impl AsRef<ChatTypePrivate> for RTDChatTypePrivateBuilder {
 
 /// A secret chat with a user
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatTypeSecret")]
 pub struct ChatTypeSecret {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Secret chat identifier
-    secret_chat_id: i64,
+    secret_chat_id: SecretChatId,
     /// User identifier of the secret chat peer
-    user_id: i64,
+    user_id: UserId,
 }
 
 impl RObject for ChatTypeSecret {
@@ -266,6 +354,21 @@ impl RObject for ChatTypeSecret {
     }
 }
 
+impl PartialEq for ChatTypeSecret {
+    fn eq(&self, other: &Self) -> bool {
+        self.secret_chat_id == other.secret_chat_id && self.user_id == other.user_id
+    }
+}
+
+impl Eq for ChatTypeSecret {}
+
+impl std::hash::Hash for ChatTypeSecret {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.secret_chat_id, state);
+        std::hash::Hash::hash(&self.user_id, state);
+    }
+}
+
 impl TDChatType for ChatTypeSecret {}
 
 impl ChatTypeSecret {
@@ -273,38 +376,77 @@ impl ChatTypeSecret {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatTypeSecretBuilder {
-        let mut inner = ChatTypeSecret::default();
-        inner.td_name = "chatTypeSecret".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatTypeSecretBuilder { inner }
+        RTDChatTypeSecretBuilder {
+            inner: ChatTypeSecret::default(),
+            missing: vec!["secret_chat_id", "user_id"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatTypeSecretBuilder {
+        RTDChatTypeSecretBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatTypeSecretBuilder {
+        RTDChatTypeSecretBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn secret_chat_id(&self) -> i64 {
+    pub fn secret_chat_id(&self) -> SecretChatId {
         self.secret_chat_id
     }
 
-    pub fn user_id(&self) -> i64 {
+    pub fn user_id(&self) -> UserId {
         self.user_id
     }
+
+    pub fn with_secret_chat_id<T: Into<SecretChatId>>(mut self, secret_chat_id: T) -> Self {
+        self.secret_chat_id = secret_chat_id.into();
+        self
+    }
+
+    pub fn with_user_id<T: Into<UserId>>(mut self, user_id: T) -> Self {
+        self.user_id = user_id.into();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDChatTypeSecretBuilder {
     inner: ChatTypeSecret,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatTypeSecretBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatTypeSecret {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatTypeSecret> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatTypeSecret",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn secret_chat_id(&mut self, secret_chat_id: i64) -> &mut Self {
-        self.inner.secret_chat_id = secret_chat_id;
+    pub fn secret_chat_id<T: Into<SecretChatId>>(&mut self, secret_chat_id: T) -> &mut Self {
+        self.inner.secret_chat_id = secret_chat_id.into();
+        self.missing.retain(|f| *f != "secret_chat_id");
         self
     }
 
-    pub fn user_id(&mut self, user_id: i64) -> &mut Self {
-        self.inner.user_id = user_id;
+    pub fn user_id<T: Into<UserId>>(&mut self, user_id: T) -> &mut Self {
+        self.inner.user_id = user_id.into();
+        self.missing.retain(|f| *f != "user_id");
         self
     }
 }
@@ -323,15 +465,16 @@ impl AsRef<ChatTypeSecret> for RTDChatTypeSecretBuilder {
 
 /// A supergroup (i.e. a chat with up to GetOption("supergroup_max_size") other users), or channel (with unlimited members)
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatTypeSupergroup")]
 pub struct ChatTypeSupergroup {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Supergroup or channel identifier
-    supergroup_id: i64,
+    supergroup_id: SupergroupId,
     /// True, if the supergroup is a channel
     is_channel: bool,
 }
@@ -350,6 +493,21 @@ impl RObject for ChatTypeSupergroup {
     }
 }
 
+impl PartialEq for ChatTypeSupergroup {
+    fn eq(&self, other: &Self) -> bool {
+        self.supergroup_id == other.supergroup_id && self.is_channel == other.is_channel
+    }
+}
+
+impl Eq for ChatTypeSupergroup {}
+
+impl std::hash::Hash for ChatTypeSupergroup {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.supergroup_id, state);
+        std::hash::Hash::hash(&self.is_channel, state);
+    }
+}
+
 impl TDChatType for ChatTypeSupergroup {}
 
 impl ChatTypeSupergroup {
@@ -357,33 +515,71 @@ impl ChatTypeSupergroup {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatTypeSupergroupBuilder {
-        let mut inner = ChatTypeSupergroup::default();
-        inner.td_name = "chatTypeSupergroup".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatTypeSupergroupBuilder { inner }
+        RTDChatTypeSupergroupBuilder {
+            inner: ChatTypeSupergroup::default(),
+            missing: vec!["supergroup_id"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatTypeSupergroupBuilder {
+        RTDChatTypeSupergroupBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatTypeSupergroupBuilder {
+        RTDChatTypeSupergroupBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn supergroup_id(&self) -> i64 {
+    pub fn supergroup_id(&self) -> SupergroupId {
         self.supergroup_id
     }
 
     pub fn is_channel(&self) -> bool {
         self.is_channel
     }
+
+    pub fn with_supergroup_id<T: Into<SupergroupId>>(mut self, supergroup_id: T) -> Self {
+        self.supergroup_id = supergroup_id.into();
+        self
+    }
+
+    pub fn with_is_channel(mut self, is_channel: bool) -> Self {
+        self.is_channel = is_channel;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDChatTypeSupergroupBuilder {
     inner: ChatTypeSupergroup,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatTypeSupergroupBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatTypeSupergroup {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatTypeSupergroup> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ChatTypeSupergroup",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn supergroup_id(&mut self, supergroup_id: i64) -> &mut Self {
-        self.inner.supergroup_id = supergroup_id;
+    pub fn supergroup_id<T: Into<SupergroupId>>(&mut self, supergroup_id: T) -> &mut Self {
+        self.inner.supergroup_id = supergroup_id.into();
+        self.missing.retain(|f| *f != "supergroup_id");
         self
     }
 
@@ -404,3 +600,33 @@ impl AsRef<ChatTypeSupergroup> for RTDChatTypeSupergroupBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(ChatType::BasicGroup(
+            ChatTypeBasicGroup::builder()
+                .basic_group_id(i32::MAX)
+                .build(),
+        ));
+        assert_round_trip(ChatType::Private(
+            ChatTypePrivate::builder().user_id(i32::MAX).build(),
+        ));
+        assert_round_trip(ChatType::Secret(
+            ChatTypeSecret::builder()
+                .secret_chat_id(i32::MAX)
+                .user_id(i32::MAX)
+                .build(),
+        ));
+        assert_round_trip(ChatType::Supergroup(
+            ChatTypeSupergroup::builder()
+                .supergroup_id(i32::MAX)
+                .is_channel(true)
+                .build(),
+        ));
+    }
+}
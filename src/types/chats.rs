@@ -1,18 +1,18 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Represents a list of chats
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chats")]
 pub struct Chats {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// List of chat identifiers
-    chat_ids: Vec<i64>,
+    chat_ids: Vec<ChatId>,
 }
 
 impl RObject for Chats {
@@ -29,34 +29,82 @@ impl RObject for Chats {
     }
 }
 
+impl PartialEq for Chats {
+    fn eq(&self, other: &Self) -> bool {
+        self.chat_ids == other.chat_ids
+    }
+}
+
+impl Eq for Chats {}
+
+impl std::hash::Hash for Chats {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.chat_ids, state);
+    }
+}
+
 impl Chats {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatsBuilder {
-        let mut inner = Chats::default();
-        inner.td_name = "chats".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatsBuilder { inner }
+        RTDChatsBuilder {
+            inner: Chats::default(),
+            missing: vec!["chat_ids"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatsBuilder {
+        RTDChatsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatsBuilder {
+        RTDChatsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn chat_ids(&self) -> &Vec<i64> {
+    pub fn chat_ids(&self) -> &Vec<ChatId> {
         &self.chat_ids
     }
+
+    pub fn into_chat_ids(self) -> Vec<ChatId> {
+        self.chat_ids
+    }
+
+    pub fn with_chat_ids(mut self, chat_ids: Vec<ChatId>) -> Self {
+        self.chat_ids = chat_ids;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDChatsBuilder {
     inner: Chats,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> Chats {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<Chats> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("Chats", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn chat_ids(&mut self, chat_ids: Vec<i64>) -> &mut Self {
+    pub fn chat_ids(&mut self, chat_ids: Vec<ChatId>) -> &mut Self {
         self.inner.chat_ids = chat_ids;
+        self.missing.retain(|f| *f != "chat_ids");
         self
     }
 }
@@ -72,3 +120,14 @@ impl AsRef<Chats> for RTDChatsBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(Chats::builder().chat_ids(vec![Default::default()]).build());
+    }
+}
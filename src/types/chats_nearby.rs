@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Represents a list of chats located nearby
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "chatsNearby")]
 pub struct ChatsNearby {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// List of users nearby
     users_nearby: Vec<ChatNearby>,
@@ -31,15 +31,45 @@ impl RObject for ChatsNearby {
     }
 }
 
+impl PartialEq for ChatsNearby {
+    fn eq(&self, other: &Self) -> bool {
+        self.users_nearby == other.users_nearby
+            && self.supergroups_nearby == other.supergroups_nearby
+    }
+}
+
+impl Eq for ChatsNearby {}
+
+impl std::hash::Hash for ChatsNearby {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.users_nearby, state);
+        std::hash::Hash::hash(&self.supergroups_nearby, state);
+    }
+}
+
 impl ChatsNearby {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDChatsNearbyBuilder {
-        let mut inner = ChatsNearby::default();
-        inner.td_name = "chatsNearby".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDChatsNearbyBuilder { inner }
+        RTDChatsNearbyBuilder {
+            inner: ChatsNearby::default(),
+            missing: vec!["users_nearby", "supergroups_nearby"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDChatsNearbyBuilder {
+        RTDChatsNearbyBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDChatsNearbyBuilder {
+        RTDChatsNearbyBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn users_nearby(&self) -> &Vec<ChatNearby> {
@@ -49,25 +79,55 @@ impl ChatsNearby {
     pub fn supergroups_nearby(&self) -> &Vec<ChatNearby> {
         &self.supergroups_nearby
     }
+
+    pub fn into_users_nearby(self) -> Vec<ChatNearby> {
+        self.users_nearby
+    }
+
+    pub fn into_supergroups_nearby(self) -> Vec<ChatNearby> {
+        self.supergroups_nearby
+    }
+
+    pub fn with_users_nearby(mut self, users_nearby: Vec<ChatNearby>) -> Self {
+        self.users_nearby = users_nearby;
+        self
+    }
+
+    pub fn with_supergroups_nearby(mut self, supergroups_nearby: Vec<ChatNearby>) -> Self {
+        self.supergroups_nearby = supergroups_nearby;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDChatsNearbyBuilder {
     inner: ChatsNearby,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDChatsNearbyBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ChatsNearby {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ChatsNearby> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("ChatsNearby", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn users_nearby(&mut self, users_nearby: Vec<ChatNearby>) -> &mut Self {
         self.inner.users_nearby = users_nearby;
+        self.missing.retain(|f| *f != "users_nearby");
         self
     }
 
     pub fn supergroups_nearby(&mut self, supergroups_nearby: Vec<ChatNearby>) -> &mut Self {
         self.inner.supergroups_nearby = supergroups_nearby;
+        self.missing.retain(|f| *f != "supergroups_nearby");
         self
     }
 }
@@ -83,3 +143,19 @@ impl AsRef<ChatsNearby> for RTDChatsNearbyBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            ChatsNearby::builder()
+                .users_nearby(vec![])
+                .supergroups_nearby(vec![])
+                .build(),
+        );
+    }
+}
@@ -1,55 +1,52 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Represents result of checking whether a username can be set for a chat
 pub trait TDCheckChatUsernameResult: Debug + RObject {}
 
 /// Represents result of checking whether a username can be set for a chat
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum CheckChatUsernameResult {
-    #[doc(hidden)]
-    _Default(()),
-    /// Checks whether a username can be set for a chat
-    CheckChatUsername(CheckChatUsername),
     /// The username can be set
+    #[serde(rename = "checkChatUsernameResultOk")]
     Ok(CheckChatUsernameResultOk),
     /// The user has too much chats with username, one of them should be made private first
+    #[serde(rename = "checkChatUsernameResultPublicChatsTooMuch")]
     PublicChatsTooMuch(CheckChatUsernameResultPublicChatsTooMuch),
     /// The user can't be a member of a public supergroup
+    #[serde(rename = "checkChatUsernameResultPublicGroupsUnavailable")]
     PublicGroupsUnavailable(CheckChatUsernameResultPublicGroupsUnavailable),
     /// The username is invalid
+    #[serde(rename = "checkChatUsernameResultUsernameInvalid")]
     UsernameInvalid(CheckChatUsernameResultUsernameInvalid),
     /// The username is occupied
+    #[serde(rename = "checkChatUsernameResultUsernameOccupied")]
     UsernameOccupied(CheckChatUsernameResultUsernameOccupied),
 }
 
+/// Default value is default of [CheckChatUsernameResultOk], builders still require the field to be set
 impl Default for CheckChatUsernameResult {
     fn default() -> Self {
-        CheckChatUsernameResult::_Default(())
+        CheckChatUsernameResult::Ok(CheckChatUsernameResultOk::default())
     }
 }
 
-impl<'de> Deserialize<'de> for CheckChatUsernameResult {
-    fn deserialize<D>(deserializer: D) -> Result<CheckChatUsernameResult, D::Error>
+impl Serialize for CheckChatUsernameResult {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          CheckChatUsernameResult,
-          (checkChatUsername, CheckChatUsername);
-          (checkChatUsernameResultOk, Ok);
-          (checkChatUsernameResultPublicChatsTooMuch, PublicChatsTooMuch);
-          (checkChatUsernameResultPublicGroupsUnavailable, PublicGroupsUnavailable);
-          (checkChatUsernameResultUsernameInvalid, UsernameInvalid);
-          (checkChatUsernameResultUsernameOccupied, UsernameOccupied);
-
-        )(deserializer)
+        match self {
+            CheckChatUsernameResult::Ok(t) => t.serialize(serializer),
+            CheckChatUsernameResult::PublicChatsTooMuch(t) => t.serialize(serializer),
+            CheckChatUsernameResult::PublicGroupsUnavailable(t) => t.serialize(serializer),
+            CheckChatUsernameResult::UsernameInvalid(t) => t.serialize(serializer),
+            CheckChatUsernameResult::UsernameOccupied(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -57,27 +54,21 @@ impl RObject for CheckChatUsernameResult {
     #[doc(hidden)]
     fn td_name(&self) -> &'static str {
         match self {
-            CheckChatUsernameResult::CheckChatUsername(t) => t.td_name(),
             CheckChatUsernameResult::Ok(t) => t.td_name(),
             CheckChatUsernameResult::PublicChatsTooMuch(t) => t.td_name(),
             CheckChatUsernameResult::PublicGroupsUnavailable(t) => t.td_name(),
             CheckChatUsernameResult::UsernameInvalid(t) => t.td_name(),
             CheckChatUsernameResult::UsernameOccupied(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
     fn extra(&self) -> Option<String> {
         match self {
-            CheckChatUsernameResult::CheckChatUsername(t) => t.extra(),
             CheckChatUsernameResult::Ok(t) => t.extra(),
             CheckChatUsernameResult::PublicChatsTooMuch(t) => t.extra(),
             CheckChatUsernameResult::PublicGroupsUnavailable(t) => t.extra(),
             CheckChatUsernameResult::UsernameInvalid(t) => t.extra(),
             CheckChatUsernameResult::UsernameOccupied(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -89,10 +80,6 @@ impl CheckChatUsernameResult {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, CheckChatUsernameResult::_Default(_))
-    }
 }
 
 impl AsRef<CheckChatUsernameResult> for CheckChatUsernameResult {
@@ -103,12 +90,13 @@ impl AsRef<CheckChatUsernameResult> for CheckChatUsernameResult {
 
 /// The username can be set
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "checkChatUsernameResultOk")]
 pub struct CheckChatUsernameResultOk {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -126,6 +114,18 @@ impl RObject for CheckChatUsernameResultOk {
     }
 }
 
+impl PartialEq for CheckChatUsernameResultOk {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for CheckChatUsernameResultOk {}
+
+impl std::hash::Hash for CheckChatUsernameResultOk {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDCheckChatUsernameResult for CheckChatUsernameResultOk {}
 
 impl CheckChatUsernameResultOk {
@@ -133,22 +133,49 @@ impl CheckChatUsernameResultOk {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDCheckChatUsernameResultOkBuilder {
-        let mut inner = CheckChatUsernameResultOk::default();
-        inner.td_name = "checkChatUsernameResultOk".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDCheckChatUsernameResultOkBuilder { inner }
+        RTDCheckChatUsernameResultOkBuilder {
+            inner: CheckChatUsernameResultOk::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDCheckChatUsernameResultOkBuilder {
+        RTDCheckChatUsernameResultOkBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDCheckChatUsernameResultOkBuilder {
+        RTDCheckChatUsernameResultOkBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDCheckChatUsernameResultOkBuilder {
     inner: CheckChatUsernameResultOk,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDCheckChatUsernameResultOkBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> CheckChatUsernameResultOk {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<CheckChatUsernameResultOk> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "CheckChatUsernameResultOk",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<CheckChatUsernameResultOk> for CheckChatUsernameResultOk {
@@ -165,12 +192,13 @@ impl AsRef<CheckChatUsernameResultOk> for RTDCheckChatUsernameResultOkBuilder {
 
 /// The user has too much chats with username, one of them should be made private first
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "checkChatUsernameResultPublicChatsTooMuch")]
 pub struct CheckChatUsernameResultPublicChatsTooMuch {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -188,6 +216,18 @@ impl RObject for CheckChatUsernameResultPublicChatsTooMuch {
     }
 }
 
+impl PartialEq for CheckChatUsernameResultPublicChatsTooMuch {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for CheckChatUsernameResultPublicChatsTooMuch {}
+
+impl std::hash::Hash for CheckChatUsernameResultPublicChatsTooMuch {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDCheckChatUsernameResult for CheckChatUsernameResultPublicChatsTooMuch {}
 
 impl CheckChatUsernameResultPublicChatsTooMuch {
@@ -195,22 +235,49 @@ impl CheckChatUsernameResultPublicChatsTooMuch {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDCheckChatUsernameResultPublicChatsTooMuchBuilder {
-        let mut inner = CheckChatUsernameResultPublicChatsTooMuch::default();
-        inner.td_name = "checkChatUsernameResultPublicChatsTooMuch".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDCheckChatUsernameResultPublicChatsTooMuchBuilder { inner }
+        RTDCheckChatUsernameResultPublicChatsTooMuchBuilder {
+            inner: CheckChatUsernameResultPublicChatsTooMuch::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDCheckChatUsernameResultPublicChatsTooMuchBuilder {
+        RTDCheckChatUsernameResultPublicChatsTooMuchBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDCheckChatUsernameResultPublicChatsTooMuchBuilder {
+        RTDCheckChatUsernameResultPublicChatsTooMuchBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDCheckChatUsernameResultPublicChatsTooMuchBuilder {
     inner: CheckChatUsernameResultPublicChatsTooMuch,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDCheckChatUsernameResultPublicChatsTooMuchBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> CheckChatUsernameResultPublicChatsTooMuch {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<CheckChatUsernameResultPublicChatsTooMuch> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "CheckChatUsernameResultPublicChatsTooMuch",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<CheckChatUsernameResultPublicChatsTooMuch>
@@ -231,12 +298,16 @@ impl AsRef<CheckChatUsernameResultPublicChatsTooMuch>
 
 /// The user can't be a member of a public supergroup
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(
+    tag = "@type",
+    rename = "checkChatUsernameResultPublicGroupsUnavailable"
+)]
 pub struct CheckChatUsernameResultPublicGroupsUnavailable {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -254,6 +325,18 @@ impl RObject for CheckChatUsernameResultPublicGroupsUnavailable {
     }
 }
 
+impl PartialEq for CheckChatUsernameResultPublicGroupsUnavailable {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for CheckChatUsernameResultPublicGroupsUnavailable {}
+
+impl std::hash::Hash for CheckChatUsernameResultPublicGroupsUnavailable {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDCheckChatUsernameResult for CheckChatUsernameResultPublicGroupsUnavailable {}
 
 impl CheckChatUsernameResultPublicGroupsUnavailable {
@@ -261,22 +344,49 @@ impl CheckChatUsernameResultPublicGroupsUnavailable {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDCheckChatUsernameResultPublicGroupsUnavailableBuilder {
-        let mut inner = CheckChatUsernameResultPublicGroupsUnavailable::default();
-        inner.td_name = "checkChatUsernameResultPublicGroupsUnavailable".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDCheckChatUsernameResultPublicGroupsUnavailableBuilder { inner }
+        RTDCheckChatUsernameResultPublicGroupsUnavailableBuilder {
+            inner: CheckChatUsernameResultPublicGroupsUnavailable::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDCheckChatUsernameResultPublicGroupsUnavailableBuilder {
+        RTDCheckChatUsernameResultPublicGroupsUnavailableBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDCheckChatUsernameResultPublicGroupsUnavailableBuilder {
+        RTDCheckChatUsernameResultPublicGroupsUnavailableBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDCheckChatUsernameResultPublicGroupsUnavailableBuilder {
     inner: CheckChatUsernameResultPublicGroupsUnavailable,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDCheckChatUsernameResultPublicGroupsUnavailableBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> CheckChatUsernameResultPublicGroupsUnavailable {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<CheckChatUsernameResultPublicGroupsUnavailable> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "CheckChatUsernameResultPublicGroupsUnavailable",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<CheckChatUsernameResultPublicGroupsUnavailable>
@@ -297,12 +407,13 @@ impl AsRef<CheckChatUsernameResultPublicGroupsUnavailable>
 
 /// The username is invalid
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "checkChatUsernameResultUsernameInvalid")]
 pub struct CheckChatUsernameResultUsernameInvalid {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -320,6 +431,18 @@ impl RObject for CheckChatUsernameResultUsernameInvalid {
     }
 }
 
+impl PartialEq for CheckChatUsernameResultUsernameInvalid {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for CheckChatUsernameResultUsernameInvalid {}
+
+impl std::hash::Hash for CheckChatUsernameResultUsernameInvalid {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDCheckChatUsernameResult for CheckChatUsernameResultUsernameInvalid {}
 
 impl CheckChatUsernameResultUsernameInvalid {
@@ -327,22 +450,49 @@ impl CheckChatUsernameResultUsernameInvalid {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDCheckChatUsernameResultUsernameInvalidBuilder {
-        let mut inner = CheckChatUsernameResultUsernameInvalid::default();
-        inner.td_name = "checkChatUsernameResultUsernameInvalid".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDCheckChatUsernameResultUsernameInvalidBuilder { inner }
+        RTDCheckChatUsernameResultUsernameInvalidBuilder {
+            inner: CheckChatUsernameResultUsernameInvalid::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDCheckChatUsernameResultUsernameInvalidBuilder {
+        RTDCheckChatUsernameResultUsernameInvalidBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDCheckChatUsernameResultUsernameInvalidBuilder {
+        RTDCheckChatUsernameResultUsernameInvalidBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDCheckChatUsernameResultUsernameInvalidBuilder {
     inner: CheckChatUsernameResultUsernameInvalid,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDCheckChatUsernameResultUsernameInvalidBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> CheckChatUsernameResultUsernameInvalid {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<CheckChatUsernameResultUsernameInvalid> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "CheckChatUsernameResultUsernameInvalid",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<CheckChatUsernameResultUsernameInvalid> for CheckChatUsernameResultUsernameInvalid {
@@ -361,12 +511,13 @@ impl AsRef<CheckChatUsernameResultUsernameInvalid>
 
 /// The username is occupied
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "checkChatUsernameResultUsernameOccupied")]
 pub struct CheckChatUsernameResultUsernameOccupied {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -384,6 +535,18 @@ impl RObject for CheckChatUsernameResultUsernameOccupied {
     }
 }
 
+impl PartialEq for CheckChatUsernameResultUsernameOccupied {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for CheckChatUsernameResultUsernameOccupied {}
+
+impl std::hash::Hash for CheckChatUsernameResultUsernameOccupied {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDCheckChatUsernameResult for CheckChatUsernameResultUsernameOccupied {}
 
 impl CheckChatUsernameResultUsernameOccupied {
@@ -391,22 +554,49 @@ impl CheckChatUsernameResultUsernameOccupied {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDCheckChatUsernameResultUsernameOccupiedBuilder {
-        let mut inner = CheckChatUsernameResultUsernameOccupied::default();
-        inner.td_name = "checkChatUsernameResultUsernameOccupied".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDCheckChatUsernameResultUsernameOccupiedBuilder { inner }
+        RTDCheckChatUsernameResultUsernameOccupiedBuilder {
+            inner: CheckChatUsernameResultUsernameOccupied::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDCheckChatUsernameResultUsernameOccupiedBuilder {
+        RTDCheckChatUsernameResultUsernameOccupiedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDCheckChatUsernameResultUsernameOccupiedBuilder {
+        RTDCheckChatUsernameResultUsernameOccupiedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDCheckChatUsernameResultUsernameOccupiedBuilder {
     inner: CheckChatUsernameResultUsernameOccupied,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDCheckChatUsernameResultUsernameOccupiedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> CheckChatUsernameResultUsernameOccupied {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<CheckChatUsernameResultUsernameOccupied> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "CheckChatUsernameResultUsernameOccupied",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<CheckChatUsernameResultUsernameOccupied> for CheckChatUsernameResultUsernameOccupied {
@@ -422,3 +612,28 @@ impl AsRef<CheckChatUsernameResultUsernameOccupied>
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(CheckChatUsernameResult::Ok(
+            CheckChatUsernameResultOk::builder().build(),
+        ));
+        assert_round_trip(CheckChatUsernameResult::PublicChatsTooMuch(
+            CheckChatUsernameResultPublicChatsTooMuch::builder().build(),
+        ));
+        assert_round_trip(CheckChatUsernameResult::PublicGroupsUnavailable(
+            CheckChatUsernameResultPublicGroupsUnavailable::builder().build(),
+        ));
+        assert_round_trip(CheckChatUsernameResult::UsernameInvalid(
+            CheckChatUsernameResultUsernameInvalid::builder().build(),
+        ));
+        assert_round_trip(CheckChatUsernameResult::UsernameOccupied(
+            CheckChatUsernameResultUsernameOccupied::builder().build(),
+        ));
+    }
+}
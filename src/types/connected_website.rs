@@ -1,30 +1,31 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains information about one website the current user is logged in with Telegram
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "connectedWebsite")]
 pub struct ConnectedWebsite {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Website identifier
-    id: isize,
+    #[serde(with = "super::_common::int64")]
+    id: i64,
     /// The domain name of the website
     domain_name: String,
     /// User identifier of a bot linked with the website
-    bot_user_id: i64,
+    bot_user_id: UserId,
     /// The version of a browser used to log in
     browser: String,
     /// Operating system the browser is running on
     platform: String,
     /// Point in time (Unix timestamp) when the user was logged in
-    log_in_date: i64,
+    log_in_date: i32,
     /// Point in time (Unix timestamp) when obtained authorization was last used
-    last_active_date: i64,
+    last_active_date: i32,
     /// IP address from which the user was logged in, in human-readable format
     ip: String,
     /// Human-readable description of a country and a region, from which the user was logged in, based on the IP address
@@ -45,18 +46,72 @@ impl RObject for ConnectedWebsite {
     }
 }
 
+impl PartialEq for ConnectedWebsite {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.domain_name == other.domain_name
+            && self.bot_user_id == other.bot_user_id
+            && self.browser == other.browser
+            && self.platform == other.platform
+            && self.log_in_date == other.log_in_date
+            && self.last_active_date == other.last_active_date
+            && self.ip == other.ip
+            && self.location == other.location
+    }
+}
+
+impl Eq for ConnectedWebsite {}
+
+impl std::hash::Hash for ConnectedWebsite {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.id, state);
+        std::hash::Hash::hash(&self.domain_name, state);
+        std::hash::Hash::hash(&self.bot_user_id, state);
+        std::hash::Hash::hash(&self.browser, state);
+        std::hash::Hash::hash(&self.platform, state);
+        std::hash::Hash::hash(&self.log_in_date, state);
+        std::hash::Hash::hash(&self.last_active_date, state);
+        std::hash::Hash::hash(&self.ip, state);
+        std::hash::Hash::hash(&self.location, state);
+    }
+}
+
 impl ConnectedWebsite {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDConnectedWebsiteBuilder {
-        let mut inner = ConnectedWebsite::default();
-        inner.td_name = "connectedWebsite".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDConnectedWebsiteBuilder { inner }
+        RTDConnectedWebsiteBuilder {
+            inner: ConnectedWebsite::default(),
+            missing: vec![
+                "id",
+                "domain_name",
+                "bot_user_id",
+                "browser",
+                "platform",
+                "log_in_date",
+                "last_active_date",
+                "ip",
+                "location",
+            ],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDConnectedWebsiteBuilder {
+        RTDConnectedWebsiteBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDConnectedWebsiteBuilder {
+        RTDConnectedWebsiteBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn id(&self) -> isize {
+    pub fn id(&self) -> i64 {
         self.id
     }
 
@@ -64,7 +119,7 @@ impl ConnectedWebsite {
         &self.domain_name
     }
 
-    pub fn bot_user_id(&self) -> i64 {
+    pub fn bot_user_id(&self) -> UserId {
         self.bot_user_id
     }
 
@@ -76,11 +131,11 @@ impl ConnectedWebsite {
         &self.platform
     }
 
-    pub fn log_in_date(&self) -> i64 {
+    pub fn log_in_date(&self) -> i32 {
         self.log_in_date
     }
 
-    pub fn last_active_date(&self) -> i64 {
+    pub fn last_active_date(&self) -> i32 {
         self.last_active_date
     }
 
@@ -91,60 +146,147 @@ impl ConnectedWebsite {
     pub fn location(&self) -> &String {
         &self.location
     }
+
+    pub fn into_domain_name(self) -> String {
+        self.domain_name
+    }
+
+    pub fn into_browser(self) -> String {
+        self.browser
+    }
+
+    pub fn into_platform(self) -> String {
+        self.platform
+    }
+
+    pub fn into_ip(self) -> String {
+        self.ip
+    }
+
+    pub fn into_location(self) -> String {
+        self.location
+    }
+
+    pub fn with_id(mut self, id: i64) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn with_domain_name<T: AsRef<str>>(mut self, domain_name: T) -> Self {
+        self.domain_name = domain_name.as_ref().to_string();
+        self
+    }
+
+    pub fn with_bot_user_id<T: Into<UserId>>(mut self, bot_user_id: T) -> Self {
+        self.bot_user_id = bot_user_id.into();
+        self
+    }
+
+    pub fn with_browser<T: AsRef<str>>(mut self, browser: T) -> Self {
+        self.browser = browser.as_ref().to_string();
+        self
+    }
+
+    pub fn with_platform<T: AsRef<str>>(mut self, platform: T) -> Self {
+        self.platform = platform.as_ref().to_string();
+        self
+    }
+
+    pub fn with_log_in_date(mut self, log_in_date: i32) -> Self {
+        self.log_in_date = log_in_date;
+        self
+    }
+
+    pub fn with_last_active_date(mut self, last_active_date: i32) -> Self {
+        self.last_active_date = last_active_date;
+        self
+    }
+
+    pub fn with_ip<T: AsRef<str>>(mut self, ip: T) -> Self {
+        self.ip = ip.as_ref().to_string();
+        self
+    }
+
+    pub fn with_location<T: AsRef<str>>(mut self, location: T) -> Self {
+        self.location = location.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDConnectedWebsiteBuilder {
     inner: ConnectedWebsite,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDConnectedWebsiteBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ConnectedWebsite {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ConnectedWebsite> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ConnectedWebsite",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn id(&mut self, id: isize) -> &mut Self {
+    pub fn id(&mut self, id: i64) -> &mut Self {
         self.inner.id = id;
+        self.missing.retain(|f| *f != "id");
         self
     }
 
     pub fn domain_name<T: AsRef<str>>(&mut self, domain_name: T) -> &mut Self {
         self.inner.domain_name = domain_name.as_ref().to_string();
+        self.missing.retain(|f| *f != "domain_name");
         self
     }
 
-    pub fn bot_user_id(&mut self, bot_user_id: i64) -> &mut Self {
-        self.inner.bot_user_id = bot_user_id;
+    pub fn bot_user_id<T: Into<UserId>>(&mut self, bot_user_id: T) -> &mut Self {
+        self.inner.bot_user_id = bot_user_id.into();
+        self.missing.retain(|f| *f != "bot_user_id");
         self
     }
 
     pub fn browser<T: AsRef<str>>(&mut self, browser: T) -> &mut Self {
         self.inner.browser = browser.as_ref().to_string();
+        self.missing.retain(|f| *f != "browser");
         self
     }
 
     pub fn platform<T: AsRef<str>>(&mut self, platform: T) -> &mut Self {
         self.inner.platform = platform.as_ref().to_string();
+        self.missing.retain(|f| *f != "platform");
         self
     }
 
-    pub fn log_in_date(&mut self, log_in_date: i64) -> &mut Self {
+    pub fn log_in_date(&mut self, log_in_date: i32) -> &mut Self {
         self.inner.log_in_date = log_in_date;
+        self.missing.retain(|f| *f != "log_in_date");
         self
     }
 
-    pub fn last_active_date(&mut self, last_active_date: i64) -> &mut Self {
+    pub fn last_active_date(&mut self, last_active_date: i32) -> &mut Self {
         self.inner.last_active_date = last_active_date;
+        self.missing.retain(|f| *f != "last_active_date");
         self
     }
 
     pub fn ip<T: AsRef<str>>(&mut self, ip: T) -> &mut Self {
         self.inner.ip = ip.as_ref().to_string();
+        self.missing.retain(|f| *f != "ip");
         self
     }
 
     pub fn location<T: AsRef<str>>(&mut self, location: T) -> &mut Self {
         self.inner.location = location.as_ref().to_string();
+        self.missing.retain(|f| *f != "location");
         self
     }
 }
@@ -160,3 +302,26 @@ impl AsRef<ConnectedWebsite> for RTDConnectedWebsiteBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            ConnectedWebsite::builder()
+                .id(i64::MAX)
+                .domain_name("text")
+                .bot_user_id(i32::MAX)
+                .browser("text")
+                .platform("text")
+                .log_in_date(i32::MAX)
+                .last_active_date(i32::MAX)
+                .ip("text")
+                .location("text")
+                .build(),
+        );
+    }
+}
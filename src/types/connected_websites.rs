@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains a list of websites the current user is logged in with Telegram
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "connectedWebsites")]
 pub struct ConnectedWebsites {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// List of connected websites
     websites: Vec<ConnectedWebsite>,
@@ -29,34 +29,85 @@ impl RObject for ConnectedWebsites {
     }
 }
 
+impl PartialEq for ConnectedWebsites {
+    fn eq(&self, other: &Self) -> bool {
+        self.websites == other.websites
+    }
+}
+
+impl Eq for ConnectedWebsites {}
+
+impl std::hash::Hash for ConnectedWebsites {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.websites, state);
+    }
+}
+
 impl ConnectedWebsites {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDConnectedWebsitesBuilder {
-        let mut inner = ConnectedWebsites::default();
-        inner.td_name = "connectedWebsites".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDConnectedWebsitesBuilder { inner }
+        RTDConnectedWebsitesBuilder {
+            inner: ConnectedWebsites::default(),
+            missing: vec!["websites"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDConnectedWebsitesBuilder {
+        RTDConnectedWebsitesBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDConnectedWebsitesBuilder {
+        RTDConnectedWebsitesBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn websites(&self) -> &Vec<ConnectedWebsite> {
         &self.websites
     }
+
+    pub fn into_websites(self) -> Vec<ConnectedWebsite> {
+        self.websites
+    }
+
+    pub fn with_websites(mut self, websites: Vec<ConnectedWebsite>) -> Self {
+        self.websites = websites;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDConnectedWebsitesBuilder {
     inner: ConnectedWebsites,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDConnectedWebsitesBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ConnectedWebsites {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ConnectedWebsites> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ConnectedWebsites",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn websites(&mut self, websites: Vec<ConnectedWebsite>) -> &mut Self {
         self.inner.websites = websites;
+        self.missing.retain(|f| *f != "websites");
         self
     }
 }
@@ -72,3 +123,14 @@ impl AsRef<ConnectedWebsites> for RTDConnectedWebsitesBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(ConnectedWebsites::builder().websites(vec![]).build());
+    }
+}
@@ -1,52 +1,52 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Describes the current state of the connection to Telegram servers
 pub trait TDConnectionState: Debug + RObject {}
 
 /// Describes the current state of the connection to Telegram servers
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum ConnectionState {
-    #[doc(hidden)]
-    _Default(()),
     /// Currently establishing a connection to the Telegram servers
+    #[serde(rename = "connectionStateConnecting")]
     Connecting(ConnectionStateConnecting),
     /// Currently establishing a connection with a proxy server
+    #[serde(rename = "connectionStateConnectingToProxy")]
     ConnectingToProxy(ConnectionStateConnectingToProxy),
     /// There is a working connection to the Telegram servers
+    #[serde(rename = "connectionStateReady")]
     Ready(ConnectionStateReady),
     /// Downloading data received while the client was offline
+    #[serde(rename = "connectionStateUpdating")]
     Updating(ConnectionStateUpdating),
     /// Currently waiting for the network to become available. Use setNetworkType to change the available network type
+    #[serde(rename = "connectionStateWaitingForNetwork")]
     WaitingForNetwork(ConnectionStateWaitingForNetwork),
 }
 
+/// Default value is default of [ConnectionStateConnecting], builders still require the field to be set
 impl Default for ConnectionState {
     fn default() -> Self {
-        ConnectionState::_Default(())
+        ConnectionState::Connecting(ConnectionStateConnecting::default())
     }
 }
 
-impl<'de> Deserialize<'de> for ConnectionState {
-    fn deserialize<D>(deserializer: D) -> Result<ConnectionState, D::Error>
+impl Serialize for ConnectionState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          ConnectionState,
-          (connectionStateConnecting, Connecting);
-          (connectionStateConnectingToProxy, ConnectingToProxy);
-          (connectionStateReady, Ready);
-          (connectionStateUpdating, Updating);
-          (connectionStateWaitingForNetwork, WaitingForNetwork);
-
-        )(deserializer)
+        match self {
+            ConnectionState::Connecting(t) => t.serialize(serializer),
+            ConnectionState::ConnectingToProxy(t) => t.serialize(serializer),
+            ConnectionState::Ready(t) => t.serialize(serializer),
+            ConnectionState::Updating(t) => t.serialize(serializer),
+            ConnectionState::WaitingForNetwork(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -59,8 +59,6 @@ impl RObject for ConnectionState {
             ConnectionState::Ready(t) => t.td_name(),
             ConnectionState::Updating(t) => t.td_name(),
             ConnectionState::WaitingForNetwork(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -71,8 +69,6 @@ impl RObject for ConnectionState {
             ConnectionState::Ready(t) => t.extra(),
             ConnectionState::Updating(t) => t.extra(),
             ConnectionState::WaitingForNetwork(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -84,10 +80,6 @@ impl ConnectionState {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, ConnectionState::_Default(_))
-    }
 }
 
 impl AsRef<ConnectionState> for ConnectionState {
@@ -98,12 +90,13 @@ impl AsRef<ConnectionState> for ConnectionState {
 
 /// Currently establishing a connection to the Telegram servers
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "connectionStateConnecting")]
 pub struct ConnectionStateConnecting {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -121,6 +114,18 @@ impl RObject for ConnectionStateConnecting {
     }
 }
 
+impl PartialEq for ConnectionStateConnecting {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ConnectionStateConnecting {}
+
+impl std::hash::Hash for ConnectionStateConnecting {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDConnectionState for ConnectionStateConnecting {}
 
 impl ConnectionStateConnecting {
@@ -128,22 +133,49 @@ impl ConnectionStateConnecting {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDConnectionStateConnectingBuilder {
-        let mut inner = ConnectionStateConnecting::default();
-        inner.td_name = "connectionStateConnecting".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDConnectionStateConnectingBuilder { inner }
+        RTDConnectionStateConnectingBuilder {
+            inner: ConnectionStateConnecting::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDConnectionStateConnectingBuilder {
+        RTDConnectionStateConnectingBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDConnectionStateConnectingBuilder {
+        RTDConnectionStateConnectingBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDConnectionStateConnectingBuilder {
     inner: ConnectionStateConnecting,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDConnectionStateConnectingBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ConnectionStateConnecting {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ConnectionStateConnecting> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ConnectionStateConnecting",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<ConnectionStateConnecting> for ConnectionStateConnecting {
@@ -160,12 +192,13 @@ impl AsRef<ConnectionStateConnecting> for RTDConnectionStateConnectingBuilder {
 
 /// Currently establishing a connection with a proxy server
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "connectionStateConnectingToProxy")]
 pub struct ConnectionStateConnectingToProxy {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -183,6 +216,18 @@ impl RObject for ConnectionStateConnectingToProxy {
     }
 }
 
+impl PartialEq for ConnectionStateConnectingToProxy {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ConnectionStateConnectingToProxy {}
+
+impl std::hash::Hash for ConnectionStateConnectingToProxy {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDConnectionState for ConnectionStateConnectingToProxy {}
 
 impl ConnectionStateConnectingToProxy {
@@ -190,22 +235,49 @@ impl ConnectionStateConnectingToProxy {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDConnectionStateConnectingToProxyBuilder {
-        let mut inner = ConnectionStateConnectingToProxy::default();
-        inner.td_name = "connectionStateConnectingToProxy".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDConnectionStateConnectingToProxyBuilder { inner }
+        RTDConnectionStateConnectingToProxyBuilder {
+            inner: ConnectionStateConnectingToProxy::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDConnectionStateConnectingToProxyBuilder {
+        RTDConnectionStateConnectingToProxyBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDConnectionStateConnectingToProxyBuilder {
+        RTDConnectionStateConnectingToProxyBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDConnectionStateConnectingToProxyBuilder {
     inner: ConnectionStateConnectingToProxy,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDConnectionStateConnectingToProxyBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ConnectionStateConnectingToProxy {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ConnectionStateConnectingToProxy> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ConnectionStateConnectingToProxy",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<ConnectionStateConnectingToProxy> for ConnectionStateConnectingToProxy {
@@ -222,12 +294,13 @@ impl AsRef<ConnectionStateConnectingToProxy> for RTDConnectionStateConnectingToP
 
 /// There is a working connection to the Telegram servers
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "connectionStateReady")]
 pub struct ConnectionStateReady {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -245,6 +318,18 @@ impl RObject for ConnectionStateReady {
     }
 }
 
+impl PartialEq for ConnectionStateReady {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ConnectionStateReady {}
+
+impl std::hash::Hash for ConnectionStateReady {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDConnectionState for ConnectionStateReady {}
 
 impl ConnectionStateReady {
@@ -252,22 +337,49 @@ impl ConnectionStateReady {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDConnectionStateReadyBuilder {
-        let mut inner = ConnectionStateReady::default();
-        inner.td_name = "connectionStateReady".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDConnectionStateReadyBuilder { inner }
+        RTDConnectionStateReadyBuilder {
+            inner: ConnectionStateReady::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDConnectionStateReadyBuilder {
+        RTDConnectionStateReadyBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDConnectionStateReadyBuilder {
+        RTDConnectionStateReadyBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDConnectionStateReadyBuilder {
     inner: ConnectionStateReady,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDConnectionStateReadyBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ConnectionStateReady {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ConnectionStateReady> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ConnectionStateReady",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<ConnectionStateReady> for ConnectionStateReady {
@@ -284,12 +396,13 @@ impl AsRef<ConnectionStateReady> for RTDConnectionStateReadyBuilder {
 
 /// Downloading data received while the client was offline
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "connectionStateUpdating")]
 pub struct ConnectionStateUpdating {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -307,6 +420,18 @@ impl RObject for ConnectionStateUpdating {
     }
 }
 
+impl PartialEq for ConnectionStateUpdating {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ConnectionStateUpdating {}
+
+impl std::hash::Hash for ConnectionStateUpdating {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDConnectionState for ConnectionStateUpdating {}
 
 impl ConnectionStateUpdating {
@@ -314,22 +439,49 @@ impl ConnectionStateUpdating {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDConnectionStateUpdatingBuilder {
-        let mut inner = ConnectionStateUpdating::default();
-        inner.td_name = "connectionStateUpdating".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDConnectionStateUpdatingBuilder { inner }
+        RTDConnectionStateUpdatingBuilder {
+            inner: ConnectionStateUpdating::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDConnectionStateUpdatingBuilder {
+        RTDConnectionStateUpdatingBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDConnectionStateUpdatingBuilder {
+        RTDConnectionStateUpdatingBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDConnectionStateUpdatingBuilder {
     inner: ConnectionStateUpdating,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDConnectionStateUpdatingBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ConnectionStateUpdating {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ConnectionStateUpdating> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ConnectionStateUpdating",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<ConnectionStateUpdating> for ConnectionStateUpdating {
@@ -346,12 +498,13 @@ impl AsRef<ConnectionStateUpdating> for RTDConnectionStateUpdatingBuilder {
 
 /// Currently waiting for the network to become available. Use setNetworkType to change the available network type
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "connectionStateWaitingForNetwork")]
 pub struct ConnectionStateWaitingForNetwork {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -369,6 +522,18 @@ impl RObject for ConnectionStateWaitingForNetwork {
     }
 }
 
+impl PartialEq for ConnectionStateWaitingForNetwork {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ConnectionStateWaitingForNetwork {}
+
+impl std::hash::Hash for ConnectionStateWaitingForNetwork {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDConnectionState for ConnectionStateWaitingForNetwork {}
 
 impl ConnectionStateWaitingForNetwork {
@@ -376,22 +541,49 @@ impl ConnectionStateWaitingForNetwork {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDConnectionStateWaitingForNetworkBuilder {
-        let mut inner = ConnectionStateWaitingForNetwork::default();
-        inner.td_name = "connectionStateWaitingForNetwork".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDConnectionStateWaitingForNetworkBuilder { inner }
+        RTDConnectionStateWaitingForNetworkBuilder {
+            inner: ConnectionStateWaitingForNetwork::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDConnectionStateWaitingForNetworkBuilder {
+        RTDConnectionStateWaitingForNetworkBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDConnectionStateWaitingForNetworkBuilder {
+        RTDConnectionStateWaitingForNetworkBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDConnectionStateWaitingForNetworkBuilder {
     inner: ConnectionStateWaitingForNetwork,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDConnectionStateWaitingForNetworkBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ConnectionStateWaitingForNetwork {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ConnectionStateWaitingForNetwork> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ConnectionStateWaitingForNetwork",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<ConnectionStateWaitingForNetwork> for ConnectionStateWaitingForNetwork {
@@ -405,3 +597,28 @@ impl AsRef<ConnectionStateWaitingForNetwork> for RTDConnectionStateWaitingForNet
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(ConnectionState::Connecting(
+            ConnectionStateConnecting::builder().build(),
+        ));
+        assert_round_trip(ConnectionState::ConnectingToProxy(
+            ConnectionStateConnectingToProxy::builder().build(),
+        ));
+        assert_round_trip(ConnectionState::Ready(
+            ConnectionStateReady::builder().build(),
+        ));
+        assert_round_trip(ConnectionState::Updating(
+            ConnectionStateUpdating::builder().build(),
+        ));
+        assert_round_trip(ConnectionState::WaitingForNetwork(
+            ConnectionStateWaitingForNetwork::builder().build(),
+        ));
+    }
+}
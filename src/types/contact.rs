@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Describes a user contact
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "contact")]
 pub struct Contact {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Phone number of the user
     phone_number: String,
@@ -20,7 +20,7 @@ pub struct Contact {
     /// Additional data about the user in a form of vCard; 0-2048 bytes in length
     vcard: String,
     /// Identifier of the user, if known; otherwise 0
-    user_id: i64,
+    user_id: UserId,
 }
 
 impl RObject for Contact {
@@ -37,15 +37,51 @@ impl RObject for Contact {
     }
 }
 
+impl PartialEq for Contact {
+    fn eq(&self, other: &Self) -> bool {
+        self.phone_number == other.phone_number
+            && self.first_name == other.first_name
+            && self.last_name == other.last_name
+            && self.vcard == other.vcard
+            && self.user_id == other.user_id
+    }
+}
+
+impl Eq for Contact {}
+
+impl std::hash::Hash for Contact {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.phone_number, state);
+        std::hash::Hash::hash(&self.first_name, state);
+        std::hash::Hash::hash(&self.last_name, state);
+        std::hash::Hash::hash(&self.vcard, state);
+        std::hash::Hash::hash(&self.user_id, state);
+    }
+}
+
 impl Contact {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDContactBuilder {
-        let mut inner = Contact::default();
-        inner.td_name = "contact".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDContactBuilder { inner }
+        RTDContactBuilder {
+            inner: Contact::default(),
+            missing: vec!["phone_number", "first_name", "last_name"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDContactBuilder {
+        RTDContactBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDContactBuilder {
+        RTDContactBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn phone_number(&self) -> &String {
@@ -64,33 +100,87 @@ impl Contact {
         &self.vcard
     }
 
-    pub fn user_id(&self) -> i64 {
+    pub fn user_id(&self) -> UserId {
         self.user_id
     }
+
+    pub fn into_phone_number(self) -> String {
+        self.phone_number
+    }
+
+    pub fn into_first_name(self) -> String {
+        self.first_name
+    }
+
+    pub fn into_last_name(self) -> String {
+        self.last_name
+    }
+
+    pub fn into_vcard(self) -> String {
+        self.vcard
+    }
+
+    pub fn with_phone_number<T: AsRef<str>>(mut self, phone_number: T) -> Self {
+        self.phone_number = phone_number.as_ref().to_string();
+        self
+    }
+
+    pub fn with_first_name<T: AsRef<str>>(mut self, first_name: T) -> Self {
+        self.first_name = first_name.as_ref().to_string();
+        self
+    }
+
+    pub fn with_last_name<T: AsRef<str>>(mut self, last_name: T) -> Self {
+        self.last_name = last_name.as_ref().to_string();
+        self
+    }
+
+    pub fn with_vcard<T: AsRef<str>>(mut self, vcard: T) -> Self {
+        self.vcard = vcard.as_ref().to_string();
+        self
+    }
+
+    pub fn with_user_id<T: Into<UserId>>(mut self, user_id: T) -> Self {
+        self.user_id = user_id.into();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDContactBuilder {
     inner: Contact,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDContactBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> Contact {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<Contact> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("Contact", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn phone_number<T: AsRef<str>>(&mut self, phone_number: T) -> &mut Self {
         self.inner.phone_number = phone_number.as_ref().to_string();
+        self.missing.retain(|f| *f != "phone_number");
         self
     }
 
     pub fn first_name<T: AsRef<str>>(&mut self, first_name: T) -> &mut Self {
         self.inner.first_name = first_name.as_ref().to_string();
+        self.missing.retain(|f| *f != "first_name");
         self
     }
 
     pub fn last_name<T: AsRef<str>>(&mut self, last_name: T) -> &mut Self {
         self.inner.last_name = last_name.as_ref().to_string();
+        self.missing.retain(|f| *f != "last_name");
         self
     }
 
@@ -99,8 +189,8 @@ impl RTDContactBuilder {
         self
     }
 
-    pub fn user_id(&mut self, user_id: i64) -> &mut Self {
-        self.inner.user_id = user_id;
+    pub fn user_id<T: Into<UserId>>(&mut self, user_id: T) -> &mut Self {
+        self.inner.user_id = user_id.into();
         self
     }
 }
@@ -116,3 +206,22 @@ impl AsRef<Contact> for RTDContactBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            Contact::builder()
+                .phone_number("text")
+                .first_name("text")
+                .last_name("text")
+                .vcard("text")
+                .user_id(i32::MAX)
+                .build(),
+        );
+    }
+}
@@ -1,18 +1,18 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains a counter
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "count")]
 pub struct Count {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Count
-    count: i64,
+    count: i32,
 }
 
 impl RObject for Count {
@@ -29,34 +29,78 @@ impl RObject for Count {
     }
 }
 
+impl PartialEq for Count {
+    fn eq(&self, other: &Self) -> bool {
+        self.count == other.count
+    }
+}
+
+impl Eq for Count {}
+
+impl std::hash::Hash for Count {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.count, state);
+    }
+}
+
 impl Count {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDCountBuilder {
-        let mut inner = Count::default();
-        inner.td_name = "count".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDCountBuilder { inner }
+        RTDCountBuilder {
+            inner: Count::default(),
+            missing: vec!["count"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDCountBuilder {
+        RTDCountBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDCountBuilder {
+        RTDCountBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn count(&self) -> i64 {
+    pub fn count(&self) -> i32 {
         self.count
     }
+
+    pub fn with_count(mut self, count: i32) -> Self {
+        self.count = count;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDCountBuilder {
     inner: Count,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDCountBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> Count {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<Count> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("Count", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn count(&mut self, count: i64) -> &mut Self {
+    pub fn count(&mut self, count: i32) -> &mut Self {
         self.inner.count = count;
+        self.missing.retain(|f| *f != "count");
         self
     }
 }
@@ -72,3 +116,14 @@ impl AsRef<Count> for RTDCountBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(Count::builder().count(i32::MAX).build());
+    }
+}
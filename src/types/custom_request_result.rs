@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains the result of a custom request
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "customRequestResult")]
 pub struct CustomRequestResult {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// A JSON-serialized result
     result: String,
@@ -29,34 +29,85 @@ impl RObject for CustomRequestResult {
     }
 }
 
+impl PartialEq for CustomRequestResult {
+    fn eq(&self, other: &Self) -> bool {
+        self.result == other.result
+    }
+}
+
+impl Eq for CustomRequestResult {}
+
+impl std::hash::Hash for CustomRequestResult {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.result, state);
+    }
+}
+
 impl CustomRequestResult {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDCustomRequestResultBuilder {
-        let mut inner = CustomRequestResult::default();
-        inner.td_name = "customRequestResult".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDCustomRequestResultBuilder { inner }
+        RTDCustomRequestResultBuilder {
+            inner: CustomRequestResult::default(),
+            missing: vec!["result"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDCustomRequestResultBuilder {
+        RTDCustomRequestResultBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDCustomRequestResultBuilder {
+        RTDCustomRequestResultBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn result(&self) -> &String {
         &self.result
     }
+
+    pub fn into_result(self) -> String {
+        self.result
+    }
+
+    pub fn with_result<T: AsRef<str>>(mut self, result: T) -> Self {
+        self.result = result.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDCustomRequestResultBuilder {
     inner: CustomRequestResult,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDCustomRequestResultBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> CustomRequestResult {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<CustomRequestResult> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "CustomRequestResult",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn result<T: AsRef<str>>(&mut self, result: T) -> &mut Self {
         self.inner.result = result.as_ref().to_string();
+        self.missing.retain(|f| *f != "result");
         self
     }
 }
@@ -72,3 +123,14 @@ impl AsRef<CustomRequestResult> for RTDCustomRequestResultBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(CustomRequestResult::builder().result("text").build());
+    }
+}
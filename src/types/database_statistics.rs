@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains database statistics
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "databaseStatistics")]
 pub struct DatabaseStatistics {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Database statistics in an unspecified human-readable format
     statistics: String,
@@ -29,34 +29,85 @@ impl RObject for DatabaseStatistics {
     }
 }
 
+impl PartialEq for DatabaseStatistics {
+    fn eq(&self, other: &Self) -> bool {
+        self.statistics == other.statistics
+    }
+}
+
+impl Eq for DatabaseStatistics {}
+
+impl std::hash::Hash for DatabaseStatistics {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.statistics, state);
+    }
+}
+
 impl DatabaseStatistics {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDDatabaseStatisticsBuilder {
-        let mut inner = DatabaseStatistics::default();
-        inner.td_name = "databaseStatistics".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDDatabaseStatisticsBuilder { inner }
+        RTDDatabaseStatisticsBuilder {
+            inner: DatabaseStatistics::default(),
+            missing: vec!["statistics"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDDatabaseStatisticsBuilder {
+        RTDDatabaseStatisticsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDDatabaseStatisticsBuilder {
+        RTDDatabaseStatisticsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn statistics(&self) -> &String {
         &self.statistics
     }
+
+    pub fn into_statistics(self) -> String {
+        self.statistics
+    }
+
+    pub fn with_statistics<T: AsRef<str>>(mut self, statistics: T) -> Self {
+        self.statistics = statistics.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDDatabaseStatisticsBuilder {
     inner: DatabaseStatistics,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDDatabaseStatisticsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> DatabaseStatistics {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<DatabaseStatistics> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "DatabaseStatistics",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn statistics<T: AsRef<str>>(&mut self, statistics: T) -> &mut Self {
         self.inner.statistics = statistics.as_ref().to_string();
+        self.missing.retain(|f| *f != "statistics");
         self
     }
 }
@@ -72,3 +123,14 @@ impl AsRef<DatabaseStatistics> for RTDDatabaseStatisticsBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(DatabaseStatistics::builder().statistics("text").build());
+    }
+}
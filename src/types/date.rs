@@ -1,22 +1,22 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Represents a date according to the Gregorian calendar
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "date")]
 pub struct Date {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Day of the month, 1-31
-    day: i64,
+    day: i32,
     /// Month, 1-12
-    month: i64,
+    month: i32,
     /// Year, 1-9999
-    year: i64,
+    year: i32,
 }
 
 impl RObject for Date {
@@ -33,52 +33,110 @@ impl RObject for Date {
     }
 }
 
+impl PartialEq for Date {
+    fn eq(&self, other: &Self) -> bool {
+        self.day == other.day && self.month == other.month && self.year == other.year
+    }
+}
+
+impl Eq for Date {}
+
+impl std::hash::Hash for Date {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.day, state);
+        std::hash::Hash::hash(&self.month, state);
+        std::hash::Hash::hash(&self.year, state);
+    }
+}
+
 impl Date {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDDateBuilder {
-        let mut inner = Date::default();
-        inner.td_name = "date".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDDateBuilder { inner }
+        RTDDateBuilder {
+            inner: Date::default(),
+            missing: vec!["day", "month", "year"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDDateBuilder {
+        RTDDateBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDDateBuilder {
+        RTDDateBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn day(&self) -> i64 {
+    pub fn day(&self) -> i32 {
         self.day
     }
 
-    pub fn month(&self) -> i64 {
+    pub fn month(&self) -> i32 {
         self.month
     }
 
-    pub fn year(&self) -> i64 {
+    pub fn year(&self) -> i32 {
         self.year
     }
+
+    pub fn with_day(mut self, day: i32) -> Self {
+        self.day = day;
+        self
+    }
+
+    pub fn with_month(mut self, month: i32) -> Self {
+        self.month = month;
+        self
+    }
+
+    pub fn with_year(mut self, year: i32) -> Self {
+        self.year = year;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDDateBuilder {
     inner: Date,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDDateBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> Date {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<Date> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("Date", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn day(&mut self, day: i64) -> &mut Self {
+    pub fn day(&mut self, day: i32) -> &mut Self {
         self.inner.day = day;
+        self.missing.retain(|f| *f != "day");
         self
     }
 
-    pub fn month(&mut self, month: i64) -> &mut Self {
+    pub fn month(&mut self, month: i32) -> &mut Self {
         self.inner.month = month;
+        self.missing.retain(|f| *f != "month");
         self
     }
 
-    pub fn year(&mut self, year: i64) -> &mut Self {
+    pub fn year(&mut self, year: i32) -> &mut Self {
         self.inner.year = year;
+        self.missing.retain(|f| *f != "year");
         self
     }
 }
@@ -94,3 +152,20 @@ impl AsRef<Date> for RTDDateBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            Date::builder()
+                .day(i32::MAX)
+                .month(i32::MAX)
+                .year(i32::MAX)
+                .build(),
+        );
+    }
+}
@@ -1,20 +1,20 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// File with the date it was uploaded
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "datedFile")]
 pub struct DatedFile {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The file
     file: File,
     /// Point in time (Unix timestamp) when the file was uploaded
-    date: i64,
+    date: i32,
 }
 
 impl RObject for DatedFile {
@@ -31,43 +31,98 @@ impl RObject for DatedFile {
     }
 }
 
+impl PartialEq for DatedFile {
+    fn eq(&self, other: &Self) -> bool {
+        self.file == other.file && self.date == other.date
+    }
+}
+
+impl Eq for DatedFile {}
+
+impl std::hash::Hash for DatedFile {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.file, state);
+        std::hash::Hash::hash(&self.date, state);
+    }
+}
+
 impl DatedFile {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDDatedFileBuilder {
-        let mut inner = DatedFile::default();
-        inner.td_name = "datedFile".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDDatedFileBuilder { inner }
+        RTDDatedFileBuilder {
+            inner: DatedFile::default(),
+            missing: vec!["file", "date"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDDatedFileBuilder {
+        RTDDatedFileBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDDatedFileBuilder {
+        RTDDatedFileBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn file(&self) -> &File {
         &self.file
     }
 
-    pub fn date(&self) -> i64 {
+    pub fn date(&self) -> i32 {
         self.date
     }
+
+    pub fn into_file(self) -> File {
+        self.file
+    }
+
+    pub fn with_file<T: AsRef<File>>(mut self, file: T) -> Self {
+        self.file = file.as_ref().clone();
+        self
+    }
+
+    pub fn with_date(mut self, date: i32) -> Self {
+        self.date = date;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDDatedFileBuilder {
     inner: DatedFile,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDDatedFileBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> DatedFile {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<DatedFile> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("DatedFile", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn file<T: AsRef<File>>(&mut self, file: T) -> &mut Self {
         self.inner.file = file.as_ref().clone();
+        self.missing.retain(|f| *f != "file");
         self
     }
 
-    pub fn date(&mut self, date: i64) -> &mut Self {
+    pub fn date(&mut self, date: i32) -> &mut Self {
         self.inner.date = date;
+        self.missing.retain(|f| *f != "date");
         self
     }
 }
@@ -83,3 +138,46 @@ impl AsRef<DatedFile> for RTDDatedFileBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            DatedFile::builder()
+                .file(
+                    File::builder()
+                        .id(i32::MAX)
+                        .size(i32::MAX)
+                        .expected_size(i32::MAX)
+                        .local(
+                            LocalFile::builder()
+                                .path("text")
+                                .can_be_downloaded(true)
+                                .can_be_deleted(true)
+                                .is_downloading_active(true)
+                                .is_downloading_completed(true)
+                                .download_offset(i32::MAX)
+                                .downloaded_prefix_size(i32::MAX)
+                                .downloaded_size(i32::MAX)
+                                .build(),
+                        )
+                        .remote(
+                            RemoteFile::builder()
+                                .id("text")
+                                .unique_id("text")
+                                .is_uploading_active(true)
+                                .is_uploading_completed(true)
+                                .uploaded_size(i32::MAX)
+                                .build(),
+                        )
+                        .build(),
+                )
+                .date(i32::MAX)
+                .build(),
+        );
+    }
+}
@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains information about a tg:// deep link
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "deepLinkInfo")]
 pub struct DeepLinkInfo {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Text to be shown to the user
     text: FormattedText,
@@ -31,15 +31,44 @@ impl RObject for DeepLinkInfo {
     }
 }
 
+impl PartialEq for DeepLinkInfo {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text && self.need_update_application == other.need_update_application
+    }
+}
+
+impl Eq for DeepLinkInfo {}
+
+impl std::hash::Hash for DeepLinkInfo {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.text, state);
+        std::hash::Hash::hash(&self.need_update_application, state);
+    }
+}
+
 impl DeepLinkInfo {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDDeepLinkInfoBuilder {
-        let mut inner = DeepLinkInfo::default();
-        inner.td_name = "deepLinkInfo".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDDeepLinkInfoBuilder { inner }
+        RTDDeepLinkInfoBuilder {
+            inner: DeepLinkInfo::default(),
+            missing: vec!["text"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDDeepLinkInfoBuilder {
+        RTDDeepLinkInfoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDDeepLinkInfoBuilder {
+        RTDDeepLinkInfoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn text(&self) -> &FormattedText {
@@ -49,20 +78,48 @@ impl DeepLinkInfo {
     pub fn need_update_application(&self) -> bool {
         self.need_update_application
     }
+
+    pub fn into_text(self) -> FormattedText {
+        self.text
+    }
+
+    pub fn with_text<T: AsRef<FormattedText>>(mut self, text: T) -> Self {
+        self.text = text.as_ref().clone();
+        self
+    }
+
+    pub fn with_need_update_application(mut self, need_update_application: bool) -> Self {
+        self.need_update_application = need_update_application;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDDeepLinkInfoBuilder {
     inner: DeepLinkInfo,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDDeepLinkInfoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> DeepLinkInfo {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<DeepLinkInfo> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "DeepLinkInfo",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn text<T: AsRef<FormattedText>>(&mut self, text: T) -> &mut Self {
         self.inner.text = text.as_ref().clone();
+        self.missing.retain(|f| *f != "text");
         self
     }
 
@@ -83,3 +140,24 @@ impl AsRef<DeepLinkInfo> for RTDDeepLinkInfoBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            DeepLinkInfo::builder()
+                .text(
+                    FormattedText::builder()
+                        .text("text")
+                        .entities(vec![])
+                        .build(),
+                )
+                .need_update_application(true)
+                .build(),
+        );
+    }
+}
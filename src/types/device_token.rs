@@ -1,70 +1,76 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Represents a data needed to subscribe for push notifications through registerDevice method. To use specific push notification service, you must specify the correct application platform and upload valid server authentication data at https://my.telegram.org
 pub trait TDDeviceToken: Debug + RObject {}
 
 /// Represents a data needed to subscribe for push notifications through registerDevice method. To use specific push notification service, you must specify the correct application platform and upload valid server authentication data at https://my.telegram.org
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum DeviceToken {
-    #[doc(hidden)]
-    _Default(()),
     /// A token for Apple Push Notification service
+    #[serde(rename = "deviceTokenApplePush")]
     ApplePush(DeviceTokenApplePush),
     /// A token for Apple Push Notification service VoIP notifications
+    #[serde(rename = "deviceTokenApplePushVoIP")]
     ApplePushVoIP(DeviceTokenApplePushVoIP),
     /// A token for BlackBerry Push Service
+    #[serde(rename = "deviceTokenBlackBerryPush")]
     BlackBerryPush(DeviceTokenBlackBerryPush),
     /// A token for Firebase Cloud Messaging
+    #[serde(rename = "deviceTokenFirebaseCloudMessaging")]
     FirebaseCloudMessaging(DeviceTokenFirebaseCloudMessaging),
     /// A token for Microsoft Push Notification Service
+    #[serde(rename = "deviceTokenMicrosoftPush")]
     MicrosoftPush(DeviceTokenMicrosoftPush),
     /// A token for Microsoft Push Notification Service VoIP channel
+    #[serde(rename = "deviceTokenMicrosoftPushVoIP")]
     MicrosoftPushVoIP(DeviceTokenMicrosoftPushVoIP),
     /// A token for Simple Push API for Firefox OS
+    #[serde(rename = "deviceTokenSimplePush")]
     SimplePush(DeviceTokenSimplePush),
     /// A token for Tizen Push Service
+    #[serde(rename = "deviceTokenTizenPush")]
     TizenPush(DeviceTokenTizenPush),
     /// A token for Ubuntu Push Client service
+    #[serde(rename = "deviceTokenUbuntuPush")]
     UbuntuPush(DeviceTokenUbuntuPush),
     /// A token for web Push API
+    #[serde(rename = "deviceTokenWebPush")]
     WebPush(DeviceTokenWebPush),
     /// A token for Windows Push Notification Services
+    #[serde(rename = "deviceTokenWindowsPush")]
     WindowsPush(DeviceTokenWindowsPush),
 }
 
+/// Default value is default of [DeviceTokenApplePush], builders still require the field to be set
 impl Default for DeviceToken {
     fn default() -> Self {
-        DeviceToken::_Default(())
+        DeviceToken::ApplePush(DeviceTokenApplePush::default())
     }
 }
 
-impl<'de> Deserialize<'de> for DeviceToken {
-    fn deserialize<D>(deserializer: D) -> Result<DeviceToken, D::Error>
+impl Serialize for DeviceToken {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          DeviceToken,
-          (deviceTokenApplePush, ApplePush);
-          (deviceTokenApplePushVoIP, ApplePushVoIP);
-          (deviceTokenBlackBerryPush, BlackBerryPush);
-          (deviceTokenFirebaseCloudMessaging, FirebaseCloudMessaging);
-          (deviceTokenMicrosoftPush, MicrosoftPush);
-          (deviceTokenMicrosoftPushVoIP, MicrosoftPushVoIP);
-          (deviceTokenSimplePush, SimplePush);
-          (deviceTokenTizenPush, TizenPush);
-          (deviceTokenUbuntuPush, UbuntuPush);
-          (deviceTokenWebPush, WebPush);
-          (deviceTokenWindowsPush, WindowsPush);
-
-        )(deserializer)
+        match self {
+            DeviceToken::ApplePush(t) => t.serialize(serializer),
+            DeviceToken::ApplePushVoIP(t) => t.serialize(serializer),
+            DeviceToken::BlackBerryPush(t) => t.serialize(serializer),
+            DeviceToken::FirebaseCloudMessaging(t) => t.serialize(serializer),
+            DeviceToken::MicrosoftPush(t) => t.serialize(serializer),
+            DeviceToken::MicrosoftPushVoIP(t) => t.serialize(serializer),
+            DeviceToken::SimplePush(t) => t.serialize(serializer),
+            DeviceToken::TizenPush(t) => t.serialize(serializer),
+            DeviceToken::UbuntuPush(t) => t.serialize(serializer),
+            DeviceToken::WebPush(t) => t.serialize(serializer),
+            DeviceToken::WindowsPush(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -83,8 +89,6 @@ impl RObject for DeviceToken {
             DeviceToken::UbuntuPush(t) => t.td_name(),
             DeviceToken::WebPush(t) => t.td_name(),
             DeviceToken::WindowsPush(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -101,8 +105,6 @@ impl RObject for DeviceToken {
             DeviceToken::UbuntuPush(t) => t.extra(),
             DeviceToken::WebPush(t) => t.extra(),
             DeviceToken::WindowsPush(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -114,10 +116,6 @@ impl DeviceToken {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, DeviceToken::_Default(_))
-    }
 }
 
 impl AsRef<DeviceToken> for DeviceToken {
@@ -128,12 +126,13 @@ impl AsRef<DeviceToken> for DeviceToken {
 
 /// A token for Apple Push Notification service
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "deviceTokenApplePush")]
 pub struct DeviceTokenApplePush {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Device token; may be empty to de-register a device
     device_token: String,
@@ -155,6 +154,21 @@ impl RObject for DeviceTokenApplePush {
     }
 }
 
+impl PartialEq for DeviceTokenApplePush {
+    fn eq(&self, other: &Self) -> bool {
+        self.device_token == other.device_token && self.is_app_sandbox == other.is_app_sandbox
+    }
+}
+
+impl Eq for DeviceTokenApplePush {}
+
+impl std::hash::Hash for DeviceTokenApplePush {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.device_token, state);
+        std::hash::Hash::hash(&self.is_app_sandbox, state);
+    }
+}
+
 impl TDDeviceToken for DeviceTokenApplePush {}
 
 impl DeviceTokenApplePush {
@@ -162,10 +176,24 @@ impl DeviceTokenApplePush {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDDeviceTokenApplePushBuilder {
-        let mut inner = DeviceTokenApplePush::default();
-        inner.td_name = "deviceTokenApplePush".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDDeviceTokenApplePushBuilder { inner }
+        RTDDeviceTokenApplePushBuilder {
+            inner: DeviceTokenApplePush::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDDeviceTokenApplePushBuilder {
+        RTDDeviceTokenApplePushBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDDeviceTokenApplePushBuilder {
+        RTDDeviceTokenApplePushBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn device_token(&self) -> &String {
@@ -175,17 +203,44 @@ impl DeviceTokenApplePush {
     pub fn is_app_sandbox(&self) -> bool {
         self.is_app_sandbox
     }
+
+    pub fn into_device_token(self) -> String {
+        self.device_token
+    }
+
+    pub fn with_device_token<T: AsRef<str>>(mut self, device_token: T) -> Self {
+        self.device_token = device_token.as_ref().to_string();
+        self
+    }
+
+    pub fn with_is_app_sandbox(mut self, is_app_sandbox: bool) -> Self {
+        self.is_app_sandbox = is_app_sandbox;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDDeviceTokenApplePushBuilder {
     inner: DeviceTokenApplePush,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDDeviceTokenApplePushBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> DeviceTokenApplePush {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<DeviceTokenApplePush> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "DeviceTokenApplePush",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn device_token<T: AsRef<str>>(&mut self, device_token: T) -> &mut Self {
         self.inner.device_token = device_token.as_ref().to_string();
@@ -212,12 +267,13 @@ impl AsRef<DeviceTokenApplePush> for RTDDeviceTokenApplePushBuilder {
 
 /// A token for Apple Push Notification service VoIP notifications
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "deviceTokenApplePushVoIP")]
 pub struct DeviceTokenApplePushVoIP {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Device token; may be empty to de-register a device
     device_token: String,
@@ -241,6 +297,24 @@ impl RObject for DeviceTokenApplePushVoIP {
     }
 }
 
+impl PartialEq for DeviceTokenApplePushVoIP {
+    fn eq(&self, other: &Self) -> bool {
+        self.device_token == other.device_token
+            && self.is_app_sandbox == other.is_app_sandbox
+            && self.encrypt == other.encrypt
+    }
+}
+
+impl Eq for DeviceTokenApplePushVoIP {}
+
+impl std::hash::Hash for DeviceTokenApplePushVoIP {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.device_token, state);
+        std::hash::Hash::hash(&self.is_app_sandbox, state);
+        std::hash::Hash::hash(&self.encrypt, state);
+    }
+}
+
 impl TDDeviceToken for DeviceTokenApplePushVoIP {}
 
 impl DeviceTokenApplePushVoIP {
@@ -248,10 +322,24 @@ impl DeviceTokenApplePushVoIP {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDDeviceTokenApplePushVoIPBuilder {
-        let mut inner = DeviceTokenApplePushVoIP::default();
-        inner.td_name = "deviceTokenApplePushVoIP".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDDeviceTokenApplePushVoIPBuilder { inner }
+        RTDDeviceTokenApplePushVoIPBuilder {
+            inner: DeviceTokenApplePushVoIP::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDDeviceTokenApplePushVoIPBuilder {
+        RTDDeviceTokenApplePushVoIPBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDDeviceTokenApplePushVoIPBuilder {
+        RTDDeviceTokenApplePushVoIPBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn device_token(&self) -> &String {
@@ -265,17 +353,49 @@ impl DeviceTokenApplePushVoIP {
     pub fn encrypt(&self) -> bool {
         self.encrypt
     }
+
+    pub fn into_device_token(self) -> String {
+        self.device_token
+    }
+
+    pub fn with_device_token<T: AsRef<str>>(mut self, device_token: T) -> Self {
+        self.device_token = device_token.as_ref().to_string();
+        self
+    }
+
+    pub fn with_is_app_sandbox(mut self, is_app_sandbox: bool) -> Self {
+        self.is_app_sandbox = is_app_sandbox;
+        self
+    }
+
+    pub fn with_encrypt(mut self, encrypt: bool) -> Self {
+        self.encrypt = encrypt;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDDeviceTokenApplePushVoIPBuilder {
     inner: DeviceTokenApplePushVoIP,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDDeviceTokenApplePushVoIPBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> DeviceTokenApplePushVoIP {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<DeviceTokenApplePushVoIP> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "DeviceTokenApplePushVoIP",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn device_token<T: AsRef<str>>(&mut self, device_token: T) -> &mut Self {
         self.inner.device_token = device_token.as_ref().to_string();
@@ -307,12 +427,13 @@ impl AsRef<DeviceTokenApplePushVoIP> for RTDDeviceTokenApplePushVoIPBuilder {
 
 /// A token for BlackBerry Push Service
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "deviceTokenBlackBerryPush")]
 pub struct DeviceTokenBlackBerryPush {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Token; may be empty to de-register a device
     token: String,
@@ -332,6 +453,20 @@ impl RObject for DeviceTokenBlackBerryPush {
     }
 }
 
+impl PartialEq for DeviceTokenBlackBerryPush {
+    fn eq(&self, other: &Self) -> bool {
+        self.token == other.token
+    }
+}
+
+impl Eq for DeviceTokenBlackBerryPush {}
+
+impl std::hash::Hash for DeviceTokenBlackBerryPush {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.token, state);
+    }
+}
+
 impl TDDeviceToken for DeviceTokenBlackBerryPush {}
 
 impl DeviceTokenBlackBerryPush {
@@ -339,26 +474,62 @@ impl DeviceTokenBlackBerryPush {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDDeviceTokenBlackBerryPushBuilder {
-        let mut inner = DeviceTokenBlackBerryPush::default();
-        inner.td_name = "deviceTokenBlackBerryPush".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDDeviceTokenBlackBerryPushBuilder { inner }
+        RTDDeviceTokenBlackBerryPushBuilder {
+            inner: DeviceTokenBlackBerryPush::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDDeviceTokenBlackBerryPushBuilder {
+        RTDDeviceTokenBlackBerryPushBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDDeviceTokenBlackBerryPushBuilder {
+        RTDDeviceTokenBlackBerryPushBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn token(&self) -> &String {
         &self.token
     }
+
+    pub fn into_token(self) -> String {
+        self.token
+    }
+
+    pub fn with_token<T: AsRef<str>>(mut self, token: T) -> Self {
+        self.token = token.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDDeviceTokenBlackBerryPushBuilder {
     inner: DeviceTokenBlackBerryPush,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDDeviceTokenBlackBerryPushBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> DeviceTokenBlackBerryPush {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<DeviceTokenBlackBerryPush> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "DeviceTokenBlackBerryPush",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn token<T: AsRef<str>>(&mut self, token: T) -> &mut Self {
         self.inner.token = token.as_ref().to_string();
@@ -380,12 +551,13 @@ impl AsRef<DeviceTokenBlackBerryPush> for RTDDeviceTokenBlackBerryPushBuilder {
 
 /// A token for Firebase Cloud Messaging
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "deviceTokenFirebaseCloudMessaging")]
 pub struct DeviceTokenFirebaseCloudMessaging {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Device registration token; may be empty to de-register a device
     token: String,
@@ -407,6 +579,21 @@ impl RObject for DeviceTokenFirebaseCloudMessaging {
     }
 }
 
+impl PartialEq for DeviceTokenFirebaseCloudMessaging {
+    fn eq(&self, other: &Self) -> bool {
+        self.token == other.token && self.encrypt == other.encrypt
+    }
+}
+
+impl Eq for DeviceTokenFirebaseCloudMessaging {}
+
+impl std::hash::Hash for DeviceTokenFirebaseCloudMessaging {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.token, state);
+        std::hash::Hash::hash(&self.encrypt, state);
+    }
+}
+
 impl TDDeviceToken for DeviceTokenFirebaseCloudMessaging {}
 
 impl DeviceTokenFirebaseCloudMessaging {
@@ -414,10 +601,24 @@ impl DeviceTokenFirebaseCloudMessaging {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDDeviceTokenFirebaseCloudMessagingBuilder {
-        let mut inner = DeviceTokenFirebaseCloudMessaging::default();
-        inner.td_name = "deviceTokenFirebaseCloudMessaging".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDDeviceTokenFirebaseCloudMessagingBuilder { inner }
+        RTDDeviceTokenFirebaseCloudMessagingBuilder {
+            inner: DeviceTokenFirebaseCloudMessaging::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDDeviceTokenFirebaseCloudMessagingBuilder {
+        RTDDeviceTokenFirebaseCloudMessagingBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDDeviceTokenFirebaseCloudMessagingBuilder {
+        RTDDeviceTokenFirebaseCloudMessagingBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn token(&self) -> &String {
@@ -427,17 +628,44 @@ impl DeviceTokenFirebaseCloudMessaging {
     pub fn encrypt(&self) -> bool {
         self.encrypt
     }
+
+    pub fn into_token(self) -> String {
+        self.token
+    }
+
+    pub fn with_token<T: AsRef<str>>(mut self, token: T) -> Self {
+        self.token = token.as_ref().to_string();
+        self
+    }
+
+    pub fn with_encrypt(mut self, encrypt: bool) -> Self {
+        self.encrypt = encrypt;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDDeviceTokenFirebaseCloudMessagingBuilder {
     inner: DeviceTokenFirebaseCloudMessaging,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDDeviceTokenFirebaseCloudMessagingBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> DeviceTokenFirebaseCloudMessaging {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<DeviceTokenFirebaseCloudMessaging> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "DeviceTokenFirebaseCloudMessaging",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn token<T: AsRef<str>>(&mut self, token: T) -> &mut Self {
         self.inner.token = token.as_ref().to_string();
@@ -464,12 +692,13 @@ impl AsRef<DeviceTokenFirebaseCloudMessaging> for RTDDeviceTokenFirebaseCloudMes
 
 /// A token for Microsoft Push Notification Service
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "deviceTokenMicrosoftPush")]
 pub struct DeviceTokenMicrosoftPush {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Push notification channel URI; may be empty to de-register a device
     channel_uri: String,
@@ -489,6 +718,20 @@ impl RObject for DeviceTokenMicrosoftPush {
     }
 }
 
+impl PartialEq for DeviceTokenMicrosoftPush {
+    fn eq(&self, other: &Self) -> bool {
+        self.channel_uri == other.channel_uri
+    }
+}
+
+impl Eq for DeviceTokenMicrosoftPush {}
+
+impl std::hash::Hash for DeviceTokenMicrosoftPush {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.channel_uri, state);
+    }
+}
+
 impl TDDeviceToken for DeviceTokenMicrosoftPush {}
 
 impl DeviceTokenMicrosoftPush {
@@ -496,26 +739,62 @@ impl DeviceTokenMicrosoftPush {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDDeviceTokenMicrosoftPushBuilder {
-        let mut inner = DeviceTokenMicrosoftPush::default();
-        inner.td_name = "deviceTokenMicrosoftPush".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDDeviceTokenMicrosoftPushBuilder { inner }
+        RTDDeviceTokenMicrosoftPushBuilder {
+            inner: DeviceTokenMicrosoftPush::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDDeviceTokenMicrosoftPushBuilder {
+        RTDDeviceTokenMicrosoftPushBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDDeviceTokenMicrosoftPushBuilder {
+        RTDDeviceTokenMicrosoftPushBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn channel_uri(&self) -> &String {
         &self.channel_uri
     }
+
+    pub fn into_channel_uri(self) -> String {
+        self.channel_uri
+    }
+
+    pub fn with_channel_uri<T: AsRef<str>>(mut self, channel_uri: T) -> Self {
+        self.channel_uri = channel_uri.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDDeviceTokenMicrosoftPushBuilder {
     inner: DeviceTokenMicrosoftPush,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDDeviceTokenMicrosoftPushBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> DeviceTokenMicrosoftPush {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<DeviceTokenMicrosoftPush> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "DeviceTokenMicrosoftPush",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn channel_uri<T: AsRef<str>>(&mut self, channel_uri: T) -> &mut Self {
         self.inner.channel_uri = channel_uri.as_ref().to_string();
@@ -537,12 +816,13 @@ impl AsRef<DeviceTokenMicrosoftPush> for RTDDeviceTokenMicrosoftPushBuilder {
 
 /// A token for Microsoft Push Notification Service VoIP channel
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "deviceTokenMicrosoftPushVoIP")]
 pub struct DeviceTokenMicrosoftPushVoIP {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Push notification channel URI; may be empty to de-register a device
     channel_uri: String,
@@ -562,6 +842,20 @@ impl RObject for DeviceTokenMicrosoftPushVoIP {
     }
 }
 
+impl PartialEq for DeviceTokenMicrosoftPushVoIP {
+    fn eq(&self, other: &Self) -> bool {
+        self.channel_uri == other.channel_uri
+    }
+}
+
+impl Eq for DeviceTokenMicrosoftPushVoIP {}
+
+impl std::hash::Hash for DeviceTokenMicrosoftPushVoIP {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.channel_uri, state);
+    }
+}
+
 impl TDDeviceToken for DeviceTokenMicrosoftPushVoIP {}
 
 impl DeviceTokenMicrosoftPushVoIP {
@@ -569,26 +863,62 @@ impl DeviceTokenMicrosoftPushVoIP {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDDeviceTokenMicrosoftPushVoIPBuilder {
-        let mut inner = DeviceTokenMicrosoftPushVoIP::default();
-        inner.td_name = "deviceTokenMicrosoftPushVoIP".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDDeviceTokenMicrosoftPushVoIPBuilder { inner }
+        RTDDeviceTokenMicrosoftPushVoIPBuilder {
+            inner: DeviceTokenMicrosoftPushVoIP::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDDeviceTokenMicrosoftPushVoIPBuilder {
+        RTDDeviceTokenMicrosoftPushVoIPBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDDeviceTokenMicrosoftPushVoIPBuilder {
+        RTDDeviceTokenMicrosoftPushVoIPBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn channel_uri(&self) -> &String {
         &self.channel_uri
     }
+
+    pub fn into_channel_uri(self) -> String {
+        self.channel_uri
+    }
+
+    pub fn with_channel_uri<T: AsRef<str>>(mut self, channel_uri: T) -> Self {
+        self.channel_uri = channel_uri.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDDeviceTokenMicrosoftPushVoIPBuilder {
     inner: DeviceTokenMicrosoftPushVoIP,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDDeviceTokenMicrosoftPushVoIPBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> DeviceTokenMicrosoftPushVoIP {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<DeviceTokenMicrosoftPushVoIP> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "DeviceTokenMicrosoftPushVoIP",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn channel_uri<T: AsRef<str>>(&mut self, channel_uri: T) -> &mut Self {
         self.inner.channel_uri = channel_uri.as_ref().to_string();
@@ -610,12 +940,13 @@ impl AsRef<DeviceTokenMicrosoftPushVoIP> for RTDDeviceTokenMicrosoftPushVoIPBuil
 
 /// A token for Simple Push API for Firefox OS
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "deviceTokenSimplePush")]
 pub struct DeviceTokenSimplePush {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Absolute URL exposed by the push service where the application server can send push messages; may be empty to de-register a device
     endpoint: String,
@@ -635,6 +966,20 @@ impl RObject for DeviceTokenSimplePush {
     }
 }
 
+impl PartialEq for DeviceTokenSimplePush {
+    fn eq(&self, other: &Self) -> bool {
+        self.endpoint == other.endpoint
+    }
+}
+
+impl Eq for DeviceTokenSimplePush {}
+
+impl std::hash::Hash for DeviceTokenSimplePush {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.endpoint, state);
+    }
+}
+
 impl TDDeviceToken for DeviceTokenSimplePush {}
 
 impl DeviceTokenSimplePush {
@@ -642,26 +987,62 @@ impl DeviceTokenSimplePush {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDDeviceTokenSimplePushBuilder {
-        let mut inner = DeviceTokenSimplePush::default();
-        inner.td_name = "deviceTokenSimplePush".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDDeviceTokenSimplePushBuilder { inner }
+        RTDDeviceTokenSimplePushBuilder {
+            inner: DeviceTokenSimplePush::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDDeviceTokenSimplePushBuilder {
+        RTDDeviceTokenSimplePushBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDDeviceTokenSimplePushBuilder {
+        RTDDeviceTokenSimplePushBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn endpoint(&self) -> &String {
         &self.endpoint
     }
+
+    pub fn into_endpoint(self) -> String {
+        self.endpoint
+    }
+
+    pub fn with_endpoint<T: AsRef<str>>(mut self, endpoint: T) -> Self {
+        self.endpoint = endpoint.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDDeviceTokenSimplePushBuilder {
     inner: DeviceTokenSimplePush,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDDeviceTokenSimplePushBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> DeviceTokenSimplePush {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<DeviceTokenSimplePush> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "DeviceTokenSimplePush",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn endpoint<T: AsRef<str>>(&mut self, endpoint: T) -> &mut Self {
         self.inner.endpoint = endpoint.as_ref().to_string();
@@ -683,12 +1064,13 @@ impl AsRef<DeviceTokenSimplePush> for RTDDeviceTokenSimplePushBuilder {
 
 /// A token for Tizen Push Service
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "deviceTokenTizenPush")]
 pub struct DeviceTokenTizenPush {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Push service registration identifier; may be empty to de-register a device
     reg_id: String,
@@ -708,6 +1090,20 @@ impl RObject for DeviceTokenTizenPush {
     }
 }
 
+impl PartialEq for DeviceTokenTizenPush {
+    fn eq(&self, other: &Self) -> bool {
+        self.reg_id == other.reg_id
+    }
+}
+
+impl Eq for DeviceTokenTizenPush {}
+
+impl std::hash::Hash for DeviceTokenTizenPush {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.reg_id, state);
+    }
+}
+
 impl TDDeviceToken for DeviceTokenTizenPush {}
 
 impl DeviceTokenTizenPush {
@@ -715,26 +1111,62 @@ impl DeviceTokenTizenPush {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDDeviceTokenTizenPushBuilder {
-        let mut inner = DeviceTokenTizenPush::default();
-        inner.td_name = "deviceTokenTizenPush".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDDeviceTokenTizenPushBuilder { inner }
+        RTDDeviceTokenTizenPushBuilder {
+            inner: DeviceTokenTizenPush::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDDeviceTokenTizenPushBuilder {
+        RTDDeviceTokenTizenPushBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDDeviceTokenTizenPushBuilder {
+        RTDDeviceTokenTizenPushBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn reg_id(&self) -> &String {
         &self.reg_id
     }
+
+    pub fn into_reg_id(self) -> String {
+        self.reg_id
+    }
+
+    pub fn with_reg_id<T: AsRef<str>>(mut self, reg_id: T) -> Self {
+        self.reg_id = reg_id.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDDeviceTokenTizenPushBuilder {
     inner: DeviceTokenTizenPush,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDDeviceTokenTizenPushBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> DeviceTokenTizenPush {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<DeviceTokenTizenPush> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "DeviceTokenTizenPush",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn reg_id<T: AsRef<str>>(&mut self, reg_id: T) -> &mut Self {
         self.inner.reg_id = reg_id.as_ref().to_string();
@@ -756,12 +1188,13 @@ impl AsRef<DeviceTokenTizenPush> for RTDDeviceTokenTizenPushBuilder {
 
 /// A token for Ubuntu Push Client service
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "deviceTokenUbuntuPush")]
 pub struct DeviceTokenUbuntuPush {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Token; may be empty to de-register a device
     token: String,
@@ -781,6 +1214,20 @@ impl RObject for DeviceTokenUbuntuPush {
     }
 }
 
+impl PartialEq for DeviceTokenUbuntuPush {
+    fn eq(&self, other: &Self) -> bool {
+        self.token == other.token
+    }
+}
+
+impl Eq for DeviceTokenUbuntuPush {}
+
+impl std::hash::Hash for DeviceTokenUbuntuPush {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.token, state);
+    }
+}
+
 impl TDDeviceToken for DeviceTokenUbuntuPush {}
 
 impl DeviceTokenUbuntuPush {
@@ -788,26 +1235,62 @@ impl DeviceTokenUbuntuPush {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDDeviceTokenUbuntuPushBuilder {
-        let mut inner = DeviceTokenUbuntuPush::default();
-        inner.td_name = "deviceTokenUbuntuPush".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDDeviceTokenUbuntuPushBuilder { inner }
+        RTDDeviceTokenUbuntuPushBuilder {
+            inner: DeviceTokenUbuntuPush::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDDeviceTokenUbuntuPushBuilder {
+        RTDDeviceTokenUbuntuPushBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDDeviceTokenUbuntuPushBuilder {
+        RTDDeviceTokenUbuntuPushBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn token(&self) -> &String {
         &self.token
     }
+
+    pub fn into_token(self) -> String {
+        self.token
+    }
+
+    pub fn with_token<T: AsRef<str>>(mut self, token: T) -> Self {
+        self.token = token.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDDeviceTokenUbuntuPushBuilder {
     inner: DeviceTokenUbuntuPush,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDDeviceTokenUbuntuPushBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> DeviceTokenUbuntuPush {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<DeviceTokenUbuntuPush> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "DeviceTokenUbuntuPush",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn token<T: AsRef<str>>(&mut self, token: T) -> &mut Self {
         self.inner.token = token.as_ref().to_string();
@@ -829,12 +1312,13 @@ impl AsRef<DeviceTokenUbuntuPush> for RTDDeviceTokenUbuntuPushBuilder {
 
 /// A token for web Push API
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "deviceTokenWebPush")]
 pub struct DeviceTokenWebPush {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Absolute URL exposed by the push service where the application server can send push messages; may be empty to de-register a device
     endpoint: String,
@@ -858,6 +1342,24 @@ impl RObject for DeviceTokenWebPush {
     }
 }
 
+impl PartialEq for DeviceTokenWebPush {
+    fn eq(&self, other: &Self) -> bool {
+        self.endpoint == other.endpoint
+            && self.p256dh_base64url == other.p256dh_base64url
+            && self.auth_base64url == other.auth_base64url
+    }
+}
+
+impl Eq for DeviceTokenWebPush {}
+
+impl std::hash::Hash for DeviceTokenWebPush {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.endpoint, state);
+        std::hash::Hash::hash(&self.p256dh_base64url, state);
+        std::hash::Hash::hash(&self.auth_base64url, state);
+    }
+}
+
 impl TDDeviceToken for DeviceTokenWebPush {}
 
 impl DeviceTokenWebPush {
@@ -865,10 +1367,24 @@ impl DeviceTokenWebPush {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDDeviceTokenWebPushBuilder {
-        let mut inner = DeviceTokenWebPush::default();
-        inner.td_name = "deviceTokenWebPush".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDDeviceTokenWebPushBuilder { inner }
+        RTDDeviceTokenWebPushBuilder {
+            inner: DeviceTokenWebPush::default(),
+            missing: vec!["p256dh_base64url", "auth_base64url"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDDeviceTokenWebPushBuilder {
+        RTDDeviceTokenWebPushBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDDeviceTokenWebPushBuilder {
+        RTDDeviceTokenWebPushBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn endpoint(&self) -> &String {
@@ -882,17 +1398,57 @@ impl DeviceTokenWebPush {
     pub fn auth_base64url(&self) -> &String {
         &self.auth_base64url
     }
+
+    pub fn into_endpoint(self) -> String {
+        self.endpoint
+    }
+
+    pub fn into_p256dh_base64url(self) -> String {
+        self.p256dh_base64url
+    }
+
+    pub fn into_auth_base64url(self) -> String {
+        self.auth_base64url
+    }
+
+    pub fn with_endpoint<T: AsRef<str>>(mut self, endpoint: T) -> Self {
+        self.endpoint = endpoint.as_ref().to_string();
+        self
+    }
+
+    pub fn with_p256dh_base64url<T: AsRef<str>>(mut self, p256dh_base64url: T) -> Self {
+        self.p256dh_base64url = p256dh_base64url.as_ref().to_string();
+        self
+    }
+
+    pub fn with_auth_base64url<T: AsRef<str>>(mut self, auth_base64url: T) -> Self {
+        self.auth_base64url = auth_base64url.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDDeviceTokenWebPushBuilder {
     inner: DeviceTokenWebPush,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDDeviceTokenWebPushBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> DeviceTokenWebPush {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<DeviceTokenWebPush> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "DeviceTokenWebPush",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn endpoint<T: AsRef<str>>(&mut self, endpoint: T) -> &mut Self {
         self.inner.endpoint = endpoint.as_ref().to_string();
@@ -901,11 +1457,13 @@ impl RTDDeviceTokenWebPushBuilder {
 
     pub fn p256dh_base64url<T: AsRef<str>>(&mut self, p256dh_base64url: T) -> &mut Self {
         self.inner.p256dh_base64url = p256dh_base64url.as_ref().to_string();
+        self.missing.retain(|f| *f != "p256dh_base64url");
         self
     }
 
     pub fn auth_base64url<T: AsRef<str>>(&mut self, auth_base64url: T) -> &mut Self {
         self.inner.auth_base64url = auth_base64url.as_ref().to_string();
+        self.missing.retain(|f| *f != "auth_base64url");
         self
     }
 }
@@ -924,12 +1482,13 @@ impl AsRef<DeviceTokenWebPush> for RTDDeviceTokenWebPushBuilder {
 
 /// A token for Windows Push Notification Services
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "deviceTokenWindowsPush")]
 pub struct DeviceTokenWindowsPush {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The access token that will be used to send notifications; may be empty to de-register a device
     access_token: String,
@@ -949,6 +1508,20 @@ impl RObject for DeviceTokenWindowsPush {
     }
 }
 
+impl PartialEq for DeviceTokenWindowsPush {
+    fn eq(&self, other: &Self) -> bool {
+        self.access_token == other.access_token
+    }
+}
+
+impl Eq for DeviceTokenWindowsPush {}
+
+impl std::hash::Hash for DeviceTokenWindowsPush {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.access_token, state);
+    }
+}
+
 impl TDDeviceToken for DeviceTokenWindowsPush {}
 
 impl DeviceTokenWindowsPush {
@@ -956,26 +1529,62 @@ impl DeviceTokenWindowsPush {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDDeviceTokenWindowsPushBuilder {
-        let mut inner = DeviceTokenWindowsPush::default();
-        inner.td_name = "deviceTokenWindowsPush".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDDeviceTokenWindowsPushBuilder { inner }
+        RTDDeviceTokenWindowsPushBuilder {
+            inner: DeviceTokenWindowsPush::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDDeviceTokenWindowsPushBuilder {
+        RTDDeviceTokenWindowsPushBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDDeviceTokenWindowsPushBuilder {
+        RTDDeviceTokenWindowsPushBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn access_token(&self) -> &String {
         &self.access_token
     }
+
+    pub fn into_access_token(self) -> String {
+        self.access_token
+    }
+
+    pub fn with_access_token<T: AsRef<str>>(mut self, access_token: T) -> Self {
+        self.access_token = access_token.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDDeviceTokenWindowsPushBuilder {
     inner: DeviceTokenWindowsPush,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDDeviceTokenWindowsPushBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> DeviceTokenWindowsPush {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<DeviceTokenWindowsPush> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "DeviceTokenWindowsPush",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn access_token<T: AsRef<str>>(&mut self, access_token: T) -> &mut Self {
         self.inner.access_token = access_token.as_ref().to_string();
@@ -994,3 +1603,66 @@ impl AsRef<DeviceTokenWindowsPush> for RTDDeviceTokenWindowsPushBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(DeviceToken::ApplePush(
+            DeviceTokenApplePush::builder()
+                .device_token("text")
+                .is_app_sandbox(true)
+                .build(),
+        ));
+        assert_round_trip(DeviceToken::ApplePushVoIP(
+            DeviceTokenApplePushVoIP::builder()
+                .device_token("text")
+                .is_app_sandbox(true)
+                .encrypt(true)
+                .build(),
+        ));
+        assert_round_trip(DeviceToken::BlackBerryPush(
+            DeviceTokenBlackBerryPush::builder().token("text").build(),
+        ));
+        assert_round_trip(DeviceToken::FirebaseCloudMessaging(
+            DeviceTokenFirebaseCloudMessaging::builder()
+                .token("text")
+                .encrypt(true)
+                .build(),
+        ));
+        assert_round_trip(DeviceToken::MicrosoftPush(
+            DeviceTokenMicrosoftPush::builder()
+                .channel_uri("text")
+                .build(),
+        ));
+        assert_round_trip(DeviceToken::MicrosoftPushVoIP(
+            DeviceTokenMicrosoftPushVoIP::builder()
+                .channel_uri("text")
+                .build(),
+        ));
+        assert_round_trip(DeviceToken::SimplePush(
+            DeviceTokenSimplePush::builder().endpoint("text").build(),
+        ));
+        assert_round_trip(DeviceToken::TizenPush(
+            DeviceTokenTizenPush::builder().reg_id("text").build(),
+        ));
+        assert_round_trip(DeviceToken::UbuntuPush(
+            DeviceTokenUbuntuPush::builder().token("text").build(),
+        ));
+        assert_round_trip(DeviceToken::WebPush(
+            DeviceTokenWebPush::builder()
+                .endpoint("text")
+                .p256dh_base64url("text")
+                .auth_base64url("text")
+                .build(),
+        ));
+        assert_round_trip(DeviceToken::WindowsPush(
+            DeviceTokenWindowsPush::builder()
+                .access_token("text")
+                .build(),
+        ));
+    }
+}
@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Describes a document of any type
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "document")]
 pub struct Document {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Original name of the file; as defined by the sender
     file_name: String,
@@ -37,15 +37,51 @@ impl RObject for Document {
     }
 }
 
+impl PartialEq for Document {
+    fn eq(&self, other: &Self) -> bool {
+        self.file_name == other.file_name
+            && self.mime_type == other.mime_type
+            && self.minithumbnail == other.minithumbnail
+            && self.thumbnail == other.thumbnail
+            && self.document == other.document
+    }
+}
+
+impl Eq for Document {}
+
+impl std::hash::Hash for Document {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.file_name, state);
+        std::hash::Hash::hash(&self.mime_type, state);
+        std::hash::Hash::hash(&self.minithumbnail, state);
+        std::hash::Hash::hash(&self.thumbnail, state);
+        std::hash::Hash::hash(&self.document, state);
+    }
+}
+
 impl Document {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDDocumentBuilder {
-        let mut inner = Document::default();
-        inner.td_name = "document".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDDocumentBuilder { inner }
+        RTDDocumentBuilder {
+            inner: Document::default(),
+            missing: vec!["file_name", "mime_type", "document"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDDocumentBuilder {
+        RTDDocumentBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDDocumentBuilder {
+        RTDDocumentBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn file_name(&self) -> &String {
@@ -67,25 +103,82 @@ impl Document {
     pub fn document(&self) -> &File {
         &self.document
     }
+
+    pub fn into_file_name(self) -> String {
+        self.file_name
+    }
+
+    pub fn into_mime_type(self) -> String {
+        self.mime_type
+    }
+
+    pub fn into_minithumbnail(self) -> Option<Minithumbnail> {
+        self.minithumbnail
+    }
+
+    pub fn into_thumbnail(self) -> Option<PhotoSize> {
+        self.thumbnail
+    }
+
+    pub fn into_document(self) -> File {
+        self.document
+    }
+
+    pub fn with_file_name<T: AsRef<str>>(mut self, file_name: T) -> Self {
+        self.file_name = file_name.as_ref().to_string();
+        self
+    }
+
+    pub fn with_mime_type<T: AsRef<str>>(mut self, mime_type: T) -> Self {
+        self.mime_type = mime_type.as_ref().to_string();
+        self
+    }
+
+    pub fn with_minithumbnail<T: AsRef<Minithumbnail>>(mut self, minithumbnail: T) -> Self {
+        self.minithumbnail = Some(minithumbnail.as_ref().clone());
+        self
+    }
+
+    pub fn with_thumbnail<T: AsRef<PhotoSize>>(mut self, thumbnail: T) -> Self {
+        self.thumbnail = Some(thumbnail.as_ref().clone());
+        self
+    }
+
+    pub fn with_document<T: AsRef<File>>(mut self, document: T) -> Self {
+        self.document = document.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDDocumentBuilder {
     inner: Document,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDDocumentBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> Document {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<Document> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("Document", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn file_name<T: AsRef<str>>(&mut self, file_name: T) -> &mut Self {
         self.inner.file_name = file_name.as_ref().to_string();
+        self.missing.retain(|f| *f != "file_name");
         self
     }
 
     pub fn mime_type<T: AsRef<str>>(&mut self, mime_type: T) -> &mut Self {
         self.inner.mime_type = mime_type.as_ref().to_string();
+        self.missing.retain(|f| *f != "mime_type");
         self
     }
 
@@ -101,6 +194,7 @@ impl RTDDocumentBuilder {
 
     pub fn document<T: AsRef<File>>(&mut self, document: T) -> &mut Self {
         self.inner.document = document.as_ref().clone();
+        self.missing.retain(|f| *f != "document");
         self
     }
 }
@@ -116,3 +210,47 @@ impl AsRef<Document> for RTDDocumentBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            Document::builder()
+                .file_name("text")
+                .mime_type("text")
+                .document(
+                    File::builder()
+                        .id(i32::MAX)
+                        .size(i32::MAX)
+                        .expected_size(i32::MAX)
+                        .local(
+                            LocalFile::builder()
+                                .path("text")
+                                .can_be_downloaded(true)
+                                .can_be_deleted(true)
+                                .is_downloading_active(true)
+                                .is_downloading_completed(true)
+                                .download_offset(i32::MAX)
+                                .downloaded_prefix_size(i32::MAX)
+                                .downloaded_size(i32::MAX)
+                                .build(),
+                        )
+                        .remote(
+                            RemoteFile::builder()
+                                .id("text")
+                                .unique_id("text")
+                                .is_uploading_active(true)
+                                .is_uploading_completed(true)
+                                .uploaded_size(i32::MAX)
+                                .build(),
+                        )
+                        .build(),
+                )
+                .build(),
+        );
+    }
+}
@@ -1,18 +1,18 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains information about a message draft
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "draftMessage")]
 pub struct DraftMessage {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Identifier of the message to reply to; 0 if none
-    reply_to_message_id: i64,
+    reply_to_message_id: MessageId,
     /// Content of the message draft; this should always be of type inputMessageText
     input_message_text: InputMessageContent,
 }
@@ -31,38 +31,89 @@ impl RObject for DraftMessage {
     }
 }
 
+impl PartialEq for DraftMessage {
+    fn eq(&self, other: &Self) -> bool {
+        self.reply_to_message_id == other.reply_to_message_id
+            && self.input_message_text == other.input_message_text
+    }
+}
+
 impl DraftMessage {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDDraftMessageBuilder {
-        let mut inner = DraftMessage::default();
-        inner.td_name = "draftMessage".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDDraftMessageBuilder { inner }
+        RTDDraftMessageBuilder {
+            inner: DraftMessage::default(),
+            missing: vec!["input_message_text"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDDraftMessageBuilder {
+        RTDDraftMessageBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDDraftMessageBuilder {
+        RTDDraftMessageBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn reply_to_message_id(&self) -> i64 {
+    pub fn reply_to_message_id(&self) -> MessageId {
         self.reply_to_message_id
     }
 
     pub fn input_message_text(&self) -> &InputMessageContent {
         &self.input_message_text
     }
+
+    pub fn into_input_message_text(self) -> InputMessageContent {
+        self.input_message_text
+    }
+
+    pub fn with_reply_to_message_id<T: Into<MessageId>>(mut self, reply_to_message_id: T) -> Self {
+        self.reply_to_message_id = reply_to_message_id.into();
+        self
+    }
+
+    pub fn with_input_message_text<T: AsRef<InputMessageContent>>(
+        mut self,
+        input_message_text: T,
+    ) -> Self {
+        self.input_message_text = input_message_text.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDDraftMessageBuilder {
     inner: DraftMessage,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDDraftMessageBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> DraftMessage {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<DraftMessage> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "DraftMessage",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn reply_to_message_id(&mut self, reply_to_message_id: i64) -> &mut Self {
-        self.inner.reply_to_message_id = reply_to_message_id;
+    pub fn reply_to_message_id<T: Into<MessageId>>(&mut self, reply_to_message_id: T) -> &mut Self {
+        self.inner.reply_to_message_id = reply_to_message_id.into();
         self
     }
 
@@ -71,6 +122,7 @@ impl RTDDraftMessageBuilder {
         input_message_text: T,
     ) -> &mut Self {
         self.inner.input_message_text = input_message_text.as_ref().clone();
+        self.missing.retain(|f| *f != "input_message_text");
         self
     }
 }
@@ -86,3 +138,27 @@ impl AsRef<DraftMessage> for RTDDraftMessageBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            DraftMessage::builder()
+                .reply_to_message_id(9007199254740991i64)
+                .input_message_text(InputMessageContent::InputMessageForwarded(
+                    InputMessageForwarded::builder()
+                        .from_chat_id(9007199254740991i64)
+                        .message_id(9007199254740991i64)
+                        .in_game_share(true)
+                        .send_copy(true)
+                        .remove_caption(true)
+                        .build(),
+                ))
+                .build(),
+        );
+    }
+}
@@ -1,20 +1,20 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Information about the email address authentication code that was sent
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "emailAddressAuthenticationCodeInfo")]
 pub struct EmailAddressAuthenticationCodeInfo {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Pattern of the email address to which an authentication code was sent
     email_address_pattern: String,
     /// Length of the code; 0 if unknown
-    length: i64,
+    length: i32,
 }
 
 impl RObject for EmailAddressAuthenticationCodeInfo {
@@ -31,42 +31,99 @@ impl RObject for EmailAddressAuthenticationCodeInfo {
     }
 }
 
+impl PartialEq for EmailAddressAuthenticationCodeInfo {
+    fn eq(&self, other: &Self) -> bool {
+        self.email_address_pattern == other.email_address_pattern && self.length == other.length
+    }
+}
+
+impl Eq for EmailAddressAuthenticationCodeInfo {}
+
+impl std::hash::Hash for EmailAddressAuthenticationCodeInfo {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.email_address_pattern, state);
+        std::hash::Hash::hash(&self.length, state);
+    }
+}
+
 impl EmailAddressAuthenticationCodeInfo {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDEmailAddressAuthenticationCodeInfoBuilder {
-        let mut inner = EmailAddressAuthenticationCodeInfo::default();
-        inner.td_name = "emailAddressAuthenticationCodeInfo".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDEmailAddressAuthenticationCodeInfoBuilder { inner }
+        RTDEmailAddressAuthenticationCodeInfoBuilder {
+            inner: EmailAddressAuthenticationCodeInfo::default(),
+            missing: vec!["email_address_pattern"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDEmailAddressAuthenticationCodeInfoBuilder {
+        RTDEmailAddressAuthenticationCodeInfoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDEmailAddressAuthenticationCodeInfoBuilder {
+        RTDEmailAddressAuthenticationCodeInfoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn email_address_pattern(&self) -> &String {
         &self.email_address_pattern
     }
 
-    pub fn length(&self) -> i64 {
+    pub fn length(&self) -> i32 {
         self.length
     }
+
+    pub fn into_email_address_pattern(self) -> String {
+        self.email_address_pattern
+    }
+
+    pub fn with_email_address_pattern<T: AsRef<str>>(mut self, email_address_pattern: T) -> Self {
+        self.email_address_pattern = email_address_pattern.as_ref().to_string();
+        self
+    }
+
+    pub fn with_length(mut self, length: i32) -> Self {
+        self.length = length;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDEmailAddressAuthenticationCodeInfoBuilder {
     inner: EmailAddressAuthenticationCodeInfo,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDEmailAddressAuthenticationCodeInfoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> EmailAddressAuthenticationCodeInfo {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<EmailAddressAuthenticationCodeInfo> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "EmailAddressAuthenticationCodeInfo",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn email_address_pattern<T: AsRef<str>>(&mut self, email_address_pattern: T) -> &mut Self {
         self.inner.email_address_pattern = email_address_pattern.as_ref().to_string();
+        self.missing.retain(|f| *f != "email_address_pattern");
         self
     }
 
-    pub fn length(&mut self, length: i64) -> &mut Self {
+    pub fn length(&mut self, length: i32) -> &mut Self {
         self.inner.length = length;
         self
     }
@@ -83,3 +140,19 @@ impl AsRef<EmailAddressAuthenticationCodeInfo> for RTDEmailAddressAuthentication
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            EmailAddressAuthenticationCodeInfo::builder()
+                .email_address_pattern("text")
+                .length(i32::MAX)
+                .build(),
+        );
+    }
+}
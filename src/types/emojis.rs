@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Represents a list of emoji
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "emojis")]
 pub struct Emojis {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// List of emojis
     emojis: Vec<String>,
@@ -29,34 +29,82 @@ impl RObject for Emojis {
     }
 }
 
+impl PartialEq for Emojis {
+    fn eq(&self, other: &Self) -> bool {
+        self.emojis == other.emojis
+    }
+}
+
+impl Eq for Emojis {}
+
+impl std::hash::Hash for Emojis {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.emojis, state);
+    }
+}
+
 impl Emojis {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDEmojisBuilder {
-        let mut inner = Emojis::default();
-        inner.td_name = "emojis".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDEmojisBuilder { inner }
+        RTDEmojisBuilder {
+            inner: Emojis::default(),
+            missing: vec!["emojis"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDEmojisBuilder {
+        RTDEmojisBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDEmojisBuilder {
+        RTDEmojisBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn emojis(&self) -> &Vec<String> {
         &self.emojis
     }
+
+    pub fn into_emojis(self) -> Vec<String> {
+        self.emojis
+    }
+
+    pub fn with_emojis(mut self, emojis: Vec<String>) -> Self {
+        self.emojis = emojis;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDEmojisBuilder {
     inner: Emojis,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDEmojisBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> Emojis {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<Emojis> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("Emojis", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn emojis(&mut self, emojis: Vec<String>) -> &mut Self {
         self.inner.emojis = emojis;
+        self.missing.retain(|f| *f != "emojis");
         self
     }
 }
@@ -72,3 +120,14 @@ impl AsRef<Emojis> for RTDEmojisBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(Emojis::builder().emojis(vec!["text".to_string()]).build());
+    }
+}
@@ -1,22 +1,25 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains encrypted Telegram Passport data credentials
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "encryptedCredentials")]
 pub struct EncryptedCredentials {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The encrypted credentials
-    data: String,
+    #[serde(with = "super::_common::bytes")]
+    data: Vec<u8>,
     /// The decrypted data hash
-    hash: String,
+    #[serde(with = "super::_common::bytes")]
+    hash: Vec<u8>,
     /// Secret for data decryption, encrypted with the service's public key
-    secret: String,
+    #[serde(with = "super::_common::bytes")]
+    secret: Vec<u8>,
 }
 
 impl RObject for EncryptedCredentials {
@@ -33,52 +36,125 @@ impl RObject for EncryptedCredentials {
     }
 }
 
+impl PartialEq for EncryptedCredentials {
+    fn eq(&self, other: &Self) -> bool {
+        self.data == other.data && self.hash == other.hash && self.secret == other.secret
+    }
+}
+
+impl Eq for EncryptedCredentials {}
+
+impl std::hash::Hash for EncryptedCredentials {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.data, state);
+        std::hash::Hash::hash(&self.hash, state);
+        std::hash::Hash::hash(&self.secret, state);
+    }
+}
+
 impl EncryptedCredentials {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDEncryptedCredentialsBuilder {
-        let mut inner = EncryptedCredentials::default();
-        inner.td_name = "encryptedCredentials".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDEncryptedCredentialsBuilder { inner }
+        RTDEncryptedCredentialsBuilder {
+            inner: EncryptedCredentials::default(),
+            missing: vec!["data", "hash", "secret"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDEncryptedCredentialsBuilder {
+        RTDEncryptedCredentialsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDEncryptedCredentialsBuilder {
+        RTDEncryptedCredentialsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn data(&self) -> &String {
+    pub fn data(&self) -> &[u8] {
         &self.data
     }
 
-    pub fn hash(&self) -> &String {
+    pub fn hash(&self) -> &[u8] {
         &self.hash
     }
 
-    pub fn secret(&self) -> &String {
+    pub fn secret(&self) -> &[u8] {
         &self.secret
     }
+
+    pub fn into_data(self) -> Vec<u8> {
+        self.data
+    }
+
+    pub fn into_hash(self) -> Vec<u8> {
+        self.hash
+    }
+
+    pub fn into_secret(self) -> Vec<u8> {
+        self.secret
+    }
+
+    pub fn with_data<T: AsRef<[u8]>>(mut self, data: T) -> Self {
+        self.data = data.as_ref().to_vec();
+        self
+    }
+
+    pub fn with_hash<T: AsRef<[u8]>>(mut self, hash: T) -> Self {
+        self.hash = hash.as_ref().to_vec();
+        self
+    }
+
+    pub fn with_secret<T: AsRef<[u8]>>(mut self, secret: T) -> Self {
+        self.secret = secret.as_ref().to_vec();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDEncryptedCredentialsBuilder {
     inner: EncryptedCredentials,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDEncryptedCredentialsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> EncryptedCredentials {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<EncryptedCredentials> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "EncryptedCredentials",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn data<T: AsRef<str>>(&mut self, data: T) -> &mut Self {
-        self.inner.data = data.as_ref().to_string();
+    pub fn data<T: AsRef<[u8]>>(&mut self, data: T) -> &mut Self {
+        self.inner.data = data.as_ref().to_vec();
+        self.missing.retain(|f| *f != "data");
         self
     }
 
-    pub fn hash<T: AsRef<str>>(&mut self, hash: T) -> &mut Self {
-        self.inner.hash = hash.as_ref().to_string();
+    pub fn hash<T: AsRef<[u8]>>(&mut self, hash: T) -> &mut Self {
+        self.inner.hash = hash.as_ref().to_vec();
+        self.missing.retain(|f| *f != "hash");
         self
     }
 
-    pub fn secret<T: AsRef<str>>(&mut self, secret: T) -> &mut Self {
-        self.inner.secret = secret.as_ref().to_string();
+    pub fn secret<T: AsRef<[u8]>>(&mut self, secret: T) -> &mut Self {
+        self.inner.secret = secret.as_ref().to_vec();
+        self.missing.retain(|f| *f != "secret");
         self
     }
 }
@@ -94,3 +170,20 @@ impl AsRef<EncryptedCredentials> for RTDEncryptedCredentialsBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            EncryptedCredentials::builder()
+                .data(b"\x00\x01\xfe\xff")
+                .hash(b"\x00\x01\xfe\xff")
+                .secret(b"\x00\x01\xfe\xff")
+                .build(),
+        );
+    }
+}
@@ -1,21 +1,22 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains information about an encrypted Telegram Passport element; for bots only
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "encryptedPassportElement")]
 pub struct EncryptedPassportElement {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Type of Telegram Passport element
     #[serde(rename(serialize = "type", deserialize = "type"))]
     type_: PassportElementType,
     /// Encrypted JSON-encoded data about the user
-    data: String,
+    #[serde(with = "super::_common::bytes")]
+    data: Vec<u8>,
     /// The front side of an identity document
     front_side: DatedFile,
     /// The reverse side of an identity document; may be null
@@ -46,22 +47,74 @@ impl RObject for EncryptedPassportElement {
     }
 }
 
+impl PartialEq for EncryptedPassportElement {
+    fn eq(&self, other: &Self) -> bool {
+        self.type_ == other.type_
+            && self.data == other.data
+            && self.front_side == other.front_side
+            && self.reverse_side == other.reverse_side
+            && self.selfie == other.selfie
+            && self.translation == other.translation
+            && self.files == other.files
+            && self.value == other.value
+            && self.hash == other.hash
+    }
+}
+
+impl Eq for EncryptedPassportElement {}
+
+impl std::hash::Hash for EncryptedPassportElement {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.type_, state);
+        std::hash::Hash::hash(&self.data, state);
+        std::hash::Hash::hash(&self.front_side, state);
+        std::hash::Hash::hash(&self.reverse_side, state);
+        std::hash::Hash::hash(&self.selfie, state);
+        std::hash::Hash::hash(&self.translation, state);
+        std::hash::Hash::hash(&self.files, state);
+        std::hash::Hash::hash(&self.value, state);
+        std::hash::Hash::hash(&self.hash, state);
+    }
+}
+
 impl EncryptedPassportElement {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDEncryptedPassportElementBuilder {
-        let mut inner = EncryptedPassportElement::default();
-        inner.td_name = "encryptedPassportElement".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDEncryptedPassportElementBuilder { inner }
+        RTDEncryptedPassportElementBuilder {
+            inner: EncryptedPassportElement::default(),
+            missing: vec![
+                "type_",
+                "data",
+                "front_side",
+                "translation",
+                "files",
+                "value",
+                "hash",
+            ],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDEncryptedPassportElementBuilder {
+        RTDEncryptedPassportElementBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDEncryptedPassportElementBuilder {
+        RTDEncryptedPassportElementBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn type_(&self) -> &PassportElementType {
         &self.type_
     }
 
-    pub fn data(&self) -> &String {
+    pub fn data(&self) -> &[u8] {
         &self.data
     }
 
@@ -92,30 +145,127 @@ impl EncryptedPassportElement {
     pub fn hash(&self) -> &String {
         &self.hash
     }
+
+    pub fn into_type(self) -> PassportElementType {
+        self.type_
+    }
+
+    pub fn into_data(self) -> Vec<u8> {
+        self.data
+    }
+
+    pub fn into_front_side(self) -> DatedFile {
+        self.front_side
+    }
+
+    pub fn into_reverse_side(self) -> Option<DatedFile> {
+        self.reverse_side
+    }
+
+    pub fn into_selfie(self) -> Option<DatedFile> {
+        self.selfie
+    }
+
+    pub fn into_translation(self) -> Vec<DatedFile> {
+        self.translation
+    }
+
+    pub fn into_files(self) -> Vec<DatedFile> {
+        self.files
+    }
+
+    pub fn into_value(self) -> String {
+        self.value
+    }
+
+    pub fn into_hash(self) -> String {
+        self.hash
+    }
+
+    pub fn with_type<T: AsRef<PassportElementType>>(mut self, type_: T) -> Self {
+        self.type_ = type_.as_ref().clone();
+        self
+    }
+
+    pub fn with_data<T: AsRef<[u8]>>(mut self, data: T) -> Self {
+        self.data = data.as_ref().to_vec();
+        self
+    }
+
+    pub fn with_front_side<T: AsRef<DatedFile>>(mut self, front_side: T) -> Self {
+        self.front_side = front_side.as_ref().clone();
+        self
+    }
+
+    pub fn with_reverse_side<T: AsRef<DatedFile>>(mut self, reverse_side: T) -> Self {
+        self.reverse_side = Some(reverse_side.as_ref().clone());
+        self
+    }
+
+    pub fn with_selfie<T: AsRef<DatedFile>>(mut self, selfie: T) -> Self {
+        self.selfie = Some(selfie.as_ref().clone());
+        self
+    }
+
+    pub fn with_translation(mut self, translation: Vec<DatedFile>) -> Self {
+        self.translation = translation;
+        self
+    }
+
+    pub fn with_files(mut self, files: Vec<DatedFile>) -> Self {
+        self.files = files;
+        self
+    }
+
+    pub fn with_value<T: AsRef<str>>(mut self, value: T) -> Self {
+        self.value = value.as_ref().to_string();
+        self
+    }
+
+    pub fn with_hash<T: AsRef<str>>(mut self, hash: T) -> Self {
+        self.hash = hash.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDEncryptedPassportElementBuilder {
     inner: EncryptedPassportElement,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDEncryptedPassportElementBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> EncryptedPassportElement {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<EncryptedPassportElement> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "EncryptedPassportElement",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn type_<T: AsRef<PassportElementType>>(&mut self, type_: T) -> &mut Self {
         self.inner.type_ = type_.as_ref().clone();
+        self.missing.retain(|f| *f != "type_");
         self
     }
 
-    pub fn data<T: AsRef<str>>(&mut self, data: T) -> &mut Self {
-        self.inner.data = data.as_ref().to_string();
+    pub fn data<T: AsRef<[u8]>>(&mut self, data: T) -> &mut Self {
+        self.inner.data = data.as_ref().to_vec();
+        self.missing.retain(|f| *f != "data");
         self
     }
 
     pub fn front_side<T: AsRef<DatedFile>>(&mut self, front_side: T) -> &mut Self {
         self.inner.front_side = front_side.as_ref().clone();
+        self.missing.retain(|f| *f != "front_side");
         self
     }
 
@@ -131,21 +281,25 @@ impl RTDEncryptedPassportElementBuilder {
 
     pub fn translation(&mut self, translation: Vec<DatedFile>) -> &mut Self {
         self.inner.translation = translation;
+        self.missing.retain(|f| *f != "translation");
         self
     }
 
     pub fn files(&mut self, files: Vec<DatedFile>) -> &mut Self {
         self.inner.files = files;
+        self.missing.retain(|f| *f != "files");
         self
     }
 
     pub fn value<T: AsRef<str>>(&mut self, value: T) -> &mut Self {
         self.inner.value = value.as_ref().to_string();
+        self.missing.retain(|f| *f != "value");
         self
     }
 
     pub fn hash<T: AsRef<str>>(&mut self, hash: T) -> &mut Self {
         self.inner.hash = hash.as_ref().to_string();
+        self.missing.retain(|f| *f != "hash");
         self
     }
 }
@@ -161,3 +315,58 @@ impl AsRef<EncryptedPassportElement> for RTDEncryptedPassportElementBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            EncryptedPassportElement::builder()
+                .type_(PassportElementType::Address(
+                    PassportElementTypeAddress::builder().build(),
+                ))
+                .data(b"\x00\x01\xfe\xff")
+                .front_side(
+                    DatedFile::builder()
+                        .file(
+                            File::builder()
+                                .id(i32::MAX)
+                                .size(i32::MAX)
+                                .expected_size(i32::MAX)
+                                .local(
+                                    LocalFile::builder()
+                                        .path("text")
+                                        .can_be_downloaded(true)
+                                        .can_be_deleted(true)
+                                        .is_downloading_active(true)
+                                        .is_downloading_completed(true)
+                                        .download_offset(i32::MAX)
+                                        .downloaded_prefix_size(i32::MAX)
+                                        .downloaded_size(i32::MAX)
+                                        .build(),
+                                )
+                                .remote(
+                                    RemoteFile::builder()
+                                        .id("text")
+                                        .unique_id("text")
+                                        .is_uploading_active(true)
+                                        .is_uploading_completed(true)
+                                        .uploaded_size(i32::MAX)
+                                        .build(),
+                                )
+                                .build(),
+                        )
+                        .date(i32::MAX)
+                        .build(),
+                )
+                .translation(vec![])
+                .files(vec![])
+                .value("text")
+                .hash("text")
+                .build(),
+        );
+    }
+}
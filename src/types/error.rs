@@ -1,18 +1,18 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// An object of this type can be returned on every function call, in case of an error
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "error")]
 pub struct Error {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Error code; subject to future changes. If the error code is 406, the error message must not be processed in any way and must not be displayed to the user
-    code: i64,
+    code: i32,
     /// Error message; subject to future changes
     message: String,
 }
@@ -31,43 +31,98 @@ impl RObject for Error {
     }
 }
 
+impl PartialEq for Error {
+    fn eq(&self, other: &Self) -> bool {
+        self.code == other.code && self.message == other.message
+    }
+}
+
+impl Eq for Error {}
+
+impl std::hash::Hash for Error {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.code, state);
+        std::hash::Hash::hash(&self.message, state);
+    }
+}
+
 impl Error {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDErrorBuilder {
-        let mut inner = Error::default();
-        inner.td_name = "error".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDErrorBuilder { inner }
+        RTDErrorBuilder {
+            inner: Error::default(),
+            missing: vec!["code", "message"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDErrorBuilder {
+        RTDErrorBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDErrorBuilder {
+        RTDErrorBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn code(&self) -> i64 {
+    pub fn code(&self) -> i32 {
         self.code
     }
 
     pub fn message(&self) -> &String {
         &self.message
     }
+
+    pub fn into_message(self) -> String {
+        self.message
+    }
+
+    pub fn with_code(mut self, code: i32) -> Self {
+        self.code = code;
+        self
+    }
+
+    pub fn with_message<T: AsRef<str>>(mut self, message: T) -> Self {
+        self.message = message.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDErrorBuilder {
     inner: Error,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDErrorBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> Error {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<Error> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("Error", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn code(&mut self, code: i64) -> &mut Self {
+    pub fn code(&mut self, code: i32) -> &mut Self {
         self.inner.code = code;
+        self.missing.retain(|f| *f != "code");
         self
     }
 
     pub fn message<T: AsRef<str>>(&mut self, message: T) -> &mut Self {
         self.inner.message = message.as_ref().to_string();
+        self.missing.retain(|f| *f != "message");
         self
     }
 }
@@ -83,3 +138,14 @@ impl AsRef<Error> for RTDErrorBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(Error::builder().code(i32::MAX).message("text").build());
+    }
+}
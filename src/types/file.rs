@@ -1,22 +1,22 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Represents a file
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "file")]
 pub struct File {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Unique file identifier
-    id: i64,
+    id: FileId,
     /// File size; 0 if unknown
-    size: i64,
+    size: i32,
     /// Expected file size in case the exact file size is unknown, but an approximate size is known. Can be used to show download/upload progress
-    expected_size: i64,
+    expected_size: i32,
     /// Information about the local copy of the file
     local: LocalFile,
     /// Information about the remote copy of the file
@@ -37,26 +37,62 @@ impl RObject for File {
     }
 }
 
+impl PartialEq for File {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.size == other.size
+            && self.expected_size == other.expected_size
+            && self.local == other.local
+            && self.remote == other.remote
+    }
+}
+
+impl Eq for File {}
+
+impl std::hash::Hash for File {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.id, state);
+        std::hash::Hash::hash(&self.size, state);
+        std::hash::Hash::hash(&self.expected_size, state);
+        std::hash::Hash::hash(&self.local, state);
+        std::hash::Hash::hash(&self.remote, state);
+    }
+}
+
 impl File {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDFileBuilder {
-        let mut inner = File::default();
-        inner.td_name = "file".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDFileBuilder { inner }
-    }
-
-    pub fn id(&self) -> i64 {
+        RTDFileBuilder {
+            inner: File::default(),
+            missing: vec!["id", "expected_size", "local", "remote"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDFileBuilder {
+        RTDFileBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDFileBuilder {
+        RTDFileBuilder {
+            inner: self,
+            missing: vec![],
+        }
+    }
+
+    pub fn id(&self) -> FileId {
         self.id
     }
 
-    pub fn size(&self) -> i64 {
+    pub fn size(&self) -> i32 {
         self.size
     }
 
-    pub fn expected_size(&self) -> i64 {
+    pub fn expected_size(&self) -> i32 {
         self.expected_size
     }
 
@@ -67,40 +103,87 @@ impl File {
     pub fn remote(&self) -> &RemoteFile {
         &self.remote
     }
+
+    pub fn into_local(self) -> LocalFile {
+        self.local
+    }
+
+    pub fn into_remote(self) -> RemoteFile {
+        self.remote
+    }
+
+    pub fn with_id<T: Into<FileId>>(mut self, id: T) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    pub fn with_size(mut self, size: i32) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn with_expected_size(mut self, expected_size: i32) -> Self {
+        self.expected_size = expected_size;
+        self
+    }
+
+    pub fn with_local<T: AsRef<LocalFile>>(mut self, local: T) -> Self {
+        self.local = local.as_ref().clone();
+        self
+    }
+
+    pub fn with_remote<T: AsRef<RemoteFile>>(mut self, remote: T) -> Self {
+        self.remote = remote.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDFileBuilder {
     inner: File,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDFileBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> File {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<File> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("File", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn id(&mut self, id: i64) -> &mut Self {
-        self.inner.id = id;
+    pub fn id<T: Into<FileId>>(&mut self, id: T) -> &mut Self {
+        self.inner.id = id.into();
+        self.missing.retain(|f| *f != "id");
         self
     }
 
-    pub fn size(&mut self, size: i64) -> &mut Self {
+    pub fn size(&mut self, size: i32) -> &mut Self {
         self.inner.size = size;
         self
     }
 
-    pub fn expected_size(&mut self, expected_size: i64) -> &mut Self {
+    pub fn expected_size(&mut self, expected_size: i32) -> &mut Self {
         self.inner.expected_size = expected_size;
+        self.missing.retain(|f| *f != "expected_size");
         self
     }
 
     pub fn local<T: AsRef<LocalFile>>(&mut self, local: T) -> &mut Self {
         self.inner.local = local.as_ref().clone();
+        self.missing.retain(|f| *f != "local");
         self
     }
 
     pub fn remote<T: AsRef<RemoteFile>>(&mut self, remote: T) -> &mut Self {
         self.inner.remote = remote.as_ref().clone();
+        self.missing.retain(|f| *f != "remote");
         self
     }
 }
@@ -116,3 +199,41 @@ impl AsRef<File> for RTDFileBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            File::builder()
+                .id(i32::MAX)
+                .size(i32::MAX)
+                .expected_size(i32::MAX)
+                .local(
+                    LocalFile::builder()
+                        .path("text")
+                        .can_be_downloaded(true)
+                        .can_be_deleted(true)
+                        .is_downloading_active(true)
+                        .is_downloading_completed(true)
+                        .download_offset(i32::MAX)
+                        .downloaded_prefix_size(i32::MAX)
+                        .downloaded_size(i32::MAX)
+                        .build(),
+                )
+                .remote(
+                    RemoteFile::builder()
+                        .id("text")
+                        .unique_id("text")
+                        .is_uploading_active(true)
+                        .is_uploading_completed(true)
+                        .uploaded_size(i32::MAX)
+                        .build(),
+                )
+                .build(),
+        );
+    }
+}
@@ -1,18 +1,19 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains a part of a file
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "filePart")]
 pub struct FilePart {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// File bytes
-    data: String,
+    #[serde(with = "super::_common::bytes")]
+    data: Vec<u8>,
 }
 
 impl RObject for FilePart {
@@ -29,34 +30,82 @@ impl RObject for FilePart {
     }
 }
 
+impl PartialEq for FilePart {
+    fn eq(&self, other: &Self) -> bool {
+        self.data == other.data
+    }
+}
+
+impl Eq for FilePart {}
+
+impl std::hash::Hash for FilePart {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.data, state);
+    }
+}
+
 impl FilePart {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDFilePartBuilder {
-        let mut inner = FilePart::default();
-        inner.td_name = "filePart".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDFilePartBuilder { inner }
+        RTDFilePartBuilder {
+            inner: FilePart::default(),
+            missing: vec!["data"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDFilePartBuilder {
+        RTDFilePartBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDFilePartBuilder {
+        RTDFilePartBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn data(&self) -> &String {
+    pub fn data(&self) -> &[u8] {
         &self.data
     }
+
+    pub fn into_data(self) -> Vec<u8> {
+        self.data
+    }
+
+    pub fn with_data<T: AsRef<[u8]>>(mut self, data: T) -> Self {
+        self.data = data.as_ref().to_vec();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDFilePartBuilder {
     inner: FilePart,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDFilePartBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> FilePart {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<FilePart> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("FilePart", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn data<T: AsRef<str>>(&mut self, data: T) -> &mut Self {
-        self.inner.data = data.as_ref().to_string();
+    pub fn data<T: AsRef<[u8]>>(&mut self, data: T) -> &mut Self {
+        self.inner.data = data.as_ref().to_vec();
+        self.missing.retain(|f| *f != "data");
         self
     }
 }
@@ -72,3 +121,14 @@ impl AsRef<FilePart> for RTDFilePartBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(FilePart::builder().data(b"\x00\x01\xfe\xff").build());
+    }
+}
@@ -1,85 +1,96 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Represents the type of a file
 pub trait TDFileType: Debug + RObject {}
 
 /// Represents the type of a file
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum FileType {
-    #[doc(hidden)]
-    _Default(()),
     /// The file is an animation
+    #[serde(rename = "fileTypeAnimation")]
     Animation(FileTypeAnimation),
     /// The file is an audio file
+    #[serde(rename = "fileTypeAudio")]
     Audio(FileTypeAudio),
     /// The file is a document
+    #[serde(rename = "fileTypeDocument")]
     Document(FileTypeDocument),
     /// The data is not a file
+    #[serde(rename = "fileTypeNone")]
     None(FileTypeNone),
     /// The file is a photo
+    #[serde(rename = "fileTypePhoto")]
     Photo(FileTypePhoto),
     /// The file is a profile photo
+    #[serde(rename = "fileTypeProfilePhoto")]
     ProfilePhoto(FileTypeProfilePhoto),
     /// The file was sent to a secret chat (the file type is not known to the server)
+    #[serde(rename = "fileTypeSecret")]
     Secret(FileTypeSecret),
     /// The file is a thumbnail of a file from a secret chat
+    #[serde(rename = "fileTypeSecretThumbnail")]
     SecretThumbnail(FileTypeSecretThumbnail),
     /// The file is a file from Secure storage used for storing Telegram Passport files
+    #[serde(rename = "fileTypeSecure")]
     Secure(FileTypeSecure),
     /// The file is a sticker
+    #[serde(rename = "fileTypeSticker")]
     Sticker(FileTypeSticker),
     /// The file is a thumbnail of another file
+    #[serde(rename = "fileTypeThumbnail")]
     Thumbnail(FileTypeThumbnail),
     /// The file type is not yet known
+    #[serde(rename = "fileTypeUnknown")]
     Unknown(FileTypeUnknown),
     /// The file is a video
+    #[serde(rename = "fileTypeVideo")]
     Video(FileTypeVideo),
     /// The file is a video note
+    #[serde(rename = "fileTypeVideoNote")]
     VideoNote(FileTypeVideoNote),
     /// The file is a voice note
+    #[serde(rename = "fileTypeVoiceNote")]
     VoiceNote(FileTypeVoiceNote),
     /// The file is a wallpaper or a background pattern
+    #[serde(rename = "fileTypeWallpaper")]
     Wallpaper(FileTypeWallpaper),
 }
 
+/// Default value is default of [FileTypeAnimation], builders still require the field to be set
 impl Default for FileType {
     fn default() -> Self {
-        FileType::_Default(())
+        FileType::Animation(FileTypeAnimation::default())
     }
 }
 
-impl<'de> Deserialize<'de> for FileType {
-    fn deserialize<D>(deserializer: D) -> Result<FileType, D::Error>
+impl Serialize for FileType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          FileType,
-          (fileTypeAnimation, Animation);
-          (fileTypeAudio, Audio);
-          (fileTypeDocument, Document);
-          (fileTypeNone, None);
-          (fileTypePhoto, Photo);
-          (fileTypeProfilePhoto, ProfilePhoto);
-          (fileTypeSecret, Secret);
-          (fileTypeSecretThumbnail, SecretThumbnail);
-          (fileTypeSecure, Secure);
-          (fileTypeSticker, Sticker);
-          (fileTypeThumbnail, Thumbnail);
-          (fileTypeUnknown, Unknown);
-          (fileTypeVideo, Video);
-          (fileTypeVideoNote, VideoNote);
-          (fileTypeVoiceNote, VoiceNote);
-          (fileTypeWallpaper, Wallpaper);
-
-        )(deserializer)
+        match self {
+            FileType::Animation(t) => t.serialize(serializer),
+            FileType::Audio(t) => t.serialize(serializer),
+            FileType::Document(t) => t.serialize(serializer),
+            FileType::None(t) => t.serialize(serializer),
+            FileType::Photo(t) => t.serialize(serializer),
+            FileType::ProfilePhoto(t) => t.serialize(serializer),
+            FileType::Secret(t) => t.serialize(serializer),
+            FileType::SecretThumbnail(t) => t.serialize(serializer),
+            FileType::Secure(t) => t.serialize(serializer),
+            FileType::Sticker(t) => t.serialize(serializer),
+            FileType::Thumbnail(t) => t.serialize(serializer),
+            FileType::Unknown(t) => t.serialize(serializer),
+            FileType::Video(t) => t.serialize(serializer),
+            FileType::VideoNote(t) => t.serialize(serializer),
+            FileType::VoiceNote(t) => t.serialize(serializer),
+            FileType::Wallpaper(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -103,8 +114,6 @@ impl RObject for FileType {
             FileType::VideoNote(t) => t.td_name(),
             FileType::VoiceNote(t) => t.td_name(),
             FileType::Wallpaper(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -126,8 +135,6 @@ impl RObject for FileType {
             FileType::VideoNote(t) => t.extra(),
             FileType::VoiceNote(t) => t.extra(),
             FileType::Wallpaper(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -139,10 +146,6 @@ impl FileType {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, FileType::_Default(_))
-    }
 }
 
 impl AsRef<FileType> for FileType {
@@ -153,12 +156,13 @@ impl AsRef<FileType> for FileType {
 
 /// The file is an animation
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "fileTypeAnimation")]
 pub struct FileTypeAnimation {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -176,6 +180,18 @@ impl RObject for FileTypeAnimation {
     }
 }
 
+impl PartialEq for FileTypeAnimation {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for FileTypeAnimation {}
+
+impl std::hash::Hash for FileTypeAnimation {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDFileType for FileTypeAnimation {}
 
 impl FileTypeAnimation {
@@ -183,22 +199,49 @@ impl FileTypeAnimation {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDFileTypeAnimationBuilder {
-        let mut inner = FileTypeAnimation::default();
-        inner.td_name = "fileTypeAnimation".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDFileTypeAnimationBuilder { inner }
+        RTDFileTypeAnimationBuilder {
+            inner: FileTypeAnimation::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDFileTypeAnimationBuilder {
+        RTDFileTypeAnimationBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDFileTypeAnimationBuilder {
+        RTDFileTypeAnimationBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDFileTypeAnimationBuilder {
     inner: FileTypeAnimation,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDFileTypeAnimationBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> FileTypeAnimation {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<FileTypeAnimation> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "FileTypeAnimation",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<FileTypeAnimation> for FileTypeAnimation {
@@ -215,12 +258,13 @@ impl AsRef<FileTypeAnimation> for RTDFileTypeAnimationBuilder {
 
 /// The file is an audio file
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "fileTypeAudio")]
 pub struct FileTypeAudio {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -238,6 +282,18 @@ impl RObject for FileTypeAudio {
     }
 }
 
+impl PartialEq for FileTypeAudio {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for FileTypeAudio {}
+
+impl std::hash::Hash for FileTypeAudio {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDFileType for FileTypeAudio {}
 
 impl FileTypeAudio {
@@ -245,22 +301,49 @@ impl FileTypeAudio {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDFileTypeAudioBuilder {
-        let mut inner = FileTypeAudio::default();
-        inner.td_name = "fileTypeAudio".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDFileTypeAudioBuilder { inner }
+        RTDFileTypeAudioBuilder {
+            inner: FileTypeAudio::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDFileTypeAudioBuilder {
+        RTDFileTypeAudioBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDFileTypeAudioBuilder {
+        RTDFileTypeAudioBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDFileTypeAudioBuilder {
     inner: FileTypeAudio,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDFileTypeAudioBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> FileTypeAudio {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<FileTypeAudio> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "FileTypeAudio",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<FileTypeAudio> for FileTypeAudio {
@@ -277,12 +360,13 @@ impl AsRef<FileTypeAudio> for RTDFileTypeAudioBuilder {
 
 /// The file is a document
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "fileTypeDocument")]
 pub struct FileTypeDocument {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -300,6 +384,18 @@ impl RObject for FileTypeDocument {
     }
 }
 
+impl PartialEq for FileTypeDocument {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for FileTypeDocument {}
+
+impl std::hash::Hash for FileTypeDocument {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDFileType for FileTypeDocument {}
 
 impl FileTypeDocument {
@@ -307,22 +403,49 @@ impl FileTypeDocument {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDFileTypeDocumentBuilder {
-        let mut inner = FileTypeDocument::default();
-        inner.td_name = "fileTypeDocument".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDFileTypeDocumentBuilder { inner }
+        RTDFileTypeDocumentBuilder {
+            inner: FileTypeDocument::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDFileTypeDocumentBuilder {
+        RTDFileTypeDocumentBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDFileTypeDocumentBuilder {
+        RTDFileTypeDocumentBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDFileTypeDocumentBuilder {
     inner: FileTypeDocument,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDFileTypeDocumentBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> FileTypeDocument {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<FileTypeDocument> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "FileTypeDocument",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<FileTypeDocument> for FileTypeDocument {
@@ -339,12 +462,13 @@ impl AsRef<FileTypeDocument> for RTDFileTypeDocumentBuilder {
 
 /// The data is not a file
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "fileTypeNone")]
 pub struct FileTypeNone {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -362,6 +486,18 @@ impl RObject for FileTypeNone {
     }
 }
 
+impl PartialEq for FileTypeNone {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for FileTypeNone {}
+
+impl std::hash::Hash for FileTypeNone {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDFileType for FileTypeNone {}
 
 impl FileTypeNone {
@@ -369,22 +505,49 @@ impl FileTypeNone {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDFileTypeNoneBuilder {
-        let mut inner = FileTypeNone::default();
-        inner.td_name = "fileTypeNone".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDFileTypeNoneBuilder { inner }
+        RTDFileTypeNoneBuilder {
+            inner: FileTypeNone::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDFileTypeNoneBuilder {
+        RTDFileTypeNoneBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDFileTypeNoneBuilder {
+        RTDFileTypeNoneBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDFileTypeNoneBuilder {
     inner: FileTypeNone,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDFileTypeNoneBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> FileTypeNone {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<FileTypeNone> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "FileTypeNone",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<FileTypeNone> for FileTypeNone {
@@ -401,12 +564,13 @@ impl AsRef<FileTypeNone> for RTDFileTypeNoneBuilder {
 
 /// The file is a photo
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "fileTypePhoto")]
 pub struct FileTypePhoto {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -424,6 +588,18 @@ impl RObject for FileTypePhoto {
     }
 }
 
+impl PartialEq for FileTypePhoto {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for FileTypePhoto {}
+
+impl std::hash::Hash for FileTypePhoto {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDFileType for FileTypePhoto {}
 
 impl FileTypePhoto {
@@ -431,22 +607,49 @@ impl FileTypePhoto {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDFileTypePhotoBuilder {
-        let mut inner = FileTypePhoto::default();
-        inner.td_name = "fileTypePhoto".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDFileTypePhotoBuilder { inner }
+        RTDFileTypePhotoBuilder {
+            inner: FileTypePhoto::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDFileTypePhotoBuilder {
+        RTDFileTypePhotoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDFileTypePhotoBuilder {
+        RTDFileTypePhotoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDFileTypePhotoBuilder {
     inner: FileTypePhoto,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDFileTypePhotoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> FileTypePhoto {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<FileTypePhoto> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "FileTypePhoto",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<FileTypePhoto> for FileTypePhoto {
@@ -463,12 +666,13 @@ impl AsRef<FileTypePhoto> for RTDFileTypePhotoBuilder {
 
 /// The file is a profile photo
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "fileTypeProfilePhoto")]
 pub struct FileTypeProfilePhoto {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -486,6 +690,18 @@ impl RObject for FileTypeProfilePhoto {
     }
 }
 
+impl PartialEq for FileTypeProfilePhoto {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for FileTypeProfilePhoto {}
+
+impl std::hash::Hash for FileTypeProfilePhoto {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDFileType for FileTypeProfilePhoto {}
 
 impl FileTypeProfilePhoto {
@@ -493,22 +709,49 @@ impl FileTypeProfilePhoto {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDFileTypeProfilePhotoBuilder {
-        let mut inner = FileTypeProfilePhoto::default();
-        inner.td_name = "fileTypeProfilePhoto".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDFileTypeProfilePhotoBuilder { inner }
+        RTDFileTypeProfilePhotoBuilder {
+            inner: FileTypeProfilePhoto::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDFileTypeProfilePhotoBuilder {
+        RTDFileTypeProfilePhotoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDFileTypeProfilePhotoBuilder {
+        RTDFileTypeProfilePhotoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDFileTypeProfilePhotoBuilder {
     inner: FileTypeProfilePhoto,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDFileTypeProfilePhotoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> FileTypeProfilePhoto {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<FileTypeProfilePhoto> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "FileTypeProfilePhoto",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<FileTypeProfilePhoto> for FileTypeProfilePhoto {
@@ -525,12 +768,13 @@ impl AsRef<FileTypeProfilePhoto> for RTDFileTypeProfilePhotoBuilder {
 
 /// The file was sent to a secret chat (the file type is not known to the server)
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "fileTypeSecret")]
 pub struct FileTypeSecret {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -548,6 +792,18 @@ impl RObject for FileTypeSecret {
     }
 }
 
+impl PartialEq for FileTypeSecret {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for FileTypeSecret {}
+
+impl std::hash::Hash for FileTypeSecret {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDFileType for FileTypeSecret {}
 
 impl FileTypeSecret {
@@ -555,22 +811,49 @@ impl FileTypeSecret {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDFileTypeSecretBuilder {
-        let mut inner = FileTypeSecret::default();
-        inner.td_name = "fileTypeSecret".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDFileTypeSecretBuilder { inner }
+        RTDFileTypeSecretBuilder {
+            inner: FileTypeSecret::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDFileTypeSecretBuilder {
+        RTDFileTypeSecretBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDFileTypeSecretBuilder {
+        RTDFileTypeSecretBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDFileTypeSecretBuilder {
     inner: FileTypeSecret,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDFileTypeSecretBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> FileTypeSecret {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<FileTypeSecret> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "FileTypeSecret",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<FileTypeSecret> for FileTypeSecret {
@@ -587,12 +870,13 @@ impl AsRef<FileTypeSecret> for RTDFileTypeSecretBuilder {
 
 /// The file is a thumbnail of a file from a secret chat
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "fileTypeSecretThumbnail")]
 pub struct FileTypeSecretThumbnail {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -610,6 +894,18 @@ impl RObject for FileTypeSecretThumbnail {
     }
 }
 
+impl PartialEq for FileTypeSecretThumbnail {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for FileTypeSecretThumbnail {}
+
+impl std::hash::Hash for FileTypeSecretThumbnail {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDFileType for FileTypeSecretThumbnail {}
 
 impl FileTypeSecretThumbnail {
@@ -617,22 +913,49 @@ impl FileTypeSecretThumbnail {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDFileTypeSecretThumbnailBuilder {
-        let mut inner = FileTypeSecretThumbnail::default();
-        inner.td_name = "fileTypeSecretThumbnail".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDFileTypeSecretThumbnailBuilder { inner }
+        RTDFileTypeSecretThumbnailBuilder {
+            inner: FileTypeSecretThumbnail::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDFileTypeSecretThumbnailBuilder {
+        RTDFileTypeSecretThumbnailBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDFileTypeSecretThumbnailBuilder {
+        RTDFileTypeSecretThumbnailBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDFileTypeSecretThumbnailBuilder {
     inner: FileTypeSecretThumbnail,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDFileTypeSecretThumbnailBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> FileTypeSecretThumbnail {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<FileTypeSecretThumbnail> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "FileTypeSecretThumbnail",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<FileTypeSecretThumbnail> for FileTypeSecretThumbnail {
@@ -649,12 +972,13 @@ impl AsRef<FileTypeSecretThumbnail> for RTDFileTypeSecretThumbnailBuilder {
 
 /// The file is a file from Secure storage used for storing Telegram Passport files
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "fileTypeSecure")]
 pub struct FileTypeSecure {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -672,6 +996,18 @@ impl RObject for FileTypeSecure {
     }
 }
 
+impl PartialEq for FileTypeSecure {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for FileTypeSecure {}
+
+impl std::hash::Hash for FileTypeSecure {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDFileType for FileTypeSecure {}
 
 impl FileTypeSecure {
@@ -679,22 +1015,49 @@ impl FileTypeSecure {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDFileTypeSecureBuilder {
-        let mut inner = FileTypeSecure::default();
-        inner.td_name = "fileTypeSecure".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDFileTypeSecureBuilder { inner }
+        RTDFileTypeSecureBuilder {
+            inner: FileTypeSecure::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDFileTypeSecureBuilder {
+        RTDFileTypeSecureBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDFileTypeSecureBuilder {
+        RTDFileTypeSecureBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDFileTypeSecureBuilder {
     inner: FileTypeSecure,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDFileTypeSecureBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> FileTypeSecure {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<FileTypeSecure> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "FileTypeSecure",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<FileTypeSecure> for FileTypeSecure {
@@ -711,12 +1074,13 @@ impl AsRef<FileTypeSecure> for RTDFileTypeSecureBuilder {
 
 /// The file is a sticker
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "fileTypeSticker")]
 pub struct FileTypeSticker {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -734,6 +1098,18 @@ impl RObject for FileTypeSticker {
     }
 }
 
+impl PartialEq for FileTypeSticker {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for FileTypeSticker {}
+
+impl std::hash::Hash for FileTypeSticker {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDFileType for FileTypeSticker {}
 
 impl FileTypeSticker {
@@ -741,22 +1117,49 @@ impl FileTypeSticker {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDFileTypeStickerBuilder {
-        let mut inner = FileTypeSticker::default();
-        inner.td_name = "fileTypeSticker".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDFileTypeStickerBuilder { inner }
+        RTDFileTypeStickerBuilder {
+            inner: FileTypeSticker::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDFileTypeStickerBuilder {
+        RTDFileTypeStickerBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDFileTypeStickerBuilder {
+        RTDFileTypeStickerBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDFileTypeStickerBuilder {
     inner: FileTypeSticker,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDFileTypeStickerBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> FileTypeSticker {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<FileTypeSticker> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "FileTypeSticker",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<FileTypeSticker> for FileTypeSticker {
@@ -773,12 +1176,13 @@ impl AsRef<FileTypeSticker> for RTDFileTypeStickerBuilder {
 
 /// The file is a thumbnail of another file
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "fileTypeThumbnail")]
 pub struct FileTypeThumbnail {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -796,6 +1200,18 @@ impl RObject for FileTypeThumbnail {
     }
 }
 
+impl PartialEq for FileTypeThumbnail {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for FileTypeThumbnail {}
+
+impl std::hash::Hash for FileTypeThumbnail {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDFileType for FileTypeThumbnail {}
 
 impl FileTypeThumbnail {
@@ -803,22 +1219,49 @@ impl FileTypeThumbnail {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDFileTypeThumbnailBuilder {
-        let mut inner = FileTypeThumbnail::default();
-        inner.td_name = "fileTypeThumbnail".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDFileTypeThumbnailBuilder { inner }
+        RTDFileTypeThumbnailBuilder {
+            inner: FileTypeThumbnail::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDFileTypeThumbnailBuilder {
+        RTDFileTypeThumbnailBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDFileTypeThumbnailBuilder {
+        RTDFileTypeThumbnailBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDFileTypeThumbnailBuilder {
     inner: FileTypeThumbnail,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDFileTypeThumbnailBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> FileTypeThumbnail {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<FileTypeThumbnail> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "FileTypeThumbnail",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<FileTypeThumbnail> for FileTypeThumbnail {
@@ -835,12 +1278,13 @@ impl AsRef<FileTypeThumbnail> for RTDFileTypeThumbnailBuilder {
 
 /// The file type is not yet known
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "fileTypeUnknown")]
 pub struct FileTypeUnknown {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -858,6 +1302,18 @@ impl RObject for FileTypeUnknown {
     }
 }
 
+impl PartialEq for FileTypeUnknown {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for FileTypeUnknown {}
+
+impl std::hash::Hash for FileTypeUnknown {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDFileType for FileTypeUnknown {}
 
 impl FileTypeUnknown {
@@ -865,22 +1321,49 @@ impl FileTypeUnknown {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDFileTypeUnknownBuilder {
-        let mut inner = FileTypeUnknown::default();
-        inner.td_name = "fileTypeUnknown".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDFileTypeUnknownBuilder { inner }
+        RTDFileTypeUnknownBuilder {
+            inner: FileTypeUnknown::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDFileTypeUnknownBuilder {
+        RTDFileTypeUnknownBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDFileTypeUnknownBuilder {
+        RTDFileTypeUnknownBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDFileTypeUnknownBuilder {
     inner: FileTypeUnknown,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDFileTypeUnknownBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> FileTypeUnknown {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<FileTypeUnknown> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "FileTypeUnknown",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<FileTypeUnknown> for FileTypeUnknown {
@@ -897,12 +1380,13 @@ impl AsRef<FileTypeUnknown> for RTDFileTypeUnknownBuilder {
 
 /// The file is a video
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "fileTypeVideo")]
 pub struct FileTypeVideo {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -920,6 +1404,18 @@ impl RObject for FileTypeVideo {
     }
 }
 
+impl PartialEq for FileTypeVideo {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for FileTypeVideo {}
+
+impl std::hash::Hash for FileTypeVideo {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDFileType for FileTypeVideo {}
 
 impl FileTypeVideo {
@@ -927,22 +1423,49 @@ impl FileTypeVideo {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDFileTypeVideoBuilder {
-        let mut inner = FileTypeVideo::default();
-        inner.td_name = "fileTypeVideo".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDFileTypeVideoBuilder { inner }
+        RTDFileTypeVideoBuilder {
+            inner: FileTypeVideo::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDFileTypeVideoBuilder {
+        RTDFileTypeVideoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDFileTypeVideoBuilder {
+        RTDFileTypeVideoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDFileTypeVideoBuilder {
     inner: FileTypeVideo,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDFileTypeVideoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> FileTypeVideo {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<FileTypeVideo> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "FileTypeVideo",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<FileTypeVideo> for FileTypeVideo {
@@ -959,12 +1482,13 @@ impl AsRef<FileTypeVideo> for RTDFileTypeVideoBuilder {
 
 /// The file is a video note
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "fileTypeVideoNote")]
 pub struct FileTypeVideoNote {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -982,6 +1506,18 @@ impl RObject for FileTypeVideoNote {
     }
 }
 
+impl PartialEq for FileTypeVideoNote {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for FileTypeVideoNote {}
+
+impl std::hash::Hash for FileTypeVideoNote {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDFileType for FileTypeVideoNote {}
 
 impl FileTypeVideoNote {
@@ -989,22 +1525,49 @@ impl FileTypeVideoNote {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDFileTypeVideoNoteBuilder {
-        let mut inner = FileTypeVideoNote::default();
-        inner.td_name = "fileTypeVideoNote".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDFileTypeVideoNoteBuilder { inner }
+        RTDFileTypeVideoNoteBuilder {
+            inner: FileTypeVideoNote::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDFileTypeVideoNoteBuilder {
+        RTDFileTypeVideoNoteBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDFileTypeVideoNoteBuilder {
+        RTDFileTypeVideoNoteBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDFileTypeVideoNoteBuilder {
     inner: FileTypeVideoNote,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDFileTypeVideoNoteBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> FileTypeVideoNote {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<FileTypeVideoNote> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "FileTypeVideoNote",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<FileTypeVideoNote> for FileTypeVideoNote {
@@ -1021,12 +1584,13 @@ impl AsRef<FileTypeVideoNote> for RTDFileTypeVideoNoteBuilder {
 
 /// The file is a voice note
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "fileTypeVoiceNote")]
 pub struct FileTypeVoiceNote {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -1044,6 +1608,18 @@ impl RObject for FileTypeVoiceNote {
     }
 }
 
+impl PartialEq for FileTypeVoiceNote {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for FileTypeVoiceNote {}
+
+impl std::hash::Hash for FileTypeVoiceNote {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDFileType for FileTypeVoiceNote {}
 
 impl FileTypeVoiceNote {
@@ -1051,22 +1627,49 @@ impl FileTypeVoiceNote {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDFileTypeVoiceNoteBuilder {
-        let mut inner = FileTypeVoiceNote::default();
-        inner.td_name = "fileTypeVoiceNote".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDFileTypeVoiceNoteBuilder { inner }
+        RTDFileTypeVoiceNoteBuilder {
+            inner: FileTypeVoiceNote::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDFileTypeVoiceNoteBuilder {
+        RTDFileTypeVoiceNoteBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDFileTypeVoiceNoteBuilder {
+        RTDFileTypeVoiceNoteBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDFileTypeVoiceNoteBuilder {
     inner: FileTypeVoiceNote,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDFileTypeVoiceNoteBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> FileTypeVoiceNote {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<FileTypeVoiceNote> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "FileTypeVoiceNote",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<FileTypeVoiceNote> for FileTypeVoiceNote {
@@ -1083,12 +1686,13 @@ impl AsRef<FileTypeVoiceNote> for RTDFileTypeVoiceNoteBuilder {
 
 /// The file is a wallpaper or a background pattern
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "fileTypeWallpaper")]
 pub struct FileTypeWallpaper {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -1106,6 +1710,18 @@ impl RObject for FileTypeWallpaper {
     }
 }
 
+impl PartialEq for FileTypeWallpaper {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for FileTypeWallpaper {}
+
+impl std::hash::Hash for FileTypeWallpaper {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDFileType for FileTypeWallpaper {}
 
 impl FileTypeWallpaper {
@@ -1113,22 +1729,49 @@ impl FileTypeWallpaper {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDFileTypeWallpaperBuilder {
-        let mut inner = FileTypeWallpaper::default();
-        inner.td_name = "fileTypeWallpaper".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDFileTypeWallpaperBuilder { inner }
+        RTDFileTypeWallpaperBuilder {
+            inner: FileTypeWallpaper::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDFileTypeWallpaperBuilder {
+        RTDFileTypeWallpaperBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDFileTypeWallpaperBuilder {
+        RTDFileTypeWallpaperBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDFileTypeWallpaperBuilder {
     inner: FileTypeWallpaper,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDFileTypeWallpaperBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> FileTypeWallpaper {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<FileTypeWallpaper> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "FileTypeWallpaper",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<FileTypeWallpaper> for FileTypeWallpaper {
@@ -1142,3 +1785,33 @@ impl AsRef<FileTypeWallpaper> for RTDFileTypeWallpaperBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(FileType::Animation(FileTypeAnimation::builder().build()));
+        assert_round_trip(FileType::Audio(FileTypeAudio::builder().build()));
+        assert_round_trip(FileType::Document(FileTypeDocument::builder().build()));
+        assert_round_trip(FileType::None(FileTypeNone::builder().build()));
+        assert_round_trip(FileType::Photo(FileTypePhoto::builder().build()));
+        assert_round_trip(FileType::ProfilePhoto(
+            FileTypeProfilePhoto::builder().build(),
+        ));
+        assert_round_trip(FileType::Secret(FileTypeSecret::builder().build()));
+        assert_round_trip(FileType::SecretThumbnail(
+            FileTypeSecretThumbnail::builder().build(),
+        ));
+        assert_round_trip(FileType::Secure(FileTypeSecure::builder().build()));
+        assert_round_trip(FileType::Sticker(FileTypeSticker::builder().build()));
+        assert_round_trip(FileType::Thumbnail(FileTypeThumbnail::builder().build()));
+        assert_round_trip(FileType::Unknown(FileTypeUnknown::builder().build()));
+        assert_round_trip(FileType::Video(FileTypeVideo::builder().build()));
+        assert_round_trip(FileType::VideoNote(FileTypeVideoNote::builder().build()));
+        assert_round_trip(FileType::VoiceNote(FileTypeVoiceNote::builder().build()));
+        assert_round_trip(FileType::Wallpaper(FileTypeWallpaper::builder().build()));
+    }
+}
@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// A text with some entities
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "formattedText")]
 pub struct FormattedText {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The text
     text: String,
@@ -31,15 +31,44 @@ impl RObject for FormattedText {
     }
 }
 
+impl PartialEq for FormattedText {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text && self.entities == other.entities
+    }
+}
+
+impl Eq for FormattedText {}
+
+impl std::hash::Hash for FormattedText {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.text, state);
+        std::hash::Hash::hash(&self.entities, state);
+    }
+}
+
 impl FormattedText {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDFormattedTextBuilder {
-        let mut inner = FormattedText::default();
-        inner.td_name = "formattedText".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDFormattedTextBuilder { inner }
+        RTDFormattedTextBuilder {
+            inner: FormattedText::default(),
+            missing: vec!["text", "entities"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDFormattedTextBuilder {
+        RTDFormattedTextBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDFormattedTextBuilder {
+        RTDFormattedTextBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn text(&self) -> &String {
@@ -49,25 +78,58 @@ impl FormattedText {
     pub fn entities(&self) -> &Vec<TextEntity> {
         &self.entities
     }
+
+    pub fn into_text(self) -> String {
+        self.text
+    }
+
+    pub fn into_entities(self) -> Vec<TextEntity> {
+        self.entities
+    }
+
+    pub fn with_text<T: AsRef<str>>(mut self, text: T) -> Self {
+        self.text = text.as_ref().to_string();
+        self
+    }
+
+    pub fn with_entities(mut self, entities: Vec<TextEntity>) -> Self {
+        self.entities = entities;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDFormattedTextBuilder {
     inner: FormattedText,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDFormattedTextBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> FormattedText {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<FormattedText> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "FormattedText",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn text<T: AsRef<str>>(&mut self, text: T) -> &mut Self {
         self.inner.text = text.as_ref().to_string();
+        self.missing.retain(|f| *f != "text");
         self
     }
 
     pub fn entities(&mut self, entities: Vec<TextEntity>) -> &mut Self {
         self.inner.entities = entities;
+        self.missing.retain(|f| *f != "entities");
         self
     }
 }
@@ -83,3 +145,19 @@ impl AsRef<FormattedText> for RTDFormattedTextBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            FormattedText::builder()
+                .text("text")
+                .entities(vec![])
+                .build(),
+        );
+    }
+}
@@ -1,20 +1,21 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains a list of messages found by a search
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "foundMessages")]
 pub struct FoundMessages {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// List of messages
     messages: Vec<Message>,
     /// Value to pass as from_search_id to get more results
-    next_from_search_id: isize,
+    #[serde(with = "super::_common::int64")]
+    next_from_search_id: i64,
 }
 
 impl RObject for FoundMessages {
@@ -31,43 +32,92 @@ impl RObject for FoundMessages {
     }
 }
 
+impl PartialEq for FoundMessages {
+    fn eq(&self, other: &Self) -> bool {
+        self.messages == other.messages && self.next_from_search_id == other.next_from_search_id
+    }
+}
+
 impl FoundMessages {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDFoundMessagesBuilder {
-        let mut inner = FoundMessages::default();
-        inner.td_name = "foundMessages".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDFoundMessagesBuilder { inner }
+        RTDFoundMessagesBuilder {
+            inner: FoundMessages::default(),
+            missing: vec!["messages", "next_from_search_id"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDFoundMessagesBuilder {
+        RTDFoundMessagesBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDFoundMessagesBuilder {
+        RTDFoundMessagesBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn messages(&self) -> &Vec<Message> {
         &self.messages
     }
 
-    pub fn next_from_search_id(&self) -> isize {
+    pub fn next_from_search_id(&self) -> i64 {
         self.next_from_search_id
     }
+
+    pub fn into_messages(self) -> Vec<Message> {
+        self.messages
+    }
+
+    pub fn with_messages(mut self, messages: Vec<Message>) -> Self {
+        self.messages = messages;
+        self
+    }
+
+    pub fn with_next_from_search_id(mut self, next_from_search_id: i64) -> Self {
+        self.next_from_search_id = next_from_search_id;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDFoundMessagesBuilder {
     inner: FoundMessages,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDFoundMessagesBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> FoundMessages {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<FoundMessages> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "FoundMessages",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn messages(&mut self, messages: Vec<Message>) -> &mut Self {
         self.inner.messages = messages;
+        self.missing.retain(|f| *f != "messages");
         self
     }
 
-    pub fn next_from_search_id(&mut self, next_from_search_id: isize) -> &mut Self {
+    pub fn next_from_search_id(&mut self, next_from_search_id: i64) -> &mut Self {
         self.inner.next_from_search_id = next_from_search_id;
+        self.missing.retain(|f| *f != "next_from_search_id");
         self
     }
 }
@@ -83,3 +133,19 @@ impl AsRef<FoundMessages> for RTDFoundMessagesBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            FoundMessages::builder()
+                .messages(vec![])
+                .next_from_search_id(i64::MAX)
+                .build(),
+        );
+    }
+}
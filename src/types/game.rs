@@ -1,18 +1,19 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Describes a game
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "game")]
 pub struct Game {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Game ID
-    id: isize,
+    #[serde(with = "super::_common::int64")]
+    id: i64,
     /// Game short name. To share a game use the URL https://t.me/{bot_username}?game={game_short_name}
     short_name: String,
     /// Game title
@@ -41,18 +42,58 @@ impl RObject for Game {
     }
 }
 
+impl PartialEq for Game {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.short_name == other.short_name
+            && self.title == other.title
+            && self.text == other.text
+            && self.description == other.description
+            && self.photo == other.photo
+            && self.animation == other.animation
+    }
+}
+
+impl Eq for Game {}
+
+impl std::hash::Hash for Game {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.id, state);
+        std::hash::Hash::hash(&self.short_name, state);
+        std::hash::Hash::hash(&self.title, state);
+        std::hash::Hash::hash(&self.text, state);
+        std::hash::Hash::hash(&self.description, state);
+        std::hash::Hash::hash(&self.photo, state);
+        std::hash::Hash::hash(&self.animation, state);
+    }
+}
+
 impl Game {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDGameBuilder {
-        let mut inner = Game::default();
-        inner.td_name = "game".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDGameBuilder { inner }
+        RTDGameBuilder {
+            inner: Game::default(),
+            missing: vec!["id", "short_name", "title", "text", "description", "photo"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDGameBuilder {
+        RTDGameBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDGameBuilder {
+        RTDGameBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn id(&self) -> isize {
+    pub fn id(&self) -> i64 {
         self.id
     }
 
@@ -79,45 +120,120 @@ impl Game {
     pub fn animation(&self) -> &Option<Animation> {
         &self.animation
     }
+
+    pub fn into_short_name(self) -> String {
+        self.short_name
+    }
+
+    pub fn into_title(self) -> String {
+        self.title
+    }
+
+    pub fn into_text(self) -> FormattedText {
+        self.text
+    }
+
+    pub fn into_description(self) -> String {
+        self.description
+    }
+
+    pub fn into_photo(self) -> Photo {
+        self.photo
+    }
+
+    pub fn into_animation(self) -> Option<Animation> {
+        self.animation
+    }
+
+    pub fn with_id(mut self, id: i64) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn with_short_name<T: AsRef<str>>(mut self, short_name: T) -> Self {
+        self.short_name = short_name.as_ref().to_string();
+        self
+    }
+
+    pub fn with_title<T: AsRef<str>>(mut self, title: T) -> Self {
+        self.title = title.as_ref().to_string();
+        self
+    }
+
+    pub fn with_text<T: AsRef<FormattedText>>(mut self, text: T) -> Self {
+        self.text = text.as_ref().clone();
+        self
+    }
+
+    pub fn with_description<T: AsRef<str>>(mut self, description: T) -> Self {
+        self.description = description.as_ref().to_string();
+        self
+    }
+
+    pub fn with_photo<T: AsRef<Photo>>(mut self, photo: T) -> Self {
+        self.photo = photo.as_ref().clone();
+        self
+    }
+
+    pub fn with_animation<T: AsRef<Animation>>(mut self, animation: T) -> Self {
+        self.animation = Some(animation.as_ref().clone());
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDGameBuilder {
     inner: Game,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDGameBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> Game {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<Game> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("Game", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn id(&mut self, id: isize) -> &mut Self {
+    pub fn id(&mut self, id: i64) -> &mut Self {
         self.inner.id = id;
+        self.missing.retain(|f| *f != "id");
         self
     }
 
     pub fn short_name<T: AsRef<str>>(&mut self, short_name: T) -> &mut Self {
         self.inner.short_name = short_name.as_ref().to_string();
+        self.missing.retain(|f| *f != "short_name");
         self
     }
 
     pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
         self.inner.title = title.as_ref().to_string();
+        self.missing.retain(|f| *f != "title");
         self
     }
 
     pub fn text<T: AsRef<FormattedText>>(&mut self, text: T) -> &mut Self {
         self.inner.text = text.as_ref().clone();
+        self.missing.retain(|f| *f != "text");
         self
     }
 
     pub fn description<T: AsRef<str>>(&mut self, description: T) -> &mut Self {
         self.inner.description = description.as_ref().to_string();
+        self.missing.retain(|f| *f != "description");
         self
     }
 
     pub fn photo<T: AsRef<Photo>>(&mut self, photo: T) -> &mut Self {
         self.inner.photo = photo.as_ref().clone();
+        self.missing.retain(|f| *f != "photo");
         self
     }
 
@@ -138,3 +254,28 @@ impl AsRef<Game> for RTDGameBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            Game::builder()
+                .id(i64::MAX)
+                .short_name("text")
+                .title("text")
+                .text(
+                    FormattedText::builder()
+                        .text("text")
+                        .entities(vec![])
+                        .build(),
+                )
+                .description("text")
+                .photo(Photo::builder().has_stickers(true).sizes(vec![]).build())
+                .build(),
+        );
+    }
+}
@@ -1,22 +1,22 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains one row of the game high score table
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "gameHighScore")]
 pub struct GameHighScore {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Position in the high score table
-    position: i64,
+    position: i32,
     /// User identifier
-    user_id: i64,
+    user_id: UserId,
     /// User score
-    score: i64,
+    score: i32,
 }
 
 impl RObject for GameHighScore {
@@ -33,52 +33,115 @@ impl RObject for GameHighScore {
     }
 }
 
+impl PartialEq for GameHighScore {
+    fn eq(&self, other: &Self) -> bool {
+        self.position == other.position
+            && self.user_id == other.user_id
+            && self.score == other.score
+    }
+}
+
+impl Eq for GameHighScore {}
+
+impl std::hash::Hash for GameHighScore {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.position, state);
+        std::hash::Hash::hash(&self.user_id, state);
+        std::hash::Hash::hash(&self.score, state);
+    }
+}
+
 impl GameHighScore {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDGameHighScoreBuilder {
-        let mut inner = GameHighScore::default();
-        inner.td_name = "gameHighScore".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDGameHighScoreBuilder { inner }
+        RTDGameHighScoreBuilder {
+            inner: GameHighScore::default(),
+            missing: vec!["position", "user_id", "score"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDGameHighScoreBuilder {
+        RTDGameHighScoreBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDGameHighScoreBuilder {
+        RTDGameHighScoreBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn position(&self) -> i64 {
+    pub fn position(&self) -> i32 {
         self.position
     }
 
-    pub fn user_id(&self) -> i64 {
+    pub fn user_id(&self) -> UserId {
         self.user_id
     }
 
-    pub fn score(&self) -> i64 {
+    pub fn score(&self) -> i32 {
         self.score
     }
+
+    pub fn with_position(mut self, position: i32) -> Self {
+        self.position = position;
+        self
+    }
+
+    pub fn with_user_id<T: Into<UserId>>(mut self, user_id: T) -> Self {
+        self.user_id = user_id.into();
+        self
+    }
+
+    pub fn with_score(mut self, score: i32) -> Self {
+        self.score = score;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDGameHighScoreBuilder {
     inner: GameHighScore,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDGameHighScoreBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> GameHighScore {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<GameHighScore> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "GameHighScore",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn position(&mut self, position: i64) -> &mut Self {
+    pub fn position(&mut self, position: i32) -> &mut Self {
         self.inner.position = position;
+        self.missing.retain(|f| *f != "position");
         self
     }
 
-    pub fn user_id(&mut self, user_id: i64) -> &mut Self {
-        self.inner.user_id = user_id;
+    pub fn user_id<T: Into<UserId>>(&mut self, user_id: T) -> &mut Self {
+        self.inner.user_id = user_id.into();
+        self.missing.retain(|f| *f != "user_id");
         self
     }
 
-    pub fn score(&mut self, score: i64) -> &mut Self {
+    pub fn score(&mut self, score: i32) -> &mut Self {
         self.inner.score = score;
+        self.missing.retain(|f| *f != "score");
         self
     }
 }
@@ -94,3 +157,20 @@ impl AsRef<GameHighScore> for RTDGameHighScoreBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            GameHighScore::builder()
+                .position(i32::MAX)
+                .user_id(i32::MAX)
+                .score(i32::MAX)
+                .build(),
+        );
+    }
+}
@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains a list of game high scores
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "gameHighScores")]
 pub struct GameHighScores {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// A list of game high scores
     scores: Vec<GameHighScore>,
@@ -29,34 +29,85 @@ impl RObject for GameHighScores {
     }
 }
 
+impl PartialEq for GameHighScores {
+    fn eq(&self, other: &Self) -> bool {
+        self.scores == other.scores
+    }
+}
+
+impl Eq for GameHighScores {}
+
+impl std::hash::Hash for GameHighScores {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.scores, state);
+    }
+}
+
 impl GameHighScores {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDGameHighScoresBuilder {
-        let mut inner = GameHighScores::default();
-        inner.td_name = "gameHighScores".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDGameHighScoresBuilder { inner }
+        RTDGameHighScoresBuilder {
+            inner: GameHighScores::default(),
+            missing: vec!["scores"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDGameHighScoresBuilder {
+        RTDGameHighScoresBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDGameHighScoresBuilder {
+        RTDGameHighScoresBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn scores(&self) -> &Vec<GameHighScore> {
         &self.scores
     }
+
+    pub fn into_scores(self) -> Vec<GameHighScore> {
+        self.scores
+    }
+
+    pub fn with_scores(mut self, scores: Vec<GameHighScore>) -> Self {
+        self.scores = scores;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDGameHighScoresBuilder {
     inner: GameHighScores,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDGameHighScoresBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> GameHighScores {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<GameHighScores> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "GameHighScores",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn scores(&mut self, scores: Vec<GameHighScore>) -> &mut Self {
         self.inner.scores = scores;
+        self.missing.retain(|f| *f != "scores");
         self
     }
 }
@@ -72,3 +123,14 @@ impl AsRef<GameHighScores> for RTDGameHighScoresBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(GameHighScores::builder().scores(vec![]).build());
+    }
+}
@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains a list of hashtags
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "hashtags")]
 pub struct Hashtags {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// A list of hashtags
     hashtags: Vec<String>,
@@ -29,34 +29,82 @@ impl RObject for Hashtags {
     }
 }
 
+impl PartialEq for Hashtags {
+    fn eq(&self, other: &Self) -> bool {
+        self.hashtags == other.hashtags
+    }
+}
+
+impl Eq for Hashtags {}
+
+impl std::hash::Hash for Hashtags {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.hashtags, state);
+    }
+}
+
 impl Hashtags {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDHashtagsBuilder {
-        let mut inner = Hashtags::default();
-        inner.td_name = "hashtags".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDHashtagsBuilder { inner }
+        RTDHashtagsBuilder {
+            inner: Hashtags::default(),
+            missing: vec!["hashtags"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDHashtagsBuilder {
+        RTDHashtagsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDHashtagsBuilder {
+        RTDHashtagsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn hashtags(&self) -> &Vec<String> {
         &self.hashtags
     }
+
+    pub fn into_hashtags(self) -> Vec<String> {
+        self.hashtags
+    }
+
+    pub fn with_hashtags(mut self, hashtags: Vec<String>) -> Self {
+        self.hashtags = hashtags;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDHashtagsBuilder {
     inner: Hashtags,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDHashtagsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> Hashtags {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<Hashtags> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("Hashtags", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn hashtags(&mut self, hashtags: Vec<String>) -> &mut Self {
         self.inner.hashtags = hashtags;
+        self.missing.retain(|f| *f != "hashtags");
         self
     }
 }
@@ -72,3 +120,18 @@ impl AsRef<Hashtags> for RTDHashtagsBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            Hashtags::builder()
+                .hashtags(vec!["text".to_string()])
+                .build(),
+        );
+    }
+}
@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains an HTTP URL
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "httpUrl")]
 pub struct HttpUrl {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The URL
     url: String,
@@ -29,34 +29,82 @@ impl RObject for HttpUrl {
     }
 }
 
+impl PartialEq for HttpUrl {
+    fn eq(&self, other: &Self) -> bool {
+        self.url == other.url
+    }
+}
+
+impl Eq for HttpUrl {}
+
+impl std::hash::Hash for HttpUrl {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.url, state);
+    }
+}
+
 impl HttpUrl {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDHttpUrlBuilder {
-        let mut inner = HttpUrl::default();
-        inner.td_name = "httpUrl".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDHttpUrlBuilder { inner }
+        RTDHttpUrlBuilder {
+            inner: HttpUrl::default(),
+            missing: vec!["url"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDHttpUrlBuilder {
+        RTDHttpUrlBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDHttpUrlBuilder {
+        RTDHttpUrlBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn url(&self) -> &String {
         &self.url
     }
+
+    pub fn into_url(self) -> String {
+        self.url
+    }
+
+    pub fn with_url<T: AsRef<str>>(mut self, url: T) -> Self {
+        self.url = url.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDHttpUrlBuilder {
     inner: HttpUrl,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDHttpUrlBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> HttpUrl {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<HttpUrl> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("HttpUrl", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
         self.inner.url = url.as_ref().to_string();
+        self.missing.retain(|f| *f != "url");
         self
     }
 }
@@ -72,3 +120,14 @@ impl AsRef<HttpUrl> for RTDHttpUrlBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(HttpUrl::builder().url("text").build());
+    }
+}
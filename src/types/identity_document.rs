@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// An identity document
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "identityDocument")]
 pub struct IdentityDocument {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Document number; 1-24 characters
     number: String,
@@ -39,15 +39,53 @@ impl RObject for IdentityDocument {
     }
 }
 
+impl PartialEq for IdentityDocument {
+    fn eq(&self, other: &Self) -> bool {
+        self.number == other.number
+            && self.expiry_date == other.expiry_date
+            && self.front_side == other.front_side
+            && self.reverse_side == other.reverse_side
+            && self.selfie == other.selfie
+            && self.translation == other.translation
+    }
+}
+
+impl Eq for IdentityDocument {}
+
+impl std::hash::Hash for IdentityDocument {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.number, state);
+        std::hash::Hash::hash(&self.expiry_date, state);
+        std::hash::Hash::hash(&self.front_side, state);
+        std::hash::Hash::hash(&self.reverse_side, state);
+        std::hash::Hash::hash(&self.selfie, state);
+        std::hash::Hash::hash(&self.translation, state);
+    }
+}
+
 impl IdentityDocument {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDIdentityDocumentBuilder {
-        let mut inner = IdentityDocument::default();
-        inner.td_name = "identityDocument".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDIdentityDocumentBuilder { inner }
+        RTDIdentityDocumentBuilder {
+            inner: IdentityDocument::default(),
+            missing: vec!["number", "front_side", "reverse_side", "translation"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDIdentityDocumentBuilder {
+        RTDIdentityDocumentBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDIdentityDocumentBuilder {
+        RTDIdentityDocumentBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn number(&self) -> &String {
@@ -73,20 +111,88 @@ impl IdentityDocument {
     pub fn translation(&self) -> &Vec<DatedFile> {
         &self.translation
     }
+
+    pub fn into_number(self) -> String {
+        self.number
+    }
+
+    pub fn into_expiry_date(self) -> Option<Date> {
+        self.expiry_date
+    }
+
+    pub fn into_front_side(self) -> DatedFile {
+        self.front_side
+    }
+
+    pub fn into_reverse_side(self) -> DatedFile {
+        self.reverse_side
+    }
+
+    pub fn into_selfie(self) -> Option<DatedFile> {
+        self.selfie
+    }
+
+    pub fn into_translation(self) -> Vec<DatedFile> {
+        self.translation
+    }
+
+    pub fn with_number<T: AsRef<str>>(mut self, number: T) -> Self {
+        self.number = number.as_ref().to_string();
+        self
+    }
+
+    pub fn with_expiry_date<T: AsRef<Date>>(mut self, expiry_date: T) -> Self {
+        self.expiry_date = Some(expiry_date.as_ref().clone());
+        self
+    }
+
+    pub fn with_front_side<T: AsRef<DatedFile>>(mut self, front_side: T) -> Self {
+        self.front_side = front_side.as_ref().clone();
+        self
+    }
+
+    pub fn with_reverse_side<T: AsRef<DatedFile>>(mut self, reverse_side: T) -> Self {
+        self.reverse_side = reverse_side.as_ref().clone();
+        self
+    }
+
+    pub fn with_selfie<T: AsRef<DatedFile>>(mut self, selfie: T) -> Self {
+        self.selfie = Some(selfie.as_ref().clone());
+        self
+    }
+
+    pub fn with_translation(mut self, translation: Vec<DatedFile>) -> Self {
+        self.translation = translation;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDIdentityDocumentBuilder {
     inner: IdentityDocument,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDIdentityDocumentBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> IdentityDocument {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<IdentityDocument> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "IdentityDocument",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn number<T: AsRef<str>>(&mut self, number: T) -> &mut Self {
         self.inner.number = number.as_ref().to_string();
+        self.missing.retain(|f| *f != "number");
         self
     }
 
@@ -97,11 +203,13 @@ impl RTDIdentityDocumentBuilder {
 
     pub fn front_side<T: AsRef<DatedFile>>(&mut self, front_side: T) -> &mut Self {
         self.inner.front_side = front_side.as_ref().clone();
+        self.missing.retain(|f| *f != "front_side");
         self
     }
 
     pub fn reverse_side<T: AsRef<DatedFile>>(&mut self, reverse_side: T) -> &mut Self {
         self.inner.reverse_side = reverse_side.as_ref().clone();
+        self.missing.retain(|f| *f != "reverse_side");
         self
     }
 
@@ -112,6 +220,7 @@ impl RTDIdentityDocumentBuilder {
 
     pub fn translation(&mut self, translation: Vec<DatedFile>) -> &mut Self {
         self.inner.translation = translation;
+        self.missing.retain(|f| *f != "translation");
         self
     }
 }
@@ -127,3 +236,85 @@ impl AsRef<IdentityDocument> for RTDIdentityDocumentBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            IdentityDocument::builder()
+                .number("text")
+                .front_side(
+                    DatedFile::builder()
+                        .file(
+                            File::builder()
+                                .id(i32::MAX)
+                                .size(i32::MAX)
+                                .expected_size(i32::MAX)
+                                .local(
+                                    LocalFile::builder()
+                                        .path("text")
+                                        .can_be_downloaded(true)
+                                        .can_be_deleted(true)
+                                        .is_downloading_active(true)
+                                        .is_downloading_completed(true)
+                                        .download_offset(i32::MAX)
+                                        .downloaded_prefix_size(i32::MAX)
+                                        .downloaded_size(i32::MAX)
+                                        .build(),
+                                )
+                                .remote(
+                                    RemoteFile::builder()
+                                        .id("text")
+                                        .unique_id("text")
+                                        .is_uploading_active(true)
+                                        .is_uploading_completed(true)
+                                        .uploaded_size(i32::MAX)
+                                        .build(),
+                                )
+                                .build(),
+                        )
+                        .date(i32::MAX)
+                        .build(),
+                )
+                .reverse_side(
+                    DatedFile::builder()
+                        .file(
+                            File::builder()
+                                .id(i32::MAX)
+                                .size(i32::MAX)
+                                .expected_size(i32::MAX)
+                                .local(
+                                    LocalFile::builder()
+                                        .path("text")
+                                        .can_be_downloaded(true)
+                                        .can_be_deleted(true)
+                                        .is_downloading_active(true)
+                                        .is_downloading_completed(true)
+                                        .download_offset(i32::MAX)
+                                        .downloaded_prefix_size(i32::MAX)
+                                        .downloaded_size(i32::MAX)
+                                        .build(),
+                                )
+                                .remote(
+                                    RemoteFile::builder()
+                                        .id("text")
+                                        .unique_id("text")
+                                        .is_uploading_active(true)
+                                        .is_uploading_completed(true)
+                                        .uploaded_size(i32::MAX)
+                                        .build(),
+                                )
+                                .build(),
+                        )
+                        .date(i32::MAX)
+                        .build(),
+                )
+                .translation(vec![])
+                .build(),
+        );
+    }
+}
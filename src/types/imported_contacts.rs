@@ -1,20 +1,20 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Represents the result of an ImportContacts request
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "importedContacts")]
 pub struct ImportedContacts {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// User identifiers of the imported contacts in the same order as they were specified in the request; 0 if the contact is not yet a registered user
-    user_ids: Vec<i64>,
+    user_ids: Vec<UserId>,
     /// The number of users that imported the corresponding contact; 0 for already registered users or if unavailable
-    importer_count: Vec<i64>,
+    importer_count: Vec<i32>,
 }
 
 impl RObject for ImportedContacts {
@@ -31,42 +31,102 @@ impl RObject for ImportedContacts {
     }
 }
 
+impl PartialEq for ImportedContacts {
+    fn eq(&self, other: &Self) -> bool {
+        self.user_ids == other.user_ids && self.importer_count == other.importer_count
+    }
+}
+
+impl Eq for ImportedContacts {}
+
+impl std::hash::Hash for ImportedContacts {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.user_ids, state);
+        std::hash::Hash::hash(&self.importer_count, state);
+    }
+}
+
 impl ImportedContacts {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDImportedContactsBuilder {
-        let mut inner = ImportedContacts::default();
-        inner.td_name = "importedContacts".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDImportedContactsBuilder { inner }
+        RTDImportedContactsBuilder {
+            inner: ImportedContacts::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDImportedContactsBuilder {
+        RTDImportedContactsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDImportedContactsBuilder {
+        RTDImportedContactsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn user_ids(&self) -> &Vec<i64> {
+    pub fn user_ids(&self) -> &Vec<UserId> {
         &self.user_ids
     }
 
-    pub fn importer_count(&self) -> &Vec<i64> {
+    pub fn importer_count(&self) -> &Vec<i32> {
         &self.importer_count
     }
+
+    pub fn into_user_ids(self) -> Vec<UserId> {
+        self.user_ids
+    }
+
+    pub fn into_importer_count(self) -> Vec<i32> {
+        self.importer_count
+    }
+
+    pub fn with_user_ids(mut self, user_ids: Vec<UserId>) -> Self {
+        self.user_ids = user_ids;
+        self
+    }
+
+    pub fn with_importer_count(mut self, importer_count: Vec<i32>) -> Self {
+        self.importer_count = importer_count;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDImportedContactsBuilder {
     inner: ImportedContacts,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDImportedContactsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ImportedContacts {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ImportedContacts> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ImportedContacts",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn user_ids(&mut self, user_ids: Vec<i64>) -> &mut Self {
+    pub fn user_ids(&mut self, user_ids: Vec<UserId>) -> &mut Self {
         self.inner.user_ids = user_ids;
         self
     }
 
-    pub fn importer_count(&mut self, importer_count: Vec<i64>) -> &mut Self {
+    pub fn importer_count(&mut self, importer_count: Vec<i32>) -> &mut Self {
         self.inner.importer_count = importer_count;
         self
     }
@@ -83,3 +143,19 @@ impl AsRef<ImportedContacts> for RTDImportedContactsBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            ImportedContacts::builder()
+                .user_ids(vec![Default::default()])
+                .importer_count(vec![i32::MAX])
+                .build(),
+        );
+    }
+}
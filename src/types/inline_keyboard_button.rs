@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Represents a single button in an inline keyboard
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inlineKeyboardButton")]
 pub struct InlineKeyboardButton {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Text of the button
     text: String,
@@ -32,15 +32,44 @@ impl RObject for InlineKeyboardButton {
     }
 }
 
+impl PartialEq for InlineKeyboardButton {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text && self.type_ == other.type_
+    }
+}
+
+impl Eq for InlineKeyboardButton {}
+
+impl std::hash::Hash for InlineKeyboardButton {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.text, state);
+        std::hash::Hash::hash(&self.type_, state);
+    }
+}
+
 impl InlineKeyboardButton {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInlineKeyboardButtonBuilder {
-        let mut inner = InlineKeyboardButton::default();
-        inner.td_name = "inlineKeyboardButton".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInlineKeyboardButtonBuilder { inner }
+        RTDInlineKeyboardButtonBuilder {
+            inner: InlineKeyboardButton::default(),
+            missing: vec!["text", "type_"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInlineKeyboardButtonBuilder {
+        RTDInlineKeyboardButtonBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInlineKeyboardButtonBuilder {
+        RTDInlineKeyboardButtonBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn text(&self) -> &String {
@@ -50,25 +79,58 @@ impl InlineKeyboardButton {
     pub fn type_(&self) -> &InlineKeyboardButtonType {
         &self.type_
     }
+
+    pub fn into_text(self) -> String {
+        self.text
+    }
+
+    pub fn into_type(self) -> InlineKeyboardButtonType {
+        self.type_
+    }
+
+    pub fn with_text<T: AsRef<str>>(mut self, text: T) -> Self {
+        self.text = text.as_ref().to_string();
+        self
+    }
+
+    pub fn with_type<T: AsRef<InlineKeyboardButtonType>>(mut self, type_: T) -> Self {
+        self.type_ = type_.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInlineKeyboardButtonBuilder {
     inner: InlineKeyboardButton,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInlineKeyboardButtonBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InlineKeyboardButton {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InlineKeyboardButton> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InlineKeyboardButton",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn text<T: AsRef<str>>(&mut self, text: T) -> &mut Self {
         self.inner.text = text.as_ref().to_string();
+        self.missing.retain(|f| *f != "text");
         self
     }
 
     pub fn type_<T: AsRef<InlineKeyboardButtonType>>(&mut self, type_: T) -> &mut Self {
         self.inner.type_ = type_.as_ref().clone();
+        self.missing.retain(|f| *f != "type_");
         self
     }
 }
@@ -84,3 +146,21 @@ impl AsRef<InlineKeyboardButton> for RTDInlineKeyboardButtonBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            InlineKeyboardButton::builder()
+                .text("text")
+                .type_(InlineKeyboardButtonType::Buy(
+                    InlineKeyboardButtonTypeBuy::builder().build(),
+                ))
+                .build(),
+        );
+    }
+}
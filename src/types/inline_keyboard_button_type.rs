@@ -1,55 +1,56 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Describes the type of an inline keyboard button
 pub trait TDInlineKeyboardButtonType: Debug + RObject {}
 
 /// Describes the type of an inline keyboard button
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum InlineKeyboardButtonType {
-    #[doc(hidden)]
-    _Default(()),
     /// A button to buy something. This button must be in the first column and row of the keyboard and can be attached only to a message with content of the type messageInvoice
+    #[serde(rename = "inlineKeyboardButtonTypeBuy")]
     Buy(InlineKeyboardButtonTypeBuy),
     /// A button that sends a special callback query to a bot
+    #[serde(rename = "inlineKeyboardButtonTypeCallback")]
     Callback(InlineKeyboardButtonTypeCallback),
     /// A button with a game that sends a special callback query to a bot. This button must be in the first column and row of the keyboard and can be attached only to a message with content of the type messageGame
+    #[serde(rename = "inlineKeyboardButtonTypeCallbackGame")]
     CallbackGame(InlineKeyboardButtonTypeCallbackGame),
     /// A button that opens a specified URL and automatically logs in in current user if they allowed to do that
+    #[serde(rename = "inlineKeyboardButtonTypeLoginUrl")]
     LoginUrl(InlineKeyboardButtonTypeLoginUrl),
     /// A button that forces an inline query to the bot to be inserted in the input field
+    #[serde(rename = "inlineKeyboardButtonTypeSwitchInline")]
     SwitchInline(InlineKeyboardButtonTypeSwitchInline),
     /// A button that opens a specified URL
+    #[serde(rename = "inlineKeyboardButtonTypeUrl")]
     Url(InlineKeyboardButtonTypeUrl),
 }
 
+/// Default value is default of [InlineKeyboardButtonTypeBuy], builders still require the field to be set
 impl Default for InlineKeyboardButtonType {
     fn default() -> Self {
-        InlineKeyboardButtonType::_Default(())
+        InlineKeyboardButtonType::Buy(InlineKeyboardButtonTypeBuy::default())
     }
 }
 
-impl<'de> Deserialize<'de> for InlineKeyboardButtonType {
-    fn deserialize<D>(deserializer: D) -> Result<InlineKeyboardButtonType, D::Error>
+impl Serialize for InlineKeyboardButtonType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          InlineKeyboardButtonType,
-          (inlineKeyboardButtonTypeBuy, Buy);
-          (inlineKeyboardButtonTypeCallback, Callback);
-          (inlineKeyboardButtonTypeCallbackGame, CallbackGame);
-          (inlineKeyboardButtonTypeLoginUrl, LoginUrl);
-          (inlineKeyboardButtonTypeSwitchInline, SwitchInline);
-          (inlineKeyboardButtonTypeUrl, Url);
-
-        )(deserializer)
+        match self {
+            InlineKeyboardButtonType::Buy(t) => t.serialize(serializer),
+            InlineKeyboardButtonType::Callback(t) => t.serialize(serializer),
+            InlineKeyboardButtonType::CallbackGame(t) => t.serialize(serializer),
+            InlineKeyboardButtonType::LoginUrl(t) => t.serialize(serializer),
+            InlineKeyboardButtonType::SwitchInline(t) => t.serialize(serializer),
+            InlineKeyboardButtonType::Url(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -63,8 +64,6 @@ impl RObject for InlineKeyboardButtonType {
             InlineKeyboardButtonType::LoginUrl(t) => t.td_name(),
             InlineKeyboardButtonType::SwitchInline(t) => t.td_name(),
             InlineKeyboardButtonType::Url(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -76,8 +75,6 @@ impl RObject for InlineKeyboardButtonType {
             InlineKeyboardButtonType::LoginUrl(t) => t.extra(),
             InlineKeyboardButtonType::SwitchInline(t) => t.extra(),
             InlineKeyboardButtonType::Url(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -89,10 +86,6 @@ impl InlineKeyboardButtonType {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, InlineKeyboardButtonType::_Default(_))
-    }
 }
 
 impl AsRef<InlineKeyboardButtonType> for InlineKeyboardButtonType {
@@ -103,12 +96,13 @@ impl AsRef<InlineKeyboardButtonType> for InlineKeyboardButtonType {
 
 /// A button to buy something. This button must be in the first column and row of the keyboard and can be attached only to a message with content of the type messageInvoice
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inlineKeyboardButtonTypeBuy")]
 pub struct InlineKeyboardButtonTypeBuy {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -126,6 +120,18 @@ impl RObject for InlineKeyboardButtonTypeBuy {
     }
 }
 
+impl PartialEq for InlineKeyboardButtonTypeBuy {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for InlineKeyboardButtonTypeBuy {}
+
+impl std::hash::Hash for InlineKeyboardButtonTypeBuy {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDInlineKeyboardButtonType for InlineKeyboardButtonTypeBuy {}
 
 impl InlineKeyboardButtonTypeBuy {
@@ -133,22 +139,49 @@ impl InlineKeyboardButtonTypeBuy {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInlineKeyboardButtonTypeBuyBuilder {
-        let mut inner = InlineKeyboardButtonTypeBuy::default();
-        inner.td_name = "inlineKeyboardButtonTypeBuy".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInlineKeyboardButtonTypeBuyBuilder { inner }
+        RTDInlineKeyboardButtonTypeBuyBuilder {
+            inner: InlineKeyboardButtonTypeBuy::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInlineKeyboardButtonTypeBuyBuilder {
+        RTDInlineKeyboardButtonTypeBuyBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInlineKeyboardButtonTypeBuyBuilder {
+        RTDInlineKeyboardButtonTypeBuyBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDInlineKeyboardButtonTypeBuyBuilder {
     inner: InlineKeyboardButtonTypeBuy,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInlineKeyboardButtonTypeBuyBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InlineKeyboardButtonTypeBuy {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InlineKeyboardButtonTypeBuy> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InlineKeyboardButtonTypeBuy",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<InlineKeyboardButtonTypeBuy> for InlineKeyboardButtonTypeBuy {
@@ -165,15 +198,17 @@ impl AsRef<InlineKeyboardButtonTypeBuy> for RTDInlineKeyboardButtonTypeBuyBuilde
 
 /// A button that sends a special callback query to a bot
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inlineKeyboardButtonTypeCallback")]
 pub struct InlineKeyboardButtonTypeCallback {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Data to be sent to the bot via a callback query
-    data: String,
+    #[serde(with = "super::_common::bytes")]
+    data: Vec<u8>,
 }
 
 impl RObject for InlineKeyboardButtonTypeCallback {
@@ -190,6 +225,20 @@ impl RObject for InlineKeyboardButtonTypeCallback {
     }
 }
 
+impl PartialEq for InlineKeyboardButtonTypeCallback {
+    fn eq(&self, other: &Self) -> bool {
+        self.data == other.data
+    }
+}
+
+impl Eq for InlineKeyboardButtonTypeCallback {}
+
+impl std::hash::Hash for InlineKeyboardButtonTypeCallback {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.data, state);
+    }
+}
+
 impl TDInlineKeyboardButtonType for InlineKeyboardButtonTypeCallback {}
 
 impl InlineKeyboardButtonTypeCallback {
@@ -197,29 +246,66 @@ impl InlineKeyboardButtonTypeCallback {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInlineKeyboardButtonTypeCallbackBuilder {
-        let mut inner = InlineKeyboardButtonTypeCallback::default();
-        inner.td_name = "inlineKeyboardButtonTypeCallback".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInlineKeyboardButtonTypeCallbackBuilder { inner }
+        RTDInlineKeyboardButtonTypeCallbackBuilder {
+            inner: InlineKeyboardButtonTypeCallback::default(),
+            missing: vec!["data"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInlineKeyboardButtonTypeCallbackBuilder {
+        RTDInlineKeyboardButtonTypeCallbackBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInlineKeyboardButtonTypeCallbackBuilder {
+        RTDInlineKeyboardButtonTypeCallbackBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn data(&self) -> &String {
+    pub fn data(&self) -> &[u8] {
         &self.data
     }
+
+    pub fn into_data(self) -> Vec<u8> {
+        self.data
+    }
+
+    pub fn with_data<T: AsRef<[u8]>>(mut self, data: T) -> Self {
+        self.data = data.as_ref().to_vec();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInlineKeyboardButtonTypeCallbackBuilder {
     inner: InlineKeyboardButtonTypeCallback,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInlineKeyboardButtonTypeCallbackBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InlineKeyboardButtonTypeCallback {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InlineKeyboardButtonTypeCallback> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InlineKeyboardButtonTypeCallback",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn data<T: AsRef<str>>(&mut self, data: T) -> &mut Self {
-        self.inner.data = data.as_ref().to_string();
+    pub fn data<T: AsRef<[u8]>>(&mut self, data: T) -> &mut Self {
+        self.inner.data = data.as_ref().to_vec();
+        self.missing.retain(|f| *f != "data");
         self
     }
 }
@@ -238,12 +324,13 @@ impl AsRef<InlineKeyboardButtonTypeCallback> for RTDInlineKeyboardButtonTypeCall
 
 /// A button with a game that sends a special callback query to a bot. This button must be in the first column and row of the keyboard and can be attached only to a message with content of the type messageGame
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inlineKeyboardButtonTypeCallbackGame")]
 pub struct InlineKeyboardButtonTypeCallbackGame {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -261,6 +348,18 @@ impl RObject for InlineKeyboardButtonTypeCallbackGame {
     }
 }
 
+impl PartialEq for InlineKeyboardButtonTypeCallbackGame {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for InlineKeyboardButtonTypeCallbackGame {}
+
+impl std::hash::Hash for InlineKeyboardButtonTypeCallbackGame {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDInlineKeyboardButtonType for InlineKeyboardButtonTypeCallbackGame {}
 
 impl InlineKeyboardButtonTypeCallbackGame {
@@ -268,22 +367,49 @@ impl InlineKeyboardButtonTypeCallbackGame {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInlineKeyboardButtonTypeCallbackGameBuilder {
-        let mut inner = InlineKeyboardButtonTypeCallbackGame::default();
-        inner.td_name = "inlineKeyboardButtonTypeCallbackGame".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInlineKeyboardButtonTypeCallbackGameBuilder { inner }
+        RTDInlineKeyboardButtonTypeCallbackGameBuilder {
+            inner: InlineKeyboardButtonTypeCallbackGame::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInlineKeyboardButtonTypeCallbackGameBuilder {
+        RTDInlineKeyboardButtonTypeCallbackGameBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInlineKeyboardButtonTypeCallbackGameBuilder {
+        RTDInlineKeyboardButtonTypeCallbackGameBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDInlineKeyboardButtonTypeCallbackGameBuilder {
     inner: InlineKeyboardButtonTypeCallbackGame,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInlineKeyboardButtonTypeCallbackGameBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InlineKeyboardButtonTypeCallbackGame {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InlineKeyboardButtonTypeCallbackGame> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InlineKeyboardButtonTypeCallbackGame",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<InlineKeyboardButtonTypeCallbackGame> for InlineKeyboardButtonTypeCallbackGame {
@@ -302,17 +428,18 @@ impl AsRef<InlineKeyboardButtonTypeCallbackGame>
 
 /// A button that opens a specified URL and automatically logs in in current user if they allowed to do that
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inlineKeyboardButtonTypeLoginUrl")]
 pub struct InlineKeyboardButtonTypeLoginUrl {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// An HTTP URL to open
     url: String,
     /// Unique button identifier
-    id: i64,
+    id: i32,
     /// If non-empty, new text of the button in forwarded messages
     forward_text: String,
 }
@@ -331,6 +458,22 @@ impl RObject for InlineKeyboardButtonTypeLoginUrl {
     }
 }
 
+impl PartialEq for InlineKeyboardButtonTypeLoginUrl {
+    fn eq(&self, other: &Self) -> bool {
+        self.url == other.url && self.id == other.id && self.forward_text == other.forward_text
+    }
+}
+
+impl Eq for InlineKeyboardButtonTypeLoginUrl {}
+
+impl std::hash::Hash for InlineKeyboardButtonTypeLoginUrl {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.url, state);
+        std::hash::Hash::hash(&self.id, state);
+        std::hash::Hash::hash(&self.forward_text, state);
+    }
+}
+
 impl TDInlineKeyboardButtonType for InlineKeyboardButtonTypeLoginUrl {}
 
 impl InlineKeyboardButtonTypeLoginUrl {
@@ -338,42 +481,94 @@ impl InlineKeyboardButtonTypeLoginUrl {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInlineKeyboardButtonTypeLoginUrlBuilder {
-        let mut inner = InlineKeyboardButtonTypeLoginUrl::default();
-        inner.td_name = "inlineKeyboardButtonTypeLoginUrl".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInlineKeyboardButtonTypeLoginUrlBuilder { inner }
+        RTDInlineKeyboardButtonTypeLoginUrlBuilder {
+            inner: InlineKeyboardButtonTypeLoginUrl::default(),
+            missing: vec!["url", "id"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInlineKeyboardButtonTypeLoginUrlBuilder {
+        RTDInlineKeyboardButtonTypeLoginUrlBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInlineKeyboardButtonTypeLoginUrlBuilder {
+        RTDInlineKeyboardButtonTypeLoginUrlBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn url(&self) -> &String {
         &self.url
     }
 
-    pub fn id(&self) -> i64 {
+    pub fn id(&self) -> i32 {
         self.id
     }
 
     pub fn forward_text(&self) -> &String {
         &self.forward_text
     }
+
+    pub fn into_url(self) -> String {
+        self.url
+    }
+
+    pub fn into_forward_text(self) -> String {
+        self.forward_text
+    }
+
+    pub fn with_url<T: AsRef<str>>(mut self, url: T) -> Self {
+        self.url = url.as_ref().to_string();
+        self
+    }
+
+    pub fn with_id(mut self, id: i32) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn with_forward_text<T: AsRef<str>>(mut self, forward_text: T) -> Self {
+        self.forward_text = forward_text.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInlineKeyboardButtonTypeLoginUrlBuilder {
     inner: InlineKeyboardButtonTypeLoginUrl,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInlineKeyboardButtonTypeLoginUrlBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InlineKeyboardButtonTypeLoginUrl {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InlineKeyboardButtonTypeLoginUrl> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InlineKeyboardButtonTypeLoginUrl",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
         self.inner.url = url.as_ref().to_string();
+        self.missing.retain(|f| *f != "url");
         self
     }
 
-    pub fn id(&mut self, id: i64) -> &mut Self {
+    pub fn id(&mut self, id: i32) -> &mut Self {
         self.inner.id = id;
+        self.missing.retain(|f| *f != "id");
         self
     }
 
@@ -397,12 +592,13 @@ impl AsRef<InlineKeyboardButtonTypeLoginUrl> for RTDInlineKeyboardButtonTypeLogi
 
 /// A button that forces an inline query to the bot to be inserted in the input field
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inlineKeyboardButtonTypeSwitchInline")]
 pub struct InlineKeyboardButtonTypeSwitchInline {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Inline query to be sent to the bot
     query: String,
@@ -424,6 +620,21 @@ impl RObject for InlineKeyboardButtonTypeSwitchInline {
     }
 }
 
+impl PartialEq for InlineKeyboardButtonTypeSwitchInline {
+    fn eq(&self, other: &Self) -> bool {
+        self.query == other.query && self.in_current_chat == other.in_current_chat
+    }
+}
+
+impl Eq for InlineKeyboardButtonTypeSwitchInline {}
+
+impl std::hash::Hash for InlineKeyboardButtonTypeSwitchInline {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.query, state);
+        std::hash::Hash::hash(&self.in_current_chat, state);
+    }
+}
+
 impl TDInlineKeyboardButtonType for InlineKeyboardButtonTypeSwitchInline {}
 
 impl InlineKeyboardButtonTypeSwitchInline {
@@ -431,10 +642,24 @@ impl InlineKeyboardButtonTypeSwitchInline {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInlineKeyboardButtonTypeSwitchInlineBuilder {
-        let mut inner = InlineKeyboardButtonTypeSwitchInline::default();
-        inner.td_name = "inlineKeyboardButtonTypeSwitchInline".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInlineKeyboardButtonTypeSwitchInlineBuilder { inner }
+        RTDInlineKeyboardButtonTypeSwitchInlineBuilder {
+            inner: InlineKeyboardButtonTypeSwitchInline::default(),
+            missing: vec!["query"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInlineKeyboardButtonTypeSwitchInlineBuilder {
+        RTDInlineKeyboardButtonTypeSwitchInlineBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInlineKeyboardButtonTypeSwitchInlineBuilder {
+        RTDInlineKeyboardButtonTypeSwitchInlineBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn query(&self) -> &String {
@@ -444,20 +669,48 @@ impl InlineKeyboardButtonTypeSwitchInline {
     pub fn in_current_chat(&self) -> bool {
         self.in_current_chat
     }
+
+    pub fn into_query(self) -> String {
+        self.query
+    }
+
+    pub fn with_query<T: AsRef<str>>(mut self, query: T) -> Self {
+        self.query = query.as_ref().to_string();
+        self
+    }
+
+    pub fn with_in_current_chat(mut self, in_current_chat: bool) -> Self {
+        self.in_current_chat = in_current_chat;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInlineKeyboardButtonTypeSwitchInlineBuilder {
     inner: InlineKeyboardButtonTypeSwitchInline,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInlineKeyboardButtonTypeSwitchInlineBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InlineKeyboardButtonTypeSwitchInline {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InlineKeyboardButtonTypeSwitchInline> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InlineKeyboardButtonTypeSwitchInline",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn query<T: AsRef<str>>(&mut self, query: T) -> &mut Self {
         self.inner.query = query.as_ref().to_string();
+        self.missing.retain(|f| *f != "query");
         self
     }
 
@@ -483,12 +736,13 @@ impl AsRef<InlineKeyboardButtonTypeSwitchInline>
 
 /// A button that opens a specified URL
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inlineKeyboardButtonTypeUrl")]
 pub struct InlineKeyboardButtonTypeUrl {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// HTTP or tg:// URL to open
     url: String,
@@ -508,6 +762,20 @@ impl RObject for InlineKeyboardButtonTypeUrl {
     }
 }
 
+impl PartialEq for InlineKeyboardButtonTypeUrl {
+    fn eq(&self, other: &Self) -> bool {
+        self.url == other.url
+    }
+}
+
+impl Eq for InlineKeyboardButtonTypeUrl {}
+
+impl std::hash::Hash for InlineKeyboardButtonTypeUrl {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.url, state);
+    }
+}
+
 impl TDInlineKeyboardButtonType for InlineKeyboardButtonTypeUrl {}
 
 impl InlineKeyboardButtonTypeUrl {
@@ -515,29 +783,66 @@ impl InlineKeyboardButtonTypeUrl {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInlineKeyboardButtonTypeUrlBuilder {
-        let mut inner = InlineKeyboardButtonTypeUrl::default();
-        inner.td_name = "inlineKeyboardButtonTypeUrl".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInlineKeyboardButtonTypeUrlBuilder { inner }
+        RTDInlineKeyboardButtonTypeUrlBuilder {
+            inner: InlineKeyboardButtonTypeUrl::default(),
+            missing: vec!["url"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInlineKeyboardButtonTypeUrlBuilder {
+        RTDInlineKeyboardButtonTypeUrlBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInlineKeyboardButtonTypeUrlBuilder {
+        RTDInlineKeyboardButtonTypeUrlBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn url(&self) -> &String {
         &self.url
     }
+
+    pub fn into_url(self) -> String {
+        self.url
+    }
+
+    pub fn with_url<T: AsRef<str>>(mut self, url: T) -> Self {
+        self.url = url.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInlineKeyboardButtonTypeUrlBuilder {
     inner: InlineKeyboardButtonTypeUrl,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInlineKeyboardButtonTypeUrlBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InlineKeyboardButtonTypeUrl {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InlineKeyboardButtonTypeUrl> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InlineKeyboardButtonTypeUrl",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
         self.inner.url = url.as_ref().to_string();
+        self.missing.retain(|f| *f != "url");
         self
     }
 }
@@ -553,3 +858,40 @@ impl AsRef<InlineKeyboardButtonTypeUrl> for RTDInlineKeyboardButtonTypeUrlBuilde
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(InlineKeyboardButtonType::Buy(
+            InlineKeyboardButtonTypeBuy::builder().build(),
+        ));
+        assert_round_trip(InlineKeyboardButtonType::Callback(
+            InlineKeyboardButtonTypeCallback::builder()
+                .data(b"\x00\x01\xfe\xff")
+                .build(),
+        ));
+        assert_round_trip(InlineKeyboardButtonType::CallbackGame(
+            InlineKeyboardButtonTypeCallbackGame::builder().build(),
+        ));
+        assert_round_trip(InlineKeyboardButtonType::LoginUrl(
+            InlineKeyboardButtonTypeLoginUrl::builder()
+                .url("text")
+                .id(i32::MAX)
+                .forward_text("text")
+                .build(),
+        ));
+        assert_round_trip(InlineKeyboardButtonType::SwitchInline(
+            InlineKeyboardButtonTypeSwitchInline::builder()
+                .query("text")
+                .in_current_chat(true)
+                .build(),
+        ));
+        assert_round_trip(InlineKeyboardButtonType::Url(
+            InlineKeyboardButtonTypeUrl::builder().url("text").build(),
+        ));
+    }
+}
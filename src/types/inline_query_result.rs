@@ -1,73 +1,80 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Represents a single result of an inline query
 pub trait TDInlineQueryResult: Debug + RObject {}
 
 /// Represents a single result of an inline query
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "@type")]
 pub enum InlineQueryResult {
-    #[doc(hidden)]
-    _Default(()),
     /// Represents an animation file
+    #[serde(rename = "inlineQueryResultAnimation")]
     Animation(InlineQueryResultAnimation),
     /// Represents a link to an article or web page
+    #[serde(rename = "inlineQueryResultArticle")]
     Article(InlineQueryResultArticle),
     /// Represents an audio file
+    #[serde(rename = "inlineQueryResultAudio")]
     Audio(InlineQueryResultAudio),
     /// Represents a user contact
+    #[serde(rename = "inlineQueryResultContact")]
     Contact(InlineQueryResultContact),
     /// Represents a document
+    #[serde(rename = "inlineQueryResultDocument")]
     Document(InlineQueryResultDocument),
     /// Represents information about a game
+    #[serde(rename = "inlineQueryResultGame")]
     Game(InlineQueryResultGame),
     /// Represents a point on the map
+    #[serde(rename = "inlineQueryResultLocation")]
     Location(InlineQueryResultLocation),
     /// Represents a photo
+    #[serde(rename = "inlineQueryResultPhoto")]
     Photo(InlineQueryResultPhoto),
     /// Represents a sticker
+    #[serde(rename = "inlineQueryResultSticker")]
     Sticker(InlineQueryResultSticker),
     /// Represents information about a venue
+    #[serde(rename = "inlineQueryResultVenue")]
     Venue(InlineQueryResultVenue),
     /// Represents a video
+    #[serde(rename = "inlineQueryResultVideo")]
     Video(InlineQueryResultVideo),
     /// Represents a voice note
+    #[serde(rename = "inlineQueryResultVoiceNote")]
     VoiceNote(InlineQueryResultVoiceNote),
 }
 
+/// Default value is default of [InlineQueryResultArticle], builders still require the field to be set
 impl Default for InlineQueryResult {
     fn default() -> Self {
-        InlineQueryResult::_Default(())
+        InlineQueryResult::Article(InlineQueryResultArticle::default())
     }
 }
 
-impl<'de> Deserialize<'de> for InlineQueryResult {
-    fn deserialize<D>(deserializer: D) -> Result<InlineQueryResult, D::Error>
+impl Serialize for InlineQueryResult {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          InlineQueryResult,
-          (inlineQueryResultAnimation, Animation);
-          (inlineQueryResultArticle, Article);
-          (inlineQueryResultAudio, Audio);
-          (inlineQueryResultContact, Contact);
-          (inlineQueryResultDocument, Document);
-          (inlineQueryResultGame, Game);
-          (inlineQueryResultLocation, Location);
-          (inlineQueryResultPhoto, Photo);
-          (inlineQueryResultSticker, Sticker);
-          (inlineQueryResultVenue, Venue);
-          (inlineQueryResultVideo, Video);
-          (inlineQueryResultVoiceNote, VoiceNote);
-
-        )(deserializer)
+        match self {
+            InlineQueryResult::Animation(t) => t.serialize(serializer),
+            InlineQueryResult::Article(t) => t.serialize(serializer),
+            InlineQueryResult::Audio(t) => t.serialize(serializer),
+            InlineQueryResult::Contact(t) => t.serialize(serializer),
+            InlineQueryResult::Document(t) => t.serialize(serializer),
+            InlineQueryResult::Game(t) => t.serialize(serializer),
+            InlineQueryResult::Location(t) => t.serialize(serializer),
+            InlineQueryResult::Photo(t) => t.serialize(serializer),
+            InlineQueryResult::Sticker(t) => t.serialize(serializer),
+            InlineQueryResult::Venue(t) => t.serialize(serializer),
+            InlineQueryResult::Video(t) => t.serialize(serializer),
+            InlineQueryResult::VoiceNote(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -87,8 +94,6 @@ impl RObject for InlineQueryResult {
             InlineQueryResult::Venue(t) => t.td_name(),
             InlineQueryResult::Video(t) => t.td_name(),
             InlineQueryResult::VoiceNote(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -106,8 +111,6 @@ impl RObject for InlineQueryResult {
             InlineQueryResult::Venue(t) => t.extra(),
             InlineQueryResult::Video(t) => t.extra(),
             InlineQueryResult::VoiceNote(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -119,10 +122,6 @@ impl InlineQueryResult {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, InlineQueryResult::_Default(_))
-    }
 }
 
 impl AsRef<InlineQueryResult> for InlineQueryResult {
@@ -133,12 +132,13 @@ impl AsRef<InlineQueryResult> for InlineQueryResult {
 
 /// Represents an animation file
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inlineQueryResultAnimation")]
 pub struct InlineQueryResultAnimation {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Unique identifier of the query result
     id: String,
@@ -162,6 +162,22 @@ impl RObject for InlineQueryResultAnimation {
     }
 }
 
+impl PartialEq for InlineQueryResultAnimation {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id && self.animation == other.animation && self.title == other.title
+    }
+}
+
+impl Eq for InlineQueryResultAnimation {}
+
+impl std::hash::Hash for InlineQueryResultAnimation {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.id, state);
+        std::hash::Hash::hash(&self.animation, state);
+        std::hash::Hash::hash(&self.title, state);
+    }
+}
+
 impl TDInlineQueryResult for InlineQueryResultAnimation {}
 
 impl InlineQueryResultAnimation {
@@ -169,10 +185,24 @@ impl InlineQueryResultAnimation {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInlineQueryResultAnimationBuilder {
-        let mut inner = InlineQueryResultAnimation::default();
-        inner.td_name = "inlineQueryResultAnimation".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInlineQueryResultAnimationBuilder { inner }
+        RTDInlineQueryResultAnimationBuilder {
+            inner: InlineQueryResultAnimation::default(),
+            missing: vec!["id", "animation", "title"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInlineQueryResultAnimationBuilder {
+        RTDInlineQueryResultAnimationBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInlineQueryResultAnimationBuilder {
+        RTDInlineQueryResultAnimationBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn id(&self) -> &String {
@@ -186,30 +216,73 @@ impl InlineQueryResultAnimation {
     pub fn title(&self) -> &String {
         &self.title
     }
+
+    pub fn into_id(self) -> String {
+        self.id
+    }
+
+    pub fn into_animation(self) -> Animation {
+        self.animation
+    }
+
+    pub fn into_title(self) -> String {
+        self.title
+    }
+
+    pub fn with_id<T: AsRef<str>>(mut self, id: T) -> Self {
+        self.id = id.as_ref().to_string();
+        self
+    }
+
+    pub fn with_animation<T: AsRef<Animation>>(mut self, animation: T) -> Self {
+        self.animation = animation.as_ref().clone();
+        self
+    }
+
+    pub fn with_title<T: AsRef<str>>(mut self, title: T) -> Self {
+        self.title = title.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInlineQueryResultAnimationBuilder {
     inner: InlineQueryResultAnimation,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInlineQueryResultAnimationBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InlineQueryResultAnimation {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InlineQueryResultAnimation> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InlineQueryResultAnimation",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
         self.inner.id = id.as_ref().to_string();
+        self.missing.retain(|f| *f != "id");
         self
     }
 
     pub fn animation<T: AsRef<Animation>>(&mut self, animation: T) -> &mut Self {
         self.inner.animation = animation.as_ref().clone();
+        self.missing.retain(|f| *f != "animation");
         self
     }
 
     pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
         self.inner.title = title.as_ref().to_string();
+        self.missing.retain(|f| *f != "title");
         self
     }
 }
@@ -228,12 +301,13 @@ impl AsRef<InlineQueryResultAnimation> for RTDInlineQueryResultAnimationBuilder
 
 /// Represents a link to an article or web page
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inlineQueryResultArticle")]
 pub struct InlineQueryResultArticle {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Unique identifier of the query result
     id: String,
@@ -263,6 +337,30 @@ impl RObject for InlineQueryResultArticle {
     }
 }
 
+impl PartialEq for InlineQueryResultArticle {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.url == other.url
+            && self.hide_url == other.hide_url
+            && self.title == other.title
+            && self.description == other.description
+            && self.thumbnail == other.thumbnail
+    }
+}
+
+impl Eq for InlineQueryResultArticle {}
+
+impl std::hash::Hash for InlineQueryResultArticle {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.id, state);
+        std::hash::Hash::hash(&self.url, state);
+        std::hash::Hash::hash(&self.hide_url, state);
+        std::hash::Hash::hash(&self.title, state);
+        std::hash::Hash::hash(&self.description, state);
+        std::hash::Hash::hash(&self.thumbnail, state);
+    }
+}
+
 impl TDInlineQueryResult for InlineQueryResultArticle {}
 
 impl InlineQueryResultArticle {
@@ -270,10 +368,24 @@ impl InlineQueryResultArticle {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInlineQueryResultArticleBuilder {
-        let mut inner = InlineQueryResultArticle::default();
-        inner.td_name = "inlineQueryResultArticle".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInlineQueryResultArticleBuilder { inner }
+        RTDInlineQueryResultArticleBuilder {
+            inner: InlineQueryResultArticle::default(),
+            missing: vec!["id", "url", "title", "description"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInlineQueryResultArticleBuilder {
+        RTDInlineQueryResultArticleBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInlineQueryResultArticleBuilder {
+        RTDInlineQueryResultArticleBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn id(&self) -> &String {
@@ -299,25 +411,90 @@ impl InlineQueryResultArticle {
     pub fn thumbnail(&self) -> &Option<PhotoSize> {
         &self.thumbnail
     }
+
+    pub fn into_id(self) -> String {
+        self.id
+    }
+
+    pub fn into_url(self) -> String {
+        self.url
+    }
+
+    pub fn into_title(self) -> String {
+        self.title
+    }
+
+    pub fn into_description(self) -> String {
+        self.description
+    }
+
+    pub fn into_thumbnail(self) -> Option<PhotoSize> {
+        self.thumbnail
+    }
+
+    pub fn with_id<T: AsRef<str>>(mut self, id: T) -> Self {
+        self.id = id.as_ref().to_string();
+        self
+    }
+
+    pub fn with_url<T: AsRef<str>>(mut self, url: T) -> Self {
+        self.url = url.as_ref().to_string();
+        self
+    }
+
+    pub fn with_hide_url(mut self, hide_url: bool) -> Self {
+        self.hide_url = hide_url;
+        self
+    }
+
+    pub fn with_title<T: AsRef<str>>(mut self, title: T) -> Self {
+        self.title = title.as_ref().to_string();
+        self
+    }
+
+    pub fn with_description<T: AsRef<str>>(mut self, description: T) -> Self {
+        self.description = description.as_ref().to_string();
+        self
+    }
+
+    pub fn with_thumbnail<T: AsRef<PhotoSize>>(mut self, thumbnail: T) -> Self {
+        self.thumbnail = Some(thumbnail.as_ref().clone());
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInlineQueryResultArticleBuilder {
     inner: InlineQueryResultArticle,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInlineQueryResultArticleBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InlineQueryResultArticle {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InlineQueryResultArticle> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InlineQueryResultArticle",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
         self.inner.id = id.as_ref().to_string();
+        self.missing.retain(|f| *f != "id");
         self
     }
 
     pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
         self.inner.url = url.as_ref().to_string();
+        self.missing.retain(|f| *f != "url");
         self
     }
 
@@ -328,11 +505,13 @@ impl RTDInlineQueryResultArticleBuilder {
 
     pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
         self.inner.title = title.as_ref().to_string();
+        self.missing.retain(|f| *f != "title");
         self
     }
 
     pub fn description<T: AsRef<str>>(&mut self, description: T) -> &mut Self {
         self.inner.description = description.as_ref().to_string();
+        self.missing.retain(|f| *f != "description");
         self
     }
 
@@ -356,12 +535,13 @@ impl AsRef<InlineQueryResultArticle> for RTDInlineQueryResultArticleBuilder {
 
 /// Represents an audio file
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inlineQueryResultAudio")]
 pub struct InlineQueryResultAudio {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Unique identifier of the query result
     id: String,
@@ -383,6 +563,21 @@ impl RObject for InlineQueryResultAudio {
     }
 }
 
+impl PartialEq for InlineQueryResultAudio {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id && self.audio == other.audio
+    }
+}
+
+impl Eq for InlineQueryResultAudio {}
+
+impl std::hash::Hash for InlineQueryResultAudio {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.id, state);
+        std::hash::Hash::hash(&self.audio, state);
+    }
+}
+
 impl TDInlineQueryResult for InlineQueryResultAudio {}
 
 impl InlineQueryResultAudio {
@@ -390,10 +585,24 @@ impl InlineQueryResultAudio {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInlineQueryResultAudioBuilder {
-        let mut inner = InlineQueryResultAudio::default();
-        inner.td_name = "inlineQueryResultAudio".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInlineQueryResultAudioBuilder { inner }
+        RTDInlineQueryResultAudioBuilder {
+            inner: InlineQueryResultAudio::default(),
+            missing: vec!["id", "audio"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInlineQueryResultAudioBuilder {
+        RTDInlineQueryResultAudioBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInlineQueryResultAudioBuilder {
+        RTDInlineQueryResultAudioBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn id(&self) -> &String {
@@ -403,25 +612,58 @@ impl InlineQueryResultAudio {
     pub fn audio(&self) -> &Audio {
         &self.audio
     }
+
+    pub fn into_id(self) -> String {
+        self.id
+    }
+
+    pub fn into_audio(self) -> Audio {
+        self.audio
+    }
+
+    pub fn with_id<T: AsRef<str>>(mut self, id: T) -> Self {
+        self.id = id.as_ref().to_string();
+        self
+    }
+
+    pub fn with_audio<T: AsRef<Audio>>(mut self, audio: T) -> Self {
+        self.audio = audio.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInlineQueryResultAudioBuilder {
     inner: InlineQueryResultAudio,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInlineQueryResultAudioBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InlineQueryResultAudio {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InlineQueryResultAudio> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InlineQueryResultAudio",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
         self.inner.id = id.as_ref().to_string();
+        self.missing.retain(|f| *f != "id");
         self
     }
 
     pub fn audio<T: AsRef<Audio>>(&mut self, audio: T) -> &mut Self {
         self.inner.audio = audio.as_ref().clone();
+        self.missing.retain(|f| *f != "audio");
         self
     }
 }
@@ -440,12 +682,13 @@ impl AsRef<InlineQueryResultAudio> for RTDInlineQueryResultAudioBuilder {
 
 /// Represents a user contact
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inlineQueryResultContact")]
 pub struct InlineQueryResultContact {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Unique identifier of the query result
     id: String,
@@ -469,6 +712,22 @@ impl RObject for InlineQueryResultContact {
     }
 }
 
+impl PartialEq for InlineQueryResultContact {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id && self.contact == other.contact && self.thumbnail == other.thumbnail
+    }
+}
+
+impl Eq for InlineQueryResultContact {}
+
+impl std::hash::Hash for InlineQueryResultContact {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.id, state);
+        std::hash::Hash::hash(&self.contact, state);
+        std::hash::Hash::hash(&self.thumbnail, state);
+    }
+}
+
 impl TDInlineQueryResult for InlineQueryResultContact {}
 
 impl InlineQueryResultContact {
@@ -476,10 +735,24 @@ impl InlineQueryResultContact {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInlineQueryResultContactBuilder {
-        let mut inner = InlineQueryResultContact::default();
-        inner.td_name = "inlineQueryResultContact".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInlineQueryResultContactBuilder { inner }
+        RTDInlineQueryResultContactBuilder {
+            inner: InlineQueryResultContact::default(),
+            missing: vec!["id", "contact"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInlineQueryResultContactBuilder {
+        RTDInlineQueryResultContactBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInlineQueryResultContactBuilder {
+        RTDInlineQueryResultContactBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn id(&self) -> &String {
@@ -493,25 +766,67 @@ impl InlineQueryResultContact {
     pub fn thumbnail(&self) -> &Option<PhotoSize> {
         &self.thumbnail
     }
+
+    pub fn into_id(self) -> String {
+        self.id
+    }
+
+    pub fn into_contact(self) -> Contact {
+        self.contact
+    }
+
+    pub fn into_thumbnail(self) -> Option<PhotoSize> {
+        self.thumbnail
+    }
+
+    pub fn with_id<T: AsRef<str>>(mut self, id: T) -> Self {
+        self.id = id.as_ref().to_string();
+        self
+    }
+
+    pub fn with_contact<T: AsRef<Contact>>(mut self, contact: T) -> Self {
+        self.contact = contact.as_ref().clone();
+        self
+    }
+
+    pub fn with_thumbnail<T: AsRef<PhotoSize>>(mut self, thumbnail: T) -> Self {
+        self.thumbnail = Some(thumbnail.as_ref().clone());
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInlineQueryResultContactBuilder {
     inner: InlineQueryResultContact,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInlineQueryResultContactBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InlineQueryResultContact {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InlineQueryResultContact> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InlineQueryResultContact",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
         self.inner.id = id.as_ref().to_string();
+        self.missing.retain(|f| *f != "id");
         self
     }
 
     pub fn contact<T: AsRef<Contact>>(&mut self, contact: T) -> &mut Self {
         self.inner.contact = contact.as_ref().clone();
+        self.missing.retain(|f| *f != "contact");
         self
     }
 
@@ -535,12 +850,13 @@ impl AsRef<InlineQueryResultContact> for RTDInlineQueryResultContactBuilder {
 
 /// Represents a document
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inlineQueryResultDocument")]
 pub struct InlineQueryResultDocument {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Unique identifier of the query result
     id: String,
@@ -566,6 +882,26 @@ impl RObject for InlineQueryResultDocument {
     }
 }
 
+impl PartialEq for InlineQueryResultDocument {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.document == other.document
+            && self.title == other.title
+            && self.description == other.description
+    }
+}
+
+impl Eq for InlineQueryResultDocument {}
+
+impl std::hash::Hash for InlineQueryResultDocument {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.id, state);
+        std::hash::Hash::hash(&self.document, state);
+        std::hash::Hash::hash(&self.title, state);
+        std::hash::Hash::hash(&self.description, state);
+    }
+}
+
 impl TDInlineQueryResult for InlineQueryResultDocument {}
 
 impl InlineQueryResultDocument {
@@ -573,10 +909,24 @@ impl InlineQueryResultDocument {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInlineQueryResultDocumentBuilder {
-        let mut inner = InlineQueryResultDocument::default();
-        inner.td_name = "inlineQueryResultDocument".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInlineQueryResultDocumentBuilder { inner }
+        RTDInlineQueryResultDocumentBuilder {
+            inner: InlineQueryResultDocument::default(),
+            missing: vec!["id", "document", "title", "description"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInlineQueryResultDocumentBuilder {
+        RTDInlineQueryResultDocumentBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInlineQueryResultDocumentBuilder {
+        RTDInlineQueryResultDocumentBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn id(&self) -> &String {
@@ -594,35 +944,88 @@ impl InlineQueryResultDocument {
     pub fn description(&self) -> &String {
         &self.description
     }
+
+    pub fn into_id(self) -> String {
+        self.id
+    }
+
+    pub fn into_document(self) -> Document {
+        self.document
+    }
+
+    pub fn into_title(self) -> String {
+        self.title
+    }
+
+    pub fn into_description(self) -> String {
+        self.description
+    }
+
+    pub fn with_id<T: AsRef<str>>(mut self, id: T) -> Self {
+        self.id = id.as_ref().to_string();
+        self
+    }
+
+    pub fn with_document<T: AsRef<Document>>(mut self, document: T) -> Self {
+        self.document = document.as_ref().clone();
+        self
+    }
+
+    pub fn with_title<T: AsRef<str>>(mut self, title: T) -> Self {
+        self.title = title.as_ref().to_string();
+        self
+    }
+
+    pub fn with_description<T: AsRef<str>>(mut self, description: T) -> Self {
+        self.description = description.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInlineQueryResultDocumentBuilder {
     inner: InlineQueryResultDocument,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInlineQueryResultDocumentBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InlineQueryResultDocument {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InlineQueryResultDocument> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InlineQueryResultDocument",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
         self.inner.id = id.as_ref().to_string();
+        self.missing.retain(|f| *f != "id");
         self
     }
 
     pub fn document<T: AsRef<Document>>(&mut self, document: T) -> &mut Self {
         self.inner.document = document.as_ref().clone();
+        self.missing.retain(|f| *f != "document");
         self
     }
 
     pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
         self.inner.title = title.as_ref().to_string();
+        self.missing.retain(|f| *f != "title");
         self
     }
 
     pub fn description<T: AsRef<str>>(&mut self, description: T) -> &mut Self {
         self.inner.description = description.as_ref().to_string();
+        self.missing.retain(|f| *f != "description");
         self
     }
 }
@@ -641,12 +1044,13 @@ impl AsRef<InlineQueryResultDocument> for RTDInlineQueryResultDocumentBuilder {
 
 /// Represents information about a game
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inlineQueryResultGame")]
 pub struct InlineQueryResultGame {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Unique identifier of the query result
     id: String,
@@ -668,6 +1072,21 @@ impl RObject for InlineQueryResultGame {
     }
 }
 
+impl PartialEq for InlineQueryResultGame {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id && self.game == other.game
+    }
+}
+
+impl Eq for InlineQueryResultGame {}
+
+impl std::hash::Hash for InlineQueryResultGame {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.id, state);
+        std::hash::Hash::hash(&self.game, state);
+    }
+}
+
 impl TDInlineQueryResult for InlineQueryResultGame {}
 
 impl InlineQueryResultGame {
@@ -675,10 +1094,24 @@ impl InlineQueryResultGame {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInlineQueryResultGameBuilder {
-        let mut inner = InlineQueryResultGame::default();
-        inner.td_name = "inlineQueryResultGame".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInlineQueryResultGameBuilder { inner }
+        RTDInlineQueryResultGameBuilder {
+            inner: InlineQueryResultGame::default(),
+            missing: vec!["id", "game"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInlineQueryResultGameBuilder {
+        RTDInlineQueryResultGameBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInlineQueryResultGameBuilder {
+        RTDInlineQueryResultGameBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn id(&self) -> &String {
@@ -688,25 +1121,58 @@ impl InlineQueryResultGame {
     pub fn game(&self) -> &Game {
         &self.game
     }
+
+    pub fn into_id(self) -> String {
+        self.id
+    }
+
+    pub fn into_game(self) -> Game {
+        self.game
+    }
+
+    pub fn with_id<T: AsRef<str>>(mut self, id: T) -> Self {
+        self.id = id.as_ref().to_string();
+        self
+    }
+
+    pub fn with_game<T: AsRef<Game>>(mut self, game: T) -> Self {
+        self.game = game.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInlineQueryResultGameBuilder {
     inner: InlineQueryResultGame,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInlineQueryResultGameBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InlineQueryResultGame {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InlineQueryResultGame> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InlineQueryResultGame",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
         self.inner.id = id.as_ref().to_string();
+        self.missing.retain(|f| *f != "id");
         self
     }
 
     pub fn game<T: AsRef<Game>>(&mut self, game: T) -> &mut Self {
         self.inner.game = game.as_ref().clone();
+        self.missing.retain(|f| *f != "game");
         self
     }
 }
@@ -725,12 +1191,13 @@ impl AsRef<InlineQueryResultGame> for RTDInlineQueryResultGameBuilder {
 
 /// Represents a point on the map
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inlineQueryResultLocation")]
 pub struct InlineQueryResultLocation {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Unique identifier of the query result
     id: String,
@@ -756,6 +1223,15 @@ impl RObject for InlineQueryResultLocation {
     }
 }
 
+impl PartialEq for InlineQueryResultLocation {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.location == other.location
+            && self.title == other.title
+            && self.thumbnail == other.thumbnail
+    }
+}
+
 impl TDInlineQueryResult for InlineQueryResultLocation {}
 
 impl InlineQueryResultLocation {
@@ -763,10 +1239,24 @@ impl InlineQueryResultLocation {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInlineQueryResultLocationBuilder {
-        let mut inner = InlineQueryResultLocation::default();
-        inner.td_name = "inlineQueryResultLocation".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInlineQueryResultLocationBuilder { inner }
+        RTDInlineQueryResultLocationBuilder {
+            inner: InlineQueryResultLocation::default(),
+            missing: vec!["id", "location", "title"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInlineQueryResultLocationBuilder {
+        RTDInlineQueryResultLocationBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInlineQueryResultLocationBuilder {
+        RTDInlineQueryResultLocationBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn id(&self) -> &String {
@@ -784,30 +1274,82 @@ impl InlineQueryResultLocation {
     pub fn thumbnail(&self) -> &Option<PhotoSize> {
         &self.thumbnail
     }
+
+    pub fn into_id(self) -> String {
+        self.id
+    }
+
+    pub fn into_location(self) -> Location {
+        self.location
+    }
+
+    pub fn into_title(self) -> String {
+        self.title
+    }
+
+    pub fn into_thumbnail(self) -> Option<PhotoSize> {
+        self.thumbnail
+    }
+
+    pub fn with_id<T: AsRef<str>>(mut self, id: T) -> Self {
+        self.id = id.as_ref().to_string();
+        self
+    }
+
+    pub fn with_location<T: AsRef<Location>>(mut self, location: T) -> Self {
+        self.location = location.as_ref().clone();
+        self
+    }
+
+    pub fn with_title<T: AsRef<str>>(mut self, title: T) -> Self {
+        self.title = title.as_ref().to_string();
+        self
+    }
+
+    pub fn with_thumbnail<T: AsRef<PhotoSize>>(mut self, thumbnail: T) -> Self {
+        self.thumbnail = Some(thumbnail.as_ref().clone());
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInlineQueryResultLocationBuilder {
     inner: InlineQueryResultLocation,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInlineQueryResultLocationBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InlineQueryResultLocation {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InlineQueryResultLocation> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InlineQueryResultLocation",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
         self.inner.id = id.as_ref().to_string();
+        self.missing.retain(|f| *f != "id");
         self
     }
 
     pub fn location<T: AsRef<Location>>(&mut self, location: T) -> &mut Self {
         self.inner.location = location.as_ref().clone();
+        self.missing.retain(|f| *f != "location");
         self
     }
 
     pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
         self.inner.title = title.as_ref().to_string();
+        self.missing.retain(|f| *f != "title");
         self
     }
 
@@ -831,12 +1373,13 @@ impl AsRef<InlineQueryResultLocation> for RTDInlineQueryResultLocationBuilder {
 
 /// Represents a photo
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inlineQueryResultPhoto")]
 pub struct InlineQueryResultPhoto {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Unique identifier of the query result
     id: String,
@@ -862,6 +1405,26 @@ impl RObject for InlineQueryResultPhoto {
     }
 }
 
+impl PartialEq for InlineQueryResultPhoto {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.photo == other.photo
+            && self.title == other.title
+            && self.description == other.description
+    }
+}
+
+impl Eq for InlineQueryResultPhoto {}
+
+impl std::hash::Hash for InlineQueryResultPhoto {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.id, state);
+        std::hash::Hash::hash(&self.photo, state);
+        std::hash::Hash::hash(&self.title, state);
+        std::hash::Hash::hash(&self.description, state);
+    }
+}
+
 impl TDInlineQueryResult for InlineQueryResultPhoto {}
 
 impl InlineQueryResultPhoto {
@@ -869,10 +1432,24 @@ impl InlineQueryResultPhoto {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInlineQueryResultPhotoBuilder {
-        let mut inner = InlineQueryResultPhoto::default();
-        inner.td_name = "inlineQueryResultPhoto".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInlineQueryResultPhotoBuilder { inner }
+        RTDInlineQueryResultPhotoBuilder {
+            inner: InlineQueryResultPhoto::default(),
+            missing: vec!["id", "photo", "description"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInlineQueryResultPhotoBuilder {
+        RTDInlineQueryResultPhotoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInlineQueryResultPhotoBuilder {
+        RTDInlineQueryResultPhotoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn id(&self) -> &String {
@@ -890,25 +1467,76 @@ impl InlineQueryResultPhoto {
     pub fn description(&self) -> &String {
         &self.description
     }
+
+    pub fn into_id(self) -> String {
+        self.id
+    }
+
+    pub fn into_photo(self) -> Photo {
+        self.photo
+    }
+
+    pub fn into_title(self) -> String {
+        self.title
+    }
+
+    pub fn into_description(self) -> String {
+        self.description
+    }
+
+    pub fn with_id<T: AsRef<str>>(mut self, id: T) -> Self {
+        self.id = id.as_ref().to_string();
+        self
+    }
+
+    pub fn with_photo<T: AsRef<Photo>>(mut self, photo: T) -> Self {
+        self.photo = photo.as_ref().clone();
+        self
+    }
+
+    pub fn with_title<T: AsRef<str>>(mut self, title: T) -> Self {
+        self.title = title.as_ref().to_string();
+        self
+    }
+
+    pub fn with_description<T: AsRef<str>>(mut self, description: T) -> Self {
+        self.description = description.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInlineQueryResultPhotoBuilder {
     inner: InlineQueryResultPhoto,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInlineQueryResultPhotoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InlineQueryResultPhoto {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InlineQueryResultPhoto> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InlineQueryResultPhoto",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
         self.inner.id = id.as_ref().to_string();
+        self.missing.retain(|f| *f != "id");
         self
     }
 
     pub fn photo<T: AsRef<Photo>>(&mut self, photo: T) -> &mut Self {
         self.inner.photo = photo.as_ref().clone();
+        self.missing.retain(|f| *f != "photo");
         self
     }
 
@@ -919,6 +1547,7 @@ impl RTDInlineQueryResultPhotoBuilder {
 
     pub fn description<T: AsRef<str>>(&mut self, description: T) -> &mut Self {
         self.inner.description = description.as_ref().to_string();
+        self.missing.retain(|f| *f != "description");
         self
     }
 }
@@ -937,12 +1566,13 @@ impl AsRef<InlineQueryResultPhoto> for RTDInlineQueryResultPhotoBuilder {
 
 /// Represents a sticker
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inlineQueryResultSticker")]
 pub struct InlineQueryResultSticker {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Unique identifier of the query result
     id: String,
@@ -964,6 +1594,12 @@ impl RObject for InlineQueryResultSticker {
     }
 }
 
+impl PartialEq for InlineQueryResultSticker {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id && self.sticker == other.sticker
+    }
+}
+
 impl TDInlineQueryResult for InlineQueryResultSticker {}
 
 impl InlineQueryResultSticker {
@@ -971,10 +1607,24 @@ impl InlineQueryResultSticker {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInlineQueryResultStickerBuilder {
-        let mut inner = InlineQueryResultSticker::default();
-        inner.td_name = "inlineQueryResultSticker".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInlineQueryResultStickerBuilder { inner }
+        RTDInlineQueryResultStickerBuilder {
+            inner: InlineQueryResultSticker::default(),
+            missing: vec!["id", "sticker"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInlineQueryResultStickerBuilder {
+        RTDInlineQueryResultStickerBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInlineQueryResultStickerBuilder {
+        RTDInlineQueryResultStickerBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn id(&self) -> &String {
@@ -984,25 +1634,58 @@ impl InlineQueryResultSticker {
     pub fn sticker(&self) -> &Sticker {
         &self.sticker
     }
+
+    pub fn into_id(self) -> String {
+        self.id
+    }
+
+    pub fn into_sticker(self) -> Sticker {
+        self.sticker
+    }
+
+    pub fn with_id<T: AsRef<str>>(mut self, id: T) -> Self {
+        self.id = id.as_ref().to_string();
+        self
+    }
+
+    pub fn with_sticker<T: AsRef<Sticker>>(mut self, sticker: T) -> Self {
+        self.sticker = sticker.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInlineQueryResultStickerBuilder {
     inner: InlineQueryResultSticker,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInlineQueryResultStickerBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InlineQueryResultSticker {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InlineQueryResultSticker> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InlineQueryResultSticker",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
         self.inner.id = id.as_ref().to_string();
+        self.missing.retain(|f| *f != "id");
         self
     }
 
     pub fn sticker<T: AsRef<Sticker>>(&mut self, sticker: T) -> &mut Self {
         self.inner.sticker = sticker.as_ref().clone();
+        self.missing.retain(|f| *f != "sticker");
         self
     }
 }
@@ -1021,12 +1704,13 @@ impl AsRef<InlineQueryResultSticker> for RTDInlineQueryResultStickerBuilder {
 
 /// Represents information about a venue
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inlineQueryResultVenue")]
 pub struct InlineQueryResultVenue {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Unique identifier of the query result
     id: String,
@@ -1050,6 +1734,12 @@ impl RObject for InlineQueryResultVenue {
     }
 }
 
+impl PartialEq for InlineQueryResultVenue {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id && self.venue == other.venue && self.thumbnail == other.thumbnail
+    }
+}
+
 impl TDInlineQueryResult for InlineQueryResultVenue {}
 
 impl InlineQueryResultVenue {
@@ -1057,10 +1747,24 @@ impl InlineQueryResultVenue {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInlineQueryResultVenueBuilder {
-        let mut inner = InlineQueryResultVenue::default();
-        inner.td_name = "inlineQueryResultVenue".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInlineQueryResultVenueBuilder { inner }
+        RTDInlineQueryResultVenueBuilder {
+            inner: InlineQueryResultVenue::default(),
+            missing: vec!["id", "venue"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInlineQueryResultVenueBuilder {
+        RTDInlineQueryResultVenueBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInlineQueryResultVenueBuilder {
+        RTDInlineQueryResultVenueBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn id(&self) -> &String {
@@ -1074,25 +1778,67 @@ impl InlineQueryResultVenue {
     pub fn thumbnail(&self) -> &Option<PhotoSize> {
         &self.thumbnail
     }
+
+    pub fn into_id(self) -> String {
+        self.id
+    }
+
+    pub fn into_venue(self) -> Venue {
+        self.venue
+    }
+
+    pub fn into_thumbnail(self) -> Option<PhotoSize> {
+        self.thumbnail
+    }
+
+    pub fn with_id<T: AsRef<str>>(mut self, id: T) -> Self {
+        self.id = id.as_ref().to_string();
+        self
+    }
+
+    pub fn with_venue<T: AsRef<Venue>>(mut self, venue: T) -> Self {
+        self.venue = venue.as_ref().clone();
+        self
+    }
+
+    pub fn with_thumbnail<T: AsRef<PhotoSize>>(mut self, thumbnail: T) -> Self {
+        self.thumbnail = Some(thumbnail.as_ref().clone());
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInlineQueryResultVenueBuilder {
     inner: InlineQueryResultVenue,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInlineQueryResultVenueBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InlineQueryResultVenue {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InlineQueryResultVenue> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InlineQueryResultVenue",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
         self.inner.id = id.as_ref().to_string();
+        self.missing.retain(|f| *f != "id");
         self
     }
 
     pub fn venue<T: AsRef<Venue>>(&mut self, venue: T) -> &mut Self {
         self.inner.venue = venue.as_ref().clone();
+        self.missing.retain(|f| *f != "venue");
         self
     }
 
@@ -1116,12 +1862,13 @@ impl AsRef<InlineQueryResultVenue> for RTDInlineQueryResultVenueBuilder {
 
 /// Represents a video
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inlineQueryResultVideo")]
 pub struct InlineQueryResultVideo {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Unique identifier of the query result
     id: String,
@@ -1147,6 +1894,26 @@ impl RObject for InlineQueryResultVideo {
     }
 }
 
+impl PartialEq for InlineQueryResultVideo {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.video == other.video
+            && self.title == other.title
+            && self.description == other.description
+    }
+}
+
+impl Eq for InlineQueryResultVideo {}
+
+impl std::hash::Hash for InlineQueryResultVideo {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.id, state);
+        std::hash::Hash::hash(&self.video, state);
+        std::hash::Hash::hash(&self.title, state);
+        std::hash::Hash::hash(&self.description, state);
+    }
+}
+
 impl TDInlineQueryResult for InlineQueryResultVideo {}
 
 impl InlineQueryResultVideo {
@@ -1154,10 +1921,24 @@ impl InlineQueryResultVideo {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInlineQueryResultVideoBuilder {
-        let mut inner = InlineQueryResultVideo::default();
-        inner.td_name = "inlineQueryResultVideo".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInlineQueryResultVideoBuilder { inner }
+        RTDInlineQueryResultVideoBuilder {
+            inner: InlineQueryResultVideo::default(),
+            missing: vec!["id", "video", "title", "description"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInlineQueryResultVideoBuilder {
+        RTDInlineQueryResultVideoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInlineQueryResultVideoBuilder {
+        RTDInlineQueryResultVideoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn id(&self) -> &String {
@@ -1175,35 +1956,88 @@ impl InlineQueryResultVideo {
     pub fn description(&self) -> &String {
         &self.description
     }
+
+    pub fn into_id(self) -> String {
+        self.id
+    }
+
+    pub fn into_video(self) -> Video {
+        self.video
+    }
+
+    pub fn into_title(self) -> String {
+        self.title
+    }
+
+    pub fn into_description(self) -> String {
+        self.description
+    }
+
+    pub fn with_id<T: AsRef<str>>(mut self, id: T) -> Self {
+        self.id = id.as_ref().to_string();
+        self
+    }
+
+    pub fn with_video<T: AsRef<Video>>(mut self, video: T) -> Self {
+        self.video = video.as_ref().clone();
+        self
+    }
+
+    pub fn with_title<T: AsRef<str>>(mut self, title: T) -> Self {
+        self.title = title.as_ref().to_string();
+        self
+    }
+
+    pub fn with_description<T: AsRef<str>>(mut self, description: T) -> Self {
+        self.description = description.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInlineQueryResultVideoBuilder {
     inner: InlineQueryResultVideo,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInlineQueryResultVideoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InlineQueryResultVideo {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InlineQueryResultVideo> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InlineQueryResultVideo",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
         self.inner.id = id.as_ref().to_string();
+        self.missing.retain(|f| *f != "id");
         self
     }
 
     pub fn video<T: AsRef<Video>>(&mut self, video: T) -> &mut Self {
         self.inner.video = video.as_ref().clone();
+        self.missing.retain(|f| *f != "video");
         self
     }
 
     pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
         self.inner.title = title.as_ref().to_string();
+        self.missing.retain(|f| *f != "title");
         self
     }
 
     pub fn description<T: AsRef<str>>(&mut self, description: T) -> &mut Self {
         self.inner.description = description.as_ref().to_string();
+        self.missing.retain(|f| *f != "description");
         self
     }
 }
@@ -1222,12 +2056,13 @@ impl AsRef<InlineQueryResultVideo> for RTDInlineQueryResultVideoBuilder {
 
 /// Represents a voice note
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inlineQueryResultVoiceNote")]
 pub struct InlineQueryResultVoiceNote {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Unique identifier of the query result
     id: String,
@@ -1251,6 +2086,22 @@ impl RObject for InlineQueryResultVoiceNote {
     }
 }
 
+impl PartialEq for InlineQueryResultVoiceNote {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id && self.voice_note == other.voice_note && self.title == other.title
+    }
+}
+
+impl Eq for InlineQueryResultVoiceNote {}
+
+impl std::hash::Hash for InlineQueryResultVoiceNote {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.id, state);
+        std::hash::Hash::hash(&self.voice_note, state);
+        std::hash::Hash::hash(&self.title, state);
+    }
+}
+
 impl TDInlineQueryResult for InlineQueryResultVoiceNote {}
 
 impl InlineQueryResultVoiceNote {
@@ -1258,10 +2109,24 @@ impl InlineQueryResultVoiceNote {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInlineQueryResultVoiceNoteBuilder {
-        let mut inner = InlineQueryResultVoiceNote::default();
-        inner.td_name = "inlineQueryResultVoiceNote".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInlineQueryResultVoiceNoteBuilder { inner }
+        RTDInlineQueryResultVoiceNoteBuilder {
+            inner: InlineQueryResultVoiceNote::default(),
+            missing: vec!["id", "voice_note", "title"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInlineQueryResultVoiceNoteBuilder {
+        RTDInlineQueryResultVoiceNoteBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInlineQueryResultVoiceNoteBuilder {
+        RTDInlineQueryResultVoiceNoteBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn id(&self) -> &String {
@@ -1275,30 +2140,73 @@ impl InlineQueryResultVoiceNote {
     pub fn title(&self) -> &String {
         &self.title
     }
+
+    pub fn into_id(self) -> String {
+        self.id
+    }
+
+    pub fn into_voice_note(self) -> VoiceNote {
+        self.voice_note
+    }
+
+    pub fn into_title(self) -> String {
+        self.title
+    }
+
+    pub fn with_id<T: AsRef<str>>(mut self, id: T) -> Self {
+        self.id = id.as_ref().to_string();
+        self
+    }
+
+    pub fn with_voice_note<T: AsRef<VoiceNote>>(mut self, voice_note: T) -> Self {
+        self.voice_note = voice_note.as_ref().clone();
+        self
+    }
+
+    pub fn with_title<T: AsRef<str>>(mut self, title: T) -> Self {
+        self.title = title.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInlineQueryResultVoiceNoteBuilder {
     inner: InlineQueryResultVoiceNote,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInlineQueryResultVoiceNoteBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InlineQueryResultVoiceNote {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InlineQueryResultVoiceNote> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InlineQueryResultVoiceNote",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
         self.inner.id = id.as_ref().to_string();
+        self.missing.retain(|f| *f != "id");
         self
     }
 
     pub fn voice_note<T: AsRef<VoiceNote>>(&mut self, voice_note: T) -> &mut Self {
         self.inner.voice_note = voice_note.as_ref().clone();
+        self.missing.retain(|f| *f != "voice_note");
         self
     }
 
     pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
         self.inner.title = title.as_ref().to_string();
+        self.missing.retain(|f| *f != "title");
         self
     }
 }
@@ -1314,3 +2222,342 @@ impl AsRef<InlineQueryResultVoiceNote> for RTDInlineQueryResultVoiceNoteBuilder
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(InlineQueryResult::Animation(
+            InlineQueryResultAnimation::builder()
+                .id("text")
+                .animation(
+                    Animation::builder()
+                        .duration(i32::MAX)
+                        .width(i32::MAX)
+                        .height(i32::MAX)
+                        .file_name("text")
+                        .mime_type("text")
+                        .animation(
+                            File::builder()
+                                .id(i32::MAX)
+                                .size(i32::MAX)
+                                .expected_size(i32::MAX)
+                                .local(
+                                    LocalFile::builder()
+                                        .path("text")
+                                        .can_be_downloaded(true)
+                                        .can_be_deleted(true)
+                                        .is_downloading_active(true)
+                                        .is_downloading_completed(true)
+                                        .download_offset(i32::MAX)
+                                        .downloaded_prefix_size(i32::MAX)
+                                        .downloaded_size(i32::MAX)
+                                        .build(),
+                                )
+                                .remote(
+                                    RemoteFile::builder()
+                                        .id("text")
+                                        .unique_id("text")
+                                        .is_uploading_active(true)
+                                        .is_uploading_completed(true)
+                                        .uploaded_size(i32::MAX)
+                                        .build(),
+                                )
+                                .build(),
+                        )
+                        .build(),
+                )
+                .title("text")
+                .build(),
+        ));
+        assert_round_trip(InlineQueryResult::Article(
+            InlineQueryResultArticle::builder()
+                .id("text")
+                .url("text")
+                .hide_url(true)
+                .title("text")
+                .description("text")
+                .build(),
+        ));
+        assert_round_trip(InlineQueryResult::Audio(
+            InlineQueryResultAudio::builder()
+                .id("text")
+                .audio(
+                    Audio::builder()
+                        .duration(i32::MAX)
+                        .title("text")
+                        .performer("text")
+                        .file_name("text")
+                        .mime_type("text")
+                        .audio(
+                            File::builder()
+                                .id(i32::MAX)
+                                .size(i32::MAX)
+                                .expected_size(i32::MAX)
+                                .local(
+                                    LocalFile::builder()
+                                        .path("text")
+                                        .can_be_downloaded(true)
+                                        .can_be_deleted(true)
+                                        .is_downloading_active(true)
+                                        .is_downloading_completed(true)
+                                        .download_offset(i32::MAX)
+                                        .downloaded_prefix_size(i32::MAX)
+                                        .downloaded_size(i32::MAX)
+                                        .build(),
+                                )
+                                .remote(
+                                    RemoteFile::builder()
+                                        .id("text")
+                                        .unique_id("text")
+                                        .is_uploading_active(true)
+                                        .is_uploading_completed(true)
+                                        .uploaded_size(i32::MAX)
+                                        .build(),
+                                )
+                                .build(),
+                        )
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(InlineQueryResult::Contact(
+            InlineQueryResultContact::builder()
+                .id("text")
+                .contact(
+                    Contact::builder()
+                        .phone_number("text")
+                        .first_name("text")
+                        .last_name("text")
+                        .vcard("text")
+                        .user_id(i32::MAX)
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(InlineQueryResult::Document(
+            InlineQueryResultDocument::builder()
+                .id("text")
+                .document(
+                    Document::builder()
+                        .file_name("text")
+                        .mime_type("text")
+                        .document(
+                            File::builder()
+                                .id(i32::MAX)
+                                .size(i32::MAX)
+                                .expected_size(i32::MAX)
+                                .local(
+                                    LocalFile::builder()
+                                        .path("text")
+                                        .can_be_downloaded(true)
+                                        .can_be_deleted(true)
+                                        .is_downloading_active(true)
+                                        .is_downloading_completed(true)
+                                        .download_offset(i32::MAX)
+                                        .downloaded_prefix_size(i32::MAX)
+                                        .downloaded_size(i32::MAX)
+                                        .build(),
+                                )
+                                .remote(
+                                    RemoteFile::builder()
+                                        .id("text")
+                                        .unique_id("text")
+                                        .is_uploading_active(true)
+                                        .is_uploading_completed(true)
+                                        .uploaded_size(i32::MAX)
+                                        .build(),
+                                )
+                                .build(),
+                        )
+                        .build(),
+                )
+                .title("text")
+                .description("text")
+                .build(),
+        ));
+        assert_round_trip(InlineQueryResult::Game(
+            InlineQueryResultGame::builder()
+                .id("text")
+                .game(
+                    Game::builder()
+                        .id(i64::MAX)
+                        .short_name("text")
+                        .title("text")
+                        .text(
+                            FormattedText::builder()
+                                .text("text")
+                                .entities(vec![])
+                                .build(),
+                        )
+                        .description("text")
+                        .photo(Photo::builder().has_stickers(true).sizes(vec![]).build())
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(InlineQueryResult::Location(
+            InlineQueryResultLocation::builder()
+                .id("text")
+                .location(Location::builder().latitude(1.5).longitude(1.5).build())
+                .title("text")
+                .build(),
+        ));
+        assert_round_trip(InlineQueryResult::Photo(
+            InlineQueryResultPhoto::builder()
+                .id("text")
+                .photo(Photo::builder().has_stickers(true).sizes(vec![]).build())
+                .title("text")
+                .description("text")
+                .build(),
+        ));
+        assert_round_trip(InlineQueryResult::Sticker(
+            InlineQueryResultSticker::builder()
+                .id("text")
+                .sticker(
+                    Sticker::builder()
+                        .set_id(i64::MAX)
+                        .width(i32::MAX)
+                        .height(i32::MAX)
+                        .emoji("text")
+                        .is_animated(true)
+                        .is_mask(true)
+                        .sticker(
+                            File::builder()
+                                .id(i32::MAX)
+                                .size(i32::MAX)
+                                .expected_size(i32::MAX)
+                                .local(
+                                    LocalFile::builder()
+                                        .path("text")
+                                        .can_be_downloaded(true)
+                                        .can_be_deleted(true)
+                                        .is_downloading_active(true)
+                                        .is_downloading_completed(true)
+                                        .download_offset(i32::MAX)
+                                        .downloaded_prefix_size(i32::MAX)
+                                        .downloaded_size(i32::MAX)
+                                        .build(),
+                                )
+                                .remote(
+                                    RemoteFile::builder()
+                                        .id("text")
+                                        .unique_id("text")
+                                        .is_uploading_active(true)
+                                        .is_uploading_completed(true)
+                                        .uploaded_size(i32::MAX)
+                                        .build(),
+                                )
+                                .build(),
+                        )
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(InlineQueryResult::Venue(
+            InlineQueryResultVenue::builder()
+                .id("text")
+                .venue(
+                    Venue::builder()
+                        .location(Location::builder().latitude(1.5).longitude(1.5).build())
+                        .title("text")
+                        .address("text")
+                        .provider("text")
+                        .id("text")
+                        .type_("text")
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(InlineQueryResult::Video(
+            InlineQueryResultVideo::builder()
+                .id("text")
+                .video(
+                    Video::builder()
+                        .duration(i32::MAX)
+                        .width(i32::MAX)
+                        .height(i32::MAX)
+                        .file_name("text")
+                        .mime_type("text")
+                        .has_stickers(true)
+                        .supports_streaming(true)
+                        .video(
+                            File::builder()
+                                .id(i32::MAX)
+                                .size(i32::MAX)
+                                .expected_size(i32::MAX)
+                                .local(
+                                    LocalFile::builder()
+                                        .path("text")
+                                        .can_be_downloaded(true)
+                                        .can_be_deleted(true)
+                                        .is_downloading_active(true)
+                                        .is_downloading_completed(true)
+                                        .download_offset(i32::MAX)
+                                        .downloaded_prefix_size(i32::MAX)
+                                        .downloaded_size(i32::MAX)
+                                        .build(),
+                                )
+                                .remote(
+                                    RemoteFile::builder()
+                                        .id("text")
+                                        .unique_id("text")
+                                        .is_uploading_active(true)
+                                        .is_uploading_completed(true)
+                                        .uploaded_size(i32::MAX)
+                                        .build(),
+                                )
+                                .build(),
+                        )
+                        .build(),
+                )
+                .title("text")
+                .description("text")
+                .build(),
+        ));
+        assert_round_trip(InlineQueryResult::VoiceNote(
+            InlineQueryResultVoiceNote::builder()
+                .id("text")
+                .voice_note(
+                    VoiceNote::builder()
+                        .duration(i32::MAX)
+                        .waveform(b"\x00\x01\xfe\xff")
+                        .mime_type("text")
+                        .voice(
+                            File::builder()
+                                .id(i32::MAX)
+                                .size(i32::MAX)
+                                .expected_size(i32::MAX)
+                                .local(
+                                    LocalFile::builder()
+                                        .path("text")
+                                        .can_be_downloaded(true)
+                                        .can_be_deleted(true)
+                                        .is_downloading_active(true)
+                                        .is_downloading_completed(true)
+                                        .download_offset(i32::MAX)
+                                        .downloaded_prefix_size(i32::MAX)
+                                        .downloaded_size(i32::MAX)
+                                        .build(),
+                                )
+                                .remote(
+                                    RemoteFile::builder()
+                                        .id("text")
+                                        .unique_id("text")
+                                        .is_uploading_active(true)
+                                        .is_uploading_completed(true)
+                                        .uploaded_size(i32::MAX)
+                                        .build(),
+                                )
+                                .build(),
+                        )
+                        .build(),
+                )
+                .title("text")
+                .build(),
+        ));
+    }
+}
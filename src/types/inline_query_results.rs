@@ -1,18 +1,19 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Represents the results of the inline query. Use sendInlineQueryResultMessage to send the result of the query
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inlineQueryResults")]
 pub struct InlineQueryResults {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Unique identifier of the inline query
-    inline_query_id: isize,
+    #[serde(with = "super::_common::int64")]
+    inline_query_id: i64,
     /// The offset for the next request. If empty, there are no more results
     next_offset: String,
     /// Results of the query
@@ -37,18 +38,42 @@ impl RObject for InlineQueryResults {
     }
 }
 
+impl PartialEq for InlineQueryResults {
+    fn eq(&self, other: &Self) -> bool {
+        self.inline_query_id == other.inline_query_id
+            && self.next_offset == other.next_offset
+            && self.results == other.results
+            && self.switch_pm_text == other.switch_pm_text
+            && self.switch_pm_parameter == other.switch_pm_parameter
+    }
+}
+
 impl InlineQueryResults {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInlineQueryResultsBuilder {
-        let mut inner = InlineQueryResults::default();
-        inner.td_name = "inlineQueryResults".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInlineQueryResultsBuilder { inner }
-    }
-
-    pub fn inline_query_id(&self) -> isize {
+        RTDInlineQueryResultsBuilder {
+            inner: InlineQueryResults::default(),
+            missing: vec!["inline_query_id", "results", "switch_pm_parameter"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInlineQueryResultsBuilder {
+        RTDInlineQueryResultsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInlineQueryResultsBuilder {
+        RTDInlineQueryResultsBuilder {
+            inner: self,
+            missing: vec![],
+        }
+    }
+
+    pub fn inline_query_id(&self) -> i64 {
         self.inline_query_id
     }
 
@@ -67,20 +92,75 @@ impl InlineQueryResults {
     pub fn switch_pm_parameter(&self) -> &String {
         &self.switch_pm_parameter
     }
+
+    pub fn into_next_offset(self) -> String {
+        self.next_offset
+    }
+
+    pub fn into_results(self) -> Vec<InlineQueryResult> {
+        self.results
+    }
+
+    pub fn into_switch_pm_text(self) -> String {
+        self.switch_pm_text
+    }
+
+    pub fn into_switch_pm_parameter(self) -> String {
+        self.switch_pm_parameter
+    }
+
+    pub fn with_inline_query_id(mut self, inline_query_id: i64) -> Self {
+        self.inline_query_id = inline_query_id;
+        self
+    }
+
+    pub fn with_next_offset<T: AsRef<str>>(mut self, next_offset: T) -> Self {
+        self.next_offset = next_offset.as_ref().to_string();
+        self
+    }
+
+    pub fn with_results(mut self, results: Vec<InlineQueryResult>) -> Self {
+        self.results = results;
+        self
+    }
+
+    pub fn with_switch_pm_text<T: AsRef<str>>(mut self, switch_pm_text: T) -> Self {
+        self.switch_pm_text = switch_pm_text.as_ref().to_string();
+        self
+    }
+
+    pub fn with_switch_pm_parameter<T: AsRef<str>>(mut self, switch_pm_parameter: T) -> Self {
+        self.switch_pm_parameter = switch_pm_parameter.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInlineQueryResultsBuilder {
     inner: InlineQueryResults,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInlineQueryResultsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InlineQueryResults {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InlineQueryResults> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InlineQueryResults",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn inline_query_id(&mut self, inline_query_id: isize) -> &mut Self {
+    pub fn inline_query_id(&mut self, inline_query_id: i64) -> &mut Self {
         self.inner.inline_query_id = inline_query_id;
+        self.missing.retain(|f| *f != "inline_query_id");
         self
     }
 
@@ -91,6 +171,7 @@ impl RTDInlineQueryResultsBuilder {
 
     pub fn results(&mut self, results: Vec<InlineQueryResult>) -> &mut Self {
         self.inner.results = results;
+        self.missing.retain(|f| *f != "results");
         self
     }
 
@@ -101,6 +182,7 @@ impl RTDInlineQueryResultsBuilder {
 
     pub fn switch_pm_parameter<T: AsRef<str>>(&mut self, switch_pm_parameter: T) -> &mut Self {
         self.inner.switch_pm_parameter = switch_pm_parameter.as_ref().to_string();
+        self.missing.retain(|f| *f != "switch_pm_parameter");
         self
     }
 }
@@ -116,3 +198,22 @@ impl AsRef<InlineQueryResults> for RTDInlineQueryResultsBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            InlineQueryResults::builder()
+                .inline_query_id(i64::MAX)
+                .next_offset("text")
+                .results(vec![])
+                .switch_pm_text("text")
+                .switch_pm_parameter("text")
+                .build(),
+        );
+    }
+}
@@ -1,43 +1,40 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Contains information about background to set
 pub trait TDInputBackground: Debug + RObject {}
 
 /// Contains information about background to set
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum InputBackground {
-    #[doc(hidden)]
-    _Default(()),
     /// A background from a local file
+    #[serde(rename = "inputBackgroundLocal")]
     Local(InputBackgroundLocal),
     /// A background from the server
+    #[serde(rename = "inputBackgroundRemote")]
     Remote(InputBackgroundRemote),
 }
 
+/// Default value is default of [InputBackgroundRemote], builders still require the field to be set
 impl Default for InputBackground {
     fn default() -> Self {
-        InputBackground::_Default(())
+        InputBackground::Remote(InputBackgroundRemote::default())
     }
 }
 
-impl<'de> Deserialize<'de> for InputBackground {
-    fn deserialize<D>(deserializer: D) -> Result<InputBackground, D::Error>
+impl Serialize for InputBackground {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          InputBackground,
-          (inputBackgroundLocal, Local);
-          (inputBackgroundRemote, Remote);
-
-        )(deserializer)
+        match self {
+            InputBackground::Local(t) => t.serialize(serializer),
+            InputBackground::Remote(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -47,8 +44,6 @@ impl RObject for InputBackground {
         match self {
             InputBackground::Local(t) => t.td_name(),
             InputBackground::Remote(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -56,8 +51,6 @@ impl RObject for InputBackground {
         match self {
             InputBackground::Local(t) => t.extra(),
             InputBackground::Remote(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -69,10 +62,6 @@ impl InputBackground {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, InputBackground::_Default(_))
-    }
 }
 
 impl AsRef<InputBackground> for InputBackground {
@@ -83,12 +72,13 @@ impl AsRef<InputBackground> for InputBackground {
 
 /// A background from a local file
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputBackgroundLocal")]
 pub struct InputBackgroundLocal {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Background file to use. Only inputFileLocal and inputFileGenerated are supported. The file must be in JPEG format for wallpapers and in PNG format for patterns
     background: InputFile,
@@ -108,6 +98,20 @@ impl RObject for InputBackgroundLocal {
     }
 }
 
+impl PartialEq for InputBackgroundLocal {
+    fn eq(&self, other: &Self) -> bool {
+        self.background == other.background
+    }
+}
+
+impl Eq for InputBackgroundLocal {}
+
+impl std::hash::Hash for InputBackgroundLocal {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.background, state);
+    }
+}
+
 impl TDInputBackground for InputBackgroundLocal {}
 
 impl InputBackgroundLocal {
@@ -115,29 +119,66 @@ impl InputBackgroundLocal {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputBackgroundLocalBuilder {
-        let mut inner = InputBackgroundLocal::default();
-        inner.td_name = "inputBackgroundLocal".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputBackgroundLocalBuilder { inner }
+        RTDInputBackgroundLocalBuilder {
+            inner: InputBackgroundLocal::default(),
+            missing: vec!["background"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputBackgroundLocalBuilder {
+        RTDInputBackgroundLocalBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputBackgroundLocalBuilder {
+        RTDInputBackgroundLocalBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn background(&self) -> &InputFile {
         &self.background
     }
+
+    pub fn into_background(self) -> InputFile {
+        self.background
+    }
+
+    pub fn with_background<T: AsRef<InputFile>>(mut self, background: T) -> Self {
+        self.background = background.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputBackgroundLocalBuilder {
     inner: InputBackgroundLocal,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputBackgroundLocalBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputBackgroundLocal {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputBackgroundLocal> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputBackgroundLocal",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn background<T: AsRef<InputFile>>(&mut self, background: T) -> &mut Self {
         self.inner.background = background.as_ref().clone();
+        self.missing.retain(|f| *f != "background");
         self
     }
 }
@@ -156,15 +197,17 @@ impl AsRef<InputBackgroundLocal> for RTDInputBackgroundLocalBuilder {
 
 /// A background from the server
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputBackgroundRemote")]
 pub struct InputBackgroundRemote {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The background identifier
-    background_id: isize,
+    #[serde(with = "super::_common::int64")]
+    background_id: i64,
 }
 
 impl RObject for InputBackgroundRemote {
@@ -181,6 +224,20 @@ impl RObject for InputBackgroundRemote {
     }
 }
 
+impl PartialEq for InputBackgroundRemote {
+    fn eq(&self, other: &Self) -> bool {
+        self.background_id == other.background_id
+    }
+}
+
+impl Eq for InputBackgroundRemote {}
+
+impl std::hash::Hash for InputBackgroundRemote {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.background_id, state);
+    }
+}
+
 impl TDInputBackground for InputBackgroundRemote {}
 
 impl InputBackgroundRemote {
@@ -188,29 +245,62 @@ impl InputBackgroundRemote {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputBackgroundRemoteBuilder {
-        let mut inner = InputBackgroundRemote::default();
-        inner.td_name = "inputBackgroundRemote".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputBackgroundRemoteBuilder { inner }
+        RTDInputBackgroundRemoteBuilder {
+            inner: InputBackgroundRemote::default(),
+            missing: vec!["background_id"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputBackgroundRemoteBuilder {
+        RTDInputBackgroundRemoteBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputBackgroundRemoteBuilder {
+        RTDInputBackgroundRemoteBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn background_id(&self) -> isize {
+    pub fn background_id(&self) -> i64 {
         self.background_id
     }
+
+    pub fn with_background_id(mut self, background_id: i64) -> Self {
+        self.background_id = background_id;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputBackgroundRemoteBuilder {
     inner: InputBackgroundRemote,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputBackgroundRemoteBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputBackgroundRemote {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputBackgroundRemote> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputBackgroundRemote",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn background_id(&mut self, background_id: isize) -> &mut Self {
+    pub fn background_id(&mut self, background_id: i64) -> &mut Self {
         self.inner.background_id = background_id;
+        self.missing.retain(|f| *f != "background_id");
         self
     }
 }
@@ -226,3 +316,29 @@ impl AsRef<InputBackgroundRemote> for RTDInputBackgroundRemoteBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(InputBackground::Local(
+            InputBackgroundLocal::builder()
+                .background(InputFile::Generated(
+                    InputFileGenerated::builder()
+                        .original_path("text")
+                        .conversion("text")
+                        .expected_size(i32::MAX)
+                        .build(),
+                ))
+                .build(),
+        ));
+        assert_round_trip(InputBackground::Remote(
+            InputBackgroundRemote::builder()
+                .background_id(i64::MAX)
+                .build(),
+        ));
+    }
+}
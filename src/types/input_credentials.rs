@@ -1,49 +1,48 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Contains information about the payment method chosen by the user
 pub trait TDInputCredentials: Debug + RObject {}
 
 /// Contains information about the payment method chosen by the user
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum InputCredentials {
-    #[doc(hidden)]
-    _Default(()),
     /// Applies if a user enters new credentials using Android Pay
+    #[serde(rename = "inputCredentialsAndroidPay")]
     AndroidPay(InputCredentialsAndroidPay),
     /// Applies if a user enters new credentials using Apple Pay
+    #[serde(rename = "inputCredentialsApplePay")]
     ApplePay(InputCredentialsApplePay),
     /// Applies if a user enters new credentials on a payment provider website
+    #[serde(rename = "inputCredentialsNew")]
     New(InputCredentialsNew),
     /// Applies if a user chooses some previously saved payment credentials. To use their previously saved credentials, the user must have a valid temporary password
+    #[serde(rename = "inputCredentialsSaved")]
     Saved(InputCredentialsSaved),
 }
 
+/// Default value is default of [InputCredentialsAndroidPay], builders still require the field to be set
 impl Default for InputCredentials {
     fn default() -> Self {
-        InputCredentials::_Default(())
+        InputCredentials::AndroidPay(InputCredentialsAndroidPay::default())
     }
 }
 
-impl<'de> Deserialize<'de> for InputCredentials {
-    fn deserialize<D>(deserializer: D) -> Result<InputCredentials, D::Error>
+impl Serialize for InputCredentials {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          InputCredentials,
-          (inputCredentialsAndroidPay, AndroidPay);
-          (inputCredentialsApplePay, ApplePay);
-          (inputCredentialsNew, New);
-          (inputCredentialsSaved, Saved);
-
-        )(deserializer)
+        match self {
+            InputCredentials::AndroidPay(t) => t.serialize(serializer),
+            InputCredentials::ApplePay(t) => t.serialize(serializer),
+            InputCredentials::New(t) => t.serialize(serializer),
+            InputCredentials::Saved(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -55,8 +54,6 @@ impl RObject for InputCredentials {
             InputCredentials::ApplePay(t) => t.td_name(),
             InputCredentials::New(t) => t.td_name(),
             InputCredentials::Saved(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -66,8 +63,6 @@ impl RObject for InputCredentials {
             InputCredentials::ApplePay(t) => t.extra(),
             InputCredentials::New(t) => t.extra(),
             InputCredentials::Saved(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -79,10 +74,6 @@ impl InputCredentials {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, InputCredentials::_Default(_))
-    }
 }
 
 impl AsRef<InputCredentials> for InputCredentials {
@@ -93,12 +84,13 @@ impl AsRef<InputCredentials> for InputCredentials {
 
 /// Applies if a user enters new credentials using Android Pay
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputCredentialsAndroidPay")]
 pub struct InputCredentialsAndroidPay {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// JSON-encoded data with the credential identifier
     data: String,
@@ -118,6 +110,20 @@ impl RObject for InputCredentialsAndroidPay {
     }
 }
 
+impl PartialEq for InputCredentialsAndroidPay {
+    fn eq(&self, other: &Self) -> bool {
+        self.data == other.data
+    }
+}
+
+impl Eq for InputCredentialsAndroidPay {}
+
+impl std::hash::Hash for InputCredentialsAndroidPay {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.data, state);
+    }
+}
+
 impl TDInputCredentials for InputCredentialsAndroidPay {}
 
 impl InputCredentialsAndroidPay {
@@ -125,29 +131,66 @@ impl InputCredentialsAndroidPay {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputCredentialsAndroidPayBuilder {
-        let mut inner = InputCredentialsAndroidPay::default();
-        inner.td_name = "inputCredentialsAndroidPay".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputCredentialsAndroidPayBuilder { inner }
+        RTDInputCredentialsAndroidPayBuilder {
+            inner: InputCredentialsAndroidPay::default(),
+            missing: vec!["data"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputCredentialsAndroidPayBuilder {
+        RTDInputCredentialsAndroidPayBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputCredentialsAndroidPayBuilder {
+        RTDInputCredentialsAndroidPayBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn data(&self) -> &String {
         &self.data
     }
+
+    pub fn into_data(self) -> String {
+        self.data
+    }
+
+    pub fn with_data<T: AsRef<str>>(mut self, data: T) -> Self {
+        self.data = data.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputCredentialsAndroidPayBuilder {
     inner: InputCredentialsAndroidPay,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputCredentialsAndroidPayBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputCredentialsAndroidPay {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputCredentialsAndroidPay> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputCredentialsAndroidPay",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn data<T: AsRef<str>>(&mut self, data: T) -> &mut Self {
         self.inner.data = data.as_ref().to_string();
+        self.missing.retain(|f| *f != "data");
         self
     }
 }
@@ -166,12 +209,13 @@ impl AsRef<InputCredentialsAndroidPay> for RTDInputCredentialsAndroidPayBuilder
 
 /// Applies if a user enters new credentials using Apple Pay
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputCredentialsApplePay")]
 pub struct InputCredentialsApplePay {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// JSON-encoded data with the credential identifier
     data: String,
@@ -191,6 +235,20 @@ impl RObject for InputCredentialsApplePay {
     }
 }
 
+impl PartialEq for InputCredentialsApplePay {
+    fn eq(&self, other: &Self) -> bool {
+        self.data == other.data
+    }
+}
+
+impl Eq for InputCredentialsApplePay {}
+
+impl std::hash::Hash for InputCredentialsApplePay {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.data, state);
+    }
+}
+
 impl TDInputCredentials for InputCredentialsApplePay {}
 
 impl InputCredentialsApplePay {
@@ -198,29 +256,66 @@ impl InputCredentialsApplePay {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputCredentialsApplePayBuilder {
-        let mut inner = InputCredentialsApplePay::default();
-        inner.td_name = "inputCredentialsApplePay".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputCredentialsApplePayBuilder { inner }
+        RTDInputCredentialsApplePayBuilder {
+            inner: InputCredentialsApplePay::default(),
+            missing: vec!["data"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputCredentialsApplePayBuilder {
+        RTDInputCredentialsApplePayBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputCredentialsApplePayBuilder {
+        RTDInputCredentialsApplePayBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn data(&self) -> &String {
         &self.data
     }
+
+    pub fn into_data(self) -> String {
+        self.data
+    }
+
+    pub fn with_data<T: AsRef<str>>(mut self, data: T) -> Self {
+        self.data = data.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputCredentialsApplePayBuilder {
     inner: InputCredentialsApplePay,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputCredentialsApplePayBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputCredentialsApplePay {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputCredentialsApplePay> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputCredentialsApplePay",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn data<T: AsRef<str>>(&mut self, data: T) -> &mut Self {
         self.inner.data = data.as_ref().to_string();
+        self.missing.retain(|f| *f != "data");
         self
     }
 }
@@ -239,12 +334,13 @@ impl AsRef<InputCredentialsApplePay> for RTDInputCredentialsApplePayBuilder {
 
 /// Applies if a user enters new credentials on a payment provider website
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputCredentialsNew")]
 pub struct InputCredentialsNew {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Contains JSON-encoded data with a credential identifier from the payment provider
     data: String,
@@ -266,6 +362,21 @@ impl RObject for InputCredentialsNew {
     }
 }
 
+impl PartialEq for InputCredentialsNew {
+    fn eq(&self, other: &Self) -> bool {
+        self.data == other.data && self.allow_save == other.allow_save
+    }
+}
+
+impl Eq for InputCredentialsNew {}
+
+impl std::hash::Hash for InputCredentialsNew {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.data, state);
+        std::hash::Hash::hash(&self.allow_save, state);
+    }
+}
+
 impl TDInputCredentials for InputCredentialsNew {}
 
 impl InputCredentialsNew {
@@ -273,10 +384,24 @@ impl InputCredentialsNew {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputCredentialsNewBuilder {
-        let mut inner = InputCredentialsNew::default();
-        inner.td_name = "inputCredentialsNew".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputCredentialsNewBuilder { inner }
+        RTDInputCredentialsNewBuilder {
+            inner: InputCredentialsNew::default(),
+            missing: vec!["data"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputCredentialsNewBuilder {
+        RTDInputCredentialsNewBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputCredentialsNewBuilder {
+        RTDInputCredentialsNewBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn data(&self) -> &String {
@@ -286,20 +411,48 @@ impl InputCredentialsNew {
     pub fn allow_save(&self) -> bool {
         self.allow_save
     }
+
+    pub fn into_data(self) -> String {
+        self.data
+    }
+
+    pub fn with_data<T: AsRef<str>>(mut self, data: T) -> Self {
+        self.data = data.as_ref().to_string();
+        self
+    }
+
+    pub fn with_allow_save(mut self, allow_save: bool) -> Self {
+        self.allow_save = allow_save;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputCredentialsNewBuilder {
     inner: InputCredentialsNew,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputCredentialsNewBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputCredentialsNew {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputCredentialsNew> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputCredentialsNew",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn data<T: AsRef<str>>(&mut self, data: T) -> &mut Self {
         self.inner.data = data.as_ref().to_string();
+        self.missing.retain(|f| *f != "data");
         self
     }
 
@@ -323,12 +476,13 @@ impl AsRef<InputCredentialsNew> for RTDInputCredentialsNewBuilder {
 
 /// Applies if a user chooses some previously saved payment credentials. To use their previously saved credentials, the user must have a valid temporary password
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputCredentialsSaved")]
 pub struct InputCredentialsSaved {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Identifier of the saved credentials
     saved_credentials_id: String,
@@ -348,6 +502,20 @@ impl RObject for InputCredentialsSaved {
     }
 }
 
+impl PartialEq for InputCredentialsSaved {
+    fn eq(&self, other: &Self) -> bool {
+        self.saved_credentials_id == other.saved_credentials_id
+    }
+}
+
+impl Eq for InputCredentialsSaved {}
+
+impl std::hash::Hash for InputCredentialsSaved {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.saved_credentials_id, state);
+    }
+}
+
 impl TDInputCredentials for InputCredentialsSaved {}
 
 impl InputCredentialsSaved {
@@ -355,29 +523,66 @@ impl InputCredentialsSaved {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputCredentialsSavedBuilder {
-        let mut inner = InputCredentialsSaved::default();
-        inner.td_name = "inputCredentialsSaved".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputCredentialsSavedBuilder { inner }
+        RTDInputCredentialsSavedBuilder {
+            inner: InputCredentialsSaved::default(),
+            missing: vec!["saved_credentials_id"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputCredentialsSavedBuilder {
+        RTDInputCredentialsSavedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputCredentialsSavedBuilder {
+        RTDInputCredentialsSavedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn saved_credentials_id(&self) -> &String {
         &self.saved_credentials_id
     }
+
+    pub fn into_saved_credentials_id(self) -> String {
+        self.saved_credentials_id
+    }
+
+    pub fn with_saved_credentials_id<T: AsRef<str>>(mut self, saved_credentials_id: T) -> Self {
+        self.saved_credentials_id = saved_credentials_id.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputCredentialsSavedBuilder {
     inner: InputCredentialsSaved,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputCredentialsSavedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputCredentialsSaved {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputCredentialsSaved> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputCredentialsSaved",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn saved_credentials_id<T: AsRef<str>>(&mut self, saved_credentials_id: T) -> &mut Self {
         self.inner.saved_credentials_id = saved_credentials_id.as_ref().to_string();
+        self.missing.retain(|f| *f != "saved_credentials_id");
         self
     }
 }
@@ -393,3 +598,30 @@ impl AsRef<InputCredentialsSaved> for RTDInputCredentialsSavedBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(InputCredentials::AndroidPay(
+            InputCredentialsAndroidPay::builder().data("text").build(),
+        ));
+        assert_round_trip(InputCredentials::ApplePay(
+            InputCredentialsApplePay::builder().data("text").build(),
+        ));
+        assert_round_trip(InputCredentials::New(
+            InputCredentialsNew::builder()
+                .data("text")
+                .allow_save(true)
+                .build(),
+        ));
+        assert_round_trip(InputCredentials::Saved(
+            InputCredentialsSaved::builder()
+                .saved_credentials_id("text")
+                .build(),
+        ));
+    }
+}
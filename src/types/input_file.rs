@@ -1,49 +1,48 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Points to a file
 pub trait TDInputFile: Debug + RObject {}
 
 /// Points to a file
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum InputFile {
-    #[doc(hidden)]
-    _Default(()),
     /// A file generated by the client
+    #[serde(rename = "inputFileGenerated")]
     Generated(InputFileGenerated),
     /// A file defined by its unique ID
+    #[serde(rename = "inputFileId")]
     Id(InputFileId),
     /// A file defined by a local path
+    #[serde(rename = "inputFileLocal")]
     Local(InputFileLocal),
     /// A file defined by its remote ID. The remote ID is guaranteed to be usable only if the corresponding file is still accessible to the user and known to TDLib. For example, if the file is from a message, then the message must be not deleted and accessible to the user. If the file database is disabled, then the corresponding object with the file must be preloaded by the client
+    #[serde(rename = "inputFileRemote")]
     Remote(InputFileRemote),
 }
 
+/// Default value is default of [InputFileGenerated], builders still require the field to be set
 impl Default for InputFile {
     fn default() -> Self {
-        InputFile::_Default(())
+        InputFile::Generated(InputFileGenerated::default())
     }
 }
 
-impl<'de> Deserialize<'de> for InputFile {
-    fn deserialize<D>(deserializer: D) -> Result<InputFile, D::Error>
+impl Serialize for InputFile {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          InputFile,
-          (inputFileGenerated, Generated);
-          (inputFileId, Id);
-          (inputFileLocal, Local);
-          (inputFileRemote, Remote);
-
-        )(deserializer)
+        match self {
+            InputFile::Generated(t) => t.serialize(serializer),
+            InputFile::Id(t) => t.serialize(serializer),
+            InputFile::Local(t) => t.serialize(serializer),
+            InputFile::Remote(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -55,8 +54,6 @@ impl RObject for InputFile {
             InputFile::Id(t) => t.td_name(),
             InputFile::Local(t) => t.td_name(),
             InputFile::Remote(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -66,8 +63,6 @@ impl RObject for InputFile {
             InputFile::Id(t) => t.extra(),
             InputFile::Local(t) => t.extra(),
             InputFile::Remote(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -79,10 +74,6 @@ impl InputFile {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, InputFile::_Default(_))
-    }
 }
 
 impl AsRef<InputFile> for InputFile {
@@ -93,19 +84,20 @@ impl AsRef<InputFile> for InputFile {
 
 /// A file generated by the client
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputFileGenerated")]
 pub struct InputFileGenerated {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Local path to a file from which the file is generated; may be empty if there is no such file
     original_path: String,
     /// String specifying the conversion applied to the original file; should be persistent across application restarts. Conversions beginning with '#' are reserved for internal TDLib usage
     conversion: String,
     /// Expected size of the generated file; 0 if unknown
-    expected_size: i64,
+    expected_size: i32,
 }
 
 impl RObject for InputFileGenerated {
@@ -122,6 +114,24 @@ impl RObject for InputFileGenerated {
     }
 }
 
+impl PartialEq for InputFileGenerated {
+    fn eq(&self, other: &Self) -> bool {
+        self.original_path == other.original_path
+            && self.conversion == other.conversion
+            && self.expected_size == other.expected_size
+    }
+}
+
+impl Eq for InputFileGenerated {}
+
+impl std::hash::Hash for InputFileGenerated {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.original_path, state);
+        std::hash::Hash::hash(&self.conversion, state);
+        std::hash::Hash::hash(&self.expected_size, state);
+    }
+}
+
 impl TDInputFile for InputFileGenerated {}
 
 impl InputFileGenerated {
@@ -129,10 +139,24 @@ impl InputFileGenerated {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputFileGeneratedBuilder {
-        let mut inner = InputFileGenerated::default();
-        inner.td_name = "inputFileGenerated".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputFileGeneratedBuilder { inner }
+        RTDInputFileGeneratedBuilder {
+            inner: InputFileGenerated::default(),
+            missing: vec!["conversion"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputFileGeneratedBuilder {
+        RTDInputFileGeneratedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputFileGeneratedBuilder {
+        RTDInputFileGeneratedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn original_path(&self) -> &String {
@@ -143,20 +167,56 @@ impl InputFileGenerated {
         &self.conversion
     }
 
-    pub fn expected_size(&self) -> i64 {
+    pub fn expected_size(&self) -> i32 {
         self.expected_size
     }
+
+    pub fn into_original_path(self) -> String {
+        self.original_path
+    }
+
+    pub fn into_conversion(self) -> String {
+        self.conversion
+    }
+
+    pub fn with_original_path<T: AsRef<str>>(mut self, original_path: T) -> Self {
+        self.original_path = original_path.as_ref().to_string();
+        self
+    }
+
+    pub fn with_conversion<T: AsRef<str>>(mut self, conversion: T) -> Self {
+        self.conversion = conversion.as_ref().to_string();
+        self
+    }
+
+    pub fn with_expected_size(mut self, expected_size: i32) -> Self {
+        self.expected_size = expected_size;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputFileGeneratedBuilder {
     inner: InputFileGenerated,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputFileGeneratedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputFileGenerated {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputFileGenerated> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputFileGenerated",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn original_path<T: AsRef<str>>(&mut self, original_path: T) -> &mut Self {
         self.inner.original_path = original_path.as_ref().to_string();
@@ -165,10 +225,11 @@ impl RTDInputFileGeneratedBuilder {
 
     pub fn conversion<T: AsRef<str>>(&mut self, conversion: T) -> &mut Self {
         self.inner.conversion = conversion.as_ref().to_string();
+        self.missing.retain(|f| *f != "conversion");
         self
     }
 
-    pub fn expected_size(&mut self, expected_size: i64) -> &mut Self {
+    pub fn expected_size(&mut self, expected_size: i32) -> &mut Self {
         self.inner.expected_size = expected_size;
         self
     }
@@ -188,15 +249,16 @@ impl AsRef<InputFileGenerated> for RTDInputFileGeneratedBuilder {
 
 /// A file defined by its unique ID
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputFileId")]
 pub struct InputFileId {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Unique file identifier
-    id: i64,
+    id: FileId,
 }
 
 impl RObject for InputFileId {
@@ -213,6 +275,20 @@ impl RObject for InputFileId {
     }
 }
 
+impl PartialEq for InputFileId {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for InputFileId {}
+
+impl std::hash::Hash for InputFileId {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.id, state);
+    }
+}
+
 impl TDInputFile for InputFileId {}
 
 impl InputFileId {
@@ -220,29 +296,59 @@ impl InputFileId {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputFileIdBuilder {
-        let mut inner = InputFileId::default();
-        inner.td_name = "inputFileId".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputFileIdBuilder { inner }
+        RTDInputFileIdBuilder {
+            inner: InputFileId::default(),
+            missing: vec!["id"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputFileIdBuilder {
+        RTDInputFileIdBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputFileIdBuilder {
+        RTDInputFileIdBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn id(&self) -> i64 {
+    pub fn id(&self) -> FileId {
         self.id
     }
+
+    pub fn with_id<T: Into<FileId>>(mut self, id: T) -> Self {
+        self.id = id.into();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputFileIdBuilder {
     inner: InputFileId,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputFileIdBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputFileId {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputFileId> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("InputFileId", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn id(&mut self, id: i64) -> &mut Self {
-        self.inner.id = id;
+    pub fn id<T: Into<FileId>>(&mut self, id: T) -> &mut Self {
+        self.inner.id = id.into();
+        self.missing.retain(|f| *f != "id");
         self
     }
 }
@@ -261,12 +367,13 @@ impl AsRef<InputFileId> for RTDInputFileIdBuilder {
 
 /// A file defined by a local path
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputFileLocal")]
 pub struct InputFileLocal {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Local path to the file
     path: String,
@@ -286,6 +393,20 @@ impl RObject for InputFileLocal {
     }
 }
 
+impl PartialEq for InputFileLocal {
+    fn eq(&self, other: &Self) -> bool {
+        self.path == other.path
+    }
+}
+
+impl Eq for InputFileLocal {}
+
+impl std::hash::Hash for InputFileLocal {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.path, state);
+    }
+}
+
 impl TDInputFile for InputFileLocal {}
 
 impl InputFileLocal {
@@ -293,29 +414,66 @@ impl InputFileLocal {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputFileLocalBuilder {
-        let mut inner = InputFileLocal::default();
-        inner.td_name = "inputFileLocal".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputFileLocalBuilder { inner }
+        RTDInputFileLocalBuilder {
+            inner: InputFileLocal::default(),
+            missing: vec!["path"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputFileLocalBuilder {
+        RTDInputFileLocalBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputFileLocalBuilder {
+        RTDInputFileLocalBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn path(&self) -> &String {
         &self.path
     }
+
+    pub fn into_path(self) -> String {
+        self.path
+    }
+
+    pub fn with_path<T: AsRef<str>>(mut self, path: T) -> Self {
+        self.path = path.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputFileLocalBuilder {
     inner: InputFileLocal,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputFileLocalBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputFileLocal {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputFileLocal> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputFileLocal",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn path<T: AsRef<str>>(&mut self, path: T) -> &mut Self {
         self.inner.path = path.as_ref().to_string();
+        self.missing.retain(|f| *f != "path");
         self
     }
 }
@@ -334,12 +492,13 @@ impl AsRef<InputFileLocal> for RTDInputFileLocalBuilder {
 
 /// A file defined by its remote ID. The remote ID is guaranteed to be usable only if the corresponding file is still accessible to the user and known to TDLib. For example, if the file is from a message, then the message must be not deleted and accessible to the user. If the file database is disabled, then the corresponding object with the file must be preloaded by the client
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputFileRemote")]
 pub struct InputFileRemote {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Remote file identifier
     id: String,
@@ -359,6 +518,20 @@ impl RObject for InputFileRemote {
     }
 }
 
+impl PartialEq for InputFileRemote {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for InputFileRemote {}
+
+impl std::hash::Hash for InputFileRemote {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.id, state);
+    }
+}
+
 impl TDInputFile for InputFileRemote {}
 
 impl InputFileRemote {
@@ -366,29 +539,66 @@ impl InputFileRemote {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputFileRemoteBuilder {
-        let mut inner = InputFileRemote::default();
-        inner.td_name = "inputFileRemote".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputFileRemoteBuilder { inner }
+        RTDInputFileRemoteBuilder {
+            inner: InputFileRemote::default(),
+            missing: vec!["id"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputFileRemoteBuilder {
+        RTDInputFileRemoteBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputFileRemoteBuilder {
+        RTDInputFileRemoteBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn id(&self) -> &String {
         &self.id
     }
+
+    pub fn into_id(self) -> String {
+        self.id
+    }
+
+    pub fn with_id<T: AsRef<str>>(mut self, id: T) -> Self {
+        self.id = id.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputFileRemoteBuilder {
     inner: InputFileRemote,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputFileRemoteBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputFileRemote {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputFileRemote> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputFileRemote",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
         self.inner.id = id.as_ref().to_string();
+        self.missing.retain(|f| *f != "id");
         self
     }
 }
@@ -404,3 +614,27 @@ impl AsRef<InputFileRemote> for RTDInputFileRemoteBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(InputFile::Generated(
+            InputFileGenerated::builder()
+                .original_path("text")
+                .conversion("text")
+                .expected_size(i32::MAX)
+                .build(),
+        ));
+        assert_round_trip(InputFile::Id(InputFileId::builder().id(i32::MAX).build()));
+        assert_round_trip(InputFile::Local(
+            InputFileLocal::builder().path("text").build(),
+        ));
+        assert_round_trip(InputFile::Remote(
+            InputFileRemote::builder().id("text").build(),
+        ));
+    }
+}
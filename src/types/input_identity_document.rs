@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// An identity document to be saved to Telegram Passport
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputIdentityDocument")]
 pub struct InputIdentityDocument {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Document number; 1-24 characters
     number: String,
@@ -39,15 +39,60 @@ impl RObject for InputIdentityDocument {
     }
 }
 
+impl PartialEq for InputIdentityDocument {
+    fn eq(&self, other: &Self) -> bool {
+        self.number == other.number
+            && self.expiry_date == other.expiry_date
+            && self.front_side == other.front_side
+            && self.reverse_side == other.reverse_side
+            && self.selfie == other.selfie
+            && self.translation == other.translation
+    }
+}
+
+impl Eq for InputIdentityDocument {}
+
+impl std::hash::Hash for InputIdentityDocument {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.number, state);
+        std::hash::Hash::hash(&self.expiry_date, state);
+        std::hash::Hash::hash(&self.front_side, state);
+        std::hash::Hash::hash(&self.reverse_side, state);
+        std::hash::Hash::hash(&self.selfie, state);
+        std::hash::Hash::hash(&self.translation, state);
+    }
+}
+
 impl InputIdentityDocument {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputIdentityDocumentBuilder {
-        let mut inner = InputIdentityDocument::default();
-        inner.td_name = "inputIdentityDocument".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputIdentityDocumentBuilder { inner }
+        RTDInputIdentityDocumentBuilder {
+            inner: InputIdentityDocument::default(),
+            missing: vec![
+                "number",
+                "expiry_date",
+                "front_side",
+                "reverse_side",
+                "selfie",
+                "translation",
+            ],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputIdentityDocumentBuilder {
+        RTDInputIdentityDocumentBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputIdentityDocumentBuilder {
+        RTDInputIdentityDocumentBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn number(&self) -> &String {
@@ -73,45 +118,118 @@ impl InputIdentityDocument {
     pub fn translation(&self) -> &Vec<InputFile> {
         &self.translation
     }
+
+    pub fn into_number(self) -> String {
+        self.number
+    }
+
+    pub fn into_expiry_date(self) -> Date {
+        self.expiry_date
+    }
+
+    pub fn into_front_side(self) -> InputFile {
+        self.front_side
+    }
+
+    pub fn into_reverse_side(self) -> InputFile {
+        self.reverse_side
+    }
+
+    pub fn into_selfie(self) -> InputFile {
+        self.selfie
+    }
+
+    pub fn into_translation(self) -> Vec<InputFile> {
+        self.translation
+    }
+
+    pub fn with_number<T: AsRef<str>>(mut self, number: T) -> Self {
+        self.number = number.as_ref().to_string();
+        self
+    }
+
+    pub fn with_expiry_date<T: AsRef<Date>>(mut self, expiry_date: T) -> Self {
+        self.expiry_date = expiry_date.as_ref().clone();
+        self
+    }
+
+    pub fn with_front_side<T: AsRef<InputFile>>(mut self, front_side: T) -> Self {
+        self.front_side = front_side.as_ref().clone();
+        self
+    }
+
+    pub fn with_reverse_side<T: AsRef<InputFile>>(mut self, reverse_side: T) -> Self {
+        self.reverse_side = reverse_side.as_ref().clone();
+        self
+    }
+
+    pub fn with_selfie<T: AsRef<InputFile>>(mut self, selfie: T) -> Self {
+        self.selfie = selfie.as_ref().clone();
+        self
+    }
+
+    pub fn with_translation(mut self, translation: Vec<InputFile>) -> Self {
+        self.translation = translation;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputIdentityDocumentBuilder {
     inner: InputIdentityDocument,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputIdentityDocumentBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputIdentityDocument {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputIdentityDocument> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputIdentityDocument",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn number<T: AsRef<str>>(&mut self, number: T) -> &mut Self {
         self.inner.number = number.as_ref().to_string();
+        self.missing.retain(|f| *f != "number");
         self
     }
 
     pub fn expiry_date<T: AsRef<Date>>(&mut self, expiry_date: T) -> &mut Self {
         self.inner.expiry_date = expiry_date.as_ref().clone();
+        self.missing.retain(|f| *f != "expiry_date");
         self
     }
 
     pub fn front_side<T: AsRef<InputFile>>(&mut self, front_side: T) -> &mut Self {
         self.inner.front_side = front_side.as_ref().clone();
+        self.missing.retain(|f| *f != "front_side");
         self
     }
 
     pub fn reverse_side<T: AsRef<InputFile>>(&mut self, reverse_side: T) -> &mut Self {
         self.inner.reverse_side = reverse_side.as_ref().clone();
+        self.missing.retain(|f| *f != "reverse_side");
         self
     }
 
     pub fn selfie<T: AsRef<InputFile>>(&mut self, selfie: T) -> &mut Self {
         self.inner.selfie = selfie.as_ref().clone();
+        self.missing.retain(|f| *f != "selfie");
         self
     }
 
     pub fn translation(&mut self, translation: Vec<InputFile>) -> &mut Self {
         self.inner.translation = translation;
+        self.missing.retain(|f| *f != "translation");
         self
     }
 }
@@ -127,3 +245,47 @@ impl AsRef<InputIdentityDocument> for RTDInputIdentityDocumentBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            InputIdentityDocument::builder()
+                .number("text")
+                .expiry_date(
+                    Date::builder()
+                        .day(i32::MAX)
+                        .month(i32::MAX)
+                        .year(i32::MAX)
+                        .build(),
+                )
+                .front_side(InputFile::Generated(
+                    InputFileGenerated::builder()
+                        .original_path("text")
+                        .conversion("text")
+                        .expected_size(i32::MAX)
+                        .build(),
+                ))
+                .reverse_side(InputFile::Generated(
+                    InputFileGenerated::builder()
+                        .original_path("text")
+                        .conversion("text")
+                        .expected_size(i32::MAX)
+                        .build(),
+                ))
+                .selfie(InputFile::Generated(
+                    InputFileGenerated::builder()
+                        .original_path("text")
+                        .conversion("text")
+                        .expected_size(i32::MAX)
+                        .build(),
+                ))
+                .translation(vec![])
+                .build(),
+        );
+    }
+}
@@ -1,76 +1,84 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Represents a single result of an inline query; for bots only
 pub trait TDInputInlineQueryResult: Debug + RObject {}
 
 /// Represents a single result of an inline query; for bots only
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "@type")]
 pub enum InputInlineQueryResult {
-    #[doc(hidden)]
-    _Default(()),
     /// Represents a link to an animated GIF
+    #[serde(rename = "inputInlineQueryResultAnimatedGif")]
     AnimatedGif(InputInlineQueryResultAnimatedGif),
     /// Represents a link to an animated (i.e. without sound) H.264/MPEG-4 AVC video
+    #[serde(rename = "inputInlineQueryResultAnimatedMpeg4")]
     AnimatedMpeg4(InputInlineQueryResultAnimatedMpeg4),
     /// Represents a link to an article or web page
+    #[serde(rename = "inputInlineQueryResultArticle")]
     Article(InputInlineQueryResultArticle),
     /// Represents a link to an MP3 audio file
+    #[serde(rename = "inputInlineQueryResultAudio")]
     Audio(InputInlineQueryResultAudio),
     /// Represents a user contact
+    #[serde(rename = "inputInlineQueryResultContact")]
     Contact(InputInlineQueryResultContact),
     /// Represents a link to a file
+    #[serde(rename = "inputInlineQueryResultDocument")]
     Document(InputInlineQueryResultDocument),
     /// Represents a game
+    #[serde(rename = "inputInlineQueryResultGame")]
     Game(InputInlineQueryResultGame),
     /// Represents a point on the map
+    #[serde(rename = "inputInlineQueryResultLocation")]
     Location(InputInlineQueryResultLocation),
     /// Represents link to a JPEG image
+    #[serde(rename = "inputInlineQueryResultPhoto")]
     Photo(InputInlineQueryResultPhoto),
     /// Represents a link to a WEBP or TGS sticker
+    #[serde(rename = "inputInlineQueryResultSticker")]
     Sticker(InputInlineQueryResultSticker),
     /// Represents information about a venue
+    #[serde(rename = "inputInlineQueryResultVenue")]
     Venue(InputInlineQueryResultVenue),
     /// Represents a link to a page containing an embedded video player or a video file
+    #[serde(rename = "inputInlineQueryResultVideo")]
     Video(InputInlineQueryResultVideo),
     /// Represents a link to an opus-encoded audio file within an OGG container, single channel audio
+    #[serde(rename = "inputInlineQueryResultVoiceNote")]
     VoiceNote(InputInlineQueryResultVoiceNote),
 }
 
+/// Default value is default of [InputInlineQueryResultAnimatedGif], builders still require the field to be set
 impl Default for InputInlineQueryResult {
     fn default() -> Self {
-        InputInlineQueryResult::_Default(())
+        InputInlineQueryResult::AnimatedGif(InputInlineQueryResultAnimatedGif::default())
     }
 }
 
-impl<'de> Deserialize<'de> for InputInlineQueryResult {
-    fn deserialize<D>(deserializer: D) -> Result<InputInlineQueryResult, D::Error>
+impl Serialize for InputInlineQueryResult {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          InputInlineQueryResult,
-          (inputInlineQueryResultAnimatedGif, AnimatedGif);
-          (inputInlineQueryResultAnimatedMpeg4, AnimatedMpeg4);
-          (inputInlineQueryResultArticle, Article);
-          (inputInlineQueryResultAudio, Audio);
-          (inputInlineQueryResultContact, Contact);
-          (inputInlineQueryResultDocument, Document);
-          (inputInlineQueryResultGame, Game);
-          (inputInlineQueryResultLocation, Location);
-          (inputInlineQueryResultPhoto, Photo);
-          (inputInlineQueryResultSticker, Sticker);
-          (inputInlineQueryResultVenue, Venue);
-          (inputInlineQueryResultVideo, Video);
-          (inputInlineQueryResultVoiceNote, VoiceNote);
-
-        )(deserializer)
+        match self {
+            InputInlineQueryResult::AnimatedGif(t) => t.serialize(serializer),
+            InputInlineQueryResult::AnimatedMpeg4(t) => t.serialize(serializer),
+            InputInlineQueryResult::Article(t) => t.serialize(serializer),
+            InputInlineQueryResult::Audio(t) => t.serialize(serializer),
+            InputInlineQueryResult::Contact(t) => t.serialize(serializer),
+            InputInlineQueryResult::Document(t) => t.serialize(serializer),
+            InputInlineQueryResult::Game(t) => t.serialize(serializer),
+            InputInlineQueryResult::Location(t) => t.serialize(serializer),
+            InputInlineQueryResult::Photo(t) => t.serialize(serializer),
+            InputInlineQueryResult::Sticker(t) => t.serialize(serializer),
+            InputInlineQueryResult::Venue(t) => t.serialize(serializer),
+            InputInlineQueryResult::Video(t) => t.serialize(serializer),
+            InputInlineQueryResult::VoiceNote(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -91,8 +99,6 @@ impl RObject for InputInlineQueryResult {
             InputInlineQueryResult::Venue(t) => t.td_name(),
             InputInlineQueryResult::Video(t) => t.td_name(),
             InputInlineQueryResult::VoiceNote(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -111,8 +117,6 @@ impl RObject for InputInlineQueryResult {
             InputInlineQueryResult::Venue(t) => t.extra(),
             InputInlineQueryResult::Video(t) => t.extra(),
             InputInlineQueryResult::VoiceNote(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -124,10 +128,6 @@ impl InputInlineQueryResult {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, InputInlineQueryResult::_Default(_))
-    }
 }
 
 impl AsRef<InputInlineQueryResult> for InputInlineQueryResult {
@@ -138,12 +138,13 @@ impl AsRef<InputInlineQueryResult> for InputInlineQueryResult {
 
 /// Represents a link to an animated GIF
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputInlineQueryResultAnimatedGif")]
 pub struct InputInlineQueryResultAnimatedGif {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Unique identifier of the query result
     id: String,
@@ -154,11 +155,11 @@ pub struct InputInlineQueryResultAnimatedGif {
     /// The URL of the GIF-file (file size must not exceed 1MB)
     gif_url: String,
     /// Duration of the GIF, in seconds
-    gif_duration: i64,
+    gif_duration: i32,
     /// Width of the GIF
-    gif_width: i64,
+    gif_width: i32,
     /// Height of the GIF
-    gif_height: i64,
+    gif_height: i32,
     /// The message reply markup. Must be of type replyMarkupInlineKeyboard or null
     reply_markup: ReplyMarkup,
     /// The content of the message to be sent. Must be one of the following types: InputMessageText, InputMessageAnimation, InputMessageLocation, InputMessageVenue or InputMessageContact
@@ -179,6 +180,20 @@ impl RObject for InputInlineQueryResultAnimatedGif {
     }
 }
 
+impl PartialEq for InputInlineQueryResultAnimatedGif {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.title == other.title
+            && self.thumbnail_url == other.thumbnail_url
+            && self.gif_url == other.gif_url
+            && self.gif_duration == other.gif_duration
+            && self.gif_width == other.gif_width
+            && self.gif_height == other.gif_height
+            && self.reply_markup == other.reply_markup
+            && self.input_message_content == other.input_message_content
+    }
+}
+
 impl TDInputInlineQueryResult for InputInlineQueryResultAnimatedGif {}
 
 impl InputInlineQueryResultAnimatedGif {
@@ -186,10 +201,33 @@ impl InputInlineQueryResultAnimatedGif {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputInlineQueryResultAnimatedGifBuilder {
-        let mut inner = InputInlineQueryResultAnimatedGif::default();
-        inner.td_name = "inputInlineQueryResultAnimatedGif".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputInlineQueryResultAnimatedGifBuilder { inner }
+        RTDInputInlineQueryResultAnimatedGifBuilder {
+            inner: InputInlineQueryResultAnimatedGif::default(),
+            missing: vec![
+                "id",
+                "title",
+                "thumbnail_url",
+                "gif_url",
+                "gif_duration",
+                "gif_width",
+                "gif_height",
+                "input_message_content",
+            ],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputInlineQueryResultAnimatedGifBuilder {
+        RTDInputInlineQueryResultAnimatedGifBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputInlineQueryResultAnimatedGifBuilder {
+        RTDInputInlineQueryResultAnimatedGifBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn id(&self) -> &String {
@@ -208,15 +246,15 @@ impl InputInlineQueryResultAnimatedGif {
         &self.gif_url
     }
 
-    pub fn gif_duration(&self) -> i64 {
+    pub fn gif_duration(&self) -> i32 {
         self.gif_duration
     }
 
-    pub fn gif_width(&self) -> i64 {
+    pub fn gif_width(&self) -> i32 {
         self.gif_width
     }
 
-    pub fn gif_height(&self) -> i64 {
+    pub fn gif_height(&self) -> i32 {
         self.gif_height
     }
 
@@ -227,50 +265,142 @@ impl InputInlineQueryResultAnimatedGif {
     pub fn input_message_content(&self) -> &InputMessageContent {
         &self.input_message_content
     }
+
+    pub fn into_id(self) -> String {
+        self.id
+    }
+
+    pub fn into_title(self) -> String {
+        self.title
+    }
+
+    pub fn into_thumbnail_url(self) -> String {
+        self.thumbnail_url
+    }
+
+    pub fn into_gif_url(self) -> String {
+        self.gif_url
+    }
+
+    pub fn into_reply_markup(self) -> ReplyMarkup {
+        self.reply_markup
+    }
+
+    pub fn into_input_message_content(self) -> InputMessageContent {
+        self.input_message_content
+    }
+
+    pub fn with_id<T: AsRef<str>>(mut self, id: T) -> Self {
+        self.id = id.as_ref().to_string();
+        self
+    }
+
+    pub fn with_title<T: AsRef<str>>(mut self, title: T) -> Self {
+        self.title = title.as_ref().to_string();
+        self
+    }
+
+    pub fn with_thumbnail_url<T: AsRef<str>>(mut self, thumbnail_url: T) -> Self {
+        self.thumbnail_url = thumbnail_url.as_ref().to_string();
+        self
+    }
+
+    pub fn with_gif_url<T: AsRef<str>>(mut self, gif_url: T) -> Self {
+        self.gif_url = gif_url.as_ref().to_string();
+        self
+    }
+
+    pub fn with_gif_duration(mut self, gif_duration: i32) -> Self {
+        self.gif_duration = gif_duration;
+        self
+    }
+
+    pub fn with_gif_width(mut self, gif_width: i32) -> Self {
+        self.gif_width = gif_width;
+        self
+    }
+
+    pub fn with_gif_height(mut self, gif_height: i32) -> Self {
+        self.gif_height = gif_height;
+        self
+    }
+
+    pub fn with_reply_markup<T: AsRef<ReplyMarkup>>(mut self, reply_markup: T) -> Self {
+        self.reply_markup = reply_markup.as_ref().clone();
+        self
+    }
+
+    pub fn with_input_message_content<T: AsRef<InputMessageContent>>(
+        mut self,
+        input_message_content: T,
+    ) -> Self {
+        self.input_message_content = input_message_content.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputInlineQueryResultAnimatedGifBuilder {
     inner: InputInlineQueryResultAnimatedGif,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputInlineQueryResultAnimatedGifBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputInlineQueryResultAnimatedGif {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputInlineQueryResultAnimatedGif> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputInlineQueryResultAnimatedGif",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
         self.inner.id = id.as_ref().to_string();
+        self.missing.retain(|f| *f != "id");
         self
     }
 
     pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
         self.inner.title = title.as_ref().to_string();
+        self.missing.retain(|f| *f != "title");
         self
     }
 
     pub fn thumbnail_url<T: AsRef<str>>(&mut self, thumbnail_url: T) -> &mut Self {
         self.inner.thumbnail_url = thumbnail_url.as_ref().to_string();
+        self.missing.retain(|f| *f != "thumbnail_url");
         self
     }
 
     pub fn gif_url<T: AsRef<str>>(&mut self, gif_url: T) -> &mut Self {
         self.inner.gif_url = gif_url.as_ref().to_string();
+        self.missing.retain(|f| *f != "gif_url");
         self
     }
 
-    pub fn gif_duration(&mut self, gif_duration: i64) -> &mut Self {
+    pub fn gif_duration(&mut self, gif_duration: i32) -> &mut Self {
         self.inner.gif_duration = gif_duration;
+        self.missing.retain(|f| *f != "gif_duration");
         self
     }
 
-    pub fn gif_width(&mut self, gif_width: i64) -> &mut Self {
+    pub fn gif_width(&mut self, gif_width: i32) -> &mut Self {
         self.inner.gif_width = gif_width;
+        self.missing.retain(|f| *f != "gif_width");
         self
     }
 
-    pub fn gif_height(&mut self, gif_height: i64) -> &mut Self {
+    pub fn gif_height(&mut self, gif_height: i32) -> &mut Self {
         self.inner.gif_height = gif_height;
+        self.missing.retain(|f| *f != "gif_height");
         self
     }
 
@@ -284,6 +414,7 @@ impl RTDInputInlineQueryResultAnimatedGifBuilder {
         input_message_content: T,
     ) -> &mut Self {
         self.inner.input_message_content = input_message_content.as_ref().clone();
+        self.missing.retain(|f| *f != "input_message_content");
         self
     }
 }
@@ -302,12 +433,13 @@ impl AsRef<InputInlineQueryResultAnimatedGif> for RTDInputInlineQueryResultAnima
 
 /// Represents a link to an animated (i.e. without sound) H.264/MPEG-4 AVC video
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputInlineQueryResultAnimatedMpeg4")]
 pub struct InputInlineQueryResultAnimatedMpeg4 {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Unique identifier of the query result
     id: String,
@@ -318,11 +450,11 @@ pub struct InputInlineQueryResultAnimatedMpeg4 {
     /// The URL of the MPEG4-file (file size must not exceed 1MB)
     mpeg4_url: String,
     /// Duration of the video, in seconds
-    mpeg4_duration: i64,
+    mpeg4_duration: i32,
     /// Width of the video
-    mpeg4_width: i64,
+    mpeg4_width: i32,
     /// Height of the video
-    mpeg4_height: i64,
+    mpeg4_height: i32,
     /// The message reply markup. Must be of type replyMarkupInlineKeyboard or null
     reply_markup: ReplyMarkup,
     /// The content of the message to be sent. Must be one of the following types: InputMessageText, InputMessageAnimation, InputMessageLocation, InputMessageVenue or InputMessageContact
@@ -343,6 +475,20 @@ impl RObject for InputInlineQueryResultAnimatedMpeg4 {
     }
 }
 
+impl PartialEq for InputInlineQueryResultAnimatedMpeg4 {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.title == other.title
+            && self.thumbnail_url == other.thumbnail_url
+            && self.mpeg4_url == other.mpeg4_url
+            && self.mpeg4_duration == other.mpeg4_duration
+            && self.mpeg4_width == other.mpeg4_width
+            && self.mpeg4_height == other.mpeg4_height
+            && self.reply_markup == other.reply_markup
+            && self.input_message_content == other.input_message_content
+    }
+}
+
 impl TDInputInlineQueryResult for InputInlineQueryResultAnimatedMpeg4 {}
 
 impl InputInlineQueryResultAnimatedMpeg4 {
@@ -350,10 +496,33 @@ impl InputInlineQueryResultAnimatedMpeg4 {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputInlineQueryResultAnimatedMpeg4Builder {
-        let mut inner = InputInlineQueryResultAnimatedMpeg4::default();
-        inner.td_name = "inputInlineQueryResultAnimatedMpeg4".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputInlineQueryResultAnimatedMpeg4Builder { inner }
+        RTDInputInlineQueryResultAnimatedMpeg4Builder {
+            inner: InputInlineQueryResultAnimatedMpeg4::default(),
+            missing: vec![
+                "id",
+                "title",
+                "thumbnail_url",
+                "mpeg4_url",
+                "mpeg4_duration",
+                "mpeg4_width",
+                "mpeg4_height",
+                "input_message_content",
+            ],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputInlineQueryResultAnimatedMpeg4Builder {
+        RTDInputInlineQueryResultAnimatedMpeg4Builder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputInlineQueryResultAnimatedMpeg4Builder {
+        RTDInputInlineQueryResultAnimatedMpeg4Builder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn id(&self) -> &String {
@@ -372,15 +541,15 @@ impl InputInlineQueryResultAnimatedMpeg4 {
         &self.mpeg4_url
     }
 
-    pub fn mpeg4_duration(&self) -> i64 {
+    pub fn mpeg4_duration(&self) -> i32 {
         self.mpeg4_duration
     }
 
-    pub fn mpeg4_width(&self) -> i64 {
+    pub fn mpeg4_width(&self) -> i32 {
         self.mpeg4_width
     }
 
-    pub fn mpeg4_height(&self) -> i64 {
+    pub fn mpeg4_height(&self) -> i32 {
         self.mpeg4_height
     }
 
@@ -391,50 +560,142 @@ impl InputInlineQueryResultAnimatedMpeg4 {
     pub fn input_message_content(&self) -> &InputMessageContent {
         &self.input_message_content
     }
+
+    pub fn into_id(self) -> String {
+        self.id
+    }
+
+    pub fn into_title(self) -> String {
+        self.title
+    }
+
+    pub fn into_thumbnail_url(self) -> String {
+        self.thumbnail_url
+    }
+
+    pub fn into_mpeg4_url(self) -> String {
+        self.mpeg4_url
+    }
+
+    pub fn into_reply_markup(self) -> ReplyMarkup {
+        self.reply_markup
+    }
+
+    pub fn into_input_message_content(self) -> InputMessageContent {
+        self.input_message_content
+    }
+
+    pub fn with_id<T: AsRef<str>>(mut self, id: T) -> Self {
+        self.id = id.as_ref().to_string();
+        self
+    }
+
+    pub fn with_title<T: AsRef<str>>(mut self, title: T) -> Self {
+        self.title = title.as_ref().to_string();
+        self
+    }
+
+    pub fn with_thumbnail_url<T: AsRef<str>>(mut self, thumbnail_url: T) -> Self {
+        self.thumbnail_url = thumbnail_url.as_ref().to_string();
+        self
+    }
+
+    pub fn with_mpeg4_url<T: AsRef<str>>(mut self, mpeg4_url: T) -> Self {
+        self.mpeg4_url = mpeg4_url.as_ref().to_string();
+        self
+    }
+
+    pub fn with_mpeg4_duration(mut self, mpeg4_duration: i32) -> Self {
+        self.mpeg4_duration = mpeg4_duration;
+        self
+    }
+
+    pub fn with_mpeg4_width(mut self, mpeg4_width: i32) -> Self {
+        self.mpeg4_width = mpeg4_width;
+        self
+    }
+
+    pub fn with_mpeg4_height(mut self, mpeg4_height: i32) -> Self {
+        self.mpeg4_height = mpeg4_height;
+        self
+    }
+
+    pub fn with_reply_markup<T: AsRef<ReplyMarkup>>(mut self, reply_markup: T) -> Self {
+        self.reply_markup = reply_markup.as_ref().clone();
+        self
+    }
+
+    pub fn with_input_message_content<T: AsRef<InputMessageContent>>(
+        mut self,
+        input_message_content: T,
+    ) -> Self {
+        self.input_message_content = input_message_content.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputInlineQueryResultAnimatedMpeg4Builder {
     inner: InputInlineQueryResultAnimatedMpeg4,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputInlineQueryResultAnimatedMpeg4Builder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputInlineQueryResultAnimatedMpeg4 {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputInlineQueryResultAnimatedMpeg4> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputInlineQueryResultAnimatedMpeg4",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
         self.inner.id = id.as_ref().to_string();
+        self.missing.retain(|f| *f != "id");
         self
     }
 
     pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
         self.inner.title = title.as_ref().to_string();
+        self.missing.retain(|f| *f != "title");
         self
     }
 
     pub fn thumbnail_url<T: AsRef<str>>(&mut self, thumbnail_url: T) -> &mut Self {
         self.inner.thumbnail_url = thumbnail_url.as_ref().to_string();
+        self.missing.retain(|f| *f != "thumbnail_url");
         self
     }
 
     pub fn mpeg4_url<T: AsRef<str>>(&mut self, mpeg4_url: T) -> &mut Self {
         self.inner.mpeg4_url = mpeg4_url.as_ref().to_string();
+        self.missing.retain(|f| *f != "mpeg4_url");
         self
     }
 
-    pub fn mpeg4_duration(&mut self, mpeg4_duration: i64) -> &mut Self {
+    pub fn mpeg4_duration(&mut self, mpeg4_duration: i32) -> &mut Self {
         self.inner.mpeg4_duration = mpeg4_duration;
+        self.missing.retain(|f| *f != "mpeg4_duration");
         self
     }
 
-    pub fn mpeg4_width(&mut self, mpeg4_width: i64) -> &mut Self {
+    pub fn mpeg4_width(&mut self, mpeg4_width: i32) -> &mut Self {
         self.inner.mpeg4_width = mpeg4_width;
+        self.missing.retain(|f| *f != "mpeg4_width");
         self
     }
 
-    pub fn mpeg4_height(&mut self, mpeg4_height: i64) -> &mut Self {
+    pub fn mpeg4_height(&mut self, mpeg4_height: i32) -> &mut Self {
         self.inner.mpeg4_height = mpeg4_height;
+        self.missing.retain(|f| *f != "mpeg4_height");
         self
     }
 
@@ -448,6 +709,7 @@ impl RTDInputInlineQueryResultAnimatedMpeg4Builder {
         input_message_content: T,
     ) -> &mut Self {
         self.inner.input_message_content = input_message_content.as_ref().clone();
+        self.missing.retain(|f| *f != "input_message_content");
         self
     }
 }
@@ -466,12 +728,13 @@ impl AsRef<InputInlineQueryResultAnimatedMpeg4> for RTDInputInlineQueryResultAni
 
 /// Represents a link to an article or web page
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputInlineQueryResultArticle")]
 pub struct InputInlineQueryResultArticle {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Unique identifier of the query result
     id: String,
@@ -486,9 +749,9 @@ pub struct InputInlineQueryResultArticle {
     /// URL of the result thumbnail, if it exists
     thumbnail_url: String,
     /// Thumbnail width, if known
-    thumbnail_width: i64,
+    thumbnail_width: i32,
     /// Thumbnail height, if known
-    thumbnail_height: i64,
+    thumbnail_height: i32,
     /// The message reply markup. Must be of type replyMarkupInlineKeyboard or null
     reply_markup: ReplyMarkup,
     /// The content of the message to be sent. Must be one of the following types: InputMessageText, InputMessageLocation, InputMessageVenue or InputMessageContact
@@ -509,6 +772,21 @@ impl RObject for InputInlineQueryResultArticle {
     }
 }
 
+impl PartialEq for InputInlineQueryResultArticle {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.url == other.url
+            && self.hide_url == other.hide_url
+            && self.title == other.title
+            && self.description == other.description
+            && self.thumbnail_url == other.thumbnail_url
+            && self.thumbnail_width == other.thumbnail_width
+            && self.thumbnail_height == other.thumbnail_height
+            && self.reply_markup == other.reply_markup
+            && self.input_message_content == other.input_message_content
+    }
+}
+
 impl TDInputInlineQueryResult for InputInlineQueryResultArticle {}
 
 impl InputInlineQueryResultArticle {
@@ -516,10 +794,31 @@ impl InputInlineQueryResultArticle {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputInlineQueryResultArticleBuilder {
-        let mut inner = InputInlineQueryResultArticle::default();
-        inner.td_name = "inputInlineQueryResultArticle".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputInlineQueryResultArticleBuilder { inner }
+        RTDInputInlineQueryResultArticleBuilder {
+            inner: InputInlineQueryResultArticle::default(),
+            missing: vec![
+                "id",
+                "url",
+                "title",
+                "description",
+                "thumbnail_url",
+                "input_message_content",
+            ],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputInlineQueryResultArticleBuilder {
+        RTDInputInlineQueryResultArticleBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputInlineQueryResultArticleBuilder {
+        RTDInputInlineQueryResultArticleBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn id(&self) -> &String {
@@ -546,11 +845,11 @@ impl InputInlineQueryResultArticle {
         &self.thumbnail_url
     }
 
-    pub fn thumbnail_width(&self) -> i64 {
+    pub fn thumbnail_width(&self) -> i32 {
         self.thumbnail_width
     }
 
-    pub fn thumbnail_height(&self) -> i64 {
+    pub fn thumbnail_height(&self) -> i32 {
         self.thumbnail_height
     }
 
@@ -561,25 +860,121 @@ impl InputInlineQueryResultArticle {
     pub fn input_message_content(&self) -> &InputMessageContent {
         &self.input_message_content
     }
+
+    pub fn into_id(self) -> String {
+        self.id
+    }
+
+    pub fn into_url(self) -> String {
+        self.url
+    }
+
+    pub fn into_title(self) -> String {
+        self.title
+    }
+
+    pub fn into_description(self) -> String {
+        self.description
+    }
+
+    pub fn into_thumbnail_url(self) -> String {
+        self.thumbnail_url
+    }
+
+    pub fn into_reply_markup(self) -> ReplyMarkup {
+        self.reply_markup
+    }
+
+    pub fn into_input_message_content(self) -> InputMessageContent {
+        self.input_message_content
+    }
+
+    pub fn with_id<T: AsRef<str>>(mut self, id: T) -> Self {
+        self.id = id.as_ref().to_string();
+        self
+    }
+
+    pub fn with_url<T: AsRef<str>>(mut self, url: T) -> Self {
+        self.url = url.as_ref().to_string();
+        self
+    }
+
+    pub fn with_hide_url(mut self, hide_url: bool) -> Self {
+        self.hide_url = hide_url;
+        self
+    }
+
+    pub fn with_title<T: AsRef<str>>(mut self, title: T) -> Self {
+        self.title = title.as_ref().to_string();
+        self
+    }
+
+    pub fn with_description<T: AsRef<str>>(mut self, description: T) -> Self {
+        self.description = description.as_ref().to_string();
+        self
+    }
+
+    pub fn with_thumbnail_url<T: AsRef<str>>(mut self, thumbnail_url: T) -> Self {
+        self.thumbnail_url = thumbnail_url.as_ref().to_string();
+        self
+    }
+
+    pub fn with_thumbnail_width(mut self, thumbnail_width: i32) -> Self {
+        self.thumbnail_width = thumbnail_width;
+        self
+    }
+
+    pub fn with_thumbnail_height(mut self, thumbnail_height: i32) -> Self {
+        self.thumbnail_height = thumbnail_height;
+        self
+    }
+
+    pub fn with_reply_markup<T: AsRef<ReplyMarkup>>(mut self, reply_markup: T) -> Self {
+        self.reply_markup = reply_markup.as_ref().clone();
+        self
+    }
+
+    pub fn with_input_message_content<T: AsRef<InputMessageContent>>(
+        mut self,
+        input_message_content: T,
+    ) -> Self {
+        self.input_message_content = input_message_content.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputInlineQueryResultArticleBuilder {
     inner: InputInlineQueryResultArticle,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputInlineQueryResultArticleBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputInlineQueryResultArticle {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputInlineQueryResultArticle> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputInlineQueryResultArticle",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
         self.inner.id = id.as_ref().to_string();
+        self.missing.retain(|f| *f != "id");
         self
     }
 
     pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
         self.inner.url = url.as_ref().to_string();
+        self.missing.retain(|f| *f != "url");
         self
     }
 
@@ -590,25 +985,28 @@ impl RTDInputInlineQueryResultArticleBuilder {
 
     pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
         self.inner.title = title.as_ref().to_string();
+        self.missing.retain(|f| *f != "title");
         self
     }
 
     pub fn description<T: AsRef<str>>(&mut self, description: T) -> &mut Self {
         self.inner.description = description.as_ref().to_string();
+        self.missing.retain(|f| *f != "description");
         self
     }
 
     pub fn thumbnail_url<T: AsRef<str>>(&mut self, thumbnail_url: T) -> &mut Self {
         self.inner.thumbnail_url = thumbnail_url.as_ref().to_string();
+        self.missing.retain(|f| *f != "thumbnail_url");
         self
     }
 
-    pub fn thumbnail_width(&mut self, thumbnail_width: i64) -> &mut Self {
+    pub fn thumbnail_width(&mut self, thumbnail_width: i32) -> &mut Self {
         self.inner.thumbnail_width = thumbnail_width;
         self
     }
 
-    pub fn thumbnail_height(&mut self, thumbnail_height: i64) -> &mut Self {
+    pub fn thumbnail_height(&mut self, thumbnail_height: i32) -> &mut Self {
         self.inner.thumbnail_height = thumbnail_height;
         self
     }
@@ -623,6 +1021,7 @@ impl RTDInputInlineQueryResultArticleBuilder {
         input_message_content: T,
     ) -> &mut Self {
         self.inner.input_message_content = input_message_content.as_ref().clone();
+        self.missing.retain(|f| *f != "input_message_content");
         self
     }
 }
@@ -641,12 +1040,13 @@ impl AsRef<InputInlineQueryResultArticle> for RTDInputInlineQueryResultArticleBu
 
 /// Represents a link to an MP3 audio file
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputInlineQueryResultAudio")]
 pub struct InputInlineQueryResultAudio {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Unique identifier of the query result
     id: String,
@@ -657,7 +1057,7 @@ pub struct InputInlineQueryResultAudio {
     /// The URL of the audio file
     audio_url: String,
     /// Audio file duration, in seconds
-    audio_duration: i64,
+    audio_duration: i32,
     /// The message reply markup. Must be of type replyMarkupInlineKeyboard or null
     reply_markup: ReplyMarkup,
     /// The content of the message to be sent. Must be one of the following types: InputMessageText, InputMessageAudio, InputMessageLocation, InputMessageVenue or InputMessageContact
@@ -678,6 +1078,18 @@ impl RObject for InputInlineQueryResultAudio {
     }
 }
 
+impl PartialEq for InputInlineQueryResultAudio {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.title == other.title
+            && self.performer == other.performer
+            && self.audio_url == other.audio_url
+            && self.audio_duration == other.audio_duration
+            && self.reply_markup == other.reply_markup
+            && self.input_message_content == other.input_message_content
+    }
+}
+
 impl TDInputInlineQueryResult for InputInlineQueryResultAudio {}
 
 impl InputInlineQueryResultAudio {
@@ -685,10 +1097,31 @@ impl InputInlineQueryResultAudio {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputInlineQueryResultAudioBuilder {
-        let mut inner = InputInlineQueryResultAudio::default();
-        inner.td_name = "inputInlineQueryResultAudio".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputInlineQueryResultAudioBuilder { inner }
+        RTDInputInlineQueryResultAudioBuilder {
+            inner: InputInlineQueryResultAudio::default(),
+            missing: vec![
+                "id",
+                "title",
+                "performer",
+                "audio_url",
+                "audio_duration",
+                "input_message_content",
+            ],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputInlineQueryResultAudioBuilder {
+        RTDInputInlineQueryResultAudioBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputInlineQueryResultAudioBuilder {
+        RTDInputInlineQueryResultAudioBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn id(&self) -> &String {
@@ -707,7 +1140,7 @@ impl InputInlineQueryResultAudio {
         &self.audio_url
     }
 
-    pub fn audio_duration(&self) -> i64 {
+    pub fn audio_duration(&self) -> i32 {
         self.audio_duration
     }
 
@@ -718,40 +1151,120 @@ impl InputInlineQueryResultAudio {
     pub fn input_message_content(&self) -> &InputMessageContent {
         &self.input_message_content
     }
+
+    pub fn into_id(self) -> String {
+        self.id
+    }
+
+    pub fn into_title(self) -> String {
+        self.title
+    }
+
+    pub fn into_performer(self) -> String {
+        self.performer
+    }
+
+    pub fn into_audio_url(self) -> String {
+        self.audio_url
+    }
+
+    pub fn into_reply_markup(self) -> ReplyMarkup {
+        self.reply_markup
+    }
+
+    pub fn into_input_message_content(self) -> InputMessageContent {
+        self.input_message_content
+    }
+
+    pub fn with_id<T: AsRef<str>>(mut self, id: T) -> Self {
+        self.id = id.as_ref().to_string();
+        self
+    }
+
+    pub fn with_title<T: AsRef<str>>(mut self, title: T) -> Self {
+        self.title = title.as_ref().to_string();
+        self
+    }
+
+    pub fn with_performer<T: AsRef<str>>(mut self, performer: T) -> Self {
+        self.performer = performer.as_ref().to_string();
+        self
+    }
+
+    pub fn with_audio_url<T: AsRef<str>>(mut self, audio_url: T) -> Self {
+        self.audio_url = audio_url.as_ref().to_string();
+        self
+    }
+
+    pub fn with_audio_duration(mut self, audio_duration: i32) -> Self {
+        self.audio_duration = audio_duration;
+        self
+    }
+
+    pub fn with_reply_markup<T: AsRef<ReplyMarkup>>(mut self, reply_markup: T) -> Self {
+        self.reply_markup = reply_markup.as_ref().clone();
+        self
+    }
+
+    pub fn with_input_message_content<T: AsRef<InputMessageContent>>(
+        mut self,
+        input_message_content: T,
+    ) -> Self {
+        self.input_message_content = input_message_content.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputInlineQueryResultAudioBuilder {
     inner: InputInlineQueryResultAudio,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputInlineQueryResultAudioBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputInlineQueryResultAudio {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputInlineQueryResultAudio> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputInlineQueryResultAudio",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
         self.inner.id = id.as_ref().to_string();
+        self.missing.retain(|f| *f != "id");
         self
     }
 
     pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
         self.inner.title = title.as_ref().to_string();
+        self.missing.retain(|f| *f != "title");
         self
     }
 
     pub fn performer<T: AsRef<str>>(&mut self, performer: T) -> &mut Self {
         self.inner.performer = performer.as_ref().to_string();
+        self.missing.retain(|f| *f != "performer");
         self
     }
 
     pub fn audio_url<T: AsRef<str>>(&mut self, audio_url: T) -> &mut Self {
         self.inner.audio_url = audio_url.as_ref().to_string();
+        self.missing.retain(|f| *f != "audio_url");
         self
     }
 
-    pub fn audio_duration(&mut self, audio_duration: i64) -> &mut Self {
+    pub fn audio_duration(&mut self, audio_duration: i32) -> &mut Self {
         self.inner.audio_duration = audio_duration;
+        self.missing.retain(|f| *f != "audio_duration");
         self
     }
 
@@ -765,6 +1278,7 @@ impl RTDInputInlineQueryResultAudioBuilder {
         input_message_content: T,
     ) -> &mut Self {
         self.inner.input_message_content = input_message_content.as_ref().clone();
+        self.missing.retain(|f| *f != "input_message_content");
         self
     }
 }
@@ -783,12 +1297,13 @@ impl AsRef<InputInlineQueryResultAudio> for RTDInputInlineQueryResultAudioBuilde
 
 /// Represents a user contact
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputInlineQueryResultContact")]
 pub struct InputInlineQueryResultContact {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Unique identifier of the query result
     id: String,
@@ -797,9 +1312,9 @@ pub struct InputInlineQueryResultContact {
     /// URL of the result thumbnail, if it exists
     thumbnail_url: String,
     /// Thumbnail width, if known
-    thumbnail_width: i64,
+    thumbnail_width: i32,
     /// Thumbnail height, if known
-    thumbnail_height: i64,
+    thumbnail_height: i32,
     /// The message reply markup. Must be of type replyMarkupInlineKeyboard or null
     reply_markup: ReplyMarkup,
     /// The content of the message to be sent. Must be one of the following types: InputMessageText, InputMessageLocation, InputMessageVenue or InputMessageContact
@@ -820,6 +1335,18 @@ impl RObject for InputInlineQueryResultContact {
     }
 }
 
+impl PartialEq for InputInlineQueryResultContact {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.contact == other.contact
+            && self.thumbnail_url == other.thumbnail_url
+            && self.thumbnail_width == other.thumbnail_width
+            && self.thumbnail_height == other.thumbnail_height
+            && self.reply_markup == other.reply_markup
+            && self.input_message_content == other.input_message_content
+    }
+}
+
 impl TDInputInlineQueryResult for InputInlineQueryResultContact {}
 
 impl InputInlineQueryResultContact {
@@ -827,10 +1354,24 @@ impl InputInlineQueryResultContact {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputInlineQueryResultContactBuilder {
-        let mut inner = InputInlineQueryResultContact::default();
-        inner.td_name = "inputInlineQueryResultContact".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputInlineQueryResultContactBuilder { inner }
+        RTDInputInlineQueryResultContactBuilder {
+            inner: InputInlineQueryResultContact::default(),
+            missing: vec!["id", "contact", "thumbnail_url", "input_message_content"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputInlineQueryResultContactBuilder {
+        RTDInputInlineQueryResultContactBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputInlineQueryResultContactBuilder {
+        RTDInputInlineQueryResultContactBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn id(&self) -> &String {
@@ -845,11 +1386,11 @@ impl InputInlineQueryResultContact {
         &self.thumbnail_url
     }
 
-    pub fn thumbnail_width(&self) -> i64 {
+    pub fn thumbnail_width(&self) -> i32 {
         self.thumbnail_width
     }
 
-    pub fn thumbnail_height(&self) -> i64 {
+    pub fn thumbnail_height(&self) -> i32 {
         self.thumbnail_height
     }
 
@@ -860,39 +1401,113 @@ impl InputInlineQueryResultContact {
     pub fn input_message_content(&self) -> &InputMessageContent {
         &self.input_message_content
     }
+
+    pub fn into_id(self) -> String {
+        self.id
+    }
+
+    pub fn into_contact(self) -> Contact {
+        self.contact
+    }
+
+    pub fn into_thumbnail_url(self) -> String {
+        self.thumbnail_url
+    }
+
+    pub fn into_reply_markup(self) -> ReplyMarkup {
+        self.reply_markup
+    }
+
+    pub fn into_input_message_content(self) -> InputMessageContent {
+        self.input_message_content
+    }
+
+    pub fn with_id<T: AsRef<str>>(mut self, id: T) -> Self {
+        self.id = id.as_ref().to_string();
+        self
+    }
+
+    pub fn with_contact<T: AsRef<Contact>>(mut self, contact: T) -> Self {
+        self.contact = contact.as_ref().clone();
+        self
+    }
+
+    pub fn with_thumbnail_url<T: AsRef<str>>(mut self, thumbnail_url: T) -> Self {
+        self.thumbnail_url = thumbnail_url.as_ref().to_string();
+        self
+    }
+
+    pub fn with_thumbnail_width(mut self, thumbnail_width: i32) -> Self {
+        self.thumbnail_width = thumbnail_width;
+        self
+    }
+
+    pub fn with_thumbnail_height(mut self, thumbnail_height: i32) -> Self {
+        self.thumbnail_height = thumbnail_height;
+        self
+    }
+
+    pub fn with_reply_markup<T: AsRef<ReplyMarkup>>(mut self, reply_markup: T) -> Self {
+        self.reply_markup = reply_markup.as_ref().clone();
+        self
+    }
+
+    pub fn with_input_message_content<T: AsRef<InputMessageContent>>(
+        mut self,
+        input_message_content: T,
+    ) -> Self {
+        self.input_message_content = input_message_content.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputInlineQueryResultContactBuilder {
     inner: InputInlineQueryResultContact,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputInlineQueryResultContactBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputInlineQueryResultContact {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputInlineQueryResultContact> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputInlineQueryResultContact",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
         self.inner.id = id.as_ref().to_string();
+        self.missing.retain(|f| *f != "id");
         self
     }
 
     pub fn contact<T: AsRef<Contact>>(&mut self, contact: T) -> &mut Self {
         self.inner.contact = contact.as_ref().clone();
+        self.missing.retain(|f| *f != "contact");
         self
     }
 
     pub fn thumbnail_url<T: AsRef<str>>(&mut self, thumbnail_url: T) -> &mut Self {
         self.inner.thumbnail_url = thumbnail_url.as_ref().to_string();
+        self.missing.retain(|f| *f != "thumbnail_url");
         self
     }
 
-    pub fn thumbnail_width(&mut self, thumbnail_width: i64) -> &mut Self {
+    pub fn thumbnail_width(&mut self, thumbnail_width: i32) -> &mut Self {
         self.inner.thumbnail_width = thumbnail_width;
         self
     }
 
-    pub fn thumbnail_height(&mut self, thumbnail_height: i64) -> &mut Self {
+    pub fn thumbnail_height(&mut self, thumbnail_height: i32) -> &mut Self {
         self.inner.thumbnail_height = thumbnail_height;
         self
     }
@@ -907,6 +1522,7 @@ impl RTDInputInlineQueryResultContactBuilder {
         input_message_content: T,
     ) -> &mut Self {
         self.inner.input_message_content = input_message_content.as_ref().clone();
+        self.missing.retain(|f| *f != "input_message_content");
         self
     }
 }
@@ -925,12 +1541,13 @@ impl AsRef<InputInlineQueryResultContact> for RTDInputInlineQueryResultContactBu
 
 /// Represents a link to a file
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputInlineQueryResultDocument")]
 pub struct InputInlineQueryResultDocument {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Unique identifier of the query result
     id: String,
@@ -945,9 +1562,9 @@ pub struct InputInlineQueryResultDocument {
     /// The URL of the file thumbnail, if it exists
     thumbnail_url: String,
     /// Width of the thumbnail
-    thumbnail_width: i64,
+    thumbnail_width: i32,
     /// Height of the thumbnail
-    thumbnail_height: i64,
+    thumbnail_height: i32,
     /// The message reply markup. Must be of type replyMarkupInlineKeyboard or null
     reply_markup: ReplyMarkup,
     /// The content of the message to be sent. Must be one of the following types: InputMessageText, InputMessageDocument, InputMessageLocation, InputMessageVenue or InputMessageContact
@@ -968,17 +1585,56 @@ impl RObject for InputInlineQueryResultDocument {
     }
 }
 
-impl TDInputInlineQueryResult for InputInlineQueryResultDocument {}
-
+impl PartialEq for InputInlineQueryResultDocument {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.title == other.title
+            && self.description == other.description
+            && self.document_url == other.document_url
+            && self.mime_type == other.mime_type
+            && self.thumbnail_url == other.thumbnail_url
+            && self.thumbnail_width == other.thumbnail_width
+            && self.thumbnail_height == other.thumbnail_height
+            && self.reply_markup == other.reply_markup
+            && self.input_message_content == other.input_message_content
+    }
+}
+
+impl TDInputInlineQueryResult for InputInlineQueryResultDocument {}
+
 impl InputInlineQueryResultDocument {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputInlineQueryResultDocumentBuilder {
-        let mut inner = InputInlineQueryResultDocument::default();
-        inner.td_name = "inputInlineQueryResultDocument".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputInlineQueryResultDocumentBuilder { inner }
+        RTDInputInlineQueryResultDocumentBuilder {
+            inner: InputInlineQueryResultDocument::default(),
+            missing: vec![
+                "id",
+                "title",
+                "description",
+                "document_url",
+                "mime_type",
+                "thumbnail_url",
+                "thumbnail_width",
+                "thumbnail_height",
+                "input_message_content",
+            ],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputInlineQueryResultDocumentBuilder {
+        RTDInputInlineQueryResultDocumentBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputInlineQueryResultDocumentBuilder {
+        RTDInputInlineQueryResultDocumentBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn id(&self) -> &String {
@@ -1005,11 +1661,11 @@ impl InputInlineQueryResultDocument {
         &self.thumbnail_url
     }
 
-    pub fn thumbnail_width(&self) -> i64 {
+    pub fn thumbnail_width(&self) -> i32 {
         self.thumbnail_width
     }
 
-    pub fn thumbnail_height(&self) -> i64 {
+    pub fn thumbnail_height(&self) -> i32 {
         self.thumbnail_height
     }
 
@@ -1020,55 +1676,161 @@ impl InputInlineQueryResultDocument {
     pub fn input_message_content(&self) -> &InputMessageContent {
         &self.input_message_content
     }
+
+    pub fn into_id(self) -> String {
+        self.id
+    }
+
+    pub fn into_title(self) -> String {
+        self.title
+    }
+
+    pub fn into_description(self) -> String {
+        self.description
+    }
+
+    pub fn into_document_url(self) -> String {
+        self.document_url
+    }
+
+    pub fn into_mime_type(self) -> String {
+        self.mime_type
+    }
+
+    pub fn into_thumbnail_url(self) -> String {
+        self.thumbnail_url
+    }
+
+    pub fn into_reply_markup(self) -> ReplyMarkup {
+        self.reply_markup
+    }
+
+    pub fn into_input_message_content(self) -> InputMessageContent {
+        self.input_message_content
+    }
+
+    pub fn with_id<T: AsRef<str>>(mut self, id: T) -> Self {
+        self.id = id.as_ref().to_string();
+        self
+    }
+
+    pub fn with_title<T: AsRef<str>>(mut self, title: T) -> Self {
+        self.title = title.as_ref().to_string();
+        self
+    }
+
+    pub fn with_description<T: AsRef<str>>(mut self, description: T) -> Self {
+        self.description = description.as_ref().to_string();
+        self
+    }
+
+    pub fn with_document_url<T: AsRef<str>>(mut self, document_url: T) -> Self {
+        self.document_url = document_url.as_ref().to_string();
+        self
+    }
+
+    pub fn with_mime_type<T: AsRef<str>>(mut self, mime_type: T) -> Self {
+        self.mime_type = mime_type.as_ref().to_string();
+        self
+    }
+
+    pub fn with_thumbnail_url<T: AsRef<str>>(mut self, thumbnail_url: T) -> Self {
+        self.thumbnail_url = thumbnail_url.as_ref().to_string();
+        self
+    }
+
+    pub fn with_thumbnail_width(mut self, thumbnail_width: i32) -> Self {
+        self.thumbnail_width = thumbnail_width;
+        self
+    }
+
+    pub fn with_thumbnail_height(mut self, thumbnail_height: i32) -> Self {
+        self.thumbnail_height = thumbnail_height;
+        self
+    }
+
+    pub fn with_reply_markup<T: AsRef<ReplyMarkup>>(mut self, reply_markup: T) -> Self {
+        self.reply_markup = reply_markup.as_ref().clone();
+        self
+    }
+
+    pub fn with_input_message_content<T: AsRef<InputMessageContent>>(
+        mut self,
+        input_message_content: T,
+    ) -> Self {
+        self.input_message_content = input_message_content.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputInlineQueryResultDocumentBuilder {
     inner: InputInlineQueryResultDocument,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputInlineQueryResultDocumentBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputInlineQueryResultDocument {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputInlineQueryResultDocument> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputInlineQueryResultDocument",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
         self.inner.id = id.as_ref().to_string();
+        self.missing.retain(|f| *f != "id");
         self
     }
 
     pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
         self.inner.title = title.as_ref().to_string();
+        self.missing.retain(|f| *f != "title");
         self
     }
 
     pub fn description<T: AsRef<str>>(&mut self, description: T) -> &mut Self {
         self.inner.description = description.as_ref().to_string();
+        self.missing.retain(|f| *f != "description");
         self
     }
 
     pub fn document_url<T: AsRef<str>>(&mut self, document_url: T) -> &mut Self {
         self.inner.document_url = document_url.as_ref().to_string();
+        self.missing.retain(|f| *f != "document_url");
         self
     }
 
     pub fn mime_type<T: AsRef<str>>(&mut self, mime_type: T) -> &mut Self {
         self.inner.mime_type = mime_type.as_ref().to_string();
+        self.missing.retain(|f| *f != "mime_type");
         self
     }
 
     pub fn thumbnail_url<T: AsRef<str>>(&mut self, thumbnail_url: T) -> &mut Self {
         self.inner.thumbnail_url = thumbnail_url.as_ref().to_string();
+        self.missing.retain(|f| *f != "thumbnail_url");
         self
     }
 
-    pub fn thumbnail_width(&mut self, thumbnail_width: i64) -> &mut Self {
+    pub fn thumbnail_width(&mut self, thumbnail_width: i32) -> &mut Self {
         self.inner.thumbnail_width = thumbnail_width;
+        self.missing.retain(|f| *f != "thumbnail_width");
         self
     }
 
-    pub fn thumbnail_height(&mut self, thumbnail_height: i64) -> &mut Self {
+    pub fn thumbnail_height(&mut self, thumbnail_height: i32) -> &mut Self {
         self.inner.thumbnail_height = thumbnail_height;
+        self.missing.retain(|f| *f != "thumbnail_height");
         self
     }
 
@@ -1082,6 +1844,7 @@ impl RTDInputInlineQueryResultDocumentBuilder {
         input_message_content: T,
     ) -> &mut Self {
         self.inner.input_message_content = input_message_content.as_ref().clone();
+        self.missing.retain(|f| *f != "input_message_content");
         self
     }
 }
@@ -1100,12 +1863,13 @@ impl AsRef<InputInlineQueryResultDocument> for RTDInputInlineQueryResultDocument
 
 /// Represents a game
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputInlineQueryResultGame")]
 pub struct InputInlineQueryResultGame {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Unique identifier of the query result
     id: String,
@@ -1129,6 +1893,24 @@ impl RObject for InputInlineQueryResultGame {
     }
 }
 
+impl PartialEq for InputInlineQueryResultGame {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.game_short_name == other.game_short_name
+            && self.reply_markup == other.reply_markup
+    }
+}
+
+impl Eq for InputInlineQueryResultGame {}
+
+impl std::hash::Hash for InputInlineQueryResultGame {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.id, state);
+        std::hash::Hash::hash(&self.game_short_name, state);
+        std::hash::Hash::hash(&self.reply_markup, state);
+    }
+}
+
 impl TDInputInlineQueryResult for InputInlineQueryResultGame {}
 
 impl InputInlineQueryResultGame {
@@ -1136,10 +1918,24 @@ impl InputInlineQueryResultGame {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputInlineQueryResultGameBuilder {
-        let mut inner = InputInlineQueryResultGame::default();
-        inner.td_name = "inputInlineQueryResultGame".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputInlineQueryResultGameBuilder { inner }
+        RTDInputInlineQueryResultGameBuilder {
+            inner: InputInlineQueryResultGame::default(),
+            missing: vec!["id", "game_short_name"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputInlineQueryResultGameBuilder {
+        RTDInputInlineQueryResultGameBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputInlineQueryResultGameBuilder {
+        RTDInputInlineQueryResultGameBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn id(&self) -> &String {
@@ -1153,25 +1949,67 @@ impl InputInlineQueryResultGame {
     pub fn reply_markup(&self) -> &ReplyMarkup {
         &self.reply_markup
     }
+
+    pub fn into_id(self) -> String {
+        self.id
+    }
+
+    pub fn into_game_short_name(self) -> String {
+        self.game_short_name
+    }
+
+    pub fn into_reply_markup(self) -> ReplyMarkup {
+        self.reply_markup
+    }
+
+    pub fn with_id<T: AsRef<str>>(mut self, id: T) -> Self {
+        self.id = id.as_ref().to_string();
+        self
+    }
+
+    pub fn with_game_short_name<T: AsRef<str>>(mut self, game_short_name: T) -> Self {
+        self.game_short_name = game_short_name.as_ref().to_string();
+        self
+    }
+
+    pub fn with_reply_markup<T: AsRef<ReplyMarkup>>(mut self, reply_markup: T) -> Self {
+        self.reply_markup = reply_markup.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputInlineQueryResultGameBuilder {
     inner: InputInlineQueryResultGame,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputInlineQueryResultGameBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputInlineQueryResultGame {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputInlineQueryResultGame> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputInlineQueryResultGame",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
         self.inner.id = id.as_ref().to_string();
+        self.missing.retain(|f| *f != "id");
         self
     }
 
     pub fn game_short_name<T: AsRef<str>>(&mut self, game_short_name: T) -> &mut Self {
         self.inner.game_short_name = game_short_name.as_ref().to_string();
+        self.missing.retain(|f| *f != "game_short_name");
         self
     }
 
@@ -1195,27 +2033,28 @@ impl AsRef<InputInlineQueryResultGame> for RTDInputInlineQueryResultGameBuilder
 
 /// Represents a point on the map
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputInlineQueryResultLocation")]
 pub struct InputInlineQueryResultLocation {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Unique identifier of the query result
     id: String,
     /// Location result
     location: Location,
     /// Amount of time relative to the message sent time until the location can be updated, in seconds
-    live_period: i64,
+    live_period: i32,
     /// Title of the result
     title: String,
     /// URL of the result thumbnail, if it exists
     thumbnail_url: String,
     /// Thumbnail width, if known
-    thumbnail_width: i64,
+    thumbnail_width: i32,
     /// Thumbnail height, if known
-    thumbnail_height: i64,
+    thumbnail_height: i32,
     /// The message reply markup. Must be of type replyMarkupInlineKeyboard or null
     reply_markup: ReplyMarkup,
     /// The content of the message to be sent. Must be one of the following types: InputMessageText, InputMessageLocation, InputMessageVenue or InputMessageContact
@@ -1236,6 +2075,20 @@ impl RObject for InputInlineQueryResultLocation {
     }
 }
 
+impl PartialEq for InputInlineQueryResultLocation {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.location == other.location
+            && self.live_period == other.live_period
+            && self.title == other.title
+            && self.thumbnail_url == other.thumbnail_url
+            && self.thumbnail_width == other.thumbnail_width
+            && self.thumbnail_height == other.thumbnail_height
+            && self.reply_markup == other.reply_markup
+            && self.input_message_content == other.input_message_content
+    }
+}
+
 impl TDInputInlineQueryResult for InputInlineQueryResultLocation {}
 
 impl InputInlineQueryResultLocation {
@@ -1243,10 +2096,31 @@ impl InputInlineQueryResultLocation {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputInlineQueryResultLocationBuilder {
-        let mut inner = InputInlineQueryResultLocation::default();
-        inner.td_name = "inputInlineQueryResultLocation".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputInlineQueryResultLocationBuilder { inner }
+        RTDInputInlineQueryResultLocationBuilder {
+            inner: InputInlineQueryResultLocation::default(),
+            missing: vec![
+                "id",
+                "location",
+                "live_period",
+                "title",
+                "thumbnail_url",
+                "input_message_content",
+            ],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputInlineQueryResultLocationBuilder {
+        RTDInputInlineQueryResultLocationBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputInlineQueryResultLocationBuilder {
+        RTDInputInlineQueryResultLocationBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn id(&self) -> &String {
@@ -1257,7 +2131,7 @@ impl InputInlineQueryResultLocation {
         &self.location
     }
 
-    pub fn live_period(&self) -> i64 {
+    pub fn live_period(&self) -> i32 {
         self.live_period
     }
 
@@ -1269,11 +2143,11 @@ impl InputInlineQueryResultLocation {
         &self.thumbnail_url
     }
 
-    pub fn thumbnail_width(&self) -> i64 {
+    pub fn thumbnail_width(&self) -> i32 {
         self.thumbnail_width
     }
 
-    pub fn thumbnail_height(&self) -> i64 {
+    pub fn thumbnail_height(&self) -> i32 {
         self.thumbnail_height
     }
 
@@ -1284,49 +2158,139 @@ impl InputInlineQueryResultLocation {
     pub fn input_message_content(&self) -> &InputMessageContent {
         &self.input_message_content
     }
+
+    pub fn into_id(self) -> String {
+        self.id
+    }
+
+    pub fn into_location(self) -> Location {
+        self.location
+    }
+
+    pub fn into_title(self) -> String {
+        self.title
+    }
+
+    pub fn into_thumbnail_url(self) -> String {
+        self.thumbnail_url
+    }
+
+    pub fn into_reply_markup(self) -> ReplyMarkup {
+        self.reply_markup
+    }
+
+    pub fn into_input_message_content(self) -> InputMessageContent {
+        self.input_message_content
+    }
+
+    pub fn with_id<T: AsRef<str>>(mut self, id: T) -> Self {
+        self.id = id.as_ref().to_string();
+        self
+    }
+
+    pub fn with_location<T: AsRef<Location>>(mut self, location: T) -> Self {
+        self.location = location.as_ref().clone();
+        self
+    }
+
+    pub fn with_live_period(mut self, live_period: i32) -> Self {
+        self.live_period = live_period;
+        self
+    }
+
+    pub fn with_title<T: AsRef<str>>(mut self, title: T) -> Self {
+        self.title = title.as_ref().to_string();
+        self
+    }
+
+    pub fn with_thumbnail_url<T: AsRef<str>>(mut self, thumbnail_url: T) -> Self {
+        self.thumbnail_url = thumbnail_url.as_ref().to_string();
+        self
+    }
+
+    pub fn with_thumbnail_width(mut self, thumbnail_width: i32) -> Self {
+        self.thumbnail_width = thumbnail_width;
+        self
+    }
+
+    pub fn with_thumbnail_height(mut self, thumbnail_height: i32) -> Self {
+        self.thumbnail_height = thumbnail_height;
+        self
+    }
+
+    pub fn with_reply_markup<T: AsRef<ReplyMarkup>>(mut self, reply_markup: T) -> Self {
+        self.reply_markup = reply_markup.as_ref().clone();
+        self
+    }
+
+    pub fn with_input_message_content<T: AsRef<InputMessageContent>>(
+        mut self,
+        input_message_content: T,
+    ) -> Self {
+        self.input_message_content = input_message_content.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputInlineQueryResultLocationBuilder {
     inner: InputInlineQueryResultLocation,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputInlineQueryResultLocationBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputInlineQueryResultLocation {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputInlineQueryResultLocation> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputInlineQueryResultLocation",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
         self.inner.id = id.as_ref().to_string();
+        self.missing.retain(|f| *f != "id");
         self
     }
 
     pub fn location<T: AsRef<Location>>(&mut self, location: T) -> &mut Self {
         self.inner.location = location.as_ref().clone();
+        self.missing.retain(|f| *f != "location");
         self
     }
 
-    pub fn live_period(&mut self, live_period: i64) -> &mut Self {
+    pub fn live_period(&mut self, live_period: i32) -> &mut Self {
         self.inner.live_period = live_period;
+        self.missing.retain(|f| *f != "live_period");
         self
     }
 
     pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
         self.inner.title = title.as_ref().to_string();
+        self.missing.retain(|f| *f != "title");
         self
     }
 
     pub fn thumbnail_url<T: AsRef<str>>(&mut self, thumbnail_url: T) -> &mut Self {
         self.inner.thumbnail_url = thumbnail_url.as_ref().to_string();
+        self.missing.retain(|f| *f != "thumbnail_url");
         self
     }
 
-    pub fn thumbnail_width(&mut self, thumbnail_width: i64) -> &mut Self {
+    pub fn thumbnail_width(&mut self, thumbnail_width: i32) -> &mut Self {
         self.inner.thumbnail_width = thumbnail_width;
         self
     }
 
-    pub fn thumbnail_height(&mut self, thumbnail_height: i64) -> &mut Self {
+    pub fn thumbnail_height(&mut self, thumbnail_height: i32) -> &mut Self {
         self.inner.thumbnail_height = thumbnail_height;
         self
     }
@@ -1341,6 +2305,7 @@ impl RTDInputInlineQueryResultLocationBuilder {
         input_message_content: T,
     ) -> &mut Self {
         self.inner.input_message_content = input_message_content.as_ref().clone();
+        self.missing.retain(|f| *f != "input_message_content");
         self
     }
 }
@@ -1359,12 +2324,13 @@ impl AsRef<InputInlineQueryResultLocation> for RTDInputInlineQueryResultLocation
 
 /// Represents link to a JPEG image
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputInlineQueryResultPhoto")]
 pub struct InputInlineQueryResultPhoto {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Unique identifier of the query result
     id: String,
@@ -1377,9 +2343,9 @@ pub struct InputInlineQueryResultPhoto {
     /// The URL of the JPEG photo (photo size must not exceed 5MB)
     photo_url: String,
     /// Width of the photo
-    photo_width: i64,
+    photo_width: i32,
     /// Height of the photo
-    photo_height: i64,
+    photo_height: i32,
     /// The message reply markup. Must be of type replyMarkupInlineKeyboard or null
     reply_markup: ReplyMarkup,
     /// The content of the message to be sent. Must be one of the following types: InputMessageText, InputMessagePhoto, InputMessageLocation, InputMessageVenue or InputMessageContact
@@ -1400,6 +2366,20 @@ impl RObject for InputInlineQueryResultPhoto {
     }
 }
 
+impl PartialEq for InputInlineQueryResultPhoto {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.title == other.title
+            && self.description == other.description
+            && self.thumbnail_url == other.thumbnail_url
+            && self.photo_url == other.photo_url
+            && self.photo_width == other.photo_width
+            && self.photo_height == other.photo_height
+            && self.reply_markup == other.reply_markup
+            && self.input_message_content == other.input_message_content
+    }
+}
+
 impl TDInputInlineQueryResult for InputInlineQueryResultPhoto {}
 
 impl InputInlineQueryResultPhoto {
@@ -1407,10 +2387,32 @@ impl InputInlineQueryResultPhoto {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputInlineQueryResultPhotoBuilder {
-        let mut inner = InputInlineQueryResultPhoto::default();
-        inner.td_name = "inputInlineQueryResultPhoto".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputInlineQueryResultPhotoBuilder { inner }
+        RTDInputInlineQueryResultPhotoBuilder {
+            inner: InputInlineQueryResultPhoto::default(),
+            missing: vec![
+                "id",
+                "description",
+                "thumbnail_url",
+                "photo_url",
+                "photo_width",
+                "photo_height",
+                "input_message_content",
+            ],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputInlineQueryResultPhotoBuilder {
+        RTDInputInlineQueryResultPhotoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputInlineQueryResultPhotoBuilder {
+        RTDInputInlineQueryResultPhotoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn id(&self) -> &String {
@@ -1433,11 +2435,11 @@ impl InputInlineQueryResultPhoto {
         &self.photo_url
     }
 
-    pub fn photo_width(&self) -> i64 {
+    pub fn photo_width(&self) -> i32 {
         self.photo_width
     }
 
-    pub fn photo_height(&self) -> i64 {
+    pub fn photo_height(&self) -> i32 {
         self.photo_height
     }
 
@@ -1448,20 +2450,110 @@ impl InputInlineQueryResultPhoto {
     pub fn input_message_content(&self) -> &InputMessageContent {
         &self.input_message_content
     }
+
+    pub fn into_id(self) -> String {
+        self.id
+    }
+
+    pub fn into_title(self) -> String {
+        self.title
+    }
+
+    pub fn into_description(self) -> String {
+        self.description
+    }
+
+    pub fn into_thumbnail_url(self) -> String {
+        self.thumbnail_url
+    }
+
+    pub fn into_photo_url(self) -> String {
+        self.photo_url
+    }
+
+    pub fn into_reply_markup(self) -> ReplyMarkup {
+        self.reply_markup
+    }
+
+    pub fn into_input_message_content(self) -> InputMessageContent {
+        self.input_message_content
+    }
+
+    pub fn with_id<T: AsRef<str>>(mut self, id: T) -> Self {
+        self.id = id.as_ref().to_string();
+        self
+    }
+
+    pub fn with_title<T: AsRef<str>>(mut self, title: T) -> Self {
+        self.title = title.as_ref().to_string();
+        self
+    }
+
+    pub fn with_description<T: AsRef<str>>(mut self, description: T) -> Self {
+        self.description = description.as_ref().to_string();
+        self
+    }
+
+    pub fn with_thumbnail_url<T: AsRef<str>>(mut self, thumbnail_url: T) -> Self {
+        self.thumbnail_url = thumbnail_url.as_ref().to_string();
+        self
+    }
+
+    pub fn with_photo_url<T: AsRef<str>>(mut self, photo_url: T) -> Self {
+        self.photo_url = photo_url.as_ref().to_string();
+        self
+    }
+
+    pub fn with_photo_width(mut self, photo_width: i32) -> Self {
+        self.photo_width = photo_width;
+        self
+    }
+
+    pub fn with_photo_height(mut self, photo_height: i32) -> Self {
+        self.photo_height = photo_height;
+        self
+    }
+
+    pub fn with_reply_markup<T: AsRef<ReplyMarkup>>(mut self, reply_markup: T) -> Self {
+        self.reply_markup = reply_markup.as_ref().clone();
+        self
+    }
+
+    pub fn with_input_message_content<T: AsRef<InputMessageContent>>(
+        mut self,
+        input_message_content: T,
+    ) -> Self {
+        self.input_message_content = input_message_content.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputInlineQueryResultPhotoBuilder {
     inner: InputInlineQueryResultPhoto,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputInlineQueryResultPhotoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputInlineQueryResultPhoto {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputInlineQueryResultPhoto> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputInlineQueryResultPhoto",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
         self.inner.id = id.as_ref().to_string();
+        self.missing.retain(|f| *f != "id");
         self
     }
 
@@ -1472,26 +2564,31 @@ impl RTDInputInlineQueryResultPhotoBuilder {
 
     pub fn description<T: AsRef<str>>(&mut self, description: T) -> &mut Self {
         self.inner.description = description.as_ref().to_string();
+        self.missing.retain(|f| *f != "description");
         self
     }
 
     pub fn thumbnail_url<T: AsRef<str>>(&mut self, thumbnail_url: T) -> &mut Self {
         self.inner.thumbnail_url = thumbnail_url.as_ref().to_string();
+        self.missing.retain(|f| *f != "thumbnail_url");
         self
     }
 
     pub fn photo_url<T: AsRef<str>>(&mut self, photo_url: T) -> &mut Self {
         self.inner.photo_url = photo_url.as_ref().to_string();
+        self.missing.retain(|f| *f != "photo_url");
         self
     }
 
-    pub fn photo_width(&mut self, photo_width: i64) -> &mut Self {
+    pub fn photo_width(&mut self, photo_width: i32) -> &mut Self {
         self.inner.photo_width = photo_width;
+        self.missing.retain(|f| *f != "photo_width");
         self
     }
 
-    pub fn photo_height(&mut self, photo_height: i64) -> &mut Self {
+    pub fn photo_height(&mut self, photo_height: i32) -> &mut Self {
         self.inner.photo_height = photo_height;
+        self.missing.retain(|f| *f != "photo_height");
         self
     }
 
@@ -1505,6 +2602,7 @@ impl RTDInputInlineQueryResultPhotoBuilder {
         input_message_content: T,
     ) -> &mut Self {
         self.inner.input_message_content = input_message_content.as_ref().clone();
+        self.missing.retain(|f| *f != "input_message_content");
         self
     }
 }
@@ -1523,12 +2621,13 @@ impl AsRef<InputInlineQueryResultPhoto> for RTDInputInlineQueryResultPhotoBuilde
 
 /// Represents a link to a WEBP or TGS sticker
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputInlineQueryResultSticker")]
 pub struct InputInlineQueryResultSticker {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Unique identifier of the query result
     id: String,
@@ -1537,9 +2636,9 @@ pub struct InputInlineQueryResultSticker {
     /// The URL of the WEBP or TGS sticker (sticker file size must not exceed 5MB)
     sticker_url: String,
     /// Width of the sticker
-    sticker_width: i64,
+    sticker_width: i32,
     /// Height of the sticker
-    sticker_height: i64,
+    sticker_height: i32,
     /// The message reply markup. Must be of type replyMarkupInlineKeyboard or null
     reply_markup: ReplyMarkup,
     /// The content of the message to be sent. Must be one of the following types: InputMessageText, inputMessageSticker, InputMessageLocation, InputMessageVenue or InputMessageContact
@@ -1560,6 +2659,18 @@ impl RObject for InputInlineQueryResultSticker {
     }
 }
 
+impl PartialEq for InputInlineQueryResultSticker {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.thumbnail_url == other.thumbnail_url
+            && self.sticker_url == other.sticker_url
+            && self.sticker_width == other.sticker_width
+            && self.sticker_height == other.sticker_height
+            && self.reply_markup == other.reply_markup
+            && self.input_message_content == other.input_message_content
+    }
+}
+
 impl TDInputInlineQueryResult for InputInlineQueryResultSticker {}
 
 impl InputInlineQueryResultSticker {
@@ -1567,10 +2678,31 @@ impl InputInlineQueryResultSticker {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputInlineQueryResultStickerBuilder {
-        let mut inner = InputInlineQueryResultSticker::default();
-        inner.td_name = "inputInlineQueryResultSticker".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputInlineQueryResultStickerBuilder { inner }
+        RTDInputInlineQueryResultStickerBuilder {
+            inner: InputInlineQueryResultSticker::default(),
+            missing: vec![
+                "id",
+                "thumbnail_url",
+                "sticker_url",
+                "sticker_width",
+                "sticker_height",
+                "input_message_content",
+            ],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputInlineQueryResultStickerBuilder {
+        RTDInputInlineQueryResultStickerBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputInlineQueryResultStickerBuilder {
+        RTDInputInlineQueryResultStickerBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn id(&self) -> &String {
@@ -1585,11 +2717,11 @@ impl InputInlineQueryResultSticker {
         &self.sticker_url
     }
 
-    pub fn sticker_width(&self) -> i64 {
+    pub fn sticker_width(&self) -> i32 {
         self.sticker_width
     }
 
-    pub fn sticker_height(&self) -> i64 {
+    pub fn sticker_height(&self) -> i32 {
         self.sticker_height
     }
 
@@ -1600,40 +2732,116 @@ impl InputInlineQueryResultSticker {
     pub fn input_message_content(&self) -> &InputMessageContent {
         &self.input_message_content
     }
+
+    pub fn into_id(self) -> String {
+        self.id
+    }
+
+    pub fn into_thumbnail_url(self) -> String {
+        self.thumbnail_url
+    }
+
+    pub fn into_sticker_url(self) -> String {
+        self.sticker_url
+    }
+
+    pub fn into_reply_markup(self) -> ReplyMarkup {
+        self.reply_markup
+    }
+
+    pub fn into_input_message_content(self) -> InputMessageContent {
+        self.input_message_content
+    }
+
+    pub fn with_id<T: AsRef<str>>(mut self, id: T) -> Self {
+        self.id = id.as_ref().to_string();
+        self
+    }
+
+    pub fn with_thumbnail_url<T: AsRef<str>>(mut self, thumbnail_url: T) -> Self {
+        self.thumbnail_url = thumbnail_url.as_ref().to_string();
+        self
+    }
+
+    pub fn with_sticker_url<T: AsRef<str>>(mut self, sticker_url: T) -> Self {
+        self.sticker_url = sticker_url.as_ref().to_string();
+        self
+    }
+
+    pub fn with_sticker_width(mut self, sticker_width: i32) -> Self {
+        self.sticker_width = sticker_width;
+        self
+    }
+
+    pub fn with_sticker_height(mut self, sticker_height: i32) -> Self {
+        self.sticker_height = sticker_height;
+        self
+    }
+
+    pub fn with_reply_markup<T: AsRef<ReplyMarkup>>(mut self, reply_markup: T) -> Self {
+        self.reply_markup = reply_markup.as_ref().clone();
+        self
+    }
+
+    pub fn with_input_message_content<T: AsRef<InputMessageContent>>(
+        mut self,
+        input_message_content: T,
+    ) -> Self {
+        self.input_message_content = input_message_content.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputInlineQueryResultStickerBuilder {
     inner: InputInlineQueryResultSticker,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputInlineQueryResultStickerBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputInlineQueryResultSticker {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputInlineQueryResultSticker> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputInlineQueryResultSticker",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
         self.inner.id = id.as_ref().to_string();
+        self.missing.retain(|f| *f != "id");
         self
     }
 
     pub fn thumbnail_url<T: AsRef<str>>(&mut self, thumbnail_url: T) -> &mut Self {
         self.inner.thumbnail_url = thumbnail_url.as_ref().to_string();
+        self.missing.retain(|f| *f != "thumbnail_url");
         self
     }
 
     pub fn sticker_url<T: AsRef<str>>(&mut self, sticker_url: T) -> &mut Self {
         self.inner.sticker_url = sticker_url.as_ref().to_string();
+        self.missing.retain(|f| *f != "sticker_url");
         self
     }
 
-    pub fn sticker_width(&mut self, sticker_width: i64) -> &mut Self {
+    pub fn sticker_width(&mut self, sticker_width: i32) -> &mut Self {
         self.inner.sticker_width = sticker_width;
+        self.missing.retain(|f| *f != "sticker_width");
         self
     }
 
-    pub fn sticker_height(&mut self, sticker_height: i64) -> &mut Self {
+    pub fn sticker_height(&mut self, sticker_height: i32) -> &mut Self {
         self.inner.sticker_height = sticker_height;
+        self.missing.retain(|f| *f != "sticker_height");
         self
     }
 
@@ -1647,6 +2855,7 @@ impl RTDInputInlineQueryResultStickerBuilder {
         input_message_content: T,
     ) -> &mut Self {
         self.inner.input_message_content = input_message_content.as_ref().clone();
+        self.missing.retain(|f| *f != "input_message_content");
         self
     }
 }
@@ -1665,12 +2874,13 @@ impl AsRef<InputInlineQueryResultSticker> for RTDInputInlineQueryResultStickerBu
 
 /// Represents information about a venue
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputInlineQueryResultVenue")]
 pub struct InputInlineQueryResultVenue {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Unique identifier of the query result
     id: String,
@@ -1679,9 +2889,9 @@ pub struct InputInlineQueryResultVenue {
     /// URL of the result thumbnail, if it exists
     thumbnail_url: String,
     /// Thumbnail width, if known
-    thumbnail_width: i64,
+    thumbnail_width: i32,
     /// Thumbnail height, if known
-    thumbnail_height: i64,
+    thumbnail_height: i32,
     /// The message reply markup. Must be of type replyMarkupInlineKeyboard or null
     reply_markup: ReplyMarkup,
     /// The content of the message to be sent. Must be one of the following types: InputMessageText, InputMessageLocation, InputMessageVenue or InputMessageContact
@@ -1702,6 +2912,18 @@ impl RObject for InputInlineQueryResultVenue {
     }
 }
 
+impl PartialEq for InputInlineQueryResultVenue {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.venue == other.venue
+            && self.thumbnail_url == other.thumbnail_url
+            && self.thumbnail_width == other.thumbnail_width
+            && self.thumbnail_height == other.thumbnail_height
+            && self.reply_markup == other.reply_markup
+            && self.input_message_content == other.input_message_content
+    }
+}
+
 impl TDInputInlineQueryResult for InputInlineQueryResultVenue {}
 
 impl InputInlineQueryResultVenue {
@@ -1709,10 +2931,24 @@ impl InputInlineQueryResultVenue {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputInlineQueryResultVenueBuilder {
-        let mut inner = InputInlineQueryResultVenue::default();
-        inner.td_name = "inputInlineQueryResultVenue".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputInlineQueryResultVenueBuilder { inner }
+        RTDInputInlineQueryResultVenueBuilder {
+            inner: InputInlineQueryResultVenue::default(),
+            missing: vec!["id", "venue", "thumbnail_url", "input_message_content"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputInlineQueryResultVenueBuilder {
+        RTDInputInlineQueryResultVenueBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputInlineQueryResultVenueBuilder {
+        RTDInputInlineQueryResultVenueBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn id(&self) -> &String {
@@ -1727,11 +2963,11 @@ impl InputInlineQueryResultVenue {
         &self.thumbnail_url
     }
 
-    pub fn thumbnail_width(&self) -> i64 {
+    pub fn thumbnail_width(&self) -> i32 {
         self.thumbnail_width
     }
 
-    pub fn thumbnail_height(&self) -> i64 {
+    pub fn thumbnail_height(&self) -> i32 {
         self.thumbnail_height
     }
 
@@ -1742,39 +2978,113 @@ impl InputInlineQueryResultVenue {
     pub fn input_message_content(&self) -> &InputMessageContent {
         &self.input_message_content
     }
+
+    pub fn into_id(self) -> String {
+        self.id
+    }
+
+    pub fn into_venue(self) -> Venue {
+        self.venue
+    }
+
+    pub fn into_thumbnail_url(self) -> String {
+        self.thumbnail_url
+    }
+
+    pub fn into_reply_markup(self) -> ReplyMarkup {
+        self.reply_markup
+    }
+
+    pub fn into_input_message_content(self) -> InputMessageContent {
+        self.input_message_content
+    }
+
+    pub fn with_id<T: AsRef<str>>(mut self, id: T) -> Self {
+        self.id = id.as_ref().to_string();
+        self
+    }
+
+    pub fn with_venue<T: AsRef<Venue>>(mut self, venue: T) -> Self {
+        self.venue = venue.as_ref().clone();
+        self
+    }
+
+    pub fn with_thumbnail_url<T: AsRef<str>>(mut self, thumbnail_url: T) -> Self {
+        self.thumbnail_url = thumbnail_url.as_ref().to_string();
+        self
+    }
+
+    pub fn with_thumbnail_width(mut self, thumbnail_width: i32) -> Self {
+        self.thumbnail_width = thumbnail_width;
+        self
+    }
+
+    pub fn with_thumbnail_height(mut self, thumbnail_height: i32) -> Self {
+        self.thumbnail_height = thumbnail_height;
+        self
+    }
+
+    pub fn with_reply_markup<T: AsRef<ReplyMarkup>>(mut self, reply_markup: T) -> Self {
+        self.reply_markup = reply_markup.as_ref().clone();
+        self
+    }
+
+    pub fn with_input_message_content<T: AsRef<InputMessageContent>>(
+        mut self,
+        input_message_content: T,
+    ) -> Self {
+        self.input_message_content = input_message_content.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputInlineQueryResultVenueBuilder {
     inner: InputInlineQueryResultVenue,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputInlineQueryResultVenueBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputInlineQueryResultVenue {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputInlineQueryResultVenue> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputInlineQueryResultVenue",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
         self.inner.id = id.as_ref().to_string();
+        self.missing.retain(|f| *f != "id");
         self
     }
 
     pub fn venue<T: AsRef<Venue>>(&mut self, venue: T) -> &mut Self {
         self.inner.venue = venue.as_ref().clone();
+        self.missing.retain(|f| *f != "venue");
         self
     }
 
     pub fn thumbnail_url<T: AsRef<str>>(&mut self, thumbnail_url: T) -> &mut Self {
         self.inner.thumbnail_url = thumbnail_url.as_ref().to_string();
+        self.missing.retain(|f| *f != "thumbnail_url");
         self
     }
 
-    pub fn thumbnail_width(&mut self, thumbnail_width: i64) -> &mut Self {
+    pub fn thumbnail_width(&mut self, thumbnail_width: i32) -> &mut Self {
         self.inner.thumbnail_width = thumbnail_width;
         self
     }
 
-    pub fn thumbnail_height(&mut self, thumbnail_height: i64) -> &mut Self {
+    pub fn thumbnail_height(&mut self, thumbnail_height: i32) -> &mut Self {
         self.inner.thumbnail_height = thumbnail_height;
         self
     }
@@ -1789,6 +3099,7 @@ impl RTDInputInlineQueryResultVenueBuilder {
         input_message_content: T,
     ) -> &mut Self {
         self.inner.input_message_content = input_message_content.as_ref().clone();
+        self.missing.retain(|f| *f != "input_message_content");
         self
     }
 }
@@ -1807,12 +3118,13 @@ impl AsRef<InputInlineQueryResultVenue> for RTDInputInlineQueryResultVenueBuilde
 
 /// Represents a link to a page containing an embedded video player or a video file
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputInlineQueryResultVideo")]
 pub struct InputInlineQueryResultVideo {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Unique identifier of the query result
     id: String,
@@ -1827,11 +3139,11 @@ pub struct InputInlineQueryResultVideo {
     /// MIME type of the content of the video URL, only "text/html" or "video/mp4" are currently supported
     mime_type: String,
     /// Width of the video
-    video_width: i64,
+    video_width: i32,
     /// Height of the video
-    video_height: i64,
+    video_height: i32,
     /// Video duration, in seconds
-    video_duration: i64,
+    video_duration: i32,
     /// The message reply markup. Must be of type replyMarkupInlineKeyboard or null
     reply_markup: ReplyMarkup,
     /// The content of the message to be sent. Must be one of the following types: InputMessageText, InputMessageVideo, InputMessageLocation, InputMessageVenue or InputMessageContact
@@ -1852,6 +3164,22 @@ impl RObject for InputInlineQueryResultVideo {
     }
 }
 
+impl PartialEq for InputInlineQueryResultVideo {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.title == other.title
+            && self.description == other.description
+            && self.thumbnail_url == other.thumbnail_url
+            && self.video_url == other.video_url
+            && self.mime_type == other.mime_type
+            && self.video_width == other.video_width
+            && self.video_height == other.video_height
+            && self.video_duration == other.video_duration
+            && self.reply_markup == other.reply_markup
+            && self.input_message_content == other.input_message_content
+    }
+}
+
 impl TDInputInlineQueryResult for InputInlineQueryResultVideo {}
 
 impl InputInlineQueryResultVideo {
@@ -1859,10 +3187,35 @@ impl InputInlineQueryResultVideo {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputInlineQueryResultVideoBuilder {
-        let mut inner = InputInlineQueryResultVideo::default();
-        inner.td_name = "inputInlineQueryResultVideo".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputInlineQueryResultVideoBuilder { inner }
+        RTDInputInlineQueryResultVideoBuilder {
+            inner: InputInlineQueryResultVideo::default(),
+            missing: vec![
+                "id",
+                "title",
+                "description",
+                "thumbnail_url",
+                "video_url",
+                "mime_type",
+                "video_width",
+                "video_height",
+                "video_duration",
+                "input_message_content",
+            ],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputInlineQueryResultVideoBuilder {
+        RTDInputInlineQueryResultVideoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputInlineQueryResultVideoBuilder {
+        RTDInputInlineQueryResultVideoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn id(&self) -> &String {
@@ -1889,15 +3242,15 @@ impl InputInlineQueryResultVideo {
         &self.mime_type
     }
 
-    pub fn video_width(&self) -> i64 {
+    pub fn video_width(&self) -> i32 {
         self.video_width
     }
 
-    pub fn video_height(&self) -> i64 {
+    pub fn video_height(&self) -> i32 {
         self.video_height
     }
 
-    pub fn video_duration(&self) -> i64 {
+    pub fn video_duration(&self) -> i32 {
         self.video_duration
     }
 
@@ -1908,60 +3261,172 @@ impl InputInlineQueryResultVideo {
     pub fn input_message_content(&self) -> &InputMessageContent {
         &self.input_message_content
     }
+
+    pub fn into_id(self) -> String {
+        self.id
+    }
+
+    pub fn into_title(self) -> String {
+        self.title
+    }
+
+    pub fn into_description(self) -> String {
+        self.description
+    }
+
+    pub fn into_thumbnail_url(self) -> String {
+        self.thumbnail_url
+    }
+
+    pub fn into_video_url(self) -> String {
+        self.video_url
+    }
+
+    pub fn into_mime_type(self) -> String {
+        self.mime_type
+    }
+
+    pub fn into_reply_markup(self) -> ReplyMarkup {
+        self.reply_markup
+    }
+
+    pub fn into_input_message_content(self) -> InputMessageContent {
+        self.input_message_content
+    }
+
+    pub fn with_id<T: AsRef<str>>(mut self, id: T) -> Self {
+        self.id = id.as_ref().to_string();
+        self
+    }
+
+    pub fn with_title<T: AsRef<str>>(mut self, title: T) -> Self {
+        self.title = title.as_ref().to_string();
+        self
+    }
+
+    pub fn with_description<T: AsRef<str>>(mut self, description: T) -> Self {
+        self.description = description.as_ref().to_string();
+        self
+    }
+
+    pub fn with_thumbnail_url<T: AsRef<str>>(mut self, thumbnail_url: T) -> Self {
+        self.thumbnail_url = thumbnail_url.as_ref().to_string();
+        self
+    }
+
+    pub fn with_video_url<T: AsRef<str>>(mut self, video_url: T) -> Self {
+        self.video_url = video_url.as_ref().to_string();
+        self
+    }
+
+    pub fn with_mime_type<T: AsRef<str>>(mut self, mime_type: T) -> Self {
+        self.mime_type = mime_type.as_ref().to_string();
+        self
+    }
+
+    pub fn with_video_width(mut self, video_width: i32) -> Self {
+        self.video_width = video_width;
+        self
+    }
+
+    pub fn with_video_height(mut self, video_height: i32) -> Self {
+        self.video_height = video_height;
+        self
+    }
+
+    pub fn with_video_duration(mut self, video_duration: i32) -> Self {
+        self.video_duration = video_duration;
+        self
+    }
+
+    pub fn with_reply_markup<T: AsRef<ReplyMarkup>>(mut self, reply_markup: T) -> Self {
+        self.reply_markup = reply_markup.as_ref().clone();
+        self
+    }
+
+    pub fn with_input_message_content<T: AsRef<InputMessageContent>>(
+        mut self,
+        input_message_content: T,
+    ) -> Self {
+        self.input_message_content = input_message_content.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputInlineQueryResultVideoBuilder {
     inner: InputInlineQueryResultVideo,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputInlineQueryResultVideoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputInlineQueryResultVideo {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputInlineQueryResultVideo> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputInlineQueryResultVideo",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
         self.inner.id = id.as_ref().to_string();
+        self.missing.retain(|f| *f != "id");
         self
     }
 
     pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
         self.inner.title = title.as_ref().to_string();
+        self.missing.retain(|f| *f != "title");
         self
     }
 
     pub fn description<T: AsRef<str>>(&mut self, description: T) -> &mut Self {
         self.inner.description = description.as_ref().to_string();
+        self.missing.retain(|f| *f != "description");
         self
     }
 
     pub fn thumbnail_url<T: AsRef<str>>(&mut self, thumbnail_url: T) -> &mut Self {
         self.inner.thumbnail_url = thumbnail_url.as_ref().to_string();
+        self.missing.retain(|f| *f != "thumbnail_url");
         self
     }
 
     pub fn video_url<T: AsRef<str>>(&mut self, video_url: T) -> &mut Self {
         self.inner.video_url = video_url.as_ref().to_string();
+        self.missing.retain(|f| *f != "video_url");
         self
     }
 
     pub fn mime_type<T: AsRef<str>>(&mut self, mime_type: T) -> &mut Self {
         self.inner.mime_type = mime_type.as_ref().to_string();
+        self.missing.retain(|f| *f != "mime_type");
         self
     }
 
-    pub fn video_width(&mut self, video_width: i64) -> &mut Self {
+    pub fn video_width(&mut self, video_width: i32) -> &mut Self {
         self.inner.video_width = video_width;
+        self.missing.retain(|f| *f != "video_width");
         self
     }
 
-    pub fn video_height(&mut self, video_height: i64) -> &mut Self {
+    pub fn video_height(&mut self, video_height: i32) -> &mut Self {
         self.inner.video_height = video_height;
+        self.missing.retain(|f| *f != "video_height");
         self
     }
 
-    pub fn video_duration(&mut self, video_duration: i64) -> &mut Self {
+    pub fn video_duration(&mut self, video_duration: i32) -> &mut Self {
         self.inner.video_duration = video_duration;
+        self.missing.retain(|f| *f != "video_duration");
         self
     }
 
@@ -1975,6 +3440,7 @@ impl RTDInputInlineQueryResultVideoBuilder {
         input_message_content: T,
     ) -> &mut Self {
         self.inner.input_message_content = input_message_content.as_ref().clone();
+        self.missing.retain(|f| *f != "input_message_content");
         self
     }
 }
@@ -1993,12 +3459,13 @@ impl AsRef<InputInlineQueryResultVideo> for RTDInputInlineQueryResultVideoBuilde
 
 /// Represents a link to an opus-encoded audio file within an OGG container, single channel audio
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputInlineQueryResultVoiceNote")]
 pub struct InputInlineQueryResultVoiceNote {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Unique identifier of the query result
     id: String,
@@ -2007,7 +3474,7 @@ pub struct InputInlineQueryResultVoiceNote {
     /// The URL of the voice note file
     voice_note_url: String,
     /// Duration of the voice note, in seconds
-    voice_note_duration: i64,
+    voice_note_duration: i32,
     /// The message reply markup. Must be of type replyMarkupInlineKeyboard or null
     reply_markup: ReplyMarkup,
     /// The content of the message to be sent. Must be one of the following types: InputMessageText, InputMessageVoiceNote, InputMessageLocation, InputMessageVenue or InputMessageContact
@@ -2028,6 +3495,17 @@ impl RObject for InputInlineQueryResultVoiceNote {
     }
 }
 
+impl PartialEq for InputInlineQueryResultVoiceNote {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.title == other.title
+            && self.voice_note_url == other.voice_note_url
+            && self.voice_note_duration == other.voice_note_duration
+            && self.reply_markup == other.reply_markup
+            && self.input_message_content == other.input_message_content
+    }
+}
+
 impl TDInputInlineQueryResult for InputInlineQueryResultVoiceNote {}
 
 impl InputInlineQueryResultVoiceNote {
@@ -2035,10 +3513,30 @@ impl InputInlineQueryResultVoiceNote {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputInlineQueryResultVoiceNoteBuilder {
-        let mut inner = InputInlineQueryResultVoiceNote::default();
-        inner.td_name = "inputInlineQueryResultVoiceNote".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputInlineQueryResultVoiceNoteBuilder { inner }
+        RTDInputInlineQueryResultVoiceNoteBuilder {
+            inner: InputInlineQueryResultVoiceNote::default(),
+            missing: vec![
+                "id",
+                "title",
+                "voice_note_url",
+                "voice_note_duration",
+                "input_message_content",
+            ],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputInlineQueryResultVoiceNoteBuilder {
+        RTDInputInlineQueryResultVoiceNoteBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputInlineQueryResultVoiceNoteBuilder {
+        RTDInputInlineQueryResultVoiceNoteBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn id(&self) -> &String {
@@ -2053,7 +3551,7 @@ impl InputInlineQueryResultVoiceNote {
         &self.voice_note_url
     }
 
-    pub fn voice_note_duration(&self) -> i64 {
+    pub fn voice_note_duration(&self) -> i32 {
         self.voice_note_duration
     }
 
@@ -2064,35 +3562,105 @@ impl InputInlineQueryResultVoiceNote {
     pub fn input_message_content(&self) -> &InputMessageContent {
         &self.input_message_content
     }
+
+    pub fn into_id(self) -> String {
+        self.id
+    }
+
+    pub fn into_title(self) -> String {
+        self.title
+    }
+
+    pub fn into_voice_note_url(self) -> String {
+        self.voice_note_url
+    }
+
+    pub fn into_reply_markup(self) -> ReplyMarkup {
+        self.reply_markup
+    }
+
+    pub fn into_input_message_content(self) -> InputMessageContent {
+        self.input_message_content
+    }
+
+    pub fn with_id<T: AsRef<str>>(mut self, id: T) -> Self {
+        self.id = id.as_ref().to_string();
+        self
+    }
+
+    pub fn with_title<T: AsRef<str>>(mut self, title: T) -> Self {
+        self.title = title.as_ref().to_string();
+        self
+    }
+
+    pub fn with_voice_note_url<T: AsRef<str>>(mut self, voice_note_url: T) -> Self {
+        self.voice_note_url = voice_note_url.as_ref().to_string();
+        self
+    }
+
+    pub fn with_voice_note_duration(mut self, voice_note_duration: i32) -> Self {
+        self.voice_note_duration = voice_note_duration;
+        self
+    }
+
+    pub fn with_reply_markup<T: AsRef<ReplyMarkup>>(mut self, reply_markup: T) -> Self {
+        self.reply_markup = reply_markup.as_ref().clone();
+        self
+    }
+
+    pub fn with_input_message_content<T: AsRef<InputMessageContent>>(
+        mut self,
+        input_message_content: T,
+    ) -> Self {
+        self.input_message_content = input_message_content.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputInlineQueryResultVoiceNoteBuilder {
     inner: InputInlineQueryResultVoiceNote,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputInlineQueryResultVoiceNoteBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputInlineQueryResultVoiceNote {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputInlineQueryResultVoiceNote> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputInlineQueryResultVoiceNote",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
         self.inner.id = id.as_ref().to_string();
+        self.missing.retain(|f| *f != "id");
         self
     }
 
     pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
         self.inner.title = title.as_ref().to_string();
+        self.missing.retain(|f| *f != "title");
         self
     }
 
     pub fn voice_note_url<T: AsRef<str>>(&mut self, voice_note_url: T) -> &mut Self {
         self.inner.voice_note_url = voice_note_url.as_ref().to_string();
+        self.missing.retain(|f| *f != "voice_note_url");
         self
     }
 
-    pub fn voice_note_duration(&mut self, voice_note_duration: i64) -> &mut Self {
+    pub fn voice_note_duration(&mut self, voice_note_duration: i32) -> &mut Self {
         self.inner.voice_note_duration = voice_note_duration;
+        self.missing.retain(|f| *f != "voice_note_duration");
         self
     }
 
@@ -2106,6 +3674,7 @@ impl RTDInputInlineQueryResultVoiceNoteBuilder {
         input_message_content: T,
     ) -> &mut Self {
         self.inner.input_message_content = input_message_content.as_ref().clone();
+        self.missing.retain(|f| *f != "input_message_content");
         self
     }
 }
@@ -2121,3 +3690,308 @@ impl AsRef<InputInlineQueryResultVoiceNote> for RTDInputInlineQueryResultVoiceNo
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(InputInlineQueryResult::AnimatedGif(
+            InputInlineQueryResultAnimatedGif::builder()
+                .id("text")
+                .title("text")
+                .thumbnail_url("text")
+                .gif_url("text")
+                .gif_duration(i32::MAX)
+                .gif_width(i32::MAX)
+                .gif_height(i32::MAX)
+                .reply_markup(ReplyMarkup::ForceReply(
+                    ReplyMarkupForceReply::builder().is_personal(true).build(),
+                ))
+                .input_message_content(InputMessageContent::InputMessageForwarded(
+                    InputMessageForwarded::builder()
+                        .from_chat_id(9007199254740991i64)
+                        .message_id(9007199254740991i64)
+                        .in_game_share(true)
+                        .send_copy(true)
+                        .remove_caption(true)
+                        .build(),
+                ))
+                .build(),
+        ));
+        assert_round_trip(InputInlineQueryResult::AnimatedMpeg4(
+            InputInlineQueryResultAnimatedMpeg4::builder()
+                .id("text")
+                .title("text")
+                .thumbnail_url("text")
+                .mpeg4_url("text")
+                .mpeg4_duration(i32::MAX)
+                .mpeg4_width(i32::MAX)
+                .mpeg4_height(i32::MAX)
+                .reply_markup(ReplyMarkup::ForceReply(
+                    ReplyMarkupForceReply::builder().is_personal(true).build(),
+                ))
+                .input_message_content(InputMessageContent::InputMessageForwarded(
+                    InputMessageForwarded::builder()
+                        .from_chat_id(9007199254740991i64)
+                        .message_id(9007199254740991i64)
+                        .in_game_share(true)
+                        .send_copy(true)
+                        .remove_caption(true)
+                        .build(),
+                ))
+                .build(),
+        ));
+        assert_round_trip(InputInlineQueryResult::Article(
+            InputInlineQueryResultArticle::builder()
+                .id("text")
+                .url("text")
+                .hide_url(true)
+                .title("text")
+                .description("text")
+                .thumbnail_url("text")
+                .thumbnail_width(i32::MAX)
+                .thumbnail_height(i32::MAX)
+                .reply_markup(ReplyMarkup::ForceReply(
+                    ReplyMarkupForceReply::builder().is_personal(true).build(),
+                ))
+                .input_message_content(InputMessageContent::InputMessageForwarded(
+                    InputMessageForwarded::builder()
+                        .from_chat_id(9007199254740991i64)
+                        .message_id(9007199254740991i64)
+                        .in_game_share(true)
+                        .send_copy(true)
+                        .remove_caption(true)
+                        .build(),
+                ))
+                .build(),
+        ));
+        assert_round_trip(InputInlineQueryResult::Audio(
+            InputInlineQueryResultAudio::builder()
+                .id("text")
+                .title("text")
+                .performer("text")
+                .audio_url("text")
+                .audio_duration(i32::MAX)
+                .reply_markup(ReplyMarkup::ForceReply(
+                    ReplyMarkupForceReply::builder().is_personal(true).build(),
+                ))
+                .input_message_content(InputMessageContent::InputMessageForwarded(
+                    InputMessageForwarded::builder()
+                        .from_chat_id(9007199254740991i64)
+                        .message_id(9007199254740991i64)
+                        .in_game_share(true)
+                        .send_copy(true)
+                        .remove_caption(true)
+                        .build(),
+                ))
+                .build(),
+        ));
+        assert_round_trip(InputInlineQueryResult::Contact(
+            InputInlineQueryResultContact::builder()
+                .id("text")
+                .contact(
+                    Contact::builder()
+                        .phone_number("text")
+                        .first_name("text")
+                        .last_name("text")
+                        .vcard("text")
+                        .user_id(i32::MAX)
+                        .build(),
+                )
+                .thumbnail_url("text")
+                .thumbnail_width(i32::MAX)
+                .thumbnail_height(i32::MAX)
+                .reply_markup(ReplyMarkup::ForceReply(
+                    ReplyMarkupForceReply::builder().is_personal(true).build(),
+                ))
+                .input_message_content(InputMessageContent::InputMessageForwarded(
+                    InputMessageForwarded::builder()
+                        .from_chat_id(9007199254740991i64)
+                        .message_id(9007199254740991i64)
+                        .in_game_share(true)
+                        .send_copy(true)
+                        .remove_caption(true)
+                        .build(),
+                ))
+                .build(),
+        ));
+        assert_round_trip(InputInlineQueryResult::Document(
+            InputInlineQueryResultDocument::builder()
+                .id("text")
+                .title("text")
+                .description("text")
+                .document_url("text")
+                .mime_type("text")
+                .thumbnail_url("text")
+                .thumbnail_width(i32::MAX)
+                .thumbnail_height(i32::MAX)
+                .reply_markup(ReplyMarkup::ForceReply(
+                    ReplyMarkupForceReply::builder().is_personal(true).build(),
+                ))
+                .input_message_content(InputMessageContent::InputMessageForwarded(
+                    InputMessageForwarded::builder()
+                        .from_chat_id(9007199254740991i64)
+                        .message_id(9007199254740991i64)
+                        .in_game_share(true)
+                        .send_copy(true)
+                        .remove_caption(true)
+                        .build(),
+                ))
+                .build(),
+        ));
+        assert_round_trip(InputInlineQueryResult::Game(
+            InputInlineQueryResultGame::builder()
+                .id("text")
+                .game_short_name("text")
+                .reply_markup(ReplyMarkup::ForceReply(
+                    ReplyMarkupForceReply::builder().is_personal(true).build(),
+                ))
+                .build(),
+        ));
+        assert_round_trip(InputInlineQueryResult::Location(
+            InputInlineQueryResultLocation::builder()
+                .id("text")
+                .location(Location::builder().latitude(1.5).longitude(1.5).build())
+                .live_period(i32::MAX)
+                .title("text")
+                .thumbnail_url("text")
+                .thumbnail_width(i32::MAX)
+                .thumbnail_height(i32::MAX)
+                .reply_markup(ReplyMarkup::ForceReply(
+                    ReplyMarkupForceReply::builder().is_personal(true).build(),
+                ))
+                .input_message_content(InputMessageContent::InputMessageForwarded(
+                    InputMessageForwarded::builder()
+                        .from_chat_id(9007199254740991i64)
+                        .message_id(9007199254740991i64)
+                        .in_game_share(true)
+                        .send_copy(true)
+                        .remove_caption(true)
+                        .build(),
+                ))
+                .build(),
+        ));
+        assert_round_trip(InputInlineQueryResult::Photo(
+            InputInlineQueryResultPhoto::builder()
+                .id("text")
+                .title("text")
+                .description("text")
+                .thumbnail_url("text")
+                .photo_url("text")
+                .photo_width(i32::MAX)
+                .photo_height(i32::MAX)
+                .reply_markup(ReplyMarkup::ForceReply(
+                    ReplyMarkupForceReply::builder().is_personal(true).build(),
+                ))
+                .input_message_content(InputMessageContent::InputMessageForwarded(
+                    InputMessageForwarded::builder()
+                        .from_chat_id(9007199254740991i64)
+                        .message_id(9007199254740991i64)
+                        .in_game_share(true)
+                        .send_copy(true)
+                        .remove_caption(true)
+                        .build(),
+                ))
+                .build(),
+        ));
+        assert_round_trip(InputInlineQueryResult::Sticker(
+            InputInlineQueryResultSticker::builder()
+                .id("text")
+                .thumbnail_url("text")
+                .sticker_url("text")
+                .sticker_width(i32::MAX)
+                .sticker_height(i32::MAX)
+                .reply_markup(ReplyMarkup::ForceReply(
+                    ReplyMarkupForceReply::builder().is_personal(true).build(),
+                ))
+                .input_message_content(InputMessageContent::InputMessageForwarded(
+                    InputMessageForwarded::builder()
+                        .from_chat_id(9007199254740991i64)
+                        .message_id(9007199254740991i64)
+                        .in_game_share(true)
+                        .send_copy(true)
+                        .remove_caption(true)
+                        .build(),
+                ))
+                .build(),
+        ));
+        assert_round_trip(InputInlineQueryResult::Venue(
+            InputInlineQueryResultVenue::builder()
+                .id("text")
+                .venue(
+                    Venue::builder()
+                        .location(Location::builder().latitude(1.5).longitude(1.5).build())
+                        .title("text")
+                        .address("text")
+                        .provider("text")
+                        .id("text")
+                        .type_("text")
+                        .build(),
+                )
+                .thumbnail_url("text")
+                .thumbnail_width(i32::MAX)
+                .thumbnail_height(i32::MAX)
+                .reply_markup(ReplyMarkup::ForceReply(
+                    ReplyMarkupForceReply::builder().is_personal(true).build(),
+                ))
+                .input_message_content(InputMessageContent::InputMessageForwarded(
+                    InputMessageForwarded::builder()
+                        .from_chat_id(9007199254740991i64)
+                        .message_id(9007199254740991i64)
+                        .in_game_share(true)
+                        .send_copy(true)
+                        .remove_caption(true)
+                        .build(),
+                ))
+                .build(),
+        ));
+        assert_round_trip(InputInlineQueryResult::Video(
+            InputInlineQueryResultVideo::builder()
+                .id("text")
+                .title("text")
+                .description("text")
+                .thumbnail_url("text")
+                .video_url("text")
+                .mime_type("text")
+                .video_width(i32::MAX)
+                .video_height(i32::MAX)
+                .video_duration(i32::MAX)
+                .reply_markup(ReplyMarkup::ForceReply(
+                    ReplyMarkupForceReply::builder().is_personal(true).build(),
+                ))
+                .input_message_content(InputMessageContent::InputMessageForwarded(
+                    InputMessageForwarded::builder()
+                        .from_chat_id(9007199254740991i64)
+                        .message_id(9007199254740991i64)
+                        .in_game_share(true)
+                        .send_copy(true)
+                        .remove_caption(true)
+                        .build(),
+                ))
+                .build(),
+        ));
+        assert_round_trip(InputInlineQueryResult::VoiceNote(
+            InputInlineQueryResultVoiceNote::builder()
+                .id("text")
+                .title("text")
+                .voice_note_url("text")
+                .voice_note_duration(i32::MAX)
+                .reply_markup(ReplyMarkup::ForceReply(
+                    ReplyMarkupForceReply::builder().is_personal(true).build(),
+                ))
+                .input_message_content(InputMessageContent::InputMessageForwarded(
+                    InputMessageForwarded::builder()
+                        .from_chat_id(9007199254740991i64)
+                        .message_id(9007199254740991i64)
+                        .in_game_share(true)
+                        .send_copy(true)
+                        .remove_caption(true)
+                        .build(),
+                ))
+                .build(),
+        ));
+    }
+}
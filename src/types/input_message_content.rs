@@ -1,85 +1,96 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | The content of a message to send
 pub trait TDInputMessageContent: Debug + RObject {}
 
 /// The content of a message to send
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "@type")]
 pub enum InputMessageContent {
-    #[doc(hidden)]
-    _Default(()),
     /// An animation message (GIF-style).
+    #[serde(rename = "inputMessageAnimation")]
     InputMessageAnimation(InputMessageAnimation),
     /// An audio message
+    #[serde(rename = "inputMessageAudio")]
     InputMessageAudio(InputMessageAudio),
     /// A message containing a user contact
+    #[serde(rename = "inputMessageContact")]
     InputMessageContact(InputMessageContact),
     /// A document message (general file)
+    #[serde(rename = "inputMessageDocument")]
     InputMessageDocument(InputMessageDocument),
     /// A forwarded message
+    #[serde(rename = "inputMessageForwarded")]
     InputMessageForwarded(InputMessageForwarded),
     /// A message with a game; not supported for channels or secret chats
+    #[serde(rename = "inputMessageGame")]
     InputMessageGame(InputMessageGame),
     /// A message with an invoice; can be used only by bots and only in private chats
+    #[serde(rename = "inputMessageInvoice")]
     InputMessageInvoice(InputMessageInvoice),
     /// A message with a location
+    #[serde(rename = "inputMessageLocation")]
     InputMessageLocation(InputMessageLocation),
     /// A photo message
+    #[serde(rename = "inputMessagePhoto")]
     InputMessagePhoto(InputMessagePhoto),
     /// A message with a poll. Polls can't be sent to secret chats. Polls can be sent only to a private chat with a bot
+    #[serde(rename = "inputMessagePoll")]
     InputMessagePoll(InputMessagePoll),
     /// A sticker message
+    #[serde(rename = "inputMessageSticker")]
     InputMessageSticker(InputMessageSticker),
     /// A text message
+    #[serde(rename = "inputMessageText")]
     InputMessageText(InputMessageText),
     /// A message with information about a venue
+    #[serde(rename = "inputMessageVenue")]
     InputMessageVenue(InputMessageVenue),
     /// A video message
+    #[serde(rename = "inputMessageVideo")]
     InputMessageVideo(InputMessageVideo),
     /// A video note message
+    #[serde(rename = "inputMessageVideoNote")]
     InputMessageVideoNote(InputMessageVideoNote),
     /// A voice note message
+    #[serde(rename = "inputMessageVoiceNote")]
     InputMessageVoiceNote(InputMessageVoiceNote),
 }
 
+/// Default value is default of [InputMessageForwarded], builders still require the field to be set
 impl Default for InputMessageContent {
     fn default() -> Self {
-        InputMessageContent::_Default(())
+        InputMessageContent::InputMessageForwarded(InputMessageForwarded::default())
     }
 }
 
-impl<'de> Deserialize<'de> for InputMessageContent {
-    fn deserialize<D>(deserializer: D) -> Result<InputMessageContent, D::Error>
+impl Serialize for InputMessageContent {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          InputMessageContent,
-          (inputMessageAnimation, InputMessageAnimation);
-          (inputMessageAudio, InputMessageAudio);
-          (inputMessageContact, InputMessageContact);
-          (inputMessageDocument, InputMessageDocument);
-          (inputMessageForwarded, InputMessageForwarded);
-          (inputMessageGame, InputMessageGame);
-          (inputMessageInvoice, InputMessageInvoice);
-          (inputMessageLocation, InputMessageLocation);
-          (inputMessagePhoto, InputMessagePhoto);
-          (inputMessagePoll, InputMessagePoll);
-          (inputMessageSticker, InputMessageSticker);
-          (inputMessageText, InputMessageText);
-          (inputMessageVenue, InputMessageVenue);
-          (inputMessageVideo, InputMessageVideo);
-          (inputMessageVideoNote, InputMessageVideoNote);
-          (inputMessageVoiceNote, InputMessageVoiceNote);
-
-        )(deserializer)
+        match self {
+            InputMessageContent::InputMessageAnimation(t) => t.serialize(serializer),
+            InputMessageContent::InputMessageAudio(t) => t.serialize(serializer),
+            InputMessageContent::InputMessageContact(t) => t.serialize(serializer),
+            InputMessageContent::InputMessageDocument(t) => t.serialize(serializer),
+            InputMessageContent::InputMessageForwarded(t) => t.serialize(serializer),
+            InputMessageContent::InputMessageGame(t) => t.serialize(serializer),
+            InputMessageContent::InputMessageInvoice(t) => t.serialize(serializer),
+            InputMessageContent::InputMessageLocation(t) => t.serialize(serializer),
+            InputMessageContent::InputMessagePhoto(t) => t.serialize(serializer),
+            InputMessageContent::InputMessagePoll(t) => t.serialize(serializer),
+            InputMessageContent::InputMessageSticker(t) => t.serialize(serializer),
+            InputMessageContent::InputMessageText(t) => t.serialize(serializer),
+            InputMessageContent::InputMessageVenue(t) => t.serialize(serializer),
+            InputMessageContent::InputMessageVideo(t) => t.serialize(serializer),
+            InputMessageContent::InputMessageVideoNote(t) => t.serialize(serializer),
+            InputMessageContent::InputMessageVoiceNote(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -103,8 +114,6 @@ impl RObject for InputMessageContent {
             InputMessageContent::InputMessageVideo(t) => t.td_name(),
             InputMessageContent::InputMessageVideoNote(t) => t.td_name(),
             InputMessageContent::InputMessageVoiceNote(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -126,8 +135,6 @@ impl RObject for InputMessageContent {
             InputMessageContent::InputMessageVideo(t) => t.extra(),
             InputMessageContent::InputMessageVideoNote(t) => t.extra(),
             InputMessageContent::InputMessageVoiceNote(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -139,10 +146,6 @@ impl InputMessageContent {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, InputMessageContent::_Default(_))
-    }
 }
 
 impl AsRef<InputMessageContent> for InputMessageContent {
@@ -153,23 +156,24 @@ impl AsRef<InputMessageContent> for InputMessageContent {
 
 /// An animation message (GIF-style).
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputMessageAnimation")]
 pub struct InputMessageAnimation {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Animation file to be sent
     animation: InputFile,
     /// Animation thumbnail, if available
     thumbnail: InputThumbnail,
     /// Duration of the animation, in seconds
-    duration: i64,
+    duration: i32,
     /// Width of the animation; may be replaced by the server
-    width: i64,
+    width: i32,
     /// Height of the animation; may be replaced by the server
-    height: i64,
+    height: i32,
     /// Animation caption; 0-GetOption("message_caption_length_max") characters
     caption: FormattedText,
 }
@@ -188,6 +192,30 @@ impl RObject for InputMessageAnimation {
     }
 }
 
+impl PartialEq for InputMessageAnimation {
+    fn eq(&self, other: &Self) -> bool {
+        self.animation == other.animation
+            && self.thumbnail == other.thumbnail
+            && self.duration == other.duration
+            && self.width == other.width
+            && self.height == other.height
+            && self.caption == other.caption
+    }
+}
+
+impl Eq for InputMessageAnimation {}
+
+impl std::hash::Hash for InputMessageAnimation {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.animation, state);
+        std::hash::Hash::hash(&self.thumbnail, state);
+        std::hash::Hash::hash(&self.duration, state);
+        std::hash::Hash::hash(&self.width, state);
+        std::hash::Hash::hash(&self.height, state);
+        std::hash::Hash::hash(&self.caption, state);
+    }
+}
+
 impl TDInputMessageContent for InputMessageAnimation {}
 
 impl InputMessageAnimation {
@@ -195,10 +223,24 @@ impl InputMessageAnimation {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputMessageAnimationBuilder {
-        let mut inner = InputMessageAnimation::default();
-        inner.td_name = "inputMessageAnimation".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputMessageAnimationBuilder { inner }
+        RTDInputMessageAnimationBuilder {
+            inner: InputMessageAnimation::default(),
+            missing: vec!["animation", "thumbnail", "duration", "width", "height"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputMessageAnimationBuilder {
+        RTDInputMessageAnimationBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputMessageAnimationBuilder {
+        RTDInputMessageAnimationBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn animation(&self) -> &InputFile {
@@ -209,55 +251,115 @@ impl InputMessageAnimation {
         &self.thumbnail
     }
 
-    pub fn duration(&self) -> i64 {
+    pub fn duration(&self) -> i32 {
         self.duration
     }
 
-    pub fn width(&self) -> i64 {
+    pub fn width(&self) -> i32 {
         self.width
     }
 
-    pub fn height(&self) -> i64 {
+    pub fn height(&self) -> i32 {
         self.height
     }
 
     pub fn caption(&self) -> &FormattedText {
         &self.caption
     }
+
+    pub fn into_animation(self) -> InputFile {
+        self.animation
+    }
+
+    pub fn into_thumbnail(self) -> InputThumbnail {
+        self.thumbnail
+    }
+
+    pub fn into_caption(self) -> FormattedText {
+        self.caption
+    }
+
+    pub fn with_animation<T: AsRef<InputFile>>(mut self, animation: T) -> Self {
+        self.animation = animation.as_ref().clone();
+        self
+    }
+
+    pub fn with_thumbnail<T: AsRef<InputThumbnail>>(mut self, thumbnail: T) -> Self {
+        self.thumbnail = thumbnail.as_ref().clone();
+        self
+    }
+
+    pub fn with_duration(mut self, duration: i32) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    pub fn with_width(mut self, width: i32) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn with_height(mut self, height: i32) -> Self {
+        self.height = height;
+        self
+    }
+
+    pub fn with_caption<T: AsRef<FormattedText>>(mut self, caption: T) -> Self {
+        self.caption = caption.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputMessageAnimationBuilder {
     inner: InputMessageAnimation,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputMessageAnimationBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputMessageAnimation {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputMessageAnimation> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputMessageAnimation",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn animation<T: AsRef<InputFile>>(&mut self, animation: T) -> &mut Self {
         self.inner.animation = animation.as_ref().clone();
+        self.missing.retain(|f| *f != "animation");
         self
     }
 
     pub fn thumbnail<T: AsRef<InputThumbnail>>(&mut self, thumbnail: T) -> &mut Self {
         self.inner.thumbnail = thumbnail.as_ref().clone();
+        self.missing.retain(|f| *f != "thumbnail");
         self
     }
 
-    pub fn duration(&mut self, duration: i64) -> &mut Self {
+    pub fn duration(&mut self, duration: i32) -> &mut Self {
         self.inner.duration = duration;
+        self.missing.retain(|f| *f != "duration");
         self
     }
 
-    pub fn width(&mut self, width: i64) -> &mut Self {
+    pub fn width(&mut self, width: i32) -> &mut Self {
         self.inner.width = width;
+        self.missing.retain(|f| *f != "width");
         self
     }
 
-    pub fn height(&mut self, height: i64) -> &mut Self {
+    pub fn height(&mut self, height: i32) -> &mut Self {
         self.inner.height = height;
+        self.missing.retain(|f| *f != "height");
         self
     }
 
@@ -281,19 +383,20 @@ impl AsRef<InputMessageAnimation> for RTDInputMessageAnimationBuilder {
 
 /// An audio message
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputMessageAudio")]
 pub struct InputMessageAudio {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Audio file to be sent
     audio: InputFile,
     /// Thumbnail of the cover for the album, if available
     album_cover_thumbnail: InputThumbnail,
     /// Duration of the audio, in seconds; may be replaced by the server
-    duration: i64,
+    duration: i32,
     /// Title of the audio; 0-64 characters; may be replaced by the server
     title: String,
     /// Performer of the audio; 0-64 characters, may be replaced by the server
@@ -316,6 +419,30 @@ impl RObject for InputMessageAudio {
     }
 }
 
+impl PartialEq for InputMessageAudio {
+    fn eq(&self, other: &Self) -> bool {
+        self.audio == other.audio
+            && self.album_cover_thumbnail == other.album_cover_thumbnail
+            && self.duration == other.duration
+            && self.title == other.title
+            && self.performer == other.performer
+            && self.caption == other.caption
+    }
+}
+
+impl Eq for InputMessageAudio {}
+
+impl std::hash::Hash for InputMessageAudio {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.audio, state);
+        std::hash::Hash::hash(&self.album_cover_thumbnail, state);
+        std::hash::Hash::hash(&self.duration, state);
+        std::hash::Hash::hash(&self.title, state);
+        std::hash::Hash::hash(&self.performer, state);
+        std::hash::Hash::hash(&self.caption, state);
+    }
+}
+
 impl TDInputMessageContent for InputMessageAudio {}
 
 impl InputMessageAudio {
@@ -323,10 +450,24 @@ impl InputMessageAudio {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputMessageAudioBuilder {
-        let mut inner = InputMessageAudio::default();
-        inner.td_name = "inputMessageAudio".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputMessageAudioBuilder { inner }
+        RTDInputMessageAudioBuilder {
+            inner: InputMessageAudio::default(),
+            missing: vec!["audio", "album_cover_thumbnail", "duration"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputMessageAudioBuilder {
+        RTDInputMessageAudioBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputMessageAudioBuilder {
+        RTDInputMessageAudioBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn audio(&self) -> &InputFile {
@@ -337,7 +478,7 @@ impl InputMessageAudio {
         &self.album_cover_thumbnail
     }
 
-    pub fn duration(&self) -> i64 {
+    pub fn duration(&self) -> i32 {
         self.duration
     }
 
@@ -352,20 +493,87 @@ impl InputMessageAudio {
     pub fn caption(&self) -> &FormattedText {
         &self.caption
     }
+
+    pub fn into_audio(self) -> InputFile {
+        self.audio
+    }
+
+    pub fn into_album_cover_thumbnail(self) -> InputThumbnail {
+        self.album_cover_thumbnail
+    }
+
+    pub fn into_title(self) -> String {
+        self.title
+    }
+
+    pub fn into_performer(self) -> String {
+        self.performer
+    }
+
+    pub fn into_caption(self) -> FormattedText {
+        self.caption
+    }
+
+    pub fn with_audio<T: AsRef<InputFile>>(mut self, audio: T) -> Self {
+        self.audio = audio.as_ref().clone();
+        self
+    }
+
+    pub fn with_album_cover_thumbnail<T: AsRef<InputThumbnail>>(
+        mut self,
+        album_cover_thumbnail: T,
+    ) -> Self {
+        self.album_cover_thumbnail = album_cover_thumbnail.as_ref().clone();
+        self
+    }
+
+    pub fn with_duration(mut self, duration: i32) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    pub fn with_title<T: AsRef<str>>(mut self, title: T) -> Self {
+        self.title = title.as_ref().to_string();
+        self
+    }
+
+    pub fn with_performer<T: AsRef<str>>(mut self, performer: T) -> Self {
+        self.performer = performer.as_ref().to_string();
+        self
+    }
+
+    pub fn with_caption<T: AsRef<FormattedText>>(mut self, caption: T) -> Self {
+        self.caption = caption.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputMessageAudioBuilder {
     inner: InputMessageAudio,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputMessageAudioBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputMessageAudio {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputMessageAudio> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputMessageAudio",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn audio<T: AsRef<InputFile>>(&mut self, audio: T) -> &mut Self {
         self.inner.audio = audio.as_ref().clone();
+        self.missing.retain(|f| *f != "audio");
         self
     }
 
@@ -374,11 +582,13 @@ impl RTDInputMessageAudioBuilder {
         album_cover_thumbnail: T,
     ) -> &mut Self {
         self.inner.album_cover_thumbnail = album_cover_thumbnail.as_ref().clone();
+        self.missing.retain(|f| *f != "album_cover_thumbnail");
         self
     }
 
-    pub fn duration(&mut self, duration: i64) -> &mut Self {
+    pub fn duration(&mut self, duration: i32) -> &mut Self {
         self.inner.duration = duration;
+        self.missing.retain(|f| *f != "duration");
         self
     }
 
@@ -412,12 +622,13 @@ impl AsRef<InputMessageAudio> for RTDInputMessageAudioBuilder {
 
 /// A message containing a user contact
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputMessageContact")]
 pub struct InputMessageContact {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Contact to send
     contact: Contact,
@@ -437,6 +648,20 @@ impl RObject for InputMessageContact {
     }
 }
 
+impl PartialEq for InputMessageContact {
+    fn eq(&self, other: &Self) -> bool {
+        self.contact == other.contact
+    }
+}
+
+impl Eq for InputMessageContact {}
+
+impl std::hash::Hash for InputMessageContact {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.contact, state);
+    }
+}
+
 impl TDInputMessageContent for InputMessageContact {}
 
 impl InputMessageContact {
@@ -444,29 +669,66 @@ impl InputMessageContact {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputMessageContactBuilder {
-        let mut inner = InputMessageContact::default();
-        inner.td_name = "inputMessageContact".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputMessageContactBuilder { inner }
+        RTDInputMessageContactBuilder {
+            inner: InputMessageContact::default(),
+            missing: vec!["contact"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputMessageContactBuilder {
+        RTDInputMessageContactBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputMessageContactBuilder {
+        RTDInputMessageContactBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn contact(&self) -> &Contact {
         &self.contact
     }
+
+    pub fn into_contact(self) -> Contact {
+        self.contact
+    }
+
+    pub fn with_contact<T: AsRef<Contact>>(mut self, contact: T) -> Self {
+        self.contact = contact.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputMessageContactBuilder {
     inner: InputMessageContact,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputMessageContactBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputMessageContact {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputMessageContact> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputMessageContact",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn contact<T: AsRef<Contact>>(&mut self, contact: T) -> &mut Self {
         self.inner.contact = contact.as_ref().clone();
+        self.missing.retain(|f| *f != "contact");
         self
     }
 }
@@ -485,12 +747,13 @@ impl AsRef<InputMessageContact> for RTDInputMessageContactBuilder {
 
 /// A document message (general file)
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputMessageDocument")]
 pub struct InputMessageDocument {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Document to be sent
     document: InputFile,
@@ -514,6 +777,24 @@ impl RObject for InputMessageDocument {
     }
 }
 
+impl PartialEq for InputMessageDocument {
+    fn eq(&self, other: &Self) -> bool {
+        self.document == other.document
+            && self.thumbnail == other.thumbnail
+            && self.caption == other.caption
+    }
+}
+
+impl Eq for InputMessageDocument {}
+
+impl std::hash::Hash for InputMessageDocument {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.document, state);
+        std::hash::Hash::hash(&self.thumbnail, state);
+        std::hash::Hash::hash(&self.caption, state);
+    }
+}
+
 impl TDInputMessageContent for InputMessageDocument {}
 
 impl InputMessageDocument {
@@ -521,10 +802,24 @@ impl InputMessageDocument {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputMessageDocumentBuilder {
-        let mut inner = InputMessageDocument::default();
-        inner.td_name = "inputMessageDocument".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputMessageDocumentBuilder { inner }
+        RTDInputMessageDocumentBuilder {
+            inner: InputMessageDocument::default(),
+            missing: vec!["document", "thumbnail"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputMessageDocumentBuilder {
+        RTDInputMessageDocumentBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputMessageDocumentBuilder {
+        RTDInputMessageDocumentBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn document(&self) -> &InputFile {
@@ -538,25 +833,67 @@ impl InputMessageDocument {
     pub fn caption(&self) -> &FormattedText {
         &self.caption
     }
+
+    pub fn into_document(self) -> InputFile {
+        self.document
+    }
+
+    pub fn into_thumbnail(self) -> InputThumbnail {
+        self.thumbnail
+    }
+
+    pub fn into_caption(self) -> FormattedText {
+        self.caption
+    }
+
+    pub fn with_document<T: AsRef<InputFile>>(mut self, document: T) -> Self {
+        self.document = document.as_ref().clone();
+        self
+    }
+
+    pub fn with_thumbnail<T: AsRef<InputThumbnail>>(mut self, thumbnail: T) -> Self {
+        self.thumbnail = thumbnail.as_ref().clone();
+        self
+    }
+
+    pub fn with_caption<T: AsRef<FormattedText>>(mut self, caption: T) -> Self {
+        self.caption = caption.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputMessageDocumentBuilder {
     inner: InputMessageDocument,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputMessageDocumentBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputMessageDocument {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputMessageDocument> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputMessageDocument",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn document<T: AsRef<InputFile>>(&mut self, document: T) -> &mut Self {
         self.inner.document = document.as_ref().clone();
+        self.missing.retain(|f| *f != "document");
         self
     }
 
     pub fn thumbnail<T: AsRef<InputThumbnail>>(&mut self, thumbnail: T) -> &mut Self {
         self.inner.thumbnail = thumbnail.as_ref().clone();
+        self.missing.retain(|f| *f != "thumbnail");
         self
     }
 
@@ -580,17 +917,18 @@ impl AsRef<InputMessageDocument> for RTDInputMessageDocumentBuilder {
 
 /// A forwarded message
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputMessageForwarded")]
 pub struct InputMessageForwarded {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Identifier for the chat this forwarded message came from
-    from_chat_id: i64,
+    from_chat_id: ChatId,
     /// Identifier of the message to forward
-    message_id: i64,
+    message_id: MessageId,
     /// True, if a game message should be shared within a launched game; applies only to game messages
     in_game_share: bool,
     /// True, if content of the message needs to be copied without a link to the original message. Always true if the message is forwarded to a secret chat
@@ -613,6 +951,28 @@ impl RObject for InputMessageForwarded {
     }
 }
 
+impl PartialEq for InputMessageForwarded {
+    fn eq(&self, other: &Self) -> bool {
+        self.from_chat_id == other.from_chat_id
+            && self.message_id == other.message_id
+            && self.in_game_share == other.in_game_share
+            && self.send_copy == other.send_copy
+            && self.remove_caption == other.remove_caption
+    }
+}
+
+impl Eq for InputMessageForwarded {}
+
+impl std::hash::Hash for InputMessageForwarded {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.from_chat_id, state);
+        std::hash::Hash::hash(&self.message_id, state);
+        std::hash::Hash::hash(&self.in_game_share, state);
+        std::hash::Hash::hash(&self.send_copy, state);
+        std::hash::Hash::hash(&self.remove_caption, state);
+    }
+}
+
 impl TDInputMessageContent for InputMessageForwarded {}
 
 impl InputMessageForwarded {
@@ -620,17 +980,31 @@ impl InputMessageForwarded {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputMessageForwardedBuilder {
-        let mut inner = InputMessageForwarded::default();
-        inner.td_name = "inputMessageForwarded".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputMessageForwardedBuilder { inner }
+        RTDInputMessageForwardedBuilder {
+            inner: InputMessageForwarded::default(),
+            missing: vec!["from_chat_id", "message_id"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputMessageForwardedBuilder {
+        RTDInputMessageForwardedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputMessageForwardedBuilder {
+        RTDInputMessageForwardedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn from_chat_id(&self) -> i64 {
+    pub fn from_chat_id(&self) -> ChatId {
         self.from_chat_id
     }
 
-    pub fn message_id(&self) -> i64 {
+    pub fn message_id(&self) -> MessageId {
         self.message_id
     }
 
@@ -645,25 +1019,65 @@ impl InputMessageForwarded {
     pub fn remove_caption(&self) -> bool {
         self.remove_caption
     }
+
+    pub fn with_from_chat_id<T: Into<ChatId>>(mut self, from_chat_id: T) -> Self {
+        self.from_chat_id = from_chat_id.into();
+        self
+    }
+
+    pub fn with_message_id<T: Into<MessageId>>(mut self, message_id: T) -> Self {
+        self.message_id = message_id.into();
+        self
+    }
+
+    pub fn with_in_game_share(mut self, in_game_share: bool) -> Self {
+        self.in_game_share = in_game_share;
+        self
+    }
+
+    pub fn with_send_copy(mut self, send_copy: bool) -> Self {
+        self.send_copy = send_copy;
+        self
+    }
+
+    pub fn with_remove_caption(mut self, remove_caption: bool) -> Self {
+        self.remove_caption = remove_caption;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputMessageForwardedBuilder {
     inner: InputMessageForwarded,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputMessageForwardedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputMessageForwarded {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputMessageForwarded> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputMessageForwarded",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn from_chat_id(&mut self, from_chat_id: i64) -> &mut Self {
-        self.inner.from_chat_id = from_chat_id;
+    pub fn from_chat_id<T: Into<ChatId>>(&mut self, from_chat_id: T) -> &mut Self {
+        self.inner.from_chat_id = from_chat_id.into();
+        self.missing.retain(|f| *f != "from_chat_id");
         self
     }
 
-    pub fn message_id(&mut self, message_id: i64) -> &mut Self {
-        self.inner.message_id = message_id;
+    pub fn message_id<T: Into<MessageId>>(&mut self, message_id: T) -> &mut Self {
+        self.inner.message_id = message_id.into();
+        self.missing.retain(|f| *f != "message_id");
         self
     }
 
@@ -697,15 +1111,16 @@ impl AsRef<InputMessageForwarded> for RTDInputMessageForwardedBuilder {
 
 /// A message with a game; not supported for channels or secret chats
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputMessageGame")]
 pub struct InputMessageGame {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// User identifier of the bot that owns the game
-    bot_user_id: i64,
+    bot_user_id: UserId,
     /// Short name of the game
     game_short_name: String,
 }
@@ -724,6 +1139,21 @@ impl RObject for InputMessageGame {
     }
 }
 
+impl PartialEq for InputMessageGame {
+    fn eq(&self, other: &Self) -> bool {
+        self.bot_user_id == other.bot_user_id && self.game_short_name == other.game_short_name
+    }
+}
+
+impl Eq for InputMessageGame {}
+
+impl std::hash::Hash for InputMessageGame {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.bot_user_id, state);
+        std::hash::Hash::hash(&self.game_short_name, state);
+    }
+}
+
 impl TDInputMessageContent for InputMessageGame {}
 
 impl InputMessageGame {
@@ -731,38 +1161,81 @@ impl InputMessageGame {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputMessageGameBuilder {
-        let mut inner = InputMessageGame::default();
-        inner.td_name = "inputMessageGame".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputMessageGameBuilder { inner }
+        RTDInputMessageGameBuilder {
+            inner: InputMessageGame::default(),
+            missing: vec!["bot_user_id", "game_short_name"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputMessageGameBuilder {
+        RTDInputMessageGameBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputMessageGameBuilder {
+        RTDInputMessageGameBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn bot_user_id(&self) -> i64 {
+    pub fn bot_user_id(&self) -> UserId {
         self.bot_user_id
     }
 
     pub fn game_short_name(&self) -> &String {
         &self.game_short_name
     }
+
+    pub fn into_game_short_name(self) -> String {
+        self.game_short_name
+    }
+
+    pub fn with_bot_user_id<T: Into<UserId>>(mut self, bot_user_id: T) -> Self {
+        self.bot_user_id = bot_user_id.into();
+        self
+    }
+
+    pub fn with_game_short_name<T: AsRef<str>>(mut self, game_short_name: T) -> Self {
+        self.game_short_name = game_short_name.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputMessageGameBuilder {
     inner: InputMessageGame,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputMessageGameBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputMessageGame {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputMessageGame> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputMessageGame",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn bot_user_id(&mut self, bot_user_id: i64) -> &mut Self {
-        self.inner.bot_user_id = bot_user_id;
+    pub fn bot_user_id<T: Into<UserId>>(&mut self, bot_user_id: T) -> &mut Self {
+        self.inner.bot_user_id = bot_user_id.into();
+        self.missing.retain(|f| *f != "bot_user_id");
         self
     }
 
     pub fn game_short_name<T: AsRef<str>>(&mut self, game_short_name: T) -> &mut Self {
         self.inner.game_short_name = game_short_name.as_ref().to_string();
+        self.missing.retain(|f| *f != "game_short_name");
         self
     }
 }
@@ -781,12 +1254,13 @@ impl AsRef<InputMessageGame> for RTDInputMessageGameBuilder {
 
 /// A message with an invoice; can be used only by bots and only in private chats
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputMessageInvoice")]
 pub struct InputMessageInvoice {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Invoice
     invoice: Invoice,
@@ -797,13 +1271,14 @@ pub struct InputMessageInvoice {
     /// Product photo URL; optional
     photo_url: String,
     /// Product photo size
-    photo_size: i64,
+    photo_size: i32,
     /// Product photo width
-    photo_width: i64,
+    photo_width: i32,
     /// Product photo height
-    photo_height: i64,
+    photo_height: i32,
     /// The invoice payload
-    payload: String,
+    #[serde(with = "super::_common::bytes")]
+    payload: Vec<u8>,
     /// Payment provider token
     provider_token: String,
     /// JSON-encoded data about the invoice, which will be shared with the payment provider
@@ -826,6 +1301,40 @@ impl RObject for InputMessageInvoice {
     }
 }
 
+impl PartialEq for InputMessageInvoice {
+    fn eq(&self, other: &Self) -> bool {
+        self.invoice == other.invoice
+            && self.title == other.title
+            && self.description == other.description
+            && self.photo_url == other.photo_url
+            && self.photo_size == other.photo_size
+            && self.photo_width == other.photo_width
+            && self.photo_height == other.photo_height
+            && self.payload == other.payload
+            && self.provider_token == other.provider_token
+            && self.provider_data == other.provider_data
+            && self.start_parameter == other.start_parameter
+    }
+}
+
+impl Eq for InputMessageInvoice {}
+
+impl std::hash::Hash for InputMessageInvoice {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.invoice, state);
+        std::hash::Hash::hash(&self.title, state);
+        std::hash::Hash::hash(&self.description, state);
+        std::hash::Hash::hash(&self.photo_url, state);
+        std::hash::Hash::hash(&self.photo_size, state);
+        std::hash::Hash::hash(&self.photo_width, state);
+        std::hash::Hash::hash(&self.photo_height, state);
+        std::hash::Hash::hash(&self.payload, state);
+        std::hash::Hash::hash(&self.provider_token, state);
+        std::hash::Hash::hash(&self.provider_data, state);
+        std::hash::Hash::hash(&self.start_parameter, state);
+    }
+}
+
 impl TDInputMessageContent for InputMessageInvoice {}
 
 impl InputMessageInvoice {
@@ -833,10 +1342,35 @@ impl InputMessageInvoice {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputMessageInvoiceBuilder {
-        let mut inner = InputMessageInvoice::default();
-        inner.td_name = "inputMessageInvoice".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputMessageInvoiceBuilder { inner }
+        RTDInputMessageInvoiceBuilder {
+            inner: InputMessageInvoice::default(),
+            missing: vec![
+                "invoice",
+                "title",
+                "description",
+                "photo_size",
+                "photo_width",
+                "photo_height",
+                "payload",
+                "provider_token",
+                "provider_data",
+                "start_parameter",
+            ],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputMessageInvoiceBuilder {
+        RTDInputMessageInvoiceBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputMessageInvoiceBuilder {
+        RTDInputMessageInvoiceBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn invoice(&self) -> &Invoice {
@@ -855,19 +1389,19 @@ impl InputMessageInvoice {
         &self.photo_url
     }
 
-    pub fn photo_size(&self) -> i64 {
+    pub fn photo_size(&self) -> i32 {
         self.photo_size
     }
 
-    pub fn photo_width(&self) -> i64 {
+    pub fn photo_width(&self) -> i32 {
         self.photo_width
     }
 
-    pub fn photo_height(&self) -> i64 {
+    pub fn photo_height(&self) -> i32 {
         self.photo_height
     }
 
-    pub fn payload(&self) -> &String {
+    pub fn payload(&self) -> &[u8] {
         &self.payload
     }
 
@@ -882,99 +1416,210 @@ impl InputMessageInvoice {
     pub fn start_parameter(&self) -> &String {
         &self.start_parameter
     }
-}
 
-#[doc(hidden)]
-pub struct RTDInputMessageInvoiceBuilder {
-    inner: InputMessageInvoice,
-}
+    pub fn into_invoice(self) -> Invoice {
+        self.invoice
+    }
 
-impl RTDInputMessageInvoiceBuilder {
-    pub fn build(&self) -> InputMessageInvoice {
-        self.inner.clone()
+    pub fn into_title(self) -> String {
+        self.title
     }
 
-    pub fn invoice<T: AsRef<Invoice>>(&mut self, invoice: T) -> &mut Self {
-        self.inner.invoice = invoice.as_ref().clone();
-        self
+    pub fn into_description(self) -> String {
+        self.description
     }
 
-    pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
-        self.inner.title = title.as_ref().to_string();
+    pub fn into_photo_url(self) -> String {
+        self.photo_url
+    }
+
+    pub fn into_payload(self) -> Vec<u8> {
+        self.payload
+    }
+
+    pub fn into_provider_token(self) -> String {
+        self.provider_token
+    }
+
+    pub fn into_provider_data(self) -> String {
+        self.provider_data
+    }
+
+    pub fn into_start_parameter(self) -> String {
+        self.start_parameter
+    }
+
+    pub fn with_invoice<T: AsRef<Invoice>>(mut self, invoice: T) -> Self {
+        self.invoice = invoice.as_ref().clone();
         self
     }
 
-    pub fn description<T: AsRef<str>>(&mut self, description: T) -> &mut Self {
-        self.inner.description = description.as_ref().to_string();
+    pub fn with_title<T: AsRef<str>>(mut self, title: T) -> Self {
+        self.title = title.as_ref().to_string();
         self
     }
 
-    pub fn photo_url<T: AsRef<str>>(&mut self, photo_url: T) -> &mut Self {
-        self.inner.photo_url = photo_url.as_ref().to_string();
+    pub fn with_description<T: AsRef<str>>(mut self, description: T) -> Self {
+        self.description = description.as_ref().to_string();
         self
     }
 
-    pub fn photo_size(&mut self, photo_size: i64) -> &mut Self {
-        self.inner.photo_size = photo_size;
+    pub fn with_photo_url<T: AsRef<str>>(mut self, photo_url: T) -> Self {
+        self.photo_url = photo_url.as_ref().to_string();
         self
     }
 
-    pub fn photo_width(&mut self, photo_width: i64) -> &mut Self {
-        self.inner.photo_width = photo_width;
+    pub fn with_photo_size(mut self, photo_size: i32) -> Self {
+        self.photo_size = photo_size;
         self
     }
 
-    pub fn photo_height(&mut self, photo_height: i64) -> &mut Self {
-        self.inner.photo_height = photo_height;
+    pub fn with_photo_width(mut self, photo_width: i32) -> Self {
+        self.photo_width = photo_width;
         self
     }
 
-    pub fn payload<T: AsRef<str>>(&mut self, payload: T) -> &mut Self {
-        self.inner.payload = payload.as_ref().to_string();
+    pub fn with_photo_height(mut self, photo_height: i32) -> Self {
+        self.photo_height = photo_height;
         self
     }
 
-    pub fn provider_token<T: AsRef<str>>(&mut self, provider_token: T) -> &mut Self {
-        self.inner.provider_token = provider_token.as_ref().to_string();
+    pub fn with_payload<T: AsRef<[u8]>>(mut self, payload: T) -> Self {
+        self.payload = payload.as_ref().to_vec();
         self
     }
 
-    pub fn provider_data<T: AsRef<str>>(&mut self, provider_data: T) -> &mut Self {
-        self.inner.provider_data = provider_data.as_ref().to_string();
+    pub fn with_provider_token<T: AsRef<str>>(mut self, provider_token: T) -> Self {
+        self.provider_token = provider_token.as_ref().to_string();
         self
     }
 
-    pub fn start_parameter<T: AsRef<str>>(&mut self, start_parameter: T) -> &mut Self {
-        self.inner.start_parameter = start_parameter.as_ref().to_string();
+    pub fn with_provider_data<T: AsRef<str>>(mut self, provider_data: T) -> Self {
+        self.provider_data = provider_data.as_ref().to_string();
         self
     }
-}
 
-impl AsRef<InputMessageInvoice> for InputMessageInvoice {
-    fn as_ref(&self) -> &InputMessageInvoice {
+    pub fn with_start_parameter<T: AsRef<str>>(mut self, start_parameter: T) -> Self {
+        self.start_parameter = start_parameter.as_ref().to_string();
         self
     }
 }
 
-impl AsRef<InputMessageInvoice> for RTDInputMessageInvoiceBuilder {
-    fn as_ref(&self) -> &InputMessageInvoice {
-        &self.inner
-    }
+#[doc(hidden)]
+pub struct RTDInputMessageInvoiceBuilder {
+    inner: InputMessageInvoice,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
-/// A message with a location
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
-pub struct InputMessageLocation {
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
+impl RTDInputMessageInvoiceBuilder {
+    /// Builds the object, fields which are not set have default values
+    pub fn build(&self) -> InputMessageInvoice {
+        self.inner.clone()
+    }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputMessageInvoice> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputMessageInvoice",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
+
+    pub fn invoice<T: AsRef<Invoice>>(&mut self, invoice: T) -> &mut Self {
+        self.inner.invoice = invoice.as_ref().clone();
+        self.missing.retain(|f| *f != "invoice");
+        self
+    }
+
+    pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
+        self.inner.title = title.as_ref().to_string();
+        self.missing.retain(|f| *f != "title");
+        self
+    }
+
+    pub fn description<T: AsRef<str>>(&mut self, description: T) -> &mut Self {
+        self.inner.description = description.as_ref().to_string();
+        self.missing.retain(|f| *f != "description");
+        self
+    }
+
+    pub fn photo_url<T: AsRef<str>>(&mut self, photo_url: T) -> &mut Self {
+        self.inner.photo_url = photo_url.as_ref().to_string();
+        self
+    }
+
+    pub fn photo_size(&mut self, photo_size: i32) -> &mut Self {
+        self.inner.photo_size = photo_size;
+        self.missing.retain(|f| *f != "photo_size");
+        self
+    }
+
+    pub fn photo_width(&mut self, photo_width: i32) -> &mut Self {
+        self.inner.photo_width = photo_width;
+        self.missing.retain(|f| *f != "photo_width");
+        self
+    }
+
+    pub fn photo_height(&mut self, photo_height: i32) -> &mut Self {
+        self.inner.photo_height = photo_height;
+        self.missing.retain(|f| *f != "photo_height");
+        self
+    }
+
+    pub fn payload<T: AsRef<[u8]>>(&mut self, payload: T) -> &mut Self {
+        self.inner.payload = payload.as_ref().to_vec();
+        self.missing.retain(|f| *f != "payload");
+        self
+    }
+
+    pub fn provider_token<T: AsRef<str>>(&mut self, provider_token: T) -> &mut Self {
+        self.inner.provider_token = provider_token.as_ref().to_string();
+        self.missing.retain(|f| *f != "provider_token");
+        self
+    }
+
+    pub fn provider_data<T: AsRef<str>>(&mut self, provider_data: T) -> &mut Self {
+        self.inner.provider_data = provider_data.as_ref().to_string();
+        self.missing.retain(|f| *f != "provider_data");
+        self
+    }
+
+    pub fn start_parameter<T: AsRef<str>>(&mut self, start_parameter: T) -> &mut Self {
+        self.inner.start_parameter = start_parameter.as_ref().to_string();
+        self.missing.retain(|f| *f != "start_parameter");
+        self
+    }
+}
+
+impl AsRef<InputMessageInvoice> for InputMessageInvoice {
+    fn as_ref(&self) -> &InputMessageInvoice {
+        self
+    }
+}
+
+impl AsRef<InputMessageInvoice> for RTDInputMessageInvoiceBuilder {
+    fn as_ref(&self) -> &InputMessageInvoice {
+        &self.inner
+    }
+}
+
+/// A message with a location
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputMessageLocation")]
+pub struct InputMessageLocation {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Location to be sent
     location: Location,
     /// Period for which the location can be updated, in seconds; should be between 60 and 86400 for a live location and 0 otherwise
-    live_period: i64,
+    live_period: i32,
 }
 
 impl RObject for InputMessageLocation {
@@ -991,6 +1636,12 @@ impl RObject for InputMessageLocation {
     }
 }
 
+impl PartialEq for InputMessageLocation {
+    fn eq(&self, other: &Self) -> bool {
+        self.location == other.location && self.live_period == other.live_period
+    }
+}
+
 impl TDInputMessageContent for InputMessageLocation {}
 
 impl InputMessageLocation {
@@ -998,37 +1649,79 @@ impl InputMessageLocation {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputMessageLocationBuilder {
-        let mut inner = InputMessageLocation::default();
-        inner.td_name = "inputMessageLocation".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputMessageLocationBuilder { inner }
+        RTDInputMessageLocationBuilder {
+            inner: InputMessageLocation::default(),
+            missing: vec!["location"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputMessageLocationBuilder {
+        RTDInputMessageLocationBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputMessageLocationBuilder {
+        RTDInputMessageLocationBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn location(&self) -> &Location {
         &self.location
     }
 
-    pub fn live_period(&self) -> i64 {
+    pub fn live_period(&self) -> i32 {
         self.live_period
     }
+
+    pub fn into_location(self) -> Location {
+        self.location
+    }
+
+    pub fn with_location<T: AsRef<Location>>(mut self, location: T) -> Self {
+        self.location = location.as_ref().clone();
+        self
+    }
+
+    pub fn with_live_period(mut self, live_period: i32) -> Self {
+        self.live_period = live_period;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputMessageLocationBuilder {
     inner: InputMessageLocation,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputMessageLocationBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputMessageLocation {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputMessageLocation> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputMessageLocation",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn location<T: AsRef<Location>>(&mut self, location: T) -> &mut Self {
         self.inner.location = location.as_ref().clone();
+        self.missing.retain(|f| *f != "location");
         self
     }
 
-    pub fn live_period(&mut self, live_period: i64) -> &mut Self {
+    pub fn live_period(&mut self, live_period: i32) -> &mut Self {
         self.inner.live_period = live_period;
         self
     }
@@ -1048,27 +1741,28 @@ impl AsRef<InputMessageLocation> for RTDInputMessageLocationBuilder {
 
 /// A photo message
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputMessagePhoto")]
 pub struct InputMessagePhoto {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Photo to send
     photo: InputFile,
     /// Photo thumbnail to be sent, this is sent to the other party in secret chats only
     thumbnail: InputThumbnail,
     /// File identifiers of the stickers added to the photo, if applicable
-    added_sticker_file_ids: Vec<i64>,
+    added_sticker_file_ids: Vec<FileId>,
     /// Photo width
-    width: i64,
+    width: i32,
     /// Photo height
-    height: i64,
+    height: i32,
     /// Photo caption; 0-GetOption("message_caption_length_max") characters
     caption: FormattedText,
     /// Photo TTL (Time To Live), in seconds (0-60). A non-zero TTL can be specified only in private chats
-    ttl: i64,
+    ttl: i32,
 }
 
 impl RObject for InputMessagePhoto {
@@ -1085,6 +1779,32 @@ impl RObject for InputMessagePhoto {
     }
 }
 
+impl PartialEq for InputMessagePhoto {
+    fn eq(&self, other: &Self) -> bool {
+        self.photo == other.photo
+            && self.thumbnail == other.thumbnail
+            && self.added_sticker_file_ids == other.added_sticker_file_ids
+            && self.width == other.width
+            && self.height == other.height
+            && self.caption == other.caption
+            && self.ttl == other.ttl
+    }
+}
+
+impl Eq for InputMessagePhoto {}
+
+impl std::hash::Hash for InputMessagePhoto {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.photo, state);
+        std::hash::Hash::hash(&self.thumbnail, state);
+        std::hash::Hash::hash(&self.added_sticker_file_ids, state);
+        std::hash::Hash::hash(&self.width, state);
+        std::hash::Hash::hash(&self.height, state);
+        std::hash::Hash::hash(&self.caption, state);
+        std::hash::Hash::hash(&self.ttl, state);
+    }
+}
+
 impl TDInputMessageContent for InputMessagePhoto {}
 
 impl InputMessagePhoto {
@@ -1092,10 +1812,30 @@ impl InputMessagePhoto {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputMessagePhotoBuilder {
-        let mut inner = InputMessagePhoto::default();
-        inner.td_name = "inputMessagePhoto".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputMessagePhotoBuilder { inner }
+        RTDInputMessagePhotoBuilder {
+            inner: InputMessagePhoto::default(),
+            missing: vec![
+                "photo",
+                "thumbnail",
+                "added_sticker_file_ids",
+                "width",
+                "height",
+            ],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputMessagePhotoBuilder {
+        RTDInputMessagePhotoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputMessagePhotoBuilder {
+        RTDInputMessagePhotoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn photo(&self) -> &InputFile {
@@ -1106,15 +1846,15 @@ impl InputMessagePhoto {
         &self.thumbnail
     }
 
-    pub fn added_sticker_file_ids(&self) -> &Vec<i64> {
+    pub fn added_sticker_file_ids(&self) -> &Vec<FileId> {
         &self.added_sticker_file_ids
     }
 
-    pub fn width(&self) -> i64 {
+    pub fn width(&self) -> i32 {
         self.width
     }
 
-    pub fn height(&self) -> i64 {
+    pub fn height(&self) -> i32 {
         self.height
     }
 
@@ -1122,43 +1862,112 @@ impl InputMessagePhoto {
         &self.caption
     }
 
-    pub fn ttl(&self) -> i64 {
+    pub fn ttl(&self) -> i32 {
         self.ttl
     }
+
+    pub fn into_photo(self) -> InputFile {
+        self.photo
+    }
+
+    pub fn into_thumbnail(self) -> InputThumbnail {
+        self.thumbnail
+    }
+
+    pub fn into_added_sticker_file_ids(self) -> Vec<FileId> {
+        self.added_sticker_file_ids
+    }
+
+    pub fn into_caption(self) -> FormattedText {
+        self.caption
+    }
+
+    pub fn with_photo<T: AsRef<InputFile>>(mut self, photo: T) -> Self {
+        self.photo = photo.as_ref().clone();
+        self
+    }
+
+    pub fn with_thumbnail<T: AsRef<InputThumbnail>>(mut self, thumbnail: T) -> Self {
+        self.thumbnail = thumbnail.as_ref().clone();
+        self
+    }
+
+    pub fn with_added_sticker_file_ids(mut self, added_sticker_file_ids: Vec<FileId>) -> Self {
+        self.added_sticker_file_ids = added_sticker_file_ids;
+        self
+    }
+
+    pub fn with_width(mut self, width: i32) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn with_height(mut self, height: i32) -> Self {
+        self.height = height;
+        self
+    }
+
+    pub fn with_caption<T: AsRef<FormattedText>>(mut self, caption: T) -> Self {
+        self.caption = caption.as_ref().clone();
+        self
+    }
+
+    pub fn with_ttl(mut self, ttl: i32) -> Self {
+        self.ttl = ttl;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputMessagePhotoBuilder {
     inner: InputMessagePhoto,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputMessagePhotoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputMessagePhoto {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputMessagePhoto> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputMessagePhoto",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn photo<T: AsRef<InputFile>>(&mut self, photo: T) -> &mut Self {
         self.inner.photo = photo.as_ref().clone();
+        self.missing.retain(|f| *f != "photo");
         self
     }
 
     pub fn thumbnail<T: AsRef<InputThumbnail>>(&mut self, thumbnail: T) -> &mut Self {
         self.inner.thumbnail = thumbnail.as_ref().clone();
+        self.missing.retain(|f| *f != "thumbnail");
         self
     }
 
-    pub fn added_sticker_file_ids(&mut self, added_sticker_file_ids: Vec<i64>) -> &mut Self {
+    pub fn added_sticker_file_ids(&mut self, added_sticker_file_ids: Vec<FileId>) -> &mut Self {
         self.inner.added_sticker_file_ids = added_sticker_file_ids;
+        self.missing.retain(|f| *f != "added_sticker_file_ids");
         self
     }
 
-    pub fn width(&mut self, width: i64) -> &mut Self {
+    pub fn width(&mut self, width: i32) -> &mut Self {
         self.inner.width = width;
+        self.missing.retain(|f| *f != "width");
         self
     }
 
-    pub fn height(&mut self, height: i64) -> &mut Self {
+    pub fn height(&mut self, height: i32) -> &mut Self {
         self.inner.height = height;
+        self.missing.retain(|f| *f != "height");
         self
     }
 
@@ -1167,7 +1976,7 @@ impl RTDInputMessagePhotoBuilder {
         self
     }
 
-    pub fn ttl(&mut self, ttl: i64) -> &mut Self {
+    pub fn ttl(&mut self, ttl: i32) -> &mut Self {
         self.inner.ttl = ttl;
         self
     }
@@ -1187,12 +1996,13 @@ impl AsRef<InputMessagePhoto> for RTDInputMessagePhotoBuilder {
 
 /// A message with a poll. Polls can't be sent to secret chats. Polls can be sent only to a private chat with a bot
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputMessagePoll")]
 pub struct InputMessagePoll {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Poll question, 1-255 characters
     question: String,
@@ -1221,6 +2031,28 @@ impl RObject for InputMessagePoll {
     }
 }
 
+impl PartialEq for InputMessagePoll {
+    fn eq(&self, other: &Self) -> bool {
+        self.question == other.question
+            && self.options == other.options
+            && self.is_anonymous == other.is_anonymous
+            && self.type_ == other.type_
+            && self.is_closed == other.is_closed
+    }
+}
+
+impl Eq for InputMessagePoll {}
+
+impl std::hash::Hash for InputMessagePoll {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.question, state);
+        std::hash::Hash::hash(&self.options, state);
+        std::hash::Hash::hash(&self.is_anonymous, state);
+        std::hash::Hash::hash(&self.type_, state);
+        std::hash::Hash::hash(&self.is_closed, state);
+    }
+}
+
 impl TDInputMessageContent for InputMessagePoll {}
 
 impl InputMessagePoll {
@@ -1228,10 +2060,24 @@ impl InputMessagePoll {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputMessagePollBuilder {
-        let mut inner = InputMessagePoll::default();
-        inner.td_name = "inputMessagePoll".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputMessagePollBuilder { inner }
+        RTDInputMessagePollBuilder {
+            inner: InputMessagePoll::default(),
+            missing: vec!["question", "options", "type_"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputMessagePollBuilder {
+        RTDInputMessagePollBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputMessagePollBuilder {
+        RTDInputMessagePollBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn question(&self) -> &String {
@@ -1253,25 +2099,77 @@ impl InputMessagePoll {
     pub fn is_closed(&self) -> bool {
         self.is_closed
     }
+
+    pub fn into_question(self) -> String {
+        self.question
+    }
+
+    pub fn into_options(self) -> Vec<String> {
+        self.options
+    }
+
+    pub fn into_type(self) -> PollType {
+        self.type_
+    }
+
+    pub fn with_question<T: AsRef<str>>(mut self, question: T) -> Self {
+        self.question = question.as_ref().to_string();
+        self
+    }
+
+    pub fn with_options(mut self, options: Vec<String>) -> Self {
+        self.options = options;
+        self
+    }
+
+    pub fn with_is_anonymous(mut self, is_anonymous: bool) -> Self {
+        self.is_anonymous = is_anonymous;
+        self
+    }
+
+    pub fn with_type<T: AsRef<PollType>>(mut self, type_: T) -> Self {
+        self.type_ = type_.as_ref().clone();
+        self
+    }
+
+    pub fn with_is_closed(mut self, is_closed: bool) -> Self {
+        self.is_closed = is_closed;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputMessagePollBuilder {
     inner: InputMessagePoll,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputMessagePollBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputMessagePoll {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputMessagePoll> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputMessagePoll",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn question<T: AsRef<str>>(&mut self, question: T) -> &mut Self {
         self.inner.question = question.as_ref().to_string();
+        self.missing.retain(|f| *f != "question");
         self
     }
 
     pub fn options(&mut self, options: Vec<String>) -> &mut Self {
         self.inner.options = options;
+        self.missing.retain(|f| *f != "options");
         self
     }
 
@@ -1282,6 +2180,7 @@ impl RTDInputMessagePollBuilder {
 
     pub fn type_<T: AsRef<PollType>>(&mut self, type_: T) -> &mut Self {
         self.inner.type_ = type_.as_ref().clone();
+        self.missing.retain(|f| *f != "type_");
         self
     }
 
@@ -1305,21 +2204,22 @@ impl AsRef<InputMessagePoll> for RTDInputMessagePollBuilder {
 
 /// A sticker message
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputMessageSticker")]
 pub struct InputMessageSticker {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Sticker to be sent
     sticker: InputFile,
     /// Sticker thumbnail, if available
     thumbnail: InputThumbnail,
     /// Sticker width
-    width: i64,
+    width: i32,
     /// Sticker height
-    height: i64,
+    height: i32,
 }
 
 impl RObject for InputMessageSticker {
@@ -1336,6 +2236,26 @@ impl RObject for InputMessageSticker {
     }
 }
 
+impl PartialEq for InputMessageSticker {
+    fn eq(&self, other: &Self) -> bool {
+        self.sticker == other.sticker
+            && self.thumbnail == other.thumbnail
+            && self.width == other.width
+            && self.height == other.height
+    }
+}
+
+impl Eq for InputMessageSticker {}
+
+impl std::hash::Hash for InputMessageSticker {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.sticker, state);
+        std::hash::Hash::hash(&self.thumbnail, state);
+        std::hash::Hash::hash(&self.width, state);
+        std::hash::Hash::hash(&self.height, state);
+    }
+}
+
 impl TDInputMessageContent for InputMessageSticker {}
 
 impl InputMessageSticker {
@@ -1343,10 +2263,24 @@ impl InputMessageSticker {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputMessageStickerBuilder {
-        let mut inner = InputMessageSticker::default();
-        inner.td_name = "inputMessageSticker".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputMessageStickerBuilder { inner }
+        RTDInputMessageStickerBuilder {
+            inner: InputMessageSticker::default(),
+            missing: vec!["sticker", "thumbnail", "width", "height"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputMessageStickerBuilder {
+        RTDInputMessageStickerBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputMessageStickerBuilder {
+        RTDInputMessageStickerBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn sticker(&self) -> &InputFile {
@@ -1357,42 +2291,87 @@ impl InputMessageSticker {
         &self.thumbnail
     }
 
-    pub fn width(&self) -> i64 {
+    pub fn width(&self) -> i32 {
         self.width
     }
 
-    pub fn height(&self) -> i64 {
+    pub fn height(&self) -> i32 {
         self.height
     }
+
+    pub fn into_sticker(self) -> InputFile {
+        self.sticker
+    }
+
+    pub fn into_thumbnail(self) -> InputThumbnail {
+        self.thumbnail
+    }
+
+    pub fn with_sticker<T: AsRef<InputFile>>(mut self, sticker: T) -> Self {
+        self.sticker = sticker.as_ref().clone();
+        self
+    }
+
+    pub fn with_thumbnail<T: AsRef<InputThumbnail>>(mut self, thumbnail: T) -> Self {
+        self.thumbnail = thumbnail.as_ref().clone();
+        self
+    }
+
+    pub fn with_width(mut self, width: i32) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn with_height(mut self, height: i32) -> Self {
+        self.height = height;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputMessageStickerBuilder {
     inner: InputMessageSticker,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputMessageStickerBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputMessageSticker {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputMessageSticker> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputMessageSticker",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn sticker<T: AsRef<InputFile>>(&mut self, sticker: T) -> &mut Self {
         self.inner.sticker = sticker.as_ref().clone();
+        self.missing.retain(|f| *f != "sticker");
         self
     }
 
     pub fn thumbnail<T: AsRef<InputThumbnail>>(&mut self, thumbnail: T) -> &mut Self {
         self.inner.thumbnail = thumbnail.as_ref().clone();
+        self.missing.retain(|f| *f != "thumbnail");
         self
     }
 
-    pub fn width(&mut self, width: i64) -> &mut Self {
+    pub fn width(&mut self, width: i32) -> &mut Self {
         self.inner.width = width;
+        self.missing.retain(|f| *f != "width");
         self
     }
 
-    pub fn height(&mut self, height: i64) -> &mut Self {
+    pub fn height(&mut self, height: i32) -> &mut Self {
         self.inner.height = height;
+        self.missing.retain(|f| *f != "height");
         self
     }
 }
@@ -1411,12 +2390,13 @@ impl AsRef<InputMessageSticker> for RTDInputMessageStickerBuilder {
 
 /// A text message
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputMessageText")]
 pub struct InputMessageText {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Formatted text to be sent; 1-GetOption("message_text_length_max") characters. Only Bold, Italic, Underline, Strikethrough, Code, Pre, PreCode, TextUrl and MentionName entities are allowed to be specified manually
     text: FormattedText,
@@ -1440,6 +2420,24 @@ impl RObject for InputMessageText {
     }
 }
 
+impl PartialEq for InputMessageText {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text
+            && self.disable_web_page_preview == other.disable_web_page_preview
+            && self.clear_draft == other.clear_draft
+    }
+}
+
+impl Eq for InputMessageText {}
+
+impl std::hash::Hash for InputMessageText {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.text, state);
+        std::hash::Hash::hash(&self.disable_web_page_preview, state);
+        std::hash::Hash::hash(&self.clear_draft, state);
+    }
+}
+
 impl TDInputMessageContent for InputMessageText {}
 
 impl InputMessageText {
@@ -1447,10 +2445,24 @@ impl InputMessageText {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputMessageTextBuilder {
-        let mut inner = InputMessageText::default();
-        inner.td_name = "inputMessageText".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputMessageTextBuilder { inner }
+        RTDInputMessageTextBuilder {
+            inner: InputMessageText::default(),
+            missing: vec!["text"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputMessageTextBuilder {
+        RTDInputMessageTextBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputMessageTextBuilder {
+        RTDInputMessageTextBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn text(&self) -> &FormattedText {
@@ -1464,20 +2476,53 @@ impl InputMessageText {
     pub fn clear_draft(&self) -> bool {
         self.clear_draft
     }
+
+    pub fn into_text(self) -> FormattedText {
+        self.text
+    }
+
+    pub fn with_text<T: AsRef<FormattedText>>(mut self, text: T) -> Self {
+        self.text = text.as_ref().clone();
+        self
+    }
+
+    pub fn with_disable_web_page_preview(mut self, disable_web_page_preview: bool) -> Self {
+        self.disable_web_page_preview = disable_web_page_preview;
+        self
+    }
+
+    pub fn with_clear_draft(mut self, clear_draft: bool) -> Self {
+        self.clear_draft = clear_draft;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputMessageTextBuilder {
     inner: InputMessageText,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputMessageTextBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputMessageText {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputMessageText> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputMessageText",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn text<T: AsRef<FormattedText>>(&mut self, text: T) -> &mut Self {
         self.inner.text = text.as_ref().clone();
+        self.missing.retain(|f| *f != "text");
         self
     }
 
@@ -1506,12 +2551,13 @@ impl AsRef<InputMessageText> for RTDInputMessageTextBuilder {
 
 /// A message with information about a venue
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputMessageVenue")]
 pub struct InputMessageVenue {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Venue to send
     venue: Venue,
@@ -1531,6 +2577,12 @@ impl RObject for InputMessageVenue {
     }
 }
 
+impl PartialEq for InputMessageVenue {
+    fn eq(&self, other: &Self) -> bool {
+        self.venue == other.venue
+    }
+}
+
 impl TDInputMessageContent for InputMessageVenue {}
 
 impl InputMessageVenue {
@@ -1538,29 +2590,66 @@ impl InputMessageVenue {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputMessageVenueBuilder {
-        let mut inner = InputMessageVenue::default();
-        inner.td_name = "inputMessageVenue".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputMessageVenueBuilder { inner }
+        RTDInputMessageVenueBuilder {
+            inner: InputMessageVenue::default(),
+            missing: vec!["venue"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputMessageVenueBuilder {
+        RTDInputMessageVenueBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputMessageVenueBuilder {
+        RTDInputMessageVenueBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn venue(&self) -> &Venue {
         &self.venue
     }
+
+    pub fn into_venue(self) -> Venue {
+        self.venue
+    }
+
+    pub fn with_venue<T: AsRef<Venue>>(mut self, venue: T) -> Self {
+        self.venue = venue.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputMessageVenueBuilder {
     inner: InputMessageVenue,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputMessageVenueBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputMessageVenue {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputMessageVenue> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputMessageVenue",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn venue<T: AsRef<Venue>>(&mut self, venue: T) -> &mut Self {
         self.inner.venue = venue.as_ref().clone();
+        self.missing.retain(|f| *f != "venue");
         self
     }
 }
@@ -1579,31 +2668,32 @@ impl AsRef<InputMessageVenue> for RTDInputMessageVenueBuilder {
 
 /// A video message
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputMessageVideo")]
 pub struct InputMessageVideo {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Video to be sent
     video: InputFile,
     /// Video thumbnail, if available
     thumbnail: InputThumbnail,
     /// File identifiers of the stickers added to the video, if applicable
-    added_sticker_file_ids: Vec<i64>,
+    added_sticker_file_ids: Vec<FileId>,
     /// Duration of the video, in seconds
-    duration: i64,
+    duration: i32,
     /// Video width
-    width: i64,
+    width: i32,
     /// Video height
-    height: i64,
+    height: i32,
     /// True, if the video should be tried to be streamed
     supports_streaming: bool,
     /// Video caption; 0-GetOption("message_caption_length_max") characters
     caption: FormattedText,
     /// Video TTL (Time To Live), in seconds (0-60). A non-zero TTL can be specified only in private chats
-    ttl: i64,
+    ttl: i32,
 }
 
 impl RObject for InputMessageVideo {
@@ -1620,6 +2710,36 @@ impl RObject for InputMessageVideo {
     }
 }
 
+impl PartialEq for InputMessageVideo {
+    fn eq(&self, other: &Self) -> bool {
+        self.video == other.video
+            && self.thumbnail == other.thumbnail
+            && self.added_sticker_file_ids == other.added_sticker_file_ids
+            && self.duration == other.duration
+            && self.width == other.width
+            && self.height == other.height
+            && self.supports_streaming == other.supports_streaming
+            && self.caption == other.caption
+            && self.ttl == other.ttl
+    }
+}
+
+impl Eq for InputMessageVideo {}
+
+impl std::hash::Hash for InputMessageVideo {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.video, state);
+        std::hash::Hash::hash(&self.thumbnail, state);
+        std::hash::Hash::hash(&self.added_sticker_file_ids, state);
+        std::hash::Hash::hash(&self.duration, state);
+        std::hash::Hash::hash(&self.width, state);
+        std::hash::Hash::hash(&self.height, state);
+        std::hash::Hash::hash(&self.supports_streaming, state);
+        std::hash::Hash::hash(&self.caption, state);
+        std::hash::Hash::hash(&self.ttl, state);
+    }
+}
+
 impl TDInputMessageContent for InputMessageVideo {}
 
 impl InputMessageVideo {
@@ -1627,10 +2747,31 @@ impl InputMessageVideo {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputMessageVideoBuilder {
-        let mut inner = InputMessageVideo::default();
-        inner.td_name = "inputMessageVideo".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputMessageVideoBuilder { inner }
+        RTDInputMessageVideoBuilder {
+            inner: InputMessageVideo::default(),
+            missing: vec![
+                "video",
+                "thumbnail",
+                "added_sticker_file_ids",
+                "duration",
+                "width",
+                "height",
+            ],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputMessageVideoBuilder {
+        RTDInputMessageVideoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputMessageVideoBuilder {
+        RTDInputMessageVideoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn video(&self) -> &InputFile {
@@ -1641,19 +2782,19 @@ impl InputMessageVideo {
         &self.thumbnail
     }
 
-    pub fn added_sticker_file_ids(&self) -> &Vec<i64> {
+    pub fn added_sticker_file_ids(&self) -> &Vec<FileId> {
         &self.added_sticker_file_ids
     }
 
-    pub fn duration(&self) -> i64 {
+    pub fn duration(&self) -> i32 {
         self.duration
     }
 
-    pub fn width(&self) -> i64 {
+    pub fn width(&self) -> i32 {
         self.width
     }
 
-    pub fn height(&self) -> i64 {
+    pub fn height(&self) -> i32 {
         self.height
     }
 
@@ -1665,48 +2806,128 @@ impl InputMessageVideo {
         &self.caption
     }
 
-    pub fn ttl(&self) -> i64 {
+    pub fn ttl(&self) -> i32 {
         self.ttl
     }
+
+    pub fn into_video(self) -> InputFile {
+        self.video
+    }
+
+    pub fn into_thumbnail(self) -> InputThumbnail {
+        self.thumbnail
+    }
+
+    pub fn into_added_sticker_file_ids(self) -> Vec<FileId> {
+        self.added_sticker_file_ids
+    }
+
+    pub fn into_caption(self) -> FormattedText {
+        self.caption
+    }
+
+    pub fn with_video<T: AsRef<InputFile>>(mut self, video: T) -> Self {
+        self.video = video.as_ref().clone();
+        self
+    }
+
+    pub fn with_thumbnail<T: AsRef<InputThumbnail>>(mut self, thumbnail: T) -> Self {
+        self.thumbnail = thumbnail.as_ref().clone();
+        self
+    }
+
+    pub fn with_added_sticker_file_ids(mut self, added_sticker_file_ids: Vec<FileId>) -> Self {
+        self.added_sticker_file_ids = added_sticker_file_ids;
+        self
+    }
+
+    pub fn with_duration(mut self, duration: i32) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    pub fn with_width(mut self, width: i32) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn with_height(mut self, height: i32) -> Self {
+        self.height = height;
+        self
+    }
+
+    pub fn with_supports_streaming(mut self, supports_streaming: bool) -> Self {
+        self.supports_streaming = supports_streaming;
+        self
+    }
+
+    pub fn with_caption<T: AsRef<FormattedText>>(mut self, caption: T) -> Self {
+        self.caption = caption.as_ref().clone();
+        self
+    }
+
+    pub fn with_ttl(mut self, ttl: i32) -> Self {
+        self.ttl = ttl;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputMessageVideoBuilder {
     inner: InputMessageVideo,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputMessageVideoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputMessageVideo {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputMessageVideo> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputMessageVideo",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn video<T: AsRef<InputFile>>(&mut self, video: T) -> &mut Self {
         self.inner.video = video.as_ref().clone();
+        self.missing.retain(|f| *f != "video");
         self
     }
 
     pub fn thumbnail<T: AsRef<InputThumbnail>>(&mut self, thumbnail: T) -> &mut Self {
         self.inner.thumbnail = thumbnail.as_ref().clone();
+        self.missing.retain(|f| *f != "thumbnail");
         self
     }
 
-    pub fn added_sticker_file_ids(&mut self, added_sticker_file_ids: Vec<i64>) -> &mut Self {
+    pub fn added_sticker_file_ids(&mut self, added_sticker_file_ids: Vec<FileId>) -> &mut Self {
         self.inner.added_sticker_file_ids = added_sticker_file_ids;
+        self.missing.retain(|f| *f != "added_sticker_file_ids");
         self
     }
 
-    pub fn duration(&mut self, duration: i64) -> &mut Self {
+    pub fn duration(&mut self, duration: i32) -> &mut Self {
         self.inner.duration = duration;
+        self.missing.retain(|f| *f != "duration");
         self
     }
 
-    pub fn width(&mut self, width: i64) -> &mut Self {
+    pub fn width(&mut self, width: i32) -> &mut Self {
         self.inner.width = width;
+        self.missing.retain(|f| *f != "width");
         self
     }
 
-    pub fn height(&mut self, height: i64) -> &mut Self {
+    pub fn height(&mut self, height: i32) -> &mut Self {
         self.inner.height = height;
+        self.missing.retain(|f| *f != "height");
         self
     }
 
@@ -1720,7 +2941,7 @@ impl RTDInputMessageVideoBuilder {
         self
     }
 
-    pub fn ttl(&mut self, ttl: i64) -> &mut Self {
+    pub fn ttl(&mut self, ttl: i32) -> &mut Self {
         self.inner.ttl = ttl;
         self
     }
@@ -1740,21 +2961,22 @@ impl AsRef<InputMessageVideo> for RTDInputMessageVideoBuilder {
 
 /// A video note message
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputMessageVideoNote")]
 pub struct InputMessageVideoNote {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Video note to be sent
     video_note: InputFile,
     /// Video thumbnail, if available
     thumbnail: InputThumbnail,
     /// Duration of the video, in seconds
-    duration: i64,
+    duration: i32,
     /// Video width and height; must be positive and not greater than 640
-    length: i64,
+    length: i32,
 }
 
 impl RObject for InputMessageVideoNote {
@@ -1771,6 +2993,26 @@ impl RObject for InputMessageVideoNote {
     }
 }
 
+impl PartialEq for InputMessageVideoNote {
+    fn eq(&self, other: &Self) -> bool {
+        self.video_note == other.video_note
+            && self.thumbnail == other.thumbnail
+            && self.duration == other.duration
+            && self.length == other.length
+    }
+}
+
+impl Eq for InputMessageVideoNote {}
+
+impl std::hash::Hash for InputMessageVideoNote {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.video_note, state);
+        std::hash::Hash::hash(&self.thumbnail, state);
+        std::hash::Hash::hash(&self.duration, state);
+        std::hash::Hash::hash(&self.length, state);
+    }
+}
+
 impl TDInputMessageContent for InputMessageVideoNote {}
 
 impl InputMessageVideoNote {
@@ -1778,10 +3020,24 @@ impl InputMessageVideoNote {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputMessageVideoNoteBuilder {
-        let mut inner = InputMessageVideoNote::default();
-        inner.td_name = "inputMessageVideoNote".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputMessageVideoNoteBuilder { inner }
+        RTDInputMessageVideoNoteBuilder {
+            inner: InputMessageVideoNote::default(),
+            missing: vec!["video_note", "thumbnail", "duration", "length"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputMessageVideoNoteBuilder {
+        RTDInputMessageVideoNoteBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputMessageVideoNoteBuilder {
+        RTDInputMessageVideoNoteBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn video_note(&self) -> &InputFile {
@@ -1792,42 +3048,87 @@ impl InputMessageVideoNote {
         &self.thumbnail
     }
 
-    pub fn duration(&self) -> i64 {
+    pub fn duration(&self) -> i32 {
         self.duration
     }
 
-    pub fn length(&self) -> i64 {
+    pub fn length(&self) -> i32 {
         self.length
     }
+
+    pub fn into_video_note(self) -> InputFile {
+        self.video_note
+    }
+
+    pub fn into_thumbnail(self) -> InputThumbnail {
+        self.thumbnail
+    }
+
+    pub fn with_video_note<T: AsRef<InputFile>>(mut self, video_note: T) -> Self {
+        self.video_note = video_note.as_ref().clone();
+        self
+    }
+
+    pub fn with_thumbnail<T: AsRef<InputThumbnail>>(mut self, thumbnail: T) -> Self {
+        self.thumbnail = thumbnail.as_ref().clone();
+        self
+    }
+
+    pub fn with_duration(mut self, duration: i32) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    pub fn with_length(mut self, length: i32) -> Self {
+        self.length = length;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputMessageVideoNoteBuilder {
     inner: InputMessageVideoNote,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputMessageVideoNoteBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputMessageVideoNote {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputMessageVideoNote> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputMessageVideoNote",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn video_note<T: AsRef<InputFile>>(&mut self, video_note: T) -> &mut Self {
         self.inner.video_note = video_note.as_ref().clone();
+        self.missing.retain(|f| *f != "video_note");
         self
     }
 
     pub fn thumbnail<T: AsRef<InputThumbnail>>(&mut self, thumbnail: T) -> &mut Self {
         self.inner.thumbnail = thumbnail.as_ref().clone();
+        self.missing.retain(|f| *f != "thumbnail");
         self
     }
 
-    pub fn duration(&mut self, duration: i64) -> &mut Self {
+    pub fn duration(&mut self, duration: i32) -> &mut Self {
         self.inner.duration = duration;
+        self.missing.retain(|f| *f != "duration");
         self
     }
 
-    pub fn length(&mut self, length: i64) -> &mut Self {
+    pub fn length(&mut self, length: i32) -> &mut Self {
         self.inner.length = length;
+        self.missing.retain(|f| *f != "length");
         self
     }
 }
@@ -1846,19 +3147,21 @@ impl AsRef<InputMessageVideoNote> for RTDInputMessageVideoNoteBuilder {
 
 /// A voice note message
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputMessageVoiceNote")]
 pub struct InputMessageVoiceNote {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Voice note to be sent
     voice_note: InputFile,
     /// Duration of the voice note, in seconds
-    duration: i64,
+    duration: i32,
     /// Waveform representation of the voice note, in 5-bit format
-    waveform: String,
+    #[serde(with = "super::_common::bytes")]
+    waveform: Vec<u8>,
     /// Voice note caption; 0-GetOption("message_caption_length_max") characters
     caption: FormattedText,
 }
@@ -1877,6 +3180,26 @@ impl RObject for InputMessageVoiceNote {
     }
 }
 
+impl PartialEq for InputMessageVoiceNote {
+    fn eq(&self, other: &Self) -> bool {
+        self.voice_note == other.voice_note
+            && self.duration == other.duration
+            && self.waveform == other.waveform
+            && self.caption == other.caption
+    }
+}
+
+impl Eq for InputMessageVoiceNote {}
+
+impl std::hash::Hash for InputMessageVoiceNote {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.voice_note, state);
+        std::hash::Hash::hash(&self.duration, state);
+        std::hash::Hash::hash(&self.waveform, state);
+        std::hash::Hash::hash(&self.caption, state);
+    }
+}
+
 impl TDInputMessageContent for InputMessageVoiceNote {}
 
 impl InputMessageVoiceNote {
@@ -1884,51 +3207,113 @@ impl InputMessageVoiceNote {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputMessageVoiceNoteBuilder {
-        let mut inner = InputMessageVoiceNote::default();
-        inner.td_name = "inputMessageVoiceNote".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputMessageVoiceNoteBuilder { inner }
+        RTDInputMessageVoiceNoteBuilder {
+            inner: InputMessageVoiceNote::default(),
+            missing: vec!["voice_note", "duration", "waveform"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputMessageVoiceNoteBuilder {
+        RTDInputMessageVoiceNoteBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputMessageVoiceNoteBuilder {
+        RTDInputMessageVoiceNoteBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn voice_note(&self) -> &InputFile {
         &self.voice_note
     }
 
-    pub fn duration(&self) -> i64 {
+    pub fn duration(&self) -> i32 {
         self.duration
     }
 
-    pub fn waveform(&self) -> &String {
+    pub fn waveform(&self) -> &[u8] {
         &self.waveform
     }
 
     pub fn caption(&self) -> &FormattedText {
         &self.caption
     }
+
+    pub fn into_voice_note(self) -> InputFile {
+        self.voice_note
+    }
+
+    pub fn into_waveform(self) -> Vec<u8> {
+        self.waveform
+    }
+
+    pub fn into_caption(self) -> FormattedText {
+        self.caption
+    }
+
+    pub fn with_voice_note<T: AsRef<InputFile>>(mut self, voice_note: T) -> Self {
+        self.voice_note = voice_note.as_ref().clone();
+        self
+    }
+
+    pub fn with_duration(mut self, duration: i32) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    pub fn with_waveform<T: AsRef<[u8]>>(mut self, waveform: T) -> Self {
+        self.waveform = waveform.as_ref().to_vec();
+        self
+    }
+
+    pub fn with_caption<T: AsRef<FormattedText>>(mut self, caption: T) -> Self {
+        self.caption = caption.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputMessageVoiceNoteBuilder {
     inner: InputMessageVoiceNote,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputMessageVoiceNoteBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputMessageVoiceNote {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputMessageVoiceNote> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputMessageVoiceNote",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn voice_note<T: AsRef<InputFile>>(&mut self, voice_note: T) -> &mut Self {
         self.inner.voice_note = voice_note.as_ref().clone();
+        self.missing.retain(|f| *f != "voice_note");
         self
     }
 
-    pub fn duration(&mut self, duration: i64) -> &mut Self {
+    pub fn duration(&mut self, duration: i32) -> &mut Self {
         self.inner.duration = duration;
+        self.missing.retain(|f| *f != "duration");
         self
     }
 
-    pub fn waveform<T: AsRef<str>>(&mut self, waveform: T) -> &mut Self {
-        self.inner.waveform = waveform.as_ref().to_string();
+    pub fn waveform<T: AsRef<[u8]>>(&mut self, waveform: T) -> &mut Self {
+        self.inner.waveform = waveform.as_ref().to_vec();
+        self.missing.retain(|f| *f != "waveform");
         self
     }
 
@@ -1949,3 +3334,349 @@ impl AsRef<InputMessageVoiceNote> for RTDInputMessageVoiceNoteBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(InputMessageContent::InputMessageAnimation(
+            InputMessageAnimation::builder()
+                .animation(InputFile::Generated(
+                    InputFileGenerated::builder()
+                        .original_path("text")
+                        .conversion("text")
+                        .expected_size(i32::MAX)
+                        .build(),
+                ))
+                .thumbnail(
+                    InputThumbnail::builder()
+                        .thumbnail(InputFile::Generated(
+                            InputFileGenerated::builder()
+                                .original_path("text")
+                                .conversion("text")
+                                .expected_size(i32::MAX)
+                                .build(),
+                        ))
+                        .width(i32::MAX)
+                        .height(i32::MAX)
+                        .build(),
+                )
+                .duration(i32::MAX)
+                .width(i32::MAX)
+                .height(i32::MAX)
+                .caption(
+                    FormattedText::builder()
+                        .text("text")
+                        .entities(vec![])
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(InputMessageContent::InputMessageAudio(
+            InputMessageAudio::builder()
+                .audio(InputFile::Generated(
+                    InputFileGenerated::builder()
+                        .original_path("text")
+                        .conversion("text")
+                        .expected_size(i32::MAX)
+                        .build(),
+                ))
+                .album_cover_thumbnail(
+                    InputThumbnail::builder()
+                        .thumbnail(InputFile::Generated(
+                            InputFileGenerated::builder()
+                                .original_path("text")
+                                .conversion("text")
+                                .expected_size(i32::MAX)
+                                .build(),
+                        ))
+                        .width(i32::MAX)
+                        .height(i32::MAX)
+                        .build(),
+                )
+                .duration(i32::MAX)
+                .title("text")
+                .performer("text")
+                .caption(
+                    FormattedText::builder()
+                        .text("text")
+                        .entities(vec![])
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(InputMessageContent::InputMessageContact(
+            InputMessageContact::builder()
+                .contact(
+                    Contact::builder()
+                        .phone_number("text")
+                        .first_name("text")
+                        .last_name("text")
+                        .vcard("text")
+                        .user_id(i32::MAX)
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(InputMessageContent::InputMessageDocument(
+            InputMessageDocument::builder()
+                .document(InputFile::Generated(
+                    InputFileGenerated::builder()
+                        .original_path("text")
+                        .conversion("text")
+                        .expected_size(i32::MAX)
+                        .build(),
+                ))
+                .thumbnail(
+                    InputThumbnail::builder()
+                        .thumbnail(InputFile::Generated(
+                            InputFileGenerated::builder()
+                                .original_path("text")
+                                .conversion("text")
+                                .expected_size(i32::MAX)
+                                .build(),
+                        ))
+                        .width(i32::MAX)
+                        .height(i32::MAX)
+                        .build(),
+                )
+                .caption(
+                    FormattedText::builder()
+                        .text("text")
+                        .entities(vec![])
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(InputMessageContent::InputMessageForwarded(
+            InputMessageForwarded::builder()
+                .from_chat_id(9007199254740991i64)
+                .message_id(9007199254740991i64)
+                .in_game_share(true)
+                .send_copy(true)
+                .remove_caption(true)
+                .build(),
+        ));
+        assert_round_trip(InputMessageContent::InputMessageGame(
+            InputMessageGame::builder()
+                .bot_user_id(i32::MAX)
+                .game_short_name("text")
+                .build(),
+        ));
+        assert_round_trip(InputMessageContent::InputMessageInvoice(
+            InputMessageInvoice::builder()
+                .invoice(
+                    Invoice::builder()
+                        .currency("text")
+                        .price_parts(vec![])
+                        .is_test(true)
+                        .need_name(true)
+                        .need_phone_number(true)
+                        .need_email_address(true)
+                        .need_shipping_address(true)
+                        .send_phone_number_to_provider(true)
+                        .send_email_address_to_provider(true)
+                        .is_flexible(true)
+                        .build(),
+                )
+                .title("text")
+                .description("text")
+                .photo_url("text")
+                .photo_size(i32::MAX)
+                .photo_width(i32::MAX)
+                .photo_height(i32::MAX)
+                .payload(b"\x00\x01\xfe\xff")
+                .provider_token("text")
+                .provider_data("text")
+                .start_parameter("text")
+                .build(),
+        ));
+        assert_round_trip(InputMessageContent::InputMessageLocation(
+            InputMessageLocation::builder()
+                .location(Location::builder().latitude(1.5).longitude(1.5).build())
+                .live_period(i32::MAX)
+                .build(),
+        ));
+        assert_round_trip(InputMessageContent::InputMessagePhoto(
+            InputMessagePhoto::builder()
+                .photo(InputFile::Generated(
+                    InputFileGenerated::builder()
+                        .original_path("text")
+                        .conversion("text")
+                        .expected_size(i32::MAX)
+                        .build(),
+                ))
+                .thumbnail(
+                    InputThumbnail::builder()
+                        .thumbnail(InputFile::Generated(
+                            InputFileGenerated::builder()
+                                .original_path("text")
+                                .conversion("text")
+                                .expected_size(i32::MAX)
+                                .build(),
+                        ))
+                        .width(i32::MAX)
+                        .height(i32::MAX)
+                        .build(),
+                )
+                .added_sticker_file_ids(vec![Default::default()])
+                .width(i32::MAX)
+                .height(i32::MAX)
+                .caption(
+                    FormattedText::builder()
+                        .text("text")
+                        .entities(vec![])
+                        .build(),
+                )
+                .ttl(i32::MAX)
+                .build(),
+        ));
+        assert_round_trip(InputMessageContent::InputMessagePoll(
+            InputMessagePoll::builder()
+                .question("text")
+                .options(vec!["text".to_string()])
+                .is_anonymous(true)
+                .type_(PollType::Quiz(
+                    PollTypeQuiz::builder().correct_option_id(i32::MAX).build(),
+                ))
+                .is_closed(true)
+                .build(),
+        ));
+        assert_round_trip(InputMessageContent::InputMessageSticker(
+            InputMessageSticker::builder()
+                .sticker(InputFile::Generated(
+                    InputFileGenerated::builder()
+                        .original_path("text")
+                        .conversion("text")
+                        .expected_size(i32::MAX)
+                        .build(),
+                ))
+                .thumbnail(
+                    InputThumbnail::builder()
+                        .thumbnail(InputFile::Generated(
+                            InputFileGenerated::builder()
+                                .original_path("text")
+                                .conversion("text")
+                                .expected_size(i32::MAX)
+                                .build(),
+                        ))
+                        .width(i32::MAX)
+                        .height(i32::MAX)
+                        .build(),
+                )
+                .width(i32::MAX)
+                .height(i32::MAX)
+                .build(),
+        ));
+        assert_round_trip(InputMessageContent::InputMessageText(
+            InputMessageText::builder()
+                .text(
+                    FormattedText::builder()
+                        .text("text")
+                        .entities(vec![])
+                        .build(),
+                )
+                .disable_web_page_preview(true)
+                .clear_draft(true)
+                .build(),
+        ));
+        assert_round_trip(InputMessageContent::InputMessageVenue(
+            InputMessageVenue::builder()
+                .venue(
+                    Venue::builder()
+                        .location(Location::builder().latitude(1.5).longitude(1.5).build())
+                        .title("text")
+                        .address("text")
+                        .provider("text")
+                        .id("text")
+                        .type_("text")
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(InputMessageContent::InputMessageVideo(
+            InputMessageVideo::builder()
+                .video(InputFile::Generated(
+                    InputFileGenerated::builder()
+                        .original_path("text")
+                        .conversion("text")
+                        .expected_size(i32::MAX)
+                        .build(),
+                ))
+                .thumbnail(
+                    InputThumbnail::builder()
+                        .thumbnail(InputFile::Generated(
+                            InputFileGenerated::builder()
+                                .original_path("text")
+                                .conversion("text")
+                                .expected_size(i32::MAX)
+                                .build(),
+                        ))
+                        .width(i32::MAX)
+                        .height(i32::MAX)
+                        .build(),
+                )
+                .added_sticker_file_ids(vec![Default::default()])
+                .duration(i32::MAX)
+                .width(i32::MAX)
+                .height(i32::MAX)
+                .supports_streaming(true)
+                .caption(
+                    FormattedText::builder()
+                        .text("text")
+                        .entities(vec![])
+                        .build(),
+                )
+                .ttl(i32::MAX)
+                .build(),
+        ));
+        assert_round_trip(InputMessageContent::InputMessageVideoNote(
+            InputMessageVideoNote::builder()
+                .video_note(InputFile::Generated(
+                    InputFileGenerated::builder()
+                        .original_path("text")
+                        .conversion("text")
+                        .expected_size(i32::MAX)
+                        .build(),
+                ))
+                .thumbnail(
+                    InputThumbnail::builder()
+                        .thumbnail(InputFile::Generated(
+                            InputFileGenerated::builder()
+                                .original_path("text")
+                                .conversion("text")
+                                .expected_size(i32::MAX)
+                                .build(),
+                        ))
+                        .width(i32::MAX)
+                        .height(i32::MAX)
+                        .build(),
+                )
+                .duration(i32::MAX)
+                .length(i32::MAX)
+                .build(),
+        ));
+        assert_round_trip(InputMessageContent::InputMessageVoiceNote(
+            InputMessageVoiceNote::builder()
+                .voice_note(InputFile::Generated(
+                    InputFileGenerated::builder()
+                        .original_path("text")
+                        .conversion("text")
+                        .expected_size(i32::MAX)
+                        .build(),
+                ))
+                .duration(i32::MAX)
+                .waveform(b"\x00\x01\xfe\xff")
+                .caption(
+                    FormattedText::builder()
+                        .text("text")
+                        .entities(vec![])
+                        .build(),
+                )
+                .build(),
+        ));
+    }
+}
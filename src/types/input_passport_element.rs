@@ -1,76 +1,84 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Contains information about a Telegram Passport element to be saved
 pub trait TDInputPassportElement: Debug + RObject {}
 
 /// Contains information about a Telegram Passport element to be saved
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum InputPassportElement {
-    #[doc(hidden)]
-    _Default(()),
     /// A Telegram Passport element to be saved containing the user's address
+    #[serde(rename = "inputPassportElementAddress")]
     Address(InputPassportElementAddress),
     /// A Telegram Passport element to be saved containing the user's bank statement
+    #[serde(rename = "inputPassportElementBankStatement")]
     BankStatement(InputPassportElementBankStatement),
     /// A Telegram Passport element to be saved containing the user's driver license
+    #[serde(rename = "inputPassportElementDriverLicense")]
     DriverLicense(InputPassportElementDriverLicense),
     /// A Telegram Passport element to be saved containing the user's email address
+    #[serde(rename = "inputPassportElementEmailAddress")]
     EmailAddress(InputPassportElementEmailAddress),
     /// A Telegram Passport element to be saved containing the user's identity card
+    #[serde(rename = "inputPassportElementIdentityCard")]
     IdentityCard(InputPassportElementIdentityCard),
     /// A Telegram Passport element to be saved containing the user's internal passport
+    #[serde(rename = "inputPassportElementInternalPassport")]
     InternalPassport(InputPassportElementInternalPassport),
     /// A Telegram Passport element to be saved containing the user's passport
+    #[serde(rename = "inputPassportElementPassport")]
     Passport(InputPassportElementPassport),
     /// A Telegram Passport element to be saved containing the user's passport registration
+    #[serde(rename = "inputPassportElementPassportRegistration")]
     PassportRegistration(InputPassportElementPassportRegistration),
     /// A Telegram Passport element to be saved containing the user's personal details
+    #[serde(rename = "inputPassportElementPersonalDetails")]
     PersonalDetails(InputPassportElementPersonalDetails),
     /// A Telegram Passport element to be saved containing the user's phone number
+    #[serde(rename = "inputPassportElementPhoneNumber")]
     PhoneNumber(InputPassportElementPhoneNumber),
     /// A Telegram Passport element to be saved containing the user's rental agreement
+    #[serde(rename = "inputPassportElementRentalAgreement")]
     RentalAgreement(InputPassportElementRentalAgreement),
     /// A Telegram Passport element to be saved containing the user's temporary registration
+    #[serde(rename = "inputPassportElementTemporaryRegistration")]
     TemporaryRegistration(InputPassportElementTemporaryRegistration),
     /// A Telegram Passport element to be saved containing the user's utility bill
+    #[serde(rename = "inputPassportElementUtilityBill")]
     UtilityBill(InputPassportElementUtilityBill),
 }
 
+/// Default value is default of [InputPassportElementEmailAddress], builders still require the field to be set
 impl Default for InputPassportElement {
     fn default() -> Self {
-        InputPassportElement::_Default(())
+        InputPassportElement::EmailAddress(InputPassportElementEmailAddress::default())
     }
 }
 
-impl<'de> Deserialize<'de> for InputPassportElement {
-    fn deserialize<D>(deserializer: D) -> Result<InputPassportElement, D::Error>
+impl Serialize for InputPassportElement {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          InputPassportElement,
-          (inputPassportElementAddress, Address);
-          (inputPassportElementBankStatement, BankStatement);
-          (inputPassportElementDriverLicense, DriverLicense);
-          (inputPassportElementEmailAddress, EmailAddress);
-          (inputPassportElementIdentityCard, IdentityCard);
-          (inputPassportElementInternalPassport, InternalPassport);
-          (inputPassportElementPassport, Passport);
-          (inputPassportElementPassportRegistration, PassportRegistration);
-          (inputPassportElementPersonalDetails, PersonalDetails);
-          (inputPassportElementPhoneNumber, PhoneNumber);
-          (inputPassportElementRentalAgreement, RentalAgreement);
-          (inputPassportElementTemporaryRegistration, TemporaryRegistration);
-          (inputPassportElementUtilityBill, UtilityBill);
-
-        )(deserializer)
+        match self {
+            InputPassportElement::Address(t) => t.serialize(serializer),
+            InputPassportElement::BankStatement(t) => t.serialize(serializer),
+            InputPassportElement::DriverLicense(t) => t.serialize(serializer),
+            InputPassportElement::EmailAddress(t) => t.serialize(serializer),
+            InputPassportElement::IdentityCard(t) => t.serialize(serializer),
+            InputPassportElement::InternalPassport(t) => t.serialize(serializer),
+            InputPassportElement::Passport(t) => t.serialize(serializer),
+            InputPassportElement::PassportRegistration(t) => t.serialize(serializer),
+            InputPassportElement::PersonalDetails(t) => t.serialize(serializer),
+            InputPassportElement::PhoneNumber(t) => t.serialize(serializer),
+            InputPassportElement::RentalAgreement(t) => t.serialize(serializer),
+            InputPassportElement::TemporaryRegistration(t) => t.serialize(serializer),
+            InputPassportElement::UtilityBill(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -91,8 +99,6 @@ impl RObject for InputPassportElement {
             InputPassportElement::RentalAgreement(t) => t.td_name(),
             InputPassportElement::TemporaryRegistration(t) => t.td_name(),
             InputPassportElement::UtilityBill(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -111,8 +117,6 @@ impl RObject for InputPassportElement {
             InputPassportElement::RentalAgreement(t) => t.extra(),
             InputPassportElement::TemporaryRegistration(t) => t.extra(),
             InputPassportElement::UtilityBill(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -124,10 +128,6 @@ impl InputPassportElement {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, InputPassportElement::_Default(_))
-    }
 }
 
 impl AsRef<InputPassportElement> for InputPassportElement {
@@ -138,12 +138,13 @@ impl AsRef<InputPassportElement> for InputPassportElement {
 
 /// A Telegram Passport element to be saved containing the user's address
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputPassportElementAddress")]
 pub struct InputPassportElementAddress {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The address to be saved
     address: Address,
@@ -163,6 +164,20 @@ impl RObject for InputPassportElementAddress {
     }
 }
 
+impl PartialEq for InputPassportElementAddress {
+    fn eq(&self, other: &Self) -> bool {
+        self.address == other.address
+    }
+}
+
+impl Eq for InputPassportElementAddress {}
+
+impl std::hash::Hash for InputPassportElementAddress {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.address, state);
+    }
+}
+
 impl TDInputPassportElement for InputPassportElementAddress {}
 
 impl InputPassportElementAddress {
@@ -170,29 +185,66 @@ impl InputPassportElementAddress {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputPassportElementAddressBuilder {
-        let mut inner = InputPassportElementAddress::default();
-        inner.td_name = "inputPassportElementAddress".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputPassportElementAddressBuilder { inner }
+        RTDInputPassportElementAddressBuilder {
+            inner: InputPassportElementAddress::default(),
+            missing: vec!["address"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputPassportElementAddressBuilder {
+        RTDInputPassportElementAddressBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputPassportElementAddressBuilder {
+        RTDInputPassportElementAddressBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn address(&self) -> &Address {
         &self.address
     }
+
+    pub fn into_address(self) -> Address {
+        self.address
+    }
+
+    pub fn with_address<T: AsRef<Address>>(mut self, address: T) -> Self {
+        self.address = address.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputPassportElementAddressBuilder {
     inner: InputPassportElementAddress,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputPassportElementAddressBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputPassportElementAddress {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputPassportElementAddress> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputPassportElementAddress",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn address<T: AsRef<Address>>(&mut self, address: T) -> &mut Self {
         self.inner.address = address.as_ref().clone();
+        self.missing.retain(|f| *f != "address");
         self
     }
 }
@@ -211,12 +263,13 @@ impl AsRef<InputPassportElementAddress> for RTDInputPassportElementAddressBuilde
 
 /// A Telegram Passport element to be saved containing the user's bank statement
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputPassportElementBankStatement")]
 pub struct InputPassportElementBankStatement {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The bank statement to be saved
     bank_statement: InputPersonalDocument,
@@ -236,6 +289,20 @@ impl RObject for InputPassportElementBankStatement {
     }
 }
 
+impl PartialEq for InputPassportElementBankStatement {
+    fn eq(&self, other: &Self) -> bool {
+        self.bank_statement == other.bank_statement
+    }
+}
+
+impl Eq for InputPassportElementBankStatement {}
+
+impl std::hash::Hash for InputPassportElementBankStatement {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.bank_statement, state);
+    }
+}
+
 impl TDInputPassportElement for InputPassportElementBankStatement {}
 
 impl InputPassportElementBankStatement {
@@ -243,32 +310,72 @@ impl InputPassportElementBankStatement {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputPassportElementBankStatementBuilder {
-        let mut inner = InputPassportElementBankStatement::default();
-        inner.td_name = "inputPassportElementBankStatement".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputPassportElementBankStatementBuilder { inner }
+        RTDInputPassportElementBankStatementBuilder {
+            inner: InputPassportElementBankStatement::default(),
+            missing: vec!["bank_statement"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputPassportElementBankStatementBuilder {
+        RTDInputPassportElementBankStatementBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputPassportElementBankStatementBuilder {
+        RTDInputPassportElementBankStatementBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn bank_statement(&self) -> &InputPersonalDocument {
         &self.bank_statement
     }
+
+    pub fn into_bank_statement(self) -> InputPersonalDocument {
+        self.bank_statement
+    }
+
+    pub fn with_bank_statement<T: AsRef<InputPersonalDocument>>(
+        mut self,
+        bank_statement: T,
+    ) -> Self {
+        self.bank_statement = bank_statement.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputPassportElementBankStatementBuilder {
     inner: InputPassportElementBankStatement,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputPassportElementBankStatementBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputPassportElementBankStatement {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputPassportElementBankStatement> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputPassportElementBankStatement",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn bank_statement<T: AsRef<InputPersonalDocument>>(
         &mut self,
         bank_statement: T,
     ) -> &mut Self {
         self.inner.bank_statement = bank_statement.as_ref().clone();
+        self.missing.retain(|f| *f != "bank_statement");
         self
     }
 }
@@ -287,12 +394,13 @@ impl AsRef<InputPassportElementBankStatement> for RTDInputPassportElementBankSta
 
 /// A Telegram Passport element to be saved containing the user's driver license
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputPassportElementDriverLicense")]
 pub struct InputPassportElementDriverLicense {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The driver license to be saved
     driver_license: InputIdentityDocument,
@@ -312,6 +420,20 @@ impl RObject for InputPassportElementDriverLicense {
     }
 }
 
+impl PartialEq for InputPassportElementDriverLicense {
+    fn eq(&self, other: &Self) -> bool {
+        self.driver_license == other.driver_license
+    }
+}
+
+impl Eq for InputPassportElementDriverLicense {}
+
+impl std::hash::Hash for InputPassportElementDriverLicense {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.driver_license, state);
+    }
+}
+
 impl TDInputPassportElement for InputPassportElementDriverLicense {}
 
 impl InputPassportElementDriverLicense {
@@ -319,32 +441,72 @@ impl InputPassportElementDriverLicense {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputPassportElementDriverLicenseBuilder {
-        let mut inner = InputPassportElementDriverLicense::default();
-        inner.td_name = "inputPassportElementDriverLicense".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputPassportElementDriverLicenseBuilder { inner }
+        RTDInputPassportElementDriverLicenseBuilder {
+            inner: InputPassportElementDriverLicense::default(),
+            missing: vec!["driver_license"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputPassportElementDriverLicenseBuilder {
+        RTDInputPassportElementDriverLicenseBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputPassportElementDriverLicenseBuilder {
+        RTDInputPassportElementDriverLicenseBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn driver_license(&self) -> &InputIdentityDocument {
         &self.driver_license
     }
+
+    pub fn into_driver_license(self) -> InputIdentityDocument {
+        self.driver_license
+    }
+
+    pub fn with_driver_license<T: AsRef<InputIdentityDocument>>(
+        mut self,
+        driver_license: T,
+    ) -> Self {
+        self.driver_license = driver_license.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputPassportElementDriverLicenseBuilder {
     inner: InputPassportElementDriverLicense,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputPassportElementDriverLicenseBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputPassportElementDriverLicense {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputPassportElementDriverLicense> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputPassportElementDriverLicense",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn driver_license<T: AsRef<InputIdentityDocument>>(
         &mut self,
         driver_license: T,
     ) -> &mut Self {
         self.inner.driver_license = driver_license.as_ref().clone();
+        self.missing.retain(|f| *f != "driver_license");
         self
     }
 }
@@ -363,12 +525,13 @@ impl AsRef<InputPassportElementDriverLicense> for RTDInputPassportElementDriverL
 
 /// A Telegram Passport element to be saved containing the user's email address
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputPassportElementEmailAddress")]
 pub struct InputPassportElementEmailAddress {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The email address to be saved
     email_address: String,
@@ -388,6 +551,20 @@ impl RObject for InputPassportElementEmailAddress {
     }
 }
 
+impl PartialEq for InputPassportElementEmailAddress {
+    fn eq(&self, other: &Self) -> bool {
+        self.email_address == other.email_address
+    }
+}
+
+impl Eq for InputPassportElementEmailAddress {}
+
+impl std::hash::Hash for InputPassportElementEmailAddress {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.email_address, state);
+    }
+}
+
 impl TDInputPassportElement for InputPassportElementEmailAddress {}
 
 impl InputPassportElementEmailAddress {
@@ -395,29 +572,66 @@ impl InputPassportElementEmailAddress {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputPassportElementEmailAddressBuilder {
-        let mut inner = InputPassportElementEmailAddress::default();
-        inner.td_name = "inputPassportElementEmailAddress".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputPassportElementEmailAddressBuilder { inner }
+        RTDInputPassportElementEmailAddressBuilder {
+            inner: InputPassportElementEmailAddress::default(),
+            missing: vec!["email_address"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputPassportElementEmailAddressBuilder {
+        RTDInputPassportElementEmailAddressBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputPassportElementEmailAddressBuilder {
+        RTDInputPassportElementEmailAddressBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn email_address(&self) -> &String {
         &self.email_address
     }
+
+    pub fn into_email_address(self) -> String {
+        self.email_address
+    }
+
+    pub fn with_email_address<T: AsRef<str>>(mut self, email_address: T) -> Self {
+        self.email_address = email_address.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputPassportElementEmailAddressBuilder {
     inner: InputPassportElementEmailAddress,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputPassportElementEmailAddressBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputPassportElementEmailAddress {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputPassportElementEmailAddress> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputPassportElementEmailAddress",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn email_address<T: AsRef<str>>(&mut self, email_address: T) -> &mut Self {
         self.inner.email_address = email_address.as_ref().to_string();
+        self.missing.retain(|f| *f != "email_address");
         self
     }
 }
@@ -436,12 +650,13 @@ impl AsRef<InputPassportElementEmailAddress> for RTDInputPassportElementEmailAdd
 
 /// A Telegram Passport element to be saved containing the user's identity card
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputPassportElementIdentityCard")]
 pub struct InputPassportElementIdentityCard {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The identity card to be saved
     identity_card: InputIdentityDocument,
@@ -461,6 +676,20 @@ impl RObject for InputPassportElementIdentityCard {
     }
 }
 
+impl PartialEq for InputPassportElementIdentityCard {
+    fn eq(&self, other: &Self) -> bool {
+        self.identity_card == other.identity_card
+    }
+}
+
+impl Eq for InputPassportElementIdentityCard {}
+
+impl std::hash::Hash for InputPassportElementIdentityCard {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.identity_card, state);
+    }
+}
+
 impl TDInputPassportElement for InputPassportElementIdentityCard {}
 
 impl InputPassportElementIdentityCard {
@@ -468,32 +697,69 @@ impl InputPassportElementIdentityCard {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputPassportElementIdentityCardBuilder {
-        let mut inner = InputPassportElementIdentityCard::default();
-        inner.td_name = "inputPassportElementIdentityCard".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputPassportElementIdentityCardBuilder { inner }
+        RTDInputPassportElementIdentityCardBuilder {
+            inner: InputPassportElementIdentityCard::default(),
+            missing: vec!["identity_card"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputPassportElementIdentityCardBuilder {
+        RTDInputPassportElementIdentityCardBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputPassportElementIdentityCardBuilder {
+        RTDInputPassportElementIdentityCardBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn identity_card(&self) -> &InputIdentityDocument {
         &self.identity_card
     }
+
+    pub fn into_identity_card(self) -> InputIdentityDocument {
+        self.identity_card
+    }
+
+    pub fn with_identity_card<T: AsRef<InputIdentityDocument>>(mut self, identity_card: T) -> Self {
+        self.identity_card = identity_card.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputPassportElementIdentityCardBuilder {
     inner: InputPassportElementIdentityCard,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputPassportElementIdentityCardBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputPassportElementIdentityCard {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputPassportElementIdentityCard> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputPassportElementIdentityCard",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn identity_card<T: AsRef<InputIdentityDocument>>(
         &mut self,
         identity_card: T,
     ) -> &mut Self {
         self.inner.identity_card = identity_card.as_ref().clone();
+        self.missing.retain(|f| *f != "identity_card");
         self
     }
 }
@@ -512,12 +778,13 @@ impl AsRef<InputPassportElementIdentityCard> for RTDInputPassportElementIdentity
 
 /// A Telegram Passport element to be saved containing the user's internal passport
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputPassportElementInternalPassport")]
 pub struct InputPassportElementInternalPassport {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The internal passport to be saved
     internal_passport: InputIdentityDocument,
@@ -537,6 +804,20 @@ impl RObject for InputPassportElementInternalPassport {
     }
 }
 
+impl PartialEq for InputPassportElementInternalPassport {
+    fn eq(&self, other: &Self) -> bool {
+        self.internal_passport == other.internal_passport
+    }
+}
+
+impl Eq for InputPassportElementInternalPassport {}
+
+impl std::hash::Hash for InputPassportElementInternalPassport {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.internal_passport, state);
+    }
+}
+
 impl TDInputPassportElement for InputPassportElementInternalPassport {}
 
 impl InputPassportElementInternalPassport {
@@ -544,32 +825,72 @@ impl InputPassportElementInternalPassport {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputPassportElementInternalPassportBuilder {
-        let mut inner = InputPassportElementInternalPassport::default();
-        inner.td_name = "inputPassportElementInternalPassport".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputPassportElementInternalPassportBuilder { inner }
+        RTDInputPassportElementInternalPassportBuilder {
+            inner: InputPassportElementInternalPassport::default(),
+            missing: vec!["internal_passport"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputPassportElementInternalPassportBuilder {
+        RTDInputPassportElementInternalPassportBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputPassportElementInternalPassportBuilder {
+        RTDInputPassportElementInternalPassportBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn internal_passport(&self) -> &InputIdentityDocument {
         &self.internal_passport
     }
+
+    pub fn into_internal_passport(self) -> InputIdentityDocument {
+        self.internal_passport
+    }
+
+    pub fn with_internal_passport<T: AsRef<InputIdentityDocument>>(
+        mut self,
+        internal_passport: T,
+    ) -> Self {
+        self.internal_passport = internal_passport.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputPassportElementInternalPassportBuilder {
     inner: InputPassportElementInternalPassport,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputPassportElementInternalPassportBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputPassportElementInternalPassport {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputPassportElementInternalPassport> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputPassportElementInternalPassport",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn internal_passport<T: AsRef<InputIdentityDocument>>(
         &mut self,
         internal_passport: T,
     ) -> &mut Self {
         self.inner.internal_passport = internal_passport.as_ref().clone();
+        self.missing.retain(|f| *f != "internal_passport");
         self
     }
 }
@@ -590,12 +911,13 @@ impl AsRef<InputPassportElementInternalPassport>
 
 /// A Telegram Passport element to be saved containing the user's passport
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputPassportElementPassport")]
 pub struct InputPassportElementPassport {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The passport to be saved
     passport: InputIdentityDocument,
@@ -615,6 +937,20 @@ impl RObject for InputPassportElementPassport {
     }
 }
 
+impl PartialEq for InputPassportElementPassport {
+    fn eq(&self, other: &Self) -> bool {
+        self.passport == other.passport
+    }
+}
+
+impl Eq for InputPassportElementPassport {}
+
+impl std::hash::Hash for InputPassportElementPassport {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.passport, state);
+    }
+}
+
 impl TDInputPassportElement for InputPassportElementPassport {}
 
 impl InputPassportElementPassport {
@@ -622,29 +958,66 @@ impl InputPassportElementPassport {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputPassportElementPassportBuilder {
-        let mut inner = InputPassportElementPassport::default();
-        inner.td_name = "inputPassportElementPassport".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputPassportElementPassportBuilder { inner }
+        RTDInputPassportElementPassportBuilder {
+            inner: InputPassportElementPassport::default(),
+            missing: vec!["passport"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputPassportElementPassportBuilder {
+        RTDInputPassportElementPassportBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputPassportElementPassportBuilder {
+        RTDInputPassportElementPassportBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn passport(&self) -> &InputIdentityDocument {
         &self.passport
     }
+
+    pub fn into_passport(self) -> InputIdentityDocument {
+        self.passport
+    }
+
+    pub fn with_passport<T: AsRef<InputIdentityDocument>>(mut self, passport: T) -> Self {
+        self.passport = passport.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputPassportElementPassportBuilder {
     inner: InputPassportElementPassport,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputPassportElementPassportBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputPassportElementPassport {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputPassportElementPassport> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputPassportElementPassport",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn passport<T: AsRef<InputIdentityDocument>>(&mut self, passport: T) -> &mut Self {
         self.inner.passport = passport.as_ref().clone();
+        self.missing.retain(|f| *f != "passport");
         self
     }
 }
@@ -663,12 +1036,13 @@ impl AsRef<InputPassportElementPassport> for RTDInputPassportElementPassportBuil
 
 /// A Telegram Passport element to be saved containing the user's passport registration
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputPassportElementPassportRegistration")]
 pub struct InputPassportElementPassportRegistration {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The passport registration page to be saved
     passport_registration: InputPersonalDocument,
@@ -688,6 +1062,20 @@ impl RObject for InputPassportElementPassportRegistration {
     }
 }
 
+impl PartialEq for InputPassportElementPassportRegistration {
+    fn eq(&self, other: &Self) -> bool {
+        self.passport_registration == other.passport_registration
+    }
+}
+
+impl Eq for InputPassportElementPassportRegistration {}
+
+impl std::hash::Hash for InputPassportElementPassportRegistration {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.passport_registration, state);
+    }
+}
+
 impl TDInputPassportElement for InputPassportElementPassportRegistration {}
 
 impl InputPassportElementPassportRegistration {
@@ -695,32 +1083,72 @@ impl InputPassportElementPassportRegistration {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputPassportElementPassportRegistrationBuilder {
-        let mut inner = InputPassportElementPassportRegistration::default();
-        inner.td_name = "inputPassportElementPassportRegistration".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputPassportElementPassportRegistrationBuilder { inner }
+        RTDInputPassportElementPassportRegistrationBuilder {
+            inner: InputPassportElementPassportRegistration::default(),
+            missing: vec!["passport_registration"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputPassportElementPassportRegistrationBuilder {
+        RTDInputPassportElementPassportRegistrationBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputPassportElementPassportRegistrationBuilder {
+        RTDInputPassportElementPassportRegistrationBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn passport_registration(&self) -> &InputPersonalDocument {
         &self.passport_registration
     }
+
+    pub fn into_passport_registration(self) -> InputPersonalDocument {
+        self.passport_registration
+    }
+
+    pub fn with_passport_registration<T: AsRef<InputPersonalDocument>>(
+        mut self,
+        passport_registration: T,
+    ) -> Self {
+        self.passport_registration = passport_registration.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputPassportElementPassportRegistrationBuilder {
     inner: InputPassportElementPassportRegistration,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputPassportElementPassportRegistrationBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputPassportElementPassportRegistration {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputPassportElementPassportRegistration> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputPassportElementPassportRegistration",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn passport_registration<T: AsRef<InputPersonalDocument>>(
         &mut self,
         passport_registration: T,
     ) -> &mut Self {
         self.inner.passport_registration = passport_registration.as_ref().clone();
+        self.missing.retain(|f| *f != "passport_registration");
         self
     }
 }
@@ -741,12 +1169,13 @@ impl AsRef<InputPassportElementPassportRegistration>
 
 /// A Telegram Passport element to be saved containing the user's personal details
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputPassportElementPersonalDetails")]
 pub struct InputPassportElementPersonalDetails {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Personal details of the user
     personal_details: PersonalDetails,
@@ -766,6 +1195,20 @@ impl RObject for InputPassportElementPersonalDetails {
     }
 }
 
+impl PartialEq for InputPassportElementPersonalDetails {
+    fn eq(&self, other: &Self) -> bool {
+        self.personal_details == other.personal_details
+    }
+}
+
+impl Eq for InputPassportElementPersonalDetails {}
+
+impl std::hash::Hash for InputPassportElementPersonalDetails {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.personal_details, state);
+    }
+}
+
 impl TDInputPassportElement for InputPassportElementPersonalDetails {}
 
 impl InputPassportElementPersonalDetails {
@@ -773,32 +1216,69 @@ impl InputPassportElementPersonalDetails {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputPassportElementPersonalDetailsBuilder {
-        let mut inner = InputPassportElementPersonalDetails::default();
-        inner.td_name = "inputPassportElementPersonalDetails".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputPassportElementPersonalDetailsBuilder { inner }
+        RTDInputPassportElementPersonalDetailsBuilder {
+            inner: InputPassportElementPersonalDetails::default(),
+            missing: vec!["personal_details"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputPassportElementPersonalDetailsBuilder {
+        RTDInputPassportElementPersonalDetailsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputPassportElementPersonalDetailsBuilder {
+        RTDInputPassportElementPersonalDetailsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn personal_details(&self) -> &PersonalDetails {
         &self.personal_details
     }
+
+    pub fn into_personal_details(self) -> PersonalDetails {
+        self.personal_details
+    }
+
+    pub fn with_personal_details<T: AsRef<PersonalDetails>>(mut self, personal_details: T) -> Self {
+        self.personal_details = personal_details.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputPassportElementPersonalDetailsBuilder {
     inner: InputPassportElementPersonalDetails,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputPassportElementPersonalDetailsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputPassportElementPersonalDetails {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputPassportElementPersonalDetails> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputPassportElementPersonalDetails",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn personal_details<T: AsRef<PersonalDetails>>(
         &mut self,
         personal_details: T,
     ) -> &mut Self {
         self.inner.personal_details = personal_details.as_ref().clone();
+        self.missing.retain(|f| *f != "personal_details");
         self
     }
 }
@@ -817,12 +1297,13 @@ impl AsRef<InputPassportElementPersonalDetails> for RTDInputPassportElementPerso
 
 /// A Telegram Passport element to be saved containing the user's phone number
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputPassportElementPhoneNumber")]
 pub struct InputPassportElementPhoneNumber {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The phone number to be saved
     phone_number: String,
@@ -842,6 +1323,20 @@ impl RObject for InputPassportElementPhoneNumber {
     }
 }
 
+impl PartialEq for InputPassportElementPhoneNumber {
+    fn eq(&self, other: &Self) -> bool {
+        self.phone_number == other.phone_number
+    }
+}
+
+impl Eq for InputPassportElementPhoneNumber {}
+
+impl std::hash::Hash for InputPassportElementPhoneNumber {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.phone_number, state);
+    }
+}
+
 impl TDInputPassportElement for InputPassportElementPhoneNumber {}
 
 impl InputPassportElementPhoneNumber {
@@ -849,29 +1344,66 @@ impl InputPassportElementPhoneNumber {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputPassportElementPhoneNumberBuilder {
-        let mut inner = InputPassportElementPhoneNumber::default();
-        inner.td_name = "inputPassportElementPhoneNumber".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputPassportElementPhoneNumberBuilder { inner }
+        RTDInputPassportElementPhoneNumberBuilder {
+            inner: InputPassportElementPhoneNumber::default(),
+            missing: vec!["phone_number"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputPassportElementPhoneNumberBuilder {
+        RTDInputPassportElementPhoneNumberBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputPassportElementPhoneNumberBuilder {
+        RTDInputPassportElementPhoneNumberBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn phone_number(&self) -> &String {
         &self.phone_number
     }
+
+    pub fn into_phone_number(self) -> String {
+        self.phone_number
+    }
+
+    pub fn with_phone_number<T: AsRef<str>>(mut self, phone_number: T) -> Self {
+        self.phone_number = phone_number.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputPassportElementPhoneNumberBuilder {
     inner: InputPassportElementPhoneNumber,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputPassportElementPhoneNumberBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputPassportElementPhoneNumber {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputPassportElementPhoneNumber> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputPassportElementPhoneNumber",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn phone_number<T: AsRef<str>>(&mut self, phone_number: T) -> &mut Self {
         self.inner.phone_number = phone_number.as_ref().to_string();
+        self.missing.retain(|f| *f != "phone_number");
         self
     }
 }
@@ -890,12 +1422,13 @@ impl AsRef<InputPassportElementPhoneNumber> for RTDInputPassportElementPhoneNumb
 
 /// A Telegram Passport element to be saved containing the user's rental agreement
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputPassportElementRentalAgreement")]
 pub struct InputPassportElementRentalAgreement {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The rental agreement to be saved
     rental_agreement: InputPersonalDocument,
@@ -915,6 +1448,20 @@ impl RObject for InputPassportElementRentalAgreement {
     }
 }
 
+impl PartialEq for InputPassportElementRentalAgreement {
+    fn eq(&self, other: &Self) -> bool {
+        self.rental_agreement == other.rental_agreement
+    }
+}
+
+impl Eq for InputPassportElementRentalAgreement {}
+
+impl std::hash::Hash for InputPassportElementRentalAgreement {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.rental_agreement, state);
+    }
+}
+
 impl TDInputPassportElement for InputPassportElementRentalAgreement {}
 
 impl InputPassportElementRentalAgreement {
@@ -922,32 +1469,72 @@ impl InputPassportElementRentalAgreement {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputPassportElementRentalAgreementBuilder {
-        let mut inner = InputPassportElementRentalAgreement::default();
-        inner.td_name = "inputPassportElementRentalAgreement".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputPassportElementRentalAgreementBuilder { inner }
+        RTDInputPassportElementRentalAgreementBuilder {
+            inner: InputPassportElementRentalAgreement::default(),
+            missing: vec!["rental_agreement"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputPassportElementRentalAgreementBuilder {
+        RTDInputPassportElementRentalAgreementBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputPassportElementRentalAgreementBuilder {
+        RTDInputPassportElementRentalAgreementBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn rental_agreement(&self) -> &InputPersonalDocument {
         &self.rental_agreement
     }
+
+    pub fn into_rental_agreement(self) -> InputPersonalDocument {
+        self.rental_agreement
+    }
+
+    pub fn with_rental_agreement<T: AsRef<InputPersonalDocument>>(
+        mut self,
+        rental_agreement: T,
+    ) -> Self {
+        self.rental_agreement = rental_agreement.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputPassportElementRentalAgreementBuilder {
     inner: InputPassportElementRentalAgreement,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputPassportElementRentalAgreementBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputPassportElementRentalAgreement {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputPassportElementRentalAgreement> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputPassportElementRentalAgreement",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn rental_agreement<T: AsRef<InputPersonalDocument>>(
         &mut self,
         rental_agreement: T,
     ) -> &mut Self {
         self.inner.rental_agreement = rental_agreement.as_ref().clone();
+        self.missing.retain(|f| *f != "rental_agreement");
         self
     }
 }
@@ -966,12 +1553,13 @@ impl AsRef<InputPassportElementRentalAgreement> for RTDInputPassportElementRenta
 
 /// A Telegram Passport element to be saved containing the user's temporary registration
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputPassportElementTemporaryRegistration")]
 pub struct InputPassportElementTemporaryRegistration {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The temporary registration document to be saved
     temporary_registration: InputPersonalDocument,
@@ -991,6 +1579,20 @@ impl RObject for InputPassportElementTemporaryRegistration {
     }
 }
 
+impl PartialEq for InputPassportElementTemporaryRegistration {
+    fn eq(&self, other: &Self) -> bool {
+        self.temporary_registration == other.temporary_registration
+    }
+}
+
+impl Eq for InputPassportElementTemporaryRegistration {}
+
+impl std::hash::Hash for InputPassportElementTemporaryRegistration {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.temporary_registration, state);
+    }
+}
+
 impl TDInputPassportElement for InputPassportElementTemporaryRegistration {}
 
 impl InputPassportElementTemporaryRegistration {
@@ -998,32 +1600,72 @@ impl InputPassportElementTemporaryRegistration {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputPassportElementTemporaryRegistrationBuilder {
-        let mut inner = InputPassportElementTemporaryRegistration::default();
-        inner.td_name = "inputPassportElementTemporaryRegistration".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputPassportElementTemporaryRegistrationBuilder { inner }
+        RTDInputPassportElementTemporaryRegistrationBuilder {
+            inner: InputPassportElementTemporaryRegistration::default(),
+            missing: vec!["temporary_registration"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputPassportElementTemporaryRegistrationBuilder {
+        RTDInputPassportElementTemporaryRegistrationBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputPassportElementTemporaryRegistrationBuilder {
+        RTDInputPassportElementTemporaryRegistrationBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn temporary_registration(&self) -> &InputPersonalDocument {
         &self.temporary_registration
     }
+
+    pub fn into_temporary_registration(self) -> InputPersonalDocument {
+        self.temporary_registration
+    }
+
+    pub fn with_temporary_registration<T: AsRef<InputPersonalDocument>>(
+        mut self,
+        temporary_registration: T,
+    ) -> Self {
+        self.temporary_registration = temporary_registration.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputPassportElementTemporaryRegistrationBuilder {
     inner: InputPassportElementTemporaryRegistration,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputPassportElementTemporaryRegistrationBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputPassportElementTemporaryRegistration {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputPassportElementTemporaryRegistration> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputPassportElementTemporaryRegistration",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn temporary_registration<T: AsRef<InputPersonalDocument>>(
         &mut self,
         temporary_registration: T,
     ) -> &mut Self {
         self.inner.temporary_registration = temporary_registration.as_ref().clone();
+        self.missing.retain(|f| *f != "temporary_registration");
         self
     }
 }
@@ -1046,12 +1688,13 @@ impl AsRef<InputPassportElementTemporaryRegistration>
 
 /// A Telegram Passport element to be saved containing the user's utility bill
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputPassportElementUtilityBill")]
 pub struct InputPassportElementUtilityBill {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The utility bill to be saved
     utility_bill: InputPersonalDocument,
@@ -1071,6 +1714,20 @@ impl RObject for InputPassportElementUtilityBill {
     }
 }
 
+impl PartialEq for InputPassportElementUtilityBill {
+    fn eq(&self, other: &Self) -> bool {
+        self.utility_bill == other.utility_bill
+    }
+}
+
+impl Eq for InputPassportElementUtilityBill {}
+
+impl std::hash::Hash for InputPassportElementUtilityBill {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.utility_bill, state);
+    }
+}
+
 impl TDInputPassportElement for InputPassportElementUtilityBill {}
 
 impl InputPassportElementUtilityBill {
@@ -1078,29 +1735,66 @@ impl InputPassportElementUtilityBill {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputPassportElementUtilityBillBuilder {
-        let mut inner = InputPassportElementUtilityBill::default();
-        inner.td_name = "inputPassportElementUtilityBill".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputPassportElementUtilityBillBuilder { inner }
+        RTDInputPassportElementUtilityBillBuilder {
+            inner: InputPassportElementUtilityBill::default(),
+            missing: vec!["utility_bill"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputPassportElementUtilityBillBuilder {
+        RTDInputPassportElementUtilityBillBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputPassportElementUtilityBillBuilder {
+        RTDInputPassportElementUtilityBillBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn utility_bill(&self) -> &InputPersonalDocument {
         &self.utility_bill
     }
+
+    pub fn into_utility_bill(self) -> InputPersonalDocument {
+        self.utility_bill
+    }
+
+    pub fn with_utility_bill<T: AsRef<InputPersonalDocument>>(mut self, utility_bill: T) -> Self {
+        self.utility_bill = utility_bill.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputPassportElementUtilityBillBuilder {
     inner: InputPassportElementUtilityBill,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputPassportElementUtilityBillBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputPassportElementUtilityBill {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputPassportElementUtilityBill> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputPassportElementUtilityBill",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn utility_bill<T: AsRef<InputPersonalDocument>>(&mut self, utility_bill: T) -> &mut Self {
         self.inner.utility_bill = utility_bill.as_ref().clone();
+        self.missing.retain(|f| *f != "utility_bill");
         self
     }
 }
@@ -1116,3 +1810,263 @@ impl AsRef<InputPassportElementUtilityBill> for RTDInputPassportElementUtilityBi
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(InputPassportElement::Address(
+            InputPassportElementAddress::builder()
+                .address(
+                    Address::builder()
+                        .country_code("text")
+                        .state("text")
+                        .city("text")
+                        .street_line1("text")
+                        .street_line2("text")
+                        .postal_code("text")
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(InputPassportElement::BankStatement(
+            InputPassportElementBankStatement::builder()
+                .bank_statement(
+                    InputPersonalDocument::builder()
+                        .files(vec![])
+                        .translation(vec![])
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(InputPassportElement::DriverLicense(
+            InputPassportElementDriverLicense::builder()
+                .driver_license(
+                    InputIdentityDocument::builder()
+                        .number("text")
+                        .expiry_date(
+                            Date::builder()
+                                .day(i32::MAX)
+                                .month(i32::MAX)
+                                .year(i32::MAX)
+                                .build(),
+                        )
+                        .front_side(InputFile::Generated(
+                            InputFileGenerated::builder()
+                                .original_path("text")
+                                .conversion("text")
+                                .expected_size(i32::MAX)
+                                .build(),
+                        ))
+                        .reverse_side(InputFile::Generated(
+                            InputFileGenerated::builder()
+                                .original_path("text")
+                                .conversion("text")
+                                .expected_size(i32::MAX)
+                                .build(),
+                        ))
+                        .selfie(InputFile::Generated(
+                            InputFileGenerated::builder()
+                                .original_path("text")
+                                .conversion("text")
+                                .expected_size(i32::MAX)
+                                .build(),
+                        ))
+                        .translation(vec![])
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(InputPassportElement::EmailAddress(
+            InputPassportElementEmailAddress::builder()
+                .email_address("text")
+                .build(),
+        ));
+        assert_round_trip(InputPassportElement::IdentityCard(
+            InputPassportElementIdentityCard::builder()
+                .identity_card(
+                    InputIdentityDocument::builder()
+                        .number("text")
+                        .expiry_date(
+                            Date::builder()
+                                .day(i32::MAX)
+                                .month(i32::MAX)
+                                .year(i32::MAX)
+                                .build(),
+                        )
+                        .front_side(InputFile::Generated(
+                            InputFileGenerated::builder()
+                                .original_path("text")
+                                .conversion("text")
+                                .expected_size(i32::MAX)
+                                .build(),
+                        ))
+                        .reverse_side(InputFile::Generated(
+                            InputFileGenerated::builder()
+                                .original_path("text")
+                                .conversion("text")
+                                .expected_size(i32::MAX)
+                                .build(),
+                        ))
+                        .selfie(InputFile::Generated(
+                            InputFileGenerated::builder()
+                                .original_path("text")
+                                .conversion("text")
+                                .expected_size(i32::MAX)
+                                .build(),
+                        ))
+                        .translation(vec![])
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(InputPassportElement::InternalPassport(
+            InputPassportElementInternalPassport::builder()
+                .internal_passport(
+                    InputIdentityDocument::builder()
+                        .number("text")
+                        .expiry_date(
+                            Date::builder()
+                                .day(i32::MAX)
+                                .month(i32::MAX)
+                                .year(i32::MAX)
+                                .build(),
+                        )
+                        .front_side(InputFile::Generated(
+                            InputFileGenerated::builder()
+                                .original_path("text")
+                                .conversion("text")
+                                .expected_size(i32::MAX)
+                                .build(),
+                        ))
+                        .reverse_side(InputFile::Generated(
+                            InputFileGenerated::builder()
+                                .original_path("text")
+                                .conversion("text")
+                                .expected_size(i32::MAX)
+                                .build(),
+                        ))
+                        .selfie(InputFile::Generated(
+                            InputFileGenerated::builder()
+                                .original_path("text")
+                                .conversion("text")
+                                .expected_size(i32::MAX)
+                                .build(),
+                        ))
+                        .translation(vec![])
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(InputPassportElement::Passport(
+            InputPassportElementPassport::builder()
+                .passport(
+                    InputIdentityDocument::builder()
+                        .number("text")
+                        .expiry_date(
+                            Date::builder()
+                                .day(i32::MAX)
+                                .month(i32::MAX)
+                                .year(i32::MAX)
+                                .build(),
+                        )
+                        .front_side(InputFile::Generated(
+                            InputFileGenerated::builder()
+                                .original_path("text")
+                                .conversion("text")
+                                .expected_size(i32::MAX)
+                                .build(),
+                        ))
+                        .reverse_side(InputFile::Generated(
+                            InputFileGenerated::builder()
+                                .original_path("text")
+                                .conversion("text")
+                                .expected_size(i32::MAX)
+                                .build(),
+                        ))
+                        .selfie(InputFile::Generated(
+                            InputFileGenerated::builder()
+                                .original_path("text")
+                                .conversion("text")
+                                .expected_size(i32::MAX)
+                                .build(),
+                        ))
+                        .translation(vec![])
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(InputPassportElement::PassportRegistration(
+            InputPassportElementPassportRegistration::builder()
+                .passport_registration(
+                    InputPersonalDocument::builder()
+                        .files(vec![])
+                        .translation(vec![])
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(InputPassportElement::PersonalDetails(
+            InputPassportElementPersonalDetails::builder()
+                .personal_details(
+                    PersonalDetails::builder()
+                        .first_name("text")
+                        .middle_name("text")
+                        .last_name("text")
+                        .native_first_name("text")
+                        .native_middle_name("text")
+                        .native_last_name("text")
+                        .birthdate(
+                            Date::builder()
+                                .day(i32::MAX)
+                                .month(i32::MAX)
+                                .year(i32::MAX)
+                                .build(),
+                        )
+                        .gender("text")
+                        .country_code("text")
+                        .residence_country_code("text")
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(InputPassportElement::PhoneNumber(
+            InputPassportElementPhoneNumber::builder()
+                .phone_number("text")
+                .build(),
+        ));
+        assert_round_trip(InputPassportElement::RentalAgreement(
+            InputPassportElementRentalAgreement::builder()
+                .rental_agreement(
+                    InputPersonalDocument::builder()
+                        .files(vec![])
+                        .translation(vec![])
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(InputPassportElement::TemporaryRegistration(
+            InputPassportElementTemporaryRegistration::builder()
+                .temporary_registration(
+                    InputPersonalDocument::builder()
+                        .files(vec![])
+                        .translation(vec![])
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(InputPassportElement::UtilityBill(
+            InputPassportElementUtilityBill::builder()
+                .utility_bill(
+                    InputPersonalDocument::builder()
+                        .files(vec![])
+                        .translation(vec![])
+                        .build(),
+                )
+                .build(),
+        ));
+    }
+}
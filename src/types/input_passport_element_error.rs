@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains the description of an error in a Telegram Passport element; for bots only
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputPassportElementError")]
 pub struct InputPassportElementError {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Type of Telegram Passport element that has the error
     #[serde(rename(serialize = "type", deserialize = "type"))]
@@ -34,15 +34,45 @@ impl RObject for InputPassportElementError {
     }
 }
 
+impl PartialEq for InputPassportElementError {
+    fn eq(&self, other: &Self) -> bool {
+        self.type_ == other.type_ && self.message == other.message && self.source == other.source
+    }
+}
+
+impl Eq for InputPassportElementError {}
+
+impl std::hash::Hash for InputPassportElementError {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.type_, state);
+        std::hash::Hash::hash(&self.message, state);
+        std::hash::Hash::hash(&self.source, state);
+    }
+}
+
 impl InputPassportElementError {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputPassportElementErrorBuilder {
-        let mut inner = InputPassportElementError::default();
-        inner.td_name = "inputPassportElementError".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputPassportElementErrorBuilder { inner }
+        RTDInputPassportElementErrorBuilder {
+            inner: InputPassportElementError::default(),
+            missing: vec!["type_", "message", "source"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputPassportElementErrorBuilder {
+        RTDInputPassportElementErrorBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputPassportElementErrorBuilder {
+        RTDInputPassportElementErrorBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn type_(&self) -> &PassportElementType {
@@ -56,30 +86,73 @@ impl InputPassportElementError {
     pub fn source(&self) -> &InputPassportElementErrorSource {
         &self.source
     }
+
+    pub fn into_type(self) -> PassportElementType {
+        self.type_
+    }
+
+    pub fn into_message(self) -> String {
+        self.message
+    }
+
+    pub fn into_source(self) -> InputPassportElementErrorSource {
+        self.source
+    }
+
+    pub fn with_type<T: AsRef<PassportElementType>>(mut self, type_: T) -> Self {
+        self.type_ = type_.as_ref().clone();
+        self
+    }
+
+    pub fn with_message<T: AsRef<str>>(mut self, message: T) -> Self {
+        self.message = message.as_ref().to_string();
+        self
+    }
+
+    pub fn with_source<T: AsRef<InputPassportElementErrorSource>>(mut self, source: T) -> Self {
+        self.source = source.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputPassportElementErrorBuilder {
     inner: InputPassportElementError,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputPassportElementErrorBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputPassportElementError {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputPassportElementError> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputPassportElementError",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn type_<T: AsRef<PassportElementType>>(&mut self, type_: T) -> &mut Self {
         self.inner.type_ = type_.as_ref().clone();
+        self.missing.retain(|f| *f != "type_");
         self
     }
 
     pub fn message<T: AsRef<str>>(&mut self, message: T) -> &mut Self {
         self.inner.message = message.as_ref().to_string();
+        self.missing.retain(|f| *f != "message");
         self
     }
 
     pub fn source<T: AsRef<InputPassportElementErrorSource>>(&mut self, source: T) -> &mut Self {
         self.inner.source = source.as_ref().clone();
+        self.missing.retain(|f| *f != "source");
         self
     }
 }
@@ -95,3 +168,27 @@ impl AsRef<InputPassportElementError> for RTDInputPassportElementErrorBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            InputPassportElementError::builder()
+                .type_(PassportElementType::Address(
+                    PassportElementTypeAddress::builder().build(),
+                ))
+                .message("text")
+                .source(InputPassportElementErrorSource::DataField(
+                    InputPassportElementErrorSourceDataField::builder()
+                        .field_name("text")
+                        .data_hash(b"\x00\x01\xfe\xff")
+                        .build(),
+                ))
+                .build(),
+        );
+    }
+}
@@ -1,64 +1,70 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Contains the description of an error in a Telegram Passport element; for bots only
 pub trait TDInputPassportElementErrorSource: Debug + RObject {}
 
 /// Contains the description of an error in a Telegram Passport element; for bots only
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum InputPassportElementErrorSource {
-    #[doc(hidden)]
-    _Default(()),
     /// A data field contains an error. The error is considered resolved when the field's value changes
+    #[serde(rename = "inputPassportElementErrorSourceDataField")]
     DataField(InputPassportElementErrorSourceDataField),
     /// The file contains an error. The error is considered resolved when the file changes
+    #[serde(rename = "inputPassportElementErrorSourceFile")]
     File(InputPassportElementErrorSourceFile),
     /// The list of attached files contains an error. The error is considered resolved when the file list changes
+    #[serde(rename = "inputPassportElementErrorSourceFiles")]
     Files(InputPassportElementErrorSourceFiles),
     /// The front side of the document contains an error. The error is considered resolved when the file with the front side of the document changes
+    #[serde(rename = "inputPassportElementErrorSourceFrontSide")]
     FrontSide(InputPassportElementErrorSourceFrontSide),
     /// The reverse side of the document contains an error. The error is considered resolved when the file with the reverse side of the document changes
+    #[serde(rename = "inputPassportElementErrorSourceReverseSide")]
     ReverseSide(InputPassportElementErrorSourceReverseSide),
     /// The selfie contains an error. The error is considered resolved when the file with the selfie changes
+    #[serde(rename = "inputPassportElementErrorSourceSelfie")]
     Selfie(InputPassportElementErrorSourceSelfie),
     /// One of the files containing the translation of the document contains an error. The error is considered resolved when the file with the translation changes
+    #[serde(rename = "inputPassportElementErrorSourceTranslationFile")]
     TranslationFile(InputPassportElementErrorSourceTranslationFile),
     /// The translation of the document contains an error. The error is considered resolved when the list of files changes
+    #[serde(rename = "inputPassportElementErrorSourceTranslationFiles")]
     TranslationFiles(InputPassportElementErrorSourceTranslationFiles),
     /// The element contains an error in an unspecified place. The error will be considered resolved when new data is added
+    #[serde(rename = "inputPassportElementErrorSourceUnspecified")]
     Unspecified(InputPassportElementErrorSourceUnspecified),
 }
 
+/// Default value is default of [InputPassportElementErrorSourceDataField], builders still require the field to be set
 impl Default for InputPassportElementErrorSource {
     fn default() -> Self {
-        InputPassportElementErrorSource::_Default(())
+        InputPassportElementErrorSource::DataField(
+            InputPassportElementErrorSourceDataField::default(),
+        )
     }
 }
 
-impl<'de> Deserialize<'de> for InputPassportElementErrorSource {
-    fn deserialize<D>(deserializer: D) -> Result<InputPassportElementErrorSource, D::Error>
+impl Serialize for InputPassportElementErrorSource {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          InputPassportElementErrorSource,
-          (inputPassportElementErrorSourceDataField, DataField);
-          (inputPassportElementErrorSourceFile, File);
-          (inputPassportElementErrorSourceFiles, Files);
-          (inputPassportElementErrorSourceFrontSide, FrontSide);
-          (inputPassportElementErrorSourceReverseSide, ReverseSide);
-          (inputPassportElementErrorSourceSelfie, Selfie);
-          (inputPassportElementErrorSourceTranslationFile, TranslationFile);
-          (inputPassportElementErrorSourceTranslationFiles, TranslationFiles);
-          (inputPassportElementErrorSourceUnspecified, Unspecified);
-
-        )(deserializer)
+        match self {
+            InputPassportElementErrorSource::DataField(t) => t.serialize(serializer),
+            InputPassportElementErrorSource::File(t) => t.serialize(serializer),
+            InputPassportElementErrorSource::Files(t) => t.serialize(serializer),
+            InputPassportElementErrorSource::FrontSide(t) => t.serialize(serializer),
+            InputPassportElementErrorSource::ReverseSide(t) => t.serialize(serializer),
+            InputPassportElementErrorSource::Selfie(t) => t.serialize(serializer),
+            InputPassportElementErrorSource::TranslationFile(t) => t.serialize(serializer),
+            InputPassportElementErrorSource::TranslationFiles(t) => t.serialize(serializer),
+            InputPassportElementErrorSource::Unspecified(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -75,8 +81,6 @@ impl RObject for InputPassportElementErrorSource {
             InputPassportElementErrorSource::TranslationFile(t) => t.td_name(),
             InputPassportElementErrorSource::TranslationFiles(t) => t.td_name(),
             InputPassportElementErrorSource::Unspecified(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -91,8 +95,6 @@ impl RObject for InputPassportElementErrorSource {
             InputPassportElementErrorSource::TranslationFile(t) => t.extra(),
             InputPassportElementErrorSource::TranslationFiles(t) => t.extra(),
             InputPassportElementErrorSource::Unspecified(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -104,10 +106,6 @@ impl InputPassportElementErrorSource {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, InputPassportElementErrorSource::_Default(_))
-    }
 }
 
 impl AsRef<InputPassportElementErrorSource> for InputPassportElementErrorSource {
@@ -118,17 +116,19 @@ impl AsRef<InputPassportElementErrorSource> for InputPassportElementErrorSource
 
 /// A data field contains an error. The error is considered resolved when the field's value changes
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputPassportElementErrorSourceDataField")]
 pub struct InputPassportElementErrorSourceDataField {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Field name
     field_name: String,
     /// Current data hash
-    data_hash: String,
+    #[serde(with = "super::_common::bytes")]
+    data_hash: Vec<u8>,
 }
 
 impl RObject for InputPassportElementErrorSourceDataField {
@@ -145,6 +145,21 @@ impl RObject for InputPassportElementErrorSourceDataField {
     }
 }
 
+impl PartialEq for InputPassportElementErrorSourceDataField {
+    fn eq(&self, other: &Self) -> bool {
+        self.field_name == other.field_name && self.data_hash == other.data_hash
+    }
+}
+
+impl Eq for InputPassportElementErrorSourceDataField {}
+
+impl std::hash::Hash for InputPassportElementErrorSourceDataField {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.field_name, state);
+        std::hash::Hash::hash(&self.data_hash, state);
+    }
+}
+
 impl TDInputPassportElementErrorSource for InputPassportElementErrorSourceDataField {}
 
 impl InputPassportElementErrorSourceDataField {
@@ -152,38 +167,85 @@ impl InputPassportElementErrorSourceDataField {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputPassportElementErrorSourceDataFieldBuilder {
-        let mut inner = InputPassportElementErrorSourceDataField::default();
-        inner.td_name = "inputPassportElementErrorSourceDataField".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputPassportElementErrorSourceDataFieldBuilder { inner }
+        RTDInputPassportElementErrorSourceDataFieldBuilder {
+            inner: InputPassportElementErrorSourceDataField::default(),
+            missing: vec!["field_name", "data_hash"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputPassportElementErrorSourceDataFieldBuilder {
+        RTDInputPassportElementErrorSourceDataFieldBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputPassportElementErrorSourceDataFieldBuilder {
+        RTDInputPassportElementErrorSourceDataFieldBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn field_name(&self) -> &String {
         &self.field_name
     }
 
-    pub fn data_hash(&self) -> &String {
+    pub fn data_hash(&self) -> &[u8] {
         &self.data_hash
     }
+
+    pub fn into_field_name(self) -> String {
+        self.field_name
+    }
+
+    pub fn into_data_hash(self) -> Vec<u8> {
+        self.data_hash
+    }
+
+    pub fn with_field_name<T: AsRef<str>>(mut self, field_name: T) -> Self {
+        self.field_name = field_name.as_ref().to_string();
+        self
+    }
+
+    pub fn with_data_hash<T: AsRef<[u8]>>(mut self, data_hash: T) -> Self {
+        self.data_hash = data_hash.as_ref().to_vec();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputPassportElementErrorSourceDataFieldBuilder {
     inner: InputPassportElementErrorSourceDataField,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputPassportElementErrorSourceDataFieldBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputPassportElementErrorSourceDataField {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputPassportElementErrorSourceDataField> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputPassportElementErrorSourceDataField",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn field_name<T: AsRef<str>>(&mut self, field_name: T) -> &mut Self {
         self.inner.field_name = field_name.as_ref().to_string();
+        self.missing.retain(|f| *f != "field_name");
         self
     }
 
-    pub fn data_hash<T: AsRef<str>>(&mut self, data_hash: T) -> &mut Self {
-        self.inner.data_hash = data_hash.as_ref().to_string();
+    pub fn data_hash<T: AsRef<[u8]>>(&mut self, data_hash: T) -> &mut Self {
+        self.inner.data_hash = data_hash.as_ref().to_vec();
+        self.missing.retain(|f| *f != "data_hash");
         self
     }
 }
@@ -204,15 +266,17 @@ impl AsRef<InputPassportElementErrorSourceDataField>
 
 /// The file contains an error. The error is considered resolved when the file changes
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputPassportElementErrorSourceFile")]
 pub struct InputPassportElementErrorSourceFile {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Current hash of the file which has the error
-    file_hash: String,
+    #[serde(with = "super::_common::bytes")]
+    file_hash: Vec<u8>,
 }
 
 impl RObject for InputPassportElementErrorSourceFile {
@@ -229,6 +293,20 @@ impl RObject for InputPassportElementErrorSourceFile {
     }
 }
 
+impl PartialEq for InputPassportElementErrorSourceFile {
+    fn eq(&self, other: &Self) -> bool {
+        self.file_hash == other.file_hash
+    }
+}
+
+impl Eq for InputPassportElementErrorSourceFile {}
+
+impl std::hash::Hash for InputPassportElementErrorSourceFile {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.file_hash, state);
+    }
+}
+
 impl TDInputPassportElementErrorSource for InputPassportElementErrorSourceFile {}
 
 impl InputPassportElementErrorSourceFile {
@@ -236,29 +314,66 @@ impl InputPassportElementErrorSourceFile {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputPassportElementErrorSourceFileBuilder {
-        let mut inner = InputPassportElementErrorSourceFile::default();
-        inner.td_name = "inputPassportElementErrorSourceFile".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputPassportElementErrorSourceFileBuilder { inner }
+        RTDInputPassportElementErrorSourceFileBuilder {
+            inner: InputPassportElementErrorSourceFile::default(),
+            missing: vec!["file_hash"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputPassportElementErrorSourceFileBuilder {
+        RTDInputPassportElementErrorSourceFileBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputPassportElementErrorSourceFileBuilder {
+        RTDInputPassportElementErrorSourceFileBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn file_hash(&self) -> &String {
+    pub fn file_hash(&self) -> &[u8] {
         &self.file_hash
     }
+
+    pub fn into_file_hash(self) -> Vec<u8> {
+        self.file_hash
+    }
+
+    pub fn with_file_hash<T: AsRef<[u8]>>(mut self, file_hash: T) -> Self {
+        self.file_hash = file_hash.as_ref().to_vec();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputPassportElementErrorSourceFileBuilder {
     inner: InputPassportElementErrorSourceFile,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputPassportElementErrorSourceFileBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputPassportElementErrorSourceFile {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputPassportElementErrorSourceFile> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputPassportElementErrorSourceFile",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn file_hash<T: AsRef<str>>(&mut self, file_hash: T) -> &mut Self {
-        self.inner.file_hash = file_hash.as_ref().to_string();
+    pub fn file_hash<T: AsRef<[u8]>>(&mut self, file_hash: T) -> &mut Self {
+        self.inner.file_hash = file_hash.as_ref().to_vec();
+        self.missing.retain(|f| *f != "file_hash");
         self
     }
 }
@@ -277,15 +392,17 @@ impl AsRef<InputPassportElementErrorSourceFile> for RTDInputPassportElementError
 
 /// The list of attached files contains an error. The error is considered resolved when the file list changes
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputPassportElementErrorSourceFiles")]
 pub struct InputPassportElementErrorSourceFiles {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Current hashes of all attached files
-    file_hashes: Vec<String>,
+    #[serde(with = "super::_common::bytes_vec")]
+    file_hashes: Vec<Vec<u8>>,
 }
 
 impl RObject for InputPassportElementErrorSourceFiles {
@@ -302,6 +419,20 @@ impl RObject for InputPassportElementErrorSourceFiles {
     }
 }
 
+impl PartialEq for InputPassportElementErrorSourceFiles {
+    fn eq(&self, other: &Self) -> bool {
+        self.file_hashes == other.file_hashes
+    }
+}
+
+impl Eq for InputPassportElementErrorSourceFiles {}
+
+impl std::hash::Hash for InputPassportElementErrorSourceFiles {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.file_hashes, state);
+    }
+}
+
 impl TDInputPassportElementErrorSource for InputPassportElementErrorSourceFiles {}
 
 impl InputPassportElementErrorSourceFiles {
@@ -309,29 +440,66 @@ impl InputPassportElementErrorSourceFiles {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputPassportElementErrorSourceFilesBuilder {
-        let mut inner = InputPassportElementErrorSourceFiles::default();
-        inner.td_name = "inputPassportElementErrorSourceFiles".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputPassportElementErrorSourceFilesBuilder { inner }
+        RTDInputPassportElementErrorSourceFilesBuilder {
+            inner: InputPassportElementErrorSourceFiles::default(),
+            missing: vec!["file_hashes"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputPassportElementErrorSourceFilesBuilder {
+        RTDInputPassportElementErrorSourceFilesBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputPassportElementErrorSourceFilesBuilder {
+        RTDInputPassportElementErrorSourceFilesBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn file_hashes(&self) -> &Vec<String> {
+    pub fn file_hashes(&self) -> &Vec<Vec<u8>> {
         &self.file_hashes
     }
+
+    pub fn into_file_hashes(self) -> Vec<Vec<u8>> {
+        self.file_hashes
+    }
+
+    pub fn with_file_hashes(mut self, file_hashes: Vec<Vec<u8>>) -> Self {
+        self.file_hashes = file_hashes;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputPassportElementErrorSourceFilesBuilder {
     inner: InputPassportElementErrorSourceFiles,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputPassportElementErrorSourceFilesBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputPassportElementErrorSourceFiles {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputPassportElementErrorSourceFiles> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputPassportElementErrorSourceFiles",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn file_hashes(&mut self, file_hashes: Vec<String>) -> &mut Self {
+    pub fn file_hashes(&mut self, file_hashes: Vec<Vec<u8>>) -> &mut Self {
         self.inner.file_hashes = file_hashes;
+        self.missing.retain(|f| *f != "file_hashes");
         self
     }
 }
@@ -352,15 +520,17 @@ impl AsRef<InputPassportElementErrorSourceFiles>
 
 /// The front side of the document contains an error. The error is considered resolved when the file with the front side of the document changes
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputPassportElementErrorSourceFrontSide")]
 pub struct InputPassportElementErrorSourceFrontSide {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Current hash of the file containing the front side
-    file_hash: String,
+    #[serde(with = "super::_common::bytes")]
+    file_hash: Vec<u8>,
 }
 
 impl RObject for InputPassportElementErrorSourceFrontSide {
@@ -377,6 +547,20 @@ impl RObject for InputPassportElementErrorSourceFrontSide {
     }
 }
 
+impl PartialEq for InputPassportElementErrorSourceFrontSide {
+    fn eq(&self, other: &Self) -> bool {
+        self.file_hash == other.file_hash
+    }
+}
+
+impl Eq for InputPassportElementErrorSourceFrontSide {}
+
+impl std::hash::Hash for InputPassportElementErrorSourceFrontSide {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.file_hash, state);
+    }
+}
+
 impl TDInputPassportElementErrorSource for InputPassportElementErrorSourceFrontSide {}
 
 impl InputPassportElementErrorSourceFrontSide {
@@ -384,29 +568,66 @@ impl InputPassportElementErrorSourceFrontSide {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputPassportElementErrorSourceFrontSideBuilder {
-        let mut inner = InputPassportElementErrorSourceFrontSide::default();
-        inner.td_name = "inputPassportElementErrorSourceFrontSide".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputPassportElementErrorSourceFrontSideBuilder { inner }
+        RTDInputPassportElementErrorSourceFrontSideBuilder {
+            inner: InputPassportElementErrorSourceFrontSide::default(),
+            missing: vec!["file_hash"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputPassportElementErrorSourceFrontSideBuilder {
+        RTDInputPassportElementErrorSourceFrontSideBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputPassportElementErrorSourceFrontSideBuilder {
+        RTDInputPassportElementErrorSourceFrontSideBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn file_hash(&self) -> &String {
+    pub fn file_hash(&self) -> &[u8] {
         &self.file_hash
     }
+
+    pub fn into_file_hash(self) -> Vec<u8> {
+        self.file_hash
+    }
+
+    pub fn with_file_hash<T: AsRef<[u8]>>(mut self, file_hash: T) -> Self {
+        self.file_hash = file_hash.as_ref().to_vec();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputPassportElementErrorSourceFrontSideBuilder {
     inner: InputPassportElementErrorSourceFrontSide,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputPassportElementErrorSourceFrontSideBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputPassportElementErrorSourceFrontSide {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputPassportElementErrorSourceFrontSide> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputPassportElementErrorSourceFrontSide",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn file_hash<T: AsRef<str>>(&mut self, file_hash: T) -> &mut Self {
-        self.inner.file_hash = file_hash.as_ref().to_string();
+    pub fn file_hash<T: AsRef<[u8]>>(&mut self, file_hash: T) -> &mut Self {
+        self.inner.file_hash = file_hash.as_ref().to_vec();
+        self.missing.retain(|f| *f != "file_hash");
         self
     }
 }
@@ -427,15 +648,17 @@ impl AsRef<InputPassportElementErrorSourceFrontSide>
 
 /// The reverse side of the document contains an error. The error is considered resolved when the file with the reverse side of the document changes
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputPassportElementErrorSourceReverseSide")]
 pub struct InputPassportElementErrorSourceReverseSide {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Current hash of the file containing the reverse side
-    file_hash: String,
+    #[serde(with = "super::_common::bytes")]
+    file_hash: Vec<u8>,
 }
 
 impl RObject for InputPassportElementErrorSourceReverseSide {
@@ -452,6 +675,20 @@ impl RObject for InputPassportElementErrorSourceReverseSide {
     }
 }
 
+impl PartialEq for InputPassportElementErrorSourceReverseSide {
+    fn eq(&self, other: &Self) -> bool {
+        self.file_hash == other.file_hash
+    }
+}
+
+impl Eq for InputPassportElementErrorSourceReverseSide {}
+
+impl std::hash::Hash for InputPassportElementErrorSourceReverseSide {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.file_hash, state);
+    }
+}
+
 impl TDInputPassportElementErrorSource for InputPassportElementErrorSourceReverseSide {}
 
 impl InputPassportElementErrorSourceReverseSide {
@@ -459,29 +696,66 @@ impl InputPassportElementErrorSourceReverseSide {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputPassportElementErrorSourceReverseSideBuilder {
-        let mut inner = InputPassportElementErrorSourceReverseSide::default();
-        inner.td_name = "inputPassportElementErrorSourceReverseSide".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputPassportElementErrorSourceReverseSideBuilder { inner }
+        RTDInputPassportElementErrorSourceReverseSideBuilder {
+            inner: InputPassportElementErrorSourceReverseSide::default(),
+            missing: vec!["file_hash"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputPassportElementErrorSourceReverseSideBuilder {
+        RTDInputPassportElementErrorSourceReverseSideBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputPassportElementErrorSourceReverseSideBuilder {
+        RTDInputPassportElementErrorSourceReverseSideBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn file_hash(&self) -> &String {
+    pub fn file_hash(&self) -> &[u8] {
         &self.file_hash
     }
+
+    pub fn into_file_hash(self) -> Vec<u8> {
+        self.file_hash
+    }
+
+    pub fn with_file_hash<T: AsRef<[u8]>>(mut self, file_hash: T) -> Self {
+        self.file_hash = file_hash.as_ref().to_vec();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputPassportElementErrorSourceReverseSideBuilder {
     inner: InputPassportElementErrorSourceReverseSide,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputPassportElementErrorSourceReverseSideBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputPassportElementErrorSourceReverseSide {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputPassportElementErrorSourceReverseSide> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputPassportElementErrorSourceReverseSide",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn file_hash<T: AsRef<str>>(&mut self, file_hash: T) -> &mut Self {
-        self.inner.file_hash = file_hash.as_ref().to_string();
+    pub fn file_hash<T: AsRef<[u8]>>(&mut self, file_hash: T) -> &mut Self {
+        self.inner.file_hash = file_hash.as_ref().to_vec();
+        self.missing.retain(|f| *f != "file_hash");
         self
     }
 }
@@ -504,15 +778,17 @@ impl AsRef<InputPassportElementErrorSourceReverseSide>
 
 /// The selfie contains an error. The error is considered resolved when the file with the selfie changes
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputPassportElementErrorSourceSelfie")]
 pub struct InputPassportElementErrorSourceSelfie {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Current hash of the file containing the selfie
-    file_hash: String,
+    #[serde(with = "super::_common::bytes")]
+    file_hash: Vec<u8>,
 }
 
 impl RObject for InputPassportElementErrorSourceSelfie {
@@ -529,6 +805,20 @@ impl RObject for InputPassportElementErrorSourceSelfie {
     }
 }
 
+impl PartialEq for InputPassportElementErrorSourceSelfie {
+    fn eq(&self, other: &Self) -> bool {
+        self.file_hash == other.file_hash
+    }
+}
+
+impl Eq for InputPassportElementErrorSourceSelfie {}
+
+impl std::hash::Hash for InputPassportElementErrorSourceSelfie {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.file_hash, state);
+    }
+}
+
 impl TDInputPassportElementErrorSource for InputPassportElementErrorSourceSelfie {}
 
 impl InputPassportElementErrorSourceSelfie {
@@ -536,29 +826,66 @@ impl InputPassportElementErrorSourceSelfie {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputPassportElementErrorSourceSelfieBuilder {
-        let mut inner = InputPassportElementErrorSourceSelfie::default();
-        inner.td_name = "inputPassportElementErrorSourceSelfie".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputPassportElementErrorSourceSelfieBuilder { inner }
+        RTDInputPassportElementErrorSourceSelfieBuilder {
+            inner: InputPassportElementErrorSourceSelfie::default(),
+            missing: vec!["file_hash"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputPassportElementErrorSourceSelfieBuilder {
+        RTDInputPassportElementErrorSourceSelfieBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputPassportElementErrorSourceSelfieBuilder {
+        RTDInputPassportElementErrorSourceSelfieBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn file_hash(&self) -> &String {
+    pub fn file_hash(&self) -> &[u8] {
         &self.file_hash
     }
+
+    pub fn into_file_hash(self) -> Vec<u8> {
+        self.file_hash
+    }
+
+    pub fn with_file_hash<T: AsRef<[u8]>>(mut self, file_hash: T) -> Self {
+        self.file_hash = file_hash.as_ref().to_vec();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputPassportElementErrorSourceSelfieBuilder {
     inner: InputPassportElementErrorSourceSelfie,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputPassportElementErrorSourceSelfieBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputPassportElementErrorSourceSelfie {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputPassportElementErrorSourceSelfie> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputPassportElementErrorSourceSelfie",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn file_hash<T: AsRef<str>>(&mut self, file_hash: T) -> &mut Self {
-        self.inner.file_hash = file_hash.as_ref().to_string();
+    pub fn file_hash<T: AsRef<[u8]>>(&mut self, file_hash: T) -> &mut Self {
+        self.inner.file_hash = file_hash.as_ref().to_vec();
+        self.missing.retain(|f| *f != "file_hash");
         self
     }
 }
@@ -579,15 +906,20 @@ impl AsRef<InputPassportElementErrorSourceSelfie>
 
 /// One of the files containing the translation of the document contains an error. The error is considered resolved when the file with the translation changes
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(
+    tag = "@type",
+    rename = "inputPassportElementErrorSourceTranslationFile"
+)]
 pub struct InputPassportElementErrorSourceTranslationFile {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Current hash of the file containing the translation
-    file_hash: String,
+    #[serde(with = "super::_common::bytes")]
+    file_hash: Vec<u8>,
 }
 
 impl RObject for InputPassportElementErrorSourceTranslationFile {
@@ -604,6 +936,20 @@ impl RObject for InputPassportElementErrorSourceTranslationFile {
     }
 }
 
+impl PartialEq for InputPassportElementErrorSourceTranslationFile {
+    fn eq(&self, other: &Self) -> bool {
+        self.file_hash == other.file_hash
+    }
+}
+
+impl Eq for InputPassportElementErrorSourceTranslationFile {}
+
+impl std::hash::Hash for InputPassportElementErrorSourceTranslationFile {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.file_hash, state);
+    }
+}
+
 impl TDInputPassportElementErrorSource for InputPassportElementErrorSourceTranslationFile {}
 
 impl InputPassportElementErrorSourceTranslationFile {
@@ -611,29 +957,66 @@ impl InputPassportElementErrorSourceTranslationFile {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputPassportElementErrorSourceTranslationFileBuilder {
-        let mut inner = InputPassportElementErrorSourceTranslationFile::default();
-        inner.td_name = "inputPassportElementErrorSourceTranslationFile".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputPassportElementErrorSourceTranslationFileBuilder { inner }
+        RTDInputPassportElementErrorSourceTranslationFileBuilder {
+            inner: InputPassportElementErrorSourceTranslationFile::default(),
+            missing: vec!["file_hash"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputPassportElementErrorSourceTranslationFileBuilder {
+        RTDInputPassportElementErrorSourceTranslationFileBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputPassportElementErrorSourceTranslationFileBuilder {
+        RTDInputPassportElementErrorSourceTranslationFileBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn file_hash(&self) -> &String {
+    pub fn file_hash(&self) -> &[u8] {
         &self.file_hash
     }
+
+    pub fn into_file_hash(self) -> Vec<u8> {
+        self.file_hash
+    }
+
+    pub fn with_file_hash<T: AsRef<[u8]>>(mut self, file_hash: T) -> Self {
+        self.file_hash = file_hash.as_ref().to_vec();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputPassportElementErrorSourceTranslationFileBuilder {
     inner: InputPassportElementErrorSourceTranslationFile,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputPassportElementErrorSourceTranslationFileBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputPassportElementErrorSourceTranslationFile {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputPassportElementErrorSourceTranslationFile> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputPassportElementErrorSourceTranslationFile",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn file_hash<T: AsRef<str>>(&mut self, file_hash: T) -> &mut Self {
-        self.inner.file_hash = file_hash.as_ref().to_string();
+    pub fn file_hash<T: AsRef<[u8]>>(&mut self, file_hash: T) -> &mut Self {
+        self.inner.file_hash = file_hash.as_ref().to_vec();
+        self.missing.retain(|f| *f != "file_hash");
         self
     }
 }
@@ -656,15 +1039,20 @@ impl AsRef<InputPassportElementErrorSourceTranslationFile>
 
 /// The translation of the document contains an error. The error is considered resolved when the list of files changes
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(
+    tag = "@type",
+    rename = "inputPassportElementErrorSourceTranslationFiles"
+)]
 pub struct InputPassportElementErrorSourceTranslationFiles {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Current hashes of all files with the translation
-    file_hashes: Vec<String>,
+    #[serde(with = "super::_common::bytes_vec")]
+    file_hashes: Vec<Vec<u8>>,
 }
 
 impl RObject for InputPassportElementErrorSourceTranslationFiles {
@@ -681,6 +1069,20 @@ impl RObject for InputPassportElementErrorSourceTranslationFiles {
     }
 }
 
+impl PartialEq for InputPassportElementErrorSourceTranslationFiles {
+    fn eq(&self, other: &Self) -> bool {
+        self.file_hashes == other.file_hashes
+    }
+}
+
+impl Eq for InputPassportElementErrorSourceTranslationFiles {}
+
+impl std::hash::Hash for InputPassportElementErrorSourceTranslationFiles {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.file_hashes, state);
+    }
+}
+
 impl TDInputPassportElementErrorSource for InputPassportElementErrorSourceTranslationFiles {}
 
 impl InputPassportElementErrorSourceTranslationFiles {
@@ -688,29 +1090,66 @@ impl InputPassportElementErrorSourceTranslationFiles {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputPassportElementErrorSourceTranslationFilesBuilder {
-        let mut inner = InputPassportElementErrorSourceTranslationFiles::default();
-        inner.td_name = "inputPassportElementErrorSourceTranslationFiles".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputPassportElementErrorSourceTranslationFilesBuilder { inner }
+        RTDInputPassportElementErrorSourceTranslationFilesBuilder {
+            inner: InputPassportElementErrorSourceTranslationFiles::default(),
+            missing: vec!["file_hashes"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputPassportElementErrorSourceTranslationFilesBuilder {
+        RTDInputPassportElementErrorSourceTranslationFilesBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputPassportElementErrorSourceTranslationFilesBuilder {
+        RTDInputPassportElementErrorSourceTranslationFilesBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn file_hashes(&self) -> &Vec<String> {
+    pub fn file_hashes(&self) -> &Vec<Vec<u8>> {
         &self.file_hashes
     }
+
+    pub fn into_file_hashes(self) -> Vec<Vec<u8>> {
+        self.file_hashes
+    }
+
+    pub fn with_file_hashes(mut self, file_hashes: Vec<Vec<u8>>) -> Self {
+        self.file_hashes = file_hashes;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputPassportElementErrorSourceTranslationFilesBuilder {
     inner: InputPassportElementErrorSourceTranslationFiles,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputPassportElementErrorSourceTranslationFilesBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputPassportElementErrorSourceTranslationFiles {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputPassportElementErrorSourceTranslationFiles> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputPassportElementErrorSourceTranslationFiles",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn file_hashes(&mut self, file_hashes: Vec<String>) -> &mut Self {
+    pub fn file_hashes(&mut self, file_hashes: Vec<Vec<u8>>) -> &mut Self {
         self.inner.file_hashes = file_hashes;
+        self.missing.retain(|f| *f != "file_hashes");
         self
     }
 }
@@ -733,15 +1172,17 @@ impl AsRef<InputPassportElementErrorSourceTranslationFiles>
 
 /// The element contains an error in an unspecified place. The error will be considered resolved when new data is added
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputPassportElementErrorSourceUnspecified")]
 pub struct InputPassportElementErrorSourceUnspecified {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Current hash of the entire element
-    element_hash: String,
+    #[serde(with = "super::_common::bytes")]
+    element_hash: Vec<u8>,
 }
 
 impl RObject for InputPassportElementErrorSourceUnspecified {
@@ -758,6 +1199,20 @@ impl RObject for InputPassportElementErrorSourceUnspecified {
     }
 }
 
+impl PartialEq for InputPassportElementErrorSourceUnspecified {
+    fn eq(&self, other: &Self) -> bool {
+        self.element_hash == other.element_hash
+    }
+}
+
+impl Eq for InputPassportElementErrorSourceUnspecified {}
+
+impl std::hash::Hash for InputPassportElementErrorSourceUnspecified {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.element_hash, state);
+    }
+}
+
 impl TDInputPassportElementErrorSource for InputPassportElementErrorSourceUnspecified {}
 
 impl InputPassportElementErrorSourceUnspecified {
@@ -765,29 +1220,66 @@ impl InputPassportElementErrorSourceUnspecified {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputPassportElementErrorSourceUnspecifiedBuilder {
-        let mut inner = InputPassportElementErrorSourceUnspecified::default();
-        inner.td_name = "inputPassportElementErrorSourceUnspecified".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputPassportElementErrorSourceUnspecifiedBuilder { inner }
+        RTDInputPassportElementErrorSourceUnspecifiedBuilder {
+            inner: InputPassportElementErrorSourceUnspecified::default(),
+            missing: vec!["element_hash"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputPassportElementErrorSourceUnspecifiedBuilder {
+        RTDInputPassportElementErrorSourceUnspecifiedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputPassportElementErrorSourceUnspecifiedBuilder {
+        RTDInputPassportElementErrorSourceUnspecifiedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn element_hash(&self) -> &String {
+    pub fn element_hash(&self) -> &[u8] {
         &self.element_hash
     }
+
+    pub fn into_element_hash(self) -> Vec<u8> {
+        self.element_hash
+    }
+
+    pub fn with_element_hash<T: AsRef<[u8]>>(mut self, element_hash: T) -> Self {
+        self.element_hash = element_hash.as_ref().to_vec();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputPassportElementErrorSourceUnspecifiedBuilder {
     inner: InputPassportElementErrorSourceUnspecified,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputPassportElementErrorSourceUnspecifiedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputPassportElementErrorSourceUnspecified {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputPassportElementErrorSourceUnspecified> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputPassportElementErrorSourceUnspecified",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn element_hash<T: AsRef<str>>(&mut self, element_hash: T) -> &mut Self {
-        self.inner.element_hash = element_hash.as_ref().to_string();
+    pub fn element_hash<T: AsRef<[u8]>>(&mut self, element_hash: T) -> &mut Self {
+        self.inner.element_hash = element_hash.as_ref().to_vec();
+        self.missing.retain(|f| *f != "element_hash");
         self
     }
 }
@@ -807,3 +1299,59 @@ impl AsRef<InputPassportElementErrorSourceUnspecified>
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(InputPassportElementErrorSource::DataField(
+            InputPassportElementErrorSourceDataField::builder()
+                .field_name("text")
+                .data_hash(b"\x00\x01\xfe\xff")
+                .build(),
+        ));
+        assert_round_trip(InputPassportElementErrorSource::File(
+            InputPassportElementErrorSourceFile::builder()
+                .file_hash(b"\x00\x01\xfe\xff")
+                .build(),
+        ));
+        assert_round_trip(InputPassportElementErrorSource::Files(
+            InputPassportElementErrorSourceFiles::builder()
+                .file_hashes(vec![b"\x00\x01\xfe\xff".to_vec()])
+                .build(),
+        ));
+        assert_round_trip(InputPassportElementErrorSource::FrontSide(
+            InputPassportElementErrorSourceFrontSide::builder()
+                .file_hash(b"\x00\x01\xfe\xff")
+                .build(),
+        ));
+        assert_round_trip(InputPassportElementErrorSource::ReverseSide(
+            InputPassportElementErrorSourceReverseSide::builder()
+                .file_hash(b"\x00\x01\xfe\xff")
+                .build(),
+        ));
+        assert_round_trip(InputPassportElementErrorSource::Selfie(
+            InputPassportElementErrorSourceSelfie::builder()
+                .file_hash(b"\x00\x01\xfe\xff")
+                .build(),
+        ));
+        assert_round_trip(InputPassportElementErrorSource::TranslationFile(
+            InputPassportElementErrorSourceTranslationFile::builder()
+                .file_hash(b"\x00\x01\xfe\xff")
+                .build(),
+        ));
+        assert_round_trip(InputPassportElementErrorSource::TranslationFiles(
+            InputPassportElementErrorSourceTranslationFiles::builder()
+                .file_hashes(vec![b"\x00\x01\xfe\xff".to_vec()])
+                .build(),
+        ));
+        assert_round_trip(InputPassportElementErrorSource::Unspecified(
+            InputPassportElementErrorSourceUnspecified::builder()
+                .element_hash(b"\x00\x01\xfe\xff")
+                .build(),
+        ));
+    }
+}
@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// A personal document to be saved to Telegram Passport
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputPersonalDocument")]
 pub struct InputPersonalDocument {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// List of files containing the pages of the document
     files: Vec<InputFile>,
@@ -31,15 +31,44 @@ impl RObject for InputPersonalDocument {
     }
 }
 
+impl PartialEq for InputPersonalDocument {
+    fn eq(&self, other: &Self) -> bool {
+        self.files == other.files && self.translation == other.translation
+    }
+}
+
+impl Eq for InputPersonalDocument {}
+
+impl std::hash::Hash for InputPersonalDocument {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.files, state);
+        std::hash::Hash::hash(&self.translation, state);
+    }
+}
+
 impl InputPersonalDocument {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputPersonalDocumentBuilder {
-        let mut inner = InputPersonalDocument::default();
-        inner.td_name = "inputPersonalDocument".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputPersonalDocumentBuilder { inner }
+        RTDInputPersonalDocumentBuilder {
+            inner: InputPersonalDocument::default(),
+            missing: vec!["files", "translation"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputPersonalDocumentBuilder {
+        RTDInputPersonalDocumentBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputPersonalDocumentBuilder {
+        RTDInputPersonalDocumentBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn files(&self) -> &Vec<InputFile> {
@@ -49,25 +78,58 @@ impl InputPersonalDocument {
     pub fn translation(&self) -> &Vec<InputFile> {
         &self.translation
     }
+
+    pub fn into_files(self) -> Vec<InputFile> {
+        self.files
+    }
+
+    pub fn into_translation(self) -> Vec<InputFile> {
+        self.translation
+    }
+
+    pub fn with_files(mut self, files: Vec<InputFile>) -> Self {
+        self.files = files;
+        self
+    }
+
+    pub fn with_translation(mut self, translation: Vec<InputFile>) -> Self {
+        self.translation = translation;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputPersonalDocumentBuilder {
     inner: InputPersonalDocument,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputPersonalDocumentBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputPersonalDocument {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputPersonalDocument> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputPersonalDocument",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn files(&mut self, files: Vec<InputFile>) -> &mut Self {
         self.inner.files = files;
+        self.missing.retain(|f| *f != "files");
         self
     }
 
     pub fn translation(&mut self, translation: Vec<InputFile>) -> &mut Self {
         self.inner.translation = translation;
+        self.missing.retain(|f| *f != "translation");
         self
     }
 }
@@ -83,3 +145,19 @@ impl AsRef<InputPersonalDocument> for RTDInputPersonalDocumentBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            InputPersonalDocument::builder()
+                .files(vec![])
+                .translation(vec![])
+                .build(),
+        );
+    }
+}
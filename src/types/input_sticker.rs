@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Describes a sticker that should be added to a sticker set
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputSticker")]
 pub struct InputSticker {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// PNG image with the sticker; must be up to 512 kB in size and fit in a 512x512 square
     png_sticker: InputFile,
@@ -33,15 +33,37 @@ impl RObject for InputSticker {
     }
 }
 
+impl PartialEq for InputSticker {
+    fn eq(&self, other: &Self) -> bool {
+        self.png_sticker == other.png_sticker
+            && self.emojis == other.emojis
+            && self.mask_position == other.mask_position
+    }
+}
+
 impl InputSticker {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputStickerBuilder {
-        let mut inner = InputSticker::default();
-        inner.td_name = "inputSticker".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputStickerBuilder { inner }
+        RTDInputStickerBuilder {
+            inner: InputSticker::default(),
+            missing: vec!["png_sticker", "emojis"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputStickerBuilder {
+        RTDInputStickerBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputStickerBuilder {
+        RTDInputStickerBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn png_sticker(&self) -> &InputFile {
@@ -55,25 +77,67 @@ impl InputSticker {
     pub fn mask_position(&self) -> &Option<MaskPosition> {
         &self.mask_position
     }
+
+    pub fn into_png_sticker(self) -> InputFile {
+        self.png_sticker
+    }
+
+    pub fn into_emojis(self) -> String {
+        self.emojis
+    }
+
+    pub fn into_mask_position(self) -> Option<MaskPosition> {
+        self.mask_position
+    }
+
+    pub fn with_png_sticker<T: AsRef<InputFile>>(mut self, png_sticker: T) -> Self {
+        self.png_sticker = png_sticker.as_ref().clone();
+        self
+    }
+
+    pub fn with_emojis<T: AsRef<str>>(mut self, emojis: T) -> Self {
+        self.emojis = emojis.as_ref().to_string();
+        self
+    }
+
+    pub fn with_mask_position<T: AsRef<MaskPosition>>(mut self, mask_position: T) -> Self {
+        self.mask_position = Some(mask_position.as_ref().clone());
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputStickerBuilder {
     inner: InputSticker,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputStickerBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputSticker {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputSticker> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputSticker",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn png_sticker<T: AsRef<InputFile>>(&mut self, png_sticker: T) -> &mut Self {
         self.inner.png_sticker = png_sticker.as_ref().clone();
+        self.missing.retain(|f| *f != "png_sticker");
         self
     }
 
     pub fn emojis<T: AsRef<str>>(&mut self, emojis: T) -> &mut Self {
         self.inner.emojis = emojis.as_ref().to_string();
+        self.missing.retain(|f| *f != "emojis");
         self
     }
 
@@ -94,3 +158,25 @@ impl AsRef<InputSticker> for RTDInputStickerBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            InputSticker::builder()
+                .png_sticker(InputFile::Generated(
+                    InputFileGenerated::builder()
+                        .original_path("text")
+                        .conversion("text")
+                        .expected_size(i32::MAX)
+                        .build(),
+                ))
+                .emojis("text")
+                .build(),
+        );
+    }
+}
@@ -1,22 +1,22 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// A thumbnail to be sent along with a file; should be in JPEG or WEBP format for stickers, and less than 200 kB in size
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "inputThumbnail")]
 pub struct InputThumbnail {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Thumbnail file to send. Sending thumbnails by file_id is currently not supported
     thumbnail: InputFile,
     /// Thumbnail width, usually shouldn't exceed 320. Use 0 if unknown
-    width: i64,
+    width: i32,
     /// Thumbnail height, usually shouldn't exceed 320. Use 0 if unknown
-    height: i64,
+    height: i32,
 }
 
 impl RObject for InputThumbnail {
@@ -33,51 +33,116 @@ impl RObject for InputThumbnail {
     }
 }
 
+impl PartialEq for InputThumbnail {
+    fn eq(&self, other: &Self) -> bool {
+        self.thumbnail == other.thumbnail
+            && self.width == other.width
+            && self.height == other.height
+    }
+}
+
+impl Eq for InputThumbnail {}
+
+impl std::hash::Hash for InputThumbnail {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.thumbnail, state);
+        std::hash::Hash::hash(&self.width, state);
+        std::hash::Hash::hash(&self.height, state);
+    }
+}
+
 impl InputThumbnail {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInputThumbnailBuilder {
-        let mut inner = InputThumbnail::default();
-        inner.td_name = "inputThumbnail".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInputThumbnailBuilder { inner }
+        RTDInputThumbnailBuilder {
+            inner: InputThumbnail::default(),
+            missing: vec!["thumbnail"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInputThumbnailBuilder {
+        RTDInputThumbnailBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInputThumbnailBuilder {
+        RTDInputThumbnailBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn thumbnail(&self) -> &InputFile {
         &self.thumbnail
     }
 
-    pub fn width(&self) -> i64 {
+    pub fn width(&self) -> i32 {
         self.width
     }
 
-    pub fn height(&self) -> i64 {
+    pub fn height(&self) -> i32 {
         self.height
     }
+
+    pub fn into_thumbnail(self) -> InputFile {
+        self.thumbnail
+    }
+
+    pub fn with_thumbnail<T: AsRef<InputFile>>(mut self, thumbnail: T) -> Self {
+        self.thumbnail = thumbnail.as_ref().clone();
+        self
+    }
+
+    pub fn with_width(mut self, width: i32) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn with_height(mut self, height: i32) -> Self {
+        self.height = height;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInputThumbnailBuilder {
     inner: InputThumbnail,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInputThumbnailBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> InputThumbnail {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<InputThumbnail> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "InputThumbnail",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn thumbnail<T: AsRef<InputFile>>(&mut self, thumbnail: T) -> &mut Self {
         self.inner.thumbnail = thumbnail.as_ref().clone();
+        self.missing.retain(|f| *f != "thumbnail");
         self
     }
 
-    pub fn width(&mut self, width: i64) -> &mut Self {
+    pub fn width(&mut self, width: i32) -> &mut Self {
         self.inner.width = width;
         self
     }
 
-    pub fn height(&mut self, height: i64) -> &mut Self {
+    pub fn height(&mut self, height: i32) -> &mut Self {
         self.inner.height = height;
         self
     }
@@ -94,3 +159,26 @@ impl AsRef<InputThumbnail> for RTDInputThumbnailBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            InputThumbnail::builder()
+                .thumbnail(InputFile::Generated(
+                    InputFileGenerated::builder()
+                        .original_path("text")
+                        .conversion("text")
+                        .expected_size(i32::MAX)
+                        .build(),
+                ))
+                .width(i32::MAX)
+                .height(i32::MAX)
+                .build(),
+        );
+    }
+}
@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Product invoice
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "invoice")]
 pub struct Invoice {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// ISO 4217 currency code
     currency: String,
@@ -47,15 +47,61 @@ impl RObject for Invoice {
     }
 }
 
+impl PartialEq for Invoice {
+    fn eq(&self, other: &Self) -> bool {
+        self.currency == other.currency
+            && self.price_parts == other.price_parts
+            && self.is_test == other.is_test
+            && self.need_name == other.need_name
+            && self.need_phone_number == other.need_phone_number
+            && self.need_email_address == other.need_email_address
+            && self.need_shipping_address == other.need_shipping_address
+            && self.send_phone_number_to_provider == other.send_phone_number_to_provider
+            && self.send_email_address_to_provider == other.send_email_address_to_provider
+            && self.is_flexible == other.is_flexible
+    }
+}
+
+impl Eq for Invoice {}
+
+impl std::hash::Hash for Invoice {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.currency, state);
+        std::hash::Hash::hash(&self.price_parts, state);
+        std::hash::Hash::hash(&self.is_test, state);
+        std::hash::Hash::hash(&self.need_name, state);
+        std::hash::Hash::hash(&self.need_phone_number, state);
+        std::hash::Hash::hash(&self.need_email_address, state);
+        std::hash::Hash::hash(&self.need_shipping_address, state);
+        std::hash::Hash::hash(&self.send_phone_number_to_provider, state);
+        std::hash::Hash::hash(&self.send_email_address_to_provider, state);
+        std::hash::Hash::hash(&self.is_flexible, state);
+    }
+}
+
 impl Invoice {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDInvoiceBuilder {
-        let mut inner = Invoice::default();
-        inner.td_name = "invoice".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDInvoiceBuilder { inner }
+        RTDInvoiceBuilder {
+            inner: Invoice::default(),
+            missing: vec!["currency", "price_parts"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDInvoiceBuilder {
+        RTDInvoiceBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDInvoiceBuilder {
+        RTDInvoiceBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn currency(&self) -> &String {
@@ -97,25 +143,101 @@ impl Invoice {
     pub fn is_flexible(&self) -> bool {
         self.is_flexible
     }
+
+    pub fn into_currency(self) -> String {
+        self.currency
+    }
+
+    pub fn into_price_parts(self) -> Vec<LabeledPricePart> {
+        self.price_parts
+    }
+
+    pub fn with_currency<T: AsRef<str>>(mut self, currency: T) -> Self {
+        self.currency = currency.as_ref().to_string();
+        self
+    }
+
+    pub fn with_price_parts(mut self, price_parts: Vec<LabeledPricePart>) -> Self {
+        self.price_parts = price_parts;
+        self
+    }
+
+    pub fn with_is_test(mut self, is_test: bool) -> Self {
+        self.is_test = is_test;
+        self
+    }
+
+    pub fn with_need_name(mut self, need_name: bool) -> Self {
+        self.need_name = need_name;
+        self
+    }
+
+    pub fn with_need_phone_number(mut self, need_phone_number: bool) -> Self {
+        self.need_phone_number = need_phone_number;
+        self
+    }
+
+    pub fn with_need_email_address(mut self, need_email_address: bool) -> Self {
+        self.need_email_address = need_email_address;
+        self
+    }
+
+    pub fn with_need_shipping_address(mut self, need_shipping_address: bool) -> Self {
+        self.need_shipping_address = need_shipping_address;
+        self
+    }
+
+    pub fn with_send_phone_number_to_provider(
+        mut self,
+        send_phone_number_to_provider: bool,
+    ) -> Self {
+        self.send_phone_number_to_provider = send_phone_number_to_provider;
+        self
+    }
+
+    pub fn with_send_email_address_to_provider(
+        mut self,
+        send_email_address_to_provider: bool,
+    ) -> Self {
+        self.send_email_address_to_provider = send_email_address_to_provider;
+        self
+    }
+
+    pub fn with_is_flexible(mut self, is_flexible: bool) -> Self {
+        self.is_flexible = is_flexible;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDInvoiceBuilder {
     inner: Invoice,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDInvoiceBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> Invoice {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<Invoice> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("Invoice", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn currency<T: AsRef<str>>(&mut self, currency: T) -> &mut Self {
         self.inner.currency = currency.as_ref().to_string();
+        self.missing.retain(|f| *f != "currency");
         self
     }
 
     pub fn price_parts(&mut self, price_parts: Vec<LabeledPricePart>) -> &mut Self {
         self.inner.price_parts = price_parts;
+        self.missing.retain(|f| *f != "price_parts");
         self
     }
 
@@ -177,3 +299,27 @@ impl AsRef<Invoice> for RTDInvoiceBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            Invoice::builder()
+                .currency("text")
+                .price_parts(vec![])
+                .is_test(true)
+                .need_name(true)
+                .need_phone_number(true)
+                .need_email_address(true)
+                .need_shipping_address(true)
+                .send_phone_number_to_provider(true)
+                .send_email_address_to_provider(true)
+                .is_flexible(true)
+                .build(),
+        );
+    }
+}
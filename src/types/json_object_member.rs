@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Represents one member of a JSON object
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "jsonObjectMember")]
 pub struct JsonObjectMember {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Member's key
     key: String,
@@ -31,15 +31,35 @@ impl RObject for JsonObjectMember {
     }
 }
 
+impl PartialEq for JsonObjectMember {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key && self.value == other.value
+    }
+}
+
 impl JsonObjectMember {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDJsonObjectMemberBuilder {
-        let mut inner = JsonObjectMember::default();
-        inner.td_name = "jsonObjectMember".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDJsonObjectMemberBuilder { inner }
+        RTDJsonObjectMemberBuilder {
+            inner: JsonObjectMember::default(),
+            missing: vec!["key", "value"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDJsonObjectMemberBuilder {
+        RTDJsonObjectMemberBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDJsonObjectMemberBuilder {
+        RTDJsonObjectMemberBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn key(&self) -> &String {
@@ -49,25 +69,58 @@ impl JsonObjectMember {
     pub fn value(&self) -> &JsonValue {
         &self.value
     }
+
+    pub fn into_key(self) -> String {
+        self.key
+    }
+
+    pub fn into_value(self) -> JsonValue {
+        self.value
+    }
+
+    pub fn with_key<T: AsRef<str>>(mut self, key: T) -> Self {
+        self.key = key.as_ref().to_string();
+        self
+    }
+
+    pub fn with_value<T: AsRef<JsonValue>>(mut self, value: T) -> Self {
+        self.value = value.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDJsonObjectMemberBuilder {
     inner: JsonObjectMember,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDJsonObjectMemberBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> JsonObjectMember {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<JsonObjectMember> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "JsonObjectMember",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn key<T: AsRef<str>>(&mut self, key: T) -> &mut Self {
         self.inner.key = key.as_ref().to_string();
+        self.missing.retain(|f| *f != "key");
         self
     }
 
     pub fn value<T: AsRef<JsonValue>>(&mut self, value: T) -> &mut Self {
         self.inner.value = value.as_ref().clone();
+        self.missing.retain(|f| *f != "value");
         self
     }
 }
@@ -83,3 +136,21 @@ impl AsRef<JsonObjectMember> for RTDJsonObjectMemberBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            JsonObjectMember::builder()
+                .key("text")
+                .value(JsonValue::Array(
+                    JsonValueArray::builder().values(vec![]).build(),
+                ))
+                .build(),
+        );
+    }
+}
@@ -1,61 +1,56 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Represents a JSON value
 pub trait TDJsonValue: Debug + RObject {}
 
 /// Represents a JSON value
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "@type")]
 pub enum JsonValue {
-    #[doc(hidden)]
-    _Default(()),
-    /// Returns application config, provided by the server. Can be called before authorization
-    GetApplicationConfig(GetApplicationConfig),
-    /// Converts a JSON-serialized string to corresponding JsonValue object. This is an offline method. Can be called before authorization. Can be called synchronously
-    GetJsonValue(GetJsonValue),
     /// Represents a JSON array
+    #[serde(rename = "jsonValueArray")]
     Array(JsonValueArray),
     /// Represents a boolean JSON value
+    #[serde(rename = "jsonValueBoolean")]
     Boolean(JsonValueBoolean),
     /// Represents a null JSON value
+    #[serde(rename = "jsonValueNull")]
     Null(JsonValueNull),
     /// Represents a numeric JSON value
+    #[serde(rename = "jsonValueNumber")]
     Number(JsonValueNumber),
     /// Represents a JSON object
+    #[serde(rename = "jsonValueObject")]
     Object(JsonValueObject),
     /// Represents a string JSON value
+    #[serde(rename = "jsonValueString")]
     String(JsonValueString),
 }
 
+/// Default value is default of [JsonValueArray], builders still require the field to be set
 impl Default for JsonValue {
     fn default() -> Self {
-        JsonValue::_Default(())
+        JsonValue::Array(JsonValueArray::default())
     }
 }
 
-impl<'de> Deserialize<'de> for JsonValue {
-    fn deserialize<D>(deserializer: D) -> Result<JsonValue, D::Error>
+impl Serialize for JsonValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          JsonValue,
-          (getApplicationConfig, GetApplicationConfig);
-          (getJsonValue, GetJsonValue);
-          (jsonValueArray, Array);
-          (jsonValueBoolean, Boolean);
-          (jsonValueNull, Null);
-          (jsonValueNumber, Number);
-          (jsonValueObject, Object);
-          (jsonValueString, String);
-
-        )(deserializer)
+        match self {
+            JsonValue::Array(t) => t.serialize(serializer),
+            JsonValue::Boolean(t) => t.serialize(serializer),
+            JsonValue::Null(t) => t.serialize(serializer),
+            JsonValue::Number(t) => t.serialize(serializer),
+            JsonValue::Object(t) => t.serialize(serializer),
+            JsonValue::String(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -63,31 +58,23 @@ impl RObject for JsonValue {
     #[doc(hidden)]
     fn td_name(&self) -> &'static str {
         match self {
-            JsonValue::GetApplicationConfig(t) => t.td_name(),
-            JsonValue::GetJsonValue(t) => t.td_name(),
             JsonValue::Array(t) => t.td_name(),
             JsonValue::Boolean(t) => t.td_name(),
             JsonValue::Null(t) => t.td_name(),
             JsonValue::Number(t) => t.td_name(),
             JsonValue::Object(t) => t.td_name(),
             JsonValue::String(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
     fn extra(&self) -> Option<String> {
         match self {
-            JsonValue::GetApplicationConfig(t) => t.extra(),
-            JsonValue::GetJsonValue(t) => t.extra(),
             JsonValue::Array(t) => t.extra(),
             JsonValue::Boolean(t) => t.extra(),
             JsonValue::Null(t) => t.extra(),
             JsonValue::Number(t) => t.extra(),
             JsonValue::Object(t) => t.extra(),
             JsonValue::String(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -99,10 +86,6 @@ impl JsonValue {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, JsonValue::_Default(_))
-    }
 }
 
 impl AsRef<JsonValue> for JsonValue {
@@ -113,12 +96,13 @@ impl AsRef<JsonValue> for JsonValue {
 
 /// Represents a JSON array
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "jsonValueArray")]
 pub struct JsonValueArray {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The list of array elements
     values: Vec<JsonValue>,
@@ -138,6 +122,12 @@ impl RObject for JsonValueArray {
     }
 }
 
+impl PartialEq for JsonValueArray {
+    fn eq(&self, other: &Self) -> bool {
+        self.values == other.values
+    }
+}
+
 impl TDJsonValue for JsonValueArray {}
 
 impl JsonValueArray {
@@ -145,29 +135,66 @@ impl JsonValueArray {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDJsonValueArrayBuilder {
-        let mut inner = JsonValueArray::default();
-        inner.td_name = "jsonValueArray".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDJsonValueArrayBuilder { inner }
+        RTDJsonValueArrayBuilder {
+            inner: JsonValueArray::default(),
+            missing: vec!["values"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDJsonValueArrayBuilder {
+        RTDJsonValueArrayBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDJsonValueArrayBuilder {
+        RTDJsonValueArrayBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn values(&self) -> &Vec<JsonValue> {
         &self.values
     }
+
+    pub fn into_values(self) -> Vec<JsonValue> {
+        self.values
+    }
+
+    pub fn with_values(mut self, values: Vec<JsonValue>) -> Self {
+        self.values = values;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDJsonValueArrayBuilder {
     inner: JsonValueArray,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDJsonValueArrayBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> JsonValueArray {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<JsonValueArray> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "JsonValueArray",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn values(&mut self, values: Vec<JsonValue>) -> &mut Self {
         self.inner.values = values;
+        self.missing.retain(|f| *f != "values");
         self
     }
 }
@@ -186,12 +213,13 @@ impl AsRef<JsonValueArray> for RTDJsonValueArrayBuilder {
 
 /// Represents a boolean JSON value
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "jsonValueBoolean")]
 pub struct JsonValueBoolean {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The value
     value: bool,
@@ -211,6 +239,20 @@ impl RObject for JsonValueBoolean {
     }
 }
 
+impl PartialEq for JsonValueBoolean {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for JsonValueBoolean {}
+
+impl std::hash::Hash for JsonValueBoolean {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.value, state);
+    }
+}
+
 impl TDJsonValue for JsonValueBoolean {}
 
 impl JsonValueBoolean {
@@ -218,26 +260,58 @@ impl JsonValueBoolean {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDJsonValueBooleanBuilder {
-        let mut inner = JsonValueBoolean::default();
-        inner.td_name = "jsonValueBoolean".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDJsonValueBooleanBuilder { inner }
+        RTDJsonValueBooleanBuilder {
+            inner: JsonValueBoolean::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDJsonValueBooleanBuilder {
+        RTDJsonValueBooleanBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDJsonValueBooleanBuilder {
+        RTDJsonValueBooleanBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn value(&self) -> bool {
         self.value
     }
+
+    pub fn with_value(mut self, value: bool) -> Self {
+        self.value = value;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDJsonValueBooleanBuilder {
     inner: JsonValueBoolean,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDJsonValueBooleanBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> JsonValueBoolean {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<JsonValueBoolean> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "JsonValueBoolean",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn value(&mut self, value: bool) -> &mut Self {
         self.inner.value = value;
@@ -259,12 +333,13 @@ impl AsRef<JsonValueBoolean> for RTDJsonValueBooleanBuilder {
 
 /// Represents a null JSON value
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "jsonValueNull")]
 pub struct JsonValueNull {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -282,6 +357,18 @@ impl RObject for JsonValueNull {
     }
 }
 
+impl PartialEq for JsonValueNull {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for JsonValueNull {}
+
+impl std::hash::Hash for JsonValueNull {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDJsonValue for JsonValueNull {}
 
 impl JsonValueNull {
@@ -289,22 +376,49 @@ impl JsonValueNull {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDJsonValueNullBuilder {
-        let mut inner = JsonValueNull::default();
-        inner.td_name = "jsonValueNull".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDJsonValueNullBuilder { inner }
+        RTDJsonValueNullBuilder {
+            inner: JsonValueNull::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDJsonValueNullBuilder {
+        RTDJsonValueNullBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDJsonValueNullBuilder {
+        RTDJsonValueNullBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDJsonValueNullBuilder {
     inner: JsonValueNull,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDJsonValueNullBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> JsonValueNull {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<JsonValueNull> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "JsonValueNull",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<JsonValueNull> for JsonValueNull {
@@ -321,12 +435,13 @@ impl AsRef<JsonValueNull> for RTDJsonValueNullBuilder {
 
 /// Represents a numeric JSON value
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "jsonValueNumber")]
 pub struct JsonValueNumber {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The value
     value: f32,
@@ -346,6 +461,12 @@ impl RObject for JsonValueNumber {
     }
 }
 
+impl PartialEq for JsonValueNumber {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
 impl TDJsonValue for JsonValueNumber {}
 
 impl JsonValueNumber {
@@ -353,29 +474,62 @@ impl JsonValueNumber {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDJsonValueNumberBuilder {
-        let mut inner = JsonValueNumber::default();
-        inner.td_name = "jsonValueNumber".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDJsonValueNumberBuilder { inner }
+        RTDJsonValueNumberBuilder {
+            inner: JsonValueNumber::default(),
+            missing: vec!["value"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDJsonValueNumberBuilder {
+        RTDJsonValueNumberBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDJsonValueNumberBuilder {
+        RTDJsonValueNumberBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn value(&self) -> f32 {
         self.value
     }
+
+    pub fn with_value(mut self, value: f32) -> Self {
+        self.value = value;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDJsonValueNumberBuilder {
     inner: JsonValueNumber,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDJsonValueNumberBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> JsonValueNumber {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<JsonValueNumber> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "JsonValueNumber",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn value(&mut self, value: f32) -> &mut Self {
         self.inner.value = value;
+        self.missing.retain(|f| *f != "value");
         self
     }
 }
@@ -394,12 +548,13 @@ impl AsRef<JsonValueNumber> for RTDJsonValueNumberBuilder {
 
 /// Represents a JSON object
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "jsonValueObject")]
 pub struct JsonValueObject {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The list of object members
     members: Vec<JsonObjectMember>,
@@ -419,6 +574,12 @@ impl RObject for JsonValueObject {
     }
 }
 
+impl PartialEq for JsonValueObject {
+    fn eq(&self, other: &Self) -> bool {
+        self.members == other.members
+    }
+}
+
 impl TDJsonValue for JsonValueObject {}
 
 impl JsonValueObject {
@@ -426,29 +587,66 @@ impl JsonValueObject {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDJsonValueObjectBuilder {
-        let mut inner = JsonValueObject::default();
-        inner.td_name = "jsonValueObject".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDJsonValueObjectBuilder { inner }
+        RTDJsonValueObjectBuilder {
+            inner: JsonValueObject::default(),
+            missing: vec!["members"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDJsonValueObjectBuilder {
+        RTDJsonValueObjectBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDJsonValueObjectBuilder {
+        RTDJsonValueObjectBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn members(&self) -> &Vec<JsonObjectMember> {
         &self.members
     }
+
+    pub fn into_members(self) -> Vec<JsonObjectMember> {
+        self.members
+    }
+
+    pub fn with_members(mut self, members: Vec<JsonObjectMember>) -> Self {
+        self.members = members;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDJsonValueObjectBuilder {
     inner: JsonValueObject,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDJsonValueObjectBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> JsonValueObject {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<JsonValueObject> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "JsonValueObject",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn members(&mut self, members: Vec<JsonObjectMember>) -> &mut Self {
         self.inner.members = members;
+        self.missing.retain(|f| *f != "members");
         self
     }
 }
@@ -467,12 +665,13 @@ impl AsRef<JsonValueObject> for RTDJsonValueObjectBuilder {
 
 /// Represents a string JSON value
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "jsonValueString")]
 pub struct JsonValueString {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The value
     value: String,
@@ -492,6 +691,20 @@ impl RObject for JsonValueString {
     }
 }
 
+impl PartialEq for JsonValueString {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for JsonValueString {}
+
+impl std::hash::Hash for JsonValueString {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.value, state);
+    }
+}
+
 impl TDJsonValue for JsonValueString {}
 
 impl JsonValueString {
@@ -499,29 +712,66 @@ impl JsonValueString {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDJsonValueStringBuilder {
-        let mut inner = JsonValueString::default();
-        inner.td_name = "jsonValueString".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDJsonValueStringBuilder { inner }
+        RTDJsonValueStringBuilder {
+            inner: JsonValueString::default(),
+            missing: vec!["value"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDJsonValueStringBuilder {
+        RTDJsonValueStringBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDJsonValueStringBuilder {
+        RTDJsonValueStringBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn value(&self) -> &String {
         &self.value
     }
+
+    pub fn into_value(self) -> String {
+        self.value
+    }
+
+    pub fn with_value<T: AsRef<str>>(mut self, value: T) -> Self {
+        self.value = value.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDJsonValueStringBuilder {
     inner: JsonValueString,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDJsonValueStringBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> JsonValueString {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<JsonValueString> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "JsonValueString",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn value<T: AsRef<str>>(&mut self, value: T) -> &mut Self {
         self.inner.value = value.as_ref().to_string();
+        self.missing.retain(|f| *f != "value");
         self
     }
 }
@@ -537,3 +787,29 @@ impl AsRef<JsonValueString> for RTDJsonValueStringBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(JsonValue::Array(
+            JsonValueArray::builder().values(vec![]).build(),
+        ));
+        assert_round_trip(JsonValue::Boolean(
+            JsonValueBoolean::builder().value(true).build(),
+        ));
+        assert_round_trip(JsonValue::Null(JsonValueNull::builder().build()));
+        assert_round_trip(JsonValue::Number(
+            JsonValueNumber::builder().value(1.5).build(),
+        ));
+        assert_round_trip(JsonValue::Object(
+            JsonValueObject::builder().members(vec![]).build(),
+        ));
+        assert_round_trip(JsonValue::String(
+            JsonValueString::builder().value("text").build(),
+        ));
+    }
+}
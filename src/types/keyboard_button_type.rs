@@ -1,49 +1,48 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Describes a keyboard button type
 pub trait TDKeyboardButtonType: Debug + RObject {}
 
 /// Describes a keyboard button type
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum KeyboardButtonType {
-    #[doc(hidden)]
-    _Default(()),
     /// A button that sends the user's location when pressed; available only in private chats
+    #[serde(rename = "keyboardButtonTypeRequestLocation")]
     RequestLocation(KeyboardButtonTypeRequestLocation),
     /// A button that sends the user's phone number when pressed; available only in private chats
+    #[serde(rename = "keyboardButtonTypeRequestPhoneNumber")]
     RequestPhoneNumber(KeyboardButtonTypeRequestPhoneNumber),
     /// A button that allows the user to create and send a poll when pressed; available only in private chats
+    #[serde(rename = "keyboardButtonTypeRequestPoll")]
     RequestPoll(KeyboardButtonTypeRequestPoll),
     /// A simple button, with text that should be sent when the button is pressed
+    #[serde(rename = "keyboardButtonTypeText")]
     Text(KeyboardButtonTypeText),
 }
 
+/// Default value is default of [KeyboardButtonTypeRequestLocation], builders still require the field to be set
 impl Default for KeyboardButtonType {
     fn default() -> Self {
-        KeyboardButtonType::_Default(())
+        KeyboardButtonType::RequestLocation(KeyboardButtonTypeRequestLocation::default())
     }
 }
 
-impl<'de> Deserialize<'de> for KeyboardButtonType {
-    fn deserialize<D>(deserializer: D) -> Result<KeyboardButtonType, D::Error>
+impl Serialize for KeyboardButtonType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          KeyboardButtonType,
-          (keyboardButtonTypeRequestLocation, RequestLocation);
-          (keyboardButtonTypeRequestPhoneNumber, RequestPhoneNumber);
-          (keyboardButtonTypeRequestPoll, RequestPoll);
-          (keyboardButtonTypeText, Text);
-
-        )(deserializer)
+        match self {
+            KeyboardButtonType::RequestLocation(t) => t.serialize(serializer),
+            KeyboardButtonType::RequestPhoneNumber(t) => t.serialize(serializer),
+            KeyboardButtonType::RequestPoll(t) => t.serialize(serializer),
+            KeyboardButtonType::Text(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -55,8 +54,6 @@ impl RObject for KeyboardButtonType {
             KeyboardButtonType::RequestPhoneNumber(t) => t.td_name(),
             KeyboardButtonType::RequestPoll(t) => t.td_name(),
             KeyboardButtonType::Text(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -66,8 +63,6 @@ impl RObject for KeyboardButtonType {
             KeyboardButtonType::RequestPhoneNumber(t) => t.extra(),
             KeyboardButtonType::RequestPoll(t) => t.extra(),
             KeyboardButtonType::Text(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -79,10 +74,6 @@ impl KeyboardButtonType {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, KeyboardButtonType::_Default(_))
-    }
 }
 
 impl AsRef<KeyboardButtonType> for KeyboardButtonType {
@@ -93,12 +84,13 @@ impl AsRef<KeyboardButtonType> for KeyboardButtonType {
 
 /// A button that sends the user's location when pressed; available only in private chats
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "keyboardButtonTypeRequestLocation")]
 pub struct KeyboardButtonTypeRequestLocation {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -116,6 +108,18 @@ impl RObject for KeyboardButtonTypeRequestLocation {
     }
 }
 
+impl PartialEq for KeyboardButtonTypeRequestLocation {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for KeyboardButtonTypeRequestLocation {}
+
+impl std::hash::Hash for KeyboardButtonTypeRequestLocation {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDKeyboardButtonType for KeyboardButtonTypeRequestLocation {}
 
 impl KeyboardButtonTypeRequestLocation {
@@ -123,22 +127,49 @@ impl KeyboardButtonTypeRequestLocation {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDKeyboardButtonTypeRequestLocationBuilder {
-        let mut inner = KeyboardButtonTypeRequestLocation::default();
-        inner.td_name = "keyboardButtonTypeRequestLocation".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDKeyboardButtonTypeRequestLocationBuilder { inner }
+        RTDKeyboardButtonTypeRequestLocationBuilder {
+            inner: KeyboardButtonTypeRequestLocation::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDKeyboardButtonTypeRequestLocationBuilder {
+        RTDKeyboardButtonTypeRequestLocationBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDKeyboardButtonTypeRequestLocationBuilder {
+        RTDKeyboardButtonTypeRequestLocationBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDKeyboardButtonTypeRequestLocationBuilder {
     inner: KeyboardButtonTypeRequestLocation,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDKeyboardButtonTypeRequestLocationBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> KeyboardButtonTypeRequestLocation {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<KeyboardButtonTypeRequestLocation> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "KeyboardButtonTypeRequestLocation",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<KeyboardButtonTypeRequestLocation> for KeyboardButtonTypeRequestLocation {
@@ -155,12 +186,13 @@ impl AsRef<KeyboardButtonTypeRequestLocation> for RTDKeyboardButtonTypeRequestLo
 
 /// A button that sends the user's phone number when pressed; available only in private chats
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "keyboardButtonTypeRequestPhoneNumber")]
 pub struct KeyboardButtonTypeRequestPhoneNumber {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -178,6 +210,18 @@ impl RObject for KeyboardButtonTypeRequestPhoneNumber {
     }
 }
 
+impl PartialEq for KeyboardButtonTypeRequestPhoneNumber {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for KeyboardButtonTypeRequestPhoneNumber {}
+
+impl std::hash::Hash for KeyboardButtonTypeRequestPhoneNumber {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDKeyboardButtonType for KeyboardButtonTypeRequestPhoneNumber {}
 
 impl KeyboardButtonTypeRequestPhoneNumber {
@@ -185,22 +229,49 @@ impl KeyboardButtonTypeRequestPhoneNumber {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDKeyboardButtonTypeRequestPhoneNumberBuilder {
-        let mut inner = KeyboardButtonTypeRequestPhoneNumber::default();
-        inner.td_name = "keyboardButtonTypeRequestPhoneNumber".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDKeyboardButtonTypeRequestPhoneNumberBuilder { inner }
+        RTDKeyboardButtonTypeRequestPhoneNumberBuilder {
+            inner: KeyboardButtonTypeRequestPhoneNumber::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDKeyboardButtonTypeRequestPhoneNumberBuilder {
+        RTDKeyboardButtonTypeRequestPhoneNumberBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDKeyboardButtonTypeRequestPhoneNumberBuilder {
+        RTDKeyboardButtonTypeRequestPhoneNumberBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDKeyboardButtonTypeRequestPhoneNumberBuilder {
     inner: KeyboardButtonTypeRequestPhoneNumber,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDKeyboardButtonTypeRequestPhoneNumberBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> KeyboardButtonTypeRequestPhoneNumber {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<KeyboardButtonTypeRequestPhoneNumber> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "KeyboardButtonTypeRequestPhoneNumber",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<KeyboardButtonTypeRequestPhoneNumber> for KeyboardButtonTypeRequestPhoneNumber {
@@ -219,12 +290,13 @@ impl AsRef<KeyboardButtonTypeRequestPhoneNumber>
 
 /// A button that allows the user to create and send a poll when pressed; available only in private chats
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "keyboardButtonTypeRequestPoll")]
 pub struct KeyboardButtonTypeRequestPoll {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// If true, only regular polls must be allowed to create
     force_regular: bool,
@@ -246,6 +318,21 @@ impl RObject for KeyboardButtonTypeRequestPoll {
     }
 }
 
+impl PartialEq for KeyboardButtonTypeRequestPoll {
+    fn eq(&self, other: &Self) -> bool {
+        self.force_regular == other.force_regular && self.force_quiz == other.force_quiz
+    }
+}
+
+impl Eq for KeyboardButtonTypeRequestPoll {}
+
+impl std::hash::Hash for KeyboardButtonTypeRequestPoll {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.force_regular, state);
+        std::hash::Hash::hash(&self.force_quiz, state);
+    }
+}
+
 impl TDKeyboardButtonType for KeyboardButtonTypeRequestPoll {}
 
 impl KeyboardButtonTypeRequestPoll {
@@ -253,10 +340,24 @@ impl KeyboardButtonTypeRequestPoll {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDKeyboardButtonTypeRequestPollBuilder {
-        let mut inner = KeyboardButtonTypeRequestPoll::default();
-        inner.td_name = "keyboardButtonTypeRequestPoll".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDKeyboardButtonTypeRequestPollBuilder { inner }
+        RTDKeyboardButtonTypeRequestPollBuilder {
+            inner: KeyboardButtonTypeRequestPoll::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDKeyboardButtonTypeRequestPollBuilder {
+        RTDKeyboardButtonTypeRequestPollBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDKeyboardButtonTypeRequestPollBuilder {
+        RTDKeyboardButtonTypeRequestPollBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn force_regular(&self) -> bool {
@@ -266,17 +367,40 @@ impl KeyboardButtonTypeRequestPoll {
     pub fn force_quiz(&self) -> bool {
         self.force_quiz
     }
+
+    pub fn with_force_regular(mut self, force_regular: bool) -> Self {
+        self.force_regular = force_regular;
+        self
+    }
+
+    pub fn with_force_quiz(mut self, force_quiz: bool) -> Self {
+        self.force_quiz = force_quiz;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDKeyboardButtonTypeRequestPollBuilder {
     inner: KeyboardButtonTypeRequestPoll,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDKeyboardButtonTypeRequestPollBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> KeyboardButtonTypeRequestPoll {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<KeyboardButtonTypeRequestPoll> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "KeyboardButtonTypeRequestPoll",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn force_regular(&mut self, force_regular: bool) -> &mut Self {
         self.inner.force_regular = force_regular;
@@ -303,12 +427,13 @@ impl AsRef<KeyboardButtonTypeRequestPoll> for RTDKeyboardButtonTypeRequestPollBu
 
 /// A simple button, with text that should be sent when the button is pressed
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "keyboardButtonTypeText")]
 pub struct KeyboardButtonTypeText {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -326,6 +451,18 @@ impl RObject for KeyboardButtonTypeText {
     }
 }
 
+impl PartialEq for KeyboardButtonTypeText {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for KeyboardButtonTypeText {}
+
+impl std::hash::Hash for KeyboardButtonTypeText {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDKeyboardButtonType for KeyboardButtonTypeText {}
 
 impl KeyboardButtonTypeText {
@@ -333,22 +470,49 @@ impl KeyboardButtonTypeText {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDKeyboardButtonTypeTextBuilder {
-        let mut inner = KeyboardButtonTypeText::default();
-        inner.td_name = "keyboardButtonTypeText".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDKeyboardButtonTypeTextBuilder { inner }
+        RTDKeyboardButtonTypeTextBuilder {
+            inner: KeyboardButtonTypeText::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDKeyboardButtonTypeTextBuilder {
+        RTDKeyboardButtonTypeTextBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDKeyboardButtonTypeTextBuilder {
+        RTDKeyboardButtonTypeTextBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDKeyboardButtonTypeTextBuilder {
     inner: KeyboardButtonTypeText,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDKeyboardButtonTypeTextBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> KeyboardButtonTypeText {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<KeyboardButtonTypeText> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "KeyboardButtonTypeText",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<KeyboardButtonTypeText> for KeyboardButtonTypeText {
@@ -362,3 +526,28 @@ impl AsRef<KeyboardButtonTypeText> for RTDKeyboardButtonTypeTextBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(KeyboardButtonType::RequestLocation(
+            KeyboardButtonTypeRequestLocation::builder().build(),
+        ));
+        assert_round_trip(KeyboardButtonType::RequestPhoneNumber(
+            KeyboardButtonTypeRequestPhoneNumber::builder().build(),
+        ));
+        assert_round_trip(KeyboardButtonType::RequestPoll(
+            KeyboardButtonTypeRequestPoll::builder()
+                .force_regular(true)
+                .force_quiz(true)
+                .build(),
+        ));
+        assert_round_trip(KeyboardButtonType::Text(
+            KeyboardButtonTypeText::builder().build(),
+        ));
+    }
+}
@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Portion of the price of a product (e.g., "delivery cost", "tax amount")
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "labeledPricePart")]
 pub struct LabeledPricePart {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Label for this portion of the product price
     label: String,
@@ -31,15 +31,44 @@ impl RObject for LabeledPricePart {
     }
 }
 
+impl PartialEq for LabeledPricePart {
+    fn eq(&self, other: &Self) -> bool {
+        self.label == other.label && self.amount == other.amount
+    }
+}
+
+impl Eq for LabeledPricePart {}
+
+impl std::hash::Hash for LabeledPricePart {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.label, state);
+        std::hash::Hash::hash(&self.amount, state);
+    }
+}
+
 impl LabeledPricePart {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDLabeledPricePartBuilder {
-        let mut inner = LabeledPricePart::default();
-        inner.td_name = "labeledPricePart".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDLabeledPricePartBuilder { inner }
+        RTDLabeledPricePartBuilder {
+            inner: LabeledPricePart::default(),
+            missing: vec!["label", "amount"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDLabeledPricePartBuilder {
+        RTDLabeledPricePartBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDLabeledPricePartBuilder {
+        RTDLabeledPricePartBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn label(&self) -> &String {
@@ -49,25 +78,54 @@ impl LabeledPricePart {
     pub fn amount(&self) -> i64 {
         self.amount
     }
+
+    pub fn into_label(self) -> String {
+        self.label
+    }
+
+    pub fn with_label<T: AsRef<str>>(mut self, label: T) -> Self {
+        self.label = label.as_ref().to_string();
+        self
+    }
+
+    pub fn with_amount(mut self, amount: i64) -> Self {
+        self.amount = amount;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDLabeledPricePartBuilder {
     inner: LabeledPricePart,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDLabeledPricePartBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> LabeledPricePart {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<LabeledPricePart> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "LabeledPricePart",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn label<T: AsRef<str>>(&mut self, label: T) -> &mut Self {
         self.inner.label = label.as_ref().to_string();
+        self.missing.retain(|f| *f != "label");
         self
     }
 
     pub fn amount(&mut self, amount: i64) -> &mut Self {
         self.inner.amount = amount;
+        self.missing.retain(|f| *f != "amount");
         self
     }
 }
@@ -83,3 +141,19 @@ impl AsRef<LabeledPricePart> for RTDLabeledPricePartBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            LabeledPricePart::builder()
+                .label("text")
+                .amount(9007199254740991i64)
+                .build(),
+        );
+    }
+}
@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains information about a language pack
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "languagePackInfo")]
 pub struct LanguagePackInfo {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Unique language pack identifier
     id: String,
@@ -30,11 +30,11 @@ pub struct LanguagePackInfo {
     /// True, if the language pack is installed by the current user
     is_installed: bool,
     /// Total number of non-deleted strings from the language pack
-    total_string_count: i64,
+    total_string_count: i32,
     /// Total number of translated strings from the language pack
-    translated_string_count: i64,
+    translated_string_count: i32,
     /// Total number of non-deleted strings from the language pack available locally
-    local_string_count: i64,
+    local_string_count: i32,
     /// Link to language translation interface; empty for custom local language packs
     translation_url: String,
 }
@@ -53,15 +53,76 @@ impl RObject for LanguagePackInfo {
     }
 }
 
+impl PartialEq for LanguagePackInfo {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.base_language_pack_id == other.base_language_pack_id
+            && self.name == other.name
+            && self.native_name == other.native_name
+            && self.plural_code == other.plural_code
+            && self.is_official == other.is_official
+            && self.is_rtl == other.is_rtl
+            && self.is_beta == other.is_beta
+            && self.is_installed == other.is_installed
+            && self.total_string_count == other.total_string_count
+            && self.translated_string_count == other.translated_string_count
+            && self.local_string_count == other.local_string_count
+            && self.translation_url == other.translation_url
+    }
+}
+
+impl Eq for LanguagePackInfo {}
+
+impl std::hash::Hash for LanguagePackInfo {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.id, state);
+        std::hash::Hash::hash(&self.base_language_pack_id, state);
+        std::hash::Hash::hash(&self.name, state);
+        std::hash::Hash::hash(&self.native_name, state);
+        std::hash::Hash::hash(&self.plural_code, state);
+        std::hash::Hash::hash(&self.is_official, state);
+        std::hash::Hash::hash(&self.is_rtl, state);
+        std::hash::Hash::hash(&self.is_beta, state);
+        std::hash::Hash::hash(&self.is_installed, state);
+        std::hash::Hash::hash(&self.total_string_count, state);
+        std::hash::Hash::hash(&self.translated_string_count, state);
+        std::hash::Hash::hash(&self.local_string_count, state);
+        std::hash::Hash::hash(&self.translation_url, state);
+    }
+}
+
 impl LanguagePackInfo {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDLanguagePackInfoBuilder {
-        let mut inner = LanguagePackInfo::default();
-        inner.td_name = "languagePackInfo".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDLanguagePackInfoBuilder { inner }
+        RTDLanguagePackInfoBuilder {
+            inner: LanguagePackInfo::default(),
+            missing: vec![
+                "id",
+                "name",
+                "native_name",
+                "plural_code",
+                "total_string_count",
+                "translated_string_count",
+                "local_string_count",
+                "translation_url",
+            ],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDLanguagePackInfoBuilder {
+        RTDLanguagePackInfoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDLanguagePackInfoBuilder {
+        RTDLanguagePackInfoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn id(&self) -> &String {
@@ -100,35 +161,138 @@ impl LanguagePackInfo {
         self.is_installed
     }
 
-    pub fn total_string_count(&self) -> i64 {
+    pub fn total_string_count(&self) -> i32 {
         self.total_string_count
     }
 
-    pub fn translated_string_count(&self) -> i64 {
+    pub fn translated_string_count(&self) -> i32 {
         self.translated_string_count
     }
 
-    pub fn local_string_count(&self) -> i64 {
+    pub fn local_string_count(&self) -> i32 {
         self.local_string_count
     }
 
     pub fn translation_url(&self) -> &String {
         &self.translation_url
     }
+
+    pub fn into_id(self) -> String {
+        self.id
+    }
+
+    pub fn into_base_language_pack_id(self) -> String {
+        self.base_language_pack_id
+    }
+
+    pub fn into_name(self) -> String {
+        self.name
+    }
+
+    pub fn into_native_name(self) -> String {
+        self.native_name
+    }
+
+    pub fn into_plural_code(self) -> String {
+        self.plural_code
+    }
+
+    pub fn into_translation_url(self) -> String {
+        self.translation_url
+    }
+
+    pub fn with_id<T: AsRef<str>>(mut self, id: T) -> Self {
+        self.id = id.as_ref().to_string();
+        self
+    }
+
+    pub fn with_base_language_pack_id<T: AsRef<str>>(mut self, base_language_pack_id: T) -> Self {
+        self.base_language_pack_id = base_language_pack_id.as_ref().to_string();
+        self
+    }
+
+    pub fn with_name<T: AsRef<str>>(mut self, name: T) -> Self {
+        self.name = name.as_ref().to_string();
+        self
+    }
+
+    pub fn with_native_name<T: AsRef<str>>(mut self, native_name: T) -> Self {
+        self.native_name = native_name.as_ref().to_string();
+        self
+    }
+
+    pub fn with_plural_code<T: AsRef<str>>(mut self, plural_code: T) -> Self {
+        self.plural_code = plural_code.as_ref().to_string();
+        self
+    }
+
+    pub fn with_is_official(mut self, is_official: bool) -> Self {
+        self.is_official = is_official;
+        self
+    }
+
+    pub fn with_is_rtl(mut self, is_rtl: bool) -> Self {
+        self.is_rtl = is_rtl;
+        self
+    }
+
+    pub fn with_is_beta(mut self, is_beta: bool) -> Self {
+        self.is_beta = is_beta;
+        self
+    }
+
+    pub fn with_is_installed(mut self, is_installed: bool) -> Self {
+        self.is_installed = is_installed;
+        self
+    }
+
+    pub fn with_total_string_count(mut self, total_string_count: i32) -> Self {
+        self.total_string_count = total_string_count;
+        self
+    }
+
+    pub fn with_translated_string_count(mut self, translated_string_count: i32) -> Self {
+        self.translated_string_count = translated_string_count;
+        self
+    }
+
+    pub fn with_local_string_count(mut self, local_string_count: i32) -> Self {
+        self.local_string_count = local_string_count;
+        self
+    }
+
+    pub fn with_translation_url<T: AsRef<str>>(mut self, translation_url: T) -> Self {
+        self.translation_url = translation_url.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDLanguagePackInfoBuilder {
     inner: LanguagePackInfo,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDLanguagePackInfoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> LanguagePackInfo {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<LanguagePackInfo> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "LanguagePackInfo",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
         self.inner.id = id.as_ref().to_string();
+        self.missing.retain(|f| *f != "id");
         self
     }
 
@@ -139,16 +303,19 @@ impl RTDLanguagePackInfoBuilder {
 
     pub fn name<T: AsRef<str>>(&mut self, name: T) -> &mut Self {
         self.inner.name = name.as_ref().to_string();
+        self.missing.retain(|f| *f != "name");
         self
     }
 
     pub fn native_name<T: AsRef<str>>(&mut self, native_name: T) -> &mut Self {
         self.inner.native_name = native_name.as_ref().to_string();
+        self.missing.retain(|f| *f != "native_name");
         self
     }
 
     pub fn plural_code<T: AsRef<str>>(&mut self, plural_code: T) -> &mut Self {
         self.inner.plural_code = plural_code.as_ref().to_string();
+        self.missing.retain(|f| *f != "plural_code");
         self
     }
 
@@ -172,23 +339,27 @@ impl RTDLanguagePackInfoBuilder {
         self
     }
 
-    pub fn total_string_count(&mut self, total_string_count: i64) -> &mut Self {
+    pub fn total_string_count(&mut self, total_string_count: i32) -> &mut Self {
         self.inner.total_string_count = total_string_count;
+        self.missing.retain(|f| *f != "total_string_count");
         self
     }
 
-    pub fn translated_string_count(&mut self, translated_string_count: i64) -> &mut Self {
+    pub fn translated_string_count(&mut self, translated_string_count: i32) -> &mut Self {
         self.inner.translated_string_count = translated_string_count;
+        self.missing.retain(|f| *f != "translated_string_count");
         self
     }
 
-    pub fn local_string_count(&mut self, local_string_count: i64) -> &mut Self {
+    pub fn local_string_count(&mut self, local_string_count: i32) -> &mut Self {
         self.inner.local_string_count = local_string_count;
+        self.missing.retain(|f| *f != "local_string_count");
         self
     }
 
     pub fn translation_url<T: AsRef<str>>(&mut self, translation_url: T) -> &mut Self {
         self.inner.translation_url = translation_url.as_ref().to_string();
+        self.missing.retain(|f| *f != "translation_url");
         self
     }
 }
@@ -204,3 +375,30 @@ impl AsRef<LanguagePackInfo> for RTDLanguagePackInfoBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            LanguagePackInfo::builder()
+                .id("text")
+                .base_language_pack_id("text")
+                .name("text")
+                .native_name("text")
+                .plural_code("text")
+                .is_official(true)
+                .is_rtl(true)
+                .is_beta(true)
+                .is_installed(true)
+                .total_string_count(i32::MAX)
+                .translated_string_count(i32::MAX)
+                .local_string_count(i32::MAX)
+                .translation_url("text")
+                .build(),
+        );
+    }
+}
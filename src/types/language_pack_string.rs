@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Represents one language pack string
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "languagePackString")]
 pub struct LanguagePackString {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// String key
     key: String,
@@ -31,15 +31,44 @@ impl RObject for LanguagePackString {
     }
 }
 
+impl PartialEq for LanguagePackString {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key && self.value == other.value
+    }
+}
+
+impl Eq for LanguagePackString {}
+
+impl std::hash::Hash for LanguagePackString {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.key, state);
+        std::hash::Hash::hash(&self.value, state);
+    }
+}
+
 impl LanguagePackString {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDLanguagePackStringBuilder {
-        let mut inner = LanguagePackString::default();
-        inner.td_name = "languagePackString".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDLanguagePackStringBuilder { inner }
+        RTDLanguagePackStringBuilder {
+            inner: LanguagePackString::default(),
+            missing: vec!["key", "value"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDLanguagePackStringBuilder {
+        RTDLanguagePackStringBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDLanguagePackStringBuilder {
+        RTDLanguagePackStringBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn key(&self) -> &String {
@@ -49,25 +78,58 @@ impl LanguagePackString {
     pub fn value(&self) -> &LanguagePackStringValue {
         &self.value
     }
+
+    pub fn into_key(self) -> String {
+        self.key
+    }
+
+    pub fn into_value(self) -> LanguagePackStringValue {
+        self.value
+    }
+
+    pub fn with_key<T: AsRef<str>>(mut self, key: T) -> Self {
+        self.key = key.as_ref().to_string();
+        self
+    }
+
+    pub fn with_value<T: AsRef<LanguagePackStringValue>>(mut self, value: T) -> Self {
+        self.value = value.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDLanguagePackStringBuilder {
     inner: LanguagePackString,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDLanguagePackStringBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> LanguagePackString {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<LanguagePackString> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "LanguagePackString",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn key<T: AsRef<str>>(&mut self, key: T) -> &mut Self {
         self.inner.key = key.as_ref().to_string();
+        self.missing.retain(|f| *f != "key");
         self
     }
 
     pub fn value<T: AsRef<LanguagePackStringValue>>(&mut self, value: T) -> &mut Self {
         self.inner.value = value.as_ref().clone();
+        self.missing.retain(|f| *f != "value");
         self
     }
 }
@@ -83,3 +145,21 @@ impl AsRef<LanguagePackString> for RTDLanguagePackStringBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            LanguagePackString::builder()
+                .key("text")
+                .value(LanguagePackStringValue::Deleted(
+                    LanguagePackStringValueDeleted::builder().build(),
+                ))
+                .build(),
+        );
+    }
+}
@@ -1,49 +1,44 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Represents the value of a string in a language pack
 pub trait TDLanguagePackStringValue: Debug + RObject {}
 
 /// Represents the value of a string in a language pack
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum LanguagePackStringValue {
-    #[doc(hidden)]
-    _Default(()),
-    /// Returns a string stored in the local database from the specified localization target and language pack by its key. Returns a 404 error if the string is not found. This is an offline method. Can be called before authorization. Can be called synchronously
-    GetLanguagePackString(GetLanguagePackString),
     /// A deleted language pack string, the value should be taken from the built-in english language pack
+    #[serde(rename = "languagePackStringValueDeleted")]
     Deleted(LanguagePackStringValueDeleted),
     /// An ordinary language pack string
+    #[serde(rename = "languagePackStringValueOrdinary")]
     Ordinary(LanguagePackStringValueOrdinary),
     /// A language pack string which has different forms based on the number of some object it mentions. See https://www.unicode.org/cldr/charts/latest/supplemental/language_plural_rules.html for more info
+    #[serde(rename = "languagePackStringValuePluralized")]
     Pluralized(LanguagePackStringValuePluralized),
 }
 
+/// Default value is default of [LanguagePackStringValueDeleted], builders still require the field to be set
 impl Default for LanguagePackStringValue {
     fn default() -> Self {
-        LanguagePackStringValue::_Default(())
+        LanguagePackStringValue::Deleted(LanguagePackStringValueDeleted::default())
     }
 }
 
-impl<'de> Deserialize<'de> for LanguagePackStringValue {
-    fn deserialize<D>(deserializer: D) -> Result<LanguagePackStringValue, D::Error>
+impl Serialize for LanguagePackStringValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          LanguagePackStringValue,
-          (getLanguagePackString, GetLanguagePackString);
-          (languagePackStringValueDeleted, Deleted);
-          (languagePackStringValueOrdinary, Ordinary);
-          (languagePackStringValuePluralized, Pluralized);
-
-        )(deserializer)
+        match self {
+            LanguagePackStringValue::Deleted(t) => t.serialize(serializer),
+            LanguagePackStringValue::Ordinary(t) => t.serialize(serializer),
+            LanguagePackStringValue::Pluralized(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -51,23 +46,17 @@ impl RObject for LanguagePackStringValue {
     #[doc(hidden)]
     fn td_name(&self) -> &'static str {
         match self {
-            LanguagePackStringValue::GetLanguagePackString(t) => t.td_name(),
             LanguagePackStringValue::Deleted(t) => t.td_name(),
             LanguagePackStringValue::Ordinary(t) => t.td_name(),
             LanguagePackStringValue::Pluralized(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
     fn extra(&self) -> Option<String> {
         match self {
-            LanguagePackStringValue::GetLanguagePackString(t) => t.extra(),
             LanguagePackStringValue::Deleted(t) => t.extra(),
             LanguagePackStringValue::Ordinary(t) => t.extra(),
             LanguagePackStringValue::Pluralized(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -79,10 +68,6 @@ impl LanguagePackStringValue {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, LanguagePackStringValue::_Default(_))
-    }
 }
 
 impl AsRef<LanguagePackStringValue> for LanguagePackStringValue {
@@ -93,12 +78,13 @@ impl AsRef<LanguagePackStringValue> for LanguagePackStringValue {
 
 /// A deleted language pack string, the value should be taken from the built-in english language pack
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "languagePackStringValueDeleted")]
 pub struct LanguagePackStringValueDeleted {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -116,6 +102,18 @@ impl RObject for LanguagePackStringValueDeleted {
     }
 }
 
+impl PartialEq for LanguagePackStringValueDeleted {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for LanguagePackStringValueDeleted {}
+
+impl std::hash::Hash for LanguagePackStringValueDeleted {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDLanguagePackStringValue for LanguagePackStringValueDeleted {}
 
 impl LanguagePackStringValueDeleted {
@@ -123,22 +121,49 @@ impl LanguagePackStringValueDeleted {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDLanguagePackStringValueDeletedBuilder {
-        let mut inner = LanguagePackStringValueDeleted::default();
-        inner.td_name = "languagePackStringValueDeleted".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDLanguagePackStringValueDeletedBuilder { inner }
+        RTDLanguagePackStringValueDeletedBuilder {
+            inner: LanguagePackStringValueDeleted::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDLanguagePackStringValueDeletedBuilder {
+        RTDLanguagePackStringValueDeletedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDLanguagePackStringValueDeletedBuilder {
+        RTDLanguagePackStringValueDeletedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDLanguagePackStringValueDeletedBuilder {
     inner: LanguagePackStringValueDeleted,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDLanguagePackStringValueDeletedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> LanguagePackStringValueDeleted {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<LanguagePackStringValueDeleted> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "LanguagePackStringValueDeleted",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<LanguagePackStringValueDeleted> for LanguagePackStringValueDeleted {
@@ -155,12 +180,13 @@ impl AsRef<LanguagePackStringValueDeleted> for RTDLanguagePackStringValueDeleted
 
 /// An ordinary language pack string
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "languagePackStringValueOrdinary")]
 pub struct LanguagePackStringValueOrdinary {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// String value
     value: String,
@@ -180,6 +206,20 @@ impl RObject for LanguagePackStringValueOrdinary {
     }
 }
 
+impl PartialEq for LanguagePackStringValueOrdinary {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for LanguagePackStringValueOrdinary {}
+
+impl std::hash::Hash for LanguagePackStringValueOrdinary {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.value, state);
+    }
+}
+
 impl TDLanguagePackStringValue for LanguagePackStringValueOrdinary {}
 
 impl LanguagePackStringValueOrdinary {
@@ -187,29 +227,66 @@ impl LanguagePackStringValueOrdinary {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDLanguagePackStringValueOrdinaryBuilder {
-        let mut inner = LanguagePackStringValueOrdinary::default();
-        inner.td_name = "languagePackStringValueOrdinary".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDLanguagePackStringValueOrdinaryBuilder { inner }
+        RTDLanguagePackStringValueOrdinaryBuilder {
+            inner: LanguagePackStringValueOrdinary::default(),
+            missing: vec!["value"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDLanguagePackStringValueOrdinaryBuilder {
+        RTDLanguagePackStringValueOrdinaryBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDLanguagePackStringValueOrdinaryBuilder {
+        RTDLanguagePackStringValueOrdinaryBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn value(&self) -> &String {
         &self.value
     }
+
+    pub fn into_value(self) -> String {
+        self.value
+    }
+
+    pub fn with_value<T: AsRef<str>>(mut self, value: T) -> Self {
+        self.value = value.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDLanguagePackStringValueOrdinaryBuilder {
     inner: LanguagePackStringValueOrdinary,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDLanguagePackStringValueOrdinaryBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> LanguagePackStringValueOrdinary {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<LanguagePackStringValueOrdinary> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "LanguagePackStringValueOrdinary",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn value<T: AsRef<str>>(&mut self, value: T) -> &mut Self {
         self.inner.value = value.as_ref().to_string();
+        self.missing.retain(|f| *f != "value");
         self
     }
 }
@@ -228,12 +305,13 @@ impl AsRef<LanguagePackStringValueOrdinary> for RTDLanguagePackStringValueOrdina
 
 /// A language pack string which has different forms based on the number of some object it mentions. See https://www.unicode.org/cldr/charts/latest/supplemental/language_plural_rules.html for more info
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "languagePackStringValuePluralized")]
 pub struct LanguagePackStringValuePluralized {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Value for zero objects
     zero_value: String,
@@ -263,6 +341,30 @@ impl RObject for LanguagePackStringValuePluralized {
     }
 }
 
+impl PartialEq for LanguagePackStringValuePluralized {
+    fn eq(&self, other: &Self) -> bool {
+        self.zero_value == other.zero_value
+            && self.one_value == other.one_value
+            && self.two_value == other.two_value
+            && self.few_value == other.few_value
+            && self.many_value == other.many_value
+            && self.other_value == other.other_value
+    }
+}
+
+impl Eq for LanguagePackStringValuePluralized {}
+
+impl std::hash::Hash for LanguagePackStringValuePluralized {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.zero_value, state);
+        std::hash::Hash::hash(&self.one_value, state);
+        std::hash::Hash::hash(&self.two_value, state);
+        std::hash::Hash::hash(&self.few_value, state);
+        std::hash::Hash::hash(&self.many_value, state);
+        std::hash::Hash::hash(&self.other_value, state);
+    }
+}
+
 impl TDLanguagePackStringValue for LanguagePackStringValuePluralized {}
 
 impl LanguagePackStringValuePluralized {
@@ -270,10 +372,30 @@ impl LanguagePackStringValuePluralized {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDLanguagePackStringValuePluralizedBuilder {
-        let mut inner = LanguagePackStringValuePluralized::default();
-        inner.td_name = "languagePackStringValuePluralized".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDLanguagePackStringValuePluralizedBuilder { inner }
+        RTDLanguagePackStringValuePluralizedBuilder {
+            inner: LanguagePackStringValuePluralized::default(),
+            missing: vec![
+                "one_value",
+                "two_value",
+                "few_value",
+                "many_value",
+                "other_value",
+            ],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDLanguagePackStringValuePluralizedBuilder {
+        RTDLanguagePackStringValuePluralizedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDLanguagePackStringValuePluralizedBuilder {
+        RTDLanguagePackStringValuePluralizedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn zero_value(&self) -> &String {
@@ -299,17 +421,84 @@ impl LanguagePackStringValuePluralized {
     pub fn other_value(&self) -> &String {
         &self.other_value
     }
+
+    pub fn into_zero_value(self) -> String {
+        self.zero_value
+    }
+
+    pub fn into_one_value(self) -> String {
+        self.one_value
+    }
+
+    pub fn into_two_value(self) -> String {
+        self.two_value
+    }
+
+    pub fn into_few_value(self) -> String {
+        self.few_value
+    }
+
+    pub fn into_many_value(self) -> String {
+        self.many_value
+    }
+
+    pub fn into_other_value(self) -> String {
+        self.other_value
+    }
+
+    pub fn with_zero_value<T: AsRef<str>>(mut self, zero_value: T) -> Self {
+        self.zero_value = zero_value.as_ref().to_string();
+        self
+    }
+
+    pub fn with_one_value<T: AsRef<str>>(mut self, one_value: T) -> Self {
+        self.one_value = one_value.as_ref().to_string();
+        self
+    }
+
+    pub fn with_two_value<T: AsRef<str>>(mut self, two_value: T) -> Self {
+        self.two_value = two_value.as_ref().to_string();
+        self
+    }
+
+    pub fn with_few_value<T: AsRef<str>>(mut self, few_value: T) -> Self {
+        self.few_value = few_value.as_ref().to_string();
+        self
+    }
+
+    pub fn with_many_value<T: AsRef<str>>(mut self, many_value: T) -> Self {
+        self.many_value = many_value.as_ref().to_string();
+        self
+    }
+
+    pub fn with_other_value<T: AsRef<str>>(mut self, other_value: T) -> Self {
+        self.other_value = other_value.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDLanguagePackStringValuePluralizedBuilder {
     inner: LanguagePackStringValuePluralized,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDLanguagePackStringValuePluralizedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> LanguagePackStringValuePluralized {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<LanguagePackStringValuePluralized> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "LanguagePackStringValuePluralized",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn zero_value<T: AsRef<str>>(&mut self, zero_value: T) -> &mut Self {
         self.inner.zero_value = zero_value.as_ref().to_string();
@@ -318,26 +507,31 @@ impl RTDLanguagePackStringValuePluralizedBuilder {
 
     pub fn one_value<T: AsRef<str>>(&mut self, one_value: T) -> &mut Self {
         self.inner.one_value = one_value.as_ref().to_string();
+        self.missing.retain(|f| *f != "one_value");
         self
     }
 
     pub fn two_value<T: AsRef<str>>(&mut self, two_value: T) -> &mut Self {
         self.inner.two_value = two_value.as_ref().to_string();
+        self.missing.retain(|f| *f != "two_value");
         self
     }
 
     pub fn few_value<T: AsRef<str>>(&mut self, few_value: T) -> &mut Self {
         self.inner.few_value = few_value.as_ref().to_string();
+        self.missing.retain(|f| *f != "few_value");
         self
     }
 
     pub fn many_value<T: AsRef<str>>(&mut self, many_value: T) -> &mut Self {
         self.inner.many_value = many_value.as_ref().to_string();
+        self.missing.retain(|f| *f != "many_value");
         self
     }
 
     pub fn other_value<T: AsRef<str>>(&mut self, other_value: T) -> &mut Self {
         self.inner.other_value = other_value.as_ref().to_string();
+        self.missing.retain(|f| *f != "other_value");
         self
     }
 }
@@ -353,3 +547,31 @@ impl AsRef<LanguagePackStringValuePluralized> for RTDLanguagePackStringValuePlur
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(LanguagePackStringValue::Deleted(
+            LanguagePackStringValueDeleted::builder().build(),
+        ));
+        assert_round_trip(LanguagePackStringValue::Ordinary(
+            LanguagePackStringValueOrdinary::builder()
+                .value("text")
+                .build(),
+        ));
+        assert_round_trip(LanguagePackStringValue::Pluralized(
+            LanguagePackStringValuePluralized::builder()
+                .zero_value("text")
+                .one_value("text")
+                .two_value("text")
+                .few_value("text")
+                .many_value("text")
+                .other_value("text")
+                .build(),
+        ));
+    }
+}
@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains a list of language pack strings
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "languagePackStrings")]
 pub struct LanguagePackStrings {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// A list of language pack strings
     strings: Vec<LanguagePackString>,
@@ -29,34 +29,85 @@ impl RObject for LanguagePackStrings {
     }
 }
 
+impl PartialEq for LanguagePackStrings {
+    fn eq(&self, other: &Self) -> bool {
+        self.strings == other.strings
+    }
+}
+
+impl Eq for LanguagePackStrings {}
+
+impl std::hash::Hash for LanguagePackStrings {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.strings, state);
+    }
+}
+
 impl LanguagePackStrings {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDLanguagePackStringsBuilder {
-        let mut inner = LanguagePackStrings::default();
-        inner.td_name = "languagePackStrings".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDLanguagePackStringsBuilder { inner }
+        RTDLanguagePackStringsBuilder {
+            inner: LanguagePackStrings::default(),
+            missing: vec!["strings"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDLanguagePackStringsBuilder {
+        RTDLanguagePackStringsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDLanguagePackStringsBuilder {
+        RTDLanguagePackStringsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn strings(&self) -> &Vec<LanguagePackString> {
         &self.strings
     }
+
+    pub fn into_strings(self) -> Vec<LanguagePackString> {
+        self.strings
+    }
+
+    pub fn with_strings(mut self, strings: Vec<LanguagePackString>) -> Self {
+        self.strings = strings;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDLanguagePackStringsBuilder {
     inner: LanguagePackStrings,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDLanguagePackStringsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> LanguagePackStrings {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<LanguagePackStrings> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "LanguagePackStrings",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn strings(&mut self, strings: Vec<LanguagePackString>) -> &mut Self {
         self.inner.strings = strings;
+        self.missing.retain(|f| *f != "strings");
         self
     }
 }
@@ -72,3 +123,14 @@ impl AsRef<LanguagePackStrings> for RTDLanguagePackStringsBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(LanguagePackStrings::builder().strings(vec![]).build());
+    }
+}
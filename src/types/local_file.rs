@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Represents a local file
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "localFile")]
 pub struct LocalFile {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Local path to the locally available file part; may be empty
     path: String,
@@ -22,11 +22,11 @@ pub struct LocalFile {
     /// True, if the local copy is fully available
     is_downloading_completed: bool,
     /// Download will be started from this offset. downloaded_prefix_size is calculated from this offset
-    download_offset: i64,
+    download_offset: i32,
     /// If is_downloading_completed is false, then only some prefix of the file starting from download_offset is ready to be read. downloaded_prefix_size is the size of that prefix
-    downloaded_prefix_size: i64,
+    downloaded_prefix_size: i32,
     /// Total downloaded file bytes. Should be used only for calculating download progress. The actual file size may be bigger, and some parts of it may contain garbage
-    downloaded_size: i64,
+    downloaded_size: i32,
 }
 
 impl RObject for LocalFile {
@@ -43,15 +43,61 @@ impl RObject for LocalFile {
     }
 }
 
+impl PartialEq for LocalFile {
+    fn eq(&self, other: &Self) -> bool {
+        self.path == other.path
+            && self.can_be_downloaded == other.can_be_downloaded
+            && self.can_be_deleted == other.can_be_deleted
+            && self.is_downloading_active == other.is_downloading_active
+            && self.is_downloading_completed == other.is_downloading_completed
+            && self.download_offset == other.download_offset
+            && self.downloaded_prefix_size == other.downloaded_prefix_size
+            && self.downloaded_size == other.downloaded_size
+    }
+}
+
+impl Eq for LocalFile {}
+
+impl std::hash::Hash for LocalFile {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.path, state);
+        std::hash::Hash::hash(&self.can_be_downloaded, state);
+        std::hash::Hash::hash(&self.can_be_deleted, state);
+        std::hash::Hash::hash(&self.is_downloading_active, state);
+        std::hash::Hash::hash(&self.is_downloading_completed, state);
+        std::hash::Hash::hash(&self.download_offset, state);
+        std::hash::Hash::hash(&self.downloaded_prefix_size, state);
+        std::hash::Hash::hash(&self.downloaded_size, state);
+    }
+}
+
 impl LocalFile {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDLocalFileBuilder {
-        let mut inner = LocalFile::default();
-        inner.td_name = "localFile".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDLocalFileBuilder { inner }
+        RTDLocalFileBuilder {
+            inner: LocalFile::default(),
+            missing: vec![
+                "download_offset",
+                "downloaded_prefix_size",
+                "downloaded_size",
+            ],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDLocalFileBuilder {
+        RTDLocalFileBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDLocalFileBuilder {
+        RTDLocalFileBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn path(&self) -> &String {
@@ -74,28 +120,82 @@ impl LocalFile {
         self.is_downloading_completed
     }
 
-    pub fn download_offset(&self) -> i64 {
+    pub fn download_offset(&self) -> i32 {
         self.download_offset
     }
 
-    pub fn downloaded_prefix_size(&self) -> i64 {
+    pub fn downloaded_prefix_size(&self) -> i32 {
         self.downloaded_prefix_size
     }
 
-    pub fn downloaded_size(&self) -> i64 {
+    pub fn downloaded_size(&self) -> i32 {
         self.downloaded_size
     }
+
+    pub fn into_path(self) -> String {
+        self.path
+    }
+
+    pub fn with_path<T: AsRef<str>>(mut self, path: T) -> Self {
+        self.path = path.as_ref().to_string();
+        self
+    }
+
+    pub fn with_can_be_downloaded(mut self, can_be_downloaded: bool) -> Self {
+        self.can_be_downloaded = can_be_downloaded;
+        self
+    }
+
+    pub fn with_can_be_deleted(mut self, can_be_deleted: bool) -> Self {
+        self.can_be_deleted = can_be_deleted;
+        self
+    }
+
+    pub fn with_is_downloading_active(mut self, is_downloading_active: bool) -> Self {
+        self.is_downloading_active = is_downloading_active;
+        self
+    }
+
+    pub fn with_is_downloading_completed(mut self, is_downloading_completed: bool) -> Self {
+        self.is_downloading_completed = is_downloading_completed;
+        self
+    }
+
+    pub fn with_download_offset(mut self, download_offset: i32) -> Self {
+        self.download_offset = download_offset;
+        self
+    }
+
+    pub fn with_downloaded_prefix_size(mut self, downloaded_prefix_size: i32) -> Self {
+        self.downloaded_prefix_size = downloaded_prefix_size;
+        self
+    }
+
+    pub fn with_downloaded_size(mut self, downloaded_size: i32) -> Self {
+        self.downloaded_size = downloaded_size;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDLocalFileBuilder {
     inner: LocalFile,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDLocalFileBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> LocalFile {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<LocalFile> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("LocalFile", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn path<T: AsRef<str>>(&mut self, path: T) -> &mut Self {
         self.inner.path = path.as_ref().to_string();
@@ -122,18 +222,21 @@ impl RTDLocalFileBuilder {
         self
     }
 
-    pub fn download_offset(&mut self, download_offset: i64) -> &mut Self {
+    pub fn download_offset(&mut self, download_offset: i32) -> &mut Self {
         self.inner.download_offset = download_offset;
+        self.missing.retain(|f| *f != "download_offset");
         self
     }
 
-    pub fn downloaded_prefix_size(&mut self, downloaded_prefix_size: i64) -> &mut Self {
+    pub fn downloaded_prefix_size(&mut self, downloaded_prefix_size: i32) -> &mut Self {
         self.inner.downloaded_prefix_size = downloaded_prefix_size;
+        self.missing.retain(|f| *f != "downloaded_prefix_size");
         self
     }
 
-    pub fn downloaded_size(&mut self, downloaded_size: i64) -> &mut Self {
+    pub fn downloaded_size(&mut self, downloaded_size: i32) -> &mut Self {
         self.inner.downloaded_size = downloaded_size;
+        self.missing.retain(|f| *f != "downloaded_size");
         self
     }
 }
@@ -149,3 +252,25 @@ impl AsRef<LocalFile> for RTDLocalFileBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            LocalFile::builder()
+                .path("text")
+                .can_be_downloaded(true)
+                .can_be_deleted(true)
+                .is_downloading_active(true)
+                .is_downloading_completed(true)
+                .download_offset(i32::MAX)
+                .downloaded_prefix_size(i32::MAX)
+                .downloaded_size(i32::MAX)
+                .build(),
+        );
+    }
+}
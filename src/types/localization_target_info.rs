@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains information about the current localization target
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "localizationTargetInfo")]
 pub struct LocalizationTargetInfo {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// List of available language packs for this application
     language_packs: Vec<LanguagePackInfo>,
@@ -29,34 +29,85 @@ impl RObject for LocalizationTargetInfo {
     }
 }
 
+impl PartialEq for LocalizationTargetInfo {
+    fn eq(&self, other: &Self) -> bool {
+        self.language_packs == other.language_packs
+    }
+}
+
+impl Eq for LocalizationTargetInfo {}
+
+impl std::hash::Hash for LocalizationTargetInfo {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.language_packs, state);
+    }
+}
+
 impl LocalizationTargetInfo {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDLocalizationTargetInfoBuilder {
-        let mut inner = LocalizationTargetInfo::default();
-        inner.td_name = "localizationTargetInfo".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDLocalizationTargetInfoBuilder { inner }
+        RTDLocalizationTargetInfoBuilder {
+            inner: LocalizationTargetInfo::default(),
+            missing: vec!["language_packs"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDLocalizationTargetInfoBuilder {
+        RTDLocalizationTargetInfoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDLocalizationTargetInfoBuilder {
+        RTDLocalizationTargetInfoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn language_packs(&self) -> &Vec<LanguagePackInfo> {
         &self.language_packs
     }
+
+    pub fn into_language_packs(self) -> Vec<LanguagePackInfo> {
+        self.language_packs
+    }
+
+    pub fn with_language_packs(mut self, language_packs: Vec<LanguagePackInfo>) -> Self {
+        self.language_packs = language_packs;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDLocalizationTargetInfoBuilder {
     inner: LocalizationTargetInfo,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDLocalizationTargetInfoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> LocalizationTargetInfo {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<LocalizationTargetInfo> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "LocalizationTargetInfo",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn language_packs(&mut self, language_packs: Vec<LanguagePackInfo>) -> &mut Self {
         self.inner.language_packs = language_packs;
+        self.missing.retain(|f| *f != "language_packs");
         self
     }
 }
@@ -72,3 +123,18 @@ impl AsRef<LocalizationTargetInfo> for RTDLocalizationTargetInfoBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            LocalizationTargetInfo::builder()
+                .language_packs(vec![])
+                .build(),
+        );
+    }
+}
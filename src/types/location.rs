@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Describes a location on planet Earth
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "location")]
 pub struct Location {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Latitude of the location in degrees; as defined by the sender
     latitude: f32,
@@ -31,15 +31,35 @@ impl RObject for Location {
     }
 }
 
+impl PartialEq for Location {
+    fn eq(&self, other: &Self) -> bool {
+        self.latitude == other.latitude && self.longitude == other.longitude
+    }
+}
+
 impl Location {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDLocationBuilder {
-        let mut inner = Location::default();
-        inner.td_name = "location".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDLocationBuilder { inner }
+        RTDLocationBuilder {
+            inner: Location::default(),
+            missing: vec!["latitude", "longitude"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDLocationBuilder {
+        RTDLocationBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDLocationBuilder {
+        RTDLocationBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn latitude(&self) -> f32 {
@@ -49,25 +69,47 @@ impl Location {
     pub fn longitude(&self) -> f32 {
         self.longitude
     }
+
+    pub fn with_latitude(mut self, latitude: f32) -> Self {
+        self.latitude = latitude;
+        self
+    }
+
+    pub fn with_longitude(mut self, longitude: f32) -> Self {
+        self.longitude = longitude;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDLocationBuilder {
     inner: Location,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDLocationBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> Location {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<Location> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("Location", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn latitude(&mut self, latitude: f32) -> &mut Self {
         self.inner.latitude = latitude;
+        self.missing.retain(|f| *f != "latitude");
         self
     }
 
     pub fn longitude(&mut self, longitude: f32) -> &mut Self {
         self.inner.longitude = longitude;
+        self.missing.retain(|f| *f != "longitude");
         self
     }
 }
@@ -83,3 +125,14 @@ impl AsRef<Location> for RTDLocationBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(Location::builder().latitude(1.5).longitude(1.5).build());
+    }
+}
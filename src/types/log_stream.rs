@@ -1,49 +1,44 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Describes a stream to which TDLib internal log is written
 pub trait TDLogStream: Debug + RObject {}
 
 /// Describes a stream to which TDLib internal log is written
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum LogStream {
-    #[doc(hidden)]
-    _Default(()),
-    /// Returns information about currently used log stream for internal logging of TDLib. This is an offline method. Can be called before authorization. Can be called synchronously
-    GetLogStream(GetLogStream),
     /// The log is written to stderr or an OS specific log
+    #[serde(rename = "logStreamDefault")]
     Default(LogStreamDefault),
     /// The log is written nowhere
+    #[serde(rename = "logStreamEmpty")]
     Empty(LogStreamEmpty),
     /// The log is written to a file
+    #[serde(rename = "logStreamFile")]
     File(LogStreamFile),
 }
 
+/// Default value is default of [LogStreamDefault], builders still require the field to be set
 impl Default for LogStream {
     fn default() -> Self {
-        LogStream::_Default(())
+        LogStream::Default(LogStreamDefault::default())
     }
 }
 
-impl<'de> Deserialize<'de> for LogStream {
-    fn deserialize<D>(deserializer: D) -> Result<LogStream, D::Error>
+impl Serialize for LogStream {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          LogStream,
-          (getLogStream, GetLogStream);
-          (logStreamDefault, Default);
-          (logStreamEmpty, Empty);
-          (logStreamFile, File);
-
-        )(deserializer)
+        match self {
+            LogStream::Default(t) => t.serialize(serializer),
+            LogStream::Empty(t) => t.serialize(serializer),
+            LogStream::File(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -51,23 +46,17 @@ impl RObject for LogStream {
     #[doc(hidden)]
     fn td_name(&self) -> &'static str {
         match self {
-            LogStream::GetLogStream(t) => t.td_name(),
             LogStream::Default(t) => t.td_name(),
             LogStream::Empty(t) => t.td_name(),
             LogStream::File(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
     fn extra(&self) -> Option<String> {
         match self {
-            LogStream::GetLogStream(t) => t.extra(),
             LogStream::Default(t) => t.extra(),
             LogStream::Empty(t) => t.extra(),
             LogStream::File(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -79,10 +68,6 @@ impl LogStream {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, LogStream::_Default(_))
-    }
 }
 
 impl AsRef<LogStream> for LogStream {
@@ -93,12 +78,13 @@ impl AsRef<LogStream> for LogStream {
 
 /// The log is written to stderr or an OS specific log
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "logStreamDefault")]
 pub struct LogStreamDefault {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -116,6 +102,18 @@ impl RObject for LogStreamDefault {
     }
 }
 
+impl PartialEq for LogStreamDefault {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for LogStreamDefault {}
+
+impl std::hash::Hash for LogStreamDefault {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDLogStream for LogStreamDefault {}
 
 impl LogStreamDefault {
@@ -123,22 +121,49 @@ impl LogStreamDefault {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDLogStreamDefaultBuilder {
-        let mut inner = LogStreamDefault::default();
-        inner.td_name = "logStreamDefault".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDLogStreamDefaultBuilder { inner }
+        RTDLogStreamDefaultBuilder {
+            inner: LogStreamDefault::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDLogStreamDefaultBuilder {
+        RTDLogStreamDefaultBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDLogStreamDefaultBuilder {
+        RTDLogStreamDefaultBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDLogStreamDefaultBuilder {
     inner: LogStreamDefault,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDLogStreamDefaultBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> LogStreamDefault {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<LogStreamDefault> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "LogStreamDefault",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<LogStreamDefault> for LogStreamDefault {
@@ -155,12 +180,13 @@ impl AsRef<LogStreamDefault> for RTDLogStreamDefaultBuilder {
 
 /// The log is written nowhere
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "logStreamEmpty")]
 pub struct LogStreamEmpty {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -178,6 +204,18 @@ impl RObject for LogStreamEmpty {
     }
 }
 
+impl PartialEq for LogStreamEmpty {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for LogStreamEmpty {}
+
+impl std::hash::Hash for LogStreamEmpty {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDLogStream for LogStreamEmpty {}
 
 impl LogStreamEmpty {
@@ -185,22 +223,49 @@ impl LogStreamEmpty {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDLogStreamEmptyBuilder {
-        let mut inner = LogStreamEmpty::default();
-        inner.td_name = "logStreamEmpty".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDLogStreamEmptyBuilder { inner }
+        RTDLogStreamEmptyBuilder {
+            inner: LogStreamEmpty::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDLogStreamEmptyBuilder {
+        RTDLogStreamEmptyBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDLogStreamEmptyBuilder {
+        RTDLogStreamEmptyBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDLogStreamEmptyBuilder {
     inner: LogStreamEmpty,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDLogStreamEmptyBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> LogStreamEmpty {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<LogStreamEmpty> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "LogStreamEmpty",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<LogStreamEmpty> for LogStreamEmpty {
@@ -217,12 +282,13 @@ impl AsRef<LogStreamEmpty> for RTDLogStreamEmptyBuilder {
 
 /// The log is written to a file
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "logStreamFile")]
 pub struct LogStreamFile {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Path to the file to where the internal TDLib log will be written
     path: String,
@@ -244,6 +310,21 @@ impl RObject for LogStreamFile {
     }
 }
 
+impl PartialEq for LogStreamFile {
+    fn eq(&self, other: &Self) -> bool {
+        self.path == other.path && self.max_file_size == other.max_file_size
+    }
+}
+
+impl Eq for LogStreamFile {}
+
+impl std::hash::Hash for LogStreamFile {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.path, state);
+        std::hash::Hash::hash(&self.max_file_size, state);
+    }
+}
+
 impl TDLogStream for LogStreamFile {}
 
 impl LogStreamFile {
@@ -251,10 +332,24 @@ impl LogStreamFile {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDLogStreamFileBuilder {
-        let mut inner = LogStreamFile::default();
-        inner.td_name = "logStreamFile".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDLogStreamFileBuilder { inner }
+        RTDLogStreamFileBuilder {
+            inner: LogStreamFile::default(),
+            missing: vec!["path", "max_file_size"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDLogStreamFileBuilder {
+        RTDLogStreamFileBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDLogStreamFileBuilder {
+        RTDLogStreamFileBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn path(&self) -> &String {
@@ -264,25 +359,54 @@ impl LogStreamFile {
     pub fn max_file_size(&self) -> i64 {
         self.max_file_size
     }
+
+    pub fn into_path(self) -> String {
+        self.path
+    }
+
+    pub fn with_path<T: AsRef<str>>(mut self, path: T) -> Self {
+        self.path = path.as_ref().to_string();
+        self
+    }
+
+    pub fn with_max_file_size(mut self, max_file_size: i64) -> Self {
+        self.max_file_size = max_file_size;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDLogStreamFileBuilder {
     inner: LogStreamFile,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDLogStreamFileBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> LogStreamFile {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<LogStreamFile> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "LogStreamFile",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn path<T: AsRef<str>>(&mut self, path: T) -> &mut Self {
         self.inner.path = path.as_ref().to_string();
+        self.missing.retain(|f| *f != "path");
         self
     }
 
     pub fn max_file_size(&mut self, max_file_size: i64) -> &mut Self {
         self.inner.max_file_size = max_file_size;
+        self.missing.retain(|f| *f != "max_file_size");
         self
     }
 }
@@ -298,3 +422,21 @@ impl AsRef<LogStreamFile> for RTDLogStreamFileBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(LogStream::Default(LogStreamDefault::builder().build()));
+        assert_round_trip(LogStream::Empty(LogStreamEmpty::builder().build()));
+        assert_round_trip(LogStream::File(
+            LogStreamFile::builder()
+                .path("text")
+                .max_file_size(9007199254740991i64)
+                .build(),
+        ));
+    }
+}
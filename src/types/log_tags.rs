@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains a list of available TDLib internal log tags
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "logTags")]
 pub struct LogTags {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// List of log tags
     tags: Vec<String>,
@@ -29,34 +29,82 @@ impl RObject for LogTags {
     }
 }
 
+impl PartialEq for LogTags {
+    fn eq(&self, other: &Self) -> bool {
+        self.tags == other.tags
+    }
+}
+
+impl Eq for LogTags {}
+
+impl std::hash::Hash for LogTags {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.tags, state);
+    }
+}
+
 impl LogTags {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDLogTagsBuilder {
-        let mut inner = LogTags::default();
-        inner.td_name = "logTags".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDLogTagsBuilder { inner }
+        RTDLogTagsBuilder {
+            inner: LogTags::default(),
+            missing: vec!["tags"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDLogTagsBuilder {
+        RTDLogTagsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDLogTagsBuilder {
+        RTDLogTagsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn tags(&self) -> &Vec<String> {
         &self.tags
     }
+
+    pub fn into_tags(self) -> Vec<String> {
+        self.tags
+    }
+
+    pub fn with_tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = tags;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDLogTagsBuilder {
     inner: LogTags,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDLogTagsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> LogTags {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<LogTags> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("LogTags", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn tags(&mut self, tags: Vec<String>) -> &mut Self {
         self.inner.tags = tags;
+        self.missing.retain(|f| *f != "tags");
         self
     }
 }
@@ -72,3 +120,14 @@ impl AsRef<LogTags> for RTDLogTagsBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(LogTags::builder().tags(vec!["text".to_string()]).build());
+    }
+}
@@ -1,18 +1,18 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains a TDLib internal log verbosity level
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "logVerbosityLevel")]
 pub struct LogVerbosityLevel {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Log verbosity level
-    verbosity_level: i64,
+    verbosity_level: i32,
 }
 
 impl RObject for LogVerbosityLevel {
@@ -29,34 +29,81 @@ impl RObject for LogVerbosityLevel {
     }
 }
 
+impl PartialEq for LogVerbosityLevel {
+    fn eq(&self, other: &Self) -> bool {
+        self.verbosity_level == other.verbosity_level
+    }
+}
+
+impl Eq for LogVerbosityLevel {}
+
+impl std::hash::Hash for LogVerbosityLevel {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.verbosity_level, state);
+    }
+}
+
 impl LogVerbosityLevel {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDLogVerbosityLevelBuilder {
-        let mut inner = LogVerbosityLevel::default();
-        inner.td_name = "logVerbosityLevel".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDLogVerbosityLevelBuilder { inner }
+        RTDLogVerbosityLevelBuilder {
+            inner: LogVerbosityLevel::default(),
+            missing: vec!["verbosity_level"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDLogVerbosityLevelBuilder {
+        RTDLogVerbosityLevelBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDLogVerbosityLevelBuilder {
+        RTDLogVerbosityLevelBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn verbosity_level(&self) -> i64 {
+    pub fn verbosity_level(&self) -> i32 {
         self.verbosity_level
     }
+
+    pub fn with_verbosity_level(mut self, verbosity_level: i32) -> Self {
+        self.verbosity_level = verbosity_level;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDLogVerbosityLevelBuilder {
     inner: LogVerbosityLevel,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDLogVerbosityLevelBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> LogVerbosityLevel {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<LogVerbosityLevel> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "LogVerbosityLevel",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn verbosity_level(&mut self, verbosity_level: i64) -> &mut Self {
+    pub fn verbosity_level(&mut self, verbosity_level: i32) -> &mut Self {
         self.inner.verbosity_level = verbosity_level;
+        self.missing.retain(|f| *f != "verbosity_level");
         self
     }
 }
@@ -72,3 +119,18 @@ impl AsRef<LogVerbosityLevel> for RTDLogVerbosityLevelBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            LogVerbosityLevel::builder()
+                .verbosity_level(i32::MAX)
+                .build(),
+        );
+    }
+}
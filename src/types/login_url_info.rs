@@ -1,46 +1,40 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Contains information about an inline button of type inlineKeyboardButtonTypeLoginUrl
 pub trait TDLoginUrlInfo: Debug + RObject {}
 
 /// Contains information about an inline button of type inlineKeyboardButtonTypeLoginUrl
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum LoginUrlInfo {
-    #[doc(hidden)]
-    _Default(()),
-    /// Returns information about a button of type inlineKeyboardButtonTypeLoginUrl. The method needs to be called when the user presses the button
-    GetLoginUrlInfo(GetLoginUrlInfo),
     /// An HTTP url needs to be open
+    #[serde(rename = "loginUrlInfoOpen")]
     Open(LoginUrlInfoOpen),
     /// An authorization confirmation dialog needs to be shown to the user
+    #[serde(rename = "loginUrlInfoRequestConfirmation")]
     RequestConfirmation(LoginUrlInfoRequestConfirmation),
 }
 
+/// Default value is default of [LoginUrlInfoOpen], builders still require the field to be set
 impl Default for LoginUrlInfo {
     fn default() -> Self {
-        LoginUrlInfo::_Default(())
+        LoginUrlInfo::Open(LoginUrlInfoOpen::default())
     }
 }
 
-impl<'de> Deserialize<'de> for LoginUrlInfo {
-    fn deserialize<D>(deserializer: D) -> Result<LoginUrlInfo, D::Error>
+impl Serialize for LoginUrlInfo {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          LoginUrlInfo,
-          (getLoginUrlInfo, GetLoginUrlInfo);
-          (loginUrlInfoOpen, Open);
-          (loginUrlInfoRequestConfirmation, RequestConfirmation);
-
-        )(deserializer)
+        match self {
+            LoginUrlInfo::Open(t) => t.serialize(serializer),
+            LoginUrlInfo::RequestConfirmation(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -48,21 +42,15 @@ impl RObject for LoginUrlInfo {
     #[doc(hidden)]
     fn td_name(&self) -> &'static str {
         match self {
-            LoginUrlInfo::GetLoginUrlInfo(t) => t.td_name(),
             LoginUrlInfo::Open(t) => t.td_name(),
             LoginUrlInfo::RequestConfirmation(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
     fn extra(&self) -> Option<String> {
         match self {
-            LoginUrlInfo::GetLoginUrlInfo(t) => t.extra(),
             LoginUrlInfo::Open(t) => t.extra(),
             LoginUrlInfo::RequestConfirmation(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -74,10 +62,6 @@ impl LoginUrlInfo {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, LoginUrlInfo::_Default(_))
-    }
 }
 
 impl AsRef<LoginUrlInfo> for LoginUrlInfo {
@@ -88,12 +72,13 @@ impl AsRef<LoginUrlInfo> for LoginUrlInfo {
 
 /// An HTTP url needs to be open
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "loginUrlInfoOpen")]
 pub struct LoginUrlInfoOpen {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The URL to open
     url: String,
@@ -115,6 +100,21 @@ impl RObject for LoginUrlInfoOpen {
     }
 }
 
+impl PartialEq for LoginUrlInfoOpen {
+    fn eq(&self, other: &Self) -> bool {
+        self.url == other.url && self.skip_confirm == other.skip_confirm
+    }
+}
+
+impl Eq for LoginUrlInfoOpen {}
+
+impl std::hash::Hash for LoginUrlInfoOpen {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.url, state);
+        std::hash::Hash::hash(&self.skip_confirm, state);
+    }
+}
+
 impl TDLoginUrlInfo for LoginUrlInfoOpen {}
 
 impl LoginUrlInfoOpen {
@@ -122,10 +122,24 @@ impl LoginUrlInfoOpen {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDLoginUrlInfoOpenBuilder {
-        let mut inner = LoginUrlInfoOpen::default();
-        inner.td_name = "loginUrlInfoOpen".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDLoginUrlInfoOpenBuilder { inner }
+        RTDLoginUrlInfoOpenBuilder {
+            inner: LoginUrlInfoOpen::default(),
+            missing: vec!["url"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDLoginUrlInfoOpenBuilder {
+        RTDLoginUrlInfoOpenBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDLoginUrlInfoOpenBuilder {
+        RTDLoginUrlInfoOpenBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn url(&self) -> &String {
@@ -135,20 +149,48 @@ impl LoginUrlInfoOpen {
     pub fn skip_confirm(&self) -> bool {
         self.skip_confirm
     }
+
+    pub fn into_url(self) -> String {
+        self.url
+    }
+
+    pub fn with_url<T: AsRef<str>>(mut self, url: T) -> Self {
+        self.url = url.as_ref().to_string();
+        self
+    }
+
+    pub fn with_skip_confirm(mut self, skip_confirm: bool) -> Self {
+        self.skip_confirm = skip_confirm;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDLoginUrlInfoOpenBuilder {
     inner: LoginUrlInfoOpen,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDLoginUrlInfoOpenBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> LoginUrlInfoOpen {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<LoginUrlInfoOpen> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "LoginUrlInfoOpen",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
         self.inner.url = url.as_ref().to_string();
+        self.missing.retain(|f| *f != "url");
         self
     }
 
@@ -172,19 +214,20 @@ impl AsRef<LoginUrlInfoOpen> for RTDLoginUrlInfoOpenBuilder {
 
 /// An authorization confirmation dialog needs to be shown to the user
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "loginUrlInfoRequestConfirmation")]
 pub struct LoginUrlInfoRequestConfirmation {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// An HTTP URL to be opened
     url: String,
     /// A domain of the URL
     domain: String,
     /// User identifier of a bot linked with the website
-    bot_user_id: i64,
+    bot_user_id: UserId,
     /// True, if the user needs to be requested to give the permission to the bot to send them messages
     request_write_access: bool,
 }
@@ -203,6 +246,26 @@ impl RObject for LoginUrlInfoRequestConfirmation {
     }
 }
 
+impl PartialEq for LoginUrlInfoRequestConfirmation {
+    fn eq(&self, other: &Self) -> bool {
+        self.url == other.url
+            && self.domain == other.domain
+            && self.bot_user_id == other.bot_user_id
+            && self.request_write_access == other.request_write_access
+    }
+}
+
+impl Eq for LoginUrlInfoRequestConfirmation {}
+
+impl std::hash::Hash for LoginUrlInfoRequestConfirmation {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.url, state);
+        std::hash::Hash::hash(&self.domain, state);
+        std::hash::Hash::hash(&self.bot_user_id, state);
+        std::hash::Hash::hash(&self.request_write_access, state);
+    }
+}
+
 impl TDLoginUrlInfo for LoginUrlInfoRequestConfirmation {}
 
 impl LoginUrlInfoRequestConfirmation {
@@ -210,10 +273,24 @@ impl LoginUrlInfoRequestConfirmation {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDLoginUrlInfoRequestConfirmationBuilder {
-        let mut inner = LoginUrlInfoRequestConfirmation::default();
-        inner.td_name = "loginUrlInfoRequestConfirmation".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDLoginUrlInfoRequestConfirmationBuilder { inner }
+        RTDLoginUrlInfoRequestConfirmationBuilder {
+            inner: LoginUrlInfoRequestConfirmation::default(),
+            missing: vec!["url", "domain", "bot_user_id"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDLoginUrlInfoRequestConfirmationBuilder {
+        RTDLoginUrlInfoRequestConfirmationBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDLoginUrlInfoRequestConfirmationBuilder {
+        RTDLoginUrlInfoRequestConfirmationBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn url(&self) -> &String {
@@ -224,37 +301,81 @@ impl LoginUrlInfoRequestConfirmation {
         &self.domain
     }
 
-    pub fn bot_user_id(&self) -> i64 {
+    pub fn bot_user_id(&self) -> UserId {
         self.bot_user_id
     }
 
     pub fn request_write_access(&self) -> bool {
         self.request_write_access
     }
+
+    pub fn into_url(self) -> String {
+        self.url
+    }
+
+    pub fn into_domain(self) -> String {
+        self.domain
+    }
+
+    pub fn with_url<T: AsRef<str>>(mut self, url: T) -> Self {
+        self.url = url.as_ref().to_string();
+        self
+    }
+
+    pub fn with_domain<T: AsRef<str>>(mut self, domain: T) -> Self {
+        self.domain = domain.as_ref().to_string();
+        self
+    }
+
+    pub fn with_bot_user_id<T: Into<UserId>>(mut self, bot_user_id: T) -> Self {
+        self.bot_user_id = bot_user_id.into();
+        self
+    }
+
+    pub fn with_request_write_access(mut self, request_write_access: bool) -> Self {
+        self.request_write_access = request_write_access;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDLoginUrlInfoRequestConfirmationBuilder {
     inner: LoginUrlInfoRequestConfirmation,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDLoginUrlInfoRequestConfirmationBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> LoginUrlInfoRequestConfirmation {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<LoginUrlInfoRequestConfirmation> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "LoginUrlInfoRequestConfirmation",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
         self.inner.url = url.as_ref().to_string();
+        self.missing.retain(|f| *f != "url");
         self
     }
 
     pub fn domain<T: AsRef<str>>(&mut self, domain: T) -> &mut Self {
         self.inner.domain = domain.as_ref().to_string();
+        self.missing.retain(|f| *f != "domain");
         self
     }
 
-    pub fn bot_user_id(&mut self, bot_user_id: i64) -> &mut Self {
-        self.inner.bot_user_id = bot_user_id;
+    pub fn bot_user_id<T: Into<UserId>>(&mut self, bot_user_id: T) -> &mut Self {
+        self.inner.bot_user_id = bot_user_id.into();
+        self.missing.retain(|f| *f != "bot_user_id");
         self
     }
 
@@ -275,3 +396,27 @@ impl AsRef<LoginUrlInfoRequestConfirmation> for RTDLoginUrlInfoRequestConfirmati
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(LoginUrlInfo::Open(
+            LoginUrlInfoOpen::builder()
+                .url("text")
+                .skip_confirm(true)
+                .build(),
+        ));
+        assert_round_trip(LoginUrlInfo::RequestConfirmation(
+            LoginUrlInfoRequestConfirmation::builder()
+                .url("text")
+                .domain("text")
+                .bot_user_id(i32::MAX)
+                .request_write_access(true)
+                .build(),
+        ));
+    }
+}
@@ -1,49 +1,48 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Part of the face, relative to which a mask should be placed
 pub trait TDMaskPoint: Debug + RObject {}
 
 /// Part of the face, relative to which a mask should be placed
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum MaskPoint {
-    #[doc(hidden)]
-    _Default(()),
     /// A mask should be placed relatively to the chin
+    #[serde(rename = "maskPointChin")]
     Chin(MaskPointChin),
     /// A mask should be placed relatively to the eyes
+    #[serde(rename = "maskPointEyes")]
     Eyes(MaskPointEyes),
     /// A mask should be placed relatively to the forehead
+    #[serde(rename = "maskPointForehead")]
     Forehead(MaskPointForehead),
     /// A mask should be placed relatively to the mouth
+    #[serde(rename = "maskPointMouth")]
     Mouth(MaskPointMouth),
 }
 
+/// Default value is default of [MaskPointChin], builders still require the field to be set
 impl Default for MaskPoint {
     fn default() -> Self {
-        MaskPoint::_Default(())
+        MaskPoint::Chin(MaskPointChin::default())
     }
 }
 
-impl<'de> Deserialize<'de> for MaskPoint {
-    fn deserialize<D>(deserializer: D) -> Result<MaskPoint, D::Error>
+impl Serialize for MaskPoint {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          MaskPoint,
-          (maskPointChin, Chin);
-          (maskPointEyes, Eyes);
-          (maskPointForehead, Forehead);
-          (maskPointMouth, Mouth);
-
-        )(deserializer)
+        match self {
+            MaskPoint::Chin(t) => t.serialize(serializer),
+            MaskPoint::Eyes(t) => t.serialize(serializer),
+            MaskPoint::Forehead(t) => t.serialize(serializer),
+            MaskPoint::Mouth(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -55,8 +54,6 @@ impl RObject for MaskPoint {
             MaskPoint::Eyes(t) => t.td_name(),
             MaskPoint::Forehead(t) => t.td_name(),
             MaskPoint::Mouth(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -66,8 +63,6 @@ impl RObject for MaskPoint {
             MaskPoint::Eyes(t) => t.extra(),
             MaskPoint::Forehead(t) => t.extra(),
             MaskPoint::Mouth(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -79,10 +74,6 @@ impl MaskPoint {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, MaskPoint::_Default(_))
-    }
 }
 
 impl AsRef<MaskPoint> for MaskPoint {
@@ -93,12 +84,13 @@ impl AsRef<MaskPoint> for MaskPoint {
 
 /// A mask should be placed relatively to the chin
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "maskPointChin")]
 pub struct MaskPointChin {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -116,6 +108,18 @@ impl RObject for MaskPointChin {
     }
 }
 
+impl PartialEq for MaskPointChin {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for MaskPointChin {}
+
+impl std::hash::Hash for MaskPointChin {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDMaskPoint for MaskPointChin {}
 
 impl MaskPointChin {
@@ -123,22 +127,49 @@ impl MaskPointChin {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMaskPointChinBuilder {
-        let mut inner = MaskPointChin::default();
-        inner.td_name = "maskPointChin".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMaskPointChinBuilder { inner }
+        RTDMaskPointChinBuilder {
+            inner: MaskPointChin::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMaskPointChinBuilder {
+        RTDMaskPointChinBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMaskPointChinBuilder {
+        RTDMaskPointChinBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDMaskPointChinBuilder {
     inner: MaskPointChin,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMaskPointChinBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MaskPointChin {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MaskPointChin> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "MaskPointChin",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<MaskPointChin> for MaskPointChin {
@@ -155,12 +186,13 @@ impl AsRef<MaskPointChin> for RTDMaskPointChinBuilder {
 
 /// A mask should be placed relatively to the eyes
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "maskPointEyes")]
 pub struct MaskPointEyes {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -178,6 +210,18 @@ impl RObject for MaskPointEyes {
     }
 }
 
+impl PartialEq for MaskPointEyes {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for MaskPointEyes {}
+
+impl std::hash::Hash for MaskPointEyes {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDMaskPoint for MaskPointEyes {}
 
 impl MaskPointEyes {
@@ -185,22 +229,49 @@ impl MaskPointEyes {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMaskPointEyesBuilder {
-        let mut inner = MaskPointEyes::default();
-        inner.td_name = "maskPointEyes".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMaskPointEyesBuilder { inner }
+        RTDMaskPointEyesBuilder {
+            inner: MaskPointEyes::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMaskPointEyesBuilder {
+        RTDMaskPointEyesBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMaskPointEyesBuilder {
+        RTDMaskPointEyesBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDMaskPointEyesBuilder {
     inner: MaskPointEyes,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMaskPointEyesBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MaskPointEyes {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MaskPointEyes> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "MaskPointEyes",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<MaskPointEyes> for MaskPointEyes {
@@ -217,12 +288,13 @@ impl AsRef<MaskPointEyes> for RTDMaskPointEyesBuilder {
 
 /// A mask should be placed relatively to the forehead
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "maskPointForehead")]
 pub struct MaskPointForehead {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -240,6 +312,18 @@ impl RObject for MaskPointForehead {
     }
 }
 
+impl PartialEq for MaskPointForehead {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for MaskPointForehead {}
+
+impl std::hash::Hash for MaskPointForehead {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDMaskPoint for MaskPointForehead {}
 
 impl MaskPointForehead {
@@ -247,22 +331,49 @@ impl MaskPointForehead {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMaskPointForeheadBuilder {
-        let mut inner = MaskPointForehead::default();
-        inner.td_name = "maskPointForehead".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMaskPointForeheadBuilder { inner }
+        RTDMaskPointForeheadBuilder {
+            inner: MaskPointForehead::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMaskPointForeheadBuilder {
+        RTDMaskPointForeheadBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMaskPointForeheadBuilder {
+        RTDMaskPointForeheadBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDMaskPointForeheadBuilder {
     inner: MaskPointForehead,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMaskPointForeheadBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MaskPointForehead {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MaskPointForehead> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "MaskPointForehead",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<MaskPointForehead> for MaskPointForehead {
@@ -279,12 +390,13 @@ impl AsRef<MaskPointForehead> for RTDMaskPointForeheadBuilder {
 
 /// A mask should be placed relatively to the mouth
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "maskPointMouth")]
 pub struct MaskPointMouth {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -302,6 +414,18 @@ impl RObject for MaskPointMouth {
     }
 }
 
+impl PartialEq for MaskPointMouth {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for MaskPointMouth {}
+
+impl std::hash::Hash for MaskPointMouth {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDMaskPoint for MaskPointMouth {}
 
 impl MaskPointMouth {
@@ -309,22 +433,49 @@ impl MaskPointMouth {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMaskPointMouthBuilder {
-        let mut inner = MaskPointMouth::default();
-        inner.td_name = "maskPointMouth".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMaskPointMouthBuilder { inner }
+        RTDMaskPointMouthBuilder {
+            inner: MaskPointMouth::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMaskPointMouthBuilder {
+        RTDMaskPointMouthBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMaskPointMouthBuilder {
+        RTDMaskPointMouthBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDMaskPointMouthBuilder {
     inner: MaskPointMouth,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMaskPointMouthBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MaskPointMouth {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MaskPointMouth> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "MaskPointMouth",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<MaskPointMouth> for MaskPointMouth {
@@ -338,3 +489,17 @@ impl AsRef<MaskPointMouth> for RTDMaskPointMouthBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(MaskPoint::Chin(MaskPointChin::builder().build()));
+        assert_round_trip(MaskPoint::Eyes(MaskPointEyes::builder().build()));
+        assert_round_trip(MaskPoint::Forehead(MaskPointForehead::builder().build()));
+        assert_round_trip(MaskPoint::Mouth(MaskPointMouth::builder().build()));
+    }
+}
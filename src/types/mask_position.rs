@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Position on a photo where a mask should be placed
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "maskPosition")]
 pub struct MaskPosition {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Part of the face, relative to which the mask should be placed
     point: MaskPoint,
@@ -35,15 +35,38 @@ impl RObject for MaskPosition {
     }
 }
 
+impl PartialEq for MaskPosition {
+    fn eq(&self, other: &Self) -> bool {
+        self.point == other.point
+            && self.x_shift == other.x_shift
+            && self.y_shift == other.y_shift
+            && self.scale == other.scale
+    }
+}
+
 impl MaskPosition {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMaskPositionBuilder {
-        let mut inner = MaskPosition::default();
-        inner.td_name = "maskPosition".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMaskPositionBuilder { inner }
+        RTDMaskPositionBuilder {
+            inner: MaskPosition::default(),
+            missing: vec!["point", "x_shift", "y_shift", "scale"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMaskPositionBuilder {
+        RTDMaskPositionBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMaskPositionBuilder {
+        RTDMaskPositionBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn point(&self) -> &MaskPoint {
@@ -61,35 +84,76 @@ impl MaskPosition {
     pub fn scale(&self) -> f32 {
         self.scale
     }
+
+    pub fn into_point(self) -> MaskPoint {
+        self.point
+    }
+
+    pub fn with_point<T: AsRef<MaskPoint>>(mut self, point: T) -> Self {
+        self.point = point.as_ref().clone();
+        self
+    }
+
+    pub fn with_x_shift(mut self, x_shift: f32) -> Self {
+        self.x_shift = x_shift;
+        self
+    }
+
+    pub fn with_y_shift(mut self, y_shift: f32) -> Self {
+        self.y_shift = y_shift;
+        self
+    }
+
+    pub fn with_scale(mut self, scale: f32) -> Self {
+        self.scale = scale;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDMaskPositionBuilder {
     inner: MaskPosition,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMaskPositionBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MaskPosition {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MaskPosition> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "MaskPosition",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn point<T: AsRef<MaskPoint>>(&mut self, point: T) -> &mut Self {
         self.inner.point = point.as_ref().clone();
+        self.missing.retain(|f| *f != "point");
         self
     }
 
     pub fn x_shift(&mut self, x_shift: f32) -> &mut Self {
         self.inner.x_shift = x_shift;
+        self.missing.retain(|f| *f != "x_shift");
         self
     }
 
     pub fn y_shift(&mut self, y_shift: f32) -> &mut Self {
         self.inner.y_shift = y_shift;
+        self.missing.retain(|f| *f != "y_shift");
         self
     }
 
     pub fn scale(&mut self, scale: f32) -> &mut Self {
         self.inner.scale = scale;
+        self.missing.retain(|f| *f != "scale");
         self
     }
 }
@@ -105,3 +169,21 @@ impl AsRef<MaskPosition> for RTDMaskPositionBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            MaskPosition::builder()
+                .point(MaskPoint::Chin(MaskPointChin::builder().build()))
+                .x_shift(1.5)
+                .y_shift(1.5)
+                .scale(1.5)
+                .build(),
+        );
+    }
+}
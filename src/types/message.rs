@@ -1,22 +1,22 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Describes a message
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "message")]
 pub struct Message {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Message identifier, unique for the chat to which the message belongs
-    id: i64,
+    id: MessageId,
     /// Identifier of the user who sent the message; 0 if unknown. Currently, it is unknown for channel posts and for channel posts automatically forwarded to discussion group
-    sender_user_id: i64,
+    sender_user_id: UserId,
     /// Chat identifier
-    chat_id: i64,
+    chat_id: ChatId,
     /// Information about the sending state of the message; may be null
     sending_state: Option<MessageSendingState>,
     /// Information about the scheduling state of the message; may be null
@@ -36,26 +36,26 @@ pub struct Message {
     /// True, if the message contains an unread mention for the current user
     contains_unread_mention: bool,
     /// Point in time (Unix timestamp) when the message was sent
-    date: i64,
+    date: i32,
     /// Point in time (Unix timestamp) when the message was last edited
-    edit_date: i64,
+    edit_date: i32,
     /// Information about the initial message sender; may be null
     forward_info: Option<MessageForwardInfo>,
     /// If non-zero, the identifier of the message this message is replying to; can be the identifier of a deleted message
-    reply_to_message_id: i64,
+    reply_to_message_id: MessageId,
     /// For self-destructing messages, the message's TTL (Time To Live), in seconds; 0 if none. TDLib will send updateDeleteMessages or updateMessageContent once the TTL expires
-    ttl: i64,
+    ttl: i32,
     /// Time left before the message expires, in seconds
     ttl_expires_in: f32,
     /// If non-zero, the user identifier of the bot through which this message was sent
-    via_bot_user_id: i64,
+    via_bot_user_id: UserId,
     /// For channel posts, optional author signature
     author_signature: String,
     /// Number of times this message was viewed
-    views: i64,
+    views: i32,
     /// Unique identifier of an album this message belongs to. Only photos and videos can be grouped together in albums
-    #[serde(deserialize_with = "super::_common::number_from_string")]
-    media_album_id: isize,
+    #[serde(with = "super::_common::int64")]
+    media_album_id: i64,
     /// If non-empty, contains a human-readable description of the reason why access to this message must be restricted
     restriction_reason: String,
     /// Content of the message
@@ -78,26 +78,79 @@ impl RObject for Message {
     }
 }
 
+impl PartialEq for Message {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.sender_user_id == other.sender_user_id
+            && self.chat_id == other.chat_id
+            && self.sending_state == other.sending_state
+            && self.scheduling_state == other.scheduling_state
+            && self.is_outgoing == other.is_outgoing
+            && self.can_be_edited == other.can_be_edited
+            && self.can_be_forwarded == other.can_be_forwarded
+            && self.can_be_deleted_only_for_self == other.can_be_deleted_only_for_self
+            && self.can_be_deleted_for_all_users == other.can_be_deleted_for_all_users
+            && self.is_channel_post == other.is_channel_post
+            && self.contains_unread_mention == other.contains_unread_mention
+            && self.date == other.date
+            && self.edit_date == other.edit_date
+            && self.forward_info == other.forward_info
+            && self.reply_to_message_id == other.reply_to_message_id
+            && self.ttl == other.ttl
+            && self.ttl_expires_in == other.ttl_expires_in
+            && self.via_bot_user_id == other.via_bot_user_id
+            && self.author_signature == other.author_signature
+            && self.views == other.views
+            && self.media_album_id == other.media_album_id
+            && self.restriction_reason == other.restriction_reason
+            && self.content == other.content
+            && self.reply_markup == other.reply_markup
+    }
+}
+
 impl Message {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessageBuilder {
-        let mut inner = Message::default();
-        inner.td_name = "message".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessageBuilder { inner }
-    }
-
-    pub fn id(&self) -> i64 {
+        RTDMessageBuilder {
+            inner: Message::default(),
+            missing: vec![
+                "id",
+                "chat_id",
+                "date",
+                "edit_date",
+                "ttl_expires_in",
+                "views",
+                "media_album_id",
+                "content",
+            ],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessageBuilder {
+        RTDMessageBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessageBuilder {
+        RTDMessageBuilder {
+            inner: self,
+            missing: vec![],
+        }
+    }
+
+    pub fn id(&self) -> MessageId {
         self.id
     }
 
-    pub fn sender_user_id(&self) -> i64 {
+    pub fn sender_user_id(&self) -> UserId {
         self.sender_user_id
     }
 
-    pub fn chat_id(&self) -> i64 {
+    pub fn chat_id(&self) -> ChatId {
         self.chat_id
     }
 
@@ -137,11 +190,11 @@ impl Message {
         self.contains_unread_mention
     }
 
-    pub fn date(&self) -> i64 {
+    pub fn date(&self) -> i32 {
         self.date
     }
 
-    pub fn edit_date(&self) -> i64 {
+    pub fn edit_date(&self) -> i32 {
         self.edit_date
     }
 
@@ -149,11 +202,11 @@ impl Message {
         &self.forward_info
     }
 
-    pub fn reply_to_message_id(&self) -> i64 {
+    pub fn reply_to_message_id(&self) -> MessageId {
         self.reply_to_message_id
     }
 
-    pub fn ttl(&self) -> i64 {
+    pub fn ttl(&self) -> i32 {
         self.ttl
     }
 
@@ -161,7 +214,7 @@ impl Message {
         self.ttl_expires_in
     }
 
-    pub fn via_bot_user_id(&self) -> i64 {
+    pub fn via_bot_user_id(&self) -> UserId {
         self.via_bot_user_id
     }
 
@@ -169,11 +222,11 @@ impl Message {
         &self.author_signature
     }
 
-    pub fn views(&self) -> i64 {
+    pub fn views(&self) -> i32 {
         self.views
     }
 
-    pub fn media_album_id(&self) -> isize {
+    pub fn media_album_id(&self) -> i64 {
         self.media_album_id
     }
 
@@ -188,30 +241,198 @@ impl Message {
     pub fn reply_markup(&self) -> &Option<ReplyMarkup> {
         &self.reply_markup
     }
+
+    pub fn into_sending_state(self) -> Option<MessageSendingState> {
+        self.sending_state
+    }
+
+    pub fn into_scheduling_state(self) -> Option<MessageSchedulingState> {
+        self.scheduling_state
+    }
+
+    pub fn into_forward_info(self) -> Option<MessageForwardInfo> {
+        self.forward_info
+    }
+
+    pub fn into_author_signature(self) -> String {
+        self.author_signature
+    }
+
+    pub fn into_restriction_reason(self) -> String {
+        self.restriction_reason
+    }
+
+    pub fn into_content(self) -> MessageContent {
+        self.content
+    }
+
+    pub fn into_reply_markup(self) -> Option<ReplyMarkup> {
+        self.reply_markup
+    }
+
+    pub fn with_id<T: Into<MessageId>>(mut self, id: T) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    pub fn with_sender_user_id<T: Into<UserId>>(mut self, sender_user_id: T) -> Self {
+        self.sender_user_id = sender_user_id.into();
+        self
+    }
+
+    pub fn with_chat_id<T: Into<ChatId>>(mut self, chat_id: T) -> Self {
+        self.chat_id = chat_id.into();
+        self
+    }
+
+    pub fn with_sending_state<T: AsRef<MessageSendingState>>(mut self, sending_state: T) -> Self {
+        self.sending_state = Some(sending_state.as_ref().clone());
+        self
+    }
+
+    pub fn with_scheduling_state<T: AsRef<MessageSchedulingState>>(
+        mut self,
+        scheduling_state: T,
+    ) -> Self {
+        self.scheduling_state = Some(scheduling_state.as_ref().clone());
+        self
+    }
+
+    pub fn with_is_outgoing(mut self, is_outgoing: bool) -> Self {
+        self.is_outgoing = is_outgoing;
+        self
+    }
+
+    pub fn with_can_be_edited(mut self, can_be_edited: bool) -> Self {
+        self.can_be_edited = can_be_edited;
+        self
+    }
+
+    pub fn with_can_be_forwarded(mut self, can_be_forwarded: bool) -> Self {
+        self.can_be_forwarded = can_be_forwarded;
+        self
+    }
+
+    pub fn with_can_be_deleted_only_for_self(mut self, can_be_deleted_only_for_self: bool) -> Self {
+        self.can_be_deleted_only_for_self = can_be_deleted_only_for_self;
+        self
+    }
+
+    pub fn with_can_be_deleted_for_all_users(mut self, can_be_deleted_for_all_users: bool) -> Self {
+        self.can_be_deleted_for_all_users = can_be_deleted_for_all_users;
+        self
+    }
+
+    pub fn with_is_channel_post(mut self, is_channel_post: bool) -> Self {
+        self.is_channel_post = is_channel_post;
+        self
+    }
+
+    pub fn with_contains_unread_mention(mut self, contains_unread_mention: bool) -> Self {
+        self.contains_unread_mention = contains_unread_mention;
+        self
+    }
+
+    pub fn with_date(mut self, date: i32) -> Self {
+        self.date = date;
+        self
+    }
+
+    pub fn with_edit_date(mut self, edit_date: i32) -> Self {
+        self.edit_date = edit_date;
+        self
+    }
+
+    pub fn with_forward_info<T: AsRef<MessageForwardInfo>>(mut self, forward_info: T) -> Self {
+        self.forward_info = Some(forward_info.as_ref().clone());
+        self
+    }
+
+    pub fn with_reply_to_message_id<T: Into<MessageId>>(mut self, reply_to_message_id: T) -> Self {
+        self.reply_to_message_id = reply_to_message_id.into();
+        self
+    }
+
+    pub fn with_ttl(mut self, ttl: i32) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    pub fn with_ttl_expires_in(mut self, ttl_expires_in: f32) -> Self {
+        self.ttl_expires_in = ttl_expires_in;
+        self
+    }
+
+    pub fn with_via_bot_user_id<T: Into<UserId>>(mut self, via_bot_user_id: T) -> Self {
+        self.via_bot_user_id = via_bot_user_id.into();
+        self
+    }
+
+    pub fn with_author_signature<T: AsRef<str>>(mut self, author_signature: T) -> Self {
+        self.author_signature = author_signature.as_ref().to_string();
+        self
+    }
+
+    pub fn with_views(mut self, views: i32) -> Self {
+        self.views = views;
+        self
+    }
+
+    pub fn with_media_album_id(mut self, media_album_id: i64) -> Self {
+        self.media_album_id = media_album_id;
+        self
+    }
+
+    pub fn with_restriction_reason<T: AsRef<str>>(mut self, restriction_reason: T) -> Self {
+        self.restriction_reason = restriction_reason.as_ref().to_string();
+        self
+    }
+
+    pub fn with_content<T: AsRef<MessageContent>>(mut self, content: T) -> Self {
+        self.content = content.as_ref().clone();
+        self
+    }
+
+    pub fn with_reply_markup<T: AsRef<ReplyMarkup>>(mut self, reply_markup: T) -> Self {
+        self.reply_markup = Some(reply_markup.as_ref().clone());
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDMessageBuilder {
     inner: Message,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessageBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> Message {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<Message> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("Message", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn id(&mut self, id: i64) -> &mut Self {
-        self.inner.id = id;
+    pub fn id<T: Into<MessageId>>(&mut self, id: T) -> &mut Self {
+        self.inner.id = id.into();
+        self.missing.retain(|f| *f != "id");
         self
     }
 
-    pub fn sender_user_id(&mut self, sender_user_id: i64) -> &mut Self {
-        self.inner.sender_user_id = sender_user_id;
+    pub fn sender_user_id<T: Into<UserId>>(&mut self, sender_user_id: T) -> &mut Self {
+        self.inner.sender_user_id = sender_user_id.into();
         self
     }
 
-    pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
-        self.inner.chat_id = chat_id;
+    pub fn chat_id<T: Into<ChatId>>(&mut self, chat_id: T) -> &mut Self {
+        self.inner.chat_id = chat_id.into();
+        self.missing.retain(|f| *f != "chat_id");
         self
     }
 
@@ -269,13 +490,15 @@ impl RTDMessageBuilder {
         self
     }
 
-    pub fn date(&mut self, date: i64) -> &mut Self {
+    pub fn date(&mut self, date: i32) -> &mut Self {
         self.inner.date = date;
+        self.missing.retain(|f| *f != "date");
         self
     }
 
-    pub fn edit_date(&mut self, edit_date: i64) -> &mut Self {
+    pub fn edit_date(&mut self, edit_date: i32) -> &mut Self {
         self.inner.edit_date = edit_date;
+        self.missing.retain(|f| *f != "edit_date");
         self
     }
 
@@ -284,23 +507,24 @@ impl RTDMessageBuilder {
         self
     }
 
-    pub fn reply_to_message_id(&mut self, reply_to_message_id: i64) -> &mut Self {
-        self.inner.reply_to_message_id = reply_to_message_id;
+    pub fn reply_to_message_id<T: Into<MessageId>>(&mut self, reply_to_message_id: T) -> &mut Self {
+        self.inner.reply_to_message_id = reply_to_message_id.into();
         self
     }
 
-    pub fn ttl(&mut self, ttl: i64) -> &mut Self {
+    pub fn ttl(&mut self, ttl: i32) -> &mut Self {
         self.inner.ttl = ttl;
         self
     }
 
     pub fn ttl_expires_in(&mut self, ttl_expires_in: f32) -> &mut Self {
         self.inner.ttl_expires_in = ttl_expires_in;
+        self.missing.retain(|f| *f != "ttl_expires_in");
         self
     }
 
-    pub fn via_bot_user_id(&mut self, via_bot_user_id: i64) -> &mut Self {
-        self.inner.via_bot_user_id = via_bot_user_id;
+    pub fn via_bot_user_id<T: Into<UserId>>(&mut self, via_bot_user_id: T) -> &mut Self {
+        self.inner.via_bot_user_id = via_bot_user_id.into();
         self
     }
 
@@ -309,13 +533,15 @@ impl RTDMessageBuilder {
         self
     }
 
-    pub fn views(&mut self, views: i64) -> &mut Self {
+    pub fn views(&mut self, views: i32) -> &mut Self {
         self.inner.views = views;
+        self.missing.retain(|f| *f != "views");
         self
     }
 
-    pub fn media_album_id(&mut self, media_album_id: isize) -> &mut Self {
+    pub fn media_album_id(&mut self, media_album_id: i64) -> &mut Self {
         self.inner.media_album_id = media_album_id;
+        self.missing.retain(|f| *f != "media_album_id");
         self
     }
 
@@ -326,6 +552,7 @@ impl RTDMessageBuilder {
 
     pub fn content<T: AsRef<MessageContent>>(&mut self, content: T) -> &mut Self {
         self.inner.content = content.as_ref().clone();
+        self.missing.retain(|f| *f != "content");
         self
     }
 
@@ -346,3 +573,43 @@ impl AsRef<Message> for RTDMessageBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            Message::builder()
+                .id(9007199254740991i64)
+                .sender_user_id(i32::MAX)
+                .chat_id(9007199254740991i64)
+                .is_outgoing(true)
+                .can_be_edited(true)
+                .can_be_forwarded(true)
+                .can_be_deleted_only_for_self(true)
+                .can_be_deleted_for_all_users(true)
+                .is_channel_post(true)
+                .contains_unread_mention(true)
+                .date(i32::MAX)
+                .edit_date(i32::MAX)
+                .reply_to_message_id(9007199254740991i64)
+                .ttl(i32::MAX)
+                .ttl_expires_in(1.5)
+                .via_bot_user_id(i32::MAX)
+                .author_signature("text")
+                .views(i32::MAX)
+                .media_album_id(i64::MAX)
+                .restriction_reason("text")
+                .content(MessageContent::MessageBasicGroupChatCreate(
+                    MessageBasicGroupChatCreate::builder()
+                        .title("text")
+                        .member_user_ids(vec![Default::default()])
+                        .build(),
+                ))
+                .build(),
+        );
+    }
+}
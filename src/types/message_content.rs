@@ -1,157 +1,192 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Contains the content of a message
 pub trait TDMessageContent: Debug + RObject {}
 
 /// Contains the content of a message
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "@type")]
 pub enum MessageContent {
-    #[doc(hidden)]
-    _Default(()),
     /// An animation message (GIF-style).
+    #[serde(rename = "messageAnimation")]
     MessageAnimation(MessageAnimation),
     /// An audio message
+    #[serde(rename = "messageAudio")]
     MessageAudio(MessageAudio),
     /// A newly created basic group
+    #[serde(rename = "messageBasicGroupChatCreate")]
     MessageBasicGroupChatCreate(MessageBasicGroupChatCreate),
     /// A message with information about an ended call
+    #[serde(rename = "messageCall")]
     MessageCall(MessageCall),
     /// New chat members were added
+    #[serde(rename = "messageChatAddMembers")]
     MessageChatAddMembers(MessageChatAddMembers),
     /// An updated chat photo
+    #[serde(rename = "messageChatChangePhoto")]
     MessageChatChangePhoto(MessageChatChangePhoto),
     /// An updated chat title
+    #[serde(rename = "messageChatChangeTitle")]
     MessageChatChangeTitle(MessageChatChangeTitle),
     /// A chat member was deleted
+    #[serde(rename = "messageChatDeleteMember")]
     MessageChatDeleteMember(MessageChatDeleteMember),
     /// A deleted chat photo
+    #[serde(rename = "messageChatDeletePhoto")]
     MessageChatDeletePhoto(MessageChatDeletePhoto),
     /// A new member joined the chat by invite link
+    #[serde(rename = "messageChatJoinByLink")]
     MessageChatJoinByLink(MessageChatJoinByLink),
     /// The TTL (Time To Live) setting messages in a secret chat has been changed
+    #[serde(rename = "messageChatSetTtl")]
     MessageChatSetTtl(MessageChatSetTtl),
     /// A supergroup has been created from a basic group
+    #[serde(rename = "messageChatUpgradeFrom")]
     MessageChatUpgradeFrom(MessageChatUpgradeFrom),
     /// A basic group was upgraded to a supergroup and was deactivated as the result
+    #[serde(rename = "messageChatUpgradeTo")]
     MessageChatUpgradeTo(MessageChatUpgradeTo),
     /// A message with a user contact
+    #[serde(rename = "messageContact")]
     MessageContact(MessageContact),
     /// A contact has registered with Telegram
+    #[serde(rename = "messageContactRegistered")]
     MessageContactRegistered(MessageContactRegistered),
     /// A non-standard action has happened in the chat
+    #[serde(rename = "messageCustomServiceAction")]
     MessageCustomServiceAction(MessageCustomServiceAction),
     /// A document message (general file)
+    #[serde(rename = "messageDocument")]
     MessageDocument(MessageDocument),
     /// An expired photo message (self-destructed after TTL has elapsed)
+    #[serde(rename = "messageExpiredPhoto")]
     MessageExpiredPhoto(MessageExpiredPhoto),
     /// An expired video message (self-destructed after TTL has elapsed)
+    #[serde(rename = "messageExpiredVideo")]
     MessageExpiredVideo(MessageExpiredVideo),
     /// A message with a game
+    #[serde(rename = "messageGame")]
     MessageGame(MessageGame),
     /// A new high score was achieved in a game
+    #[serde(rename = "messageGameScore")]
     MessageGameScore(MessageGameScore),
     /// A message with an invoice from a bot
+    #[serde(rename = "messageInvoice")]
     MessageInvoice(MessageInvoice),
     /// A message with a location
+    #[serde(rename = "messageLocation")]
     MessageLocation(MessageLocation),
     /// Telegram Passport data has been received; for bots only
+    #[serde(rename = "messagePassportDataReceived")]
     MessagePassportDataReceived(MessagePassportDataReceived),
     /// Telegram Passport data has been sent
+    #[serde(rename = "messagePassportDataSent")]
     MessagePassportDataSent(MessagePassportDataSent),
     /// A payment has been completed
+    #[serde(rename = "messagePaymentSuccessful")]
     MessagePaymentSuccessful(MessagePaymentSuccessful),
     /// A payment has been completed; for bots only
+    #[serde(rename = "messagePaymentSuccessfulBot")]
     MessagePaymentSuccessfulBot(MessagePaymentSuccessfulBot),
     /// A photo message
+    #[serde(rename = "messagePhoto")]
     MessagePhoto(MessagePhoto),
     /// A message has been pinned
+    #[serde(rename = "messagePinMessage")]
     MessagePinMessage(MessagePinMessage),
     /// A message with a poll
+    #[serde(rename = "messagePoll")]
     MessagePoll(MessagePoll),
     /// A screenshot of a message in the chat has been taken
+    #[serde(rename = "messageScreenshotTaken")]
     MessageScreenshotTaken(MessageScreenshotTaken),
     /// A sticker message
+    #[serde(rename = "messageSticker")]
     MessageSticker(MessageSticker),
     /// A newly created supergroup or channel
+    #[serde(rename = "messageSupergroupChatCreate")]
     MessageSupergroupChatCreate(MessageSupergroupChatCreate),
     /// A text message
+    #[serde(rename = "messageText")]
     MessageText(MessageText),
     /// Message content that is not supported by the client
+    #[serde(rename = "messageUnsupported")]
     MessageUnsupported(MessageUnsupported),
     /// A message with information about a venue
+    #[serde(rename = "messageVenue")]
     MessageVenue(MessageVenue),
     /// A video message
+    #[serde(rename = "messageVideo")]
     MessageVideo(MessageVideo),
     /// A video note message
+    #[serde(rename = "messageVideoNote")]
     MessageVideoNote(MessageVideoNote),
     /// A voice note message
+    #[serde(rename = "messageVoiceNote")]
     MessageVoiceNote(MessageVoiceNote),
     /// The current user has connected a website by logging in using Telegram Login Widget on it
+    #[serde(rename = "messageWebsiteConnected")]
     MessageWebsiteConnected(MessageWebsiteConnected),
 }
 
+/// Default value is default of [MessageBasicGroupChatCreate], builders still require the field to be set
 impl Default for MessageContent {
     fn default() -> Self {
-        MessageContent::_Default(())
+        MessageContent::MessageBasicGroupChatCreate(MessageBasicGroupChatCreate::default())
     }
 }
 
-impl<'de> Deserialize<'de> for MessageContent {
-    fn deserialize<D>(deserializer: D) -> Result<MessageContent, D::Error>
+impl Serialize for MessageContent {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          MessageContent,
-          (messageAnimation, MessageAnimation);
-          (messageAudio, MessageAudio);
-          (messageBasicGroupChatCreate, MessageBasicGroupChatCreate);
-          (messageCall, MessageCall);
-          (messageChatAddMembers, MessageChatAddMembers);
-          (messageChatChangePhoto, MessageChatChangePhoto);
-          (messageChatChangeTitle, MessageChatChangeTitle);
-          (messageChatDeleteMember, MessageChatDeleteMember);
-          (messageChatDeletePhoto, MessageChatDeletePhoto);
-          (messageChatJoinByLink, MessageChatJoinByLink);
-          (messageChatSetTtl, MessageChatSetTtl);
-          (messageChatUpgradeFrom, MessageChatUpgradeFrom);
-          (messageChatUpgradeTo, MessageChatUpgradeTo);
-          (messageContact, MessageContact);
-          (messageContactRegistered, MessageContactRegistered);
-          (messageCustomServiceAction, MessageCustomServiceAction);
-          (messageDocument, MessageDocument);
-          (messageExpiredPhoto, MessageExpiredPhoto);
-          (messageExpiredVideo, MessageExpiredVideo);
-          (messageGame, MessageGame);
-          (messageGameScore, MessageGameScore);
-          (messageInvoice, MessageInvoice);
-          (messageLocation, MessageLocation);
-          (messagePassportDataReceived, MessagePassportDataReceived);
-          (messagePassportDataSent, MessagePassportDataSent);
-          (messagePaymentSuccessful, MessagePaymentSuccessful);
-          (messagePaymentSuccessfulBot, MessagePaymentSuccessfulBot);
-          (messagePhoto, MessagePhoto);
-          (messagePinMessage, MessagePinMessage);
-          (messagePoll, MessagePoll);
-          (messageScreenshotTaken, MessageScreenshotTaken);
-          (messageSticker, MessageSticker);
-          (messageSupergroupChatCreate, MessageSupergroupChatCreate);
-          (messageText, MessageText);
-          (messageUnsupported, MessageUnsupported);
-          (messageVenue, MessageVenue);
-          (messageVideo, MessageVideo);
-          (messageVideoNote, MessageVideoNote);
-          (messageVoiceNote, MessageVoiceNote);
-          (messageWebsiteConnected, MessageWebsiteConnected);
-
-        )(deserializer)
+        match self {
+            MessageContent::MessageAnimation(t) => t.serialize(serializer),
+            MessageContent::MessageAudio(t) => t.serialize(serializer),
+            MessageContent::MessageBasicGroupChatCreate(t) => t.serialize(serializer),
+            MessageContent::MessageCall(t) => t.serialize(serializer),
+            MessageContent::MessageChatAddMembers(t) => t.serialize(serializer),
+            MessageContent::MessageChatChangePhoto(t) => t.serialize(serializer),
+            MessageContent::MessageChatChangeTitle(t) => t.serialize(serializer),
+            MessageContent::MessageChatDeleteMember(t) => t.serialize(serializer),
+            MessageContent::MessageChatDeletePhoto(t) => t.serialize(serializer),
+            MessageContent::MessageChatJoinByLink(t) => t.serialize(serializer),
+            MessageContent::MessageChatSetTtl(t) => t.serialize(serializer),
+            MessageContent::MessageChatUpgradeFrom(t) => t.serialize(serializer),
+            MessageContent::MessageChatUpgradeTo(t) => t.serialize(serializer),
+            MessageContent::MessageContact(t) => t.serialize(serializer),
+            MessageContent::MessageContactRegistered(t) => t.serialize(serializer),
+            MessageContent::MessageCustomServiceAction(t) => t.serialize(serializer),
+            MessageContent::MessageDocument(t) => t.serialize(serializer),
+            MessageContent::MessageExpiredPhoto(t) => t.serialize(serializer),
+            MessageContent::MessageExpiredVideo(t) => t.serialize(serializer),
+            MessageContent::MessageGame(t) => t.serialize(serializer),
+            MessageContent::MessageGameScore(t) => t.serialize(serializer),
+            MessageContent::MessageInvoice(t) => t.serialize(serializer),
+            MessageContent::MessageLocation(t) => t.serialize(serializer),
+            MessageContent::MessagePassportDataReceived(t) => t.serialize(serializer),
+            MessageContent::MessagePassportDataSent(t) => t.serialize(serializer),
+            MessageContent::MessagePaymentSuccessful(t) => t.serialize(serializer),
+            MessageContent::MessagePaymentSuccessfulBot(t) => t.serialize(serializer),
+            MessageContent::MessagePhoto(t) => t.serialize(serializer),
+            MessageContent::MessagePinMessage(t) => t.serialize(serializer),
+            MessageContent::MessagePoll(t) => t.serialize(serializer),
+            MessageContent::MessageScreenshotTaken(t) => t.serialize(serializer),
+            MessageContent::MessageSticker(t) => t.serialize(serializer),
+            MessageContent::MessageSupergroupChatCreate(t) => t.serialize(serializer),
+            MessageContent::MessageText(t) => t.serialize(serializer),
+            MessageContent::MessageUnsupported(t) => t.serialize(serializer),
+            MessageContent::MessageVenue(t) => t.serialize(serializer),
+            MessageContent::MessageVideo(t) => t.serialize(serializer),
+            MessageContent::MessageVideoNote(t) => t.serialize(serializer),
+            MessageContent::MessageVoiceNote(t) => t.serialize(serializer),
+            MessageContent::MessageWebsiteConnected(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -199,8 +234,6 @@ impl RObject for MessageContent {
             MessageContent::MessageVideoNote(t) => t.td_name(),
             MessageContent::MessageVoiceNote(t) => t.td_name(),
             MessageContent::MessageWebsiteConnected(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -246,8 +279,6 @@ impl RObject for MessageContent {
             MessageContent::MessageVideoNote(t) => t.extra(),
             MessageContent::MessageVoiceNote(t) => t.extra(),
             MessageContent::MessageWebsiteConnected(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -259,10 +290,6 @@ impl MessageContent {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, MessageContent::_Default(_))
-    }
 }
 
 impl AsRef<MessageContent> for MessageContent {
@@ -273,12 +300,13 @@ impl AsRef<MessageContent> for MessageContent {
 
 /// An animation message (GIF-style).
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "messageAnimation")]
 pub struct MessageAnimation {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The animation description
     animation: Animation,
@@ -302,6 +330,24 @@ impl RObject for MessageAnimation {
     }
 }
 
+impl PartialEq for MessageAnimation {
+    fn eq(&self, other: &Self) -> bool {
+        self.animation == other.animation
+            && self.caption == other.caption
+            && self.is_secret == other.is_secret
+    }
+}
+
+impl Eq for MessageAnimation {}
+
+impl std::hash::Hash for MessageAnimation {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.animation, state);
+        std::hash::Hash::hash(&self.caption, state);
+        std::hash::Hash::hash(&self.is_secret, state);
+    }
+}
+
 impl TDMessageContent for MessageAnimation {}
 
 impl MessageAnimation {
@@ -309,10 +355,24 @@ impl MessageAnimation {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessageAnimationBuilder {
-        let mut inner = MessageAnimation::default();
-        inner.td_name = "messageAnimation".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessageAnimationBuilder { inner }
+        RTDMessageAnimationBuilder {
+            inner: MessageAnimation::default(),
+            missing: vec!["animation", "caption"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessageAnimationBuilder {
+        RTDMessageAnimationBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessageAnimationBuilder {
+        RTDMessageAnimationBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn animation(&self) -> &Animation {
@@ -326,25 +386,63 @@ impl MessageAnimation {
     pub fn is_secret(&self) -> bool {
         self.is_secret
     }
+
+    pub fn into_animation(self) -> Animation {
+        self.animation
+    }
+
+    pub fn into_caption(self) -> FormattedText {
+        self.caption
+    }
+
+    pub fn with_animation<T: AsRef<Animation>>(mut self, animation: T) -> Self {
+        self.animation = animation.as_ref().clone();
+        self
+    }
+
+    pub fn with_caption<T: AsRef<FormattedText>>(mut self, caption: T) -> Self {
+        self.caption = caption.as_ref().clone();
+        self
+    }
+
+    pub fn with_is_secret(mut self, is_secret: bool) -> Self {
+        self.is_secret = is_secret;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDMessageAnimationBuilder {
     inner: MessageAnimation,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessageAnimationBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MessageAnimation {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MessageAnimation> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "MessageAnimation",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn animation<T: AsRef<Animation>>(&mut self, animation: T) -> &mut Self {
         self.inner.animation = animation.as_ref().clone();
+        self.missing.retain(|f| *f != "animation");
         self
     }
 
     pub fn caption<T: AsRef<FormattedText>>(&mut self, caption: T) -> &mut Self {
         self.inner.caption = caption.as_ref().clone();
+        self.missing.retain(|f| *f != "caption");
         self
     }
 
@@ -368,12 +466,13 @@ impl AsRef<MessageAnimation> for RTDMessageAnimationBuilder {
 
 /// An audio message
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "messageAudio")]
 pub struct MessageAudio {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The audio description
     audio: Audio,
@@ -395,6 +494,21 @@ impl RObject for MessageAudio {
     }
 }
 
+impl PartialEq for MessageAudio {
+    fn eq(&self, other: &Self) -> bool {
+        self.audio == other.audio && self.caption == other.caption
+    }
+}
+
+impl Eq for MessageAudio {}
+
+impl std::hash::Hash for MessageAudio {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.audio, state);
+        std::hash::Hash::hash(&self.caption, state);
+    }
+}
+
 impl TDMessageContent for MessageAudio {}
 
 impl MessageAudio {
@@ -402,10 +516,24 @@ impl MessageAudio {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessageAudioBuilder {
-        let mut inner = MessageAudio::default();
-        inner.td_name = "messageAudio".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessageAudioBuilder { inner }
+        RTDMessageAudioBuilder {
+            inner: MessageAudio::default(),
+            missing: vec!["audio", "caption"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessageAudioBuilder {
+        RTDMessageAudioBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessageAudioBuilder {
+        RTDMessageAudioBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn audio(&self) -> &Audio {
@@ -415,25 +543,58 @@ impl MessageAudio {
     pub fn caption(&self) -> &FormattedText {
         &self.caption
     }
+
+    pub fn into_audio(self) -> Audio {
+        self.audio
+    }
+
+    pub fn into_caption(self) -> FormattedText {
+        self.caption
+    }
+
+    pub fn with_audio<T: AsRef<Audio>>(mut self, audio: T) -> Self {
+        self.audio = audio.as_ref().clone();
+        self
+    }
+
+    pub fn with_caption<T: AsRef<FormattedText>>(mut self, caption: T) -> Self {
+        self.caption = caption.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDMessageAudioBuilder {
     inner: MessageAudio,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessageAudioBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MessageAudio {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MessageAudio> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "MessageAudio",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn audio<T: AsRef<Audio>>(&mut self, audio: T) -> &mut Self {
         self.inner.audio = audio.as_ref().clone();
+        self.missing.retain(|f| *f != "audio");
         self
     }
 
     pub fn caption<T: AsRef<FormattedText>>(&mut self, caption: T) -> &mut Self {
         self.inner.caption = caption.as_ref().clone();
+        self.missing.retain(|f| *f != "caption");
         self
     }
 }
@@ -452,17 +613,18 @@ impl AsRef<MessageAudio> for RTDMessageAudioBuilder {
 
 /// A newly created basic group
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "messageBasicGroupChatCreate")]
 pub struct MessageBasicGroupChatCreate {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Title of the basic group
     title: String,
     /// User identifiers of members in the basic group
-    member_user_ids: Vec<i64>,
+    member_user_ids: Vec<UserId>,
 }
 
 impl RObject for MessageBasicGroupChatCreate {
@@ -479,6 +641,21 @@ impl RObject for MessageBasicGroupChatCreate {
     }
 }
 
+impl PartialEq for MessageBasicGroupChatCreate {
+    fn eq(&self, other: &Self) -> bool {
+        self.title == other.title && self.member_user_ids == other.member_user_ids
+    }
+}
+
+impl Eq for MessageBasicGroupChatCreate {}
+
+impl std::hash::Hash for MessageBasicGroupChatCreate {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.title, state);
+        std::hash::Hash::hash(&self.member_user_ids, state);
+    }
+}
+
 impl TDMessageContent for MessageBasicGroupChatCreate {}
 
 impl MessageBasicGroupChatCreate {
@@ -486,38 +663,85 @@ impl MessageBasicGroupChatCreate {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessageBasicGroupChatCreateBuilder {
-        let mut inner = MessageBasicGroupChatCreate::default();
-        inner.td_name = "messageBasicGroupChatCreate".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessageBasicGroupChatCreateBuilder { inner }
+        RTDMessageBasicGroupChatCreateBuilder {
+            inner: MessageBasicGroupChatCreate::default(),
+            missing: vec!["title", "member_user_ids"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessageBasicGroupChatCreateBuilder {
+        RTDMessageBasicGroupChatCreateBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessageBasicGroupChatCreateBuilder {
+        RTDMessageBasicGroupChatCreateBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn title(&self) -> &String {
         &self.title
     }
 
-    pub fn member_user_ids(&self) -> &Vec<i64> {
+    pub fn member_user_ids(&self) -> &Vec<UserId> {
         &self.member_user_ids
     }
+
+    pub fn into_title(self) -> String {
+        self.title
+    }
+
+    pub fn into_member_user_ids(self) -> Vec<UserId> {
+        self.member_user_ids
+    }
+
+    pub fn with_title<T: AsRef<str>>(mut self, title: T) -> Self {
+        self.title = title.as_ref().to_string();
+        self
+    }
+
+    pub fn with_member_user_ids(mut self, member_user_ids: Vec<UserId>) -> Self {
+        self.member_user_ids = member_user_ids;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDMessageBasicGroupChatCreateBuilder {
     inner: MessageBasicGroupChatCreate,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessageBasicGroupChatCreateBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MessageBasicGroupChatCreate {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MessageBasicGroupChatCreate> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "MessageBasicGroupChatCreate",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
         self.inner.title = title.as_ref().to_string();
+        self.missing.retain(|f| *f != "title");
         self
     }
 
-    pub fn member_user_ids(&mut self, member_user_ids: Vec<i64>) -> &mut Self {
+    pub fn member_user_ids(&mut self, member_user_ids: Vec<UserId>) -> &mut Self {
         self.inner.member_user_ids = member_user_ids;
+        self.missing.retain(|f| *f != "member_user_ids");
         self
     }
 }
@@ -536,17 +760,18 @@ impl AsRef<MessageBasicGroupChatCreate> for RTDMessageBasicGroupChatCreateBuilde
 
 /// A message with information about an ended call
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "messageCall")]
 pub struct MessageCall {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Reason why the call was discarded
     discard_reason: CallDiscardReason,
     /// Call duration, in seconds
-    duration: i64,
+    duration: i32,
 }
 
 impl RObject for MessageCall {
@@ -563,6 +788,21 @@ impl RObject for MessageCall {
     }
 }
 
+impl PartialEq for MessageCall {
+    fn eq(&self, other: &Self) -> bool {
+        self.discard_reason == other.discard_reason && self.duration == other.duration
+    }
+}
+
+impl Eq for MessageCall {}
+
+impl std::hash::Hash for MessageCall {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.discard_reason, state);
+        std::hash::Hash::hash(&self.duration, state);
+    }
+}
+
 impl TDMessageContent for MessageCall {}
 
 impl MessageCall {
@@ -570,38 +810,78 @@ impl MessageCall {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessageCallBuilder {
-        let mut inner = MessageCall::default();
-        inner.td_name = "messageCall".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessageCallBuilder { inner }
+        RTDMessageCallBuilder {
+            inner: MessageCall::default(),
+            missing: vec!["discard_reason", "duration"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessageCallBuilder {
+        RTDMessageCallBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessageCallBuilder {
+        RTDMessageCallBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn discard_reason(&self) -> &CallDiscardReason {
         &self.discard_reason
     }
 
-    pub fn duration(&self) -> i64 {
+    pub fn duration(&self) -> i32 {
         self.duration
     }
+
+    pub fn into_discard_reason(self) -> CallDiscardReason {
+        self.discard_reason
+    }
+
+    pub fn with_discard_reason<T: AsRef<CallDiscardReason>>(mut self, discard_reason: T) -> Self {
+        self.discard_reason = discard_reason.as_ref().clone();
+        self
+    }
+
+    pub fn with_duration(mut self, duration: i32) -> Self {
+        self.duration = duration;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDMessageCallBuilder {
     inner: MessageCall,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessageCallBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MessageCall {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MessageCall> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("MessageCall", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn discard_reason<T: AsRef<CallDiscardReason>>(&mut self, discard_reason: T) -> &mut Self {
         self.inner.discard_reason = discard_reason.as_ref().clone();
+        self.missing.retain(|f| *f != "discard_reason");
         self
     }
 
-    pub fn duration(&mut self, duration: i64) -> &mut Self {
+    pub fn duration(&mut self, duration: i32) -> &mut Self {
         self.inner.duration = duration;
+        self.missing.retain(|f| *f != "duration");
         self
     }
 }
@@ -620,15 +900,16 @@ impl AsRef<MessageCall> for RTDMessageCallBuilder {
 
 /// New chat members were added
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "messageChatAddMembers")]
 pub struct MessageChatAddMembers {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// User identifiers of the new members
-    member_user_ids: Vec<i64>,
+    member_user_ids: Vec<UserId>,
 }
 
 impl RObject for MessageChatAddMembers {
@@ -645,6 +926,20 @@ impl RObject for MessageChatAddMembers {
     }
 }
 
+impl PartialEq for MessageChatAddMembers {
+    fn eq(&self, other: &Self) -> bool {
+        self.member_user_ids == other.member_user_ids
+    }
+}
+
+impl Eq for MessageChatAddMembers {}
+
+impl std::hash::Hash for MessageChatAddMembers {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.member_user_ids, state);
+    }
+}
+
 impl TDMessageContent for MessageChatAddMembers {}
 
 impl MessageChatAddMembers {
@@ -652,29 +947,66 @@ impl MessageChatAddMembers {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessageChatAddMembersBuilder {
-        let mut inner = MessageChatAddMembers::default();
-        inner.td_name = "messageChatAddMembers".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessageChatAddMembersBuilder { inner }
+        RTDMessageChatAddMembersBuilder {
+            inner: MessageChatAddMembers::default(),
+            missing: vec!["member_user_ids"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessageChatAddMembersBuilder {
+        RTDMessageChatAddMembersBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessageChatAddMembersBuilder {
+        RTDMessageChatAddMembersBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn member_user_ids(&self) -> &Vec<i64> {
+    pub fn member_user_ids(&self) -> &Vec<UserId> {
         &self.member_user_ids
     }
+
+    pub fn into_member_user_ids(self) -> Vec<UserId> {
+        self.member_user_ids
+    }
+
+    pub fn with_member_user_ids(mut self, member_user_ids: Vec<UserId>) -> Self {
+        self.member_user_ids = member_user_ids;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDMessageChatAddMembersBuilder {
     inner: MessageChatAddMembers,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessageChatAddMembersBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MessageChatAddMembers {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MessageChatAddMembers> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "MessageChatAddMembers",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn member_user_ids(&mut self, member_user_ids: Vec<i64>) -> &mut Self {
+    pub fn member_user_ids(&mut self, member_user_ids: Vec<UserId>) -> &mut Self {
         self.inner.member_user_ids = member_user_ids;
+        self.missing.retain(|f| *f != "member_user_ids");
         self
     }
 }
@@ -693,12 +1025,13 @@ impl AsRef<MessageChatAddMembers> for RTDMessageChatAddMembersBuilder {
 
 /// An updated chat photo
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "messageChatChangePhoto")]
 pub struct MessageChatChangePhoto {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// New chat photo
     photo: Photo,
@@ -718,6 +1051,20 @@ impl RObject for MessageChatChangePhoto {
     }
 }
 
+impl PartialEq for MessageChatChangePhoto {
+    fn eq(&self, other: &Self) -> bool {
+        self.photo == other.photo
+    }
+}
+
+impl Eq for MessageChatChangePhoto {}
+
+impl std::hash::Hash for MessageChatChangePhoto {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.photo, state);
+    }
+}
+
 impl TDMessageContent for MessageChatChangePhoto {}
 
 impl MessageChatChangePhoto {
@@ -725,29 +1072,66 @@ impl MessageChatChangePhoto {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessageChatChangePhotoBuilder {
-        let mut inner = MessageChatChangePhoto::default();
-        inner.td_name = "messageChatChangePhoto".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessageChatChangePhotoBuilder { inner }
+        RTDMessageChatChangePhotoBuilder {
+            inner: MessageChatChangePhoto::default(),
+            missing: vec!["photo"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessageChatChangePhotoBuilder {
+        RTDMessageChatChangePhotoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessageChatChangePhotoBuilder {
+        RTDMessageChatChangePhotoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn photo(&self) -> &Photo {
         &self.photo
     }
+
+    pub fn into_photo(self) -> Photo {
+        self.photo
+    }
+
+    pub fn with_photo<T: AsRef<Photo>>(mut self, photo: T) -> Self {
+        self.photo = photo.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDMessageChatChangePhotoBuilder {
     inner: MessageChatChangePhoto,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessageChatChangePhotoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MessageChatChangePhoto {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MessageChatChangePhoto> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "MessageChatChangePhoto",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn photo<T: AsRef<Photo>>(&mut self, photo: T) -> &mut Self {
         self.inner.photo = photo.as_ref().clone();
+        self.missing.retain(|f| *f != "photo");
         self
     }
 }
@@ -766,12 +1150,13 @@ impl AsRef<MessageChatChangePhoto> for RTDMessageChatChangePhotoBuilder {
 
 /// An updated chat title
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "messageChatChangeTitle")]
 pub struct MessageChatChangeTitle {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// New chat title
     title: String,
@@ -791,6 +1176,20 @@ impl RObject for MessageChatChangeTitle {
     }
 }
 
+impl PartialEq for MessageChatChangeTitle {
+    fn eq(&self, other: &Self) -> bool {
+        self.title == other.title
+    }
+}
+
+impl Eq for MessageChatChangeTitle {}
+
+impl std::hash::Hash for MessageChatChangeTitle {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.title, state);
+    }
+}
+
 impl TDMessageContent for MessageChatChangeTitle {}
 
 impl MessageChatChangeTitle {
@@ -798,29 +1197,66 @@ impl MessageChatChangeTitle {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessageChatChangeTitleBuilder {
-        let mut inner = MessageChatChangeTitle::default();
-        inner.td_name = "messageChatChangeTitle".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessageChatChangeTitleBuilder { inner }
+        RTDMessageChatChangeTitleBuilder {
+            inner: MessageChatChangeTitle::default(),
+            missing: vec!["title"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessageChatChangeTitleBuilder {
+        RTDMessageChatChangeTitleBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessageChatChangeTitleBuilder {
+        RTDMessageChatChangeTitleBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn title(&self) -> &String {
         &self.title
     }
+
+    pub fn into_title(self) -> String {
+        self.title
+    }
+
+    pub fn with_title<T: AsRef<str>>(mut self, title: T) -> Self {
+        self.title = title.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDMessageChatChangeTitleBuilder {
     inner: MessageChatChangeTitle,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessageChatChangeTitleBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MessageChatChangeTitle {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MessageChatChangeTitle> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "MessageChatChangeTitle",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
         self.inner.title = title.as_ref().to_string();
+        self.missing.retain(|f| *f != "title");
         self
     }
 }
@@ -839,15 +1275,16 @@ impl AsRef<MessageChatChangeTitle> for RTDMessageChatChangeTitleBuilder {
 
 /// A chat member was deleted
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "messageChatDeleteMember")]
 pub struct MessageChatDeleteMember {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// User identifier of the deleted chat member
-    user_id: i64,
+    user_id: UserId,
 }
 
 impl RObject for MessageChatDeleteMember {
@@ -864,6 +1301,20 @@ impl RObject for MessageChatDeleteMember {
     }
 }
 
+impl PartialEq for MessageChatDeleteMember {
+    fn eq(&self, other: &Self) -> bool {
+        self.user_id == other.user_id
+    }
+}
+
+impl Eq for MessageChatDeleteMember {}
+
+impl std::hash::Hash for MessageChatDeleteMember {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.user_id, state);
+    }
+}
+
 impl TDMessageContent for MessageChatDeleteMember {}
 
 impl MessageChatDeleteMember {
@@ -871,29 +1322,62 @@ impl MessageChatDeleteMember {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessageChatDeleteMemberBuilder {
-        let mut inner = MessageChatDeleteMember::default();
-        inner.td_name = "messageChatDeleteMember".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessageChatDeleteMemberBuilder { inner }
+        RTDMessageChatDeleteMemberBuilder {
+            inner: MessageChatDeleteMember::default(),
+            missing: vec!["user_id"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessageChatDeleteMemberBuilder {
+        RTDMessageChatDeleteMemberBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessageChatDeleteMemberBuilder {
+        RTDMessageChatDeleteMemberBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn user_id(&self) -> i64 {
+    pub fn user_id(&self) -> UserId {
         self.user_id
     }
+
+    pub fn with_user_id<T: Into<UserId>>(mut self, user_id: T) -> Self {
+        self.user_id = user_id.into();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDMessageChatDeleteMemberBuilder {
     inner: MessageChatDeleteMember,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessageChatDeleteMemberBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MessageChatDeleteMember {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MessageChatDeleteMember> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "MessageChatDeleteMember",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn user_id(&mut self, user_id: i64) -> &mut Self {
-        self.inner.user_id = user_id;
+    pub fn user_id<T: Into<UserId>>(&mut self, user_id: T) -> &mut Self {
+        self.inner.user_id = user_id.into();
+        self.missing.retain(|f| *f != "user_id");
         self
     }
 }
@@ -912,12 +1396,13 @@ impl AsRef<MessageChatDeleteMember> for RTDMessageChatDeleteMemberBuilder {
 
 /// A deleted chat photo
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "messageChatDeletePhoto")]
 pub struct MessageChatDeletePhoto {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -935,6 +1420,18 @@ impl RObject for MessageChatDeletePhoto {
     }
 }
 
+impl PartialEq for MessageChatDeletePhoto {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for MessageChatDeletePhoto {}
+
+impl std::hash::Hash for MessageChatDeletePhoto {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDMessageContent for MessageChatDeletePhoto {}
 
 impl MessageChatDeletePhoto {
@@ -942,22 +1439,49 @@ impl MessageChatDeletePhoto {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessageChatDeletePhotoBuilder {
-        let mut inner = MessageChatDeletePhoto::default();
-        inner.td_name = "messageChatDeletePhoto".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessageChatDeletePhotoBuilder { inner }
+        RTDMessageChatDeletePhotoBuilder {
+            inner: MessageChatDeletePhoto::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessageChatDeletePhotoBuilder {
+        RTDMessageChatDeletePhotoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessageChatDeletePhotoBuilder {
+        RTDMessageChatDeletePhotoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDMessageChatDeletePhotoBuilder {
     inner: MessageChatDeletePhoto,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessageChatDeletePhotoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MessageChatDeletePhoto {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MessageChatDeletePhoto> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "MessageChatDeletePhoto",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<MessageChatDeletePhoto> for MessageChatDeletePhoto {
@@ -974,12 +1498,13 @@ impl AsRef<MessageChatDeletePhoto> for RTDMessageChatDeletePhotoBuilder {
 
 /// A new member joined the chat by invite link
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "messageChatJoinByLink")]
 pub struct MessageChatJoinByLink {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -997,6 +1522,18 @@ impl RObject for MessageChatJoinByLink {
     }
 }
 
+impl PartialEq for MessageChatJoinByLink {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for MessageChatJoinByLink {}
+
+impl std::hash::Hash for MessageChatJoinByLink {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDMessageContent for MessageChatJoinByLink {}
 
 impl MessageChatJoinByLink {
@@ -1004,22 +1541,49 @@ impl MessageChatJoinByLink {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessageChatJoinByLinkBuilder {
-        let mut inner = MessageChatJoinByLink::default();
-        inner.td_name = "messageChatJoinByLink".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessageChatJoinByLinkBuilder { inner }
+        RTDMessageChatJoinByLinkBuilder {
+            inner: MessageChatJoinByLink::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessageChatJoinByLinkBuilder {
+        RTDMessageChatJoinByLinkBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessageChatJoinByLinkBuilder {
+        RTDMessageChatJoinByLinkBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDMessageChatJoinByLinkBuilder {
     inner: MessageChatJoinByLink,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessageChatJoinByLinkBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MessageChatJoinByLink {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MessageChatJoinByLink> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "MessageChatJoinByLink",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<MessageChatJoinByLink> for MessageChatJoinByLink {
@@ -1036,15 +1600,16 @@ impl AsRef<MessageChatJoinByLink> for RTDMessageChatJoinByLinkBuilder {
 
 /// The TTL (Time To Live) setting messages in a secret chat has been changed
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "messageChatSetTtl")]
 pub struct MessageChatSetTtl {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// New TTL
-    ttl: i64,
+    ttl: i32,
 }
 
 impl RObject for MessageChatSetTtl {
@@ -1061,6 +1626,20 @@ impl RObject for MessageChatSetTtl {
     }
 }
 
+impl PartialEq for MessageChatSetTtl {
+    fn eq(&self, other: &Self) -> bool {
+        self.ttl == other.ttl
+    }
+}
+
+impl Eq for MessageChatSetTtl {}
+
+impl std::hash::Hash for MessageChatSetTtl {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.ttl, state);
+    }
+}
+
 impl TDMessageContent for MessageChatSetTtl {}
 
 impl MessageChatSetTtl {
@@ -1068,29 +1647,62 @@ impl MessageChatSetTtl {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessageChatSetTtlBuilder {
-        let mut inner = MessageChatSetTtl::default();
-        inner.td_name = "messageChatSetTtl".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessageChatSetTtlBuilder { inner }
+        RTDMessageChatSetTtlBuilder {
+            inner: MessageChatSetTtl::default(),
+            missing: vec!["ttl"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessageChatSetTtlBuilder {
+        RTDMessageChatSetTtlBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessageChatSetTtlBuilder {
+        RTDMessageChatSetTtlBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn ttl(&self) -> i64 {
+    pub fn ttl(&self) -> i32 {
         self.ttl
     }
+
+    pub fn with_ttl(mut self, ttl: i32) -> Self {
+        self.ttl = ttl;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDMessageChatSetTtlBuilder {
     inner: MessageChatSetTtl,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessageChatSetTtlBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MessageChatSetTtl {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MessageChatSetTtl> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "MessageChatSetTtl",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn ttl(&mut self, ttl: i64) -> &mut Self {
+    pub fn ttl(&mut self, ttl: i32) -> &mut Self {
         self.inner.ttl = ttl;
+        self.missing.retain(|f| *f != "ttl");
         self
     }
 }
@@ -1109,17 +1721,18 @@ impl AsRef<MessageChatSetTtl> for RTDMessageChatSetTtlBuilder {
 
 /// A supergroup has been created from a basic group
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "messageChatUpgradeFrom")]
 pub struct MessageChatUpgradeFrom {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Title of the newly created supergroup
     title: String,
     /// The identifier of the original basic group
-    basic_group_id: i64,
+    basic_group_id: BasicGroupId,
 }
 
 impl RObject for MessageChatUpgradeFrom {
@@ -1136,6 +1749,21 @@ impl RObject for MessageChatUpgradeFrom {
     }
 }
 
+impl PartialEq for MessageChatUpgradeFrom {
+    fn eq(&self, other: &Self) -> bool {
+        self.title == other.title && self.basic_group_id == other.basic_group_id
+    }
+}
+
+impl Eq for MessageChatUpgradeFrom {}
+
+impl std::hash::Hash for MessageChatUpgradeFrom {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.title, state);
+        std::hash::Hash::hash(&self.basic_group_id, state);
+    }
+}
+
 impl TDMessageContent for MessageChatUpgradeFrom {}
 
 impl MessageChatUpgradeFrom {
@@ -1143,38 +1771,81 @@ impl MessageChatUpgradeFrom {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessageChatUpgradeFromBuilder {
-        let mut inner = MessageChatUpgradeFrom::default();
-        inner.td_name = "messageChatUpgradeFrom".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessageChatUpgradeFromBuilder { inner }
+        RTDMessageChatUpgradeFromBuilder {
+            inner: MessageChatUpgradeFrom::default(),
+            missing: vec!["title", "basic_group_id"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessageChatUpgradeFromBuilder {
+        RTDMessageChatUpgradeFromBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessageChatUpgradeFromBuilder {
+        RTDMessageChatUpgradeFromBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn title(&self) -> &String {
         &self.title
     }
 
-    pub fn basic_group_id(&self) -> i64 {
+    pub fn basic_group_id(&self) -> BasicGroupId {
         self.basic_group_id
     }
+
+    pub fn into_title(self) -> String {
+        self.title
+    }
+
+    pub fn with_title<T: AsRef<str>>(mut self, title: T) -> Self {
+        self.title = title.as_ref().to_string();
+        self
+    }
+
+    pub fn with_basic_group_id<T: Into<BasicGroupId>>(mut self, basic_group_id: T) -> Self {
+        self.basic_group_id = basic_group_id.into();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDMessageChatUpgradeFromBuilder {
     inner: MessageChatUpgradeFrom,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessageChatUpgradeFromBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MessageChatUpgradeFrom {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MessageChatUpgradeFrom> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "MessageChatUpgradeFrom",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
         self.inner.title = title.as_ref().to_string();
+        self.missing.retain(|f| *f != "title");
         self
     }
 
-    pub fn basic_group_id(&mut self, basic_group_id: i64) -> &mut Self {
-        self.inner.basic_group_id = basic_group_id;
+    pub fn basic_group_id<T: Into<BasicGroupId>>(&mut self, basic_group_id: T) -> &mut Self {
+        self.inner.basic_group_id = basic_group_id.into();
+        self.missing.retain(|f| *f != "basic_group_id");
         self
     }
 }
@@ -1193,15 +1864,16 @@ impl AsRef<MessageChatUpgradeFrom> for RTDMessageChatUpgradeFromBuilder {
 
 /// A basic group was upgraded to a supergroup and was deactivated as the result
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "messageChatUpgradeTo")]
 pub struct MessageChatUpgradeTo {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Identifier of the supergroup to which the basic group was upgraded
-    supergroup_id: i64,
+    supergroup_id: SupergroupId,
 }
 
 impl RObject for MessageChatUpgradeTo {
@@ -1218,6 +1890,20 @@ impl RObject for MessageChatUpgradeTo {
     }
 }
 
+impl PartialEq for MessageChatUpgradeTo {
+    fn eq(&self, other: &Self) -> bool {
+        self.supergroup_id == other.supergroup_id
+    }
+}
+
+impl Eq for MessageChatUpgradeTo {}
+
+impl std::hash::Hash for MessageChatUpgradeTo {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.supergroup_id, state);
+    }
+}
+
 impl TDMessageContent for MessageChatUpgradeTo {}
 
 impl MessageChatUpgradeTo {
@@ -1225,29 +1911,62 @@ impl MessageChatUpgradeTo {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessageChatUpgradeToBuilder {
-        let mut inner = MessageChatUpgradeTo::default();
-        inner.td_name = "messageChatUpgradeTo".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessageChatUpgradeToBuilder { inner }
+        RTDMessageChatUpgradeToBuilder {
+            inner: MessageChatUpgradeTo::default(),
+            missing: vec!["supergroup_id"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessageChatUpgradeToBuilder {
+        RTDMessageChatUpgradeToBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessageChatUpgradeToBuilder {
+        RTDMessageChatUpgradeToBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn supergroup_id(&self) -> i64 {
+    pub fn supergroup_id(&self) -> SupergroupId {
         self.supergroup_id
     }
+
+    pub fn with_supergroup_id<T: Into<SupergroupId>>(mut self, supergroup_id: T) -> Self {
+        self.supergroup_id = supergroup_id.into();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDMessageChatUpgradeToBuilder {
     inner: MessageChatUpgradeTo,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessageChatUpgradeToBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MessageChatUpgradeTo {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MessageChatUpgradeTo> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "MessageChatUpgradeTo",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn supergroup_id(&mut self, supergroup_id: i64) -> &mut Self {
-        self.inner.supergroup_id = supergroup_id;
+    pub fn supergroup_id<T: Into<SupergroupId>>(&mut self, supergroup_id: T) -> &mut Self {
+        self.inner.supergroup_id = supergroup_id.into();
+        self.missing.retain(|f| *f != "supergroup_id");
         self
     }
 }
@@ -1266,12 +1985,13 @@ impl AsRef<MessageChatUpgradeTo> for RTDMessageChatUpgradeToBuilder {
 
 /// A message with a user contact
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "messageContact")]
 pub struct MessageContact {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The contact description
     contact: Contact,
@@ -1291,6 +2011,20 @@ impl RObject for MessageContact {
     }
 }
 
+impl PartialEq for MessageContact {
+    fn eq(&self, other: &Self) -> bool {
+        self.contact == other.contact
+    }
+}
+
+impl Eq for MessageContact {}
+
+impl std::hash::Hash for MessageContact {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.contact, state);
+    }
+}
+
 impl TDMessageContent for MessageContact {}
 
 impl MessageContact {
@@ -1298,29 +2032,66 @@ impl MessageContact {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessageContactBuilder {
-        let mut inner = MessageContact::default();
-        inner.td_name = "messageContact".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessageContactBuilder { inner }
+        RTDMessageContactBuilder {
+            inner: MessageContact::default(),
+            missing: vec!["contact"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessageContactBuilder {
+        RTDMessageContactBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessageContactBuilder {
+        RTDMessageContactBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn contact(&self) -> &Contact {
         &self.contact
     }
+
+    pub fn into_contact(self) -> Contact {
+        self.contact
+    }
+
+    pub fn with_contact<T: AsRef<Contact>>(mut self, contact: T) -> Self {
+        self.contact = contact.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDMessageContactBuilder {
     inner: MessageContact,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessageContactBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MessageContact {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MessageContact> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "MessageContact",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn contact<T: AsRef<Contact>>(&mut self, contact: T) -> &mut Self {
         self.inner.contact = contact.as_ref().clone();
+        self.missing.retain(|f| *f != "contact");
         self
     }
 }
@@ -1339,12 +2110,13 @@ impl AsRef<MessageContact> for RTDMessageContactBuilder {
 
 /// A contact has registered with Telegram
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "messageContactRegistered")]
 pub struct MessageContactRegistered {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -1362,6 +2134,18 @@ impl RObject for MessageContactRegistered {
     }
 }
 
+impl PartialEq for MessageContactRegistered {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for MessageContactRegistered {}
+
+impl std::hash::Hash for MessageContactRegistered {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDMessageContent for MessageContactRegistered {}
 
 impl MessageContactRegistered {
@@ -1369,22 +2153,49 @@ impl MessageContactRegistered {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessageContactRegisteredBuilder {
-        let mut inner = MessageContactRegistered::default();
-        inner.td_name = "messageContactRegistered".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessageContactRegisteredBuilder { inner }
+        RTDMessageContactRegisteredBuilder {
+            inner: MessageContactRegistered::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessageContactRegisteredBuilder {
+        RTDMessageContactRegisteredBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessageContactRegisteredBuilder {
+        RTDMessageContactRegisteredBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDMessageContactRegisteredBuilder {
     inner: MessageContactRegistered,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessageContactRegisteredBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MessageContactRegistered {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MessageContactRegistered> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "MessageContactRegistered",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<MessageContactRegistered> for MessageContactRegistered {
@@ -1401,12 +2212,13 @@ impl AsRef<MessageContactRegistered> for RTDMessageContactRegisteredBuilder {
 
 /// A non-standard action has happened in the chat
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "messageCustomServiceAction")]
 pub struct MessageCustomServiceAction {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Message text to be shown in the chat
     text: String,
@@ -1426,6 +2238,20 @@ impl RObject for MessageCustomServiceAction {
     }
 }
 
+impl PartialEq for MessageCustomServiceAction {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text
+    }
+}
+
+impl Eq for MessageCustomServiceAction {}
+
+impl std::hash::Hash for MessageCustomServiceAction {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.text, state);
+    }
+}
+
 impl TDMessageContent for MessageCustomServiceAction {}
 
 impl MessageCustomServiceAction {
@@ -1433,29 +2259,66 @@ impl MessageCustomServiceAction {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessageCustomServiceActionBuilder {
-        let mut inner = MessageCustomServiceAction::default();
-        inner.td_name = "messageCustomServiceAction".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessageCustomServiceActionBuilder { inner }
+        RTDMessageCustomServiceActionBuilder {
+            inner: MessageCustomServiceAction::default(),
+            missing: vec!["text"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessageCustomServiceActionBuilder {
+        RTDMessageCustomServiceActionBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessageCustomServiceActionBuilder {
+        RTDMessageCustomServiceActionBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn text(&self) -> &String {
         &self.text
     }
+
+    pub fn into_text(self) -> String {
+        self.text
+    }
+
+    pub fn with_text<T: AsRef<str>>(mut self, text: T) -> Self {
+        self.text = text.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDMessageCustomServiceActionBuilder {
     inner: MessageCustomServiceAction,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessageCustomServiceActionBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MessageCustomServiceAction {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MessageCustomServiceAction> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "MessageCustomServiceAction",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn text<T: AsRef<str>>(&mut self, text: T) -> &mut Self {
         self.inner.text = text.as_ref().to_string();
+        self.missing.retain(|f| *f != "text");
         self
     }
 }
@@ -1474,12 +2337,13 @@ impl AsRef<MessageCustomServiceAction> for RTDMessageCustomServiceActionBuilder
 
 /// A document message (general file)
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "messageDocument")]
 pub struct MessageDocument {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The document description
     document: Document,
@@ -1501,6 +2365,21 @@ impl RObject for MessageDocument {
     }
 }
 
+impl PartialEq for MessageDocument {
+    fn eq(&self, other: &Self) -> bool {
+        self.document == other.document && self.caption == other.caption
+    }
+}
+
+impl Eq for MessageDocument {}
+
+impl std::hash::Hash for MessageDocument {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.document, state);
+        std::hash::Hash::hash(&self.caption, state);
+    }
+}
+
 impl TDMessageContent for MessageDocument {}
 
 impl MessageDocument {
@@ -1508,10 +2387,24 @@ impl MessageDocument {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessageDocumentBuilder {
-        let mut inner = MessageDocument::default();
-        inner.td_name = "messageDocument".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessageDocumentBuilder { inner }
+        RTDMessageDocumentBuilder {
+            inner: MessageDocument::default(),
+            missing: vec!["document", "caption"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessageDocumentBuilder {
+        RTDMessageDocumentBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessageDocumentBuilder {
+        RTDMessageDocumentBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn document(&self) -> &Document {
@@ -1521,25 +2414,58 @@ impl MessageDocument {
     pub fn caption(&self) -> &FormattedText {
         &self.caption
     }
+
+    pub fn into_document(self) -> Document {
+        self.document
+    }
+
+    pub fn into_caption(self) -> FormattedText {
+        self.caption
+    }
+
+    pub fn with_document<T: AsRef<Document>>(mut self, document: T) -> Self {
+        self.document = document.as_ref().clone();
+        self
+    }
+
+    pub fn with_caption<T: AsRef<FormattedText>>(mut self, caption: T) -> Self {
+        self.caption = caption.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDMessageDocumentBuilder {
     inner: MessageDocument,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessageDocumentBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MessageDocument {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MessageDocument> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "MessageDocument",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn document<T: AsRef<Document>>(&mut self, document: T) -> &mut Self {
         self.inner.document = document.as_ref().clone();
+        self.missing.retain(|f| *f != "document");
         self
     }
 
     pub fn caption<T: AsRef<FormattedText>>(&mut self, caption: T) -> &mut Self {
         self.inner.caption = caption.as_ref().clone();
+        self.missing.retain(|f| *f != "caption");
         self
     }
 }
@@ -1558,12 +2484,13 @@ impl AsRef<MessageDocument> for RTDMessageDocumentBuilder {
 
 /// An expired photo message (self-destructed after TTL has elapsed)
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "messageExpiredPhoto")]
 pub struct MessageExpiredPhoto {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -1581,6 +2508,18 @@ impl RObject for MessageExpiredPhoto {
     }
 }
 
+impl PartialEq for MessageExpiredPhoto {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for MessageExpiredPhoto {}
+
+impl std::hash::Hash for MessageExpiredPhoto {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDMessageContent for MessageExpiredPhoto {}
 
 impl MessageExpiredPhoto {
@@ -1588,22 +2527,49 @@ impl MessageExpiredPhoto {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessageExpiredPhotoBuilder {
-        let mut inner = MessageExpiredPhoto::default();
-        inner.td_name = "messageExpiredPhoto".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessageExpiredPhotoBuilder { inner }
+        RTDMessageExpiredPhotoBuilder {
+            inner: MessageExpiredPhoto::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessageExpiredPhotoBuilder {
+        RTDMessageExpiredPhotoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessageExpiredPhotoBuilder {
+        RTDMessageExpiredPhotoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDMessageExpiredPhotoBuilder {
     inner: MessageExpiredPhoto,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessageExpiredPhotoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MessageExpiredPhoto {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MessageExpiredPhoto> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "MessageExpiredPhoto",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<MessageExpiredPhoto> for MessageExpiredPhoto {
@@ -1620,12 +2586,13 @@ impl AsRef<MessageExpiredPhoto> for RTDMessageExpiredPhotoBuilder {
 
 /// An expired video message (self-destructed after TTL has elapsed)
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "messageExpiredVideo")]
 pub struct MessageExpiredVideo {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -1643,6 +2610,18 @@ impl RObject for MessageExpiredVideo {
     }
 }
 
+impl PartialEq for MessageExpiredVideo {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for MessageExpiredVideo {}
+
+impl std::hash::Hash for MessageExpiredVideo {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDMessageContent for MessageExpiredVideo {}
 
 impl MessageExpiredVideo {
@@ -1650,22 +2629,49 @@ impl MessageExpiredVideo {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessageExpiredVideoBuilder {
-        let mut inner = MessageExpiredVideo::default();
-        inner.td_name = "messageExpiredVideo".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessageExpiredVideoBuilder { inner }
+        RTDMessageExpiredVideoBuilder {
+            inner: MessageExpiredVideo::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessageExpiredVideoBuilder {
+        RTDMessageExpiredVideoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessageExpiredVideoBuilder {
+        RTDMessageExpiredVideoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDMessageExpiredVideoBuilder {
     inner: MessageExpiredVideo,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessageExpiredVideoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MessageExpiredVideo {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MessageExpiredVideo> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "MessageExpiredVideo",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<MessageExpiredVideo> for MessageExpiredVideo {
@@ -1682,12 +2688,13 @@ impl AsRef<MessageExpiredVideo> for RTDMessageExpiredVideoBuilder {
 
 /// A message with a game
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "messageGame")]
 pub struct MessageGame {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The game description
     game: Game,
@@ -1707,6 +2714,20 @@ impl RObject for MessageGame {
     }
 }
 
+impl PartialEq for MessageGame {
+    fn eq(&self, other: &Self) -> bool {
+        self.game == other.game
+    }
+}
+
+impl Eq for MessageGame {}
+
+impl std::hash::Hash for MessageGame {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.game, state);
+    }
+}
+
 impl TDMessageContent for MessageGame {}
 
 impl MessageGame {
@@ -1714,29 +2735,63 @@ impl MessageGame {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessageGameBuilder {
-        let mut inner = MessageGame::default();
-        inner.td_name = "messageGame".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessageGameBuilder { inner }
+        RTDMessageGameBuilder {
+            inner: MessageGame::default(),
+            missing: vec!["game"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessageGameBuilder {
+        RTDMessageGameBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessageGameBuilder {
+        RTDMessageGameBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn game(&self) -> &Game {
         &self.game
     }
+
+    pub fn into_game(self) -> Game {
+        self.game
+    }
+
+    pub fn with_game<T: AsRef<Game>>(mut self, game: T) -> Self {
+        self.game = game.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDMessageGameBuilder {
     inner: MessageGame,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessageGameBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MessageGame {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MessageGame> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("MessageGame", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn game<T: AsRef<Game>>(&mut self, game: T) -> &mut Self {
         self.inner.game = game.as_ref().clone();
+        self.missing.retain(|f| *f != "game");
         self
     }
 }
@@ -1755,19 +2810,21 @@ impl AsRef<MessageGame> for RTDMessageGameBuilder {
 
 /// A new high score was achieved in a game
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "messageGameScore")]
 pub struct MessageGameScore {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Identifier of the message with the game, can be an identifier of a deleted message
-    game_message_id: i64,
+    game_message_id: MessageId,
     /// Identifier of the game; may be different from the games presented in the message with the game
-    game_id: isize,
+    #[serde(with = "super::_common::int64")]
+    game_id: i64,
     /// New score
-    score: i64,
+    score: i32,
 }
 
 impl RObject for MessageGameScore {
@@ -1784,6 +2841,24 @@ impl RObject for MessageGameScore {
     }
 }
 
+impl PartialEq for MessageGameScore {
+    fn eq(&self, other: &Self) -> bool {
+        self.game_message_id == other.game_message_id
+            && self.game_id == other.game_id
+            && self.score == other.score
+    }
+}
+
+impl Eq for MessageGameScore {}
+
+impl std::hash::Hash for MessageGameScore {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.game_message_id, state);
+        std::hash::Hash::hash(&self.game_id, state);
+        std::hash::Hash::hash(&self.score, state);
+    }
+}
+
 impl TDMessageContent for MessageGameScore {}
 
 impl MessageGameScore {
@@ -1791,47 +2866,92 @@ impl MessageGameScore {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessageGameScoreBuilder {
-        let mut inner = MessageGameScore::default();
-        inner.td_name = "messageGameScore".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessageGameScoreBuilder { inner }
+        RTDMessageGameScoreBuilder {
+            inner: MessageGameScore::default(),
+            missing: vec!["game_message_id", "game_id", "score"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessageGameScoreBuilder {
+        RTDMessageGameScoreBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessageGameScoreBuilder {
+        RTDMessageGameScoreBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn game_message_id(&self) -> i64 {
+    pub fn game_message_id(&self) -> MessageId {
         self.game_message_id
     }
 
-    pub fn game_id(&self) -> isize {
+    pub fn game_id(&self) -> i64 {
         self.game_id
     }
 
-    pub fn score(&self) -> i64 {
+    pub fn score(&self) -> i32 {
         self.score
     }
+
+    pub fn with_game_message_id<T: Into<MessageId>>(mut self, game_message_id: T) -> Self {
+        self.game_message_id = game_message_id.into();
+        self
+    }
+
+    pub fn with_game_id(mut self, game_id: i64) -> Self {
+        self.game_id = game_id;
+        self
+    }
+
+    pub fn with_score(mut self, score: i32) -> Self {
+        self.score = score;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDMessageGameScoreBuilder {
     inner: MessageGameScore,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessageGameScoreBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MessageGameScore {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MessageGameScore> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "MessageGameScore",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn game_message_id(&mut self, game_message_id: i64) -> &mut Self {
-        self.inner.game_message_id = game_message_id;
+    pub fn game_message_id<T: Into<MessageId>>(&mut self, game_message_id: T) -> &mut Self {
+        self.inner.game_message_id = game_message_id.into();
+        self.missing.retain(|f| *f != "game_message_id");
         self
     }
 
-    pub fn game_id(&mut self, game_id: isize) -> &mut Self {
+    pub fn game_id(&mut self, game_id: i64) -> &mut Self {
         self.inner.game_id = game_id;
+        self.missing.retain(|f| *f != "game_id");
         self
     }
 
-    pub fn score(&mut self, score: i64) -> &mut Self {
+    pub fn score(&mut self, score: i32) -> &mut Self {
         self.inner.score = score;
+        self.missing.retain(|f| *f != "score");
         self
     }
 }
@@ -1850,12 +2970,13 @@ impl AsRef<MessageGameScore> for RTDMessageGameScoreBuilder {
 
 /// A message with an invoice from a bot
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "messageInvoice")]
 pub struct MessageInvoice {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Product title
     title: String,
@@ -1874,7 +2995,7 @@ pub struct MessageInvoice {
     /// True, if the shipping address should be specified
     need_shipping_address: bool,
     /// The identifier of the message with the receipt, after the product has been purchased
-    receipt_message_id: i64,
+    receipt_message_id: MessageId,
 }
 
 impl RObject for MessageInvoice {
@@ -1891,6 +3012,36 @@ impl RObject for MessageInvoice {
     }
 }
 
+impl PartialEq for MessageInvoice {
+    fn eq(&self, other: &Self) -> bool {
+        self.title == other.title
+            && self.description == other.description
+            && self.photo == other.photo
+            && self.currency == other.currency
+            && self.total_amount == other.total_amount
+            && self.start_parameter == other.start_parameter
+            && self.is_test == other.is_test
+            && self.need_shipping_address == other.need_shipping_address
+            && self.receipt_message_id == other.receipt_message_id
+    }
+}
+
+impl Eq for MessageInvoice {}
+
+impl std::hash::Hash for MessageInvoice {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.title, state);
+        std::hash::Hash::hash(&self.description, state);
+        std::hash::Hash::hash(&self.photo, state);
+        std::hash::Hash::hash(&self.currency, state);
+        std::hash::Hash::hash(&self.total_amount, state);
+        std::hash::Hash::hash(&self.start_parameter, state);
+        std::hash::Hash::hash(&self.is_test, state);
+        std::hash::Hash::hash(&self.need_shipping_address, state);
+        std::hash::Hash::hash(&self.receipt_message_id, state);
+    }
+}
+
 impl TDMessageContent for MessageInvoice {}
 
 impl MessageInvoice {
@@ -1898,10 +3049,31 @@ impl MessageInvoice {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessageInvoiceBuilder {
-        let mut inner = MessageInvoice::default();
-        inner.td_name = "messageInvoice".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessageInvoiceBuilder { inner }
+        RTDMessageInvoiceBuilder {
+            inner: MessageInvoice::default(),
+            missing: vec![
+                "title",
+                "description",
+                "currency",
+                "total_amount",
+                "start_parameter",
+                "receipt_message_id",
+            ],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessageInvoiceBuilder {
+        RTDMessageInvoiceBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessageInvoiceBuilder {
+        RTDMessageInvoiceBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn title(&self) -> &String {
@@ -1936,28 +3108,108 @@ impl MessageInvoice {
         self.need_shipping_address
     }
 
-    pub fn receipt_message_id(&self) -> i64 {
+    pub fn receipt_message_id(&self) -> MessageId {
         self.receipt_message_id
     }
+
+    pub fn into_title(self) -> String {
+        self.title
+    }
+
+    pub fn into_description(self) -> String {
+        self.description
+    }
+
+    pub fn into_photo(self) -> Option<Photo> {
+        self.photo
+    }
+
+    pub fn into_currency(self) -> String {
+        self.currency
+    }
+
+    pub fn into_start_parameter(self) -> String {
+        self.start_parameter
+    }
+
+    pub fn with_title<T: AsRef<str>>(mut self, title: T) -> Self {
+        self.title = title.as_ref().to_string();
+        self
+    }
+
+    pub fn with_description<T: AsRef<str>>(mut self, description: T) -> Self {
+        self.description = description.as_ref().to_string();
+        self
+    }
+
+    pub fn with_photo<T: AsRef<Photo>>(mut self, photo: T) -> Self {
+        self.photo = Some(photo.as_ref().clone());
+        self
+    }
+
+    pub fn with_currency<T: AsRef<str>>(mut self, currency: T) -> Self {
+        self.currency = currency.as_ref().to_string();
+        self
+    }
+
+    pub fn with_total_amount(mut self, total_amount: i64) -> Self {
+        self.total_amount = total_amount;
+        self
+    }
+
+    pub fn with_start_parameter<T: AsRef<str>>(mut self, start_parameter: T) -> Self {
+        self.start_parameter = start_parameter.as_ref().to_string();
+        self
+    }
+
+    pub fn with_is_test(mut self, is_test: bool) -> Self {
+        self.is_test = is_test;
+        self
+    }
+
+    pub fn with_need_shipping_address(mut self, need_shipping_address: bool) -> Self {
+        self.need_shipping_address = need_shipping_address;
+        self
+    }
+
+    pub fn with_receipt_message_id<T: Into<MessageId>>(mut self, receipt_message_id: T) -> Self {
+        self.receipt_message_id = receipt_message_id.into();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDMessageInvoiceBuilder {
     inner: MessageInvoice,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessageInvoiceBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MessageInvoice {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MessageInvoice> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "MessageInvoice",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
         self.inner.title = title.as_ref().to_string();
+        self.missing.retain(|f| *f != "title");
         self
     }
 
     pub fn description<T: AsRef<str>>(&mut self, description: T) -> &mut Self {
         self.inner.description = description.as_ref().to_string();
+        self.missing.retain(|f| *f != "description");
         self
     }
 
@@ -1968,16 +3220,19 @@ impl RTDMessageInvoiceBuilder {
 
     pub fn currency<T: AsRef<str>>(&mut self, currency: T) -> &mut Self {
         self.inner.currency = currency.as_ref().to_string();
+        self.missing.retain(|f| *f != "currency");
         self
     }
 
     pub fn total_amount(&mut self, total_amount: i64) -> &mut Self {
         self.inner.total_amount = total_amount;
+        self.missing.retain(|f| *f != "total_amount");
         self
     }
 
     pub fn start_parameter<T: AsRef<str>>(&mut self, start_parameter: T) -> &mut Self {
         self.inner.start_parameter = start_parameter.as_ref().to_string();
+        self.missing.retain(|f| *f != "start_parameter");
         self
     }
 
@@ -1991,8 +3246,9 @@ impl RTDMessageInvoiceBuilder {
         self
     }
 
-    pub fn receipt_message_id(&mut self, receipt_message_id: i64) -> &mut Self {
-        self.inner.receipt_message_id = receipt_message_id;
+    pub fn receipt_message_id<T: Into<MessageId>>(&mut self, receipt_message_id: T) -> &mut Self {
+        self.inner.receipt_message_id = receipt_message_id.into();
+        self.missing.retain(|f| *f != "receipt_message_id");
         self
     }
 }
@@ -2011,19 +3267,20 @@ impl AsRef<MessageInvoice> for RTDMessageInvoiceBuilder {
 
 /// A message with a location
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "messageLocation")]
 pub struct MessageLocation {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The location description
     location: Location,
     /// Time relative to the message sent date until which the location can be updated, in seconds
-    live_period: i64,
+    live_period: i32,
     /// Left time for which the location can be updated, in seconds. updateMessageContent is not sent when this field changes
-    expires_in: i64,
+    expires_in: i32,
 }
 
 impl RObject for MessageLocation {
@@ -2040,6 +3297,14 @@ impl RObject for MessageLocation {
     }
 }
 
+impl PartialEq for MessageLocation {
+    fn eq(&self, other: &Self) -> bool {
+        self.location == other.location
+            && self.live_period == other.live_period
+            && self.expires_in == other.expires_in
+    }
+}
+
 impl TDMessageContent for MessageLocation {}
 
 impl MessageLocation {
@@ -2047,47 +3312,96 @@ impl MessageLocation {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessageLocationBuilder {
-        let mut inner = MessageLocation::default();
-        inner.td_name = "messageLocation".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessageLocationBuilder { inner }
+        RTDMessageLocationBuilder {
+            inner: MessageLocation::default(),
+            missing: vec!["location", "live_period", "expires_in"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessageLocationBuilder {
+        RTDMessageLocationBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessageLocationBuilder {
+        RTDMessageLocationBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn location(&self) -> &Location {
         &self.location
     }
 
-    pub fn live_period(&self) -> i64 {
+    pub fn live_period(&self) -> i32 {
         self.live_period
     }
 
-    pub fn expires_in(&self) -> i64 {
+    pub fn expires_in(&self) -> i32 {
         self.expires_in
     }
+
+    pub fn into_location(self) -> Location {
+        self.location
+    }
+
+    pub fn with_location<T: AsRef<Location>>(mut self, location: T) -> Self {
+        self.location = location.as_ref().clone();
+        self
+    }
+
+    pub fn with_live_period(mut self, live_period: i32) -> Self {
+        self.live_period = live_period;
+        self
+    }
+
+    pub fn with_expires_in(mut self, expires_in: i32) -> Self {
+        self.expires_in = expires_in;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDMessageLocationBuilder {
     inner: MessageLocation,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessageLocationBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MessageLocation {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MessageLocation> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "MessageLocation",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn location<T: AsRef<Location>>(&mut self, location: T) -> &mut Self {
         self.inner.location = location.as_ref().clone();
+        self.missing.retain(|f| *f != "location");
         self
     }
 
-    pub fn live_period(&mut self, live_period: i64) -> &mut Self {
+    pub fn live_period(&mut self, live_period: i32) -> &mut Self {
         self.inner.live_period = live_period;
+        self.missing.retain(|f| *f != "live_period");
         self
     }
 
-    pub fn expires_in(&mut self, expires_in: i64) -> &mut Self {
+    pub fn expires_in(&mut self, expires_in: i32) -> &mut Self {
         self.inner.expires_in = expires_in;
+        self.missing.retain(|f| *f != "expires_in");
         self
     }
 }
@@ -2106,12 +3420,13 @@ impl AsRef<MessageLocation> for RTDMessageLocationBuilder {
 
 /// Telegram Passport data has been received; for bots only
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "messagePassportDataReceived")]
 pub struct MessagePassportDataReceived {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// List of received Telegram Passport elements
     elements: Vec<EncryptedPassportElement>,
@@ -2133,6 +3448,21 @@ impl RObject for MessagePassportDataReceived {
     }
 }
 
+impl PartialEq for MessagePassportDataReceived {
+    fn eq(&self, other: &Self) -> bool {
+        self.elements == other.elements && self.credentials == other.credentials
+    }
+}
+
+impl Eq for MessagePassportDataReceived {}
+
+impl std::hash::Hash for MessagePassportDataReceived {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.elements, state);
+        std::hash::Hash::hash(&self.credentials, state);
+    }
+}
+
 impl TDMessageContent for MessagePassportDataReceived {}
 
 impl MessagePassportDataReceived {
@@ -2140,10 +3470,24 @@ impl MessagePassportDataReceived {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessagePassportDataReceivedBuilder {
-        let mut inner = MessagePassportDataReceived::default();
-        inner.td_name = "messagePassportDataReceived".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessagePassportDataReceivedBuilder { inner }
+        RTDMessagePassportDataReceivedBuilder {
+            inner: MessagePassportDataReceived::default(),
+            missing: vec!["elements", "credentials"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessagePassportDataReceivedBuilder {
+        RTDMessagePassportDataReceivedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessagePassportDataReceivedBuilder {
+        RTDMessagePassportDataReceivedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn elements(&self) -> &Vec<EncryptedPassportElement> {
@@ -2153,25 +3497,58 @@ impl MessagePassportDataReceived {
     pub fn credentials(&self) -> &EncryptedCredentials {
         &self.credentials
     }
+
+    pub fn into_elements(self) -> Vec<EncryptedPassportElement> {
+        self.elements
+    }
+
+    pub fn into_credentials(self) -> EncryptedCredentials {
+        self.credentials
+    }
+
+    pub fn with_elements(mut self, elements: Vec<EncryptedPassportElement>) -> Self {
+        self.elements = elements;
+        self
+    }
+
+    pub fn with_credentials<T: AsRef<EncryptedCredentials>>(mut self, credentials: T) -> Self {
+        self.credentials = credentials.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDMessagePassportDataReceivedBuilder {
     inner: MessagePassportDataReceived,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessagePassportDataReceivedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MessagePassportDataReceived {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MessagePassportDataReceived> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "MessagePassportDataReceived",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn elements(&mut self, elements: Vec<EncryptedPassportElement>) -> &mut Self {
         self.inner.elements = elements;
+        self.missing.retain(|f| *f != "elements");
         self
     }
 
     pub fn credentials<T: AsRef<EncryptedCredentials>>(&mut self, credentials: T) -> &mut Self {
         self.inner.credentials = credentials.as_ref().clone();
+        self.missing.retain(|f| *f != "credentials");
         self
     }
 }
@@ -2190,12 +3567,13 @@ impl AsRef<MessagePassportDataReceived> for RTDMessagePassportDataReceivedBuilde
 
 /// Telegram Passport data has been sent
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "messagePassportDataSent")]
 pub struct MessagePassportDataSent {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// List of Telegram Passport element types sent
     types: Vec<PassportElementType>,
@@ -2215,6 +3593,20 @@ impl RObject for MessagePassportDataSent {
     }
 }
 
+impl PartialEq for MessagePassportDataSent {
+    fn eq(&self, other: &Self) -> bool {
+        self.types == other.types
+    }
+}
+
+impl Eq for MessagePassportDataSent {}
+
+impl std::hash::Hash for MessagePassportDataSent {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.types, state);
+    }
+}
+
 impl TDMessageContent for MessagePassportDataSent {}
 
 impl MessagePassportDataSent {
@@ -2222,29 +3614,66 @@ impl MessagePassportDataSent {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessagePassportDataSentBuilder {
-        let mut inner = MessagePassportDataSent::default();
-        inner.td_name = "messagePassportDataSent".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessagePassportDataSentBuilder { inner }
+        RTDMessagePassportDataSentBuilder {
+            inner: MessagePassportDataSent::default(),
+            missing: vec!["types"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessagePassportDataSentBuilder {
+        RTDMessagePassportDataSentBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessagePassportDataSentBuilder {
+        RTDMessagePassportDataSentBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn types(&self) -> &Vec<PassportElementType> {
         &self.types
     }
+
+    pub fn into_types(self) -> Vec<PassportElementType> {
+        self.types
+    }
+
+    pub fn with_types(mut self, types: Vec<PassportElementType>) -> Self {
+        self.types = types;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDMessagePassportDataSentBuilder {
     inner: MessagePassportDataSent,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessagePassportDataSentBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MessagePassportDataSent {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MessagePassportDataSent> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "MessagePassportDataSent",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn types(&mut self, types: Vec<PassportElementType>) -> &mut Self {
         self.inner.types = types;
+        self.missing.retain(|f| *f != "types");
         self
     }
 }
@@ -2263,15 +3692,16 @@ impl AsRef<MessagePassportDataSent> for RTDMessagePassportDataSentBuilder {
 
 /// A payment has been completed
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "messagePaymentSuccessful")]
 pub struct MessagePaymentSuccessful {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Identifier of the message with the corresponding invoice; can be an identifier of a deleted message
-    invoice_message_id: i64,
+    invoice_message_id: MessageId,
     /// Currency for the price of the product
     currency: String,
     /// Total price for the product, in the minimal quantity of the currency
@@ -2292,6 +3722,24 @@ impl RObject for MessagePaymentSuccessful {
     }
 }
 
+impl PartialEq for MessagePaymentSuccessful {
+    fn eq(&self, other: &Self) -> bool {
+        self.invoice_message_id == other.invoice_message_id
+            && self.currency == other.currency
+            && self.total_amount == other.total_amount
+    }
+}
+
+impl Eq for MessagePaymentSuccessful {}
+
+impl std::hash::Hash for MessagePaymentSuccessful {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.invoice_message_id, state);
+        std::hash::Hash::hash(&self.currency, state);
+        std::hash::Hash::hash(&self.total_amount, state);
+    }
+}
+
 impl TDMessageContent for MessagePaymentSuccessful {}
 
 impl MessagePaymentSuccessful {
@@ -2299,13 +3747,27 @@ impl MessagePaymentSuccessful {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessagePaymentSuccessfulBuilder {
-        let mut inner = MessagePaymentSuccessful::default();
-        inner.td_name = "messagePaymentSuccessful".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessagePaymentSuccessfulBuilder { inner }
+        RTDMessagePaymentSuccessfulBuilder {
+            inner: MessagePaymentSuccessful::default(),
+            missing: vec!["invoice_message_id", "currency", "total_amount"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessagePaymentSuccessfulBuilder {
+        RTDMessagePaymentSuccessfulBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessagePaymentSuccessfulBuilder {
+        RTDMessagePaymentSuccessfulBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn invoice_message_id(&self) -> i64 {
+    pub fn invoice_message_id(&self) -> MessageId {
         self.invoice_message_id
     }
 
@@ -2316,30 +3778,65 @@ impl MessagePaymentSuccessful {
     pub fn total_amount(&self) -> i64 {
         self.total_amount
     }
+
+    pub fn into_currency(self) -> String {
+        self.currency
+    }
+
+    pub fn with_invoice_message_id<T: Into<MessageId>>(mut self, invoice_message_id: T) -> Self {
+        self.invoice_message_id = invoice_message_id.into();
+        self
+    }
+
+    pub fn with_currency<T: AsRef<str>>(mut self, currency: T) -> Self {
+        self.currency = currency.as_ref().to_string();
+        self
+    }
+
+    pub fn with_total_amount(mut self, total_amount: i64) -> Self {
+        self.total_amount = total_amount;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDMessagePaymentSuccessfulBuilder {
     inner: MessagePaymentSuccessful,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessagePaymentSuccessfulBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MessagePaymentSuccessful {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MessagePaymentSuccessful> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "MessagePaymentSuccessful",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn invoice_message_id(&mut self, invoice_message_id: i64) -> &mut Self {
-        self.inner.invoice_message_id = invoice_message_id;
+    pub fn invoice_message_id<T: Into<MessageId>>(&mut self, invoice_message_id: T) -> &mut Self {
+        self.inner.invoice_message_id = invoice_message_id.into();
+        self.missing.retain(|f| *f != "invoice_message_id");
         self
     }
 
     pub fn currency<T: AsRef<str>>(&mut self, currency: T) -> &mut Self {
         self.inner.currency = currency.as_ref().to_string();
+        self.missing.retain(|f| *f != "currency");
         self
     }
 
     pub fn total_amount(&mut self, total_amount: i64) -> &mut Self {
         self.inner.total_amount = total_amount;
+        self.missing.retain(|f| *f != "total_amount");
         self
     }
 }
@@ -2358,21 +3855,23 @@ impl AsRef<MessagePaymentSuccessful> for RTDMessagePaymentSuccessfulBuilder {
 
 /// A payment has been completed; for bots only
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "messagePaymentSuccessfulBot")]
 pub struct MessagePaymentSuccessfulBot {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Identifier of the message with the corresponding invoice; can be an identifier of a deleted message
-    invoice_message_id: i64,
+    invoice_message_id: MessageId,
     /// Currency for price of the product
     currency: String,
     /// Total price for the product, in the minimal quantity of the currency
     total_amount: i64,
     /// Invoice payload
-    invoice_payload: String,
+    #[serde(with = "super::_common::bytes")]
+    invoice_payload: Vec<u8>,
     /// Identifier of the shipping option chosen by the user; may be empty if not applicable
     shipping_option_id: String,
     /// Information about the order; may be null
@@ -2397,6 +3896,34 @@ impl RObject for MessagePaymentSuccessfulBot {
     }
 }
 
+impl PartialEq for MessagePaymentSuccessfulBot {
+    fn eq(&self, other: &Self) -> bool {
+        self.invoice_message_id == other.invoice_message_id
+            && self.currency == other.currency
+            && self.total_amount == other.total_amount
+            && self.invoice_payload == other.invoice_payload
+            && self.shipping_option_id == other.shipping_option_id
+            && self.order_info == other.order_info
+            && self.telegram_payment_charge_id == other.telegram_payment_charge_id
+            && self.provider_payment_charge_id == other.provider_payment_charge_id
+    }
+}
+
+impl Eq for MessagePaymentSuccessfulBot {}
+
+impl std::hash::Hash for MessagePaymentSuccessfulBot {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.invoice_message_id, state);
+        std::hash::Hash::hash(&self.currency, state);
+        std::hash::Hash::hash(&self.total_amount, state);
+        std::hash::Hash::hash(&self.invoice_payload, state);
+        std::hash::Hash::hash(&self.shipping_option_id, state);
+        std::hash::Hash::hash(&self.order_info, state);
+        std::hash::Hash::hash(&self.telegram_payment_charge_id, state);
+        std::hash::Hash::hash(&self.provider_payment_charge_id, state);
+    }
+}
+
 impl TDMessageContent for MessagePaymentSuccessfulBot {}
 
 impl MessagePaymentSuccessfulBot {
@@ -2404,13 +3931,34 @@ impl MessagePaymentSuccessfulBot {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessagePaymentSuccessfulBotBuilder {
-        let mut inner = MessagePaymentSuccessfulBot::default();
-        inner.td_name = "messagePaymentSuccessfulBot".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessagePaymentSuccessfulBotBuilder { inner }
+        RTDMessagePaymentSuccessfulBotBuilder {
+            inner: MessagePaymentSuccessfulBot::default(),
+            missing: vec![
+                "invoice_message_id",
+                "currency",
+                "total_amount",
+                "invoice_payload",
+                "telegram_payment_charge_id",
+                "provider_payment_charge_id",
+            ],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessagePaymentSuccessfulBotBuilder {
+        RTDMessagePaymentSuccessfulBotBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessagePaymentSuccessfulBotBuilder {
+        RTDMessagePaymentSuccessfulBotBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn invoice_message_id(&self) -> i64 {
+    pub fn invoice_message_id(&self) -> MessageId {
         self.invoice_message_id
     }
 
@@ -2422,7 +3970,7 @@ impl MessagePaymentSuccessfulBot {
         self.total_amount
     }
 
-    pub fn invoice_payload(&self) -> &String {
+    pub fn invoice_payload(&self) -> &[u8] {
         &self.invoice_payload
     }
 
@@ -2441,35 +3989,122 @@ impl MessagePaymentSuccessfulBot {
     pub fn provider_payment_charge_id(&self) -> &String {
         &self.provider_payment_charge_id
     }
+
+    pub fn into_currency(self) -> String {
+        self.currency
+    }
+
+    pub fn into_invoice_payload(self) -> Vec<u8> {
+        self.invoice_payload
+    }
+
+    pub fn into_shipping_option_id(self) -> String {
+        self.shipping_option_id
+    }
+
+    pub fn into_order_info(self) -> Option<OrderInfo> {
+        self.order_info
+    }
+
+    pub fn into_telegram_payment_charge_id(self) -> String {
+        self.telegram_payment_charge_id
+    }
+
+    pub fn into_provider_payment_charge_id(self) -> String {
+        self.provider_payment_charge_id
+    }
+
+    pub fn with_invoice_message_id<T: Into<MessageId>>(mut self, invoice_message_id: T) -> Self {
+        self.invoice_message_id = invoice_message_id.into();
+        self
+    }
+
+    pub fn with_currency<T: AsRef<str>>(mut self, currency: T) -> Self {
+        self.currency = currency.as_ref().to_string();
+        self
+    }
+
+    pub fn with_total_amount(mut self, total_amount: i64) -> Self {
+        self.total_amount = total_amount;
+        self
+    }
+
+    pub fn with_invoice_payload<T: AsRef<[u8]>>(mut self, invoice_payload: T) -> Self {
+        self.invoice_payload = invoice_payload.as_ref().to_vec();
+        self
+    }
+
+    pub fn with_shipping_option_id<T: AsRef<str>>(mut self, shipping_option_id: T) -> Self {
+        self.shipping_option_id = shipping_option_id.as_ref().to_string();
+        self
+    }
+
+    pub fn with_order_info<T: AsRef<OrderInfo>>(mut self, order_info: T) -> Self {
+        self.order_info = Some(order_info.as_ref().clone());
+        self
+    }
+
+    pub fn with_telegram_payment_charge_id<T: AsRef<str>>(
+        mut self,
+        telegram_payment_charge_id: T,
+    ) -> Self {
+        self.telegram_payment_charge_id = telegram_payment_charge_id.as_ref().to_string();
+        self
+    }
+
+    pub fn with_provider_payment_charge_id<T: AsRef<str>>(
+        mut self,
+        provider_payment_charge_id: T,
+    ) -> Self {
+        self.provider_payment_charge_id = provider_payment_charge_id.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDMessagePaymentSuccessfulBotBuilder {
     inner: MessagePaymentSuccessfulBot,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessagePaymentSuccessfulBotBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MessagePaymentSuccessfulBot {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MessagePaymentSuccessfulBot> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "MessagePaymentSuccessfulBot",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn invoice_message_id(&mut self, invoice_message_id: i64) -> &mut Self {
-        self.inner.invoice_message_id = invoice_message_id;
+    pub fn invoice_message_id<T: Into<MessageId>>(&mut self, invoice_message_id: T) -> &mut Self {
+        self.inner.invoice_message_id = invoice_message_id.into();
+        self.missing.retain(|f| *f != "invoice_message_id");
         self
     }
 
     pub fn currency<T: AsRef<str>>(&mut self, currency: T) -> &mut Self {
         self.inner.currency = currency.as_ref().to_string();
+        self.missing.retain(|f| *f != "currency");
         self
     }
 
     pub fn total_amount(&mut self, total_amount: i64) -> &mut Self {
         self.inner.total_amount = total_amount;
+        self.missing.retain(|f| *f != "total_amount");
         self
     }
 
-    pub fn invoice_payload<T: AsRef<str>>(&mut self, invoice_payload: T) -> &mut Self {
-        self.inner.invoice_payload = invoice_payload.as_ref().to_string();
+    pub fn invoice_payload<T: AsRef<[u8]>>(&mut self, invoice_payload: T) -> &mut Self {
+        self.inner.invoice_payload = invoice_payload.as_ref().to_vec();
+        self.missing.retain(|f| *f != "invoice_payload");
         self
     }
 
@@ -2488,6 +4123,7 @@ impl RTDMessagePaymentSuccessfulBotBuilder {
         telegram_payment_charge_id: T,
     ) -> &mut Self {
         self.inner.telegram_payment_charge_id = telegram_payment_charge_id.as_ref().to_string();
+        self.missing.retain(|f| *f != "telegram_payment_charge_id");
         self
     }
 
@@ -2496,6 +4132,7 @@ impl RTDMessagePaymentSuccessfulBotBuilder {
         provider_payment_charge_id: T,
     ) -> &mut Self {
         self.inner.provider_payment_charge_id = provider_payment_charge_id.as_ref().to_string();
+        self.missing.retain(|f| *f != "provider_payment_charge_id");
         self
     }
 }
@@ -2514,12 +4151,13 @@ impl AsRef<MessagePaymentSuccessfulBot> for RTDMessagePaymentSuccessfulBotBuilde
 
 /// A photo message
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "messagePhoto")]
 pub struct MessagePhoto {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The photo description
     photo: Photo,
@@ -2543,6 +4181,24 @@ impl RObject for MessagePhoto {
     }
 }
 
+impl PartialEq for MessagePhoto {
+    fn eq(&self, other: &Self) -> bool {
+        self.photo == other.photo
+            && self.caption == other.caption
+            && self.is_secret == other.is_secret
+    }
+}
+
+impl Eq for MessagePhoto {}
+
+impl std::hash::Hash for MessagePhoto {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.photo, state);
+        std::hash::Hash::hash(&self.caption, state);
+        std::hash::Hash::hash(&self.is_secret, state);
+    }
+}
+
 impl TDMessageContent for MessagePhoto {}
 
 impl MessagePhoto {
@@ -2550,42 +4206,94 @@ impl MessagePhoto {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessagePhotoBuilder {
-        let mut inner = MessagePhoto::default();
-        inner.td_name = "messagePhoto".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessagePhotoBuilder { inner }
+        RTDMessagePhotoBuilder {
+            inner: MessagePhoto::default(),
+            missing: vec!["photo", "caption"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessagePhotoBuilder {
+        RTDMessagePhotoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessagePhotoBuilder {
+        RTDMessagePhotoBuilder {
+            inner: self,
+            missing: vec![],
+        }
+    }
+
+    pub fn photo(&self) -> &Photo {
+        &self.photo
+    }
+
+    pub fn caption(&self) -> &FormattedText {
+        &self.caption
+    }
+
+    pub fn is_secret(&self) -> bool {
+        self.is_secret
+    }
+
+    pub fn into_photo(self) -> Photo {
+        self.photo
+    }
+
+    pub fn into_caption(self) -> FormattedText {
+        self.caption
     }
 
-    pub fn photo(&self) -> &Photo {
-        &self.photo
+    pub fn with_photo<T: AsRef<Photo>>(mut self, photo: T) -> Self {
+        self.photo = photo.as_ref().clone();
+        self
     }
 
-    pub fn caption(&self) -> &FormattedText {
-        &self.caption
+    pub fn with_caption<T: AsRef<FormattedText>>(mut self, caption: T) -> Self {
+        self.caption = caption.as_ref().clone();
+        self
     }
 
-    pub fn is_secret(&self) -> bool {
-        self.is_secret
+    pub fn with_is_secret(mut self, is_secret: bool) -> Self {
+        self.is_secret = is_secret;
+        self
     }
 }
 
 #[doc(hidden)]
 pub struct RTDMessagePhotoBuilder {
     inner: MessagePhoto,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessagePhotoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MessagePhoto {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MessagePhoto> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "MessagePhoto",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn photo<T: AsRef<Photo>>(&mut self, photo: T) -> &mut Self {
         self.inner.photo = photo.as_ref().clone();
+        self.missing.retain(|f| *f != "photo");
         self
     }
 
     pub fn caption<T: AsRef<FormattedText>>(&mut self, caption: T) -> &mut Self {
         self.inner.caption = caption.as_ref().clone();
+        self.missing.retain(|f| *f != "caption");
         self
     }
 
@@ -2609,15 +4317,16 @@ impl AsRef<MessagePhoto> for RTDMessagePhotoBuilder {
 
 /// A message has been pinned
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "messagePinMessage")]
 pub struct MessagePinMessage {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Identifier of the pinned message, can be an identifier of a deleted message or 0
-    message_id: i64,
+    message_id: MessageId,
 }
 
 impl RObject for MessagePinMessage {
@@ -2634,6 +4343,20 @@ impl RObject for MessagePinMessage {
     }
 }
 
+impl PartialEq for MessagePinMessage {
+    fn eq(&self, other: &Self) -> bool {
+        self.message_id == other.message_id
+    }
+}
+
+impl Eq for MessagePinMessage {}
+
+impl std::hash::Hash for MessagePinMessage {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.message_id, state);
+    }
+}
+
 impl TDMessageContent for MessagePinMessage {}
 
 impl MessagePinMessage {
@@ -2641,29 +4364,61 @@ impl MessagePinMessage {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessagePinMessageBuilder {
-        let mut inner = MessagePinMessage::default();
-        inner.td_name = "messagePinMessage".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessagePinMessageBuilder { inner }
+        RTDMessagePinMessageBuilder {
+            inner: MessagePinMessage::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessagePinMessageBuilder {
+        RTDMessagePinMessageBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessagePinMessageBuilder {
+        RTDMessagePinMessageBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn message_id(&self) -> i64 {
+    pub fn message_id(&self) -> MessageId {
         self.message_id
     }
+
+    pub fn with_message_id<T: Into<MessageId>>(mut self, message_id: T) -> Self {
+        self.message_id = message_id.into();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDMessagePinMessageBuilder {
     inner: MessagePinMessage,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessagePinMessageBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MessagePinMessage {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MessagePinMessage> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "MessagePinMessage",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn message_id(&mut self, message_id: i64) -> &mut Self {
-        self.inner.message_id = message_id;
+    pub fn message_id<T: Into<MessageId>>(&mut self, message_id: T) -> &mut Self {
+        self.inner.message_id = message_id.into();
         self
     }
 }
@@ -2682,12 +4437,13 @@ impl AsRef<MessagePinMessage> for RTDMessagePinMessageBuilder {
 
 /// A message with a poll
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "messagePoll")]
 pub struct MessagePoll {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The poll description
     poll: Poll,
@@ -2707,6 +4463,20 @@ impl RObject for MessagePoll {
     }
 }
 
+impl PartialEq for MessagePoll {
+    fn eq(&self, other: &Self) -> bool {
+        self.poll == other.poll
+    }
+}
+
+impl Eq for MessagePoll {}
+
+impl std::hash::Hash for MessagePoll {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.poll, state);
+    }
+}
+
 impl TDMessageContent for MessagePoll {}
 
 impl MessagePoll {
@@ -2714,29 +4484,63 @@ impl MessagePoll {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessagePollBuilder {
-        let mut inner = MessagePoll::default();
-        inner.td_name = "messagePoll".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessagePollBuilder { inner }
+        RTDMessagePollBuilder {
+            inner: MessagePoll::default(),
+            missing: vec!["poll"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessagePollBuilder {
+        RTDMessagePollBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessagePollBuilder {
+        RTDMessagePollBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn poll(&self) -> &Poll {
         &self.poll
     }
+
+    pub fn into_poll(self) -> Poll {
+        self.poll
+    }
+
+    pub fn with_poll<T: AsRef<Poll>>(mut self, poll: T) -> Self {
+        self.poll = poll.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDMessagePollBuilder {
     inner: MessagePoll,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessagePollBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MessagePoll {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MessagePoll> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("MessagePoll", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn poll<T: AsRef<Poll>>(&mut self, poll: T) -> &mut Self {
         self.inner.poll = poll.as_ref().clone();
+        self.missing.retain(|f| *f != "poll");
         self
     }
 }
@@ -2755,12 +4559,13 @@ impl AsRef<MessagePoll> for RTDMessagePollBuilder {
 
 /// A screenshot of a message in the chat has been taken
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "messageScreenshotTaken")]
 pub struct MessageScreenshotTaken {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -2778,6 +4583,18 @@ impl RObject for MessageScreenshotTaken {
     }
 }
 
+impl PartialEq for MessageScreenshotTaken {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for MessageScreenshotTaken {}
+
+impl std::hash::Hash for MessageScreenshotTaken {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDMessageContent for MessageScreenshotTaken {}
 
 impl MessageScreenshotTaken {
@@ -2785,22 +4602,49 @@ impl MessageScreenshotTaken {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessageScreenshotTakenBuilder {
-        let mut inner = MessageScreenshotTaken::default();
-        inner.td_name = "messageScreenshotTaken".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessageScreenshotTakenBuilder { inner }
+        RTDMessageScreenshotTakenBuilder {
+            inner: MessageScreenshotTaken::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessageScreenshotTakenBuilder {
+        RTDMessageScreenshotTakenBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessageScreenshotTakenBuilder {
+        RTDMessageScreenshotTakenBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDMessageScreenshotTakenBuilder {
     inner: MessageScreenshotTaken,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessageScreenshotTakenBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MessageScreenshotTaken {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MessageScreenshotTaken> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "MessageScreenshotTaken",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<MessageScreenshotTaken> for MessageScreenshotTaken {
@@ -2817,12 +4661,13 @@ impl AsRef<MessageScreenshotTaken> for RTDMessageScreenshotTakenBuilder {
 
 /// A sticker message
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "messageSticker")]
 pub struct MessageSticker {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The sticker description
     sticker: Sticker,
@@ -2842,6 +4687,12 @@ impl RObject for MessageSticker {
     }
 }
 
+impl PartialEq for MessageSticker {
+    fn eq(&self, other: &Self) -> bool {
+        self.sticker == other.sticker
+    }
+}
+
 impl TDMessageContent for MessageSticker {}
 
 impl MessageSticker {
@@ -2849,29 +4700,66 @@ impl MessageSticker {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessageStickerBuilder {
-        let mut inner = MessageSticker::default();
-        inner.td_name = "messageSticker".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessageStickerBuilder { inner }
+        RTDMessageStickerBuilder {
+            inner: MessageSticker::default(),
+            missing: vec!["sticker"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessageStickerBuilder {
+        RTDMessageStickerBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessageStickerBuilder {
+        RTDMessageStickerBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn sticker(&self) -> &Sticker {
         &self.sticker
     }
+
+    pub fn into_sticker(self) -> Sticker {
+        self.sticker
+    }
+
+    pub fn with_sticker<T: AsRef<Sticker>>(mut self, sticker: T) -> Self {
+        self.sticker = sticker.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDMessageStickerBuilder {
     inner: MessageSticker,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessageStickerBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MessageSticker {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MessageSticker> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "MessageSticker",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn sticker<T: AsRef<Sticker>>(&mut self, sticker: T) -> &mut Self {
         self.inner.sticker = sticker.as_ref().clone();
+        self.missing.retain(|f| *f != "sticker");
         self
     }
 }
@@ -2890,12 +4778,13 @@ impl AsRef<MessageSticker> for RTDMessageStickerBuilder {
 
 /// A newly created supergroup or channel
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "messageSupergroupChatCreate")]
 pub struct MessageSupergroupChatCreate {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Title of the supergroup or channel
     title: String,
@@ -2915,6 +4804,20 @@ impl RObject for MessageSupergroupChatCreate {
     }
 }
 
+impl PartialEq for MessageSupergroupChatCreate {
+    fn eq(&self, other: &Self) -> bool {
+        self.title == other.title
+    }
+}
+
+impl Eq for MessageSupergroupChatCreate {}
+
+impl std::hash::Hash for MessageSupergroupChatCreate {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.title, state);
+    }
+}
+
 impl TDMessageContent for MessageSupergroupChatCreate {}
 
 impl MessageSupergroupChatCreate {
@@ -2922,29 +4825,66 @@ impl MessageSupergroupChatCreate {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessageSupergroupChatCreateBuilder {
-        let mut inner = MessageSupergroupChatCreate::default();
-        inner.td_name = "messageSupergroupChatCreate".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessageSupergroupChatCreateBuilder { inner }
+        RTDMessageSupergroupChatCreateBuilder {
+            inner: MessageSupergroupChatCreate::default(),
+            missing: vec!["title"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessageSupergroupChatCreateBuilder {
+        RTDMessageSupergroupChatCreateBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessageSupergroupChatCreateBuilder {
+        RTDMessageSupergroupChatCreateBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn title(&self) -> &String {
         &self.title
     }
+
+    pub fn into_title(self) -> String {
+        self.title
+    }
+
+    pub fn with_title<T: AsRef<str>>(mut self, title: T) -> Self {
+        self.title = title.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDMessageSupergroupChatCreateBuilder {
     inner: MessageSupergroupChatCreate,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessageSupergroupChatCreateBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MessageSupergroupChatCreate {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MessageSupergroupChatCreate> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "MessageSupergroupChatCreate",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
         self.inner.title = title.as_ref().to_string();
+        self.missing.retain(|f| *f != "title");
         self
     }
 }
@@ -2963,12 +4903,13 @@ impl AsRef<MessageSupergroupChatCreate> for RTDMessageSupergroupChatCreateBuilde
 
 /// A text message
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "messageText")]
 pub struct MessageText {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Text of the message
     text: FormattedText,
@@ -2990,6 +4931,12 @@ impl RObject for MessageText {
     }
 }
 
+impl PartialEq for MessageText {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text && self.web_page == other.web_page
+    }
+}
+
 impl TDMessageContent for MessageText {}
 
 impl MessageText {
@@ -2997,10 +4944,24 @@ impl MessageText {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessageTextBuilder {
-        let mut inner = MessageText::default();
-        inner.td_name = "messageText".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessageTextBuilder { inner }
+        RTDMessageTextBuilder {
+            inner: MessageText::default(),
+            missing: vec!["text"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessageTextBuilder {
+        RTDMessageTextBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessageTextBuilder {
+        RTDMessageTextBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn text(&self) -> &FormattedText {
@@ -3010,20 +4971,49 @@ impl MessageText {
     pub fn web_page(&self) -> &Option<WebPage> {
         &self.web_page
     }
+
+    pub fn into_text(self) -> FormattedText {
+        self.text
+    }
+
+    pub fn into_web_page(self) -> Option<WebPage> {
+        self.web_page
+    }
+
+    pub fn with_text<T: AsRef<FormattedText>>(mut self, text: T) -> Self {
+        self.text = text.as_ref().clone();
+        self
+    }
+
+    pub fn with_web_page<T: AsRef<WebPage>>(mut self, web_page: T) -> Self {
+        self.web_page = Some(web_page.as_ref().clone());
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDMessageTextBuilder {
     inner: MessageText,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessageTextBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MessageText {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MessageText> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("MessageText", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn text<T: AsRef<FormattedText>>(&mut self, text: T) -> &mut Self {
         self.inner.text = text.as_ref().clone();
+        self.missing.retain(|f| *f != "text");
         self
     }
 
@@ -3047,12 +5037,13 @@ impl AsRef<MessageText> for RTDMessageTextBuilder {
 
 /// Message content that is not supported by the client
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "messageUnsupported")]
 pub struct MessageUnsupported {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -3070,6 +5061,18 @@ impl RObject for MessageUnsupported {
     }
 }
 
+impl PartialEq for MessageUnsupported {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for MessageUnsupported {}
+
+impl std::hash::Hash for MessageUnsupported {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDMessageContent for MessageUnsupported {}
 
 impl MessageUnsupported {
@@ -3077,22 +5080,49 @@ impl MessageUnsupported {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessageUnsupportedBuilder {
-        let mut inner = MessageUnsupported::default();
-        inner.td_name = "messageUnsupported".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessageUnsupportedBuilder { inner }
+        RTDMessageUnsupportedBuilder {
+            inner: MessageUnsupported::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessageUnsupportedBuilder {
+        RTDMessageUnsupportedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessageUnsupportedBuilder {
+        RTDMessageUnsupportedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDMessageUnsupportedBuilder {
     inner: MessageUnsupported,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessageUnsupportedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MessageUnsupported {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MessageUnsupported> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "MessageUnsupported",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<MessageUnsupported> for MessageUnsupported {
@@ -3109,12 +5139,13 @@ impl AsRef<MessageUnsupported> for RTDMessageUnsupportedBuilder {
 
 /// A message with information about a venue
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "messageVenue")]
 pub struct MessageVenue {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The venue description
     venue: Venue,
@@ -3134,6 +5165,12 @@ impl RObject for MessageVenue {
     }
 }
 
+impl PartialEq for MessageVenue {
+    fn eq(&self, other: &Self) -> bool {
+        self.venue == other.venue
+    }
+}
+
 impl TDMessageContent for MessageVenue {}
 
 impl MessageVenue {
@@ -3141,29 +5178,66 @@ impl MessageVenue {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessageVenueBuilder {
-        let mut inner = MessageVenue::default();
-        inner.td_name = "messageVenue".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessageVenueBuilder { inner }
+        RTDMessageVenueBuilder {
+            inner: MessageVenue::default(),
+            missing: vec!["venue"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessageVenueBuilder {
+        RTDMessageVenueBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessageVenueBuilder {
+        RTDMessageVenueBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn venue(&self) -> &Venue {
         &self.venue
     }
+
+    pub fn into_venue(self) -> Venue {
+        self.venue
+    }
+
+    pub fn with_venue<T: AsRef<Venue>>(mut self, venue: T) -> Self {
+        self.venue = venue.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDMessageVenueBuilder {
     inner: MessageVenue,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessageVenueBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MessageVenue {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MessageVenue> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "MessageVenue",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn venue<T: AsRef<Venue>>(&mut self, venue: T) -> &mut Self {
         self.inner.venue = venue.as_ref().clone();
+        self.missing.retain(|f| *f != "venue");
         self
     }
 }
@@ -3182,12 +5256,13 @@ impl AsRef<MessageVenue> for RTDMessageVenueBuilder {
 
 /// A video message
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "messageVideo")]
 pub struct MessageVideo {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The video description
     video: Video,
@@ -3211,6 +5286,24 @@ impl RObject for MessageVideo {
     }
 }
 
+impl PartialEq for MessageVideo {
+    fn eq(&self, other: &Self) -> bool {
+        self.video == other.video
+            && self.caption == other.caption
+            && self.is_secret == other.is_secret
+    }
+}
+
+impl Eq for MessageVideo {}
+
+impl std::hash::Hash for MessageVideo {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.video, state);
+        std::hash::Hash::hash(&self.caption, state);
+        std::hash::Hash::hash(&self.is_secret, state);
+    }
+}
+
 impl TDMessageContent for MessageVideo {}
 
 impl MessageVideo {
@@ -3218,10 +5311,24 @@ impl MessageVideo {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessageVideoBuilder {
-        let mut inner = MessageVideo::default();
-        inner.td_name = "messageVideo".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessageVideoBuilder { inner }
+        RTDMessageVideoBuilder {
+            inner: MessageVideo::default(),
+            missing: vec!["video", "caption"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessageVideoBuilder {
+        RTDMessageVideoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessageVideoBuilder {
+        RTDMessageVideoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn video(&self) -> &Video {
@@ -3235,25 +5342,63 @@ impl MessageVideo {
     pub fn is_secret(&self) -> bool {
         self.is_secret
     }
+
+    pub fn into_video(self) -> Video {
+        self.video
+    }
+
+    pub fn into_caption(self) -> FormattedText {
+        self.caption
+    }
+
+    pub fn with_video<T: AsRef<Video>>(mut self, video: T) -> Self {
+        self.video = video.as_ref().clone();
+        self
+    }
+
+    pub fn with_caption<T: AsRef<FormattedText>>(mut self, caption: T) -> Self {
+        self.caption = caption.as_ref().clone();
+        self
+    }
+
+    pub fn with_is_secret(mut self, is_secret: bool) -> Self {
+        self.is_secret = is_secret;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDMessageVideoBuilder {
     inner: MessageVideo,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessageVideoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MessageVideo {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MessageVideo> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "MessageVideo",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn video<T: AsRef<Video>>(&mut self, video: T) -> &mut Self {
         self.inner.video = video.as_ref().clone();
+        self.missing.retain(|f| *f != "video");
         self
     }
 
     pub fn caption<T: AsRef<FormattedText>>(&mut self, caption: T) -> &mut Self {
         self.inner.caption = caption.as_ref().clone();
+        self.missing.retain(|f| *f != "caption");
         self
     }
 
@@ -3277,12 +5422,13 @@ impl AsRef<MessageVideo> for RTDMessageVideoBuilder {
 
 /// A video note message
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "messageVideoNote")]
 pub struct MessageVideoNote {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The video note description
     video_note: VideoNote,
@@ -3306,6 +5452,24 @@ impl RObject for MessageVideoNote {
     }
 }
 
+impl PartialEq for MessageVideoNote {
+    fn eq(&self, other: &Self) -> bool {
+        self.video_note == other.video_note
+            && self.is_viewed == other.is_viewed
+            && self.is_secret == other.is_secret
+    }
+}
+
+impl Eq for MessageVideoNote {}
+
+impl std::hash::Hash for MessageVideoNote {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.video_note, state);
+        std::hash::Hash::hash(&self.is_viewed, state);
+        std::hash::Hash::hash(&self.is_secret, state);
+    }
+}
+
 impl TDMessageContent for MessageVideoNote {}
 
 impl MessageVideoNote {
@@ -3313,10 +5477,24 @@ impl MessageVideoNote {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessageVideoNoteBuilder {
-        let mut inner = MessageVideoNote::default();
-        inner.td_name = "messageVideoNote".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessageVideoNoteBuilder { inner }
+        RTDMessageVideoNoteBuilder {
+            inner: MessageVideoNote::default(),
+            missing: vec!["video_note"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessageVideoNoteBuilder {
+        RTDMessageVideoNoteBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessageVideoNoteBuilder {
+        RTDMessageVideoNoteBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn video_note(&self) -> &VideoNote {
@@ -3330,20 +5508,53 @@ impl MessageVideoNote {
     pub fn is_secret(&self) -> bool {
         self.is_secret
     }
+
+    pub fn into_video_note(self) -> VideoNote {
+        self.video_note
+    }
+
+    pub fn with_video_note<T: AsRef<VideoNote>>(mut self, video_note: T) -> Self {
+        self.video_note = video_note.as_ref().clone();
+        self
+    }
+
+    pub fn with_is_viewed(mut self, is_viewed: bool) -> Self {
+        self.is_viewed = is_viewed;
+        self
+    }
+
+    pub fn with_is_secret(mut self, is_secret: bool) -> Self {
+        self.is_secret = is_secret;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDMessageVideoNoteBuilder {
     inner: MessageVideoNote,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessageVideoNoteBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MessageVideoNote {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MessageVideoNote> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "MessageVideoNote",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn video_note<T: AsRef<VideoNote>>(&mut self, video_note: T) -> &mut Self {
         self.inner.video_note = video_note.as_ref().clone();
+        self.missing.retain(|f| *f != "video_note");
         self
     }
 
@@ -3372,12 +5583,13 @@ impl AsRef<MessageVideoNote> for RTDMessageVideoNoteBuilder {
 
 /// A voice note message
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "messageVoiceNote")]
 pub struct MessageVoiceNote {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The voice note description
     voice_note: VoiceNote,
@@ -3401,6 +5613,24 @@ impl RObject for MessageVoiceNote {
     }
 }
 
+impl PartialEq for MessageVoiceNote {
+    fn eq(&self, other: &Self) -> bool {
+        self.voice_note == other.voice_note
+            && self.caption == other.caption
+            && self.is_listened == other.is_listened
+    }
+}
+
+impl Eq for MessageVoiceNote {}
+
+impl std::hash::Hash for MessageVoiceNote {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.voice_note, state);
+        std::hash::Hash::hash(&self.caption, state);
+        std::hash::Hash::hash(&self.is_listened, state);
+    }
+}
+
 impl TDMessageContent for MessageVoiceNote {}
 
 impl MessageVoiceNote {
@@ -3408,10 +5638,24 @@ impl MessageVoiceNote {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessageVoiceNoteBuilder {
-        let mut inner = MessageVoiceNote::default();
-        inner.td_name = "messageVoiceNote".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessageVoiceNoteBuilder { inner }
+        RTDMessageVoiceNoteBuilder {
+            inner: MessageVoiceNote::default(),
+            missing: vec!["voice_note", "caption"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessageVoiceNoteBuilder {
+        RTDMessageVoiceNoteBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessageVoiceNoteBuilder {
+        RTDMessageVoiceNoteBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn voice_note(&self) -> &VoiceNote {
@@ -3425,25 +5669,63 @@ impl MessageVoiceNote {
     pub fn is_listened(&self) -> bool {
         self.is_listened
     }
+
+    pub fn into_voice_note(self) -> VoiceNote {
+        self.voice_note
+    }
+
+    pub fn into_caption(self) -> FormattedText {
+        self.caption
+    }
+
+    pub fn with_voice_note<T: AsRef<VoiceNote>>(mut self, voice_note: T) -> Self {
+        self.voice_note = voice_note.as_ref().clone();
+        self
+    }
+
+    pub fn with_caption<T: AsRef<FormattedText>>(mut self, caption: T) -> Self {
+        self.caption = caption.as_ref().clone();
+        self
+    }
+
+    pub fn with_is_listened(mut self, is_listened: bool) -> Self {
+        self.is_listened = is_listened;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDMessageVoiceNoteBuilder {
     inner: MessageVoiceNote,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessageVoiceNoteBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MessageVoiceNote {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MessageVoiceNote> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "MessageVoiceNote",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn voice_note<T: AsRef<VoiceNote>>(&mut self, voice_note: T) -> &mut Self {
         self.inner.voice_note = voice_note.as_ref().clone();
+        self.missing.retain(|f| *f != "voice_note");
         self
     }
 
     pub fn caption<T: AsRef<FormattedText>>(&mut self, caption: T) -> &mut Self {
         self.inner.caption = caption.as_ref().clone();
+        self.missing.retain(|f| *f != "caption");
         self
     }
 
@@ -3467,12 +5749,13 @@ impl AsRef<MessageVoiceNote> for RTDMessageVoiceNoteBuilder {
 
 /// The current user has connected a website by logging in using Telegram Login Widget on it
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "messageWebsiteConnected")]
 pub struct MessageWebsiteConnected {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Domain name of the connected website
     domain_name: String,
@@ -3492,6 +5775,20 @@ impl RObject for MessageWebsiteConnected {
     }
 }
 
+impl PartialEq for MessageWebsiteConnected {
+    fn eq(&self, other: &Self) -> bool {
+        self.domain_name == other.domain_name
+    }
+}
+
+impl Eq for MessageWebsiteConnected {}
+
+impl std::hash::Hash for MessageWebsiteConnected {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.domain_name, state);
+    }
+}
+
 impl TDMessageContent for MessageWebsiteConnected {}
 
 impl MessageWebsiteConnected {
@@ -3499,29 +5796,66 @@ impl MessageWebsiteConnected {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessageWebsiteConnectedBuilder {
-        let mut inner = MessageWebsiteConnected::default();
-        inner.td_name = "messageWebsiteConnected".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessageWebsiteConnectedBuilder { inner }
+        RTDMessageWebsiteConnectedBuilder {
+            inner: MessageWebsiteConnected::default(),
+            missing: vec!["domain_name"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessageWebsiteConnectedBuilder {
+        RTDMessageWebsiteConnectedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessageWebsiteConnectedBuilder {
+        RTDMessageWebsiteConnectedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn domain_name(&self) -> &String {
         &self.domain_name
     }
+
+    pub fn into_domain_name(self) -> String {
+        self.domain_name
+    }
+
+    pub fn with_domain_name<T: AsRef<str>>(mut self, domain_name: T) -> Self {
+        self.domain_name = domain_name.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDMessageWebsiteConnectedBuilder {
     inner: MessageWebsiteConnected,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessageWebsiteConnectedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MessageWebsiteConnected {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MessageWebsiteConnected> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "MessageWebsiteConnected",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn domain_name<T: AsRef<str>>(&mut self, domain_name: T) -> &mut Self {
         self.inner.domain_name = domain_name.as_ref().to_string();
+        self.missing.retain(|f| *f != "domain_name");
         self
     }
 }
@@ -3537,3 +5871,556 @@ impl AsRef<MessageWebsiteConnected> for RTDMessageWebsiteConnectedBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(MessageContent::MessageAnimation(
+            MessageAnimation::builder()
+                .animation(
+                    Animation::builder()
+                        .duration(i32::MAX)
+                        .width(i32::MAX)
+                        .height(i32::MAX)
+                        .file_name("text")
+                        .mime_type("text")
+                        .animation(
+                            File::builder()
+                                .id(i32::MAX)
+                                .size(i32::MAX)
+                                .expected_size(i32::MAX)
+                                .local(
+                                    LocalFile::builder()
+                                        .path("text")
+                                        .can_be_downloaded(true)
+                                        .can_be_deleted(true)
+                                        .is_downloading_active(true)
+                                        .is_downloading_completed(true)
+                                        .download_offset(i32::MAX)
+                                        .downloaded_prefix_size(i32::MAX)
+                                        .downloaded_size(i32::MAX)
+                                        .build(),
+                                )
+                                .remote(
+                                    RemoteFile::builder()
+                                        .id("text")
+                                        .unique_id("text")
+                                        .is_uploading_active(true)
+                                        .is_uploading_completed(true)
+                                        .uploaded_size(i32::MAX)
+                                        .build(),
+                                )
+                                .build(),
+                        )
+                        .build(),
+                )
+                .caption(
+                    FormattedText::builder()
+                        .text("text")
+                        .entities(vec![])
+                        .build(),
+                )
+                .is_secret(true)
+                .build(),
+        ));
+        assert_round_trip(MessageContent::MessageAudio(
+            MessageAudio::builder()
+                .audio(
+                    Audio::builder()
+                        .duration(i32::MAX)
+                        .title("text")
+                        .performer("text")
+                        .file_name("text")
+                        .mime_type("text")
+                        .audio(
+                            File::builder()
+                                .id(i32::MAX)
+                                .size(i32::MAX)
+                                .expected_size(i32::MAX)
+                                .local(
+                                    LocalFile::builder()
+                                        .path("text")
+                                        .can_be_downloaded(true)
+                                        .can_be_deleted(true)
+                                        .is_downloading_active(true)
+                                        .is_downloading_completed(true)
+                                        .download_offset(i32::MAX)
+                                        .downloaded_prefix_size(i32::MAX)
+                                        .downloaded_size(i32::MAX)
+                                        .build(),
+                                )
+                                .remote(
+                                    RemoteFile::builder()
+                                        .id("text")
+                                        .unique_id("text")
+                                        .is_uploading_active(true)
+                                        .is_uploading_completed(true)
+                                        .uploaded_size(i32::MAX)
+                                        .build(),
+                                )
+                                .build(),
+                        )
+                        .build(),
+                )
+                .caption(
+                    FormattedText::builder()
+                        .text("text")
+                        .entities(vec![])
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(MessageContent::MessageBasicGroupChatCreate(
+            MessageBasicGroupChatCreate::builder()
+                .title("text")
+                .member_user_ids(vec![Default::default()])
+                .build(),
+        ));
+        assert_round_trip(MessageContent::MessageCall(
+            MessageCall::builder()
+                .discard_reason(CallDiscardReason::Declined(
+                    CallDiscardReasonDeclined::builder().build(),
+                ))
+                .duration(i32::MAX)
+                .build(),
+        ));
+        assert_round_trip(MessageContent::MessageChatAddMembers(
+            MessageChatAddMembers::builder()
+                .member_user_ids(vec![Default::default()])
+                .build(),
+        ));
+        assert_round_trip(MessageContent::MessageChatChangePhoto(
+            MessageChatChangePhoto::builder()
+                .photo(Photo::builder().has_stickers(true).sizes(vec![]).build())
+                .build(),
+        ));
+        assert_round_trip(MessageContent::MessageChatChangeTitle(
+            MessageChatChangeTitle::builder().title("text").build(),
+        ));
+        assert_round_trip(MessageContent::MessageChatDeleteMember(
+            MessageChatDeleteMember::builder().user_id(i32::MAX).build(),
+        ));
+        assert_round_trip(MessageContent::MessageChatDeletePhoto(
+            MessageChatDeletePhoto::builder().build(),
+        ));
+        assert_round_trip(MessageContent::MessageChatJoinByLink(
+            MessageChatJoinByLink::builder().build(),
+        ));
+        assert_round_trip(MessageContent::MessageChatSetTtl(
+            MessageChatSetTtl::builder().ttl(i32::MAX).build(),
+        ));
+        assert_round_trip(MessageContent::MessageChatUpgradeFrom(
+            MessageChatUpgradeFrom::builder()
+                .title("text")
+                .basic_group_id(i32::MAX)
+                .build(),
+        ));
+        assert_round_trip(MessageContent::MessageChatUpgradeTo(
+            MessageChatUpgradeTo::builder()
+                .supergroup_id(i32::MAX)
+                .build(),
+        ));
+        assert_round_trip(MessageContent::MessageContact(
+            MessageContact::builder()
+                .contact(
+                    Contact::builder()
+                        .phone_number("text")
+                        .first_name("text")
+                        .last_name("text")
+                        .vcard("text")
+                        .user_id(i32::MAX)
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(MessageContent::MessageContactRegistered(
+            MessageContactRegistered::builder().build(),
+        ));
+        assert_round_trip(MessageContent::MessageCustomServiceAction(
+            MessageCustomServiceAction::builder().text("text").build(),
+        ));
+        assert_round_trip(MessageContent::MessageDocument(
+            MessageDocument::builder()
+                .document(
+                    Document::builder()
+                        .file_name("text")
+                        .mime_type("text")
+                        .document(
+                            File::builder()
+                                .id(i32::MAX)
+                                .size(i32::MAX)
+                                .expected_size(i32::MAX)
+                                .local(
+                                    LocalFile::builder()
+                                        .path("text")
+                                        .can_be_downloaded(true)
+                                        .can_be_deleted(true)
+                                        .is_downloading_active(true)
+                                        .is_downloading_completed(true)
+                                        .download_offset(i32::MAX)
+                                        .downloaded_prefix_size(i32::MAX)
+                                        .downloaded_size(i32::MAX)
+                                        .build(),
+                                )
+                                .remote(
+                                    RemoteFile::builder()
+                                        .id("text")
+                                        .unique_id("text")
+                                        .is_uploading_active(true)
+                                        .is_uploading_completed(true)
+                                        .uploaded_size(i32::MAX)
+                                        .build(),
+                                )
+                                .build(),
+                        )
+                        .build(),
+                )
+                .caption(
+                    FormattedText::builder()
+                        .text("text")
+                        .entities(vec![])
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(MessageContent::MessageExpiredPhoto(
+            MessageExpiredPhoto::builder().build(),
+        ));
+        assert_round_trip(MessageContent::MessageExpiredVideo(
+            MessageExpiredVideo::builder().build(),
+        ));
+        assert_round_trip(MessageContent::MessageGame(
+            MessageGame::builder()
+                .game(
+                    Game::builder()
+                        .id(i64::MAX)
+                        .short_name("text")
+                        .title("text")
+                        .text(
+                            FormattedText::builder()
+                                .text("text")
+                                .entities(vec![])
+                                .build(),
+                        )
+                        .description("text")
+                        .photo(Photo::builder().has_stickers(true).sizes(vec![]).build())
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(MessageContent::MessageGameScore(
+            MessageGameScore::builder()
+                .game_message_id(9007199254740991i64)
+                .game_id(i64::MAX)
+                .score(i32::MAX)
+                .build(),
+        ));
+        assert_round_trip(MessageContent::MessageInvoice(
+            MessageInvoice::builder()
+                .title("text")
+                .description("text")
+                .currency("text")
+                .total_amount(9007199254740991i64)
+                .start_parameter("text")
+                .is_test(true)
+                .need_shipping_address(true)
+                .receipt_message_id(9007199254740991i64)
+                .build(),
+        ));
+        assert_round_trip(MessageContent::MessageLocation(
+            MessageLocation::builder()
+                .location(Location::builder().latitude(1.5).longitude(1.5).build())
+                .live_period(i32::MAX)
+                .expires_in(i32::MAX)
+                .build(),
+        ));
+        assert_round_trip(MessageContent::MessagePassportDataReceived(
+            MessagePassportDataReceived::builder()
+                .elements(vec![])
+                .credentials(
+                    EncryptedCredentials::builder()
+                        .data(b"\x00\x01\xfe\xff")
+                        .hash(b"\x00\x01\xfe\xff")
+                        .secret(b"\x00\x01\xfe\xff")
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(MessageContent::MessagePassportDataSent(
+            MessagePassportDataSent::builder().types(vec![]).build(),
+        ));
+        assert_round_trip(MessageContent::MessagePaymentSuccessful(
+            MessagePaymentSuccessful::builder()
+                .invoice_message_id(9007199254740991i64)
+                .currency("text")
+                .total_amount(9007199254740991i64)
+                .build(),
+        ));
+        assert_round_trip(MessageContent::MessagePaymentSuccessfulBot(
+            MessagePaymentSuccessfulBot::builder()
+                .invoice_message_id(9007199254740991i64)
+                .currency("text")
+                .total_amount(9007199254740991i64)
+                .invoice_payload(b"\x00\x01\xfe\xff")
+                .shipping_option_id("text")
+                .telegram_payment_charge_id("text")
+                .provider_payment_charge_id("text")
+                .build(),
+        ));
+        assert_round_trip(MessageContent::MessagePhoto(
+            MessagePhoto::builder()
+                .photo(Photo::builder().has_stickers(true).sizes(vec![]).build())
+                .caption(
+                    FormattedText::builder()
+                        .text("text")
+                        .entities(vec![])
+                        .build(),
+                )
+                .is_secret(true)
+                .build(),
+        ));
+        assert_round_trip(MessageContent::MessagePinMessage(
+            MessagePinMessage::builder()
+                .message_id(9007199254740991i64)
+                .build(),
+        ));
+        assert_round_trip(MessageContent::MessagePoll(
+            MessagePoll::builder()
+                .poll(
+                    Poll::builder()
+                        .id(i64::MAX)
+                        .question("text")
+                        .options(vec![])
+                        .total_voter_count(i32::MAX)
+                        .recent_voter_user_ids(vec![Default::default()])
+                        .is_anonymous(true)
+                        .type_(PollType::Quiz(
+                            PollTypeQuiz::builder().correct_option_id(i32::MAX).build(),
+                        ))
+                        .is_closed(true)
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(MessageContent::MessageScreenshotTaken(
+            MessageScreenshotTaken::builder().build(),
+        ));
+        assert_round_trip(MessageContent::MessageSticker(
+            MessageSticker::builder()
+                .sticker(
+                    Sticker::builder()
+                        .set_id(i64::MAX)
+                        .width(i32::MAX)
+                        .height(i32::MAX)
+                        .emoji("text")
+                        .is_animated(true)
+                        .is_mask(true)
+                        .sticker(
+                            File::builder()
+                                .id(i32::MAX)
+                                .size(i32::MAX)
+                                .expected_size(i32::MAX)
+                                .local(
+                                    LocalFile::builder()
+                                        .path("text")
+                                        .can_be_downloaded(true)
+                                        .can_be_deleted(true)
+                                        .is_downloading_active(true)
+                                        .is_downloading_completed(true)
+                                        .download_offset(i32::MAX)
+                                        .downloaded_prefix_size(i32::MAX)
+                                        .downloaded_size(i32::MAX)
+                                        .build(),
+                                )
+                                .remote(
+                                    RemoteFile::builder()
+                                        .id("text")
+                                        .unique_id("text")
+                                        .is_uploading_active(true)
+                                        .is_uploading_completed(true)
+                                        .uploaded_size(i32::MAX)
+                                        .build(),
+                                )
+                                .build(),
+                        )
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(MessageContent::MessageSupergroupChatCreate(
+            MessageSupergroupChatCreate::builder().title("text").build(),
+        ));
+        assert_round_trip(MessageContent::MessageText(
+            MessageText::builder()
+                .text(
+                    FormattedText::builder()
+                        .text("text")
+                        .entities(vec![])
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(MessageContent::MessageUnsupported(
+            MessageUnsupported::builder().build(),
+        ));
+        assert_round_trip(MessageContent::MessageVenue(
+            MessageVenue::builder()
+                .venue(
+                    Venue::builder()
+                        .location(Location::builder().latitude(1.5).longitude(1.5).build())
+                        .title("text")
+                        .address("text")
+                        .provider("text")
+                        .id("text")
+                        .type_("text")
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(MessageContent::MessageVideo(
+            MessageVideo::builder()
+                .video(
+                    Video::builder()
+                        .duration(i32::MAX)
+                        .width(i32::MAX)
+                        .height(i32::MAX)
+                        .file_name("text")
+                        .mime_type("text")
+                        .has_stickers(true)
+                        .supports_streaming(true)
+                        .video(
+                            File::builder()
+                                .id(i32::MAX)
+                                .size(i32::MAX)
+                                .expected_size(i32::MAX)
+                                .local(
+                                    LocalFile::builder()
+                                        .path("text")
+                                        .can_be_downloaded(true)
+                                        .can_be_deleted(true)
+                                        .is_downloading_active(true)
+                                        .is_downloading_completed(true)
+                                        .download_offset(i32::MAX)
+                                        .downloaded_prefix_size(i32::MAX)
+                                        .downloaded_size(i32::MAX)
+                                        .build(),
+                                )
+                                .remote(
+                                    RemoteFile::builder()
+                                        .id("text")
+                                        .unique_id("text")
+                                        .is_uploading_active(true)
+                                        .is_uploading_completed(true)
+                                        .uploaded_size(i32::MAX)
+                                        .build(),
+                                )
+                                .build(),
+                        )
+                        .build(),
+                )
+                .caption(
+                    FormattedText::builder()
+                        .text("text")
+                        .entities(vec![])
+                        .build(),
+                )
+                .is_secret(true)
+                .build(),
+        ));
+        assert_round_trip(MessageContent::MessageVideoNote(
+            MessageVideoNote::builder()
+                .video_note(
+                    VideoNote::builder()
+                        .duration(i32::MAX)
+                        .length(i32::MAX)
+                        .video(
+                            File::builder()
+                                .id(i32::MAX)
+                                .size(i32::MAX)
+                                .expected_size(i32::MAX)
+                                .local(
+                                    LocalFile::builder()
+                                        .path("text")
+                                        .can_be_downloaded(true)
+                                        .can_be_deleted(true)
+                                        .is_downloading_active(true)
+                                        .is_downloading_completed(true)
+                                        .download_offset(i32::MAX)
+                                        .downloaded_prefix_size(i32::MAX)
+                                        .downloaded_size(i32::MAX)
+                                        .build(),
+                                )
+                                .remote(
+                                    RemoteFile::builder()
+                                        .id("text")
+                                        .unique_id("text")
+                                        .is_uploading_active(true)
+                                        .is_uploading_completed(true)
+                                        .uploaded_size(i32::MAX)
+                                        .build(),
+                                )
+                                .build(),
+                        )
+                        .build(),
+                )
+                .is_viewed(true)
+                .is_secret(true)
+                .build(),
+        ));
+        assert_round_trip(MessageContent::MessageVoiceNote(
+            MessageVoiceNote::builder()
+                .voice_note(
+                    VoiceNote::builder()
+                        .duration(i32::MAX)
+                        .waveform(b"\x00\x01\xfe\xff")
+                        .mime_type("text")
+                        .voice(
+                            File::builder()
+                                .id(i32::MAX)
+                                .size(i32::MAX)
+                                .expected_size(i32::MAX)
+                                .local(
+                                    LocalFile::builder()
+                                        .path("text")
+                                        .can_be_downloaded(true)
+                                        .can_be_deleted(true)
+                                        .is_downloading_active(true)
+                                        .is_downloading_completed(true)
+                                        .download_offset(i32::MAX)
+                                        .downloaded_prefix_size(i32::MAX)
+                                        .downloaded_size(i32::MAX)
+                                        .build(),
+                                )
+                                .remote(
+                                    RemoteFile::builder()
+                                        .id("text")
+                                        .unique_id("text")
+                                        .is_uploading_active(true)
+                                        .is_uploading_completed(true)
+                                        .uploaded_size(i32::MAX)
+                                        .build(),
+                                )
+                                .build(),
+                        )
+                        .build(),
+                )
+                .caption(
+                    FormattedText::builder()
+                        .text("text")
+                        .entities(vec![])
+                        .build(),
+                )
+                .is_listened(true)
+                .build(),
+        ));
+        assert_round_trip(MessageContent::MessageWebsiteConnected(
+            MessageWebsiteConnected::builder()
+                .domain_name("text")
+                .build(),
+        ));
+    }
+}
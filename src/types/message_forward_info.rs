@@ -1,24 +1,24 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains information about a forwarded message
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "messageForwardInfo")]
 pub struct MessageForwardInfo {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Origin of a forwarded message
     origin: MessageForwardOrigin,
     /// Point in time (Unix timestamp) when the message was originally sent
-    date: i64,
+    date: i32,
     /// For messages forwarded to the chat with the current user (Saved Messages) or to the channel's discussion group, the identifier of the chat from which the message was forwarded last time; 0 if unknown
-    from_chat_id: i64,
+    from_chat_id: ChatId,
     /// For messages forwarded to the chat with the current user (Saved Messages) or to the channel's discussion group, the identifier of the original message from which the new message was forwarded last time; 0 if unknown
-    from_message_id: i64,
+    from_message_id: MessageId,
 }
 
 impl RObject for MessageForwardInfo {
@@ -35,61 +35,134 @@ impl RObject for MessageForwardInfo {
     }
 }
 
+impl PartialEq for MessageForwardInfo {
+    fn eq(&self, other: &Self) -> bool {
+        self.origin == other.origin
+            && self.date == other.date
+            && self.from_chat_id == other.from_chat_id
+            && self.from_message_id == other.from_message_id
+    }
+}
+
+impl Eq for MessageForwardInfo {}
+
+impl std::hash::Hash for MessageForwardInfo {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.origin, state);
+        std::hash::Hash::hash(&self.date, state);
+        std::hash::Hash::hash(&self.from_chat_id, state);
+        std::hash::Hash::hash(&self.from_message_id, state);
+    }
+}
+
 impl MessageForwardInfo {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessageForwardInfoBuilder {
-        let mut inner = MessageForwardInfo::default();
-        inner.td_name = "messageForwardInfo".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessageForwardInfoBuilder { inner }
+        RTDMessageForwardInfoBuilder {
+            inner: MessageForwardInfo::default(),
+            missing: vec!["origin", "date"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessageForwardInfoBuilder {
+        RTDMessageForwardInfoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessageForwardInfoBuilder {
+        RTDMessageForwardInfoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn origin(&self) -> &MessageForwardOrigin {
         &self.origin
     }
 
-    pub fn date(&self) -> i64 {
+    pub fn date(&self) -> i32 {
         self.date
     }
 
-    pub fn from_chat_id(&self) -> i64 {
+    pub fn from_chat_id(&self) -> ChatId {
         self.from_chat_id
     }
 
-    pub fn from_message_id(&self) -> i64 {
+    pub fn from_message_id(&self) -> MessageId {
         self.from_message_id
     }
+
+    pub fn into_origin(self) -> MessageForwardOrigin {
+        self.origin
+    }
+
+    pub fn with_origin<T: AsRef<MessageForwardOrigin>>(mut self, origin: T) -> Self {
+        self.origin = origin.as_ref().clone();
+        self
+    }
+
+    pub fn with_date(mut self, date: i32) -> Self {
+        self.date = date;
+        self
+    }
+
+    pub fn with_from_chat_id<T: Into<ChatId>>(mut self, from_chat_id: T) -> Self {
+        self.from_chat_id = from_chat_id.into();
+        self
+    }
+
+    pub fn with_from_message_id<T: Into<MessageId>>(mut self, from_message_id: T) -> Self {
+        self.from_message_id = from_message_id.into();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDMessageForwardInfoBuilder {
     inner: MessageForwardInfo,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessageForwardInfoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MessageForwardInfo {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MessageForwardInfo> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "MessageForwardInfo",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn origin<T: AsRef<MessageForwardOrigin>>(&mut self, origin: T) -> &mut Self {
         self.inner.origin = origin.as_ref().clone();
+        self.missing.retain(|f| *f != "origin");
         self
     }
 
-    pub fn date(&mut self, date: i64) -> &mut Self {
+    pub fn date(&mut self, date: i32) -> &mut Self {
         self.inner.date = date;
+        self.missing.retain(|f| *f != "date");
         self
     }
 
-    pub fn from_chat_id(&mut self, from_chat_id: i64) -> &mut Self {
-        self.inner.from_chat_id = from_chat_id;
+    pub fn from_chat_id<T: Into<ChatId>>(&mut self, from_chat_id: T) -> &mut Self {
+        self.inner.from_chat_id = from_chat_id.into();
         self
     }
 
-    pub fn from_message_id(&mut self, from_message_id: i64) -> &mut Self {
-        self.inner.from_message_id = from_message_id;
+    pub fn from_message_id<T: Into<MessageId>>(&mut self, from_message_id: T) -> &mut Self {
+        self.inner.from_message_id = from_message_id.into();
         self
     }
 }
@@ -105,3 +178,27 @@ impl AsRef<MessageForwardInfo> for RTDMessageForwardInfoBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            MessageForwardInfo::builder()
+                .origin(MessageForwardOrigin::Channel(
+                    MessageForwardOriginChannel::builder()
+                        .chat_id(9007199254740991i64)
+                        .message_id(9007199254740991i64)
+                        .author_signature("text")
+                        .build(),
+                ))
+                .date(i32::MAX)
+                .from_chat_id(9007199254740991i64)
+                .from_message_id(9007199254740991i64)
+                .build(),
+        );
+    }
+}
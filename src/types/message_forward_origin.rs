@@ -1,46 +1,44 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Contains information about the origin of a forwarded message
 pub trait TDMessageForwardOrigin: Debug + RObject {}
 
 /// Contains information about the origin of a forwarded message
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum MessageForwardOrigin {
-    #[doc(hidden)]
-    _Default(()),
     /// The message was originally a post in a channel
+    #[serde(rename = "messageForwardOriginChannel")]
     Channel(MessageForwardOriginChannel),
     /// The message was originally written by a user, which is hidden by their privacy settings
+    #[serde(rename = "messageForwardOriginHiddenUser")]
     HiddenUser(MessageForwardOriginHiddenUser),
     /// The message was originally written by a known user
+    #[serde(rename = "messageForwardOriginUser")]
     User(MessageForwardOriginUser),
 }
 
+/// Default value is default of [MessageForwardOriginChannel], builders still require the field to be set
 impl Default for MessageForwardOrigin {
     fn default() -> Self {
-        MessageForwardOrigin::_Default(())
+        MessageForwardOrigin::Channel(MessageForwardOriginChannel::default())
     }
 }
 
-impl<'de> Deserialize<'de> for MessageForwardOrigin {
-    fn deserialize<D>(deserializer: D) -> Result<MessageForwardOrigin, D::Error>
+impl Serialize for MessageForwardOrigin {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          MessageForwardOrigin,
-          (messageForwardOriginChannel, Channel);
-          (messageForwardOriginHiddenUser, HiddenUser);
-          (messageForwardOriginUser, User);
-
-        )(deserializer)
+        match self {
+            MessageForwardOrigin::Channel(t) => t.serialize(serializer),
+            MessageForwardOrigin::HiddenUser(t) => t.serialize(serializer),
+            MessageForwardOrigin::User(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -51,8 +49,6 @@ impl RObject for MessageForwardOrigin {
             MessageForwardOrigin::Channel(t) => t.td_name(),
             MessageForwardOrigin::HiddenUser(t) => t.td_name(),
             MessageForwardOrigin::User(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -61,8 +57,6 @@ impl RObject for MessageForwardOrigin {
             MessageForwardOrigin::Channel(t) => t.extra(),
             MessageForwardOrigin::HiddenUser(t) => t.extra(),
             MessageForwardOrigin::User(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -74,10 +68,6 @@ impl MessageForwardOrigin {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, MessageForwardOrigin::_Default(_))
-    }
 }
 
 impl AsRef<MessageForwardOrigin> for MessageForwardOrigin {
@@ -88,17 +78,18 @@ impl AsRef<MessageForwardOrigin> for MessageForwardOrigin {
 
 /// The message was originally a post in a channel
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "messageForwardOriginChannel")]
 pub struct MessageForwardOriginChannel {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Identifier of the chat from which the message was originally forwarded
-    chat_id: i64,
+    chat_id: ChatId,
     /// Message identifier of the original message; 0 if unknown
-    message_id: i64,
+    message_id: MessageId,
     /// Original post author signature
     author_signature: String,
 }
@@ -117,6 +108,24 @@ impl RObject for MessageForwardOriginChannel {
     }
 }
 
+impl PartialEq for MessageForwardOriginChannel {
+    fn eq(&self, other: &Self) -> bool {
+        self.chat_id == other.chat_id
+            && self.message_id == other.message_id
+            && self.author_signature == other.author_signature
+    }
+}
+
+impl Eq for MessageForwardOriginChannel {}
+
+impl std::hash::Hash for MessageForwardOriginChannel {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.chat_id, state);
+        std::hash::Hash::hash(&self.message_id, state);
+        std::hash::Hash::hash(&self.author_signature, state);
+    }
+}
+
 impl TDMessageForwardOrigin for MessageForwardOriginChannel {}
 
 impl MessageForwardOriginChannel {
@@ -124,47 +133,95 @@ impl MessageForwardOriginChannel {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessageForwardOriginChannelBuilder {
-        let mut inner = MessageForwardOriginChannel::default();
-        inner.td_name = "messageForwardOriginChannel".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessageForwardOriginChannelBuilder { inner }
+        RTDMessageForwardOriginChannelBuilder {
+            inner: MessageForwardOriginChannel::default(),
+            missing: vec!["chat_id", "author_signature"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessageForwardOriginChannelBuilder {
+        RTDMessageForwardOriginChannelBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessageForwardOriginChannelBuilder {
+        RTDMessageForwardOriginChannelBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn chat_id(&self) -> i64 {
+    pub fn chat_id(&self) -> ChatId {
         self.chat_id
     }
 
-    pub fn message_id(&self) -> i64 {
+    pub fn message_id(&self) -> MessageId {
         self.message_id
     }
 
     pub fn author_signature(&self) -> &String {
         &self.author_signature
     }
+
+    pub fn into_author_signature(self) -> String {
+        self.author_signature
+    }
+
+    pub fn with_chat_id<T: Into<ChatId>>(mut self, chat_id: T) -> Self {
+        self.chat_id = chat_id.into();
+        self
+    }
+
+    pub fn with_message_id<T: Into<MessageId>>(mut self, message_id: T) -> Self {
+        self.message_id = message_id.into();
+        self
+    }
+
+    pub fn with_author_signature<T: AsRef<str>>(mut self, author_signature: T) -> Self {
+        self.author_signature = author_signature.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDMessageForwardOriginChannelBuilder {
     inner: MessageForwardOriginChannel,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessageForwardOriginChannelBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MessageForwardOriginChannel {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MessageForwardOriginChannel> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "MessageForwardOriginChannel",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
-        self.inner.chat_id = chat_id;
+    pub fn chat_id<T: Into<ChatId>>(&mut self, chat_id: T) -> &mut Self {
+        self.inner.chat_id = chat_id.into();
+        self.missing.retain(|f| *f != "chat_id");
         self
     }
 
-    pub fn message_id(&mut self, message_id: i64) -> &mut Self {
-        self.inner.message_id = message_id;
+    pub fn message_id<T: Into<MessageId>>(&mut self, message_id: T) -> &mut Self {
+        self.inner.message_id = message_id.into();
         self
     }
 
     pub fn author_signature<T: AsRef<str>>(&mut self, author_signature: T) -> &mut Self {
         self.inner.author_signature = author_signature.as_ref().to_string();
+        self.missing.retain(|f| *f != "author_signature");
         self
     }
 }
@@ -183,12 +240,13 @@ impl AsRef<MessageForwardOriginChannel> for RTDMessageForwardOriginChannelBuilde
 
 /// The message was originally written by a user, which is hidden by their privacy settings
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "messageForwardOriginHiddenUser")]
 pub struct MessageForwardOriginHiddenUser {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Name of the sender
     sender_name: String,
@@ -208,6 +266,20 @@ impl RObject for MessageForwardOriginHiddenUser {
     }
 }
 
+impl PartialEq for MessageForwardOriginHiddenUser {
+    fn eq(&self, other: &Self) -> bool {
+        self.sender_name == other.sender_name
+    }
+}
+
+impl Eq for MessageForwardOriginHiddenUser {}
+
+impl std::hash::Hash for MessageForwardOriginHiddenUser {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.sender_name, state);
+    }
+}
+
 impl TDMessageForwardOrigin for MessageForwardOriginHiddenUser {}
 
 impl MessageForwardOriginHiddenUser {
@@ -215,29 +287,66 @@ impl MessageForwardOriginHiddenUser {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessageForwardOriginHiddenUserBuilder {
-        let mut inner = MessageForwardOriginHiddenUser::default();
-        inner.td_name = "messageForwardOriginHiddenUser".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessageForwardOriginHiddenUserBuilder { inner }
+        RTDMessageForwardOriginHiddenUserBuilder {
+            inner: MessageForwardOriginHiddenUser::default(),
+            missing: vec!["sender_name"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessageForwardOriginHiddenUserBuilder {
+        RTDMessageForwardOriginHiddenUserBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessageForwardOriginHiddenUserBuilder {
+        RTDMessageForwardOriginHiddenUserBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn sender_name(&self) -> &String {
         &self.sender_name
     }
+
+    pub fn into_sender_name(self) -> String {
+        self.sender_name
+    }
+
+    pub fn with_sender_name<T: AsRef<str>>(mut self, sender_name: T) -> Self {
+        self.sender_name = sender_name.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDMessageForwardOriginHiddenUserBuilder {
     inner: MessageForwardOriginHiddenUser,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessageForwardOriginHiddenUserBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MessageForwardOriginHiddenUser {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MessageForwardOriginHiddenUser> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "MessageForwardOriginHiddenUser",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn sender_name<T: AsRef<str>>(&mut self, sender_name: T) -> &mut Self {
         self.inner.sender_name = sender_name.as_ref().to_string();
+        self.missing.retain(|f| *f != "sender_name");
         self
     }
 }
@@ -256,15 +365,16 @@ impl AsRef<MessageForwardOriginHiddenUser> for RTDMessageForwardOriginHiddenUser
 
 /// The message was originally written by a known user
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "messageForwardOriginUser")]
 pub struct MessageForwardOriginUser {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Identifier of the user that originally sent the message
-    sender_user_id: i64,
+    sender_user_id: UserId,
 }
 
 impl RObject for MessageForwardOriginUser {
@@ -281,6 +391,20 @@ impl RObject for MessageForwardOriginUser {
     }
 }
 
+impl PartialEq for MessageForwardOriginUser {
+    fn eq(&self, other: &Self) -> bool {
+        self.sender_user_id == other.sender_user_id
+    }
+}
+
+impl Eq for MessageForwardOriginUser {}
+
+impl std::hash::Hash for MessageForwardOriginUser {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.sender_user_id, state);
+    }
+}
+
 impl TDMessageForwardOrigin for MessageForwardOriginUser {}
 
 impl MessageForwardOriginUser {
@@ -288,29 +412,62 @@ impl MessageForwardOriginUser {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessageForwardOriginUserBuilder {
-        let mut inner = MessageForwardOriginUser::default();
-        inner.td_name = "messageForwardOriginUser".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessageForwardOriginUserBuilder { inner }
+        RTDMessageForwardOriginUserBuilder {
+            inner: MessageForwardOriginUser::default(),
+            missing: vec!["sender_user_id"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessageForwardOriginUserBuilder {
+        RTDMessageForwardOriginUserBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessageForwardOriginUserBuilder {
+        RTDMessageForwardOriginUserBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn sender_user_id(&self) -> i64 {
+    pub fn sender_user_id(&self) -> UserId {
         self.sender_user_id
     }
+
+    pub fn with_sender_user_id<T: Into<UserId>>(mut self, sender_user_id: T) -> Self {
+        self.sender_user_id = sender_user_id.into();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDMessageForwardOriginUserBuilder {
     inner: MessageForwardOriginUser,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessageForwardOriginUserBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MessageForwardOriginUser {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MessageForwardOriginUser> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "MessageForwardOriginUser",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn sender_user_id(&mut self, sender_user_id: i64) -> &mut Self {
-        self.inner.sender_user_id = sender_user_id;
+    pub fn sender_user_id<T: Into<UserId>>(&mut self, sender_user_id: T) -> &mut Self {
+        self.inner.sender_user_id = sender_user_id.into();
+        self.missing.retain(|f| *f != "sender_user_id");
         self
     }
 }
@@ -326,3 +483,30 @@ impl AsRef<MessageForwardOriginUser> for RTDMessageForwardOriginUserBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(MessageForwardOrigin::Channel(
+            MessageForwardOriginChannel::builder()
+                .chat_id(9007199254740991i64)
+                .message_id(9007199254740991i64)
+                .author_signature("text")
+                .build(),
+        ));
+        assert_round_trip(MessageForwardOrigin::HiddenUser(
+            MessageForwardOriginHiddenUser::builder()
+                .sender_name("text")
+                .build(),
+        ));
+        assert_round_trip(MessageForwardOrigin::User(
+            MessageForwardOriginUser::builder()
+                .sender_user_id(i32::MAX)
+                .build(),
+        ));
+    }
+}
@@ -1,20 +1,20 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains information about a link to a message in a chat
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "messageLinkInfo")]
 pub struct MessageLinkInfo {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// True, if the link is a public link for a message in a chat
     is_public: bool,
     /// If found, identifier of the chat to which the message belongs, 0 otherwise
-    chat_id: i64,
+    chat_id: ChatId,
     /// If found, the linked message; may be null
     message: Option<Message>,
     /// True, if the whole media album to which the message belongs is linked
@@ -35,22 +35,45 @@ impl RObject for MessageLinkInfo {
     }
 }
 
+impl PartialEq for MessageLinkInfo {
+    fn eq(&self, other: &Self) -> bool {
+        self.is_public == other.is_public
+            && self.chat_id == other.chat_id
+            && self.message == other.message
+            && self.for_album == other.for_album
+    }
+}
+
 impl MessageLinkInfo {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessageLinkInfoBuilder {
-        let mut inner = MessageLinkInfo::default();
-        inner.td_name = "messageLinkInfo".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessageLinkInfoBuilder { inner }
+        RTDMessageLinkInfoBuilder {
+            inner: MessageLinkInfo::default(),
+            missing: vec!["chat_id"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessageLinkInfoBuilder {
+        RTDMessageLinkInfoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessageLinkInfoBuilder {
+        RTDMessageLinkInfoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn is_public(&self) -> bool {
         self.is_public
     }
 
-    pub fn chat_id(&self) -> i64 {
+    pub fn chat_id(&self) -> ChatId {
         self.chat_id
     }
 
@@ -61,25 +84,63 @@ impl MessageLinkInfo {
     pub fn for_album(&self) -> bool {
         self.for_album
     }
+
+    pub fn into_message(self) -> Option<Message> {
+        self.message
+    }
+
+    pub fn with_is_public(mut self, is_public: bool) -> Self {
+        self.is_public = is_public;
+        self
+    }
+
+    pub fn with_chat_id<T: Into<ChatId>>(mut self, chat_id: T) -> Self {
+        self.chat_id = chat_id.into();
+        self
+    }
+
+    pub fn with_message<T: AsRef<Message>>(mut self, message: T) -> Self {
+        self.message = Some(message.as_ref().clone());
+        self
+    }
+
+    pub fn with_for_album(mut self, for_album: bool) -> Self {
+        self.for_album = for_album;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDMessageLinkInfoBuilder {
     inner: MessageLinkInfo,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessageLinkInfoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MessageLinkInfo {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MessageLinkInfo> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "MessageLinkInfo",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn is_public(&mut self, is_public: bool) -> &mut Self {
         self.inner.is_public = is_public;
         self
     }
 
-    pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
-        self.inner.chat_id = chat_id;
+    pub fn chat_id<T: Into<ChatId>>(&mut self, chat_id: T) -> &mut Self {
+        self.inner.chat_id = chat_id.into();
+        self.missing.retain(|f| *f != "chat_id");
         self
     }
 
@@ -105,3 +166,20 @@ impl AsRef<MessageLinkInfo> for RTDMessageLinkInfoBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            MessageLinkInfo::builder()
+                .is_public(true)
+                .chat_id(9007199254740991i64)
+                .for_album(true)
+                .build(),
+        );
+    }
+}
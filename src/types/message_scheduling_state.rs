@@ -1,43 +1,40 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Contains information about the time when a scheduled message will be sent
 pub trait TDMessageSchedulingState: Debug + RObject {}
 
 /// Contains information about the time when a scheduled message will be sent
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum MessageSchedulingState {
-    #[doc(hidden)]
-    _Default(()),
     /// The message will be sent at the specified date
+    #[serde(rename = "messageSchedulingStateSendAtDate")]
     SendAtDate(MessageSchedulingStateSendAtDate),
     /// The message will be sent when the peer will be online. Applicable to private chats only and when the exact online status of the peer is known
+    #[serde(rename = "messageSchedulingStateSendWhenOnline")]
     SendWhenOnline(MessageSchedulingStateSendWhenOnline),
 }
 
+/// Default value is default of [MessageSchedulingStateSendAtDate], builders still require the field to be set
 impl Default for MessageSchedulingState {
     fn default() -> Self {
-        MessageSchedulingState::_Default(())
+        MessageSchedulingState::SendAtDate(MessageSchedulingStateSendAtDate::default())
     }
 }
 
-impl<'de> Deserialize<'de> for MessageSchedulingState {
-    fn deserialize<D>(deserializer: D) -> Result<MessageSchedulingState, D::Error>
+impl Serialize for MessageSchedulingState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          MessageSchedulingState,
-          (messageSchedulingStateSendAtDate, SendAtDate);
-          (messageSchedulingStateSendWhenOnline, SendWhenOnline);
-
-        )(deserializer)
+        match self {
+            MessageSchedulingState::SendAtDate(t) => t.serialize(serializer),
+            MessageSchedulingState::SendWhenOnline(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -47,8 +44,6 @@ impl RObject for MessageSchedulingState {
         match self {
             MessageSchedulingState::SendAtDate(t) => t.td_name(),
             MessageSchedulingState::SendWhenOnline(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -56,8 +51,6 @@ impl RObject for MessageSchedulingState {
         match self {
             MessageSchedulingState::SendAtDate(t) => t.extra(),
             MessageSchedulingState::SendWhenOnline(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -69,10 +62,6 @@ impl MessageSchedulingState {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, MessageSchedulingState::_Default(_))
-    }
 }
 
 impl AsRef<MessageSchedulingState> for MessageSchedulingState {
@@ -83,15 +72,16 @@ impl AsRef<MessageSchedulingState> for MessageSchedulingState {
 
 /// The message will be sent at the specified date
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "messageSchedulingStateSendAtDate")]
 pub struct MessageSchedulingStateSendAtDate {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Date the message will be sent. The date must be within 367 days in the future
-    send_date: i64,
+    send_date: i32,
 }
 
 impl RObject for MessageSchedulingStateSendAtDate {
@@ -108,6 +98,20 @@ impl RObject for MessageSchedulingStateSendAtDate {
     }
 }
 
+impl PartialEq for MessageSchedulingStateSendAtDate {
+    fn eq(&self, other: &Self) -> bool {
+        self.send_date == other.send_date
+    }
+}
+
+impl Eq for MessageSchedulingStateSendAtDate {}
+
+impl std::hash::Hash for MessageSchedulingStateSendAtDate {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.send_date, state);
+    }
+}
+
 impl TDMessageSchedulingState for MessageSchedulingStateSendAtDate {}
 
 impl MessageSchedulingStateSendAtDate {
@@ -115,29 +119,62 @@ impl MessageSchedulingStateSendAtDate {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessageSchedulingStateSendAtDateBuilder {
-        let mut inner = MessageSchedulingStateSendAtDate::default();
-        inner.td_name = "messageSchedulingStateSendAtDate".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessageSchedulingStateSendAtDateBuilder { inner }
+        RTDMessageSchedulingStateSendAtDateBuilder {
+            inner: MessageSchedulingStateSendAtDate::default(),
+            missing: vec!["send_date"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessageSchedulingStateSendAtDateBuilder {
+        RTDMessageSchedulingStateSendAtDateBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessageSchedulingStateSendAtDateBuilder {
+        RTDMessageSchedulingStateSendAtDateBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn send_date(&self) -> i64 {
+    pub fn send_date(&self) -> i32 {
         self.send_date
     }
+
+    pub fn with_send_date(mut self, send_date: i32) -> Self {
+        self.send_date = send_date;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDMessageSchedulingStateSendAtDateBuilder {
     inner: MessageSchedulingStateSendAtDate,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessageSchedulingStateSendAtDateBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MessageSchedulingStateSendAtDate {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MessageSchedulingStateSendAtDate> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "MessageSchedulingStateSendAtDate",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn send_date(&mut self, send_date: i64) -> &mut Self {
+    pub fn send_date(&mut self, send_date: i32) -> &mut Self {
         self.inner.send_date = send_date;
+        self.missing.retain(|f| *f != "send_date");
         self
     }
 }
@@ -156,12 +193,13 @@ impl AsRef<MessageSchedulingStateSendAtDate> for RTDMessageSchedulingStateSendAt
 
 /// The message will be sent when the peer will be online. Applicable to private chats only and when the exact online status of the peer is known
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "messageSchedulingStateSendWhenOnline")]
 pub struct MessageSchedulingStateSendWhenOnline {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -179,6 +217,18 @@ impl RObject for MessageSchedulingStateSendWhenOnline {
     }
 }
 
+impl PartialEq for MessageSchedulingStateSendWhenOnline {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for MessageSchedulingStateSendWhenOnline {}
+
+impl std::hash::Hash for MessageSchedulingStateSendWhenOnline {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDMessageSchedulingState for MessageSchedulingStateSendWhenOnline {}
 
 impl MessageSchedulingStateSendWhenOnline {
@@ -186,22 +236,49 @@ impl MessageSchedulingStateSendWhenOnline {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessageSchedulingStateSendWhenOnlineBuilder {
-        let mut inner = MessageSchedulingStateSendWhenOnline::default();
-        inner.td_name = "messageSchedulingStateSendWhenOnline".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessageSchedulingStateSendWhenOnlineBuilder { inner }
+        RTDMessageSchedulingStateSendWhenOnlineBuilder {
+            inner: MessageSchedulingStateSendWhenOnline::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessageSchedulingStateSendWhenOnlineBuilder {
+        RTDMessageSchedulingStateSendWhenOnlineBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessageSchedulingStateSendWhenOnlineBuilder {
+        RTDMessageSchedulingStateSendWhenOnlineBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDMessageSchedulingStateSendWhenOnlineBuilder {
     inner: MessageSchedulingStateSendWhenOnline,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessageSchedulingStateSendWhenOnlineBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MessageSchedulingStateSendWhenOnline {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MessageSchedulingStateSendWhenOnline> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "MessageSchedulingStateSendWhenOnline",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<MessageSchedulingStateSendWhenOnline> for MessageSchedulingStateSendWhenOnline {
@@ -217,3 +294,21 @@ impl AsRef<MessageSchedulingStateSendWhenOnline>
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(MessageSchedulingState::SendAtDate(
+            MessageSchedulingStateSendAtDate::builder()
+                .send_date(i32::MAX)
+                .build(),
+        ));
+        assert_round_trip(MessageSchedulingState::SendWhenOnline(
+            MessageSchedulingStateSendWhenOnline::builder().build(),
+        ));
+    }
+}
@@ -1,43 +1,40 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Contains information about the sending state of the message
 pub trait TDMessageSendingState: Debug + RObject {}
 
 /// Contains information about the sending state of the message
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "@type")]
 pub enum MessageSendingState {
-    #[doc(hidden)]
-    _Default(()),
     /// The message failed to be sent
+    #[serde(rename = "messageSendingStateFailed")]
     Failed(MessageSendingStateFailed),
     /// The message is being sent now, but has not yet been delivered to the server
+    #[serde(rename = "messageSendingStatePending")]
     Pending(MessageSendingStatePending),
 }
 
+/// Default value is default of [MessageSendingStateFailed], builders still require the field to be set
 impl Default for MessageSendingState {
     fn default() -> Self {
-        MessageSendingState::_Default(())
+        MessageSendingState::Failed(MessageSendingStateFailed::default())
     }
 }
 
-impl<'de> Deserialize<'de> for MessageSendingState {
-    fn deserialize<D>(deserializer: D) -> Result<MessageSendingState, D::Error>
+impl Serialize for MessageSendingState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          MessageSendingState,
-          (messageSendingStateFailed, Failed);
-          (messageSendingStatePending, Pending);
-
-        )(deserializer)
+        match self {
+            MessageSendingState::Failed(t) => t.serialize(serializer),
+            MessageSendingState::Pending(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -47,8 +44,6 @@ impl RObject for MessageSendingState {
         match self {
             MessageSendingState::Failed(t) => t.td_name(),
             MessageSendingState::Pending(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -56,8 +51,6 @@ impl RObject for MessageSendingState {
         match self {
             MessageSendingState::Failed(t) => t.extra(),
             MessageSendingState::Pending(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -69,10 +62,6 @@ impl MessageSendingState {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, MessageSendingState::_Default(_))
-    }
 }
 
 impl AsRef<MessageSendingState> for MessageSendingState {
@@ -83,15 +72,16 @@ impl AsRef<MessageSendingState> for MessageSendingState {
 
 /// The message failed to be sent
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "messageSendingStateFailed")]
 pub struct MessageSendingStateFailed {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// An error code; 0 if unknown
-    error_code: i64,
+    error_code: i32,
     /// Error message
     error_message: String,
     /// True, if the message can be re-sent
@@ -114,6 +104,15 @@ impl RObject for MessageSendingStateFailed {
     }
 }
 
+impl PartialEq for MessageSendingStateFailed {
+    fn eq(&self, other: &Self) -> bool {
+        self.error_code == other.error_code
+            && self.error_message == other.error_message
+            && self.can_retry == other.can_retry
+            && self.retry_after == other.retry_after
+    }
+}
+
 impl TDMessageSendingState for MessageSendingStateFailed {}
 
 impl MessageSendingStateFailed {
@@ -121,13 +120,27 @@ impl MessageSendingStateFailed {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessageSendingStateFailedBuilder {
-        let mut inner = MessageSendingStateFailed::default();
-        inner.td_name = "messageSendingStateFailed".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessageSendingStateFailedBuilder { inner }
+        RTDMessageSendingStateFailedBuilder {
+            inner: MessageSendingStateFailed::default(),
+            missing: vec!["error_message", "retry_after"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessageSendingStateFailedBuilder {
+        RTDMessageSendingStateFailedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessageSendingStateFailedBuilder {
+        RTDMessageSendingStateFailedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn error_code(&self) -> i64 {
+    pub fn error_code(&self) -> i32 {
         self.error_code
     }
 
@@ -142,25 +155,63 @@ impl MessageSendingStateFailed {
     pub fn retry_after(&self) -> f32 {
         self.retry_after
     }
+
+    pub fn into_error_message(self) -> String {
+        self.error_message
+    }
+
+    pub fn with_error_code(mut self, error_code: i32) -> Self {
+        self.error_code = error_code;
+        self
+    }
+
+    pub fn with_error_message<T: AsRef<str>>(mut self, error_message: T) -> Self {
+        self.error_message = error_message.as_ref().to_string();
+        self
+    }
+
+    pub fn with_can_retry(mut self, can_retry: bool) -> Self {
+        self.can_retry = can_retry;
+        self
+    }
+
+    pub fn with_retry_after(mut self, retry_after: f32) -> Self {
+        self.retry_after = retry_after;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDMessageSendingStateFailedBuilder {
     inner: MessageSendingStateFailed,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessageSendingStateFailedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MessageSendingStateFailed {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MessageSendingStateFailed> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "MessageSendingStateFailed",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn error_code(&mut self, error_code: i64) -> &mut Self {
+    pub fn error_code(&mut self, error_code: i32) -> &mut Self {
         self.inner.error_code = error_code;
         self
     }
 
     pub fn error_message<T: AsRef<str>>(&mut self, error_message: T) -> &mut Self {
         self.inner.error_message = error_message.as_ref().to_string();
+        self.missing.retain(|f| *f != "error_message");
         self
     }
 
@@ -171,6 +222,7 @@ impl RTDMessageSendingStateFailedBuilder {
 
     pub fn retry_after(&mut self, retry_after: f32) -> &mut Self {
         self.inner.retry_after = retry_after;
+        self.missing.retain(|f| *f != "retry_after");
         self
     }
 }
@@ -189,12 +241,13 @@ impl AsRef<MessageSendingStateFailed> for RTDMessageSendingStateFailedBuilder {
 
 /// The message is being sent now, but has not yet been delivered to the server
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "messageSendingStatePending")]
 pub struct MessageSendingStatePending {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -212,6 +265,18 @@ impl RObject for MessageSendingStatePending {
     }
 }
 
+impl PartialEq for MessageSendingStatePending {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for MessageSendingStatePending {}
+
+impl std::hash::Hash for MessageSendingStatePending {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDMessageSendingState for MessageSendingStatePending {}
 
 impl MessageSendingStatePending {
@@ -219,22 +284,49 @@ impl MessageSendingStatePending {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessageSendingStatePendingBuilder {
-        let mut inner = MessageSendingStatePending::default();
-        inner.td_name = "messageSendingStatePending".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessageSendingStatePendingBuilder { inner }
+        RTDMessageSendingStatePendingBuilder {
+            inner: MessageSendingStatePending::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessageSendingStatePendingBuilder {
+        RTDMessageSendingStatePendingBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessageSendingStatePendingBuilder {
+        RTDMessageSendingStatePendingBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDMessageSendingStatePendingBuilder {
     inner: MessageSendingStatePending,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessageSendingStatePendingBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> MessageSendingStatePending {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<MessageSendingStatePending> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "MessageSendingStatePending",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<MessageSendingStatePending> for MessageSendingStatePending {
@@ -248,3 +340,24 @@ impl AsRef<MessageSendingStatePending> for RTDMessageSendingStatePendingBuilder
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(MessageSendingState::Failed(
+            MessageSendingStateFailed::builder()
+                .error_code(i32::MAX)
+                .error_message("text")
+                .can_retry(true)
+                .retry_after(1.5)
+                .build(),
+        ));
+        assert_round_trip(MessageSendingState::Pending(
+            MessageSendingStatePending::builder().build(),
+        ));
+    }
+}
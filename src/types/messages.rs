@@ -1,18 +1,18 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains a list of messages
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "messages")]
 pub struct Messages {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Approximate total count of messages found
-    total_count: i64,
+    total_count: i32,
     /// List of messages; messages may be null
     messages: Vec<Option<Message>>,
 }
@@ -31,38 +31,83 @@ impl RObject for Messages {
     }
 }
 
+impl PartialEq for Messages {
+    fn eq(&self, other: &Self) -> bool {
+        self.total_count == other.total_count && self.messages == other.messages
+    }
+}
+
 impl Messages {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMessagesBuilder {
-        let mut inner = Messages::default();
-        inner.td_name = "messages".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMessagesBuilder { inner }
+        RTDMessagesBuilder {
+            inner: Messages::default(),
+            missing: vec!["total_count"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMessagesBuilder {
+        RTDMessagesBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMessagesBuilder {
+        RTDMessagesBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn total_count(&self) -> i64 {
+    pub fn total_count(&self) -> i32 {
         self.total_count
     }
 
     pub fn messages(&self) -> &Vec<Option<Message>> {
         &self.messages
     }
+
+    pub fn into_messages(self) -> Vec<Option<Message>> {
+        self.messages
+    }
+
+    pub fn with_total_count(mut self, total_count: i32) -> Self {
+        self.total_count = total_count;
+        self
+    }
+
+    pub fn with_messages(mut self, messages: Vec<Option<Message>>) -> Self {
+        self.messages = messages;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDMessagesBuilder {
     inner: Messages,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMessagesBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> Messages {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<Messages> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("Messages", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn total_count(&mut self, total_count: i64) -> &mut Self {
+    pub fn total_count(&mut self, total_count: i32) -> &mut Self {
         self.inner.total_count = total_count;
+        self.missing.retain(|f| *f != "total_count");
         self
     }
 
@@ -83,3 +128,19 @@ impl AsRef<Messages> for RTDMessagesBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            Messages::builder()
+                .total_count(i32::MAX)
+                .messages(vec![])
+                .build(),
+        );
+    }
+}
@@ -1,22 +1,23 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Thumbnail image of a very poor quality and low resolution
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "minithumbnail")]
 pub struct Minithumbnail {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Thumbnail width, usually doesn't exceed 40
-    width: i64,
+    width: i32,
     /// Thumbnail height, usually doesn't exceed 40
-    height: i64,
+    height: i32,
     /// The thumbnail in JPEG format
-    data: String,
+    #[serde(with = "super::_common::bytes")]
+    data: Vec<u8>,
 }
 
 impl RObject for Minithumbnail {
@@ -33,52 +34,117 @@ impl RObject for Minithumbnail {
     }
 }
 
+impl PartialEq for Minithumbnail {
+    fn eq(&self, other: &Self) -> bool {
+        self.width == other.width && self.height == other.height && self.data == other.data
+    }
+}
+
+impl Eq for Minithumbnail {}
+
+impl std::hash::Hash for Minithumbnail {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.width, state);
+        std::hash::Hash::hash(&self.height, state);
+        std::hash::Hash::hash(&self.data, state);
+    }
+}
+
 impl Minithumbnail {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDMinithumbnailBuilder {
-        let mut inner = Minithumbnail::default();
-        inner.td_name = "minithumbnail".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDMinithumbnailBuilder { inner }
+        RTDMinithumbnailBuilder {
+            inner: Minithumbnail::default(),
+            missing: vec!["width", "height", "data"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDMinithumbnailBuilder {
+        RTDMinithumbnailBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDMinithumbnailBuilder {
+        RTDMinithumbnailBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn width(&self) -> i64 {
+    pub fn width(&self) -> i32 {
         self.width
     }
 
-    pub fn height(&self) -> i64 {
+    pub fn height(&self) -> i32 {
         self.height
     }
 
-    pub fn data(&self) -> &String {
+    pub fn data(&self) -> &[u8] {
         &self.data
     }
+
+    pub fn into_data(self) -> Vec<u8> {
+        self.data
+    }
+
+    pub fn with_width(mut self, width: i32) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn with_height(mut self, height: i32) -> Self {
+        self.height = height;
+        self
+    }
+
+    pub fn with_data<T: AsRef<[u8]>>(mut self, data: T) -> Self {
+        self.data = data.as_ref().to_vec();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDMinithumbnailBuilder {
     inner: Minithumbnail,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDMinithumbnailBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> Minithumbnail {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<Minithumbnail> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "Minithumbnail",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn width(&mut self, width: i64) -> &mut Self {
+    pub fn width(&mut self, width: i32) -> &mut Self {
         self.inner.width = width;
+        self.missing.retain(|f| *f != "width");
         self
     }
 
-    pub fn height(&mut self, height: i64) -> &mut Self {
+    pub fn height(&mut self, height: i32) -> &mut Self {
         self.inner.height = height;
+        self.missing.retain(|f| *f != "height");
         self
     }
 
-    pub fn data<T: AsRef<str>>(&mut self, data: T) -> &mut Self {
-        self.inner.data = data.as_ref().to_string();
+    pub fn data<T: AsRef<[u8]>>(&mut self, data: T) -> &mut Self {
+        self.inner.data = data.as_ref().to_vec();
+        self.missing.retain(|f| *f != "data");
         self
     }
 }
@@ -94,3 +160,20 @@ impl AsRef<Minithumbnail> for RTDMinithumbnailBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            Minithumbnail::builder()
+                .width(i32::MAX)
+                .height(i32::MAX)
+                .data(b"\x00\x01\xfe\xff")
+                .build(),
+        );
+    }
+}
@@ -1,12 +1,22 @@
 //! Module provides all TDlib types.
 //! For types details you can see [TDlib API Scheme](https://github.com/tdlib/td/blob/master/td/generate/scheme/td_api.tl)
-pub use self::_common::{RFunction, RObject, TdType};
+pub use self::_common::{RFunction, RObject};
+pub use self::_ids::{
+    BasicGroupId, CallIdentifier, ChatId, FileId, MessageId, SecretChatId, SupergroupId, UserId,
+};
+pub use self::_td_type::TdType;
+
+/// TDlib version, which types are generated for.
+pub const TDLIB_VERSION: &str = "1.6";
 
 #[allow(dead_code, unused_imports)]
-pub(crate) use self::_common::from_json;
+pub(crate) use self::_common::{from_json, TdHeader};
 
 #[macro_use]
 mod _common;
+mod _display;
+mod _ids;
+mod _td_type;
 
 pub use self::account_ttl::*;
 pub use self::address::*;
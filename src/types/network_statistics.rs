@@ -1,18 +1,18 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// A full list of available network statistic entries
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "networkStatistics")]
 pub struct NetworkStatistics {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Point in time (Unix timestamp) when the app began collecting statistics
-    since_date: i64,
+    since_date: i32,
     /// Network statistics entries
     entries: Vec<NetworkStatisticsEntry>,
 }
@@ -31,43 +31,92 @@ impl RObject for NetworkStatistics {
     }
 }
 
+impl PartialEq for NetworkStatistics {
+    fn eq(&self, other: &Self) -> bool {
+        self.since_date == other.since_date && self.entries == other.entries
+    }
+}
+
 impl NetworkStatistics {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDNetworkStatisticsBuilder {
-        let mut inner = NetworkStatistics::default();
-        inner.td_name = "networkStatistics".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDNetworkStatisticsBuilder { inner }
+        RTDNetworkStatisticsBuilder {
+            inner: NetworkStatistics::default(),
+            missing: vec!["since_date", "entries"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDNetworkStatisticsBuilder {
+        RTDNetworkStatisticsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDNetworkStatisticsBuilder {
+        RTDNetworkStatisticsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn since_date(&self) -> i64 {
+    pub fn since_date(&self) -> i32 {
         self.since_date
     }
 
     pub fn entries(&self) -> &Vec<NetworkStatisticsEntry> {
         &self.entries
     }
+
+    pub fn into_entries(self) -> Vec<NetworkStatisticsEntry> {
+        self.entries
+    }
+
+    pub fn with_since_date(mut self, since_date: i32) -> Self {
+        self.since_date = since_date;
+        self
+    }
+
+    pub fn with_entries(mut self, entries: Vec<NetworkStatisticsEntry>) -> Self {
+        self.entries = entries;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDNetworkStatisticsBuilder {
     inner: NetworkStatistics,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDNetworkStatisticsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> NetworkStatistics {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<NetworkStatistics> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "NetworkStatistics",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn since_date(&mut self, since_date: i64) -> &mut Self {
+    pub fn since_date(&mut self, since_date: i32) -> &mut Self {
         self.inner.since_date = since_date;
+        self.missing.retain(|f| *f != "since_date");
         self
     }
 
     pub fn entries(&mut self, entries: Vec<NetworkStatisticsEntry>) -> &mut Self {
         self.inner.entries = entries;
+        self.missing.retain(|f| *f != "entries");
         self
     }
 }
@@ -83,3 +132,19 @@ impl AsRef<NetworkStatistics> for RTDNetworkStatisticsBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            NetworkStatistics::builder()
+                .since_date(i32::MAX)
+                .entries(vec![])
+                .build(),
+        );
+    }
+}
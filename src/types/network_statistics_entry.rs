@@ -1,43 +1,40 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Contains statistics about network usage
 pub trait TDNetworkStatisticsEntry: Debug + RObject {}
 
 /// Contains statistics about network usage
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "@type")]
 pub enum NetworkStatisticsEntry {
-    #[doc(hidden)]
-    _Default(()),
     /// Contains information about the total amount of data that was used for calls
+    #[serde(rename = "networkStatisticsEntryCall")]
     Call(NetworkStatisticsEntryCall),
     /// Contains information about the total amount of data that was used to send and receive files
+    #[serde(rename = "networkStatisticsEntryFile")]
     File(NetworkStatisticsEntryFile),
 }
 
+/// Default value is default of [NetworkStatisticsEntryCall], builders still require the field to be set
 impl Default for NetworkStatisticsEntry {
     fn default() -> Self {
-        NetworkStatisticsEntry::_Default(())
+        NetworkStatisticsEntry::Call(NetworkStatisticsEntryCall::default())
     }
 }
 
-impl<'de> Deserialize<'de> for NetworkStatisticsEntry {
-    fn deserialize<D>(deserializer: D) -> Result<NetworkStatisticsEntry, D::Error>
+impl Serialize for NetworkStatisticsEntry {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          NetworkStatisticsEntry,
-          (networkStatisticsEntryCall, Call);
-          (networkStatisticsEntryFile, File);
-
-        )(deserializer)
+        match self {
+            NetworkStatisticsEntry::Call(t) => t.serialize(serializer),
+            NetworkStatisticsEntry::File(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -47,8 +44,6 @@ impl RObject for NetworkStatisticsEntry {
         match self {
             NetworkStatisticsEntry::Call(t) => t.td_name(),
             NetworkStatisticsEntry::File(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -56,8 +51,6 @@ impl RObject for NetworkStatisticsEntry {
         match self {
             NetworkStatisticsEntry::Call(t) => t.extra(),
             NetworkStatisticsEntry::File(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -69,10 +62,6 @@ impl NetworkStatisticsEntry {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, NetworkStatisticsEntry::_Default(_))
-    }
 }
 
 impl AsRef<NetworkStatisticsEntry> for NetworkStatisticsEntry {
@@ -83,12 +72,13 @@ impl AsRef<NetworkStatisticsEntry> for NetworkStatisticsEntry {
 
 /// Contains information about the total amount of data that was used for calls
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "networkStatisticsEntryCall")]
 pub struct NetworkStatisticsEntryCall {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Type of the network the data was sent through. Call setNetworkType to maintain the actual network type
     network_type: NetworkType,
@@ -114,6 +104,15 @@ impl RObject for NetworkStatisticsEntryCall {
     }
 }
 
+impl PartialEq for NetworkStatisticsEntryCall {
+    fn eq(&self, other: &Self) -> bool {
+        self.network_type == other.network_type
+            && self.sent_bytes == other.sent_bytes
+            && self.received_bytes == other.received_bytes
+            && self.duration == other.duration
+    }
+}
+
 impl TDNetworkStatisticsEntry for NetworkStatisticsEntryCall {}
 
 impl NetworkStatisticsEntryCall {
@@ -121,10 +120,24 @@ impl NetworkStatisticsEntryCall {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDNetworkStatisticsEntryCallBuilder {
-        let mut inner = NetworkStatisticsEntryCall::default();
-        inner.td_name = "networkStatisticsEntryCall".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDNetworkStatisticsEntryCallBuilder { inner }
+        RTDNetworkStatisticsEntryCallBuilder {
+            inner: NetworkStatisticsEntryCall::default(),
+            missing: vec!["network_type", "sent_bytes", "received_bytes", "duration"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDNetworkStatisticsEntryCallBuilder {
+        RTDNetworkStatisticsEntryCallBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDNetworkStatisticsEntryCallBuilder {
+        RTDNetworkStatisticsEntryCallBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn network_type(&self) -> &NetworkType {
@@ -142,35 +155,76 @@ impl NetworkStatisticsEntryCall {
     pub fn duration(&self) -> f32 {
         self.duration
     }
+
+    pub fn into_network_type(self) -> NetworkType {
+        self.network_type
+    }
+
+    pub fn with_network_type<T: AsRef<NetworkType>>(mut self, network_type: T) -> Self {
+        self.network_type = network_type.as_ref().clone();
+        self
+    }
+
+    pub fn with_sent_bytes(mut self, sent_bytes: i64) -> Self {
+        self.sent_bytes = sent_bytes;
+        self
+    }
+
+    pub fn with_received_bytes(mut self, received_bytes: i64) -> Self {
+        self.received_bytes = received_bytes;
+        self
+    }
+
+    pub fn with_duration(mut self, duration: f32) -> Self {
+        self.duration = duration;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDNetworkStatisticsEntryCallBuilder {
     inner: NetworkStatisticsEntryCall,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDNetworkStatisticsEntryCallBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> NetworkStatisticsEntryCall {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<NetworkStatisticsEntryCall> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "NetworkStatisticsEntryCall",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn network_type<T: AsRef<NetworkType>>(&mut self, network_type: T) -> &mut Self {
         self.inner.network_type = network_type.as_ref().clone();
+        self.missing.retain(|f| *f != "network_type");
         self
     }
 
     pub fn sent_bytes(&mut self, sent_bytes: i64) -> &mut Self {
         self.inner.sent_bytes = sent_bytes;
+        self.missing.retain(|f| *f != "sent_bytes");
         self
     }
 
     pub fn received_bytes(&mut self, received_bytes: i64) -> &mut Self {
         self.inner.received_bytes = received_bytes;
+        self.missing.retain(|f| *f != "received_bytes");
         self
     }
 
     pub fn duration(&mut self, duration: f32) -> &mut Self {
         self.inner.duration = duration;
+        self.missing.retain(|f| *f != "duration");
         self
     }
 }
@@ -189,12 +243,13 @@ impl AsRef<NetworkStatisticsEntryCall> for RTDNetworkStatisticsEntryCallBuilder
 
 /// Contains information about the total amount of data that was used to send and receive files
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "networkStatisticsEntryFile")]
 pub struct NetworkStatisticsEntryFile {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Type of the file the data is part of
     file_type: FileType,
@@ -220,6 +275,26 @@ impl RObject for NetworkStatisticsEntryFile {
     }
 }
 
+impl PartialEq for NetworkStatisticsEntryFile {
+    fn eq(&self, other: &Self) -> bool {
+        self.file_type == other.file_type
+            && self.network_type == other.network_type
+            && self.sent_bytes == other.sent_bytes
+            && self.received_bytes == other.received_bytes
+    }
+}
+
+impl Eq for NetworkStatisticsEntryFile {}
+
+impl std::hash::Hash for NetworkStatisticsEntryFile {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.file_type, state);
+        std::hash::Hash::hash(&self.network_type, state);
+        std::hash::Hash::hash(&self.sent_bytes, state);
+        std::hash::Hash::hash(&self.received_bytes, state);
+    }
+}
+
 impl TDNetworkStatisticsEntry for NetworkStatisticsEntryFile {}
 
 impl NetworkStatisticsEntryFile {
@@ -227,10 +302,24 @@ impl NetworkStatisticsEntryFile {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDNetworkStatisticsEntryFileBuilder {
-        let mut inner = NetworkStatisticsEntryFile::default();
-        inner.td_name = "networkStatisticsEntryFile".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDNetworkStatisticsEntryFileBuilder { inner }
+        RTDNetworkStatisticsEntryFileBuilder {
+            inner: NetworkStatisticsEntryFile::default(),
+            missing: vec!["file_type", "network_type", "sent_bytes", "received_bytes"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDNetworkStatisticsEntryFileBuilder {
+        RTDNetworkStatisticsEntryFileBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDNetworkStatisticsEntryFileBuilder {
+        RTDNetworkStatisticsEntryFileBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn file_type(&self) -> &FileType {
@@ -248,35 +337,80 @@ impl NetworkStatisticsEntryFile {
     pub fn received_bytes(&self) -> i64 {
         self.received_bytes
     }
+
+    pub fn into_file_type(self) -> FileType {
+        self.file_type
+    }
+
+    pub fn into_network_type(self) -> NetworkType {
+        self.network_type
+    }
+
+    pub fn with_file_type<T: AsRef<FileType>>(mut self, file_type: T) -> Self {
+        self.file_type = file_type.as_ref().clone();
+        self
+    }
+
+    pub fn with_network_type<T: AsRef<NetworkType>>(mut self, network_type: T) -> Self {
+        self.network_type = network_type.as_ref().clone();
+        self
+    }
+
+    pub fn with_sent_bytes(mut self, sent_bytes: i64) -> Self {
+        self.sent_bytes = sent_bytes;
+        self
+    }
+
+    pub fn with_received_bytes(mut self, received_bytes: i64) -> Self {
+        self.received_bytes = received_bytes;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDNetworkStatisticsEntryFileBuilder {
     inner: NetworkStatisticsEntryFile,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDNetworkStatisticsEntryFileBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> NetworkStatisticsEntryFile {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<NetworkStatisticsEntryFile> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "NetworkStatisticsEntryFile",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn file_type<T: AsRef<FileType>>(&mut self, file_type: T) -> &mut Self {
         self.inner.file_type = file_type.as_ref().clone();
+        self.missing.retain(|f| *f != "file_type");
         self
     }
 
     pub fn network_type<T: AsRef<NetworkType>>(&mut self, network_type: T) -> &mut Self {
         self.inner.network_type = network_type.as_ref().clone();
+        self.missing.retain(|f| *f != "network_type");
         self
     }
 
     pub fn sent_bytes(&mut self, sent_bytes: i64) -> &mut Self {
         self.inner.sent_bytes = sent_bytes;
+        self.missing.retain(|f| *f != "sent_bytes");
         self
     }
 
     pub fn received_bytes(&mut self, received_bytes: i64) -> &mut Self {
         self.inner.received_bytes = received_bytes;
+        self.missing.retain(|f| *f != "received_bytes");
         self
     }
 }
@@ -292,3 +426,29 @@ impl AsRef<NetworkStatisticsEntryFile> for RTDNetworkStatisticsEntryFileBuilder
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(NetworkStatisticsEntry::Call(
+            NetworkStatisticsEntryCall::builder()
+                .network_type(NetworkType::Mobile(NetworkTypeMobile::builder().build()))
+                .sent_bytes(9007199254740991i64)
+                .received_bytes(9007199254740991i64)
+                .duration(1.5)
+                .build(),
+        ));
+        assert_round_trip(NetworkStatisticsEntry::File(
+            NetworkStatisticsEntryFile::builder()
+                .file_type(FileType::Animation(FileTypeAnimation::builder().build()))
+                .network_type(NetworkType::Mobile(NetworkTypeMobile::builder().build()))
+                .sent_bytes(9007199254740991i64)
+                .received_bytes(9007199254740991i64)
+                .build(),
+        ));
+    }
+}
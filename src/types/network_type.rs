@@ -1,52 +1,52 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Represents the type of a network
 pub trait TDNetworkType: Debug + RObject {}
 
 /// Represents the type of a network
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum NetworkType {
-    #[doc(hidden)]
-    _Default(()),
     /// A mobile network
+    #[serde(rename = "networkTypeMobile")]
     Mobile(NetworkTypeMobile),
     /// A mobile roaming network
+    #[serde(rename = "networkTypeMobileRoaming")]
     MobileRoaming(NetworkTypeMobileRoaming),
     /// The network is not available
+    #[serde(rename = "networkTypeNone")]
     None(NetworkTypeNone),
     /// A different network type (e.g., Ethernet network)
+    #[serde(rename = "networkTypeOther")]
     Other(NetworkTypeOther),
     /// A Wi-Fi network
+    #[serde(rename = "networkTypeWiFi")]
     WiFi(NetworkTypeWiFi),
 }
 
+/// Default value is default of [NetworkTypeMobile], builders still require the field to be set
 impl Default for NetworkType {
     fn default() -> Self {
-        NetworkType::_Default(())
+        NetworkType::Mobile(NetworkTypeMobile::default())
     }
 }
 
-impl<'de> Deserialize<'de> for NetworkType {
-    fn deserialize<D>(deserializer: D) -> Result<NetworkType, D::Error>
+impl Serialize for NetworkType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          NetworkType,
-          (networkTypeMobile, Mobile);
-          (networkTypeMobileRoaming, MobileRoaming);
-          (networkTypeNone, None);
-          (networkTypeOther, Other);
-          (networkTypeWiFi, WiFi);
-
-        )(deserializer)
+        match self {
+            NetworkType::Mobile(t) => t.serialize(serializer),
+            NetworkType::MobileRoaming(t) => t.serialize(serializer),
+            NetworkType::None(t) => t.serialize(serializer),
+            NetworkType::Other(t) => t.serialize(serializer),
+            NetworkType::WiFi(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -59,8 +59,6 @@ impl RObject for NetworkType {
             NetworkType::None(t) => t.td_name(),
             NetworkType::Other(t) => t.td_name(),
             NetworkType::WiFi(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -71,8 +69,6 @@ impl RObject for NetworkType {
             NetworkType::None(t) => t.extra(),
             NetworkType::Other(t) => t.extra(),
             NetworkType::WiFi(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -84,10 +80,6 @@ impl NetworkType {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, NetworkType::_Default(_))
-    }
 }
 
 impl AsRef<NetworkType> for NetworkType {
@@ -98,12 +90,13 @@ impl AsRef<NetworkType> for NetworkType {
 
 /// A mobile network
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "networkTypeMobile")]
 pub struct NetworkTypeMobile {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -121,6 +114,18 @@ impl RObject for NetworkTypeMobile {
     }
 }
 
+impl PartialEq for NetworkTypeMobile {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for NetworkTypeMobile {}
+
+impl std::hash::Hash for NetworkTypeMobile {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDNetworkType for NetworkTypeMobile {}
 
 impl NetworkTypeMobile {
@@ -128,22 +133,49 @@ impl NetworkTypeMobile {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDNetworkTypeMobileBuilder {
-        let mut inner = NetworkTypeMobile::default();
-        inner.td_name = "networkTypeMobile".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDNetworkTypeMobileBuilder { inner }
+        RTDNetworkTypeMobileBuilder {
+            inner: NetworkTypeMobile::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDNetworkTypeMobileBuilder {
+        RTDNetworkTypeMobileBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDNetworkTypeMobileBuilder {
+        RTDNetworkTypeMobileBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDNetworkTypeMobileBuilder {
     inner: NetworkTypeMobile,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDNetworkTypeMobileBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> NetworkTypeMobile {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<NetworkTypeMobile> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "NetworkTypeMobile",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<NetworkTypeMobile> for NetworkTypeMobile {
@@ -160,12 +192,13 @@ impl AsRef<NetworkTypeMobile> for RTDNetworkTypeMobileBuilder {
 
 /// A mobile roaming network
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "networkTypeMobileRoaming")]
 pub struct NetworkTypeMobileRoaming {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -183,6 +216,18 @@ impl RObject for NetworkTypeMobileRoaming {
     }
 }
 
+impl PartialEq for NetworkTypeMobileRoaming {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for NetworkTypeMobileRoaming {}
+
+impl std::hash::Hash for NetworkTypeMobileRoaming {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDNetworkType for NetworkTypeMobileRoaming {}
 
 impl NetworkTypeMobileRoaming {
@@ -190,22 +235,49 @@ impl NetworkTypeMobileRoaming {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDNetworkTypeMobileRoamingBuilder {
-        let mut inner = NetworkTypeMobileRoaming::default();
-        inner.td_name = "networkTypeMobileRoaming".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDNetworkTypeMobileRoamingBuilder { inner }
+        RTDNetworkTypeMobileRoamingBuilder {
+            inner: NetworkTypeMobileRoaming::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDNetworkTypeMobileRoamingBuilder {
+        RTDNetworkTypeMobileRoamingBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDNetworkTypeMobileRoamingBuilder {
+        RTDNetworkTypeMobileRoamingBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDNetworkTypeMobileRoamingBuilder {
     inner: NetworkTypeMobileRoaming,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDNetworkTypeMobileRoamingBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> NetworkTypeMobileRoaming {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<NetworkTypeMobileRoaming> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "NetworkTypeMobileRoaming",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<NetworkTypeMobileRoaming> for NetworkTypeMobileRoaming {
@@ -222,12 +294,13 @@ impl AsRef<NetworkTypeMobileRoaming> for RTDNetworkTypeMobileRoamingBuilder {
 
 /// The network is not available
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "networkTypeNone")]
 pub struct NetworkTypeNone {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -245,6 +318,18 @@ impl RObject for NetworkTypeNone {
     }
 }
 
+impl PartialEq for NetworkTypeNone {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for NetworkTypeNone {}
+
+impl std::hash::Hash for NetworkTypeNone {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDNetworkType for NetworkTypeNone {}
 
 impl NetworkTypeNone {
@@ -252,22 +337,49 @@ impl NetworkTypeNone {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDNetworkTypeNoneBuilder {
-        let mut inner = NetworkTypeNone::default();
-        inner.td_name = "networkTypeNone".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDNetworkTypeNoneBuilder { inner }
+        RTDNetworkTypeNoneBuilder {
+            inner: NetworkTypeNone::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDNetworkTypeNoneBuilder {
+        RTDNetworkTypeNoneBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDNetworkTypeNoneBuilder {
+        RTDNetworkTypeNoneBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDNetworkTypeNoneBuilder {
     inner: NetworkTypeNone,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDNetworkTypeNoneBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> NetworkTypeNone {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<NetworkTypeNone> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "NetworkTypeNone",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<NetworkTypeNone> for NetworkTypeNone {
@@ -284,12 +396,13 @@ impl AsRef<NetworkTypeNone> for RTDNetworkTypeNoneBuilder {
 
 /// A different network type (e.g., Ethernet network)
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "networkTypeOther")]
 pub struct NetworkTypeOther {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -307,6 +420,18 @@ impl RObject for NetworkTypeOther {
     }
 }
 
+impl PartialEq for NetworkTypeOther {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for NetworkTypeOther {}
+
+impl std::hash::Hash for NetworkTypeOther {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDNetworkType for NetworkTypeOther {}
 
 impl NetworkTypeOther {
@@ -314,22 +439,49 @@ impl NetworkTypeOther {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDNetworkTypeOtherBuilder {
-        let mut inner = NetworkTypeOther::default();
-        inner.td_name = "networkTypeOther".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDNetworkTypeOtherBuilder { inner }
+        RTDNetworkTypeOtherBuilder {
+            inner: NetworkTypeOther::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDNetworkTypeOtherBuilder {
+        RTDNetworkTypeOtherBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDNetworkTypeOtherBuilder {
+        RTDNetworkTypeOtherBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDNetworkTypeOtherBuilder {
     inner: NetworkTypeOther,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDNetworkTypeOtherBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> NetworkTypeOther {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<NetworkTypeOther> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "NetworkTypeOther",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<NetworkTypeOther> for NetworkTypeOther {
@@ -346,12 +498,13 @@ impl AsRef<NetworkTypeOther> for RTDNetworkTypeOtherBuilder {
 
 /// A Wi-Fi network
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "networkTypeWiFi")]
 pub struct NetworkTypeWiFi {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -369,6 +522,18 @@ impl RObject for NetworkTypeWiFi {
     }
 }
 
+impl PartialEq for NetworkTypeWiFi {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for NetworkTypeWiFi {}
+
+impl std::hash::Hash for NetworkTypeWiFi {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDNetworkType for NetworkTypeWiFi {}
 
 impl NetworkTypeWiFi {
@@ -376,22 +541,49 @@ impl NetworkTypeWiFi {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDNetworkTypeWiFiBuilder {
-        let mut inner = NetworkTypeWiFi::default();
-        inner.td_name = "networkTypeWiFi".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDNetworkTypeWiFiBuilder { inner }
+        RTDNetworkTypeWiFiBuilder {
+            inner: NetworkTypeWiFi::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDNetworkTypeWiFiBuilder {
+        RTDNetworkTypeWiFiBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDNetworkTypeWiFiBuilder {
+        RTDNetworkTypeWiFiBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDNetworkTypeWiFiBuilder {
     inner: NetworkTypeWiFi,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDNetworkTypeWiFiBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> NetworkTypeWiFi {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<NetworkTypeWiFi> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "NetworkTypeWiFi",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<NetworkTypeWiFi> for NetworkTypeWiFi {
@@ -405,3 +597,20 @@ impl AsRef<NetworkTypeWiFi> for RTDNetworkTypeWiFiBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(NetworkType::Mobile(NetworkTypeMobile::builder().build()));
+        assert_round_trip(NetworkType::MobileRoaming(
+            NetworkTypeMobileRoaming::builder().build(),
+        ));
+        assert_round_trip(NetworkType::None(NetworkTypeNone::builder().build()));
+        assert_round_trip(NetworkType::Other(NetworkTypeOther::builder().build()));
+        assert_round_trip(NetworkType::WiFi(NetworkTypeWiFi::builder().build()));
+    }
+}
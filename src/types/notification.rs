@@ -1,20 +1,20 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains information about a notification
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "notification")]
 pub struct Notification {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Unique persistent identifier of this notification
-    id: i64,
+    id: i32,
     /// Notification date
-    date: i64,
+    date: i32,
     /// True, if the notification was initially silent
     is_silent: bool,
     /// Notification type
@@ -36,22 +36,45 @@ impl RObject for Notification {
     }
 }
 
+impl PartialEq for Notification {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.date == other.date
+            && self.is_silent == other.is_silent
+            && self.type_ == other.type_
+    }
+}
+
 impl Notification {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDNotificationBuilder {
-        let mut inner = Notification::default();
-        inner.td_name = "notification".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDNotificationBuilder { inner }
+        RTDNotificationBuilder {
+            inner: Notification::default(),
+            missing: vec!["id", "date", "type_"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDNotificationBuilder {
+        RTDNotificationBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDNotificationBuilder {
+        RTDNotificationBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn id(&self) -> i64 {
+    pub fn id(&self) -> i32 {
         self.id
     }
 
-    pub fn date(&self) -> i64 {
+    pub fn date(&self) -> i32 {
         self.date
     }
 
@@ -62,25 +85,64 @@ impl Notification {
     pub fn type_(&self) -> &NotificationType {
         &self.type_
     }
+
+    pub fn into_type(self) -> NotificationType {
+        self.type_
+    }
+
+    pub fn with_id(mut self, id: i32) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn with_date(mut self, date: i32) -> Self {
+        self.date = date;
+        self
+    }
+
+    pub fn with_is_silent(mut self, is_silent: bool) -> Self {
+        self.is_silent = is_silent;
+        self
+    }
+
+    pub fn with_type<T: AsRef<NotificationType>>(mut self, type_: T) -> Self {
+        self.type_ = type_.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDNotificationBuilder {
     inner: Notification,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDNotificationBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> Notification {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<Notification> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "Notification",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn id(&mut self, id: i64) -> &mut Self {
+    pub fn id(&mut self, id: i32) -> &mut Self {
         self.inner.id = id;
+        self.missing.retain(|f| *f != "id");
         self
     }
 
-    pub fn date(&mut self, date: i64) -> &mut Self {
+    pub fn date(&mut self, date: i32) -> &mut Self {
         self.inner.date = date;
+        self.missing.retain(|f| *f != "date");
         self
     }
 
@@ -91,6 +153,7 @@ impl RTDNotificationBuilder {
 
     pub fn type_<T: AsRef<NotificationType>>(&mut self, type_: T) -> &mut Self {
         self.inner.type_ = type_.as_ref().clone();
+        self.missing.retain(|f| *f != "type_");
         self
     }
 }
@@ -106,3 +169,23 @@ impl AsRef<Notification> for RTDNotificationBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            Notification::builder()
+                .id(i32::MAX)
+                .date(i32::MAX)
+                .is_silent(true)
+                .type_(NotificationType::NewCall(
+                    NotificationTypeNewCall::builder().call_id(i32::MAX).build(),
+                ))
+                .build(),
+        );
+    }
+}
@@ -1,25 +1,25 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Describes a group of notifications
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "notificationGroup")]
 pub struct NotificationGroup {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Unique persistent auto-incremented from 1 identifier of the notification group
-    id: i64,
+    id: i32,
     /// Type of the group
     #[serde(rename(serialize = "type", deserialize = "type"))]
     type_: NotificationGroupType,
     /// Identifier of a chat to which all notifications in the group belong
-    chat_id: i64,
+    chat_id: ChatId,
     /// Total number of active notifications in the group
-    total_count: i64,
+    total_count: i32,
     /// The list of active notifications
     notifications: Vec<Notification>,
 }
@@ -38,18 +38,42 @@ impl RObject for NotificationGroup {
     }
 }
 
+impl PartialEq for NotificationGroup {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.type_ == other.type_
+            && self.chat_id == other.chat_id
+            && self.total_count == other.total_count
+            && self.notifications == other.notifications
+    }
+}
+
 impl NotificationGroup {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDNotificationGroupBuilder {
-        let mut inner = NotificationGroup::default();
-        inner.td_name = "notificationGroup".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDNotificationGroupBuilder { inner }
-    }
-
-    pub fn id(&self) -> i64 {
+        RTDNotificationGroupBuilder {
+            inner: NotificationGroup::default(),
+            missing: vec!["id", "type_", "chat_id", "total_count", "notifications"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDNotificationGroupBuilder {
+        RTDNotificationGroupBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDNotificationGroupBuilder {
+        RTDNotificationGroupBuilder {
+            inner: self,
+            missing: vec![],
+        }
+    }
+
+    pub fn id(&self) -> i32 {
         self.id
     }
 
@@ -57,51 +81,102 @@ impl NotificationGroup {
         &self.type_
     }
 
-    pub fn chat_id(&self) -> i64 {
+    pub fn chat_id(&self) -> ChatId {
         self.chat_id
     }
 
-    pub fn total_count(&self) -> i64 {
+    pub fn total_count(&self) -> i32 {
         self.total_count
     }
 
     pub fn notifications(&self) -> &Vec<Notification> {
         &self.notifications
     }
+
+    pub fn into_type(self) -> NotificationGroupType {
+        self.type_
+    }
+
+    pub fn into_notifications(self) -> Vec<Notification> {
+        self.notifications
+    }
+
+    pub fn with_id(mut self, id: i32) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn with_type<T: AsRef<NotificationGroupType>>(mut self, type_: T) -> Self {
+        self.type_ = type_.as_ref().clone();
+        self
+    }
+
+    pub fn with_chat_id<T: Into<ChatId>>(mut self, chat_id: T) -> Self {
+        self.chat_id = chat_id.into();
+        self
+    }
+
+    pub fn with_total_count(mut self, total_count: i32) -> Self {
+        self.total_count = total_count;
+        self
+    }
+
+    pub fn with_notifications(mut self, notifications: Vec<Notification>) -> Self {
+        self.notifications = notifications;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDNotificationGroupBuilder {
     inner: NotificationGroup,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDNotificationGroupBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> NotificationGroup {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<NotificationGroup> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "NotificationGroup",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn id(&mut self, id: i64) -> &mut Self {
+    pub fn id(&mut self, id: i32) -> &mut Self {
         self.inner.id = id;
+        self.missing.retain(|f| *f != "id");
         self
     }
 
     pub fn type_<T: AsRef<NotificationGroupType>>(&mut self, type_: T) -> &mut Self {
         self.inner.type_ = type_.as_ref().clone();
+        self.missing.retain(|f| *f != "type_");
         self
     }
 
-    pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
-        self.inner.chat_id = chat_id;
+    pub fn chat_id<T: Into<ChatId>>(&mut self, chat_id: T) -> &mut Self {
+        self.inner.chat_id = chat_id.into();
+        self.missing.retain(|f| *f != "chat_id");
         self
     }
 
-    pub fn total_count(&mut self, total_count: i64) -> &mut Self {
+    pub fn total_count(&mut self, total_count: i32) -> &mut Self {
         self.inner.total_count = total_count;
+        self.missing.retain(|f| *f != "total_count");
         self
     }
 
     pub fn notifications(&mut self, notifications: Vec<Notification>) -> &mut Self {
         self.inner.notifications = notifications;
+        self.missing.retain(|f| *f != "notifications");
         self
     }
 }
@@ -117,3 +192,24 @@ impl AsRef<NotificationGroup> for RTDNotificationGroupBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            NotificationGroup::builder()
+                .id(i32::MAX)
+                .type_(NotificationGroupType::Calls(
+                    NotificationGroupTypeCalls::builder().build(),
+                ))
+                .chat_id(9007199254740991i64)
+                .total_count(i32::MAX)
+                .notifications(vec![])
+                .build(),
+        );
+    }
+}
@@ -1,49 +1,48 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Describes the type of notifications in a notification group
 pub trait TDNotificationGroupType: Debug + RObject {}
 
 /// Describes the type of notifications in a notification group
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum NotificationGroupType {
-    #[doc(hidden)]
-    _Default(()),
     /// A group containing notifications of type notificationTypeNewCall
+    #[serde(rename = "notificationGroupTypeCalls")]
     Calls(NotificationGroupTypeCalls),
     /// A group containing notifications of type notificationTypeNewMessage and notificationTypeNewPushMessage with unread mentions of the current user, replies to their messages, or a pinned message
+    #[serde(rename = "notificationGroupTypeMentions")]
     Mentions(NotificationGroupTypeMentions),
     /// A group containing notifications of type notificationTypeNewMessage and notificationTypeNewPushMessage with ordinary unread messages
+    #[serde(rename = "notificationGroupTypeMessages")]
     Messages(NotificationGroupTypeMessages),
     /// A group containing a notification of type notificationTypeNewSecretChat
+    #[serde(rename = "notificationGroupTypeSecretChat")]
     SecretChat(NotificationGroupTypeSecretChat),
 }
 
+/// Default value is default of [NotificationGroupTypeCalls], builders still require the field to be set
 impl Default for NotificationGroupType {
     fn default() -> Self {
-        NotificationGroupType::_Default(())
+        NotificationGroupType::Calls(NotificationGroupTypeCalls::default())
     }
 }
 
-impl<'de> Deserialize<'de> for NotificationGroupType {
-    fn deserialize<D>(deserializer: D) -> Result<NotificationGroupType, D::Error>
+impl Serialize for NotificationGroupType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          NotificationGroupType,
-          (notificationGroupTypeCalls, Calls);
-          (notificationGroupTypeMentions, Mentions);
-          (notificationGroupTypeMessages, Messages);
-          (notificationGroupTypeSecretChat, SecretChat);
-
-        )(deserializer)
+        match self {
+            NotificationGroupType::Calls(t) => t.serialize(serializer),
+            NotificationGroupType::Mentions(t) => t.serialize(serializer),
+            NotificationGroupType::Messages(t) => t.serialize(serializer),
+            NotificationGroupType::SecretChat(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -55,8 +54,6 @@ impl RObject for NotificationGroupType {
             NotificationGroupType::Mentions(t) => t.td_name(),
             NotificationGroupType::Messages(t) => t.td_name(),
             NotificationGroupType::SecretChat(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -66,8 +63,6 @@ impl RObject for NotificationGroupType {
             NotificationGroupType::Mentions(t) => t.extra(),
             NotificationGroupType::Messages(t) => t.extra(),
             NotificationGroupType::SecretChat(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -79,10 +74,6 @@ impl NotificationGroupType {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, NotificationGroupType::_Default(_))
-    }
 }
 
 impl AsRef<NotificationGroupType> for NotificationGroupType {
@@ -93,12 +84,13 @@ impl AsRef<NotificationGroupType> for NotificationGroupType {
 
 /// A group containing notifications of type notificationTypeNewCall
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "notificationGroupTypeCalls")]
 pub struct NotificationGroupTypeCalls {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -116,6 +108,18 @@ impl RObject for NotificationGroupTypeCalls {
     }
 }
 
+impl PartialEq for NotificationGroupTypeCalls {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for NotificationGroupTypeCalls {}
+
+impl std::hash::Hash for NotificationGroupTypeCalls {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDNotificationGroupType for NotificationGroupTypeCalls {}
 
 impl NotificationGroupTypeCalls {
@@ -123,22 +127,49 @@ impl NotificationGroupTypeCalls {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDNotificationGroupTypeCallsBuilder {
-        let mut inner = NotificationGroupTypeCalls::default();
-        inner.td_name = "notificationGroupTypeCalls".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDNotificationGroupTypeCallsBuilder { inner }
+        RTDNotificationGroupTypeCallsBuilder {
+            inner: NotificationGroupTypeCalls::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDNotificationGroupTypeCallsBuilder {
+        RTDNotificationGroupTypeCallsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDNotificationGroupTypeCallsBuilder {
+        RTDNotificationGroupTypeCallsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDNotificationGroupTypeCallsBuilder {
     inner: NotificationGroupTypeCalls,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDNotificationGroupTypeCallsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> NotificationGroupTypeCalls {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<NotificationGroupTypeCalls> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "NotificationGroupTypeCalls",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<NotificationGroupTypeCalls> for NotificationGroupTypeCalls {
@@ -155,12 +186,13 @@ impl AsRef<NotificationGroupTypeCalls> for RTDNotificationGroupTypeCallsBuilder
 
 /// A group containing notifications of type notificationTypeNewMessage and notificationTypeNewPushMessage with unread mentions of the current user, replies to their messages, or a pinned message
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "notificationGroupTypeMentions")]
 pub struct NotificationGroupTypeMentions {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -178,6 +210,18 @@ impl RObject for NotificationGroupTypeMentions {
     }
 }
 
+impl PartialEq for NotificationGroupTypeMentions {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for NotificationGroupTypeMentions {}
+
+impl std::hash::Hash for NotificationGroupTypeMentions {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDNotificationGroupType for NotificationGroupTypeMentions {}
 
 impl NotificationGroupTypeMentions {
@@ -185,22 +229,49 @@ impl NotificationGroupTypeMentions {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDNotificationGroupTypeMentionsBuilder {
-        let mut inner = NotificationGroupTypeMentions::default();
-        inner.td_name = "notificationGroupTypeMentions".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDNotificationGroupTypeMentionsBuilder { inner }
+        RTDNotificationGroupTypeMentionsBuilder {
+            inner: NotificationGroupTypeMentions::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDNotificationGroupTypeMentionsBuilder {
+        RTDNotificationGroupTypeMentionsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDNotificationGroupTypeMentionsBuilder {
+        RTDNotificationGroupTypeMentionsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDNotificationGroupTypeMentionsBuilder {
     inner: NotificationGroupTypeMentions,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDNotificationGroupTypeMentionsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> NotificationGroupTypeMentions {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<NotificationGroupTypeMentions> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "NotificationGroupTypeMentions",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<NotificationGroupTypeMentions> for NotificationGroupTypeMentions {
@@ -217,12 +288,13 @@ impl AsRef<NotificationGroupTypeMentions> for RTDNotificationGroupTypeMentionsBu
 
 /// A group containing notifications of type notificationTypeNewMessage and notificationTypeNewPushMessage with ordinary unread messages
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "notificationGroupTypeMessages")]
 pub struct NotificationGroupTypeMessages {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -240,6 +312,18 @@ impl RObject for NotificationGroupTypeMessages {
     }
 }
 
+impl PartialEq for NotificationGroupTypeMessages {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for NotificationGroupTypeMessages {}
+
+impl std::hash::Hash for NotificationGroupTypeMessages {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDNotificationGroupType for NotificationGroupTypeMessages {}
 
 impl NotificationGroupTypeMessages {
@@ -247,22 +331,49 @@ impl NotificationGroupTypeMessages {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDNotificationGroupTypeMessagesBuilder {
-        let mut inner = NotificationGroupTypeMessages::default();
-        inner.td_name = "notificationGroupTypeMessages".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDNotificationGroupTypeMessagesBuilder { inner }
+        RTDNotificationGroupTypeMessagesBuilder {
+            inner: NotificationGroupTypeMessages::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDNotificationGroupTypeMessagesBuilder {
+        RTDNotificationGroupTypeMessagesBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDNotificationGroupTypeMessagesBuilder {
+        RTDNotificationGroupTypeMessagesBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDNotificationGroupTypeMessagesBuilder {
     inner: NotificationGroupTypeMessages,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDNotificationGroupTypeMessagesBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> NotificationGroupTypeMessages {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<NotificationGroupTypeMessages> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "NotificationGroupTypeMessages",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<NotificationGroupTypeMessages> for NotificationGroupTypeMessages {
@@ -279,12 +390,13 @@ impl AsRef<NotificationGroupTypeMessages> for RTDNotificationGroupTypeMessagesBu
 
 /// A group containing a notification of type notificationTypeNewSecretChat
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "notificationGroupTypeSecretChat")]
 pub struct NotificationGroupTypeSecretChat {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -302,6 +414,18 @@ impl RObject for NotificationGroupTypeSecretChat {
     }
 }
 
+impl PartialEq for NotificationGroupTypeSecretChat {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for NotificationGroupTypeSecretChat {}
+
+impl std::hash::Hash for NotificationGroupTypeSecretChat {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDNotificationGroupType for NotificationGroupTypeSecretChat {}
 
 impl NotificationGroupTypeSecretChat {
@@ -309,22 +433,49 @@ impl NotificationGroupTypeSecretChat {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDNotificationGroupTypeSecretChatBuilder {
-        let mut inner = NotificationGroupTypeSecretChat::default();
-        inner.td_name = "notificationGroupTypeSecretChat".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDNotificationGroupTypeSecretChatBuilder { inner }
+        RTDNotificationGroupTypeSecretChatBuilder {
+            inner: NotificationGroupTypeSecretChat::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDNotificationGroupTypeSecretChatBuilder {
+        RTDNotificationGroupTypeSecretChatBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDNotificationGroupTypeSecretChatBuilder {
+        RTDNotificationGroupTypeSecretChatBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDNotificationGroupTypeSecretChatBuilder {
     inner: NotificationGroupTypeSecretChat,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDNotificationGroupTypeSecretChatBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> NotificationGroupTypeSecretChat {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<NotificationGroupTypeSecretChat> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "NotificationGroupTypeSecretChat",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<NotificationGroupTypeSecretChat> for NotificationGroupTypeSecretChat {
@@ -338,3 +489,25 @@ impl AsRef<NotificationGroupTypeSecretChat> for RTDNotificationGroupTypeSecretCh
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(NotificationGroupType::Calls(
+            NotificationGroupTypeCalls::builder().build(),
+        ));
+        assert_round_trip(NotificationGroupType::Mentions(
+            NotificationGroupTypeMentions::builder().build(),
+        ));
+        assert_round_trip(NotificationGroupType::Messages(
+            NotificationGroupTypeMessages::builder().build(),
+        ));
+        assert_round_trip(NotificationGroupType::SecretChat(
+            NotificationGroupTypeSecretChat::builder().build(),
+        ));
+    }
+}
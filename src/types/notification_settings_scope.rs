@@ -1,46 +1,44 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Describes the types of chats to which notification settings are applied
 pub trait TDNotificationSettingsScope: Debug + RObject {}
 
 /// Describes the types of chats to which notification settings are applied
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum NotificationSettingsScope {
-    #[doc(hidden)]
-    _Default(()),
     /// Notification settings applied to all channels when the corresponding chat setting has a default value
+    #[serde(rename = "notificationSettingsScopeChannelChats")]
     ChannelChats(NotificationSettingsScopeChannelChats),
     /// Notification settings applied to all basic groups and supergroups when the corresponding chat setting has a default value
+    #[serde(rename = "notificationSettingsScopeGroupChats")]
     GroupChats(NotificationSettingsScopeGroupChats),
     /// Notification settings applied to all private and secret chats when the corresponding chat setting has a default value
+    #[serde(rename = "notificationSettingsScopePrivateChats")]
     PrivateChats(NotificationSettingsScopePrivateChats),
 }
 
+/// Default value is default of [NotificationSettingsScopeChannelChats], builders still require the field to be set
 impl Default for NotificationSettingsScope {
     fn default() -> Self {
-        NotificationSettingsScope::_Default(())
+        NotificationSettingsScope::ChannelChats(NotificationSettingsScopeChannelChats::default())
     }
 }
 
-impl<'de> Deserialize<'de> for NotificationSettingsScope {
-    fn deserialize<D>(deserializer: D) -> Result<NotificationSettingsScope, D::Error>
+impl Serialize for NotificationSettingsScope {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          NotificationSettingsScope,
-          (notificationSettingsScopeChannelChats, ChannelChats);
-          (notificationSettingsScopeGroupChats, GroupChats);
-          (notificationSettingsScopePrivateChats, PrivateChats);
-
-        )(deserializer)
+        match self {
+            NotificationSettingsScope::ChannelChats(t) => t.serialize(serializer),
+            NotificationSettingsScope::GroupChats(t) => t.serialize(serializer),
+            NotificationSettingsScope::PrivateChats(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -51,8 +49,6 @@ impl RObject for NotificationSettingsScope {
             NotificationSettingsScope::ChannelChats(t) => t.td_name(),
             NotificationSettingsScope::GroupChats(t) => t.td_name(),
             NotificationSettingsScope::PrivateChats(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -61,8 +57,6 @@ impl RObject for NotificationSettingsScope {
             NotificationSettingsScope::ChannelChats(t) => t.extra(),
             NotificationSettingsScope::GroupChats(t) => t.extra(),
             NotificationSettingsScope::PrivateChats(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -74,10 +68,6 @@ impl NotificationSettingsScope {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, NotificationSettingsScope::_Default(_))
-    }
 }
 
 impl AsRef<NotificationSettingsScope> for NotificationSettingsScope {
@@ -88,12 +78,13 @@ impl AsRef<NotificationSettingsScope> for NotificationSettingsScope {
 
 /// Notification settings applied to all channels when the corresponding chat setting has a default value
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "notificationSettingsScopeChannelChats")]
 pub struct NotificationSettingsScopeChannelChats {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -111,6 +102,18 @@ impl RObject for NotificationSettingsScopeChannelChats {
     }
 }
 
+impl PartialEq for NotificationSettingsScopeChannelChats {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for NotificationSettingsScopeChannelChats {}
+
+impl std::hash::Hash for NotificationSettingsScopeChannelChats {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDNotificationSettingsScope for NotificationSettingsScopeChannelChats {}
 
 impl NotificationSettingsScopeChannelChats {
@@ -118,22 +121,49 @@ impl NotificationSettingsScopeChannelChats {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDNotificationSettingsScopeChannelChatsBuilder {
-        let mut inner = NotificationSettingsScopeChannelChats::default();
-        inner.td_name = "notificationSettingsScopeChannelChats".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDNotificationSettingsScopeChannelChatsBuilder { inner }
+        RTDNotificationSettingsScopeChannelChatsBuilder {
+            inner: NotificationSettingsScopeChannelChats::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDNotificationSettingsScopeChannelChatsBuilder {
+        RTDNotificationSettingsScopeChannelChatsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDNotificationSettingsScopeChannelChatsBuilder {
+        RTDNotificationSettingsScopeChannelChatsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDNotificationSettingsScopeChannelChatsBuilder {
     inner: NotificationSettingsScopeChannelChats,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDNotificationSettingsScopeChannelChatsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> NotificationSettingsScopeChannelChats {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<NotificationSettingsScopeChannelChats> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "NotificationSettingsScopeChannelChats",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<NotificationSettingsScopeChannelChats> for NotificationSettingsScopeChannelChats {
@@ -152,12 +182,13 @@ impl AsRef<NotificationSettingsScopeChannelChats>
 
 /// Notification settings applied to all basic groups and supergroups when the corresponding chat setting has a default value
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "notificationSettingsScopeGroupChats")]
 pub struct NotificationSettingsScopeGroupChats {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -175,6 +206,18 @@ impl RObject for NotificationSettingsScopeGroupChats {
     }
 }
 
+impl PartialEq for NotificationSettingsScopeGroupChats {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for NotificationSettingsScopeGroupChats {}
+
+impl std::hash::Hash for NotificationSettingsScopeGroupChats {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDNotificationSettingsScope for NotificationSettingsScopeGroupChats {}
 
 impl NotificationSettingsScopeGroupChats {
@@ -182,22 +225,49 @@ impl NotificationSettingsScopeGroupChats {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDNotificationSettingsScopeGroupChatsBuilder {
-        let mut inner = NotificationSettingsScopeGroupChats::default();
-        inner.td_name = "notificationSettingsScopeGroupChats".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDNotificationSettingsScopeGroupChatsBuilder { inner }
+        RTDNotificationSettingsScopeGroupChatsBuilder {
+            inner: NotificationSettingsScopeGroupChats::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDNotificationSettingsScopeGroupChatsBuilder {
+        RTDNotificationSettingsScopeGroupChatsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDNotificationSettingsScopeGroupChatsBuilder {
+        RTDNotificationSettingsScopeGroupChatsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDNotificationSettingsScopeGroupChatsBuilder {
     inner: NotificationSettingsScopeGroupChats,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDNotificationSettingsScopeGroupChatsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> NotificationSettingsScopeGroupChats {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<NotificationSettingsScopeGroupChats> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "NotificationSettingsScopeGroupChats",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<NotificationSettingsScopeGroupChats> for NotificationSettingsScopeGroupChats {
@@ -214,12 +284,13 @@ impl AsRef<NotificationSettingsScopeGroupChats> for RTDNotificationSettingsScope
 
 /// Notification settings applied to all private and secret chats when the corresponding chat setting has a default value
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "notificationSettingsScopePrivateChats")]
 pub struct NotificationSettingsScopePrivateChats {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -237,6 +308,18 @@ impl RObject for NotificationSettingsScopePrivateChats {
     }
 }
 
+impl PartialEq for NotificationSettingsScopePrivateChats {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for NotificationSettingsScopePrivateChats {}
+
+impl std::hash::Hash for NotificationSettingsScopePrivateChats {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDNotificationSettingsScope for NotificationSettingsScopePrivateChats {}
 
 impl NotificationSettingsScopePrivateChats {
@@ -244,22 +327,49 @@ impl NotificationSettingsScopePrivateChats {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDNotificationSettingsScopePrivateChatsBuilder {
-        let mut inner = NotificationSettingsScopePrivateChats::default();
-        inner.td_name = "notificationSettingsScopePrivateChats".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDNotificationSettingsScopePrivateChatsBuilder { inner }
+        RTDNotificationSettingsScopePrivateChatsBuilder {
+            inner: NotificationSettingsScopePrivateChats::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDNotificationSettingsScopePrivateChatsBuilder {
+        RTDNotificationSettingsScopePrivateChatsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDNotificationSettingsScopePrivateChatsBuilder {
+        RTDNotificationSettingsScopePrivateChatsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDNotificationSettingsScopePrivateChatsBuilder {
     inner: NotificationSettingsScopePrivateChats,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDNotificationSettingsScopePrivateChatsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> NotificationSettingsScopePrivateChats {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<NotificationSettingsScopePrivateChats> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "NotificationSettingsScopePrivateChats",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<NotificationSettingsScopePrivateChats> for NotificationSettingsScopePrivateChats {
@@ -275,3 +385,22 @@ impl AsRef<NotificationSettingsScopePrivateChats>
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(NotificationSettingsScope::ChannelChats(
+            NotificationSettingsScopeChannelChats::builder().build(),
+        ));
+        assert_round_trip(NotificationSettingsScope::GroupChats(
+            NotificationSettingsScopeGroupChats::builder().build(),
+        ));
+        assert_round_trip(NotificationSettingsScope::PrivateChats(
+            NotificationSettingsScopePrivateChats::builder().build(),
+        ));
+    }
+}
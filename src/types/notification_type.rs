@@ -1,49 +1,48 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Contains detailed information about a notification
 pub trait TDNotificationType: Debug + RObject {}
 
 /// Contains detailed information about a notification
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "@type")]
 pub enum NotificationType {
-    #[doc(hidden)]
-    _Default(()),
     /// New call was received
+    #[serde(rename = "notificationTypeNewCall")]
     NewCall(NotificationTypeNewCall),
     /// New message was received
+    #[serde(rename = "notificationTypeNewMessage")]
     NewMessage(NotificationTypeNewMessage),
     /// New message was received through a push notification
+    #[serde(rename = "notificationTypeNewPushMessage")]
     NewPushMessage(NotificationTypeNewPushMessage),
     /// New secret chat was created
+    #[serde(rename = "notificationTypeNewSecretChat")]
     NewSecretChat(NotificationTypeNewSecretChat),
 }
 
+/// Default value is default of [NotificationTypeNewCall], builders still require the field to be set
 impl Default for NotificationType {
     fn default() -> Self {
-        NotificationType::_Default(())
+        NotificationType::NewCall(NotificationTypeNewCall::default())
     }
 }
 
-impl<'de> Deserialize<'de> for NotificationType {
-    fn deserialize<D>(deserializer: D) -> Result<NotificationType, D::Error>
+impl Serialize for NotificationType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          NotificationType,
-          (notificationTypeNewCall, NewCall);
-          (notificationTypeNewMessage, NewMessage);
-          (notificationTypeNewPushMessage, NewPushMessage);
-          (notificationTypeNewSecretChat, NewSecretChat);
-
-        )(deserializer)
+        match self {
+            NotificationType::NewCall(t) => t.serialize(serializer),
+            NotificationType::NewMessage(t) => t.serialize(serializer),
+            NotificationType::NewPushMessage(t) => t.serialize(serializer),
+            NotificationType::NewSecretChat(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -55,8 +54,6 @@ impl RObject for NotificationType {
             NotificationType::NewMessage(t) => t.td_name(),
             NotificationType::NewPushMessage(t) => t.td_name(),
             NotificationType::NewSecretChat(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -66,8 +63,6 @@ impl RObject for NotificationType {
             NotificationType::NewMessage(t) => t.extra(),
             NotificationType::NewPushMessage(t) => t.extra(),
             NotificationType::NewSecretChat(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -79,10 +74,6 @@ impl NotificationType {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, NotificationType::_Default(_))
-    }
 }
 
 impl AsRef<NotificationType> for NotificationType {
@@ -93,15 +84,16 @@ impl AsRef<NotificationType> for NotificationType {
 
 /// New call was received
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "notificationTypeNewCall")]
 pub struct NotificationTypeNewCall {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Call identifier
-    call_id: i64,
+    call_id: CallIdentifier,
 }
 
 impl RObject for NotificationTypeNewCall {
@@ -118,6 +110,20 @@ impl RObject for NotificationTypeNewCall {
     }
 }
 
+impl PartialEq for NotificationTypeNewCall {
+    fn eq(&self, other: &Self) -> bool {
+        self.call_id == other.call_id
+    }
+}
+
+impl Eq for NotificationTypeNewCall {}
+
+impl std::hash::Hash for NotificationTypeNewCall {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.call_id, state);
+    }
+}
+
 impl TDNotificationType for NotificationTypeNewCall {}
 
 impl NotificationTypeNewCall {
@@ -125,29 +131,62 @@ impl NotificationTypeNewCall {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDNotificationTypeNewCallBuilder {
-        let mut inner = NotificationTypeNewCall::default();
-        inner.td_name = "notificationTypeNewCall".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDNotificationTypeNewCallBuilder { inner }
+        RTDNotificationTypeNewCallBuilder {
+            inner: NotificationTypeNewCall::default(),
+            missing: vec!["call_id"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDNotificationTypeNewCallBuilder {
+        RTDNotificationTypeNewCallBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDNotificationTypeNewCallBuilder {
+        RTDNotificationTypeNewCallBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn call_id(&self) -> i64 {
+    pub fn call_id(&self) -> CallIdentifier {
         self.call_id
     }
+
+    pub fn with_call_id<T: Into<CallIdentifier>>(mut self, call_id: T) -> Self {
+        self.call_id = call_id.into();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDNotificationTypeNewCallBuilder {
     inner: NotificationTypeNewCall,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDNotificationTypeNewCallBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> NotificationTypeNewCall {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<NotificationTypeNewCall> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "NotificationTypeNewCall",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn call_id(&mut self, call_id: i64) -> &mut Self {
-        self.inner.call_id = call_id;
+    pub fn call_id<T: Into<CallIdentifier>>(&mut self, call_id: T) -> &mut Self {
+        self.inner.call_id = call_id.into();
+        self.missing.retain(|f| *f != "call_id");
         self
     }
 }
@@ -166,12 +205,13 @@ impl AsRef<NotificationTypeNewCall> for RTDNotificationTypeNewCallBuilder {
 
 /// New message was received
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "notificationTypeNewMessage")]
 pub struct NotificationTypeNewMessage {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The message
     message: Message,
@@ -191,6 +231,12 @@ impl RObject for NotificationTypeNewMessage {
     }
 }
 
+impl PartialEq for NotificationTypeNewMessage {
+    fn eq(&self, other: &Self) -> bool {
+        self.message == other.message
+    }
+}
+
 impl TDNotificationType for NotificationTypeNewMessage {}
 
 impl NotificationTypeNewMessage {
@@ -198,29 +244,66 @@ impl NotificationTypeNewMessage {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDNotificationTypeNewMessageBuilder {
-        let mut inner = NotificationTypeNewMessage::default();
-        inner.td_name = "notificationTypeNewMessage".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDNotificationTypeNewMessageBuilder { inner }
+        RTDNotificationTypeNewMessageBuilder {
+            inner: NotificationTypeNewMessage::default(),
+            missing: vec!["message"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDNotificationTypeNewMessageBuilder {
+        RTDNotificationTypeNewMessageBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDNotificationTypeNewMessageBuilder {
+        RTDNotificationTypeNewMessageBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn message(&self) -> &Message {
         &self.message
     }
+
+    pub fn into_message(self) -> Message {
+        self.message
+    }
+
+    pub fn with_message<T: AsRef<Message>>(mut self, message: T) -> Self {
+        self.message = message.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDNotificationTypeNewMessageBuilder {
     inner: NotificationTypeNewMessage,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDNotificationTypeNewMessageBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> NotificationTypeNewMessage {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<NotificationTypeNewMessage> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "NotificationTypeNewMessage",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn message<T: AsRef<Message>>(&mut self, message: T) -> &mut Self {
         self.inner.message = message.as_ref().clone();
+        self.missing.retain(|f| *f != "message");
         self
     }
 }
@@ -239,17 +322,18 @@ impl AsRef<NotificationTypeNewMessage> for RTDNotificationTypeNewMessageBuilder
 
 /// New message was received through a push notification
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "notificationTypeNewPushMessage")]
 pub struct NotificationTypeNewPushMessage {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The message identifier. The message will not be available in the chat history, but the ID can be used in viewMessages and as reply_to_message_id
-    message_id: i64,
+    message_id: MessageId,
     /// Sender of the message. Corresponding user may be inaccessible
-    sender_user_id: i64,
+    sender_user_id: UserId,
     /// Push message content
     content: PushMessageContent,
 }
@@ -268,6 +352,14 @@ impl RObject for NotificationTypeNewPushMessage {
     }
 }
 
+impl PartialEq for NotificationTypeNewPushMessage {
+    fn eq(&self, other: &Self) -> bool {
+        self.message_id == other.message_id
+            && self.sender_user_id == other.sender_user_id
+            && self.content == other.content
+    }
+}
+
 impl TDNotificationType for NotificationTypeNewPushMessage {}
 
 impl NotificationTypeNewPushMessage {
@@ -275,47 +367,96 @@ impl NotificationTypeNewPushMessage {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDNotificationTypeNewPushMessageBuilder {
-        let mut inner = NotificationTypeNewPushMessage::default();
-        inner.td_name = "notificationTypeNewPushMessage".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDNotificationTypeNewPushMessageBuilder { inner }
+        RTDNotificationTypeNewPushMessageBuilder {
+            inner: NotificationTypeNewPushMessage::default(),
+            missing: vec!["message_id", "sender_user_id", "content"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDNotificationTypeNewPushMessageBuilder {
+        RTDNotificationTypeNewPushMessageBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDNotificationTypeNewPushMessageBuilder {
+        RTDNotificationTypeNewPushMessageBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn message_id(&self) -> i64 {
+    pub fn message_id(&self) -> MessageId {
         self.message_id
     }
 
-    pub fn sender_user_id(&self) -> i64 {
+    pub fn sender_user_id(&self) -> UserId {
         self.sender_user_id
     }
 
     pub fn content(&self) -> &PushMessageContent {
         &self.content
     }
+
+    pub fn into_content(self) -> PushMessageContent {
+        self.content
+    }
+
+    pub fn with_message_id<T: Into<MessageId>>(mut self, message_id: T) -> Self {
+        self.message_id = message_id.into();
+        self
+    }
+
+    pub fn with_sender_user_id<T: Into<UserId>>(mut self, sender_user_id: T) -> Self {
+        self.sender_user_id = sender_user_id.into();
+        self
+    }
+
+    pub fn with_content<T: AsRef<PushMessageContent>>(mut self, content: T) -> Self {
+        self.content = content.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDNotificationTypeNewPushMessageBuilder {
     inner: NotificationTypeNewPushMessage,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDNotificationTypeNewPushMessageBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> NotificationTypeNewPushMessage {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<NotificationTypeNewPushMessage> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "NotificationTypeNewPushMessage",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn message_id(&mut self, message_id: i64) -> &mut Self {
-        self.inner.message_id = message_id;
+    pub fn message_id<T: Into<MessageId>>(&mut self, message_id: T) -> &mut Self {
+        self.inner.message_id = message_id.into();
+        self.missing.retain(|f| *f != "message_id");
         self
     }
 
-    pub fn sender_user_id(&mut self, sender_user_id: i64) -> &mut Self {
-        self.inner.sender_user_id = sender_user_id;
+    pub fn sender_user_id<T: Into<UserId>>(&mut self, sender_user_id: T) -> &mut Self {
+        self.inner.sender_user_id = sender_user_id.into();
+        self.missing.retain(|f| *f != "sender_user_id");
         self
     }
 
     pub fn content<T: AsRef<PushMessageContent>>(&mut self, content: T) -> &mut Self {
         self.inner.content = content.as_ref().clone();
+        self.missing.retain(|f| *f != "content");
         self
     }
 }
@@ -334,12 +475,13 @@ impl AsRef<NotificationTypeNewPushMessage> for RTDNotificationTypeNewPushMessage
 
 /// New secret chat was created
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "notificationTypeNewSecretChat")]
 pub struct NotificationTypeNewSecretChat {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -357,6 +499,18 @@ impl RObject for NotificationTypeNewSecretChat {
     }
 }
 
+impl PartialEq for NotificationTypeNewSecretChat {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for NotificationTypeNewSecretChat {}
+
+impl std::hash::Hash for NotificationTypeNewSecretChat {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDNotificationType for NotificationTypeNewSecretChat {}
 
 impl NotificationTypeNewSecretChat {
@@ -364,22 +518,49 @@ impl NotificationTypeNewSecretChat {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDNotificationTypeNewSecretChatBuilder {
-        let mut inner = NotificationTypeNewSecretChat::default();
-        inner.td_name = "notificationTypeNewSecretChat".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDNotificationTypeNewSecretChatBuilder { inner }
+        RTDNotificationTypeNewSecretChatBuilder {
+            inner: NotificationTypeNewSecretChat::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDNotificationTypeNewSecretChatBuilder {
+        RTDNotificationTypeNewSecretChatBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDNotificationTypeNewSecretChatBuilder {
+        RTDNotificationTypeNewSecretChatBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDNotificationTypeNewSecretChatBuilder {
     inner: NotificationTypeNewSecretChat,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDNotificationTypeNewSecretChatBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> NotificationTypeNewSecretChat {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<NotificationTypeNewSecretChat> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "NotificationTypeNewSecretChat",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<NotificationTypeNewSecretChat> for NotificationTypeNewSecretChat {
@@ -393,3 +574,62 @@ impl AsRef<NotificationTypeNewSecretChat> for RTDNotificationTypeNewSecretChatBu
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(NotificationType::NewCall(
+            NotificationTypeNewCall::builder().call_id(i32::MAX).build(),
+        ));
+        assert_round_trip(NotificationType::NewMessage(
+            NotificationTypeNewMessage::builder()
+                .message(
+                    Message::builder()
+                        .id(9007199254740991i64)
+                        .sender_user_id(i32::MAX)
+                        .chat_id(9007199254740991i64)
+                        .is_outgoing(true)
+                        .can_be_edited(true)
+                        .can_be_forwarded(true)
+                        .can_be_deleted_only_for_self(true)
+                        .can_be_deleted_for_all_users(true)
+                        .is_channel_post(true)
+                        .contains_unread_mention(true)
+                        .date(i32::MAX)
+                        .edit_date(i32::MAX)
+                        .reply_to_message_id(9007199254740991i64)
+                        .ttl(i32::MAX)
+                        .ttl_expires_in(1.5)
+                        .via_bot_user_id(i32::MAX)
+                        .author_signature("text")
+                        .views(i32::MAX)
+                        .media_album_id(i64::MAX)
+                        .restriction_reason("text")
+                        .content(MessageContent::MessageBasicGroupChatCreate(
+                            MessageBasicGroupChatCreate::builder()
+                                .title("text")
+                                .member_user_ids(vec![Default::default()])
+                                .build(),
+                        ))
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(NotificationType::NewPushMessage(
+            NotificationTypeNewPushMessage::builder()
+                .message_id(9007199254740991i64)
+                .sender_user_id(i32::MAX)
+                .content(PushMessageContent::BasicGroupChatCreate(
+                    PushMessageContentBasicGroupChatCreate::builder().build(),
+                ))
+                .build(),
+        ));
+        assert_round_trip(NotificationType::NewSecretChat(
+            NotificationTypeNewSecretChat::builder().build(),
+        ));
+    }
+}
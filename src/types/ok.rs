@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// An object of this type is returned on a successful function call for certain functions
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "ok")]
 pub struct Ok {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -27,27 +27,63 @@ impl RObject for Ok {
     }
 }
 
+impl PartialEq for Ok {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for Ok {}
+
+impl std::hash::Hash for Ok {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl Ok {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDOkBuilder {
-        let mut inner = Ok::default();
-        inner.td_name = "ok".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDOkBuilder { inner }
+        RTDOkBuilder {
+            inner: Ok::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDOkBuilder {
+        RTDOkBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDOkBuilder {
+        RTDOkBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDOkBuilder {
     inner: Ok,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDOkBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> Ok {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<Ok> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("Ok", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<Ok> for Ok {
@@ -61,3 +97,14 @@ impl AsRef<Ok> for RTDOkBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(Ok::builder().build());
+    }
+}
@@ -1,52 +1,48 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Represents the value of an option
 pub trait TDOptionValue: Debug + RObject {}
 
 /// Represents the value of an option
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum OptionValue {
-    #[doc(hidden)]
-    _Default(()),
-    /// Returns the value of an option by its name. (Check the list of available options on https://core.telegram.org/tdlib/options.) Can be called before authorization
-    GetOption(GetOption),
     /// Represents a boolean option
+    #[serde(rename = "optionValueBoolean")]
     Boolean(OptionValueBoolean),
     /// Represents an unknown option or an option which has a default value
+    #[serde(rename = "optionValueEmpty")]
     Empty(OptionValueEmpty),
     /// Represents an integer option
+    #[serde(rename = "optionValueInteger")]
     Integer(OptionValueInteger),
     /// Represents a string option
+    #[serde(rename = "optionValueString")]
     String(OptionValueString),
 }
 
+/// Default value is default of [OptionValueBoolean], builders still require the field to be set
 impl Default for OptionValue {
     fn default() -> Self {
-        OptionValue::_Default(())
+        OptionValue::Boolean(OptionValueBoolean::default())
     }
 }
 
-impl<'de> Deserialize<'de> for OptionValue {
-    fn deserialize<D>(deserializer: D) -> Result<OptionValue, D::Error>
+impl Serialize for OptionValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          OptionValue,
-          (getOption, GetOption);
-          (optionValueBoolean, Boolean);
-          (optionValueEmpty, Empty);
-          (optionValueInteger, Integer);
-          (optionValueString, String);
-
-        )(deserializer)
+        match self {
+            OptionValue::Boolean(t) => t.serialize(serializer),
+            OptionValue::Empty(t) => t.serialize(serializer),
+            OptionValue::Integer(t) => t.serialize(serializer),
+            OptionValue::String(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -54,25 +50,19 @@ impl RObject for OptionValue {
     #[doc(hidden)]
     fn td_name(&self) -> &'static str {
         match self {
-            OptionValue::GetOption(t) => t.td_name(),
             OptionValue::Boolean(t) => t.td_name(),
             OptionValue::Empty(t) => t.td_name(),
             OptionValue::Integer(t) => t.td_name(),
             OptionValue::String(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
     fn extra(&self) -> Option<String> {
         match self {
-            OptionValue::GetOption(t) => t.extra(),
             OptionValue::Boolean(t) => t.extra(),
             OptionValue::Empty(t) => t.extra(),
             OptionValue::Integer(t) => t.extra(),
             OptionValue::String(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -84,10 +74,6 @@ impl OptionValue {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, OptionValue::_Default(_))
-    }
 }
 
 impl AsRef<OptionValue> for OptionValue {
@@ -98,12 +84,13 @@ impl AsRef<OptionValue> for OptionValue {
 
 /// Represents a boolean option
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "optionValueBoolean")]
 pub struct OptionValueBoolean {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The value of the option
     value: bool,
@@ -123,6 +110,20 @@ impl RObject for OptionValueBoolean {
     }
 }
 
+impl PartialEq for OptionValueBoolean {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for OptionValueBoolean {}
+
+impl std::hash::Hash for OptionValueBoolean {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.value, state);
+    }
+}
+
 impl TDOptionValue for OptionValueBoolean {}
 
 impl OptionValueBoolean {
@@ -130,26 +131,58 @@ impl OptionValueBoolean {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDOptionValueBooleanBuilder {
-        let mut inner = OptionValueBoolean::default();
-        inner.td_name = "optionValueBoolean".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDOptionValueBooleanBuilder { inner }
+        RTDOptionValueBooleanBuilder {
+            inner: OptionValueBoolean::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDOptionValueBooleanBuilder {
+        RTDOptionValueBooleanBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDOptionValueBooleanBuilder {
+        RTDOptionValueBooleanBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn value(&self) -> bool {
         self.value
     }
+
+    pub fn with_value(mut self, value: bool) -> Self {
+        self.value = value;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDOptionValueBooleanBuilder {
     inner: OptionValueBoolean,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDOptionValueBooleanBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> OptionValueBoolean {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<OptionValueBoolean> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "OptionValueBoolean",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn value(&mut self, value: bool) -> &mut Self {
         self.inner.value = value;
@@ -171,12 +204,13 @@ impl AsRef<OptionValueBoolean> for RTDOptionValueBooleanBuilder {
 
 /// Represents an unknown option or an option which has a default value
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "optionValueEmpty")]
 pub struct OptionValueEmpty {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -194,6 +228,18 @@ impl RObject for OptionValueEmpty {
     }
 }
 
+impl PartialEq for OptionValueEmpty {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for OptionValueEmpty {}
+
+impl std::hash::Hash for OptionValueEmpty {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDOptionValue for OptionValueEmpty {}
 
 impl OptionValueEmpty {
@@ -201,22 +247,49 @@ impl OptionValueEmpty {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDOptionValueEmptyBuilder {
-        let mut inner = OptionValueEmpty::default();
-        inner.td_name = "optionValueEmpty".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDOptionValueEmptyBuilder { inner }
+        RTDOptionValueEmptyBuilder {
+            inner: OptionValueEmpty::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDOptionValueEmptyBuilder {
+        RTDOptionValueEmptyBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDOptionValueEmptyBuilder {
+        RTDOptionValueEmptyBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDOptionValueEmptyBuilder {
     inner: OptionValueEmpty,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDOptionValueEmptyBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> OptionValueEmpty {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<OptionValueEmpty> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "OptionValueEmpty",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<OptionValueEmpty> for OptionValueEmpty {
@@ -233,15 +306,16 @@ impl AsRef<OptionValueEmpty> for RTDOptionValueEmptyBuilder {
 
 /// Represents an integer option
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "optionValueInteger")]
 pub struct OptionValueInteger {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The value of the option
-    value: i64,
+    value: i32,
 }
 
 impl RObject for OptionValueInteger {
@@ -258,6 +332,20 @@ impl RObject for OptionValueInteger {
     }
 }
 
+impl PartialEq for OptionValueInteger {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for OptionValueInteger {}
+
+impl std::hash::Hash for OptionValueInteger {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.value, state);
+    }
+}
+
 impl TDOptionValue for OptionValueInteger {}
 
 impl OptionValueInteger {
@@ -265,29 +353,62 @@ impl OptionValueInteger {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDOptionValueIntegerBuilder {
-        let mut inner = OptionValueInteger::default();
-        inner.td_name = "optionValueInteger".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDOptionValueIntegerBuilder { inner }
+        RTDOptionValueIntegerBuilder {
+            inner: OptionValueInteger::default(),
+            missing: vec!["value"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDOptionValueIntegerBuilder {
+        RTDOptionValueIntegerBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDOptionValueIntegerBuilder {
+        RTDOptionValueIntegerBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn value(&self) -> i64 {
+    pub fn value(&self) -> i32 {
         self.value
     }
+
+    pub fn with_value(mut self, value: i32) -> Self {
+        self.value = value;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDOptionValueIntegerBuilder {
     inner: OptionValueInteger,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDOptionValueIntegerBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> OptionValueInteger {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<OptionValueInteger> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "OptionValueInteger",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn value(&mut self, value: i64) -> &mut Self {
+    pub fn value(&mut self, value: i32) -> &mut Self {
         self.inner.value = value;
+        self.missing.retain(|f| *f != "value");
         self
     }
 }
@@ -306,12 +427,13 @@ impl AsRef<OptionValueInteger> for RTDOptionValueIntegerBuilder {
 
 /// Represents a string option
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "optionValueString")]
 pub struct OptionValueString {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The value of the option
     value: String,
@@ -331,6 +453,20 @@ impl RObject for OptionValueString {
     }
 }
 
+impl PartialEq for OptionValueString {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for OptionValueString {}
+
+impl std::hash::Hash for OptionValueString {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.value, state);
+    }
+}
+
 impl TDOptionValue for OptionValueString {}
 
 impl OptionValueString {
@@ -338,29 +474,66 @@ impl OptionValueString {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDOptionValueStringBuilder {
-        let mut inner = OptionValueString::default();
-        inner.td_name = "optionValueString".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDOptionValueStringBuilder { inner }
+        RTDOptionValueStringBuilder {
+            inner: OptionValueString::default(),
+            missing: vec!["value"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDOptionValueStringBuilder {
+        RTDOptionValueStringBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDOptionValueStringBuilder {
+        RTDOptionValueStringBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn value(&self) -> &String {
         &self.value
     }
+
+    pub fn into_value(self) -> String {
+        self.value
+    }
+
+    pub fn with_value<T: AsRef<str>>(mut self, value: T) -> Self {
+        self.value = value.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDOptionValueStringBuilder {
     inner: OptionValueString,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDOptionValueStringBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> OptionValueString {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<OptionValueString> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "OptionValueString",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn value<T: AsRef<str>>(&mut self, value: T) -> &mut Self {
         self.inner.value = value.as_ref().to_string();
+        self.missing.retain(|f| *f != "value");
         self
     }
 }
@@ -376,3 +549,23 @@ impl AsRef<OptionValueString> for RTDOptionValueStringBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(OptionValue::Boolean(
+            OptionValueBoolean::builder().value(true).build(),
+        ));
+        assert_round_trip(OptionValue::Empty(OptionValueEmpty::builder().build()));
+        assert_round_trip(OptionValue::Integer(
+            OptionValueInteger::builder().value(i32::MAX).build(),
+        ));
+        assert_round_trip(OptionValue::String(
+            OptionValueString::builder().value("text").build(),
+        ));
+    }
+}
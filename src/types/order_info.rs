@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Order information
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "orderInfo")]
 pub struct OrderInfo {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Name of the user
     name: String,
@@ -35,15 +35,49 @@ impl RObject for OrderInfo {
     }
 }
 
+impl PartialEq for OrderInfo {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && self.phone_number == other.phone_number
+            && self.email_address == other.email_address
+            && self.shipping_address == other.shipping_address
+    }
+}
+
+impl Eq for OrderInfo {}
+
+impl std::hash::Hash for OrderInfo {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.name, state);
+        std::hash::Hash::hash(&self.phone_number, state);
+        std::hash::Hash::hash(&self.email_address, state);
+        std::hash::Hash::hash(&self.shipping_address, state);
+    }
+}
+
 impl OrderInfo {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDOrderInfoBuilder {
-        let mut inner = OrderInfo::default();
-        inner.td_name = "orderInfo".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDOrderInfoBuilder { inner }
+        RTDOrderInfoBuilder {
+            inner: OrderInfo::default(),
+            missing: vec!["name", "phone_number", "email_address"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDOrderInfoBuilder {
+        RTDOrderInfoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDOrderInfoBuilder {
+        RTDOrderInfoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn name(&self) -> &String {
@@ -61,30 +95,79 @@ impl OrderInfo {
     pub fn shipping_address(&self) -> &Option<Address> {
         &self.shipping_address
     }
+
+    pub fn into_name(self) -> String {
+        self.name
+    }
+
+    pub fn into_phone_number(self) -> String {
+        self.phone_number
+    }
+
+    pub fn into_email_address(self) -> String {
+        self.email_address
+    }
+
+    pub fn into_shipping_address(self) -> Option<Address> {
+        self.shipping_address
+    }
+
+    pub fn with_name<T: AsRef<str>>(mut self, name: T) -> Self {
+        self.name = name.as_ref().to_string();
+        self
+    }
+
+    pub fn with_phone_number<T: AsRef<str>>(mut self, phone_number: T) -> Self {
+        self.phone_number = phone_number.as_ref().to_string();
+        self
+    }
+
+    pub fn with_email_address<T: AsRef<str>>(mut self, email_address: T) -> Self {
+        self.email_address = email_address.as_ref().to_string();
+        self
+    }
+
+    pub fn with_shipping_address<T: AsRef<Address>>(mut self, shipping_address: T) -> Self {
+        self.shipping_address = Some(shipping_address.as_ref().clone());
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDOrderInfoBuilder {
     inner: OrderInfo,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDOrderInfoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> OrderInfo {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<OrderInfo> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("OrderInfo", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn name<T: AsRef<str>>(&mut self, name: T) -> &mut Self {
         self.inner.name = name.as_ref().to_string();
+        self.missing.retain(|f| *f != "name");
         self
     }
 
     pub fn phone_number<T: AsRef<str>>(&mut self, phone_number: T) -> &mut Self {
         self.inner.phone_number = phone_number.as_ref().to_string();
+        self.missing.retain(|f| *f != "phone_number");
         self
     }
 
     pub fn email_address<T: AsRef<str>>(&mut self, email_address: T) -> &mut Self {
         self.inner.email_address = email_address.as_ref().to_string();
+        self.missing.retain(|f| *f != "email_address");
         self
     }
 
@@ -105,3 +188,20 @@ impl AsRef<OrderInfo> for RTDOrderInfoBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            OrderInfo::builder()
+                .name("text")
+                .phone_number("text")
+                .email_address("text")
+                .build(),
+        );
+    }
+}
@@ -1,124 +1,148 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Describes a block of an instant view web page
 pub trait TDPageBlock: Debug + RObject {}
 
 /// Describes a block of an instant view web page
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "@type")]
 pub enum PageBlock {
-    #[doc(hidden)]
-    _Default(()),
     /// An invisible anchor on a page, which can be used in a URL to open the page from the specified anchor
+    #[serde(rename = "pageBlockAnchor")]
     Anchor(PageBlockAnchor),
     /// An animation
+    #[serde(rename = "pageBlockAnimation")]
     Animation(PageBlockAnimation),
     /// An audio file
+    #[serde(rename = "pageBlockAudio")]
     Audio(PageBlockAudio),
     /// The author and publishing date of a page
+    #[serde(rename = "pageBlockAuthorDate")]
     AuthorDate(PageBlockAuthorDate),
     /// A block quote
+    #[serde(rename = "pageBlockBlockQuote")]
     BlockQuote(PageBlockBlockQuote),
     /// A link to a chat
+    #[serde(rename = "pageBlockChatLink")]
     ChatLink(PageBlockChatLink),
     /// A collage
+    #[serde(rename = "pageBlockCollage")]
     Collage(PageBlockCollage),
     /// A page cover
+    #[serde(rename = "pageBlockCover")]
     Cover(PageBlockCover),
     /// A collapsible block
+    #[serde(rename = "pageBlockDetails")]
     Details(PageBlockDetails),
     /// An empty block separating a page
+    #[serde(rename = "pageBlockDivider")]
     Divider(PageBlockDivider),
     /// An embedded web page
+    #[serde(rename = "pageBlockEmbedded")]
     Embedded(PageBlockEmbedded),
     /// An embedded post
+    #[serde(rename = "pageBlockEmbeddedPost")]
     EmbeddedPost(PageBlockEmbeddedPost),
     /// The footer of a page
+    #[serde(rename = "pageBlockFooter")]
     Footer(PageBlockFooter),
     /// A header
+    #[serde(rename = "pageBlockHeader")]
     Header(PageBlockHeader),
     /// A kicker
+    #[serde(rename = "pageBlockKicker")]
     Kicker(PageBlockKicker),
     /// A list of data blocks
+    #[serde(rename = "pageBlockList")]
     List(PageBlockList),
     /// A map
+    #[serde(rename = "pageBlockMap")]
     Map(PageBlockMap),
     /// A text paragraph
+    #[serde(rename = "pageBlockParagraph")]
     Paragraph(PageBlockParagraph),
     /// A photo
+    #[serde(rename = "pageBlockPhoto")]
     Photo(PageBlockPhoto),
     /// A preformatted text paragraph
+    #[serde(rename = "pageBlockPreformatted")]
     Preformatted(PageBlockPreformatted),
     /// A pull quote
+    #[serde(rename = "pageBlockPullQuote")]
     PullQuote(PageBlockPullQuote),
     /// Related articles
+    #[serde(rename = "pageBlockRelatedArticles")]
     RelatedArticles(PageBlockRelatedArticles),
     /// A slideshow
+    #[serde(rename = "pageBlockSlideshow")]
     Slideshow(PageBlockSlideshow),
     /// A subheader
+    #[serde(rename = "pageBlockSubheader")]
     Subheader(PageBlockSubheader),
     /// The subtitle of a page
+    #[serde(rename = "pageBlockSubtitle")]
     Subtitle(PageBlockSubtitle),
     /// A table
+    #[serde(rename = "pageBlockTable")]
     Table(PageBlockTable),
     /// The title of a page
+    #[serde(rename = "pageBlockTitle")]
     Title(PageBlockTitle),
     /// A video
+    #[serde(rename = "pageBlockVideo")]
     Video(PageBlockVideo),
     /// A voice note
+    #[serde(rename = "pageBlockVoiceNote")]
     VoiceNote(PageBlockVoiceNote),
 }
 
+/// Default value is default of [PageBlockAnchor], builders still require the field to be set
 impl Default for PageBlock {
     fn default() -> Self {
-        PageBlock::_Default(())
+        PageBlock::Anchor(PageBlockAnchor::default())
     }
 }
 
-impl<'de> Deserialize<'de> for PageBlock {
-    fn deserialize<D>(deserializer: D) -> Result<PageBlock, D::Error>
+impl Serialize for PageBlock {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          PageBlock,
-          (pageBlockAnchor, Anchor);
-          (pageBlockAnimation, Animation);
-          (pageBlockAudio, Audio);
-          (pageBlockAuthorDate, AuthorDate);
-          (pageBlockBlockQuote, BlockQuote);
-          (pageBlockChatLink, ChatLink);
-          (pageBlockCollage, Collage);
-          (pageBlockCover, Cover);
-          (pageBlockDetails, Details);
-          (pageBlockDivider, Divider);
-          (pageBlockEmbedded, Embedded);
-          (pageBlockEmbeddedPost, EmbeddedPost);
-          (pageBlockFooter, Footer);
-          (pageBlockHeader, Header);
-          (pageBlockKicker, Kicker);
-          (pageBlockList, List);
-          (pageBlockMap, Map);
-          (pageBlockParagraph, Paragraph);
-          (pageBlockPhoto, Photo);
-          (pageBlockPreformatted, Preformatted);
-          (pageBlockPullQuote, PullQuote);
-          (pageBlockRelatedArticles, RelatedArticles);
-          (pageBlockSlideshow, Slideshow);
-          (pageBlockSubheader, Subheader);
-          (pageBlockSubtitle, Subtitle);
-          (pageBlockTable, Table);
-          (pageBlockTitle, Title);
-          (pageBlockVideo, Video);
-          (pageBlockVoiceNote, VoiceNote);
-
-        )(deserializer)
+        match self {
+            PageBlock::Anchor(t) => t.serialize(serializer),
+            PageBlock::Animation(t) => t.serialize(serializer),
+            PageBlock::Audio(t) => t.serialize(serializer),
+            PageBlock::AuthorDate(t) => t.serialize(serializer),
+            PageBlock::BlockQuote(t) => t.serialize(serializer),
+            PageBlock::ChatLink(t) => t.serialize(serializer),
+            PageBlock::Collage(t) => t.serialize(serializer),
+            PageBlock::Cover(t) => t.serialize(serializer),
+            PageBlock::Details(t) => t.serialize(serializer),
+            PageBlock::Divider(t) => t.serialize(serializer),
+            PageBlock::Embedded(t) => t.serialize(serializer),
+            PageBlock::EmbeddedPost(t) => t.serialize(serializer),
+            PageBlock::Footer(t) => t.serialize(serializer),
+            PageBlock::Header(t) => t.serialize(serializer),
+            PageBlock::Kicker(t) => t.serialize(serializer),
+            PageBlock::List(t) => t.serialize(serializer),
+            PageBlock::Map(t) => t.serialize(serializer),
+            PageBlock::Paragraph(t) => t.serialize(serializer),
+            PageBlock::Photo(t) => t.serialize(serializer),
+            PageBlock::Preformatted(t) => t.serialize(serializer),
+            PageBlock::PullQuote(t) => t.serialize(serializer),
+            PageBlock::RelatedArticles(t) => t.serialize(serializer),
+            PageBlock::Slideshow(t) => t.serialize(serializer),
+            PageBlock::Subheader(t) => t.serialize(serializer),
+            PageBlock::Subtitle(t) => t.serialize(serializer),
+            PageBlock::Table(t) => t.serialize(serializer),
+            PageBlock::Title(t) => t.serialize(serializer),
+            PageBlock::Video(t) => t.serialize(serializer),
+            PageBlock::VoiceNote(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -155,8 +179,6 @@ impl RObject for PageBlock {
             PageBlock::Title(t) => t.td_name(),
             PageBlock::Video(t) => t.td_name(),
             PageBlock::VoiceNote(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -191,8 +213,6 @@ impl RObject for PageBlock {
             PageBlock::Title(t) => t.extra(),
             PageBlock::Video(t) => t.extra(),
             PageBlock::VoiceNote(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -204,10 +224,6 @@ impl PageBlock {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, PageBlock::_Default(_))
-    }
 }
 
 impl AsRef<PageBlock> for PageBlock {
@@ -218,12 +234,13 @@ impl AsRef<PageBlock> for PageBlock {
 
 /// An invisible anchor on a page, which can be used in a URL to open the page from the specified anchor
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pageBlockAnchor")]
 pub struct PageBlockAnchor {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Name of the anchor
     name: String,
@@ -243,6 +260,20 @@ impl RObject for PageBlockAnchor {
     }
 }
 
+impl PartialEq for PageBlockAnchor {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+}
+
+impl Eq for PageBlockAnchor {}
+
+impl std::hash::Hash for PageBlockAnchor {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.name, state);
+    }
+}
+
 impl TDPageBlock for PageBlockAnchor {}
 
 impl PageBlockAnchor {
@@ -250,29 +281,66 @@ impl PageBlockAnchor {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPageBlockAnchorBuilder {
-        let mut inner = PageBlockAnchor::default();
-        inner.td_name = "pageBlockAnchor".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPageBlockAnchorBuilder { inner }
+        RTDPageBlockAnchorBuilder {
+            inner: PageBlockAnchor::default(),
+            missing: vec!["name"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPageBlockAnchorBuilder {
+        RTDPageBlockAnchorBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPageBlockAnchorBuilder {
+        RTDPageBlockAnchorBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn name(&self) -> &String {
         &self.name
     }
+
+    pub fn into_name(self) -> String {
+        self.name
+    }
+
+    pub fn with_name<T: AsRef<str>>(mut self, name: T) -> Self {
+        self.name = name.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPageBlockAnchorBuilder {
     inner: PageBlockAnchor,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPageBlockAnchorBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PageBlockAnchor {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PageBlockAnchor> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PageBlockAnchor",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn name<T: AsRef<str>>(&mut self, name: T) -> &mut Self {
         self.inner.name = name.as_ref().to_string();
+        self.missing.retain(|f| *f != "name");
         self
     }
 }
@@ -291,12 +359,13 @@ impl AsRef<PageBlockAnchor> for RTDPageBlockAnchorBuilder {
 
 /// An animation
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pageBlockAnimation")]
 pub struct PageBlockAnimation {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Animation file; may be null
     animation: Option<Animation>,
@@ -320,6 +389,24 @@ impl RObject for PageBlockAnimation {
     }
 }
 
+impl PartialEq for PageBlockAnimation {
+    fn eq(&self, other: &Self) -> bool {
+        self.animation == other.animation
+            && self.caption == other.caption
+            && self.need_autoplay == other.need_autoplay
+    }
+}
+
+impl Eq for PageBlockAnimation {}
+
+impl std::hash::Hash for PageBlockAnimation {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.animation, state);
+        std::hash::Hash::hash(&self.caption, state);
+        std::hash::Hash::hash(&self.need_autoplay, state);
+    }
+}
+
 impl TDPageBlock for PageBlockAnimation {}
 
 impl PageBlockAnimation {
@@ -327,10 +414,24 @@ impl PageBlockAnimation {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPageBlockAnimationBuilder {
-        let mut inner = PageBlockAnimation::default();
-        inner.td_name = "pageBlockAnimation".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPageBlockAnimationBuilder { inner }
+        RTDPageBlockAnimationBuilder {
+            inner: PageBlockAnimation::default(),
+            missing: vec!["caption"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPageBlockAnimationBuilder {
+        RTDPageBlockAnimationBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPageBlockAnimationBuilder {
+        RTDPageBlockAnimationBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn animation(&self) -> &Option<Animation> {
@@ -344,17 +445,53 @@ impl PageBlockAnimation {
     pub fn need_autoplay(&self) -> bool {
         self.need_autoplay
     }
+
+    pub fn into_animation(self) -> Option<Animation> {
+        self.animation
+    }
+
+    pub fn into_caption(self) -> PageBlockCaption {
+        self.caption
+    }
+
+    pub fn with_animation<T: AsRef<Animation>>(mut self, animation: T) -> Self {
+        self.animation = Some(animation.as_ref().clone());
+        self
+    }
+
+    pub fn with_caption<T: AsRef<PageBlockCaption>>(mut self, caption: T) -> Self {
+        self.caption = caption.as_ref().clone();
+        self
+    }
+
+    pub fn with_need_autoplay(mut self, need_autoplay: bool) -> Self {
+        self.need_autoplay = need_autoplay;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPageBlockAnimationBuilder {
     inner: PageBlockAnimation,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPageBlockAnimationBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PageBlockAnimation {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PageBlockAnimation> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PageBlockAnimation",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn animation<T: AsRef<Animation>>(&mut self, animation: T) -> &mut Self {
         self.inner.animation = Some(animation.as_ref().clone());
@@ -363,6 +500,7 @@ impl RTDPageBlockAnimationBuilder {
 
     pub fn caption<T: AsRef<PageBlockCaption>>(&mut self, caption: T) -> &mut Self {
         self.inner.caption = caption.as_ref().clone();
+        self.missing.retain(|f| *f != "caption");
         self
     }
 
@@ -386,12 +524,13 @@ impl AsRef<PageBlockAnimation> for RTDPageBlockAnimationBuilder {
 
 /// An audio file
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pageBlockAudio")]
 pub struct PageBlockAudio {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Audio file; may be null
     audio: Option<Audio>,
@@ -413,6 +552,21 @@ impl RObject for PageBlockAudio {
     }
 }
 
+impl PartialEq for PageBlockAudio {
+    fn eq(&self, other: &Self) -> bool {
+        self.audio == other.audio && self.caption == other.caption
+    }
+}
+
+impl Eq for PageBlockAudio {}
+
+impl std::hash::Hash for PageBlockAudio {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.audio, state);
+        std::hash::Hash::hash(&self.caption, state);
+    }
+}
+
 impl TDPageBlock for PageBlockAudio {}
 
 impl PageBlockAudio {
@@ -420,10 +574,24 @@ impl PageBlockAudio {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPageBlockAudioBuilder {
-        let mut inner = PageBlockAudio::default();
-        inner.td_name = "pageBlockAudio".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPageBlockAudioBuilder { inner }
+        RTDPageBlockAudioBuilder {
+            inner: PageBlockAudio::default(),
+            missing: vec!["caption"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPageBlockAudioBuilder {
+        RTDPageBlockAudioBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPageBlockAudioBuilder {
+        RTDPageBlockAudioBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn audio(&self) -> &Option<Audio> {
@@ -433,17 +601,48 @@ impl PageBlockAudio {
     pub fn caption(&self) -> &PageBlockCaption {
         &self.caption
     }
+
+    pub fn into_audio(self) -> Option<Audio> {
+        self.audio
+    }
+
+    pub fn into_caption(self) -> PageBlockCaption {
+        self.caption
+    }
+
+    pub fn with_audio<T: AsRef<Audio>>(mut self, audio: T) -> Self {
+        self.audio = Some(audio.as_ref().clone());
+        self
+    }
+
+    pub fn with_caption<T: AsRef<PageBlockCaption>>(mut self, caption: T) -> Self {
+        self.caption = caption.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPageBlockAudioBuilder {
     inner: PageBlockAudio,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPageBlockAudioBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PageBlockAudio {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PageBlockAudio> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PageBlockAudio",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn audio<T: AsRef<Audio>>(&mut self, audio: T) -> &mut Self {
         self.inner.audio = Some(audio.as_ref().clone());
@@ -452,6 +651,7 @@ impl RTDPageBlockAudioBuilder {
 
     pub fn caption<T: AsRef<PageBlockCaption>>(&mut self, caption: T) -> &mut Self {
         self.inner.caption = caption.as_ref().clone();
+        self.missing.retain(|f| *f != "caption");
         self
     }
 }
@@ -470,17 +670,18 @@ impl AsRef<PageBlockAudio> for RTDPageBlockAudioBuilder {
 
 /// The author and publishing date of a page
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pageBlockAuthorDate")]
 pub struct PageBlockAuthorDate {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Author
     author: RichText,
     /// Point in time (Unix timestamp) when the article was published; 0 if unknown
-    publish_date: i64,
+    publish_date: i32,
 }
 
 impl RObject for PageBlockAuthorDate {
@@ -497,6 +698,21 @@ impl RObject for PageBlockAuthorDate {
     }
 }
 
+impl PartialEq for PageBlockAuthorDate {
+    fn eq(&self, other: &Self) -> bool {
+        self.author == other.author && self.publish_date == other.publish_date
+    }
+}
+
+impl Eq for PageBlockAuthorDate {}
+
+impl std::hash::Hash for PageBlockAuthorDate {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.author, state);
+        std::hash::Hash::hash(&self.publish_date, state);
+    }
+}
+
 impl TDPageBlock for PageBlockAuthorDate {}
 
 impl PageBlockAuthorDate {
@@ -504,37 +720,79 @@ impl PageBlockAuthorDate {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPageBlockAuthorDateBuilder {
-        let mut inner = PageBlockAuthorDate::default();
-        inner.td_name = "pageBlockAuthorDate".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPageBlockAuthorDateBuilder { inner }
+        RTDPageBlockAuthorDateBuilder {
+            inner: PageBlockAuthorDate::default(),
+            missing: vec!["author"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPageBlockAuthorDateBuilder {
+        RTDPageBlockAuthorDateBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPageBlockAuthorDateBuilder {
+        RTDPageBlockAuthorDateBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn author(&self) -> &RichText {
         &self.author
     }
 
-    pub fn publish_date(&self) -> i64 {
+    pub fn publish_date(&self) -> i32 {
         self.publish_date
     }
+
+    pub fn into_author(self) -> RichText {
+        self.author
+    }
+
+    pub fn with_author<T: AsRef<RichText>>(mut self, author: T) -> Self {
+        self.author = author.as_ref().clone();
+        self
+    }
+
+    pub fn with_publish_date(mut self, publish_date: i32) -> Self {
+        self.publish_date = publish_date;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPageBlockAuthorDateBuilder {
     inner: PageBlockAuthorDate,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPageBlockAuthorDateBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PageBlockAuthorDate {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PageBlockAuthorDate> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PageBlockAuthorDate",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn author<T: AsRef<RichText>>(&mut self, author: T) -> &mut Self {
         self.inner.author = author.as_ref().clone();
+        self.missing.retain(|f| *f != "author");
         self
     }
 
-    pub fn publish_date(&mut self, publish_date: i64) -> &mut Self {
+    pub fn publish_date(&mut self, publish_date: i32) -> &mut Self {
         self.inner.publish_date = publish_date;
         self
     }
@@ -554,12 +812,13 @@ impl AsRef<PageBlockAuthorDate> for RTDPageBlockAuthorDateBuilder {
 
 /// A block quote
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pageBlockBlockQuote")]
 pub struct PageBlockBlockQuote {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Quote text
     text: RichText,
@@ -581,6 +840,21 @@ impl RObject for PageBlockBlockQuote {
     }
 }
 
+impl PartialEq for PageBlockBlockQuote {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text && self.credit == other.credit
+    }
+}
+
+impl Eq for PageBlockBlockQuote {}
+
+impl std::hash::Hash for PageBlockBlockQuote {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.text, state);
+        std::hash::Hash::hash(&self.credit, state);
+    }
+}
+
 impl TDPageBlock for PageBlockBlockQuote {}
 
 impl PageBlockBlockQuote {
@@ -588,10 +862,24 @@ impl PageBlockBlockQuote {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPageBlockBlockQuoteBuilder {
-        let mut inner = PageBlockBlockQuote::default();
-        inner.td_name = "pageBlockBlockQuote".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPageBlockBlockQuoteBuilder { inner }
+        RTDPageBlockBlockQuoteBuilder {
+            inner: PageBlockBlockQuote::default(),
+            missing: vec!["text", "credit"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPageBlockBlockQuoteBuilder {
+        RTDPageBlockBlockQuoteBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPageBlockBlockQuoteBuilder {
+        RTDPageBlockBlockQuoteBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn text(&self) -> &RichText {
@@ -601,25 +889,58 @@ impl PageBlockBlockQuote {
     pub fn credit(&self) -> &RichText {
         &self.credit
     }
+
+    pub fn into_text(self) -> RichText {
+        self.text
+    }
+
+    pub fn into_credit(self) -> RichText {
+        self.credit
+    }
+
+    pub fn with_text<T: AsRef<RichText>>(mut self, text: T) -> Self {
+        self.text = text.as_ref().clone();
+        self
+    }
+
+    pub fn with_credit<T: AsRef<RichText>>(mut self, credit: T) -> Self {
+        self.credit = credit.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPageBlockBlockQuoteBuilder {
     inner: PageBlockBlockQuote,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPageBlockBlockQuoteBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PageBlockBlockQuote {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PageBlockBlockQuote> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PageBlockBlockQuote",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn text<T: AsRef<RichText>>(&mut self, text: T) -> &mut Self {
         self.inner.text = text.as_ref().clone();
+        self.missing.retain(|f| *f != "text");
         self
     }
 
     pub fn credit<T: AsRef<RichText>>(&mut self, credit: T) -> &mut Self {
         self.inner.credit = credit.as_ref().clone();
+        self.missing.retain(|f| *f != "credit");
         self
     }
 }
@@ -638,12 +959,13 @@ impl AsRef<PageBlockBlockQuote> for RTDPageBlockBlockQuoteBuilder {
 
 /// A link to a chat
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pageBlockChatLink")]
 pub struct PageBlockChatLink {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Chat title
     title: String,
@@ -667,6 +989,22 @@ impl RObject for PageBlockChatLink {
     }
 }
 
+impl PartialEq for PageBlockChatLink {
+    fn eq(&self, other: &Self) -> bool {
+        self.title == other.title && self.photo == other.photo && self.username == other.username
+    }
+}
+
+impl Eq for PageBlockChatLink {}
+
+impl std::hash::Hash for PageBlockChatLink {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.title, state);
+        std::hash::Hash::hash(&self.photo, state);
+        std::hash::Hash::hash(&self.username, state);
+    }
+}
+
 impl TDPageBlock for PageBlockChatLink {}
 
 impl PageBlockChatLink {
@@ -674,10 +1012,24 @@ impl PageBlockChatLink {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPageBlockChatLinkBuilder {
-        let mut inner = PageBlockChatLink::default();
-        inner.td_name = "pageBlockChatLink".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPageBlockChatLinkBuilder { inner }
+        RTDPageBlockChatLinkBuilder {
+            inner: PageBlockChatLink::default(),
+            missing: vec!["title", "username"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPageBlockChatLinkBuilder {
+        RTDPageBlockChatLinkBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPageBlockChatLinkBuilder {
+        RTDPageBlockChatLinkBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn title(&self) -> &String {
@@ -691,20 +1043,61 @@ impl PageBlockChatLink {
     pub fn username(&self) -> &String {
         &self.username
     }
+
+    pub fn into_title(self) -> String {
+        self.title
+    }
+
+    pub fn into_photo(self) -> Option<ChatPhoto> {
+        self.photo
+    }
+
+    pub fn into_username(self) -> String {
+        self.username
+    }
+
+    pub fn with_title<T: AsRef<str>>(mut self, title: T) -> Self {
+        self.title = title.as_ref().to_string();
+        self
+    }
+
+    pub fn with_photo<T: AsRef<ChatPhoto>>(mut self, photo: T) -> Self {
+        self.photo = Some(photo.as_ref().clone());
+        self
+    }
+
+    pub fn with_username<T: AsRef<str>>(mut self, username: T) -> Self {
+        self.username = username.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPageBlockChatLinkBuilder {
     inner: PageBlockChatLink,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPageBlockChatLinkBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PageBlockChatLink {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PageBlockChatLink> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PageBlockChatLink",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
         self.inner.title = title.as_ref().to_string();
+        self.missing.retain(|f| *f != "title");
         self
     }
 
@@ -715,6 +1108,7 @@ impl RTDPageBlockChatLinkBuilder {
 
     pub fn username<T: AsRef<str>>(&mut self, username: T) -> &mut Self {
         self.inner.username = username.as_ref().to_string();
+        self.missing.retain(|f| *f != "username");
         self
     }
 }
@@ -733,12 +1127,13 @@ impl AsRef<PageBlockChatLink> for RTDPageBlockChatLinkBuilder {
 
 /// A collage
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pageBlockCollage")]
 pub struct PageBlockCollage {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Collage item contents
     page_blocks: Vec<PageBlock>,
@@ -760,6 +1155,12 @@ impl RObject for PageBlockCollage {
     }
 }
 
+impl PartialEq for PageBlockCollage {
+    fn eq(&self, other: &Self) -> bool {
+        self.page_blocks == other.page_blocks && self.caption == other.caption
+    }
+}
+
 impl TDPageBlock for PageBlockCollage {}
 
 impl PageBlockCollage {
@@ -767,10 +1168,24 @@ impl PageBlockCollage {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPageBlockCollageBuilder {
-        let mut inner = PageBlockCollage::default();
-        inner.td_name = "pageBlockCollage".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPageBlockCollageBuilder { inner }
+        RTDPageBlockCollageBuilder {
+            inner: PageBlockCollage::default(),
+            missing: vec!["page_blocks", "caption"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPageBlockCollageBuilder {
+        RTDPageBlockCollageBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPageBlockCollageBuilder {
+        RTDPageBlockCollageBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn page_blocks(&self) -> &Vec<PageBlock> {
@@ -780,25 +1195,58 @@ impl PageBlockCollage {
     pub fn caption(&self) -> &PageBlockCaption {
         &self.caption
     }
+
+    pub fn into_page_blocks(self) -> Vec<PageBlock> {
+        self.page_blocks
+    }
+
+    pub fn into_caption(self) -> PageBlockCaption {
+        self.caption
+    }
+
+    pub fn with_page_blocks(mut self, page_blocks: Vec<PageBlock>) -> Self {
+        self.page_blocks = page_blocks;
+        self
+    }
+
+    pub fn with_caption<T: AsRef<PageBlockCaption>>(mut self, caption: T) -> Self {
+        self.caption = caption.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPageBlockCollageBuilder {
     inner: PageBlockCollage,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPageBlockCollageBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PageBlockCollage {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PageBlockCollage> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PageBlockCollage",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn page_blocks(&mut self, page_blocks: Vec<PageBlock>) -> &mut Self {
         self.inner.page_blocks = page_blocks;
+        self.missing.retain(|f| *f != "page_blocks");
         self
     }
 
     pub fn caption<T: AsRef<PageBlockCaption>>(&mut self, caption: T) -> &mut Self {
         self.inner.caption = caption.as_ref().clone();
+        self.missing.retain(|f| *f != "caption");
         self
     }
 }
@@ -817,12 +1265,13 @@ impl AsRef<PageBlockCollage> for RTDPageBlockCollageBuilder {
 
 /// A page cover
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pageBlockCover")]
 pub struct PageBlockCover {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Cover
     cover: Box<PageBlock>,
@@ -842,6 +1291,12 @@ impl RObject for PageBlockCover {
     }
 }
 
+impl PartialEq for PageBlockCover {
+    fn eq(&self, other: &Self) -> bool {
+        self.cover == other.cover
+    }
+}
+
 impl TDPageBlock for PageBlockCover {}
 
 impl PageBlockCover {
@@ -849,29 +1304,66 @@ impl PageBlockCover {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPageBlockCoverBuilder {
-        let mut inner = PageBlockCover::default();
-        inner.td_name = "pageBlockCover".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPageBlockCoverBuilder { inner }
+        RTDPageBlockCoverBuilder {
+            inner: PageBlockCover::default(),
+            missing: vec!["cover"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPageBlockCoverBuilder {
+        RTDPageBlockCoverBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPageBlockCoverBuilder {
+        RTDPageBlockCoverBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn cover(&self) -> &Box<PageBlock> {
         &self.cover
     }
+
+    pub fn into_cover(self) -> PageBlock {
+        *self.cover
+    }
+
+    pub fn with_cover<T: AsRef<PageBlock>>(mut self, cover: T) -> Self {
+        *self.cover = cover.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPageBlockCoverBuilder {
     inner: PageBlockCover,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPageBlockCoverBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PageBlockCover {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PageBlockCover> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PageBlockCover",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn cover<T: AsRef<Box<PageBlock>>>(&mut self, cover: T) -> &mut Self {
-        self.inner.cover = cover.as_ref().clone();
+    pub fn cover<T: AsRef<PageBlock>>(&mut self, cover: T) -> &mut Self {
+        *self.inner.cover = cover.as_ref().clone();
+        self.missing.retain(|f| *f != "cover");
         self
     }
 }
@@ -890,12 +1382,13 @@ impl AsRef<PageBlockCover> for RTDPageBlockCoverBuilder {
 
 /// A collapsible block
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pageBlockDetails")]
 pub struct PageBlockDetails {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Always visible heading for the block
     header: RichText,
@@ -919,6 +1412,14 @@ impl RObject for PageBlockDetails {
     }
 }
 
+impl PartialEq for PageBlockDetails {
+    fn eq(&self, other: &Self) -> bool {
+        self.header == other.header
+            && self.page_blocks == other.page_blocks
+            && self.is_open == other.is_open
+    }
+}
+
 impl TDPageBlock for PageBlockDetails {}
 
 impl PageBlockDetails {
@@ -926,10 +1427,24 @@ impl PageBlockDetails {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPageBlockDetailsBuilder {
-        let mut inner = PageBlockDetails::default();
-        inner.td_name = "pageBlockDetails".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPageBlockDetailsBuilder { inner }
+        RTDPageBlockDetailsBuilder {
+            inner: PageBlockDetails::default(),
+            missing: vec!["header", "page_blocks"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPageBlockDetailsBuilder {
+        RTDPageBlockDetailsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPageBlockDetailsBuilder {
+        RTDPageBlockDetailsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn header(&self) -> &RichText {
@@ -943,25 +1458,63 @@ impl PageBlockDetails {
     pub fn is_open(&self) -> bool {
         self.is_open
     }
+
+    pub fn into_header(self) -> RichText {
+        self.header
+    }
+
+    pub fn into_page_blocks(self) -> Vec<PageBlock> {
+        self.page_blocks
+    }
+
+    pub fn with_header<T: AsRef<RichText>>(mut self, header: T) -> Self {
+        self.header = header.as_ref().clone();
+        self
+    }
+
+    pub fn with_page_blocks(mut self, page_blocks: Vec<PageBlock>) -> Self {
+        self.page_blocks = page_blocks;
+        self
+    }
+
+    pub fn with_is_open(mut self, is_open: bool) -> Self {
+        self.is_open = is_open;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPageBlockDetailsBuilder {
     inner: PageBlockDetails,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPageBlockDetailsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PageBlockDetails {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PageBlockDetails> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PageBlockDetails",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn header<T: AsRef<RichText>>(&mut self, header: T) -> &mut Self {
         self.inner.header = header.as_ref().clone();
+        self.missing.retain(|f| *f != "header");
         self
     }
 
     pub fn page_blocks(&mut self, page_blocks: Vec<PageBlock>) -> &mut Self {
         self.inner.page_blocks = page_blocks;
+        self.missing.retain(|f| *f != "page_blocks");
         self
     }
 
@@ -985,12 +1538,13 @@ impl AsRef<PageBlockDetails> for RTDPageBlockDetailsBuilder {
 
 /// An empty block separating a page
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pageBlockDivider")]
 pub struct PageBlockDivider {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -1008,6 +1562,18 @@ impl RObject for PageBlockDivider {
     }
 }
 
+impl PartialEq for PageBlockDivider {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for PageBlockDivider {}
+
+impl std::hash::Hash for PageBlockDivider {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDPageBlock for PageBlockDivider {}
 
 impl PageBlockDivider {
@@ -1015,22 +1581,49 @@ impl PageBlockDivider {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPageBlockDividerBuilder {
-        let mut inner = PageBlockDivider::default();
-        inner.td_name = "pageBlockDivider".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPageBlockDividerBuilder { inner }
+        RTDPageBlockDividerBuilder {
+            inner: PageBlockDivider::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPageBlockDividerBuilder {
+        RTDPageBlockDividerBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPageBlockDividerBuilder {
+        RTDPageBlockDividerBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDPageBlockDividerBuilder {
     inner: PageBlockDivider,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPageBlockDividerBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PageBlockDivider {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PageBlockDivider> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PageBlockDivider",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<PageBlockDivider> for PageBlockDivider {
@@ -1047,12 +1640,13 @@ impl AsRef<PageBlockDivider> for RTDPageBlockDividerBuilder {
 
 /// An embedded web page
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pageBlockEmbedded")]
 pub struct PageBlockEmbedded {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Web page URL, if available
     url: String,
@@ -1061,9 +1655,9 @@ pub struct PageBlockEmbedded {
     /// Poster photo, if available; may be null
     poster_photo: Option<Photo>,
     /// Block width; 0 if unknown
-    width: i64,
+    width: i32,
     /// Block height; 0 if unknown
-    height: i64,
+    height: i32,
     /// Block caption
     caption: PageBlockCaption,
     /// True, if the block should be full width
@@ -1086,6 +1680,34 @@ impl RObject for PageBlockEmbedded {
     }
 }
 
+impl PartialEq for PageBlockEmbedded {
+    fn eq(&self, other: &Self) -> bool {
+        self.url == other.url
+            && self.html == other.html
+            && self.poster_photo == other.poster_photo
+            && self.width == other.width
+            && self.height == other.height
+            && self.caption == other.caption
+            && self.is_full_width == other.is_full_width
+            && self.allow_scrolling == other.allow_scrolling
+    }
+}
+
+impl Eq for PageBlockEmbedded {}
+
+impl std::hash::Hash for PageBlockEmbedded {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.url, state);
+        std::hash::Hash::hash(&self.html, state);
+        std::hash::Hash::hash(&self.poster_photo, state);
+        std::hash::Hash::hash(&self.width, state);
+        std::hash::Hash::hash(&self.height, state);
+        std::hash::Hash::hash(&self.caption, state);
+        std::hash::Hash::hash(&self.is_full_width, state);
+        std::hash::Hash::hash(&self.allow_scrolling, state);
+    }
+}
+
 impl TDPageBlock for PageBlockEmbedded {}
 
 impl PageBlockEmbedded {
@@ -1093,10 +1715,24 @@ impl PageBlockEmbedded {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPageBlockEmbeddedBuilder {
-        let mut inner = PageBlockEmbedded::default();
-        inner.td_name = "pageBlockEmbedded".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPageBlockEmbeddedBuilder { inner }
+        RTDPageBlockEmbeddedBuilder {
+            inner: PageBlockEmbedded::default(),
+            missing: vec!["url", "html", "caption"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPageBlockEmbeddedBuilder {
+        RTDPageBlockEmbeddedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPageBlockEmbeddedBuilder {
+        RTDPageBlockEmbeddedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn url(&self) -> &String {
@@ -1111,11 +1747,11 @@ impl PageBlockEmbedded {
         &self.poster_photo
     }
 
-    pub fn width(&self) -> i64 {
+    pub fn width(&self) -> i32 {
         self.width
     }
 
-    pub fn height(&self) -> i64 {
+    pub fn height(&self) -> i32 {
         self.height
     }
 
@@ -1130,25 +1766,96 @@ impl PageBlockEmbedded {
     pub fn allow_scrolling(&self) -> bool {
         self.allow_scrolling
     }
-}
 
-#[doc(hidden)]
-pub struct RTDPageBlockEmbeddedBuilder {
-    inner: PageBlockEmbedded,
-}
+    pub fn into_url(self) -> String {
+        self.url
+    }
 
-impl RTDPageBlockEmbeddedBuilder {
-    pub fn build(&self) -> PageBlockEmbedded {
-        self.inner.clone()
+    pub fn into_html(self) -> String {
+        self.html
     }
 
-    pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
+    pub fn into_poster_photo(self) -> Option<Photo> {
+        self.poster_photo
+    }
+
+    pub fn into_caption(self) -> PageBlockCaption {
+        self.caption
+    }
+
+    pub fn with_url<T: AsRef<str>>(mut self, url: T) -> Self {
+        self.url = url.as_ref().to_string();
+        self
+    }
+
+    pub fn with_html<T: AsRef<str>>(mut self, html: T) -> Self {
+        self.html = html.as_ref().to_string();
+        self
+    }
+
+    pub fn with_poster_photo<T: AsRef<Photo>>(mut self, poster_photo: T) -> Self {
+        self.poster_photo = Some(poster_photo.as_ref().clone());
+        self
+    }
+
+    pub fn with_width(mut self, width: i32) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn with_height(mut self, height: i32) -> Self {
+        self.height = height;
+        self
+    }
+
+    pub fn with_caption<T: AsRef<PageBlockCaption>>(mut self, caption: T) -> Self {
+        self.caption = caption.as_ref().clone();
+        self
+    }
+
+    pub fn with_is_full_width(mut self, is_full_width: bool) -> Self {
+        self.is_full_width = is_full_width;
+        self
+    }
+
+    pub fn with_allow_scrolling(mut self, allow_scrolling: bool) -> Self {
+        self.allow_scrolling = allow_scrolling;
+        self
+    }
+}
+
+#[doc(hidden)]
+pub struct RTDPageBlockEmbeddedBuilder {
+    inner: PageBlockEmbedded,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
+}
+
+impl RTDPageBlockEmbeddedBuilder {
+    /// Builds the object, fields which are not set have default values
+    pub fn build(&self) -> PageBlockEmbedded {
+        self.inner.clone()
+    }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PageBlockEmbedded> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PageBlockEmbedded",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
+
+    pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
         self.inner.url = url.as_ref().to_string();
+        self.missing.retain(|f| *f != "url");
         self
     }
 
     pub fn html<T: AsRef<str>>(&mut self, html: T) -> &mut Self {
         self.inner.html = html.as_ref().to_string();
+        self.missing.retain(|f| *f != "html");
         self
     }
 
@@ -1157,18 +1864,19 @@ impl RTDPageBlockEmbeddedBuilder {
         self
     }
 
-    pub fn width(&mut self, width: i64) -> &mut Self {
+    pub fn width(&mut self, width: i32) -> &mut Self {
         self.inner.width = width;
         self
     }
 
-    pub fn height(&mut self, height: i64) -> &mut Self {
+    pub fn height(&mut self, height: i32) -> &mut Self {
         self.inner.height = height;
         self
     }
 
     pub fn caption<T: AsRef<PageBlockCaption>>(&mut self, caption: T) -> &mut Self {
         self.inner.caption = caption.as_ref().clone();
+        self.missing.retain(|f| *f != "caption");
         self
     }
 
@@ -1197,12 +1905,13 @@ impl AsRef<PageBlockEmbedded> for RTDPageBlockEmbeddedBuilder {
 
 /// An embedded post
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pageBlockEmbeddedPost")]
 pub struct PageBlockEmbeddedPost {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Web page URL
     url: String,
@@ -1211,7 +1920,7 @@ pub struct PageBlockEmbeddedPost {
     /// Post author photo; may be null
     author_photo: Option<Photo>,
     /// Point in time (Unix timestamp) when the post was created; 0 if unknown
-    date: i64,
+    date: i32,
     /// Post content
     page_blocks: Vec<PageBlock>,
     /// Post caption
@@ -1232,6 +1941,17 @@ impl RObject for PageBlockEmbeddedPost {
     }
 }
 
+impl PartialEq for PageBlockEmbeddedPost {
+    fn eq(&self, other: &Self) -> bool {
+        self.url == other.url
+            && self.author == other.author
+            && self.author_photo == other.author_photo
+            && self.date == other.date
+            && self.page_blocks == other.page_blocks
+            && self.caption == other.caption
+    }
+}
+
 impl TDPageBlock for PageBlockEmbeddedPost {}
 
 impl PageBlockEmbeddedPost {
@@ -1239,10 +1959,24 @@ impl PageBlockEmbeddedPost {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPageBlockEmbeddedPostBuilder {
-        let mut inner = PageBlockEmbeddedPost::default();
-        inner.td_name = "pageBlockEmbeddedPost".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPageBlockEmbeddedPostBuilder { inner }
+        RTDPageBlockEmbeddedPostBuilder {
+            inner: PageBlockEmbeddedPost::default(),
+            missing: vec!["url", "author", "page_blocks", "caption"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPageBlockEmbeddedPostBuilder {
+        RTDPageBlockEmbeddedPostBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPageBlockEmbeddedPostBuilder {
+        RTDPageBlockEmbeddedPostBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn url(&self) -> &String {
@@ -1257,7 +1991,7 @@ impl PageBlockEmbeddedPost {
         &self.author_photo
     }
 
-    pub fn date(&self) -> i64 {
+    pub fn date(&self) -> i32 {
         self.date
     }
 
@@ -1268,25 +2002,90 @@ impl PageBlockEmbeddedPost {
     pub fn caption(&self) -> &PageBlockCaption {
         &self.caption
     }
+
+    pub fn into_url(self) -> String {
+        self.url
+    }
+
+    pub fn into_author(self) -> String {
+        self.author
+    }
+
+    pub fn into_author_photo(self) -> Option<Photo> {
+        self.author_photo
+    }
+
+    pub fn into_page_blocks(self) -> Vec<PageBlock> {
+        self.page_blocks
+    }
+
+    pub fn into_caption(self) -> PageBlockCaption {
+        self.caption
+    }
+
+    pub fn with_url<T: AsRef<str>>(mut self, url: T) -> Self {
+        self.url = url.as_ref().to_string();
+        self
+    }
+
+    pub fn with_author<T: AsRef<str>>(mut self, author: T) -> Self {
+        self.author = author.as_ref().to_string();
+        self
+    }
+
+    pub fn with_author_photo<T: AsRef<Photo>>(mut self, author_photo: T) -> Self {
+        self.author_photo = Some(author_photo.as_ref().clone());
+        self
+    }
+
+    pub fn with_date(mut self, date: i32) -> Self {
+        self.date = date;
+        self
+    }
+
+    pub fn with_page_blocks(mut self, page_blocks: Vec<PageBlock>) -> Self {
+        self.page_blocks = page_blocks;
+        self
+    }
+
+    pub fn with_caption<T: AsRef<PageBlockCaption>>(mut self, caption: T) -> Self {
+        self.caption = caption.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPageBlockEmbeddedPostBuilder {
     inner: PageBlockEmbeddedPost,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPageBlockEmbeddedPostBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PageBlockEmbeddedPost {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PageBlockEmbeddedPost> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PageBlockEmbeddedPost",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
         self.inner.url = url.as_ref().to_string();
+        self.missing.retain(|f| *f != "url");
         self
     }
 
     pub fn author<T: AsRef<str>>(&mut self, author: T) -> &mut Self {
         self.inner.author = author.as_ref().to_string();
+        self.missing.retain(|f| *f != "author");
         self
     }
 
@@ -1295,18 +2094,20 @@ impl RTDPageBlockEmbeddedPostBuilder {
         self
     }
 
-    pub fn date(&mut self, date: i64) -> &mut Self {
+    pub fn date(&mut self, date: i32) -> &mut Self {
         self.inner.date = date;
         self
     }
 
     pub fn page_blocks(&mut self, page_blocks: Vec<PageBlock>) -> &mut Self {
         self.inner.page_blocks = page_blocks;
+        self.missing.retain(|f| *f != "page_blocks");
         self
     }
 
     pub fn caption<T: AsRef<PageBlockCaption>>(&mut self, caption: T) -> &mut Self {
         self.inner.caption = caption.as_ref().clone();
+        self.missing.retain(|f| *f != "caption");
         self
     }
 }
@@ -1325,12 +2126,13 @@ impl AsRef<PageBlockEmbeddedPost> for RTDPageBlockEmbeddedPostBuilder {
 
 /// The footer of a page
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pageBlockFooter")]
 pub struct PageBlockFooter {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Footer
     footer: RichText,
@@ -1350,6 +2152,20 @@ impl RObject for PageBlockFooter {
     }
 }
 
+impl PartialEq for PageBlockFooter {
+    fn eq(&self, other: &Self) -> bool {
+        self.footer == other.footer
+    }
+}
+
+impl Eq for PageBlockFooter {}
+
+impl std::hash::Hash for PageBlockFooter {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.footer, state);
+    }
+}
+
 impl TDPageBlock for PageBlockFooter {}
 
 impl PageBlockFooter {
@@ -1357,29 +2173,66 @@ impl PageBlockFooter {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPageBlockFooterBuilder {
-        let mut inner = PageBlockFooter::default();
-        inner.td_name = "pageBlockFooter".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPageBlockFooterBuilder { inner }
+        RTDPageBlockFooterBuilder {
+            inner: PageBlockFooter::default(),
+            missing: vec!["footer"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPageBlockFooterBuilder {
+        RTDPageBlockFooterBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPageBlockFooterBuilder {
+        RTDPageBlockFooterBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn footer(&self) -> &RichText {
         &self.footer
     }
+
+    pub fn into_footer(self) -> RichText {
+        self.footer
+    }
+
+    pub fn with_footer<T: AsRef<RichText>>(mut self, footer: T) -> Self {
+        self.footer = footer.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPageBlockFooterBuilder {
     inner: PageBlockFooter,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPageBlockFooterBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PageBlockFooter {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PageBlockFooter> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PageBlockFooter",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn footer<T: AsRef<RichText>>(&mut self, footer: T) -> &mut Self {
         self.inner.footer = footer.as_ref().clone();
+        self.missing.retain(|f| *f != "footer");
         self
     }
 }
@@ -1398,12 +2251,13 @@ impl AsRef<PageBlockFooter> for RTDPageBlockFooterBuilder {
 
 /// A header
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pageBlockHeader")]
 pub struct PageBlockHeader {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Header
     header: RichText,
@@ -1423,6 +2277,20 @@ impl RObject for PageBlockHeader {
     }
 }
 
+impl PartialEq for PageBlockHeader {
+    fn eq(&self, other: &Self) -> bool {
+        self.header == other.header
+    }
+}
+
+impl Eq for PageBlockHeader {}
+
+impl std::hash::Hash for PageBlockHeader {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.header, state);
+    }
+}
+
 impl TDPageBlock for PageBlockHeader {}
 
 impl PageBlockHeader {
@@ -1430,29 +2298,66 @@ impl PageBlockHeader {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPageBlockHeaderBuilder {
-        let mut inner = PageBlockHeader::default();
-        inner.td_name = "pageBlockHeader".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPageBlockHeaderBuilder { inner }
+        RTDPageBlockHeaderBuilder {
+            inner: PageBlockHeader::default(),
+            missing: vec!["header"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPageBlockHeaderBuilder {
+        RTDPageBlockHeaderBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPageBlockHeaderBuilder {
+        RTDPageBlockHeaderBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn header(&self) -> &RichText {
         &self.header
     }
+
+    pub fn into_header(self) -> RichText {
+        self.header
+    }
+
+    pub fn with_header<T: AsRef<RichText>>(mut self, header: T) -> Self {
+        self.header = header.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPageBlockHeaderBuilder {
     inner: PageBlockHeader,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPageBlockHeaderBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PageBlockHeader {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PageBlockHeader> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PageBlockHeader",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn header<T: AsRef<RichText>>(&mut self, header: T) -> &mut Self {
         self.inner.header = header.as_ref().clone();
+        self.missing.retain(|f| *f != "header");
         self
     }
 }
@@ -1471,12 +2376,13 @@ impl AsRef<PageBlockHeader> for RTDPageBlockHeaderBuilder {
 
 /// A kicker
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pageBlockKicker")]
 pub struct PageBlockKicker {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Kicker
     kicker: RichText,
@@ -1496,6 +2402,20 @@ impl RObject for PageBlockKicker {
     }
 }
 
+impl PartialEq for PageBlockKicker {
+    fn eq(&self, other: &Self) -> bool {
+        self.kicker == other.kicker
+    }
+}
+
+impl Eq for PageBlockKicker {}
+
+impl std::hash::Hash for PageBlockKicker {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.kicker, state);
+    }
+}
+
 impl TDPageBlock for PageBlockKicker {}
 
 impl PageBlockKicker {
@@ -1503,29 +2423,66 @@ impl PageBlockKicker {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPageBlockKickerBuilder {
-        let mut inner = PageBlockKicker::default();
-        inner.td_name = "pageBlockKicker".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPageBlockKickerBuilder { inner }
+        RTDPageBlockKickerBuilder {
+            inner: PageBlockKicker::default(),
+            missing: vec!["kicker"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPageBlockKickerBuilder {
+        RTDPageBlockKickerBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPageBlockKickerBuilder {
+        RTDPageBlockKickerBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn kicker(&self) -> &RichText {
         &self.kicker
     }
+
+    pub fn into_kicker(self) -> RichText {
+        self.kicker
+    }
+
+    pub fn with_kicker<T: AsRef<RichText>>(mut self, kicker: T) -> Self {
+        self.kicker = kicker.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPageBlockKickerBuilder {
     inner: PageBlockKicker,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPageBlockKickerBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PageBlockKicker {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PageBlockKicker> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PageBlockKicker",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn kicker<T: AsRef<RichText>>(&mut self, kicker: T) -> &mut Self {
         self.inner.kicker = kicker.as_ref().clone();
+        self.missing.retain(|f| *f != "kicker");
         self
     }
 }
@@ -1544,12 +2501,13 @@ impl AsRef<PageBlockKicker> for RTDPageBlockKickerBuilder {
 
 /// A list of data blocks
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pageBlockList")]
 pub struct PageBlockList {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The items of the list
     items: Vec<PageBlockListItem>,
@@ -1569,6 +2527,12 @@ impl RObject for PageBlockList {
     }
 }
 
+impl PartialEq for PageBlockList {
+    fn eq(&self, other: &Self) -> bool {
+        self.items == other.items
+    }
+}
+
 impl TDPageBlock for PageBlockList {}
 
 impl PageBlockList {
@@ -1576,29 +2540,66 @@ impl PageBlockList {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPageBlockListBuilder {
-        let mut inner = PageBlockList::default();
-        inner.td_name = "pageBlockList".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPageBlockListBuilder { inner }
+        RTDPageBlockListBuilder {
+            inner: PageBlockList::default(),
+            missing: vec!["items"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPageBlockListBuilder {
+        RTDPageBlockListBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPageBlockListBuilder {
+        RTDPageBlockListBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn items(&self) -> &Vec<PageBlockListItem> {
         &self.items
     }
+
+    pub fn into_items(self) -> Vec<PageBlockListItem> {
+        self.items
+    }
+
+    pub fn with_items(mut self, items: Vec<PageBlockListItem>) -> Self {
+        self.items = items;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPageBlockListBuilder {
     inner: PageBlockList,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPageBlockListBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PageBlockList {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PageBlockList> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PageBlockList",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn items(&mut self, items: Vec<PageBlockListItem>) -> &mut Self {
         self.inner.items = items;
+        self.missing.retain(|f| *f != "items");
         self
     }
 }
@@ -1617,21 +2618,22 @@ impl AsRef<PageBlockList> for RTDPageBlockListBuilder {
 
 /// A map
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pageBlockMap")]
 pub struct PageBlockMap {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Location of the map center
     location: Location,
     /// Map zoom level
-    zoom: i64,
+    zoom: i32,
     /// Map width
-    width: i64,
+    width: i32,
     /// Map height
-    height: i64,
+    height: i32,
     /// Block caption
     caption: PageBlockCaption,
 }
@@ -1650,6 +2652,16 @@ impl RObject for PageBlockMap {
     }
 }
 
+impl PartialEq for PageBlockMap {
+    fn eq(&self, other: &Self) -> bool {
+        self.location == other.location
+            && self.zoom == other.zoom
+            && self.width == other.width
+            && self.height == other.height
+            && self.caption == other.caption
+    }
+}
+
 impl TDPageBlock for PageBlockMap {}
 
 impl PageBlockMap {
@@ -1657,65 +2669,130 @@ impl PageBlockMap {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPageBlockMapBuilder {
-        let mut inner = PageBlockMap::default();
-        inner.td_name = "pageBlockMap".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPageBlockMapBuilder { inner }
+        RTDPageBlockMapBuilder {
+            inner: PageBlockMap::default(),
+            missing: vec!["location", "zoom", "width", "height", "caption"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPageBlockMapBuilder {
+        RTDPageBlockMapBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPageBlockMapBuilder {
+        RTDPageBlockMapBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn location(&self) -> &Location {
         &self.location
     }
 
-    pub fn zoom(&self) -> i64 {
+    pub fn zoom(&self) -> i32 {
         self.zoom
     }
 
-    pub fn width(&self) -> i64 {
+    pub fn width(&self) -> i32 {
         self.width
     }
 
-    pub fn height(&self) -> i64 {
+    pub fn height(&self) -> i32 {
         self.height
     }
 
     pub fn caption(&self) -> &PageBlockCaption {
         &self.caption
     }
+
+    pub fn into_location(self) -> Location {
+        self.location
+    }
+
+    pub fn into_caption(self) -> PageBlockCaption {
+        self.caption
+    }
+
+    pub fn with_location<T: AsRef<Location>>(mut self, location: T) -> Self {
+        self.location = location.as_ref().clone();
+        self
+    }
+
+    pub fn with_zoom(mut self, zoom: i32) -> Self {
+        self.zoom = zoom;
+        self
+    }
+
+    pub fn with_width(mut self, width: i32) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn with_height(mut self, height: i32) -> Self {
+        self.height = height;
+        self
+    }
+
+    pub fn with_caption<T: AsRef<PageBlockCaption>>(mut self, caption: T) -> Self {
+        self.caption = caption.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPageBlockMapBuilder {
     inner: PageBlockMap,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPageBlockMapBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PageBlockMap {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PageBlockMap> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PageBlockMap",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn location<T: AsRef<Location>>(&mut self, location: T) -> &mut Self {
         self.inner.location = location.as_ref().clone();
+        self.missing.retain(|f| *f != "location");
         self
     }
 
-    pub fn zoom(&mut self, zoom: i64) -> &mut Self {
+    pub fn zoom(&mut self, zoom: i32) -> &mut Self {
         self.inner.zoom = zoom;
+        self.missing.retain(|f| *f != "zoom");
         self
     }
 
-    pub fn width(&mut self, width: i64) -> &mut Self {
+    pub fn width(&mut self, width: i32) -> &mut Self {
         self.inner.width = width;
+        self.missing.retain(|f| *f != "width");
         self
     }
 
-    pub fn height(&mut self, height: i64) -> &mut Self {
+    pub fn height(&mut self, height: i32) -> &mut Self {
         self.inner.height = height;
+        self.missing.retain(|f| *f != "height");
         self
     }
 
     pub fn caption<T: AsRef<PageBlockCaption>>(&mut self, caption: T) -> &mut Self {
         self.inner.caption = caption.as_ref().clone();
+        self.missing.retain(|f| *f != "caption");
         self
     }
 }
@@ -1734,12 +2811,13 @@ impl AsRef<PageBlockMap> for RTDPageBlockMapBuilder {
 
 /// A text paragraph
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pageBlockParagraph")]
 pub struct PageBlockParagraph {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Paragraph text
     text: RichText,
@@ -1759,6 +2837,20 @@ impl RObject for PageBlockParagraph {
     }
 }
 
+impl PartialEq for PageBlockParagraph {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text
+    }
+}
+
+impl Eq for PageBlockParagraph {}
+
+impl std::hash::Hash for PageBlockParagraph {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.text, state);
+    }
+}
+
 impl TDPageBlock for PageBlockParagraph {}
 
 impl PageBlockParagraph {
@@ -1766,29 +2858,66 @@ impl PageBlockParagraph {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPageBlockParagraphBuilder {
-        let mut inner = PageBlockParagraph::default();
-        inner.td_name = "pageBlockParagraph".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPageBlockParagraphBuilder { inner }
+        RTDPageBlockParagraphBuilder {
+            inner: PageBlockParagraph::default(),
+            missing: vec!["text"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPageBlockParagraphBuilder {
+        RTDPageBlockParagraphBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPageBlockParagraphBuilder {
+        RTDPageBlockParagraphBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn text(&self) -> &RichText {
         &self.text
     }
+
+    pub fn into_text(self) -> RichText {
+        self.text
+    }
+
+    pub fn with_text<T: AsRef<RichText>>(mut self, text: T) -> Self {
+        self.text = text.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPageBlockParagraphBuilder {
     inner: PageBlockParagraph,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPageBlockParagraphBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PageBlockParagraph {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PageBlockParagraph> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PageBlockParagraph",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn text<T: AsRef<RichText>>(&mut self, text: T) -> &mut Self {
         self.inner.text = text.as_ref().clone();
+        self.missing.retain(|f| *f != "text");
         self
     }
 }
@@ -1807,12 +2936,13 @@ impl AsRef<PageBlockParagraph> for RTDPageBlockParagraphBuilder {
 
 /// A photo
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pageBlockPhoto")]
 pub struct PageBlockPhoto {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Photo file; may be null
     photo: Option<Photo>,
@@ -1836,6 +2966,22 @@ impl RObject for PageBlockPhoto {
     }
 }
 
+impl PartialEq for PageBlockPhoto {
+    fn eq(&self, other: &Self) -> bool {
+        self.photo == other.photo && self.caption == other.caption && self.url == other.url
+    }
+}
+
+impl Eq for PageBlockPhoto {}
+
+impl std::hash::Hash for PageBlockPhoto {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.photo, state);
+        std::hash::Hash::hash(&self.caption, state);
+        std::hash::Hash::hash(&self.url, state);
+    }
+}
+
 impl TDPageBlock for PageBlockPhoto {}
 
 impl PageBlockPhoto {
@@ -1843,10 +2989,24 @@ impl PageBlockPhoto {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPageBlockPhotoBuilder {
-        let mut inner = PageBlockPhoto::default();
-        inner.td_name = "pageBlockPhoto".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPageBlockPhotoBuilder { inner }
+        RTDPageBlockPhotoBuilder {
+            inner: PageBlockPhoto::default(),
+            missing: vec!["caption", "url"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPageBlockPhotoBuilder {
+        RTDPageBlockPhotoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPageBlockPhotoBuilder {
+        RTDPageBlockPhotoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn photo(&self) -> &Option<Photo> {
@@ -1860,17 +3020,57 @@ impl PageBlockPhoto {
     pub fn url(&self) -> &String {
         &self.url
     }
+
+    pub fn into_photo(self) -> Option<Photo> {
+        self.photo
+    }
+
+    pub fn into_caption(self) -> PageBlockCaption {
+        self.caption
+    }
+
+    pub fn into_url(self) -> String {
+        self.url
+    }
+
+    pub fn with_photo<T: AsRef<Photo>>(mut self, photo: T) -> Self {
+        self.photo = Some(photo.as_ref().clone());
+        self
+    }
+
+    pub fn with_caption<T: AsRef<PageBlockCaption>>(mut self, caption: T) -> Self {
+        self.caption = caption.as_ref().clone();
+        self
+    }
+
+    pub fn with_url<T: AsRef<str>>(mut self, url: T) -> Self {
+        self.url = url.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPageBlockPhotoBuilder {
     inner: PageBlockPhoto,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPageBlockPhotoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PageBlockPhoto {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PageBlockPhoto> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PageBlockPhoto",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn photo<T: AsRef<Photo>>(&mut self, photo: T) -> &mut Self {
         self.inner.photo = Some(photo.as_ref().clone());
@@ -1879,11 +3079,13 @@ impl RTDPageBlockPhotoBuilder {
 
     pub fn caption<T: AsRef<PageBlockCaption>>(&mut self, caption: T) -> &mut Self {
         self.inner.caption = caption.as_ref().clone();
+        self.missing.retain(|f| *f != "caption");
         self
     }
 
     pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
         self.inner.url = url.as_ref().to_string();
+        self.missing.retain(|f| *f != "url");
         self
     }
 }
@@ -1902,12 +3104,13 @@ impl AsRef<PageBlockPhoto> for RTDPageBlockPhotoBuilder {
 
 /// A preformatted text paragraph
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pageBlockPreformatted")]
 pub struct PageBlockPreformatted {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Paragraph text
     text: RichText,
@@ -1929,6 +3132,21 @@ impl RObject for PageBlockPreformatted {
     }
 }
 
+impl PartialEq for PageBlockPreformatted {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text && self.language == other.language
+    }
+}
+
+impl Eq for PageBlockPreformatted {}
+
+impl std::hash::Hash for PageBlockPreformatted {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.text, state);
+        std::hash::Hash::hash(&self.language, state);
+    }
+}
+
 impl TDPageBlock for PageBlockPreformatted {}
 
 impl PageBlockPreformatted {
@@ -1936,10 +3154,24 @@ impl PageBlockPreformatted {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPageBlockPreformattedBuilder {
-        let mut inner = PageBlockPreformatted::default();
-        inner.td_name = "pageBlockPreformatted".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPageBlockPreformattedBuilder { inner }
+        RTDPageBlockPreformattedBuilder {
+            inner: PageBlockPreformatted::default(),
+            missing: vec!["text", "language"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPageBlockPreformattedBuilder {
+        RTDPageBlockPreformattedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPageBlockPreformattedBuilder {
+        RTDPageBlockPreformattedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn text(&self) -> &RichText {
@@ -1949,25 +3181,58 @@ impl PageBlockPreformatted {
     pub fn language(&self) -> &String {
         &self.language
     }
+
+    pub fn into_text(self) -> RichText {
+        self.text
+    }
+
+    pub fn into_language(self) -> String {
+        self.language
+    }
+
+    pub fn with_text<T: AsRef<RichText>>(mut self, text: T) -> Self {
+        self.text = text.as_ref().clone();
+        self
+    }
+
+    pub fn with_language<T: AsRef<str>>(mut self, language: T) -> Self {
+        self.language = language.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPageBlockPreformattedBuilder {
     inner: PageBlockPreformatted,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPageBlockPreformattedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PageBlockPreformatted {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PageBlockPreformatted> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PageBlockPreformatted",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn text<T: AsRef<RichText>>(&mut self, text: T) -> &mut Self {
         self.inner.text = text.as_ref().clone();
+        self.missing.retain(|f| *f != "text");
         self
     }
 
     pub fn language<T: AsRef<str>>(&mut self, language: T) -> &mut Self {
         self.inner.language = language.as_ref().to_string();
+        self.missing.retain(|f| *f != "language");
         self
     }
 }
@@ -1986,12 +3251,13 @@ impl AsRef<PageBlockPreformatted> for RTDPageBlockPreformattedBuilder {
 
 /// A pull quote
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pageBlockPullQuote")]
 pub struct PageBlockPullQuote {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Quote text
     text: RichText,
@@ -2013,6 +3279,21 @@ impl RObject for PageBlockPullQuote {
     }
 }
 
+impl PartialEq for PageBlockPullQuote {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text && self.credit == other.credit
+    }
+}
+
+impl Eq for PageBlockPullQuote {}
+
+impl std::hash::Hash for PageBlockPullQuote {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.text, state);
+        std::hash::Hash::hash(&self.credit, state);
+    }
+}
+
 impl TDPageBlock for PageBlockPullQuote {}
 
 impl PageBlockPullQuote {
@@ -2020,10 +3301,24 @@ impl PageBlockPullQuote {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPageBlockPullQuoteBuilder {
-        let mut inner = PageBlockPullQuote::default();
-        inner.td_name = "pageBlockPullQuote".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPageBlockPullQuoteBuilder { inner }
+        RTDPageBlockPullQuoteBuilder {
+            inner: PageBlockPullQuote::default(),
+            missing: vec!["text", "credit"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPageBlockPullQuoteBuilder {
+        RTDPageBlockPullQuoteBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPageBlockPullQuoteBuilder {
+        RTDPageBlockPullQuoteBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn text(&self) -> &RichText {
@@ -2033,25 +3328,58 @@ impl PageBlockPullQuote {
     pub fn credit(&self) -> &RichText {
         &self.credit
     }
+
+    pub fn into_text(self) -> RichText {
+        self.text
+    }
+
+    pub fn into_credit(self) -> RichText {
+        self.credit
+    }
+
+    pub fn with_text<T: AsRef<RichText>>(mut self, text: T) -> Self {
+        self.text = text.as_ref().clone();
+        self
+    }
+
+    pub fn with_credit<T: AsRef<RichText>>(mut self, credit: T) -> Self {
+        self.credit = credit.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPageBlockPullQuoteBuilder {
     inner: PageBlockPullQuote,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPageBlockPullQuoteBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PageBlockPullQuote {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PageBlockPullQuote> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PageBlockPullQuote",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn text<T: AsRef<RichText>>(&mut self, text: T) -> &mut Self {
         self.inner.text = text.as_ref().clone();
+        self.missing.retain(|f| *f != "text");
         self
     }
 
     pub fn credit<T: AsRef<RichText>>(&mut self, credit: T) -> &mut Self {
         self.inner.credit = credit.as_ref().clone();
+        self.missing.retain(|f| *f != "credit");
         self
     }
 }
@@ -2070,12 +3398,13 @@ impl AsRef<PageBlockPullQuote> for RTDPageBlockPullQuoteBuilder {
 
 /// Related articles
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pageBlockRelatedArticles")]
 pub struct PageBlockRelatedArticles {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Block header
     header: RichText,
@@ -2097,6 +3426,21 @@ impl RObject for PageBlockRelatedArticles {
     }
 }
 
+impl PartialEq for PageBlockRelatedArticles {
+    fn eq(&self, other: &Self) -> bool {
+        self.header == other.header && self.articles == other.articles
+    }
+}
+
+impl Eq for PageBlockRelatedArticles {}
+
+impl std::hash::Hash for PageBlockRelatedArticles {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.header, state);
+        std::hash::Hash::hash(&self.articles, state);
+    }
+}
+
 impl TDPageBlock for PageBlockRelatedArticles {}
 
 impl PageBlockRelatedArticles {
@@ -2104,10 +3448,24 @@ impl PageBlockRelatedArticles {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPageBlockRelatedArticlesBuilder {
-        let mut inner = PageBlockRelatedArticles::default();
-        inner.td_name = "pageBlockRelatedArticles".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPageBlockRelatedArticlesBuilder { inner }
+        RTDPageBlockRelatedArticlesBuilder {
+            inner: PageBlockRelatedArticles::default(),
+            missing: vec!["header", "articles"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPageBlockRelatedArticlesBuilder {
+        RTDPageBlockRelatedArticlesBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPageBlockRelatedArticlesBuilder {
+        RTDPageBlockRelatedArticlesBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn header(&self) -> &RichText {
@@ -2117,25 +3475,58 @@ impl PageBlockRelatedArticles {
     pub fn articles(&self) -> &Vec<PageBlockRelatedArticle> {
         &self.articles
     }
+
+    pub fn into_header(self) -> RichText {
+        self.header
+    }
+
+    pub fn into_articles(self) -> Vec<PageBlockRelatedArticle> {
+        self.articles
+    }
+
+    pub fn with_header<T: AsRef<RichText>>(mut self, header: T) -> Self {
+        self.header = header.as_ref().clone();
+        self
+    }
+
+    pub fn with_articles(mut self, articles: Vec<PageBlockRelatedArticle>) -> Self {
+        self.articles = articles;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPageBlockRelatedArticlesBuilder {
     inner: PageBlockRelatedArticles,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPageBlockRelatedArticlesBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PageBlockRelatedArticles {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PageBlockRelatedArticles> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PageBlockRelatedArticles",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn header<T: AsRef<RichText>>(&mut self, header: T) -> &mut Self {
         self.inner.header = header.as_ref().clone();
+        self.missing.retain(|f| *f != "header");
         self
     }
 
     pub fn articles(&mut self, articles: Vec<PageBlockRelatedArticle>) -> &mut Self {
         self.inner.articles = articles;
+        self.missing.retain(|f| *f != "articles");
         self
     }
 }
@@ -2154,12 +3545,13 @@ impl AsRef<PageBlockRelatedArticles> for RTDPageBlockRelatedArticlesBuilder {
 
 /// A slideshow
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pageBlockSlideshow")]
 pub struct PageBlockSlideshow {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Slideshow item contents
     page_blocks: Vec<PageBlock>,
@@ -2181,6 +3573,12 @@ impl RObject for PageBlockSlideshow {
     }
 }
 
+impl PartialEq for PageBlockSlideshow {
+    fn eq(&self, other: &Self) -> bool {
+        self.page_blocks == other.page_blocks && self.caption == other.caption
+    }
+}
+
 impl TDPageBlock for PageBlockSlideshow {}
 
 impl PageBlockSlideshow {
@@ -2188,10 +3586,24 @@ impl PageBlockSlideshow {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPageBlockSlideshowBuilder {
-        let mut inner = PageBlockSlideshow::default();
-        inner.td_name = "pageBlockSlideshow".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPageBlockSlideshowBuilder { inner }
+        RTDPageBlockSlideshowBuilder {
+            inner: PageBlockSlideshow::default(),
+            missing: vec!["page_blocks", "caption"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPageBlockSlideshowBuilder {
+        RTDPageBlockSlideshowBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPageBlockSlideshowBuilder {
+        RTDPageBlockSlideshowBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn page_blocks(&self) -> &Vec<PageBlock> {
@@ -2201,25 +3613,58 @@ impl PageBlockSlideshow {
     pub fn caption(&self) -> &PageBlockCaption {
         &self.caption
     }
+
+    pub fn into_page_blocks(self) -> Vec<PageBlock> {
+        self.page_blocks
+    }
+
+    pub fn into_caption(self) -> PageBlockCaption {
+        self.caption
+    }
+
+    pub fn with_page_blocks(mut self, page_blocks: Vec<PageBlock>) -> Self {
+        self.page_blocks = page_blocks;
+        self
+    }
+
+    pub fn with_caption<T: AsRef<PageBlockCaption>>(mut self, caption: T) -> Self {
+        self.caption = caption.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPageBlockSlideshowBuilder {
     inner: PageBlockSlideshow,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPageBlockSlideshowBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PageBlockSlideshow {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PageBlockSlideshow> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PageBlockSlideshow",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn page_blocks(&mut self, page_blocks: Vec<PageBlock>) -> &mut Self {
         self.inner.page_blocks = page_blocks;
+        self.missing.retain(|f| *f != "page_blocks");
         self
     }
 
     pub fn caption<T: AsRef<PageBlockCaption>>(&mut self, caption: T) -> &mut Self {
         self.inner.caption = caption.as_ref().clone();
+        self.missing.retain(|f| *f != "caption");
         self
     }
 }
@@ -2238,12 +3683,13 @@ impl AsRef<PageBlockSlideshow> for RTDPageBlockSlideshowBuilder {
 
 /// A subheader
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pageBlockSubheader")]
 pub struct PageBlockSubheader {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Subheader
     subheader: RichText,
@@ -2263,6 +3709,20 @@ impl RObject for PageBlockSubheader {
     }
 }
 
+impl PartialEq for PageBlockSubheader {
+    fn eq(&self, other: &Self) -> bool {
+        self.subheader == other.subheader
+    }
+}
+
+impl Eq for PageBlockSubheader {}
+
+impl std::hash::Hash for PageBlockSubheader {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.subheader, state);
+    }
+}
+
 impl TDPageBlock for PageBlockSubheader {}
 
 impl PageBlockSubheader {
@@ -2270,29 +3730,66 @@ impl PageBlockSubheader {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPageBlockSubheaderBuilder {
-        let mut inner = PageBlockSubheader::default();
-        inner.td_name = "pageBlockSubheader".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPageBlockSubheaderBuilder { inner }
+        RTDPageBlockSubheaderBuilder {
+            inner: PageBlockSubheader::default(),
+            missing: vec!["subheader"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPageBlockSubheaderBuilder {
+        RTDPageBlockSubheaderBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPageBlockSubheaderBuilder {
+        RTDPageBlockSubheaderBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn subheader(&self) -> &RichText {
         &self.subheader
     }
+
+    pub fn into_subheader(self) -> RichText {
+        self.subheader
+    }
+
+    pub fn with_subheader<T: AsRef<RichText>>(mut self, subheader: T) -> Self {
+        self.subheader = subheader.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPageBlockSubheaderBuilder {
     inner: PageBlockSubheader,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPageBlockSubheaderBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PageBlockSubheader {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PageBlockSubheader> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PageBlockSubheader",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn subheader<T: AsRef<RichText>>(&mut self, subheader: T) -> &mut Self {
         self.inner.subheader = subheader.as_ref().clone();
+        self.missing.retain(|f| *f != "subheader");
         self
     }
 }
@@ -2311,12 +3808,13 @@ impl AsRef<PageBlockSubheader> for RTDPageBlockSubheaderBuilder {
 
 /// The subtitle of a page
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pageBlockSubtitle")]
 pub struct PageBlockSubtitle {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Subtitle
     subtitle: RichText,
@@ -2336,6 +3834,20 @@ impl RObject for PageBlockSubtitle {
     }
 }
 
+impl PartialEq for PageBlockSubtitle {
+    fn eq(&self, other: &Self) -> bool {
+        self.subtitle == other.subtitle
+    }
+}
+
+impl Eq for PageBlockSubtitle {}
+
+impl std::hash::Hash for PageBlockSubtitle {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.subtitle, state);
+    }
+}
+
 impl TDPageBlock for PageBlockSubtitle {}
 
 impl PageBlockSubtitle {
@@ -2343,29 +3855,66 @@ impl PageBlockSubtitle {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPageBlockSubtitleBuilder {
-        let mut inner = PageBlockSubtitle::default();
-        inner.td_name = "pageBlockSubtitle".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPageBlockSubtitleBuilder { inner }
+        RTDPageBlockSubtitleBuilder {
+            inner: PageBlockSubtitle::default(),
+            missing: vec!["subtitle"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPageBlockSubtitleBuilder {
+        RTDPageBlockSubtitleBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPageBlockSubtitleBuilder {
+        RTDPageBlockSubtitleBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn subtitle(&self) -> &RichText {
         &self.subtitle
     }
+
+    pub fn into_subtitle(self) -> RichText {
+        self.subtitle
+    }
+
+    pub fn with_subtitle<T: AsRef<RichText>>(mut self, subtitle: T) -> Self {
+        self.subtitle = subtitle.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPageBlockSubtitleBuilder {
     inner: PageBlockSubtitle,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPageBlockSubtitleBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PageBlockSubtitle {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PageBlockSubtitle> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PageBlockSubtitle",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn subtitle<T: AsRef<RichText>>(&mut self, subtitle: T) -> &mut Self {
         self.inner.subtitle = subtitle.as_ref().clone();
+        self.missing.retain(|f| *f != "subtitle");
         self
     }
 }
@@ -2384,12 +3933,13 @@ impl AsRef<PageBlockSubtitle> for RTDPageBlockSubtitleBuilder {
 
 /// A table
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pageBlockTable")]
 pub struct PageBlockTable {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Table caption
     caption: RichText,
@@ -2415,6 +3965,26 @@ impl RObject for PageBlockTable {
     }
 }
 
+impl PartialEq for PageBlockTable {
+    fn eq(&self, other: &Self) -> bool {
+        self.caption == other.caption
+            && self.cells == other.cells
+            && self.is_bordered == other.is_bordered
+            && self.is_striped == other.is_striped
+    }
+}
+
+impl Eq for PageBlockTable {}
+
+impl std::hash::Hash for PageBlockTable {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.caption, state);
+        std::hash::Hash::hash(&self.cells, state);
+        std::hash::Hash::hash(&self.is_bordered, state);
+        std::hash::Hash::hash(&self.is_striped, state);
+    }
+}
+
 impl TDPageBlock for PageBlockTable {}
 
 impl PageBlockTable {
@@ -2422,10 +3992,24 @@ impl PageBlockTable {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPageBlockTableBuilder {
-        let mut inner = PageBlockTable::default();
-        inner.td_name = "pageBlockTable".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPageBlockTableBuilder { inner }
+        RTDPageBlockTableBuilder {
+            inner: PageBlockTable::default(),
+            missing: vec!["caption", "cells"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPageBlockTableBuilder {
+        RTDPageBlockTableBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPageBlockTableBuilder {
+        RTDPageBlockTableBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn caption(&self) -> &RichText {
@@ -2443,25 +4027,68 @@ impl PageBlockTable {
     pub fn is_striped(&self) -> bool {
         self.is_striped
     }
+
+    pub fn into_caption(self) -> RichText {
+        self.caption
+    }
+
+    pub fn into_cells(self) -> Vec<Vec<PageBlockTableCell>> {
+        self.cells
+    }
+
+    pub fn with_caption<T: AsRef<RichText>>(mut self, caption: T) -> Self {
+        self.caption = caption.as_ref().clone();
+        self
+    }
+
+    pub fn with_cells(mut self, cells: Vec<Vec<PageBlockTableCell>>) -> Self {
+        self.cells = cells;
+        self
+    }
+
+    pub fn with_is_bordered(mut self, is_bordered: bool) -> Self {
+        self.is_bordered = is_bordered;
+        self
+    }
+
+    pub fn with_is_striped(mut self, is_striped: bool) -> Self {
+        self.is_striped = is_striped;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPageBlockTableBuilder {
     inner: PageBlockTable,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPageBlockTableBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PageBlockTable {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PageBlockTable> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PageBlockTable",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn caption<T: AsRef<RichText>>(&mut self, caption: T) -> &mut Self {
         self.inner.caption = caption.as_ref().clone();
+        self.missing.retain(|f| *f != "caption");
         self
     }
 
     pub fn cells(&mut self, cells: Vec<Vec<PageBlockTableCell>>) -> &mut Self {
         self.inner.cells = cells;
+        self.missing.retain(|f| *f != "cells");
         self
     }
 
@@ -2490,12 +4117,13 @@ impl AsRef<PageBlockTable> for RTDPageBlockTableBuilder {
 
 /// The title of a page
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pageBlockTitle")]
 pub struct PageBlockTitle {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Title
     title: RichText,
@@ -2515,6 +4143,20 @@ impl RObject for PageBlockTitle {
     }
 }
 
+impl PartialEq for PageBlockTitle {
+    fn eq(&self, other: &Self) -> bool {
+        self.title == other.title
+    }
+}
+
+impl Eq for PageBlockTitle {}
+
+impl std::hash::Hash for PageBlockTitle {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.title, state);
+    }
+}
+
 impl TDPageBlock for PageBlockTitle {}
 
 impl PageBlockTitle {
@@ -2522,29 +4164,66 @@ impl PageBlockTitle {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPageBlockTitleBuilder {
-        let mut inner = PageBlockTitle::default();
-        inner.td_name = "pageBlockTitle".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPageBlockTitleBuilder { inner }
+        RTDPageBlockTitleBuilder {
+            inner: PageBlockTitle::default(),
+            missing: vec!["title"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPageBlockTitleBuilder {
+        RTDPageBlockTitleBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPageBlockTitleBuilder {
+        RTDPageBlockTitleBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn title(&self) -> &RichText {
         &self.title
     }
+
+    pub fn into_title(self) -> RichText {
+        self.title
+    }
+
+    pub fn with_title<T: AsRef<RichText>>(mut self, title: T) -> Self {
+        self.title = title.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPageBlockTitleBuilder {
     inner: PageBlockTitle,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPageBlockTitleBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PageBlockTitle {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PageBlockTitle> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PageBlockTitle",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn title<T: AsRef<RichText>>(&mut self, title: T) -> &mut Self {
         self.inner.title = title.as_ref().clone();
+        self.missing.retain(|f| *f != "title");
         self
     }
 }
@@ -2563,12 +4242,13 @@ impl AsRef<PageBlockTitle> for RTDPageBlockTitleBuilder {
 
 /// A video
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pageBlockVideo")]
 pub struct PageBlockVideo {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Video file; may be null
     video: Option<Video>,
@@ -2594,6 +4274,26 @@ impl RObject for PageBlockVideo {
     }
 }
 
+impl PartialEq for PageBlockVideo {
+    fn eq(&self, other: &Self) -> bool {
+        self.video == other.video
+            && self.caption == other.caption
+            && self.need_autoplay == other.need_autoplay
+            && self.is_looped == other.is_looped
+    }
+}
+
+impl Eq for PageBlockVideo {}
+
+impl std::hash::Hash for PageBlockVideo {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.video, state);
+        std::hash::Hash::hash(&self.caption, state);
+        std::hash::Hash::hash(&self.need_autoplay, state);
+        std::hash::Hash::hash(&self.is_looped, state);
+    }
+}
+
 impl TDPageBlock for PageBlockVideo {}
 
 impl PageBlockVideo {
@@ -2601,10 +4301,24 @@ impl PageBlockVideo {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPageBlockVideoBuilder {
-        let mut inner = PageBlockVideo::default();
-        inner.td_name = "pageBlockVideo".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPageBlockVideoBuilder { inner }
+        RTDPageBlockVideoBuilder {
+            inner: PageBlockVideo::default(),
+            missing: vec!["caption"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPageBlockVideoBuilder {
+        RTDPageBlockVideoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPageBlockVideoBuilder {
+        RTDPageBlockVideoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn video(&self) -> &Option<Video> {
@@ -2622,17 +4336,58 @@ impl PageBlockVideo {
     pub fn is_looped(&self) -> bool {
         self.is_looped
     }
+
+    pub fn into_video(self) -> Option<Video> {
+        self.video
+    }
+
+    pub fn into_caption(self) -> PageBlockCaption {
+        self.caption
+    }
+
+    pub fn with_video<T: AsRef<Video>>(mut self, video: T) -> Self {
+        self.video = Some(video.as_ref().clone());
+        self
+    }
+
+    pub fn with_caption<T: AsRef<PageBlockCaption>>(mut self, caption: T) -> Self {
+        self.caption = caption.as_ref().clone();
+        self
+    }
+
+    pub fn with_need_autoplay(mut self, need_autoplay: bool) -> Self {
+        self.need_autoplay = need_autoplay;
+        self
+    }
+
+    pub fn with_is_looped(mut self, is_looped: bool) -> Self {
+        self.is_looped = is_looped;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPageBlockVideoBuilder {
     inner: PageBlockVideo,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPageBlockVideoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PageBlockVideo {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PageBlockVideo> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PageBlockVideo",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn video<T: AsRef<Video>>(&mut self, video: T) -> &mut Self {
         self.inner.video = Some(video.as_ref().clone());
@@ -2641,6 +4396,7 @@ impl RTDPageBlockVideoBuilder {
 
     pub fn caption<T: AsRef<PageBlockCaption>>(&mut self, caption: T) -> &mut Self {
         self.inner.caption = caption.as_ref().clone();
+        self.missing.retain(|f| *f != "caption");
         self
     }
 
@@ -2669,12 +4425,13 @@ impl AsRef<PageBlockVideo> for RTDPageBlockVideoBuilder {
 
 /// A voice note
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pageBlockVoiceNote")]
 pub struct PageBlockVoiceNote {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Voice note; may be null
     voice_note: Option<VoiceNote>,
@@ -2696,6 +4453,21 @@ impl RObject for PageBlockVoiceNote {
     }
 }
 
+impl PartialEq for PageBlockVoiceNote {
+    fn eq(&self, other: &Self) -> bool {
+        self.voice_note == other.voice_note && self.caption == other.caption
+    }
+}
+
+impl Eq for PageBlockVoiceNote {}
+
+impl std::hash::Hash for PageBlockVoiceNote {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.voice_note, state);
+        std::hash::Hash::hash(&self.caption, state);
+    }
+}
+
 impl TDPageBlock for PageBlockVoiceNote {}
 
 impl PageBlockVoiceNote {
@@ -2703,10 +4475,24 @@ impl PageBlockVoiceNote {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPageBlockVoiceNoteBuilder {
-        let mut inner = PageBlockVoiceNote::default();
-        inner.td_name = "pageBlockVoiceNote".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPageBlockVoiceNoteBuilder { inner }
+        RTDPageBlockVoiceNoteBuilder {
+            inner: PageBlockVoiceNote::default(),
+            missing: vec!["caption"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPageBlockVoiceNoteBuilder {
+        RTDPageBlockVoiceNoteBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPageBlockVoiceNoteBuilder {
+        RTDPageBlockVoiceNoteBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn voice_note(&self) -> &Option<VoiceNote> {
@@ -2716,17 +4502,48 @@ impl PageBlockVoiceNote {
     pub fn caption(&self) -> &PageBlockCaption {
         &self.caption
     }
+
+    pub fn into_voice_note(self) -> Option<VoiceNote> {
+        self.voice_note
+    }
+
+    pub fn into_caption(self) -> PageBlockCaption {
+        self.caption
+    }
+
+    pub fn with_voice_note<T: AsRef<VoiceNote>>(mut self, voice_note: T) -> Self {
+        self.voice_note = Some(voice_note.as_ref().clone());
+        self
+    }
+
+    pub fn with_caption<T: AsRef<PageBlockCaption>>(mut self, caption: T) -> Self {
+        self.caption = caption.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPageBlockVoiceNoteBuilder {
     inner: PageBlockVoiceNote,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPageBlockVoiceNoteBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PageBlockVoiceNote {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PageBlockVoiceNote> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PageBlockVoiceNote",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn voice_note<T: AsRef<VoiceNote>>(&mut self, voice_note: T) -> &mut Self {
         self.inner.voice_note = Some(voice_note.as_ref().clone());
@@ -2735,6 +4552,7 @@ impl RTDPageBlockVoiceNoteBuilder {
 
     pub fn caption<T: AsRef<PageBlockCaption>>(&mut self, caption: T) -> &mut Self {
         self.inner.caption = caption.as_ref().clone();
+        self.missing.retain(|f| *f != "caption");
         self
     }
 }
@@ -2750,3 +4568,305 @@ impl AsRef<PageBlockVoiceNote> for RTDPageBlockVoiceNoteBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(PageBlock::Anchor(
+            PageBlockAnchor::builder().name("text").build(),
+        ));
+        assert_round_trip(PageBlock::Animation(
+            PageBlockAnimation::builder()
+                .caption(
+                    PageBlockCaption::builder()
+                        .text(RichText::RichTexts(
+                            RichTexts::builder().texts(vec![]).build(),
+                        ))
+                        .credit(RichText::RichTexts(
+                            RichTexts::builder().texts(vec![]).build(),
+                        ))
+                        .build(),
+                )
+                .need_autoplay(true)
+                .build(),
+        ));
+        assert_round_trip(PageBlock::Audio(
+            PageBlockAudio::builder()
+                .caption(
+                    PageBlockCaption::builder()
+                        .text(RichText::RichTexts(
+                            RichTexts::builder().texts(vec![]).build(),
+                        ))
+                        .credit(RichText::RichTexts(
+                            RichTexts::builder().texts(vec![]).build(),
+                        ))
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(PageBlock::AuthorDate(
+            PageBlockAuthorDate::builder()
+                .author(RichText::RichTexts(
+                    RichTexts::builder().texts(vec![]).build(),
+                ))
+                .publish_date(i32::MAX)
+                .build(),
+        ));
+        assert_round_trip(PageBlock::BlockQuote(
+            PageBlockBlockQuote::builder()
+                .text(RichText::RichTexts(
+                    RichTexts::builder().texts(vec![]).build(),
+                ))
+                .credit(RichText::RichTexts(
+                    RichTexts::builder().texts(vec![]).build(),
+                ))
+                .build(),
+        ));
+        assert_round_trip(PageBlock::ChatLink(
+            PageBlockChatLink::builder()
+                .title("text")
+                .username("text")
+                .build(),
+        ));
+        assert_round_trip(PageBlock::Collage(
+            PageBlockCollage::builder()
+                .page_blocks(vec![])
+                .caption(
+                    PageBlockCaption::builder()
+                        .text(RichText::RichTexts(
+                            RichTexts::builder().texts(vec![]).build(),
+                        ))
+                        .credit(RichText::RichTexts(
+                            RichTexts::builder().texts(vec![]).build(),
+                        ))
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(PageBlock::Cover(
+            PageBlockCover::builder()
+                .cover(PageBlock::Anchor(
+                    PageBlockAnchor::builder().name("text").build(),
+                ))
+                .build(),
+        ));
+        assert_round_trip(PageBlock::Details(
+            PageBlockDetails::builder()
+                .header(RichText::RichTexts(
+                    RichTexts::builder().texts(vec![]).build(),
+                ))
+                .page_blocks(vec![])
+                .is_open(true)
+                .build(),
+        ));
+        assert_round_trip(PageBlock::Divider(PageBlockDivider::builder().build()));
+        assert_round_trip(PageBlock::Embedded(
+            PageBlockEmbedded::builder()
+                .url("text")
+                .html("text")
+                .width(i32::MAX)
+                .height(i32::MAX)
+                .caption(
+                    PageBlockCaption::builder()
+                        .text(RichText::RichTexts(
+                            RichTexts::builder().texts(vec![]).build(),
+                        ))
+                        .credit(RichText::RichTexts(
+                            RichTexts::builder().texts(vec![]).build(),
+                        ))
+                        .build(),
+                )
+                .is_full_width(true)
+                .allow_scrolling(true)
+                .build(),
+        ));
+        assert_round_trip(PageBlock::EmbeddedPost(
+            PageBlockEmbeddedPost::builder()
+                .url("text")
+                .author("text")
+                .date(i32::MAX)
+                .page_blocks(vec![])
+                .caption(
+                    PageBlockCaption::builder()
+                        .text(RichText::RichTexts(
+                            RichTexts::builder().texts(vec![]).build(),
+                        ))
+                        .credit(RichText::RichTexts(
+                            RichTexts::builder().texts(vec![]).build(),
+                        ))
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(PageBlock::Footer(
+            PageBlockFooter::builder()
+                .footer(RichText::RichTexts(
+                    RichTexts::builder().texts(vec![]).build(),
+                ))
+                .build(),
+        ));
+        assert_round_trip(PageBlock::Header(
+            PageBlockHeader::builder()
+                .header(RichText::RichTexts(
+                    RichTexts::builder().texts(vec![]).build(),
+                ))
+                .build(),
+        ));
+        assert_round_trip(PageBlock::Kicker(
+            PageBlockKicker::builder()
+                .kicker(RichText::RichTexts(
+                    RichTexts::builder().texts(vec![]).build(),
+                ))
+                .build(),
+        ));
+        assert_round_trip(PageBlock::List(
+            PageBlockList::builder().items(vec![]).build(),
+        ));
+        assert_round_trip(PageBlock::Map(
+            PageBlockMap::builder()
+                .location(Location::builder().latitude(1.5).longitude(1.5).build())
+                .zoom(i32::MAX)
+                .width(i32::MAX)
+                .height(i32::MAX)
+                .caption(
+                    PageBlockCaption::builder()
+                        .text(RichText::RichTexts(
+                            RichTexts::builder().texts(vec![]).build(),
+                        ))
+                        .credit(RichText::RichTexts(
+                            RichTexts::builder().texts(vec![]).build(),
+                        ))
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(PageBlock::Paragraph(
+            PageBlockParagraph::builder()
+                .text(RichText::RichTexts(
+                    RichTexts::builder().texts(vec![]).build(),
+                ))
+                .build(),
+        ));
+        assert_round_trip(PageBlock::Photo(
+            PageBlockPhoto::builder()
+                .caption(
+                    PageBlockCaption::builder()
+                        .text(RichText::RichTexts(
+                            RichTexts::builder().texts(vec![]).build(),
+                        ))
+                        .credit(RichText::RichTexts(
+                            RichTexts::builder().texts(vec![]).build(),
+                        ))
+                        .build(),
+                )
+                .url("text")
+                .build(),
+        ));
+        assert_round_trip(PageBlock::Preformatted(
+            PageBlockPreformatted::builder()
+                .text(RichText::RichTexts(
+                    RichTexts::builder().texts(vec![]).build(),
+                ))
+                .language("text")
+                .build(),
+        ));
+        assert_round_trip(PageBlock::PullQuote(
+            PageBlockPullQuote::builder()
+                .text(RichText::RichTexts(
+                    RichTexts::builder().texts(vec![]).build(),
+                ))
+                .credit(RichText::RichTexts(
+                    RichTexts::builder().texts(vec![]).build(),
+                ))
+                .build(),
+        ));
+        assert_round_trip(PageBlock::RelatedArticles(
+            PageBlockRelatedArticles::builder()
+                .header(RichText::RichTexts(
+                    RichTexts::builder().texts(vec![]).build(),
+                ))
+                .articles(vec![])
+                .build(),
+        ));
+        assert_round_trip(PageBlock::Slideshow(
+            PageBlockSlideshow::builder()
+                .page_blocks(vec![])
+                .caption(
+                    PageBlockCaption::builder()
+                        .text(RichText::RichTexts(
+                            RichTexts::builder().texts(vec![]).build(),
+                        ))
+                        .credit(RichText::RichTexts(
+                            RichTexts::builder().texts(vec![]).build(),
+                        ))
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(PageBlock::Subheader(
+            PageBlockSubheader::builder()
+                .subheader(RichText::RichTexts(
+                    RichTexts::builder().texts(vec![]).build(),
+                ))
+                .build(),
+        ));
+        assert_round_trip(PageBlock::Subtitle(
+            PageBlockSubtitle::builder()
+                .subtitle(RichText::RichTexts(
+                    RichTexts::builder().texts(vec![]).build(),
+                ))
+                .build(),
+        ));
+        assert_round_trip(PageBlock::Table(
+            PageBlockTable::builder()
+                .caption(RichText::RichTexts(
+                    RichTexts::builder().texts(vec![]).build(),
+                ))
+                .cells(vec![])
+                .is_bordered(true)
+                .is_striped(true)
+                .build(),
+        ));
+        assert_round_trip(PageBlock::Title(
+            PageBlockTitle::builder()
+                .title(RichText::RichTexts(
+                    RichTexts::builder().texts(vec![]).build(),
+                ))
+                .build(),
+        ));
+        assert_round_trip(PageBlock::Video(
+            PageBlockVideo::builder()
+                .caption(
+                    PageBlockCaption::builder()
+                        .text(RichText::RichTexts(
+                            RichTexts::builder().texts(vec![]).build(),
+                        ))
+                        .credit(RichText::RichTexts(
+                            RichTexts::builder().texts(vec![]).build(),
+                        ))
+                        .build(),
+                )
+                .need_autoplay(true)
+                .is_looped(true)
+                .build(),
+        ));
+        assert_round_trip(PageBlock::VoiceNote(
+            PageBlockVoiceNote::builder()
+                .caption(
+                    PageBlockCaption::builder()
+                        .text(RichText::RichTexts(
+                            RichTexts::builder().texts(vec![]).build(),
+                        ))
+                        .credit(RichText::RichTexts(
+                            RichTexts::builder().texts(vec![]).build(),
+                        ))
+                        .build(),
+                )
+                .build(),
+        ));
+    }
+}
@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains a caption of an instant view web page block, consisting of a text and a trailing credit
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pageBlockCaption")]
 pub struct PageBlockCaption {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Content of the caption
     text: RichText,
@@ -31,15 +31,44 @@ impl RObject for PageBlockCaption {
     }
 }
 
+impl PartialEq for PageBlockCaption {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text && self.credit == other.credit
+    }
+}
+
+impl Eq for PageBlockCaption {}
+
+impl std::hash::Hash for PageBlockCaption {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.text, state);
+        std::hash::Hash::hash(&self.credit, state);
+    }
+}
+
 impl PageBlockCaption {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPageBlockCaptionBuilder {
-        let mut inner = PageBlockCaption::default();
-        inner.td_name = "pageBlockCaption".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPageBlockCaptionBuilder { inner }
+        RTDPageBlockCaptionBuilder {
+            inner: PageBlockCaption::default(),
+            missing: vec!["text", "credit"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPageBlockCaptionBuilder {
+        RTDPageBlockCaptionBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPageBlockCaptionBuilder {
+        RTDPageBlockCaptionBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn text(&self) -> &RichText {
@@ -49,25 +78,58 @@ impl PageBlockCaption {
     pub fn credit(&self) -> &RichText {
         &self.credit
     }
+
+    pub fn into_text(self) -> RichText {
+        self.text
+    }
+
+    pub fn into_credit(self) -> RichText {
+        self.credit
+    }
+
+    pub fn with_text<T: AsRef<RichText>>(mut self, text: T) -> Self {
+        self.text = text.as_ref().clone();
+        self
+    }
+
+    pub fn with_credit<T: AsRef<RichText>>(mut self, credit: T) -> Self {
+        self.credit = credit.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPageBlockCaptionBuilder {
     inner: PageBlockCaption,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPageBlockCaptionBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PageBlockCaption {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PageBlockCaption> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PageBlockCaption",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn text<T: AsRef<RichText>>(&mut self, text: T) -> &mut Self {
         self.inner.text = text.as_ref().clone();
+        self.missing.retain(|f| *f != "text");
         self
     }
 
     pub fn credit<T: AsRef<RichText>>(&mut self, credit: T) -> &mut Self {
         self.inner.credit = credit.as_ref().clone();
+        self.missing.retain(|f| *f != "credit");
         self
     }
 }
@@ -83,3 +145,23 @@ impl AsRef<PageBlockCaption> for RTDPageBlockCaptionBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            PageBlockCaption::builder()
+                .text(RichText::RichTexts(
+                    RichTexts::builder().texts(vec![]).build(),
+                ))
+                .credit(RichText::RichTexts(
+                    RichTexts::builder().texts(vec![]).build(),
+                ))
+                .build(),
+        );
+    }
+}
@@ -1,46 +1,44 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Describes a horizontal alignment of a table cell content
 pub trait TDPageBlockHorizontalAlignment: Debug + RObject {}
 
 /// Describes a horizontal alignment of a table cell content
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum PageBlockHorizontalAlignment {
-    #[doc(hidden)]
-    _Default(()),
     /// The content should be center-aligned
+    #[serde(rename = "pageBlockHorizontalAlignmentCenter")]
     Center(PageBlockHorizontalAlignmentCenter),
     /// The content should be left-aligned
+    #[serde(rename = "pageBlockHorizontalAlignmentLeft")]
     Left(PageBlockHorizontalAlignmentLeft),
     /// The content should be right-aligned
+    #[serde(rename = "pageBlockHorizontalAlignmentRight")]
     Right(PageBlockHorizontalAlignmentRight),
 }
 
+/// Default value is default of [PageBlockHorizontalAlignmentCenter], builders still require the field to be set
 impl Default for PageBlockHorizontalAlignment {
     fn default() -> Self {
-        PageBlockHorizontalAlignment::_Default(())
+        PageBlockHorizontalAlignment::Center(PageBlockHorizontalAlignmentCenter::default())
     }
 }
 
-impl<'de> Deserialize<'de> for PageBlockHorizontalAlignment {
-    fn deserialize<D>(deserializer: D) -> Result<PageBlockHorizontalAlignment, D::Error>
+impl Serialize for PageBlockHorizontalAlignment {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          PageBlockHorizontalAlignment,
-          (pageBlockHorizontalAlignmentCenter, Center);
-          (pageBlockHorizontalAlignmentLeft, Left);
-          (pageBlockHorizontalAlignmentRight, Right);
-
-        )(deserializer)
+        match self {
+            PageBlockHorizontalAlignment::Center(t) => t.serialize(serializer),
+            PageBlockHorizontalAlignment::Left(t) => t.serialize(serializer),
+            PageBlockHorizontalAlignment::Right(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -51,8 +49,6 @@ impl RObject for PageBlockHorizontalAlignment {
             PageBlockHorizontalAlignment::Center(t) => t.td_name(),
             PageBlockHorizontalAlignment::Left(t) => t.td_name(),
             PageBlockHorizontalAlignment::Right(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -61,8 +57,6 @@ impl RObject for PageBlockHorizontalAlignment {
             PageBlockHorizontalAlignment::Center(t) => t.extra(),
             PageBlockHorizontalAlignment::Left(t) => t.extra(),
             PageBlockHorizontalAlignment::Right(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -74,10 +68,6 @@ impl PageBlockHorizontalAlignment {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, PageBlockHorizontalAlignment::_Default(_))
-    }
 }
 
 impl AsRef<PageBlockHorizontalAlignment> for PageBlockHorizontalAlignment {
@@ -88,12 +78,13 @@ impl AsRef<PageBlockHorizontalAlignment> for PageBlockHorizontalAlignment {
 
 /// The content should be center-aligned
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pageBlockHorizontalAlignmentCenter")]
 pub struct PageBlockHorizontalAlignmentCenter {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -111,6 +102,18 @@ impl RObject for PageBlockHorizontalAlignmentCenter {
     }
 }
 
+impl PartialEq for PageBlockHorizontalAlignmentCenter {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for PageBlockHorizontalAlignmentCenter {}
+
+impl std::hash::Hash for PageBlockHorizontalAlignmentCenter {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDPageBlockHorizontalAlignment for PageBlockHorizontalAlignmentCenter {}
 
 impl PageBlockHorizontalAlignmentCenter {
@@ -118,22 +121,49 @@ impl PageBlockHorizontalAlignmentCenter {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPageBlockHorizontalAlignmentCenterBuilder {
-        let mut inner = PageBlockHorizontalAlignmentCenter::default();
-        inner.td_name = "pageBlockHorizontalAlignmentCenter".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPageBlockHorizontalAlignmentCenterBuilder { inner }
+        RTDPageBlockHorizontalAlignmentCenterBuilder {
+            inner: PageBlockHorizontalAlignmentCenter::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPageBlockHorizontalAlignmentCenterBuilder {
+        RTDPageBlockHorizontalAlignmentCenterBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPageBlockHorizontalAlignmentCenterBuilder {
+        RTDPageBlockHorizontalAlignmentCenterBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDPageBlockHorizontalAlignmentCenterBuilder {
     inner: PageBlockHorizontalAlignmentCenter,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPageBlockHorizontalAlignmentCenterBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PageBlockHorizontalAlignmentCenter {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PageBlockHorizontalAlignmentCenter> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PageBlockHorizontalAlignmentCenter",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<PageBlockHorizontalAlignmentCenter> for PageBlockHorizontalAlignmentCenter {
@@ -150,12 +180,13 @@ impl AsRef<PageBlockHorizontalAlignmentCenter> for RTDPageBlockHorizontalAlignme
 
 /// The content should be left-aligned
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pageBlockHorizontalAlignmentLeft")]
 pub struct PageBlockHorizontalAlignmentLeft {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -173,6 +204,18 @@ impl RObject for PageBlockHorizontalAlignmentLeft {
     }
 }
 
+impl PartialEq for PageBlockHorizontalAlignmentLeft {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for PageBlockHorizontalAlignmentLeft {}
+
+impl std::hash::Hash for PageBlockHorizontalAlignmentLeft {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDPageBlockHorizontalAlignment for PageBlockHorizontalAlignmentLeft {}
 
 impl PageBlockHorizontalAlignmentLeft {
@@ -180,22 +223,49 @@ impl PageBlockHorizontalAlignmentLeft {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPageBlockHorizontalAlignmentLeftBuilder {
-        let mut inner = PageBlockHorizontalAlignmentLeft::default();
-        inner.td_name = "pageBlockHorizontalAlignmentLeft".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPageBlockHorizontalAlignmentLeftBuilder { inner }
+        RTDPageBlockHorizontalAlignmentLeftBuilder {
+            inner: PageBlockHorizontalAlignmentLeft::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPageBlockHorizontalAlignmentLeftBuilder {
+        RTDPageBlockHorizontalAlignmentLeftBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPageBlockHorizontalAlignmentLeftBuilder {
+        RTDPageBlockHorizontalAlignmentLeftBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDPageBlockHorizontalAlignmentLeftBuilder {
     inner: PageBlockHorizontalAlignmentLeft,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPageBlockHorizontalAlignmentLeftBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PageBlockHorizontalAlignmentLeft {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PageBlockHorizontalAlignmentLeft> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PageBlockHorizontalAlignmentLeft",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<PageBlockHorizontalAlignmentLeft> for PageBlockHorizontalAlignmentLeft {
@@ -212,12 +282,13 @@ impl AsRef<PageBlockHorizontalAlignmentLeft> for RTDPageBlockHorizontalAlignment
 
 /// The content should be right-aligned
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pageBlockHorizontalAlignmentRight")]
 pub struct PageBlockHorizontalAlignmentRight {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -235,6 +306,18 @@ impl RObject for PageBlockHorizontalAlignmentRight {
     }
 }
 
+impl PartialEq for PageBlockHorizontalAlignmentRight {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for PageBlockHorizontalAlignmentRight {}
+
+impl std::hash::Hash for PageBlockHorizontalAlignmentRight {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDPageBlockHorizontalAlignment for PageBlockHorizontalAlignmentRight {}
 
 impl PageBlockHorizontalAlignmentRight {
@@ -242,22 +325,49 @@ impl PageBlockHorizontalAlignmentRight {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPageBlockHorizontalAlignmentRightBuilder {
-        let mut inner = PageBlockHorizontalAlignmentRight::default();
-        inner.td_name = "pageBlockHorizontalAlignmentRight".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPageBlockHorizontalAlignmentRightBuilder { inner }
+        RTDPageBlockHorizontalAlignmentRightBuilder {
+            inner: PageBlockHorizontalAlignmentRight::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPageBlockHorizontalAlignmentRightBuilder {
+        RTDPageBlockHorizontalAlignmentRightBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPageBlockHorizontalAlignmentRightBuilder {
+        RTDPageBlockHorizontalAlignmentRightBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDPageBlockHorizontalAlignmentRightBuilder {
     inner: PageBlockHorizontalAlignmentRight,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPageBlockHorizontalAlignmentRightBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PageBlockHorizontalAlignmentRight {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PageBlockHorizontalAlignmentRight> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PageBlockHorizontalAlignmentRight",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<PageBlockHorizontalAlignmentRight> for PageBlockHorizontalAlignmentRight {
@@ -271,3 +381,22 @@ impl AsRef<PageBlockHorizontalAlignmentRight> for RTDPageBlockHorizontalAlignmen
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(PageBlockHorizontalAlignment::Center(
+            PageBlockHorizontalAlignmentCenter::builder().build(),
+        ));
+        assert_round_trip(PageBlockHorizontalAlignment::Left(
+            PageBlockHorizontalAlignmentLeft::builder().build(),
+        ));
+        assert_round_trip(PageBlockHorizontalAlignment::Right(
+            PageBlockHorizontalAlignmentRight::builder().build(),
+        ));
+    }
+}
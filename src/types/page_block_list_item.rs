@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Describes an item of a list page block
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pageBlockListItem")]
 pub struct PageBlockListItem {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Item label
     label: String,
@@ -31,15 +31,35 @@ impl RObject for PageBlockListItem {
     }
 }
 
+impl PartialEq for PageBlockListItem {
+    fn eq(&self, other: &Self) -> bool {
+        self.label == other.label && self.page_blocks == other.page_blocks
+    }
+}
+
 impl PageBlockListItem {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPageBlockListItemBuilder {
-        let mut inner = PageBlockListItem::default();
-        inner.td_name = "pageBlockListItem".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPageBlockListItemBuilder { inner }
+        RTDPageBlockListItemBuilder {
+            inner: PageBlockListItem::default(),
+            missing: vec!["label", "page_blocks"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPageBlockListItemBuilder {
+        RTDPageBlockListItemBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPageBlockListItemBuilder {
+        RTDPageBlockListItemBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn label(&self) -> &String {
@@ -49,25 +69,58 @@ impl PageBlockListItem {
     pub fn page_blocks(&self) -> &Vec<PageBlock> {
         &self.page_blocks
     }
+
+    pub fn into_label(self) -> String {
+        self.label
+    }
+
+    pub fn into_page_blocks(self) -> Vec<PageBlock> {
+        self.page_blocks
+    }
+
+    pub fn with_label<T: AsRef<str>>(mut self, label: T) -> Self {
+        self.label = label.as_ref().to_string();
+        self
+    }
+
+    pub fn with_page_blocks(mut self, page_blocks: Vec<PageBlock>) -> Self {
+        self.page_blocks = page_blocks;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPageBlockListItemBuilder {
     inner: PageBlockListItem,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPageBlockListItemBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PageBlockListItem {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PageBlockListItem> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PageBlockListItem",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn label<T: AsRef<str>>(&mut self, label: T) -> &mut Self {
         self.inner.label = label.as_ref().to_string();
+        self.missing.retain(|f| *f != "label");
         self
     }
 
     pub fn page_blocks(&mut self, page_blocks: Vec<PageBlock>) -> &mut Self {
         self.inner.page_blocks = page_blocks;
+        self.missing.retain(|f| *f != "page_blocks");
         self
     }
 }
@@ -83,3 +136,19 @@ impl AsRef<PageBlockListItem> for RTDPageBlockListItemBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            PageBlockListItem::builder()
+                .label("text")
+                .page_blocks(vec![])
+                .build(),
+        );
+    }
+}
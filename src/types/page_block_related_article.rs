@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains information about a related article
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pageBlockRelatedArticle")]
 pub struct PageBlockRelatedArticle {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Related article URL
     url: String,
@@ -22,7 +22,7 @@ pub struct PageBlockRelatedArticle {
     /// Article author; may be empty
     author: String,
     /// Point in time (Unix timestamp) when the article was published; 0 if unknown
-    publish_date: i64,
+    publish_date: i32,
 }
 
 impl RObject for PageBlockRelatedArticle {
@@ -39,15 +39,53 @@ impl RObject for PageBlockRelatedArticle {
     }
 }
 
+impl PartialEq for PageBlockRelatedArticle {
+    fn eq(&self, other: &Self) -> bool {
+        self.url == other.url
+            && self.title == other.title
+            && self.description == other.description
+            && self.photo == other.photo
+            && self.author == other.author
+            && self.publish_date == other.publish_date
+    }
+}
+
+impl Eq for PageBlockRelatedArticle {}
+
+impl std::hash::Hash for PageBlockRelatedArticle {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.url, state);
+        std::hash::Hash::hash(&self.title, state);
+        std::hash::Hash::hash(&self.description, state);
+        std::hash::Hash::hash(&self.photo, state);
+        std::hash::Hash::hash(&self.author, state);
+        std::hash::Hash::hash(&self.publish_date, state);
+    }
+}
+
 impl PageBlockRelatedArticle {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPageBlockRelatedArticleBuilder {
-        let mut inner = PageBlockRelatedArticle::default();
-        inner.td_name = "pageBlockRelatedArticle".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPageBlockRelatedArticleBuilder { inner }
+        RTDPageBlockRelatedArticleBuilder {
+            inner: PageBlockRelatedArticle::default(),
+            missing: vec!["url", "description"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPageBlockRelatedArticleBuilder {
+        RTDPageBlockRelatedArticleBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPageBlockRelatedArticleBuilder {
+        RTDPageBlockRelatedArticleBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn url(&self) -> &String {
@@ -70,23 +108,87 @@ impl PageBlockRelatedArticle {
         &self.author
     }
 
-    pub fn publish_date(&self) -> i64 {
+    pub fn publish_date(&self) -> i32 {
         self.publish_date
     }
+
+    pub fn into_url(self) -> String {
+        self.url
+    }
+
+    pub fn into_title(self) -> String {
+        self.title
+    }
+
+    pub fn into_description(self) -> String {
+        self.description
+    }
+
+    pub fn into_photo(self) -> Option<Photo> {
+        self.photo
+    }
+
+    pub fn into_author(self) -> String {
+        self.author
+    }
+
+    pub fn with_url<T: AsRef<str>>(mut self, url: T) -> Self {
+        self.url = url.as_ref().to_string();
+        self
+    }
+
+    pub fn with_title<T: AsRef<str>>(mut self, title: T) -> Self {
+        self.title = title.as_ref().to_string();
+        self
+    }
+
+    pub fn with_description<T: AsRef<str>>(mut self, description: T) -> Self {
+        self.description = description.as_ref().to_string();
+        self
+    }
+
+    pub fn with_photo<T: AsRef<Photo>>(mut self, photo: T) -> Self {
+        self.photo = Some(photo.as_ref().clone());
+        self
+    }
+
+    pub fn with_author<T: AsRef<str>>(mut self, author: T) -> Self {
+        self.author = author.as_ref().to_string();
+        self
+    }
+
+    pub fn with_publish_date(mut self, publish_date: i32) -> Self {
+        self.publish_date = publish_date;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPageBlockRelatedArticleBuilder {
     inner: PageBlockRelatedArticle,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPageBlockRelatedArticleBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PageBlockRelatedArticle {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PageBlockRelatedArticle> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PageBlockRelatedArticle",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
         self.inner.url = url.as_ref().to_string();
+        self.missing.retain(|f| *f != "url");
         self
     }
 
@@ -97,6 +199,7 @@ impl RTDPageBlockRelatedArticleBuilder {
 
     pub fn description<T: AsRef<str>>(&mut self, description: T) -> &mut Self {
         self.inner.description = description.as_ref().to_string();
+        self.missing.retain(|f| *f != "description");
         self
     }
 
@@ -110,7 +213,7 @@ impl RTDPageBlockRelatedArticleBuilder {
         self
     }
 
-    pub fn publish_date(&mut self, publish_date: i64) -> &mut Self {
+    pub fn publish_date(&mut self, publish_date: i32) -> &mut Self {
         self.inner.publish_date = publish_date;
         self
     }
@@ -127,3 +230,22 @@ impl AsRef<PageBlockRelatedArticle> for RTDPageBlockRelatedArticleBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            PageBlockRelatedArticle::builder()
+                .url("text")
+                .title("text")
+                .description("text")
+                .author("text")
+                .publish_date(i32::MAX)
+                .build(),
+        );
+    }
+}
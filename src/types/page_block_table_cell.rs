@@ -1,24 +1,24 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Represents a cell of a table
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pageBlockTableCell")]
 pub struct PageBlockTableCell {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Cell text; may be null. If the text is null, then the cell should be invisible
     text: Option<RichText>,
     /// True, if it is a header cell
     is_header: bool,
     /// The number of columns the cell should span
-    colspan: i64,
+    colspan: i32,
     /// The number of rows the cell should span
-    rowspan: i64,
+    rowspan: i32,
     /// Horizontal cell content alignment
     align: PageBlockHorizontalAlignment,
     /// Vertical cell content alignment
@@ -39,15 +39,53 @@ impl RObject for PageBlockTableCell {
     }
 }
 
+impl PartialEq for PageBlockTableCell {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text
+            && self.is_header == other.is_header
+            && self.colspan == other.colspan
+            && self.rowspan == other.rowspan
+            && self.align == other.align
+            && self.valign == other.valign
+    }
+}
+
+impl Eq for PageBlockTableCell {}
+
+impl std::hash::Hash for PageBlockTableCell {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.text, state);
+        std::hash::Hash::hash(&self.is_header, state);
+        std::hash::Hash::hash(&self.colspan, state);
+        std::hash::Hash::hash(&self.rowspan, state);
+        std::hash::Hash::hash(&self.align, state);
+        std::hash::Hash::hash(&self.valign, state);
+    }
+}
+
 impl PageBlockTableCell {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPageBlockTableCellBuilder {
-        let mut inner = PageBlockTableCell::default();
-        inner.td_name = "pageBlockTableCell".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPageBlockTableCellBuilder { inner }
+        RTDPageBlockTableCellBuilder {
+            inner: PageBlockTableCell::default(),
+            missing: vec!["colspan", "rowspan", "align", "valign"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPageBlockTableCellBuilder {
+        RTDPageBlockTableCellBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPageBlockTableCellBuilder {
+        RTDPageBlockTableCellBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn text(&self) -> &Option<RichText> {
@@ -58,11 +96,11 @@ impl PageBlockTableCell {
         self.is_header
     }
 
-    pub fn colspan(&self) -> i64 {
+    pub fn colspan(&self) -> i32 {
         self.colspan
     }
 
-    pub fn rowspan(&self) -> i64 {
+    pub fn rowspan(&self) -> i32 {
         self.rowspan
     }
 
@@ -73,17 +111,72 @@ impl PageBlockTableCell {
     pub fn valign(&self) -> &PageBlockVerticalAlignment {
         &self.valign
     }
+
+    pub fn into_text(self) -> Option<RichText> {
+        self.text
+    }
+
+    pub fn into_align(self) -> PageBlockHorizontalAlignment {
+        self.align
+    }
+
+    pub fn into_valign(self) -> PageBlockVerticalAlignment {
+        self.valign
+    }
+
+    pub fn with_text<T: AsRef<RichText>>(mut self, text: T) -> Self {
+        self.text = Some(text.as_ref().clone());
+        self
+    }
+
+    pub fn with_is_header(mut self, is_header: bool) -> Self {
+        self.is_header = is_header;
+        self
+    }
+
+    pub fn with_colspan(mut self, colspan: i32) -> Self {
+        self.colspan = colspan;
+        self
+    }
+
+    pub fn with_rowspan(mut self, rowspan: i32) -> Self {
+        self.rowspan = rowspan;
+        self
+    }
+
+    pub fn with_align<T: AsRef<PageBlockHorizontalAlignment>>(mut self, align: T) -> Self {
+        self.align = align.as_ref().clone();
+        self
+    }
+
+    pub fn with_valign<T: AsRef<PageBlockVerticalAlignment>>(mut self, valign: T) -> Self {
+        self.valign = valign.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPageBlockTableCellBuilder {
     inner: PageBlockTableCell,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPageBlockTableCellBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PageBlockTableCell {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PageBlockTableCell> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PageBlockTableCell",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn text<T: AsRef<RichText>>(&mut self, text: T) -> &mut Self {
         self.inner.text = Some(text.as_ref().clone());
@@ -95,23 +188,27 @@ impl RTDPageBlockTableCellBuilder {
         self
     }
 
-    pub fn colspan(&mut self, colspan: i64) -> &mut Self {
+    pub fn colspan(&mut self, colspan: i32) -> &mut Self {
         self.inner.colspan = colspan;
+        self.missing.retain(|f| *f != "colspan");
         self
     }
 
-    pub fn rowspan(&mut self, rowspan: i64) -> &mut Self {
+    pub fn rowspan(&mut self, rowspan: i32) -> &mut Self {
         self.inner.rowspan = rowspan;
+        self.missing.retain(|f| *f != "rowspan");
         self
     }
 
     pub fn align<T: AsRef<PageBlockHorizontalAlignment>>(&mut self, align: T) -> &mut Self {
         self.inner.align = align.as_ref().clone();
+        self.missing.retain(|f| *f != "align");
         self
     }
 
     pub fn valign<T: AsRef<PageBlockVerticalAlignment>>(&mut self, valign: T) -> &mut Self {
         self.inner.valign = valign.as_ref().clone();
+        self.missing.retain(|f| *f != "valign");
         self
     }
 }
@@ -127,3 +224,26 @@ impl AsRef<PageBlockTableCell> for RTDPageBlockTableCellBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            PageBlockTableCell::builder()
+                .is_header(true)
+                .colspan(i32::MAX)
+                .rowspan(i32::MAX)
+                .align(PageBlockHorizontalAlignment::Center(
+                    PageBlockHorizontalAlignmentCenter::builder().build(),
+                ))
+                .valign(PageBlockVerticalAlignment::Bottom(
+                    PageBlockVerticalAlignmentBottom::builder().build(),
+                ))
+                .build(),
+        );
+    }
+}
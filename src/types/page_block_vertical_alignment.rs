@@ -1,46 +1,44 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Describes a Vertical alignment of a table cell content
 pub trait TDPageBlockVerticalAlignment: Debug + RObject {}
 
 /// Describes a Vertical alignment of a table cell content
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum PageBlockVerticalAlignment {
-    #[doc(hidden)]
-    _Default(()),
     /// The content should be bottom-aligned
+    #[serde(rename = "pageBlockVerticalAlignmentBottom")]
     Bottom(PageBlockVerticalAlignmentBottom),
     /// The content should be middle-aligned
+    #[serde(rename = "pageBlockVerticalAlignmentMiddle")]
     Middle(PageBlockVerticalAlignmentMiddle),
     /// The content should be top-aligned
+    #[serde(rename = "pageBlockVerticalAlignmentTop")]
     Top(PageBlockVerticalAlignmentTop),
 }
 
+/// Default value is default of [PageBlockVerticalAlignmentBottom], builders still require the field to be set
 impl Default for PageBlockVerticalAlignment {
     fn default() -> Self {
-        PageBlockVerticalAlignment::_Default(())
+        PageBlockVerticalAlignment::Bottom(PageBlockVerticalAlignmentBottom::default())
     }
 }
 
-impl<'de> Deserialize<'de> for PageBlockVerticalAlignment {
-    fn deserialize<D>(deserializer: D) -> Result<PageBlockVerticalAlignment, D::Error>
+impl Serialize for PageBlockVerticalAlignment {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          PageBlockVerticalAlignment,
-          (pageBlockVerticalAlignmentBottom, Bottom);
-          (pageBlockVerticalAlignmentMiddle, Middle);
-          (pageBlockVerticalAlignmentTop, Top);
-
-        )(deserializer)
+        match self {
+            PageBlockVerticalAlignment::Bottom(t) => t.serialize(serializer),
+            PageBlockVerticalAlignment::Middle(t) => t.serialize(serializer),
+            PageBlockVerticalAlignment::Top(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -51,8 +49,6 @@ impl RObject for PageBlockVerticalAlignment {
             PageBlockVerticalAlignment::Bottom(t) => t.td_name(),
             PageBlockVerticalAlignment::Middle(t) => t.td_name(),
             PageBlockVerticalAlignment::Top(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -61,8 +57,6 @@ impl RObject for PageBlockVerticalAlignment {
             PageBlockVerticalAlignment::Bottom(t) => t.extra(),
             PageBlockVerticalAlignment::Middle(t) => t.extra(),
             PageBlockVerticalAlignment::Top(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -74,10 +68,6 @@ impl PageBlockVerticalAlignment {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, PageBlockVerticalAlignment::_Default(_))
-    }
 }
 
 impl AsRef<PageBlockVerticalAlignment> for PageBlockVerticalAlignment {
@@ -88,12 +78,13 @@ impl AsRef<PageBlockVerticalAlignment> for PageBlockVerticalAlignment {
 
 /// The content should be bottom-aligned
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pageBlockVerticalAlignmentBottom")]
 pub struct PageBlockVerticalAlignmentBottom {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -111,6 +102,18 @@ impl RObject for PageBlockVerticalAlignmentBottom {
     }
 }
 
+impl PartialEq for PageBlockVerticalAlignmentBottom {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for PageBlockVerticalAlignmentBottom {}
+
+impl std::hash::Hash for PageBlockVerticalAlignmentBottom {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDPageBlockVerticalAlignment for PageBlockVerticalAlignmentBottom {}
 
 impl PageBlockVerticalAlignmentBottom {
@@ -118,22 +121,49 @@ impl PageBlockVerticalAlignmentBottom {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPageBlockVerticalAlignmentBottomBuilder {
-        let mut inner = PageBlockVerticalAlignmentBottom::default();
-        inner.td_name = "pageBlockVerticalAlignmentBottom".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPageBlockVerticalAlignmentBottomBuilder { inner }
+        RTDPageBlockVerticalAlignmentBottomBuilder {
+            inner: PageBlockVerticalAlignmentBottom::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPageBlockVerticalAlignmentBottomBuilder {
+        RTDPageBlockVerticalAlignmentBottomBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPageBlockVerticalAlignmentBottomBuilder {
+        RTDPageBlockVerticalAlignmentBottomBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDPageBlockVerticalAlignmentBottomBuilder {
     inner: PageBlockVerticalAlignmentBottom,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPageBlockVerticalAlignmentBottomBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PageBlockVerticalAlignmentBottom {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PageBlockVerticalAlignmentBottom> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PageBlockVerticalAlignmentBottom",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<PageBlockVerticalAlignmentBottom> for PageBlockVerticalAlignmentBottom {
@@ -150,12 +180,13 @@ impl AsRef<PageBlockVerticalAlignmentBottom> for RTDPageBlockVerticalAlignmentBo
 
 /// The content should be middle-aligned
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pageBlockVerticalAlignmentMiddle")]
 pub struct PageBlockVerticalAlignmentMiddle {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -173,6 +204,18 @@ impl RObject for PageBlockVerticalAlignmentMiddle {
     }
 }
 
+impl PartialEq for PageBlockVerticalAlignmentMiddle {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for PageBlockVerticalAlignmentMiddle {}
+
+impl std::hash::Hash for PageBlockVerticalAlignmentMiddle {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDPageBlockVerticalAlignment for PageBlockVerticalAlignmentMiddle {}
 
 impl PageBlockVerticalAlignmentMiddle {
@@ -180,22 +223,49 @@ impl PageBlockVerticalAlignmentMiddle {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPageBlockVerticalAlignmentMiddleBuilder {
-        let mut inner = PageBlockVerticalAlignmentMiddle::default();
-        inner.td_name = "pageBlockVerticalAlignmentMiddle".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPageBlockVerticalAlignmentMiddleBuilder { inner }
+        RTDPageBlockVerticalAlignmentMiddleBuilder {
+            inner: PageBlockVerticalAlignmentMiddle::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPageBlockVerticalAlignmentMiddleBuilder {
+        RTDPageBlockVerticalAlignmentMiddleBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPageBlockVerticalAlignmentMiddleBuilder {
+        RTDPageBlockVerticalAlignmentMiddleBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDPageBlockVerticalAlignmentMiddleBuilder {
     inner: PageBlockVerticalAlignmentMiddle,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPageBlockVerticalAlignmentMiddleBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PageBlockVerticalAlignmentMiddle {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PageBlockVerticalAlignmentMiddle> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PageBlockVerticalAlignmentMiddle",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<PageBlockVerticalAlignmentMiddle> for PageBlockVerticalAlignmentMiddle {
@@ -212,12 +282,13 @@ impl AsRef<PageBlockVerticalAlignmentMiddle> for RTDPageBlockVerticalAlignmentMi
 
 /// The content should be top-aligned
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pageBlockVerticalAlignmentTop")]
 pub struct PageBlockVerticalAlignmentTop {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -235,6 +306,18 @@ impl RObject for PageBlockVerticalAlignmentTop {
     }
 }
 
+impl PartialEq for PageBlockVerticalAlignmentTop {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for PageBlockVerticalAlignmentTop {}
+
+impl std::hash::Hash for PageBlockVerticalAlignmentTop {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDPageBlockVerticalAlignment for PageBlockVerticalAlignmentTop {}
 
 impl PageBlockVerticalAlignmentTop {
@@ -242,22 +325,49 @@ impl PageBlockVerticalAlignmentTop {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPageBlockVerticalAlignmentTopBuilder {
-        let mut inner = PageBlockVerticalAlignmentTop::default();
-        inner.td_name = "pageBlockVerticalAlignmentTop".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPageBlockVerticalAlignmentTopBuilder { inner }
+        RTDPageBlockVerticalAlignmentTopBuilder {
+            inner: PageBlockVerticalAlignmentTop::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPageBlockVerticalAlignmentTopBuilder {
+        RTDPageBlockVerticalAlignmentTopBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPageBlockVerticalAlignmentTopBuilder {
+        RTDPageBlockVerticalAlignmentTopBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDPageBlockVerticalAlignmentTopBuilder {
     inner: PageBlockVerticalAlignmentTop,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPageBlockVerticalAlignmentTopBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PageBlockVerticalAlignmentTop {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PageBlockVerticalAlignmentTop> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PageBlockVerticalAlignmentTop",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<PageBlockVerticalAlignmentTop> for PageBlockVerticalAlignmentTop {
@@ -271,3 +381,22 @@ impl AsRef<PageBlockVerticalAlignmentTop> for RTDPageBlockVerticalAlignmentTopBu
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(PageBlockVerticalAlignment::Bottom(
+            PageBlockVerticalAlignmentBottom::builder().build(),
+        ));
+        assert_round_trip(PageBlockVerticalAlignment::Middle(
+            PageBlockVerticalAlignmentMiddle::builder().build(),
+        ));
+        assert_round_trip(PageBlockVerticalAlignment::Top(
+            PageBlockVerticalAlignmentTop::builder().build(),
+        ));
+    }
+}
@@ -1,18 +1,18 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains information about a Telegram Passport authorization form that was requested
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "passportAuthorizationForm")]
 pub struct PassportAuthorizationForm {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Unique identifier of the authorization form
-    id: i64,
+    id: i32,
     /// Information about the Telegram Passport elements that need to be provided to complete the form
     required_elements: Vec<PassportRequiredElement>,
     /// URL for the privacy policy of the service; may be empty
@@ -33,18 +33,50 @@ impl RObject for PassportAuthorizationForm {
     }
 }
 
+impl PartialEq for PassportAuthorizationForm {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.required_elements == other.required_elements
+            && self.privacy_policy_url == other.privacy_policy_url
+    }
+}
+
+impl Eq for PassportAuthorizationForm {}
+
+impl std::hash::Hash for PassportAuthorizationForm {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.id, state);
+        std::hash::Hash::hash(&self.required_elements, state);
+        std::hash::Hash::hash(&self.privacy_policy_url, state);
+    }
+}
+
 impl PassportAuthorizationForm {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPassportAuthorizationFormBuilder {
-        let mut inner = PassportAuthorizationForm::default();
-        inner.td_name = "passportAuthorizationForm".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPassportAuthorizationFormBuilder { inner }
+        RTDPassportAuthorizationFormBuilder {
+            inner: PassportAuthorizationForm::default(),
+            missing: vec!["id", "required_elements"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPassportAuthorizationFormBuilder {
+        RTDPassportAuthorizationFormBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPassportAuthorizationFormBuilder {
+        RTDPassportAuthorizationFormBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn id(&self) -> i64 {
+    pub fn id(&self) -> i32 {
         self.id
     }
 
@@ -55,20 +87,60 @@ impl PassportAuthorizationForm {
     pub fn privacy_policy_url(&self) -> &String {
         &self.privacy_policy_url
     }
+
+    pub fn into_required_elements(self) -> Vec<PassportRequiredElement> {
+        self.required_elements
+    }
+
+    pub fn into_privacy_policy_url(self) -> String {
+        self.privacy_policy_url
+    }
+
+    pub fn with_id(mut self, id: i32) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn with_required_elements(
+        mut self,
+        required_elements: Vec<PassportRequiredElement>,
+    ) -> Self {
+        self.required_elements = required_elements;
+        self
+    }
+
+    pub fn with_privacy_policy_url<T: AsRef<str>>(mut self, privacy_policy_url: T) -> Self {
+        self.privacy_policy_url = privacy_policy_url.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPassportAuthorizationFormBuilder {
     inner: PassportAuthorizationForm,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPassportAuthorizationFormBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PassportAuthorizationForm {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PassportAuthorizationForm> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PassportAuthorizationForm",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn id(&mut self, id: i64) -> &mut Self {
+    pub fn id(&mut self, id: i32) -> &mut Self {
         self.inner.id = id;
+        self.missing.retain(|f| *f != "id");
         self
     }
 
@@ -77,6 +149,7 @@ impl RTDPassportAuthorizationFormBuilder {
         required_elements: Vec<PassportRequiredElement>,
     ) -> &mut Self {
         self.inner.required_elements = required_elements;
+        self.missing.retain(|f| *f != "required_elements");
         self
     }
 
@@ -97,3 +170,20 @@ impl AsRef<PassportAuthorizationForm> for RTDPassportAuthorizationFormBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            PassportAuthorizationForm::builder()
+                .id(i32::MAX)
+                .required_elements(vec![])
+                .privacy_policy_url("text")
+                .build(),
+        );
+    }
+}
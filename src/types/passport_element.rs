@@ -1,82 +1,84 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Contains information about a Telegram Passport element
 pub trait TDPassportElement: Debug + RObject {}
 
 /// Contains information about a Telegram Passport element
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum PassportElement {
-    #[doc(hidden)]
-    _Default(()),
-    /// Returns one of the available Telegram Passport elements
-    GetPassportElement(GetPassportElement),
     /// A Telegram Passport element containing the user's address
+    #[serde(rename = "passportElementAddress")]
     Address(PassportElementAddress),
     /// A Telegram Passport element containing the user's bank statement
+    #[serde(rename = "passportElementBankStatement")]
     BankStatement(PassportElementBankStatement),
     /// A Telegram Passport element containing the user's driver license
+    #[serde(rename = "passportElementDriverLicense")]
     DriverLicense(PassportElementDriverLicense),
     /// A Telegram Passport element containing the user's email address
+    #[serde(rename = "passportElementEmailAddress")]
     EmailAddress(PassportElementEmailAddress),
     /// A Telegram Passport element containing the user's identity card
+    #[serde(rename = "passportElementIdentityCard")]
     IdentityCard(PassportElementIdentityCard),
     /// A Telegram Passport element containing the user's internal passport
+    #[serde(rename = "passportElementInternalPassport")]
     InternalPassport(PassportElementInternalPassport),
     /// A Telegram Passport element containing the user's passport
+    #[serde(rename = "passportElementPassport")]
     Passport(PassportElementPassport),
     /// A Telegram Passport element containing the user's passport registration pages
+    #[serde(rename = "passportElementPassportRegistration")]
     PassportRegistration(PassportElementPassportRegistration),
     /// A Telegram Passport element containing the user's personal details
+    #[serde(rename = "passportElementPersonalDetails")]
     PersonalDetails(PassportElementPersonalDetails),
     /// A Telegram Passport element containing the user's phone number
+    #[serde(rename = "passportElementPhoneNumber")]
     PhoneNumber(PassportElementPhoneNumber),
     /// A Telegram Passport element containing the user's rental agreement
+    #[serde(rename = "passportElementRentalAgreement")]
     RentalAgreement(PassportElementRentalAgreement),
     /// A Telegram Passport element containing the user's temporary registration
+    #[serde(rename = "passportElementTemporaryRegistration")]
     TemporaryRegistration(PassportElementTemporaryRegistration),
     /// A Telegram Passport element containing the user's utility bill
+    #[serde(rename = "passportElementUtilityBill")]
     UtilityBill(PassportElementUtilityBill),
-    /// Adds an element to the user's Telegram Passport. May return an error with a message "PHONE_VERIFICATION_NEEDED" or "EMAIL_VERIFICATION_NEEDED" if the chosen phone number or the chosen email address must be verified first
-    SetPassportElement(SetPassportElement),
 }
 
+/// Default value is default of [PassportElementEmailAddress], builders still require the field to be set
 impl Default for PassportElement {
     fn default() -> Self {
-        PassportElement::_Default(())
+        PassportElement::EmailAddress(PassportElementEmailAddress::default())
     }
 }
 
-impl<'de> Deserialize<'de> for PassportElement {
-    fn deserialize<D>(deserializer: D) -> Result<PassportElement, D::Error>
+impl Serialize for PassportElement {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          PassportElement,
-          (getPassportElement, GetPassportElement);
-          (passportElementAddress, Address);
-          (passportElementBankStatement, BankStatement);
-          (passportElementDriverLicense, DriverLicense);
-          (passportElementEmailAddress, EmailAddress);
-          (passportElementIdentityCard, IdentityCard);
-          (passportElementInternalPassport, InternalPassport);
-          (passportElementPassport, Passport);
-          (passportElementPassportRegistration, PassportRegistration);
-          (passportElementPersonalDetails, PersonalDetails);
-          (passportElementPhoneNumber, PhoneNumber);
-          (passportElementRentalAgreement, RentalAgreement);
-          (passportElementTemporaryRegistration, TemporaryRegistration);
-          (passportElementUtilityBill, UtilityBill);
-          (setPassportElement, SetPassportElement);
-
-        )(deserializer)
+        match self {
+            PassportElement::Address(t) => t.serialize(serializer),
+            PassportElement::BankStatement(t) => t.serialize(serializer),
+            PassportElement::DriverLicense(t) => t.serialize(serializer),
+            PassportElement::EmailAddress(t) => t.serialize(serializer),
+            PassportElement::IdentityCard(t) => t.serialize(serializer),
+            PassportElement::InternalPassport(t) => t.serialize(serializer),
+            PassportElement::Passport(t) => t.serialize(serializer),
+            PassportElement::PassportRegistration(t) => t.serialize(serializer),
+            PassportElement::PersonalDetails(t) => t.serialize(serializer),
+            PassportElement::PhoneNumber(t) => t.serialize(serializer),
+            PassportElement::RentalAgreement(t) => t.serialize(serializer),
+            PassportElement::TemporaryRegistration(t) => t.serialize(serializer),
+            PassportElement::UtilityBill(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -84,7 +86,6 @@ impl RObject for PassportElement {
     #[doc(hidden)]
     fn td_name(&self) -> &'static str {
         match self {
-            PassportElement::GetPassportElement(t) => t.td_name(),
             PassportElement::Address(t) => t.td_name(),
             PassportElement::BankStatement(t) => t.td_name(),
             PassportElement::DriverLicense(t) => t.td_name(),
@@ -98,15 +99,11 @@ impl RObject for PassportElement {
             PassportElement::RentalAgreement(t) => t.td_name(),
             PassportElement::TemporaryRegistration(t) => t.td_name(),
             PassportElement::UtilityBill(t) => t.td_name(),
-            PassportElement::SetPassportElement(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
     fn extra(&self) -> Option<String> {
         match self {
-            PassportElement::GetPassportElement(t) => t.extra(),
             PassportElement::Address(t) => t.extra(),
             PassportElement::BankStatement(t) => t.extra(),
             PassportElement::DriverLicense(t) => t.extra(),
@@ -120,9 +117,6 @@ impl RObject for PassportElement {
             PassportElement::RentalAgreement(t) => t.extra(),
             PassportElement::TemporaryRegistration(t) => t.extra(),
             PassportElement::UtilityBill(t) => t.extra(),
-            PassportElement::SetPassportElement(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -134,10 +128,6 @@ impl PassportElement {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, PassportElement::_Default(_))
-    }
 }
 
 impl AsRef<PassportElement> for PassportElement {
@@ -148,12 +138,13 @@ impl AsRef<PassportElement> for PassportElement {
 
 /// A Telegram Passport element containing the user's address
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "passportElementAddress")]
 pub struct PassportElementAddress {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Address
     address: Address,
@@ -173,6 +164,20 @@ impl RObject for PassportElementAddress {
     }
 }
 
+impl PartialEq for PassportElementAddress {
+    fn eq(&self, other: &Self) -> bool {
+        self.address == other.address
+    }
+}
+
+impl Eq for PassportElementAddress {}
+
+impl std::hash::Hash for PassportElementAddress {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.address, state);
+    }
+}
+
 impl TDPassportElement for PassportElementAddress {}
 
 impl PassportElementAddress {
@@ -180,29 +185,66 @@ impl PassportElementAddress {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPassportElementAddressBuilder {
-        let mut inner = PassportElementAddress::default();
-        inner.td_name = "passportElementAddress".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPassportElementAddressBuilder { inner }
+        RTDPassportElementAddressBuilder {
+            inner: PassportElementAddress::default(),
+            missing: vec!["address"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPassportElementAddressBuilder {
+        RTDPassportElementAddressBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPassportElementAddressBuilder {
+        RTDPassportElementAddressBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn address(&self) -> &Address {
         &self.address
     }
+
+    pub fn into_address(self) -> Address {
+        self.address
+    }
+
+    pub fn with_address<T: AsRef<Address>>(mut self, address: T) -> Self {
+        self.address = address.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPassportElementAddressBuilder {
     inner: PassportElementAddress,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPassportElementAddressBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PassportElementAddress {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PassportElementAddress> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PassportElementAddress",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn address<T: AsRef<Address>>(&mut self, address: T) -> &mut Self {
         self.inner.address = address.as_ref().clone();
+        self.missing.retain(|f| *f != "address");
         self
     }
 }
@@ -221,12 +263,13 @@ impl AsRef<PassportElementAddress> for RTDPassportElementAddressBuilder {
 
 /// A Telegram Passport element containing the user's bank statement
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "passportElementBankStatement")]
 pub struct PassportElementBankStatement {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Bank statement
     bank_statement: PersonalDocument,
@@ -246,6 +289,20 @@ impl RObject for PassportElementBankStatement {
     }
 }
 
+impl PartialEq for PassportElementBankStatement {
+    fn eq(&self, other: &Self) -> bool {
+        self.bank_statement == other.bank_statement
+    }
+}
+
+impl Eq for PassportElementBankStatement {}
+
+impl std::hash::Hash for PassportElementBankStatement {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.bank_statement, state);
+    }
+}
+
 impl TDPassportElement for PassportElementBankStatement {}
 
 impl PassportElementBankStatement {
@@ -253,29 +310,66 @@ impl PassportElementBankStatement {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPassportElementBankStatementBuilder {
-        let mut inner = PassportElementBankStatement::default();
-        inner.td_name = "passportElementBankStatement".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPassportElementBankStatementBuilder { inner }
+        RTDPassportElementBankStatementBuilder {
+            inner: PassportElementBankStatement::default(),
+            missing: vec!["bank_statement"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPassportElementBankStatementBuilder {
+        RTDPassportElementBankStatementBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPassportElementBankStatementBuilder {
+        RTDPassportElementBankStatementBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn bank_statement(&self) -> &PersonalDocument {
         &self.bank_statement
     }
+
+    pub fn into_bank_statement(self) -> PersonalDocument {
+        self.bank_statement
+    }
+
+    pub fn with_bank_statement<T: AsRef<PersonalDocument>>(mut self, bank_statement: T) -> Self {
+        self.bank_statement = bank_statement.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPassportElementBankStatementBuilder {
     inner: PassportElementBankStatement,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPassportElementBankStatementBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PassportElementBankStatement {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PassportElementBankStatement> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PassportElementBankStatement",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn bank_statement<T: AsRef<PersonalDocument>>(&mut self, bank_statement: T) -> &mut Self {
         self.inner.bank_statement = bank_statement.as_ref().clone();
+        self.missing.retain(|f| *f != "bank_statement");
         self
     }
 }
@@ -294,12 +388,13 @@ impl AsRef<PassportElementBankStatement> for RTDPassportElementBankStatementBuil
 
 /// A Telegram Passport element containing the user's driver license
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "passportElementDriverLicense")]
 pub struct PassportElementDriverLicense {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Driver license
     driver_license: IdentityDocument,
@@ -319,6 +414,20 @@ impl RObject for PassportElementDriverLicense {
     }
 }
 
+impl PartialEq for PassportElementDriverLicense {
+    fn eq(&self, other: &Self) -> bool {
+        self.driver_license == other.driver_license
+    }
+}
+
+impl Eq for PassportElementDriverLicense {}
+
+impl std::hash::Hash for PassportElementDriverLicense {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.driver_license, state);
+    }
+}
+
 impl TDPassportElement for PassportElementDriverLicense {}
 
 impl PassportElementDriverLicense {
@@ -326,29 +435,66 @@ impl PassportElementDriverLicense {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPassportElementDriverLicenseBuilder {
-        let mut inner = PassportElementDriverLicense::default();
-        inner.td_name = "passportElementDriverLicense".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPassportElementDriverLicenseBuilder { inner }
+        RTDPassportElementDriverLicenseBuilder {
+            inner: PassportElementDriverLicense::default(),
+            missing: vec!["driver_license"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPassportElementDriverLicenseBuilder {
+        RTDPassportElementDriverLicenseBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPassportElementDriverLicenseBuilder {
+        RTDPassportElementDriverLicenseBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn driver_license(&self) -> &IdentityDocument {
         &self.driver_license
     }
+
+    pub fn into_driver_license(self) -> IdentityDocument {
+        self.driver_license
+    }
+
+    pub fn with_driver_license<T: AsRef<IdentityDocument>>(mut self, driver_license: T) -> Self {
+        self.driver_license = driver_license.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPassportElementDriverLicenseBuilder {
     inner: PassportElementDriverLicense,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPassportElementDriverLicenseBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PassportElementDriverLicense {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PassportElementDriverLicense> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PassportElementDriverLicense",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn driver_license<T: AsRef<IdentityDocument>>(&mut self, driver_license: T) -> &mut Self {
         self.inner.driver_license = driver_license.as_ref().clone();
+        self.missing.retain(|f| *f != "driver_license");
         self
     }
 }
@@ -367,12 +513,13 @@ impl AsRef<PassportElementDriverLicense> for RTDPassportElementDriverLicenseBuil
 
 /// A Telegram Passport element containing the user's email address
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "passportElementEmailAddress")]
 pub struct PassportElementEmailAddress {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Email address
     email_address: String,
@@ -392,6 +539,20 @@ impl RObject for PassportElementEmailAddress {
     }
 }
 
+impl PartialEq for PassportElementEmailAddress {
+    fn eq(&self, other: &Self) -> bool {
+        self.email_address == other.email_address
+    }
+}
+
+impl Eq for PassportElementEmailAddress {}
+
+impl std::hash::Hash for PassportElementEmailAddress {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.email_address, state);
+    }
+}
+
 impl TDPassportElement for PassportElementEmailAddress {}
 
 impl PassportElementEmailAddress {
@@ -399,29 +560,66 @@ impl PassportElementEmailAddress {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPassportElementEmailAddressBuilder {
-        let mut inner = PassportElementEmailAddress::default();
-        inner.td_name = "passportElementEmailAddress".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPassportElementEmailAddressBuilder { inner }
+        RTDPassportElementEmailAddressBuilder {
+            inner: PassportElementEmailAddress::default(),
+            missing: vec!["email_address"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPassportElementEmailAddressBuilder {
+        RTDPassportElementEmailAddressBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPassportElementEmailAddressBuilder {
+        RTDPassportElementEmailAddressBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn email_address(&self) -> &String {
         &self.email_address
     }
+
+    pub fn into_email_address(self) -> String {
+        self.email_address
+    }
+
+    pub fn with_email_address<T: AsRef<str>>(mut self, email_address: T) -> Self {
+        self.email_address = email_address.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPassportElementEmailAddressBuilder {
     inner: PassportElementEmailAddress,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPassportElementEmailAddressBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PassportElementEmailAddress {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PassportElementEmailAddress> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PassportElementEmailAddress",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn email_address<T: AsRef<str>>(&mut self, email_address: T) -> &mut Self {
         self.inner.email_address = email_address.as_ref().to_string();
+        self.missing.retain(|f| *f != "email_address");
         self
     }
 }
@@ -440,12 +638,13 @@ impl AsRef<PassportElementEmailAddress> for RTDPassportElementEmailAddressBuilde
 
 /// A Telegram Passport element containing the user's identity card
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "passportElementIdentityCard")]
 pub struct PassportElementIdentityCard {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Identity card
     identity_card: IdentityDocument,
@@ -465,6 +664,20 @@ impl RObject for PassportElementIdentityCard {
     }
 }
 
+impl PartialEq for PassportElementIdentityCard {
+    fn eq(&self, other: &Self) -> bool {
+        self.identity_card == other.identity_card
+    }
+}
+
+impl Eq for PassportElementIdentityCard {}
+
+impl std::hash::Hash for PassportElementIdentityCard {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.identity_card, state);
+    }
+}
+
 impl TDPassportElement for PassportElementIdentityCard {}
 
 impl PassportElementIdentityCard {
@@ -472,29 +685,66 @@ impl PassportElementIdentityCard {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPassportElementIdentityCardBuilder {
-        let mut inner = PassportElementIdentityCard::default();
-        inner.td_name = "passportElementIdentityCard".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPassportElementIdentityCardBuilder { inner }
+        RTDPassportElementIdentityCardBuilder {
+            inner: PassportElementIdentityCard::default(),
+            missing: vec!["identity_card"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPassportElementIdentityCardBuilder {
+        RTDPassportElementIdentityCardBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPassportElementIdentityCardBuilder {
+        RTDPassportElementIdentityCardBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn identity_card(&self) -> &IdentityDocument {
         &self.identity_card
     }
+
+    pub fn into_identity_card(self) -> IdentityDocument {
+        self.identity_card
+    }
+
+    pub fn with_identity_card<T: AsRef<IdentityDocument>>(mut self, identity_card: T) -> Self {
+        self.identity_card = identity_card.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPassportElementIdentityCardBuilder {
     inner: PassportElementIdentityCard,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPassportElementIdentityCardBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PassportElementIdentityCard {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PassportElementIdentityCard> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PassportElementIdentityCard",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn identity_card<T: AsRef<IdentityDocument>>(&mut self, identity_card: T) -> &mut Self {
         self.inner.identity_card = identity_card.as_ref().clone();
+        self.missing.retain(|f| *f != "identity_card");
         self
     }
 }
@@ -513,12 +763,13 @@ impl AsRef<PassportElementIdentityCard> for RTDPassportElementIdentityCardBuilde
 
 /// A Telegram Passport element containing the user's internal passport
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "passportElementInternalPassport")]
 pub struct PassportElementInternalPassport {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Internal passport
     internal_passport: IdentityDocument,
@@ -538,6 +789,20 @@ impl RObject for PassportElementInternalPassport {
     }
 }
 
+impl PartialEq for PassportElementInternalPassport {
+    fn eq(&self, other: &Self) -> bool {
+        self.internal_passport == other.internal_passport
+    }
+}
+
+impl Eq for PassportElementInternalPassport {}
+
+impl std::hash::Hash for PassportElementInternalPassport {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.internal_passport, state);
+    }
+}
+
 impl TDPassportElement for PassportElementInternalPassport {}
 
 impl PassportElementInternalPassport {
@@ -545,32 +810,72 @@ impl PassportElementInternalPassport {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPassportElementInternalPassportBuilder {
-        let mut inner = PassportElementInternalPassport::default();
-        inner.td_name = "passportElementInternalPassport".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPassportElementInternalPassportBuilder { inner }
+        RTDPassportElementInternalPassportBuilder {
+            inner: PassportElementInternalPassport::default(),
+            missing: vec!["internal_passport"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPassportElementInternalPassportBuilder {
+        RTDPassportElementInternalPassportBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPassportElementInternalPassportBuilder {
+        RTDPassportElementInternalPassportBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn internal_passport(&self) -> &IdentityDocument {
         &self.internal_passport
     }
+
+    pub fn into_internal_passport(self) -> IdentityDocument {
+        self.internal_passport
+    }
+
+    pub fn with_internal_passport<T: AsRef<IdentityDocument>>(
+        mut self,
+        internal_passport: T,
+    ) -> Self {
+        self.internal_passport = internal_passport.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPassportElementInternalPassportBuilder {
     inner: PassportElementInternalPassport,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPassportElementInternalPassportBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PassportElementInternalPassport {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PassportElementInternalPassport> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PassportElementInternalPassport",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn internal_passport<T: AsRef<IdentityDocument>>(
         &mut self,
         internal_passport: T,
     ) -> &mut Self {
         self.inner.internal_passport = internal_passport.as_ref().clone();
+        self.missing.retain(|f| *f != "internal_passport");
         self
     }
 }
@@ -589,12 +894,13 @@ impl AsRef<PassportElementInternalPassport> for RTDPassportElementInternalPasspo
 
 /// A Telegram Passport element containing the user's passport
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "passportElementPassport")]
 pub struct PassportElementPassport {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Passport
     passport: IdentityDocument,
@@ -614,6 +920,20 @@ impl RObject for PassportElementPassport {
     }
 }
 
+impl PartialEq for PassportElementPassport {
+    fn eq(&self, other: &Self) -> bool {
+        self.passport == other.passport
+    }
+}
+
+impl Eq for PassportElementPassport {}
+
+impl std::hash::Hash for PassportElementPassport {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.passport, state);
+    }
+}
+
 impl TDPassportElement for PassportElementPassport {}
 
 impl PassportElementPassport {
@@ -621,29 +941,66 @@ impl PassportElementPassport {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPassportElementPassportBuilder {
-        let mut inner = PassportElementPassport::default();
-        inner.td_name = "passportElementPassport".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPassportElementPassportBuilder { inner }
+        RTDPassportElementPassportBuilder {
+            inner: PassportElementPassport::default(),
+            missing: vec!["passport"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPassportElementPassportBuilder {
+        RTDPassportElementPassportBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPassportElementPassportBuilder {
+        RTDPassportElementPassportBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn passport(&self) -> &IdentityDocument {
         &self.passport
     }
+
+    pub fn into_passport(self) -> IdentityDocument {
+        self.passport
+    }
+
+    pub fn with_passport<T: AsRef<IdentityDocument>>(mut self, passport: T) -> Self {
+        self.passport = passport.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPassportElementPassportBuilder {
     inner: PassportElementPassport,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPassportElementPassportBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PassportElementPassport {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PassportElementPassport> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PassportElementPassport",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn passport<T: AsRef<IdentityDocument>>(&mut self, passport: T) -> &mut Self {
         self.inner.passport = passport.as_ref().clone();
+        self.missing.retain(|f| *f != "passport");
         self
     }
 }
@@ -662,12 +1019,13 @@ impl AsRef<PassportElementPassport> for RTDPassportElementPassportBuilder {
 
 /// A Telegram Passport element containing the user's passport registration pages
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "passportElementPassportRegistration")]
 pub struct PassportElementPassportRegistration {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Passport registration pages
     passport_registration: PersonalDocument,
@@ -687,6 +1045,20 @@ impl RObject for PassportElementPassportRegistration {
     }
 }
 
+impl PartialEq for PassportElementPassportRegistration {
+    fn eq(&self, other: &Self) -> bool {
+        self.passport_registration == other.passport_registration
+    }
+}
+
+impl Eq for PassportElementPassportRegistration {}
+
+impl std::hash::Hash for PassportElementPassportRegistration {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.passport_registration, state);
+    }
+}
+
 impl TDPassportElement for PassportElementPassportRegistration {}
 
 impl PassportElementPassportRegistration {
@@ -694,32 +1066,72 @@ impl PassportElementPassportRegistration {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPassportElementPassportRegistrationBuilder {
-        let mut inner = PassportElementPassportRegistration::default();
-        inner.td_name = "passportElementPassportRegistration".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPassportElementPassportRegistrationBuilder { inner }
+        RTDPassportElementPassportRegistrationBuilder {
+            inner: PassportElementPassportRegistration::default(),
+            missing: vec!["passport_registration"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPassportElementPassportRegistrationBuilder {
+        RTDPassportElementPassportRegistrationBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPassportElementPassportRegistrationBuilder {
+        RTDPassportElementPassportRegistrationBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn passport_registration(&self) -> &PersonalDocument {
         &self.passport_registration
     }
+
+    pub fn into_passport_registration(self) -> PersonalDocument {
+        self.passport_registration
+    }
+
+    pub fn with_passport_registration<T: AsRef<PersonalDocument>>(
+        mut self,
+        passport_registration: T,
+    ) -> Self {
+        self.passport_registration = passport_registration.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPassportElementPassportRegistrationBuilder {
     inner: PassportElementPassportRegistration,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPassportElementPassportRegistrationBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PassportElementPassportRegistration {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PassportElementPassportRegistration> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PassportElementPassportRegistration",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn passport_registration<T: AsRef<PersonalDocument>>(
         &mut self,
         passport_registration: T,
     ) -> &mut Self {
         self.inner.passport_registration = passport_registration.as_ref().clone();
+        self.missing.retain(|f| *f != "passport_registration");
         self
     }
 }
@@ -738,12 +1150,13 @@ impl AsRef<PassportElementPassportRegistration> for RTDPassportElementPassportRe
 
 /// A Telegram Passport element containing the user's personal details
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "passportElementPersonalDetails")]
 pub struct PassportElementPersonalDetails {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Personal details of the user
     personal_details: PersonalDetails,
@@ -763,6 +1176,20 @@ impl RObject for PassportElementPersonalDetails {
     }
 }
 
+impl PartialEq for PassportElementPersonalDetails {
+    fn eq(&self, other: &Self) -> bool {
+        self.personal_details == other.personal_details
+    }
+}
+
+impl Eq for PassportElementPersonalDetails {}
+
+impl std::hash::Hash for PassportElementPersonalDetails {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.personal_details, state);
+    }
+}
+
 impl TDPassportElement for PassportElementPersonalDetails {}
 
 impl PassportElementPersonalDetails {
@@ -770,32 +1197,69 @@ impl PassportElementPersonalDetails {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPassportElementPersonalDetailsBuilder {
-        let mut inner = PassportElementPersonalDetails::default();
-        inner.td_name = "passportElementPersonalDetails".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPassportElementPersonalDetailsBuilder { inner }
+        RTDPassportElementPersonalDetailsBuilder {
+            inner: PassportElementPersonalDetails::default(),
+            missing: vec!["personal_details"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPassportElementPersonalDetailsBuilder {
+        RTDPassportElementPersonalDetailsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPassportElementPersonalDetailsBuilder {
+        RTDPassportElementPersonalDetailsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn personal_details(&self) -> &PersonalDetails {
         &self.personal_details
     }
+
+    pub fn into_personal_details(self) -> PersonalDetails {
+        self.personal_details
+    }
+
+    pub fn with_personal_details<T: AsRef<PersonalDetails>>(mut self, personal_details: T) -> Self {
+        self.personal_details = personal_details.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPassportElementPersonalDetailsBuilder {
     inner: PassportElementPersonalDetails,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPassportElementPersonalDetailsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PassportElementPersonalDetails {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PassportElementPersonalDetails> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PassportElementPersonalDetails",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn personal_details<T: AsRef<PersonalDetails>>(
         &mut self,
         personal_details: T,
     ) -> &mut Self {
         self.inner.personal_details = personal_details.as_ref().clone();
+        self.missing.retain(|f| *f != "personal_details");
         self
     }
 }
@@ -814,12 +1278,13 @@ impl AsRef<PassportElementPersonalDetails> for RTDPassportElementPersonalDetails
 
 /// A Telegram Passport element containing the user's phone number
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "passportElementPhoneNumber")]
 pub struct PassportElementPhoneNumber {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Phone number
     phone_number: String,
@@ -839,6 +1304,20 @@ impl RObject for PassportElementPhoneNumber {
     }
 }
 
+impl PartialEq for PassportElementPhoneNumber {
+    fn eq(&self, other: &Self) -> bool {
+        self.phone_number == other.phone_number
+    }
+}
+
+impl Eq for PassportElementPhoneNumber {}
+
+impl std::hash::Hash for PassportElementPhoneNumber {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.phone_number, state);
+    }
+}
+
 impl TDPassportElement for PassportElementPhoneNumber {}
 
 impl PassportElementPhoneNumber {
@@ -846,29 +1325,66 @@ impl PassportElementPhoneNumber {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPassportElementPhoneNumberBuilder {
-        let mut inner = PassportElementPhoneNumber::default();
-        inner.td_name = "passportElementPhoneNumber".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPassportElementPhoneNumberBuilder { inner }
+        RTDPassportElementPhoneNumberBuilder {
+            inner: PassportElementPhoneNumber::default(),
+            missing: vec!["phone_number"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPassportElementPhoneNumberBuilder {
+        RTDPassportElementPhoneNumberBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPassportElementPhoneNumberBuilder {
+        RTDPassportElementPhoneNumberBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn phone_number(&self) -> &String {
         &self.phone_number
     }
+
+    pub fn into_phone_number(self) -> String {
+        self.phone_number
+    }
+
+    pub fn with_phone_number<T: AsRef<str>>(mut self, phone_number: T) -> Self {
+        self.phone_number = phone_number.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPassportElementPhoneNumberBuilder {
     inner: PassportElementPhoneNumber,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPassportElementPhoneNumberBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PassportElementPhoneNumber {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PassportElementPhoneNumber> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PassportElementPhoneNumber",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn phone_number<T: AsRef<str>>(&mut self, phone_number: T) -> &mut Self {
         self.inner.phone_number = phone_number.as_ref().to_string();
+        self.missing.retain(|f| *f != "phone_number");
         self
     }
 }
@@ -887,12 +1403,13 @@ impl AsRef<PassportElementPhoneNumber> for RTDPassportElementPhoneNumberBuilder
 
 /// A Telegram Passport element containing the user's rental agreement
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "passportElementRentalAgreement")]
 pub struct PassportElementRentalAgreement {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Rental agreement
     rental_agreement: PersonalDocument,
@@ -912,6 +1429,20 @@ impl RObject for PassportElementRentalAgreement {
     }
 }
 
+impl PartialEq for PassportElementRentalAgreement {
+    fn eq(&self, other: &Self) -> bool {
+        self.rental_agreement == other.rental_agreement
+    }
+}
+
+impl Eq for PassportElementRentalAgreement {}
+
+impl std::hash::Hash for PassportElementRentalAgreement {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.rental_agreement, state);
+    }
+}
+
 impl TDPassportElement for PassportElementRentalAgreement {}
 
 impl PassportElementRentalAgreement {
@@ -919,32 +1450,72 @@ impl PassportElementRentalAgreement {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPassportElementRentalAgreementBuilder {
-        let mut inner = PassportElementRentalAgreement::default();
-        inner.td_name = "passportElementRentalAgreement".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPassportElementRentalAgreementBuilder { inner }
+        RTDPassportElementRentalAgreementBuilder {
+            inner: PassportElementRentalAgreement::default(),
+            missing: vec!["rental_agreement"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPassportElementRentalAgreementBuilder {
+        RTDPassportElementRentalAgreementBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPassportElementRentalAgreementBuilder {
+        RTDPassportElementRentalAgreementBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn rental_agreement(&self) -> &PersonalDocument {
         &self.rental_agreement
     }
+
+    pub fn into_rental_agreement(self) -> PersonalDocument {
+        self.rental_agreement
+    }
+
+    pub fn with_rental_agreement<T: AsRef<PersonalDocument>>(
+        mut self,
+        rental_agreement: T,
+    ) -> Self {
+        self.rental_agreement = rental_agreement.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPassportElementRentalAgreementBuilder {
     inner: PassportElementRentalAgreement,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPassportElementRentalAgreementBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PassportElementRentalAgreement {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PassportElementRentalAgreement> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PassportElementRentalAgreement",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn rental_agreement<T: AsRef<PersonalDocument>>(
         &mut self,
         rental_agreement: T,
     ) -> &mut Self {
         self.inner.rental_agreement = rental_agreement.as_ref().clone();
+        self.missing.retain(|f| *f != "rental_agreement");
         self
     }
 }
@@ -963,12 +1534,13 @@ impl AsRef<PassportElementRentalAgreement> for RTDPassportElementRentalAgreement
 
 /// A Telegram Passport element containing the user's temporary registration
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "passportElementTemporaryRegistration")]
 pub struct PassportElementTemporaryRegistration {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Temporary registration
     temporary_registration: PersonalDocument,
@@ -988,6 +1560,20 @@ impl RObject for PassportElementTemporaryRegistration {
     }
 }
 
+impl PartialEq for PassportElementTemporaryRegistration {
+    fn eq(&self, other: &Self) -> bool {
+        self.temporary_registration == other.temporary_registration
+    }
+}
+
+impl Eq for PassportElementTemporaryRegistration {}
+
+impl std::hash::Hash for PassportElementTemporaryRegistration {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.temporary_registration, state);
+    }
+}
+
 impl TDPassportElement for PassportElementTemporaryRegistration {}
 
 impl PassportElementTemporaryRegistration {
@@ -995,32 +1581,72 @@ impl PassportElementTemporaryRegistration {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPassportElementTemporaryRegistrationBuilder {
-        let mut inner = PassportElementTemporaryRegistration::default();
-        inner.td_name = "passportElementTemporaryRegistration".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPassportElementTemporaryRegistrationBuilder { inner }
+        RTDPassportElementTemporaryRegistrationBuilder {
+            inner: PassportElementTemporaryRegistration::default(),
+            missing: vec!["temporary_registration"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPassportElementTemporaryRegistrationBuilder {
+        RTDPassportElementTemporaryRegistrationBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPassportElementTemporaryRegistrationBuilder {
+        RTDPassportElementTemporaryRegistrationBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn temporary_registration(&self) -> &PersonalDocument {
         &self.temporary_registration
     }
+
+    pub fn into_temporary_registration(self) -> PersonalDocument {
+        self.temporary_registration
+    }
+
+    pub fn with_temporary_registration<T: AsRef<PersonalDocument>>(
+        mut self,
+        temporary_registration: T,
+    ) -> Self {
+        self.temporary_registration = temporary_registration.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPassportElementTemporaryRegistrationBuilder {
     inner: PassportElementTemporaryRegistration,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPassportElementTemporaryRegistrationBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PassportElementTemporaryRegistration {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PassportElementTemporaryRegistration> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PassportElementTemporaryRegistration",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn temporary_registration<T: AsRef<PersonalDocument>>(
         &mut self,
         temporary_registration: T,
     ) -> &mut Self {
         self.inner.temporary_registration = temporary_registration.as_ref().clone();
+        self.missing.retain(|f| *f != "temporary_registration");
         self
     }
 }
@@ -1041,12 +1667,13 @@ impl AsRef<PassportElementTemporaryRegistration>
 
 /// A Telegram Passport element containing the user's utility bill
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "passportElementUtilityBill")]
 pub struct PassportElementUtilityBill {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Utility bill
     utility_bill: PersonalDocument,
@@ -1066,6 +1693,20 @@ impl RObject for PassportElementUtilityBill {
     }
 }
 
+impl PartialEq for PassportElementUtilityBill {
+    fn eq(&self, other: &Self) -> bool {
+        self.utility_bill == other.utility_bill
+    }
+}
+
+impl Eq for PassportElementUtilityBill {}
+
+impl std::hash::Hash for PassportElementUtilityBill {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.utility_bill, state);
+    }
+}
+
 impl TDPassportElement for PassportElementUtilityBill {}
 
 impl PassportElementUtilityBill {
@@ -1073,29 +1714,66 @@ impl PassportElementUtilityBill {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPassportElementUtilityBillBuilder {
-        let mut inner = PassportElementUtilityBill::default();
-        inner.td_name = "passportElementUtilityBill".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPassportElementUtilityBillBuilder { inner }
+        RTDPassportElementUtilityBillBuilder {
+            inner: PassportElementUtilityBill::default(),
+            missing: vec!["utility_bill"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPassportElementUtilityBillBuilder {
+        RTDPassportElementUtilityBillBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPassportElementUtilityBillBuilder {
+        RTDPassportElementUtilityBillBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn utility_bill(&self) -> &PersonalDocument {
         &self.utility_bill
     }
+
+    pub fn into_utility_bill(self) -> PersonalDocument {
+        self.utility_bill
+    }
+
+    pub fn with_utility_bill<T: AsRef<PersonalDocument>>(mut self, utility_bill: T) -> Self {
+        self.utility_bill = utility_bill.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPassportElementUtilityBillBuilder {
     inner: PassportElementUtilityBill,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPassportElementUtilityBillBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PassportElementUtilityBill {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PassportElementUtilityBill> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PassportElementUtilityBill",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn utility_bill<T: AsRef<PersonalDocument>>(&mut self, utility_bill: T) -> &mut Self {
         self.inner.utility_bill = utility_bill.as_ref().clone();
+        self.missing.retain(|f| *f != "utility_bill");
         self
     }
 }
@@ -1111,3 +1789,415 @@ impl AsRef<PassportElementUtilityBill> for RTDPassportElementUtilityBillBuilder
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(PassportElement::Address(
+            PassportElementAddress::builder()
+                .address(
+                    Address::builder()
+                        .country_code("text")
+                        .state("text")
+                        .city("text")
+                        .street_line1("text")
+                        .street_line2("text")
+                        .postal_code("text")
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(PassportElement::BankStatement(
+            PassportElementBankStatement::builder()
+                .bank_statement(
+                    PersonalDocument::builder()
+                        .files(vec![])
+                        .translation(vec![])
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(PassportElement::DriverLicense(
+            PassportElementDriverLicense::builder()
+                .driver_license(
+                    IdentityDocument::builder()
+                        .number("text")
+                        .front_side(
+                            DatedFile::builder()
+                                .file(
+                                    File::builder()
+                                        .id(i32::MAX)
+                                        .size(i32::MAX)
+                                        .expected_size(i32::MAX)
+                                        .local(
+                                            LocalFile::builder()
+                                                .path("text")
+                                                .can_be_downloaded(true)
+                                                .can_be_deleted(true)
+                                                .is_downloading_active(true)
+                                                .is_downloading_completed(true)
+                                                .download_offset(i32::MAX)
+                                                .downloaded_prefix_size(i32::MAX)
+                                                .downloaded_size(i32::MAX)
+                                                .build(),
+                                        )
+                                        .remote(
+                                            RemoteFile::builder()
+                                                .id("text")
+                                                .unique_id("text")
+                                                .is_uploading_active(true)
+                                                .is_uploading_completed(true)
+                                                .uploaded_size(i32::MAX)
+                                                .build(),
+                                        )
+                                        .build(),
+                                )
+                                .date(i32::MAX)
+                                .build(),
+                        )
+                        .reverse_side(
+                            DatedFile::builder()
+                                .file(
+                                    File::builder()
+                                        .id(i32::MAX)
+                                        .size(i32::MAX)
+                                        .expected_size(i32::MAX)
+                                        .local(
+                                            LocalFile::builder()
+                                                .path("text")
+                                                .can_be_downloaded(true)
+                                                .can_be_deleted(true)
+                                                .is_downloading_active(true)
+                                                .is_downloading_completed(true)
+                                                .download_offset(i32::MAX)
+                                                .downloaded_prefix_size(i32::MAX)
+                                                .downloaded_size(i32::MAX)
+                                                .build(),
+                                        )
+                                        .remote(
+                                            RemoteFile::builder()
+                                                .id("text")
+                                                .unique_id("text")
+                                                .is_uploading_active(true)
+                                                .is_uploading_completed(true)
+                                                .uploaded_size(i32::MAX)
+                                                .build(),
+                                        )
+                                        .build(),
+                                )
+                                .date(i32::MAX)
+                                .build(),
+                        )
+                        .translation(vec![])
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(PassportElement::EmailAddress(
+            PassportElementEmailAddress::builder()
+                .email_address("text")
+                .build(),
+        ));
+        assert_round_trip(PassportElement::IdentityCard(
+            PassportElementIdentityCard::builder()
+                .identity_card(
+                    IdentityDocument::builder()
+                        .number("text")
+                        .front_side(
+                            DatedFile::builder()
+                                .file(
+                                    File::builder()
+                                        .id(i32::MAX)
+                                        .size(i32::MAX)
+                                        .expected_size(i32::MAX)
+                                        .local(
+                                            LocalFile::builder()
+                                                .path("text")
+                                                .can_be_downloaded(true)
+                                                .can_be_deleted(true)
+                                                .is_downloading_active(true)
+                                                .is_downloading_completed(true)
+                                                .download_offset(i32::MAX)
+                                                .downloaded_prefix_size(i32::MAX)
+                                                .downloaded_size(i32::MAX)
+                                                .build(),
+                                        )
+                                        .remote(
+                                            RemoteFile::builder()
+                                                .id("text")
+                                                .unique_id("text")
+                                                .is_uploading_active(true)
+                                                .is_uploading_completed(true)
+                                                .uploaded_size(i32::MAX)
+                                                .build(),
+                                        )
+                                        .build(),
+                                )
+                                .date(i32::MAX)
+                                .build(),
+                        )
+                        .reverse_side(
+                            DatedFile::builder()
+                                .file(
+                                    File::builder()
+                                        .id(i32::MAX)
+                                        .size(i32::MAX)
+                                        .expected_size(i32::MAX)
+                                        .local(
+                                            LocalFile::builder()
+                                                .path("text")
+                                                .can_be_downloaded(true)
+                                                .can_be_deleted(true)
+                                                .is_downloading_active(true)
+                                                .is_downloading_completed(true)
+                                                .download_offset(i32::MAX)
+                                                .downloaded_prefix_size(i32::MAX)
+                                                .downloaded_size(i32::MAX)
+                                                .build(),
+                                        )
+                                        .remote(
+                                            RemoteFile::builder()
+                                                .id("text")
+                                                .unique_id("text")
+                                                .is_uploading_active(true)
+                                                .is_uploading_completed(true)
+                                                .uploaded_size(i32::MAX)
+                                                .build(),
+                                        )
+                                        .build(),
+                                )
+                                .date(i32::MAX)
+                                .build(),
+                        )
+                        .translation(vec![])
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(PassportElement::InternalPassport(
+            PassportElementInternalPassport::builder()
+                .internal_passport(
+                    IdentityDocument::builder()
+                        .number("text")
+                        .front_side(
+                            DatedFile::builder()
+                                .file(
+                                    File::builder()
+                                        .id(i32::MAX)
+                                        .size(i32::MAX)
+                                        .expected_size(i32::MAX)
+                                        .local(
+                                            LocalFile::builder()
+                                                .path("text")
+                                                .can_be_downloaded(true)
+                                                .can_be_deleted(true)
+                                                .is_downloading_active(true)
+                                                .is_downloading_completed(true)
+                                                .download_offset(i32::MAX)
+                                                .downloaded_prefix_size(i32::MAX)
+                                                .downloaded_size(i32::MAX)
+                                                .build(),
+                                        )
+                                        .remote(
+                                            RemoteFile::builder()
+                                                .id("text")
+                                                .unique_id("text")
+                                                .is_uploading_active(true)
+                                                .is_uploading_completed(true)
+                                                .uploaded_size(i32::MAX)
+                                                .build(),
+                                        )
+                                        .build(),
+                                )
+                                .date(i32::MAX)
+                                .build(),
+                        )
+                        .reverse_side(
+                            DatedFile::builder()
+                                .file(
+                                    File::builder()
+                                        .id(i32::MAX)
+                                        .size(i32::MAX)
+                                        .expected_size(i32::MAX)
+                                        .local(
+                                            LocalFile::builder()
+                                                .path("text")
+                                                .can_be_downloaded(true)
+                                                .can_be_deleted(true)
+                                                .is_downloading_active(true)
+                                                .is_downloading_completed(true)
+                                                .download_offset(i32::MAX)
+                                                .downloaded_prefix_size(i32::MAX)
+                                                .downloaded_size(i32::MAX)
+                                                .build(),
+                                        )
+                                        .remote(
+                                            RemoteFile::builder()
+                                                .id("text")
+                                                .unique_id("text")
+                                                .is_uploading_active(true)
+                                                .is_uploading_completed(true)
+                                                .uploaded_size(i32::MAX)
+                                                .build(),
+                                        )
+                                        .build(),
+                                )
+                                .date(i32::MAX)
+                                .build(),
+                        )
+                        .translation(vec![])
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(PassportElement::Passport(
+            PassportElementPassport::builder()
+                .passport(
+                    IdentityDocument::builder()
+                        .number("text")
+                        .front_side(
+                            DatedFile::builder()
+                                .file(
+                                    File::builder()
+                                        .id(i32::MAX)
+                                        .size(i32::MAX)
+                                        .expected_size(i32::MAX)
+                                        .local(
+                                            LocalFile::builder()
+                                                .path("text")
+                                                .can_be_downloaded(true)
+                                                .can_be_deleted(true)
+                                                .is_downloading_active(true)
+                                                .is_downloading_completed(true)
+                                                .download_offset(i32::MAX)
+                                                .downloaded_prefix_size(i32::MAX)
+                                                .downloaded_size(i32::MAX)
+                                                .build(),
+                                        )
+                                        .remote(
+                                            RemoteFile::builder()
+                                                .id("text")
+                                                .unique_id("text")
+                                                .is_uploading_active(true)
+                                                .is_uploading_completed(true)
+                                                .uploaded_size(i32::MAX)
+                                                .build(),
+                                        )
+                                        .build(),
+                                )
+                                .date(i32::MAX)
+                                .build(),
+                        )
+                        .reverse_side(
+                            DatedFile::builder()
+                                .file(
+                                    File::builder()
+                                        .id(i32::MAX)
+                                        .size(i32::MAX)
+                                        .expected_size(i32::MAX)
+                                        .local(
+                                            LocalFile::builder()
+                                                .path("text")
+                                                .can_be_downloaded(true)
+                                                .can_be_deleted(true)
+                                                .is_downloading_active(true)
+                                                .is_downloading_completed(true)
+                                                .download_offset(i32::MAX)
+                                                .downloaded_prefix_size(i32::MAX)
+                                                .downloaded_size(i32::MAX)
+                                                .build(),
+                                        )
+                                        .remote(
+                                            RemoteFile::builder()
+                                                .id("text")
+                                                .unique_id("text")
+                                                .is_uploading_active(true)
+                                                .is_uploading_completed(true)
+                                                .uploaded_size(i32::MAX)
+                                                .build(),
+                                        )
+                                        .build(),
+                                )
+                                .date(i32::MAX)
+                                .build(),
+                        )
+                        .translation(vec![])
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(PassportElement::PassportRegistration(
+            PassportElementPassportRegistration::builder()
+                .passport_registration(
+                    PersonalDocument::builder()
+                        .files(vec![])
+                        .translation(vec![])
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(PassportElement::PersonalDetails(
+            PassportElementPersonalDetails::builder()
+                .personal_details(
+                    PersonalDetails::builder()
+                        .first_name("text")
+                        .middle_name("text")
+                        .last_name("text")
+                        .native_first_name("text")
+                        .native_middle_name("text")
+                        .native_last_name("text")
+                        .birthdate(
+                            Date::builder()
+                                .day(i32::MAX)
+                                .month(i32::MAX)
+                                .year(i32::MAX)
+                                .build(),
+                        )
+                        .gender("text")
+                        .country_code("text")
+                        .residence_country_code("text")
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(PassportElement::PhoneNumber(
+            PassportElementPhoneNumber::builder()
+                .phone_number("text")
+                .build(),
+        ));
+        assert_round_trip(PassportElement::RentalAgreement(
+            PassportElementRentalAgreement::builder()
+                .rental_agreement(
+                    PersonalDocument::builder()
+                        .files(vec![])
+                        .translation(vec![])
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(PassportElement::TemporaryRegistration(
+            PassportElementTemporaryRegistration::builder()
+                .temporary_registration(
+                    PersonalDocument::builder()
+                        .files(vec![])
+                        .translation(vec![])
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(PassportElement::UtilityBill(
+            PassportElementUtilityBill::builder()
+                .utility_bill(
+                    PersonalDocument::builder()
+                        .files(vec![])
+                        .translation(vec![])
+                        .build(),
+                )
+                .build(),
+        ));
+    }
+}
@@ -1,64 +1,68 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Contains the description of an error in a Telegram Passport element
 pub trait TDPassportElementErrorSource: Debug + RObject {}
 
 /// Contains the description of an error in a Telegram Passport element
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum PassportElementErrorSource {
-    #[doc(hidden)]
-    _Default(()),
     /// One of the data fields contains an error. The error will be considered resolved when the value of the field changes
+    #[serde(rename = "passportElementErrorSourceDataField")]
     DataField(PassportElementErrorSourceDataField),
     /// The file contains an error. The error will be considered resolved when the file changes
+    #[serde(rename = "passportElementErrorSourceFile")]
     File(PassportElementErrorSourceFile),
     /// The list of attached files contains an error. The error will be considered resolved when the list of files changes
+    #[serde(rename = "passportElementErrorSourceFiles")]
     Files(PassportElementErrorSourceFiles),
     /// The front side of the document contains an error. The error will be considered resolved when the file with the front side changes
+    #[serde(rename = "passportElementErrorSourceFrontSide")]
     FrontSide(PassportElementErrorSourceFrontSide),
     /// The reverse side of the document contains an error. The error will be considered resolved when the file with the reverse side changes
+    #[serde(rename = "passportElementErrorSourceReverseSide")]
     ReverseSide(PassportElementErrorSourceReverseSide),
     /// The selfie with the document contains an error. The error will be considered resolved when the file with the selfie changes
+    #[serde(rename = "passportElementErrorSourceSelfie")]
     Selfie(PassportElementErrorSourceSelfie),
     /// One of files with the translation of the document contains an error. The error will be considered resolved when the file changes
+    #[serde(rename = "passportElementErrorSourceTranslationFile")]
     TranslationFile(PassportElementErrorSourceTranslationFile),
     /// The translation of the document contains an error. The error will be considered resolved when the list of translation files changes
+    #[serde(rename = "passportElementErrorSourceTranslationFiles")]
     TranslationFiles(PassportElementErrorSourceTranslationFiles),
     /// The element contains an error in an unspecified place. The error will be considered resolved when new data is added
+    #[serde(rename = "passportElementErrorSourceUnspecified")]
     Unspecified(PassportElementErrorSourceUnspecified),
 }
 
+/// Default value is default of [PassportElementErrorSourceDataField], builders still require the field to be set
 impl Default for PassportElementErrorSource {
     fn default() -> Self {
-        PassportElementErrorSource::_Default(())
+        PassportElementErrorSource::DataField(PassportElementErrorSourceDataField::default())
     }
 }
 
-impl<'de> Deserialize<'de> for PassportElementErrorSource {
-    fn deserialize<D>(deserializer: D) -> Result<PassportElementErrorSource, D::Error>
+impl Serialize for PassportElementErrorSource {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          PassportElementErrorSource,
-          (passportElementErrorSourceDataField, DataField);
-          (passportElementErrorSourceFile, File);
-          (passportElementErrorSourceFiles, Files);
-          (passportElementErrorSourceFrontSide, FrontSide);
-          (passportElementErrorSourceReverseSide, ReverseSide);
-          (passportElementErrorSourceSelfie, Selfie);
-          (passportElementErrorSourceTranslationFile, TranslationFile);
-          (passportElementErrorSourceTranslationFiles, TranslationFiles);
-          (passportElementErrorSourceUnspecified, Unspecified);
-
-        )(deserializer)
+        match self {
+            PassportElementErrorSource::DataField(t) => t.serialize(serializer),
+            PassportElementErrorSource::File(t) => t.serialize(serializer),
+            PassportElementErrorSource::Files(t) => t.serialize(serializer),
+            PassportElementErrorSource::FrontSide(t) => t.serialize(serializer),
+            PassportElementErrorSource::ReverseSide(t) => t.serialize(serializer),
+            PassportElementErrorSource::Selfie(t) => t.serialize(serializer),
+            PassportElementErrorSource::TranslationFile(t) => t.serialize(serializer),
+            PassportElementErrorSource::TranslationFiles(t) => t.serialize(serializer),
+            PassportElementErrorSource::Unspecified(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -75,8 +79,6 @@ impl RObject for PassportElementErrorSource {
             PassportElementErrorSource::TranslationFile(t) => t.td_name(),
             PassportElementErrorSource::TranslationFiles(t) => t.td_name(),
             PassportElementErrorSource::Unspecified(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -91,8 +93,6 @@ impl RObject for PassportElementErrorSource {
             PassportElementErrorSource::TranslationFile(t) => t.extra(),
             PassportElementErrorSource::TranslationFiles(t) => t.extra(),
             PassportElementErrorSource::Unspecified(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -104,10 +104,6 @@ impl PassportElementErrorSource {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, PassportElementErrorSource::_Default(_))
-    }
 }
 
 impl AsRef<PassportElementErrorSource> for PassportElementErrorSource {
@@ -118,12 +114,13 @@ impl AsRef<PassportElementErrorSource> for PassportElementErrorSource {
 
 /// One of the data fields contains an error. The error will be considered resolved when the value of the field changes
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "passportElementErrorSourceDataField")]
 pub struct PassportElementErrorSourceDataField {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Field name
     field_name: String,
@@ -143,6 +140,20 @@ impl RObject for PassportElementErrorSourceDataField {
     }
 }
 
+impl PartialEq for PassportElementErrorSourceDataField {
+    fn eq(&self, other: &Self) -> bool {
+        self.field_name == other.field_name
+    }
+}
+
+impl Eq for PassportElementErrorSourceDataField {}
+
+impl std::hash::Hash for PassportElementErrorSourceDataField {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.field_name, state);
+    }
+}
+
 impl TDPassportElementErrorSource for PassportElementErrorSourceDataField {}
 
 impl PassportElementErrorSourceDataField {
@@ -150,29 +161,66 @@ impl PassportElementErrorSourceDataField {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPassportElementErrorSourceDataFieldBuilder {
-        let mut inner = PassportElementErrorSourceDataField::default();
-        inner.td_name = "passportElementErrorSourceDataField".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPassportElementErrorSourceDataFieldBuilder { inner }
+        RTDPassportElementErrorSourceDataFieldBuilder {
+            inner: PassportElementErrorSourceDataField::default(),
+            missing: vec!["field_name"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPassportElementErrorSourceDataFieldBuilder {
+        RTDPassportElementErrorSourceDataFieldBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPassportElementErrorSourceDataFieldBuilder {
+        RTDPassportElementErrorSourceDataFieldBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn field_name(&self) -> &String {
         &self.field_name
     }
+
+    pub fn into_field_name(self) -> String {
+        self.field_name
+    }
+
+    pub fn with_field_name<T: AsRef<str>>(mut self, field_name: T) -> Self {
+        self.field_name = field_name.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPassportElementErrorSourceDataFieldBuilder {
     inner: PassportElementErrorSourceDataField,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPassportElementErrorSourceDataFieldBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PassportElementErrorSourceDataField {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PassportElementErrorSourceDataField> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PassportElementErrorSourceDataField",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn field_name<T: AsRef<str>>(&mut self, field_name: T) -> &mut Self {
         self.inner.field_name = field_name.as_ref().to_string();
+        self.missing.retain(|f| *f != "field_name");
         self
     }
 }
@@ -191,15 +239,16 @@ impl AsRef<PassportElementErrorSourceDataField> for RTDPassportElementErrorSourc
 
 /// The file contains an error. The error will be considered resolved when the file changes
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "passportElementErrorSourceFile")]
 pub struct PassportElementErrorSourceFile {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Index of a file with the error
-    file_index: i64,
+    file_index: i32,
 }
 
 impl RObject for PassportElementErrorSourceFile {
@@ -216,6 +265,20 @@ impl RObject for PassportElementErrorSourceFile {
     }
 }
 
+impl PartialEq for PassportElementErrorSourceFile {
+    fn eq(&self, other: &Self) -> bool {
+        self.file_index == other.file_index
+    }
+}
+
+impl Eq for PassportElementErrorSourceFile {}
+
+impl std::hash::Hash for PassportElementErrorSourceFile {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.file_index, state);
+    }
+}
+
 impl TDPassportElementErrorSource for PassportElementErrorSourceFile {}
 
 impl PassportElementErrorSourceFile {
@@ -223,29 +286,62 @@ impl PassportElementErrorSourceFile {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPassportElementErrorSourceFileBuilder {
-        let mut inner = PassportElementErrorSourceFile::default();
-        inner.td_name = "passportElementErrorSourceFile".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPassportElementErrorSourceFileBuilder { inner }
+        RTDPassportElementErrorSourceFileBuilder {
+            inner: PassportElementErrorSourceFile::default(),
+            missing: vec!["file_index"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPassportElementErrorSourceFileBuilder {
+        RTDPassportElementErrorSourceFileBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPassportElementErrorSourceFileBuilder {
+        RTDPassportElementErrorSourceFileBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn file_index(&self) -> i64 {
+    pub fn file_index(&self) -> i32 {
         self.file_index
     }
+
+    pub fn with_file_index(mut self, file_index: i32) -> Self {
+        self.file_index = file_index;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPassportElementErrorSourceFileBuilder {
     inner: PassportElementErrorSourceFile,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPassportElementErrorSourceFileBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PassportElementErrorSourceFile {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PassportElementErrorSourceFile> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PassportElementErrorSourceFile",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn file_index(&mut self, file_index: i64) -> &mut Self {
+    pub fn file_index(&mut self, file_index: i32) -> &mut Self {
         self.inner.file_index = file_index;
+        self.missing.retain(|f| *f != "file_index");
         self
     }
 }
@@ -264,12 +360,13 @@ impl AsRef<PassportElementErrorSourceFile> for RTDPassportElementErrorSourceFile
 
 /// The list of attached files contains an error. The error will be considered resolved when the list of files changes
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "passportElementErrorSourceFiles")]
 pub struct PassportElementErrorSourceFiles {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -287,6 +384,18 @@ impl RObject for PassportElementErrorSourceFiles {
     }
 }
 
+impl PartialEq for PassportElementErrorSourceFiles {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for PassportElementErrorSourceFiles {}
+
+impl std::hash::Hash for PassportElementErrorSourceFiles {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDPassportElementErrorSource for PassportElementErrorSourceFiles {}
 
 impl PassportElementErrorSourceFiles {
@@ -294,22 +403,49 @@ impl PassportElementErrorSourceFiles {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPassportElementErrorSourceFilesBuilder {
-        let mut inner = PassportElementErrorSourceFiles::default();
-        inner.td_name = "passportElementErrorSourceFiles".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPassportElementErrorSourceFilesBuilder { inner }
+        RTDPassportElementErrorSourceFilesBuilder {
+            inner: PassportElementErrorSourceFiles::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPassportElementErrorSourceFilesBuilder {
+        RTDPassportElementErrorSourceFilesBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPassportElementErrorSourceFilesBuilder {
+        RTDPassportElementErrorSourceFilesBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDPassportElementErrorSourceFilesBuilder {
     inner: PassportElementErrorSourceFiles,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPassportElementErrorSourceFilesBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PassportElementErrorSourceFiles {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PassportElementErrorSourceFiles> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PassportElementErrorSourceFiles",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<PassportElementErrorSourceFiles> for PassportElementErrorSourceFiles {
@@ -326,12 +462,13 @@ impl AsRef<PassportElementErrorSourceFiles> for RTDPassportElementErrorSourceFil
 
 /// The front side of the document contains an error. The error will be considered resolved when the file with the front side changes
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "passportElementErrorSourceFrontSide")]
 pub struct PassportElementErrorSourceFrontSide {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -349,6 +486,18 @@ impl RObject for PassportElementErrorSourceFrontSide {
     }
 }
 
+impl PartialEq for PassportElementErrorSourceFrontSide {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for PassportElementErrorSourceFrontSide {}
+
+impl std::hash::Hash for PassportElementErrorSourceFrontSide {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDPassportElementErrorSource for PassportElementErrorSourceFrontSide {}
 
 impl PassportElementErrorSourceFrontSide {
@@ -356,22 +505,49 @@ impl PassportElementErrorSourceFrontSide {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPassportElementErrorSourceFrontSideBuilder {
-        let mut inner = PassportElementErrorSourceFrontSide::default();
-        inner.td_name = "passportElementErrorSourceFrontSide".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPassportElementErrorSourceFrontSideBuilder { inner }
+        RTDPassportElementErrorSourceFrontSideBuilder {
+            inner: PassportElementErrorSourceFrontSide::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPassportElementErrorSourceFrontSideBuilder {
+        RTDPassportElementErrorSourceFrontSideBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPassportElementErrorSourceFrontSideBuilder {
+        RTDPassportElementErrorSourceFrontSideBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDPassportElementErrorSourceFrontSideBuilder {
     inner: PassportElementErrorSourceFrontSide,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPassportElementErrorSourceFrontSideBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PassportElementErrorSourceFrontSide {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PassportElementErrorSourceFrontSide> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PassportElementErrorSourceFrontSide",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<PassportElementErrorSourceFrontSide> for PassportElementErrorSourceFrontSide {
@@ -388,12 +564,13 @@ impl AsRef<PassportElementErrorSourceFrontSide> for RTDPassportElementErrorSourc
 
 /// The reverse side of the document contains an error. The error will be considered resolved when the file with the reverse side changes
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "passportElementErrorSourceReverseSide")]
 pub struct PassportElementErrorSourceReverseSide {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -411,6 +588,18 @@ impl RObject for PassportElementErrorSourceReverseSide {
     }
 }
 
+impl PartialEq for PassportElementErrorSourceReverseSide {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for PassportElementErrorSourceReverseSide {}
+
+impl std::hash::Hash for PassportElementErrorSourceReverseSide {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDPassportElementErrorSource for PassportElementErrorSourceReverseSide {}
 
 impl PassportElementErrorSourceReverseSide {
@@ -418,22 +607,49 @@ impl PassportElementErrorSourceReverseSide {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPassportElementErrorSourceReverseSideBuilder {
-        let mut inner = PassportElementErrorSourceReverseSide::default();
-        inner.td_name = "passportElementErrorSourceReverseSide".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPassportElementErrorSourceReverseSideBuilder { inner }
+        RTDPassportElementErrorSourceReverseSideBuilder {
+            inner: PassportElementErrorSourceReverseSide::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPassportElementErrorSourceReverseSideBuilder {
+        RTDPassportElementErrorSourceReverseSideBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPassportElementErrorSourceReverseSideBuilder {
+        RTDPassportElementErrorSourceReverseSideBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDPassportElementErrorSourceReverseSideBuilder {
     inner: PassportElementErrorSourceReverseSide,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPassportElementErrorSourceReverseSideBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PassportElementErrorSourceReverseSide {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PassportElementErrorSourceReverseSide> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PassportElementErrorSourceReverseSide",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<PassportElementErrorSourceReverseSide> for PassportElementErrorSourceReverseSide {
@@ -452,12 +668,13 @@ impl AsRef<PassportElementErrorSourceReverseSide>
 
 /// The selfie with the document contains an error. The error will be considered resolved when the file with the selfie changes
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "passportElementErrorSourceSelfie")]
 pub struct PassportElementErrorSourceSelfie {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -475,6 +692,18 @@ impl RObject for PassportElementErrorSourceSelfie {
     }
 }
 
+impl PartialEq for PassportElementErrorSourceSelfie {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for PassportElementErrorSourceSelfie {}
+
+impl std::hash::Hash for PassportElementErrorSourceSelfie {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDPassportElementErrorSource for PassportElementErrorSourceSelfie {}
 
 impl PassportElementErrorSourceSelfie {
@@ -482,22 +711,49 @@ impl PassportElementErrorSourceSelfie {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPassportElementErrorSourceSelfieBuilder {
-        let mut inner = PassportElementErrorSourceSelfie::default();
-        inner.td_name = "passportElementErrorSourceSelfie".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPassportElementErrorSourceSelfieBuilder { inner }
+        RTDPassportElementErrorSourceSelfieBuilder {
+            inner: PassportElementErrorSourceSelfie::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPassportElementErrorSourceSelfieBuilder {
+        RTDPassportElementErrorSourceSelfieBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPassportElementErrorSourceSelfieBuilder {
+        RTDPassportElementErrorSourceSelfieBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDPassportElementErrorSourceSelfieBuilder {
     inner: PassportElementErrorSourceSelfie,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPassportElementErrorSourceSelfieBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PassportElementErrorSourceSelfie {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PassportElementErrorSourceSelfie> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PassportElementErrorSourceSelfie",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<PassportElementErrorSourceSelfie> for PassportElementErrorSourceSelfie {
@@ -514,15 +770,16 @@ impl AsRef<PassportElementErrorSourceSelfie> for RTDPassportElementErrorSourceSe
 
 /// One of files with the translation of the document contains an error. The error will be considered resolved when the file changes
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "passportElementErrorSourceTranslationFile")]
 pub struct PassportElementErrorSourceTranslationFile {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Index of a file with the error
-    file_index: i64,
+    file_index: i32,
 }
 
 impl RObject for PassportElementErrorSourceTranslationFile {
@@ -539,6 +796,20 @@ impl RObject for PassportElementErrorSourceTranslationFile {
     }
 }
 
+impl PartialEq for PassportElementErrorSourceTranslationFile {
+    fn eq(&self, other: &Self) -> bool {
+        self.file_index == other.file_index
+    }
+}
+
+impl Eq for PassportElementErrorSourceTranslationFile {}
+
+impl std::hash::Hash for PassportElementErrorSourceTranslationFile {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.file_index, state);
+    }
+}
+
 impl TDPassportElementErrorSource for PassportElementErrorSourceTranslationFile {}
 
 impl PassportElementErrorSourceTranslationFile {
@@ -546,29 +817,62 @@ impl PassportElementErrorSourceTranslationFile {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPassportElementErrorSourceTranslationFileBuilder {
-        let mut inner = PassportElementErrorSourceTranslationFile::default();
-        inner.td_name = "passportElementErrorSourceTranslationFile".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPassportElementErrorSourceTranslationFileBuilder { inner }
+        RTDPassportElementErrorSourceTranslationFileBuilder {
+            inner: PassportElementErrorSourceTranslationFile::default(),
+            missing: vec!["file_index"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPassportElementErrorSourceTranslationFileBuilder {
+        RTDPassportElementErrorSourceTranslationFileBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPassportElementErrorSourceTranslationFileBuilder {
+        RTDPassportElementErrorSourceTranslationFileBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn file_index(&self) -> i64 {
+    pub fn file_index(&self) -> i32 {
         self.file_index
     }
+
+    pub fn with_file_index(mut self, file_index: i32) -> Self {
+        self.file_index = file_index;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPassportElementErrorSourceTranslationFileBuilder {
     inner: PassportElementErrorSourceTranslationFile,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPassportElementErrorSourceTranslationFileBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PassportElementErrorSourceTranslationFile {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PassportElementErrorSourceTranslationFile> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PassportElementErrorSourceTranslationFile",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn file_index(&mut self, file_index: i64) -> &mut Self {
+    pub fn file_index(&mut self, file_index: i32) -> &mut Self {
         self.inner.file_index = file_index;
+        self.missing.retain(|f| *f != "file_index");
         self
     }
 }
@@ -591,12 +895,13 @@ impl AsRef<PassportElementErrorSourceTranslationFile>
 
 /// The translation of the document contains an error. The error will be considered resolved when the list of translation files changes
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "passportElementErrorSourceTranslationFiles")]
 pub struct PassportElementErrorSourceTranslationFiles {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -614,6 +919,18 @@ impl RObject for PassportElementErrorSourceTranslationFiles {
     }
 }
 
+impl PartialEq for PassportElementErrorSourceTranslationFiles {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for PassportElementErrorSourceTranslationFiles {}
+
+impl std::hash::Hash for PassportElementErrorSourceTranslationFiles {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDPassportElementErrorSource for PassportElementErrorSourceTranslationFiles {}
 
 impl PassportElementErrorSourceTranslationFiles {
@@ -621,22 +938,49 @@ impl PassportElementErrorSourceTranslationFiles {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPassportElementErrorSourceTranslationFilesBuilder {
-        let mut inner = PassportElementErrorSourceTranslationFiles::default();
-        inner.td_name = "passportElementErrorSourceTranslationFiles".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPassportElementErrorSourceTranslationFilesBuilder { inner }
+        RTDPassportElementErrorSourceTranslationFilesBuilder {
+            inner: PassportElementErrorSourceTranslationFiles::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPassportElementErrorSourceTranslationFilesBuilder {
+        RTDPassportElementErrorSourceTranslationFilesBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPassportElementErrorSourceTranslationFilesBuilder {
+        RTDPassportElementErrorSourceTranslationFilesBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDPassportElementErrorSourceTranslationFilesBuilder {
     inner: PassportElementErrorSourceTranslationFiles,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPassportElementErrorSourceTranslationFilesBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PassportElementErrorSourceTranslationFiles {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PassportElementErrorSourceTranslationFiles> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PassportElementErrorSourceTranslationFiles",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<PassportElementErrorSourceTranslationFiles>
@@ -657,12 +1001,13 @@ impl AsRef<PassportElementErrorSourceTranslationFiles>
 
 /// The element contains an error in an unspecified place. The error will be considered resolved when new data is added
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "passportElementErrorSourceUnspecified")]
 pub struct PassportElementErrorSourceUnspecified {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -680,6 +1025,18 @@ impl RObject for PassportElementErrorSourceUnspecified {
     }
 }
 
+impl PartialEq for PassportElementErrorSourceUnspecified {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for PassportElementErrorSourceUnspecified {}
+
+impl std::hash::Hash for PassportElementErrorSourceUnspecified {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDPassportElementErrorSource for PassportElementErrorSourceUnspecified {}
 
 impl PassportElementErrorSourceUnspecified {
@@ -687,22 +1044,49 @@ impl PassportElementErrorSourceUnspecified {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPassportElementErrorSourceUnspecifiedBuilder {
-        let mut inner = PassportElementErrorSourceUnspecified::default();
-        inner.td_name = "passportElementErrorSourceUnspecified".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPassportElementErrorSourceUnspecifiedBuilder { inner }
+        RTDPassportElementErrorSourceUnspecifiedBuilder {
+            inner: PassportElementErrorSourceUnspecified::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPassportElementErrorSourceUnspecifiedBuilder {
+        RTDPassportElementErrorSourceUnspecifiedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPassportElementErrorSourceUnspecifiedBuilder {
+        RTDPassportElementErrorSourceUnspecifiedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDPassportElementErrorSourceUnspecifiedBuilder {
     inner: PassportElementErrorSourceUnspecified,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPassportElementErrorSourceUnspecifiedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PassportElementErrorSourceUnspecified {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PassportElementErrorSourceUnspecified> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PassportElementErrorSourceUnspecified",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<PassportElementErrorSourceUnspecified> for PassportElementErrorSourceUnspecified {
@@ -718,3 +1102,46 @@ impl AsRef<PassportElementErrorSourceUnspecified>
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(PassportElementErrorSource::DataField(
+            PassportElementErrorSourceDataField::builder()
+                .field_name("text")
+                .build(),
+        ));
+        assert_round_trip(PassportElementErrorSource::File(
+            PassportElementErrorSourceFile::builder()
+                .file_index(i32::MAX)
+                .build(),
+        ));
+        assert_round_trip(PassportElementErrorSource::Files(
+            PassportElementErrorSourceFiles::builder().build(),
+        ));
+        assert_round_trip(PassportElementErrorSource::FrontSide(
+            PassportElementErrorSourceFrontSide::builder().build(),
+        ));
+        assert_round_trip(PassportElementErrorSource::ReverseSide(
+            PassportElementErrorSourceReverseSide::builder().build(),
+        ));
+        assert_round_trip(PassportElementErrorSource::Selfie(
+            PassportElementErrorSourceSelfie::builder().build(),
+        ));
+        assert_round_trip(PassportElementErrorSource::TranslationFile(
+            PassportElementErrorSourceTranslationFile::builder()
+                .file_index(i32::MAX)
+                .build(),
+        ));
+        assert_round_trip(PassportElementErrorSource::TranslationFiles(
+            PassportElementErrorSourceTranslationFiles::builder().build(),
+        ));
+        assert_round_trip(PassportElementErrorSource::Unspecified(
+            PassportElementErrorSourceUnspecified::builder().build(),
+        ));
+    }
+}
@@ -1,76 +1,84 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Contains the type of a Telegram Passport element
 pub trait TDPassportElementType: Debug + RObject {}
 
 /// Contains the type of a Telegram Passport element
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum PassportElementType {
-    #[doc(hidden)]
-    _Default(()),
     /// A Telegram Passport element containing the user's address
+    #[serde(rename = "passportElementTypeAddress")]
     Address(PassportElementTypeAddress),
     /// A Telegram Passport element containing the user's bank statement
+    #[serde(rename = "passportElementTypeBankStatement")]
     BankStatement(PassportElementTypeBankStatement),
     /// A Telegram Passport element containing the user's driver license
+    #[serde(rename = "passportElementTypeDriverLicense")]
     DriverLicense(PassportElementTypeDriverLicense),
     /// A Telegram Passport element containing the user's email address
+    #[serde(rename = "passportElementTypeEmailAddress")]
     EmailAddress(PassportElementTypeEmailAddress),
     /// A Telegram Passport element containing the user's identity card
+    #[serde(rename = "passportElementTypeIdentityCard")]
     IdentityCard(PassportElementTypeIdentityCard),
     /// A Telegram Passport element containing the user's internal passport
+    #[serde(rename = "passportElementTypeInternalPassport")]
     InternalPassport(PassportElementTypeInternalPassport),
     /// A Telegram Passport element containing the user's passport
+    #[serde(rename = "passportElementTypePassport")]
     Passport(PassportElementTypePassport),
     /// A Telegram Passport element containing the registration page of the user's passport
+    #[serde(rename = "passportElementTypePassportRegistration")]
     PassportRegistration(PassportElementTypePassportRegistration),
     /// A Telegram Passport element containing the user's personal details
+    #[serde(rename = "passportElementTypePersonalDetails")]
     PersonalDetails(PassportElementTypePersonalDetails),
     /// A Telegram Passport element containing the user's phone number
+    #[serde(rename = "passportElementTypePhoneNumber")]
     PhoneNumber(PassportElementTypePhoneNumber),
     /// A Telegram Passport element containing the user's rental agreement
+    #[serde(rename = "passportElementTypeRentalAgreement")]
     RentalAgreement(PassportElementTypeRentalAgreement),
     /// A Telegram Passport element containing the user's temporary registration
+    #[serde(rename = "passportElementTypeTemporaryRegistration")]
     TemporaryRegistration(PassportElementTypeTemporaryRegistration),
     /// A Telegram Passport element containing the user's utility bill
+    #[serde(rename = "passportElementTypeUtilityBill")]
     UtilityBill(PassportElementTypeUtilityBill),
 }
 
+/// Default value is default of [PassportElementTypeAddress], builders still require the field to be set
 impl Default for PassportElementType {
     fn default() -> Self {
-        PassportElementType::_Default(())
+        PassportElementType::Address(PassportElementTypeAddress::default())
     }
 }
 
-impl<'de> Deserialize<'de> for PassportElementType {
-    fn deserialize<D>(deserializer: D) -> Result<PassportElementType, D::Error>
+impl Serialize for PassportElementType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          PassportElementType,
-          (passportElementTypeAddress, Address);
-          (passportElementTypeBankStatement, BankStatement);
-          (passportElementTypeDriverLicense, DriverLicense);
-          (passportElementTypeEmailAddress, EmailAddress);
-          (passportElementTypeIdentityCard, IdentityCard);
-          (passportElementTypeInternalPassport, InternalPassport);
-          (passportElementTypePassport, Passport);
-          (passportElementTypePassportRegistration, PassportRegistration);
-          (passportElementTypePersonalDetails, PersonalDetails);
-          (passportElementTypePhoneNumber, PhoneNumber);
-          (passportElementTypeRentalAgreement, RentalAgreement);
-          (passportElementTypeTemporaryRegistration, TemporaryRegistration);
-          (passportElementTypeUtilityBill, UtilityBill);
-
-        )(deserializer)
+        match self {
+            PassportElementType::Address(t) => t.serialize(serializer),
+            PassportElementType::BankStatement(t) => t.serialize(serializer),
+            PassportElementType::DriverLicense(t) => t.serialize(serializer),
+            PassportElementType::EmailAddress(t) => t.serialize(serializer),
+            PassportElementType::IdentityCard(t) => t.serialize(serializer),
+            PassportElementType::InternalPassport(t) => t.serialize(serializer),
+            PassportElementType::Passport(t) => t.serialize(serializer),
+            PassportElementType::PassportRegistration(t) => t.serialize(serializer),
+            PassportElementType::PersonalDetails(t) => t.serialize(serializer),
+            PassportElementType::PhoneNumber(t) => t.serialize(serializer),
+            PassportElementType::RentalAgreement(t) => t.serialize(serializer),
+            PassportElementType::TemporaryRegistration(t) => t.serialize(serializer),
+            PassportElementType::UtilityBill(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -91,8 +99,6 @@ impl RObject for PassportElementType {
             PassportElementType::RentalAgreement(t) => t.td_name(),
             PassportElementType::TemporaryRegistration(t) => t.td_name(),
             PassportElementType::UtilityBill(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -111,8 +117,6 @@ impl RObject for PassportElementType {
             PassportElementType::RentalAgreement(t) => t.extra(),
             PassportElementType::TemporaryRegistration(t) => t.extra(),
             PassportElementType::UtilityBill(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -124,10 +128,6 @@ impl PassportElementType {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, PassportElementType::_Default(_))
-    }
 }
 
 impl AsRef<PassportElementType> for PassportElementType {
@@ -138,12 +138,13 @@ impl AsRef<PassportElementType> for PassportElementType {
 
 /// A Telegram Passport element containing the user's address
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "passportElementTypeAddress")]
 pub struct PassportElementTypeAddress {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -161,6 +162,18 @@ impl RObject for PassportElementTypeAddress {
     }
 }
 
+impl PartialEq for PassportElementTypeAddress {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for PassportElementTypeAddress {}
+
+impl std::hash::Hash for PassportElementTypeAddress {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDPassportElementType for PassportElementTypeAddress {}
 
 impl PassportElementTypeAddress {
@@ -168,22 +181,49 @@ impl PassportElementTypeAddress {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPassportElementTypeAddressBuilder {
-        let mut inner = PassportElementTypeAddress::default();
-        inner.td_name = "passportElementTypeAddress".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPassportElementTypeAddressBuilder { inner }
+        RTDPassportElementTypeAddressBuilder {
+            inner: PassportElementTypeAddress::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPassportElementTypeAddressBuilder {
+        RTDPassportElementTypeAddressBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPassportElementTypeAddressBuilder {
+        RTDPassportElementTypeAddressBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDPassportElementTypeAddressBuilder {
     inner: PassportElementTypeAddress,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPassportElementTypeAddressBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PassportElementTypeAddress {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PassportElementTypeAddress> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PassportElementTypeAddress",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<PassportElementTypeAddress> for PassportElementTypeAddress {
@@ -200,12 +240,13 @@ impl AsRef<PassportElementTypeAddress> for RTDPassportElementTypeAddressBuilder
 
 /// A Telegram Passport element containing the user's bank statement
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "passportElementTypeBankStatement")]
 pub struct PassportElementTypeBankStatement {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -223,6 +264,18 @@ impl RObject for PassportElementTypeBankStatement {
     }
 }
 
+impl PartialEq for PassportElementTypeBankStatement {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for PassportElementTypeBankStatement {}
+
+impl std::hash::Hash for PassportElementTypeBankStatement {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDPassportElementType for PassportElementTypeBankStatement {}
 
 impl PassportElementTypeBankStatement {
@@ -230,22 +283,49 @@ impl PassportElementTypeBankStatement {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPassportElementTypeBankStatementBuilder {
-        let mut inner = PassportElementTypeBankStatement::default();
-        inner.td_name = "passportElementTypeBankStatement".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPassportElementTypeBankStatementBuilder { inner }
+        RTDPassportElementTypeBankStatementBuilder {
+            inner: PassportElementTypeBankStatement::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPassportElementTypeBankStatementBuilder {
+        RTDPassportElementTypeBankStatementBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPassportElementTypeBankStatementBuilder {
+        RTDPassportElementTypeBankStatementBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDPassportElementTypeBankStatementBuilder {
     inner: PassportElementTypeBankStatement,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPassportElementTypeBankStatementBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PassportElementTypeBankStatement {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PassportElementTypeBankStatement> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PassportElementTypeBankStatement",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<PassportElementTypeBankStatement> for PassportElementTypeBankStatement {
@@ -262,12 +342,13 @@ impl AsRef<PassportElementTypeBankStatement> for RTDPassportElementTypeBankState
 
 /// A Telegram Passport element containing the user's driver license
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "passportElementTypeDriverLicense")]
 pub struct PassportElementTypeDriverLicense {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -285,6 +366,18 @@ impl RObject for PassportElementTypeDriverLicense {
     }
 }
 
+impl PartialEq for PassportElementTypeDriverLicense {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for PassportElementTypeDriverLicense {}
+
+impl std::hash::Hash for PassportElementTypeDriverLicense {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDPassportElementType for PassportElementTypeDriverLicense {}
 
 impl PassportElementTypeDriverLicense {
@@ -292,22 +385,49 @@ impl PassportElementTypeDriverLicense {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPassportElementTypeDriverLicenseBuilder {
-        let mut inner = PassportElementTypeDriverLicense::default();
-        inner.td_name = "passportElementTypeDriverLicense".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPassportElementTypeDriverLicenseBuilder { inner }
+        RTDPassportElementTypeDriverLicenseBuilder {
+            inner: PassportElementTypeDriverLicense::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPassportElementTypeDriverLicenseBuilder {
+        RTDPassportElementTypeDriverLicenseBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPassportElementTypeDriverLicenseBuilder {
+        RTDPassportElementTypeDriverLicenseBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDPassportElementTypeDriverLicenseBuilder {
     inner: PassportElementTypeDriverLicense,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPassportElementTypeDriverLicenseBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PassportElementTypeDriverLicense {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PassportElementTypeDriverLicense> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PassportElementTypeDriverLicense",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<PassportElementTypeDriverLicense> for PassportElementTypeDriverLicense {
@@ -324,12 +444,13 @@ impl AsRef<PassportElementTypeDriverLicense> for RTDPassportElementTypeDriverLic
 
 /// A Telegram Passport element containing the user's email address
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "passportElementTypeEmailAddress")]
 pub struct PassportElementTypeEmailAddress {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -347,6 +468,18 @@ impl RObject for PassportElementTypeEmailAddress {
     }
 }
 
+impl PartialEq for PassportElementTypeEmailAddress {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for PassportElementTypeEmailAddress {}
+
+impl std::hash::Hash for PassportElementTypeEmailAddress {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDPassportElementType for PassportElementTypeEmailAddress {}
 
 impl PassportElementTypeEmailAddress {
@@ -354,22 +487,49 @@ impl PassportElementTypeEmailAddress {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPassportElementTypeEmailAddressBuilder {
-        let mut inner = PassportElementTypeEmailAddress::default();
-        inner.td_name = "passportElementTypeEmailAddress".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPassportElementTypeEmailAddressBuilder { inner }
+        RTDPassportElementTypeEmailAddressBuilder {
+            inner: PassportElementTypeEmailAddress::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPassportElementTypeEmailAddressBuilder {
+        RTDPassportElementTypeEmailAddressBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPassportElementTypeEmailAddressBuilder {
+        RTDPassportElementTypeEmailAddressBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDPassportElementTypeEmailAddressBuilder {
     inner: PassportElementTypeEmailAddress,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPassportElementTypeEmailAddressBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PassportElementTypeEmailAddress {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PassportElementTypeEmailAddress> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PassportElementTypeEmailAddress",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<PassportElementTypeEmailAddress> for PassportElementTypeEmailAddress {
@@ -386,12 +546,13 @@ impl AsRef<PassportElementTypeEmailAddress> for RTDPassportElementTypeEmailAddre
 
 /// A Telegram Passport element containing the user's identity card
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "passportElementTypeIdentityCard")]
 pub struct PassportElementTypeIdentityCard {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -409,6 +570,18 @@ impl RObject for PassportElementTypeIdentityCard {
     }
 }
 
+impl PartialEq for PassportElementTypeIdentityCard {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for PassportElementTypeIdentityCard {}
+
+impl std::hash::Hash for PassportElementTypeIdentityCard {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDPassportElementType for PassportElementTypeIdentityCard {}
 
 impl PassportElementTypeIdentityCard {
@@ -416,22 +589,49 @@ impl PassportElementTypeIdentityCard {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPassportElementTypeIdentityCardBuilder {
-        let mut inner = PassportElementTypeIdentityCard::default();
-        inner.td_name = "passportElementTypeIdentityCard".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPassportElementTypeIdentityCardBuilder { inner }
+        RTDPassportElementTypeIdentityCardBuilder {
+            inner: PassportElementTypeIdentityCard::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPassportElementTypeIdentityCardBuilder {
+        RTDPassportElementTypeIdentityCardBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPassportElementTypeIdentityCardBuilder {
+        RTDPassportElementTypeIdentityCardBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDPassportElementTypeIdentityCardBuilder {
     inner: PassportElementTypeIdentityCard,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPassportElementTypeIdentityCardBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PassportElementTypeIdentityCard {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PassportElementTypeIdentityCard> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PassportElementTypeIdentityCard",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<PassportElementTypeIdentityCard> for PassportElementTypeIdentityCard {
@@ -448,12 +648,13 @@ impl AsRef<PassportElementTypeIdentityCard> for RTDPassportElementTypeIdentityCa
 
 /// A Telegram Passport element containing the user's internal passport
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "passportElementTypeInternalPassport")]
 pub struct PassportElementTypeInternalPassport {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -471,6 +672,18 @@ impl RObject for PassportElementTypeInternalPassport {
     }
 }
 
+impl PartialEq for PassportElementTypeInternalPassport {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for PassportElementTypeInternalPassport {}
+
+impl std::hash::Hash for PassportElementTypeInternalPassport {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDPassportElementType for PassportElementTypeInternalPassport {}
 
 impl PassportElementTypeInternalPassport {
@@ -478,22 +691,49 @@ impl PassportElementTypeInternalPassport {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPassportElementTypeInternalPassportBuilder {
-        let mut inner = PassportElementTypeInternalPassport::default();
-        inner.td_name = "passportElementTypeInternalPassport".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPassportElementTypeInternalPassportBuilder { inner }
+        RTDPassportElementTypeInternalPassportBuilder {
+            inner: PassportElementTypeInternalPassport::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPassportElementTypeInternalPassportBuilder {
+        RTDPassportElementTypeInternalPassportBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPassportElementTypeInternalPassportBuilder {
+        RTDPassportElementTypeInternalPassportBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDPassportElementTypeInternalPassportBuilder {
     inner: PassportElementTypeInternalPassport,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPassportElementTypeInternalPassportBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PassportElementTypeInternalPassport {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PassportElementTypeInternalPassport> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PassportElementTypeInternalPassport",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<PassportElementTypeInternalPassport> for PassportElementTypeInternalPassport {
@@ -510,12 +750,13 @@ impl AsRef<PassportElementTypeInternalPassport> for RTDPassportElementTypeIntern
 
 /// A Telegram Passport element containing the user's passport
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "passportElementTypePassport")]
 pub struct PassportElementTypePassport {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -533,6 +774,18 @@ impl RObject for PassportElementTypePassport {
     }
 }
 
+impl PartialEq for PassportElementTypePassport {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for PassportElementTypePassport {}
+
+impl std::hash::Hash for PassportElementTypePassport {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDPassportElementType for PassportElementTypePassport {}
 
 impl PassportElementTypePassport {
@@ -540,22 +793,49 @@ impl PassportElementTypePassport {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPassportElementTypePassportBuilder {
-        let mut inner = PassportElementTypePassport::default();
-        inner.td_name = "passportElementTypePassport".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPassportElementTypePassportBuilder { inner }
+        RTDPassportElementTypePassportBuilder {
+            inner: PassportElementTypePassport::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPassportElementTypePassportBuilder {
+        RTDPassportElementTypePassportBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPassportElementTypePassportBuilder {
+        RTDPassportElementTypePassportBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDPassportElementTypePassportBuilder {
     inner: PassportElementTypePassport,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPassportElementTypePassportBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PassportElementTypePassport {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PassportElementTypePassport> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PassportElementTypePassport",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<PassportElementTypePassport> for PassportElementTypePassport {
@@ -572,12 +852,13 @@ impl AsRef<PassportElementTypePassport> for RTDPassportElementTypePassportBuilde
 
 /// A Telegram Passport element containing the registration page of the user's passport
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "passportElementTypePassportRegistration")]
 pub struct PassportElementTypePassportRegistration {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -595,6 +876,18 @@ impl RObject for PassportElementTypePassportRegistration {
     }
 }
 
+impl PartialEq for PassportElementTypePassportRegistration {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for PassportElementTypePassportRegistration {}
+
+impl std::hash::Hash for PassportElementTypePassportRegistration {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDPassportElementType for PassportElementTypePassportRegistration {}
 
 impl PassportElementTypePassportRegistration {
@@ -602,22 +895,49 @@ impl PassportElementTypePassportRegistration {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPassportElementTypePassportRegistrationBuilder {
-        let mut inner = PassportElementTypePassportRegistration::default();
-        inner.td_name = "passportElementTypePassportRegistration".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPassportElementTypePassportRegistrationBuilder { inner }
+        RTDPassportElementTypePassportRegistrationBuilder {
+            inner: PassportElementTypePassportRegistration::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPassportElementTypePassportRegistrationBuilder {
+        RTDPassportElementTypePassportRegistrationBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPassportElementTypePassportRegistrationBuilder {
+        RTDPassportElementTypePassportRegistrationBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDPassportElementTypePassportRegistrationBuilder {
     inner: PassportElementTypePassportRegistration,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPassportElementTypePassportRegistrationBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PassportElementTypePassportRegistration {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PassportElementTypePassportRegistration> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PassportElementTypePassportRegistration",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<PassportElementTypePassportRegistration> for PassportElementTypePassportRegistration {
@@ -636,12 +956,13 @@ impl AsRef<PassportElementTypePassportRegistration>
 
 /// A Telegram Passport element containing the user's personal details
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "passportElementTypePersonalDetails")]
 pub struct PassportElementTypePersonalDetails {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -659,6 +980,18 @@ impl RObject for PassportElementTypePersonalDetails {
     }
 }
 
+impl PartialEq for PassportElementTypePersonalDetails {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for PassportElementTypePersonalDetails {}
+
+impl std::hash::Hash for PassportElementTypePersonalDetails {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDPassportElementType for PassportElementTypePersonalDetails {}
 
 impl PassportElementTypePersonalDetails {
@@ -666,22 +999,49 @@ impl PassportElementTypePersonalDetails {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPassportElementTypePersonalDetailsBuilder {
-        let mut inner = PassportElementTypePersonalDetails::default();
-        inner.td_name = "passportElementTypePersonalDetails".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPassportElementTypePersonalDetailsBuilder { inner }
+        RTDPassportElementTypePersonalDetailsBuilder {
+            inner: PassportElementTypePersonalDetails::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPassportElementTypePersonalDetailsBuilder {
+        RTDPassportElementTypePersonalDetailsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPassportElementTypePersonalDetailsBuilder {
+        RTDPassportElementTypePersonalDetailsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDPassportElementTypePersonalDetailsBuilder {
     inner: PassportElementTypePersonalDetails,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPassportElementTypePersonalDetailsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PassportElementTypePersonalDetails {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PassportElementTypePersonalDetails> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PassportElementTypePersonalDetails",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<PassportElementTypePersonalDetails> for PassportElementTypePersonalDetails {
@@ -698,12 +1058,13 @@ impl AsRef<PassportElementTypePersonalDetails> for RTDPassportElementTypePersona
 
 /// A Telegram Passport element containing the user's phone number
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "passportElementTypePhoneNumber")]
 pub struct PassportElementTypePhoneNumber {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -721,6 +1082,18 @@ impl RObject for PassportElementTypePhoneNumber {
     }
 }
 
+impl PartialEq for PassportElementTypePhoneNumber {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for PassportElementTypePhoneNumber {}
+
+impl std::hash::Hash for PassportElementTypePhoneNumber {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDPassportElementType for PassportElementTypePhoneNumber {}
 
 impl PassportElementTypePhoneNumber {
@@ -728,22 +1101,49 @@ impl PassportElementTypePhoneNumber {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPassportElementTypePhoneNumberBuilder {
-        let mut inner = PassportElementTypePhoneNumber::default();
-        inner.td_name = "passportElementTypePhoneNumber".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPassportElementTypePhoneNumberBuilder { inner }
+        RTDPassportElementTypePhoneNumberBuilder {
+            inner: PassportElementTypePhoneNumber::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPassportElementTypePhoneNumberBuilder {
+        RTDPassportElementTypePhoneNumberBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPassportElementTypePhoneNumberBuilder {
+        RTDPassportElementTypePhoneNumberBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDPassportElementTypePhoneNumberBuilder {
     inner: PassportElementTypePhoneNumber,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPassportElementTypePhoneNumberBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PassportElementTypePhoneNumber {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PassportElementTypePhoneNumber> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PassportElementTypePhoneNumber",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<PassportElementTypePhoneNumber> for PassportElementTypePhoneNumber {
@@ -760,12 +1160,13 @@ impl AsRef<PassportElementTypePhoneNumber> for RTDPassportElementTypePhoneNumber
 
 /// A Telegram Passport element containing the user's rental agreement
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "passportElementTypeRentalAgreement")]
 pub struct PassportElementTypeRentalAgreement {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -783,6 +1184,18 @@ impl RObject for PassportElementTypeRentalAgreement {
     }
 }
 
+impl PartialEq for PassportElementTypeRentalAgreement {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for PassportElementTypeRentalAgreement {}
+
+impl std::hash::Hash for PassportElementTypeRentalAgreement {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDPassportElementType for PassportElementTypeRentalAgreement {}
 
 impl PassportElementTypeRentalAgreement {
@@ -790,22 +1203,49 @@ impl PassportElementTypeRentalAgreement {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPassportElementTypeRentalAgreementBuilder {
-        let mut inner = PassportElementTypeRentalAgreement::default();
-        inner.td_name = "passportElementTypeRentalAgreement".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPassportElementTypeRentalAgreementBuilder { inner }
+        RTDPassportElementTypeRentalAgreementBuilder {
+            inner: PassportElementTypeRentalAgreement::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPassportElementTypeRentalAgreementBuilder {
+        RTDPassportElementTypeRentalAgreementBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPassportElementTypeRentalAgreementBuilder {
+        RTDPassportElementTypeRentalAgreementBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDPassportElementTypeRentalAgreementBuilder {
     inner: PassportElementTypeRentalAgreement,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPassportElementTypeRentalAgreementBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PassportElementTypeRentalAgreement {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PassportElementTypeRentalAgreement> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PassportElementTypeRentalAgreement",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<PassportElementTypeRentalAgreement> for PassportElementTypeRentalAgreement {
@@ -822,12 +1262,13 @@ impl AsRef<PassportElementTypeRentalAgreement> for RTDPassportElementTypeRentalA
 
 /// A Telegram Passport element containing the user's temporary registration
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "passportElementTypeTemporaryRegistration")]
 pub struct PassportElementTypeTemporaryRegistration {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -845,6 +1286,18 @@ impl RObject for PassportElementTypeTemporaryRegistration {
     }
 }
 
+impl PartialEq for PassportElementTypeTemporaryRegistration {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for PassportElementTypeTemporaryRegistration {}
+
+impl std::hash::Hash for PassportElementTypeTemporaryRegistration {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDPassportElementType for PassportElementTypeTemporaryRegistration {}
 
 impl PassportElementTypeTemporaryRegistration {
@@ -852,22 +1305,49 @@ impl PassportElementTypeTemporaryRegistration {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPassportElementTypeTemporaryRegistrationBuilder {
-        let mut inner = PassportElementTypeTemporaryRegistration::default();
-        inner.td_name = "passportElementTypeTemporaryRegistration".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPassportElementTypeTemporaryRegistrationBuilder { inner }
+        RTDPassportElementTypeTemporaryRegistrationBuilder {
+            inner: PassportElementTypeTemporaryRegistration::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPassportElementTypeTemporaryRegistrationBuilder {
+        RTDPassportElementTypeTemporaryRegistrationBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPassportElementTypeTemporaryRegistrationBuilder {
+        RTDPassportElementTypeTemporaryRegistrationBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDPassportElementTypeTemporaryRegistrationBuilder {
     inner: PassportElementTypeTemporaryRegistration,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPassportElementTypeTemporaryRegistrationBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PassportElementTypeTemporaryRegistration {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PassportElementTypeTemporaryRegistration> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PassportElementTypeTemporaryRegistration",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<PassportElementTypeTemporaryRegistration> for PassportElementTypeTemporaryRegistration {
@@ -886,12 +1366,13 @@ impl AsRef<PassportElementTypeTemporaryRegistration>
 
 /// A Telegram Passport element containing the user's utility bill
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "passportElementTypeUtilityBill")]
 pub struct PassportElementTypeUtilityBill {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -909,6 +1390,18 @@ impl RObject for PassportElementTypeUtilityBill {
     }
 }
 
+impl PartialEq for PassportElementTypeUtilityBill {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for PassportElementTypeUtilityBill {}
+
+impl std::hash::Hash for PassportElementTypeUtilityBill {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDPassportElementType for PassportElementTypeUtilityBill {}
 
 impl PassportElementTypeUtilityBill {
@@ -916,22 +1409,49 @@ impl PassportElementTypeUtilityBill {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPassportElementTypeUtilityBillBuilder {
-        let mut inner = PassportElementTypeUtilityBill::default();
-        inner.td_name = "passportElementTypeUtilityBill".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPassportElementTypeUtilityBillBuilder { inner }
+        RTDPassportElementTypeUtilityBillBuilder {
+            inner: PassportElementTypeUtilityBill::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPassportElementTypeUtilityBillBuilder {
+        RTDPassportElementTypeUtilityBillBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPassportElementTypeUtilityBillBuilder {
+        RTDPassportElementTypeUtilityBillBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDPassportElementTypeUtilityBillBuilder {
     inner: PassportElementTypeUtilityBill,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPassportElementTypeUtilityBillBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PassportElementTypeUtilityBill {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PassportElementTypeUtilityBill> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PassportElementTypeUtilityBill",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<PassportElementTypeUtilityBill> for PassportElementTypeUtilityBill {
@@ -945,3 +1465,52 @@ impl AsRef<PassportElementTypeUtilityBill> for RTDPassportElementTypeUtilityBill
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(PassportElementType::Address(
+            PassportElementTypeAddress::builder().build(),
+        ));
+        assert_round_trip(PassportElementType::BankStatement(
+            PassportElementTypeBankStatement::builder().build(),
+        ));
+        assert_round_trip(PassportElementType::DriverLicense(
+            PassportElementTypeDriverLicense::builder().build(),
+        ));
+        assert_round_trip(PassportElementType::EmailAddress(
+            PassportElementTypeEmailAddress::builder().build(),
+        ));
+        assert_round_trip(PassportElementType::IdentityCard(
+            PassportElementTypeIdentityCard::builder().build(),
+        ));
+        assert_round_trip(PassportElementType::InternalPassport(
+            PassportElementTypeInternalPassport::builder().build(),
+        ));
+        assert_round_trip(PassportElementType::Passport(
+            PassportElementTypePassport::builder().build(),
+        ));
+        assert_round_trip(PassportElementType::PassportRegistration(
+            PassportElementTypePassportRegistration::builder().build(),
+        ));
+        assert_round_trip(PassportElementType::PersonalDetails(
+            PassportElementTypePersonalDetails::builder().build(),
+        ));
+        assert_round_trip(PassportElementType::PhoneNumber(
+            PassportElementTypePhoneNumber::builder().build(),
+        ));
+        assert_round_trip(PassportElementType::RentalAgreement(
+            PassportElementTypeRentalAgreement::builder().build(),
+        ));
+        assert_round_trip(PassportElementType::TemporaryRegistration(
+            PassportElementTypeTemporaryRegistration::builder().build(),
+        ));
+        assert_round_trip(PassportElementType::UtilityBill(
+            PassportElementTypeUtilityBill::builder().build(),
+        ));
+    }
+}
@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains information about saved Telegram Passport elements
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "passportElements")]
 pub struct PassportElements {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Telegram Passport elements
     elements: Vec<PassportElement>,
@@ -29,34 +29,85 @@ impl RObject for PassportElements {
     }
 }
 
+impl PartialEq for PassportElements {
+    fn eq(&self, other: &Self) -> bool {
+        self.elements == other.elements
+    }
+}
+
+impl Eq for PassportElements {}
+
+impl std::hash::Hash for PassportElements {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.elements, state);
+    }
+}
+
 impl PassportElements {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPassportElementsBuilder {
-        let mut inner = PassportElements::default();
-        inner.td_name = "passportElements".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPassportElementsBuilder { inner }
+        RTDPassportElementsBuilder {
+            inner: PassportElements::default(),
+            missing: vec!["elements"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPassportElementsBuilder {
+        RTDPassportElementsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPassportElementsBuilder {
+        RTDPassportElementsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn elements(&self) -> &Vec<PassportElement> {
         &self.elements
     }
+
+    pub fn into_elements(self) -> Vec<PassportElement> {
+        self.elements
+    }
+
+    pub fn with_elements(mut self, elements: Vec<PassportElement>) -> Self {
+        self.elements = elements;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPassportElementsBuilder {
     inner: PassportElements,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPassportElementsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PassportElements {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PassportElements> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PassportElements",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn elements(&mut self, elements: Vec<PassportElement>) -> &mut Self {
         self.inner.elements = elements;
+        self.missing.retain(|f| *f != "elements");
         self
     }
 }
@@ -72,3 +123,14 @@ impl AsRef<PassportElements> for RTDPassportElementsBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(PassportElements::builder().elements(vec![]).build());
+    }
+}
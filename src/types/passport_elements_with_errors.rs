@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains information about a Telegram Passport elements and corresponding errors
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "passportElementsWithErrors")]
 pub struct PassportElementsWithErrors {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Telegram Passport elements
     elements: Vec<PassportElement>,
@@ -31,15 +31,44 @@ impl RObject for PassportElementsWithErrors {
     }
 }
 
+impl PartialEq for PassportElementsWithErrors {
+    fn eq(&self, other: &Self) -> bool {
+        self.elements == other.elements && self.errors == other.errors
+    }
+}
+
+impl Eq for PassportElementsWithErrors {}
+
+impl std::hash::Hash for PassportElementsWithErrors {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.elements, state);
+        std::hash::Hash::hash(&self.errors, state);
+    }
+}
+
 impl PassportElementsWithErrors {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPassportElementsWithErrorsBuilder {
-        let mut inner = PassportElementsWithErrors::default();
-        inner.td_name = "passportElementsWithErrors".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPassportElementsWithErrorsBuilder { inner }
+        RTDPassportElementsWithErrorsBuilder {
+            inner: PassportElementsWithErrors::default(),
+            missing: vec!["elements", "errors"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPassportElementsWithErrorsBuilder {
+        RTDPassportElementsWithErrorsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPassportElementsWithErrorsBuilder {
+        RTDPassportElementsWithErrorsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn elements(&self) -> &Vec<PassportElement> {
@@ -49,25 +78,58 @@ impl PassportElementsWithErrors {
     pub fn errors(&self) -> &Vec<PassportElementError> {
         &self.errors
     }
+
+    pub fn into_elements(self) -> Vec<PassportElement> {
+        self.elements
+    }
+
+    pub fn into_errors(self) -> Vec<PassportElementError> {
+        self.errors
+    }
+
+    pub fn with_elements(mut self, elements: Vec<PassportElement>) -> Self {
+        self.elements = elements;
+        self
+    }
+
+    pub fn with_errors(mut self, errors: Vec<PassportElementError>) -> Self {
+        self.errors = errors;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPassportElementsWithErrorsBuilder {
     inner: PassportElementsWithErrors,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPassportElementsWithErrorsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PassportElementsWithErrors {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PassportElementsWithErrors> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PassportElementsWithErrors",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn elements(&mut self, elements: Vec<PassportElement>) -> &mut Self {
         self.inner.elements = elements;
+        self.missing.retain(|f| *f != "elements");
         self
     }
 
     pub fn errors(&mut self, errors: Vec<PassportElementError>) -> &mut Self {
         self.inner.errors = errors;
+        self.missing.retain(|f| *f != "errors");
         self
     }
 }
@@ -83,3 +145,19 @@ impl AsRef<PassportElementsWithErrors> for RTDPassportElementsWithErrorsBuilder
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            PassportElementsWithErrors::builder()
+                .elements(vec![])
+                .errors(vec![])
+                .build(),
+        );
+    }
+}
@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains a description of the required Telegram Passport element that was requested by a service
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "passportRequiredElement")]
 pub struct PassportRequiredElement {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// List of Telegram Passport elements any of which is enough to provide
     suitable_elements: Vec<PassportSuitableElement>,
@@ -29,37 +29,91 @@ impl RObject for PassportRequiredElement {
     }
 }
 
+impl PartialEq for PassportRequiredElement {
+    fn eq(&self, other: &Self) -> bool {
+        self.suitable_elements == other.suitable_elements
+    }
+}
+
+impl Eq for PassportRequiredElement {}
+
+impl std::hash::Hash for PassportRequiredElement {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.suitable_elements, state);
+    }
+}
+
 impl PassportRequiredElement {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPassportRequiredElementBuilder {
-        let mut inner = PassportRequiredElement::default();
-        inner.td_name = "passportRequiredElement".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPassportRequiredElementBuilder { inner }
+        RTDPassportRequiredElementBuilder {
+            inner: PassportRequiredElement::default(),
+            missing: vec!["suitable_elements"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPassportRequiredElementBuilder {
+        RTDPassportRequiredElementBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPassportRequiredElementBuilder {
+        RTDPassportRequiredElementBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn suitable_elements(&self) -> &Vec<PassportSuitableElement> {
         &self.suitable_elements
     }
+
+    pub fn into_suitable_elements(self) -> Vec<PassportSuitableElement> {
+        self.suitable_elements
+    }
+
+    pub fn with_suitable_elements(
+        mut self,
+        suitable_elements: Vec<PassportSuitableElement>,
+    ) -> Self {
+        self.suitable_elements = suitable_elements;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPassportRequiredElementBuilder {
     inner: PassportRequiredElement,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPassportRequiredElementBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PassportRequiredElement {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PassportRequiredElement> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PassportRequiredElement",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn suitable_elements(
         &mut self,
         suitable_elements: Vec<PassportSuitableElement>,
     ) -> &mut Self {
         self.inner.suitable_elements = suitable_elements;
+        self.missing.retain(|f| *f != "suitable_elements");
         self
     }
 }
@@ -75,3 +129,18 @@ impl AsRef<PassportRequiredElement> for RTDPassportRequiredElementBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            PassportRequiredElement::builder()
+                .suitable_elements(vec![])
+                .build(),
+        );
+    }
+}
@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains information about a Telegram Passport element that was requested by a service
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "passportSuitableElement")]
 pub struct PassportSuitableElement {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Type of the element
     #[serde(rename(serialize = "type", deserialize = "type"))]
@@ -36,15 +36,49 @@ impl RObject for PassportSuitableElement {
     }
 }
 
+impl PartialEq for PassportSuitableElement {
+    fn eq(&self, other: &Self) -> bool {
+        self.type_ == other.type_
+            && self.is_selfie_required == other.is_selfie_required
+            && self.is_translation_required == other.is_translation_required
+            && self.is_native_name_required == other.is_native_name_required
+    }
+}
+
+impl Eq for PassportSuitableElement {}
+
+impl std::hash::Hash for PassportSuitableElement {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.type_, state);
+        std::hash::Hash::hash(&self.is_selfie_required, state);
+        std::hash::Hash::hash(&self.is_translation_required, state);
+        std::hash::Hash::hash(&self.is_native_name_required, state);
+    }
+}
+
 impl PassportSuitableElement {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPassportSuitableElementBuilder {
-        let mut inner = PassportSuitableElement::default();
-        inner.td_name = "passportSuitableElement".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPassportSuitableElementBuilder { inner }
+        RTDPassportSuitableElementBuilder {
+            inner: PassportSuitableElement::default(),
+            missing: vec!["type_"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPassportSuitableElementBuilder {
+        RTDPassportSuitableElementBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPassportSuitableElementBuilder {
+        RTDPassportSuitableElementBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn type_(&self) -> &PassportElementType {
@@ -62,20 +96,58 @@ impl PassportSuitableElement {
     pub fn is_native_name_required(&self) -> bool {
         self.is_native_name_required
     }
+
+    pub fn into_type(self) -> PassportElementType {
+        self.type_
+    }
+
+    pub fn with_type<T: AsRef<PassportElementType>>(mut self, type_: T) -> Self {
+        self.type_ = type_.as_ref().clone();
+        self
+    }
+
+    pub fn with_is_selfie_required(mut self, is_selfie_required: bool) -> Self {
+        self.is_selfie_required = is_selfie_required;
+        self
+    }
+
+    pub fn with_is_translation_required(mut self, is_translation_required: bool) -> Self {
+        self.is_translation_required = is_translation_required;
+        self
+    }
+
+    pub fn with_is_native_name_required(mut self, is_native_name_required: bool) -> Self {
+        self.is_native_name_required = is_native_name_required;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPassportSuitableElementBuilder {
     inner: PassportSuitableElement,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPassportSuitableElementBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PassportSuitableElement {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PassportSuitableElement> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PassportSuitableElement",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn type_<T: AsRef<PassportElementType>>(&mut self, type_: T) -> &mut Self {
         self.inner.type_ = type_.as_ref().clone();
+        self.missing.retain(|f| *f != "type_");
         self
     }
 
@@ -106,3 +178,23 @@ impl AsRef<PassportSuitableElement> for RTDPassportSuitableElementBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            PassportSuitableElement::builder()
+                .type_(PassportElementType::Address(
+                    PassportElementTypeAddress::builder().build(),
+                ))
+                .is_selfie_required(true)
+                .is_translation_required(true)
+                .is_native_name_required(true)
+                .build(),
+        );
+    }
+}
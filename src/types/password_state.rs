@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Represents the current state of 2-step verification
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "passwordState")]
 pub struct PasswordState {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// True, if a 2-step verification password is set
     has_password: bool,
@@ -37,15 +37,51 @@ impl RObject for PasswordState {
     }
 }
 
+impl PartialEq for PasswordState {
+    fn eq(&self, other: &Self) -> bool {
+        self.has_password == other.has_password
+            && self.password_hint == other.password_hint
+            && self.has_recovery_email_address == other.has_recovery_email_address
+            && self.has_passport_data == other.has_passport_data
+            && self.recovery_email_address_code_info == other.recovery_email_address_code_info
+    }
+}
+
+impl Eq for PasswordState {}
+
+impl std::hash::Hash for PasswordState {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.has_password, state);
+        std::hash::Hash::hash(&self.password_hint, state);
+        std::hash::Hash::hash(&self.has_recovery_email_address, state);
+        std::hash::Hash::hash(&self.has_passport_data, state);
+        std::hash::Hash::hash(&self.recovery_email_address_code_info, state);
+    }
+}
+
 impl PasswordState {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPasswordStateBuilder {
-        let mut inner = PasswordState::default();
-        inner.td_name = "passwordState".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPasswordStateBuilder { inner }
+        RTDPasswordStateBuilder {
+            inner: PasswordState::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPasswordStateBuilder {
+        RTDPasswordStateBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPasswordStateBuilder {
+        RTDPasswordStateBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn has_password(&self) -> bool {
@@ -67,17 +103,69 @@ impl PasswordState {
     pub fn recovery_email_address_code_info(&self) -> &Option<EmailAddressAuthenticationCodeInfo> {
         &self.recovery_email_address_code_info
     }
+
+    pub fn into_password_hint(self) -> String {
+        self.password_hint
+    }
+
+    pub fn into_recovery_email_address_code_info(
+        self,
+    ) -> Option<EmailAddressAuthenticationCodeInfo> {
+        self.recovery_email_address_code_info
+    }
+
+    pub fn with_has_password(mut self, has_password: bool) -> Self {
+        self.has_password = has_password;
+        self
+    }
+
+    pub fn with_password_hint<T: AsRef<str>>(mut self, password_hint: T) -> Self {
+        self.password_hint = password_hint.as_ref().to_string();
+        self
+    }
+
+    pub fn with_has_recovery_email_address(mut self, has_recovery_email_address: bool) -> Self {
+        self.has_recovery_email_address = has_recovery_email_address;
+        self
+    }
+
+    pub fn with_has_passport_data(mut self, has_passport_data: bool) -> Self {
+        self.has_passport_data = has_passport_data;
+        self
+    }
+
+    pub fn with_recovery_email_address_code_info<T: AsRef<EmailAddressAuthenticationCodeInfo>>(
+        mut self,
+        recovery_email_address_code_info: T,
+    ) -> Self {
+        self.recovery_email_address_code_info =
+            Some(recovery_email_address_code_info.as_ref().clone());
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPasswordStateBuilder {
     inner: PasswordState,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPasswordStateBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PasswordState {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PasswordState> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PasswordState",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn has_password(&mut self, has_password: bool) -> &mut Self {
         self.inner.has_password = has_password;
@@ -120,3 +208,21 @@ impl AsRef<PasswordState> for RTDPasswordStateBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            PasswordState::builder()
+                .has_password(true)
+                .password_hint("text")
+                .has_recovery_email_address(true)
+                .has_passport_data(true)
+                .build(),
+        );
+    }
+}
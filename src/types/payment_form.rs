@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains information about an invoice payment form
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "paymentForm")]
 pub struct PaymentForm {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Full information of the invoice
     invoice: Invoice,
@@ -41,15 +41,55 @@ impl RObject for PaymentForm {
     }
 }
 
+impl PartialEq for PaymentForm {
+    fn eq(&self, other: &Self) -> bool {
+        self.invoice == other.invoice
+            && self.url == other.url
+            && self.payments_provider == other.payments_provider
+            && self.saved_order_info == other.saved_order_info
+            && self.saved_credentials == other.saved_credentials
+            && self.can_save_credentials == other.can_save_credentials
+            && self.need_password == other.need_password
+    }
+}
+
+impl Eq for PaymentForm {}
+
+impl std::hash::Hash for PaymentForm {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.invoice, state);
+        std::hash::Hash::hash(&self.url, state);
+        std::hash::Hash::hash(&self.payments_provider, state);
+        std::hash::Hash::hash(&self.saved_order_info, state);
+        std::hash::Hash::hash(&self.saved_credentials, state);
+        std::hash::Hash::hash(&self.can_save_credentials, state);
+        std::hash::Hash::hash(&self.need_password, state);
+    }
+}
+
 impl PaymentForm {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPaymentFormBuilder {
-        let mut inner = PaymentForm::default();
-        inner.td_name = "paymentForm".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPaymentFormBuilder { inner }
+        RTDPaymentFormBuilder {
+            inner: PaymentForm::default(),
+            missing: vec!["invoice", "url"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPaymentFormBuilder {
+        RTDPaymentFormBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPaymentFormBuilder {
+        RTDPaymentFormBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn invoice(&self) -> &Invoice {
@@ -79,25 +119,98 @@ impl PaymentForm {
     pub fn need_password(&self) -> bool {
         self.need_password
     }
+
+    pub fn into_invoice(self) -> Invoice {
+        self.invoice
+    }
+
+    pub fn into_url(self) -> String {
+        self.url
+    }
+
+    pub fn into_payments_provider(self) -> Option<PaymentsProviderStripe> {
+        self.payments_provider
+    }
+
+    pub fn into_saved_order_info(self) -> Option<OrderInfo> {
+        self.saved_order_info
+    }
+
+    pub fn into_saved_credentials(self) -> Option<SavedCredentials> {
+        self.saved_credentials
+    }
+
+    pub fn with_invoice<T: AsRef<Invoice>>(mut self, invoice: T) -> Self {
+        self.invoice = invoice.as_ref().clone();
+        self
+    }
+
+    pub fn with_url<T: AsRef<str>>(mut self, url: T) -> Self {
+        self.url = url.as_ref().to_string();
+        self
+    }
+
+    pub fn with_payments_provider<T: AsRef<PaymentsProviderStripe>>(
+        mut self,
+        payments_provider: T,
+    ) -> Self {
+        self.payments_provider = Some(payments_provider.as_ref().clone());
+        self
+    }
+
+    pub fn with_saved_order_info<T: AsRef<OrderInfo>>(mut self, saved_order_info: T) -> Self {
+        self.saved_order_info = Some(saved_order_info.as_ref().clone());
+        self
+    }
+
+    pub fn with_saved_credentials<T: AsRef<SavedCredentials>>(
+        mut self,
+        saved_credentials: T,
+    ) -> Self {
+        self.saved_credentials = Some(saved_credentials.as_ref().clone());
+        self
+    }
+
+    pub fn with_can_save_credentials(mut self, can_save_credentials: bool) -> Self {
+        self.can_save_credentials = can_save_credentials;
+        self
+    }
+
+    pub fn with_need_password(mut self, need_password: bool) -> Self {
+        self.need_password = need_password;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPaymentFormBuilder {
     inner: PaymentForm,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPaymentFormBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PaymentForm {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PaymentForm> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("PaymentForm", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn invoice<T: AsRef<Invoice>>(&mut self, invoice: T) -> &mut Self {
         self.inner.invoice = invoice.as_ref().clone();
+        self.missing.retain(|f| *f != "invoice");
         self
     }
 
     pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
         self.inner.url = url.as_ref().to_string();
+        self.missing.retain(|f| *f != "url");
         self
     }
 
@@ -144,3 +257,34 @@ impl AsRef<PaymentForm> for RTDPaymentFormBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            PaymentForm::builder()
+                .invoice(
+                    Invoice::builder()
+                        .currency("text")
+                        .price_parts(vec![])
+                        .is_test(true)
+                        .need_name(true)
+                        .need_phone_number(true)
+                        .need_email_address(true)
+                        .need_shipping_address(true)
+                        .send_phone_number_to_provider(true)
+                        .send_email_address_to_provider(true)
+                        .is_flexible(true)
+                        .build(),
+                )
+                .url("text")
+                .can_save_credentials(true)
+                .need_password(true)
+                .build(),
+        );
+    }
+}
@@ -1,20 +1,20 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains information about a successful payment
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "paymentReceipt")]
 pub struct PaymentReceipt {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Point in time (Unix timestamp) when the payment was made
-    date: i64,
+    date: i32,
     /// User identifier of the payment provider bot
-    payments_provider_user_id: i64,
+    payments_provider_user_id: UserId,
     /// Contains information about the invoice
     invoice: Invoice,
     /// Contains order information; may be null
@@ -39,22 +39,65 @@ impl RObject for PaymentReceipt {
     }
 }
 
+impl PartialEq for PaymentReceipt {
+    fn eq(&self, other: &Self) -> bool {
+        self.date == other.date
+            && self.payments_provider_user_id == other.payments_provider_user_id
+            && self.invoice == other.invoice
+            && self.order_info == other.order_info
+            && self.shipping_option == other.shipping_option
+            && self.credentials_title == other.credentials_title
+    }
+}
+
+impl Eq for PaymentReceipt {}
+
+impl std::hash::Hash for PaymentReceipt {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.date, state);
+        std::hash::Hash::hash(&self.payments_provider_user_id, state);
+        std::hash::Hash::hash(&self.invoice, state);
+        std::hash::Hash::hash(&self.order_info, state);
+        std::hash::Hash::hash(&self.shipping_option, state);
+        std::hash::Hash::hash(&self.credentials_title, state);
+    }
+}
+
 impl PaymentReceipt {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPaymentReceiptBuilder {
-        let mut inner = PaymentReceipt::default();
-        inner.td_name = "paymentReceipt".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPaymentReceiptBuilder { inner }
+        RTDPaymentReceiptBuilder {
+            inner: PaymentReceipt::default(),
+            missing: vec![
+                "date",
+                "payments_provider_user_id",
+                "invoice",
+                "credentials_title",
+            ],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPaymentReceiptBuilder {
+        RTDPaymentReceiptBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPaymentReceiptBuilder {
+        RTDPaymentReceiptBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn date(&self) -> i64 {
+    pub fn date(&self) -> i32 {
         self.date
     }
 
-    pub fn payments_provider_user_id(&self) -> i64 {
+    pub fn payments_provider_user_id(&self) -> UserId {
         self.payments_provider_user_id
     }
 
@@ -73,30 +116,98 @@ impl PaymentReceipt {
     pub fn credentials_title(&self) -> &String {
         &self.credentials_title
     }
+
+    pub fn into_invoice(self) -> Invoice {
+        self.invoice
+    }
+
+    pub fn into_order_info(self) -> Option<OrderInfo> {
+        self.order_info
+    }
+
+    pub fn into_shipping_option(self) -> Option<ShippingOption> {
+        self.shipping_option
+    }
+
+    pub fn into_credentials_title(self) -> String {
+        self.credentials_title
+    }
+
+    pub fn with_date(mut self, date: i32) -> Self {
+        self.date = date;
+        self
+    }
+
+    pub fn with_payments_provider_user_id<T: Into<UserId>>(
+        mut self,
+        payments_provider_user_id: T,
+    ) -> Self {
+        self.payments_provider_user_id = payments_provider_user_id.into();
+        self
+    }
+
+    pub fn with_invoice<T: AsRef<Invoice>>(mut self, invoice: T) -> Self {
+        self.invoice = invoice.as_ref().clone();
+        self
+    }
+
+    pub fn with_order_info<T: AsRef<OrderInfo>>(mut self, order_info: T) -> Self {
+        self.order_info = Some(order_info.as_ref().clone());
+        self
+    }
+
+    pub fn with_shipping_option<T: AsRef<ShippingOption>>(mut self, shipping_option: T) -> Self {
+        self.shipping_option = Some(shipping_option.as_ref().clone());
+        self
+    }
+
+    pub fn with_credentials_title<T: AsRef<str>>(mut self, credentials_title: T) -> Self {
+        self.credentials_title = credentials_title.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPaymentReceiptBuilder {
     inner: PaymentReceipt,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPaymentReceiptBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PaymentReceipt {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PaymentReceipt> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PaymentReceipt",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn date(&mut self, date: i64) -> &mut Self {
+    pub fn date(&mut self, date: i32) -> &mut Self {
         self.inner.date = date;
+        self.missing.retain(|f| *f != "date");
         self
     }
 
-    pub fn payments_provider_user_id(&mut self, payments_provider_user_id: i64) -> &mut Self {
-        self.inner.payments_provider_user_id = payments_provider_user_id;
+    pub fn payments_provider_user_id<T: Into<UserId>>(
+        &mut self,
+        payments_provider_user_id: T,
+    ) -> &mut Self {
+        self.inner.payments_provider_user_id = payments_provider_user_id.into();
+        self.missing.retain(|f| *f != "payments_provider_user_id");
         self
     }
 
     pub fn invoice<T: AsRef<Invoice>>(&mut self, invoice: T) -> &mut Self {
         self.inner.invoice = invoice.as_ref().clone();
+        self.missing.retain(|f| *f != "invoice");
         self
     }
 
@@ -112,6 +223,7 @@ impl RTDPaymentReceiptBuilder {
 
     pub fn credentials_title<T: AsRef<str>>(&mut self, credentials_title: T) -> &mut Self {
         self.inner.credentials_title = credentials_title.as_ref().to_string();
+        self.missing.retain(|f| *f != "credentials_title");
         self
     }
 }
@@ -127,3 +239,34 @@ impl AsRef<PaymentReceipt> for RTDPaymentReceiptBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            PaymentReceipt::builder()
+                .date(i32::MAX)
+                .payments_provider_user_id(i32::MAX)
+                .invoice(
+                    Invoice::builder()
+                        .currency("text")
+                        .price_parts(vec![])
+                        .is_test(true)
+                        .need_name(true)
+                        .need_phone_number(true)
+                        .need_email_address(true)
+                        .need_shipping_address(true)
+                        .send_phone_number_to_provider(true)
+                        .send_email_address_to_provider(true)
+                        .is_flexible(true)
+                        .build(),
+                )
+                .credentials_title("text")
+                .build(),
+        );
+    }
+}
@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains the result of a payment request
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "paymentResult")]
 pub struct PaymentResult {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// True, if the payment request was successful; otherwise the verification_url will be not empty
     success: bool,
@@ -31,15 +31,44 @@ impl RObject for PaymentResult {
     }
 }
 
+impl PartialEq for PaymentResult {
+    fn eq(&self, other: &Self) -> bool {
+        self.success == other.success && self.verification_url == other.verification_url
+    }
+}
+
+impl Eq for PaymentResult {}
+
+impl std::hash::Hash for PaymentResult {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.success, state);
+        std::hash::Hash::hash(&self.verification_url, state);
+    }
+}
+
 impl PaymentResult {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPaymentResultBuilder {
-        let mut inner = PaymentResult::default();
-        inner.td_name = "paymentResult".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPaymentResultBuilder { inner }
+        RTDPaymentResultBuilder {
+            inner: PaymentResult::default(),
+            missing: vec!["verification_url"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPaymentResultBuilder {
+        RTDPaymentResultBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPaymentResultBuilder {
+        RTDPaymentResultBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn success(&self) -> bool {
@@ -49,17 +78,44 @@ impl PaymentResult {
     pub fn verification_url(&self) -> &String {
         &self.verification_url
     }
+
+    pub fn into_verification_url(self) -> String {
+        self.verification_url
+    }
+
+    pub fn with_success(mut self, success: bool) -> Self {
+        self.success = success;
+        self
+    }
+
+    pub fn with_verification_url<T: AsRef<str>>(mut self, verification_url: T) -> Self {
+        self.verification_url = verification_url.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPaymentResultBuilder {
     inner: PaymentResult,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPaymentResultBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PaymentResult {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PaymentResult> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PaymentResult",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn success(&mut self, success: bool) -> &mut Self {
         self.inner.success = success;
@@ -68,6 +124,7 @@ impl RTDPaymentResultBuilder {
 
     pub fn verification_url<T: AsRef<str>>(&mut self, verification_url: T) -> &mut Self {
         self.inner.verification_url = verification_url.as_ref().to_string();
+        self.missing.retain(|f| *f != "verification_url");
         self
     }
 }
@@ -83,3 +140,19 @@ impl AsRef<PaymentResult> for RTDPaymentResultBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            PaymentResult::builder()
+                .success(true)
+                .verification_url("text")
+                .build(),
+        );
+    }
+}
@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Stripe payment provider
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "paymentsProviderStripe")]
 pub struct PaymentsProviderStripe {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Stripe API publishable key
     publishable_key: String,
@@ -35,15 +35,49 @@ impl RObject for PaymentsProviderStripe {
     }
 }
 
+impl PartialEq for PaymentsProviderStripe {
+    fn eq(&self, other: &Self) -> bool {
+        self.publishable_key == other.publishable_key
+            && self.need_country == other.need_country
+            && self.need_postal_code == other.need_postal_code
+            && self.need_cardholder_name == other.need_cardholder_name
+    }
+}
+
+impl Eq for PaymentsProviderStripe {}
+
+impl std::hash::Hash for PaymentsProviderStripe {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.publishable_key, state);
+        std::hash::Hash::hash(&self.need_country, state);
+        std::hash::Hash::hash(&self.need_postal_code, state);
+        std::hash::Hash::hash(&self.need_cardholder_name, state);
+    }
+}
+
 impl PaymentsProviderStripe {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPaymentsProviderStripeBuilder {
-        let mut inner = PaymentsProviderStripe::default();
-        inner.td_name = "paymentsProviderStripe".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPaymentsProviderStripeBuilder { inner }
+        RTDPaymentsProviderStripeBuilder {
+            inner: PaymentsProviderStripe::default(),
+            missing: vec!["publishable_key"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPaymentsProviderStripeBuilder {
+        RTDPaymentsProviderStripeBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPaymentsProviderStripeBuilder {
+        RTDPaymentsProviderStripeBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn publishable_key(&self) -> &String {
@@ -61,20 +95,58 @@ impl PaymentsProviderStripe {
     pub fn need_cardholder_name(&self) -> bool {
         self.need_cardholder_name
     }
+
+    pub fn into_publishable_key(self) -> String {
+        self.publishable_key
+    }
+
+    pub fn with_publishable_key<T: AsRef<str>>(mut self, publishable_key: T) -> Self {
+        self.publishable_key = publishable_key.as_ref().to_string();
+        self
+    }
+
+    pub fn with_need_country(mut self, need_country: bool) -> Self {
+        self.need_country = need_country;
+        self
+    }
+
+    pub fn with_need_postal_code(mut self, need_postal_code: bool) -> Self {
+        self.need_postal_code = need_postal_code;
+        self
+    }
+
+    pub fn with_need_cardholder_name(mut self, need_cardholder_name: bool) -> Self {
+        self.need_cardholder_name = need_cardholder_name;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPaymentsProviderStripeBuilder {
     inner: PaymentsProviderStripe,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPaymentsProviderStripeBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PaymentsProviderStripe {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PaymentsProviderStripe> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PaymentsProviderStripe",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn publishable_key<T: AsRef<str>>(&mut self, publishable_key: T) -> &mut Self {
         self.inner.publishable_key = publishable_key.as_ref().to_string();
+        self.missing.retain(|f| *f != "publishable_key");
         self
     }
 
@@ -105,3 +177,21 @@ impl AsRef<PaymentsProviderStripe> for RTDPaymentsProviderStripeBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            PaymentsProviderStripe::builder()
+                .publishable_key("text")
+                .need_country(true)
+                .need_postal_code(true)
+                .need_cardholder_name(true)
+                .build(),
+        );
+    }
+}
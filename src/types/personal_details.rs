@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains the user's personal details
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "personalDetails")]
 pub struct PersonalDetails {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// First name of the user written in English; 1-255 characters
     first_name: String,
@@ -47,15 +47,70 @@ impl RObject for PersonalDetails {
     }
 }
 
+impl PartialEq for PersonalDetails {
+    fn eq(&self, other: &Self) -> bool {
+        self.first_name == other.first_name
+            && self.middle_name == other.middle_name
+            && self.last_name == other.last_name
+            && self.native_first_name == other.native_first_name
+            && self.native_middle_name == other.native_middle_name
+            && self.native_last_name == other.native_last_name
+            && self.birthdate == other.birthdate
+            && self.gender == other.gender
+            && self.country_code == other.country_code
+            && self.residence_country_code == other.residence_country_code
+    }
+}
+
+impl Eq for PersonalDetails {}
+
+impl std::hash::Hash for PersonalDetails {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.first_name, state);
+        std::hash::Hash::hash(&self.middle_name, state);
+        std::hash::Hash::hash(&self.last_name, state);
+        std::hash::Hash::hash(&self.native_first_name, state);
+        std::hash::Hash::hash(&self.native_middle_name, state);
+        std::hash::Hash::hash(&self.native_last_name, state);
+        std::hash::Hash::hash(&self.birthdate, state);
+        std::hash::Hash::hash(&self.gender, state);
+        std::hash::Hash::hash(&self.country_code, state);
+        std::hash::Hash::hash(&self.residence_country_code, state);
+    }
+}
+
 impl PersonalDetails {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPersonalDetailsBuilder {
-        let mut inner = PersonalDetails::default();
-        inner.td_name = "personalDetails".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPersonalDetailsBuilder { inner }
+        RTDPersonalDetailsBuilder {
+            inner: PersonalDetails::default(),
+            missing: vec![
+                "first_name",
+                "last_name",
+                "native_first_name",
+                "native_last_name",
+                "birthdate",
+                "gender",
+                "country_code",
+                "residence_country_code",
+            ],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPersonalDetailsBuilder {
+        RTDPersonalDetailsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPersonalDetailsBuilder {
+        RTDPersonalDetailsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn first_name(&self) -> &String {
@@ -97,20 +152,124 @@ impl PersonalDetails {
     pub fn residence_country_code(&self) -> &String {
         &self.residence_country_code
     }
+
+    pub fn into_first_name(self) -> String {
+        self.first_name
+    }
+
+    pub fn into_middle_name(self) -> String {
+        self.middle_name
+    }
+
+    pub fn into_last_name(self) -> String {
+        self.last_name
+    }
+
+    pub fn into_native_first_name(self) -> String {
+        self.native_first_name
+    }
+
+    pub fn into_native_middle_name(self) -> String {
+        self.native_middle_name
+    }
+
+    pub fn into_native_last_name(self) -> String {
+        self.native_last_name
+    }
+
+    pub fn into_birthdate(self) -> Date {
+        self.birthdate
+    }
+
+    pub fn into_gender(self) -> String {
+        self.gender
+    }
+
+    pub fn into_country_code(self) -> String {
+        self.country_code
+    }
+
+    pub fn into_residence_country_code(self) -> String {
+        self.residence_country_code
+    }
+
+    pub fn with_first_name<T: AsRef<str>>(mut self, first_name: T) -> Self {
+        self.first_name = first_name.as_ref().to_string();
+        self
+    }
+
+    pub fn with_middle_name<T: AsRef<str>>(mut self, middle_name: T) -> Self {
+        self.middle_name = middle_name.as_ref().to_string();
+        self
+    }
+
+    pub fn with_last_name<T: AsRef<str>>(mut self, last_name: T) -> Self {
+        self.last_name = last_name.as_ref().to_string();
+        self
+    }
+
+    pub fn with_native_first_name<T: AsRef<str>>(mut self, native_first_name: T) -> Self {
+        self.native_first_name = native_first_name.as_ref().to_string();
+        self
+    }
+
+    pub fn with_native_middle_name<T: AsRef<str>>(mut self, native_middle_name: T) -> Self {
+        self.native_middle_name = native_middle_name.as_ref().to_string();
+        self
+    }
+
+    pub fn with_native_last_name<T: AsRef<str>>(mut self, native_last_name: T) -> Self {
+        self.native_last_name = native_last_name.as_ref().to_string();
+        self
+    }
+
+    pub fn with_birthdate<T: AsRef<Date>>(mut self, birthdate: T) -> Self {
+        self.birthdate = birthdate.as_ref().clone();
+        self
+    }
+
+    pub fn with_gender<T: AsRef<str>>(mut self, gender: T) -> Self {
+        self.gender = gender.as_ref().to_string();
+        self
+    }
+
+    pub fn with_country_code<T: AsRef<str>>(mut self, country_code: T) -> Self {
+        self.country_code = country_code.as_ref().to_string();
+        self
+    }
+
+    pub fn with_residence_country_code<T: AsRef<str>>(mut self, residence_country_code: T) -> Self {
+        self.residence_country_code = residence_country_code.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPersonalDetailsBuilder {
     inner: PersonalDetails,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPersonalDetailsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PersonalDetails {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PersonalDetails> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PersonalDetails",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn first_name<T: AsRef<str>>(&mut self, first_name: T) -> &mut Self {
         self.inner.first_name = first_name.as_ref().to_string();
+        self.missing.retain(|f| *f != "first_name");
         self
     }
 
@@ -121,11 +280,13 @@ impl RTDPersonalDetailsBuilder {
 
     pub fn last_name<T: AsRef<str>>(&mut self, last_name: T) -> &mut Self {
         self.inner.last_name = last_name.as_ref().to_string();
+        self.missing.retain(|f| *f != "last_name");
         self
     }
 
     pub fn native_first_name<T: AsRef<str>>(&mut self, native_first_name: T) -> &mut Self {
         self.inner.native_first_name = native_first_name.as_ref().to_string();
+        self.missing.retain(|f| *f != "native_first_name");
         self
     }
 
@@ -136,21 +297,25 @@ impl RTDPersonalDetailsBuilder {
 
     pub fn native_last_name<T: AsRef<str>>(&mut self, native_last_name: T) -> &mut Self {
         self.inner.native_last_name = native_last_name.as_ref().to_string();
+        self.missing.retain(|f| *f != "native_last_name");
         self
     }
 
     pub fn birthdate<T: AsRef<Date>>(&mut self, birthdate: T) -> &mut Self {
         self.inner.birthdate = birthdate.as_ref().clone();
+        self.missing.retain(|f| *f != "birthdate");
         self
     }
 
     pub fn gender<T: AsRef<str>>(&mut self, gender: T) -> &mut Self {
         self.inner.gender = gender.as_ref().to_string();
+        self.missing.retain(|f| *f != "gender");
         self
     }
 
     pub fn country_code<T: AsRef<str>>(&mut self, country_code: T) -> &mut Self {
         self.inner.country_code = country_code.as_ref().to_string();
+        self.missing.retain(|f| *f != "country_code");
         self
     }
 
@@ -159,6 +324,7 @@ impl RTDPersonalDetailsBuilder {
         residence_country_code: T,
     ) -> &mut Self {
         self.inner.residence_country_code = residence_country_code.as_ref().to_string();
+        self.missing.retain(|f| *f != "residence_country_code");
         self
     }
 }
@@ -174,3 +340,33 @@ impl AsRef<PersonalDetails> for RTDPersonalDetailsBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            PersonalDetails::builder()
+                .first_name("text")
+                .middle_name("text")
+                .last_name("text")
+                .native_first_name("text")
+                .native_middle_name("text")
+                .native_last_name("text")
+                .birthdate(
+                    Date::builder()
+                        .day(i32::MAX)
+                        .month(i32::MAX)
+                        .year(i32::MAX)
+                        .build(),
+                )
+                .gender("text")
+                .country_code("text")
+                .residence_country_code("text")
+                .build(),
+        );
+    }
+}
@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// A personal document, containing some information about a user
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "personalDocument")]
 pub struct PersonalDocument {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// List of files containing the pages of the document
     files: Vec<DatedFile>,
@@ -31,15 +31,44 @@ impl RObject for PersonalDocument {
     }
 }
 
+impl PartialEq for PersonalDocument {
+    fn eq(&self, other: &Self) -> bool {
+        self.files == other.files && self.translation == other.translation
+    }
+}
+
+impl Eq for PersonalDocument {}
+
+impl std::hash::Hash for PersonalDocument {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.files, state);
+        std::hash::Hash::hash(&self.translation, state);
+    }
+}
+
 impl PersonalDocument {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPersonalDocumentBuilder {
-        let mut inner = PersonalDocument::default();
-        inner.td_name = "personalDocument".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPersonalDocumentBuilder { inner }
+        RTDPersonalDocumentBuilder {
+            inner: PersonalDocument::default(),
+            missing: vec!["files", "translation"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPersonalDocumentBuilder {
+        RTDPersonalDocumentBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPersonalDocumentBuilder {
+        RTDPersonalDocumentBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn files(&self) -> &Vec<DatedFile> {
@@ -49,25 +78,58 @@ impl PersonalDocument {
     pub fn translation(&self) -> &Vec<DatedFile> {
         &self.translation
     }
+
+    pub fn into_files(self) -> Vec<DatedFile> {
+        self.files
+    }
+
+    pub fn into_translation(self) -> Vec<DatedFile> {
+        self.translation
+    }
+
+    pub fn with_files(mut self, files: Vec<DatedFile>) -> Self {
+        self.files = files;
+        self
+    }
+
+    pub fn with_translation(mut self, translation: Vec<DatedFile>) -> Self {
+        self.translation = translation;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPersonalDocumentBuilder {
     inner: PersonalDocument,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPersonalDocumentBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PersonalDocument {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PersonalDocument> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PersonalDocument",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn files(&mut self, files: Vec<DatedFile>) -> &mut Self {
         self.inner.files = files;
+        self.missing.retain(|f| *f != "files");
         self
     }
 
     pub fn translation(&mut self, translation: Vec<DatedFile>) -> &mut Self {
         self.inner.translation = translation;
+        self.missing.retain(|f| *f != "translation");
         self
     }
 }
@@ -83,3 +145,19 @@ impl AsRef<PersonalDocument> for RTDPersonalDocumentBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            PersonalDocument::builder()
+                .files(vec![])
+                .translation(vec![])
+                .build(),
+        );
+    }
+}
@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains settings for the authentication of the user's phone number
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "phoneNumberAuthenticationSettings")]
 pub struct PhoneNumberAuthenticationSettings {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Pass true if the authentication code may be sent via flash call to the specified phone number
     allow_flash_call: bool,
@@ -33,15 +33,47 @@ impl RObject for PhoneNumberAuthenticationSettings {
     }
 }
 
+impl PartialEq for PhoneNumberAuthenticationSettings {
+    fn eq(&self, other: &Self) -> bool {
+        self.allow_flash_call == other.allow_flash_call
+            && self.is_current_phone_number == other.is_current_phone_number
+            && self.allow_sms_retriever_api == other.allow_sms_retriever_api
+    }
+}
+
+impl Eq for PhoneNumberAuthenticationSettings {}
+
+impl std::hash::Hash for PhoneNumberAuthenticationSettings {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.allow_flash_call, state);
+        std::hash::Hash::hash(&self.is_current_phone_number, state);
+        std::hash::Hash::hash(&self.allow_sms_retriever_api, state);
+    }
+}
+
 impl PhoneNumberAuthenticationSettings {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPhoneNumberAuthenticationSettingsBuilder {
-        let mut inner = PhoneNumberAuthenticationSettings::default();
-        inner.td_name = "phoneNumberAuthenticationSettings".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPhoneNumberAuthenticationSettingsBuilder { inner }
+        RTDPhoneNumberAuthenticationSettingsBuilder {
+            inner: PhoneNumberAuthenticationSettings::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPhoneNumberAuthenticationSettingsBuilder {
+        RTDPhoneNumberAuthenticationSettingsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPhoneNumberAuthenticationSettingsBuilder {
+        RTDPhoneNumberAuthenticationSettingsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn allow_flash_call(&self) -> bool {
@@ -55,17 +87,45 @@ impl PhoneNumberAuthenticationSettings {
     pub fn allow_sms_retriever_api(&self) -> bool {
         self.allow_sms_retriever_api
     }
+
+    pub fn with_allow_flash_call(mut self, allow_flash_call: bool) -> Self {
+        self.allow_flash_call = allow_flash_call;
+        self
+    }
+
+    pub fn with_is_current_phone_number(mut self, is_current_phone_number: bool) -> Self {
+        self.is_current_phone_number = is_current_phone_number;
+        self
+    }
+
+    pub fn with_allow_sms_retriever_api(mut self, allow_sms_retriever_api: bool) -> Self {
+        self.allow_sms_retriever_api = allow_sms_retriever_api;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPhoneNumberAuthenticationSettingsBuilder {
     inner: PhoneNumberAuthenticationSettings,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPhoneNumberAuthenticationSettingsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PhoneNumberAuthenticationSettings {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PhoneNumberAuthenticationSettings> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PhoneNumberAuthenticationSettings",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn allow_flash_call(&mut self, allow_flash_call: bool) -> &mut Self {
         self.inner.allow_flash_call = allow_flash_call;
@@ -94,3 +154,20 @@ impl AsRef<PhoneNumberAuthenticationSettings> for RTDPhoneNumberAuthenticationSe
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            PhoneNumberAuthenticationSettings::builder()
+                .allow_flash_call(true)
+                .is_current_phone_number(true)
+                .allow_sms_retriever_api(true)
+                .build(),
+        );
+    }
+}
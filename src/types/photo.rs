@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Describes a photo
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "photo")]
 pub struct Photo {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// True, if stickers were added to the photo
     has_stickers: bool,
@@ -33,15 +33,47 @@ impl RObject for Photo {
     }
 }
 
+impl PartialEq for Photo {
+    fn eq(&self, other: &Self) -> bool {
+        self.has_stickers == other.has_stickers
+            && self.minithumbnail == other.minithumbnail
+            && self.sizes == other.sizes
+    }
+}
+
+impl Eq for Photo {}
+
+impl std::hash::Hash for Photo {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.has_stickers, state);
+        std::hash::Hash::hash(&self.minithumbnail, state);
+        std::hash::Hash::hash(&self.sizes, state);
+    }
+}
+
 impl Photo {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPhotoBuilder {
-        let mut inner = Photo::default();
-        inner.td_name = "photo".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPhotoBuilder { inner }
+        RTDPhotoBuilder {
+            inner: Photo::default(),
+            missing: vec!["sizes"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPhotoBuilder {
+        RTDPhotoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPhotoBuilder {
+        RTDPhotoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn has_stickers(&self) -> bool {
@@ -55,17 +87,50 @@ impl Photo {
     pub fn sizes(&self) -> &Vec<PhotoSize> {
         &self.sizes
     }
+
+    pub fn into_minithumbnail(self) -> Option<Minithumbnail> {
+        self.minithumbnail
+    }
+
+    pub fn into_sizes(self) -> Vec<PhotoSize> {
+        self.sizes
+    }
+
+    pub fn with_has_stickers(mut self, has_stickers: bool) -> Self {
+        self.has_stickers = has_stickers;
+        self
+    }
+
+    pub fn with_minithumbnail<T: AsRef<Minithumbnail>>(mut self, minithumbnail: T) -> Self {
+        self.minithumbnail = Some(minithumbnail.as_ref().clone());
+        self
+    }
+
+    pub fn with_sizes(mut self, sizes: Vec<PhotoSize>) -> Self {
+        self.sizes = sizes;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPhotoBuilder {
     inner: Photo,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPhotoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> Photo {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<Photo> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("Photo", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn has_stickers(&mut self, has_stickers: bool) -> &mut Self {
         self.inner.has_stickers = has_stickers;
@@ -79,6 +144,7 @@ impl RTDPhotoBuilder {
 
     pub fn sizes(&mut self, sizes: Vec<PhotoSize>) -> &mut Self {
         self.inner.sizes = sizes;
+        self.missing.retain(|f| *f != "sizes");
         self
     }
 }
@@ -94,3 +160,14 @@ impl AsRef<Photo> for RTDPhotoBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(Photo::builder().has_stickers(true).sizes(vec![]).build());
+    }
+}
@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Photo description
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "photoSize")]
 pub struct PhotoSize {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Thumbnail type (see https://core.telegram.org/constructor/photoSize)
     #[serde(rename(serialize = "type", deserialize = "type"))]
@@ -17,9 +17,9 @@ pub struct PhotoSize {
     /// Information about the photo file
     photo: File,
     /// Photo width
-    width: i64,
+    width: i32,
     /// Photo height
-    height: i64,
+    height: i32,
 }
 
 impl RObject for PhotoSize {
@@ -36,15 +36,49 @@ impl RObject for PhotoSize {
     }
 }
 
+impl PartialEq for PhotoSize {
+    fn eq(&self, other: &Self) -> bool {
+        self.type_ == other.type_
+            && self.photo == other.photo
+            && self.width == other.width
+            && self.height == other.height
+    }
+}
+
+impl Eq for PhotoSize {}
+
+impl std::hash::Hash for PhotoSize {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.type_, state);
+        std::hash::Hash::hash(&self.photo, state);
+        std::hash::Hash::hash(&self.width, state);
+        std::hash::Hash::hash(&self.height, state);
+    }
+}
+
 impl PhotoSize {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPhotoSizeBuilder {
-        let mut inner = PhotoSize::default();
-        inner.td_name = "photoSize".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPhotoSizeBuilder { inner }
+        RTDPhotoSizeBuilder {
+            inner: PhotoSize::default(),
+            missing: vec!["type_", "photo", "width", "height"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPhotoSizeBuilder {
+        RTDPhotoSizeBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPhotoSizeBuilder {
+        RTDPhotoSizeBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn type_(&self) -> &String {
@@ -55,42 +89,84 @@ impl PhotoSize {
         &self.photo
     }
 
-    pub fn width(&self) -> i64 {
+    pub fn width(&self) -> i32 {
         self.width
     }
 
-    pub fn height(&self) -> i64 {
+    pub fn height(&self) -> i32 {
         self.height
     }
+
+    pub fn into_type(self) -> String {
+        self.type_
+    }
+
+    pub fn into_photo(self) -> File {
+        self.photo
+    }
+
+    pub fn with_type<T: AsRef<str>>(mut self, type_: T) -> Self {
+        self.type_ = type_.as_ref().to_string();
+        self
+    }
+
+    pub fn with_photo<T: AsRef<File>>(mut self, photo: T) -> Self {
+        self.photo = photo.as_ref().clone();
+        self
+    }
+
+    pub fn with_width(mut self, width: i32) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn with_height(mut self, height: i32) -> Self {
+        self.height = height;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPhotoSizeBuilder {
     inner: PhotoSize,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPhotoSizeBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PhotoSize {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PhotoSize> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("PhotoSize", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn type_<T: AsRef<str>>(&mut self, type_: T) -> &mut Self {
         self.inner.type_ = type_.as_ref().to_string();
+        self.missing.retain(|f| *f != "type_");
         self
     }
 
     pub fn photo<T: AsRef<File>>(&mut self, photo: T) -> &mut Self {
         self.inner.photo = photo.as_ref().clone();
+        self.missing.retain(|f| *f != "photo");
         self
     }
 
-    pub fn width(&mut self, width: i64) -> &mut Self {
+    pub fn width(&mut self, width: i32) -> &mut Self {
         self.inner.width = width;
+        self.missing.retain(|f| *f != "width");
         self
     }
 
-    pub fn height(&mut self, height: i64) -> &mut Self {
+    pub fn height(&mut self, height: i32) -> &mut Self {
         self.inner.height = height;
+        self.missing.retain(|f| *f != "height");
         self
     }
 }
@@ -106,3 +182,48 @@ impl AsRef<PhotoSize> for RTDPhotoSizeBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            PhotoSize::builder()
+                .type_("text")
+                .photo(
+                    File::builder()
+                        .id(i32::MAX)
+                        .size(i32::MAX)
+                        .expected_size(i32::MAX)
+                        .local(
+                            LocalFile::builder()
+                                .path("text")
+                                .can_be_downloaded(true)
+                                .can_be_deleted(true)
+                                .is_downloading_active(true)
+                                .is_downloading_completed(true)
+                                .download_offset(i32::MAX)
+                                .downloaded_prefix_size(i32::MAX)
+                                .downloaded_size(i32::MAX)
+                                .build(),
+                        )
+                        .remote(
+                            RemoteFile::builder()
+                                .id("text")
+                                .unique_id("text")
+                                .is_uploading_active(true)
+                                .is_uploading_completed(true)
+                                .uploaded_size(i32::MAX)
+                                .build(),
+                        )
+                        .build(),
+                )
+                .width(i32::MAX)
+                .height(i32::MAX)
+                .build(),
+        );
+    }
+}
@@ -1,27 +1,27 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Describes a poll
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "poll")]
 pub struct Poll {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Unique poll identifier
-    #[serde(deserialize_with = "super::_common::number_from_string")]
-    id: isize,
+    #[serde(with = "super::_common::int64")]
+    id: i64,
     /// Poll question, 1-255 characters
     question: String,
     /// List of poll answer options
     options: Vec<PollOption>,
     /// Total number of voters, participating in the poll
-    total_voter_count: i64,
+    total_voter_count: i32,
     /// User identifiers of recent voters, if the poll is non-anonymous
-    recent_voter_user_ids: Vec<i64>,
+    recent_voter_user_ids: Vec<UserId>,
     /// True, if the poll is anonymous
     is_anonymous: bool,
     /// Type of the poll
@@ -45,18 +45,67 @@ impl RObject for Poll {
     }
 }
 
+impl PartialEq for Poll {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.question == other.question
+            && self.options == other.options
+            && self.total_voter_count == other.total_voter_count
+            && self.recent_voter_user_ids == other.recent_voter_user_ids
+            && self.is_anonymous == other.is_anonymous
+            && self.type_ == other.type_
+            && self.is_closed == other.is_closed
+    }
+}
+
+impl Eq for Poll {}
+
+impl std::hash::Hash for Poll {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.id, state);
+        std::hash::Hash::hash(&self.question, state);
+        std::hash::Hash::hash(&self.options, state);
+        std::hash::Hash::hash(&self.total_voter_count, state);
+        std::hash::Hash::hash(&self.recent_voter_user_ids, state);
+        std::hash::Hash::hash(&self.is_anonymous, state);
+        std::hash::Hash::hash(&self.type_, state);
+        std::hash::Hash::hash(&self.is_closed, state);
+    }
+}
+
 impl Poll {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPollBuilder {
-        let mut inner = Poll::default();
-        inner.td_name = "poll".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPollBuilder { inner }
+        RTDPollBuilder {
+            inner: Poll::default(),
+            missing: vec![
+                "id",
+                "question",
+                "options",
+                "total_voter_count",
+                "recent_voter_user_ids",
+                "type_",
+            ],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPollBuilder {
+        RTDPollBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPollBuilder {
+        RTDPollBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn id(&self) -> isize {
+    pub fn id(&self) -> i64 {
         self.id
     }
 
@@ -68,11 +117,11 @@ impl Poll {
         &self.options
     }
 
-    pub fn total_voter_count(&self) -> i64 {
+    pub fn total_voter_count(&self) -> i32 {
         self.total_voter_count
     }
 
-    pub fn recent_voter_user_ids(&self) -> &Vec<i64> {
+    pub fn recent_voter_user_ids(&self) -> &Vec<UserId> {
         &self.recent_voter_user_ids
     }
 
@@ -87,40 +136,111 @@ impl Poll {
     pub fn is_closed(&self) -> bool {
         self.is_closed
     }
+
+    pub fn into_question(self) -> String {
+        self.question
+    }
+
+    pub fn into_options(self) -> Vec<PollOption> {
+        self.options
+    }
+
+    pub fn into_recent_voter_user_ids(self) -> Vec<UserId> {
+        self.recent_voter_user_ids
+    }
+
+    pub fn into_type(self) -> PollType {
+        self.type_
+    }
+
+    pub fn with_id(mut self, id: i64) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn with_question<T: AsRef<str>>(mut self, question: T) -> Self {
+        self.question = question.as_ref().to_string();
+        self
+    }
+
+    pub fn with_options(mut self, options: Vec<PollOption>) -> Self {
+        self.options = options;
+        self
+    }
+
+    pub fn with_total_voter_count(mut self, total_voter_count: i32) -> Self {
+        self.total_voter_count = total_voter_count;
+        self
+    }
+
+    pub fn with_recent_voter_user_ids(mut self, recent_voter_user_ids: Vec<UserId>) -> Self {
+        self.recent_voter_user_ids = recent_voter_user_ids;
+        self
+    }
+
+    pub fn with_is_anonymous(mut self, is_anonymous: bool) -> Self {
+        self.is_anonymous = is_anonymous;
+        self
+    }
+
+    pub fn with_type<T: AsRef<PollType>>(mut self, type_: T) -> Self {
+        self.type_ = type_.as_ref().clone();
+        self
+    }
+
+    pub fn with_is_closed(mut self, is_closed: bool) -> Self {
+        self.is_closed = is_closed;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPollBuilder {
     inner: Poll,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPollBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> Poll {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<Poll> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("Poll", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn id(&mut self, id: isize) -> &mut Self {
+    pub fn id(&mut self, id: i64) -> &mut Self {
         self.inner.id = id;
+        self.missing.retain(|f| *f != "id");
         self
     }
 
     pub fn question<T: AsRef<str>>(&mut self, question: T) -> &mut Self {
         self.inner.question = question.as_ref().to_string();
+        self.missing.retain(|f| *f != "question");
         self
     }
 
     pub fn options(&mut self, options: Vec<PollOption>) -> &mut Self {
         self.inner.options = options;
+        self.missing.retain(|f| *f != "options");
         self
     }
 
-    pub fn total_voter_count(&mut self, total_voter_count: i64) -> &mut Self {
+    pub fn total_voter_count(&mut self, total_voter_count: i32) -> &mut Self {
         self.inner.total_voter_count = total_voter_count;
+        self.missing.retain(|f| *f != "total_voter_count");
         self
     }
 
-    pub fn recent_voter_user_ids(&mut self, recent_voter_user_ids: Vec<i64>) -> &mut Self {
+    pub fn recent_voter_user_ids(&mut self, recent_voter_user_ids: Vec<UserId>) -> &mut Self {
         self.inner.recent_voter_user_ids = recent_voter_user_ids;
+        self.missing.retain(|f| *f != "recent_voter_user_ids");
         self
     }
 
@@ -131,6 +251,7 @@ impl RTDPollBuilder {
 
     pub fn type_<T: AsRef<PollType>>(&mut self, type_: T) -> &mut Self {
         self.inner.type_ = type_.as_ref().clone();
+        self.missing.retain(|f| *f != "type_");
         self
     }
 
@@ -151,3 +272,27 @@ impl AsRef<Poll> for RTDPollBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            Poll::builder()
+                .id(i64::MAX)
+                .question("text")
+                .options(vec![])
+                .total_voter_count(i32::MAX)
+                .recent_voter_user_ids(vec![Default::default()])
+                .is_anonymous(true)
+                .type_(PollType::Quiz(
+                    PollTypeQuiz::builder().correct_option_id(i32::MAX).build(),
+                ))
+                .is_closed(true)
+                .build(),
+        );
+    }
+}
@@ -1,22 +1,22 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Describes one answer option of a poll
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pollOption")]
 pub struct PollOption {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Option text, 1-100 characters
     text: String,
     /// Number of voters for this option, available only for closed or voted polls
-    voter_count: i64,
+    voter_count: i32,
     /// The percentage of votes for this option, 0-100
-    vote_percentage: i64,
+    vote_percentage: i32,
     /// True, if the option was chosen by the user
     is_chosen: bool,
     /// True, if the option is being chosen by a pending setPollAnswer request
@@ -37,26 +37,62 @@ impl RObject for PollOption {
     }
 }
 
+impl PartialEq for PollOption {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text
+            && self.voter_count == other.voter_count
+            && self.vote_percentage == other.vote_percentage
+            && self.is_chosen == other.is_chosen
+            && self.is_being_chosen == other.is_being_chosen
+    }
+}
+
+impl Eq for PollOption {}
+
+impl std::hash::Hash for PollOption {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.text, state);
+        std::hash::Hash::hash(&self.voter_count, state);
+        std::hash::Hash::hash(&self.vote_percentage, state);
+        std::hash::Hash::hash(&self.is_chosen, state);
+        std::hash::Hash::hash(&self.is_being_chosen, state);
+    }
+}
+
 impl PollOption {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPollOptionBuilder {
-        let mut inner = PollOption::default();
-        inner.td_name = "pollOption".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPollOptionBuilder { inner }
+        RTDPollOptionBuilder {
+            inner: PollOption::default(),
+            missing: vec!["text", "voter_count"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPollOptionBuilder {
+        RTDPollOptionBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPollOptionBuilder {
+        RTDPollOptionBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn text(&self) -> &String {
         &self.text
     }
 
-    pub fn voter_count(&self) -> i64 {
+    pub fn voter_count(&self) -> i32 {
         self.voter_count
     }
 
-    pub fn vote_percentage(&self) -> i64 {
+    pub fn vote_percentage(&self) -> i32 {
         self.vote_percentage
     }
 
@@ -67,29 +103,70 @@ impl PollOption {
     pub fn is_being_chosen(&self) -> bool {
         self.is_being_chosen
     }
+
+    pub fn into_text(self) -> String {
+        self.text
+    }
+
+    pub fn with_text<T: AsRef<str>>(mut self, text: T) -> Self {
+        self.text = text.as_ref().to_string();
+        self
+    }
+
+    pub fn with_voter_count(mut self, voter_count: i32) -> Self {
+        self.voter_count = voter_count;
+        self
+    }
+
+    pub fn with_vote_percentage(mut self, vote_percentage: i32) -> Self {
+        self.vote_percentage = vote_percentage;
+        self
+    }
+
+    pub fn with_is_chosen(mut self, is_chosen: bool) -> Self {
+        self.is_chosen = is_chosen;
+        self
+    }
+
+    pub fn with_is_being_chosen(mut self, is_being_chosen: bool) -> Self {
+        self.is_being_chosen = is_being_chosen;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPollOptionBuilder {
     inner: PollOption,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPollOptionBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PollOption {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PollOption> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("PollOption", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn text<T: AsRef<str>>(&mut self, text: T) -> &mut Self {
         self.inner.text = text.as_ref().to_string();
+        self.missing.retain(|f| *f != "text");
         self
     }
 
-    pub fn voter_count(&mut self, voter_count: i64) -> &mut Self {
+    pub fn voter_count(&mut self, voter_count: i32) -> &mut Self {
         self.inner.voter_count = voter_count;
+        self.missing.retain(|f| *f != "voter_count");
         self
     }
 
-    pub fn vote_percentage(&mut self, vote_percentage: i64) -> &mut Self {
+    pub fn vote_percentage(&mut self, vote_percentage: i32) -> &mut Self {
         self.inner.vote_percentage = vote_percentage;
         self
     }
@@ -116,3 +193,22 @@ impl AsRef<PollOption> for RTDPollOptionBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            PollOption::builder()
+                .text("text")
+                .voter_count(i32::MAX)
+                .vote_percentage(i32::MAX)
+                .is_chosen(true)
+                .is_being_chosen(true)
+                .build(),
+        );
+    }
+}
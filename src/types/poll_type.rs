@@ -1,43 +1,40 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Describes the type of a poll
 pub trait TDPollType: Debug + RObject {}
 
 /// Describes the type of a poll
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum PollType {
-    #[doc(hidden)]
-    _Default(()),
     /// A poll in quiz mode, which has exactly one correct answer option and can be answered only once
+    #[serde(rename = "pollTypeQuiz")]
     Quiz(PollTypeQuiz),
     /// A regular poll
+    #[serde(rename = "pollTypeRegular")]
     Regular(PollTypeRegular),
 }
 
+/// Default value is default of [PollTypeQuiz], builders still require the field to be set
 impl Default for PollType {
     fn default() -> Self {
-        PollType::_Default(())
+        PollType::Quiz(PollTypeQuiz::default())
     }
 }
 
-impl<'de> Deserialize<'de> for PollType {
-    fn deserialize<D>(deserializer: D) -> Result<PollType, D::Error>
+impl Serialize for PollType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          PollType,
-          (pollTypeQuiz, Quiz);
-          (pollTypeRegular, Regular);
-
-        )(deserializer)
+        match self {
+            PollType::Quiz(t) => t.serialize(serializer),
+            PollType::Regular(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -47,8 +44,6 @@ impl RObject for PollType {
         match self {
             PollType::Quiz(t) => t.td_name(),
             PollType::Regular(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -56,8 +51,6 @@ impl RObject for PollType {
         match self {
             PollType::Quiz(t) => t.extra(),
             PollType::Regular(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -69,10 +62,6 @@ impl PollType {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, PollType::_Default(_))
-    }
 }
 
 impl AsRef<PollType> for PollType {
@@ -83,15 +72,16 @@ impl AsRef<PollType> for PollType {
 
 /// A poll in quiz mode, which has exactly one correct answer option and can be answered only once
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pollTypeQuiz")]
 pub struct PollTypeQuiz {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// 0-based identifier of the correct answer option; 1 for a yet unanswered poll
-    correct_option_id: i64,
+    correct_option_id: i32,
 }
 
 impl RObject for PollTypeQuiz {
@@ -108,6 +98,20 @@ impl RObject for PollTypeQuiz {
     }
 }
 
+impl PartialEq for PollTypeQuiz {
+    fn eq(&self, other: &Self) -> bool {
+        self.correct_option_id == other.correct_option_id
+    }
+}
+
+impl Eq for PollTypeQuiz {}
+
+impl std::hash::Hash for PollTypeQuiz {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.correct_option_id, state);
+    }
+}
+
 impl TDPollType for PollTypeQuiz {}
 
 impl PollTypeQuiz {
@@ -115,28 +119,60 @@ impl PollTypeQuiz {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPollTypeQuizBuilder {
-        let mut inner = PollTypeQuiz::default();
-        inner.td_name = "pollTypeQuiz".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPollTypeQuizBuilder { inner }
+        RTDPollTypeQuizBuilder {
+            inner: PollTypeQuiz::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPollTypeQuizBuilder {
+        RTDPollTypeQuizBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPollTypeQuizBuilder {
+        RTDPollTypeQuizBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn correct_option_id(&self) -> i64 {
+    pub fn correct_option_id(&self) -> i32 {
         self.correct_option_id
     }
+
+    pub fn with_correct_option_id(mut self, correct_option_id: i32) -> Self {
+        self.correct_option_id = correct_option_id;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPollTypeQuizBuilder {
     inner: PollTypeQuiz,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPollTypeQuizBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PollTypeQuiz {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PollTypeQuiz> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PollTypeQuiz",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn correct_option_id(&mut self, correct_option_id: i64) -> &mut Self {
+    pub fn correct_option_id(&mut self, correct_option_id: i32) -> &mut Self {
         self.inner.correct_option_id = correct_option_id;
         self
     }
@@ -156,12 +192,13 @@ impl AsRef<PollTypeQuiz> for RTDPollTypeQuizBuilder {
 
 /// A regular poll
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pollTypeRegular")]
 pub struct PollTypeRegular {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// True, if multiple answer options can be chosen simultaneously
     allow_multiple_answers: bool,
@@ -181,6 +218,20 @@ impl RObject for PollTypeRegular {
     }
 }
 
+impl PartialEq for PollTypeRegular {
+    fn eq(&self, other: &Self) -> bool {
+        self.allow_multiple_answers == other.allow_multiple_answers
+    }
+}
+
+impl Eq for PollTypeRegular {}
+
+impl std::hash::Hash for PollTypeRegular {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.allow_multiple_answers, state);
+    }
+}
+
 impl TDPollType for PollTypeRegular {}
 
 impl PollTypeRegular {
@@ -188,26 +239,58 @@ impl PollTypeRegular {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPollTypeRegularBuilder {
-        let mut inner = PollTypeRegular::default();
-        inner.td_name = "pollTypeRegular".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPollTypeRegularBuilder { inner }
+        RTDPollTypeRegularBuilder {
+            inner: PollTypeRegular::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPollTypeRegularBuilder {
+        RTDPollTypeRegularBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPollTypeRegularBuilder {
+        RTDPollTypeRegularBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn allow_multiple_answers(&self) -> bool {
         self.allow_multiple_answers
     }
+
+    pub fn with_allow_multiple_answers(mut self, allow_multiple_answers: bool) -> Self {
+        self.allow_multiple_answers = allow_multiple_answers;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPollTypeRegularBuilder {
     inner: PollTypeRegular,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPollTypeRegularBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PollTypeRegular {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PollTypeRegular> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PollTypeRegular",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn allow_multiple_answers(&mut self, allow_multiple_answers: bool) -> &mut Self {
         self.inner.allow_multiple_answers = allow_multiple_answers;
@@ -226,3 +309,21 @@ impl AsRef<PollTypeRegular> for RTDPollTypeRegularBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(PollType::Quiz(
+            PollTypeQuiz::builder().correct_option_id(i32::MAX).build(),
+        ));
+        assert_round_trip(PollType::Regular(
+            PollTypeRegular::builder()
+                .allow_multiple_answers(true)
+                .build(),
+        ));
+    }
+}
@@ -1,19 +1,19 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Describes a user profile photo
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "profilePhoto")]
 pub struct ProfilePhoto {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Photo identifier; 0 for an empty photo. Can be used to find a photo in a list of userProfilePhotos
-    #[serde(deserialize_with = "super::_common::number_from_string")]
-    id: isize,
+    #[serde(with = "super::_common::int64")]
+    id: i64,
     /// A small (160x160) user profile photo. The file can be downloaded only before the photo is changed
     small: File,
     /// A big (640x640) user profile photo. The file can be downloaded only before the photo is changed
@@ -34,18 +34,48 @@ impl RObject for ProfilePhoto {
     }
 }
 
+impl PartialEq for ProfilePhoto {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id && self.small == other.small && self.big == other.big
+    }
+}
+
+impl Eq for ProfilePhoto {}
+
+impl std::hash::Hash for ProfilePhoto {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.id, state);
+        std::hash::Hash::hash(&self.small, state);
+        std::hash::Hash::hash(&self.big, state);
+    }
+}
+
 impl ProfilePhoto {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDProfilePhotoBuilder {
-        let mut inner = ProfilePhoto::default();
-        inner.td_name = "profilePhoto".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDProfilePhotoBuilder { inner }
+        RTDProfilePhotoBuilder {
+            inner: ProfilePhoto::default(),
+            missing: vec!["small", "big"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDProfilePhotoBuilder {
+        RTDProfilePhotoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDProfilePhotoBuilder {
+        RTDProfilePhotoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn id(&self) -> isize {
+    pub fn id(&self) -> i64 {
         self.id
     }
 
@@ -56,30 +86,68 @@ impl ProfilePhoto {
     pub fn big(&self) -> &File {
         &self.big
     }
+
+    pub fn into_small(self) -> File {
+        self.small
+    }
+
+    pub fn into_big(self) -> File {
+        self.big
+    }
+
+    pub fn with_id(mut self, id: i64) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn with_small<T: AsRef<File>>(mut self, small: T) -> Self {
+        self.small = small.as_ref().clone();
+        self
+    }
+
+    pub fn with_big<T: AsRef<File>>(mut self, big: T) -> Self {
+        self.big = big.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDProfilePhotoBuilder {
     inner: ProfilePhoto,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDProfilePhotoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ProfilePhoto {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ProfilePhoto> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ProfilePhoto",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn id(&mut self, id: isize) -> &mut Self {
+    pub fn id(&mut self, id: i64) -> &mut Self {
         self.inner.id = id;
         self
     }
 
     pub fn small<T: AsRef<File>>(&mut self, small: T) -> &mut Self {
         self.inner.small = small.as_ref().clone();
+        self.missing.retain(|f| *f != "small");
         self
     }
 
     pub fn big<T: AsRef<File>>(&mut self, big: T) -> &mut Self {
         self.inner.big = big.as_ref().clone();
+        self.missing.retain(|f| *f != "big");
         self
     }
 }
@@ -95,3 +163,74 @@ impl AsRef<ProfilePhoto> for RTDProfilePhotoBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            ProfilePhoto::builder()
+                .id(i64::MAX)
+                .small(
+                    File::builder()
+                        .id(i32::MAX)
+                        .size(i32::MAX)
+                        .expected_size(i32::MAX)
+                        .local(
+                            LocalFile::builder()
+                                .path("text")
+                                .can_be_downloaded(true)
+                                .can_be_deleted(true)
+                                .is_downloading_active(true)
+                                .is_downloading_completed(true)
+                                .download_offset(i32::MAX)
+                                .downloaded_prefix_size(i32::MAX)
+                                .downloaded_size(i32::MAX)
+                                .build(),
+                        )
+                        .remote(
+                            RemoteFile::builder()
+                                .id("text")
+                                .unique_id("text")
+                                .is_uploading_active(true)
+                                .is_uploading_completed(true)
+                                .uploaded_size(i32::MAX)
+                                .build(),
+                        )
+                        .build(),
+                )
+                .big(
+                    File::builder()
+                        .id(i32::MAX)
+                        .size(i32::MAX)
+                        .expected_size(i32::MAX)
+                        .local(
+                            LocalFile::builder()
+                                .path("text")
+                                .can_be_downloaded(true)
+                                .can_be_deleted(true)
+                                .is_downloading_active(true)
+                                .is_downloading_completed(true)
+                                .download_offset(i32::MAX)
+                                .downloaded_prefix_size(i32::MAX)
+                                .downloaded_size(i32::MAX)
+                                .build(),
+                        )
+                        .remote(
+                            RemoteFile::builder()
+                                .id("text")
+                                .unique_id("text")
+                                .is_uploading_active(true)
+                                .is_uploading_completed(true)
+                                .uploaded_size(i32::MAX)
+                                .build(),
+                        )
+                        .build(),
+                )
+                .build(),
+        );
+    }
+}
@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Represents a list of proxy servers
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "proxies")]
 pub struct Proxies {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// List of proxy servers
     proxies: Vec<Proxy>,
@@ -29,34 +29,82 @@ impl RObject for Proxies {
     }
 }
 
+impl PartialEq for Proxies {
+    fn eq(&self, other: &Self) -> bool {
+        self.proxies == other.proxies
+    }
+}
+
+impl Eq for Proxies {}
+
+impl std::hash::Hash for Proxies {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.proxies, state);
+    }
+}
+
 impl Proxies {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDProxiesBuilder {
-        let mut inner = Proxies::default();
-        inner.td_name = "proxies".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDProxiesBuilder { inner }
+        RTDProxiesBuilder {
+            inner: Proxies::default(),
+            missing: vec!["proxies"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDProxiesBuilder {
+        RTDProxiesBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDProxiesBuilder {
+        RTDProxiesBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn proxies(&self) -> &Vec<Proxy> {
         &self.proxies
     }
+
+    pub fn into_proxies(self) -> Vec<Proxy> {
+        self.proxies
+    }
+
+    pub fn with_proxies(mut self, proxies: Vec<Proxy>) -> Self {
+        self.proxies = proxies;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDProxiesBuilder {
     inner: Proxies,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDProxiesBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> Proxies {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<Proxies> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("Proxies", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn proxies(&mut self, proxies: Vec<Proxy>) -> &mut Self {
         self.inner.proxies = proxies;
+        self.missing.retain(|f| *f != "proxies");
         self
     }
 }
@@ -72,3 +120,14 @@ impl AsRef<Proxies> for RTDProxiesBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(Proxies::builder().proxies(vec![]).build());
+    }
+}
@@ -1,24 +1,24 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains information about a proxy server
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "proxy")]
 pub struct Proxy {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Unique identifier of the proxy
-    id: i64,
+    id: i32,
     /// Proxy server IP address
     server: String,
     /// Proxy server port
-    port: i64,
+    port: i32,
     /// Point in time (Unix timestamp) when the proxy was last used; 0 if never
-    last_used_date: i64,
+    last_used_date: i32,
     /// True, if the proxy is enabled now
     is_enabled: bool,
     /// Type of the proxy
@@ -40,18 +40,56 @@ impl RObject for Proxy {
     }
 }
 
+impl PartialEq for Proxy {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.server == other.server
+            && self.port == other.port
+            && self.last_used_date == other.last_used_date
+            && self.is_enabled == other.is_enabled
+            && self.type_ == other.type_
+    }
+}
+
+impl Eq for Proxy {}
+
+impl std::hash::Hash for Proxy {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.id, state);
+        std::hash::Hash::hash(&self.server, state);
+        std::hash::Hash::hash(&self.port, state);
+        std::hash::Hash::hash(&self.last_used_date, state);
+        std::hash::Hash::hash(&self.is_enabled, state);
+        std::hash::Hash::hash(&self.type_, state);
+    }
+}
+
 impl Proxy {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDProxyBuilder {
-        let mut inner = Proxy::default();
-        inner.td_name = "proxy".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDProxyBuilder { inner }
-    }
-
-    pub fn id(&self) -> i64 {
+        RTDProxyBuilder {
+            inner: Proxy::default(),
+            missing: vec!["id", "server", "port", "type_"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDProxyBuilder {
+        RTDProxyBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDProxyBuilder {
+        RTDProxyBuilder {
+            inner: self,
+            missing: vec![],
+        }
+    }
+
+    pub fn id(&self) -> i32 {
         self.id
     }
 
@@ -59,11 +97,11 @@ impl Proxy {
         &self.server
     }
 
-    pub fn port(&self) -> i64 {
+    pub fn port(&self) -> i32 {
         self.port
     }
 
-    pub fn last_used_date(&self) -> i64 {
+    pub fn last_used_date(&self) -> i32 {
         self.last_used_date
     }
 
@@ -74,34 +112,85 @@ impl Proxy {
     pub fn type_(&self) -> &ProxyType {
         &self.type_
     }
+
+    pub fn into_server(self) -> String {
+        self.server
+    }
+
+    pub fn into_type(self) -> ProxyType {
+        self.type_
+    }
+
+    pub fn with_id(mut self, id: i32) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn with_server<T: AsRef<str>>(mut self, server: T) -> Self {
+        self.server = server.as_ref().to_string();
+        self
+    }
+
+    pub fn with_port(mut self, port: i32) -> Self {
+        self.port = port;
+        self
+    }
+
+    pub fn with_last_used_date(mut self, last_used_date: i32) -> Self {
+        self.last_used_date = last_used_date;
+        self
+    }
+
+    pub fn with_is_enabled(mut self, is_enabled: bool) -> Self {
+        self.is_enabled = is_enabled;
+        self
+    }
+
+    pub fn with_type<T: AsRef<ProxyType>>(mut self, type_: T) -> Self {
+        self.type_ = type_.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDProxyBuilder {
     inner: Proxy,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDProxyBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> Proxy {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<Proxy> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("Proxy", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn id(&mut self, id: i64) -> &mut Self {
+    pub fn id(&mut self, id: i32) -> &mut Self {
         self.inner.id = id;
+        self.missing.retain(|f| *f != "id");
         self
     }
 
     pub fn server<T: AsRef<str>>(&mut self, server: T) -> &mut Self {
         self.inner.server = server.as_ref().to_string();
+        self.missing.retain(|f| *f != "server");
         self
     }
 
-    pub fn port(&mut self, port: i64) -> &mut Self {
+    pub fn port(&mut self, port: i32) -> &mut Self {
         self.inner.port = port;
+        self.missing.retain(|f| *f != "port");
         self
     }
 
-    pub fn last_used_date(&mut self, last_used_date: i64) -> &mut Self {
+    pub fn last_used_date(&mut self, last_used_date: i32) -> &mut Self {
         self.inner.last_used_date = last_used_date;
         self
     }
@@ -113,6 +202,7 @@ impl RTDProxyBuilder {
 
     pub fn type_<T: AsRef<ProxyType>>(&mut self, type_: T) -> &mut Self {
         self.inner.type_ = type_.as_ref().clone();
+        self.missing.retain(|f| *f != "type_");
         self
     }
 }
@@ -128,3 +218,29 @@ impl AsRef<Proxy> for RTDProxyBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            Proxy::builder()
+                .id(i32::MAX)
+                .server("text")
+                .port(i32::MAX)
+                .last_used_date(i32::MAX)
+                .is_enabled(true)
+                .type_(ProxyType::Http(
+                    ProxyTypeHttp::builder()
+                        .username("text")
+                        .password("text")
+                        .http_only(true)
+                        .build(),
+                ))
+                .build(),
+        );
+    }
+}
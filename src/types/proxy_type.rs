@@ -1,46 +1,44 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Describes the type of a proxy server
 pub trait TDProxyType: Debug + RObject {}
 
 /// Describes the type of a proxy server
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum ProxyType {
-    #[doc(hidden)]
-    _Default(()),
     /// A HTTP transparent proxy server
+    #[serde(rename = "proxyTypeHttp")]
     Http(ProxyTypeHttp),
     /// An MTProto proxy server
+    #[serde(rename = "proxyTypeMtproto")]
     Mtproto(ProxyTypeMtproto),
     /// A SOCKS5 proxy server
+    #[serde(rename = "proxyTypeSocks5")]
     Socks5(ProxyTypeSocks5),
 }
 
+/// Default value is default of [ProxyTypeHttp], builders still require the field to be set
 impl Default for ProxyType {
     fn default() -> Self {
-        ProxyType::_Default(())
+        ProxyType::Http(ProxyTypeHttp::default())
     }
 }
 
-impl<'de> Deserialize<'de> for ProxyType {
-    fn deserialize<D>(deserializer: D) -> Result<ProxyType, D::Error>
+impl Serialize for ProxyType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          ProxyType,
-          (proxyTypeHttp, Http);
-          (proxyTypeMtproto, Mtproto);
-          (proxyTypeSocks5, Socks5);
-
-        )(deserializer)
+        match self {
+            ProxyType::Http(t) => t.serialize(serializer),
+            ProxyType::Mtproto(t) => t.serialize(serializer),
+            ProxyType::Socks5(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -51,8 +49,6 @@ impl RObject for ProxyType {
             ProxyType::Http(t) => t.td_name(),
             ProxyType::Mtproto(t) => t.td_name(),
             ProxyType::Socks5(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -61,8 +57,6 @@ impl RObject for ProxyType {
             ProxyType::Http(t) => t.extra(),
             ProxyType::Mtproto(t) => t.extra(),
             ProxyType::Socks5(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -74,10 +68,6 @@ impl ProxyType {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, ProxyType::_Default(_))
-    }
 }
 
 impl AsRef<ProxyType> for ProxyType {
@@ -88,12 +78,13 @@ impl AsRef<ProxyType> for ProxyType {
 
 /// A HTTP transparent proxy server
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "proxyTypeHttp")]
 pub struct ProxyTypeHttp {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Username for logging in; may be empty
     username: String,
@@ -117,6 +108,24 @@ impl RObject for ProxyTypeHttp {
     }
 }
 
+impl PartialEq for ProxyTypeHttp {
+    fn eq(&self, other: &Self) -> bool {
+        self.username == other.username
+            && self.password == other.password
+            && self.http_only == other.http_only
+    }
+}
+
+impl Eq for ProxyTypeHttp {}
+
+impl std::hash::Hash for ProxyTypeHttp {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.username, state);
+        std::hash::Hash::hash(&self.password, state);
+        std::hash::Hash::hash(&self.http_only, state);
+    }
+}
+
 impl TDProxyType for ProxyTypeHttp {}
 
 impl ProxyTypeHttp {
@@ -124,10 +133,24 @@ impl ProxyTypeHttp {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDProxyTypeHttpBuilder {
-        let mut inner = ProxyTypeHttp::default();
-        inner.td_name = "proxyTypeHttp".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDProxyTypeHttpBuilder { inner }
+        RTDProxyTypeHttpBuilder {
+            inner: ProxyTypeHttp::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDProxyTypeHttpBuilder {
+        RTDProxyTypeHttpBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDProxyTypeHttpBuilder {
+        RTDProxyTypeHttpBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn username(&self) -> &String {
@@ -141,17 +164,53 @@ impl ProxyTypeHttp {
     pub fn http_only(&self) -> bool {
         self.http_only
     }
+
+    pub fn into_username(self) -> String {
+        self.username
+    }
+
+    pub fn into_password(self) -> String {
+        self.password
+    }
+
+    pub fn with_username<T: AsRef<str>>(mut self, username: T) -> Self {
+        self.username = username.as_ref().to_string();
+        self
+    }
+
+    pub fn with_password<T: AsRef<str>>(mut self, password: T) -> Self {
+        self.password = password.as_ref().to_string();
+        self
+    }
+
+    pub fn with_http_only(mut self, http_only: bool) -> Self {
+        self.http_only = http_only;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDProxyTypeHttpBuilder {
     inner: ProxyTypeHttp,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDProxyTypeHttpBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ProxyTypeHttp {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ProxyTypeHttp> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ProxyTypeHttp",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn username<T: AsRef<str>>(&mut self, username: T) -> &mut Self {
         self.inner.username = username.as_ref().to_string();
@@ -183,12 +242,13 @@ impl AsRef<ProxyTypeHttp> for RTDProxyTypeHttpBuilder {
 
 /// An MTProto proxy server
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "proxyTypeMtproto")]
 pub struct ProxyTypeMtproto {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The proxy's secret in hexadecimal encoding
     secret: String,
@@ -208,6 +268,20 @@ impl RObject for ProxyTypeMtproto {
     }
 }
 
+impl PartialEq for ProxyTypeMtproto {
+    fn eq(&self, other: &Self) -> bool {
+        self.secret == other.secret
+    }
+}
+
+impl Eq for ProxyTypeMtproto {}
+
+impl std::hash::Hash for ProxyTypeMtproto {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.secret, state);
+    }
+}
+
 impl TDProxyType for ProxyTypeMtproto {}
 
 impl ProxyTypeMtproto {
@@ -215,29 +289,66 @@ impl ProxyTypeMtproto {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDProxyTypeMtprotoBuilder {
-        let mut inner = ProxyTypeMtproto::default();
-        inner.td_name = "proxyTypeMtproto".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDProxyTypeMtprotoBuilder { inner }
+        RTDProxyTypeMtprotoBuilder {
+            inner: ProxyTypeMtproto::default(),
+            missing: vec!["secret"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDProxyTypeMtprotoBuilder {
+        RTDProxyTypeMtprotoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDProxyTypeMtprotoBuilder {
+        RTDProxyTypeMtprotoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn secret(&self) -> &String {
         &self.secret
     }
+
+    pub fn into_secret(self) -> String {
+        self.secret
+    }
+
+    pub fn with_secret<T: AsRef<str>>(mut self, secret: T) -> Self {
+        self.secret = secret.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDProxyTypeMtprotoBuilder {
     inner: ProxyTypeMtproto,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDProxyTypeMtprotoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ProxyTypeMtproto {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ProxyTypeMtproto> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ProxyTypeMtproto",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn secret<T: AsRef<str>>(&mut self, secret: T) -> &mut Self {
         self.inner.secret = secret.as_ref().to_string();
+        self.missing.retain(|f| *f != "secret");
         self
     }
 }
@@ -256,12 +367,13 @@ impl AsRef<ProxyTypeMtproto> for RTDProxyTypeMtprotoBuilder {
 
 /// A SOCKS5 proxy server
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "proxyTypeSocks5")]
 pub struct ProxyTypeSocks5 {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Username for logging in; may be empty
     username: String,
@@ -283,6 +395,21 @@ impl RObject for ProxyTypeSocks5 {
     }
 }
 
+impl PartialEq for ProxyTypeSocks5 {
+    fn eq(&self, other: &Self) -> bool {
+        self.username == other.username && self.password == other.password
+    }
+}
+
+impl Eq for ProxyTypeSocks5 {}
+
+impl std::hash::Hash for ProxyTypeSocks5 {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.username, state);
+        std::hash::Hash::hash(&self.password, state);
+    }
+}
+
 impl TDProxyType for ProxyTypeSocks5 {}
 
 impl ProxyTypeSocks5 {
@@ -290,10 +417,24 @@ impl ProxyTypeSocks5 {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDProxyTypeSocks5Builder {
-        let mut inner = ProxyTypeSocks5::default();
-        inner.td_name = "proxyTypeSocks5".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDProxyTypeSocks5Builder { inner }
+        RTDProxyTypeSocks5Builder {
+            inner: ProxyTypeSocks5::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDProxyTypeSocks5Builder {
+        RTDProxyTypeSocks5Builder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDProxyTypeSocks5Builder {
+        RTDProxyTypeSocks5Builder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn username(&self) -> &String {
@@ -303,17 +444,48 @@ impl ProxyTypeSocks5 {
     pub fn password(&self) -> &String {
         &self.password
     }
+
+    pub fn into_username(self) -> String {
+        self.username
+    }
+
+    pub fn into_password(self) -> String {
+        self.password
+    }
+
+    pub fn with_username<T: AsRef<str>>(mut self, username: T) -> Self {
+        self.username = username.as_ref().to_string();
+        self
+    }
+
+    pub fn with_password<T: AsRef<str>>(mut self, password: T) -> Self {
+        self.password = password.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDProxyTypeSocks5Builder {
     inner: ProxyTypeSocks5,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDProxyTypeSocks5Builder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ProxyTypeSocks5 {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ProxyTypeSocks5> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ProxyTypeSocks5",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn username<T: AsRef<str>>(&mut self, username: T) -> &mut Self {
         self.inner.username = username.as_ref().to_string();
@@ -337,3 +509,29 @@ impl AsRef<ProxyTypeSocks5> for RTDProxyTypeSocks5Builder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(ProxyType::Http(
+            ProxyTypeHttp::builder()
+                .username("text")
+                .password("text")
+                .http_only(true)
+                .build(),
+        ));
+        assert_round_trip(ProxyType::Mtproto(
+            ProxyTypeMtproto::builder().secret("text").build(),
+        ));
+        assert_round_trip(ProxyType::Socks5(
+            ProxyTypeSocks5::builder()
+                .username("text")
+                .password("text")
+                .build(),
+        ));
+    }
+}
@@ -1,43 +1,40 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Describes a type of public chats
 pub trait TDPublicChatType: Debug + RObject {}
 
 /// Describes a type of public chats
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum PublicChatType {
-    #[doc(hidden)]
-    _Default(()),
     /// The chat is public, because it has username
+    #[serde(rename = "publicChatTypeHasUsername")]
     HasUsername(PublicChatTypeHasUsername),
     /// The chat is public, because it is a location-based supergroup
+    #[serde(rename = "publicChatTypeIsLocationBased")]
     IsLocationBased(PublicChatTypeIsLocationBased),
 }
 
+/// Default value is default of [PublicChatTypeHasUsername], builders still require the field to be set
 impl Default for PublicChatType {
     fn default() -> Self {
-        PublicChatType::_Default(())
+        PublicChatType::HasUsername(PublicChatTypeHasUsername::default())
     }
 }
 
-impl<'de> Deserialize<'de> for PublicChatType {
-    fn deserialize<D>(deserializer: D) -> Result<PublicChatType, D::Error>
+impl Serialize for PublicChatType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          PublicChatType,
-          (publicChatTypeHasUsername, HasUsername);
-          (publicChatTypeIsLocationBased, IsLocationBased);
-
-        )(deserializer)
+        match self {
+            PublicChatType::HasUsername(t) => t.serialize(serializer),
+            PublicChatType::IsLocationBased(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -47,8 +44,6 @@ impl RObject for PublicChatType {
         match self {
             PublicChatType::HasUsername(t) => t.td_name(),
             PublicChatType::IsLocationBased(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -56,8 +51,6 @@ impl RObject for PublicChatType {
         match self {
             PublicChatType::HasUsername(t) => t.extra(),
             PublicChatType::IsLocationBased(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -69,10 +62,6 @@ impl PublicChatType {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, PublicChatType::_Default(_))
-    }
 }
 
 impl AsRef<PublicChatType> for PublicChatType {
@@ -83,12 +72,13 @@ impl AsRef<PublicChatType> for PublicChatType {
 
 /// The chat is public, because it has username
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "publicChatTypeHasUsername")]
 pub struct PublicChatTypeHasUsername {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -106,6 +96,18 @@ impl RObject for PublicChatTypeHasUsername {
     }
 }
 
+impl PartialEq for PublicChatTypeHasUsername {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for PublicChatTypeHasUsername {}
+
+impl std::hash::Hash for PublicChatTypeHasUsername {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDPublicChatType for PublicChatTypeHasUsername {}
 
 impl PublicChatTypeHasUsername {
@@ -113,22 +115,49 @@ impl PublicChatTypeHasUsername {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPublicChatTypeHasUsernameBuilder {
-        let mut inner = PublicChatTypeHasUsername::default();
-        inner.td_name = "publicChatTypeHasUsername".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPublicChatTypeHasUsernameBuilder { inner }
+        RTDPublicChatTypeHasUsernameBuilder {
+            inner: PublicChatTypeHasUsername::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPublicChatTypeHasUsernameBuilder {
+        RTDPublicChatTypeHasUsernameBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPublicChatTypeHasUsernameBuilder {
+        RTDPublicChatTypeHasUsernameBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDPublicChatTypeHasUsernameBuilder {
     inner: PublicChatTypeHasUsername,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPublicChatTypeHasUsernameBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PublicChatTypeHasUsername {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PublicChatTypeHasUsername> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PublicChatTypeHasUsername",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<PublicChatTypeHasUsername> for PublicChatTypeHasUsername {
@@ -145,12 +174,13 @@ impl AsRef<PublicChatTypeHasUsername> for RTDPublicChatTypeHasUsernameBuilder {
 
 /// The chat is public, because it is a location-based supergroup
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "publicChatTypeIsLocationBased")]
 pub struct PublicChatTypeIsLocationBased {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -168,6 +198,18 @@ impl RObject for PublicChatTypeIsLocationBased {
     }
 }
 
+impl PartialEq for PublicChatTypeIsLocationBased {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for PublicChatTypeIsLocationBased {}
+
+impl std::hash::Hash for PublicChatTypeIsLocationBased {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDPublicChatType for PublicChatTypeIsLocationBased {}
 
 impl PublicChatTypeIsLocationBased {
@@ -175,22 +217,49 @@ impl PublicChatTypeIsLocationBased {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPublicChatTypeIsLocationBasedBuilder {
-        let mut inner = PublicChatTypeIsLocationBased::default();
-        inner.td_name = "publicChatTypeIsLocationBased".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPublicChatTypeIsLocationBasedBuilder { inner }
+        RTDPublicChatTypeIsLocationBasedBuilder {
+            inner: PublicChatTypeIsLocationBased::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPublicChatTypeIsLocationBasedBuilder {
+        RTDPublicChatTypeIsLocationBasedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPublicChatTypeIsLocationBasedBuilder {
+        RTDPublicChatTypeIsLocationBasedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDPublicChatTypeIsLocationBasedBuilder {
     inner: PublicChatTypeIsLocationBased,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPublicChatTypeIsLocationBasedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PublicChatTypeIsLocationBased {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PublicChatTypeIsLocationBased> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PublicChatTypeIsLocationBased",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<PublicChatTypeIsLocationBased> for PublicChatTypeIsLocationBased {
@@ -204,3 +273,19 @@ impl AsRef<PublicChatTypeIsLocationBased> for RTDPublicChatTypeIsLocationBasedBu
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(PublicChatType::HasUsername(
+            PublicChatTypeHasUsername::builder().build(),
+        ));
+        assert_round_trip(PublicChatType::IsLocationBased(
+            PublicChatTypeIsLocationBased::builder().build(),
+        ));
+    }
+}
@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains a public HTTPS link to a message in a supergroup or channel with a username
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "publicMessageLink")]
 pub struct PublicMessageLink {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Message link
     link: String,
@@ -31,15 +31,44 @@ impl RObject for PublicMessageLink {
     }
 }
 
+impl PartialEq for PublicMessageLink {
+    fn eq(&self, other: &Self) -> bool {
+        self.link == other.link && self.html == other.html
+    }
+}
+
+impl Eq for PublicMessageLink {}
+
+impl std::hash::Hash for PublicMessageLink {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.link, state);
+        std::hash::Hash::hash(&self.html, state);
+    }
+}
+
 impl PublicMessageLink {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPublicMessageLinkBuilder {
-        let mut inner = PublicMessageLink::default();
-        inner.td_name = "publicMessageLink".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPublicMessageLinkBuilder { inner }
+        RTDPublicMessageLinkBuilder {
+            inner: PublicMessageLink::default(),
+            missing: vec!["link", "html"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPublicMessageLinkBuilder {
+        RTDPublicMessageLinkBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPublicMessageLinkBuilder {
+        RTDPublicMessageLinkBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn link(&self) -> &String {
@@ -49,25 +78,58 @@ impl PublicMessageLink {
     pub fn html(&self) -> &String {
         &self.html
     }
+
+    pub fn into_link(self) -> String {
+        self.link
+    }
+
+    pub fn into_html(self) -> String {
+        self.html
+    }
+
+    pub fn with_link<T: AsRef<str>>(mut self, link: T) -> Self {
+        self.link = link.as_ref().to_string();
+        self
+    }
+
+    pub fn with_html<T: AsRef<str>>(mut self, html: T) -> Self {
+        self.html = html.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPublicMessageLinkBuilder {
     inner: PublicMessageLink,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPublicMessageLinkBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PublicMessageLink {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PublicMessageLink> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PublicMessageLink",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn link<T: AsRef<str>>(&mut self, link: T) -> &mut Self {
         self.inner.link = link.as_ref().to_string();
+        self.missing.retain(|f| *f != "link");
         self
     }
 
     pub fn html<T: AsRef<str>>(&mut self, html: T) -> &mut Self {
         self.inner.html = html.as_ref().to_string();
+        self.missing.retain(|f| *f != "html");
         self
     }
 }
@@ -83,3 +145,19 @@ impl AsRef<PublicMessageLink> for RTDPublicMessageLinkBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            PublicMessageLink::builder()
+                .link("text")
+                .html("text")
+                .build(),
+        );
+    }
+}
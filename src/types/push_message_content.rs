@@ -1,115 +1,136 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Contains content of a push message notification
 pub trait TDPushMessageContent: Debug + RObject {}
 
 /// Contains content of a push message notification
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "@type")]
 pub enum PushMessageContent {
-    #[doc(hidden)]
-    _Default(()),
     /// An animation message (GIF-style).
+    #[serde(rename = "pushMessageContentAnimation")]
     Animation(PushMessageContentAnimation),
     /// An audio message
+    #[serde(rename = "pushMessageContentAudio")]
     Audio(PushMessageContentAudio),
     /// A newly created basic group
+    #[serde(rename = "pushMessageContentBasicGroupChatCreate")]
     BasicGroupChatCreate(PushMessageContentBasicGroupChatCreate),
     /// New chat members were invited to a group
+    #[serde(rename = "pushMessageContentChatAddMembers")]
     ChatAddMembers(PushMessageContentChatAddMembers),
     /// A chat photo was edited
+    #[serde(rename = "pushMessageContentChatChangePhoto")]
     ChatChangePhoto(PushMessageContentChatChangePhoto),
     /// A chat title was edited
+    #[serde(rename = "pushMessageContentChatChangeTitle")]
     ChatChangeTitle(PushMessageContentChatChangeTitle),
     /// A chat member was deleted
+    #[serde(rename = "pushMessageContentChatDeleteMember")]
     ChatDeleteMember(PushMessageContentChatDeleteMember),
     /// A new member joined the chat by invite link
+    #[serde(rename = "pushMessageContentChatJoinByLink")]
     ChatJoinByLink(PushMessageContentChatJoinByLink),
     /// A message with a user contact
+    #[serde(rename = "pushMessageContentContact")]
     Contact(PushMessageContentContact),
     /// A contact has registered with Telegram
+    #[serde(rename = "pushMessageContentContactRegistered")]
     ContactRegistered(PushMessageContentContactRegistered),
     /// A document message (a general file)
+    #[serde(rename = "pushMessageContentDocument")]
     Document(PushMessageContentDocument),
     /// A message with a game
+    #[serde(rename = "pushMessageContentGame")]
     Game(PushMessageContentGame),
     /// A new high score was achieved in a game
+    #[serde(rename = "pushMessageContentGameScore")]
     GameScore(PushMessageContentGameScore),
     /// A general message with hidden content
+    #[serde(rename = "pushMessageContentHidden")]
     Hidden(PushMessageContentHidden),
     /// A message with an invoice from a bot
+    #[serde(rename = "pushMessageContentInvoice")]
     Invoice(PushMessageContentInvoice),
     /// A message with a location
+    #[serde(rename = "pushMessageContentLocation")]
     Location(PushMessageContentLocation),
     /// A media album
+    #[serde(rename = "pushMessageContentMediaAlbum")]
     MediaAlbum(PushMessageContentMediaAlbum),
     /// A forwarded messages
+    #[serde(rename = "pushMessageContentMessageForwards")]
     MessageForwards(PushMessageContentMessageForwards),
     /// A photo message
+    #[serde(rename = "pushMessageContentPhoto")]
     Photo(PushMessageContentPhoto),
     /// A message with a poll
+    #[serde(rename = "pushMessageContentPoll")]
     Poll(PushMessageContentPoll),
     /// A screenshot of a message in the chat has been taken
+    #[serde(rename = "pushMessageContentScreenshotTaken")]
     ScreenshotTaken(PushMessageContentScreenshotTaken),
     /// A message with a sticker
+    #[serde(rename = "pushMessageContentSticker")]
     Sticker(PushMessageContentSticker),
     /// A text message
+    #[serde(rename = "pushMessageContentText")]
     Text(PushMessageContentText),
     /// A video message
+    #[serde(rename = "pushMessageContentVideo")]
     Video(PushMessageContentVideo),
     /// A video note message
+    #[serde(rename = "pushMessageContentVideoNote")]
     VideoNote(PushMessageContentVideoNote),
     /// A voice note message
+    #[serde(rename = "pushMessageContentVoiceNote")]
     VoiceNote(PushMessageContentVoiceNote),
 }
 
+/// Default value is default of [PushMessageContentAnimation], builders still require the field to be set
 impl Default for PushMessageContent {
     fn default() -> Self {
-        PushMessageContent::_Default(())
+        PushMessageContent::Animation(PushMessageContentAnimation::default())
     }
 }
 
-impl<'de> Deserialize<'de> for PushMessageContent {
-    fn deserialize<D>(deserializer: D) -> Result<PushMessageContent, D::Error>
+impl Serialize for PushMessageContent {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          PushMessageContent,
-          (pushMessageContentAnimation, Animation);
-          (pushMessageContentAudio, Audio);
-          (pushMessageContentBasicGroupChatCreate, BasicGroupChatCreate);
-          (pushMessageContentChatAddMembers, ChatAddMembers);
-          (pushMessageContentChatChangePhoto, ChatChangePhoto);
-          (pushMessageContentChatChangeTitle, ChatChangeTitle);
-          (pushMessageContentChatDeleteMember, ChatDeleteMember);
-          (pushMessageContentChatJoinByLink, ChatJoinByLink);
-          (pushMessageContentContact, Contact);
-          (pushMessageContentContactRegistered, ContactRegistered);
-          (pushMessageContentDocument, Document);
-          (pushMessageContentGame, Game);
-          (pushMessageContentGameScore, GameScore);
-          (pushMessageContentHidden, Hidden);
-          (pushMessageContentInvoice, Invoice);
-          (pushMessageContentLocation, Location);
-          (pushMessageContentMediaAlbum, MediaAlbum);
-          (pushMessageContentMessageForwards, MessageForwards);
-          (pushMessageContentPhoto, Photo);
-          (pushMessageContentPoll, Poll);
-          (pushMessageContentScreenshotTaken, ScreenshotTaken);
-          (pushMessageContentSticker, Sticker);
-          (pushMessageContentText, Text);
-          (pushMessageContentVideo, Video);
-          (pushMessageContentVideoNote, VideoNote);
-          (pushMessageContentVoiceNote, VoiceNote);
-
-        )(deserializer)
+        match self {
+            PushMessageContent::Animation(t) => t.serialize(serializer),
+            PushMessageContent::Audio(t) => t.serialize(serializer),
+            PushMessageContent::BasicGroupChatCreate(t) => t.serialize(serializer),
+            PushMessageContent::ChatAddMembers(t) => t.serialize(serializer),
+            PushMessageContent::ChatChangePhoto(t) => t.serialize(serializer),
+            PushMessageContent::ChatChangeTitle(t) => t.serialize(serializer),
+            PushMessageContent::ChatDeleteMember(t) => t.serialize(serializer),
+            PushMessageContent::ChatJoinByLink(t) => t.serialize(serializer),
+            PushMessageContent::Contact(t) => t.serialize(serializer),
+            PushMessageContent::ContactRegistered(t) => t.serialize(serializer),
+            PushMessageContent::Document(t) => t.serialize(serializer),
+            PushMessageContent::Game(t) => t.serialize(serializer),
+            PushMessageContent::GameScore(t) => t.serialize(serializer),
+            PushMessageContent::Hidden(t) => t.serialize(serializer),
+            PushMessageContent::Invoice(t) => t.serialize(serializer),
+            PushMessageContent::Location(t) => t.serialize(serializer),
+            PushMessageContent::MediaAlbum(t) => t.serialize(serializer),
+            PushMessageContent::MessageForwards(t) => t.serialize(serializer),
+            PushMessageContent::Photo(t) => t.serialize(serializer),
+            PushMessageContent::Poll(t) => t.serialize(serializer),
+            PushMessageContent::ScreenshotTaken(t) => t.serialize(serializer),
+            PushMessageContent::Sticker(t) => t.serialize(serializer),
+            PushMessageContent::Text(t) => t.serialize(serializer),
+            PushMessageContent::Video(t) => t.serialize(serializer),
+            PushMessageContent::VideoNote(t) => t.serialize(serializer),
+            PushMessageContent::VoiceNote(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -143,8 +164,6 @@ impl RObject for PushMessageContent {
             PushMessageContent::Video(t) => t.td_name(),
             PushMessageContent::VideoNote(t) => t.td_name(),
             PushMessageContent::VoiceNote(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -176,8 +195,6 @@ impl RObject for PushMessageContent {
             PushMessageContent::Video(t) => t.extra(),
             PushMessageContent::VideoNote(t) => t.extra(),
             PushMessageContent::VoiceNote(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -189,10 +206,6 @@ impl PushMessageContent {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, PushMessageContent::_Default(_))
-    }
 }
 
 impl AsRef<PushMessageContent> for PushMessageContent {
@@ -203,12 +216,13 @@ impl AsRef<PushMessageContent> for PushMessageContent {
 
 /// An animation message (GIF-style).
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pushMessageContentAnimation")]
 pub struct PushMessageContentAnimation {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Message content; may be null
     animation: Option<Animation>,
@@ -232,6 +246,24 @@ impl RObject for PushMessageContentAnimation {
     }
 }
 
+impl PartialEq for PushMessageContentAnimation {
+    fn eq(&self, other: &Self) -> bool {
+        self.animation == other.animation
+            && self.caption == other.caption
+            && self.is_pinned == other.is_pinned
+    }
+}
+
+impl Eq for PushMessageContentAnimation {}
+
+impl std::hash::Hash for PushMessageContentAnimation {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.animation, state);
+        std::hash::Hash::hash(&self.caption, state);
+        std::hash::Hash::hash(&self.is_pinned, state);
+    }
+}
+
 impl TDPushMessageContent for PushMessageContentAnimation {}
 
 impl PushMessageContentAnimation {
@@ -239,10 +271,24 @@ impl PushMessageContentAnimation {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPushMessageContentAnimationBuilder {
-        let mut inner = PushMessageContentAnimation::default();
-        inner.td_name = "pushMessageContentAnimation".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPushMessageContentAnimationBuilder { inner }
+        RTDPushMessageContentAnimationBuilder {
+            inner: PushMessageContentAnimation::default(),
+            missing: vec!["caption"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPushMessageContentAnimationBuilder {
+        RTDPushMessageContentAnimationBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPushMessageContentAnimationBuilder {
+        RTDPushMessageContentAnimationBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn animation(&self) -> &Option<Animation> {
@@ -256,17 +302,53 @@ impl PushMessageContentAnimation {
     pub fn is_pinned(&self) -> bool {
         self.is_pinned
     }
+
+    pub fn into_animation(self) -> Option<Animation> {
+        self.animation
+    }
+
+    pub fn into_caption(self) -> String {
+        self.caption
+    }
+
+    pub fn with_animation<T: AsRef<Animation>>(mut self, animation: T) -> Self {
+        self.animation = Some(animation.as_ref().clone());
+        self
+    }
+
+    pub fn with_caption<T: AsRef<str>>(mut self, caption: T) -> Self {
+        self.caption = caption.as_ref().to_string();
+        self
+    }
+
+    pub fn with_is_pinned(mut self, is_pinned: bool) -> Self {
+        self.is_pinned = is_pinned;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPushMessageContentAnimationBuilder {
     inner: PushMessageContentAnimation,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPushMessageContentAnimationBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PushMessageContentAnimation {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PushMessageContentAnimation> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PushMessageContentAnimation",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn animation<T: AsRef<Animation>>(&mut self, animation: T) -> &mut Self {
         self.inner.animation = Some(animation.as_ref().clone());
@@ -275,6 +357,7 @@ impl RTDPushMessageContentAnimationBuilder {
 
     pub fn caption<T: AsRef<str>>(&mut self, caption: T) -> &mut Self {
         self.inner.caption = caption.as_ref().to_string();
+        self.missing.retain(|f| *f != "caption");
         self
     }
 
@@ -298,12 +381,13 @@ impl AsRef<PushMessageContentAnimation> for RTDPushMessageContentAnimationBuilde
 
 /// An audio message
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pushMessageContentAudio")]
 pub struct PushMessageContentAudio {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Message content; may be null
     audio: Option<Audio>,
@@ -325,6 +409,21 @@ impl RObject for PushMessageContentAudio {
     }
 }
 
+impl PartialEq for PushMessageContentAudio {
+    fn eq(&self, other: &Self) -> bool {
+        self.audio == other.audio && self.is_pinned == other.is_pinned
+    }
+}
+
+impl Eq for PushMessageContentAudio {}
+
+impl std::hash::Hash for PushMessageContentAudio {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.audio, state);
+        std::hash::Hash::hash(&self.is_pinned, state);
+    }
+}
+
 impl TDPushMessageContent for PushMessageContentAudio {}
 
 impl PushMessageContentAudio {
@@ -332,10 +431,24 @@ impl PushMessageContentAudio {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPushMessageContentAudioBuilder {
-        let mut inner = PushMessageContentAudio::default();
-        inner.td_name = "pushMessageContentAudio".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPushMessageContentAudioBuilder { inner }
+        RTDPushMessageContentAudioBuilder {
+            inner: PushMessageContentAudio::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPushMessageContentAudioBuilder {
+        RTDPushMessageContentAudioBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPushMessageContentAudioBuilder {
+        RTDPushMessageContentAudioBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn audio(&self) -> &Option<Audio> {
@@ -345,17 +458,44 @@ impl PushMessageContentAudio {
     pub fn is_pinned(&self) -> bool {
         self.is_pinned
     }
+
+    pub fn into_audio(self) -> Option<Audio> {
+        self.audio
+    }
+
+    pub fn with_audio<T: AsRef<Audio>>(mut self, audio: T) -> Self {
+        self.audio = Some(audio.as_ref().clone());
+        self
+    }
+
+    pub fn with_is_pinned(mut self, is_pinned: bool) -> Self {
+        self.is_pinned = is_pinned;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPushMessageContentAudioBuilder {
     inner: PushMessageContentAudio,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPushMessageContentAudioBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PushMessageContentAudio {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PushMessageContentAudio> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PushMessageContentAudio",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn audio<T: AsRef<Audio>>(&mut self, audio: T) -> &mut Self {
         self.inner.audio = Some(audio.as_ref().clone());
@@ -382,12 +522,13 @@ impl AsRef<PushMessageContentAudio> for RTDPushMessageContentAudioBuilder {
 
 /// A newly created basic group
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pushMessageContentBasicGroupChatCreate")]
 pub struct PushMessageContentBasicGroupChatCreate {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -405,6 +546,18 @@ impl RObject for PushMessageContentBasicGroupChatCreate {
     }
 }
 
+impl PartialEq for PushMessageContentBasicGroupChatCreate {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for PushMessageContentBasicGroupChatCreate {}
+
+impl std::hash::Hash for PushMessageContentBasicGroupChatCreate {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDPushMessageContent for PushMessageContentBasicGroupChatCreate {}
 
 impl PushMessageContentBasicGroupChatCreate {
@@ -412,22 +565,49 @@ impl PushMessageContentBasicGroupChatCreate {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPushMessageContentBasicGroupChatCreateBuilder {
-        let mut inner = PushMessageContentBasicGroupChatCreate::default();
-        inner.td_name = "pushMessageContentBasicGroupChatCreate".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPushMessageContentBasicGroupChatCreateBuilder { inner }
+        RTDPushMessageContentBasicGroupChatCreateBuilder {
+            inner: PushMessageContentBasicGroupChatCreate::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPushMessageContentBasicGroupChatCreateBuilder {
+        RTDPushMessageContentBasicGroupChatCreateBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPushMessageContentBasicGroupChatCreateBuilder {
+        RTDPushMessageContentBasicGroupChatCreateBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDPushMessageContentBasicGroupChatCreateBuilder {
     inner: PushMessageContentBasicGroupChatCreate,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPushMessageContentBasicGroupChatCreateBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PushMessageContentBasicGroupChatCreate {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PushMessageContentBasicGroupChatCreate> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PushMessageContentBasicGroupChatCreate",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<PushMessageContentBasicGroupChatCreate> for PushMessageContentBasicGroupChatCreate {
@@ -446,12 +626,13 @@ impl AsRef<PushMessageContentBasicGroupChatCreate>
 
 /// New chat members were invited to a group
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pushMessageContentChatAddMembers")]
 pub struct PushMessageContentChatAddMembers {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Name of the added member
     member_name: String,
@@ -475,6 +656,24 @@ impl RObject for PushMessageContentChatAddMembers {
     }
 }
 
+impl PartialEq for PushMessageContentChatAddMembers {
+    fn eq(&self, other: &Self) -> bool {
+        self.member_name == other.member_name
+            && self.is_current_user == other.is_current_user
+            && self.is_returned == other.is_returned
+    }
+}
+
+impl Eq for PushMessageContentChatAddMembers {}
+
+impl std::hash::Hash for PushMessageContentChatAddMembers {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.member_name, state);
+        std::hash::Hash::hash(&self.is_current_user, state);
+        std::hash::Hash::hash(&self.is_returned, state);
+    }
+}
+
 impl TDPushMessageContent for PushMessageContentChatAddMembers {}
 
 impl PushMessageContentChatAddMembers {
@@ -482,10 +681,24 @@ impl PushMessageContentChatAddMembers {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPushMessageContentChatAddMembersBuilder {
-        let mut inner = PushMessageContentChatAddMembers::default();
-        inner.td_name = "pushMessageContentChatAddMembers".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPushMessageContentChatAddMembersBuilder { inner }
+        RTDPushMessageContentChatAddMembersBuilder {
+            inner: PushMessageContentChatAddMembers::default(),
+            missing: vec!["member_name"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPushMessageContentChatAddMembersBuilder {
+        RTDPushMessageContentChatAddMembersBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPushMessageContentChatAddMembersBuilder {
+        RTDPushMessageContentChatAddMembersBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn member_name(&self) -> &String {
@@ -499,20 +712,53 @@ impl PushMessageContentChatAddMembers {
     pub fn is_returned(&self) -> bool {
         self.is_returned
     }
+
+    pub fn into_member_name(self) -> String {
+        self.member_name
+    }
+
+    pub fn with_member_name<T: AsRef<str>>(mut self, member_name: T) -> Self {
+        self.member_name = member_name.as_ref().to_string();
+        self
+    }
+
+    pub fn with_is_current_user(mut self, is_current_user: bool) -> Self {
+        self.is_current_user = is_current_user;
+        self
+    }
+
+    pub fn with_is_returned(mut self, is_returned: bool) -> Self {
+        self.is_returned = is_returned;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPushMessageContentChatAddMembersBuilder {
     inner: PushMessageContentChatAddMembers,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPushMessageContentChatAddMembersBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PushMessageContentChatAddMembers {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PushMessageContentChatAddMembers> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PushMessageContentChatAddMembers",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn member_name<T: AsRef<str>>(&mut self, member_name: T) -> &mut Self {
         self.inner.member_name = member_name.as_ref().to_string();
+        self.missing.retain(|f| *f != "member_name");
         self
     }
 
@@ -541,12 +787,13 @@ impl AsRef<PushMessageContentChatAddMembers> for RTDPushMessageContentChatAddMem
 
 /// A chat photo was edited
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pushMessageContentChatChangePhoto")]
 pub struct PushMessageContentChatChangePhoto {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -564,6 +811,18 @@ impl RObject for PushMessageContentChatChangePhoto {
     }
 }
 
+impl PartialEq for PushMessageContentChatChangePhoto {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for PushMessageContentChatChangePhoto {}
+
+impl std::hash::Hash for PushMessageContentChatChangePhoto {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDPushMessageContent for PushMessageContentChatChangePhoto {}
 
 impl PushMessageContentChatChangePhoto {
@@ -571,22 +830,49 @@ impl PushMessageContentChatChangePhoto {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPushMessageContentChatChangePhotoBuilder {
-        let mut inner = PushMessageContentChatChangePhoto::default();
-        inner.td_name = "pushMessageContentChatChangePhoto".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPushMessageContentChatChangePhotoBuilder { inner }
+        RTDPushMessageContentChatChangePhotoBuilder {
+            inner: PushMessageContentChatChangePhoto::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPushMessageContentChatChangePhotoBuilder {
+        RTDPushMessageContentChatChangePhotoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPushMessageContentChatChangePhotoBuilder {
+        RTDPushMessageContentChatChangePhotoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDPushMessageContentChatChangePhotoBuilder {
     inner: PushMessageContentChatChangePhoto,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPushMessageContentChatChangePhotoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PushMessageContentChatChangePhoto {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PushMessageContentChatChangePhoto> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PushMessageContentChatChangePhoto",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<PushMessageContentChatChangePhoto> for PushMessageContentChatChangePhoto {
@@ -603,12 +889,13 @@ impl AsRef<PushMessageContentChatChangePhoto> for RTDPushMessageContentChatChang
 
 /// A chat title was edited
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pushMessageContentChatChangeTitle")]
 pub struct PushMessageContentChatChangeTitle {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// New chat title
     title: String,
@@ -628,6 +915,20 @@ impl RObject for PushMessageContentChatChangeTitle {
     }
 }
 
+impl PartialEq for PushMessageContentChatChangeTitle {
+    fn eq(&self, other: &Self) -> bool {
+        self.title == other.title
+    }
+}
+
+impl Eq for PushMessageContentChatChangeTitle {}
+
+impl std::hash::Hash for PushMessageContentChatChangeTitle {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.title, state);
+    }
+}
+
 impl TDPushMessageContent for PushMessageContentChatChangeTitle {}
 
 impl PushMessageContentChatChangeTitle {
@@ -635,29 +936,66 @@ impl PushMessageContentChatChangeTitle {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPushMessageContentChatChangeTitleBuilder {
-        let mut inner = PushMessageContentChatChangeTitle::default();
-        inner.td_name = "pushMessageContentChatChangeTitle".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPushMessageContentChatChangeTitleBuilder { inner }
+        RTDPushMessageContentChatChangeTitleBuilder {
+            inner: PushMessageContentChatChangeTitle::default(),
+            missing: vec!["title"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPushMessageContentChatChangeTitleBuilder {
+        RTDPushMessageContentChatChangeTitleBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPushMessageContentChatChangeTitleBuilder {
+        RTDPushMessageContentChatChangeTitleBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn title(&self) -> &String {
         &self.title
     }
+
+    pub fn into_title(self) -> String {
+        self.title
+    }
+
+    pub fn with_title<T: AsRef<str>>(mut self, title: T) -> Self {
+        self.title = title.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPushMessageContentChatChangeTitleBuilder {
     inner: PushMessageContentChatChangeTitle,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPushMessageContentChatChangeTitleBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PushMessageContentChatChangeTitle {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PushMessageContentChatChangeTitle> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PushMessageContentChatChangeTitle",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
         self.inner.title = title.as_ref().to_string();
+        self.missing.retain(|f| *f != "title");
         self
     }
 }
@@ -676,12 +1014,13 @@ impl AsRef<PushMessageContentChatChangeTitle> for RTDPushMessageContentChatChang
 
 /// A chat member was deleted
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pushMessageContentChatDeleteMember")]
 pub struct PushMessageContentChatDeleteMember {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Name of the deleted member
     member_name: String,
@@ -705,6 +1044,24 @@ impl RObject for PushMessageContentChatDeleteMember {
     }
 }
 
+impl PartialEq for PushMessageContentChatDeleteMember {
+    fn eq(&self, other: &Self) -> bool {
+        self.member_name == other.member_name
+            && self.is_current_user == other.is_current_user
+            && self.is_left == other.is_left
+    }
+}
+
+impl Eq for PushMessageContentChatDeleteMember {}
+
+impl std::hash::Hash for PushMessageContentChatDeleteMember {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.member_name, state);
+        std::hash::Hash::hash(&self.is_current_user, state);
+        std::hash::Hash::hash(&self.is_left, state);
+    }
+}
+
 impl TDPushMessageContent for PushMessageContentChatDeleteMember {}
 
 impl PushMessageContentChatDeleteMember {
@@ -712,10 +1069,24 @@ impl PushMessageContentChatDeleteMember {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPushMessageContentChatDeleteMemberBuilder {
-        let mut inner = PushMessageContentChatDeleteMember::default();
-        inner.td_name = "pushMessageContentChatDeleteMember".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPushMessageContentChatDeleteMemberBuilder { inner }
+        RTDPushMessageContentChatDeleteMemberBuilder {
+            inner: PushMessageContentChatDeleteMember::default(),
+            missing: vec!["member_name"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPushMessageContentChatDeleteMemberBuilder {
+        RTDPushMessageContentChatDeleteMemberBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPushMessageContentChatDeleteMemberBuilder {
+        RTDPushMessageContentChatDeleteMemberBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn member_name(&self) -> &String {
@@ -729,20 +1100,53 @@ impl PushMessageContentChatDeleteMember {
     pub fn is_left(&self) -> bool {
         self.is_left
     }
+
+    pub fn into_member_name(self) -> String {
+        self.member_name
+    }
+
+    pub fn with_member_name<T: AsRef<str>>(mut self, member_name: T) -> Self {
+        self.member_name = member_name.as_ref().to_string();
+        self
+    }
+
+    pub fn with_is_current_user(mut self, is_current_user: bool) -> Self {
+        self.is_current_user = is_current_user;
+        self
+    }
+
+    pub fn with_is_left(mut self, is_left: bool) -> Self {
+        self.is_left = is_left;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPushMessageContentChatDeleteMemberBuilder {
     inner: PushMessageContentChatDeleteMember,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPushMessageContentChatDeleteMemberBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PushMessageContentChatDeleteMember {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PushMessageContentChatDeleteMember> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PushMessageContentChatDeleteMember",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn member_name<T: AsRef<str>>(&mut self, member_name: T) -> &mut Self {
         self.inner.member_name = member_name.as_ref().to_string();
+        self.missing.retain(|f| *f != "member_name");
         self
     }
 
@@ -771,12 +1175,13 @@ impl AsRef<PushMessageContentChatDeleteMember> for RTDPushMessageContentChatDele
 
 /// A new member joined the chat by invite link
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pushMessageContentChatJoinByLink")]
 pub struct PushMessageContentChatJoinByLink {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -794,6 +1199,18 @@ impl RObject for PushMessageContentChatJoinByLink {
     }
 }
 
+impl PartialEq for PushMessageContentChatJoinByLink {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for PushMessageContentChatJoinByLink {}
+
+impl std::hash::Hash for PushMessageContentChatJoinByLink {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDPushMessageContent for PushMessageContentChatJoinByLink {}
 
 impl PushMessageContentChatJoinByLink {
@@ -801,22 +1218,49 @@ impl PushMessageContentChatJoinByLink {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPushMessageContentChatJoinByLinkBuilder {
-        let mut inner = PushMessageContentChatJoinByLink::default();
-        inner.td_name = "pushMessageContentChatJoinByLink".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPushMessageContentChatJoinByLinkBuilder { inner }
+        RTDPushMessageContentChatJoinByLinkBuilder {
+            inner: PushMessageContentChatJoinByLink::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPushMessageContentChatJoinByLinkBuilder {
+        RTDPushMessageContentChatJoinByLinkBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPushMessageContentChatJoinByLinkBuilder {
+        RTDPushMessageContentChatJoinByLinkBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDPushMessageContentChatJoinByLinkBuilder {
     inner: PushMessageContentChatJoinByLink,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPushMessageContentChatJoinByLinkBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PushMessageContentChatJoinByLink {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PushMessageContentChatJoinByLink> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PushMessageContentChatJoinByLink",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<PushMessageContentChatJoinByLink> for PushMessageContentChatJoinByLink {
@@ -833,12 +1277,13 @@ impl AsRef<PushMessageContentChatJoinByLink> for RTDPushMessageContentChatJoinBy
 
 /// A message with a user contact
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pushMessageContentContact")]
 pub struct PushMessageContentContact {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Contact's name
     name: String,
@@ -860,6 +1305,21 @@ impl RObject for PushMessageContentContact {
     }
 }
 
+impl PartialEq for PushMessageContentContact {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.is_pinned == other.is_pinned
+    }
+}
+
+impl Eq for PushMessageContentContact {}
+
+impl std::hash::Hash for PushMessageContentContact {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.name, state);
+        std::hash::Hash::hash(&self.is_pinned, state);
+    }
+}
+
 impl TDPushMessageContent for PushMessageContentContact {}
 
 impl PushMessageContentContact {
@@ -867,10 +1327,24 @@ impl PushMessageContentContact {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPushMessageContentContactBuilder {
-        let mut inner = PushMessageContentContact::default();
-        inner.td_name = "pushMessageContentContact".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPushMessageContentContactBuilder { inner }
+        RTDPushMessageContentContactBuilder {
+            inner: PushMessageContentContact::default(),
+            missing: vec!["name"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPushMessageContentContactBuilder {
+        RTDPushMessageContentContactBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPushMessageContentContactBuilder {
+        RTDPushMessageContentContactBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn name(&self) -> &String {
@@ -880,20 +1354,48 @@ impl PushMessageContentContact {
     pub fn is_pinned(&self) -> bool {
         self.is_pinned
     }
+
+    pub fn into_name(self) -> String {
+        self.name
+    }
+
+    pub fn with_name<T: AsRef<str>>(mut self, name: T) -> Self {
+        self.name = name.as_ref().to_string();
+        self
+    }
+
+    pub fn with_is_pinned(mut self, is_pinned: bool) -> Self {
+        self.is_pinned = is_pinned;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPushMessageContentContactBuilder {
     inner: PushMessageContentContact,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPushMessageContentContactBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PushMessageContentContact {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PushMessageContentContact> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PushMessageContentContact",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn name<T: AsRef<str>>(&mut self, name: T) -> &mut Self {
         self.inner.name = name.as_ref().to_string();
+        self.missing.retain(|f| *f != "name");
         self
     }
 
@@ -917,12 +1419,13 @@ impl AsRef<PushMessageContentContact> for RTDPushMessageContentContactBuilder {
 
 /// A contact has registered with Telegram
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pushMessageContentContactRegistered")]
 pub struct PushMessageContentContactRegistered {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -940,6 +1443,18 @@ impl RObject for PushMessageContentContactRegistered {
     }
 }
 
+impl PartialEq for PushMessageContentContactRegistered {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for PushMessageContentContactRegistered {}
+
+impl std::hash::Hash for PushMessageContentContactRegistered {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDPushMessageContent for PushMessageContentContactRegistered {}
 
 impl PushMessageContentContactRegistered {
@@ -947,22 +1462,49 @@ impl PushMessageContentContactRegistered {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPushMessageContentContactRegisteredBuilder {
-        let mut inner = PushMessageContentContactRegistered::default();
-        inner.td_name = "pushMessageContentContactRegistered".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPushMessageContentContactRegisteredBuilder { inner }
+        RTDPushMessageContentContactRegisteredBuilder {
+            inner: PushMessageContentContactRegistered::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPushMessageContentContactRegisteredBuilder {
+        RTDPushMessageContentContactRegisteredBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPushMessageContentContactRegisteredBuilder {
+        RTDPushMessageContentContactRegisteredBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDPushMessageContentContactRegisteredBuilder {
     inner: PushMessageContentContactRegistered,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPushMessageContentContactRegisteredBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PushMessageContentContactRegistered {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PushMessageContentContactRegistered> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PushMessageContentContactRegistered",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<PushMessageContentContactRegistered> for PushMessageContentContactRegistered {
@@ -979,12 +1521,13 @@ impl AsRef<PushMessageContentContactRegistered> for RTDPushMessageContentContact
 
 /// A document message (a general file)
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pushMessageContentDocument")]
 pub struct PushMessageContentDocument {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Message content; may be null
     document: Option<Document>,
@@ -1006,6 +1549,21 @@ impl RObject for PushMessageContentDocument {
     }
 }
 
+impl PartialEq for PushMessageContentDocument {
+    fn eq(&self, other: &Self) -> bool {
+        self.document == other.document && self.is_pinned == other.is_pinned
+    }
+}
+
+impl Eq for PushMessageContentDocument {}
+
+impl std::hash::Hash for PushMessageContentDocument {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.document, state);
+        std::hash::Hash::hash(&self.is_pinned, state);
+    }
+}
+
 impl TDPushMessageContent for PushMessageContentDocument {}
 
 impl PushMessageContentDocument {
@@ -1013,10 +1571,24 @@ impl PushMessageContentDocument {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPushMessageContentDocumentBuilder {
-        let mut inner = PushMessageContentDocument::default();
-        inner.td_name = "pushMessageContentDocument".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPushMessageContentDocumentBuilder { inner }
+        RTDPushMessageContentDocumentBuilder {
+            inner: PushMessageContentDocument::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPushMessageContentDocumentBuilder {
+        RTDPushMessageContentDocumentBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPushMessageContentDocumentBuilder {
+        RTDPushMessageContentDocumentBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn document(&self) -> &Option<Document> {
@@ -1026,17 +1598,44 @@ impl PushMessageContentDocument {
     pub fn is_pinned(&self) -> bool {
         self.is_pinned
     }
+
+    pub fn into_document(self) -> Option<Document> {
+        self.document
+    }
+
+    pub fn with_document<T: AsRef<Document>>(mut self, document: T) -> Self {
+        self.document = Some(document.as_ref().clone());
+        self
+    }
+
+    pub fn with_is_pinned(mut self, is_pinned: bool) -> Self {
+        self.is_pinned = is_pinned;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPushMessageContentDocumentBuilder {
     inner: PushMessageContentDocument,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPushMessageContentDocumentBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PushMessageContentDocument {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PushMessageContentDocument> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PushMessageContentDocument",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn document<T: AsRef<Document>>(&mut self, document: T) -> &mut Self {
         self.inner.document = Some(document.as_ref().clone());
@@ -1063,12 +1662,13 @@ impl AsRef<PushMessageContentDocument> for RTDPushMessageContentDocumentBuilder
 
 /// A message with a game
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pushMessageContentGame")]
 pub struct PushMessageContentGame {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Game title, empty for pinned game message
     title: String,
@@ -1090,6 +1690,21 @@ impl RObject for PushMessageContentGame {
     }
 }
 
+impl PartialEq for PushMessageContentGame {
+    fn eq(&self, other: &Self) -> bool {
+        self.title == other.title && self.is_pinned == other.is_pinned
+    }
+}
+
+impl Eq for PushMessageContentGame {}
+
+impl std::hash::Hash for PushMessageContentGame {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.title, state);
+        std::hash::Hash::hash(&self.is_pinned, state);
+    }
+}
+
 impl TDPushMessageContent for PushMessageContentGame {}
 
 impl PushMessageContentGame {
@@ -1097,10 +1712,24 @@ impl PushMessageContentGame {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPushMessageContentGameBuilder {
-        let mut inner = PushMessageContentGame::default();
-        inner.td_name = "pushMessageContentGame".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPushMessageContentGameBuilder { inner }
+        RTDPushMessageContentGameBuilder {
+            inner: PushMessageContentGame::default(),
+            missing: vec!["title"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPushMessageContentGameBuilder {
+        RTDPushMessageContentGameBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPushMessageContentGameBuilder {
+        RTDPushMessageContentGameBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn title(&self) -> &String {
@@ -1110,20 +1739,48 @@ impl PushMessageContentGame {
     pub fn is_pinned(&self) -> bool {
         self.is_pinned
     }
+
+    pub fn into_title(self) -> String {
+        self.title
+    }
+
+    pub fn with_title<T: AsRef<str>>(mut self, title: T) -> Self {
+        self.title = title.as_ref().to_string();
+        self
+    }
+
+    pub fn with_is_pinned(mut self, is_pinned: bool) -> Self {
+        self.is_pinned = is_pinned;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPushMessageContentGameBuilder {
     inner: PushMessageContentGame,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPushMessageContentGameBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PushMessageContentGame {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PushMessageContentGame> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PushMessageContentGame",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
         self.inner.title = title.as_ref().to_string();
+        self.missing.retain(|f| *f != "title");
         self
     }
 
@@ -1147,17 +1804,18 @@ impl AsRef<PushMessageContentGame> for RTDPushMessageContentGameBuilder {
 
 /// A new high score was achieved in a game
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pushMessageContentGameScore")]
 pub struct PushMessageContentGameScore {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Game title, empty for pinned message
     title: String,
     /// New score, 0 for pinned message
-    score: i64,
+    score: i32,
     /// True, if the message is a pinned message with the specified content
     is_pinned: bool,
 }
@@ -1176,6 +1834,22 @@ impl RObject for PushMessageContentGameScore {
     }
 }
 
+impl PartialEq for PushMessageContentGameScore {
+    fn eq(&self, other: &Self) -> bool {
+        self.title == other.title && self.score == other.score && self.is_pinned == other.is_pinned
+    }
+}
+
+impl Eq for PushMessageContentGameScore {}
+
+impl std::hash::Hash for PushMessageContentGameScore {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.title, state);
+        std::hash::Hash::hash(&self.score, state);
+        std::hash::Hash::hash(&self.is_pinned, state);
+    }
+}
+
 impl TDPushMessageContent for PushMessageContentGameScore {}
 
 impl PushMessageContentGameScore {
@@ -1183,41 +1857,88 @@ impl PushMessageContentGameScore {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPushMessageContentGameScoreBuilder {
-        let mut inner = PushMessageContentGameScore::default();
-        inner.td_name = "pushMessageContentGameScore".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPushMessageContentGameScoreBuilder { inner }
+        RTDPushMessageContentGameScoreBuilder {
+            inner: PushMessageContentGameScore::default(),
+            missing: vec!["title"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPushMessageContentGameScoreBuilder {
+        RTDPushMessageContentGameScoreBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPushMessageContentGameScoreBuilder {
+        RTDPushMessageContentGameScoreBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn title(&self) -> &String {
         &self.title
     }
 
-    pub fn score(&self) -> i64 {
+    pub fn score(&self) -> i32 {
         self.score
     }
 
     pub fn is_pinned(&self) -> bool {
         self.is_pinned
     }
+
+    pub fn into_title(self) -> String {
+        self.title
+    }
+
+    pub fn with_title<T: AsRef<str>>(mut self, title: T) -> Self {
+        self.title = title.as_ref().to_string();
+        self
+    }
+
+    pub fn with_score(mut self, score: i32) -> Self {
+        self.score = score;
+        self
+    }
+
+    pub fn with_is_pinned(mut self, is_pinned: bool) -> Self {
+        self.is_pinned = is_pinned;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPushMessageContentGameScoreBuilder {
     inner: PushMessageContentGameScore,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPushMessageContentGameScoreBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PushMessageContentGameScore {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PushMessageContentGameScore> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PushMessageContentGameScore",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
         self.inner.title = title.as_ref().to_string();
+        self.missing.retain(|f| *f != "title");
         self
     }
 
-    pub fn score(&mut self, score: i64) -> &mut Self {
+    pub fn score(&mut self, score: i32) -> &mut Self {
         self.inner.score = score;
         self
     }
@@ -1242,12 +1963,13 @@ impl AsRef<PushMessageContentGameScore> for RTDPushMessageContentGameScoreBuilde
 
 /// A general message with hidden content
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pushMessageContentHidden")]
 pub struct PushMessageContentHidden {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// True, if the message is a pinned message with the specified content
     is_pinned: bool,
@@ -1267,6 +1989,20 @@ impl RObject for PushMessageContentHidden {
     }
 }
 
+impl PartialEq for PushMessageContentHidden {
+    fn eq(&self, other: &Self) -> bool {
+        self.is_pinned == other.is_pinned
+    }
+}
+
+impl Eq for PushMessageContentHidden {}
+
+impl std::hash::Hash for PushMessageContentHidden {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.is_pinned, state);
+    }
+}
+
 impl TDPushMessageContent for PushMessageContentHidden {}
 
 impl PushMessageContentHidden {
@@ -1274,26 +2010,58 @@ impl PushMessageContentHidden {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPushMessageContentHiddenBuilder {
-        let mut inner = PushMessageContentHidden::default();
-        inner.td_name = "pushMessageContentHidden".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPushMessageContentHiddenBuilder { inner }
+        RTDPushMessageContentHiddenBuilder {
+            inner: PushMessageContentHidden::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPushMessageContentHiddenBuilder {
+        RTDPushMessageContentHiddenBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPushMessageContentHiddenBuilder {
+        RTDPushMessageContentHiddenBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn is_pinned(&self) -> bool {
         self.is_pinned
     }
+
+    pub fn with_is_pinned(mut self, is_pinned: bool) -> Self {
+        self.is_pinned = is_pinned;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPushMessageContentHiddenBuilder {
     inner: PushMessageContentHidden,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPushMessageContentHiddenBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PushMessageContentHidden {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PushMessageContentHidden> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PushMessageContentHidden",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn is_pinned(&mut self, is_pinned: bool) -> &mut Self {
         self.inner.is_pinned = is_pinned;
@@ -1315,12 +2083,13 @@ impl AsRef<PushMessageContentHidden> for RTDPushMessageContentHiddenBuilder {
 
 /// A message with an invoice from a bot
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pushMessageContentInvoice")]
 pub struct PushMessageContentInvoice {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Product price
     price: String,
@@ -1342,6 +2111,21 @@ impl RObject for PushMessageContentInvoice {
     }
 }
 
+impl PartialEq for PushMessageContentInvoice {
+    fn eq(&self, other: &Self) -> bool {
+        self.price == other.price && self.is_pinned == other.is_pinned
+    }
+}
+
+impl Eq for PushMessageContentInvoice {}
+
+impl std::hash::Hash for PushMessageContentInvoice {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.price, state);
+        std::hash::Hash::hash(&self.is_pinned, state);
+    }
+}
+
 impl TDPushMessageContent for PushMessageContentInvoice {}
 
 impl PushMessageContentInvoice {
@@ -1349,10 +2133,24 @@ impl PushMessageContentInvoice {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPushMessageContentInvoiceBuilder {
-        let mut inner = PushMessageContentInvoice::default();
-        inner.td_name = "pushMessageContentInvoice".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPushMessageContentInvoiceBuilder { inner }
+        RTDPushMessageContentInvoiceBuilder {
+            inner: PushMessageContentInvoice::default(),
+            missing: vec!["price"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPushMessageContentInvoiceBuilder {
+        RTDPushMessageContentInvoiceBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPushMessageContentInvoiceBuilder {
+        RTDPushMessageContentInvoiceBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn price(&self) -> &String {
@@ -1362,20 +2160,48 @@ impl PushMessageContentInvoice {
     pub fn is_pinned(&self) -> bool {
         self.is_pinned
     }
+
+    pub fn into_price(self) -> String {
+        self.price
+    }
+
+    pub fn with_price<T: AsRef<str>>(mut self, price: T) -> Self {
+        self.price = price.as_ref().to_string();
+        self
+    }
+
+    pub fn with_is_pinned(mut self, is_pinned: bool) -> Self {
+        self.is_pinned = is_pinned;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPushMessageContentInvoiceBuilder {
     inner: PushMessageContentInvoice,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPushMessageContentInvoiceBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PushMessageContentInvoice {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PushMessageContentInvoice> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PushMessageContentInvoice",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn price<T: AsRef<str>>(&mut self, price: T) -> &mut Self {
         self.inner.price = price.as_ref().to_string();
+        self.missing.retain(|f| *f != "price");
         self
     }
 
@@ -1399,12 +2225,13 @@ impl AsRef<PushMessageContentInvoice> for RTDPushMessageContentInvoiceBuilder {
 
 /// A message with a location
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pushMessageContentLocation")]
 pub struct PushMessageContentLocation {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// True, if the location is live
     is_live: bool,
@@ -1426,6 +2253,21 @@ impl RObject for PushMessageContentLocation {
     }
 }
 
+impl PartialEq for PushMessageContentLocation {
+    fn eq(&self, other: &Self) -> bool {
+        self.is_live == other.is_live && self.is_pinned == other.is_pinned
+    }
+}
+
+impl Eq for PushMessageContentLocation {}
+
+impl std::hash::Hash for PushMessageContentLocation {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.is_live, state);
+        std::hash::Hash::hash(&self.is_pinned, state);
+    }
+}
+
 impl TDPushMessageContent for PushMessageContentLocation {}
 
 impl PushMessageContentLocation {
@@ -1433,10 +2275,24 @@ impl PushMessageContentLocation {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPushMessageContentLocationBuilder {
-        let mut inner = PushMessageContentLocation::default();
-        inner.td_name = "pushMessageContentLocation".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPushMessageContentLocationBuilder { inner }
+        RTDPushMessageContentLocationBuilder {
+            inner: PushMessageContentLocation::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPushMessageContentLocationBuilder {
+        RTDPushMessageContentLocationBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPushMessageContentLocationBuilder {
+        RTDPushMessageContentLocationBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn is_live(&self) -> bool {
@@ -1446,17 +2302,40 @@ impl PushMessageContentLocation {
     pub fn is_pinned(&self) -> bool {
         self.is_pinned
     }
+
+    pub fn with_is_live(mut self, is_live: bool) -> Self {
+        self.is_live = is_live;
+        self
+    }
+
+    pub fn with_is_pinned(mut self, is_pinned: bool) -> Self {
+        self.is_pinned = is_pinned;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPushMessageContentLocationBuilder {
     inner: PushMessageContentLocation,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPushMessageContentLocationBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PushMessageContentLocation {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PushMessageContentLocation> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PushMessageContentLocation",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn is_live(&mut self, is_live: bool) -> &mut Self {
         self.inner.is_live = is_live;
@@ -1483,15 +2362,16 @@ impl AsRef<PushMessageContentLocation> for RTDPushMessageContentLocationBuilder
 
 /// A media album
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pushMessageContentMediaAlbum")]
 pub struct PushMessageContentMediaAlbum {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Number of messages in the album
-    total_count: i64,
+    total_count: i32,
     /// True, if the album has at least one photo
     has_photos: bool,
     /// True, if the album has at least one video
@@ -1512,6 +2392,24 @@ impl RObject for PushMessageContentMediaAlbum {
     }
 }
 
+impl PartialEq for PushMessageContentMediaAlbum {
+    fn eq(&self, other: &Self) -> bool {
+        self.total_count == other.total_count
+            && self.has_photos == other.has_photos
+            && self.has_videos == other.has_videos
+    }
+}
+
+impl Eq for PushMessageContentMediaAlbum {}
+
+impl std::hash::Hash for PushMessageContentMediaAlbum {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.total_count, state);
+        std::hash::Hash::hash(&self.has_photos, state);
+        std::hash::Hash::hash(&self.has_videos, state);
+    }
+}
+
 impl TDPushMessageContent for PushMessageContentMediaAlbum {}
 
 impl PushMessageContentMediaAlbum {
@@ -1519,13 +2417,27 @@ impl PushMessageContentMediaAlbum {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPushMessageContentMediaAlbumBuilder {
-        let mut inner = PushMessageContentMediaAlbum::default();
-        inner.td_name = "pushMessageContentMediaAlbum".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPushMessageContentMediaAlbumBuilder { inner }
+        RTDPushMessageContentMediaAlbumBuilder {
+            inner: PushMessageContentMediaAlbum::default(),
+            missing: vec!["total_count"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPushMessageContentMediaAlbumBuilder {
+        RTDPushMessageContentMediaAlbumBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPushMessageContentMediaAlbumBuilder {
+        RTDPushMessageContentMediaAlbumBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn total_count(&self) -> i64 {
+    pub fn total_count(&self) -> i32 {
         self.total_count
     }
 
@@ -1536,20 +2448,49 @@ impl PushMessageContentMediaAlbum {
     pub fn has_videos(&self) -> bool {
         self.has_videos
     }
+
+    pub fn with_total_count(mut self, total_count: i32) -> Self {
+        self.total_count = total_count;
+        self
+    }
+
+    pub fn with_has_photos(mut self, has_photos: bool) -> Self {
+        self.has_photos = has_photos;
+        self
+    }
+
+    pub fn with_has_videos(mut self, has_videos: bool) -> Self {
+        self.has_videos = has_videos;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPushMessageContentMediaAlbumBuilder {
     inner: PushMessageContentMediaAlbum,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPushMessageContentMediaAlbumBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PushMessageContentMediaAlbum {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PushMessageContentMediaAlbum> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PushMessageContentMediaAlbum",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn total_count(&mut self, total_count: i64) -> &mut Self {
+    pub fn total_count(&mut self, total_count: i32) -> &mut Self {
         self.inner.total_count = total_count;
+        self.missing.retain(|f| *f != "total_count");
         self
     }
 
@@ -1578,15 +2519,16 @@ impl AsRef<PushMessageContentMediaAlbum> for RTDPushMessageContentMediaAlbumBuil
 
 /// A forwarded messages
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pushMessageContentMessageForwards")]
 pub struct PushMessageContentMessageForwards {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Number of forwarded messages
-    total_count: i64,
+    total_count: i32,
 }
 
 impl RObject for PushMessageContentMessageForwards {
@@ -1603,6 +2545,20 @@ impl RObject for PushMessageContentMessageForwards {
     }
 }
 
+impl PartialEq for PushMessageContentMessageForwards {
+    fn eq(&self, other: &Self) -> bool {
+        self.total_count == other.total_count
+    }
+}
+
+impl Eq for PushMessageContentMessageForwards {}
+
+impl std::hash::Hash for PushMessageContentMessageForwards {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.total_count, state);
+    }
+}
+
 impl TDPushMessageContent for PushMessageContentMessageForwards {}
 
 impl PushMessageContentMessageForwards {
@@ -1610,29 +2566,62 @@ impl PushMessageContentMessageForwards {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPushMessageContentMessageForwardsBuilder {
-        let mut inner = PushMessageContentMessageForwards::default();
-        inner.td_name = "pushMessageContentMessageForwards".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPushMessageContentMessageForwardsBuilder { inner }
+        RTDPushMessageContentMessageForwardsBuilder {
+            inner: PushMessageContentMessageForwards::default(),
+            missing: vec!["total_count"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPushMessageContentMessageForwardsBuilder {
+        RTDPushMessageContentMessageForwardsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPushMessageContentMessageForwardsBuilder {
+        RTDPushMessageContentMessageForwardsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn total_count(&self) -> i64 {
+    pub fn total_count(&self) -> i32 {
         self.total_count
     }
+
+    pub fn with_total_count(mut self, total_count: i32) -> Self {
+        self.total_count = total_count;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPushMessageContentMessageForwardsBuilder {
     inner: PushMessageContentMessageForwards,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPushMessageContentMessageForwardsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PushMessageContentMessageForwards {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PushMessageContentMessageForwards> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PushMessageContentMessageForwards",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn total_count(&mut self, total_count: i64) -> &mut Self {
+    pub fn total_count(&mut self, total_count: i32) -> &mut Self {
         self.inner.total_count = total_count;
+        self.missing.retain(|f| *f != "total_count");
         self
     }
 }
@@ -1651,12 +2640,13 @@ impl AsRef<PushMessageContentMessageForwards> for RTDPushMessageContentMessageFo
 
 /// A photo message
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pushMessageContentPhoto")]
 pub struct PushMessageContentPhoto {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Message content; may be null
     photo: Option<Photo>,
@@ -1682,6 +2672,26 @@ impl RObject for PushMessageContentPhoto {
     }
 }
 
+impl PartialEq for PushMessageContentPhoto {
+    fn eq(&self, other: &Self) -> bool {
+        self.photo == other.photo
+            && self.caption == other.caption
+            && self.is_secret == other.is_secret
+            && self.is_pinned == other.is_pinned
+    }
+}
+
+impl Eq for PushMessageContentPhoto {}
+
+impl std::hash::Hash for PushMessageContentPhoto {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.photo, state);
+        std::hash::Hash::hash(&self.caption, state);
+        std::hash::Hash::hash(&self.is_secret, state);
+        std::hash::Hash::hash(&self.is_pinned, state);
+    }
+}
+
 impl TDPushMessageContent for PushMessageContentPhoto {}
 
 impl PushMessageContentPhoto {
@@ -1689,10 +2699,24 @@ impl PushMessageContentPhoto {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPushMessageContentPhotoBuilder {
-        let mut inner = PushMessageContentPhoto::default();
-        inner.td_name = "pushMessageContentPhoto".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPushMessageContentPhotoBuilder { inner }
+        RTDPushMessageContentPhotoBuilder {
+            inner: PushMessageContentPhoto::default(),
+            missing: vec!["caption"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPushMessageContentPhotoBuilder {
+        RTDPushMessageContentPhotoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPushMessageContentPhotoBuilder {
+        RTDPushMessageContentPhotoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn photo(&self) -> &Option<Photo> {
@@ -1710,17 +2734,58 @@ impl PushMessageContentPhoto {
     pub fn is_pinned(&self) -> bool {
         self.is_pinned
     }
+
+    pub fn into_photo(self) -> Option<Photo> {
+        self.photo
+    }
+
+    pub fn into_caption(self) -> String {
+        self.caption
+    }
+
+    pub fn with_photo<T: AsRef<Photo>>(mut self, photo: T) -> Self {
+        self.photo = Some(photo.as_ref().clone());
+        self
+    }
+
+    pub fn with_caption<T: AsRef<str>>(mut self, caption: T) -> Self {
+        self.caption = caption.as_ref().to_string();
+        self
+    }
+
+    pub fn with_is_secret(mut self, is_secret: bool) -> Self {
+        self.is_secret = is_secret;
+        self
+    }
+
+    pub fn with_is_pinned(mut self, is_pinned: bool) -> Self {
+        self.is_pinned = is_pinned;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPushMessageContentPhotoBuilder {
     inner: PushMessageContentPhoto,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPushMessageContentPhotoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PushMessageContentPhoto {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PushMessageContentPhoto> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PushMessageContentPhoto",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn photo<T: AsRef<Photo>>(&mut self, photo: T) -> &mut Self {
         self.inner.photo = Some(photo.as_ref().clone());
@@ -1729,6 +2794,7 @@ impl RTDPushMessageContentPhotoBuilder {
 
     pub fn caption<T: AsRef<str>>(&mut self, caption: T) -> &mut Self {
         self.inner.caption = caption.as_ref().to_string();
+        self.missing.retain(|f| *f != "caption");
         self
     }
 
@@ -1757,12 +2823,13 @@ impl AsRef<PushMessageContentPhoto> for RTDPushMessageContentPhotoBuilder {
 
 /// A message with a poll
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pushMessageContentPoll")]
 pub struct PushMessageContentPoll {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Poll question
     question: String,
@@ -1786,6 +2853,24 @@ impl RObject for PushMessageContentPoll {
     }
 }
 
+impl PartialEq for PushMessageContentPoll {
+    fn eq(&self, other: &Self) -> bool {
+        self.question == other.question
+            && self.is_regular == other.is_regular
+            && self.is_pinned == other.is_pinned
+    }
+}
+
+impl Eq for PushMessageContentPoll {}
+
+impl std::hash::Hash for PushMessageContentPoll {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.question, state);
+        std::hash::Hash::hash(&self.is_regular, state);
+        std::hash::Hash::hash(&self.is_pinned, state);
+    }
+}
+
 impl TDPushMessageContent for PushMessageContentPoll {}
 
 impl PushMessageContentPoll {
@@ -1793,10 +2878,24 @@ impl PushMessageContentPoll {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPushMessageContentPollBuilder {
-        let mut inner = PushMessageContentPoll::default();
-        inner.td_name = "pushMessageContentPoll".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPushMessageContentPollBuilder { inner }
+        RTDPushMessageContentPollBuilder {
+            inner: PushMessageContentPoll::default(),
+            missing: vec!["question"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPushMessageContentPollBuilder {
+        RTDPushMessageContentPollBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPushMessageContentPollBuilder {
+        RTDPushMessageContentPollBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn question(&self) -> &String {
@@ -1810,20 +2909,53 @@ impl PushMessageContentPoll {
     pub fn is_pinned(&self) -> bool {
         self.is_pinned
     }
+
+    pub fn into_question(self) -> String {
+        self.question
+    }
+
+    pub fn with_question<T: AsRef<str>>(mut self, question: T) -> Self {
+        self.question = question.as_ref().to_string();
+        self
+    }
+
+    pub fn with_is_regular(mut self, is_regular: bool) -> Self {
+        self.is_regular = is_regular;
+        self
+    }
+
+    pub fn with_is_pinned(mut self, is_pinned: bool) -> Self {
+        self.is_pinned = is_pinned;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPushMessageContentPollBuilder {
     inner: PushMessageContentPoll,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPushMessageContentPollBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PushMessageContentPoll {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PushMessageContentPoll> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PushMessageContentPoll",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn question<T: AsRef<str>>(&mut self, question: T) -> &mut Self {
         self.inner.question = question.as_ref().to_string();
+        self.missing.retain(|f| *f != "question");
         self
     }
 
@@ -1852,12 +2984,13 @@ impl AsRef<PushMessageContentPoll> for RTDPushMessageContentPollBuilder {
 
 /// A screenshot of a message in the chat has been taken
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pushMessageContentScreenshotTaken")]
 pub struct PushMessageContentScreenshotTaken {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -1875,6 +3008,18 @@ impl RObject for PushMessageContentScreenshotTaken {
     }
 }
 
+impl PartialEq for PushMessageContentScreenshotTaken {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for PushMessageContentScreenshotTaken {}
+
+impl std::hash::Hash for PushMessageContentScreenshotTaken {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDPushMessageContent for PushMessageContentScreenshotTaken {}
 
 impl PushMessageContentScreenshotTaken {
@@ -1882,22 +3027,49 @@ impl PushMessageContentScreenshotTaken {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPushMessageContentScreenshotTakenBuilder {
-        let mut inner = PushMessageContentScreenshotTaken::default();
-        inner.td_name = "pushMessageContentScreenshotTaken".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPushMessageContentScreenshotTakenBuilder { inner }
+        RTDPushMessageContentScreenshotTakenBuilder {
+            inner: PushMessageContentScreenshotTaken::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPushMessageContentScreenshotTakenBuilder {
+        RTDPushMessageContentScreenshotTakenBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPushMessageContentScreenshotTakenBuilder {
+        RTDPushMessageContentScreenshotTakenBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDPushMessageContentScreenshotTakenBuilder {
     inner: PushMessageContentScreenshotTaken,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPushMessageContentScreenshotTakenBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PushMessageContentScreenshotTaken {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PushMessageContentScreenshotTaken> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PushMessageContentScreenshotTaken",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<PushMessageContentScreenshotTaken> for PushMessageContentScreenshotTaken {
@@ -1914,12 +3086,13 @@ impl AsRef<PushMessageContentScreenshotTaken> for RTDPushMessageContentScreensho
 
 /// A message with a sticker
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pushMessageContentSticker")]
 pub struct PushMessageContentSticker {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Message content; may be null
     sticker: Option<Sticker>,
@@ -1943,6 +3116,14 @@ impl RObject for PushMessageContentSticker {
     }
 }
 
+impl PartialEq for PushMessageContentSticker {
+    fn eq(&self, other: &Self) -> bool {
+        self.sticker == other.sticker
+            && self.emoji == other.emoji
+            && self.is_pinned == other.is_pinned
+    }
+}
+
 impl TDPushMessageContent for PushMessageContentSticker {}
 
 impl PushMessageContentSticker {
@@ -1950,10 +3131,24 @@ impl PushMessageContentSticker {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPushMessageContentStickerBuilder {
-        let mut inner = PushMessageContentSticker::default();
-        inner.td_name = "pushMessageContentSticker".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPushMessageContentStickerBuilder { inner }
+        RTDPushMessageContentStickerBuilder {
+            inner: PushMessageContentSticker::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPushMessageContentStickerBuilder {
+        RTDPushMessageContentStickerBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPushMessageContentStickerBuilder {
+        RTDPushMessageContentStickerBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn sticker(&self) -> &Option<Sticker> {
@@ -1967,17 +3162,53 @@ impl PushMessageContentSticker {
     pub fn is_pinned(&self) -> bool {
         self.is_pinned
     }
+
+    pub fn into_sticker(self) -> Option<Sticker> {
+        self.sticker
+    }
+
+    pub fn into_emoji(self) -> String {
+        self.emoji
+    }
+
+    pub fn with_sticker<T: AsRef<Sticker>>(mut self, sticker: T) -> Self {
+        self.sticker = Some(sticker.as_ref().clone());
+        self
+    }
+
+    pub fn with_emoji<T: AsRef<str>>(mut self, emoji: T) -> Self {
+        self.emoji = emoji.as_ref().to_string();
+        self
+    }
+
+    pub fn with_is_pinned(mut self, is_pinned: bool) -> Self {
+        self.is_pinned = is_pinned;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPushMessageContentStickerBuilder {
     inner: PushMessageContentSticker,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPushMessageContentStickerBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PushMessageContentSticker {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PushMessageContentSticker> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PushMessageContentSticker",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn sticker<T: AsRef<Sticker>>(&mut self, sticker: T) -> &mut Self {
         self.inner.sticker = Some(sticker.as_ref().clone());
@@ -2009,12 +3240,13 @@ impl AsRef<PushMessageContentSticker> for RTDPushMessageContentStickerBuilder {
 
 /// A text message
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pushMessageContentText")]
 pub struct PushMessageContentText {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Message text
     text: String,
@@ -2036,6 +3268,21 @@ impl RObject for PushMessageContentText {
     }
 }
 
+impl PartialEq for PushMessageContentText {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text && self.is_pinned == other.is_pinned
+    }
+}
+
+impl Eq for PushMessageContentText {}
+
+impl std::hash::Hash for PushMessageContentText {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.text, state);
+        std::hash::Hash::hash(&self.is_pinned, state);
+    }
+}
+
 impl TDPushMessageContent for PushMessageContentText {}
 
 impl PushMessageContentText {
@@ -2043,10 +3290,24 @@ impl PushMessageContentText {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPushMessageContentTextBuilder {
-        let mut inner = PushMessageContentText::default();
-        inner.td_name = "pushMessageContentText".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPushMessageContentTextBuilder { inner }
+        RTDPushMessageContentTextBuilder {
+            inner: PushMessageContentText::default(),
+            missing: vec!["text"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPushMessageContentTextBuilder {
+        RTDPushMessageContentTextBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPushMessageContentTextBuilder {
+        RTDPushMessageContentTextBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn text(&self) -> &String {
@@ -2056,20 +3317,48 @@ impl PushMessageContentText {
     pub fn is_pinned(&self) -> bool {
         self.is_pinned
     }
+
+    pub fn into_text(self) -> String {
+        self.text
+    }
+
+    pub fn with_text<T: AsRef<str>>(mut self, text: T) -> Self {
+        self.text = text.as_ref().to_string();
+        self
+    }
+
+    pub fn with_is_pinned(mut self, is_pinned: bool) -> Self {
+        self.is_pinned = is_pinned;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPushMessageContentTextBuilder {
     inner: PushMessageContentText,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPushMessageContentTextBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PushMessageContentText {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PushMessageContentText> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PushMessageContentText",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn text<T: AsRef<str>>(&mut self, text: T) -> &mut Self {
         self.inner.text = text.as_ref().to_string();
+        self.missing.retain(|f| *f != "text");
         self
     }
 
@@ -2093,12 +3382,13 @@ impl AsRef<PushMessageContentText> for RTDPushMessageContentTextBuilder {
 
 /// A video message
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pushMessageContentVideo")]
 pub struct PushMessageContentVideo {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Message content; may be null
     video: Option<Video>,
@@ -2124,6 +3414,26 @@ impl RObject for PushMessageContentVideo {
     }
 }
 
+impl PartialEq for PushMessageContentVideo {
+    fn eq(&self, other: &Self) -> bool {
+        self.video == other.video
+            && self.caption == other.caption
+            && self.is_secret == other.is_secret
+            && self.is_pinned == other.is_pinned
+    }
+}
+
+impl Eq for PushMessageContentVideo {}
+
+impl std::hash::Hash for PushMessageContentVideo {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.video, state);
+        std::hash::Hash::hash(&self.caption, state);
+        std::hash::Hash::hash(&self.is_secret, state);
+        std::hash::Hash::hash(&self.is_pinned, state);
+    }
+}
+
 impl TDPushMessageContent for PushMessageContentVideo {}
 
 impl PushMessageContentVideo {
@@ -2131,10 +3441,24 @@ impl PushMessageContentVideo {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPushMessageContentVideoBuilder {
-        let mut inner = PushMessageContentVideo::default();
-        inner.td_name = "pushMessageContentVideo".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPushMessageContentVideoBuilder { inner }
+        RTDPushMessageContentVideoBuilder {
+            inner: PushMessageContentVideo::default(),
+            missing: vec!["caption"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPushMessageContentVideoBuilder {
+        RTDPushMessageContentVideoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPushMessageContentVideoBuilder {
+        RTDPushMessageContentVideoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn video(&self) -> &Option<Video> {
@@ -2152,17 +3476,58 @@ impl PushMessageContentVideo {
     pub fn is_pinned(&self) -> bool {
         self.is_pinned
     }
+
+    pub fn into_video(self) -> Option<Video> {
+        self.video
+    }
+
+    pub fn into_caption(self) -> String {
+        self.caption
+    }
+
+    pub fn with_video<T: AsRef<Video>>(mut self, video: T) -> Self {
+        self.video = Some(video.as_ref().clone());
+        self
+    }
+
+    pub fn with_caption<T: AsRef<str>>(mut self, caption: T) -> Self {
+        self.caption = caption.as_ref().to_string();
+        self
+    }
+
+    pub fn with_is_secret(mut self, is_secret: bool) -> Self {
+        self.is_secret = is_secret;
+        self
+    }
+
+    pub fn with_is_pinned(mut self, is_pinned: bool) -> Self {
+        self.is_pinned = is_pinned;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPushMessageContentVideoBuilder {
     inner: PushMessageContentVideo,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPushMessageContentVideoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PushMessageContentVideo {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PushMessageContentVideo> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PushMessageContentVideo",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn video<T: AsRef<Video>>(&mut self, video: T) -> &mut Self {
         self.inner.video = Some(video.as_ref().clone());
@@ -2171,6 +3536,7 @@ impl RTDPushMessageContentVideoBuilder {
 
     pub fn caption<T: AsRef<str>>(&mut self, caption: T) -> &mut Self {
         self.inner.caption = caption.as_ref().to_string();
+        self.missing.retain(|f| *f != "caption");
         self
     }
 
@@ -2199,12 +3565,13 @@ impl AsRef<PushMessageContentVideo> for RTDPushMessageContentVideoBuilder {
 
 /// A video note message
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pushMessageContentVideoNote")]
 pub struct PushMessageContentVideoNote {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Message content; may be null
     video_note: Option<VideoNote>,
@@ -2226,6 +3593,21 @@ impl RObject for PushMessageContentVideoNote {
     }
 }
 
+impl PartialEq for PushMessageContentVideoNote {
+    fn eq(&self, other: &Self) -> bool {
+        self.video_note == other.video_note && self.is_pinned == other.is_pinned
+    }
+}
+
+impl Eq for PushMessageContentVideoNote {}
+
+impl std::hash::Hash for PushMessageContentVideoNote {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.video_note, state);
+        std::hash::Hash::hash(&self.is_pinned, state);
+    }
+}
+
 impl TDPushMessageContent for PushMessageContentVideoNote {}
 
 impl PushMessageContentVideoNote {
@@ -2233,10 +3615,24 @@ impl PushMessageContentVideoNote {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPushMessageContentVideoNoteBuilder {
-        let mut inner = PushMessageContentVideoNote::default();
-        inner.td_name = "pushMessageContentVideoNote".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPushMessageContentVideoNoteBuilder { inner }
+        RTDPushMessageContentVideoNoteBuilder {
+            inner: PushMessageContentVideoNote::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPushMessageContentVideoNoteBuilder {
+        RTDPushMessageContentVideoNoteBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPushMessageContentVideoNoteBuilder {
+        RTDPushMessageContentVideoNoteBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn video_note(&self) -> &Option<VideoNote> {
@@ -2246,17 +3642,44 @@ impl PushMessageContentVideoNote {
     pub fn is_pinned(&self) -> bool {
         self.is_pinned
     }
+
+    pub fn into_video_note(self) -> Option<VideoNote> {
+        self.video_note
+    }
+
+    pub fn with_video_note<T: AsRef<VideoNote>>(mut self, video_note: T) -> Self {
+        self.video_note = Some(video_note.as_ref().clone());
+        self
+    }
+
+    pub fn with_is_pinned(mut self, is_pinned: bool) -> Self {
+        self.is_pinned = is_pinned;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPushMessageContentVideoNoteBuilder {
     inner: PushMessageContentVideoNote,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPushMessageContentVideoNoteBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PushMessageContentVideoNote {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PushMessageContentVideoNote> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PushMessageContentVideoNote",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn video_note<T: AsRef<VideoNote>>(&mut self, video_note: T) -> &mut Self {
         self.inner.video_note = Some(video_note.as_ref().clone());
@@ -2283,12 +3706,13 @@ impl AsRef<PushMessageContentVideoNote> for RTDPushMessageContentVideoNoteBuilde
 
 /// A voice note message
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pushMessageContentVoiceNote")]
 pub struct PushMessageContentVoiceNote {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Message content; may be null
     voice_note: Option<VoiceNote>,
@@ -2310,6 +3734,21 @@ impl RObject for PushMessageContentVoiceNote {
     }
 }
 
+impl PartialEq for PushMessageContentVoiceNote {
+    fn eq(&self, other: &Self) -> bool {
+        self.voice_note == other.voice_note && self.is_pinned == other.is_pinned
+    }
+}
+
+impl Eq for PushMessageContentVoiceNote {}
+
+impl std::hash::Hash for PushMessageContentVoiceNote {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.voice_note, state);
+        std::hash::Hash::hash(&self.is_pinned, state);
+    }
+}
+
 impl TDPushMessageContent for PushMessageContentVoiceNote {}
 
 impl PushMessageContentVoiceNote {
@@ -2317,10 +3756,24 @@ impl PushMessageContentVoiceNote {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPushMessageContentVoiceNoteBuilder {
-        let mut inner = PushMessageContentVoiceNote::default();
-        inner.td_name = "pushMessageContentVoiceNote".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPushMessageContentVoiceNoteBuilder { inner }
+        RTDPushMessageContentVoiceNoteBuilder {
+            inner: PushMessageContentVoiceNote::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPushMessageContentVoiceNoteBuilder {
+        RTDPushMessageContentVoiceNoteBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPushMessageContentVoiceNoteBuilder {
+        RTDPushMessageContentVoiceNoteBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn voice_note(&self) -> &Option<VoiceNote> {
@@ -2330,17 +3783,44 @@ impl PushMessageContentVoiceNote {
     pub fn is_pinned(&self) -> bool {
         self.is_pinned
     }
+
+    pub fn into_voice_note(self) -> Option<VoiceNote> {
+        self.voice_note
+    }
+
+    pub fn with_voice_note<T: AsRef<VoiceNote>>(mut self, voice_note: T) -> Self {
+        self.voice_note = Some(voice_note.as_ref().clone());
+        self
+    }
+
+    pub fn with_is_pinned(mut self, is_pinned: bool) -> Self {
+        self.is_pinned = is_pinned;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPushMessageContentVoiceNoteBuilder {
     inner: PushMessageContentVoiceNote,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPushMessageContentVoiceNoteBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PushMessageContentVoiceNote {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PushMessageContentVoiceNote> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PushMessageContentVoiceNote",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn voice_note<T: AsRef<VoiceNote>>(&mut self, voice_note: T) -> &mut Self {
         self.inner.voice_note = Some(voice_note.as_ref().clone());
@@ -2364,3 +3844,150 @@ impl AsRef<PushMessageContentVoiceNote> for RTDPushMessageContentVoiceNoteBuilde
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(PushMessageContent::Animation(
+            PushMessageContentAnimation::builder()
+                .caption("text")
+                .is_pinned(true)
+                .build(),
+        ));
+        assert_round_trip(PushMessageContent::Audio(
+            PushMessageContentAudio::builder().is_pinned(true).build(),
+        ));
+        assert_round_trip(PushMessageContent::BasicGroupChatCreate(
+            PushMessageContentBasicGroupChatCreate::builder().build(),
+        ));
+        assert_round_trip(PushMessageContent::ChatAddMembers(
+            PushMessageContentChatAddMembers::builder()
+                .member_name("text")
+                .is_current_user(true)
+                .is_returned(true)
+                .build(),
+        ));
+        assert_round_trip(PushMessageContent::ChatChangePhoto(
+            PushMessageContentChatChangePhoto::builder().build(),
+        ));
+        assert_round_trip(PushMessageContent::ChatChangeTitle(
+            PushMessageContentChatChangeTitle::builder()
+                .title("text")
+                .build(),
+        ));
+        assert_round_trip(PushMessageContent::ChatDeleteMember(
+            PushMessageContentChatDeleteMember::builder()
+                .member_name("text")
+                .is_current_user(true)
+                .is_left(true)
+                .build(),
+        ));
+        assert_round_trip(PushMessageContent::ChatJoinByLink(
+            PushMessageContentChatJoinByLink::builder().build(),
+        ));
+        assert_round_trip(PushMessageContent::Contact(
+            PushMessageContentContact::builder()
+                .name("text")
+                .is_pinned(true)
+                .build(),
+        ));
+        assert_round_trip(PushMessageContent::ContactRegistered(
+            PushMessageContentContactRegistered::builder().build(),
+        ));
+        assert_round_trip(PushMessageContent::Document(
+            PushMessageContentDocument::builder()
+                .is_pinned(true)
+                .build(),
+        ));
+        assert_round_trip(PushMessageContent::Game(
+            PushMessageContentGame::builder()
+                .title("text")
+                .is_pinned(true)
+                .build(),
+        ));
+        assert_round_trip(PushMessageContent::GameScore(
+            PushMessageContentGameScore::builder()
+                .title("text")
+                .score(i32::MAX)
+                .is_pinned(true)
+                .build(),
+        ));
+        assert_round_trip(PushMessageContent::Hidden(
+            PushMessageContentHidden::builder().is_pinned(true).build(),
+        ));
+        assert_round_trip(PushMessageContent::Invoice(
+            PushMessageContentInvoice::builder()
+                .price("text")
+                .is_pinned(true)
+                .build(),
+        ));
+        assert_round_trip(PushMessageContent::Location(
+            PushMessageContentLocation::builder()
+                .is_live(true)
+                .is_pinned(true)
+                .build(),
+        ));
+        assert_round_trip(PushMessageContent::MediaAlbum(
+            PushMessageContentMediaAlbum::builder()
+                .total_count(i32::MAX)
+                .has_photos(true)
+                .has_videos(true)
+                .build(),
+        ));
+        assert_round_trip(PushMessageContent::MessageForwards(
+            PushMessageContentMessageForwards::builder()
+                .total_count(i32::MAX)
+                .build(),
+        ));
+        assert_round_trip(PushMessageContent::Photo(
+            PushMessageContentPhoto::builder()
+                .caption("text")
+                .is_secret(true)
+                .is_pinned(true)
+                .build(),
+        ));
+        assert_round_trip(PushMessageContent::Poll(
+            PushMessageContentPoll::builder()
+                .question("text")
+                .is_regular(true)
+                .is_pinned(true)
+                .build(),
+        ));
+        assert_round_trip(PushMessageContent::ScreenshotTaken(
+            PushMessageContentScreenshotTaken::builder().build(),
+        ));
+        assert_round_trip(PushMessageContent::Sticker(
+            PushMessageContentSticker::builder()
+                .emoji("text")
+                .is_pinned(true)
+                .build(),
+        ));
+        assert_round_trip(PushMessageContent::Text(
+            PushMessageContentText::builder()
+                .text("text")
+                .is_pinned(true)
+                .build(),
+        ));
+        assert_round_trip(PushMessageContent::Video(
+            PushMessageContentVideo::builder()
+                .caption("text")
+                .is_secret(true)
+                .is_pinned(true)
+                .build(),
+        ));
+        assert_round_trip(PushMessageContent::VideoNote(
+            PushMessageContentVideoNote::builder()
+                .is_pinned(true)
+                .build(),
+        ));
+        assert_round_trip(PushMessageContent::VoiceNote(
+            PushMessageContentVoiceNote::builder()
+                .is_pinned(true)
+                .build(),
+        ));
+    }
+}
@@ -1,18 +1,19 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains a globally unique push receiver identifier, which can be used to identify which account has received a push notification
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "pushReceiverId")]
 pub struct PushReceiverId {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The globally unique identifier of push notification subscription
-    id: isize,
+    #[serde(with = "super::_common::int64")]
+    id: i64,
 }
 
 impl RObject for PushReceiverId {
@@ -29,34 +30,81 @@ impl RObject for PushReceiverId {
     }
 }
 
+impl PartialEq for PushReceiverId {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for PushReceiverId {}
+
+impl std::hash::Hash for PushReceiverId {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.id, state);
+    }
+}
+
 impl PushReceiverId {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDPushReceiverIdBuilder {
-        let mut inner = PushReceiverId::default();
-        inner.td_name = "pushReceiverId".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDPushReceiverIdBuilder { inner }
+        RTDPushReceiverIdBuilder {
+            inner: PushReceiverId::default(),
+            missing: vec!["id"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDPushReceiverIdBuilder {
+        RTDPushReceiverIdBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDPushReceiverIdBuilder {
+        RTDPushReceiverIdBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn id(&self) -> isize {
+    pub fn id(&self) -> i64 {
         self.id
     }
+
+    pub fn with_id(mut self, id: i64) -> Self {
+        self.id = id;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDPushReceiverIdBuilder {
     inner: PushReceiverId,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDPushReceiverIdBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> PushReceiverId {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<PushReceiverId> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "PushReceiverId",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn id(&mut self, id: isize) -> &mut Self {
+    pub fn id(&mut self, id: i64) -> &mut Self {
         self.inner.id = id;
+        self.missing.retain(|f| *f != "id");
         self
     }
 }
@@ -72,3 +120,14 @@ impl AsRef<PushReceiverId> for RTDPushReceiverIdBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(PushReceiverId::builder().id(i64::MAX).build());
+    }
+}
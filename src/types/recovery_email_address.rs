@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains information about the current recovery email address
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "recoveryEmailAddress")]
 pub struct RecoveryEmailAddress {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Recovery email address
     recovery_email_address: String,
@@ -29,37 +29,88 @@ impl RObject for RecoveryEmailAddress {
     }
 }
 
+impl PartialEq for RecoveryEmailAddress {
+    fn eq(&self, other: &Self) -> bool {
+        self.recovery_email_address == other.recovery_email_address
+    }
+}
+
+impl Eq for RecoveryEmailAddress {}
+
+impl std::hash::Hash for RecoveryEmailAddress {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.recovery_email_address, state);
+    }
+}
+
 impl RecoveryEmailAddress {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDRecoveryEmailAddressBuilder {
-        let mut inner = RecoveryEmailAddress::default();
-        inner.td_name = "recoveryEmailAddress".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDRecoveryEmailAddressBuilder { inner }
+        RTDRecoveryEmailAddressBuilder {
+            inner: RecoveryEmailAddress::default(),
+            missing: vec!["recovery_email_address"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDRecoveryEmailAddressBuilder {
+        RTDRecoveryEmailAddressBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDRecoveryEmailAddressBuilder {
+        RTDRecoveryEmailAddressBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn recovery_email_address(&self) -> &String {
         &self.recovery_email_address
     }
+
+    pub fn into_recovery_email_address(self) -> String {
+        self.recovery_email_address
+    }
+
+    pub fn with_recovery_email_address<T: AsRef<str>>(mut self, recovery_email_address: T) -> Self {
+        self.recovery_email_address = recovery_email_address.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDRecoveryEmailAddressBuilder {
     inner: RecoveryEmailAddress,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDRecoveryEmailAddressBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> RecoveryEmailAddress {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<RecoveryEmailAddress> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "RecoveryEmailAddress",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn recovery_email_address<T: AsRef<str>>(
         &mut self,
         recovery_email_address: T,
     ) -> &mut Self {
         self.inner.recovery_email_address = recovery_email_address.as_ref().to_string();
+        self.missing.retain(|f| *f != "recovery_email_address");
         self
     }
 }
@@ -75,3 +126,18 @@ impl AsRef<RecoveryEmailAddress> for RTDRecoveryEmailAddressBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            RecoveryEmailAddress::builder()
+                .recovery_email_address("text")
+                .build(),
+        );
+    }
+}
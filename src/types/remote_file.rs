@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Represents a remote file
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "remoteFile")]
 pub struct RemoteFile {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Remote file identifier; may be empty. Can be used across application restarts or even from other devices for the current user. Uniquely identifies a file, but a file can have a lot of different valid identifiers. If the ID starts with "http://" or "https://", it represents the HTTP URL of the file. TDLib is currently unable to download files if only their URL is known. If downloadFile is called on such a file or if it is sent to a secret chat, TDLib starts a file generation process by sending updateFileGenerationStart to the client with the HTTP URL in the original_path and "#url#" as the conversion string. Clients should generate the file by downloading it to the specified location
     id: String,
@@ -20,7 +20,7 @@ pub struct RemoteFile {
     /// True, if a remote copy is fully available
     is_uploading_completed: bool,
     /// Size of the remote available part of the file; 0 if unknown
-    uploaded_size: i64,
+    uploaded_size: i32,
 }
 
 impl RObject for RemoteFile {
@@ -37,15 +37,51 @@ impl RObject for RemoteFile {
     }
 }
 
+impl PartialEq for RemoteFile {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.unique_id == other.unique_id
+            && self.is_uploading_active == other.is_uploading_active
+            && self.is_uploading_completed == other.is_uploading_completed
+            && self.uploaded_size == other.uploaded_size
+    }
+}
+
+impl Eq for RemoteFile {}
+
+impl std::hash::Hash for RemoteFile {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.id, state);
+        std::hash::Hash::hash(&self.unique_id, state);
+        std::hash::Hash::hash(&self.is_uploading_active, state);
+        std::hash::Hash::hash(&self.is_uploading_completed, state);
+        std::hash::Hash::hash(&self.uploaded_size, state);
+    }
+}
+
 impl RemoteFile {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDRemoteFileBuilder {
-        let mut inner = RemoteFile::default();
-        inner.td_name = "remoteFile".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDRemoteFileBuilder { inner }
+        RTDRemoteFileBuilder {
+            inner: RemoteFile::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDRemoteFileBuilder {
+        RTDRemoteFileBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDRemoteFileBuilder {
+        RTDRemoteFileBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn id(&self) -> &String {
@@ -64,20 +100,63 @@ impl RemoteFile {
         self.is_uploading_completed
     }
 
-    pub fn uploaded_size(&self) -> i64 {
+    pub fn uploaded_size(&self) -> i32 {
         self.uploaded_size
     }
+
+    pub fn into_id(self) -> String {
+        self.id
+    }
+
+    pub fn into_unique_id(self) -> String {
+        self.unique_id
+    }
+
+    pub fn with_id<T: AsRef<str>>(mut self, id: T) -> Self {
+        self.id = id.as_ref().to_string();
+        self
+    }
+
+    pub fn with_unique_id<T: AsRef<str>>(mut self, unique_id: T) -> Self {
+        self.unique_id = unique_id.as_ref().to_string();
+        self
+    }
+
+    pub fn with_is_uploading_active(mut self, is_uploading_active: bool) -> Self {
+        self.is_uploading_active = is_uploading_active;
+        self
+    }
+
+    pub fn with_is_uploading_completed(mut self, is_uploading_completed: bool) -> Self {
+        self.is_uploading_completed = is_uploading_completed;
+        self
+    }
+
+    pub fn with_uploaded_size(mut self, uploaded_size: i32) -> Self {
+        self.uploaded_size = uploaded_size;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDRemoteFileBuilder {
     inner: RemoteFile,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDRemoteFileBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> RemoteFile {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<RemoteFile> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("RemoteFile", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
         self.inner.id = id.as_ref().to_string();
@@ -99,7 +178,7 @@ impl RTDRemoteFileBuilder {
         self
     }
 
-    pub fn uploaded_size(&mut self, uploaded_size: i64) -> &mut Self {
+    pub fn uploaded_size(&mut self, uploaded_size: i32) -> &mut Self {
         self.inner.uploaded_size = uploaded_size;
         self
     }
@@ -116,3 +195,22 @@ impl AsRef<RemoteFile> for RTDRemoteFileBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            RemoteFile::builder()
+                .id("text")
+                .unique_id("text")
+                .is_uploading_active(true)
+                .is_uploading_completed(true)
+                .uploaded_size(i32::MAX)
+                .build(),
+        );
+    }
+}
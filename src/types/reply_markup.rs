@@ -1,49 +1,48 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Contains a description of a custom keyboard and actions that can be done with it to quickly reply to bots
 pub trait TDReplyMarkup: Debug + RObject {}
 
 /// Contains a description of a custom keyboard and actions that can be done with it to quickly reply to bots
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum ReplyMarkup {
-    #[doc(hidden)]
-    _Default(()),
     /// Instructs clients to force a reply to this message
+    #[serde(rename = "replyMarkupForceReply")]
     ForceReply(ReplyMarkupForceReply),
     /// Contains an inline keyboard layout
+    #[serde(rename = "replyMarkupInlineKeyboard")]
     InlineKeyboard(ReplyMarkupInlineKeyboard),
     /// Instructs clients to remove the keyboard once this message has been received. This kind of keyboard can't be received in an incoming message; instead, UpdateChatReplyMarkup with message_id == 0 will be sent
+    #[serde(rename = "replyMarkupRemoveKeyboard")]
     RemoveKeyboard(ReplyMarkupRemoveKeyboard),
     /// Contains a custom keyboard layout to quickly reply to bots
+    #[serde(rename = "replyMarkupShowKeyboard")]
     ShowKeyboard(ReplyMarkupShowKeyboard),
 }
 
+/// Default value is default of [ReplyMarkupForceReply], builders still require the field to be set
 impl Default for ReplyMarkup {
     fn default() -> Self {
-        ReplyMarkup::_Default(())
+        ReplyMarkup::ForceReply(ReplyMarkupForceReply::default())
     }
 }
 
-impl<'de> Deserialize<'de> for ReplyMarkup {
-    fn deserialize<D>(deserializer: D) -> Result<ReplyMarkup, D::Error>
+impl Serialize for ReplyMarkup {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          ReplyMarkup,
-          (replyMarkupForceReply, ForceReply);
-          (replyMarkupInlineKeyboard, InlineKeyboard);
-          (replyMarkupRemoveKeyboard, RemoveKeyboard);
-          (replyMarkupShowKeyboard, ShowKeyboard);
-
-        )(deserializer)
+        match self {
+            ReplyMarkup::ForceReply(t) => t.serialize(serializer),
+            ReplyMarkup::InlineKeyboard(t) => t.serialize(serializer),
+            ReplyMarkup::RemoveKeyboard(t) => t.serialize(serializer),
+            ReplyMarkup::ShowKeyboard(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -55,8 +54,6 @@ impl RObject for ReplyMarkup {
             ReplyMarkup::InlineKeyboard(t) => t.td_name(),
             ReplyMarkup::RemoveKeyboard(t) => t.td_name(),
             ReplyMarkup::ShowKeyboard(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -66,8 +63,6 @@ impl RObject for ReplyMarkup {
             ReplyMarkup::InlineKeyboard(t) => t.extra(),
             ReplyMarkup::RemoveKeyboard(t) => t.extra(),
             ReplyMarkup::ShowKeyboard(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -79,10 +74,6 @@ impl ReplyMarkup {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, ReplyMarkup::_Default(_))
-    }
 }
 
 impl AsRef<ReplyMarkup> for ReplyMarkup {
@@ -93,12 +84,13 @@ impl AsRef<ReplyMarkup> for ReplyMarkup {
 
 /// Instructs clients to force a reply to this message
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "replyMarkupForceReply")]
 pub struct ReplyMarkupForceReply {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// True, if a forced reply must automatically be shown to the current user. For outgoing messages, specify true to show the forced reply only for the mentioned users and for the target user of a reply
     is_personal: bool,
@@ -118,6 +110,20 @@ impl RObject for ReplyMarkupForceReply {
     }
 }
 
+impl PartialEq for ReplyMarkupForceReply {
+    fn eq(&self, other: &Self) -> bool {
+        self.is_personal == other.is_personal
+    }
+}
+
+impl Eq for ReplyMarkupForceReply {}
+
+impl std::hash::Hash for ReplyMarkupForceReply {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.is_personal, state);
+    }
+}
+
 impl TDReplyMarkup for ReplyMarkupForceReply {}
 
 impl ReplyMarkupForceReply {
@@ -125,26 +131,58 @@ impl ReplyMarkupForceReply {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDReplyMarkupForceReplyBuilder {
-        let mut inner = ReplyMarkupForceReply::default();
-        inner.td_name = "replyMarkupForceReply".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDReplyMarkupForceReplyBuilder { inner }
+        RTDReplyMarkupForceReplyBuilder {
+            inner: ReplyMarkupForceReply::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDReplyMarkupForceReplyBuilder {
+        RTDReplyMarkupForceReplyBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDReplyMarkupForceReplyBuilder {
+        RTDReplyMarkupForceReplyBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn is_personal(&self) -> bool {
         self.is_personal
     }
+
+    pub fn with_is_personal(mut self, is_personal: bool) -> Self {
+        self.is_personal = is_personal;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDReplyMarkupForceReplyBuilder {
     inner: ReplyMarkupForceReply,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDReplyMarkupForceReplyBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ReplyMarkupForceReply {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ReplyMarkupForceReply> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ReplyMarkupForceReply",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn is_personal(&mut self, is_personal: bool) -> &mut Self {
         self.inner.is_personal = is_personal;
@@ -166,12 +204,13 @@ impl AsRef<ReplyMarkupForceReply> for RTDReplyMarkupForceReplyBuilder {
 
 /// Contains an inline keyboard layout
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "replyMarkupInlineKeyboard")]
 pub struct ReplyMarkupInlineKeyboard {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// A list of rows of inline keyboard buttons
     rows: Vec<Vec<InlineKeyboardButton>>,
@@ -191,6 +230,20 @@ impl RObject for ReplyMarkupInlineKeyboard {
     }
 }
 
+impl PartialEq for ReplyMarkupInlineKeyboard {
+    fn eq(&self, other: &Self) -> bool {
+        self.rows == other.rows
+    }
+}
+
+impl Eq for ReplyMarkupInlineKeyboard {}
+
+impl std::hash::Hash for ReplyMarkupInlineKeyboard {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.rows, state);
+    }
+}
+
 impl TDReplyMarkup for ReplyMarkupInlineKeyboard {}
 
 impl ReplyMarkupInlineKeyboard {
@@ -198,29 +251,66 @@ impl ReplyMarkupInlineKeyboard {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDReplyMarkupInlineKeyboardBuilder {
-        let mut inner = ReplyMarkupInlineKeyboard::default();
-        inner.td_name = "replyMarkupInlineKeyboard".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDReplyMarkupInlineKeyboardBuilder { inner }
+        RTDReplyMarkupInlineKeyboardBuilder {
+            inner: ReplyMarkupInlineKeyboard::default(),
+            missing: vec!["rows"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDReplyMarkupInlineKeyboardBuilder {
+        RTDReplyMarkupInlineKeyboardBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDReplyMarkupInlineKeyboardBuilder {
+        RTDReplyMarkupInlineKeyboardBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn rows(&self) -> &Vec<Vec<InlineKeyboardButton>> {
         &self.rows
     }
+
+    pub fn into_rows(self) -> Vec<Vec<InlineKeyboardButton>> {
+        self.rows
+    }
+
+    pub fn with_rows(mut self, rows: Vec<Vec<InlineKeyboardButton>>) -> Self {
+        self.rows = rows;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDReplyMarkupInlineKeyboardBuilder {
     inner: ReplyMarkupInlineKeyboard,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDReplyMarkupInlineKeyboardBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ReplyMarkupInlineKeyboard {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ReplyMarkupInlineKeyboard> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ReplyMarkupInlineKeyboard",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn rows(&mut self, rows: Vec<Vec<InlineKeyboardButton>>) -> &mut Self {
         self.inner.rows = rows;
+        self.missing.retain(|f| *f != "rows");
         self
     }
 }
@@ -239,12 +329,13 @@ impl AsRef<ReplyMarkupInlineKeyboard> for RTDReplyMarkupInlineKeyboardBuilder {
 
 /// Instructs clients to remove the keyboard once this message has been received. This kind of keyboard can't be received in an incoming message; instead, UpdateChatReplyMarkup with message_id == 0 will be sent
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "replyMarkupRemoveKeyboard")]
 pub struct ReplyMarkupRemoveKeyboard {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// True, if the keyboard is removed only for the mentioned users or the target user of a reply
     is_personal: bool,
@@ -264,6 +355,20 @@ impl RObject for ReplyMarkupRemoveKeyboard {
     }
 }
 
+impl PartialEq for ReplyMarkupRemoveKeyboard {
+    fn eq(&self, other: &Self) -> bool {
+        self.is_personal == other.is_personal
+    }
+}
+
+impl Eq for ReplyMarkupRemoveKeyboard {}
+
+impl std::hash::Hash for ReplyMarkupRemoveKeyboard {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.is_personal, state);
+    }
+}
+
 impl TDReplyMarkup for ReplyMarkupRemoveKeyboard {}
 
 impl ReplyMarkupRemoveKeyboard {
@@ -271,26 +376,58 @@ impl ReplyMarkupRemoveKeyboard {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDReplyMarkupRemoveKeyboardBuilder {
-        let mut inner = ReplyMarkupRemoveKeyboard::default();
-        inner.td_name = "replyMarkupRemoveKeyboard".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDReplyMarkupRemoveKeyboardBuilder { inner }
+        RTDReplyMarkupRemoveKeyboardBuilder {
+            inner: ReplyMarkupRemoveKeyboard::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDReplyMarkupRemoveKeyboardBuilder {
+        RTDReplyMarkupRemoveKeyboardBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDReplyMarkupRemoveKeyboardBuilder {
+        RTDReplyMarkupRemoveKeyboardBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn is_personal(&self) -> bool {
         self.is_personal
     }
+
+    pub fn with_is_personal(mut self, is_personal: bool) -> Self {
+        self.is_personal = is_personal;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDReplyMarkupRemoveKeyboardBuilder {
     inner: ReplyMarkupRemoveKeyboard,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDReplyMarkupRemoveKeyboardBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ReplyMarkupRemoveKeyboard {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ReplyMarkupRemoveKeyboard> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ReplyMarkupRemoveKeyboard",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn is_personal(&mut self, is_personal: bool) -> &mut Self {
         self.inner.is_personal = is_personal;
@@ -312,12 +449,13 @@ impl AsRef<ReplyMarkupRemoveKeyboard> for RTDReplyMarkupRemoveKeyboardBuilder {
 
 /// Contains a custom keyboard layout to quickly reply to bots
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "replyMarkupShowKeyboard")]
 pub struct ReplyMarkupShowKeyboard {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// A list of rows of bot keyboard buttons
     rows: Vec<Vec<KeyboardButton>>,
@@ -343,6 +481,26 @@ impl RObject for ReplyMarkupShowKeyboard {
     }
 }
 
+impl PartialEq for ReplyMarkupShowKeyboard {
+    fn eq(&self, other: &Self) -> bool {
+        self.rows == other.rows
+            && self.resize_keyboard == other.resize_keyboard
+            && self.one_time == other.one_time
+            && self.is_personal == other.is_personal
+    }
+}
+
+impl Eq for ReplyMarkupShowKeyboard {}
+
+impl std::hash::Hash for ReplyMarkupShowKeyboard {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.rows, state);
+        std::hash::Hash::hash(&self.resize_keyboard, state);
+        std::hash::Hash::hash(&self.one_time, state);
+        std::hash::Hash::hash(&self.is_personal, state);
+    }
+}
+
 impl TDReplyMarkup for ReplyMarkupShowKeyboard {}
 
 impl ReplyMarkupShowKeyboard {
@@ -350,10 +508,24 @@ impl ReplyMarkupShowKeyboard {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDReplyMarkupShowKeyboardBuilder {
-        let mut inner = ReplyMarkupShowKeyboard::default();
-        inner.td_name = "replyMarkupShowKeyboard".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDReplyMarkupShowKeyboardBuilder { inner }
+        RTDReplyMarkupShowKeyboardBuilder {
+            inner: ReplyMarkupShowKeyboard::default(),
+            missing: vec!["rows"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDReplyMarkupShowKeyboardBuilder {
+        RTDReplyMarkupShowKeyboardBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDReplyMarkupShowKeyboardBuilder {
+        RTDReplyMarkupShowKeyboardBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn rows(&self) -> &Vec<Vec<KeyboardButton>> {
@@ -371,20 +543,58 @@ impl ReplyMarkupShowKeyboard {
     pub fn is_personal(&self) -> bool {
         self.is_personal
     }
+
+    pub fn into_rows(self) -> Vec<Vec<KeyboardButton>> {
+        self.rows
+    }
+
+    pub fn with_rows(mut self, rows: Vec<Vec<KeyboardButton>>) -> Self {
+        self.rows = rows;
+        self
+    }
+
+    pub fn with_resize_keyboard(mut self, resize_keyboard: bool) -> Self {
+        self.resize_keyboard = resize_keyboard;
+        self
+    }
+
+    pub fn with_one_time(mut self, one_time: bool) -> Self {
+        self.one_time = one_time;
+        self
+    }
+
+    pub fn with_is_personal(mut self, is_personal: bool) -> Self {
+        self.is_personal = is_personal;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDReplyMarkupShowKeyboardBuilder {
     inner: ReplyMarkupShowKeyboard,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDReplyMarkupShowKeyboardBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ReplyMarkupShowKeyboard {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ReplyMarkupShowKeyboard> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ReplyMarkupShowKeyboard",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn rows(&mut self, rows: Vec<Vec<KeyboardButton>>) -> &mut Self {
         self.inner.rows = rows;
+        self.missing.retain(|f| *f != "rows");
         self
     }
 
@@ -415,3 +625,32 @@ impl AsRef<ReplyMarkupShowKeyboard> for RTDReplyMarkupShowKeyboardBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(ReplyMarkup::ForceReply(
+            ReplyMarkupForceReply::builder().is_personal(true).build(),
+        ));
+        assert_round_trip(ReplyMarkup::InlineKeyboard(
+            ReplyMarkupInlineKeyboard::builder().rows(vec![]).build(),
+        ));
+        assert_round_trip(ReplyMarkup::RemoveKeyboard(
+            ReplyMarkupRemoveKeyboard::builder()
+                .is_personal(true)
+                .build(),
+        ));
+        assert_round_trip(ReplyMarkup::ShowKeyboard(
+            ReplyMarkupShowKeyboard::builder()
+                .rows(vec![])
+                .resize_keyboard(true)
+                .one_time(true)
+                .is_personal(true)
+                .build(),
+        ));
+    }
+}
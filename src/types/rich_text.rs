@@ -1,82 +1,92 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Describes a text object inside an instant-view web page
 pub trait TDRichText: Debug + RObject {}
 
 /// Describes a text object inside an instant-view web page
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum RichText {
-    #[doc(hidden)]
-    _Default(()),
     /// A rich text anchor
+    #[serde(rename = "richTextAnchor")]
     Anchor(RichTextAnchor),
     /// A bold rich text
+    #[serde(rename = "richTextBold")]
     Bold(RichTextBold),
     /// A rich text email link
+    #[serde(rename = "richTextEmailAddress")]
     EmailAddress(RichTextEmailAddress),
     /// A fixed-width rich text
+    #[serde(rename = "richTextFixed")]
     Fixed(RichTextFixed),
     /// A small image inside the text
+    #[serde(rename = "richTextIcon")]
     Icon(RichTextIcon),
     /// An italicized rich text
+    #[serde(rename = "richTextItalic")]
     Italic(RichTextItalic),
     /// A marked rich text
+    #[serde(rename = "richTextMarked")]
     Marked(RichTextMarked),
     /// A rich text phone number
+    #[serde(rename = "richTextPhoneNumber")]
     PhoneNumber(RichTextPhoneNumber),
     /// A plain text
+    #[serde(rename = "richTextPlain")]
     Plain(RichTextPlain),
     /// A strikethrough rich text
+    #[serde(rename = "richTextStrikethrough")]
     Strikethrough(RichTextStrikethrough),
     /// A subscript rich text
+    #[serde(rename = "richTextSubscript")]
     Subscript(RichTextSubscript),
     /// A superscript rich text
+    #[serde(rename = "richTextSuperscript")]
     Superscript(RichTextSuperscript),
     /// An underlined rich text
+    #[serde(rename = "richTextUnderline")]
     Underline(RichTextUnderline),
     /// A rich text URL link
+    #[serde(rename = "richTextUrl")]
     Url(RichTextUrl),
     /// A concatenation of rich texts
+    #[serde(rename = "richTexts")]
     RichTexts(RichTexts),
 }
 
+/// Default value is default of [RichTexts], builders still require the field to be set
 impl Default for RichText {
     fn default() -> Self {
-        RichText::_Default(())
+        RichText::RichTexts(RichTexts::default())
     }
 }
 
-impl<'de> Deserialize<'de> for RichText {
-    fn deserialize<D>(deserializer: D) -> Result<RichText, D::Error>
+impl Serialize for RichText {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          RichText,
-          (richTextAnchor, Anchor);
-          (richTextBold, Bold);
-          (richTextEmailAddress, EmailAddress);
-          (richTextFixed, Fixed);
-          (richTextIcon, Icon);
-          (richTextItalic, Italic);
-          (richTextMarked, Marked);
-          (richTextPhoneNumber, PhoneNumber);
-          (richTextPlain, Plain);
-          (richTextStrikethrough, Strikethrough);
-          (richTextSubscript, Subscript);
-          (richTextSuperscript, Superscript);
-          (richTextUnderline, Underline);
-          (richTextUrl, Url);
-          (richTexts, RichTexts);
-
-        )(deserializer)
+        match self {
+            RichText::Anchor(t) => t.serialize(serializer),
+            RichText::Bold(t) => t.serialize(serializer),
+            RichText::EmailAddress(t) => t.serialize(serializer),
+            RichText::Fixed(t) => t.serialize(serializer),
+            RichText::Icon(t) => t.serialize(serializer),
+            RichText::Italic(t) => t.serialize(serializer),
+            RichText::Marked(t) => t.serialize(serializer),
+            RichText::PhoneNumber(t) => t.serialize(serializer),
+            RichText::Plain(t) => t.serialize(serializer),
+            RichText::Strikethrough(t) => t.serialize(serializer),
+            RichText::Subscript(t) => t.serialize(serializer),
+            RichText::Superscript(t) => t.serialize(serializer),
+            RichText::Underline(t) => t.serialize(serializer),
+            RichText::Url(t) => t.serialize(serializer),
+            RichText::RichTexts(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -99,8 +109,6 @@ impl RObject for RichText {
             RichText::Underline(t) => t.td_name(),
             RichText::Url(t) => t.td_name(),
             RichText::RichTexts(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -121,8 +129,6 @@ impl RObject for RichText {
             RichText::Underline(t) => t.extra(),
             RichText::Url(t) => t.extra(),
             RichText::RichTexts(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -134,10 +140,6 @@ impl RichText {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, RichText::_Default(_))
-    }
 }
 
 impl AsRef<RichText> for RichText {
@@ -148,12 +150,13 @@ impl AsRef<RichText> for RichText {
 
 /// A rich text anchor
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "richTextAnchor")]
 pub struct RichTextAnchor {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Text
     text: Box<RichText>,
@@ -175,6 +178,21 @@ impl RObject for RichTextAnchor {
     }
 }
 
+impl PartialEq for RichTextAnchor {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text && self.name == other.name
+    }
+}
+
+impl Eq for RichTextAnchor {}
+
+impl std::hash::Hash for RichTextAnchor {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.text, state);
+        std::hash::Hash::hash(&self.name, state);
+    }
+}
+
 impl TDRichText for RichTextAnchor {}
 
 impl RichTextAnchor {
@@ -182,10 +200,24 @@ impl RichTextAnchor {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDRichTextAnchorBuilder {
-        let mut inner = RichTextAnchor::default();
-        inner.td_name = "richTextAnchor".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDRichTextAnchorBuilder { inner }
+        RTDRichTextAnchorBuilder {
+            inner: RichTextAnchor::default(),
+            missing: vec!["text", "name"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDRichTextAnchorBuilder {
+        RTDRichTextAnchorBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDRichTextAnchorBuilder {
+        RTDRichTextAnchorBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn text(&self) -> &Box<RichText> {
@@ -195,25 +227,58 @@ impl RichTextAnchor {
     pub fn name(&self) -> &String {
         &self.name
     }
+
+    pub fn into_text(self) -> RichText {
+        *self.text
+    }
+
+    pub fn into_name(self) -> String {
+        self.name
+    }
+
+    pub fn with_text<T: AsRef<RichText>>(mut self, text: T) -> Self {
+        *self.text = text.as_ref().clone();
+        self
+    }
+
+    pub fn with_name<T: AsRef<str>>(mut self, name: T) -> Self {
+        self.name = name.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDRichTextAnchorBuilder {
     inner: RichTextAnchor,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDRichTextAnchorBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> RichTextAnchor {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<RichTextAnchor> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "RichTextAnchor",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
-        self.inner.text = text.as_ref().clone();
+    pub fn text<T: AsRef<RichText>>(&mut self, text: T) -> &mut Self {
+        *self.inner.text = text.as_ref().clone();
+        self.missing.retain(|f| *f != "text");
         self
     }
 
     pub fn name<T: AsRef<str>>(&mut self, name: T) -> &mut Self {
         self.inner.name = name.as_ref().to_string();
+        self.missing.retain(|f| *f != "name");
         self
     }
 }
@@ -232,12 +297,13 @@ impl AsRef<RichTextAnchor> for RTDRichTextAnchorBuilder {
 
 /// A bold rich text
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "richTextBold")]
 pub struct RichTextBold {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Text
     text: Box<RichText>,
@@ -257,6 +323,20 @@ impl RObject for RichTextBold {
     }
 }
 
+impl PartialEq for RichTextBold {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text
+    }
+}
+
+impl Eq for RichTextBold {}
+
+impl std::hash::Hash for RichTextBold {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.text, state);
+    }
+}
+
 impl TDRichText for RichTextBold {}
 
 impl RichTextBold {
@@ -264,29 +344,66 @@ impl RichTextBold {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDRichTextBoldBuilder {
-        let mut inner = RichTextBold::default();
-        inner.td_name = "richTextBold".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDRichTextBoldBuilder { inner }
+        RTDRichTextBoldBuilder {
+            inner: RichTextBold::default(),
+            missing: vec!["text"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDRichTextBoldBuilder {
+        RTDRichTextBoldBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDRichTextBoldBuilder {
+        RTDRichTextBoldBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn text(&self) -> &Box<RichText> {
         &self.text
     }
+
+    pub fn into_text(self) -> RichText {
+        *self.text
+    }
+
+    pub fn with_text<T: AsRef<RichText>>(mut self, text: T) -> Self {
+        *self.text = text.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDRichTextBoldBuilder {
     inner: RichTextBold,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDRichTextBoldBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> RichTextBold {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<RichTextBold> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "RichTextBold",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
-        self.inner.text = text.as_ref().clone();
+    pub fn text<T: AsRef<RichText>>(&mut self, text: T) -> &mut Self {
+        *self.inner.text = text.as_ref().clone();
+        self.missing.retain(|f| *f != "text");
         self
     }
 }
@@ -305,12 +422,13 @@ impl AsRef<RichTextBold> for RTDRichTextBoldBuilder {
 
 /// A rich text email link
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "richTextEmailAddress")]
 pub struct RichTextEmailAddress {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Text
     text: Box<RichText>,
@@ -332,6 +450,21 @@ impl RObject for RichTextEmailAddress {
     }
 }
 
+impl PartialEq for RichTextEmailAddress {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text && self.email_address == other.email_address
+    }
+}
+
+impl Eq for RichTextEmailAddress {}
+
+impl std::hash::Hash for RichTextEmailAddress {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.text, state);
+        std::hash::Hash::hash(&self.email_address, state);
+    }
+}
+
 impl TDRichText for RichTextEmailAddress {}
 
 impl RichTextEmailAddress {
@@ -339,10 +472,24 @@ impl RichTextEmailAddress {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDRichTextEmailAddressBuilder {
-        let mut inner = RichTextEmailAddress::default();
-        inner.td_name = "richTextEmailAddress".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDRichTextEmailAddressBuilder { inner }
+        RTDRichTextEmailAddressBuilder {
+            inner: RichTextEmailAddress::default(),
+            missing: vec!["text", "email_address"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDRichTextEmailAddressBuilder {
+        RTDRichTextEmailAddressBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDRichTextEmailAddressBuilder {
+        RTDRichTextEmailAddressBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn text(&self) -> &Box<RichText> {
@@ -352,25 +499,58 @@ impl RichTextEmailAddress {
     pub fn email_address(&self) -> &String {
         &self.email_address
     }
+
+    pub fn into_text(self) -> RichText {
+        *self.text
+    }
+
+    pub fn into_email_address(self) -> String {
+        self.email_address
+    }
+
+    pub fn with_text<T: AsRef<RichText>>(mut self, text: T) -> Self {
+        *self.text = text.as_ref().clone();
+        self
+    }
+
+    pub fn with_email_address<T: AsRef<str>>(mut self, email_address: T) -> Self {
+        self.email_address = email_address.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDRichTextEmailAddressBuilder {
     inner: RichTextEmailAddress,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDRichTextEmailAddressBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> RichTextEmailAddress {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<RichTextEmailAddress> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "RichTextEmailAddress",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
-        self.inner.text = text.as_ref().clone();
+    pub fn text<T: AsRef<RichText>>(&mut self, text: T) -> &mut Self {
+        *self.inner.text = text.as_ref().clone();
+        self.missing.retain(|f| *f != "text");
         self
     }
 
     pub fn email_address<T: AsRef<str>>(&mut self, email_address: T) -> &mut Self {
         self.inner.email_address = email_address.as_ref().to_string();
+        self.missing.retain(|f| *f != "email_address");
         self
     }
 }
@@ -389,12 +569,13 @@ impl AsRef<RichTextEmailAddress> for RTDRichTextEmailAddressBuilder {
 
 /// A fixed-width rich text
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "richTextFixed")]
 pub struct RichTextFixed {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Text
     text: Box<RichText>,
@@ -414,6 +595,20 @@ impl RObject for RichTextFixed {
     }
 }
 
+impl PartialEq for RichTextFixed {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text
+    }
+}
+
+impl Eq for RichTextFixed {}
+
+impl std::hash::Hash for RichTextFixed {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.text, state);
+    }
+}
+
 impl TDRichText for RichTextFixed {}
 
 impl RichTextFixed {
@@ -421,29 +616,66 @@ impl RichTextFixed {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDRichTextFixedBuilder {
-        let mut inner = RichTextFixed::default();
-        inner.td_name = "richTextFixed".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDRichTextFixedBuilder { inner }
+        RTDRichTextFixedBuilder {
+            inner: RichTextFixed::default(),
+            missing: vec!["text"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDRichTextFixedBuilder {
+        RTDRichTextFixedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDRichTextFixedBuilder {
+        RTDRichTextFixedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn text(&self) -> &Box<RichText> {
         &self.text
     }
+
+    pub fn into_text(self) -> RichText {
+        *self.text
+    }
+
+    pub fn with_text<T: AsRef<RichText>>(mut self, text: T) -> Self {
+        *self.text = text.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDRichTextFixedBuilder {
     inner: RichTextFixed,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDRichTextFixedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> RichTextFixed {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<RichTextFixed> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "RichTextFixed",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
-        self.inner.text = text.as_ref().clone();
+    pub fn text<T: AsRef<RichText>>(&mut self, text: T) -> &mut Self {
+        *self.inner.text = text.as_ref().clone();
+        self.missing.retain(|f| *f != "text");
         self
     }
 }
@@ -462,19 +694,20 @@ impl AsRef<RichTextFixed> for RTDRichTextFixedBuilder {
 
 /// A small image inside the text
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "richTextIcon")]
 pub struct RichTextIcon {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The image represented as a document. The image can be in GIF, JPEG or PNG format
     document: Document,
     /// Width of a bounding box in which the image should be shown; 0 if unknown
-    width: i64,
+    width: i32,
     /// Height of a bounding box in which the image should be shown; 0 if unknown
-    height: i64,
+    height: i32,
 }
 
 impl RObject for RichTextIcon {
@@ -491,6 +724,22 @@ impl RObject for RichTextIcon {
     }
 }
 
+impl PartialEq for RichTextIcon {
+    fn eq(&self, other: &Self) -> bool {
+        self.document == other.document && self.width == other.width && self.height == other.height
+    }
+}
+
+impl Eq for RichTextIcon {}
+
+impl std::hash::Hash for RichTextIcon {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.document, state);
+        std::hash::Hash::hash(&self.width, state);
+        std::hash::Hash::hash(&self.height, state);
+    }
+}
+
 impl TDRichText for RichTextIcon {}
 
 impl RichTextIcon {
@@ -498,46 +747,93 @@ impl RichTextIcon {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDRichTextIconBuilder {
-        let mut inner = RichTextIcon::default();
-        inner.td_name = "richTextIcon".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDRichTextIconBuilder { inner }
+        RTDRichTextIconBuilder {
+            inner: RichTextIcon::default(),
+            missing: vec!["document"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDRichTextIconBuilder {
+        RTDRichTextIconBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDRichTextIconBuilder {
+        RTDRichTextIconBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn document(&self) -> &Document {
         &self.document
     }
 
-    pub fn width(&self) -> i64 {
+    pub fn width(&self) -> i32 {
         self.width
     }
 
-    pub fn height(&self) -> i64 {
+    pub fn height(&self) -> i32 {
         self.height
     }
+
+    pub fn into_document(self) -> Document {
+        self.document
+    }
+
+    pub fn with_document<T: AsRef<Document>>(mut self, document: T) -> Self {
+        self.document = document.as_ref().clone();
+        self
+    }
+
+    pub fn with_width(mut self, width: i32) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn with_height(mut self, height: i32) -> Self {
+        self.height = height;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDRichTextIconBuilder {
     inner: RichTextIcon,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDRichTextIconBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> RichTextIcon {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<RichTextIcon> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "RichTextIcon",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn document<T: AsRef<Document>>(&mut self, document: T) -> &mut Self {
         self.inner.document = document.as_ref().clone();
+        self.missing.retain(|f| *f != "document");
         self
     }
 
-    pub fn width(&mut self, width: i64) -> &mut Self {
+    pub fn width(&mut self, width: i32) -> &mut Self {
         self.inner.width = width;
         self
     }
 
-    pub fn height(&mut self, height: i64) -> &mut Self {
+    pub fn height(&mut self, height: i32) -> &mut Self {
         self.inner.height = height;
         self
     }
@@ -557,12 +853,13 @@ impl AsRef<RichTextIcon> for RTDRichTextIconBuilder {
 
 /// An italicized rich text
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "richTextItalic")]
 pub struct RichTextItalic {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Text
     text: Box<RichText>,
@@ -582,6 +879,20 @@ impl RObject for RichTextItalic {
     }
 }
 
+impl PartialEq for RichTextItalic {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text
+    }
+}
+
+impl Eq for RichTextItalic {}
+
+impl std::hash::Hash for RichTextItalic {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.text, state);
+    }
+}
+
 impl TDRichText for RichTextItalic {}
 
 impl RichTextItalic {
@@ -589,29 +900,66 @@ impl RichTextItalic {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDRichTextItalicBuilder {
-        let mut inner = RichTextItalic::default();
-        inner.td_name = "richTextItalic".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDRichTextItalicBuilder { inner }
+        RTDRichTextItalicBuilder {
+            inner: RichTextItalic::default(),
+            missing: vec!["text"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDRichTextItalicBuilder {
+        RTDRichTextItalicBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDRichTextItalicBuilder {
+        RTDRichTextItalicBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn text(&self) -> &Box<RichText> {
         &self.text
     }
+
+    pub fn into_text(self) -> RichText {
+        *self.text
+    }
+
+    pub fn with_text<T: AsRef<RichText>>(mut self, text: T) -> Self {
+        *self.text = text.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDRichTextItalicBuilder {
     inner: RichTextItalic,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDRichTextItalicBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> RichTextItalic {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<RichTextItalic> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "RichTextItalic",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
-        self.inner.text = text.as_ref().clone();
+    pub fn text<T: AsRef<RichText>>(&mut self, text: T) -> &mut Self {
+        *self.inner.text = text.as_ref().clone();
+        self.missing.retain(|f| *f != "text");
         self
     }
 }
@@ -630,12 +978,13 @@ impl AsRef<RichTextItalic> for RTDRichTextItalicBuilder {
 
 /// A marked rich text
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "richTextMarked")]
 pub struct RichTextMarked {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Text
     text: Box<RichText>,
@@ -655,6 +1004,20 @@ impl RObject for RichTextMarked {
     }
 }
 
+impl PartialEq for RichTextMarked {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text
+    }
+}
+
+impl Eq for RichTextMarked {}
+
+impl std::hash::Hash for RichTextMarked {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.text, state);
+    }
+}
+
 impl TDRichText for RichTextMarked {}
 
 impl RichTextMarked {
@@ -662,29 +1025,66 @@ impl RichTextMarked {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDRichTextMarkedBuilder {
-        let mut inner = RichTextMarked::default();
-        inner.td_name = "richTextMarked".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDRichTextMarkedBuilder { inner }
+        RTDRichTextMarkedBuilder {
+            inner: RichTextMarked::default(),
+            missing: vec!["text"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDRichTextMarkedBuilder {
+        RTDRichTextMarkedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDRichTextMarkedBuilder {
+        RTDRichTextMarkedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn text(&self) -> &Box<RichText> {
         &self.text
     }
+
+    pub fn into_text(self) -> RichText {
+        *self.text
+    }
+
+    pub fn with_text<T: AsRef<RichText>>(mut self, text: T) -> Self {
+        *self.text = text.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDRichTextMarkedBuilder {
     inner: RichTextMarked,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDRichTextMarkedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> RichTextMarked {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<RichTextMarked> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "RichTextMarked",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
-        self.inner.text = text.as_ref().clone();
+    pub fn text<T: AsRef<RichText>>(&mut self, text: T) -> &mut Self {
+        *self.inner.text = text.as_ref().clone();
+        self.missing.retain(|f| *f != "text");
         self
     }
 }
@@ -703,12 +1103,13 @@ impl AsRef<RichTextMarked> for RTDRichTextMarkedBuilder {
 
 /// A rich text phone number
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "richTextPhoneNumber")]
 pub struct RichTextPhoneNumber {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Text
     text: Box<RichText>,
@@ -730,6 +1131,21 @@ impl RObject for RichTextPhoneNumber {
     }
 }
 
+impl PartialEq for RichTextPhoneNumber {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text && self.phone_number == other.phone_number
+    }
+}
+
+impl Eq for RichTextPhoneNumber {}
+
+impl std::hash::Hash for RichTextPhoneNumber {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.text, state);
+        std::hash::Hash::hash(&self.phone_number, state);
+    }
+}
+
 impl TDRichText for RichTextPhoneNumber {}
 
 impl RichTextPhoneNumber {
@@ -737,10 +1153,24 @@ impl RichTextPhoneNumber {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDRichTextPhoneNumberBuilder {
-        let mut inner = RichTextPhoneNumber::default();
-        inner.td_name = "richTextPhoneNumber".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDRichTextPhoneNumberBuilder { inner }
+        RTDRichTextPhoneNumberBuilder {
+            inner: RichTextPhoneNumber::default(),
+            missing: vec!["text", "phone_number"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDRichTextPhoneNumberBuilder {
+        RTDRichTextPhoneNumberBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDRichTextPhoneNumberBuilder {
+        RTDRichTextPhoneNumberBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn text(&self) -> &Box<RichText> {
@@ -750,25 +1180,58 @@ impl RichTextPhoneNumber {
     pub fn phone_number(&self) -> &String {
         &self.phone_number
     }
+
+    pub fn into_text(self) -> RichText {
+        *self.text
+    }
+
+    pub fn into_phone_number(self) -> String {
+        self.phone_number
+    }
+
+    pub fn with_text<T: AsRef<RichText>>(mut self, text: T) -> Self {
+        *self.text = text.as_ref().clone();
+        self
+    }
+
+    pub fn with_phone_number<T: AsRef<str>>(mut self, phone_number: T) -> Self {
+        self.phone_number = phone_number.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDRichTextPhoneNumberBuilder {
     inner: RichTextPhoneNumber,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDRichTextPhoneNumberBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> RichTextPhoneNumber {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<RichTextPhoneNumber> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "RichTextPhoneNumber",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
-        self.inner.text = text.as_ref().clone();
+    pub fn text<T: AsRef<RichText>>(&mut self, text: T) -> &mut Self {
+        *self.inner.text = text.as_ref().clone();
+        self.missing.retain(|f| *f != "text");
         self
     }
 
     pub fn phone_number<T: AsRef<str>>(&mut self, phone_number: T) -> &mut Self {
         self.inner.phone_number = phone_number.as_ref().to_string();
+        self.missing.retain(|f| *f != "phone_number");
         self
     }
 }
@@ -787,12 +1250,13 @@ impl AsRef<RichTextPhoneNumber> for RTDRichTextPhoneNumberBuilder {
 
 /// A plain text
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "richTextPlain")]
 pub struct RichTextPlain {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Text
     text: Box<RichText>,
@@ -812,6 +1276,20 @@ impl RObject for RichTextPlain {
     }
 }
 
+impl PartialEq for RichTextPlain {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text
+    }
+}
+
+impl Eq for RichTextPlain {}
+
+impl std::hash::Hash for RichTextPlain {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.text, state);
+    }
+}
+
 impl TDRichText for RichTextPlain {}
 
 impl RichTextPlain {
@@ -819,29 +1297,66 @@ impl RichTextPlain {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDRichTextPlainBuilder {
-        let mut inner = RichTextPlain::default();
-        inner.td_name = "richTextPlain".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDRichTextPlainBuilder { inner }
+        RTDRichTextPlainBuilder {
+            inner: RichTextPlain::default(),
+            missing: vec!["text"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDRichTextPlainBuilder {
+        RTDRichTextPlainBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDRichTextPlainBuilder {
+        RTDRichTextPlainBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn text(&self) -> &Box<RichText> {
         &self.text
     }
+
+    pub fn into_text(self) -> RichText {
+        *self.text
+    }
+
+    pub fn with_text<T: AsRef<RichText>>(mut self, text: T) -> Self {
+        *self.text = text.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDRichTextPlainBuilder {
     inner: RichTextPlain,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDRichTextPlainBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> RichTextPlain {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<RichTextPlain> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "RichTextPlain",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
-        self.inner.text = text.as_ref().clone();
+    pub fn text<T: AsRef<RichText>>(&mut self, text: T) -> &mut Self {
+        *self.inner.text = text.as_ref().clone();
+        self.missing.retain(|f| *f != "text");
         self
     }
 }
@@ -860,12 +1375,13 @@ impl AsRef<RichTextPlain> for RTDRichTextPlainBuilder {
 
 /// A strikethrough rich text
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "richTextStrikethrough")]
 pub struct RichTextStrikethrough {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Text
     text: Box<RichText>,
@@ -885,6 +1401,20 @@ impl RObject for RichTextStrikethrough {
     }
 }
 
+impl PartialEq for RichTextStrikethrough {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text
+    }
+}
+
+impl Eq for RichTextStrikethrough {}
+
+impl std::hash::Hash for RichTextStrikethrough {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.text, state);
+    }
+}
+
 impl TDRichText for RichTextStrikethrough {}
 
 impl RichTextStrikethrough {
@@ -892,29 +1422,66 @@ impl RichTextStrikethrough {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDRichTextStrikethroughBuilder {
-        let mut inner = RichTextStrikethrough::default();
-        inner.td_name = "richTextStrikethrough".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDRichTextStrikethroughBuilder { inner }
+        RTDRichTextStrikethroughBuilder {
+            inner: RichTextStrikethrough::default(),
+            missing: vec!["text"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDRichTextStrikethroughBuilder {
+        RTDRichTextStrikethroughBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDRichTextStrikethroughBuilder {
+        RTDRichTextStrikethroughBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn text(&self) -> &Box<RichText> {
         &self.text
     }
+
+    pub fn into_text(self) -> RichText {
+        *self.text
+    }
+
+    pub fn with_text<T: AsRef<RichText>>(mut self, text: T) -> Self {
+        *self.text = text.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDRichTextStrikethroughBuilder {
     inner: RichTextStrikethrough,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDRichTextStrikethroughBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> RichTextStrikethrough {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<RichTextStrikethrough> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "RichTextStrikethrough",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
-        self.inner.text = text.as_ref().clone();
+    pub fn text<T: AsRef<RichText>>(&mut self, text: T) -> &mut Self {
+        *self.inner.text = text.as_ref().clone();
+        self.missing.retain(|f| *f != "text");
         self
     }
 }
@@ -933,12 +1500,13 @@ impl AsRef<RichTextStrikethrough> for RTDRichTextStrikethroughBuilder {
 
 /// A subscript rich text
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "richTextSubscript")]
 pub struct RichTextSubscript {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Text
     text: Box<RichText>,
@@ -958,6 +1526,20 @@ impl RObject for RichTextSubscript {
     }
 }
 
+impl PartialEq for RichTextSubscript {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text
+    }
+}
+
+impl Eq for RichTextSubscript {}
+
+impl std::hash::Hash for RichTextSubscript {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.text, state);
+    }
+}
+
 impl TDRichText for RichTextSubscript {}
 
 impl RichTextSubscript {
@@ -965,29 +1547,66 @@ impl RichTextSubscript {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDRichTextSubscriptBuilder {
-        let mut inner = RichTextSubscript::default();
-        inner.td_name = "richTextSubscript".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDRichTextSubscriptBuilder { inner }
+        RTDRichTextSubscriptBuilder {
+            inner: RichTextSubscript::default(),
+            missing: vec!["text"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDRichTextSubscriptBuilder {
+        RTDRichTextSubscriptBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDRichTextSubscriptBuilder {
+        RTDRichTextSubscriptBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn text(&self) -> &Box<RichText> {
         &self.text
     }
+
+    pub fn into_text(self) -> RichText {
+        *self.text
+    }
+
+    pub fn with_text<T: AsRef<RichText>>(mut self, text: T) -> Self {
+        *self.text = text.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDRichTextSubscriptBuilder {
     inner: RichTextSubscript,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDRichTextSubscriptBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> RichTextSubscript {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<RichTextSubscript> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "RichTextSubscript",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
-        self.inner.text = text.as_ref().clone();
+    pub fn text<T: AsRef<RichText>>(&mut self, text: T) -> &mut Self {
+        *self.inner.text = text.as_ref().clone();
+        self.missing.retain(|f| *f != "text");
         self
     }
 }
@@ -1006,12 +1625,13 @@ impl AsRef<RichTextSubscript> for RTDRichTextSubscriptBuilder {
 
 /// A superscript rich text
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "richTextSuperscript")]
 pub struct RichTextSuperscript {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Text
     text: Box<RichText>,
@@ -1031,6 +1651,20 @@ impl RObject for RichTextSuperscript {
     }
 }
 
+impl PartialEq for RichTextSuperscript {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text
+    }
+}
+
+impl Eq for RichTextSuperscript {}
+
+impl std::hash::Hash for RichTextSuperscript {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.text, state);
+    }
+}
+
 impl TDRichText for RichTextSuperscript {}
 
 impl RichTextSuperscript {
@@ -1038,29 +1672,66 @@ impl RichTextSuperscript {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDRichTextSuperscriptBuilder {
-        let mut inner = RichTextSuperscript::default();
-        inner.td_name = "richTextSuperscript".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDRichTextSuperscriptBuilder { inner }
+        RTDRichTextSuperscriptBuilder {
+            inner: RichTextSuperscript::default(),
+            missing: vec!["text"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDRichTextSuperscriptBuilder {
+        RTDRichTextSuperscriptBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDRichTextSuperscriptBuilder {
+        RTDRichTextSuperscriptBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn text(&self) -> &Box<RichText> {
         &self.text
     }
+
+    pub fn into_text(self) -> RichText {
+        *self.text
+    }
+
+    pub fn with_text<T: AsRef<RichText>>(mut self, text: T) -> Self {
+        *self.text = text.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDRichTextSuperscriptBuilder {
     inner: RichTextSuperscript,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDRichTextSuperscriptBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> RichTextSuperscript {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<RichTextSuperscript> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "RichTextSuperscript",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
-        self.inner.text = text.as_ref().clone();
+    pub fn text<T: AsRef<RichText>>(&mut self, text: T) -> &mut Self {
+        *self.inner.text = text.as_ref().clone();
+        self.missing.retain(|f| *f != "text");
         self
     }
 }
@@ -1079,12 +1750,13 @@ impl AsRef<RichTextSuperscript> for RTDRichTextSuperscriptBuilder {
 
 /// An underlined rich text
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "richTextUnderline")]
 pub struct RichTextUnderline {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Text
     text: Box<RichText>,
@@ -1104,6 +1776,20 @@ impl RObject for RichTextUnderline {
     }
 }
 
+impl PartialEq for RichTextUnderline {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text
+    }
+}
+
+impl Eq for RichTextUnderline {}
+
+impl std::hash::Hash for RichTextUnderline {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.text, state);
+    }
+}
+
 impl TDRichText for RichTextUnderline {}
 
 impl RichTextUnderline {
@@ -1111,29 +1797,66 @@ impl RichTextUnderline {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDRichTextUnderlineBuilder {
-        let mut inner = RichTextUnderline::default();
-        inner.td_name = "richTextUnderline".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDRichTextUnderlineBuilder { inner }
+        RTDRichTextUnderlineBuilder {
+            inner: RichTextUnderline::default(),
+            missing: vec!["text"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDRichTextUnderlineBuilder {
+        RTDRichTextUnderlineBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDRichTextUnderlineBuilder {
+        RTDRichTextUnderlineBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn text(&self) -> &Box<RichText> {
         &self.text
     }
+
+    pub fn into_text(self) -> RichText {
+        *self.text
+    }
+
+    pub fn with_text<T: AsRef<RichText>>(mut self, text: T) -> Self {
+        *self.text = text.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDRichTextUnderlineBuilder {
     inner: RichTextUnderline,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDRichTextUnderlineBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> RichTextUnderline {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<RichTextUnderline> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "RichTextUnderline",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
-        self.inner.text = text.as_ref().clone();
+    pub fn text<T: AsRef<RichText>>(&mut self, text: T) -> &mut Self {
+        *self.inner.text = text.as_ref().clone();
+        self.missing.retain(|f| *f != "text");
         self
     }
 }
@@ -1152,12 +1875,13 @@ impl AsRef<RichTextUnderline> for RTDRichTextUnderlineBuilder {
 
 /// A rich text URL link
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "richTextUrl")]
 pub struct RichTextUrl {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Text
     text: Box<RichText>,
@@ -1181,6 +1905,22 @@ impl RObject for RichTextUrl {
     }
 }
 
+impl PartialEq for RichTextUrl {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text && self.url == other.url && self.is_cached == other.is_cached
+    }
+}
+
+impl Eq for RichTextUrl {}
+
+impl std::hash::Hash for RichTextUrl {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.text, state);
+        std::hash::Hash::hash(&self.url, state);
+        std::hash::Hash::hash(&self.is_cached, state);
+    }
+}
+
 impl TDRichText for RichTextUrl {}
 
 impl RichTextUrl {
@@ -1188,10 +1928,24 @@ impl RichTextUrl {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDRichTextUrlBuilder {
-        let mut inner = RichTextUrl::default();
-        inner.td_name = "richTextUrl".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDRichTextUrlBuilder { inner }
+        RTDRichTextUrlBuilder {
+            inner: RichTextUrl::default(),
+            missing: vec!["text", "url"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDRichTextUrlBuilder {
+        RTDRichTextUrlBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDRichTextUrlBuilder {
+        RTDRichTextUrlBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn text(&self) -> &Box<RichText> {
@@ -1205,25 +1959,60 @@ impl RichTextUrl {
     pub fn is_cached(&self) -> bool {
         self.is_cached
     }
+
+    pub fn into_text(self) -> RichText {
+        *self.text
+    }
+
+    pub fn into_url(self) -> String {
+        self.url
+    }
+
+    pub fn with_text<T: AsRef<RichText>>(mut self, text: T) -> Self {
+        *self.text = text.as_ref().clone();
+        self
+    }
+
+    pub fn with_url<T: AsRef<str>>(mut self, url: T) -> Self {
+        self.url = url.as_ref().to_string();
+        self
+    }
+
+    pub fn with_is_cached(mut self, is_cached: bool) -> Self {
+        self.is_cached = is_cached;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDRichTextUrlBuilder {
     inner: RichTextUrl,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDRichTextUrlBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> RichTextUrl {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<RichTextUrl> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("RichTextUrl", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
-        self.inner.text = text.as_ref().clone();
+    pub fn text<T: AsRef<RichText>>(&mut self, text: T) -> &mut Self {
+        *self.inner.text = text.as_ref().clone();
+        self.missing.retain(|f| *f != "text");
         self
     }
 
     pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
         self.inner.url = url.as_ref().to_string();
+        self.missing.retain(|f| *f != "url");
         self
     }
 
@@ -1247,12 +2036,13 @@ impl AsRef<RichTextUrl> for RTDRichTextUrlBuilder {
 
 /// A concatenation of rich texts
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "richTexts")]
 pub struct RichTexts {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Texts
     texts: Vec<RichText>,
@@ -1272,6 +2062,20 @@ impl RObject for RichTexts {
     }
 }
 
+impl PartialEq for RichTexts {
+    fn eq(&self, other: &Self) -> bool {
+        self.texts == other.texts
+    }
+}
+
+impl Eq for RichTexts {}
+
+impl std::hash::Hash for RichTexts {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.texts, state);
+    }
+}
+
 impl TDRichText for RichTexts {}
 
 impl RichTexts {
@@ -1279,29 +2083,63 @@ impl RichTexts {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDRichTextsBuilder {
-        let mut inner = RichTexts::default();
-        inner.td_name = "richTexts".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDRichTextsBuilder { inner }
+        RTDRichTextsBuilder {
+            inner: RichTexts::default(),
+            missing: vec!["texts"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDRichTextsBuilder {
+        RTDRichTextsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDRichTextsBuilder {
+        RTDRichTextsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn texts(&self) -> &Vec<RichText> {
         &self.texts
     }
+
+    pub fn into_texts(self) -> Vec<RichText> {
+        self.texts
+    }
+
+    pub fn with_texts(mut self, texts: Vec<RichText>) -> Self {
+        self.texts = texts;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDRichTextsBuilder {
     inner: RichTexts,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDRichTextsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> RichTexts {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<RichTexts> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("RichTexts", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn texts(&mut self, texts: Vec<RichText>) -> &mut Self {
         self.inner.texts = texts;
+        self.missing.retain(|f| *f != "texts");
         self
     }
 }
@@ -1317,3 +2155,152 @@ impl AsRef<RichTexts> for RTDRichTextsBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(RichText::Anchor(
+            RichTextAnchor::builder()
+                .text(RichText::RichTexts(
+                    RichTexts::builder().texts(vec![]).build(),
+                ))
+                .name("text")
+                .build(),
+        ));
+        assert_round_trip(RichText::Bold(
+            RichTextBold::builder()
+                .text(RichText::RichTexts(
+                    RichTexts::builder().texts(vec![]).build(),
+                ))
+                .build(),
+        ));
+        assert_round_trip(RichText::EmailAddress(
+            RichTextEmailAddress::builder()
+                .text(RichText::RichTexts(
+                    RichTexts::builder().texts(vec![]).build(),
+                ))
+                .email_address("text")
+                .build(),
+        ));
+        assert_round_trip(RichText::Fixed(
+            RichTextFixed::builder()
+                .text(RichText::RichTexts(
+                    RichTexts::builder().texts(vec![]).build(),
+                ))
+                .build(),
+        ));
+        assert_round_trip(RichText::Icon(
+            RichTextIcon::builder()
+                .document(
+                    Document::builder()
+                        .file_name("text")
+                        .mime_type("text")
+                        .document(
+                            File::builder()
+                                .id(i32::MAX)
+                                .size(i32::MAX)
+                                .expected_size(i32::MAX)
+                                .local(
+                                    LocalFile::builder()
+                                        .path("text")
+                                        .can_be_downloaded(true)
+                                        .can_be_deleted(true)
+                                        .is_downloading_active(true)
+                                        .is_downloading_completed(true)
+                                        .download_offset(i32::MAX)
+                                        .downloaded_prefix_size(i32::MAX)
+                                        .downloaded_size(i32::MAX)
+                                        .build(),
+                                )
+                                .remote(
+                                    RemoteFile::builder()
+                                        .id("text")
+                                        .unique_id("text")
+                                        .is_uploading_active(true)
+                                        .is_uploading_completed(true)
+                                        .uploaded_size(i32::MAX)
+                                        .build(),
+                                )
+                                .build(),
+                        )
+                        .build(),
+                )
+                .width(i32::MAX)
+                .height(i32::MAX)
+                .build(),
+        ));
+        assert_round_trip(RichText::Italic(
+            RichTextItalic::builder()
+                .text(RichText::RichTexts(
+                    RichTexts::builder().texts(vec![]).build(),
+                ))
+                .build(),
+        ));
+        assert_round_trip(RichText::Marked(
+            RichTextMarked::builder()
+                .text(RichText::RichTexts(
+                    RichTexts::builder().texts(vec![]).build(),
+                ))
+                .build(),
+        ));
+        assert_round_trip(RichText::PhoneNumber(
+            RichTextPhoneNumber::builder()
+                .text(RichText::RichTexts(
+                    RichTexts::builder().texts(vec![]).build(),
+                ))
+                .phone_number("text")
+                .build(),
+        ));
+        assert_round_trip(RichText::Plain(
+            RichTextPlain::builder()
+                .text(RichText::RichTexts(
+                    RichTexts::builder().texts(vec![]).build(),
+                ))
+                .build(),
+        ));
+        assert_round_trip(RichText::Strikethrough(
+            RichTextStrikethrough::builder()
+                .text(RichText::RichTexts(
+                    RichTexts::builder().texts(vec![]).build(),
+                ))
+                .build(),
+        ));
+        assert_round_trip(RichText::Subscript(
+            RichTextSubscript::builder()
+                .text(RichText::RichTexts(
+                    RichTexts::builder().texts(vec![]).build(),
+                ))
+                .build(),
+        ));
+        assert_round_trip(RichText::Superscript(
+            RichTextSuperscript::builder()
+                .text(RichText::RichTexts(
+                    RichTexts::builder().texts(vec![]).build(),
+                ))
+                .build(),
+        ));
+        assert_round_trip(RichText::Underline(
+            RichTextUnderline::builder()
+                .text(RichText::RichTexts(
+                    RichTexts::builder().texts(vec![]).build(),
+                ))
+                .build(),
+        ));
+        assert_round_trip(RichText::Url(
+            RichTextUrl::builder()
+                .text(RichText::RichTexts(
+                    RichTexts::builder().texts(vec![]).build(),
+                ))
+                .url("text")
+                .is_cached(true)
+                .build(),
+        ));
+        assert_round_trip(RichText::RichTexts(
+            RichTexts::builder().texts(vec![]).build(),
+        ));
+    }
+}
@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains information about saved card credentials
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "savedCredentials")]
 pub struct SavedCredentials {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Unique identifier of the saved credentials
     id: String,
@@ -31,15 +31,44 @@ impl RObject for SavedCredentials {
     }
 }
 
+impl PartialEq for SavedCredentials {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id && self.title == other.title
+    }
+}
+
+impl Eq for SavedCredentials {}
+
+impl std::hash::Hash for SavedCredentials {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.id, state);
+        std::hash::Hash::hash(&self.title, state);
+    }
+}
+
 impl SavedCredentials {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDSavedCredentialsBuilder {
-        let mut inner = SavedCredentials::default();
-        inner.td_name = "savedCredentials".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDSavedCredentialsBuilder { inner }
+        RTDSavedCredentialsBuilder {
+            inner: SavedCredentials::default(),
+            missing: vec!["id", "title"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDSavedCredentialsBuilder {
+        RTDSavedCredentialsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDSavedCredentialsBuilder {
+        RTDSavedCredentialsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn id(&self) -> &String {
@@ -49,25 +78,58 @@ impl SavedCredentials {
     pub fn title(&self) -> &String {
         &self.title
     }
+
+    pub fn into_id(self) -> String {
+        self.id
+    }
+
+    pub fn into_title(self) -> String {
+        self.title
+    }
+
+    pub fn with_id<T: AsRef<str>>(mut self, id: T) -> Self {
+        self.id = id.as_ref().to_string();
+        self
+    }
+
+    pub fn with_title<T: AsRef<str>>(mut self, title: T) -> Self {
+        self.title = title.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDSavedCredentialsBuilder {
     inner: SavedCredentials,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDSavedCredentialsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> SavedCredentials {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<SavedCredentials> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "SavedCredentials",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
         self.inner.id = id.as_ref().to_string();
+        self.missing.retain(|f| *f != "id");
         self
     }
 
     pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
         self.inner.title = title.as_ref().to_string();
+        self.missing.retain(|f| *f != "title");
         self
     }
 }
@@ -83,3 +145,14 @@ impl AsRef<SavedCredentials> for RTDSavedCredentialsBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(SavedCredentials::builder().id("text").title("text").build());
+    }
+}
@@ -1,18 +1,18 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains information about notification settings for several chats
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "scopeNotificationSettings")]
 pub struct ScopeNotificationSettings {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Time left before notifications will be unmuted, in seconds
-    mute_for: i64,
+    mute_for: i32,
     /// The name of an audio file to be used for notification sounds; only applies to iOS applications
     sound: String,
     /// True, if message content should be displayed in notifications
@@ -37,18 +37,55 @@ impl RObject for ScopeNotificationSettings {
     }
 }
 
+impl PartialEq for ScopeNotificationSettings {
+    fn eq(&self, other: &Self) -> bool {
+        self.mute_for == other.mute_for
+            && self.sound == other.sound
+            && self.show_preview == other.show_preview
+            && self.disable_pinned_message_notifications
+                == other.disable_pinned_message_notifications
+            && self.disable_mention_notifications == other.disable_mention_notifications
+    }
+}
+
+impl Eq for ScopeNotificationSettings {}
+
+impl std::hash::Hash for ScopeNotificationSettings {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.mute_for, state);
+        std::hash::Hash::hash(&self.sound, state);
+        std::hash::Hash::hash(&self.show_preview, state);
+        std::hash::Hash::hash(&self.disable_pinned_message_notifications, state);
+        std::hash::Hash::hash(&self.disable_mention_notifications, state);
+    }
+}
+
 impl ScopeNotificationSettings {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDScopeNotificationSettingsBuilder {
-        let mut inner = ScopeNotificationSettings::default();
-        inner.td_name = "scopeNotificationSettings".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDScopeNotificationSettingsBuilder { inner }
-    }
-
-    pub fn mute_for(&self) -> i64 {
+        RTDScopeNotificationSettingsBuilder {
+            inner: ScopeNotificationSettings::default(),
+            missing: vec!["mute_for", "sound"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDScopeNotificationSettingsBuilder {
+        RTDScopeNotificationSettingsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDScopeNotificationSettingsBuilder {
+        RTDScopeNotificationSettingsBuilder {
+            inner: self,
+            missing: vec![],
+        }
+    }
+
+    pub fn mute_for(&self) -> i32 {
         self.mute_for
     }
 
@@ -67,25 +104,75 @@ impl ScopeNotificationSettings {
     pub fn disable_mention_notifications(&self) -> bool {
         self.disable_mention_notifications
     }
+
+    pub fn into_sound(self) -> String {
+        self.sound
+    }
+
+    pub fn with_mute_for(mut self, mute_for: i32) -> Self {
+        self.mute_for = mute_for;
+        self
+    }
+
+    pub fn with_sound<T: AsRef<str>>(mut self, sound: T) -> Self {
+        self.sound = sound.as_ref().to_string();
+        self
+    }
+
+    pub fn with_show_preview(mut self, show_preview: bool) -> Self {
+        self.show_preview = show_preview;
+        self
+    }
+
+    pub fn with_disable_pinned_message_notifications(
+        mut self,
+        disable_pinned_message_notifications: bool,
+    ) -> Self {
+        self.disable_pinned_message_notifications = disable_pinned_message_notifications;
+        self
+    }
+
+    pub fn with_disable_mention_notifications(
+        mut self,
+        disable_mention_notifications: bool,
+    ) -> Self {
+        self.disable_mention_notifications = disable_mention_notifications;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDScopeNotificationSettingsBuilder {
     inner: ScopeNotificationSettings,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDScopeNotificationSettingsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ScopeNotificationSettings {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ScopeNotificationSettings> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ScopeNotificationSettings",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn mute_for(&mut self, mute_for: i64) -> &mut Self {
+    pub fn mute_for(&mut self, mute_for: i32) -> &mut Self {
         self.inner.mute_for = mute_for;
+        self.missing.retain(|f| *f != "mute_for");
         self
     }
 
     pub fn sound<T: AsRef<str>>(&mut self, sound: T) -> &mut Self {
         self.inner.sound = sound.as_ref().to_string();
+        self.missing.retain(|f| *f != "sound");
         self
     }
 
@@ -122,3 +209,22 @@ impl AsRef<ScopeNotificationSettings> for RTDScopeNotificationSettingsBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            ScopeNotificationSettings::builder()
+                .mute_for(i32::MAX)
+                .sound("text")
+                .show_preview(true)
+                .disable_pinned_message_notifications(true)
+                .disable_mention_notifications(true)
+                .build(),
+        );
+    }
+}
@@ -1,85 +1,96 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Represents a filter for message search results
 pub trait TDSearchMessagesFilter: Debug + RObject {}
 
 /// Represents a filter for message search results
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum SearchMessagesFilter {
-    #[doc(hidden)]
-    _Default(()),
     /// Returns only animation messages
+    #[serde(rename = "searchMessagesFilterAnimation")]
     Animation(SearchMessagesFilterAnimation),
     /// Returns only audio messages
+    #[serde(rename = "searchMessagesFilterAudio")]
     Audio(SearchMessagesFilterAudio),
     /// Returns only call messages
+    #[serde(rename = "searchMessagesFilterCall")]
     Call(SearchMessagesFilterCall),
     /// Returns only messages containing chat photos
+    #[serde(rename = "searchMessagesFilterChatPhoto")]
     ChatPhoto(SearchMessagesFilterChatPhoto),
     /// Returns only document messages
+    #[serde(rename = "searchMessagesFilterDocument")]
     Document(SearchMessagesFilterDocument),
     /// Returns all found messages, no filter is applied
+    #[serde(rename = "searchMessagesFilterEmpty")]
     Empty(SearchMessagesFilterEmpty),
     /// Returns only messages with mentions of the current user, or messages that are replies to their messages
+    #[serde(rename = "searchMessagesFilterMention")]
     Mention(SearchMessagesFilterMention),
     /// Returns only incoming call messages with missed/declined discard reasons
+    #[serde(rename = "searchMessagesFilterMissedCall")]
     MissedCall(SearchMessagesFilterMissedCall),
     /// Returns only photo messages
+    #[serde(rename = "searchMessagesFilterPhoto")]
     Photo(SearchMessagesFilterPhoto),
     /// Returns only photo and video messages
+    #[serde(rename = "searchMessagesFilterPhotoAndVideo")]
     PhotoAndVideo(SearchMessagesFilterPhotoAndVideo),
     /// Returns only messages with unread mentions of the current user, or messages that are replies to their messages. When using this filter the results can't be additionally filtered by a query or by the sending user
+    #[serde(rename = "searchMessagesFilterUnreadMention")]
     UnreadMention(SearchMessagesFilterUnreadMention),
     /// Returns only messages containing URLs
+    #[serde(rename = "searchMessagesFilterUrl")]
     Url(SearchMessagesFilterUrl),
     /// Returns only video messages
+    #[serde(rename = "searchMessagesFilterVideo")]
     Video(SearchMessagesFilterVideo),
     /// Returns only video note messages
+    #[serde(rename = "searchMessagesFilterVideoNote")]
     VideoNote(SearchMessagesFilterVideoNote),
     /// Returns only voice and video note messages
+    #[serde(rename = "searchMessagesFilterVoiceAndVideoNote")]
     VoiceAndVideoNote(SearchMessagesFilterVoiceAndVideoNote),
     /// Returns only voice note messages
+    #[serde(rename = "searchMessagesFilterVoiceNote")]
     VoiceNote(SearchMessagesFilterVoiceNote),
 }
 
+/// Default value is default of [SearchMessagesFilterAnimation], builders still require the field to be set
 impl Default for SearchMessagesFilter {
     fn default() -> Self {
-        SearchMessagesFilter::_Default(())
+        SearchMessagesFilter::Animation(SearchMessagesFilterAnimation::default())
     }
 }
 
-impl<'de> Deserialize<'de> for SearchMessagesFilter {
-    fn deserialize<D>(deserializer: D) -> Result<SearchMessagesFilter, D::Error>
+impl Serialize for SearchMessagesFilter {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          SearchMessagesFilter,
-          (searchMessagesFilterAnimation, Animation);
-          (searchMessagesFilterAudio, Audio);
-          (searchMessagesFilterCall, Call);
-          (searchMessagesFilterChatPhoto, ChatPhoto);
-          (searchMessagesFilterDocument, Document);
-          (searchMessagesFilterEmpty, Empty);
-          (searchMessagesFilterMention, Mention);
-          (searchMessagesFilterMissedCall, MissedCall);
-          (searchMessagesFilterPhoto, Photo);
-          (searchMessagesFilterPhotoAndVideo, PhotoAndVideo);
-          (searchMessagesFilterUnreadMention, UnreadMention);
-          (searchMessagesFilterUrl, Url);
-          (searchMessagesFilterVideo, Video);
-          (searchMessagesFilterVideoNote, VideoNote);
-          (searchMessagesFilterVoiceAndVideoNote, VoiceAndVideoNote);
-          (searchMessagesFilterVoiceNote, VoiceNote);
-
-        )(deserializer)
+        match self {
+            SearchMessagesFilter::Animation(t) => t.serialize(serializer),
+            SearchMessagesFilter::Audio(t) => t.serialize(serializer),
+            SearchMessagesFilter::Call(t) => t.serialize(serializer),
+            SearchMessagesFilter::ChatPhoto(t) => t.serialize(serializer),
+            SearchMessagesFilter::Document(t) => t.serialize(serializer),
+            SearchMessagesFilter::Empty(t) => t.serialize(serializer),
+            SearchMessagesFilter::Mention(t) => t.serialize(serializer),
+            SearchMessagesFilter::MissedCall(t) => t.serialize(serializer),
+            SearchMessagesFilter::Photo(t) => t.serialize(serializer),
+            SearchMessagesFilter::PhotoAndVideo(t) => t.serialize(serializer),
+            SearchMessagesFilter::UnreadMention(t) => t.serialize(serializer),
+            SearchMessagesFilter::Url(t) => t.serialize(serializer),
+            SearchMessagesFilter::Video(t) => t.serialize(serializer),
+            SearchMessagesFilter::VideoNote(t) => t.serialize(serializer),
+            SearchMessagesFilter::VoiceAndVideoNote(t) => t.serialize(serializer),
+            SearchMessagesFilter::VoiceNote(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -103,8 +114,6 @@ impl RObject for SearchMessagesFilter {
             SearchMessagesFilter::VideoNote(t) => t.td_name(),
             SearchMessagesFilter::VoiceAndVideoNote(t) => t.td_name(),
             SearchMessagesFilter::VoiceNote(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -126,8 +135,6 @@ impl RObject for SearchMessagesFilter {
             SearchMessagesFilter::VideoNote(t) => t.extra(),
             SearchMessagesFilter::VoiceAndVideoNote(t) => t.extra(),
             SearchMessagesFilter::VoiceNote(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -139,10 +146,6 @@ impl SearchMessagesFilter {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, SearchMessagesFilter::_Default(_))
-    }
 }
 
 impl AsRef<SearchMessagesFilter> for SearchMessagesFilter {
@@ -153,12 +156,13 @@ impl AsRef<SearchMessagesFilter> for SearchMessagesFilter {
 
 /// Returns only animation messages
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "searchMessagesFilterAnimation")]
 pub struct SearchMessagesFilterAnimation {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -176,6 +180,18 @@ impl RObject for SearchMessagesFilterAnimation {
     }
 }
 
+impl PartialEq for SearchMessagesFilterAnimation {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for SearchMessagesFilterAnimation {}
+
+impl std::hash::Hash for SearchMessagesFilterAnimation {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDSearchMessagesFilter for SearchMessagesFilterAnimation {}
 
 impl SearchMessagesFilterAnimation {
@@ -183,22 +199,49 @@ impl SearchMessagesFilterAnimation {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDSearchMessagesFilterAnimationBuilder {
-        let mut inner = SearchMessagesFilterAnimation::default();
-        inner.td_name = "searchMessagesFilterAnimation".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDSearchMessagesFilterAnimationBuilder { inner }
+        RTDSearchMessagesFilterAnimationBuilder {
+            inner: SearchMessagesFilterAnimation::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDSearchMessagesFilterAnimationBuilder {
+        RTDSearchMessagesFilterAnimationBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDSearchMessagesFilterAnimationBuilder {
+        RTDSearchMessagesFilterAnimationBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDSearchMessagesFilterAnimationBuilder {
     inner: SearchMessagesFilterAnimation,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDSearchMessagesFilterAnimationBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> SearchMessagesFilterAnimation {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<SearchMessagesFilterAnimation> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "SearchMessagesFilterAnimation",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<SearchMessagesFilterAnimation> for SearchMessagesFilterAnimation {
@@ -215,12 +258,13 @@ impl AsRef<SearchMessagesFilterAnimation> for RTDSearchMessagesFilterAnimationBu
 
 /// Returns only audio messages
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "searchMessagesFilterAudio")]
 pub struct SearchMessagesFilterAudio {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -238,6 +282,18 @@ impl RObject for SearchMessagesFilterAudio {
     }
 }
 
+impl PartialEq for SearchMessagesFilterAudio {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for SearchMessagesFilterAudio {}
+
+impl std::hash::Hash for SearchMessagesFilterAudio {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDSearchMessagesFilter for SearchMessagesFilterAudio {}
 
 impl SearchMessagesFilterAudio {
@@ -245,22 +301,49 @@ impl SearchMessagesFilterAudio {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDSearchMessagesFilterAudioBuilder {
-        let mut inner = SearchMessagesFilterAudio::default();
-        inner.td_name = "searchMessagesFilterAudio".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDSearchMessagesFilterAudioBuilder { inner }
+        RTDSearchMessagesFilterAudioBuilder {
+            inner: SearchMessagesFilterAudio::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDSearchMessagesFilterAudioBuilder {
+        RTDSearchMessagesFilterAudioBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDSearchMessagesFilterAudioBuilder {
+        RTDSearchMessagesFilterAudioBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDSearchMessagesFilterAudioBuilder {
     inner: SearchMessagesFilterAudio,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDSearchMessagesFilterAudioBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> SearchMessagesFilterAudio {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<SearchMessagesFilterAudio> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "SearchMessagesFilterAudio",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<SearchMessagesFilterAudio> for SearchMessagesFilterAudio {
@@ -277,12 +360,13 @@ impl AsRef<SearchMessagesFilterAudio> for RTDSearchMessagesFilterAudioBuilder {
 
 /// Returns only call messages
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "searchMessagesFilterCall")]
 pub struct SearchMessagesFilterCall {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -300,6 +384,18 @@ impl RObject for SearchMessagesFilterCall {
     }
 }
 
+impl PartialEq for SearchMessagesFilterCall {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for SearchMessagesFilterCall {}
+
+impl std::hash::Hash for SearchMessagesFilterCall {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDSearchMessagesFilter for SearchMessagesFilterCall {}
 
 impl SearchMessagesFilterCall {
@@ -307,22 +403,49 @@ impl SearchMessagesFilterCall {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDSearchMessagesFilterCallBuilder {
-        let mut inner = SearchMessagesFilterCall::default();
-        inner.td_name = "searchMessagesFilterCall".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDSearchMessagesFilterCallBuilder { inner }
+        RTDSearchMessagesFilterCallBuilder {
+            inner: SearchMessagesFilterCall::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDSearchMessagesFilterCallBuilder {
+        RTDSearchMessagesFilterCallBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDSearchMessagesFilterCallBuilder {
+        RTDSearchMessagesFilterCallBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDSearchMessagesFilterCallBuilder {
     inner: SearchMessagesFilterCall,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDSearchMessagesFilterCallBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> SearchMessagesFilterCall {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<SearchMessagesFilterCall> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "SearchMessagesFilterCall",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<SearchMessagesFilterCall> for SearchMessagesFilterCall {
@@ -339,12 +462,13 @@ impl AsRef<SearchMessagesFilterCall> for RTDSearchMessagesFilterCallBuilder {
 
 /// Returns only messages containing chat photos
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "searchMessagesFilterChatPhoto")]
 pub struct SearchMessagesFilterChatPhoto {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -362,6 +486,18 @@ impl RObject for SearchMessagesFilterChatPhoto {
     }
 }
 
+impl PartialEq for SearchMessagesFilterChatPhoto {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for SearchMessagesFilterChatPhoto {}
+
+impl std::hash::Hash for SearchMessagesFilterChatPhoto {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDSearchMessagesFilter for SearchMessagesFilterChatPhoto {}
 
 impl SearchMessagesFilterChatPhoto {
@@ -369,22 +505,49 @@ impl SearchMessagesFilterChatPhoto {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDSearchMessagesFilterChatPhotoBuilder {
-        let mut inner = SearchMessagesFilterChatPhoto::default();
-        inner.td_name = "searchMessagesFilterChatPhoto".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDSearchMessagesFilterChatPhotoBuilder { inner }
+        RTDSearchMessagesFilterChatPhotoBuilder {
+            inner: SearchMessagesFilterChatPhoto::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDSearchMessagesFilterChatPhotoBuilder {
+        RTDSearchMessagesFilterChatPhotoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDSearchMessagesFilterChatPhotoBuilder {
+        RTDSearchMessagesFilterChatPhotoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDSearchMessagesFilterChatPhotoBuilder {
     inner: SearchMessagesFilterChatPhoto,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDSearchMessagesFilterChatPhotoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> SearchMessagesFilterChatPhoto {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<SearchMessagesFilterChatPhoto> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "SearchMessagesFilterChatPhoto",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<SearchMessagesFilterChatPhoto> for SearchMessagesFilterChatPhoto {
@@ -401,12 +564,13 @@ impl AsRef<SearchMessagesFilterChatPhoto> for RTDSearchMessagesFilterChatPhotoBu
 
 /// Returns only document messages
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "searchMessagesFilterDocument")]
 pub struct SearchMessagesFilterDocument {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -424,6 +588,18 @@ impl RObject for SearchMessagesFilterDocument {
     }
 }
 
+impl PartialEq for SearchMessagesFilterDocument {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for SearchMessagesFilterDocument {}
+
+impl std::hash::Hash for SearchMessagesFilterDocument {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDSearchMessagesFilter for SearchMessagesFilterDocument {}
 
 impl SearchMessagesFilterDocument {
@@ -431,22 +607,49 @@ impl SearchMessagesFilterDocument {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDSearchMessagesFilterDocumentBuilder {
-        let mut inner = SearchMessagesFilterDocument::default();
-        inner.td_name = "searchMessagesFilterDocument".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDSearchMessagesFilterDocumentBuilder { inner }
+        RTDSearchMessagesFilterDocumentBuilder {
+            inner: SearchMessagesFilterDocument::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDSearchMessagesFilterDocumentBuilder {
+        RTDSearchMessagesFilterDocumentBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDSearchMessagesFilterDocumentBuilder {
+        RTDSearchMessagesFilterDocumentBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDSearchMessagesFilterDocumentBuilder {
     inner: SearchMessagesFilterDocument,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDSearchMessagesFilterDocumentBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> SearchMessagesFilterDocument {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<SearchMessagesFilterDocument> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "SearchMessagesFilterDocument",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<SearchMessagesFilterDocument> for SearchMessagesFilterDocument {
@@ -463,12 +666,13 @@ impl AsRef<SearchMessagesFilterDocument> for RTDSearchMessagesFilterDocumentBuil
 
 /// Returns all found messages, no filter is applied
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "searchMessagesFilterEmpty")]
 pub struct SearchMessagesFilterEmpty {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -486,6 +690,18 @@ impl RObject for SearchMessagesFilterEmpty {
     }
 }
 
+impl PartialEq for SearchMessagesFilterEmpty {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for SearchMessagesFilterEmpty {}
+
+impl std::hash::Hash for SearchMessagesFilterEmpty {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDSearchMessagesFilter for SearchMessagesFilterEmpty {}
 
 impl SearchMessagesFilterEmpty {
@@ -493,22 +709,49 @@ impl SearchMessagesFilterEmpty {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDSearchMessagesFilterEmptyBuilder {
-        let mut inner = SearchMessagesFilterEmpty::default();
-        inner.td_name = "searchMessagesFilterEmpty".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDSearchMessagesFilterEmptyBuilder { inner }
+        RTDSearchMessagesFilterEmptyBuilder {
+            inner: SearchMessagesFilterEmpty::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDSearchMessagesFilterEmptyBuilder {
+        RTDSearchMessagesFilterEmptyBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDSearchMessagesFilterEmptyBuilder {
+        RTDSearchMessagesFilterEmptyBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDSearchMessagesFilterEmptyBuilder {
     inner: SearchMessagesFilterEmpty,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDSearchMessagesFilterEmptyBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> SearchMessagesFilterEmpty {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<SearchMessagesFilterEmpty> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "SearchMessagesFilterEmpty",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<SearchMessagesFilterEmpty> for SearchMessagesFilterEmpty {
@@ -525,12 +768,13 @@ impl AsRef<SearchMessagesFilterEmpty> for RTDSearchMessagesFilterEmptyBuilder {
 
 /// Returns only messages with mentions of the current user, or messages that are replies to their messages
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "searchMessagesFilterMention")]
 pub struct SearchMessagesFilterMention {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -548,6 +792,18 @@ impl RObject for SearchMessagesFilterMention {
     }
 }
 
+impl PartialEq for SearchMessagesFilterMention {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for SearchMessagesFilterMention {}
+
+impl std::hash::Hash for SearchMessagesFilterMention {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDSearchMessagesFilter for SearchMessagesFilterMention {}
 
 impl SearchMessagesFilterMention {
@@ -555,22 +811,49 @@ impl SearchMessagesFilterMention {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDSearchMessagesFilterMentionBuilder {
-        let mut inner = SearchMessagesFilterMention::default();
-        inner.td_name = "searchMessagesFilterMention".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDSearchMessagesFilterMentionBuilder { inner }
+        RTDSearchMessagesFilterMentionBuilder {
+            inner: SearchMessagesFilterMention::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDSearchMessagesFilterMentionBuilder {
+        RTDSearchMessagesFilterMentionBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDSearchMessagesFilterMentionBuilder {
+        RTDSearchMessagesFilterMentionBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDSearchMessagesFilterMentionBuilder {
     inner: SearchMessagesFilterMention,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDSearchMessagesFilterMentionBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> SearchMessagesFilterMention {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<SearchMessagesFilterMention> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "SearchMessagesFilterMention",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<SearchMessagesFilterMention> for SearchMessagesFilterMention {
@@ -587,12 +870,13 @@ impl AsRef<SearchMessagesFilterMention> for RTDSearchMessagesFilterMentionBuilde
 
 /// Returns only incoming call messages with missed/declined discard reasons
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "searchMessagesFilterMissedCall")]
 pub struct SearchMessagesFilterMissedCall {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -610,6 +894,18 @@ impl RObject for SearchMessagesFilterMissedCall {
     }
 }
 
+impl PartialEq for SearchMessagesFilterMissedCall {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for SearchMessagesFilterMissedCall {}
+
+impl std::hash::Hash for SearchMessagesFilterMissedCall {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDSearchMessagesFilter for SearchMessagesFilterMissedCall {}
 
 impl SearchMessagesFilterMissedCall {
@@ -617,22 +913,49 @@ impl SearchMessagesFilterMissedCall {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDSearchMessagesFilterMissedCallBuilder {
-        let mut inner = SearchMessagesFilterMissedCall::default();
-        inner.td_name = "searchMessagesFilterMissedCall".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDSearchMessagesFilterMissedCallBuilder { inner }
+        RTDSearchMessagesFilterMissedCallBuilder {
+            inner: SearchMessagesFilterMissedCall::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDSearchMessagesFilterMissedCallBuilder {
+        RTDSearchMessagesFilterMissedCallBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDSearchMessagesFilterMissedCallBuilder {
+        RTDSearchMessagesFilterMissedCallBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDSearchMessagesFilterMissedCallBuilder {
     inner: SearchMessagesFilterMissedCall,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDSearchMessagesFilterMissedCallBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> SearchMessagesFilterMissedCall {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<SearchMessagesFilterMissedCall> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "SearchMessagesFilterMissedCall",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<SearchMessagesFilterMissedCall> for SearchMessagesFilterMissedCall {
@@ -649,12 +972,13 @@ impl AsRef<SearchMessagesFilterMissedCall> for RTDSearchMessagesFilterMissedCall
 
 /// Returns only photo messages
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "searchMessagesFilterPhoto")]
 pub struct SearchMessagesFilterPhoto {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -672,6 +996,18 @@ impl RObject for SearchMessagesFilterPhoto {
     }
 }
 
+impl PartialEq for SearchMessagesFilterPhoto {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for SearchMessagesFilterPhoto {}
+
+impl std::hash::Hash for SearchMessagesFilterPhoto {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDSearchMessagesFilter for SearchMessagesFilterPhoto {}
 
 impl SearchMessagesFilterPhoto {
@@ -679,22 +1015,49 @@ impl SearchMessagesFilterPhoto {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDSearchMessagesFilterPhotoBuilder {
-        let mut inner = SearchMessagesFilterPhoto::default();
-        inner.td_name = "searchMessagesFilterPhoto".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDSearchMessagesFilterPhotoBuilder { inner }
+        RTDSearchMessagesFilterPhotoBuilder {
+            inner: SearchMessagesFilterPhoto::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDSearchMessagesFilterPhotoBuilder {
+        RTDSearchMessagesFilterPhotoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDSearchMessagesFilterPhotoBuilder {
+        RTDSearchMessagesFilterPhotoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDSearchMessagesFilterPhotoBuilder {
     inner: SearchMessagesFilterPhoto,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDSearchMessagesFilterPhotoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> SearchMessagesFilterPhoto {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<SearchMessagesFilterPhoto> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "SearchMessagesFilterPhoto",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<SearchMessagesFilterPhoto> for SearchMessagesFilterPhoto {
@@ -711,12 +1074,13 @@ impl AsRef<SearchMessagesFilterPhoto> for RTDSearchMessagesFilterPhotoBuilder {
 
 /// Returns only photo and video messages
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "searchMessagesFilterPhotoAndVideo")]
 pub struct SearchMessagesFilterPhotoAndVideo {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -734,6 +1098,18 @@ impl RObject for SearchMessagesFilterPhotoAndVideo {
     }
 }
 
+impl PartialEq for SearchMessagesFilterPhotoAndVideo {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for SearchMessagesFilterPhotoAndVideo {}
+
+impl std::hash::Hash for SearchMessagesFilterPhotoAndVideo {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDSearchMessagesFilter for SearchMessagesFilterPhotoAndVideo {}
 
 impl SearchMessagesFilterPhotoAndVideo {
@@ -741,22 +1117,49 @@ impl SearchMessagesFilterPhotoAndVideo {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDSearchMessagesFilterPhotoAndVideoBuilder {
-        let mut inner = SearchMessagesFilterPhotoAndVideo::default();
-        inner.td_name = "searchMessagesFilterPhotoAndVideo".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDSearchMessagesFilterPhotoAndVideoBuilder { inner }
+        RTDSearchMessagesFilterPhotoAndVideoBuilder {
+            inner: SearchMessagesFilterPhotoAndVideo::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDSearchMessagesFilterPhotoAndVideoBuilder {
+        RTDSearchMessagesFilterPhotoAndVideoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDSearchMessagesFilterPhotoAndVideoBuilder {
+        RTDSearchMessagesFilterPhotoAndVideoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDSearchMessagesFilterPhotoAndVideoBuilder {
     inner: SearchMessagesFilterPhotoAndVideo,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDSearchMessagesFilterPhotoAndVideoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> SearchMessagesFilterPhotoAndVideo {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<SearchMessagesFilterPhotoAndVideo> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "SearchMessagesFilterPhotoAndVideo",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<SearchMessagesFilterPhotoAndVideo> for SearchMessagesFilterPhotoAndVideo {
@@ -773,12 +1176,13 @@ impl AsRef<SearchMessagesFilterPhotoAndVideo> for RTDSearchMessagesFilterPhotoAn
 
 /// Returns only messages with unread mentions of the current user, or messages that are replies to their messages. When using this filter the results can't be additionally filtered by a query or by the sending user
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "searchMessagesFilterUnreadMention")]
 pub struct SearchMessagesFilterUnreadMention {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -796,6 +1200,18 @@ impl RObject for SearchMessagesFilterUnreadMention {
     }
 }
 
+impl PartialEq for SearchMessagesFilterUnreadMention {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for SearchMessagesFilterUnreadMention {}
+
+impl std::hash::Hash for SearchMessagesFilterUnreadMention {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDSearchMessagesFilter for SearchMessagesFilterUnreadMention {}
 
 impl SearchMessagesFilterUnreadMention {
@@ -803,22 +1219,49 @@ impl SearchMessagesFilterUnreadMention {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDSearchMessagesFilterUnreadMentionBuilder {
-        let mut inner = SearchMessagesFilterUnreadMention::default();
-        inner.td_name = "searchMessagesFilterUnreadMention".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDSearchMessagesFilterUnreadMentionBuilder { inner }
+        RTDSearchMessagesFilterUnreadMentionBuilder {
+            inner: SearchMessagesFilterUnreadMention::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDSearchMessagesFilterUnreadMentionBuilder {
+        RTDSearchMessagesFilterUnreadMentionBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDSearchMessagesFilterUnreadMentionBuilder {
+        RTDSearchMessagesFilterUnreadMentionBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDSearchMessagesFilterUnreadMentionBuilder {
     inner: SearchMessagesFilterUnreadMention,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDSearchMessagesFilterUnreadMentionBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> SearchMessagesFilterUnreadMention {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<SearchMessagesFilterUnreadMention> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "SearchMessagesFilterUnreadMention",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<SearchMessagesFilterUnreadMention> for SearchMessagesFilterUnreadMention {
@@ -835,12 +1278,13 @@ impl AsRef<SearchMessagesFilterUnreadMention> for RTDSearchMessagesFilterUnreadM
 
 /// Returns only messages containing URLs
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "searchMessagesFilterUrl")]
 pub struct SearchMessagesFilterUrl {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -858,6 +1302,18 @@ impl RObject for SearchMessagesFilterUrl {
     }
 }
 
+impl PartialEq for SearchMessagesFilterUrl {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for SearchMessagesFilterUrl {}
+
+impl std::hash::Hash for SearchMessagesFilterUrl {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDSearchMessagesFilter for SearchMessagesFilterUrl {}
 
 impl SearchMessagesFilterUrl {
@@ -865,22 +1321,49 @@ impl SearchMessagesFilterUrl {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDSearchMessagesFilterUrlBuilder {
-        let mut inner = SearchMessagesFilterUrl::default();
-        inner.td_name = "searchMessagesFilterUrl".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDSearchMessagesFilterUrlBuilder { inner }
+        RTDSearchMessagesFilterUrlBuilder {
+            inner: SearchMessagesFilterUrl::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDSearchMessagesFilterUrlBuilder {
+        RTDSearchMessagesFilterUrlBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDSearchMessagesFilterUrlBuilder {
+        RTDSearchMessagesFilterUrlBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDSearchMessagesFilterUrlBuilder {
     inner: SearchMessagesFilterUrl,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDSearchMessagesFilterUrlBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> SearchMessagesFilterUrl {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<SearchMessagesFilterUrl> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "SearchMessagesFilterUrl",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<SearchMessagesFilterUrl> for SearchMessagesFilterUrl {
@@ -897,12 +1380,13 @@ impl AsRef<SearchMessagesFilterUrl> for RTDSearchMessagesFilterUrlBuilder {
 
 /// Returns only video messages
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "searchMessagesFilterVideo")]
 pub struct SearchMessagesFilterVideo {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -920,6 +1404,18 @@ impl RObject for SearchMessagesFilterVideo {
     }
 }
 
+impl PartialEq for SearchMessagesFilterVideo {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for SearchMessagesFilterVideo {}
+
+impl std::hash::Hash for SearchMessagesFilterVideo {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDSearchMessagesFilter for SearchMessagesFilterVideo {}
 
 impl SearchMessagesFilterVideo {
@@ -927,22 +1423,49 @@ impl SearchMessagesFilterVideo {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDSearchMessagesFilterVideoBuilder {
-        let mut inner = SearchMessagesFilterVideo::default();
-        inner.td_name = "searchMessagesFilterVideo".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDSearchMessagesFilterVideoBuilder { inner }
+        RTDSearchMessagesFilterVideoBuilder {
+            inner: SearchMessagesFilterVideo::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDSearchMessagesFilterVideoBuilder {
+        RTDSearchMessagesFilterVideoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDSearchMessagesFilterVideoBuilder {
+        RTDSearchMessagesFilterVideoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDSearchMessagesFilterVideoBuilder {
     inner: SearchMessagesFilterVideo,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDSearchMessagesFilterVideoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> SearchMessagesFilterVideo {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<SearchMessagesFilterVideo> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "SearchMessagesFilterVideo",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<SearchMessagesFilterVideo> for SearchMessagesFilterVideo {
@@ -959,12 +1482,13 @@ impl AsRef<SearchMessagesFilterVideo> for RTDSearchMessagesFilterVideoBuilder {
 
 /// Returns only video note messages
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "searchMessagesFilterVideoNote")]
 pub struct SearchMessagesFilterVideoNote {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -982,6 +1506,18 @@ impl RObject for SearchMessagesFilterVideoNote {
     }
 }
 
+impl PartialEq for SearchMessagesFilterVideoNote {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for SearchMessagesFilterVideoNote {}
+
+impl std::hash::Hash for SearchMessagesFilterVideoNote {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDSearchMessagesFilter for SearchMessagesFilterVideoNote {}
 
 impl SearchMessagesFilterVideoNote {
@@ -989,22 +1525,49 @@ impl SearchMessagesFilterVideoNote {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDSearchMessagesFilterVideoNoteBuilder {
-        let mut inner = SearchMessagesFilterVideoNote::default();
-        inner.td_name = "searchMessagesFilterVideoNote".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDSearchMessagesFilterVideoNoteBuilder { inner }
+        RTDSearchMessagesFilterVideoNoteBuilder {
+            inner: SearchMessagesFilterVideoNote::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDSearchMessagesFilterVideoNoteBuilder {
+        RTDSearchMessagesFilterVideoNoteBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDSearchMessagesFilterVideoNoteBuilder {
+        RTDSearchMessagesFilterVideoNoteBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDSearchMessagesFilterVideoNoteBuilder {
     inner: SearchMessagesFilterVideoNote,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDSearchMessagesFilterVideoNoteBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> SearchMessagesFilterVideoNote {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<SearchMessagesFilterVideoNote> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "SearchMessagesFilterVideoNote",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<SearchMessagesFilterVideoNote> for SearchMessagesFilterVideoNote {
@@ -1021,12 +1584,13 @@ impl AsRef<SearchMessagesFilterVideoNote> for RTDSearchMessagesFilterVideoNoteBu
 
 /// Returns only voice and video note messages
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "searchMessagesFilterVoiceAndVideoNote")]
 pub struct SearchMessagesFilterVoiceAndVideoNote {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -1044,6 +1608,18 @@ impl RObject for SearchMessagesFilterVoiceAndVideoNote {
     }
 }
 
+impl PartialEq for SearchMessagesFilterVoiceAndVideoNote {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for SearchMessagesFilterVoiceAndVideoNote {}
+
+impl std::hash::Hash for SearchMessagesFilterVoiceAndVideoNote {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDSearchMessagesFilter for SearchMessagesFilterVoiceAndVideoNote {}
 
 impl SearchMessagesFilterVoiceAndVideoNote {
@@ -1051,22 +1627,49 @@ impl SearchMessagesFilterVoiceAndVideoNote {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDSearchMessagesFilterVoiceAndVideoNoteBuilder {
-        let mut inner = SearchMessagesFilterVoiceAndVideoNote::default();
-        inner.td_name = "searchMessagesFilterVoiceAndVideoNote".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDSearchMessagesFilterVoiceAndVideoNoteBuilder { inner }
+        RTDSearchMessagesFilterVoiceAndVideoNoteBuilder {
+            inner: SearchMessagesFilterVoiceAndVideoNote::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDSearchMessagesFilterVoiceAndVideoNoteBuilder {
+        RTDSearchMessagesFilterVoiceAndVideoNoteBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDSearchMessagesFilterVoiceAndVideoNoteBuilder {
+        RTDSearchMessagesFilterVoiceAndVideoNoteBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDSearchMessagesFilterVoiceAndVideoNoteBuilder {
     inner: SearchMessagesFilterVoiceAndVideoNote,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDSearchMessagesFilterVoiceAndVideoNoteBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> SearchMessagesFilterVoiceAndVideoNote {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<SearchMessagesFilterVoiceAndVideoNote> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "SearchMessagesFilterVoiceAndVideoNote",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<SearchMessagesFilterVoiceAndVideoNote> for SearchMessagesFilterVoiceAndVideoNote {
@@ -1085,12 +1688,13 @@ impl AsRef<SearchMessagesFilterVoiceAndVideoNote>
 
 /// Returns only voice note messages
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "searchMessagesFilterVoiceNote")]
 pub struct SearchMessagesFilterVoiceNote {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -1108,6 +1712,18 @@ impl RObject for SearchMessagesFilterVoiceNote {
     }
 }
 
+impl PartialEq for SearchMessagesFilterVoiceNote {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for SearchMessagesFilterVoiceNote {}
+
+impl std::hash::Hash for SearchMessagesFilterVoiceNote {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDSearchMessagesFilter for SearchMessagesFilterVoiceNote {}
 
 impl SearchMessagesFilterVoiceNote {
@@ -1115,22 +1731,49 @@ impl SearchMessagesFilterVoiceNote {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDSearchMessagesFilterVoiceNoteBuilder {
-        let mut inner = SearchMessagesFilterVoiceNote::default();
-        inner.td_name = "searchMessagesFilterVoiceNote".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDSearchMessagesFilterVoiceNoteBuilder { inner }
+        RTDSearchMessagesFilterVoiceNoteBuilder {
+            inner: SearchMessagesFilterVoiceNote::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDSearchMessagesFilterVoiceNoteBuilder {
+        RTDSearchMessagesFilterVoiceNoteBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDSearchMessagesFilterVoiceNoteBuilder {
+        RTDSearchMessagesFilterVoiceNoteBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDSearchMessagesFilterVoiceNoteBuilder {
     inner: SearchMessagesFilterVoiceNote,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDSearchMessagesFilterVoiceNoteBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> SearchMessagesFilterVoiceNote {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<SearchMessagesFilterVoiceNote> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "SearchMessagesFilterVoiceNote",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<SearchMessagesFilterVoiceNote> for SearchMessagesFilterVoiceNote {
@@ -1144,3 +1787,61 @@ impl AsRef<SearchMessagesFilterVoiceNote> for RTDSearchMessagesFilterVoiceNoteBu
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(SearchMessagesFilter::Animation(
+            SearchMessagesFilterAnimation::builder().build(),
+        ));
+        assert_round_trip(SearchMessagesFilter::Audio(
+            SearchMessagesFilterAudio::builder().build(),
+        ));
+        assert_round_trip(SearchMessagesFilter::Call(
+            SearchMessagesFilterCall::builder().build(),
+        ));
+        assert_round_trip(SearchMessagesFilter::ChatPhoto(
+            SearchMessagesFilterChatPhoto::builder().build(),
+        ));
+        assert_round_trip(SearchMessagesFilter::Document(
+            SearchMessagesFilterDocument::builder().build(),
+        ));
+        assert_round_trip(SearchMessagesFilter::Empty(
+            SearchMessagesFilterEmpty::builder().build(),
+        ));
+        assert_round_trip(SearchMessagesFilter::Mention(
+            SearchMessagesFilterMention::builder().build(),
+        ));
+        assert_round_trip(SearchMessagesFilter::MissedCall(
+            SearchMessagesFilterMissedCall::builder().build(),
+        ));
+        assert_round_trip(SearchMessagesFilter::Photo(
+            SearchMessagesFilterPhoto::builder().build(),
+        ));
+        assert_round_trip(SearchMessagesFilter::PhotoAndVideo(
+            SearchMessagesFilterPhotoAndVideo::builder().build(),
+        ));
+        assert_round_trip(SearchMessagesFilter::UnreadMention(
+            SearchMessagesFilterUnreadMention::builder().build(),
+        ));
+        assert_round_trip(SearchMessagesFilter::Url(
+            SearchMessagesFilterUrl::builder().build(),
+        ));
+        assert_round_trip(SearchMessagesFilter::Video(
+            SearchMessagesFilterVideo::builder().build(),
+        ));
+        assert_round_trip(SearchMessagesFilter::VideoNote(
+            SearchMessagesFilterVideoNote::builder().build(),
+        ));
+        assert_round_trip(SearchMessagesFilter::VoiceAndVideoNote(
+            SearchMessagesFilterVoiceAndVideoNote::builder().build(),
+        ));
+        assert_round_trip(SearchMessagesFilter::VoiceNote(
+            SearchMessagesFilterVoiceNote::builder().build(),
+        ));
+    }
+}
@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains a value representing a number of seconds
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "seconds")]
 pub struct Seconds {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Number of seconds
     seconds: f32,
@@ -29,34 +29,70 @@ impl RObject for Seconds {
     }
 }
 
+impl PartialEq for Seconds {
+    fn eq(&self, other: &Self) -> bool {
+        self.seconds == other.seconds
+    }
+}
+
 impl Seconds {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDSecondsBuilder {
-        let mut inner = Seconds::default();
-        inner.td_name = "seconds".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDSecondsBuilder { inner }
+        RTDSecondsBuilder {
+            inner: Seconds::default(),
+            missing: vec!["seconds"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDSecondsBuilder {
+        RTDSecondsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDSecondsBuilder {
+        RTDSecondsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn seconds(&self) -> f32 {
         self.seconds
     }
+
+    pub fn with_seconds(mut self, seconds: f32) -> Self {
+        self.seconds = seconds;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDSecondsBuilder {
     inner: Seconds,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDSecondsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> Seconds {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<Seconds> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("Seconds", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn seconds(&mut self, seconds: f32) -> &mut Self {
         self.inner.seconds = seconds;
+        self.missing.retain(|f| *f != "seconds");
         self
     }
 }
@@ -72,3 +108,14 @@ impl AsRef<Seconds> for RTDSecondsBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(Seconds::builder().seconds(1.5).build());
+    }
+}
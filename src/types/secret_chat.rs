@@ -1,30 +1,31 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Represents a secret chat
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "secretChat")]
 pub struct SecretChat {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Secret chat identifier
-    id: i64,
+    id: SecretChatId,
     /// Identifier of the chat partner
-    user_id: i64,
+    user_id: UserId,
     /// State of the secret chat
     state: SecretChatState,
     /// True, if the chat was created by the current user; otherwise false
     is_outbound: bool,
     /// Current message Time To Live setting (self-destruct timer) for the chat, in seconds
-    ttl: i64,
+    ttl: i32,
     /// Hash of the currently used key for comparison with the hash of the chat partner's key. This is a string of 36 little-endian bytes, which must be split into groups of 2 bits, each denoting a pixel of one of 4 colors FFFFFF, D5E6F3, 2D5775, and 2F99C9. The pixels must be used to make a 12x12 square image filled from left to right, top to bottom. Alternatively, the first 32 bytes of the hash can be converted to the hexadecimal format and printed as 32 2-digit hex numbers
-    key_hash: String,
+    #[serde(with = "super::_common::bytes")]
+    key_hash: Vec<u8>,
     /// Secret chat layer; determines features supported by the other client. Video notes are supported if the layer >= 66; nested text entities and underline and strikethrough entities are supported if the layer >= 101
-    layer: i64,
+    layer: i32,
 }
 
 impl RObject for SecretChat {
@@ -41,22 +42,62 @@ impl RObject for SecretChat {
     }
 }
 
+impl PartialEq for SecretChat {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.user_id == other.user_id
+            && self.state == other.state
+            && self.is_outbound == other.is_outbound
+            && self.ttl == other.ttl
+            && self.key_hash == other.key_hash
+            && self.layer == other.layer
+    }
+}
+
+impl Eq for SecretChat {}
+
+impl std::hash::Hash for SecretChat {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.id, state);
+        std::hash::Hash::hash(&self.user_id, state);
+        std::hash::Hash::hash(&self.state, state);
+        std::hash::Hash::hash(&self.is_outbound, state);
+        std::hash::Hash::hash(&self.ttl, state);
+        std::hash::Hash::hash(&self.key_hash, state);
+        std::hash::Hash::hash(&self.layer, state);
+    }
+}
+
 impl SecretChat {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDSecretChatBuilder {
-        let mut inner = SecretChat::default();
-        inner.td_name = "secretChat".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDSecretChatBuilder { inner }
+        RTDSecretChatBuilder {
+            inner: SecretChat::default(),
+            missing: vec!["id", "user_id", "state", "ttl", "key_hash", "layer"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDSecretChatBuilder {
+        RTDSecretChatBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDSecretChatBuilder {
+        RTDSecretChatBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn id(&self) -> i64 {
+    pub fn id(&self) -> SecretChatId {
         self.id
     }
 
-    pub fn user_id(&self) -> i64 {
+    pub fn user_id(&self) -> UserId {
         self.user_id
     }
 
@@ -68,41 +109,97 @@ impl SecretChat {
         self.is_outbound
     }
 
-    pub fn ttl(&self) -> i64 {
+    pub fn ttl(&self) -> i32 {
         self.ttl
     }
 
-    pub fn key_hash(&self) -> &String {
+    pub fn key_hash(&self) -> &[u8] {
         &self.key_hash
     }
 
-    pub fn layer(&self) -> i64 {
+    pub fn layer(&self) -> i32 {
         self.layer
     }
+
+    pub fn into_state(self) -> SecretChatState {
+        self.state
+    }
+
+    pub fn into_key_hash(self) -> Vec<u8> {
+        self.key_hash
+    }
+
+    pub fn with_id<T: Into<SecretChatId>>(mut self, id: T) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    pub fn with_user_id<T: Into<UserId>>(mut self, user_id: T) -> Self {
+        self.user_id = user_id.into();
+        self
+    }
+
+    pub fn with_state<T: AsRef<SecretChatState>>(mut self, state: T) -> Self {
+        self.state = state.as_ref().clone();
+        self
+    }
+
+    pub fn with_is_outbound(mut self, is_outbound: bool) -> Self {
+        self.is_outbound = is_outbound;
+        self
+    }
+
+    pub fn with_ttl(mut self, ttl: i32) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    pub fn with_key_hash<T: AsRef<[u8]>>(mut self, key_hash: T) -> Self {
+        self.key_hash = key_hash.as_ref().to_vec();
+        self
+    }
+
+    pub fn with_layer(mut self, layer: i32) -> Self {
+        self.layer = layer;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDSecretChatBuilder {
     inner: SecretChat,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDSecretChatBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> SecretChat {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<SecretChat> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("SecretChat", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn id(&mut self, id: i64) -> &mut Self {
-        self.inner.id = id;
+    pub fn id<T: Into<SecretChatId>>(&mut self, id: T) -> &mut Self {
+        self.inner.id = id.into();
+        self.missing.retain(|f| *f != "id");
         self
     }
 
-    pub fn user_id(&mut self, user_id: i64) -> &mut Self {
-        self.inner.user_id = user_id;
+    pub fn user_id<T: Into<UserId>>(&mut self, user_id: T) -> &mut Self {
+        self.inner.user_id = user_id.into();
+        self.missing.retain(|f| *f != "user_id");
         self
     }
 
     pub fn state<T: AsRef<SecretChatState>>(&mut self, state: T) -> &mut Self {
         self.inner.state = state.as_ref().clone();
+        self.missing.retain(|f| *f != "state");
         self
     }
 
@@ -111,18 +208,21 @@ impl RTDSecretChatBuilder {
         self
     }
 
-    pub fn ttl(&mut self, ttl: i64) -> &mut Self {
+    pub fn ttl(&mut self, ttl: i32) -> &mut Self {
         self.inner.ttl = ttl;
+        self.missing.retain(|f| *f != "ttl");
         self
     }
 
-    pub fn key_hash<T: AsRef<str>>(&mut self, key_hash: T) -> &mut Self {
-        self.inner.key_hash = key_hash.as_ref().to_string();
+    pub fn key_hash<T: AsRef<[u8]>>(&mut self, key_hash: T) -> &mut Self {
+        self.inner.key_hash = key_hash.as_ref().to_vec();
+        self.missing.retain(|f| *f != "key_hash");
         self
     }
 
-    pub fn layer(&mut self, layer: i64) -> &mut Self {
+    pub fn layer(&mut self, layer: i32) -> &mut Self {
         self.inner.layer = layer;
+        self.missing.retain(|f| *f != "layer");
         self
     }
 }
@@ -138,3 +238,26 @@ impl AsRef<SecretChat> for RTDSecretChatBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            SecretChat::builder()
+                .id(i32::MAX)
+                .user_id(i32::MAX)
+                .state(SecretChatState::Closed(
+                    SecretChatStateClosed::builder().build(),
+                ))
+                .is_outbound(true)
+                .ttl(i32::MAX)
+                .key_hash(b"\x00\x01\xfe\xff")
+                .layer(i32::MAX)
+                .build(),
+        );
+    }
+}
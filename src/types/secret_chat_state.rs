@@ -1,46 +1,44 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Describes the current secret chat state
 pub trait TDSecretChatState: Debug + RObject {}
 
 /// Describes the current secret chat state
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum SecretChatState {
-    #[doc(hidden)]
-    _Default(()),
     /// The secret chat is closed
+    #[serde(rename = "secretChatStateClosed")]
     Closed(SecretChatStateClosed),
     /// The secret chat is not yet created; waiting for the other user to get online
+    #[serde(rename = "secretChatStatePending")]
     Pending(SecretChatStatePending),
     /// The secret chat is ready to use
+    #[serde(rename = "secretChatStateReady")]
     Ready(SecretChatStateReady),
 }
 
+/// Default value is default of [SecretChatStateClosed], builders still require the field to be set
 impl Default for SecretChatState {
     fn default() -> Self {
-        SecretChatState::_Default(())
+        SecretChatState::Closed(SecretChatStateClosed::default())
     }
 }
 
-impl<'de> Deserialize<'de> for SecretChatState {
-    fn deserialize<D>(deserializer: D) -> Result<SecretChatState, D::Error>
+impl Serialize for SecretChatState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          SecretChatState,
-          (secretChatStateClosed, Closed);
-          (secretChatStatePending, Pending);
-          (secretChatStateReady, Ready);
-
-        )(deserializer)
+        match self {
+            SecretChatState::Closed(t) => t.serialize(serializer),
+            SecretChatState::Pending(t) => t.serialize(serializer),
+            SecretChatState::Ready(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -51,8 +49,6 @@ impl RObject for SecretChatState {
             SecretChatState::Closed(t) => t.td_name(),
             SecretChatState::Pending(t) => t.td_name(),
             SecretChatState::Ready(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -61,8 +57,6 @@ impl RObject for SecretChatState {
             SecretChatState::Closed(t) => t.extra(),
             SecretChatState::Pending(t) => t.extra(),
             SecretChatState::Ready(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -74,10 +68,6 @@ impl SecretChatState {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, SecretChatState::_Default(_))
-    }
 }
 
 impl AsRef<SecretChatState> for SecretChatState {
@@ -88,12 +78,13 @@ impl AsRef<SecretChatState> for SecretChatState {
 
 /// The secret chat is closed
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "secretChatStateClosed")]
 pub struct SecretChatStateClosed {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -111,6 +102,18 @@ impl RObject for SecretChatStateClosed {
     }
 }
 
+impl PartialEq for SecretChatStateClosed {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for SecretChatStateClosed {}
+
+impl std::hash::Hash for SecretChatStateClosed {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDSecretChatState for SecretChatStateClosed {}
 
 impl SecretChatStateClosed {
@@ -118,22 +121,49 @@ impl SecretChatStateClosed {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDSecretChatStateClosedBuilder {
-        let mut inner = SecretChatStateClosed::default();
-        inner.td_name = "secretChatStateClosed".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDSecretChatStateClosedBuilder { inner }
+        RTDSecretChatStateClosedBuilder {
+            inner: SecretChatStateClosed::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDSecretChatStateClosedBuilder {
+        RTDSecretChatStateClosedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDSecretChatStateClosedBuilder {
+        RTDSecretChatStateClosedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDSecretChatStateClosedBuilder {
     inner: SecretChatStateClosed,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDSecretChatStateClosedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> SecretChatStateClosed {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<SecretChatStateClosed> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "SecretChatStateClosed",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<SecretChatStateClosed> for SecretChatStateClosed {
@@ -150,12 +180,13 @@ impl AsRef<SecretChatStateClosed> for RTDSecretChatStateClosedBuilder {
 
 /// The secret chat is not yet created; waiting for the other user to get online
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "secretChatStatePending")]
 pub struct SecretChatStatePending {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -173,6 +204,18 @@ impl RObject for SecretChatStatePending {
     }
 }
 
+impl PartialEq for SecretChatStatePending {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for SecretChatStatePending {}
+
+impl std::hash::Hash for SecretChatStatePending {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDSecretChatState for SecretChatStatePending {}
 
 impl SecretChatStatePending {
@@ -180,22 +223,49 @@ impl SecretChatStatePending {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDSecretChatStatePendingBuilder {
-        let mut inner = SecretChatStatePending::default();
-        inner.td_name = "secretChatStatePending".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDSecretChatStatePendingBuilder { inner }
+        RTDSecretChatStatePendingBuilder {
+            inner: SecretChatStatePending::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDSecretChatStatePendingBuilder {
+        RTDSecretChatStatePendingBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDSecretChatStatePendingBuilder {
+        RTDSecretChatStatePendingBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDSecretChatStatePendingBuilder {
     inner: SecretChatStatePending,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDSecretChatStatePendingBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> SecretChatStatePending {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<SecretChatStatePending> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "SecretChatStatePending",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<SecretChatStatePending> for SecretChatStatePending {
@@ -212,12 +282,13 @@ impl AsRef<SecretChatStatePending> for RTDSecretChatStatePendingBuilder {
 
 /// The secret chat is ready to use
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "secretChatStateReady")]
 pub struct SecretChatStateReady {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -235,6 +306,18 @@ impl RObject for SecretChatStateReady {
     }
 }
 
+impl PartialEq for SecretChatStateReady {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for SecretChatStateReady {}
+
+impl std::hash::Hash for SecretChatStateReady {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDSecretChatState for SecretChatStateReady {}
 
 impl SecretChatStateReady {
@@ -242,22 +325,49 @@ impl SecretChatStateReady {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDSecretChatStateReadyBuilder {
-        let mut inner = SecretChatStateReady::default();
-        inner.td_name = "secretChatStateReady".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDSecretChatStateReadyBuilder { inner }
+        RTDSecretChatStateReadyBuilder {
+            inner: SecretChatStateReady::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDSecretChatStateReadyBuilder {
+        RTDSecretChatStateReadyBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDSecretChatStateReadyBuilder {
+        RTDSecretChatStateReadyBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDSecretChatStateReadyBuilder {
     inner: SecretChatStateReady,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDSecretChatStateReadyBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> SecretChatStateReady {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<SecretChatStateReady> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "SecretChatStateReady",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<SecretChatStateReady> for SecretChatStateReady {
@@ -271,3 +381,22 @@ impl AsRef<SecretChatStateReady> for RTDSecretChatStateReadyBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(SecretChatState::Closed(
+            SecretChatStateClosed::builder().build(),
+        ));
+        assert_round_trip(SecretChatState::Pending(
+            SecretChatStatePending::builder().build(),
+        ));
+        assert_round_trip(SecretChatState::Ready(
+            SecretChatStateReady::builder().build(),
+        ));
+    }
+}
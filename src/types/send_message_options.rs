@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Options to be used when a message is send
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "sendMessageOptions")]
 pub struct SendMessageOptions {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Pass true to disable notification for the message. Must be false if the message is sent to a secret chat
     disable_notification: bool,
@@ -33,15 +33,47 @@ impl RObject for SendMessageOptions {
     }
 }
 
+impl PartialEq for SendMessageOptions {
+    fn eq(&self, other: &Self) -> bool {
+        self.disable_notification == other.disable_notification
+            && self.from_background == other.from_background
+            && self.scheduling_state == other.scheduling_state
+    }
+}
+
+impl Eq for SendMessageOptions {}
+
+impl std::hash::Hash for SendMessageOptions {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.disable_notification, state);
+        std::hash::Hash::hash(&self.from_background, state);
+        std::hash::Hash::hash(&self.scheduling_state, state);
+    }
+}
+
 impl SendMessageOptions {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDSendMessageOptionsBuilder {
-        let mut inner = SendMessageOptions::default();
-        inner.td_name = "sendMessageOptions".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDSendMessageOptionsBuilder { inner }
+        RTDSendMessageOptionsBuilder {
+            inner: SendMessageOptions::default(),
+            missing: vec!["scheduling_state"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDSendMessageOptionsBuilder {
+        RTDSendMessageOptionsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDSendMessageOptionsBuilder {
+        RTDSendMessageOptionsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn disable_notification(&self) -> bool {
@@ -55,17 +87,52 @@ impl SendMessageOptions {
     pub fn scheduling_state(&self) -> &MessageSchedulingState {
         &self.scheduling_state
     }
+
+    pub fn into_scheduling_state(self) -> MessageSchedulingState {
+        self.scheduling_state
+    }
+
+    pub fn with_disable_notification(mut self, disable_notification: bool) -> Self {
+        self.disable_notification = disable_notification;
+        self
+    }
+
+    pub fn with_from_background(mut self, from_background: bool) -> Self {
+        self.from_background = from_background;
+        self
+    }
+
+    pub fn with_scheduling_state<T: AsRef<MessageSchedulingState>>(
+        mut self,
+        scheduling_state: T,
+    ) -> Self {
+        self.scheduling_state = scheduling_state.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDSendMessageOptionsBuilder {
     inner: SendMessageOptions,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDSendMessageOptionsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> SendMessageOptions {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<SendMessageOptions> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "SendMessageOptions",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn disable_notification(&mut self, disable_notification: bool) -> &mut Self {
         self.inner.disable_notification = disable_notification;
@@ -82,6 +149,7 @@ impl RTDSendMessageOptionsBuilder {
         scheduling_state: T,
     ) -> &mut Self {
         self.inner.scheduling_state = scheduling_state.as_ref().clone();
+        self.missing.retain(|f| *f != "scheduling_state");
         self
     }
 }
@@ -97,3 +165,24 @@ impl AsRef<SendMessageOptions> for RTDSendMessageOptionsBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            SendMessageOptions::builder()
+                .disable_notification(true)
+                .from_background(true)
+                .scheduling_state(MessageSchedulingState::SendAtDate(
+                    MessageSchedulingStateSendAtDate::builder()
+                        .send_date(i32::MAX)
+                        .build(),
+                ))
+                .build(),
+        );
+    }
+}
@@ -1,24 +1,25 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains information about one session in a Telegram application used by the current user. Sessions should be shown to the user in the returned order
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "session")]
 pub struct Session {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Session identifier
-    id: isize,
+    #[serde(with = "super::_common::int64")]
+    id: i64,
     /// True, if this session is the current session
     is_current: bool,
     /// True, if a password is needed to complete authorization of the session
     is_password_pending: bool,
     /// Telegram API identifier, as provided by the application
-    api_id: i64,
+    api_id: i32,
     /// Name of the application, as provided by the application
     application_name: String,
     /// The version of the application, as provided by the application
@@ -32,9 +33,9 @@ pub struct Session {
     /// Version of the operating system the application has been run or is running on, as provided by the application
     system_version: String,
     /// Point in time (Unix timestamp) when the user has logged in
-    log_in_date: i64,
+    log_in_date: i32,
     /// Point in time (Unix timestamp) when the session was last used
-    last_active_date: i64,
+    last_active_date: i32,
     /// IP address from which the session was created, in human-readable format
     ip: String,
     /// A two-letter country code for the country from which the session was created, based on the IP address
@@ -57,18 +58,87 @@ impl RObject for Session {
     }
 }
 
+impl PartialEq for Session {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.is_current == other.is_current
+            && self.is_password_pending == other.is_password_pending
+            && self.api_id == other.api_id
+            && self.application_name == other.application_name
+            && self.application_version == other.application_version
+            && self.is_official_application == other.is_official_application
+            && self.device_model == other.device_model
+            && self.platform == other.platform
+            && self.system_version == other.system_version
+            && self.log_in_date == other.log_in_date
+            && self.last_active_date == other.last_active_date
+            && self.ip == other.ip
+            && self.country == other.country
+            && self.region == other.region
+    }
+}
+
+impl Eq for Session {}
+
+impl std::hash::Hash for Session {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.id, state);
+        std::hash::Hash::hash(&self.is_current, state);
+        std::hash::Hash::hash(&self.is_password_pending, state);
+        std::hash::Hash::hash(&self.api_id, state);
+        std::hash::Hash::hash(&self.application_name, state);
+        std::hash::Hash::hash(&self.application_version, state);
+        std::hash::Hash::hash(&self.is_official_application, state);
+        std::hash::Hash::hash(&self.device_model, state);
+        std::hash::Hash::hash(&self.platform, state);
+        std::hash::Hash::hash(&self.system_version, state);
+        std::hash::Hash::hash(&self.log_in_date, state);
+        std::hash::Hash::hash(&self.last_active_date, state);
+        std::hash::Hash::hash(&self.ip, state);
+        std::hash::Hash::hash(&self.country, state);
+        std::hash::Hash::hash(&self.region, state);
+    }
+}
+
 impl Session {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDSessionBuilder {
-        let mut inner = Session::default();
-        inner.td_name = "session".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDSessionBuilder { inner }
-    }
-
-    pub fn id(&self) -> isize {
+        RTDSessionBuilder {
+            inner: Session::default(),
+            missing: vec![
+                "id",
+                "api_id",
+                "application_name",
+                "application_version",
+                "device_model",
+                "platform",
+                "system_version",
+                "log_in_date",
+                "last_active_date",
+                "ip",
+                "country",
+                "region",
+            ],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDSessionBuilder {
+        RTDSessionBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDSessionBuilder {
+        RTDSessionBuilder {
+            inner: self,
+            missing: vec![],
+        }
+    }
+
+    pub fn id(&self) -> i64 {
         self.id
     }
 
@@ -80,7 +150,7 @@ impl Session {
         self.is_password_pending
     }
 
-    pub fn api_id(&self) -> i64 {
+    pub fn api_id(&self) -> i32 {
         self.api_id
     }
 
@@ -108,11 +178,11 @@ impl Session {
         &self.system_version
     }
 
-    pub fn log_in_date(&self) -> i64 {
+    pub fn log_in_date(&self) -> i32 {
         self.log_in_date
     }
 
-    pub fn last_active_date(&self) -> i64 {
+    pub fn last_active_date(&self) -> i32 {
         self.last_active_date
     }
 
@@ -127,20 +197,138 @@ impl Session {
     pub fn region(&self) -> &String {
         &self.region
     }
+
+    pub fn into_application_name(self) -> String {
+        self.application_name
+    }
+
+    pub fn into_application_version(self) -> String {
+        self.application_version
+    }
+
+    pub fn into_device_model(self) -> String {
+        self.device_model
+    }
+
+    pub fn into_platform(self) -> String {
+        self.platform
+    }
+
+    pub fn into_system_version(self) -> String {
+        self.system_version
+    }
+
+    pub fn into_ip(self) -> String {
+        self.ip
+    }
+
+    pub fn into_country(self) -> String {
+        self.country
+    }
+
+    pub fn into_region(self) -> String {
+        self.region
+    }
+
+    pub fn with_id(mut self, id: i64) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn with_is_current(mut self, is_current: bool) -> Self {
+        self.is_current = is_current;
+        self
+    }
+
+    pub fn with_is_password_pending(mut self, is_password_pending: bool) -> Self {
+        self.is_password_pending = is_password_pending;
+        self
+    }
+
+    pub fn with_api_id(mut self, api_id: i32) -> Self {
+        self.api_id = api_id;
+        self
+    }
+
+    pub fn with_application_name<T: AsRef<str>>(mut self, application_name: T) -> Self {
+        self.application_name = application_name.as_ref().to_string();
+        self
+    }
+
+    pub fn with_application_version<T: AsRef<str>>(mut self, application_version: T) -> Self {
+        self.application_version = application_version.as_ref().to_string();
+        self
+    }
+
+    pub fn with_is_official_application(mut self, is_official_application: bool) -> Self {
+        self.is_official_application = is_official_application;
+        self
+    }
+
+    pub fn with_device_model<T: AsRef<str>>(mut self, device_model: T) -> Self {
+        self.device_model = device_model.as_ref().to_string();
+        self
+    }
+
+    pub fn with_platform<T: AsRef<str>>(mut self, platform: T) -> Self {
+        self.platform = platform.as_ref().to_string();
+        self
+    }
+
+    pub fn with_system_version<T: AsRef<str>>(mut self, system_version: T) -> Self {
+        self.system_version = system_version.as_ref().to_string();
+        self
+    }
+
+    pub fn with_log_in_date(mut self, log_in_date: i32) -> Self {
+        self.log_in_date = log_in_date;
+        self
+    }
+
+    pub fn with_last_active_date(mut self, last_active_date: i32) -> Self {
+        self.last_active_date = last_active_date;
+        self
+    }
+
+    pub fn with_ip<T: AsRef<str>>(mut self, ip: T) -> Self {
+        self.ip = ip.as_ref().to_string();
+        self
+    }
+
+    pub fn with_country<T: AsRef<str>>(mut self, country: T) -> Self {
+        self.country = country.as_ref().to_string();
+        self
+    }
+
+    pub fn with_region<T: AsRef<str>>(mut self, region: T) -> Self {
+        self.region = region.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDSessionBuilder {
     inner: Session,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDSessionBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> Session {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<Session> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("Session", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn id(&mut self, id: isize) -> &mut Self {
+    pub fn id(&mut self, id: i64) -> &mut Self {
         self.inner.id = id;
+        self.missing.retain(|f| *f != "id");
         self
     }
 
@@ -154,18 +342,21 @@ impl RTDSessionBuilder {
         self
     }
 
-    pub fn api_id(&mut self, api_id: i64) -> &mut Self {
+    pub fn api_id(&mut self, api_id: i32) -> &mut Self {
         self.inner.api_id = api_id;
+        self.missing.retain(|f| *f != "api_id");
         self
     }
 
     pub fn application_name<T: AsRef<str>>(&mut self, application_name: T) -> &mut Self {
         self.inner.application_name = application_name.as_ref().to_string();
+        self.missing.retain(|f| *f != "application_name");
         self
     }
 
     pub fn application_version<T: AsRef<str>>(&mut self, application_version: T) -> &mut Self {
         self.inner.application_version = application_version.as_ref().to_string();
+        self.missing.retain(|f| *f != "application_version");
         self
     }
 
@@ -176,41 +367,49 @@ impl RTDSessionBuilder {
 
     pub fn device_model<T: AsRef<str>>(&mut self, device_model: T) -> &mut Self {
         self.inner.device_model = device_model.as_ref().to_string();
+        self.missing.retain(|f| *f != "device_model");
         self
     }
 
     pub fn platform<T: AsRef<str>>(&mut self, platform: T) -> &mut Self {
         self.inner.platform = platform.as_ref().to_string();
+        self.missing.retain(|f| *f != "platform");
         self
     }
 
     pub fn system_version<T: AsRef<str>>(&mut self, system_version: T) -> &mut Self {
         self.inner.system_version = system_version.as_ref().to_string();
+        self.missing.retain(|f| *f != "system_version");
         self
     }
 
-    pub fn log_in_date(&mut self, log_in_date: i64) -> &mut Self {
+    pub fn log_in_date(&mut self, log_in_date: i32) -> &mut Self {
         self.inner.log_in_date = log_in_date;
+        self.missing.retain(|f| *f != "log_in_date");
         self
     }
 
-    pub fn last_active_date(&mut self, last_active_date: i64) -> &mut Self {
+    pub fn last_active_date(&mut self, last_active_date: i32) -> &mut Self {
         self.inner.last_active_date = last_active_date;
+        self.missing.retain(|f| *f != "last_active_date");
         self
     }
 
     pub fn ip<T: AsRef<str>>(&mut self, ip: T) -> &mut Self {
         self.inner.ip = ip.as_ref().to_string();
+        self.missing.retain(|f| *f != "ip");
         self
     }
 
     pub fn country<T: AsRef<str>>(&mut self, country: T) -> &mut Self {
         self.inner.country = country.as_ref().to_string();
+        self.missing.retain(|f| *f != "country");
         self
     }
 
     pub fn region<T: AsRef<str>>(&mut self, region: T) -> &mut Self {
         self.inner.region = region.as_ref().to_string();
+        self.missing.retain(|f| *f != "region");
         self
     }
 }
@@ -226,3 +425,32 @@ impl AsRef<Session> for RTDSessionBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            Session::builder()
+                .id(i64::MAX)
+                .is_current(true)
+                .is_password_pending(true)
+                .api_id(i32::MAX)
+                .application_name("text")
+                .application_version("text")
+                .is_official_application(true)
+                .device_model("text")
+                .platform("text")
+                .system_version("text")
+                .log_in_date(i32::MAX)
+                .last_active_date(i32::MAX)
+                .ip("text")
+                .country("text")
+                .region("text")
+                .build(),
+        );
+    }
+}
@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains a list of sessions
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "sessions")]
 pub struct Sessions {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// List of sessions
     sessions: Vec<Session>,
@@ -29,34 +29,82 @@ impl RObject for Sessions {
     }
 }
 
+impl PartialEq for Sessions {
+    fn eq(&self, other: &Self) -> bool {
+        self.sessions == other.sessions
+    }
+}
+
+impl Eq for Sessions {}
+
+impl std::hash::Hash for Sessions {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.sessions, state);
+    }
+}
+
 impl Sessions {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDSessionsBuilder {
-        let mut inner = Sessions::default();
-        inner.td_name = "sessions".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDSessionsBuilder { inner }
+        RTDSessionsBuilder {
+            inner: Sessions::default(),
+            missing: vec!["sessions"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDSessionsBuilder {
+        RTDSessionsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDSessionsBuilder {
+        RTDSessionsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn sessions(&self) -> &Vec<Session> {
         &self.sessions
     }
+
+    pub fn into_sessions(self) -> Vec<Session> {
+        self.sessions
+    }
+
+    pub fn with_sessions(mut self, sessions: Vec<Session>) -> Self {
+        self.sessions = sessions;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDSessionsBuilder {
     inner: Sessions,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDSessionsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> Sessions {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<Sessions> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("Sessions", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn sessions(&mut self, sessions: Vec<Session>) -> &mut Self {
         self.inner.sessions = sessions;
+        self.missing.retain(|f| *f != "sessions");
         self
     }
 }
@@ -72,3 +120,14 @@ impl AsRef<Sessions> for RTDSessionsBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(Sessions::builder().sessions(vec![]).build());
+    }
+}
@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// One shipping option
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "shippingOption")]
 pub struct ShippingOption {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Shipping option identifier
     id: String,
@@ -33,15 +33,45 @@ impl RObject for ShippingOption {
     }
 }
 
+impl PartialEq for ShippingOption {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id && self.title == other.title && self.price_parts == other.price_parts
+    }
+}
+
+impl Eq for ShippingOption {}
+
+impl std::hash::Hash for ShippingOption {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.id, state);
+        std::hash::Hash::hash(&self.title, state);
+        std::hash::Hash::hash(&self.price_parts, state);
+    }
+}
+
 impl ShippingOption {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDShippingOptionBuilder {
-        let mut inner = ShippingOption::default();
-        inner.td_name = "shippingOption".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDShippingOptionBuilder { inner }
+        RTDShippingOptionBuilder {
+            inner: ShippingOption::default(),
+            missing: vec!["id", "title", "price_parts"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDShippingOptionBuilder {
+        RTDShippingOptionBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDShippingOptionBuilder {
+        RTDShippingOptionBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn id(&self) -> &String {
@@ -55,30 +85,73 @@ impl ShippingOption {
     pub fn price_parts(&self) -> &Vec<LabeledPricePart> {
         &self.price_parts
     }
+
+    pub fn into_id(self) -> String {
+        self.id
+    }
+
+    pub fn into_title(self) -> String {
+        self.title
+    }
+
+    pub fn into_price_parts(self) -> Vec<LabeledPricePart> {
+        self.price_parts
+    }
+
+    pub fn with_id<T: AsRef<str>>(mut self, id: T) -> Self {
+        self.id = id.as_ref().to_string();
+        self
+    }
+
+    pub fn with_title<T: AsRef<str>>(mut self, title: T) -> Self {
+        self.title = title.as_ref().to_string();
+        self
+    }
+
+    pub fn with_price_parts(mut self, price_parts: Vec<LabeledPricePart>) -> Self {
+        self.price_parts = price_parts;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDShippingOptionBuilder {
     inner: ShippingOption,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDShippingOptionBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ShippingOption {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ShippingOption> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ShippingOption",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
         self.inner.id = id.as_ref().to_string();
+        self.missing.retain(|f| *f != "id");
         self
     }
 
     pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
         self.inner.title = title.as_ref().to_string();
+        self.missing.retain(|f| *f != "title");
         self
     }
 
     pub fn price_parts(&mut self, price_parts: Vec<LabeledPricePart>) -> &mut Self {
         self.inner.price_parts = price_parts;
+        self.missing.retain(|f| *f != "price_parts");
         self
     }
 }
@@ -94,3 +167,20 @@ impl AsRef<ShippingOption> for RTDShippingOptionBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            ShippingOption::builder()
+                .id("text")
+                .title("text")
+                .price_parts(vec![])
+                .build(),
+        );
+    }
+}
@@ -1,23 +1,23 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Describes a sticker
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "sticker")]
 pub struct Sticker {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The identifier of the sticker set to which the sticker belongs; 0 if none
-    #[serde(deserialize_with = "super::_common::number_from_string")]
-    set_id: isize,
+    #[serde(with = "super::_common::int64")]
+    set_id: i64,
     /// Sticker width; as defined by the sender
-    width: i64,
+    width: i32,
     /// Sticker height; as defined by the sender
-    height: i64,
+    height: i32,
     /// Emoji corresponding to the sticker
     emoji: String,
     /// True, if the sticker is an animated sticker in TGS format
@@ -46,26 +46,54 @@ impl RObject for Sticker {
     }
 }
 
+impl PartialEq for Sticker {
+    fn eq(&self, other: &Self) -> bool {
+        self.set_id == other.set_id
+            && self.width == other.width
+            && self.height == other.height
+            && self.emoji == other.emoji
+            && self.is_animated == other.is_animated
+            && self.is_mask == other.is_mask
+            && self.mask_position == other.mask_position
+            && self.thumbnail == other.thumbnail
+            && self.sticker == other.sticker
+    }
+}
+
 impl Sticker {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDStickerBuilder {
-        let mut inner = Sticker::default();
-        inner.td_name = "sticker".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDStickerBuilder { inner }
+        RTDStickerBuilder {
+            inner: Sticker::default(),
+            missing: vec!["width", "height", "emoji", "sticker"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDStickerBuilder {
+        RTDStickerBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDStickerBuilder {
+        RTDStickerBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn set_id(&self) -> isize {
+    pub fn set_id(&self) -> i64 {
         self.set_id
     }
 
-    pub fn width(&self) -> i64 {
+    pub fn width(&self) -> i32 {
         self.width
     }
 
-    pub fn height(&self) -> i64 {
+    pub fn height(&self) -> i32 {
         self.height
     }
 
@@ -92,35 +120,109 @@ impl Sticker {
     pub fn sticker(&self) -> &File {
         &self.sticker
     }
+
+    pub fn into_emoji(self) -> String {
+        self.emoji
+    }
+
+    pub fn into_mask_position(self) -> Option<MaskPosition> {
+        self.mask_position
+    }
+
+    pub fn into_thumbnail(self) -> Option<PhotoSize> {
+        self.thumbnail
+    }
+
+    pub fn into_sticker(self) -> File {
+        self.sticker
+    }
+
+    pub fn with_set_id(mut self, set_id: i64) -> Self {
+        self.set_id = set_id;
+        self
+    }
+
+    pub fn with_width(mut self, width: i32) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn with_height(mut self, height: i32) -> Self {
+        self.height = height;
+        self
+    }
+
+    pub fn with_emoji<T: AsRef<str>>(mut self, emoji: T) -> Self {
+        self.emoji = emoji.as_ref().to_string();
+        self
+    }
+
+    pub fn with_is_animated(mut self, is_animated: bool) -> Self {
+        self.is_animated = is_animated;
+        self
+    }
+
+    pub fn with_is_mask(mut self, is_mask: bool) -> Self {
+        self.is_mask = is_mask;
+        self
+    }
+
+    pub fn with_mask_position<T: AsRef<MaskPosition>>(mut self, mask_position: T) -> Self {
+        self.mask_position = Some(mask_position.as_ref().clone());
+        self
+    }
+
+    pub fn with_thumbnail<T: AsRef<PhotoSize>>(mut self, thumbnail: T) -> Self {
+        self.thumbnail = Some(thumbnail.as_ref().clone());
+        self
+    }
+
+    pub fn with_sticker<T: AsRef<File>>(mut self, sticker: T) -> Self {
+        self.sticker = sticker.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDStickerBuilder {
     inner: Sticker,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDStickerBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> Sticker {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<Sticker> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("Sticker", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn set_id(&mut self, set_id: isize) -> &mut Self {
+    pub fn set_id(&mut self, set_id: i64) -> &mut Self {
         self.inner.set_id = set_id;
         self
     }
 
-    pub fn width(&mut self, width: i64) -> &mut Self {
+    pub fn width(&mut self, width: i32) -> &mut Self {
         self.inner.width = width;
+        self.missing.retain(|f| *f != "width");
         self
     }
 
-    pub fn height(&mut self, height: i64) -> &mut Self {
+    pub fn height(&mut self, height: i32) -> &mut Self {
         self.inner.height = height;
+        self.missing.retain(|f| *f != "height");
         self
     }
 
     pub fn emoji<T: AsRef<str>>(&mut self, emoji: T) -> &mut Self {
         self.inner.emoji = emoji.as_ref().to_string();
+        self.missing.retain(|f| *f != "emoji");
         self
     }
 
@@ -146,6 +248,7 @@ impl RTDStickerBuilder {
 
     pub fn sticker<T: AsRef<File>>(&mut self, sticker: T) -> &mut Self {
         self.inner.sticker = sticker.as_ref().clone();
+        self.missing.retain(|f| *f != "sticker");
         self
     }
 }
@@ -161,3 +264,51 @@ impl AsRef<Sticker> for RTDStickerBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            Sticker::builder()
+                .set_id(i64::MAX)
+                .width(i32::MAX)
+                .height(i32::MAX)
+                .emoji("text")
+                .is_animated(true)
+                .is_mask(true)
+                .sticker(
+                    File::builder()
+                        .id(i32::MAX)
+                        .size(i32::MAX)
+                        .expected_size(i32::MAX)
+                        .local(
+                            LocalFile::builder()
+                                .path("text")
+                                .can_be_downloaded(true)
+                                .can_be_deleted(true)
+                                .is_downloading_active(true)
+                                .is_downloading_completed(true)
+                                .download_offset(i32::MAX)
+                                .downloaded_prefix_size(i32::MAX)
+                                .downloaded_size(i32::MAX)
+                                .build(),
+                        )
+                        .remote(
+                            RemoteFile::builder()
+                                .id("text")
+                                .unique_id("text")
+                                .is_uploading_active(true)
+                                .is_uploading_completed(true)
+                                .uploaded_size(i32::MAX)
+                                .build(),
+                        )
+                        .build(),
+                )
+                .build(),
+        );
+    }
+}
@@ -1,18 +1,19 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Represents short information about a sticker set
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "stickerSetInfo")]
 pub struct StickerSetInfo {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Identifier of the sticker set
-    id: isize,
+    #[serde(with = "super::_common::int64")]
+    id: i64,
     /// Title of the sticker set
     title: String,
     /// Name of the sticker set
@@ -32,7 +33,7 @@ pub struct StickerSetInfo {
     /// True for already viewed trending sticker sets
     is_viewed: bool,
     /// Total number of stickers in the set
-    size: i64,
+    size: i32,
     /// Contains up to the first 5 stickers from the set, depending on the context. If the client needs more stickers the full set should be requested
     covers: Vec<Sticker>,
 }
@@ -51,18 +52,49 @@ impl RObject for StickerSetInfo {
     }
 }
 
+impl PartialEq for StickerSetInfo {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.title == other.title
+            && self.name == other.name
+            && self.thumbnail == other.thumbnail
+            && self.is_installed == other.is_installed
+            && self.is_archived == other.is_archived
+            && self.is_official == other.is_official
+            && self.is_animated == other.is_animated
+            && self.is_masks == other.is_masks
+            && self.is_viewed == other.is_viewed
+            && self.size == other.size
+            && self.covers == other.covers
+    }
+}
+
 impl StickerSetInfo {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDStickerSetInfoBuilder {
-        let mut inner = StickerSetInfo::default();
-        inner.td_name = "stickerSetInfo".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDStickerSetInfoBuilder { inner }
+        RTDStickerSetInfoBuilder {
+            inner: StickerSetInfo::default(),
+            missing: vec!["id", "title", "name", "size", "covers"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDStickerSetInfoBuilder {
+        RTDStickerSetInfoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDStickerSetInfoBuilder {
+        RTDStickerSetInfoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn id(&self) -> isize {
+    pub fn id(&self) -> i64 {
         self.id
     }
 
@@ -102,37 +134,129 @@ impl StickerSetInfo {
         self.is_viewed
     }
 
-    pub fn size(&self) -> i64 {
+    pub fn size(&self) -> i32 {
         self.size
     }
 
     pub fn covers(&self) -> &Vec<Sticker> {
         &self.covers
     }
+
+    pub fn into_title(self) -> String {
+        self.title
+    }
+
+    pub fn into_name(self) -> String {
+        self.name
+    }
+
+    pub fn into_thumbnail(self) -> Option<PhotoSize> {
+        self.thumbnail
+    }
+
+    pub fn into_covers(self) -> Vec<Sticker> {
+        self.covers
+    }
+
+    pub fn with_id(mut self, id: i64) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn with_title<T: AsRef<str>>(mut self, title: T) -> Self {
+        self.title = title.as_ref().to_string();
+        self
+    }
+
+    pub fn with_name<T: AsRef<str>>(mut self, name: T) -> Self {
+        self.name = name.as_ref().to_string();
+        self
+    }
+
+    pub fn with_thumbnail<T: AsRef<PhotoSize>>(mut self, thumbnail: T) -> Self {
+        self.thumbnail = Some(thumbnail.as_ref().clone());
+        self
+    }
+
+    pub fn with_is_installed(mut self, is_installed: bool) -> Self {
+        self.is_installed = is_installed;
+        self
+    }
+
+    pub fn with_is_archived(mut self, is_archived: bool) -> Self {
+        self.is_archived = is_archived;
+        self
+    }
+
+    pub fn with_is_official(mut self, is_official: bool) -> Self {
+        self.is_official = is_official;
+        self
+    }
+
+    pub fn with_is_animated(mut self, is_animated: bool) -> Self {
+        self.is_animated = is_animated;
+        self
+    }
+
+    pub fn with_is_masks(mut self, is_masks: bool) -> Self {
+        self.is_masks = is_masks;
+        self
+    }
+
+    pub fn with_is_viewed(mut self, is_viewed: bool) -> Self {
+        self.is_viewed = is_viewed;
+        self
+    }
+
+    pub fn with_size(mut self, size: i32) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn with_covers(mut self, covers: Vec<Sticker>) -> Self {
+        self.covers = covers;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDStickerSetInfoBuilder {
     inner: StickerSetInfo,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDStickerSetInfoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> StickerSetInfo {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<StickerSetInfo> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "StickerSetInfo",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn id(&mut self, id: isize) -> &mut Self {
+    pub fn id(&mut self, id: i64) -> &mut Self {
         self.inner.id = id;
+        self.missing.retain(|f| *f != "id");
         self
     }
 
     pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
         self.inner.title = title.as_ref().to_string();
+        self.missing.retain(|f| *f != "title");
         self
     }
 
     pub fn name<T: AsRef<str>>(&mut self, name: T) -> &mut Self {
         self.inner.name = name.as_ref().to_string();
+        self.missing.retain(|f| *f != "name");
         self
     }
 
@@ -171,13 +295,15 @@ impl RTDStickerSetInfoBuilder {
         self
     }
 
-    pub fn size(&mut self, size: i64) -> &mut Self {
+    pub fn size(&mut self, size: i32) -> &mut Self {
         self.inner.size = size;
+        self.missing.retain(|f| *f != "size");
         self
     }
 
     pub fn covers(&mut self, covers: Vec<Sticker>) -> &mut Self {
         self.inner.covers = covers;
+        self.missing.retain(|f| *f != "covers");
         self
     }
 }
@@ -193,3 +319,28 @@ impl AsRef<StickerSetInfo> for RTDStickerSetInfoBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            StickerSetInfo::builder()
+                .id(i64::MAX)
+                .title("text")
+                .name("text")
+                .is_installed(true)
+                .is_archived(true)
+                .is_official(true)
+                .is_animated(true)
+                .is_masks(true)
+                .is_viewed(true)
+                .size(i32::MAX)
+                .covers(vec![])
+                .build(),
+        );
+    }
+}
@@ -1,18 +1,18 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Represents a list of sticker sets
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "stickerSets")]
 pub struct StickerSets {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Approximate total number of sticker sets found
-    total_count: i64,
+    total_count: i32,
     /// List of sticker sets
     sets: Vec<StickerSetInfo>,
 }
@@ -31,43 +31,89 @@ impl RObject for StickerSets {
     }
 }
 
+impl PartialEq for StickerSets {
+    fn eq(&self, other: &Self) -> bool {
+        self.total_count == other.total_count && self.sets == other.sets
+    }
+}
+
 impl StickerSets {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDStickerSetsBuilder {
-        let mut inner = StickerSets::default();
-        inner.td_name = "stickerSets".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDStickerSetsBuilder { inner }
+        RTDStickerSetsBuilder {
+            inner: StickerSets::default(),
+            missing: vec!["total_count", "sets"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDStickerSetsBuilder {
+        RTDStickerSetsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDStickerSetsBuilder {
+        RTDStickerSetsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn total_count(&self) -> i64 {
+    pub fn total_count(&self) -> i32 {
         self.total_count
     }
 
     pub fn sets(&self) -> &Vec<StickerSetInfo> {
         &self.sets
     }
+
+    pub fn into_sets(self) -> Vec<StickerSetInfo> {
+        self.sets
+    }
+
+    pub fn with_total_count(mut self, total_count: i32) -> Self {
+        self.total_count = total_count;
+        self
+    }
+
+    pub fn with_sets(mut self, sets: Vec<StickerSetInfo>) -> Self {
+        self.sets = sets;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDStickerSetsBuilder {
     inner: StickerSets,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDStickerSetsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> StickerSets {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<StickerSets> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("StickerSets", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn total_count(&mut self, total_count: i64) -> &mut Self {
+    pub fn total_count(&mut self, total_count: i32) -> &mut Self {
         self.inner.total_count = total_count;
+        self.missing.retain(|f| *f != "total_count");
         self
     }
 
     pub fn sets(&mut self, sets: Vec<StickerSetInfo>) -> &mut Self {
         self.inner.sets = sets;
+        self.missing.retain(|f| *f != "sets");
         self
     }
 }
@@ -83,3 +129,19 @@ impl AsRef<StickerSets> for RTDStickerSetsBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            StickerSets::builder()
+                .total_count(i32::MAX)
+                .sets(vec![])
+                .build(),
+        );
+    }
+}
@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Represents a list of stickers
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "stickers")]
 pub struct Stickers {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// List of stickers
     stickers: Vec<Sticker>,
@@ -29,34 +29,74 @@ impl RObject for Stickers {
     }
 }
 
+impl PartialEq for Stickers {
+    fn eq(&self, other: &Self) -> bool {
+        self.stickers == other.stickers
+    }
+}
+
 impl Stickers {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDStickersBuilder {
-        let mut inner = Stickers::default();
-        inner.td_name = "stickers".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDStickersBuilder { inner }
+        RTDStickersBuilder {
+            inner: Stickers::default(),
+            missing: vec!["stickers"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDStickersBuilder {
+        RTDStickersBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDStickersBuilder {
+        RTDStickersBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn stickers(&self) -> &Vec<Sticker> {
         &self.stickers
     }
+
+    pub fn into_stickers(self) -> Vec<Sticker> {
+        self.stickers
+    }
+
+    pub fn with_stickers(mut self, stickers: Vec<Sticker>) -> Self {
+        self.stickers = stickers;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDStickersBuilder {
     inner: Stickers,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDStickersBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> Stickers {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<Stickers> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("Stickers", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn stickers(&mut self, stickers: Vec<Sticker>) -> &mut Self {
         self.inner.stickers = stickers;
+        self.missing.retain(|f| *f != "stickers");
         self
     }
 }
@@ -72,3 +112,14 @@ impl AsRef<Stickers> for RTDStickersBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(Stickers::builder().stickers(vec![]).build());
+    }
+}
@@ -1,20 +1,20 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains the exact storage usage statistics split by chats and file type
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "storageStatistics")]
 pub struct StorageStatistics {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Total size of files
     size: i64,
     /// Total number of files
-    count: i64,
+    count: i32,
     /// Statistics split by chats
     by_chat: Vec<StorageStatisticsByChat>,
 }
@@ -33,52 +33,117 @@ impl RObject for StorageStatistics {
     }
 }
 
+impl PartialEq for StorageStatistics {
+    fn eq(&self, other: &Self) -> bool {
+        self.size == other.size && self.count == other.count && self.by_chat == other.by_chat
+    }
+}
+
+impl Eq for StorageStatistics {}
+
+impl std::hash::Hash for StorageStatistics {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.size, state);
+        std::hash::Hash::hash(&self.count, state);
+        std::hash::Hash::hash(&self.by_chat, state);
+    }
+}
+
 impl StorageStatistics {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDStorageStatisticsBuilder {
-        let mut inner = StorageStatistics::default();
-        inner.td_name = "storageStatistics".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDStorageStatisticsBuilder { inner }
+        RTDStorageStatisticsBuilder {
+            inner: StorageStatistics::default(),
+            missing: vec!["size", "count", "by_chat"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDStorageStatisticsBuilder {
+        RTDStorageStatisticsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDStorageStatisticsBuilder {
+        RTDStorageStatisticsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn size(&self) -> i64 {
         self.size
     }
 
-    pub fn count(&self) -> i64 {
+    pub fn count(&self) -> i32 {
         self.count
     }
 
     pub fn by_chat(&self) -> &Vec<StorageStatisticsByChat> {
         &self.by_chat
     }
+
+    pub fn into_by_chat(self) -> Vec<StorageStatisticsByChat> {
+        self.by_chat
+    }
+
+    pub fn with_size(mut self, size: i64) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn with_count(mut self, count: i32) -> Self {
+        self.count = count;
+        self
+    }
+
+    pub fn with_by_chat(mut self, by_chat: Vec<StorageStatisticsByChat>) -> Self {
+        self.by_chat = by_chat;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDStorageStatisticsBuilder {
     inner: StorageStatistics,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDStorageStatisticsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> StorageStatistics {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<StorageStatistics> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "StorageStatistics",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn size(&mut self, size: i64) -> &mut Self {
         self.inner.size = size;
+        self.missing.retain(|f| *f != "size");
         self
     }
 
-    pub fn count(&mut self, count: i64) -> &mut Self {
+    pub fn count(&mut self, count: i32) -> &mut Self {
         self.inner.count = count;
+        self.missing.retain(|f| *f != "count");
         self
     }
 
     pub fn by_chat(&mut self, by_chat: Vec<StorageStatisticsByChat>) -> &mut Self {
         self.inner.by_chat = by_chat;
+        self.missing.retain(|f| *f != "by_chat");
         self
     }
 }
@@ -94,3 +159,20 @@ impl AsRef<StorageStatistics> for RTDStorageStatisticsBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            StorageStatistics::builder()
+                .size(9007199254740991i64)
+                .count(i32::MAX)
+                .by_chat(vec![])
+                .build(),
+        );
+    }
+}
@@ -1,22 +1,22 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains the storage usage statistics for a specific chat
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "storageStatisticsByChat")]
 pub struct StorageStatisticsByChat {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Chat identifier; 0 if none
-    chat_id: i64,
+    chat_id: ChatId,
     /// Total size of the files in the chat
     size: i64,
     /// Total number of files in the chat
-    count: i64,
+    count: i32,
     /// Statistics split by file types
     by_file_type: Vec<StorageStatisticsByFileType>,
 }
@@ -35,18 +35,52 @@ impl RObject for StorageStatisticsByChat {
     }
 }
 
+impl PartialEq for StorageStatisticsByChat {
+    fn eq(&self, other: &Self) -> bool {
+        self.chat_id == other.chat_id
+            && self.size == other.size
+            && self.count == other.count
+            && self.by_file_type == other.by_file_type
+    }
+}
+
+impl Eq for StorageStatisticsByChat {}
+
+impl std::hash::Hash for StorageStatisticsByChat {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.chat_id, state);
+        std::hash::Hash::hash(&self.size, state);
+        std::hash::Hash::hash(&self.count, state);
+        std::hash::Hash::hash(&self.by_file_type, state);
+    }
+}
+
 impl StorageStatisticsByChat {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDStorageStatisticsByChatBuilder {
-        let mut inner = StorageStatisticsByChat::default();
-        inner.td_name = "storageStatisticsByChat".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDStorageStatisticsByChatBuilder { inner }
+        RTDStorageStatisticsByChatBuilder {
+            inner: StorageStatisticsByChat::default(),
+            missing: vec!["size", "count", "by_file_type"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDStorageStatisticsByChatBuilder {
+        RTDStorageStatisticsByChatBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDStorageStatisticsByChatBuilder {
+        RTDStorageStatisticsByChatBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn chat_id(&self) -> i64 {
+    pub fn chat_id(&self) -> ChatId {
         self.chat_id
     }
 
@@ -54,42 +88,82 @@ impl StorageStatisticsByChat {
         self.size
     }
 
-    pub fn count(&self) -> i64 {
+    pub fn count(&self) -> i32 {
         self.count
     }
 
     pub fn by_file_type(&self) -> &Vec<StorageStatisticsByFileType> {
         &self.by_file_type
     }
+
+    pub fn into_by_file_type(self) -> Vec<StorageStatisticsByFileType> {
+        self.by_file_type
+    }
+
+    pub fn with_chat_id<T: Into<ChatId>>(mut self, chat_id: T) -> Self {
+        self.chat_id = chat_id.into();
+        self
+    }
+
+    pub fn with_size(mut self, size: i64) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn with_count(mut self, count: i32) -> Self {
+        self.count = count;
+        self
+    }
+
+    pub fn with_by_file_type(mut self, by_file_type: Vec<StorageStatisticsByFileType>) -> Self {
+        self.by_file_type = by_file_type;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDStorageStatisticsByChatBuilder {
     inner: StorageStatisticsByChat,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDStorageStatisticsByChatBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> StorageStatisticsByChat {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<StorageStatisticsByChat> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "StorageStatisticsByChat",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
-        self.inner.chat_id = chat_id;
+    pub fn chat_id<T: Into<ChatId>>(&mut self, chat_id: T) -> &mut Self {
+        self.inner.chat_id = chat_id.into();
         self
     }
 
     pub fn size(&mut self, size: i64) -> &mut Self {
         self.inner.size = size;
+        self.missing.retain(|f| *f != "size");
         self
     }
 
-    pub fn count(&mut self, count: i64) -> &mut Self {
+    pub fn count(&mut self, count: i32) -> &mut Self {
         self.inner.count = count;
+        self.missing.retain(|f| *f != "count");
         self
     }
 
     pub fn by_file_type(&mut self, by_file_type: Vec<StorageStatisticsByFileType>) -> &mut Self {
         self.inner.by_file_type = by_file_type;
+        self.missing.retain(|f| *f != "by_file_type");
         self
     }
 }
@@ -105,3 +179,21 @@ impl AsRef<StorageStatisticsByChat> for RTDStorageStatisticsByChatBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            StorageStatisticsByChat::builder()
+                .chat_id(9007199254740991i64)
+                .size(9007199254740991i64)
+                .count(i32::MAX)
+                .by_file_type(vec![])
+                .build(),
+        );
+    }
+}
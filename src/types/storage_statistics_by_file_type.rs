@@ -1,22 +1,22 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains the storage usage statistics for a specific file type
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "storageStatisticsByFileType")]
 pub struct StorageStatisticsByFileType {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// File type
     file_type: FileType,
     /// Total size of the files
     size: i64,
     /// Total number of files
-    count: i64,
+    count: i32,
 }
 
 impl RObject for StorageStatisticsByFileType {
@@ -33,15 +33,45 @@ impl RObject for StorageStatisticsByFileType {
     }
 }
 
+impl PartialEq for StorageStatisticsByFileType {
+    fn eq(&self, other: &Self) -> bool {
+        self.file_type == other.file_type && self.size == other.size && self.count == other.count
+    }
+}
+
+impl Eq for StorageStatisticsByFileType {}
+
+impl std::hash::Hash for StorageStatisticsByFileType {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.file_type, state);
+        std::hash::Hash::hash(&self.size, state);
+        std::hash::Hash::hash(&self.count, state);
+    }
+}
+
 impl StorageStatisticsByFileType {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDStorageStatisticsByFileTypeBuilder {
-        let mut inner = StorageStatisticsByFileType::default();
-        inner.td_name = "storageStatisticsByFileType".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDStorageStatisticsByFileTypeBuilder { inner }
+        RTDStorageStatisticsByFileTypeBuilder {
+            inner: StorageStatisticsByFileType::default(),
+            missing: vec!["file_type", "size", "count"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDStorageStatisticsByFileTypeBuilder {
+        RTDStorageStatisticsByFileTypeBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDStorageStatisticsByFileTypeBuilder {
+        RTDStorageStatisticsByFileTypeBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn file_type(&self) -> &FileType {
@@ -52,33 +82,68 @@ impl StorageStatisticsByFileType {
         self.size
     }
 
-    pub fn count(&self) -> i64 {
+    pub fn count(&self) -> i32 {
         self.count
     }
+
+    pub fn into_file_type(self) -> FileType {
+        self.file_type
+    }
+
+    pub fn with_file_type<T: AsRef<FileType>>(mut self, file_type: T) -> Self {
+        self.file_type = file_type.as_ref().clone();
+        self
+    }
+
+    pub fn with_size(mut self, size: i64) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn with_count(mut self, count: i32) -> Self {
+        self.count = count;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDStorageStatisticsByFileTypeBuilder {
     inner: StorageStatisticsByFileType,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDStorageStatisticsByFileTypeBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> StorageStatisticsByFileType {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<StorageStatisticsByFileType> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "StorageStatisticsByFileType",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn file_type<T: AsRef<FileType>>(&mut self, file_type: T) -> &mut Self {
         self.inner.file_type = file_type.as_ref().clone();
+        self.missing.retain(|f| *f != "file_type");
         self
     }
 
     pub fn size(&mut self, size: i64) -> &mut Self {
         self.inner.size = size;
+        self.missing.retain(|f| *f != "size");
         self
     }
 
-    pub fn count(&mut self, count: i64) -> &mut Self {
+    pub fn count(&mut self, count: i32) -> &mut Self {
         self.inner.count = count;
+        self.missing.retain(|f| *f != "count");
         self
     }
 }
@@ -94,3 +159,20 @@ impl AsRef<StorageStatisticsByFileType> for RTDStorageStatisticsByFileTypeBuilde
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            StorageStatisticsByFileType::builder()
+                .file_type(FileType::Animation(FileTypeAnimation::builder().build()))
+                .size(9007199254740991i64)
+                .count(i32::MAX)
+                .build(),
+        );
+    }
+}
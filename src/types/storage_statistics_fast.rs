@@ -1,20 +1,20 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains approximate storage usage statistics, excluding files of unknown file type
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "storageStatisticsFast")]
 pub struct StorageStatisticsFast {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Approximate total size of files
     files_size: i64,
     /// Approximate number of files
-    file_count: i64,
+    file_count: i32,
     /// Size of the database
     database_size: i64,
     /// Size of the language pack database
@@ -37,22 +37,64 @@ impl RObject for StorageStatisticsFast {
     }
 }
 
+impl PartialEq for StorageStatisticsFast {
+    fn eq(&self, other: &Self) -> bool {
+        self.files_size == other.files_size
+            && self.file_count == other.file_count
+            && self.database_size == other.database_size
+            && self.language_pack_database_size == other.language_pack_database_size
+            && self.log_size == other.log_size
+    }
+}
+
+impl Eq for StorageStatisticsFast {}
+
+impl std::hash::Hash for StorageStatisticsFast {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.files_size, state);
+        std::hash::Hash::hash(&self.file_count, state);
+        std::hash::Hash::hash(&self.database_size, state);
+        std::hash::Hash::hash(&self.language_pack_database_size, state);
+        std::hash::Hash::hash(&self.log_size, state);
+    }
+}
+
 impl StorageStatisticsFast {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDStorageStatisticsFastBuilder {
-        let mut inner = StorageStatisticsFast::default();
-        inner.td_name = "storageStatisticsFast".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDStorageStatisticsFastBuilder { inner }
+        RTDStorageStatisticsFastBuilder {
+            inner: StorageStatisticsFast::default(),
+            missing: vec![
+                "files_size",
+                "file_count",
+                "database_size",
+                "language_pack_database_size",
+                "log_size",
+            ],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDStorageStatisticsFastBuilder {
+        RTDStorageStatisticsFastBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDStorageStatisticsFastBuilder {
+        RTDStorageStatisticsFastBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn files_size(&self) -> i64 {
         self.files_size
     }
 
-    pub fn file_count(&self) -> i64 {
+    pub fn file_count(&self) -> i32 {
         self.file_count
     }
 
@@ -67,40 +109,83 @@ impl StorageStatisticsFast {
     pub fn log_size(&self) -> i64 {
         self.log_size
     }
+
+    pub fn with_files_size(mut self, files_size: i64) -> Self {
+        self.files_size = files_size;
+        self
+    }
+
+    pub fn with_file_count(mut self, file_count: i32) -> Self {
+        self.file_count = file_count;
+        self
+    }
+
+    pub fn with_database_size(mut self, database_size: i64) -> Self {
+        self.database_size = database_size;
+        self
+    }
+
+    pub fn with_language_pack_database_size(mut self, language_pack_database_size: i64) -> Self {
+        self.language_pack_database_size = language_pack_database_size;
+        self
+    }
+
+    pub fn with_log_size(mut self, log_size: i64) -> Self {
+        self.log_size = log_size;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDStorageStatisticsFastBuilder {
     inner: StorageStatisticsFast,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDStorageStatisticsFastBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> StorageStatisticsFast {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<StorageStatisticsFast> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "StorageStatisticsFast",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn files_size(&mut self, files_size: i64) -> &mut Self {
         self.inner.files_size = files_size;
+        self.missing.retain(|f| *f != "files_size");
         self
     }
 
-    pub fn file_count(&mut self, file_count: i64) -> &mut Self {
+    pub fn file_count(&mut self, file_count: i32) -> &mut Self {
         self.inner.file_count = file_count;
+        self.missing.retain(|f| *f != "file_count");
         self
     }
 
     pub fn database_size(&mut self, database_size: i64) -> &mut Self {
         self.inner.database_size = database_size;
+        self.missing.retain(|f| *f != "database_size");
         self
     }
 
     pub fn language_pack_database_size(&mut self, language_pack_database_size: i64) -> &mut Self {
         self.inner.language_pack_database_size = language_pack_database_size;
+        self.missing.retain(|f| *f != "language_pack_database_size");
         self
     }
 
     pub fn log_size(&mut self, log_size: i64) -> &mut Self {
         self.inner.log_size = log_size;
+        self.missing.retain(|f| *f != "log_size");
         self
     }
 }
@@ -116,3 +201,22 @@ impl AsRef<StorageStatisticsFast> for RTDStorageStatisticsFastBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            StorageStatisticsFast::builder()
+                .files_size(9007199254740991i64)
+                .file_count(i32::MAX)
+                .database_size(9007199254740991i64)
+                .language_pack_database_size(9007199254740991i64)
+                .log_size(9007199254740991i64)
+                .build(),
+        );
+    }
+}
@@ -1,26 +1,26 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Represents a supergroup or channel with zero or more members (subscribers in the case of channels). From the point of view of the system, a channel is a special kind of a supergroup: only administrators can post and see the list of members, and posts from all administrators use the name and photo of the channel instead of individual names and profile photos. Unlike supergroups, channels can have an unlimited number of subscribers
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "supergroup")]
 pub struct Supergroup {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Supergroup or channel identifier
-    id: i64,
+    id: SupergroupId,
     /// Username of the supergroup or channel; empty for private supergroups or channels
     username: String,
     /// Point in time (Unix timestamp) when the current user joined, or the point in time when the supergroup or channel was created, in case the user is not a member
-    date: i64,
+    date: i32,
     /// Status of the current user in the supergroup or channel; custom title will be always empty
     status: ChatMemberStatus,
     /// Member count; 0 if unknown. Currently it is guaranteed to be known only if the supergroup or channel was found through SearchPublicChats
-    member_count: i64,
+    member_count: i32,
     /// True, if the channel has a discussion group, or the supergroup is the designated discussion group for a channel
     has_linked_chat: bool,
     /// True, if the supergroup is connected to a location, i.e. the supergroup is a location-based supergroup
@@ -53,18 +53,70 @@ impl RObject for Supergroup {
     }
 }
 
+impl PartialEq for Supergroup {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.username == other.username
+            && self.date == other.date
+            && self.status == other.status
+            && self.member_count == other.member_count
+            && self.has_linked_chat == other.has_linked_chat
+            && self.has_location == other.has_location
+            && self.sign_messages == other.sign_messages
+            && self.is_slow_mode_enabled == other.is_slow_mode_enabled
+            && self.is_channel == other.is_channel
+            && self.is_verified == other.is_verified
+            && self.restriction_reason == other.restriction_reason
+            && self.is_scam == other.is_scam
+    }
+}
+
+impl Eq for Supergroup {}
+
+impl std::hash::Hash for Supergroup {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.id, state);
+        std::hash::Hash::hash(&self.username, state);
+        std::hash::Hash::hash(&self.date, state);
+        std::hash::Hash::hash(&self.status, state);
+        std::hash::Hash::hash(&self.member_count, state);
+        std::hash::Hash::hash(&self.has_linked_chat, state);
+        std::hash::Hash::hash(&self.has_location, state);
+        std::hash::Hash::hash(&self.sign_messages, state);
+        std::hash::Hash::hash(&self.is_slow_mode_enabled, state);
+        std::hash::Hash::hash(&self.is_channel, state);
+        std::hash::Hash::hash(&self.is_verified, state);
+        std::hash::Hash::hash(&self.restriction_reason, state);
+        std::hash::Hash::hash(&self.is_scam, state);
+    }
+}
+
 impl Supergroup {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDSupergroupBuilder {
-        let mut inner = Supergroup::default();
-        inner.td_name = "supergroup".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDSupergroupBuilder { inner }
-    }
-
-    pub fn id(&self) -> i64 {
+        RTDSupergroupBuilder {
+            inner: Supergroup::default(),
+            missing: vec!["id", "username", "date", "status"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDSupergroupBuilder {
+        RTDSupergroupBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDSupergroupBuilder {
+        RTDSupergroupBuilder {
+            inner: self,
+            missing: vec![],
+        }
+    }
+
+    pub fn id(&self) -> SupergroupId {
         self.id
     }
 
@@ -72,7 +124,7 @@ impl Supergroup {
         &self.username
     }
 
-    pub fn date(&self) -> i64 {
+    pub fn date(&self) -> i32 {
         self.date
     }
 
@@ -80,7 +132,7 @@ impl Supergroup {
         &self.status
     }
 
-    pub fn member_count(&self) -> i64 {
+    pub fn member_count(&self) -> i32 {
         self.member_count
     }
 
@@ -115,39 +167,130 @@ impl Supergroup {
     pub fn is_scam(&self) -> bool {
         self.is_scam
     }
+
+    pub fn into_username(self) -> String {
+        self.username
+    }
+
+    pub fn into_status(self) -> ChatMemberStatus {
+        self.status
+    }
+
+    pub fn into_restriction_reason(self) -> String {
+        self.restriction_reason
+    }
+
+    pub fn with_id<T: Into<SupergroupId>>(mut self, id: T) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    pub fn with_username<T: AsRef<str>>(mut self, username: T) -> Self {
+        self.username = username.as_ref().to_string();
+        self
+    }
+
+    pub fn with_date(mut self, date: i32) -> Self {
+        self.date = date;
+        self
+    }
+
+    pub fn with_status<T: AsRef<ChatMemberStatus>>(mut self, status: T) -> Self {
+        self.status = status.as_ref().clone();
+        self
+    }
+
+    pub fn with_member_count(mut self, member_count: i32) -> Self {
+        self.member_count = member_count;
+        self
+    }
+
+    pub fn with_has_linked_chat(mut self, has_linked_chat: bool) -> Self {
+        self.has_linked_chat = has_linked_chat;
+        self
+    }
+
+    pub fn with_has_location(mut self, has_location: bool) -> Self {
+        self.has_location = has_location;
+        self
+    }
+
+    pub fn with_sign_messages(mut self, sign_messages: bool) -> Self {
+        self.sign_messages = sign_messages;
+        self
+    }
+
+    pub fn with_is_slow_mode_enabled(mut self, is_slow_mode_enabled: bool) -> Self {
+        self.is_slow_mode_enabled = is_slow_mode_enabled;
+        self
+    }
+
+    pub fn with_is_channel(mut self, is_channel: bool) -> Self {
+        self.is_channel = is_channel;
+        self
+    }
+
+    pub fn with_is_verified(mut self, is_verified: bool) -> Self {
+        self.is_verified = is_verified;
+        self
+    }
+
+    pub fn with_restriction_reason<T: AsRef<str>>(mut self, restriction_reason: T) -> Self {
+        self.restriction_reason = restriction_reason.as_ref().to_string();
+        self
+    }
+
+    pub fn with_is_scam(mut self, is_scam: bool) -> Self {
+        self.is_scam = is_scam;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDSupergroupBuilder {
     inner: Supergroup,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDSupergroupBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> Supergroup {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<Supergroup> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("Supergroup", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn id(&mut self, id: i64) -> &mut Self {
-        self.inner.id = id;
+    pub fn id<T: Into<SupergroupId>>(&mut self, id: T) -> &mut Self {
+        self.inner.id = id.into();
+        self.missing.retain(|f| *f != "id");
         self
     }
 
     pub fn username<T: AsRef<str>>(&mut self, username: T) -> &mut Self {
         self.inner.username = username.as_ref().to_string();
+        self.missing.retain(|f| *f != "username");
         self
     }
 
-    pub fn date(&mut self, date: i64) -> &mut Self {
+    pub fn date(&mut self, date: i32) -> &mut Self {
         self.inner.date = date;
+        self.missing.retain(|f| *f != "date");
         self
     }
 
     pub fn status<T: AsRef<ChatMemberStatus>>(&mut self, status: T) -> &mut Self {
         self.inner.status = status.as_ref().clone();
+        self.missing.retain(|f| *f != "status");
         self
     }
 
-    pub fn member_count(&mut self, member_count: i64) -> &mut Self {
+    pub fn member_count(&mut self, member_count: i32) -> &mut Self {
         self.inner.member_count = member_count;
         self
     }
@@ -204,3 +347,43 @@ impl AsRef<Supergroup> for RTDSupergroupBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            Supergroup::builder()
+                .id(i32::MAX)
+                .username("text")
+                .date(i32::MAX)
+                .status(ChatMemberStatus::Administrator(
+                    ChatMemberStatusAdministrator::builder()
+                        .custom_title("text")
+                        .can_be_edited(true)
+                        .can_change_info(true)
+                        .can_post_messages(true)
+                        .can_edit_messages(true)
+                        .can_delete_messages(true)
+                        .can_invite_users(true)
+                        .can_restrict_members(true)
+                        .can_pin_messages(true)
+                        .can_promote_members(true)
+                        .build(),
+                ))
+                .member_count(i32::MAX)
+                .has_linked_chat(true)
+                .has_location(true)
+                .sign_messages(true)
+                .is_slow_mode_enabled(true)
+                .is_channel(true)
+                .is_verified(true)
+                .restriction_reason("text")
+                .is_scam(true)
+                .build(),
+        );
+    }
+}
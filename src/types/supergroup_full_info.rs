@@ -1,30 +1,30 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains full information about a supergroup or channel
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "supergroupFullInfo")]
 pub struct SupergroupFullInfo {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Contains full information about a supergroup or channel
     description: String,
     /// Number of members in the supergroup or channel; 0 if unknown
-    member_count: i64,
+    member_count: i32,
     /// Number of privileged users in the supergroup or channel; 0 if unknown
-    administrator_count: i64,
+    administrator_count: i32,
     /// Number of restricted users in the supergroup; 0 if unknown
-    restricted_count: i64,
+    restricted_count: i32,
     /// Number of users banned from chat; 0 if unknown
-    banned_count: i64,
+    banned_count: i32,
     /// Chat identifier of a discussion group for the channel, or a channel, for which the supergroup is the designated discussion group; 0 if none or unknown
-    linked_chat_id: i64,
+    linked_chat_id: ChatId,
     /// Delay between consecutive sent messages for non-administrator supergroup members, in seconds
-    slow_mode_delay: i64,
+    slow_mode_delay: i32,
     /// Time left before next message can be sent in the supergroup, in seconds. An updateSupergroupFullInfo update is not triggered when value of this field changes, but both new and old values are non-zero
     slow_mode_delay_expires_in: f32,
     /// True, if members of the chat can be retrieved
@@ -40,16 +40,16 @@ pub struct SupergroupFullInfo {
     /// True, if new chat members will have access to old messages. In public or discussion groups and both public and private channels, old messages are always available, so this option affects only private supergroups without a linked chat. The value of this field is only available for chat administrators
     is_all_history_available: bool,
     /// Identifier of the supergroup sticker set; 0 if none
-    #[serde(deserialize_with = "super::_common::number_from_string")]
-    sticker_set_id: isize,
+    #[serde(with = "super::_common::int64")]
+    sticker_set_id: i64,
     /// Location to which the supergroup is connected; may be null
     location: Option<ChatLocation>,
     /// Invite link for this chat
     invite_link: String,
     /// Identifier of the basic group from which supergroup was upgraded; 0 if none
-    upgraded_from_basic_group_id: i64,
+    upgraded_from_basic_group_id: BasicGroupId,
     /// Identifier of the last message in the basic group from which supergroup was upgraded; 0 if none
-    upgraded_from_max_message_id: i64,
+    upgraded_from_max_message_id: MessageId,
 }
 
 impl RObject for SupergroupFullInfo {
@@ -66,42 +66,80 @@ impl RObject for SupergroupFullInfo {
     }
 }
 
+impl PartialEq for SupergroupFullInfo {
+    fn eq(&self, other: &Self) -> bool {
+        self.description == other.description
+            && self.member_count == other.member_count
+            && self.administrator_count == other.administrator_count
+            && self.restricted_count == other.restricted_count
+            && self.banned_count == other.banned_count
+            && self.linked_chat_id == other.linked_chat_id
+            && self.slow_mode_delay == other.slow_mode_delay
+            && self.slow_mode_delay_expires_in == other.slow_mode_delay_expires_in
+            && self.can_get_members == other.can_get_members
+            && self.can_set_username == other.can_set_username
+            && self.can_set_sticker_set == other.can_set_sticker_set
+            && self.can_set_location == other.can_set_location
+            && self.can_view_statistics == other.can_view_statistics
+            && self.is_all_history_available == other.is_all_history_available
+            && self.sticker_set_id == other.sticker_set_id
+            && self.location == other.location
+            && self.invite_link == other.invite_link
+            && self.upgraded_from_basic_group_id == other.upgraded_from_basic_group_id
+            && self.upgraded_from_max_message_id == other.upgraded_from_max_message_id
+    }
+}
+
 impl SupergroupFullInfo {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDSupergroupFullInfoBuilder {
-        let mut inner = SupergroupFullInfo::default();
-        inner.td_name = "supergroupFullInfo".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDSupergroupFullInfoBuilder { inner }
+        RTDSupergroupFullInfoBuilder {
+            inner: SupergroupFullInfo::default(),
+            missing: vec!["description", "slow_mode_delay", "invite_link"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDSupergroupFullInfoBuilder {
+        RTDSupergroupFullInfoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDSupergroupFullInfoBuilder {
+        RTDSupergroupFullInfoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn description(&self) -> &String {
         &self.description
     }
 
-    pub fn member_count(&self) -> i64 {
+    pub fn member_count(&self) -> i32 {
         self.member_count
     }
 
-    pub fn administrator_count(&self) -> i64 {
+    pub fn administrator_count(&self) -> i32 {
         self.administrator_count
     }
 
-    pub fn restricted_count(&self) -> i64 {
+    pub fn restricted_count(&self) -> i32 {
         self.restricted_count
     }
 
-    pub fn banned_count(&self) -> i64 {
+    pub fn banned_count(&self) -> i32 {
         self.banned_count
     }
 
-    pub fn linked_chat_id(&self) -> i64 {
+    pub fn linked_chat_id(&self) -> ChatId {
         self.linked_chat_id
     }
 
-    pub fn slow_mode_delay(&self) -> i64 {
+    pub fn slow_mode_delay(&self) -> i32 {
         self.slow_mode_delay
     }
 
@@ -133,7 +171,7 @@ impl SupergroupFullInfo {
         self.is_all_history_available
     }
 
-    pub fn sticker_set_id(&self) -> isize {
+    pub fn sticker_set_id(&self) -> i64 {
         self.sticker_set_id
     }
 
@@ -145,57 +183,185 @@ impl SupergroupFullInfo {
         &self.invite_link
     }
 
-    pub fn upgraded_from_basic_group_id(&self) -> i64 {
+    pub fn upgraded_from_basic_group_id(&self) -> BasicGroupId {
         self.upgraded_from_basic_group_id
     }
 
-    pub fn upgraded_from_max_message_id(&self) -> i64 {
+    pub fn upgraded_from_max_message_id(&self) -> MessageId {
         self.upgraded_from_max_message_id
     }
+
+    pub fn into_description(self) -> String {
+        self.description
+    }
+
+    pub fn into_location(self) -> Option<ChatLocation> {
+        self.location
+    }
+
+    pub fn into_invite_link(self) -> String {
+        self.invite_link
+    }
+
+    pub fn with_description<T: AsRef<str>>(mut self, description: T) -> Self {
+        self.description = description.as_ref().to_string();
+        self
+    }
+
+    pub fn with_member_count(mut self, member_count: i32) -> Self {
+        self.member_count = member_count;
+        self
+    }
+
+    pub fn with_administrator_count(mut self, administrator_count: i32) -> Self {
+        self.administrator_count = administrator_count;
+        self
+    }
+
+    pub fn with_restricted_count(mut self, restricted_count: i32) -> Self {
+        self.restricted_count = restricted_count;
+        self
+    }
+
+    pub fn with_banned_count(mut self, banned_count: i32) -> Self {
+        self.banned_count = banned_count;
+        self
+    }
+
+    pub fn with_linked_chat_id<T: Into<ChatId>>(mut self, linked_chat_id: T) -> Self {
+        self.linked_chat_id = linked_chat_id.into();
+        self
+    }
+
+    pub fn with_slow_mode_delay(mut self, slow_mode_delay: i32) -> Self {
+        self.slow_mode_delay = slow_mode_delay;
+        self
+    }
+
+    pub fn with_slow_mode_delay_expires_in(mut self, slow_mode_delay_expires_in: f32) -> Self {
+        self.slow_mode_delay_expires_in = slow_mode_delay_expires_in;
+        self
+    }
+
+    pub fn with_can_get_members(mut self, can_get_members: bool) -> Self {
+        self.can_get_members = can_get_members;
+        self
+    }
+
+    pub fn with_can_set_username(mut self, can_set_username: bool) -> Self {
+        self.can_set_username = can_set_username;
+        self
+    }
+
+    pub fn with_can_set_sticker_set(mut self, can_set_sticker_set: bool) -> Self {
+        self.can_set_sticker_set = can_set_sticker_set;
+        self
+    }
+
+    pub fn with_can_set_location(mut self, can_set_location: bool) -> Self {
+        self.can_set_location = can_set_location;
+        self
+    }
+
+    pub fn with_can_view_statistics(mut self, can_view_statistics: bool) -> Self {
+        self.can_view_statistics = can_view_statistics;
+        self
+    }
+
+    pub fn with_is_all_history_available(mut self, is_all_history_available: bool) -> Self {
+        self.is_all_history_available = is_all_history_available;
+        self
+    }
+
+    pub fn with_sticker_set_id(mut self, sticker_set_id: i64) -> Self {
+        self.sticker_set_id = sticker_set_id;
+        self
+    }
+
+    pub fn with_location<T: AsRef<ChatLocation>>(mut self, location: T) -> Self {
+        self.location = Some(location.as_ref().clone());
+        self
+    }
+
+    pub fn with_invite_link<T: AsRef<str>>(mut self, invite_link: T) -> Self {
+        self.invite_link = invite_link.as_ref().to_string();
+        self
+    }
+
+    pub fn with_upgraded_from_basic_group_id<T: Into<BasicGroupId>>(
+        mut self,
+        upgraded_from_basic_group_id: T,
+    ) -> Self {
+        self.upgraded_from_basic_group_id = upgraded_from_basic_group_id.into();
+        self
+    }
+
+    pub fn with_upgraded_from_max_message_id<T: Into<MessageId>>(
+        mut self,
+        upgraded_from_max_message_id: T,
+    ) -> Self {
+        self.upgraded_from_max_message_id = upgraded_from_max_message_id.into();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDSupergroupFullInfoBuilder {
     inner: SupergroupFullInfo,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDSupergroupFullInfoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> SupergroupFullInfo {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<SupergroupFullInfo> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "SupergroupFullInfo",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn description<T: AsRef<str>>(&mut self, description: T) -> &mut Self {
         self.inner.description = description.as_ref().to_string();
+        self.missing.retain(|f| *f != "description");
         self
     }
 
-    pub fn member_count(&mut self, member_count: i64) -> &mut Self {
+    pub fn member_count(&mut self, member_count: i32) -> &mut Self {
         self.inner.member_count = member_count;
         self
     }
 
-    pub fn administrator_count(&mut self, administrator_count: i64) -> &mut Self {
+    pub fn administrator_count(&mut self, administrator_count: i32) -> &mut Self {
         self.inner.administrator_count = administrator_count;
         self
     }
 
-    pub fn restricted_count(&mut self, restricted_count: i64) -> &mut Self {
+    pub fn restricted_count(&mut self, restricted_count: i32) -> &mut Self {
         self.inner.restricted_count = restricted_count;
         self
     }
 
-    pub fn banned_count(&mut self, banned_count: i64) -> &mut Self {
+    pub fn banned_count(&mut self, banned_count: i32) -> &mut Self {
         self.inner.banned_count = banned_count;
         self
     }
 
-    pub fn linked_chat_id(&mut self, linked_chat_id: i64) -> &mut Self {
-        self.inner.linked_chat_id = linked_chat_id;
+    pub fn linked_chat_id<T: Into<ChatId>>(&mut self, linked_chat_id: T) -> &mut Self {
+        self.inner.linked_chat_id = linked_chat_id.into();
         self
     }
 
-    pub fn slow_mode_delay(&mut self, slow_mode_delay: i64) -> &mut Self {
+    pub fn slow_mode_delay(&mut self, slow_mode_delay: i32) -> &mut Self {
         self.inner.slow_mode_delay = slow_mode_delay;
+        self.missing.retain(|f| *f != "slow_mode_delay");
         self
     }
 
@@ -234,7 +400,7 @@ impl RTDSupergroupFullInfoBuilder {
         self
     }
 
-    pub fn sticker_set_id(&mut self, sticker_set_id: isize) -> &mut Self {
+    pub fn sticker_set_id(&mut self, sticker_set_id: i64) -> &mut Self {
         self.inner.sticker_set_id = sticker_set_id;
         self
     }
@@ -246,16 +412,23 @@ impl RTDSupergroupFullInfoBuilder {
 
     pub fn invite_link<T: AsRef<str>>(&mut self, invite_link: T) -> &mut Self {
         self.inner.invite_link = invite_link.as_ref().to_string();
+        self.missing.retain(|f| *f != "invite_link");
         self
     }
 
-    pub fn upgraded_from_basic_group_id(&mut self, upgraded_from_basic_group_id: i64) -> &mut Self {
-        self.inner.upgraded_from_basic_group_id = upgraded_from_basic_group_id;
+    pub fn upgraded_from_basic_group_id<T: Into<BasicGroupId>>(
+        &mut self,
+        upgraded_from_basic_group_id: T,
+    ) -> &mut Self {
+        self.inner.upgraded_from_basic_group_id = upgraded_from_basic_group_id.into();
         self
     }
 
-    pub fn upgraded_from_max_message_id(&mut self, upgraded_from_max_message_id: i64) -> &mut Self {
-        self.inner.upgraded_from_max_message_id = upgraded_from_max_message_id;
+    pub fn upgraded_from_max_message_id<T: Into<MessageId>>(
+        &mut self,
+        upgraded_from_max_message_id: T,
+    ) -> &mut Self {
+        self.inner.upgraded_from_max_message_id = upgraded_from_max_message_id.into();
         self
     }
 }
@@ -271,3 +444,35 @@ impl AsRef<SupergroupFullInfo> for RTDSupergroupFullInfoBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            SupergroupFullInfo::builder()
+                .description("text")
+                .member_count(i32::MAX)
+                .administrator_count(i32::MAX)
+                .restricted_count(i32::MAX)
+                .banned_count(i32::MAX)
+                .linked_chat_id(9007199254740991i64)
+                .slow_mode_delay(i32::MAX)
+                .slow_mode_delay_expires_in(1.5)
+                .can_get_members(true)
+                .can_set_username(true)
+                .can_set_sticker_set(true)
+                .can_set_location(true)
+                .can_view_statistics(true)
+                .is_all_history_available(true)
+                .sticker_set_id(i64::MAX)
+                .invite_link("text")
+                .upgraded_from_basic_group_id(i32::MAX)
+                .upgraded_from_max_message_id(9007199254740991i64)
+                .build(),
+        );
+    }
+}
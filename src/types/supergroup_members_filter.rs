@@ -1,58 +1,60 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Specifies the kind of chat members to return in getSupergroupMembers
 pub trait TDSupergroupMembersFilter: Debug + RObject {}
 
 /// Specifies the kind of chat members to return in getSupergroupMembers
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum SupergroupMembersFilter {
-    #[doc(hidden)]
-    _Default(()),
     /// Returns the owner and administrators
+    #[serde(rename = "supergroupMembersFilterAdministrators")]
     Administrators(SupergroupMembersFilterAdministrators),
     /// Returns users banned from the supergroup or channel; can be used only by administrators
+    #[serde(rename = "supergroupMembersFilterBanned")]
     Banned(SupergroupMembersFilterBanned),
     /// Returns bot members of the supergroup or channel
+    #[serde(rename = "supergroupMembersFilterBots")]
     Bots(SupergroupMembersFilterBots),
     /// Returns contacts of the user, which are members of the supergroup or channel
+    #[serde(rename = "supergroupMembersFilterContacts")]
     Contacts(SupergroupMembersFilterContacts),
     /// Returns recently active users in reverse chronological order
+    #[serde(rename = "supergroupMembersFilterRecent")]
     Recent(SupergroupMembersFilterRecent),
     /// Returns restricted supergroup members; can be used only by administrators
+    #[serde(rename = "supergroupMembersFilterRestricted")]
     Restricted(SupergroupMembersFilterRestricted),
     /// Used to search for supergroup or channel members via a (string) query
+    #[serde(rename = "supergroupMembersFilterSearch")]
     Search(SupergroupMembersFilterSearch),
 }
 
+/// Default value is default of [SupergroupMembersFilterAdministrators], builders still require the field to be set
 impl Default for SupergroupMembersFilter {
     fn default() -> Self {
-        SupergroupMembersFilter::_Default(())
+        SupergroupMembersFilter::Administrators(SupergroupMembersFilterAdministrators::default())
     }
 }
 
-impl<'de> Deserialize<'de> for SupergroupMembersFilter {
-    fn deserialize<D>(deserializer: D) -> Result<SupergroupMembersFilter, D::Error>
+impl Serialize for SupergroupMembersFilter {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          SupergroupMembersFilter,
-          (supergroupMembersFilterAdministrators, Administrators);
-          (supergroupMembersFilterBanned, Banned);
-          (supergroupMembersFilterBots, Bots);
-          (supergroupMembersFilterContacts, Contacts);
-          (supergroupMembersFilterRecent, Recent);
-          (supergroupMembersFilterRestricted, Restricted);
-          (supergroupMembersFilterSearch, Search);
-
-        )(deserializer)
+        match self {
+            SupergroupMembersFilter::Administrators(t) => t.serialize(serializer),
+            SupergroupMembersFilter::Banned(t) => t.serialize(serializer),
+            SupergroupMembersFilter::Bots(t) => t.serialize(serializer),
+            SupergroupMembersFilter::Contacts(t) => t.serialize(serializer),
+            SupergroupMembersFilter::Recent(t) => t.serialize(serializer),
+            SupergroupMembersFilter::Restricted(t) => t.serialize(serializer),
+            SupergroupMembersFilter::Search(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -67,8 +69,6 @@ impl RObject for SupergroupMembersFilter {
             SupergroupMembersFilter::Recent(t) => t.td_name(),
             SupergroupMembersFilter::Restricted(t) => t.td_name(),
             SupergroupMembersFilter::Search(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -81,8 +81,6 @@ impl RObject for SupergroupMembersFilter {
             SupergroupMembersFilter::Recent(t) => t.extra(),
             SupergroupMembersFilter::Restricted(t) => t.extra(),
             SupergroupMembersFilter::Search(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -94,10 +92,6 @@ impl SupergroupMembersFilter {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, SupergroupMembersFilter::_Default(_))
-    }
 }
 
 impl AsRef<SupergroupMembersFilter> for SupergroupMembersFilter {
@@ -108,12 +102,13 @@ impl AsRef<SupergroupMembersFilter> for SupergroupMembersFilter {
 
 /// Returns the owner and administrators
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "supergroupMembersFilterAdministrators")]
 pub struct SupergroupMembersFilterAdministrators {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -131,6 +126,18 @@ impl RObject for SupergroupMembersFilterAdministrators {
     }
 }
 
+impl PartialEq for SupergroupMembersFilterAdministrators {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for SupergroupMembersFilterAdministrators {}
+
+impl std::hash::Hash for SupergroupMembersFilterAdministrators {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDSupergroupMembersFilter for SupergroupMembersFilterAdministrators {}
 
 impl SupergroupMembersFilterAdministrators {
@@ -138,22 +145,49 @@ impl SupergroupMembersFilterAdministrators {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDSupergroupMembersFilterAdministratorsBuilder {
-        let mut inner = SupergroupMembersFilterAdministrators::default();
-        inner.td_name = "supergroupMembersFilterAdministrators".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDSupergroupMembersFilterAdministratorsBuilder { inner }
+        RTDSupergroupMembersFilterAdministratorsBuilder {
+            inner: SupergroupMembersFilterAdministrators::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDSupergroupMembersFilterAdministratorsBuilder {
+        RTDSupergroupMembersFilterAdministratorsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDSupergroupMembersFilterAdministratorsBuilder {
+        RTDSupergroupMembersFilterAdministratorsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDSupergroupMembersFilterAdministratorsBuilder {
     inner: SupergroupMembersFilterAdministrators,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDSupergroupMembersFilterAdministratorsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> SupergroupMembersFilterAdministrators {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<SupergroupMembersFilterAdministrators> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "SupergroupMembersFilterAdministrators",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<SupergroupMembersFilterAdministrators> for SupergroupMembersFilterAdministrators {
@@ -172,12 +206,13 @@ impl AsRef<SupergroupMembersFilterAdministrators>
 
 /// Returns users banned from the supergroup or channel; can be used only by administrators
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "supergroupMembersFilterBanned")]
 pub struct SupergroupMembersFilterBanned {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Query to search for
     query: String,
@@ -197,6 +232,20 @@ impl RObject for SupergroupMembersFilterBanned {
     }
 }
 
+impl PartialEq for SupergroupMembersFilterBanned {
+    fn eq(&self, other: &Self) -> bool {
+        self.query == other.query
+    }
+}
+
+impl Eq for SupergroupMembersFilterBanned {}
+
+impl std::hash::Hash for SupergroupMembersFilterBanned {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.query, state);
+    }
+}
+
 impl TDSupergroupMembersFilter for SupergroupMembersFilterBanned {}
 
 impl SupergroupMembersFilterBanned {
@@ -204,29 +253,66 @@ impl SupergroupMembersFilterBanned {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDSupergroupMembersFilterBannedBuilder {
-        let mut inner = SupergroupMembersFilterBanned::default();
-        inner.td_name = "supergroupMembersFilterBanned".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDSupergroupMembersFilterBannedBuilder { inner }
+        RTDSupergroupMembersFilterBannedBuilder {
+            inner: SupergroupMembersFilterBanned::default(),
+            missing: vec!["query"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDSupergroupMembersFilterBannedBuilder {
+        RTDSupergroupMembersFilterBannedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDSupergroupMembersFilterBannedBuilder {
+        RTDSupergroupMembersFilterBannedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn query(&self) -> &String {
         &self.query
     }
+
+    pub fn into_query(self) -> String {
+        self.query
+    }
+
+    pub fn with_query<T: AsRef<str>>(mut self, query: T) -> Self {
+        self.query = query.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDSupergroupMembersFilterBannedBuilder {
     inner: SupergroupMembersFilterBanned,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDSupergroupMembersFilterBannedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> SupergroupMembersFilterBanned {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<SupergroupMembersFilterBanned> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "SupergroupMembersFilterBanned",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn query<T: AsRef<str>>(&mut self, query: T) -> &mut Self {
         self.inner.query = query.as_ref().to_string();
+        self.missing.retain(|f| *f != "query");
         self
     }
 }
@@ -245,12 +331,13 @@ impl AsRef<SupergroupMembersFilterBanned> for RTDSupergroupMembersFilterBannedBu
 
 /// Returns bot members of the supergroup or channel
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "supergroupMembersFilterBots")]
 pub struct SupergroupMembersFilterBots {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -268,6 +355,18 @@ impl RObject for SupergroupMembersFilterBots {
     }
 }
 
+impl PartialEq for SupergroupMembersFilterBots {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for SupergroupMembersFilterBots {}
+
+impl std::hash::Hash for SupergroupMembersFilterBots {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDSupergroupMembersFilter for SupergroupMembersFilterBots {}
 
 impl SupergroupMembersFilterBots {
@@ -275,22 +374,49 @@ impl SupergroupMembersFilterBots {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDSupergroupMembersFilterBotsBuilder {
-        let mut inner = SupergroupMembersFilterBots::default();
-        inner.td_name = "supergroupMembersFilterBots".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDSupergroupMembersFilterBotsBuilder { inner }
+        RTDSupergroupMembersFilterBotsBuilder {
+            inner: SupergroupMembersFilterBots::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDSupergroupMembersFilterBotsBuilder {
+        RTDSupergroupMembersFilterBotsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDSupergroupMembersFilterBotsBuilder {
+        RTDSupergroupMembersFilterBotsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDSupergroupMembersFilterBotsBuilder {
     inner: SupergroupMembersFilterBots,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDSupergroupMembersFilterBotsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> SupergroupMembersFilterBots {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<SupergroupMembersFilterBots> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "SupergroupMembersFilterBots",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<SupergroupMembersFilterBots> for SupergroupMembersFilterBots {
@@ -307,12 +433,13 @@ impl AsRef<SupergroupMembersFilterBots> for RTDSupergroupMembersFilterBotsBuilde
 
 /// Returns contacts of the user, which are members of the supergroup or channel
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "supergroupMembersFilterContacts")]
 pub struct SupergroupMembersFilterContacts {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Query to search for
     query: String,
@@ -332,6 +459,20 @@ impl RObject for SupergroupMembersFilterContacts {
     }
 }
 
+impl PartialEq for SupergroupMembersFilterContacts {
+    fn eq(&self, other: &Self) -> bool {
+        self.query == other.query
+    }
+}
+
+impl Eq for SupergroupMembersFilterContacts {}
+
+impl std::hash::Hash for SupergroupMembersFilterContacts {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.query, state);
+    }
+}
+
 impl TDSupergroupMembersFilter for SupergroupMembersFilterContacts {}
 
 impl SupergroupMembersFilterContacts {
@@ -339,29 +480,66 @@ impl SupergroupMembersFilterContacts {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDSupergroupMembersFilterContactsBuilder {
-        let mut inner = SupergroupMembersFilterContacts::default();
-        inner.td_name = "supergroupMembersFilterContacts".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDSupergroupMembersFilterContactsBuilder { inner }
+        RTDSupergroupMembersFilterContactsBuilder {
+            inner: SupergroupMembersFilterContacts::default(),
+            missing: vec!["query"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDSupergroupMembersFilterContactsBuilder {
+        RTDSupergroupMembersFilterContactsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDSupergroupMembersFilterContactsBuilder {
+        RTDSupergroupMembersFilterContactsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn query(&self) -> &String {
         &self.query
     }
+
+    pub fn into_query(self) -> String {
+        self.query
+    }
+
+    pub fn with_query<T: AsRef<str>>(mut self, query: T) -> Self {
+        self.query = query.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDSupergroupMembersFilterContactsBuilder {
     inner: SupergroupMembersFilterContacts,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDSupergroupMembersFilterContactsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> SupergroupMembersFilterContacts {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<SupergroupMembersFilterContacts> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "SupergroupMembersFilterContacts",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn query<T: AsRef<str>>(&mut self, query: T) -> &mut Self {
         self.inner.query = query.as_ref().to_string();
+        self.missing.retain(|f| *f != "query");
         self
     }
 }
@@ -380,12 +558,13 @@ impl AsRef<SupergroupMembersFilterContacts> for RTDSupergroupMembersFilterContac
 
 /// Returns recently active users in reverse chronological order
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "supergroupMembersFilterRecent")]
 pub struct SupergroupMembersFilterRecent {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -403,6 +582,18 @@ impl RObject for SupergroupMembersFilterRecent {
     }
 }
 
+impl PartialEq for SupergroupMembersFilterRecent {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for SupergroupMembersFilterRecent {}
+
+impl std::hash::Hash for SupergroupMembersFilterRecent {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDSupergroupMembersFilter for SupergroupMembersFilterRecent {}
 
 impl SupergroupMembersFilterRecent {
@@ -410,22 +601,49 @@ impl SupergroupMembersFilterRecent {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDSupergroupMembersFilterRecentBuilder {
-        let mut inner = SupergroupMembersFilterRecent::default();
-        inner.td_name = "supergroupMembersFilterRecent".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDSupergroupMembersFilterRecentBuilder { inner }
+        RTDSupergroupMembersFilterRecentBuilder {
+            inner: SupergroupMembersFilterRecent::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDSupergroupMembersFilterRecentBuilder {
+        RTDSupergroupMembersFilterRecentBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDSupergroupMembersFilterRecentBuilder {
+        RTDSupergroupMembersFilterRecentBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDSupergroupMembersFilterRecentBuilder {
     inner: SupergroupMembersFilterRecent,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDSupergroupMembersFilterRecentBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> SupergroupMembersFilterRecent {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<SupergroupMembersFilterRecent> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "SupergroupMembersFilterRecent",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<SupergroupMembersFilterRecent> for SupergroupMembersFilterRecent {
@@ -442,12 +660,13 @@ impl AsRef<SupergroupMembersFilterRecent> for RTDSupergroupMembersFilterRecentBu
 
 /// Returns restricted supergroup members; can be used only by administrators
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "supergroupMembersFilterRestricted")]
 pub struct SupergroupMembersFilterRestricted {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Query to search for
     query: String,
@@ -467,6 +686,20 @@ impl RObject for SupergroupMembersFilterRestricted {
     }
 }
 
+impl PartialEq for SupergroupMembersFilterRestricted {
+    fn eq(&self, other: &Self) -> bool {
+        self.query == other.query
+    }
+}
+
+impl Eq for SupergroupMembersFilterRestricted {}
+
+impl std::hash::Hash for SupergroupMembersFilterRestricted {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.query, state);
+    }
+}
+
 impl TDSupergroupMembersFilter for SupergroupMembersFilterRestricted {}
 
 impl SupergroupMembersFilterRestricted {
@@ -474,29 +707,66 @@ impl SupergroupMembersFilterRestricted {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDSupergroupMembersFilterRestrictedBuilder {
-        let mut inner = SupergroupMembersFilterRestricted::default();
-        inner.td_name = "supergroupMembersFilterRestricted".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDSupergroupMembersFilterRestrictedBuilder { inner }
+        RTDSupergroupMembersFilterRestrictedBuilder {
+            inner: SupergroupMembersFilterRestricted::default(),
+            missing: vec!["query"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDSupergroupMembersFilterRestrictedBuilder {
+        RTDSupergroupMembersFilterRestrictedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDSupergroupMembersFilterRestrictedBuilder {
+        RTDSupergroupMembersFilterRestrictedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn query(&self) -> &String {
         &self.query
     }
+
+    pub fn into_query(self) -> String {
+        self.query
+    }
+
+    pub fn with_query<T: AsRef<str>>(mut self, query: T) -> Self {
+        self.query = query.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDSupergroupMembersFilterRestrictedBuilder {
     inner: SupergroupMembersFilterRestricted,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDSupergroupMembersFilterRestrictedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> SupergroupMembersFilterRestricted {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<SupergroupMembersFilterRestricted> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "SupergroupMembersFilterRestricted",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn query<T: AsRef<str>>(&mut self, query: T) -> &mut Self {
         self.inner.query = query.as_ref().to_string();
+        self.missing.retain(|f| *f != "query");
         self
     }
 }
@@ -515,12 +785,13 @@ impl AsRef<SupergroupMembersFilterRestricted> for RTDSupergroupMembersFilterRest
 
 /// Used to search for supergroup or channel members via a (string) query
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "supergroupMembersFilterSearch")]
 pub struct SupergroupMembersFilterSearch {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Query to search for
     query: String,
@@ -540,6 +811,20 @@ impl RObject for SupergroupMembersFilterSearch {
     }
 }
 
+impl PartialEq for SupergroupMembersFilterSearch {
+    fn eq(&self, other: &Self) -> bool {
+        self.query == other.query
+    }
+}
+
+impl Eq for SupergroupMembersFilterSearch {}
+
+impl std::hash::Hash for SupergroupMembersFilterSearch {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.query, state);
+    }
+}
+
 impl TDSupergroupMembersFilter for SupergroupMembersFilterSearch {}
 
 impl SupergroupMembersFilterSearch {
@@ -547,29 +832,66 @@ impl SupergroupMembersFilterSearch {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDSupergroupMembersFilterSearchBuilder {
-        let mut inner = SupergroupMembersFilterSearch::default();
-        inner.td_name = "supergroupMembersFilterSearch".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDSupergroupMembersFilterSearchBuilder { inner }
+        RTDSupergroupMembersFilterSearchBuilder {
+            inner: SupergroupMembersFilterSearch::default(),
+            missing: vec!["query"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDSupergroupMembersFilterSearchBuilder {
+        RTDSupergroupMembersFilterSearchBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDSupergroupMembersFilterSearchBuilder {
+        RTDSupergroupMembersFilterSearchBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn query(&self) -> &String {
         &self.query
     }
+
+    pub fn into_query(self) -> String {
+        self.query
+    }
+
+    pub fn with_query<T: AsRef<str>>(mut self, query: T) -> Self {
+        self.query = query.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDSupergroupMembersFilterSearchBuilder {
     inner: SupergroupMembersFilterSearch,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDSupergroupMembersFilterSearchBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> SupergroupMembersFilterSearch {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<SupergroupMembersFilterSearch> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "SupergroupMembersFilterSearch",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn query<T: AsRef<str>>(&mut self, query: T) -> &mut Self {
         self.inner.query = query.as_ref().to_string();
+        self.missing.retain(|f| *f != "query");
         self
     }
 }
@@ -585,3 +907,42 @@ impl AsRef<SupergroupMembersFilterSearch> for RTDSupergroupMembersFilterSearchBu
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(SupergroupMembersFilter::Administrators(
+            SupergroupMembersFilterAdministrators::builder().build(),
+        ));
+        assert_round_trip(SupergroupMembersFilter::Banned(
+            SupergroupMembersFilterBanned::builder()
+                .query("text")
+                .build(),
+        ));
+        assert_round_trip(SupergroupMembersFilter::Bots(
+            SupergroupMembersFilterBots::builder().build(),
+        ));
+        assert_round_trip(SupergroupMembersFilter::Contacts(
+            SupergroupMembersFilterContacts::builder()
+                .query("text")
+                .build(),
+        ));
+        assert_round_trip(SupergroupMembersFilter::Recent(
+            SupergroupMembersFilterRecent::builder().build(),
+        ));
+        assert_round_trip(SupergroupMembersFilter::Restricted(
+            SupergroupMembersFilterRestricted::builder()
+                .query("text")
+                .build(),
+        ));
+        assert_round_trip(SupergroupMembersFilter::Search(
+            SupergroupMembersFilterSearch::builder()
+                .query("text")
+                .build(),
+        ));
+    }
+}
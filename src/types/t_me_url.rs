@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Represents a URL linking to an internal Telegram entity
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "tMeUrl")]
 pub struct TMeUrl {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// URL
     url: String,
@@ -32,15 +32,44 @@ impl RObject for TMeUrl {
     }
 }
 
+impl PartialEq for TMeUrl {
+    fn eq(&self, other: &Self) -> bool {
+        self.url == other.url && self.type_ == other.type_
+    }
+}
+
+impl Eq for TMeUrl {}
+
+impl std::hash::Hash for TMeUrl {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.url, state);
+        std::hash::Hash::hash(&self.type_, state);
+    }
+}
+
 impl TMeUrl {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDTMeUrlBuilder {
-        let mut inner = TMeUrl::default();
-        inner.td_name = "tMeUrl".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDTMeUrlBuilder { inner }
+        RTDTMeUrlBuilder {
+            inner: TMeUrl::default(),
+            missing: vec!["url", "type_"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDTMeUrlBuilder {
+        RTDTMeUrlBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDTMeUrlBuilder {
+        RTDTMeUrlBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn url(&self) -> &String {
@@ -50,25 +79,55 @@ impl TMeUrl {
     pub fn type_(&self) -> &TMeUrlType {
         &self.type_
     }
+
+    pub fn into_url(self) -> String {
+        self.url
+    }
+
+    pub fn into_type(self) -> TMeUrlType {
+        self.type_
+    }
+
+    pub fn with_url<T: AsRef<str>>(mut self, url: T) -> Self {
+        self.url = url.as_ref().to_string();
+        self
+    }
+
+    pub fn with_type<T: AsRef<TMeUrlType>>(mut self, type_: T) -> Self {
+        self.type_ = type_.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDTMeUrlBuilder {
     inner: TMeUrl,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDTMeUrlBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> TMeUrl {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<TMeUrl> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("TMeUrl", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
         self.inner.url = url.as_ref().to_string();
+        self.missing.retain(|f| *f != "url");
         self
     }
 
     pub fn type_<T: AsRef<TMeUrlType>>(&mut self, type_: T) -> &mut Self {
         self.inner.type_ = type_.as_ref().clone();
+        self.missing.retain(|f| *f != "type_");
         self
     }
 }
@@ -84,3 +143,23 @@ impl AsRef<TMeUrl> for RTDTMeUrlBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            TMeUrl::builder()
+                .url("text")
+                .type_(TMeUrlType::StickerSet(
+                    TMeUrlTypeStickerSet::builder()
+                        .sticker_set_id(i64::MAX)
+                        .build(),
+                ))
+                .build(),
+        );
+    }
+}
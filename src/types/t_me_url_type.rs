@@ -1,49 +1,48 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Describes the type of a URL linking to an internal Telegram entity
 pub trait TDTMeUrlType: Debug + RObject {}
 
 /// Describes the type of a URL linking to an internal Telegram entity
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum TMeUrlType {
-    #[doc(hidden)]
-    _Default(()),
     /// A chat invite link
+    #[serde(rename = "tMeUrlTypeChatInvite")]
     ChatInvite(TMeUrlTypeChatInvite),
     /// A URL linking to a sticker set
+    #[serde(rename = "tMeUrlTypeStickerSet")]
     StickerSet(TMeUrlTypeStickerSet),
     /// A URL linking to a public supergroup or channel
+    #[serde(rename = "tMeUrlTypeSupergroup")]
     Supergroup(TMeUrlTypeSupergroup),
     /// A URL linking to a user
+    #[serde(rename = "tMeUrlTypeUser")]
     User(TMeUrlTypeUser),
 }
 
+/// Default value is default of [TMeUrlTypeStickerSet], builders still require the field to be set
 impl Default for TMeUrlType {
     fn default() -> Self {
-        TMeUrlType::_Default(())
+        TMeUrlType::StickerSet(TMeUrlTypeStickerSet::default())
     }
 }
 
-impl<'de> Deserialize<'de> for TMeUrlType {
-    fn deserialize<D>(deserializer: D) -> Result<TMeUrlType, D::Error>
+impl Serialize for TMeUrlType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          TMeUrlType,
-          (tMeUrlTypeChatInvite, ChatInvite);
-          (tMeUrlTypeStickerSet, StickerSet);
-          (tMeUrlTypeSupergroup, Supergroup);
-          (tMeUrlTypeUser, User);
-
-        )(deserializer)
+        match self {
+            TMeUrlType::ChatInvite(t) => t.serialize(serializer),
+            TMeUrlType::StickerSet(t) => t.serialize(serializer),
+            TMeUrlType::Supergroup(t) => t.serialize(serializer),
+            TMeUrlType::User(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -55,8 +54,6 @@ impl RObject for TMeUrlType {
             TMeUrlType::StickerSet(t) => t.td_name(),
             TMeUrlType::Supergroup(t) => t.td_name(),
             TMeUrlType::User(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -66,8 +63,6 @@ impl RObject for TMeUrlType {
             TMeUrlType::StickerSet(t) => t.extra(),
             TMeUrlType::Supergroup(t) => t.extra(),
             TMeUrlType::User(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -79,10 +74,6 @@ impl TMeUrlType {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, TMeUrlType::_Default(_))
-    }
 }
 
 impl AsRef<TMeUrlType> for TMeUrlType {
@@ -93,12 +84,13 @@ impl AsRef<TMeUrlType> for TMeUrlType {
 
 /// A chat invite link
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "tMeUrlTypeChatInvite")]
 pub struct TMeUrlTypeChatInvite {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Chat invite link info
     info: ChatInviteLinkInfo,
@@ -118,6 +110,20 @@ impl RObject for TMeUrlTypeChatInvite {
     }
 }
 
+impl PartialEq for TMeUrlTypeChatInvite {
+    fn eq(&self, other: &Self) -> bool {
+        self.info == other.info
+    }
+}
+
+impl Eq for TMeUrlTypeChatInvite {}
+
+impl std::hash::Hash for TMeUrlTypeChatInvite {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.info, state);
+    }
+}
+
 impl TDTMeUrlType for TMeUrlTypeChatInvite {}
 
 impl TMeUrlTypeChatInvite {
@@ -125,29 +131,66 @@ impl TMeUrlTypeChatInvite {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDTMeUrlTypeChatInviteBuilder {
-        let mut inner = TMeUrlTypeChatInvite::default();
-        inner.td_name = "tMeUrlTypeChatInvite".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDTMeUrlTypeChatInviteBuilder { inner }
+        RTDTMeUrlTypeChatInviteBuilder {
+            inner: TMeUrlTypeChatInvite::default(),
+            missing: vec!["info"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDTMeUrlTypeChatInviteBuilder {
+        RTDTMeUrlTypeChatInviteBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDTMeUrlTypeChatInviteBuilder {
+        RTDTMeUrlTypeChatInviteBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn info(&self) -> &ChatInviteLinkInfo {
         &self.info
     }
+
+    pub fn into_info(self) -> ChatInviteLinkInfo {
+        self.info
+    }
+
+    pub fn with_info<T: AsRef<ChatInviteLinkInfo>>(mut self, info: T) -> Self {
+        self.info = info.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDTMeUrlTypeChatInviteBuilder {
     inner: TMeUrlTypeChatInvite,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDTMeUrlTypeChatInviteBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> TMeUrlTypeChatInvite {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<TMeUrlTypeChatInvite> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "TMeUrlTypeChatInvite",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn info<T: AsRef<ChatInviteLinkInfo>>(&mut self, info: T) -> &mut Self {
         self.inner.info = info.as_ref().clone();
+        self.missing.retain(|f| *f != "info");
         self
     }
 }
@@ -166,15 +209,17 @@ impl AsRef<TMeUrlTypeChatInvite> for RTDTMeUrlTypeChatInviteBuilder {
 
 /// A URL linking to a sticker set
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "tMeUrlTypeStickerSet")]
 pub struct TMeUrlTypeStickerSet {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Identifier of the sticker set
-    sticker_set_id: isize,
+    #[serde(with = "super::_common::int64")]
+    sticker_set_id: i64,
 }
 
 impl RObject for TMeUrlTypeStickerSet {
@@ -191,6 +236,20 @@ impl RObject for TMeUrlTypeStickerSet {
     }
 }
 
+impl PartialEq for TMeUrlTypeStickerSet {
+    fn eq(&self, other: &Self) -> bool {
+        self.sticker_set_id == other.sticker_set_id
+    }
+}
+
+impl Eq for TMeUrlTypeStickerSet {}
+
+impl std::hash::Hash for TMeUrlTypeStickerSet {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.sticker_set_id, state);
+    }
+}
+
 impl TDTMeUrlType for TMeUrlTypeStickerSet {}
 
 impl TMeUrlTypeStickerSet {
@@ -198,29 +257,62 @@ impl TMeUrlTypeStickerSet {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDTMeUrlTypeStickerSetBuilder {
-        let mut inner = TMeUrlTypeStickerSet::default();
-        inner.td_name = "tMeUrlTypeStickerSet".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDTMeUrlTypeStickerSetBuilder { inner }
+        RTDTMeUrlTypeStickerSetBuilder {
+            inner: TMeUrlTypeStickerSet::default(),
+            missing: vec!["sticker_set_id"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDTMeUrlTypeStickerSetBuilder {
+        RTDTMeUrlTypeStickerSetBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDTMeUrlTypeStickerSetBuilder {
+        RTDTMeUrlTypeStickerSetBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn sticker_set_id(&self) -> isize {
+    pub fn sticker_set_id(&self) -> i64 {
         self.sticker_set_id
     }
+
+    pub fn with_sticker_set_id(mut self, sticker_set_id: i64) -> Self {
+        self.sticker_set_id = sticker_set_id;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDTMeUrlTypeStickerSetBuilder {
     inner: TMeUrlTypeStickerSet,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDTMeUrlTypeStickerSetBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> TMeUrlTypeStickerSet {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<TMeUrlTypeStickerSet> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "TMeUrlTypeStickerSet",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn sticker_set_id(&mut self, sticker_set_id: isize) -> &mut Self {
+    pub fn sticker_set_id(&mut self, sticker_set_id: i64) -> &mut Self {
         self.inner.sticker_set_id = sticker_set_id;
+        self.missing.retain(|f| *f != "sticker_set_id");
         self
     }
 }
@@ -239,12 +331,13 @@ impl AsRef<TMeUrlTypeStickerSet> for RTDTMeUrlTypeStickerSetBuilder {
 
 /// A URL linking to a public supergroup or channel
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "tMeUrlTypeSupergroup")]
 pub struct TMeUrlTypeSupergroup {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Identifier of the supergroup or channel
     supergroup_id: i64,
@@ -264,6 +357,20 @@ impl RObject for TMeUrlTypeSupergroup {
     }
 }
 
+impl PartialEq for TMeUrlTypeSupergroup {
+    fn eq(&self, other: &Self) -> bool {
+        self.supergroup_id == other.supergroup_id
+    }
+}
+
+impl Eq for TMeUrlTypeSupergroup {}
+
+impl std::hash::Hash for TMeUrlTypeSupergroup {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.supergroup_id, state);
+    }
+}
+
 impl TDTMeUrlType for TMeUrlTypeSupergroup {}
 
 impl TMeUrlTypeSupergroup {
@@ -271,29 +378,62 @@ impl TMeUrlTypeSupergroup {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDTMeUrlTypeSupergroupBuilder {
-        let mut inner = TMeUrlTypeSupergroup::default();
-        inner.td_name = "tMeUrlTypeSupergroup".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDTMeUrlTypeSupergroupBuilder { inner }
+        RTDTMeUrlTypeSupergroupBuilder {
+            inner: TMeUrlTypeSupergroup::default(),
+            missing: vec!["supergroup_id"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDTMeUrlTypeSupergroupBuilder {
+        RTDTMeUrlTypeSupergroupBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDTMeUrlTypeSupergroupBuilder {
+        RTDTMeUrlTypeSupergroupBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn supergroup_id(&self) -> i64 {
         self.supergroup_id
     }
+
+    pub fn with_supergroup_id(mut self, supergroup_id: i64) -> Self {
+        self.supergroup_id = supergroup_id;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDTMeUrlTypeSupergroupBuilder {
     inner: TMeUrlTypeSupergroup,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDTMeUrlTypeSupergroupBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> TMeUrlTypeSupergroup {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<TMeUrlTypeSupergroup> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "TMeUrlTypeSupergroup",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn supergroup_id(&mut self, supergroup_id: i64) -> &mut Self {
         self.inner.supergroup_id = supergroup_id;
+        self.missing.retain(|f| *f != "supergroup_id");
         self
     }
 }
@@ -312,15 +452,16 @@ impl AsRef<TMeUrlTypeSupergroup> for RTDTMeUrlTypeSupergroupBuilder {
 
 /// A URL linking to a user
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "tMeUrlTypeUser")]
 pub struct TMeUrlTypeUser {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Identifier of the user
-    user_id: i64,
+    user_id: UserId,
 }
 
 impl RObject for TMeUrlTypeUser {
@@ -337,6 +478,20 @@ impl RObject for TMeUrlTypeUser {
     }
 }
 
+impl PartialEq for TMeUrlTypeUser {
+    fn eq(&self, other: &Self) -> bool {
+        self.user_id == other.user_id
+    }
+}
+
+impl Eq for TMeUrlTypeUser {}
+
+impl std::hash::Hash for TMeUrlTypeUser {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.user_id, state);
+    }
+}
+
 impl TDTMeUrlType for TMeUrlTypeUser {}
 
 impl TMeUrlTypeUser {
@@ -344,29 +499,62 @@ impl TMeUrlTypeUser {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDTMeUrlTypeUserBuilder {
-        let mut inner = TMeUrlTypeUser::default();
-        inner.td_name = "tMeUrlTypeUser".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDTMeUrlTypeUserBuilder { inner }
+        RTDTMeUrlTypeUserBuilder {
+            inner: TMeUrlTypeUser::default(),
+            missing: vec!["user_id"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDTMeUrlTypeUserBuilder {
+        RTDTMeUrlTypeUserBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDTMeUrlTypeUserBuilder {
+        RTDTMeUrlTypeUserBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn user_id(&self) -> i64 {
+    pub fn user_id(&self) -> UserId {
         self.user_id
     }
+
+    pub fn with_user_id<T: Into<UserId>>(mut self, user_id: T) -> Self {
+        self.user_id = user_id.into();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDTMeUrlTypeUserBuilder {
     inner: TMeUrlTypeUser,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDTMeUrlTypeUserBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> TMeUrlTypeUser {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<TMeUrlTypeUser> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "TMeUrlTypeUser",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn user_id(&mut self, user_id: i64) -> &mut Self {
-        self.inner.user_id = user_id;
+    pub fn user_id<T: Into<UserId>>(&mut self, user_id: T) -> &mut Self {
+        self.inner.user_id = user_id.into();
+        self.missing.retain(|f| *f != "user_id");
         self
     }
 }
@@ -382,3 +570,44 @@ impl AsRef<TMeUrlTypeUser> for RTDTMeUrlTypeUserBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(TMeUrlType::ChatInvite(
+            TMeUrlTypeChatInvite::builder()
+                .info(
+                    ChatInviteLinkInfo::builder()
+                        .chat_id(9007199254740991i64)
+                        .type_(ChatType::BasicGroup(
+                            ChatTypeBasicGroup::builder()
+                                .basic_group_id(i32::MAX)
+                                .build(),
+                        ))
+                        .title("text")
+                        .member_count(i32::MAX)
+                        .member_user_ids(vec![Default::default()])
+                        .is_public(true)
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(TMeUrlType::StickerSet(
+            TMeUrlTypeStickerSet::builder()
+                .sticker_set_id(i64::MAX)
+                .build(),
+        ));
+        assert_round_trip(TMeUrlType::Supergroup(
+            TMeUrlTypeSupergroup::builder()
+                .supergroup_id(9007199254740991i64)
+                .build(),
+        ));
+        assert_round_trip(TMeUrlType::User(
+            TMeUrlTypeUser::builder().user_id(i32::MAX).build(),
+        ));
+    }
+}
@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains a list of t.me URLs
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "tMeUrls")]
 pub struct TMeUrls {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// List of URLs
     urls: Vec<TMeUrl>,
@@ -29,34 +29,82 @@ impl RObject for TMeUrls {
     }
 }
 
+impl PartialEq for TMeUrls {
+    fn eq(&self, other: &Self) -> bool {
+        self.urls == other.urls
+    }
+}
+
+impl Eq for TMeUrls {}
+
+impl std::hash::Hash for TMeUrls {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.urls, state);
+    }
+}
+
 impl TMeUrls {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDTMeUrlsBuilder {
-        let mut inner = TMeUrls::default();
-        inner.td_name = "tMeUrls".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDTMeUrlsBuilder { inner }
+        RTDTMeUrlsBuilder {
+            inner: TMeUrls::default(),
+            missing: vec!["urls"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDTMeUrlsBuilder {
+        RTDTMeUrlsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDTMeUrlsBuilder {
+        RTDTMeUrlsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn urls(&self) -> &Vec<TMeUrl> {
         &self.urls
     }
+
+    pub fn into_urls(self) -> Vec<TMeUrl> {
+        self.urls
+    }
+
+    pub fn with_urls(mut self, urls: Vec<TMeUrl>) -> Self {
+        self.urls = urls;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDTMeUrlsBuilder {
     inner: TMeUrls,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDTMeUrlsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> TMeUrls {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<TMeUrls> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("TMeUrls", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn urls(&mut self, urls: Vec<TMeUrl>) -> &mut Self {
         self.inner.urls = urls;
+        self.missing.retain(|f| *f != "urls");
         self
     }
 }
@@ -72,3 +120,14 @@ impl AsRef<TMeUrls> for RTDTMeUrlsBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(TMeUrls::builder().urls(vec![]).build());
+    }
+}
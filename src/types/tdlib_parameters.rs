@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains parameters for TDLib initialization
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "tdlibParameters")]
 pub struct TdlibParameters {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// If set to true, the Telegram test environment will be used instead of the production environment
     use_test_dc: bool,
@@ -26,7 +26,7 @@ pub struct TdlibParameters {
     /// If set to true, support for secret chats will be enabled
     use_secret_chats: bool,
     /// Application identifier for Telegram API access, which can be obtained at https://my.telegram.org
-    api_id: i64,
+    api_id: i32,
     /// Application identifier hash for Telegram API access, which can be obtained at https://my.telegram.org
     api_hash: String,
     /// IETF language tag of the user's operating system language; must be non-empty
@@ -57,15 +57,78 @@ impl RObject for TdlibParameters {
     }
 }
 
+impl PartialEq for TdlibParameters {
+    fn eq(&self, other: &Self) -> bool {
+        self.use_test_dc == other.use_test_dc
+            && self.database_directory == other.database_directory
+            && self.files_directory == other.files_directory
+            && self.use_file_database == other.use_file_database
+            && self.use_chat_info_database == other.use_chat_info_database
+            && self.use_message_database == other.use_message_database
+            && self.use_secret_chats == other.use_secret_chats
+            && self.api_id == other.api_id
+            && self.api_hash == other.api_hash
+            && self.system_language_code == other.system_language_code
+            && self.device_model == other.device_model
+            && self.system_version == other.system_version
+            && self.application_version == other.application_version
+            && self.enable_storage_optimizer == other.enable_storage_optimizer
+            && self.ignore_file_names == other.ignore_file_names
+    }
+}
+
+impl Eq for TdlibParameters {}
+
+impl std::hash::Hash for TdlibParameters {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.use_test_dc, state);
+        std::hash::Hash::hash(&self.database_directory, state);
+        std::hash::Hash::hash(&self.files_directory, state);
+        std::hash::Hash::hash(&self.use_file_database, state);
+        std::hash::Hash::hash(&self.use_chat_info_database, state);
+        std::hash::Hash::hash(&self.use_message_database, state);
+        std::hash::Hash::hash(&self.use_secret_chats, state);
+        std::hash::Hash::hash(&self.api_id, state);
+        std::hash::Hash::hash(&self.api_hash, state);
+        std::hash::Hash::hash(&self.system_language_code, state);
+        std::hash::Hash::hash(&self.device_model, state);
+        std::hash::Hash::hash(&self.system_version, state);
+        std::hash::Hash::hash(&self.application_version, state);
+        std::hash::Hash::hash(&self.enable_storage_optimizer, state);
+        std::hash::Hash::hash(&self.ignore_file_names, state);
+    }
+}
+
 impl TdlibParameters {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDTdlibParametersBuilder {
-        let mut inner = TdlibParameters::default();
-        inner.td_name = "tdlibParameters".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDTdlibParametersBuilder { inner }
+        RTDTdlibParametersBuilder {
+            inner: TdlibParameters::default(),
+            missing: vec![
+                "api_id",
+                "api_hash",
+                "system_language_code",
+                "device_model",
+                "system_version",
+                "application_version",
+            ],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDTdlibParametersBuilder {
+        RTDTdlibParametersBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDTdlibParametersBuilder {
+        RTDTdlibParametersBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn use_test_dc(&self) -> bool {
@@ -96,7 +159,7 @@ impl TdlibParameters {
         self.use_secret_chats
     }
 
-    pub fn api_id(&self) -> i64 {
+    pub fn api_id(&self) -> i32 {
         self.api_id
     }
 
@@ -127,17 +190,133 @@ impl TdlibParameters {
     pub fn ignore_file_names(&self) -> bool {
         self.ignore_file_names
     }
+
+    pub fn into_database_directory(self) -> String {
+        self.database_directory
+    }
+
+    pub fn into_files_directory(self) -> String {
+        self.files_directory
+    }
+
+    pub fn into_api_hash(self) -> String {
+        self.api_hash
+    }
+
+    pub fn into_system_language_code(self) -> String {
+        self.system_language_code
+    }
+
+    pub fn into_device_model(self) -> String {
+        self.device_model
+    }
+
+    pub fn into_system_version(self) -> String {
+        self.system_version
+    }
+
+    pub fn into_application_version(self) -> String {
+        self.application_version
+    }
+
+    pub fn with_use_test_dc(mut self, use_test_dc: bool) -> Self {
+        self.use_test_dc = use_test_dc;
+        self
+    }
+
+    pub fn with_database_directory<T: AsRef<str>>(mut self, database_directory: T) -> Self {
+        self.database_directory = database_directory.as_ref().to_string();
+        self
+    }
+
+    pub fn with_files_directory<T: AsRef<str>>(mut self, files_directory: T) -> Self {
+        self.files_directory = files_directory.as_ref().to_string();
+        self
+    }
+
+    pub fn with_use_file_database(mut self, use_file_database: bool) -> Self {
+        self.use_file_database = use_file_database;
+        self
+    }
+
+    pub fn with_use_chat_info_database(mut self, use_chat_info_database: bool) -> Self {
+        self.use_chat_info_database = use_chat_info_database;
+        self
+    }
+
+    pub fn with_use_message_database(mut self, use_message_database: bool) -> Self {
+        self.use_message_database = use_message_database;
+        self
+    }
+
+    pub fn with_use_secret_chats(mut self, use_secret_chats: bool) -> Self {
+        self.use_secret_chats = use_secret_chats;
+        self
+    }
+
+    pub fn with_api_id(mut self, api_id: i32) -> Self {
+        self.api_id = api_id;
+        self
+    }
+
+    pub fn with_api_hash<T: AsRef<str>>(mut self, api_hash: T) -> Self {
+        self.api_hash = api_hash.as_ref().to_string();
+        self
+    }
+
+    pub fn with_system_language_code<T: AsRef<str>>(mut self, system_language_code: T) -> Self {
+        self.system_language_code = system_language_code.as_ref().to_string();
+        self
+    }
+
+    pub fn with_device_model<T: AsRef<str>>(mut self, device_model: T) -> Self {
+        self.device_model = device_model.as_ref().to_string();
+        self
+    }
+
+    pub fn with_system_version<T: AsRef<str>>(mut self, system_version: T) -> Self {
+        self.system_version = system_version.as_ref().to_string();
+        self
+    }
+
+    pub fn with_application_version<T: AsRef<str>>(mut self, application_version: T) -> Self {
+        self.application_version = application_version.as_ref().to_string();
+        self
+    }
+
+    pub fn with_enable_storage_optimizer(mut self, enable_storage_optimizer: bool) -> Self {
+        self.enable_storage_optimizer = enable_storage_optimizer;
+        self
+    }
+
+    pub fn with_ignore_file_names(mut self, ignore_file_names: bool) -> Self {
+        self.ignore_file_names = ignore_file_names;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDTdlibParametersBuilder {
     inner: TdlibParameters,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDTdlibParametersBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> TdlibParameters {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<TdlibParameters> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "TdlibParameters",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn use_test_dc(&mut self, use_test_dc: bool) -> &mut Self {
         self.inner.use_test_dc = use_test_dc;
@@ -174,33 +353,39 @@ impl RTDTdlibParametersBuilder {
         self
     }
 
-    pub fn api_id(&mut self, api_id: i64) -> &mut Self {
+    pub fn api_id(&mut self, api_id: i32) -> &mut Self {
         self.inner.api_id = api_id;
+        self.missing.retain(|f| *f != "api_id");
         self
     }
 
     pub fn api_hash<T: AsRef<str>>(&mut self, api_hash: T) -> &mut Self {
         self.inner.api_hash = api_hash.as_ref().to_string();
+        self.missing.retain(|f| *f != "api_hash");
         self
     }
 
     pub fn system_language_code<T: AsRef<str>>(&mut self, system_language_code: T) -> &mut Self {
         self.inner.system_language_code = system_language_code.as_ref().to_string();
+        self.missing.retain(|f| *f != "system_language_code");
         self
     }
 
     pub fn device_model<T: AsRef<str>>(&mut self, device_model: T) -> &mut Self {
         self.inner.device_model = device_model.as_ref().to_string();
+        self.missing.retain(|f| *f != "device_model");
         self
     }
 
     pub fn system_version<T: AsRef<str>>(&mut self, system_version: T) -> &mut Self {
         self.inner.system_version = system_version.as_ref().to_string();
+        self.missing.retain(|f| *f != "system_version");
         self
     }
 
     pub fn application_version<T: AsRef<str>>(&mut self, application_version: T) -> &mut Self {
         self.inner.application_version = application_version.as_ref().to_string();
+        self.missing.retain(|f| *f != "application_version");
         self
     }
 
@@ -226,3 +411,32 @@ impl AsRef<TdlibParameters> for RTDTdlibParametersBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            TdlibParameters::builder()
+                .use_test_dc(true)
+                .database_directory("text")
+                .files_directory("text")
+                .use_file_database(true)
+                .use_chat_info_database(true)
+                .use_message_database(true)
+                .use_secret_chats(true)
+                .api_id(i32::MAX)
+                .api_hash("text")
+                .system_language_code("text")
+                .device_model("text")
+                .system_version("text")
+                .application_version("text")
+                .enable_storage_optimizer(true)
+                .ignore_file_names(true)
+                .build(),
+        );
+    }
+}
@@ -1,20 +1,20 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Returns information about the availability of a temporary password, which can be used for payments
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "temporaryPasswordState")]
 pub struct TemporaryPasswordState {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// True, if a temporary password is available
     has_password: bool,
     /// Time left before the temporary password expires, in seconds
-    valid_for: i64,
+    valid_for: i32,
 }
 
 impl RObject for TemporaryPasswordState {
@@ -31,43 +31,96 @@ impl RObject for TemporaryPasswordState {
     }
 }
 
+impl PartialEq for TemporaryPasswordState {
+    fn eq(&self, other: &Self) -> bool {
+        self.has_password == other.has_password && self.valid_for == other.valid_for
+    }
+}
+
+impl Eq for TemporaryPasswordState {}
+
+impl std::hash::Hash for TemporaryPasswordState {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.has_password, state);
+        std::hash::Hash::hash(&self.valid_for, state);
+    }
+}
+
 impl TemporaryPasswordState {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDTemporaryPasswordStateBuilder {
-        let mut inner = TemporaryPasswordState::default();
-        inner.td_name = "temporaryPasswordState".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDTemporaryPasswordStateBuilder { inner }
+        RTDTemporaryPasswordStateBuilder {
+            inner: TemporaryPasswordState::default(),
+            missing: vec!["valid_for"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDTemporaryPasswordStateBuilder {
+        RTDTemporaryPasswordStateBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDTemporaryPasswordStateBuilder {
+        RTDTemporaryPasswordStateBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn has_password(&self) -> bool {
         self.has_password
     }
 
-    pub fn valid_for(&self) -> i64 {
+    pub fn valid_for(&self) -> i32 {
         self.valid_for
     }
+
+    pub fn with_has_password(mut self, has_password: bool) -> Self {
+        self.has_password = has_password;
+        self
+    }
+
+    pub fn with_valid_for(mut self, valid_for: i32) -> Self {
+        self.valid_for = valid_for;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDTemporaryPasswordStateBuilder {
     inner: TemporaryPasswordState,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDTemporaryPasswordStateBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> TemporaryPasswordState {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<TemporaryPasswordState> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "TemporaryPasswordState",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn has_password(&mut self, has_password: bool) -> &mut Self {
         self.inner.has_password = has_password;
         self
     }
 
-    pub fn valid_for(&mut self, valid_for: i64) -> &mut Self {
+    pub fn valid_for(&mut self, valid_for: i32) -> &mut Self {
         self.inner.valid_for = valid_for;
+        self.missing.retain(|f| *f != "valid_for");
         self
     }
 }
@@ -83,3 +136,19 @@ impl AsRef<TemporaryPasswordState> for RTDTemporaryPasswordStateBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            TemporaryPasswordState::builder()
+                .has_password(true)
+                .valid_for(i32::MAX)
+                .build(),
+        );
+    }
+}
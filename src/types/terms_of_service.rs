@@ -1,20 +1,20 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains Telegram terms of service
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "termsOfService")]
 pub struct TermsOfService {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Text of the terms of service
     text: FormattedText,
     /// The minimum age of a user to be able to accept the terms; 0 if any
-    min_user_age: i64,
+    min_user_age: i32,
     /// True, if a blocking popup with terms of service must be shown to the user
     show_popup: bool,
 }
@@ -33,46 +33,111 @@ impl RObject for TermsOfService {
     }
 }
 
+impl PartialEq for TermsOfService {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text
+            && self.min_user_age == other.min_user_age
+            && self.show_popup == other.show_popup
+    }
+}
+
+impl Eq for TermsOfService {}
+
+impl std::hash::Hash for TermsOfService {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.text, state);
+        std::hash::Hash::hash(&self.min_user_age, state);
+        std::hash::Hash::hash(&self.show_popup, state);
+    }
+}
+
 impl TermsOfService {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDTermsOfServiceBuilder {
-        let mut inner = TermsOfService::default();
-        inner.td_name = "termsOfService".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDTermsOfServiceBuilder { inner }
+        RTDTermsOfServiceBuilder {
+            inner: TermsOfService::default(),
+            missing: vec!["text"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDTermsOfServiceBuilder {
+        RTDTermsOfServiceBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDTermsOfServiceBuilder {
+        RTDTermsOfServiceBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn text(&self) -> &FormattedText {
         &self.text
     }
 
-    pub fn min_user_age(&self) -> i64 {
+    pub fn min_user_age(&self) -> i32 {
         self.min_user_age
     }
 
     pub fn show_popup(&self) -> bool {
         self.show_popup
     }
+
+    pub fn into_text(self) -> FormattedText {
+        self.text
+    }
+
+    pub fn with_text<T: AsRef<FormattedText>>(mut self, text: T) -> Self {
+        self.text = text.as_ref().clone();
+        self
+    }
+
+    pub fn with_min_user_age(mut self, min_user_age: i32) -> Self {
+        self.min_user_age = min_user_age;
+        self
+    }
+
+    pub fn with_show_popup(mut self, show_popup: bool) -> Self {
+        self.show_popup = show_popup;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDTermsOfServiceBuilder {
     inner: TermsOfService,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDTermsOfServiceBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> TermsOfService {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<TermsOfService> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "TermsOfService",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn text<T: AsRef<FormattedText>>(&mut self, text: T) -> &mut Self {
         self.inner.text = text.as_ref().clone();
+        self.missing.retain(|f| *f != "text");
         self
     }
 
-    pub fn min_user_age(&mut self, min_user_age: i64) -> &mut Self {
+    pub fn min_user_age(&mut self, min_user_age: i32) -> &mut Self {
         self.inner.min_user_age = min_user_age;
         self
     }
@@ -94,3 +159,25 @@ impl AsRef<TermsOfService> for RTDTermsOfServiceBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            TermsOfService::builder()
+                .text(
+                    FormattedText::builder()
+                        .text("text")
+                        .entities(vec![])
+                        .build(),
+                )
+                .min_user_age(i32::MAX)
+                .show_popup(true)
+                .build(),
+        );
+    }
+}
@@ -1,18 +1,19 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// A simple object containing a sequence of bytes; for testing only
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "testBytes")]
 pub struct TestBytes {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Bytes
-    value: String,
+    #[serde(with = "super::_common::bytes")]
+    value: Vec<u8>,
 }
 
 impl RObject for TestBytes {
@@ -29,34 +30,82 @@ impl RObject for TestBytes {
     }
 }
 
+impl PartialEq for TestBytes {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for TestBytes {}
+
+impl std::hash::Hash for TestBytes {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.value, state);
+    }
+}
+
 impl TestBytes {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDTestBytesBuilder {
-        let mut inner = TestBytes::default();
-        inner.td_name = "testBytes".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDTestBytesBuilder { inner }
+        RTDTestBytesBuilder {
+            inner: TestBytes::default(),
+            missing: vec!["value"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDTestBytesBuilder {
+        RTDTestBytesBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDTestBytesBuilder {
+        RTDTestBytesBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn value(&self) -> &String {
+    pub fn value(&self) -> &[u8] {
         &self.value
     }
+
+    pub fn into_value(self) -> Vec<u8> {
+        self.value
+    }
+
+    pub fn with_value<T: AsRef<[u8]>>(mut self, value: T) -> Self {
+        self.value = value.as_ref().to_vec();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDTestBytesBuilder {
     inner: TestBytes,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDTestBytesBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> TestBytes {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<TestBytes> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("TestBytes", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn value<T: AsRef<str>>(&mut self, value: T) -> &mut Self {
-        self.inner.value = value.as_ref().to_string();
+    pub fn value<T: AsRef<[u8]>>(&mut self, value: T) -> &mut Self {
+        self.inner.value = value.as_ref().to_vec();
+        self.missing.retain(|f| *f != "value");
         self
     }
 }
@@ -72,3 +121,14 @@ impl AsRef<TestBytes> for RTDTestBytesBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(TestBytes::builder().value(b"\x00\x01\xfe\xff").build());
+    }
+}
@@ -1,18 +1,18 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// A simple object containing a number; for testing only
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "testInt")]
 pub struct TestInt {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Number
-    value: i64,
+    value: i32,
 }
 
 impl RObject for TestInt {
@@ -29,34 +29,78 @@ impl RObject for TestInt {
     }
 }
 
+impl PartialEq for TestInt {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for TestInt {}
+
+impl std::hash::Hash for TestInt {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.value, state);
+    }
+}
+
 impl TestInt {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDTestIntBuilder {
-        let mut inner = TestInt::default();
-        inner.td_name = "testInt".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDTestIntBuilder { inner }
+        RTDTestIntBuilder {
+            inner: TestInt::default(),
+            missing: vec!["value"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDTestIntBuilder {
+        RTDTestIntBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDTestIntBuilder {
+        RTDTestIntBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn value(&self) -> i64 {
+    pub fn value(&self) -> i32 {
         self.value
     }
+
+    pub fn with_value(mut self, value: i32) -> Self {
+        self.value = value;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDTestIntBuilder {
     inner: TestInt,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDTestIntBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> TestInt {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<TestInt> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("TestInt", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn value(&mut self, value: i64) -> &mut Self {
+    pub fn value(&mut self, value: i32) -> &mut Self {
         self.inner.value = value;
+        self.missing.retain(|f| *f != "value");
         self
     }
 }
@@ -72,3 +116,14 @@ impl AsRef<TestInt> for RTDTestIntBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(TestInt::builder().value(i32::MAX).build());
+    }
+}
@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// A simple object containing a string; for testing only
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "testString")]
 pub struct TestString {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// String
     value: String,
@@ -29,34 +29,82 @@ impl RObject for TestString {
     }
 }
 
+impl PartialEq for TestString {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for TestString {}
+
+impl std::hash::Hash for TestString {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.value, state);
+    }
+}
+
 impl TestString {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDTestStringBuilder {
-        let mut inner = TestString::default();
-        inner.td_name = "testString".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDTestStringBuilder { inner }
+        RTDTestStringBuilder {
+            inner: TestString::default(),
+            missing: vec!["value"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDTestStringBuilder {
+        RTDTestStringBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDTestStringBuilder {
+        RTDTestStringBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn value(&self) -> &String {
         &self.value
     }
+
+    pub fn into_value(self) -> String {
+        self.value
+    }
+
+    pub fn with_value<T: AsRef<str>>(mut self, value: T) -> Self {
+        self.value = value.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDTestStringBuilder {
     inner: TestString,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDTestStringBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> TestString {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<TestString> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("TestString", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn value<T: AsRef<str>>(&mut self, value: T) -> &mut Self {
         self.inner.value = value.as_ref().to_string();
+        self.missing.retain(|f| *f != "value");
         self
     }
 }
@@ -72,3 +120,14 @@ impl AsRef<TestString> for RTDTestStringBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(TestString::builder().value("text").build());
+    }
+}
@@ -1,18 +1,18 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// A simple object containing a vector of numbers; for testing only
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "testVectorInt")]
 pub struct TestVectorInt {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Vector of numbers
-    value: Vec<i64>,
+    value: Vec<i32>,
 }
 
 impl RObject for TestVectorInt {
@@ -29,34 +29,85 @@ impl RObject for TestVectorInt {
     }
 }
 
+impl PartialEq for TestVectorInt {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for TestVectorInt {}
+
+impl std::hash::Hash for TestVectorInt {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.value, state);
+    }
+}
+
 impl TestVectorInt {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDTestVectorIntBuilder {
-        let mut inner = TestVectorInt::default();
-        inner.td_name = "testVectorInt".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDTestVectorIntBuilder { inner }
+        RTDTestVectorIntBuilder {
+            inner: TestVectorInt::default(),
+            missing: vec!["value"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDTestVectorIntBuilder {
+        RTDTestVectorIntBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDTestVectorIntBuilder {
+        RTDTestVectorIntBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn value(&self) -> &Vec<i64> {
+    pub fn value(&self) -> &Vec<i32> {
         &self.value
     }
+
+    pub fn into_value(self) -> Vec<i32> {
+        self.value
+    }
+
+    pub fn with_value(mut self, value: Vec<i32>) -> Self {
+        self.value = value;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDTestVectorIntBuilder {
     inner: TestVectorInt,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDTestVectorIntBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> TestVectorInt {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<TestVectorInt> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "TestVectorInt",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn value(&mut self, value: Vec<i64>) -> &mut Self {
+    pub fn value(&mut self, value: Vec<i32>) -> &mut Self {
         self.inner.value = value;
+        self.missing.retain(|f| *f != "value");
         self
     }
 }
@@ -72,3 +123,14 @@ impl AsRef<TestVectorInt> for RTDTestVectorIntBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(TestVectorInt::builder().value(vec![i32::MAX]).build());
+    }
+}
@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// A simple object containing a vector of objects that hold a number; for testing only
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "testVectorIntObject")]
 pub struct TestVectorIntObject {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Vector of objects
     value: Vec<TestInt>,
@@ -29,34 +29,85 @@ impl RObject for TestVectorIntObject {
     }
 }
 
+impl PartialEq for TestVectorIntObject {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for TestVectorIntObject {}
+
+impl std::hash::Hash for TestVectorIntObject {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.value, state);
+    }
+}
+
 impl TestVectorIntObject {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDTestVectorIntObjectBuilder {
-        let mut inner = TestVectorIntObject::default();
-        inner.td_name = "testVectorIntObject".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDTestVectorIntObjectBuilder { inner }
+        RTDTestVectorIntObjectBuilder {
+            inner: TestVectorIntObject::default(),
+            missing: vec!["value"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDTestVectorIntObjectBuilder {
+        RTDTestVectorIntObjectBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDTestVectorIntObjectBuilder {
+        RTDTestVectorIntObjectBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn value(&self) -> &Vec<TestInt> {
         &self.value
     }
+
+    pub fn into_value(self) -> Vec<TestInt> {
+        self.value
+    }
+
+    pub fn with_value(mut self, value: Vec<TestInt>) -> Self {
+        self.value = value;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDTestVectorIntObjectBuilder {
     inner: TestVectorIntObject,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDTestVectorIntObjectBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> TestVectorIntObject {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<TestVectorIntObject> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "TestVectorIntObject",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn value(&mut self, value: Vec<TestInt>) -> &mut Self {
         self.inner.value = value;
+        self.missing.retain(|f| *f != "value");
         self
     }
 }
@@ -72,3 +123,14 @@ impl AsRef<TestVectorIntObject> for RTDTestVectorIntObjectBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(TestVectorIntObject::builder().value(vec![]).build());
+    }
+}
@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// A simple object containing a vector of strings; for testing only
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "testVectorString")]
 pub struct TestVectorString {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Vector of strings
     value: Vec<String>,
@@ -29,34 +29,85 @@ impl RObject for TestVectorString {
     }
 }
 
+impl PartialEq for TestVectorString {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for TestVectorString {}
+
+impl std::hash::Hash for TestVectorString {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.value, state);
+    }
+}
+
 impl TestVectorString {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDTestVectorStringBuilder {
-        let mut inner = TestVectorString::default();
-        inner.td_name = "testVectorString".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDTestVectorStringBuilder { inner }
+        RTDTestVectorStringBuilder {
+            inner: TestVectorString::default(),
+            missing: vec!["value"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDTestVectorStringBuilder {
+        RTDTestVectorStringBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDTestVectorStringBuilder {
+        RTDTestVectorStringBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn value(&self) -> &Vec<String> {
         &self.value
     }
+
+    pub fn into_value(self) -> Vec<String> {
+        self.value
+    }
+
+    pub fn with_value(mut self, value: Vec<String>) -> Self {
+        self.value = value;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDTestVectorStringBuilder {
     inner: TestVectorString,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDTestVectorStringBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> TestVectorString {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<TestVectorString> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "TestVectorString",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn value(&mut self, value: Vec<String>) -> &mut Self {
         self.inner.value = value;
+        self.missing.retain(|f| *f != "value");
         self
     }
 }
@@ -72,3 +123,18 @@ impl AsRef<TestVectorString> for RTDTestVectorStringBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            TestVectorString::builder()
+                .value(vec!["text".to_string()])
+                .build(),
+        );
+    }
+}
@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// A simple object containing a vector of objects that hold a string; for testing only
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "testVectorStringObject")]
 pub struct TestVectorStringObject {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Vector of objects
     value: Vec<TestString>,
@@ -29,34 +29,85 @@ impl RObject for TestVectorStringObject {
     }
 }
 
+impl PartialEq for TestVectorStringObject {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for TestVectorStringObject {}
+
+impl std::hash::Hash for TestVectorStringObject {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.value, state);
+    }
+}
+
 impl TestVectorStringObject {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDTestVectorStringObjectBuilder {
-        let mut inner = TestVectorStringObject::default();
-        inner.td_name = "testVectorStringObject".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDTestVectorStringObjectBuilder { inner }
+        RTDTestVectorStringObjectBuilder {
+            inner: TestVectorStringObject::default(),
+            missing: vec!["value"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDTestVectorStringObjectBuilder {
+        RTDTestVectorStringObjectBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDTestVectorStringObjectBuilder {
+        RTDTestVectorStringObjectBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn value(&self) -> &Vec<TestString> {
         &self.value
     }
+
+    pub fn into_value(self) -> Vec<TestString> {
+        self.value
+    }
+
+    pub fn with_value(mut self, value: Vec<TestString>) -> Self {
+        self.value = value;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDTestVectorStringObjectBuilder {
     inner: TestVectorStringObject,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDTestVectorStringObjectBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> TestVectorStringObject {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<TestVectorStringObject> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "TestVectorStringObject",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn value(&mut self, value: Vec<TestString>) -> &mut Self {
         self.inner.value = value;
+        self.missing.retain(|f| *f != "value");
         self
     }
 }
@@ -72,3 +123,14 @@ impl AsRef<TestVectorStringObject> for RTDTestVectorStringObjectBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(TestVectorStringObject::builder().value(vec![]).build());
+    }
+}
@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains a list of text entities
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "textEntities")]
 pub struct TextEntities {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// List of text entities
     entities: Vec<TextEntity>,
@@ -29,34 +29,85 @@ impl RObject for TextEntities {
     }
 }
 
+impl PartialEq for TextEntities {
+    fn eq(&self, other: &Self) -> bool {
+        self.entities == other.entities
+    }
+}
+
+impl Eq for TextEntities {}
+
+impl std::hash::Hash for TextEntities {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.entities, state);
+    }
+}
+
 impl TextEntities {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDTextEntitiesBuilder {
-        let mut inner = TextEntities::default();
-        inner.td_name = "textEntities".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDTextEntitiesBuilder { inner }
+        RTDTextEntitiesBuilder {
+            inner: TextEntities::default(),
+            missing: vec!["entities"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDTextEntitiesBuilder {
+        RTDTextEntitiesBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDTextEntitiesBuilder {
+        RTDTextEntitiesBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn entities(&self) -> &Vec<TextEntity> {
         &self.entities
     }
+
+    pub fn into_entities(self) -> Vec<TextEntity> {
+        self.entities
+    }
+
+    pub fn with_entities(mut self, entities: Vec<TextEntity>) -> Self {
+        self.entities = entities;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDTextEntitiesBuilder {
     inner: TextEntities,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDTextEntitiesBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> TextEntities {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<TextEntities> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "TextEntities",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn entities(&mut self, entities: Vec<TextEntity>) -> &mut Self {
         self.inner.entities = entities;
+        self.missing.retain(|f| *f != "entities");
         self
     }
 }
@@ -72,3 +123,14 @@ impl AsRef<TextEntities> for RTDTextEntitiesBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(TextEntities::builder().entities(vec![]).build());
+    }
+}
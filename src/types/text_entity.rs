@@ -1,20 +1,20 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Represents a part of the text that needs to be formatted in some unusual way
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "textEntity")]
 pub struct TextEntity {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Offset of the entity in UTF-16 code units
-    offset: i64,
+    offset: i32,
     /// Length of the entity, in UTF-16 code units
-    length: i64,
+    length: i32,
     /// Type of the entity
     #[serde(rename(serialize = "type", deserialize = "type"))]
     type_: TextEntityType,
@@ -34,52 +34,114 @@ impl RObject for TextEntity {
     }
 }
 
+impl PartialEq for TextEntity {
+    fn eq(&self, other: &Self) -> bool {
+        self.offset == other.offset && self.length == other.length && self.type_ == other.type_
+    }
+}
+
+impl Eq for TextEntity {}
+
+impl std::hash::Hash for TextEntity {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.offset, state);
+        std::hash::Hash::hash(&self.length, state);
+        std::hash::Hash::hash(&self.type_, state);
+    }
+}
+
 impl TextEntity {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDTextEntityBuilder {
-        let mut inner = TextEntity::default();
-        inner.td_name = "textEntity".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDTextEntityBuilder { inner }
+        RTDTextEntityBuilder {
+            inner: TextEntity::default(),
+            missing: vec!["offset", "length", "type_"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDTextEntityBuilder {
+        RTDTextEntityBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDTextEntityBuilder {
+        RTDTextEntityBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn offset(&self) -> i64 {
+    pub fn offset(&self) -> i32 {
         self.offset
     }
 
-    pub fn length(&self) -> i64 {
+    pub fn length(&self) -> i32 {
         self.length
     }
 
     pub fn type_(&self) -> &TextEntityType {
         &self.type_
     }
+
+    pub fn into_type(self) -> TextEntityType {
+        self.type_
+    }
+
+    pub fn with_offset(mut self, offset: i32) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    pub fn with_length(mut self, length: i32) -> Self {
+        self.length = length;
+        self
+    }
+
+    pub fn with_type<T: AsRef<TextEntityType>>(mut self, type_: T) -> Self {
+        self.type_ = type_.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDTextEntityBuilder {
     inner: TextEntity,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDTextEntityBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> TextEntity {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<TextEntity> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("TextEntity", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn offset(&mut self, offset: i64) -> &mut Self {
+    pub fn offset(&mut self, offset: i32) -> &mut Self {
         self.inner.offset = offset;
+        self.missing.retain(|f| *f != "offset");
         self
     }
 
-    pub fn length(&mut self, length: i64) -> &mut Self {
+    pub fn length(&mut self, length: i32) -> &mut Self {
         self.inner.length = length;
+        self.missing.retain(|f| *f != "length");
         self
     }
 
     pub fn type_<T: AsRef<TextEntityType>>(&mut self, type_: T) -> &mut Self {
         self.inner.type_ = type_.as_ref().clone();
+        self.missing.retain(|f| *f != "type_");
         self
     }
 }
@@ -95,3 +157,20 @@ impl AsRef<TextEntity> for RTDTextEntityBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            TextEntity::builder()
+                .offset(i32::MAX)
+                .length(i32::MAX)
+                .type_(TextEntityType::Bold(TextEntityTypeBold::builder().build()))
+                .build(),
+        );
+    }
+}
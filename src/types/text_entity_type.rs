@@ -1,85 +1,96 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Represents a part of the text which must be formatted differently
 pub trait TDTextEntityType: Debug + RObject {}
 
 /// Represents a part of the text which must be formatted differently
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum TextEntityType {
-    #[doc(hidden)]
-    _Default(()),
     /// A bold text
+    #[serde(rename = "textEntityTypeBold")]
     Bold(TextEntityTypeBold),
     /// A bot command, beginning with "/". This shouldn't be highlighted if there are no bots in the chat
+    #[serde(rename = "textEntityTypeBotCommand")]
     BotCommand(TextEntityTypeBotCommand),
     /// A cashtag text, beginning with "$" and consisting of capital english letters (i.e. "$USD")
+    #[serde(rename = "textEntityTypeCashtag")]
     Cashtag(TextEntityTypeCashtag),
     /// Text that must be formatted as if inside a code HTML tag
+    #[serde(rename = "textEntityTypeCode")]
     Code(TextEntityTypeCode),
     /// An email address
+    #[serde(rename = "textEntityTypeEmailAddress")]
     EmailAddress(TextEntityTypeEmailAddress),
     /// A hashtag text, beginning with "#"
+    #[serde(rename = "textEntityTypeHashtag")]
     Hashtag(TextEntityTypeHashtag),
     /// An italic text
+    #[serde(rename = "textEntityTypeItalic")]
     Italic(TextEntityTypeItalic),
     /// A mention of a user by their username
+    #[serde(rename = "textEntityTypeMention")]
     Mention(TextEntityTypeMention),
     /// A text shows instead of a raw mention of the user (e.g., when the user has no username)
+    #[serde(rename = "textEntityTypeMentionName")]
     MentionName(TextEntityTypeMentionName),
     /// A phone number
+    #[serde(rename = "textEntityTypePhoneNumber")]
     PhoneNumber(TextEntityTypePhoneNumber),
     /// Text that must be formatted as if inside a pre HTML tag
+    #[serde(rename = "textEntityTypePre")]
     Pre(TextEntityTypePre),
     /// Text that must be formatted as if inside pre, and code HTML tags
+    #[serde(rename = "textEntityTypePreCode")]
     PreCode(TextEntityTypePreCode),
     /// A strikethrough text
+    #[serde(rename = "textEntityTypeStrikethrough")]
     Strikethrough(TextEntityTypeStrikethrough),
     /// A text description shown instead of a raw URL
+    #[serde(rename = "textEntityTypeTextUrl")]
     TextUrl(TextEntityTypeTextUrl),
     /// An underlined text
+    #[serde(rename = "textEntityTypeUnderline")]
     Underline(TextEntityTypeUnderline),
     /// An HTTP URL
+    #[serde(rename = "textEntityTypeUrl")]
     Url(TextEntityTypeUrl),
 }
 
+/// Default value is default of [TextEntityTypeBold], builders still require the field to be set
 impl Default for TextEntityType {
     fn default() -> Self {
-        TextEntityType::_Default(())
+        TextEntityType::Bold(TextEntityTypeBold::default())
     }
 }
 
-impl<'de> Deserialize<'de> for TextEntityType {
-    fn deserialize<D>(deserializer: D) -> Result<TextEntityType, D::Error>
+impl Serialize for TextEntityType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          TextEntityType,
-          (textEntityTypeBold, Bold);
-          (textEntityTypeBotCommand, BotCommand);
-          (textEntityTypeCashtag, Cashtag);
-          (textEntityTypeCode, Code);
-          (textEntityTypeEmailAddress, EmailAddress);
-          (textEntityTypeHashtag, Hashtag);
-          (textEntityTypeItalic, Italic);
-          (textEntityTypeMention, Mention);
-          (textEntityTypeMentionName, MentionName);
-          (textEntityTypePhoneNumber, PhoneNumber);
-          (textEntityTypePre, Pre);
-          (textEntityTypePreCode, PreCode);
-          (textEntityTypeStrikethrough, Strikethrough);
-          (textEntityTypeTextUrl, TextUrl);
-          (textEntityTypeUnderline, Underline);
-          (textEntityTypeUrl, Url);
-
-        )(deserializer)
+        match self {
+            TextEntityType::Bold(t) => t.serialize(serializer),
+            TextEntityType::BotCommand(t) => t.serialize(serializer),
+            TextEntityType::Cashtag(t) => t.serialize(serializer),
+            TextEntityType::Code(t) => t.serialize(serializer),
+            TextEntityType::EmailAddress(t) => t.serialize(serializer),
+            TextEntityType::Hashtag(t) => t.serialize(serializer),
+            TextEntityType::Italic(t) => t.serialize(serializer),
+            TextEntityType::Mention(t) => t.serialize(serializer),
+            TextEntityType::MentionName(t) => t.serialize(serializer),
+            TextEntityType::PhoneNumber(t) => t.serialize(serializer),
+            TextEntityType::Pre(t) => t.serialize(serializer),
+            TextEntityType::PreCode(t) => t.serialize(serializer),
+            TextEntityType::Strikethrough(t) => t.serialize(serializer),
+            TextEntityType::TextUrl(t) => t.serialize(serializer),
+            TextEntityType::Underline(t) => t.serialize(serializer),
+            TextEntityType::Url(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -103,8 +114,6 @@ impl RObject for TextEntityType {
             TextEntityType::TextUrl(t) => t.td_name(),
             TextEntityType::Underline(t) => t.td_name(),
             TextEntityType::Url(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -126,8 +135,6 @@ impl RObject for TextEntityType {
             TextEntityType::TextUrl(t) => t.extra(),
             TextEntityType::Underline(t) => t.extra(),
             TextEntityType::Url(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -139,10 +146,6 @@ impl TextEntityType {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, TextEntityType::_Default(_))
-    }
 }
 
 impl AsRef<TextEntityType> for TextEntityType {
@@ -153,12 +156,13 @@ impl AsRef<TextEntityType> for TextEntityType {
 
 /// A bold text
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "textEntityTypeBold")]
 pub struct TextEntityTypeBold {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -176,6 +180,18 @@ impl RObject for TextEntityTypeBold {
     }
 }
 
+impl PartialEq for TextEntityTypeBold {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for TextEntityTypeBold {}
+
+impl std::hash::Hash for TextEntityTypeBold {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDTextEntityType for TextEntityTypeBold {}
 
 impl TextEntityTypeBold {
@@ -183,22 +199,49 @@ impl TextEntityTypeBold {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDTextEntityTypeBoldBuilder {
-        let mut inner = TextEntityTypeBold::default();
-        inner.td_name = "textEntityTypeBold".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDTextEntityTypeBoldBuilder { inner }
+        RTDTextEntityTypeBoldBuilder {
+            inner: TextEntityTypeBold::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDTextEntityTypeBoldBuilder {
+        RTDTextEntityTypeBoldBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDTextEntityTypeBoldBuilder {
+        RTDTextEntityTypeBoldBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDTextEntityTypeBoldBuilder {
     inner: TextEntityTypeBold,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDTextEntityTypeBoldBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> TextEntityTypeBold {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<TextEntityTypeBold> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "TextEntityTypeBold",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<TextEntityTypeBold> for TextEntityTypeBold {
@@ -215,12 +258,13 @@ impl AsRef<TextEntityTypeBold> for RTDTextEntityTypeBoldBuilder {
 
 /// A bot command, beginning with "/". This shouldn't be highlighted if there are no bots in the chat
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "textEntityTypeBotCommand")]
 pub struct TextEntityTypeBotCommand {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -238,6 +282,18 @@ impl RObject for TextEntityTypeBotCommand {
     }
 }
 
+impl PartialEq for TextEntityTypeBotCommand {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for TextEntityTypeBotCommand {}
+
+impl std::hash::Hash for TextEntityTypeBotCommand {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDTextEntityType for TextEntityTypeBotCommand {}
 
 impl TextEntityTypeBotCommand {
@@ -245,22 +301,49 @@ impl TextEntityTypeBotCommand {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDTextEntityTypeBotCommandBuilder {
-        let mut inner = TextEntityTypeBotCommand::default();
-        inner.td_name = "textEntityTypeBotCommand".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDTextEntityTypeBotCommandBuilder { inner }
+        RTDTextEntityTypeBotCommandBuilder {
+            inner: TextEntityTypeBotCommand::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDTextEntityTypeBotCommandBuilder {
+        RTDTextEntityTypeBotCommandBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDTextEntityTypeBotCommandBuilder {
+        RTDTextEntityTypeBotCommandBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDTextEntityTypeBotCommandBuilder {
     inner: TextEntityTypeBotCommand,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDTextEntityTypeBotCommandBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> TextEntityTypeBotCommand {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<TextEntityTypeBotCommand> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "TextEntityTypeBotCommand",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<TextEntityTypeBotCommand> for TextEntityTypeBotCommand {
@@ -277,12 +360,13 @@ impl AsRef<TextEntityTypeBotCommand> for RTDTextEntityTypeBotCommandBuilder {
 
 /// A cashtag text, beginning with "$" and consisting of capital english letters (i.e. "$USD")
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "textEntityTypeCashtag")]
 pub struct TextEntityTypeCashtag {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -300,6 +384,18 @@ impl RObject for TextEntityTypeCashtag {
     }
 }
 
+impl PartialEq for TextEntityTypeCashtag {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for TextEntityTypeCashtag {}
+
+impl std::hash::Hash for TextEntityTypeCashtag {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDTextEntityType for TextEntityTypeCashtag {}
 
 impl TextEntityTypeCashtag {
@@ -307,22 +403,49 @@ impl TextEntityTypeCashtag {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDTextEntityTypeCashtagBuilder {
-        let mut inner = TextEntityTypeCashtag::default();
-        inner.td_name = "textEntityTypeCashtag".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDTextEntityTypeCashtagBuilder { inner }
+        RTDTextEntityTypeCashtagBuilder {
+            inner: TextEntityTypeCashtag::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDTextEntityTypeCashtagBuilder {
+        RTDTextEntityTypeCashtagBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDTextEntityTypeCashtagBuilder {
+        RTDTextEntityTypeCashtagBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDTextEntityTypeCashtagBuilder {
     inner: TextEntityTypeCashtag,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDTextEntityTypeCashtagBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> TextEntityTypeCashtag {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<TextEntityTypeCashtag> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "TextEntityTypeCashtag",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<TextEntityTypeCashtag> for TextEntityTypeCashtag {
@@ -339,12 +462,13 @@ impl AsRef<TextEntityTypeCashtag> for RTDTextEntityTypeCashtagBuilder {
 
 /// Text that must be formatted as if inside a code HTML tag
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "textEntityTypeCode")]
 pub struct TextEntityTypeCode {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -362,6 +486,18 @@ impl RObject for TextEntityTypeCode {
     }
 }
 
+impl PartialEq for TextEntityTypeCode {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for TextEntityTypeCode {}
+
+impl std::hash::Hash for TextEntityTypeCode {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDTextEntityType for TextEntityTypeCode {}
 
 impl TextEntityTypeCode {
@@ -369,22 +505,49 @@ impl TextEntityTypeCode {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDTextEntityTypeCodeBuilder {
-        let mut inner = TextEntityTypeCode::default();
-        inner.td_name = "textEntityTypeCode".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDTextEntityTypeCodeBuilder { inner }
+        RTDTextEntityTypeCodeBuilder {
+            inner: TextEntityTypeCode::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDTextEntityTypeCodeBuilder {
+        RTDTextEntityTypeCodeBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDTextEntityTypeCodeBuilder {
+        RTDTextEntityTypeCodeBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDTextEntityTypeCodeBuilder {
     inner: TextEntityTypeCode,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDTextEntityTypeCodeBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> TextEntityTypeCode {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<TextEntityTypeCode> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "TextEntityTypeCode",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<TextEntityTypeCode> for TextEntityTypeCode {
@@ -401,12 +564,13 @@ impl AsRef<TextEntityTypeCode> for RTDTextEntityTypeCodeBuilder {
 
 /// An email address
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "textEntityTypeEmailAddress")]
 pub struct TextEntityTypeEmailAddress {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -424,6 +588,18 @@ impl RObject for TextEntityTypeEmailAddress {
     }
 }
 
+impl PartialEq for TextEntityTypeEmailAddress {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for TextEntityTypeEmailAddress {}
+
+impl std::hash::Hash for TextEntityTypeEmailAddress {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDTextEntityType for TextEntityTypeEmailAddress {}
 
 impl TextEntityTypeEmailAddress {
@@ -431,22 +607,49 @@ impl TextEntityTypeEmailAddress {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDTextEntityTypeEmailAddressBuilder {
-        let mut inner = TextEntityTypeEmailAddress::default();
-        inner.td_name = "textEntityTypeEmailAddress".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDTextEntityTypeEmailAddressBuilder { inner }
+        RTDTextEntityTypeEmailAddressBuilder {
+            inner: TextEntityTypeEmailAddress::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDTextEntityTypeEmailAddressBuilder {
+        RTDTextEntityTypeEmailAddressBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDTextEntityTypeEmailAddressBuilder {
+        RTDTextEntityTypeEmailAddressBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDTextEntityTypeEmailAddressBuilder {
     inner: TextEntityTypeEmailAddress,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDTextEntityTypeEmailAddressBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> TextEntityTypeEmailAddress {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<TextEntityTypeEmailAddress> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "TextEntityTypeEmailAddress",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<TextEntityTypeEmailAddress> for TextEntityTypeEmailAddress {
@@ -463,12 +666,13 @@ impl AsRef<TextEntityTypeEmailAddress> for RTDTextEntityTypeEmailAddressBuilder
 
 /// A hashtag text, beginning with "#"
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "textEntityTypeHashtag")]
 pub struct TextEntityTypeHashtag {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -486,6 +690,18 @@ impl RObject for TextEntityTypeHashtag {
     }
 }
 
+impl PartialEq for TextEntityTypeHashtag {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for TextEntityTypeHashtag {}
+
+impl std::hash::Hash for TextEntityTypeHashtag {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDTextEntityType for TextEntityTypeHashtag {}
 
 impl TextEntityTypeHashtag {
@@ -493,22 +709,49 @@ impl TextEntityTypeHashtag {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDTextEntityTypeHashtagBuilder {
-        let mut inner = TextEntityTypeHashtag::default();
-        inner.td_name = "textEntityTypeHashtag".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDTextEntityTypeHashtagBuilder { inner }
+        RTDTextEntityTypeHashtagBuilder {
+            inner: TextEntityTypeHashtag::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDTextEntityTypeHashtagBuilder {
+        RTDTextEntityTypeHashtagBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDTextEntityTypeHashtagBuilder {
+        RTDTextEntityTypeHashtagBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDTextEntityTypeHashtagBuilder {
     inner: TextEntityTypeHashtag,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDTextEntityTypeHashtagBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> TextEntityTypeHashtag {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<TextEntityTypeHashtag> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "TextEntityTypeHashtag",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<TextEntityTypeHashtag> for TextEntityTypeHashtag {
@@ -525,12 +768,13 @@ impl AsRef<TextEntityTypeHashtag> for RTDTextEntityTypeHashtagBuilder {
 
 /// An italic text
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "textEntityTypeItalic")]
 pub struct TextEntityTypeItalic {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -548,6 +792,18 @@ impl RObject for TextEntityTypeItalic {
     }
 }
 
+impl PartialEq for TextEntityTypeItalic {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for TextEntityTypeItalic {}
+
+impl std::hash::Hash for TextEntityTypeItalic {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDTextEntityType for TextEntityTypeItalic {}
 
 impl TextEntityTypeItalic {
@@ -555,22 +811,49 @@ impl TextEntityTypeItalic {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDTextEntityTypeItalicBuilder {
-        let mut inner = TextEntityTypeItalic::default();
-        inner.td_name = "textEntityTypeItalic".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDTextEntityTypeItalicBuilder { inner }
+        RTDTextEntityTypeItalicBuilder {
+            inner: TextEntityTypeItalic::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDTextEntityTypeItalicBuilder {
+        RTDTextEntityTypeItalicBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDTextEntityTypeItalicBuilder {
+        RTDTextEntityTypeItalicBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDTextEntityTypeItalicBuilder {
     inner: TextEntityTypeItalic,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDTextEntityTypeItalicBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> TextEntityTypeItalic {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<TextEntityTypeItalic> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "TextEntityTypeItalic",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<TextEntityTypeItalic> for TextEntityTypeItalic {
@@ -587,12 +870,13 @@ impl AsRef<TextEntityTypeItalic> for RTDTextEntityTypeItalicBuilder {
 
 /// A mention of a user by their username
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "textEntityTypeMention")]
 pub struct TextEntityTypeMention {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -610,6 +894,18 @@ impl RObject for TextEntityTypeMention {
     }
 }
 
+impl PartialEq for TextEntityTypeMention {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for TextEntityTypeMention {}
+
+impl std::hash::Hash for TextEntityTypeMention {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDTextEntityType for TextEntityTypeMention {}
 
 impl TextEntityTypeMention {
@@ -617,22 +913,49 @@ impl TextEntityTypeMention {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDTextEntityTypeMentionBuilder {
-        let mut inner = TextEntityTypeMention::default();
-        inner.td_name = "textEntityTypeMention".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDTextEntityTypeMentionBuilder { inner }
+        RTDTextEntityTypeMentionBuilder {
+            inner: TextEntityTypeMention::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDTextEntityTypeMentionBuilder {
+        RTDTextEntityTypeMentionBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDTextEntityTypeMentionBuilder {
+        RTDTextEntityTypeMentionBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDTextEntityTypeMentionBuilder {
     inner: TextEntityTypeMention,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDTextEntityTypeMentionBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> TextEntityTypeMention {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<TextEntityTypeMention> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "TextEntityTypeMention",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<TextEntityTypeMention> for TextEntityTypeMention {
@@ -649,15 +972,16 @@ impl AsRef<TextEntityTypeMention> for RTDTextEntityTypeMentionBuilder {
 
 /// A text shows instead of a raw mention of the user (e.g., when the user has no username)
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "textEntityTypeMentionName")]
 pub struct TextEntityTypeMentionName {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Identifier of the mentioned user
-    user_id: i64,
+    user_id: UserId,
 }
 
 impl RObject for TextEntityTypeMentionName {
@@ -674,6 +998,20 @@ impl RObject for TextEntityTypeMentionName {
     }
 }
 
+impl PartialEq for TextEntityTypeMentionName {
+    fn eq(&self, other: &Self) -> bool {
+        self.user_id == other.user_id
+    }
+}
+
+impl Eq for TextEntityTypeMentionName {}
+
+impl std::hash::Hash for TextEntityTypeMentionName {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.user_id, state);
+    }
+}
+
 impl TDTextEntityType for TextEntityTypeMentionName {}
 
 impl TextEntityTypeMentionName {
@@ -681,29 +1019,62 @@ impl TextEntityTypeMentionName {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDTextEntityTypeMentionNameBuilder {
-        let mut inner = TextEntityTypeMentionName::default();
-        inner.td_name = "textEntityTypeMentionName".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDTextEntityTypeMentionNameBuilder { inner }
+        RTDTextEntityTypeMentionNameBuilder {
+            inner: TextEntityTypeMentionName::default(),
+            missing: vec!["user_id"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDTextEntityTypeMentionNameBuilder {
+        RTDTextEntityTypeMentionNameBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDTextEntityTypeMentionNameBuilder {
+        RTDTextEntityTypeMentionNameBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn user_id(&self) -> i64 {
+    pub fn user_id(&self) -> UserId {
         self.user_id
     }
+
+    pub fn with_user_id<T: Into<UserId>>(mut self, user_id: T) -> Self {
+        self.user_id = user_id.into();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDTextEntityTypeMentionNameBuilder {
     inner: TextEntityTypeMentionName,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDTextEntityTypeMentionNameBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> TextEntityTypeMentionName {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<TextEntityTypeMentionName> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "TextEntityTypeMentionName",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn user_id(&mut self, user_id: i64) -> &mut Self {
-        self.inner.user_id = user_id;
+    pub fn user_id<T: Into<UserId>>(&mut self, user_id: T) -> &mut Self {
+        self.inner.user_id = user_id.into();
+        self.missing.retain(|f| *f != "user_id");
         self
     }
 }
@@ -722,12 +1093,13 @@ impl AsRef<TextEntityTypeMentionName> for RTDTextEntityTypeMentionNameBuilder {
 
 /// A phone number
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "textEntityTypePhoneNumber")]
 pub struct TextEntityTypePhoneNumber {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -745,6 +1117,18 @@ impl RObject for TextEntityTypePhoneNumber {
     }
 }
 
+impl PartialEq for TextEntityTypePhoneNumber {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for TextEntityTypePhoneNumber {}
+
+impl std::hash::Hash for TextEntityTypePhoneNumber {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDTextEntityType for TextEntityTypePhoneNumber {}
 
 impl TextEntityTypePhoneNumber {
@@ -752,22 +1136,49 @@ impl TextEntityTypePhoneNumber {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDTextEntityTypePhoneNumberBuilder {
-        let mut inner = TextEntityTypePhoneNumber::default();
-        inner.td_name = "textEntityTypePhoneNumber".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDTextEntityTypePhoneNumberBuilder { inner }
+        RTDTextEntityTypePhoneNumberBuilder {
+            inner: TextEntityTypePhoneNumber::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDTextEntityTypePhoneNumberBuilder {
+        RTDTextEntityTypePhoneNumberBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDTextEntityTypePhoneNumberBuilder {
+        RTDTextEntityTypePhoneNumberBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDTextEntityTypePhoneNumberBuilder {
     inner: TextEntityTypePhoneNumber,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDTextEntityTypePhoneNumberBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> TextEntityTypePhoneNumber {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<TextEntityTypePhoneNumber> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "TextEntityTypePhoneNumber",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<TextEntityTypePhoneNumber> for TextEntityTypePhoneNumber {
@@ -784,12 +1195,13 @@ impl AsRef<TextEntityTypePhoneNumber> for RTDTextEntityTypePhoneNumberBuilder {
 
 /// Text that must be formatted as if inside a pre HTML tag
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "textEntityTypePre")]
 pub struct TextEntityTypePre {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -807,6 +1219,18 @@ impl RObject for TextEntityTypePre {
     }
 }
 
+impl PartialEq for TextEntityTypePre {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for TextEntityTypePre {}
+
+impl std::hash::Hash for TextEntityTypePre {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDTextEntityType for TextEntityTypePre {}
 
 impl TextEntityTypePre {
@@ -814,22 +1238,49 @@ impl TextEntityTypePre {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDTextEntityTypePreBuilder {
-        let mut inner = TextEntityTypePre::default();
-        inner.td_name = "textEntityTypePre".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDTextEntityTypePreBuilder { inner }
+        RTDTextEntityTypePreBuilder {
+            inner: TextEntityTypePre::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDTextEntityTypePreBuilder {
+        RTDTextEntityTypePreBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDTextEntityTypePreBuilder {
+        RTDTextEntityTypePreBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDTextEntityTypePreBuilder {
     inner: TextEntityTypePre,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDTextEntityTypePreBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> TextEntityTypePre {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<TextEntityTypePre> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "TextEntityTypePre",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<TextEntityTypePre> for TextEntityTypePre {
@@ -846,12 +1297,13 @@ impl AsRef<TextEntityTypePre> for RTDTextEntityTypePreBuilder {
 
 /// Text that must be formatted as if inside pre, and code HTML tags
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "textEntityTypePreCode")]
 pub struct TextEntityTypePreCode {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Programming language of the code; as defined by the sender
     language: String,
@@ -871,6 +1323,20 @@ impl RObject for TextEntityTypePreCode {
     }
 }
 
+impl PartialEq for TextEntityTypePreCode {
+    fn eq(&self, other: &Self) -> bool {
+        self.language == other.language
+    }
+}
+
+impl Eq for TextEntityTypePreCode {}
+
+impl std::hash::Hash for TextEntityTypePreCode {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.language, state);
+    }
+}
+
 impl TDTextEntityType for TextEntityTypePreCode {}
 
 impl TextEntityTypePreCode {
@@ -878,29 +1344,66 @@ impl TextEntityTypePreCode {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDTextEntityTypePreCodeBuilder {
-        let mut inner = TextEntityTypePreCode::default();
-        inner.td_name = "textEntityTypePreCode".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDTextEntityTypePreCodeBuilder { inner }
+        RTDTextEntityTypePreCodeBuilder {
+            inner: TextEntityTypePreCode::default(),
+            missing: vec!["language"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDTextEntityTypePreCodeBuilder {
+        RTDTextEntityTypePreCodeBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDTextEntityTypePreCodeBuilder {
+        RTDTextEntityTypePreCodeBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn language(&self) -> &String {
         &self.language
     }
+
+    pub fn into_language(self) -> String {
+        self.language
+    }
+
+    pub fn with_language<T: AsRef<str>>(mut self, language: T) -> Self {
+        self.language = language.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDTextEntityTypePreCodeBuilder {
     inner: TextEntityTypePreCode,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDTextEntityTypePreCodeBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> TextEntityTypePreCode {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<TextEntityTypePreCode> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "TextEntityTypePreCode",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn language<T: AsRef<str>>(&mut self, language: T) -> &mut Self {
         self.inner.language = language.as_ref().to_string();
+        self.missing.retain(|f| *f != "language");
         self
     }
 }
@@ -919,12 +1422,13 @@ impl AsRef<TextEntityTypePreCode> for RTDTextEntityTypePreCodeBuilder {
 
 /// A strikethrough text
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "textEntityTypeStrikethrough")]
 pub struct TextEntityTypeStrikethrough {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -942,6 +1446,18 @@ impl RObject for TextEntityTypeStrikethrough {
     }
 }
 
+impl PartialEq for TextEntityTypeStrikethrough {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for TextEntityTypeStrikethrough {}
+
+impl std::hash::Hash for TextEntityTypeStrikethrough {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDTextEntityType for TextEntityTypeStrikethrough {}
 
 impl TextEntityTypeStrikethrough {
@@ -949,22 +1465,49 @@ impl TextEntityTypeStrikethrough {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDTextEntityTypeStrikethroughBuilder {
-        let mut inner = TextEntityTypeStrikethrough::default();
-        inner.td_name = "textEntityTypeStrikethrough".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDTextEntityTypeStrikethroughBuilder { inner }
+        RTDTextEntityTypeStrikethroughBuilder {
+            inner: TextEntityTypeStrikethrough::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDTextEntityTypeStrikethroughBuilder {
+        RTDTextEntityTypeStrikethroughBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDTextEntityTypeStrikethroughBuilder {
+        RTDTextEntityTypeStrikethroughBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDTextEntityTypeStrikethroughBuilder {
     inner: TextEntityTypeStrikethrough,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDTextEntityTypeStrikethroughBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> TextEntityTypeStrikethrough {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<TextEntityTypeStrikethrough> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "TextEntityTypeStrikethrough",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<TextEntityTypeStrikethrough> for TextEntityTypeStrikethrough {
@@ -981,12 +1524,13 @@ impl AsRef<TextEntityTypeStrikethrough> for RTDTextEntityTypeStrikethroughBuilde
 
 /// A text description shown instead of a raw URL
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "textEntityTypeTextUrl")]
 pub struct TextEntityTypeTextUrl {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// HTTP or tg:// URL to be opened when the link is clicked
     url: String,
@@ -1006,6 +1550,20 @@ impl RObject for TextEntityTypeTextUrl {
     }
 }
 
+impl PartialEq for TextEntityTypeTextUrl {
+    fn eq(&self, other: &Self) -> bool {
+        self.url == other.url
+    }
+}
+
+impl Eq for TextEntityTypeTextUrl {}
+
+impl std::hash::Hash for TextEntityTypeTextUrl {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.url, state);
+    }
+}
+
 impl TDTextEntityType for TextEntityTypeTextUrl {}
 
 impl TextEntityTypeTextUrl {
@@ -1013,29 +1571,66 @@ impl TextEntityTypeTextUrl {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDTextEntityTypeTextUrlBuilder {
-        let mut inner = TextEntityTypeTextUrl::default();
-        inner.td_name = "textEntityTypeTextUrl".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDTextEntityTypeTextUrlBuilder { inner }
+        RTDTextEntityTypeTextUrlBuilder {
+            inner: TextEntityTypeTextUrl::default(),
+            missing: vec!["url"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDTextEntityTypeTextUrlBuilder {
+        RTDTextEntityTypeTextUrlBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDTextEntityTypeTextUrlBuilder {
+        RTDTextEntityTypeTextUrlBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn url(&self) -> &String {
         &self.url
     }
+
+    pub fn into_url(self) -> String {
+        self.url
+    }
+
+    pub fn with_url<T: AsRef<str>>(mut self, url: T) -> Self {
+        self.url = url.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDTextEntityTypeTextUrlBuilder {
     inner: TextEntityTypeTextUrl,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDTextEntityTypeTextUrlBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> TextEntityTypeTextUrl {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<TextEntityTypeTextUrl> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "TextEntityTypeTextUrl",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
         self.inner.url = url.as_ref().to_string();
+        self.missing.retain(|f| *f != "url");
         self
     }
 }
@@ -1054,12 +1649,13 @@ impl AsRef<TextEntityTypeTextUrl> for RTDTextEntityTypeTextUrlBuilder {
 
 /// An underlined text
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "textEntityTypeUnderline")]
 pub struct TextEntityTypeUnderline {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -1077,6 +1673,18 @@ impl RObject for TextEntityTypeUnderline {
     }
 }
 
+impl PartialEq for TextEntityTypeUnderline {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for TextEntityTypeUnderline {}
+
+impl std::hash::Hash for TextEntityTypeUnderline {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDTextEntityType for TextEntityTypeUnderline {}
 
 impl TextEntityTypeUnderline {
@@ -1084,22 +1692,49 @@ impl TextEntityTypeUnderline {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDTextEntityTypeUnderlineBuilder {
-        let mut inner = TextEntityTypeUnderline::default();
-        inner.td_name = "textEntityTypeUnderline".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDTextEntityTypeUnderlineBuilder { inner }
+        RTDTextEntityTypeUnderlineBuilder {
+            inner: TextEntityTypeUnderline::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDTextEntityTypeUnderlineBuilder {
+        RTDTextEntityTypeUnderlineBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDTextEntityTypeUnderlineBuilder {
+        RTDTextEntityTypeUnderlineBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDTextEntityTypeUnderlineBuilder {
     inner: TextEntityTypeUnderline,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDTextEntityTypeUnderlineBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> TextEntityTypeUnderline {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<TextEntityTypeUnderline> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "TextEntityTypeUnderline",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<TextEntityTypeUnderline> for TextEntityTypeUnderline {
@@ -1116,12 +1751,13 @@ impl AsRef<TextEntityTypeUnderline> for RTDTextEntityTypeUnderlineBuilder {
 
 /// An HTTP URL
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "textEntityTypeUrl")]
 pub struct TextEntityTypeUrl {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -1139,6 +1775,18 @@ impl RObject for TextEntityTypeUrl {
     }
 }
 
+impl PartialEq for TextEntityTypeUrl {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for TextEntityTypeUrl {}
+
+impl std::hash::Hash for TextEntityTypeUrl {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDTextEntityType for TextEntityTypeUrl {}
 
 impl TextEntityTypeUrl {
@@ -1146,22 +1794,49 @@ impl TextEntityTypeUrl {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDTextEntityTypeUrlBuilder {
-        let mut inner = TextEntityTypeUrl::default();
-        inner.td_name = "textEntityTypeUrl".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDTextEntityTypeUrlBuilder { inner }
+        RTDTextEntityTypeUrlBuilder {
+            inner: TextEntityTypeUrl::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDTextEntityTypeUrlBuilder {
+        RTDTextEntityTypeUrlBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDTextEntityTypeUrlBuilder {
+        RTDTextEntityTypeUrlBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDTextEntityTypeUrlBuilder {
     inner: TextEntityTypeUrl,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDTextEntityTypeUrlBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> TextEntityTypeUrl {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<TextEntityTypeUrl> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "TextEntityTypeUrl",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<TextEntityTypeUrl> for TextEntityTypeUrl {
@@ -1175,3 +1850,55 @@ impl AsRef<TextEntityTypeUrl> for RTDTextEntityTypeUrlBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(TextEntityType::Bold(TextEntityTypeBold::builder().build()));
+        assert_round_trip(TextEntityType::BotCommand(
+            TextEntityTypeBotCommand::builder().build(),
+        ));
+        assert_round_trip(TextEntityType::Cashtag(
+            TextEntityTypeCashtag::builder().build(),
+        ));
+        assert_round_trip(TextEntityType::Code(TextEntityTypeCode::builder().build()));
+        assert_round_trip(TextEntityType::EmailAddress(
+            TextEntityTypeEmailAddress::builder().build(),
+        ));
+        assert_round_trip(TextEntityType::Hashtag(
+            TextEntityTypeHashtag::builder().build(),
+        ));
+        assert_round_trip(TextEntityType::Italic(
+            TextEntityTypeItalic::builder().build(),
+        ));
+        assert_round_trip(TextEntityType::Mention(
+            TextEntityTypeMention::builder().build(),
+        ));
+        assert_round_trip(TextEntityType::MentionName(
+            TextEntityTypeMentionName::builder()
+                .user_id(i32::MAX)
+                .build(),
+        ));
+        assert_round_trip(TextEntityType::PhoneNumber(
+            TextEntityTypePhoneNumber::builder().build(),
+        ));
+        assert_round_trip(TextEntityType::Pre(TextEntityTypePre::builder().build()));
+        assert_round_trip(TextEntityType::PreCode(
+            TextEntityTypePreCode::builder().language("text").build(),
+        ));
+        assert_round_trip(TextEntityType::Strikethrough(
+            TextEntityTypeStrikethrough::builder().build(),
+        ));
+        assert_round_trip(TextEntityType::TextUrl(
+            TextEntityTypeTextUrl::builder().url("text").build(),
+        ));
+        assert_round_trip(TextEntityType::Underline(
+            TextEntityTypeUnderline::builder().build(),
+        ));
+        assert_round_trip(TextEntityType::Url(TextEntityTypeUrl::builder().build()));
+    }
+}
@@ -1,43 +1,40 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Describes the way the text should be parsed for TextEntities
 pub trait TDTextParseMode: Debug + RObject {}
 
 /// Describes the way the text should be parsed for TextEntities
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum TextParseMode {
-    #[doc(hidden)]
-    _Default(()),
     /// The text should be parsed in HTML-style
+    #[serde(rename = "textParseModeHTML")]
     HTML(TextParseModeHTML),
     /// The text should be parsed in markdown-style
+    #[serde(rename = "textParseModeMarkdown")]
     Markdown(TextParseModeMarkdown),
 }
 
+/// Default value is default of [TextParseModeHTML], builders still require the field to be set
 impl Default for TextParseMode {
     fn default() -> Self {
-        TextParseMode::_Default(())
+        TextParseMode::HTML(TextParseModeHTML::default())
     }
 }
 
-impl<'de> Deserialize<'de> for TextParseMode {
-    fn deserialize<D>(deserializer: D) -> Result<TextParseMode, D::Error>
+impl Serialize for TextParseMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          TextParseMode,
-          (textParseModeHTML, HTML);
-          (textParseModeMarkdown, Markdown);
-
-        )(deserializer)
+        match self {
+            TextParseMode::HTML(t) => t.serialize(serializer),
+            TextParseMode::Markdown(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -47,8 +44,6 @@ impl RObject for TextParseMode {
         match self {
             TextParseMode::HTML(t) => t.td_name(),
             TextParseMode::Markdown(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -56,8 +51,6 @@ impl RObject for TextParseMode {
         match self {
             TextParseMode::HTML(t) => t.extra(),
             TextParseMode::Markdown(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -69,10 +62,6 @@ impl TextParseMode {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, TextParseMode::_Default(_))
-    }
 }
 
 impl AsRef<TextParseMode> for TextParseMode {
@@ -83,12 +72,13 @@ impl AsRef<TextParseMode> for TextParseMode {
 
 /// The text should be parsed in HTML-style
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "textParseModeHTML")]
 pub struct TextParseModeHTML {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -106,6 +96,18 @@ impl RObject for TextParseModeHTML {
     }
 }
 
+impl PartialEq for TextParseModeHTML {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for TextParseModeHTML {}
+
+impl std::hash::Hash for TextParseModeHTML {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDTextParseMode for TextParseModeHTML {}
 
 impl TextParseModeHTML {
@@ -113,22 +115,49 @@ impl TextParseModeHTML {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDTextParseModeHTMLBuilder {
-        let mut inner = TextParseModeHTML::default();
-        inner.td_name = "textParseModeHTML".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDTextParseModeHTMLBuilder { inner }
+        RTDTextParseModeHTMLBuilder {
+            inner: TextParseModeHTML::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDTextParseModeHTMLBuilder {
+        RTDTextParseModeHTMLBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDTextParseModeHTMLBuilder {
+        RTDTextParseModeHTMLBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDTextParseModeHTMLBuilder {
     inner: TextParseModeHTML,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDTextParseModeHTMLBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> TextParseModeHTML {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<TextParseModeHTML> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "TextParseModeHTML",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<TextParseModeHTML> for TextParseModeHTML {
@@ -145,15 +174,16 @@ impl AsRef<TextParseModeHTML> for RTDTextParseModeHTMLBuilder {
 
 /// The text should be parsed in markdown-style
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "textParseModeMarkdown")]
 pub struct TextParseModeMarkdown {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Version of the parser: 0 or 1  Bot API Markdown parse mode, 2  Bot API MarkdownV2 parse mode
-    version: i64,
+    version: i32,
 }
 
 impl RObject for TextParseModeMarkdown {
@@ -170,6 +200,20 @@ impl RObject for TextParseModeMarkdown {
     }
 }
 
+impl PartialEq for TextParseModeMarkdown {
+    fn eq(&self, other: &Self) -> bool {
+        self.version == other.version
+    }
+}
+
+impl Eq for TextParseModeMarkdown {}
+
+impl std::hash::Hash for TextParseModeMarkdown {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.version, state);
+    }
+}
+
 impl TDTextParseMode for TextParseModeMarkdown {}
 
 impl TextParseModeMarkdown {
@@ -177,29 +221,62 @@ impl TextParseModeMarkdown {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDTextParseModeMarkdownBuilder {
-        let mut inner = TextParseModeMarkdown::default();
-        inner.td_name = "textParseModeMarkdown".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDTextParseModeMarkdownBuilder { inner }
+        RTDTextParseModeMarkdownBuilder {
+            inner: TextParseModeMarkdown::default(),
+            missing: vec!["version"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDTextParseModeMarkdownBuilder {
+        RTDTextParseModeMarkdownBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDTextParseModeMarkdownBuilder {
+        RTDTextParseModeMarkdownBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn version(&self) -> i64 {
+    pub fn version(&self) -> i32 {
         self.version
     }
+
+    pub fn with_version(mut self, version: i32) -> Self {
+        self.version = version;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDTextParseModeMarkdownBuilder {
     inner: TextParseModeMarkdown,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDTextParseModeMarkdownBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> TextParseModeMarkdown {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<TextParseModeMarkdown> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "TextParseModeMarkdown",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn version(&mut self, version: i64) -> &mut Self {
+    pub fn version(&mut self, version: i32) -> &mut Self {
         self.inner.version = version;
+        self.missing.retain(|f| *f != "version");
         self
     }
 }
@@ -215,3 +292,17 @@ impl AsRef<TextParseModeMarkdown> for RTDTextParseModeMarkdownBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(TextParseMode::HTML(TextParseModeHTML::builder().build()));
+        assert_round_trip(TextParseMode::Markdown(
+            TextParseModeMarkdown::builder().version(i32::MAX).build(),
+        ));
+    }
+}
@@ -1,58 +1,60 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Represents the categories of chats for which a list of frequently used chats can be retrieved
 pub trait TDTopChatCategory: Debug + RObject {}
 
 /// Represents the categories of chats for which a list of frequently used chats can be retrieved
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum TopChatCategory {
-    #[doc(hidden)]
-    _Default(()),
     /// A category containing frequently used private chats with bot users
+    #[serde(rename = "topChatCategoryBots")]
     Bots(TopChatCategoryBots),
     /// A category containing frequently used chats used for calls
+    #[serde(rename = "topChatCategoryCalls")]
     Calls(TopChatCategoryCalls),
     /// A category containing frequently used channels
+    #[serde(rename = "topChatCategoryChannels")]
     Channels(TopChatCategoryChannels),
     /// A category containing frequently used chats used to forward messages
+    #[serde(rename = "topChatCategoryForwardChats")]
     ForwardChats(TopChatCategoryForwardChats),
     /// A category containing frequently used basic groups and supergroups
+    #[serde(rename = "topChatCategoryGroups")]
     Groups(TopChatCategoryGroups),
     /// A category containing frequently used chats with inline bots sorted by their usage in inline mode
+    #[serde(rename = "topChatCategoryInlineBots")]
     InlineBots(TopChatCategoryInlineBots),
     /// A category containing frequently used private chats with non-bot users
+    #[serde(rename = "topChatCategoryUsers")]
     Users(TopChatCategoryUsers),
 }
 
+/// Default value is default of [TopChatCategoryBots], builders still require the field to be set
 impl Default for TopChatCategory {
     fn default() -> Self {
-        TopChatCategory::_Default(())
+        TopChatCategory::Bots(TopChatCategoryBots::default())
     }
 }
 
-impl<'de> Deserialize<'de> for TopChatCategory {
-    fn deserialize<D>(deserializer: D) -> Result<TopChatCategory, D::Error>
+impl Serialize for TopChatCategory {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          TopChatCategory,
-          (topChatCategoryBots, Bots);
-          (topChatCategoryCalls, Calls);
-          (topChatCategoryChannels, Channels);
-          (topChatCategoryForwardChats, ForwardChats);
-          (topChatCategoryGroups, Groups);
-          (topChatCategoryInlineBots, InlineBots);
-          (topChatCategoryUsers, Users);
-
-        )(deserializer)
+        match self {
+            TopChatCategory::Bots(t) => t.serialize(serializer),
+            TopChatCategory::Calls(t) => t.serialize(serializer),
+            TopChatCategory::Channels(t) => t.serialize(serializer),
+            TopChatCategory::ForwardChats(t) => t.serialize(serializer),
+            TopChatCategory::Groups(t) => t.serialize(serializer),
+            TopChatCategory::InlineBots(t) => t.serialize(serializer),
+            TopChatCategory::Users(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -67,8 +69,6 @@ impl RObject for TopChatCategory {
             TopChatCategory::Groups(t) => t.td_name(),
             TopChatCategory::InlineBots(t) => t.td_name(),
             TopChatCategory::Users(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -81,8 +81,6 @@ impl RObject for TopChatCategory {
             TopChatCategory::Groups(t) => t.extra(),
             TopChatCategory::InlineBots(t) => t.extra(),
             TopChatCategory::Users(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -94,10 +92,6 @@ impl TopChatCategory {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, TopChatCategory::_Default(_))
-    }
 }
 
 impl AsRef<TopChatCategory> for TopChatCategory {
@@ -108,12 +102,13 @@ impl AsRef<TopChatCategory> for TopChatCategory {
 
 /// A category containing frequently used private chats with bot users
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "topChatCategoryBots")]
 pub struct TopChatCategoryBots {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -131,6 +126,18 @@ impl RObject for TopChatCategoryBots {
     }
 }
 
+impl PartialEq for TopChatCategoryBots {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for TopChatCategoryBots {}
+
+impl std::hash::Hash for TopChatCategoryBots {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDTopChatCategory for TopChatCategoryBots {}
 
 impl TopChatCategoryBots {
@@ -138,22 +145,49 @@ impl TopChatCategoryBots {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDTopChatCategoryBotsBuilder {
-        let mut inner = TopChatCategoryBots::default();
-        inner.td_name = "topChatCategoryBots".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDTopChatCategoryBotsBuilder { inner }
+        RTDTopChatCategoryBotsBuilder {
+            inner: TopChatCategoryBots::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDTopChatCategoryBotsBuilder {
+        RTDTopChatCategoryBotsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDTopChatCategoryBotsBuilder {
+        RTDTopChatCategoryBotsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDTopChatCategoryBotsBuilder {
     inner: TopChatCategoryBots,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDTopChatCategoryBotsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> TopChatCategoryBots {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<TopChatCategoryBots> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "TopChatCategoryBots",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<TopChatCategoryBots> for TopChatCategoryBots {
@@ -170,12 +204,13 @@ impl AsRef<TopChatCategoryBots> for RTDTopChatCategoryBotsBuilder {
 
 /// A category containing frequently used chats used for calls
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "topChatCategoryCalls")]
 pub struct TopChatCategoryCalls {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -193,6 +228,18 @@ impl RObject for TopChatCategoryCalls {
     }
 }
 
+impl PartialEq for TopChatCategoryCalls {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for TopChatCategoryCalls {}
+
+impl std::hash::Hash for TopChatCategoryCalls {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDTopChatCategory for TopChatCategoryCalls {}
 
 impl TopChatCategoryCalls {
@@ -200,22 +247,49 @@ impl TopChatCategoryCalls {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDTopChatCategoryCallsBuilder {
-        let mut inner = TopChatCategoryCalls::default();
-        inner.td_name = "topChatCategoryCalls".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDTopChatCategoryCallsBuilder { inner }
+        RTDTopChatCategoryCallsBuilder {
+            inner: TopChatCategoryCalls::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDTopChatCategoryCallsBuilder {
+        RTDTopChatCategoryCallsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDTopChatCategoryCallsBuilder {
+        RTDTopChatCategoryCallsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDTopChatCategoryCallsBuilder {
     inner: TopChatCategoryCalls,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDTopChatCategoryCallsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> TopChatCategoryCalls {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<TopChatCategoryCalls> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "TopChatCategoryCalls",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<TopChatCategoryCalls> for TopChatCategoryCalls {
@@ -232,12 +306,13 @@ impl AsRef<TopChatCategoryCalls> for RTDTopChatCategoryCallsBuilder {
 
 /// A category containing frequently used channels
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "topChatCategoryChannels")]
 pub struct TopChatCategoryChannels {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -255,6 +330,18 @@ impl RObject for TopChatCategoryChannels {
     }
 }
 
+impl PartialEq for TopChatCategoryChannels {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for TopChatCategoryChannels {}
+
+impl std::hash::Hash for TopChatCategoryChannels {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDTopChatCategory for TopChatCategoryChannels {}
 
 impl TopChatCategoryChannels {
@@ -262,22 +349,49 @@ impl TopChatCategoryChannels {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDTopChatCategoryChannelsBuilder {
-        let mut inner = TopChatCategoryChannels::default();
-        inner.td_name = "topChatCategoryChannels".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDTopChatCategoryChannelsBuilder { inner }
+        RTDTopChatCategoryChannelsBuilder {
+            inner: TopChatCategoryChannels::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDTopChatCategoryChannelsBuilder {
+        RTDTopChatCategoryChannelsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDTopChatCategoryChannelsBuilder {
+        RTDTopChatCategoryChannelsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDTopChatCategoryChannelsBuilder {
     inner: TopChatCategoryChannels,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDTopChatCategoryChannelsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> TopChatCategoryChannels {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<TopChatCategoryChannels> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "TopChatCategoryChannels",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<TopChatCategoryChannels> for TopChatCategoryChannels {
@@ -294,12 +408,13 @@ impl AsRef<TopChatCategoryChannels> for RTDTopChatCategoryChannelsBuilder {
 
 /// A category containing frequently used chats used to forward messages
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "topChatCategoryForwardChats")]
 pub struct TopChatCategoryForwardChats {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -317,6 +432,18 @@ impl RObject for TopChatCategoryForwardChats {
     }
 }
 
+impl PartialEq for TopChatCategoryForwardChats {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for TopChatCategoryForwardChats {}
+
+impl std::hash::Hash for TopChatCategoryForwardChats {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDTopChatCategory for TopChatCategoryForwardChats {}
 
 impl TopChatCategoryForwardChats {
@@ -324,22 +451,49 @@ impl TopChatCategoryForwardChats {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDTopChatCategoryForwardChatsBuilder {
-        let mut inner = TopChatCategoryForwardChats::default();
-        inner.td_name = "topChatCategoryForwardChats".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDTopChatCategoryForwardChatsBuilder { inner }
+        RTDTopChatCategoryForwardChatsBuilder {
+            inner: TopChatCategoryForwardChats::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDTopChatCategoryForwardChatsBuilder {
+        RTDTopChatCategoryForwardChatsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDTopChatCategoryForwardChatsBuilder {
+        RTDTopChatCategoryForwardChatsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDTopChatCategoryForwardChatsBuilder {
     inner: TopChatCategoryForwardChats,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDTopChatCategoryForwardChatsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> TopChatCategoryForwardChats {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<TopChatCategoryForwardChats> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "TopChatCategoryForwardChats",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<TopChatCategoryForwardChats> for TopChatCategoryForwardChats {
@@ -356,12 +510,13 @@ impl AsRef<TopChatCategoryForwardChats> for RTDTopChatCategoryForwardChatsBuilde
 
 /// A category containing frequently used basic groups and supergroups
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "topChatCategoryGroups")]
 pub struct TopChatCategoryGroups {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -379,6 +534,18 @@ impl RObject for TopChatCategoryGroups {
     }
 }
 
+impl PartialEq for TopChatCategoryGroups {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for TopChatCategoryGroups {}
+
+impl std::hash::Hash for TopChatCategoryGroups {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDTopChatCategory for TopChatCategoryGroups {}
 
 impl TopChatCategoryGroups {
@@ -386,22 +553,49 @@ impl TopChatCategoryGroups {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDTopChatCategoryGroupsBuilder {
-        let mut inner = TopChatCategoryGroups::default();
-        inner.td_name = "topChatCategoryGroups".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDTopChatCategoryGroupsBuilder { inner }
+        RTDTopChatCategoryGroupsBuilder {
+            inner: TopChatCategoryGroups::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDTopChatCategoryGroupsBuilder {
+        RTDTopChatCategoryGroupsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDTopChatCategoryGroupsBuilder {
+        RTDTopChatCategoryGroupsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDTopChatCategoryGroupsBuilder {
     inner: TopChatCategoryGroups,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDTopChatCategoryGroupsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> TopChatCategoryGroups {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<TopChatCategoryGroups> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "TopChatCategoryGroups",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<TopChatCategoryGroups> for TopChatCategoryGroups {
@@ -418,12 +612,13 @@ impl AsRef<TopChatCategoryGroups> for RTDTopChatCategoryGroupsBuilder {
 
 /// A category containing frequently used chats with inline bots sorted by their usage in inline mode
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "topChatCategoryInlineBots")]
 pub struct TopChatCategoryInlineBots {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -441,6 +636,18 @@ impl RObject for TopChatCategoryInlineBots {
     }
 }
 
+impl PartialEq for TopChatCategoryInlineBots {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for TopChatCategoryInlineBots {}
+
+impl std::hash::Hash for TopChatCategoryInlineBots {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDTopChatCategory for TopChatCategoryInlineBots {}
 
 impl TopChatCategoryInlineBots {
@@ -448,22 +655,49 @@ impl TopChatCategoryInlineBots {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDTopChatCategoryInlineBotsBuilder {
-        let mut inner = TopChatCategoryInlineBots::default();
-        inner.td_name = "topChatCategoryInlineBots".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDTopChatCategoryInlineBotsBuilder { inner }
+        RTDTopChatCategoryInlineBotsBuilder {
+            inner: TopChatCategoryInlineBots::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDTopChatCategoryInlineBotsBuilder {
+        RTDTopChatCategoryInlineBotsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDTopChatCategoryInlineBotsBuilder {
+        RTDTopChatCategoryInlineBotsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDTopChatCategoryInlineBotsBuilder {
     inner: TopChatCategoryInlineBots,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDTopChatCategoryInlineBotsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> TopChatCategoryInlineBots {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<TopChatCategoryInlineBots> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "TopChatCategoryInlineBots",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<TopChatCategoryInlineBots> for TopChatCategoryInlineBots {
@@ -480,12 +714,13 @@ impl AsRef<TopChatCategoryInlineBots> for RTDTopChatCategoryInlineBotsBuilder {
 
 /// A category containing frequently used private chats with non-bot users
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "topChatCategoryUsers")]
 pub struct TopChatCategoryUsers {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -503,6 +738,18 @@ impl RObject for TopChatCategoryUsers {
     }
 }
 
+impl PartialEq for TopChatCategoryUsers {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for TopChatCategoryUsers {}
+
+impl std::hash::Hash for TopChatCategoryUsers {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDTopChatCategory for TopChatCategoryUsers {}
 
 impl TopChatCategoryUsers {
@@ -510,22 +757,49 @@ impl TopChatCategoryUsers {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDTopChatCategoryUsersBuilder {
-        let mut inner = TopChatCategoryUsers::default();
-        inner.td_name = "topChatCategoryUsers".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDTopChatCategoryUsersBuilder { inner }
+        RTDTopChatCategoryUsersBuilder {
+            inner: TopChatCategoryUsers::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDTopChatCategoryUsersBuilder {
+        RTDTopChatCategoryUsersBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDTopChatCategoryUsersBuilder {
+        RTDTopChatCategoryUsersBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDTopChatCategoryUsersBuilder {
     inner: TopChatCategoryUsers,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDTopChatCategoryUsersBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> TopChatCategoryUsers {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<TopChatCategoryUsers> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "TopChatCategoryUsers",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<TopChatCategoryUsers> for TopChatCategoryUsers {
@@ -539,3 +813,34 @@ impl AsRef<TopChatCategoryUsers> for RTDTopChatCategoryUsersBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(TopChatCategory::Bots(
+            TopChatCategoryBots::builder().build(),
+        ));
+        assert_round_trip(TopChatCategory::Calls(
+            TopChatCategoryCalls::builder().build(),
+        ));
+        assert_round_trip(TopChatCategory::Channels(
+            TopChatCategoryChannels::builder().build(),
+        ));
+        assert_round_trip(TopChatCategory::ForwardChats(
+            TopChatCategoryForwardChats::builder().build(),
+        ));
+        assert_round_trip(TopChatCategory::Groups(
+            TopChatCategoryGroups::builder().build(),
+        ));
+        assert_round_trip(TopChatCategory::InlineBots(
+            TopChatCategoryInlineBots::builder().build(),
+        ));
+        assert_round_trip(TopChatCategory::Users(
+            TopChatCategoryUsers::builder().build(),
+        ));
+    }
+}
@@ -1,268 +1,336 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Contains notifications about data changes
 pub trait TDUpdate: Debug + RObject {}
 
 /// Contains notifications about data changes
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "@type")]
 pub enum Update {
-    #[doc(hidden)]
-    _Default(()),
-    /// Does nothing and ensures that the Update object is used; for testing only. This is an offline method. Can be called before authorization
-    TestUseUpdate(TestUseUpdate),
     /// Contains active notifications that was shown on previous application launches. This update is sent only if the message database is used. In that case it comes once before any updateNotification and updateNotificationGroup update
+    #[serde(rename = "updateActiveNotifications")]
     ActiveNotifications(UpdateActiveNotifications),
     /// The user authorization state has changed
+    #[serde(rename = "updateAuthorizationState")]
     AuthorizationState(UpdateAuthorizationState),
     /// Some data of a basic group has changed. This update is guaranteed to come before the basic group identifier is returned to the client
+    #[serde(rename = "updateBasicGroup")]
     BasicGroup(UpdateBasicGroup),
     /// Some data from basicGroupFullInfo has been changed
+    #[serde(rename = "updateBasicGroupFullInfo")]
     BasicGroupFullInfo(UpdateBasicGroupFullInfo),
     /// New call was created or information about a call was updated
+    #[serde(rename = "updateCall")]
     Call(UpdateCall),
     /// The chat action bar was changed
+    #[serde(rename = "updateChatActionBar")]
     ChatActionBar(UpdateChatActionBar),
     /// The list to which the chat belongs was changed. This update is guaranteed to be sent only when chat.order == 0 and the current or the new chat list is null
+    #[serde(rename = "updateChatChatList")]
     ChatChatList(UpdateChatChatList),
     /// The value of the default disable_notification parameter, used when a message is sent to the chat, was changed
+    #[serde(rename = "updateChatDefaultDisableNotification")]
     ChatDefaultDisableNotification(UpdateChatDefaultDisableNotification),
     /// A chat draft has changed. Be aware that the update may come in the currently opened chat but with old content of the draft. If the user has changed the content of the draft, this update shouldn't be applied
+    #[serde(rename = "updateChatDraftMessage")]
     ChatDraftMessage(UpdateChatDraftMessage),
     /// A chat's has_scheduled_messages field has changed
+    #[serde(rename = "updateChatHasScheduledMessages")]
     ChatHasScheduledMessages(UpdateChatHasScheduledMessages),
     /// A chat was marked as unread or was read
+    #[serde(rename = "updateChatIsMarkedAsUnread")]
     ChatIsMarkedAsUnread(UpdateChatIsMarkedAsUnread),
     /// A chat was pinned or unpinned
+    #[serde(rename = "updateChatIsPinned")]
     ChatIsPinned(UpdateChatIsPinned),
     /// A chat's is_sponsored field has changed
+    #[serde(rename = "updateChatIsSponsored")]
     ChatIsSponsored(UpdateChatIsSponsored),
     /// The last message of a chat was changed. If last_message is null, then the last message in the chat became unknown. Some new unknown messages might be added to the chat in this case
+    #[serde(rename = "updateChatLastMessage")]
     ChatLastMessage(UpdateChatLastMessage),
     /// Notification settings for a chat were changed
+    #[serde(rename = "updateChatNotificationSettings")]
     ChatNotificationSettings(UpdateChatNotificationSettings),
     /// The number of online group members has changed. This update with non-zero count is sent only for currently opened chats. There is no guarantee that it will be sent just after the count has changed
+    #[serde(rename = "updateChatOnlineMemberCount")]
     ChatOnlineMemberCount(UpdateChatOnlineMemberCount),
     /// The order of the chat in the chat list has changed. Instead of this update updateChatLastMessage, updateChatIsPinned, updateChatDraftMessage, or updateChatIsSponsored might be sent
+    #[serde(rename = "updateChatOrder")]
     ChatOrder(UpdateChatOrder),
     /// Chat permissions was changed
+    #[serde(rename = "updateChatPermissions")]
     ChatPermissions(UpdateChatPermissions),
     /// A chat photo was changed
+    #[serde(rename = "updateChatPhoto")]
     ChatPhoto(UpdateChatPhoto),
     /// The chat pinned message was changed
+    #[serde(rename = "updateChatPinnedMessage")]
     ChatPinnedMessage(UpdateChatPinnedMessage),
     /// Incoming messages were read or number of unread messages has been changed
+    #[serde(rename = "updateChatReadInbox")]
     ChatReadInbox(UpdateChatReadInbox),
     /// Outgoing messages were read
+    #[serde(rename = "updateChatReadOutbox")]
     ChatReadOutbox(UpdateChatReadOutbox),
     /// The default chat reply markup was changed. Can occur because new messages with reply markup were received or because an old reply markup was hidden by the user
+    #[serde(rename = "updateChatReplyMarkup")]
     ChatReplyMarkup(UpdateChatReplyMarkup),
     /// The title of a chat was changed
+    #[serde(rename = "updateChatTitle")]
     ChatTitle(UpdateChatTitle),
     /// The chat unread_mention_count has changed
+    #[serde(rename = "updateChatUnreadMentionCount")]
     ChatUnreadMentionCount(UpdateChatUnreadMentionCount),
     /// The connection state has changed
+    #[serde(rename = "updateConnectionState")]
     ConnectionState(UpdateConnectionState),
     /// Some messages were deleted
+    #[serde(rename = "updateDeleteMessages")]
     DeleteMessages(UpdateDeleteMessages),
     /// The list of favorite stickers was updated
+    #[serde(rename = "updateFavoriteStickers")]
     FavoriteStickers(UpdateFavoriteStickers),
     /// Information about a file was updated
+    #[serde(rename = "updateFile")]
     File(UpdateFile),
     /// The file generation process needs to be started by the client
+    #[serde(rename = "updateFileGenerationStart")]
     FileGenerationStart(UpdateFileGenerationStart),
     /// File generation is no longer needed
+    #[serde(rename = "updateFileGenerationStop")]
     FileGenerationStop(UpdateFileGenerationStop),
     /// Describes whether there are some pending notification updates. Can be used to prevent application from killing, while there are some pending notifications
+    #[serde(rename = "updateHavePendingNotifications")]
     HavePendingNotifications(UpdateHavePendingNotifications),
     /// The list of installed sticker sets was updated
+    #[serde(rename = "updateInstalledStickerSets")]
     InstalledStickerSets(UpdateInstalledStickerSets),
     /// Some language pack strings have been updated
+    #[serde(rename = "updateLanguagePackStrings")]
     LanguagePackStrings(UpdateLanguagePackStrings),
     /// The message content has changed
+    #[serde(rename = "updateMessageContent")]
     MessageContent(UpdateMessageContent),
     /// The message content was opened. Updates voice note messages to "listened", video note messages to "viewed" and starts the TTL timer for self-destructing messages
+    #[serde(rename = "updateMessageContentOpened")]
     MessageContentOpened(UpdateMessageContentOpened),
     /// A message was edited. Changes in the message content will come in a separate updateMessageContent
+    #[serde(rename = "updateMessageEdited")]
     MessageEdited(UpdateMessageEdited),
     /// A message with a live location was viewed. When the update is received, the client is supposed to update the live location
+    #[serde(rename = "updateMessageLiveLocationViewed")]
     MessageLiveLocationViewed(UpdateMessageLiveLocationViewed),
     /// A message with an unread mention was read
+    #[serde(rename = "updateMessageMentionRead")]
     MessageMentionRead(UpdateMessageMentionRead),
     /// A request to send a message has reached the Telegram server. This doesn't mean that the message will be sent successfully or even that the send message request will be processed. This update will be sent only if the option "use_quick_ack" is set to true. This update may be sent multiple times for the same message
+    #[serde(rename = "updateMessageSendAcknowledged")]
     MessageSendAcknowledged(UpdateMessageSendAcknowledged),
     /// A message failed to send. Be aware that some messages being sent can be irrecoverably deleted, in which case updateDeleteMessages will be received instead of this update
+    #[serde(rename = "updateMessageSendFailed")]
     MessageSendFailed(UpdateMessageSendFailed),
     /// A message has been successfully sent
+    #[serde(rename = "updateMessageSendSucceeded")]
     MessageSendSucceeded(UpdateMessageSendSucceeded),
     /// The view count of the message has changed
+    #[serde(rename = "updateMessageViews")]
     MessageViews(UpdateMessageViews),
     /// A new incoming callback query; for bots only
+    #[serde(rename = "updateNewCallbackQuery")]
     NewCallbackQuery(UpdateNewCallbackQuery),
     /// A new chat has been loaded/created. This update is guaranteed to come before the chat identifier is returned to the client. The chat field changes will be reported through separate updates
+    #[serde(rename = "updateNewChat")]
     NewChat(UpdateNewChat),
     /// The user has chosen a result of an inline query; for bots only
+    #[serde(rename = "updateNewChosenInlineResult")]
     NewChosenInlineResult(UpdateNewChosenInlineResult),
     /// A new incoming event; for bots only
+    #[serde(rename = "updateNewCustomEvent")]
     NewCustomEvent(UpdateNewCustomEvent),
     /// A new incoming query; for bots only
+    #[serde(rename = "updateNewCustomQuery")]
     NewCustomQuery(UpdateNewCustomQuery),
     /// A new incoming callback query from a message sent via a bot; for bots only
+    #[serde(rename = "updateNewInlineCallbackQuery")]
     NewInlineCallbackQuery(UpdateNewInlineCallbackQuery),
     /// A new incoming inline query; for bots only
+    #[serde(rename = "updateNewInlineQuery")]
     NewInlineQuery(UpdateNewInlineQuery),
     /// A new message was received; can also be an outgoing message
+    #[serde(rename = "updateNewMessage")]
     NewMessage(UpdateNewMessage),
     /// A new incoming pre-checkout query; for bots only. Contains full information about a checkout
+    #[serde(rename = "updateNewPreCheckoutQuery")]
     NewPreCheckoutQuery(UpdateNewPreCheckoutQuery),
     /// A new incoming shipping query; for bots only. Only for invoices with flexible price
+    #[serde(rename = "updateNewShippingQuery")]
     NewShippingQuery(UpdateNewShippingQuery),
     /// A notification was changed
+    #[serde(rename = "updateNotification")]
     Notification(UpdateNotification),
     /// A list of active notifications in a notification group has changed
+    #[serde(rename = "updateNotificationGroup")]
     NotificationGroup(UpdateNotificationGroup),
     /// An option changed its value
+    #[serde(rename = "updateOption")]
     Option(UpdateOption),
     /// A poll was updated; for bots only
+    #[serde(rename = "updatePoll")]
     Poll(UpdatePoll),
     /// A user changed the answer to a poll; for bots only
+    #[serde(rename = "updatePollAnswer")]
     PollAnswer(UpdatePollAnswer),
     /// The list of recently used stickers was updated
+    #[serde(rename = "updateRecentStickers")]
     RecentStickers(UpdateRecentStickers),
     /// The list of saved animations was updated
+    #[serde(rename = "updateSavedAnimations")]
     SavedAnimations(UpdateSavedAnimations),
     /// Notification settings for some type of chats were updated
+    #[serde(rename = "updateScopeNotificationSettings")]
     ScopeNotificationSettings(UpdateScopeNotificationSettings),
     /// Some data of a secret chat has changed. This update is guaranteed to come before the secret chat identifier is returned to the client
+    #[serde(rename = "updateSecretChat")]
     SecretChat(UpdateSecretChat),
     /// The selected background has changed
+    #[serde(rename = "updateSelectedBackground")]
     SelectedBackground(UpdateSelectedBackground),
     /// Service notification from the server. Upon receiving this the client must show a popup with the content of the notification
+    #[serde(rename = "updateServiceNotification")]
     ServiceNotification(UpdateServiceNotification),
     /// Some data of a supergroup or a channel has changed. This update is guaranteed to come before the supergroup identifier is returned to the client
+    #[serde(rename = "updateSupergroup")]
     Supergroup(UpdateSupergroup),
     /// Some data from supergroupFullInfo has been changed
+    #[serde(rename = "updateSupergroupFullInfo")]
     SupergroupFullInfo(UpdateSupergroupFullInfo),
     /// New terms of service must be accepted by the user. If the terms of service are declined, then the deleteAccount method should be called with the reason "Decline ToS update"
+    #[serde(rename = "updateTermsOfService")]
     TermsOfService(UpdateTermsOfService),
     /// The list of trending sticker sets was updated or some of them were viewed
+    #[serde(rename = "updateTrendingStickerSets")]
     TrendingStickerSets(UpdateTrendingStickerSets),
     /// Number of unread chats, i.e. with unread messages or marked as unread, has changed. This update is sent only if the message database is used
+    #[serde(rename = "updateUnreadChatCount")]
     UnreadChatCount(UpdateUnreadChatCount),
     /// Number of unread messages in a chat list has changed. This update is sent only if the message database is used
+    #[serde(rename = "updateUnreadMessageCount")]
     UnreadMessageCount(UpdateUnreadMessageCount),
     /// Some data of a user has changed. This update is guaranteed to come before the user identifier is returned to the client
+    #[serde(rename = "updateUser")]
     User(UpdateUser),
     /// User activity in the chat has changed
+    #[serde(rename = "updateUserChatAction")]
     UserChatAction(UpdateUserChatAction),
     /// Some data from userFullInfo has been changed
+    #[serde(rename = "updateUserFullInfo")]
     UserFullInfo(UpdateUserFullInfo),
     /// Some privacy setting rules have been changed
+    #[serde(rename = "updateUserPrivacySettingRules")]
     UserPrivacySettingRules(UpdateUserPrivacySettingRules),
     /// The user went online or offline
+    #[serde(rename = "updateUserStatus")]
     UserStatus(UpdateUserStatus),
     /// List of users nearby has changed. The update is sent only 60 seconds after a successful searchChatsNearby request
+    #[serde(rename = "updateUsersNearby")]
     UsersNearby(UpdateUsersNearby),
 }
 
+/// Default value is default of [UpdateActiveNotifications], builders still require the field to be set
 impl Default for Update {
     fn default() -> Self {
-        Update::_Default(())
+        Update::ActiveNotifications(UpdateActiveNotifications::default())
     }
 }
 
-impl<'de> Deserialize<'de> for Update {
-    fn deserialize<D>(deserializer: D) -> Result<Update, D::Error>
+impl Serialize for Update {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          Update,
-          (testUseUpdate, TestUseUpdate);
-          (updateActiveNotifications, ActiveNotifications);
-          (updateAuthorizationState, AuthorizationState);
-          (updateBasicGroup, BasicGroup);
-          (updateBasicGroupFullInfo, BasicGroupFullInfo);
-          (updateCall, Call);
-          (updateChatActionBar, ChatActionBar);
-          (updateChatChatList, ChatChatList);
-          (updateChatDefaultDisableNotification, ChatDefaultDisableNotification);
-          (updateChatDraftMessage, ChatDraftMessage);
-          (updateChatHasScheduledMessages, ChatHasScheduledMessages);
-          (updateChatIsMarkedAsUnread, ChatIsMarkedAsUnread);
-          (updateChatIsPinned, ChatIsPinned);
-          (updateChatIsSponsored, ChatIsSponsored);
-          (updateChatLastMessage, ChatLastMessage);
-          (updateChatNotificationSettings, ChatNotificationSettings);
-          (updateChatOnlineMemberCount, ChatOnlineMemberCount);
-          (updateChatOrder, ChatOrder);
-          (updateChatPermissions, ChatPermissions);
-          (updateChatPhoto, ChatPhoto);
-          (updateChatPinnedMessage, ChatPinnedMessage);
-          (updateChatReadInbox, ChatReadInbox);
-          (updateChatReadOutbox, ChatReadOutbox);
-          (updateChatReplyMarkup, ChatReplyMarkup);
-          (updateChatTitle, ChatTitle);
-          (updateChatUnreadMentionCount, ChatUnreadMentionCount);
-          (updateConnectionState, ConnectionState);
-          (updateDeleteMessages, DeleteMessages);
-          (updateFavoriteStickers, FavoriteStickers);
-          (updateFile, File);
-          (updateFileGenerationStart, FileGenerationStart);
-          (updateFileGenerationStop, FileGenerationStop);
-          (updateHavePendingNotifications, HavePendingNotifications);
-          (updateInstalledStickerSets, InstalledStickerSets);
-          (updateLanguagePackStrings, LanguagePackStrings);
-          (updateMessageContent, MessageContent);
-          (updateMessageContentOpened, MessageContentOpened);
-          (updateMessageEdited, MessageEdited);
-          (updateMessageLiveLocationViewed, MessageLiveLocationViewed);
-          (updateMessageMentionRead, MessageMentionRead);
-          (updateMessageSendAcknowledged, MessageSendAcknowledged);
-          (updateMessageSendFailed, MessageSendFailed);
-          (updateMessageSendSucceeded, MessageSendSucceeded);
-          (updateMessageViews, MessageViews);
-          (updateNewCallbackQuery, NewCallbackQuery);
-          (updateNewChat, NewChat);
-          (updateNewChosenInlineResult, NewChosenInlineResult);
-          (updateNewCustomEvent, NewCustomEvent);
-          (updateNewCustomQuery, NewCustomQuery);
-          (updateNewInlineCallbackQuery, NewInlineCallbackQuery);
-          (updateNewInlineQuery, NewInlineQuery);
-          (updateNewMessage, NewMessage);
-          (updateNewPreCheckoutQuery, NewPreCheckoutQuery);
-          (updateNewShippingQuery, NewShippingQuery);
-          (updateNotification, Notification);
-          (updateNotificationGroup, NotificationGroup);
-          (updateOption, Option);
-          (updatePoll, Poll);
-          (updatePollAnswer, PollAnswer);
-          (updateRecentStickers, RecentStickers);
-          (updateSavedAnimations, SavedAnimations);
-          (updateScopeNotificationSettings, ScopeNotificationSettings);
-          (updateSecretChat, SecretChat);
-          (updateSelectedBackground, SelectedBackground);
-          (updateServiceNotification, ServiceNotification);
-          (updateSupergroup, Supergroup);
-          (updateSupergroupFullInfo, SupergroupFullInfo);
-          (updateTermsOfService, TermsOfService);
-          (updateTrendingStickerSets, TrendingStickerSets);
-          (updateUnreadChatCount, UnreadChatCount);
-          (updateUnreadMessageCount, UnreadMessageCount);
-          (updateUser, User);
-          (updateUserChatAction, UserChatAction);
-          (updateUserFullInfo, UserFullInfo);
-          (updateUserPrivacySettingRules, UserPrivacySettingRules);
-          (updateUserStatus, UserStatus);
-          (updateUsersNearby, UsersNearby);
-
-        )(deserializer)
+        match self {
+            Update::ActiveNotifications(t) => t.serialize(serializer),
+            Update::AuthorizationState(t) => t.serialize(serializer),
+            Update::BasicGroup(t) => t.serialize(serializer),
+            Update::BasicGroupFullInfo(t) => t.serialize(serializer),
+            Update::Call(t) => t.serialize(serializer),
+            Update::ChatActionBar(t) => t.serialize(serializer),
+            Update::ChatChatList(t) => t.serialize(serializer),
+            Update::ChatDefaultDisableNotification(t) => t.serialize(serializer),
+            Update::ChatDraftMessage(t) => t.serialize(serializer),
+            Update::ChatHasScheduledMessages(t) => t.serialize(serializer),
+            Update::ChatIsMarkedAsUnread(t) => t.serialize(serializer),
+            Update::ChatIsPinned(t) => t.serialize(serializer),
+            Update::ChatIsSponsored(t) => t.serialize(serializer),
+            Update::ChatLastMessage(t) => t.serialize(serializer),
+            Update::ChatNotificationSettings(t) => t.serialize(serializer),
+            Update::ChatOnlineMemberCount(t) => t.serialize(serializer),
+            Update::ChatOrder(t) => t.serialize(serializer),
+            Update::ChatPermissions(t) => t.serialize(serializer),
+            Update::ChatPhoto(t) => t.serialize(serializer),
+            Update::ChatPinnedMessage(t) => t.serialize(serializer),
+            Update::ChatReadInbox(t) => t.serialize(serializer),
+            Update::ChatReadOutbox(t) => t.serialize(serializer),
+            Update::ChatReplyMarkup(t) => t.serialize(serializer),
+            Update::ChatTitle(t) => t.serialize(serializer),
+            Update::ChatUnreadMentionCount(t) => t.serialize(serializer),
+            Update::ConnectionState(t) => t.serialize(serializer),
+            Update::DeleteMessages(t) => t.serialize(serializer),
+            Update::FavoriteStickers(t) => t.serialize(serializer),
+            Update::File(t) => t.serialize(serializer),
+            Update::FileGenerationStart(t) => t.serialize(serializer),
+            Update::FileGenerationStop(t) => t.serialize(serializer),
+            Update::HavePendingNotifications(t) => t.serialize(serializer),
+            Update::InstalledStickerSets(t) => t.serialize(serializer),
+            Update::LanguagePackStrings(t) => t.serialize(serializer),
+            Update::MessageContent(t) => t.serialize(serializer),
+            Update::MessageContentOpened(t) => t.serialize(serializer),
+            Update::MessageEdited(t) => t.serialize(serializer),
+            Update::MessageLiveLocationViewed(t) => t.serialize(serializer),
+            Update::MessageMentionRead(t) => t.serialize(serializer),
+            Update::MessageSendAcknowledged(t) => t.serialize(serializer),
+            Update::MessageSendFailed(t) => t.serialize(serializer),
+            Update::MessageSendSucceeded(t) => t.serialize(serializer),
+            Update::MessageViews(t) => t.serialize(serializer),
+            Update::NewCallbackQuery(t) => t.serialize(serializer),
+            Update::NewChat(t) => t.serialize(serializer),
+            Update::NewChosenInlineResult(t) => t.serialize(serializer),
+            Update::NewCustomEvent(t) => t.serialize(serializer),
+            Update::NewCustomQuery(t) => t.serialize(serializer),
+            Update::NewInlineCallbackQuery(t) => t.serialize(serializer),
+            Update::NewInlineQuery(t) => t.serialize(serializer),
+            Update::NewMessage(t) => t.serialize(serializer),
+            Update::NewPreCheckoutQuery(t) => t.serialize(serializer),
+            Update::NewShippingQuery(t) => t.serialize(serializer),
+            Update::Notification(t) => t.serialize(serializer),
+            Update::NotificationGroup(t) => t.serialize(serializer),
+            Update::Option(t) => t.serialize(serializer),
+            Update::Poll(t) => t.serialize(serializer),
+            Update::PollAnswer(t) => t.serialize(serializer),
+            Update::RecentStickers(t) => t.serialize(serializer),
+            Update::SavedAnimations(t) => t.serialize(serializer),
+            Update::ScopeNotificationSettings(t) => t.serialize(serializer),
+            Update::SecretChat(t) => t.serialize(serializer),
+            Update::SelectedBackground(t) => t.serialize(serializer),
+            Update::ServiceNotification(t) => t.serialize(serializer),
+            Update::Supergroup(t) => t.serialize(serializer),
+            Update::SupergroupFullInfo(t) => t.serialize(serializer),
+            Update::TermsOfService(t) => t.serialize(serializer),
+            Update::TrendingStickerSets(t) => t.serialize(serializer),
+            Update::UnreadChatCount(t) => t.serialize(serializer),
+            Update::UnreadMessageCount(t) => t.serialize(serializer),
+            Update::User(t) => t.serialize(serializer),
+            Update::UserChatAction(t) => t.serialize(serializer),
+            Update::UserFullInfo(t) => t.serialize(serializer),
+            Update::UserPrivacySettingRules(t) => t.serialize(serializer),
+            Update::UserStatus(t) => t.serialize(serializer),
+            Update::UsersNearby(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -270,7 +338,6 @@ impl RObject for Update {
     #[doc(hidden)]
     fn td_name(&self) -> &'static str {
         match self {
-            Update::TestUseUpdate(t) => t.td_name(),
             Update::ActiveNotifications(t) => t.td_name(),
             Update::AuthorizationState(t) => t.td_name(),
             Update::BasicGroup(t) => t.td_name(),
@@ -347,14 +414,11 @@ impl RObject for Update {
             Update::UserPrivacySettingRules(t) => t.td_name(),
             Update::UserStatus(t) => t.td_name(),
             Update::UsersNearby(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
     fn extra(&self) -> Option<String> {
         match self {
-            Update::TestUseUpdate(t) => t.extra(),
             Update::ActiveNotifications(t) => t.extra(),
             Update::AuthorizationState(t) => t.extra(),
             Update::BasicGroup(t) => t.extra(),
@@ -431,8 +495,6 @@ impl RObject for Update {
             Update::UserPrivacySettingRules(t) => t.extra(),
             Update::UserStatus(t) => t.extra(),
             Update::UsersNearby(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -444,10 +506,6 @@ impl Update {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, Update::_Default(_))
-    }
 }
 
 impl AsRef<Update> for Update {
@@ -458,12 +516,13 @@ impl AsRef<Update> for Update {
 
 /// Contains active notifications that was shown on previous application launches. This update is sent only if the message database is used. In that case it comes once before any updateNotification and updateNotificationGroup update
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateActiveNotifications")]
 pub struct UpdateActiveNotifications {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Lists of active notification groups
     groups: Vec<NotificationGroup>,
@@ -483,6 +542,12 @@ impl RObject for UpdateActiveNotifications {
     }
 }
 
+impl PartialEq for UpdateActiveNotifications {
+    fn eq(&self, other: &Self) -> bool {
+        self.groups == other.groups
+    }
+}
+
 impl TDUpdate for UpdateActiveNotifications {}
 
 impl UpdateActiveNotifications {
@@ -490,29 +555,66 @@ impl UpdateActiveNotifications {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateActiveNotificationsBuilder {
-        let mut inner = UpdateActiveNotifications::default();
-        inner.td_name = "updateActiveNotifications".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateActiveNotificationsBuilder { inner }
+        RTDUpdateActiveNotificationsBuilder {
+            inner: UpdateActiveNotifications::default(),
+            missing: vec!["groups"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateActiveNotificationsBuilder {
+        RTDUpdateActiveNotificationsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateActiveNotificationsBuilder {
+        RTDUpdateActiveNotificationsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn groups(&self) -> &Vec<NotificationGroup> {
         &self.groups
     }
+
+    pub fn into_groups(self) -> Vec<NotificationGroup> {
+        self.groups
+    }
+
+    pub fn with_groups(mut self, groups: Vec<NotificationGroup>) -> Self {
+        self.groups = groups;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateActiveNotificationsBuilder {
     inner: UpdateActiveNotifications,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateActiveNotificationsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateActiveNotifications {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateActiveNotifications> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateActiveNotifications",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn groups(&mut self, groups: Vec<NotificationGroup>) -> &mut Self {
         self.inner.groups = groups;
+        self.missing.retain(|f| *f != "groups");
         self
     }
 }
@@ -531,12 +633,13 @@ impl AsRef<UpdateActiveNotifications> for RTDUpdateActiveNotificationsBuilder {
 
 /// The user authorization state has changed
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateAuthorizationState")]
 pub struct UpdateAuthorizationState {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// New authorization state
     authorization_state: AuthorizationState,
@@ -556,6 +659,20 @@ impl RObject for UpdateAuthorizationState {
     }
 }
 
+impl PartialEq for UpdateAuthorizationState {
+    fn eq(&self, other: &Self) -> bool {
+        self.authorization_state == other.authorization_state
+    }
+}
+
+impl Eq for UpdateAuthorizationState {}
+
+impl std::hash::Hash for UpdateAuthorizationState {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.authorization_state, state);
+    }
+}
+
 impl TDUpdate for UpdateAuthorizationState {}
 
 impl UpdateAuthorizationState {
@@ -563,32 +680,72 @@ impl UpdateAuthorizationState {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateAuthorizationStateBuilder {
-        let mut inner = UpdateAuthorizationState::default();
-        inner.td_name = "updateAuthorizationState".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateAuthorizationStateBuilder { inner }
+        RTDUpdateAuthorizationStateBuilder {
+            inner: UpdateAuthorizationState::default(),
+            missing: vec!["authorization_state"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateAuthorizationStateBuilder {
+        RTDUpdateAuthorizationStateBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateAuthorizationStateBuilder {
+        RTDUpdateAuthorizationStateBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn authorization_state(&self) -> &AuthorizationState {
         &self.authorization_state
     }
+
+    pub fn into_authorization_state(self) -> AuthorizationState {
+        self.authorization_state
+    }
+
+    pub fn with_authorization_state<T: AsRef<AuthorizationState>>(
+        mut self,
+        authorization_state: T,
+    ) -> Self {
+        self.authorization_state = authorization_state.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateAuthorizationStateBuilder {
     inner: UpdateAuthorizationState,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateAuthorizationStateBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateAuthorizationState {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateAuthorizationState> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateAuthorizationState",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn authorization_state<T: AsRef<AuthorizationState>>(
         &mut self,
         authorization_state: T,
     ) -> &mut Self {
         self.inner.authorization_state = authorization_state.as_ref().clone();
+        self.missing.retain(|f| *f != "authorization_state");
         self
     }
 }
@@ -607,12 +764,13 @@ impl AsRef<UpdateAuthorizationState> for RTDUpdateAuthorizationStateBuilder {
 
 /// Some data of a basic group has changed. This update is guaranteed to come before the basic group identifier is returned to the client
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateBasicGroup")]
 pub struct UpdateBasicGroup {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// New data about the group
     basic_group: BasicGroup,
@@ -632,6 +790,20 @@ impl RObject for UpdateBasicGroup {
     }
 }
 
+impl PartialEq for UpdateBasicGroup {
+    fn eq(&self, other: &Self) -> bool {
+        self.basic_group == other.basic_group
+    }
+}
+
+impl Eq for UpdateBasicGroup {}
+
+impl std::hash::Hash for UpdateBasicGroup {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.basic_group, state);
+    }
+}
+
 impl TDUpdate for UpdateBasicGroup {}
 
 impl UpdateBasicGroup {
@@ -639,29 +811,66 @@ impl UpdateBasicGroup {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateBasicGroupBuilder {
-        let mut inner = UpdateBasicGroup::default();
-        inner.td_name = "updateBasicGroup".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateBasicGroupBuilder { inner }
+        RTDUpdateBasicGroupBuilder {
+            inner: UpdateBasicGroup::default(),
+            missing: vec!["basic_group"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateBasicGroupBuilder {
+        RTDUpdateBasicGroupBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateBasicGroupBuilder {
+        RTDUpdateBasicGroupBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn basic_group(&self) -> &BasicGroup {
         &self.basic_group
     }
+
+    pub fn into_basic_group(self) -> BasicGroup {
+        self.basic_group
+    }
+
+    pub fn with_basic_group<T: AsRef<BasicGroup>>(mut self, basic_group: T) -> Self {
+        self.basic_group = basic_group.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateBasicGroupBuilder {
     inner: UpdateBasicGroup,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateBasicGroupBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateBasicGroup {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateBasicGroup> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateBasicGroup",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn basic_group<T: AsRef<BasicGroup>>(&mut self, basic_group: T) -> &mut Self {
         self.inner.basic_group = basic_group.as_ref().clone();
+        self.missing.retain(|f| *f != "basic_group");
         self
     }
 }
@@ -680,15 +889,16 @@ impl AsRef<UpdateBasicGroup> for RTDUpdateBasicGroupBuilder {
 
 /// Some data from basicGroupFullInfo has been changed
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateBasicGroupFullInfo")]
 pub struct UpdateBasicGroupFullInfo {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Identifier of a basic group
-    basic_group_id: i64,
+    basic_group_id: BasicGroupId,
     /// New full information about the group
     basic_group_full_info: BasicGroupFullInfo,
 }
@@ -707,6 +917,22 @@ impl RObject for UpdateBasicGroupFullInfo {
     }
 }
 
+impl PartialEq for UpdateBasicGroupFullInfo {
+    fn eq(&self, other: &Self) -> bool {
+        self.basic_group_id == other.basic_group_id
+            && self.basic_group_full_info == other.basic_group_full_info
+    }
+}
+
+impl Eq for UpdateBasicGroupFullInfo {}
+
+impl std::hash::Hash for UpdateBasicGroupFullInfo {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.basic_group_id, state);
+        std::hash::Hash::hash(&self.basic_group_full_info, state);
+    }
+}
+
 impl TDUpdate for UpdateBasicGroupFullInfo {}
 
 impl UpdateBasicGroupFullInfo {
@@ -714,33 +940,78 @@ impl UpdateBasicGroupFullInfo {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateBasicGroupFullInfoBuilder {
-        let mut inner = UpdateBasicGroupFullInfo::default();
-        inner.td_name = "updateBasicGroupFullInfo".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateBasicGroupFullInfoBuilder { inner }
+        RTDUpdateBasicGroupFullInfoBuilder {
+            inner: UpdateBasicGroupFullInfo::default(),
+            missing: vec!["basic_group_id", "basic_group_full_info"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateBasicGroupFullInfoBuilder {
+        RTDUpdateBasicGroupFullInfoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateBasicGroupFullInfoBuilder {
+        RTDUpdateBasicGroupFullInfoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn basic_group_id(&self) -> i64 {
+    pub fn basic_group_id(&self) -> BasicGroupId {
         self.basic_group_id
     }
 
     pub fn basic_group_full_info(&self) -> &BasicGroupFullInfo {
         &self.basic_group_full_info
     }
+
+    pub fn into_basic_group_full_info(self) -> BasicGroupFullInfo {
+        self.basic_group_full_info
+    }
+
+    pub fn with_basic_group_id<T: Into<BasicGroupId>>(mut self, basic_group_id: T) -> Self {
+        self.basic_group_id = basic_group_id.into();
+        self
+    }
+
+    pub fn with_basic_group_full_info<T: AsRef<BasicGroupFullInfo>>(
+        mut self,
+        basic_group_full_info: T,
+    ) -> Self {
+        self.basic_group_full_info = basic_group_full_info.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateBasicGroupFullInfoBuilder {
     inner: UpdateBasicGroupFullInfo,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateBasicGroupFullInfoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateBasicGroupFullInfo {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateBasicGroupFullInfo> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateBasicGroupFullInfo",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn basic_group_id(&mut self, basic_group_id: i64) -> &mut Self {
-        self.inner.basic_group_id = basic_group_id;
+    pub fn basic_group_id<T: Into<BasicGroupId>>(&mut self, basic_group_id: T) -> &mut Self {
+        self.inner.basic_group_id = basic_group_id.into();
+        self.missing.retain(|f| *f != "basic_group_id");
         self
     }
 
@@ -749,6 +1020,7 @@ impl RTDUpdateBasicGroupFullInfoBuilder {
         basic_group_full_info: T,
     ) -> &mut Self {
         self.inner.basic_group_full_info = basic_group_full_info.as_ref().clone();
+        self.missing.retain(|f| *f != "basic_group_full_info");
         self
     }
 }
@@ -767,12 +1039,13 @@ impl AsRef<UpdateBasicGroupFullInfo> for RTDUpdateBasicGroupFullInfoBuilder {
 
 /// New call was created or information about a call was updated
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateCall")]
 pub struct UpdateCall {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// New data about a call
     call: Call,
@@ -792,6 +1065,20 @@ impl RObject for UpdateCall {
     }
 }
 
+impl PartialEq for UpdateCall {
+    fn eq(&self, other: &Self) -> bool {
+        self.call == other.call
+    }
+}
+
+impl Eq for UpdateCall {}
+
+impl std::hash::Hash for UpdateCall {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.call, state);
+    }
+}
+
 impl TDUpdate for UpdateCall {}
 
 impl UpdateCall {
@@ -799,29 +1086,63 @@ impl UpdateCall {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateCallBuilder {
-        let mut inner = UpdateCall::default();
-        inner.td_name = "updateCall".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateCallBuilder { inner }
+        RTDUpdateCallBuilder {
+            inner: UpdateCall::default(),
+            missing: vec!["call"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateCallBuilder {
+        RTDUpdateCallBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateCallBuilder {
+        RTDUpdateCallBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn call(&self) -> &Call {
         &self.call
     }
+
+    pub fn into_call(self) -> Call {
+        self.call
+    }
+
+    pub fn with_call<T: AsRef<Call>>(mut self, call: T) -> Self {
+        self.call = call.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateCallBuilder {
     inner: UpdateCall,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateCallBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateCall {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateCall> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("UpdateCall", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn call<T: AsRef<Call>>(&mut self, call: T) -> &mut Self {
         self.inner.call = call.as_ref().clone();
+        self.missing.retain(|f| *f != "call");
         self
     }
 }
@@ -840,15 +1161,16 @@ impl AsRef<UpdateCall> for RTDUpdateCallBuilder {
 
 /// The chat action bar was changed
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateChatActionBar")]
 pub struct UpdateChatActionBar {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Chat identifier
-    chat_id: i64,
+    chat_id: ChatId,
     /// The new value of the action bar; may be null
     action_bar: Option<ChatActionBar>,
 }
@@ -867,6 +1189,21 @@ impl RObject for UpdateChatActionBar {
     }
 }
 
+impl PartialEq for UpdateChatActionBar {
+    fn eq(&self, other: &Self) -> bool {
+        self.chat_id == other.chat_id && self.action_bar == other.action_bar
+    }
+}
+
+impl Eq for UpdateChatActionBar {}
+
+impl std::hash::Hash for UpdateChatActionBar {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.chat_id, state);
+        std::hash::Hash::hash(&self.action_bar, state);
+    }
+}
+
 impl TDUpdate for UpdateChatActionBar {}
 
 impl UpdateChatActionBar {
@@ -874,33 +1211,75 @@ impl UpdateChatActionBar {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateChatActionBarBuilder {
-        let mut inner = UpdateChatActionBar::default();
-        inner.td_name = "updateChatActionBar".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateChatActionBarBuilder { inner }
+        RTDUpdateChatActionBarBuilder {
+            inner: UpdateChatActionBar::default(),
+            missing: vec!["chat_id"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateChatActionBarBuilder {
+        RTDUpdateChatActionBarBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateChatActionBarBuilder {
+        RTDUpdateChatActionBarBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn chat_id(&self) -> i64 {
+    pub fn chat_id(&self) -> ChatId {
         self.chat_id
     }
 
     pub fn action_bar(&self) -> &Option<ChatActionBar> {
         &self.action_bar
     }
+
+    pub fn into_action_bar(self) -> Option<ChatActionBar> {
+        self.action_bar
+    }
+
+    pub fn with_chat_id<T: Into<ChatId>>(mut self, chat_id: T) -> Self {
+        self.chat_id = chat_id.into();
+        self
+    }
+
+    pub fn with_action_bar<T: AsRef<ChatActionBar>>(mut self, action_bar: T) -> Self {
+        self.action_bar = Some(action_bar.as_ref().clone());
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateChatActionBarBuilder {
     inner: UpdateChatActionBar,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateChatActionBarBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateChatActionBar {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateChatActionBar> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateChatActionBar",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
-        self.inner.chat_id = chat_id;
+    pub fn chat_id<T: Into<ChatId>>(&mut self, chat_id: T) -> &mut Self {
+        self.inner.chat_id = chat_id.into();
+        self.missing.retain(|f| *f != "chat_id");
         self
     }
 
@@ -924,15 +1303,16 @@ impl AsRef<UpdateChatActionBar> for RTDUpdateChatActionBarBuilder {
 
 /// The list to which the chat belongs was changed. This update is guaranteed to be sent only when chat.order == 0 and the current or the new chat list is null
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateChatChatList")]
 pub struct UpdateChatChatList {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Chat identifier
-    chat_id: i64,
+    chat_id: ChatId,
     /// The new chat's chat list; may be null
     chat_list: Option<ChatList>,
 }
@@ -951,6 +1331,21 @@ impl RObject for UpdateChatChatList {
     }
 }
 
+impl PartialEq for UpdateChatChatList {
+    fn eq(&self, other: &Self) -> bool {
+        self.chat_id == other.chat_id && self.chat_list == other.chat_list
+    }
+}
+
+impl Eq for UpdateChatChatList {}
+
+impl std::hash::Hash for UpdateChatChatList {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.chat_id, state);
+        std::hash::Hash::hash(&self.chat_list, state);
+    }
+}
+
 impl TDUpdate for UpdateChatChatList {}
 
 impl UpdateChatChatList {
@@ -958,33 +1353,75 @@ impl UpdateChatChatList {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateChatChatListBuilder {
-        let mut inner = UpdateChatChatList::default();
-        inner.td_name = "updateChatChatList".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateChatChatListBuilder { inner }
+        RTDUpdateChatChatListBuilder {
+            inner: UpdateChatChatList::default(),
+            missing: vec!["chat_id"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateChatChatListBuilder {
+        RTDUpdateChatChatListBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateChatChatListBuilder {
+        RTDUpdateChatChatListBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn chat_id(&self) -> i64 {
+    pub fn chat_id(&self) -> ChatId {
         self.chat_id
     }
 
     pub fn chat_list(&self) -> &Option<ChatList> {
         &self.chat_list
     }
+
+    pub fn into_chat_list(self) -> Option<ChatList> {
+        self.chat_list
+    }
+
+    pub fn with_chat_id<T: Into<ChatId>>(mut self, chat_id: T) -> Self {
+        self.chat_id = chat_id.into();
+        self
+    }
+
+    pub fn with_chat_list<T: AsRef<ChatList>>(mut self, chat_list: T) -> Self {
+        self.chat_list = Some(chat_list.as_ref().clone());
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateChatChatListBuilder {
     inner: UpdateChatChatList,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateChatChatListBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateChatChatList {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateChatChatList> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateChatChatList",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
-        self.inner.chat_id = chat_id;
+    pub fn chat_id<T: Into<ChatId>>(&mut self, chat_id: T) -> &mut Self {
+        self.inner.chat_id = chat_id.into();
+        self.missing.retain(|f| *f != "chat_id");
         self
     }
 
@@ -1008,15 +1445,16 @@ impl AsRef<UpdateChatChatList> for RTDUpdateChatChatListBuilder {
 
 /// The value of the default disable_notification parameter, used when a message is sent to the chat, was changed
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateChatDefaultDisableNotification")]
 pub struct UpdateChatDefaultDisableNotification {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Chat identifier
-    chat_id: i64,
+    chat_id: ChatId,
     /// The new default_disable_notification value
     default_disable_notification: bool,
 }
@@ -1035,6 +1473,22 @@ impl RObject for UpdateChatDefaultDisableNotification {
     }
 }
 
+impl PartialEq for UpdateChatDefaultDisableNotification {
+    fn eq(&self, other: &Self) -> bool {
+        self.chat_id == other.chat_id
+            && self.default_disable_notification == other.default_disable_notification
+    }
+}
+
+impl Eq for UpdateChatDefaultDisableNotification {}
+
+impl std::hash::Hash for UpdateChatDefaultDisableNotification {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.chat_id, state);
+        std::hash::Hash::hash(&self.default_disable_notification, state);
+    }
+}
+
 impl TDUpdate for UpdateChatDefaultDisableNotification {}
 
 impl UpdateChatDefaultDisableNotification {
@@ -1042,33 +1496,71 @@ impl UpdateChatDefaultDisableNotification {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateChatDefaultDisableNotificationBuilder {
-        let mut inner = UpdateChatDefaultDisableNotification::default();
-        inner.td_name = "updateChatDefaultDisableNotification".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateChatDefaultDisableNotificationBuilder { inner }
+        RTDUpdateChatDefaultDisableNotificationBuilder {
+            inner: UpdateChatDefaultDisableNotification::default(),
+            missing: vec!["chat_id"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateChatDefaultDisableNotificationBuilder {
+        RTDUpdateChatDefaultDisableNotificationBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateChatDefaultDisableNotificationBuilder {
+        RTDUpdateChatDefaultDisableNotificationBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn chat_id(&self) -> i64 {
+    pub fn chat_id(&self) -> ChatId {
         self.chat_id
     }
 
     pub fn default_disable_notification(&self) -> bool {
         self.default_disable_notification
     }
+
+    pub fn with_chat_id<T: Into<ChatId>>(mut self, chat_id: T) -> Self {
+        self.chat_id = chat_id.into();
+        self
+    }
+
+    pub fn with_default_disable_notification(mut self, default_disable_notification: bool) -> Self {
+        self.default_disable_notification = default_disable_notification;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateChatDefaultDisableNotificationBuilder {
     inner: UpdateChatDefaultDisableNotification,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateChatDefaultDisableNotificationBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateChatDefaultDisableNotification {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateChatDefaultDisableNotification> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateChatDefaultDisableNotification",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
-        self.inner.chat_id = chat_id;
+    pub fn chat_id<T: Into<ChatId>>(&mut self, chat_id: T) -> &mut Self {
+        self.inner.chat_id = chat_id.into();
+        self.missing.retain(|f| *f != "chat_id");
         self
     }
 
@@ -1097,20 +1589,21 @@ impl AsRef<UpdateChatDefaultDisableNotification>
 
 /// A chat draft has changed. Be aware that the update may come in the currently opened chat but with old content of the draft. If the user has changed the content of the draft, this update shouldn't be applied
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateChatDraftMessage")]
 pub struct UpdateChatDraftMessage {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Chat identifier
-    chat_id: i64,
+    chat_id: ChatId,
     /// The new draft message; may be null
     draft_message: Option<DraftMessage>,
     /// New value of the chat order
-    #[serde(deserialize_with = "super::_common::number_from_string")]
-    order: isize,
+    #[serde(with = "super::_common::int64")]
+    order: i64,
 }
 
 impl RObject for UpdateChatDraftMessage {
@@ -1127,6 +1620,14 @@ impl RObject for UpdateChatDraftMessage {
     }
 }
 
+impl PartialEq for UpdateChatDraftMessage {
+    fn eq(&self, other: &Self) -> bool {
+        self.chat_id == other.chat_id
+            && self.draft_message == other.draft_message
+            && self.order == other.order
+    }
+}
+
 impl TDUpdate for UpdateChatDraftMessage {}
 
 impl UpdateChatDraftMessage {
@@ -1134,13 +1635,27 @@ impl UpdateChatDraftMessage {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateChatDraftMessageBuilder {
-        let mut inner = UpdateChatDraftMessage::default();
-        inner.td_name = "updateChatDraftMessage".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateChatDraftMessageBuilder { inner }
+        RTDUpdateChatDraftMessageBuilder {
+            inner: UpdateChatDraftMessage::default(),
+            missing: vec!["chat_id", "order"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateChatDraftMessageBuilder {
+        RTDUpdateChatDraftMessageBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateChatDraftMessageBuilder {
+        RTDUpdateChatDraftMessageBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn chat_id(&self) -> i64 {
+    pub fn chat_id(&self) -> ChatId {
         self.chat_id
     }
 
@@ -1148,23 +1663,56 @@ impl UpdateChatDraftMessage {
         &self.draft_message
     }
 
-    pub fn order(&self) -> isize {
+    pub fn order(&self) -> i64 {
         self.order
     }
+
+    pub fn into_draft_message(self) -> Option<DraftMessage> {
+        self.draft_message
+    }
+
+    pub fn with_chat_id<T: Into<ChatId>>(mut self, chat_id: T) -> Self {
+        self.chat_id = chat_id.into();
+        self
+    }
+
+    pub fn with_draft_message<T: AsRef<DraftMessage>>(mut self, draft_message: T) -> Self {
+        self.draft_message = Some(draft_message.as_ref().clone());
+        self
+    }
+
+    pub fn with_order(mut self, order: i64) -> Self {
+        self.order = order;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateChatDraftMessageBuilder {
     inner: UpdateChatDraftMessage,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateChatDraftMessageBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateChatDraftMessage {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateChatDraftMessage> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateChatDraftMessage",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
-        self.inner.chat_id = chat_id;
+    pub fn chat_id<T: Into<ChatId>>(&mut self, chat_id: T) -> &mut Self {
+        self.inner.chat_id = chat_id.into();
+        self.missing.retain(|f| *f != "chat_id");
         self
     }
 
@@ -1173,8 +1721,9 @@ impl RTDUpdateChatDraftMessageBuilder {
         self
     }
 
-    pub fn order(&mut self, order: isize) -> &mut Self {
+    pub fn order(&mut self, order: i64) -> &mut Self {
         self.inner.order = order;
+        self.missing.retain(|f| *f != "order");
         self
     }
 }
@@ -1193,15 +1742,16 @@ impl AsRef<UpdateChatDraftMessage> for RTDUpdateChatDraftMessageBuilder {
 
 /// A chat's has_scheduled_messages field has changed
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateChatHasScheduledMessages")]
 pub struct UpdateChatHasScheduledMessages {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Chat identifier
-    chat_id: i64,
+    chat_id: ChatId,
     /// New value of has_scheduled_messages
     has_scheduled_messages: bool,
 }
@@ -1220,6 +1770,21 @@ impl RObject for UpdateChatHasScheduledMessages {
     }
 }
 
+impl PartialEq for UpdateChatHasScheduledMessages {
+    fn eq(&self, other: &Self) -> bool {
+        self.chat_id == other.chat_id && self.has_scheduled_messages == other.has_scheduled_messages
+    }
+}
+
+impl Eq for UpdateChatHasScheduledMessages {}
+
+impl std::hash::Hash for UpdateChatHasScheduledMessages {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.chat_id, state);
+        std::hash::Hash::hash(&self.has_scheduled_messages, state);
+    }
+}
+
 impl TDUpdate for UpdateChatHasScheduledMessages {}
 
 impl UpdateChatHasScheduledMessages {
@@ -1227,33 +1792,71 @@ impl UpdateChatHasScheduledMessages {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateChatHasScheduledMessagesBuilder {
-        let mut inner = UpdateChatHasScheduledMessages::default();
-        inner.td_name = "updateChatHasScheduledMessages".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateChatHasScheduledMessagesBuilder { inner }
+        RTDUpdateChatHasScheduledMessagesBuilder {
+            inner: UpdateChatHasScheduledMessages::default(),
+            missing: vec!["chat_id"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateChatHasScheduledMessagesBuilder {
+        RTDUpdateChatHasScheduledMessagesBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateChatHasScheduledMessagesBuilder {
+        RTDUpdateChatHasScheduledMessagesBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn chat_id(&self) -> i64 {
+    pub fn chat_id(&self) -> ChatId {
         self.chat_id
     }
 
     pub fn has_scheduled_messages(&self) -> bool {
         self.has_scheduled_messages
     }
+
+    pub fn with_chat_id<T: Into<ChatId>>(mut self, chat_id: T) -> Self {
+        self.chat_id = chat_id.into();
+        self
+    }
+
+    pub fn with_has_scheduled_messages(mut self, has_scheduled_messages: bool) -> Self {
+        self.has_scheduled_messages = has_scheduled_messages;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateChatHasScheduledMessagesBuilder {
     inner: UpdateChatHasScheduledMessages,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateChatHasScheduledMessagesBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateChatHasScheduledMessages {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateChatHasScheduledMessages> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateChatHasScheduledMessages",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
-        self.inner.chat_id = chat_id;
+    pub fn chat_id<T: Into<ChatId>>(&mut self, chat_id: T) -> &mut Self {
+        self.inner.chat_id = chat_id.into();
+        self.missing.retain(|f| *f != "chat_id");
         self
     }
 
@@ -1277,15 +1880,16 @@ impl AsRef<UpdateChatHasScheduledMessages> for RTDUpdateChatHasScheduledMessages
 
 /// A chat was marked as unread or was read
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateChatIsMarkedAsUnread")]
 pub struct UpdateChatIsMarkedAsUnread {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Chat identifier
-    chat_id: i64,
+    chat_id: ChatId,
     /// New value of is_marked_as_unread
     is_marked_as_unread: bool,
 }
@@ -1304,6 +1908,21 @@ impl RObject for UpdateChatIsMarkedAsUnread {
     }
 }
 
+impl PartialEq for UpdateChatIsMarkedAsUnread {
+    fn eq(&self, other: &Self) -> bool {
+        self.chat_id == other.chat_id && self.is_marked_as_unread == other.is_marked_as_unread
+    }
+}
+
+impl Eq for UpdateChatIsMarkedAsUnread {}
+
+impl std::hash::Hash for UpdateChatIsMarkedAsUnread {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.chat_id, state);
+        std::hash::Hash::hash(&self.is_marked_as_unread, state);
+    }
+}
+
 impl TDUpdate for UpdateChatIsMarkedAsUnread {}
 
 impl UpdateChatIsMarkedAsUnread {
@@ -1311,33 +1930,71 @@ impl UpdateChatIsMarkedAsUnread {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateChatIsMarkedAsUnreadBuilder {
-        let mut inner = UpdateChatIsMarkedAsUnread::default();
-        inner.td_name = "updateChatIsMarkedAsUnread".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateChatIsMarkedAsUnreadBuilder { inner }
+        RTDUpdateChatIsMarkedAsUnreadBuilder {
+            inner: UpdateChatIsMarkedAsUnread::default(),
+            missing: vec!["chat_id"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateChatIsMarkedAsUnreadBuilder {
+        RTDUpdateChatIsMarkedAsUnreadBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateChatIsMarkedAsUnreadBuilder {
+        RTDUpdateChatIsMarkedAsUnreadBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn chat_id(&self) -> i64 {
+    pub fn chat_id(&self) -> ChatId {
         self.chat_id
     }
 
     pub fn is_marked_as_unread(&self) -> bool {
         self.is_marked_as_unread
     }
+
+    pub fn with_chat_id<T: Into<ChatId>>(mut self, chat_id: T) -> Self {
+        self.chat_id = chat_id.into();
+        self
+    }
+
+    pub fn with_is_marked_as_unread(mut self, is_marked_as_unread: bool) -> Self {
+        self.is_marked_as_unread = is_marked_as_unread;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateChatIsMarkedAsUnreadBuilder {
     inner: UpdateChatIsMarkedAsUnread,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateChatIsMarkedAsUnreadBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateChatIsMarkedAsUnread {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateChatIsMarkedAsUnread> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateChatIsMarkedAsUnread",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
-        self.inner.chat_id = chat_id;
+    pub fn chat_id<T: Into<ChatId>>(&mut self, chat_id: T) -> &mut Self {
+        self.inner.chat_id = chat_id.into();
+        self.missing.retain(|f| *f != "chat_id");
         self
     }
 
@@ -1361,20 +2018,21 @@ impl AsRef<UpdateChatIsMarkedAsUnread> for RTDUpdateChatIsMarkedAsUnreadBuilder
 
 /// A chat was pinned or unpinned
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateChatIsPinned")]
 pub struct UpdateChatIsPinned {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Chat identifier
-    chat_id: i64,
+    chat_id: ChatId,
     /// New value of is_pinned
     is_pinned: bool,
     /// New value of the chat order
-    #[serde(deserialize_with = "super::_common::number_from_string")]
-    order: isize,
+    #[serde(with = "super::_common::int64")]
+    order: i64,
 }
 
 impl RObject for UpdateChatIsPinned {
@@ -1391,6 +2049,24 @@ impl RObject for UpdateChatIsPinned {
     }
 }
 
+impl PartialEq for UpdateChatIsPinned {
+    fn eq(&self, other: &Self) -> bool {
+        self.chat_id == other.chat_id
+            && self.is_pinned == other.is_pinned
+            && self.order == other.order
+    }
+}
+
+impl Eq for UpdateChatIsPinned {}
+
+impl std::hash::Hash for UpdateChatIsPinned {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.chat_id, state);
+        std::hash::Hash::hash(&self.is_pinned, state);
+        std::hash::Hash::hash(&self.order, state);
+    }
+}
+
 impl TDUpdate for UpdateChatIsPinned {}
 
 impl UpdateChatIsPinned {
@@ -1398,13 +2074,27 @@ impl UpdateChatIsPinned {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateChatIsPinnedBuilder {
-        let mut inner = UpdateChatIsPinned::default();
-        inner.td_name = "updateChatIsPinned".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateChatIsPinnedBuilder { inner }
+        RTDUpdateChatIsPinnedBuilder {
+            inner: UpdateChatIsPinned::default(),
+            missing: vec!["chat_id", "order"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateChatIsPinnedBuilder {
+        RTDUpdateChatIsPinnedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateChatIsPinnedBuilder {
+        RTDUpdateChatIsPinnedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn chat_id(&self) -> i64 {
+    pub fn chat_id(&self) -> ChatId {
         self.chat_id
     }
 
@@ -1412,23 +2102,52 @@ impl UpdateChatIsPinned {
         self.is_pinned
     }
 
-    pub fn order(&self) -> isize {
+    pub fn order(&self) -> i64 {
         self.order
     }
-}
 
-#[doc(hidden)]
-pub struct RTDUpdateChatIsPinnedBuilder {
-    inner: UpdateChatIsPinned,
+    pub fn with_chat_id<T: Into<ChatId>>(mut self, chat_id: T) -> Self {
+        self.chat_id = chat_id.into();
+        self
+    }
+
+    pub fn with_is_pinned(mut self, is_pinned: bool) -> Self {
+        self.is_pinned = is_pinned;
+        self
+    }
+
+    pub fn with_order(mut self, order: i64) -> Self {
+        self.order = order;
+        self
+    }
+}
+
+#[doc(hidden)]
+pub struct RTDUpdateChatIsPinnedBuilder {
+    inner: UpdateChatIsPinned,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateChatIsPinnedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateChatIsPinned {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateChatIsPinned> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateChatIsPinned",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
-        self.inner.chat_id = chat_id;
+    pub fn chat_id<T: Into<ChatId>>(&mut self, chat_id: T) -> &mut Self {
+        self.inner.chat_id = chat_id.into();
+        self.missing.retain(|f| *f != "chat_id");
         self
     }
 
@@ -1437,8 +2156,9 @@ impl RTDUpdateChatIsPinnedBuilder {
         self
     }
 
-    pub fn order(&mut self, order: isize) -> &mut Self {
+    pub fn order(&mut self, order: i64) -> &mut Self {
         self.inner.order = order;
+        self.missing.retain(|f| *f != "order");
         self
     }
 }
@@ -1457,20 +2177,21 @@ impl AsRef<UpdateChatIsPinned> for RTDUpdateChatIsPinnedBuilder {
 
 /// A chat's is_sponsored field has changed
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateChatIsSponsored")]
 pub struct UpdateChatIsSponsored {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Chat identifier
-    chat_id: i64,
+    chat_id: ChatId,
     /// New value of is_sponsored
     is_sponsored: bool,
     /// New value of chat order
-    #[serde(deserialize_with = "super::_common::number_from_string")]
-    order: isize,
+    #[serde(with = "super::_common::int64")]
+    order: i64,
 }
 
 impl RObject for UpdateChatIsSponsored {
@@ -1487,6 +2208,24 @@ impl RObject for UpdateChatIsSponsored {
     }
 }
 
+impl PartialEq for UpdateChatIsSponsored {
+    fn eq(&self, other: &Self) -> bool {
+        self.chat_id == other.chat_id
+            && self.is_sponsored == other.is_sponsored
+            && self.order == other.order
+    }
+}
+
+impl Eq for UpdateChatIsSponsored {}
+
+impl std::hash::Hash for UpdateChatIsSponsored {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.chat_id, state);
+        std::hash::Hash::hash(&self.is_sponsored, state);
+        std::hash::Hash::hash(&self.order, state);
+    }
+}
+
 impl TDUpdate for UpdateChatIsSponsored {}
 
 impl UpdateChatIsSponsored {
@@ -1494,13 +2233,27 @@ impl UpdateChatIsSponsored {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateChatIsSponsoredBuilder {
-        let mut inner = UpdateChatIsSponsored::default();
-        inner.td_name = "updateChatIsSponsored".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateChatIsSponsoredBuilder { inner }
+        RTDUpdateChatIsSponsoredBuilder {
+            inner: UpdateChatIsSponsored::default(),
+            missing: vec!["chat_id", "order"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateChatIsSponsoredBuilder {
+        RTDUpdateChatIsSponsoredBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateChatIsSponsoredBuilder {
+        RTDUpdateChatIsSponsoredBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn chat_id(&self) -> i64 {
+    pub fn chat_id(&self) -> ChatId {
         self.chat_id
     }
 
@@ -1508,23 +2261,52 @@ impl UpdateChatIsSponsored {
         self.is_sponsored
     }
 
-    pub fn order(&self) -> isize {
+    pub fn order(&self) -> i64 {
         self.order
     }
+
+    pub fn with_chat_id<T: Into<ChatId>>(mut self, chat_id: T) -> Self {
+        self.chat_id = chat_id.into();
+        self
+    }
+
+    pub fn with_is_sponsored(mut self, is_sponsored: bool) -> Self {
+        self.is_sponsored = is_sponsored;
+        self
+    }
+
+    pub fn with_order(mut self, order: i64) -> Self {
+        self.order = order;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateChatIsSponsoredBuilder {
     inner: UpdateChatIsSponsored,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateChatIsSponsoredBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateChatIsSponsored {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateChatIsSponsored> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateChatIsSponsored",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
-        self.inner.chat_id = chat_id;
+    pub fn chat_id<T: Into<ChatId>>(&mut self, chat_id: T) -> &mut Self {
+        self.inner.chat_id = chat_id.into();
+        self.missing.retain(|f| *f != "chat_id");
         self
     }
 
@@ -1533,8 +2315,9 @@ impl RTDUpdateChatIsSponsoredBuilder {
         self
     }
 
-    pub fn order(&mut self, order: isize) -> &mut Self {
+    pub fn order(&mut self, order: i64) -> &mut Self {
         self.inner.order = order;
+        self.missing.retain(|f| *f != "order");
         self
     }
 }
@@ -1553,20 +2336,21 @@ impl AsRef<UpdateChatIsSponsored> for RTDUpdateChatIsSponsoredBuilder {
 
 /// The last message of a chat was changed. If last_message is null, then the last message in the chat became unknown. Some new unknown messages might be added to the chat in this case
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateChatLastMessage")]
 pub struct UpdateChatLastMessage {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Chat identifier
-    chat_id: i64,
+    chat_id: ChatId,
     /// The new last message in the chat; may be null
     last_message: Option<Message>,
     /// New value of the chat order
-    #[serde(deserialize_with = "super::_common::number_from_string")]
-    order: isize,
+    #[serde(with = "super::_common::int64")]
+    order: i64,
 }
 
 impl RObject for UpdateChatLastMessage {
@@ -1583,6 +2367,14 @@ impl RObject for UpdateChatLastMessage {
     }
 }
 
+impl PartialEq for UpdateChatLastMessage {
+    fn eq(&self, other: &Self) -> bool {
+        self.chat_id == other.chat_id
+            && self.last_message == other.last_message
+            && self.order == other.order
+    }
+}
+
 impl TDUpdate for UpdateChatLastMessage {}
 
 impl UpdateChatLastMessage {
@@ -1590,13 +2382,27 @@ impl UpdateChatLastMessage {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateChatLastMessageBuilder {
-        let mut inner = UpdateChatLastMessage::default();
-        inner.td_name = "updateChatLastMessage".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateChatLastMessageBuilder { inner }
+        RTDUpdateChatLastMessageBuilder {
+            inner: UpdateChatLastMessage::default(),
+            missing: vec!["chat_id", "order"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateChatLastMessageBuilder {
+        RTDUpdateChatLastMessageBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateChatLastMessageBuilder {
+        RTDUpdateChatLastMessageBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn chat_id(&self) -> i64 {
+    pub fn chat_id(&self) -> ChatId {
         self.chat_id
     }
 
@@ -1604,23 +2410,56 @@ impl UpdateChatLastMessage {
         &self.last_message
     }
 
-    pub fn order(&self) -> isize {
+    pub fn order(&self) -> i64 {
         self.order
     }
+
+    pub fn into_last_message(self) -> Option<Message> {
+        self.last_message
+    }
+
+    pub fn with_chat_id<T: Into<ChatId>>(mut self, chat_id: T) -> Self {
+        self.chat_id = chat_id.into();
+        self
+    }
+
+    pub fn with_last_message<T: AsRef<Message>>(mut self, last_message: T) -> Self {
+        self.last_message = Some(last_message.as_ref().clone());
+        self
+    }
+
+    pub fn with_order(mut self, order: i64) -> Self {
+        self.order = order;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateChatLastMessageBuilder {
     inner: UpdateChatLastMessage,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateChatLastMessageBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateChatLastMessage {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateChatLastMessage> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateChatLastMessage",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
-        self.inner.chat_id = chat_id;
+    pub fn chat_id<T: Into<ChatId>>(&mut self, chat_id: T) -> &mut Self {
+        self.inner.chat_id = chat_id.into();
+        self.missing.retain(|f| *f != "chat_id");
         self
     }
 
@@ -1629,8 +2468,9 @@ impl RTDUpdateChatLastMessageBuilder {
         self
     }
 
-    pub fn order(&mut self, order: isize) -> &mut Self {
+    pub fn order(&mut self, order: i64) -> &mut Self {
         self.inner.order = order;
+        self.missing.retain(|f| *f != "order");
         self
     }
 }
@@ -1649,15 +2489,16 @@ impl AsRef<UpdateChatLastMessage> for RTDUpdateChatLastMessageBuilder {
 
 /// Notification settings for a chat were changed
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateChatNotificationSettings")]
 pub struct UpdateChatNotificationSettings {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Chat identifier
-    chat_id: i64,
+    chat_id: ChatId,
     /// The new notification settings
     notification_settings: ChatNotificationSettings,
 }
@@ -1676,6 +2517,21 @@ impl RObject for UpdateChatNotificationSettings {
     }
 }
 
+impl PartialEq for UpdateChatNotificationSettings {
+    fn eq(&self, other: &Self) -> bool {
+        self.chat_id == other.chat_id && self.notification_settings == other.notification_settings
+    }
+}
+
+impl Eq for UpdateChatNotificationSettings {}
+
+impl std::hash::Hash for UpdateChatNotificationSettings {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.chat_id, state);
+        std::hash::Hash::hash(&self.notification_settings, state);
+    }
+}
+
 impl TDUpdate for UpdateChatNotificationSettings {}
 
 impl UpdateChatNotificationSettings {
@@ -1683,33 +2539,78 @@ impl UpdateChatNotificationSettings {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateChatNotificationSettingsBuilder {
-        let mut inner = UpdateChatNotificationSettings::default();
-        inner.td_name = "updateChatNotificationSettings".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateChatNotificationSettingsBuilder { inner }
+        RTDUpdateChatNotificationSettingsBuilder {
+            inner: UpdateChatNotificationSettings::default(),
+            missing: vec!["chat_id", "notification_settings"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateChatNotificationSettingsBuilder {
+        RTDUpdateChatNotificationSettingsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateChatNotificationSettingsBuilder {
+        RTDUpdateChatNotificationSettingsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn chat_id(&self) -> i64 {
+    pub fn chat_id(&self) -> ChatId {
         self.chat_id
     }
 
     pub fn notification_settings(&self) -> &ChatNotificationSettings {
         &self.notification_settings
     }
+
+    pub fn into_notification_settings(self) -> ChatNotificationSettings {
+        self.notification_settings
+    }
+
+    pub fn with_chat_id<T: Into<ChatId>>(mut self, chat_id: T) -> Self {
+        self.chat_id = chat_id.into();
+        self
+    }
+
+    pub fn with_notification_settings<T: AsRef<ChatNotificationSettings>>(
+        mut self,
+        notification_settings: T,
+    ) -> Self {
+        self.notification_settings = notification_settings.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateChatNotificationSettingsBuilder {
     inner: UpdateChatNotificationSettings,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateChatNotificationSettingsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateChatNotificationSettings {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateChatNotificationSettings> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateChatNotificationSettings",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
-        self.inner.chat_id = chat_id;
+    pub fn chat_id<T: Into<ChatId>>(&mut self, chat_id: T) -> &mut Self {
+        self.inner.chat_id = chat_id.into();
+        self.missing.retain(|f| *f != "chat_id");
         self
     }
 
@@ -1718,6 +2619,7 @@ impl RTDUpdateChatNotificationSettingsBuilder {
         notification_settings: T,
     ) -> &mut Self {
         self.inner.notification_settings = notification_settings.as_ref().clone();
+        self.missing.retain(|f| *f != "notification_settings");
         self
     }
 }
@@ -1736,17 +2638,18 @@ impl AsRef<UpdateChatNotificationSettings> for RTDUpdateChatNotificationSettings
 
 /// The number of online group members has changed. This update with non-zero count is sent only for currently opened chats. There is no guarantee that it will be sent just after the count has changed
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateChatOnlineMemberCount")]
 pub struct UpdateChatOnlineMemberCount {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Identifier of the chat
-    chat_id: i64,
+    chat_id: ChatId,
     /// New number of online members in the chat, or 0 if unknown
-    online_member_count: i64,
+    online_member_count: i32,
 }
 
 impl RObject for UpdateChatOnlineMemberCount {
@@ -1763,6 +2666,21 @@ impl RObject for UpdateChatOnlineMemberCount {
     }
 }
 
+impl PartialEq for UpdateChatOnlineMemberCount {
+    fn eq(&self, other: &Self) -> bool {
+        self.chat_id == other.chat_id && self.online_member_count == other.online_member_count
+    }
+}
+
+impl Eq for UpdateChatOnlineMemberCount {}
+
+impl std::hash::Hash for UpdateChatOnlineMemberCount {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.chat_id, state);
+        std::hash::Hash::hash(&self.online_member_count, state);
+    }
+}
+
 impl TDUpdate for UpdateChatOnlineMemberCount {}
 
 impl UpdateChatOnlineMemberCount {
@@ -1770,37 +2688,75 @@ impl UpdateChatOnlineMemberCount {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateChatOnlineMemberCountBuilder {
-        let mut inner = UpdateChatOnlineMemberCount::default();
-        inner.td_name = "updateChatOnlineMemberCount".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateChatOnlineMemberCountBuilder { inner }
+        RTDUpdateChatOnlineMemberCountBuilder {
+            inner: UpdateChatOnlineMemberCount::default(),
+            missing: vec!["chat_id"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateChatOnlineMemberCountBuilder {
+        RTDUpdateChatOnlineMemberCountBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateChatOnlineMemberCountBuilder {
+        RTDUpdateChatOnlineMemberCountBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn chat_id(&self) -> i64 {
+    pub fn chat_id(&self) -> ChatId {
         self.chat_id
     }
 
-    pub fn online_member_count(&self) -> i64 {
+    pub fn online_member_count(&self) -> i32 {
         self.online_member_count
     }
+
+    pub fn with_chat_id<T: Into<ChatId>>(mut self, chat_id: T) -> Self {
+        self.chat_id = chat_id.into();
+        self
+    }
+
+    pub fn with_online_member_count(mut self, online_member_count: i32) -> Self {
+        self.online_member_count = online_member_count;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateChatOnlineMemberCountBuilder {
     inner: UpdateChatOnlineMemberCount,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateChatOnlineMemberCountBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateChatOnlineMemberCount {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateChatOnlineMemberCount> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateChatOnlineMemberCount",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
-        self.inner.chat_id = chat_id;
+    pub fn chat_id<T: Into<ChatId>>(&mut self, chat_id: T) -> &mut Self {
+        self.inner.chat_id = chat_id.into();
+        self.missing.retain(|f| *f != "chat_id");
         self
     }
 
-    pub fn online_member_count(&mut self, online_member_count: i64) -> &mut Self {
+    pub fn online_member_count(&mut self, online_member_count: i32) -> &mut Self {
         self.inner.online_member_count = online_member_count;
         self
     }
@@ -1820,18 +2776,19 @@ impl AsRef<UpdateChatOnlineMemberCount> for RTDUpdateChatOnlineMemberCountBuilde
 
 /// The order of the chat in the chat list has changed. Instead of this update updateChatLastMessage, updateChatIsPinned, updateChatDraftMessage, or updateChatIsSponsored might be sent
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateChatOrder")]
 pub struct UpdateChatOrder {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Chat identifier
-    chat_id: i64,
+    chat_id: ChatId,
     /// New value of the order
-    #[serde(deserialize_with = "super::_common::number_from_string")]
-    order: isize,
+    #[serde(with = "super::_common::int64")]
+    order: i64,
 }
 
 impl RObject for UpdateChatOrder {
@@ -1848,6 +2805,21 @@ impl RObject for UpdateChatOrder {
     }
 }
 
+impl PartialEq for UpdateChatOrder {
+    fn eq(&self, other: &Self) -> bool {
+        self.chat_id == other.chat_id && self.order == other.order
+    }
+}
+
+impl Eq for UpdateChatOrder {}
+
+impl std::hash::Hash for UpdateChatOrder {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.chat_id, state);
+        std::hash::Hash::hash(&self.order, state);
+    }
+}
+
 impl TDUpdate for UpdateChatOrder {}
 
 impl UpdateChatOrder {
@@ -1855,38 +2827,77 @@ impl UpdateChatOrder {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateChatOrderBuilder {
-        let mut inner = UpdateChatOrder::default();
-        inner.td_name = "updateChatOrder".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateChatOrderBuilder { inner }
+        RTDUpdateChatOrderBuilder {
+            inner: UpdateChatOrder::default(),
+            missing: vec!["chat_id", "order"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateChatOrderBuilder {
+        RTDUpdateChatOrderBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateChatOrderBuilder {
+        RTDUpdateChatOrderBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn chat_id(&self) -> i64 {
+    pub fn chat_id(&self) -> ChatId {
         self.chat_id
     }
 
-    pub fn order(&self) -> isize {
+    pub fn order(&self) -> i64 {
         self.order
     }
+
+    pub fn with_chat_id<T: Into<ChatId>>(mut self, chat_id: T) -> Self {
+        self.chat_id = chat_id.into();
+        self
+    }
+
+    pub fn with_order(mut self, order: i64) -> Self {
+        self.order = order;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateChatOrderBuilder {
     inner: UpdateChatOrder,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateChatOrderBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateChatOrder {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateChatOrder> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateChatOrder",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
-        self.inner.chat_id = chat_id;
+    pub fn chat_id<T: Into<ChatId>>(&mut self, chat_id: T) -> &mut Self {
+        self.inner.chat_id = chat_id.into();
+        self.missing.retain(|f| *f != "chat_id");
         self
     }
 
-    pub fn order(&mut self, order: isize) -> &mut Self {
+    pub fn order(&mut self, order: i64) -> &mut Self {
         self.inner.order = order;
+        self.missing.retain(|f| *f != "order");
         self
     }
 }
@@ -1905,15 +2916,16 @@ impl AsRef<UpdateChatOrder> for RTDUpdateChatOrderBuilder {
 
 /// Chat permissions was changed
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateChatPermissions")]
 pub struct UpdateChatPermissions {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Chat identifier
-    chat_id: i64,
+    chat_id: ChatId,
     /// The new chat permissions
     permissions: ChatPermissions,
 }
@@ -1932,6 +2944,21 @@ impl RObject for UpdateChatPermissions {
     }
 }
 
+impl PartialEq for UpdateChatPermissions {
+    fn eq(&self, other: &Self) -> bool {
+        self.chat_id == other.chat_id && self.permissions == other.permissions
+    }
+}
+
+impl Eq for UpdateChatPermissions {}
+
+impl std::hash::Hash for UpdateChatPermissions {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.chat_id, state);
+        std::hash::Hash::hash(&self.permissions, state);
+    }
+}
+
 impl TDUpdate for UpdateChatPermissions {}
 
 impl UpdateChatPermissions {
@@ -1939,38 +2966,81 @@ impl UpdateChatPermissions {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateChatPermissionsBuilder {
-        let mut inner = UpdateChatPermissions::default();
-        inner.td_name = "updateChatPermissions".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateChatPermissionsBuilder { inner }
+        RTDUpdateChatPermissionsBuilder {
+            inner: UpdateChatPermissions::default(),
+            missing: vec!["chat_id", "permissions"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateChatPermissionsBuilder {
+        RTDUpdateChatPermissionsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateChatPermissionsBuilder {
+        RTDUpdateChatPermissionsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn chat_id(&self) -> i64 {
+    pub fn chat_id(&self) -> ChatId {
         self.chat_id
     }
 
     pub fn permissions(&self) -> &ChatPermissions {
         &self.permissions
     }
+
+    pub fn into_permissions(self) -> ChatPermissions {
+        self.permissions
+    }
+
+    pub fn with_chat_id<T: Into<ChatId>>(mut self, chat_id: T) -> Self {
+        self.chat_id = chat_id.into();
+        self
+    }
+
+    pub fn with_permissions<T: AsRef<ChatPermissions>>(mut self, permissions: T) -> Self {
+        self.permissions = permissions.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateChatPermissionsBuilder {
     inner: UpdateChatPermissions,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateChatPermissionsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateChatPermissions {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateChatPermissions> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateChatPermissions",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
-        self.inner.chat_id = chat_id;
+    pub fn chat_id<T: Into<ChatId>>(&mut self, chat_id: T) -> &mut Self {
+        self.inner.chat_id = chat_id.into();
+        self.missing.retain(|f| *f != "chat_id");
         self
     }
 
     pub fn permissions<T: AsRef<ChatPermissions>>(&mut self, permissions: T) -> &mut Self {
         self.inner.permissions = permissions.as_ref().clone();
+        self.missing.retain(|f| *f != "permissions");
         self
     }
 }
@@ -1989,15 +3059,16 @@ impl AsRef<UpdateChatPermissions> for RTDUpdateChatPermissionsBuilder {
 
 /// A chat photo was changed
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateChatPhoto")]
 pub struct UpdateChatPhoto {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Chat identifier
-    chat_id: i64,
+    chat_id: ChatId,
     /// The new chat photo; may be null
     photo: Option<ChatPhoto>,
 }
@@ -2016,6 +3087,21 @@ impl RObject for UpdateChatPhoto {
     }
 }
 
+impl PartialEq for UpdateChatPhoto {
+    fn eq(&self, other: &Self) -> bool {
+        self.chat_id == other.chat_id && self.photo == other.photo
+    }
+}
+
+impl Eq for UpdateChatPhoto {}
+
+impl std::hash::Hash for UpdateChatPhoto {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.chat_id, state);
+        std::hash::Hash::hash(&self.photo, state);
+    }
+}
+
 impl TDUpdate for UpdateChatPhoto {}
 
 impl UpdateChatPhoto {
@@ -2023,33 +3109,75 @@ impl UpdateChatPhoto {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateChatPhotoBuilder {
-        let mut inner = UpdateChatPhoto::default();
-        inner.td_name = "updateChatPhoto".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateChatPhotoBuilder { inner }
+        RTDUpdateChatPhotoBuilder {
+            inner: UpdateChatPhoto::default(),
+            missing: vec!["chat_id"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateChatPhotoBuilder {
+        RTDUpdateChatPhotoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateChatPhotoBuilder {
+        RTDUpdateChatPhotoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn chat_id(&self) -> i64 {
+    pub fn chat_id(&self) -> ChatId {
         self.chat_id
     }
 
     pub fn photo(&self) -> &Option<ChatPhoto> {
         &self.photo
     }
+
+    pub fn into_photo(self) -> Option<ChatPhoto> {
+        self.photo
+    }
+
+    pub fn with_chat_id<T: Into<ChatId>>(mut self, chat_id: T) -> Self {
+        self.chat_id = chat_id.into();
+        self
+    }
+
+    pub fn with_photo<T: AsRef<ChatPhoto>>(mut self, photo: T) -> Self {
+        self.photo = Some(photo.as_ref().clone());
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateChatPhotoBuilder {
     inner: UpdateChatPhoto,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateChatPhotoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateChatPhoto {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateChatPhoto> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateChatPhoto",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
-        self.inner.chat_id = chat_id;
+    pub fn chat_id<T: Into<ChatId>>(&mut self, chat_id: T) -> &mut Self {
+        self.inner.chat_id = chat_id.into();
+        self.missing.retain(|f| *f != "chat_id");
         self
     }
 
@@ -2073,17 +3201,18 @@ impl AsRef<UpdateChatPhoto> for RTDUpdateChatPhotoBuilder {
 
 /// The chat pinned message was changed
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateChatPinnedMessage")]
 pub struct UpdateChatPinnedMessage {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Chat identifier
-    chat_id: i64,
+    chat_id: ChatId,
     /// The new identifier of the pinned message; 0 if there is no pinned message in the chat
-    pinned_message_id: i64,
+    pinned_message_id: MessageId,
 }
 
 impl RObject for UpdateChatPinnedMessage {
@@ -2100,6 +3229,21 @@ impl RObject for UpdateChatPinnedMessage {
     }
 }
 
+impl PartialEq for UpdateChatPinnedMessage {
+    fn eq(&self, other: &Self) -> bool {
+        self.chat_id == other.chat_id && self.pinned_message_id == other.pinned_message_id
+    }
+}
+
+impl Eq for UpdateChatPinnedMessage {}
+
+impl std::hash::Hash for UpdateChatPinnedMessage {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.chat_id, state);
+        std::hash::Hash::hash(&self.pinned_message_id, state);
+    }
+}
+
 impl TDUpdate for UpdateChatPinnedMessage {}
 
 impl UpdateChatPinnedMessage {
@@ -2107,38 +3251,76 @@ impl UpdateChatPinnedMessage {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateChatPinnedMessageBuilder {
-        let mut inner = UpdateChatPinnedMessage::default();
-        inner.td_name = "updateChatPinnedMessage".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateChatPinnedMessageBuilder { inner }
+        RTDUpdateChatPinnedMessageBuilder {
+            inner: UpdateChatPinnedMessage::default(),
+            missing: vec!["chat_id"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateChatPinnedMessageBuilder {
+        RTDUpdateChatPinnedMessageBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateChatPinnedMessageBuilder {
+        RTDUpdateChatPinnedMessageBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn chat_id(&self) -> i64 {
+    pub fn chat_id(&self) -> ChatId {
         self.chat_id
     }
 
-    pub fn pinned_message_id(&self) -> i64 {
+    pub fn pinned_message_id(&self) -> MessageId {
         self.pinned_message_id
     }
+
+    pub fn with_chat_id<T: Into<ChatId>>(mut self, chat_id: T) -> Self {
+        self.chat_id = chat_id.into();
+        self
+    }
+
+    pub fn with_pinned_message_id<T: Into<MessageId>>(mut self, pinned_message_id: T) -> Self {
+        self.pinned_message_id = pinned_message_id.into();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateChatPinnedMessageBuilder {
     inner: UpdateChatPinnedMessage,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateChatPinnedMessageBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateChatPinnedMessage {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateChatPinnedMessage> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateChatPinnedMessage",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
-        self.inner.chat_id = chat_id;
+    pub fn chat_id<T: Into<ChatId>>(&mut self, chat_id: T) -> &mut Self {
+        self.inner.chat_id = chat_id.into();
+        self.missing.retain(|f| *f != "chat_id");
         self
     }
 
-    pub fn pinned_message_id(&mut self, pinned_message_id: i64) -> &mut Self {
-        self.inner.pinned_message_id = pinned_message_id;
+    pub fn pinned_message_id<T: Into<MessageId>>(&mut self, pinned_message_id: T) -> &mut Self {
+        self.inner.pinned_message_id = pinned_message_id.into();
         self
     }
 }
@@ -2157,19 +3339,20 @@ impl AsRef<UpdateChatPinnedMessage> for RTDUpdateChatPinnedMessageBuilder {
 
 /// Incoming messages were read or number of unread messages has been changed
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateChatReadInbox")]
 pub struct UpdateChatReadInbox {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Chat identifier
-    chat_id: i64,
+    chat_id: ChatId,
     /// Identifier of the last read incoming message
-    last_read_inbox_message_id: i64,
+    last_read_inbox_message_id: MessageId,
     /// The number of unread messages left in the chat
-    unread_count: i64,
+    unread_count: i32,
 }
 
 impl RObject for UpdateChatReadInbox {
@@ -2186,6 +3369,24 @@ impl RObject for UpdateChatReadInbox {
     }
 }
 
+impl PartialEq for UpdateChatReadInbox {
+    fn eq(&self, other: &Self) -> bool {
+        self.chat_id == other.chat_id
+            && self.last_read_inbox_message_id == other.last_read_inbox_message_id
+            && self.unread_count == other.unread_count
+    }
+}
+
+impl Eq for UpdateChatReadInbox {}
+
+impl std::hash::Hash for UpdateChatReadInbox {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.chat_id, state);
+        std::hash::Hash::hash(&self.last_read_inbox_message_id, state);
+        std::hash::Hash::hash(&self.unread_count, state);
+    }
+}
+
 impl TDUpdate for UpdateChatReadInbox {}
 
 impl UpdateChatReadInbox {
@@ -2193,47 +3394,98 @@ impl UpdateChatReadInbox {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateChatReadInboxBuilder {
-        let mut inner = UpdateChatReadInbox::default();
-        inner.td_name = "updateChatReadInbox".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateChatReadInboxBuilder { inner }
+        RTDUpdateChatReadInboxBuilder {
+            inner: UpdateChatReadInbox::default(),
+            missing: vec!["chat_id", "last_read_inbox_message_id", "unread_count"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateChatReadInboxBuilder {
+        RTDUpdateChatReadInboxBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateChatReadInboxBuilder {
+        RTDUpdateChatReadInboxBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn chat_id(&self) -> i64 {
+    pub fn chat_id(&self) -> ChatId {
         self.chat_id
     }
 
-    pub fn last_read_inbox_message_id(&self) -> i64 {
+    pub fn last_read_inbox_message_id(&self) -> MessageId {
         self.last_read_inbox_message_id
     }
 
-    pub fn unread_count(&self) -> i64 {
+    pub fn unread_count(&self) -> i32 {
         self.unread_count
     }
+
+    pub fn with_chat_id<T: Into<ChatId>>(mut self, chat_id: T) -> Self {
+        self.chat_id = chat_id.into();
+        self
+    }
+
+    pub fn with_last_read_inbox_message_id<T: Into<MessageId>>(
+        mut self,
+        last_read_inbox_message_id: T,
+    ) -> Self {
+        self.last_read_inbox_message_id = last_read_inbox_message_id.into();
+        self
+    }
+
+    pub fn with_unread_count(mut self, unread_count: i32) -> Self {
+        self.unread_count = unread_count;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateChatReadInboxBuilder {
     inner: UpdateChatReadInbox,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateChatReadInboxBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateChatReadInbox {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateChatReadInbox> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateChatReadInbox",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
-        self.inner.chat_id = chat_id;
+    pub fn chat_id<T: Into<ChatId>>(&mut self, chat_id: T) -> &mut Self {
+        self.inner.chat_id = chat_id.into();
+        self.missing.retain(|f| *f != "chat_id");
         self
     }
 
-    pub fn last_read_inbox_message_id(&mut self, last_read_inbox_message_id: i64) -> &mut Self {
-        self.inner.last_read_inbox_message_id = last_read_inbox_message_id;
+    pub fn last_read_inbox_message_id<T: Into<MessageId>>(
+        &mut self,
+        last_read_inbox_message_id: T,
+    ) -> &mut Self {
+        self.inner.last_read_inbox_message_id = last_read_inbox_message_id.into();
+        self.missing.retain(|f| *f != "last_read_inbox_message_id");
         self
     }
 
-    pub fn unread_count(&mut self, unread_count: i64) -> &mut Self {
+    pub fn unread_count(&mut self, unread_count: i32) -> &mut Self {
         self.inner.unread_count = unread_count;
+        self.missing.retain(|f| *f != "unread_count");
         self
     }
 }
@@ -2252,17 +3504,18 @@ impl AsRef<UpdateChatReadInbox> for RTDUpdateChatReadInboxBuilder {
 
 /// Outgoing messages were read
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateChatReadOutbox")]
 pub struct UpdateChatReadOutbox {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Chat identifier
-    chat_id: i64,
+    chat_id: ChatId,
     /// Identifier of last read outgoing message
-    last_read_outbox_message_id: i64,
+    last_read_outbox_message_id: MessageId,
 }
 
 impl RObject for UpdateChatReadOutbox {
@@ -2279,6 +3532,22 @@ impl RObject for UpdateChatReadOutbox {
     }
 }
 
+impl PartialEq for UpdateChatReadOutbox {
+    fn eq(&self, other: &Self) -> bool {
+        self.chat_id == other.chat_id
+            && self.last_read_outbox_message_id == other.last_read_outbox_message_id
+    }
+}
+
+impl Eq for UpdateChatReadOutbox {}
+
+impl std::hash::Hash for UpdateChatReadOutbox {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.chat_id, state);
+        std::hash::Hash::hash(&self.last_read_outbox_message_id, state);
+    }
+}
+
 impl TDUpdate for UpdateChatReadOutbox {}
 
 impl UpdateChatReadOutbox {
@@ -2286,38 +3555,83 @@ impl UpdateChatReadOutbox {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateChatReadOutboxBuilder {
-        let mut inner = UpdateChatReadOutbox::default();
-        inner.td_name = "updateChatReadOutbox".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateChatReadOutboxBuilder { inner }
+        RTDUpdateChatReadOutboxBuilder {
+            inner: UpdateChatReadOutbox::default(),
+            missing: vec!["chat_id", "last_read_outbox_message_id"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateChatReadOutboxBuilder {
+        RTDUpdateChatReadOutboxBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateChatReadOutboxBuilder {
+        RTDUpdateChatReadOutboxBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn chat_id(&self) -> i64 {
+    pub fn chat_id(&self) -> ChatId {
         self.chat_id
     }
 
-    pub fn last_read_outbox_message_id(&self) -> i64 {
+    pub fn last_read_outbox_message_id(&self) -> MessageId {
         self.last_read_outbox_message_id
     }
+
+    pub fn with_chat_id<T: Into<ChatId>>(mut self, chat_id: T) -> Self {
+        self.chat_id = chat_id.into();
+        self
+    }
+
+    pub fn with_last_read_outbox_message_id<T: Into<MessageId>>(
+        mut self,
+        last_read_outbox_message_id: T,
+    ) -> Self {
+        self.last_read_outbox_message_id = last_read_outbox_message_id.into();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateChatReadOutboxBuilder {
     inner: UpdateChatReadOutbox,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateChatReadOutboxBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateChatReadOutbox {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateChatReadOutbox> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateChatReadOutbox",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
-        self.inner.chat_id = chat_id;
+    pub fn chat_id<T: Into<ChatId>>(&mut self, chat_id: T) -> &mut Self {
+        self.inner.chat_id = chat_id.into();
+        self.missing.retain(|f| *f != "chat_id");
         self
     }
 
-    pub fn last_read_outbox_message_id(&mut self, last_read_outbox_message_id: i64) -> &mut Self {
-        self.inner.last_read_outbox_message_id = last_read_outbox_message_id;
+    pub fn last_read_outbox_message_id<T: Into<MessageId>>(
+        &mut self,
+        last_read_outbox_message_id: T,
+    ) -> &mut Self {
+        self.inner.last_read_outbox_message_id = last_read_outbox_message_id.into();
+        self.missing.retain(|f| *f != "last_read_outbox_message_id");
         self
     }
 }
@@ -2336,17 +3650,18 @@ impl AsRef<UpdateChatReadOutbox> for RTDUpdateChatReadOutboxBuilder {
 
 /// The default chat reply markup was changed. Can occur because new messages with reply markup were received or because an old reply markup was hidden by the user
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateChatReplyMarkup")]
 pub struct UpdateChatReplyMarkup {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Chat identifier
-    chat_id: i64,
+    chat_id: ChatId,
     /// Identifier of the message from which reply markup needs to be used; 0 if there is no default custom reply markup in the chat
-    reply_markup_message_id: i64,
+    reply_markup_message_id: MessageId,
 }
 
 impl RObject for UpdateChatReplyMarkup {
@@ -2363,6 +3678,22 @@ impl RObject for UpdateChatReplyMarkup {
     }
 }
 
+impl PartialEq for UpdateChatReplyMarkup {
+    fn eq(&self, other: &Self) -> bool {
+        self.chat_id == other.chat_id
+            && self.reply_markup_message_id == other.reply_markup_message_id
+    }
+}
+
+impl Eq for UpdateChatReplyMarkup {}
+
+impl std::hash::Hash for UpdateChatReplyMarkup {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.chat_id, state);
+        std::hash::Hash::hash(&self.reply_markup_message_id, state);
+    }
+}
+
 impl TDUpdate for UpdateChatReplyMarkup {}
 
 impl UpdateChatReplyMarkup {
@@ -2370,38 +3701,82 @@ impl UpdateChatReplyMarkup {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateChatReplyMarkupBuilder {
-        let mut inner = UpdateChatReplyMarkup::default();
-        inner.td_name = "updateChatReplyMarkup".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateChatReplyMarkupBuilder { inner }
+        RTDUpdateChatReplyMarkupBuilder {
+            inner: UpdateChatReplyMarkup::default(),
+            missing: vec!["chat_id"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateChatReplyMarkupBuilder {
+        RTDUpdateChatReplyMarkupBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateChatReplyMarkupBuilder {
+        RTDUpdateChatReplyMarkupBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn chat_id(&self) -> i64 {
+    pub fn chat_id(&self) -> ChatId {
         self.chat_id
     }
 
-    pub fn reply_markup_message_id(&self) -> i64 {
+    pub fn reply_markup_message_id(&self) -> MessageId {
         self.reply_markup_message_id
     }
+
+    pub fn with_chat_id<T: Into<ChatId>>(mut self, chat_id: T) -> Self {
+        self.chat_id = chat_id.into();
+        self
+    }
+
+    pub fn with_reply_markup_message_id<T: Into<MessageId>>(
+        mut self,
+        reply_markup_message_id: T,
+    ) -> Self {
+        self.reply_markup_message_id = reply_markup_message_id.into();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateChatReplyMarkupBuilder {
     inner: UpdateChatReplyMarkup,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateChatReplyMarkupBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateChatReplyMarkup {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateChatReplyMarkup> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateChatReplyMarkup",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
-        self.inner.chat_id = chat_id;
+    pub fn chat_id<T: Into<ChatId>>(&mut self, chat_id: T) -> &mut Self {
+        self.inner.chat_id = chat_id.into();
+        self.missing.retain(|f| *f != "chat_id");
         self
     }
 
-    pub fn reply_markup_message_id(&mut self, reply_markup_message_id: i64) -> &mut Self {
-        self.inner.reply_markup_message_id = reply_markup_message_id;
+    pub fn reply_markup_message_id<T: Into<MessageId>>(
+        &mut self,
+        reply_markup_message_id: T,
+    ) -> &mut Self {
+        self.inner.reply_markup_message_id = reply_markup_message_id.into();
         self
     }
 }
@@ -2420,15 +3795,16 @@ impl AsRef<UpdateChatReplyMarkup> for RTDUpdateChatReplyMarkupBuilder {
 
 /// The title of a chat was changed
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateChatTitle")]
 pub struct UpdateChatTitle {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Chat identifier
-    chat_id: i64,
+    chat_id: ChatId,
     /// The new chat title
     title: String,
 }
@@ -2447,6 +3823,21 @@ impl RObject for UpdateChatTitle {
     }
 }
 
+impl PartialEq for UpdateChatTitle {
+    fn eq(&self, other: &Self) -> bool {
+        self.chat_id == other.chat_id && self.title == other.title
+    }
+}
+
+impl Eq for UpdateChatTitle {}
+
+impl std::hash::Hash for UpdateChatTitle {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.chat_id, state);
+        std::hash::Hash::hash(&self.title, state);
+    }
+}
+
 impl TDUpdate for UpdateChatTitle {}
 
 impl UpdateChatTitle {
@@ -2454,38 +3845,81 @@ impl UpdateChatTitle {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateChatTitleBuilder {
-        let mut inner = UpdateChatTitle::default();
-        inner.td_name = "updateChatTitle".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateChatTitleBuilder { inner }
+        RTDUpdateChatTitleBuilder {
+            inner: UpdateChatTitle::default(),
+            missing: vec!["chat_id", "title"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateChatTitleBuilder {
+        RTDUpdateChatTitleBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateChatTitleBuilder {
+        RTDUpdateChatTitleBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn chat_id(&self) -> i64 {
+    pub fn chat_id(&self) -> ChatId {
         self.chat_id
     }
 
     pub fn title(&self) -> &String {
         &self.title
     }
+
+    pub fn into_title(self) -> String {
+        self.title
+    }
+
+    pub fn with_chat_id<T: Into<ChatId>>(mut self, chat_id: T) -> Self {
+        self.chat_id = chat_id.into();
+        self
+    }
+
+    pub fn with_title<T: AsRef<str>>(mut self, title: T) -> Self {
+        self.title = title.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateChatTitleBuilder {
     inner: UpdateChatTitle,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateChatTitleBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateChatTitle {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateChatTitle> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateChatTitle",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
-        self.inner.chat_id = chat_id;
+    pub fn chat_id<T: Into<ChatId>>(&mut self, chat_id: T) -> &mut Self {
+        self.inner.chat_id = chat_id.into();
+        self.missing.retain(|f| *f != "chat_id");
         self
     }
 
     pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
         self.inner.title = title.as_ref().to_string();
+        self.missing.retain(|f| *f != "title");
         self
     }
 }
@@ -2504,17 +3938,18 @@ impl AsRef<UpdateChatTitle> for RTDUpdateChatTitleBuilder {
 
 /// The chat unread_mention_count has changed
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateChatUnreadMentionCount")]
 pub struct UpdateChatUnreadMentionCount {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Chat identifier
-    chat_id: i64,
+    chat_id: ChatId,
     /// The number of unread mention messages left in the chat
-    unread_mention_count: i64,
+    unread_mention_count: i32,
 }
 
 impl RObject for UpdateChatUnreadMentionCount {
@@ -2531,6 +3966,21 @@ impl RObject for UpdateChatUnreadMentionCount {
     }
 }
 
+impl PartialEq for UpdateChatUnreadMentionCount {
+    fn eq(&self, other: &Self) -> bool {
+        self.chat_id == other.chat_id && self.unread_mention_count == other.unread_mention_count
+    }
+}
+
+impl Eq for UpdateChatUnreadMentionCount {}
+
+impl std::hash::Hash for UpdateChatUnreadMentionCount {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.chat_id, state);
+        std::hash::Hash::hash(&self.unread_mention_count, state);
+    }
+}
+
 impl TDUpdate for UpdateChatUnreadMentionCount {}
 
 impl UpdateChatUnreadMentionCount {
@@ -2538,38 +3988,77 @@ impl UpdateChatUnreadMentionCount {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateChatUnreadMentionCountBuilder {
-        let mut inner = UpdateChatUnreadMentionCount::default();
-        inner.td_name = "updateChatUnreadMentionCount".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateChatUnreadMentionCountBuilder { inner }
+        RTDUpdateChatUnreadMentionCountBuilder {
+            inner: UpdateChatUnreadMentionCount::default(),
+            missing: vec!["chat_id", "unread_mention_count"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateChatUnreadMentionCountBuilder {
+        RTDUpdateChatUnreadMentionCountBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateChatUnreadMentionCountBuilder {
+        RTDUpdateChatUnreadMentionCountBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn chat_id(&self) -> i64 {
+    pub fn chat_id(&self) -> ChatId {
         self.chat_id
     }
 
-    pub fn unread_mention_count(&self) -> i64 {
+    pub fn unread_mention_count(&self) -> i32 {
         self.unread_mention_count
     }
+
+    pub fn with_chat_id<T: Into<ChatId>>(mut self, chat_id: T) -> Self {
+        self.chat_id = chat_id.into();
+        self
+    }
+
+    pub fn with_unread_mention_count(mut self, unread_mention_count: i32) -> Self {
+        self.unread_mention_count = unread_mention_count;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateChatUnreadMentionCountBuilder {
     inner: UpdateChatUnreadMentionCount,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateChatUnreadMentionCountBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateChatUnreadMentionCount {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateChatUnreadMentionCount> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateChatUnreadMentionCount",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
-        self.inner.chat_id = chat_id;
+    pub fn chat_id<T: Into<ChatId>>(&mut self, chat_id: T) -> &mut Self {
+        self.inner.chat_id = chat_id.into();
+        self.missing.retain(|f| *f != "chat_id");
         self
     }
 
-    pub fn unread_mention_count(&mut self, unread_mention_count: i64) -> &mut Self {
+    pub fn unread_mention_count(&mut self, unread_mention_count: i32) -> &mut Self {
         self.inner.unread_mention_count = unread_mention_count;
+        self.missing.retain(|f| *f != "unread_mention_count");
         self
     }
 }
@@ -2588,12 +4077,13 @@ impl AsRef<UpdateChatUnreadMentionCount> for RTDUpdateChatUnreadMentionCountBuil
 
 /// The connection state has changed
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateConnectionState")]
 pub struct UpdateConnectionState {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The new connection state
     state: ConnectionState,
@@ -2613,6 +4103,20 @@ impl RObject for UpdateConnectionState {
     }
 }
 
+impl PartialEq for UpdateConnectionState {
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state
+    }
+}
+
+impl Eq for UpdateConnectionState {}
+
+impl std::hash::Hash for UpdateConnectionState {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.state, state);
+    }
+}
+
 impl TDUpdate for UpdateConnectionState {}
 
 impl UpdateConnectionState {
@@ -2620,29 +4124,66 @@ impl UpdateConnectionState {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateConnectionStateBuilder {
-        let mut inner = UpdateConnectionState::default();
-        inner.td_name = "updateConnectionState".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateConnectionStateBuilder { inner }
+        RTDUpdateConnectionStateBuilder {
+            inner: UpdateConnectionState::default(),
+            missing: vec!["state"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateConnectionStateBuilder {
+        RTDUpdateConnectionStateBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateConnectionStateBuilder {
+        RTDUpdateConnectionStateBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn state(&self) -> &ConnectionState {
         &self.state
     }
+
+    pub fn into_state(self) -> ConnectionState {
+        self.state
+    }
+
+    pub fn with_state<T: AsRef<ConnectionState>>(mut self, state: T) -> Self {
+        self.state = state.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateConnectionStateBuilder {
     inner: UpdateConnectionState,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateConnectionStateBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateConnectionState {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateConnectionState> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateConnectionState",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn state<T: AsRef<ConnectionState>>(&mut self, state: T) -> &mut Self {
         self.inner.state = state.as_ref().clone();
+        self.missing.retain(|f| *f != "state");
         self
     }
 }
@@ -2661,17 +4202,18 @@ impl AsRef<UpdateConnectionState> for RTDUpdateConnectionStateBuilder {
 
 /// Some messages were deleted
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateDeleteMessages")]
 pub struct UpdateDeleteMessages {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Chat identifier
-    chat_id: i64,
+    chat_id: ChatId,
     /// Identifiers of the deleted messages
-    message_ids: Vec<i64>,
+    message_ids: Vec<MessageId>,
     /// True, if the messages are permanently deleted by a user (as opposed to just becoming inaccessible)
     is_permanent: bool,
     /// True, if the messages are deleted only from the cache and can possibly be retrieved again in the future
@@ -2692,6 +4234,26 @@ impl RObject for UpdateDeleteMessages {
     }
 }
 
+impl PartialEq for UpdateDeleteMessages {
+    fn eq(&self, other: &Self) -> bool {
+        self.chat_id == other.chat_id
+            && self.message_ids == other.message_ids
+            && self.is_permanent == other.is_permanent
+            && self.from_cache == other.from_cache
+    }
+}
+
+impl Eq for UpdateDeleteMessages {}
+
+impl std::hash::Hash for UpdateDeleteMessages {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.chat_id, state);
+        std::hash::Hash::hash(&self.message_ids, state);
+        std::hash::Hash::hash(&self.is_permanent, state);
+        std::hash::Hash::hash(&self.from_cache, state);
+    }
+}
+
 impl TDUpdate for UpdateDeleteMessages {}
 
 impl UpdateDeleteMessages {
@@ -2699,17 +4261,31 @@ impl UpdateDeleteMessages {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateDeleteMessagesBuilder {
-        let mut inner = UpdateDeleteMessages::default();
-        inner.td_name = "updateDeleteMessages".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateDeleteMessagesBuilder { inner }
+        RTDUpdateDeleteMessagesBuilder {
+            inner: UpdateDeleteMessages::default(),
+            missing: vec!["chat_id", "message_ids"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateDeleteMessagesBuilder {
+        RTDUpdateDeleteMessagesBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateDeleteMessagesBuilder {
+        RTDUpdateDeleteMessagesBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn chat_id(&self) -> i64 {
+    pub fn chat_id(&self) -> ChatId {
         self.chat_id
     }
 
-    pub fn message_ids(&self) -> &Vec<i64> {
+    pub fn message_ids(&self) -> &Vec<MessageId> {
         &self.message_ids
     }
 
@@ -2720,25 +4296,64 @@ impl UpdateDeleteMessages {
     pub fn from_cache(&self) -> bool {
         self.from_cache
     }
+
+    pub fn into_message_ids(self) -> Vec<MessageId> {
+        self.message_ids
+    }
+
+    pub fn with_chat_id<T: Into<ChatId>>(mut self, chat_id: T) -> Self {
+        self.chat_id = chat_id.into();
+        self
+    }
+
+    pub fn with_message_ids(mut self, message_ids: Vec<MessageId>) -> Self {
+        self.message_ids = message_ids;
+        self
+    }
+
+    pub fn with_is_permanent(mut self, is_permanent: bool) -> Self {
+        self.is_permanent = is_permanent;
+        self
+    }
+
+    pub fn with_from_cache(mut self, from_cache: bool) -> Self {
+        self.from_cache = from_cache;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateDeleteMessagesBuilder {
     inner: UpdateDeleteMessages,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateDeleteMessagesBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateDeleteMessages {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateDeleteMessages> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateDeleteMessages",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
-        self.inner.chat_id = chat_id;
+    pub fn chat_id<T: Into<ChatId>>(&mut self, chat_id: T) -> &mut Self {
+        self.inner.chat_id = chat_id.into();
+        self.missing.retain(|f| *f != "chat_id");
         self
     }
 
-    pub fn message_ids(&mut self, message_ids: Vec<i64>) -> &mut Self {
+    pub fn message_ids(&mut self, message_ids: Vec<MessageId>) -> &mut Self {
         self.inner.message_ids = message_ids;
+        self.missing.retain(|f| *f != "message_ids");
         self
     }
 
@@ -2767,15 +4382,16 @@ impl AsRef<UpdateDeleteMessages> for RTDUpdateDeleteMessagesBuilder {
 
 /// The list of favorite stickers was updated
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateFavoriteStickers")]
 pub struct UpdateFavoriteStickers {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The new list of file identifiers of favorite stickers
-    sticker_ids: Vec<i64>,
+    sticker_ids: Vec<i32>,
 }
 
 impl RObject for UpdateFavoriteStickers {
@@ -2792,6 +4408,20 @@ impl RObject for UpdateFavoriteStickers {
     }
 }
 
+impl PartialEq for UpdateFavoriteStickers {
+    fn eq(&self, other: &Self) -> bool {
+        self.sticker_ids == other.sticker_ids
+    }
+}
+
+impl Eq for UpdateFavoriteStickers {}
+
+impl std::hash::Hash for UpdateFavoriteStickers {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.sticker_ids, state);
+    }
+}
+
 impl TDUpdate for UpdateFavoriteStickers {}
 
 impl UpdateFavoriteStickers {
@@ -2799,29 +4429,66 @@ impl UpdateFavoriteStickers {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateFavoriteStickersBuilder {
-        let mut inner = UpdateFavoriteStickers::default();
-        inner.td_name = "updateFavoriteStickers".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateFavoriteStickersBuilder { inner }
+        RTDUpdateFavoriteStickersBuilder {
+            inner: UpdateFavoriteStickers::default(),
+            missing: vec!["sticker_ids"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateFavoriteStickersBuilder {
+        RTDUpdateFavoriteStickersBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateFavoriteStickersBuilder {
+        RTDUpdateFavoriteStickersBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn sticker_ids(&self) -> &Vec<i64> {
+    pub fn sticker_ids(&self) -> &Vec<i32> {
         &self.sticker_ids
     }
+
+    pub fn into_sticker_ids(self) -> Vec<i32> {
+        self.sticker_ids
+    }
+
+    pub fn with_sticker_ids(mut self, sticker_ids: Vec<i32>) -> Self {
+        self.sticker_ids = sticker_ids;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateFavoriteStickersBuilder {
     inner: UpdateFavoriteStickers,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateFavoriteStickersBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateFavoriteStickers {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateFavoriteStickers> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateFavoriteStickers",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn sticker_ids(&mut self, sticker_ids: Vec<i64>) -> &mut Self {
+    pub fn sticker_ids(&mut self, sticker_ids: Vec<i32>) -> &mut Self {
         self.inner.sticker_ids = sticker_ids;
+        self.missing.retain(|f| *f != "sticker_ids");
         self
     }
 }
@@ -2840,12 +4507,13 @@ impl AsRef<UpdateFavoriteStickers> for RTDUpdateFavoriteStickersBuilder {
 
 /// Information about a file was updated
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateFile")]
 pub struct UpdateFile {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// New data about the file
     file: File,
@@ -2865,6 +4533,20 @@ impl RObject for UpdateFile {
     }
 }
 
+impl PartialEq for UpdateFile {
+    fn eq(&self, other: &Self) -> bool {
+        self.file == other.file
+    }
+}
+
+impl Eq for UpdateFile {}
+
+impl std::hash::Hash for UpdateFile {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.file, state);
+    }
+}
+
 impl TDUpdate for UpdateFile {}
 
 impl UpdateFile {
@@ -2872,29 +4554,63 @@ impl UpdateFile {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateFileBuilder {
-        let mut inner = UpdateFile::default();
-        inner.td_name = "updateFile".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateFileBuilder { inner }
+        RTDUpdateFileBuilder {
+            inner: UpdateFile::default(),
+            missing: vec!["file"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateFileBuilder {
+        RTDUpdateFileBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateFileBuilder {
+        RTDUpdateFileBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn file(&self) -> &File {
         &self.file
     }
+
+    pub fn into_file(self) -> File {
+        self.file
+    }
+
+    pub fn with_file<T: AsRef<File>>(mut self, file: T) -> Self {
+        self.file = file.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateFileBuilder {
     inner: UpdateFile,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateFileBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateFile {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateFile> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("UpdateFile", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn file<T: AsRef<File>>(&mut self, file: T) -> &mut Self {
         self.inner.file = file.as_ref().clone();
+        self.missing.retain(|f| *f != "file");
         self
     }
 }
@@ -2913,15 +4629,17 @@ impl AsRef<UpdateFile> for RTDUpdateFileBuilder {
 
 /// The file generation process needs to be started by the client
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateFileGenerationStart")]
 pub struct UpdateFileGenerationStart {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Unique identifier for the generation process
-    generation_id: isize,
+    #[serde(with = "super::_common::int64")]
+    generation_id: i64,
     /// The path to a file from which a new file is generated; may be empty
     original_path: String,
     /// The path to a file that should be created and where the new file should be generated
@@ -2944,6 +4662,26 @@ impl RObject for UpdateFileGenerationStart {
     }
 }
 
+impl PartialEq for UpdateFileGenerationStart {
+    fn eq(&self, other: &Self) -> bool {
+        self.generation_id == other.generation_id
+            && self.original_path == other.original_path
+            && self.destination_path == other.destination_path
+            && self.conversion == other.conversion
+    }
+}
+
+impl Eq for UpdateFileGenerationStart {}
+
+impl std::hash::Hash for UpdateFileGenerationStart {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.generation_id, state);
+        std::hash::Hash::hash(&self.original_path, state);
+        std::hash::Hash::hash(&self.destination_path, state);
+        std::hash::Hash::hash(&self.conversion, state);
+    }
+}
+
 impl TDUpdate for UpdateFileGenerationStart {}
 
 impl UpdateFileGenerationStart {
@@ -2951,13 +4689,27 @@ impl UpdateFileGenerationStart {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateFileGenerationStartBuilder {
-        let mut inner = UpdateFileGenerationStart::default();
-        inner.td_name = "updateFileGenerationStart".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateFileGenerationStartBuilder { inner }
+        RTDUpdateFileGenerationStartBuilder {
+            inner: UpdateFileGenerationStart::default(),
+            missing: vec!["generation_id", "destination_path", "conversion"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateFileGenerationStartBuilder {
+        RTDUpdateFileGenerationStartBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateFileGenerationStartBuilder {
+        RTDUpdateFileGenerationStartBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn generation_id(&self) -> isize {
+    pub fn generation_id(&self) -> i64 {
         self.generation_id
     }
 
@@ -2972,20 +4724,66 @@ impl UpdateFileGenerationStart {
     pub fn conversion(&self) -> &String {
         &self.conversion
     }
+
+    pub fn into_original_path(self) -> String {
+        self.original_path
+    }
+
+    pub fn into_destination_path(self) -> String {
+        self.destination_path
+    }
+
+    pub fn into_conversion(self) -> String {
+        self.conversion
+    }
+
+    pub fn with_generation_id(mut self, generation_id: i64) -> Self {
+        self.generation_id = generation_id;
+        self
+    }
+
+    pub fn with_original_path<T: AsRef<str>>(mut self, original_path: T) -> Self {
+        self.original_path = original_path.as_ref().to_string();
+        self
+    }
+
+    pub fn with_destination_path<T: AsRef<str>>(mut self, destination_path: T) -> Self {
+        self.destination_path = destination_path.as_ref().to_string();
+        self
+    }
+
+    pub fn with_conversion<T: AsRef<str>>(mut self, conversion: T) -> Self {
+        self.conversion = conversion.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateFileGenerationStartBuilder {
     inner: UpdateFileGenerationStart,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateFileGenerationStartBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateFileGenerationStart {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateFileGenerationStart> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateFileGenerationStart",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn generation_id(&mut self, generation_id: isize) -> &mut Self {
+    pub fn generation_id(&mut self, generation_id: i64) -> &mut Self {
         self.inner.generation_id = generation_id;
+        self.missing.retain(|f| *f != "generation_id");
         self
     }
 
@@ -2996,11 +4794,13 @@ impl RTDUpdateFileGenerationStartBuilder {
 
     pub fn destination_path<T: AsRef<str>>(&mut self, destination_path: T) -> &mut Self {
         self.inner.destination_path = destination_path.as_ref().to_string();
+        self.missing.retain(|f| *f != "destination_path");
         self
     }
 
     pub fn conversion<T: AsRef<str>>(&mut self, conversion: T) -> &mut Self {
         self.inner.conversion = conversion.as_ref().to_string();
+        self.missing.retain(|f| *f != "conversion");
         self
     }
 }
@@ -3019,15 +4819,17 @@ impl AsRef<UpdateFileGenerationStart> for RTDUpdateFileGenerationStartBuilder {
 
 /// File generation is no longer needed
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateFileGenerationStop")]
 pub struct UpdateFileGenerationStop {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Unique identifier for the generation process
-    generation_id: isize,
+    #[serde(with = "super::_common::int64")]
+    generation_id: i64,
 }
 
 impl RObject for UpdateFileGenerationStop {
@@ -3044,6 +4846,20 @@ impl RObject for UpdateFileGenerationStop {
     }
 }
 
+impl PartialEq for UpdateFileGenerationStop {
+    fn eq(&self, other: &Self) -> bool {
+        self.generation_id == other.generation_id
+    }
+}
+
+impl Eq for UpdateFileGenerationStop {}
+
+impl std::hash::Hash for UpdateFileGenerationStop {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.generation_id, state);
+    }
+}
+
 impl TDUpdate for UpdateFileGenerationStop {}
 
 impl UpdateFileGenerationStop {
@@ -3051,29 +4867,62 @@ impl UpdateFileGenerationStop {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateFileGenerationStopBuilder {
-        let mut inner = UpdateFileGenerationStop::default();
-        inner.td_name = "updateFileGenerationStop".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateFileGenerationStopBuilder { inner }
+        RTDUpdateFileGenerationStopBuilder {
+            inner: UpdateFileGenerationStop::default(),
+            missing: vec!["generation_id"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateFileGenerationStopBuilder {
+        RTDUpdateFileGenerationStopBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateFileGenerationStopBuilder {
+        RTDUpdateFileGenerationStopBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn generation_id(&self) -> isize {
+    pub fn generation_id(&self) -> i64 {
         self.generation_id
     }
+
+    pub fn with_generation_id(mut self, generation_id: i64) -> Self {
+        self.generation_id = generation_id;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateFileGenerationStopBuilder {
     inner: UpdateFileGenerationStop,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateFileGenerationStopBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateFileGenerationStop {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateFileGenerationStop> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateFileGenerationStop",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn generation_id(&mut self, generation_id: isize) -> &mut Self {
+    pub fn generation_id(&mut self, generation_id: i64) -> &mut Self {
         self.inner.generation_id = generation_id;
+        self.missing.retain(|f| *f != "generation_id");
         self
     }
 }
@@ -3092,12 +4941,13 @@ impl AsRef<UpdateFileGenerationStop> for RTDUpdateFileGenerationStopBuilder {
 
 /// Describes whether there are some pending notification updates. Can be used to prevent application from killing, while there are some pending notifications
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateHavePendingNotifications")]
 pub struct UpdateHavePendingNotifications {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// True, if there are some delayed notification updates, which will be sent soon
     have_delayed_notifications: bool,
@@ -3119,6 +4969,22 @@ impl RObject for UpdateHavePendingNotifications {
     }
 }
 
+impl PartialEq for UpdateHavePendingNotifications {
+    fn eq(&self, other: &Self) -> bool {
+        self.have_delayed_notifications == other.have_delayed_notifications
+            && self.have_unreceived_notifications == other.have_unreceived_notifications
+    }
+}
+
+impl Eq for UpdateHavePendingNotifications {}
+
+impl std::hash::Hash for UpdateHavePendingNotifications {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.have_delayed_notifications, state);
+        std::hash::Hash::hash(&self.have_unreceived_notifications, state);
+    }
+}
+
 impl TDUpdate for UpdateHavePendingNotifications {}
 
 impl UpdateHavePendingNotifications {
@@ -3126,10 +4992,24 @@ impl UpdateHavePendingNotifications {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateHavePendingNotificationsBuilder {
-        let mut inner = UpdateHavePendingNotifications::default();
-        inner.td_name = "updateHavePendingNotifications".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateHavePendingNotificationsBuilder { inner }
+        RTDUpdateHavePendingNotificationsBuilder {
+            inner: UpdateHavePendingNotifications::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateHavePendingNotificationsBuilder {
+        RTDUpdateHavePendingNotificationsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateHavePendingNotificationsBuilder {
+        RTDUpdateHavePendingNotificationsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn have_delayed_notifications(&self) -> bool {
@@ -3139,17 +5019,43 @@ impl UpdateHavePendingNotifications {
     pub fn have_unreceived_notifications(&self) -> bool {
         self.have_unreceived_notifications
     }
+
+    pub fn with_have_delayed_notifications(mut self, have_delayed_notifications: bool) -> Self {
+        self.have_delayed_notifications = have_delayed_notifications;
+        self
+    }
+
+    pub fn with_have_unreceived_notifications(
+        mut self,
+        have_unreceived_notifications: bool,
+    ) -> Self {
+        self.have_unreceived_notifications = have_unreceived_notifications;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateHavePendingNotificationsBuilder {
     inner: UpdateHavePendingNotifications,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateHavePendingNotificationsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateHavePendingNotifications {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateHavePendingNotifications> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateHavePendingNotifications",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn have_delayed_notifications(&mut self, have_delayed_notifications: bool) -> &mut Self {
         self.inner.have_delayed_notifications = have_delayed_notifications;
@@ -3179,17 +5085,19 @@ impl AsRef<UpdateHavePendingNotifications> for RTDUpdateHavePendingNotifications
 
 /// The list of installed sticker sets was updated
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateInstalledStickerSets")]
 pub struct UpdateInstalledStickerSets {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// True, if the list of installed mask sticker sets was updated
     is_masks: bool,
     /// The new list of installed ordinary sticker sets
-    sticker_set_ids: Vec<isize>,
+    #[serde(with = "super::_common::int64_vec")]
+    sticker_set_ids: Vec<i64>,
 }
 
 impl RObject for UpdateInstalledStickerSets {
@@ -3206,6 +5114,21 @@ impl RObject for UpdateInstalledStickerSets {
     }
 }
 
+impl PartialEq for UpdateInstalledStickerSets {
+    fn eq(&self, other: &Self) -> bool {
+        self.is_masks == other.is_masks && self.sticker_set_ids == other.sticker_set_ids
+    }
+}
+
+impl Eq for UpdateInstalledStickerSets {}
+
+impl std::hash::Hash for UpdateInstalledStickerSets {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.is_masks, state);
+        std::hash::Hash::hash(&self.sticker_set_ids, state);
+    }
+}
+
 impl TDUpdate for UpdateInstalledStickerSets {}
 
 impl UpdateInstalledStickerSets {
@@ -3213,38 +5136,80 @@ impl UpdateInstalledStickerSets {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateInstalledStickerSetsBuilder {
-        let mut inner = UpdateInstalledStickerSets::default();
-        inner.td_name = "updateInstalledStickerSets".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateInstalledStickerSetsBuilder { inner }
+        RTDUpdateInstalledStickerSetsBuilder {
+            inner: UpdateInstalledStickerSets::default(),
+            missing: vec!["sticker_set_ids"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateInstalledStickerSetsBuilder {
+        RTDUpdateInstalledStickerSetsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateInstalledStickerSetsBuilder {
+        RTDUpdateInstalledStickerSetsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn is_masks(&self) -> bool {
         self.is_masks
     }
 
-    pub fn sticker_set_ids(&self) -> &Vec<isize> {
+    pub fn sticker_set_ids(&self) -> &Vec<i64> {
         &self.sticker_set_ids
     }
+
+    pub fn into_sticker_set_ids(self) -> Vec<i64> {
+        self.sticker_set_ids
+    }
+
+    pub fn with_is_masks(mut self, is_masks: bool) -> Self {
+        self.is_masks = is_masks;
+        self
+    }
+
+    pub fn with_sticker_set_ids(mut self, sticker_set_ids: Vec<i64>) -> Self {
+        self.sticker_set_ids = sticker_set_ids;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateInstalledStickerSetsBuilder {
     inner: UpdateInstalledStickerSets,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateInstalledStickerSetsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateInstalledStickerSets {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateInstalledStickerSets> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateInstalledStickerSets",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn is_masks(&mut self, is_masks: bool) -> &mut Self {
         self.inner.is_masks = is_masks;
         self
     }
 
-    pub fn sticker_set_ids(&mut self, sticker_set_ids: Vec<isize>) -> &mut Self {
+    pub fn sticker_set_ids(&mut self, sticker_set_ids: Vec<i64>) -> &mut Self {
         self.inner.sticker_set_ids = sticker_set_ids;
+        self.missing.retain(|f| *f != "sticker_set_ids");
         self
     }
 }
@@ -3263,12 +5228,13 @@ impl AsRef<UpdateInstalledStickerSets> for RTDUpdateInstalledStickerSetsBuilder
 
 /// Some language pack strings have been updated
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateLanguagePackStrings")]
 pub struct UpdateLanguagePackStrings {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Localization target to which the language pack belongs
     localization_target: String,
@@ -3292,6 +5258,24 @@ impl RObject for UpdateLanguagePackStrings {
     }
 }
 
+impl PartialEq for UpdateLanguagePackStrings {
+    fn eq(&self, other: &Self) -> bool {
+        self.localization_target == other.localization_target
+            && self.language_pack_id == other.language_pack_id
+            && self.strings == other.strings
+    }
+}
+
+impl Eq for UpdateLanguagePackStrings {}
+
+impl std::hash::Hash for UpdateLanguagePackStrings {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.localization_target, state);
+        std::hash::Hash::hash(&self.language_pack_id, state);
+        std::hash::Hash::hash(&self.strings, state);
+    }
+}
+
 impl TDUpdate for UpdateLanguagePackStrings {}
 
 impl UpdateLanguagePackStrings {
@@ -3299,10 +5283,24 @@ impl UpdateLanguagePackStrings {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateLanguagePackStringsBuilder {
-        let mut inner = UpdateLanguagePackStrings::default();
-        inner.td_name = "updateLanguagePackStrings".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateLanguagePackStringsBuilder { inner }
+        RTDUpdateLanguagePackStringsBuilder {
+            inner: UpdateLanguagePackStrings::default(),
+            missing: vec!["localization_target", "language_pack_id", "strings"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateLanguagePackStringsBuilder {
+        RTDUpdateLanguagePackStringsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateLanguagePackStringsBuilder {
+        RTDUpdateLanguagePackStringsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn localization_target(&self) -> &String {
@@ -3316,30 +5314,73 @@ impl UpdateLanguagePackStrings {
     pub fn strings(&self) -> &Vec<LanguagePackString> {
         &self.strings
     }
-}
 
-#[doc(hidden)]
-pub struct RTDUpdateLanguagePackStringsBuilder {
-    inner: UpdateLanguagePackStrings,
+    pub fn into_localization_target(self) -> String {
+        self.localization_target
+    }
+
+    pub fn into_language_pack_id(self) -> String {
+        self.language_pack_id
+    }
+
+    pub fn into_strings(self) -> Vec<LanguagePackString> {
+        self.strings
+    }
+
+    pub fn with_localization_target<T: AsRef<str>>(mut self, localization_target: T) -> Self {
+        self.localization_target = localization_target.as_ref().to_string();
+        self
+    }
+
+    pub fn with_language_pack_id<T: AsRef<str>>(mut self, language_pack_id: T) -> Self {
+        self.language_pack_id = language_pack_id.as_ref().to_string();
+        self
+    }
+
+    pub fn with_strings(mut self, strings: Vec<LanguagePackString>) -> Self {
+        self.strings = strings;
+        self
+    }
+}
+
+#[doc(hidden)]
+pub struct RTDUpdateLanguagePackStringsBuilder {
+    inner: UpdateLanguagePackStrings,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateLanguagePackStringsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateLanguagePackStrings {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateLanguagePackStrings> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateLanguagePackStrings",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn localization_target<T: AsRef<str>>(&mut self, localization_target: T) -> &mut Self {
         self.inner.localization_target = localization_target.as_ref().to_string();
+        self.missing.retain(|f| *f != "localization_target");
         self
     }
 
     pub fn language_pack_id<T: AsRef<str>>(&mut self, language_pack_id: T) -> &mut Self {
         self.inner.language_pack_id = language_pack_id.as_ref().to_string();
+        self.missing.retain(|f| *f != "language_pack_id");
         self
     }
 
     pub fn strings(&mut self, strings: Vec<LanguagePackString>) -> &mut Self {
         self.inner.strings = strings;
+        self.missing.retain(|f| *f != "strings");
         self
     }
 }
@@ -3358,17 +5399,18 @@ impl AsRef<UpdateLanguagePackStrings> for RTDUpdateLanguagePackStringsBuilder {
 
 /// The message content has changed
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateMessageContent")]
 pub struct UpdateMessageContent {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Chat identifier
-    chat_id: i64,
+    chat_id: ChatId,
     /// Message identifier
-    message_id: i64,
+    message_id: MessageId,
     /// New message content
     new_content: MessageContent,
 }
@@ -3387,6 +5429,14 @@ impl RObject for UpdateMessageContent {
     }
 }
 
+impl PartialEq for UpdateMessageContent {
+    fn eq(&self, other: &Self) -> bool {
+        self.chat_id == other.chat_id
+            && self.message_id == other.message_id
+            && self.new_content == other.new_content
+    }
+}
+
 impl TDUpdate for UpdateMessageContent {}
 
 impl UpdateMessageContent {
@@ -3394,47 +5444,96 @@ impl UpdateMessageContent {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateMessageContentBuilder {
-        let mut inner = UpdateMessageContent::default();
-        inner.td_name = "updateMessageContent".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateMessageContentBuilder { inner }
+        RTDUpdateMessageContentBuilder {
+            inner: UpdateMessageContent::default(),
+            missing: vec!["chat_id", "message_id", "new_content"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateMessageContentBuilder {
+        RTDUpdateMessageContentBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateMessageContentBuilder {
+        RTDUpdateMessageContentBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn chat_id(&self) -> i64 {
+    pub fn chat_id(&self) -> ChatId {
         self.chat_id
     }
 
-    pub fn message_id(&self) -> i64 {
+    pub fn message_id(&self) -> MessageId {
         self.message_id
     }
 
     pub fn new_content(&self) -> &MessageContent {
         &self.new_content
     }
+
+    pub fn into_new_content(self) -> MessageContent {
+        self.new_content
+    }
+
+    pub fn with_chat_id<T: Into<ChatId>>(mut self, chat_id: T) -> Self {
+        self.chat_id = chat_id.into();
+        self
+    }
+
+    pub fn with_message_id<T: Into<MessageId>>(mut self, message_id: T) -> Self {
+        self.message_id = message_id.into();
+        self
+    }
+
+    pub fn with_new_content<T: AsRef<MessageContent>>(mut self, new_content: T) -> Self {
+        self.new_content = new_content.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateMessageContentBuilder {
     inner: UpdateMessageContent,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateMessageContentBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateMessageContent {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateMessageContent> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateMessageContent",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
-        self.inner.chat_id = chat_id;
+    pub fn chat_id<T: Into<ChatId>>(&mut self, chat_id: T) -> &mut Self {
+        self.inner.chat_id = chat_id.into();
+        self.missing.retain(|f| *f != "chat_id");
         self
     }
 
-    pub fn message_id(&mut self, message_id: i64) -> &mut Self {
-        self.inner.message_id = message_id;
+    pub fn message_id<T: Into<MessageId>>(&mut self, message_id: T) -> &mut Self {
+        self.inner.message_id = message_id.into();
+        self.missing.retain(|f| *f != "message_id");
         self
     }
 
     pub fn new_content<T: AsRef<MessageContent>>(&mut self, new_content: T) -> &mut Self {
         self.inner.new_content = new_content.as_ref().clone();
+        self.missing.retain(|f| *f != "new_content");
         self
     }
 }
@@ -3453,17 +5552,18 @@ impl AsRef<UpdateMessageContent> for RTDUpdateMessageContentBuilder {
 
 /// The message content was opened. Updates voice note messages to "listened", video note messages to "viewed" and starts the TTL timer for self-destructing messages
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateMessageContentOpened")]
 pub struct UpdateMessageContentOpened {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Chat identifier
-    chat_id: i64,
+    chat_id: ChatId,
     /// Message identifier
-    message_id: i64,
+    message_id: MessageId,
 }
 
 impl RObject for UpdateMessageContentOpened {
@@ -3480,6 +5580,21 @@ impl RObject for UpdateMessageContentOpened {
     }
 }
 
+impl PartialEq for UpdateMessageContentOpened {
+    fn eq(&self, other: &Self) -> bool {
+        self.chat_id == other.chat_id && self.message_id == other.message_id
+    }
+}
+
+impl Eq for UpdateMessageContentOpened {}
+
+impl std::hash::Hash for UpdateMessageContentOpened {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.chat_id, state);
+        std::hash::Hash::hash(&self.message_id, state);
+    }
+}
+
 impl TDUpdate for UpdateMessageContentOpened {}
 
 impl UpdateMessageContentOpened {
@@ -3487,38 +5602,77 @@ impl UpdateMessageContentOpened {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateMessageContentOpenedBuilder {
-        let mut inner = UpdateMessageContentOpened::default();
-        inner.td_name = "updateMessageContentOpened".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateMessageContentOpenedBuilder { inner }
+        RTDUpdateMessageContentOpenedBuilder {
+            inner: UpdateMessageContentOpened::default(),
+            missing: vec!["chat_id", "message_id"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateMessageContentOpenedBuilder {
+        RTDUpdateMessageContentOpenedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateMessageContentOpenedBuilder {
+        RTDUpdateMessageContentOpenedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn chat_id(&self) -> i64 {
+    pub fn chat_id(&self) -> ChatId {
         self.chat_id
     }
 
-    pub fn message_id(&self) -> i64 {
+    pub fn message_id(&self) -> MessageId {
         self.message_id
     }
+
+    pub fn with_chat_id<T: Into<ChatId>>(mut self, chat_id: T) -> Self {
+        self.chat_id = chat_id.into();
+        self
+    }
+
+    pub fn with_message_id<T: Into<MessageId>>(mut self, message_id: T) -> Self {
+        self.message_id = message_id.into();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateMessageContentOpenedBuilder {
     inner: UpdateMessageContentOpened,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateMessageContentOpenedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateMessageContentOpened {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateMessageContentOpened> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateMessageContentOpened",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
-        self.inner.chat_id = chat_id;
+    pub fn chat_id<T: Into<ChatId>>(&mut self, chat_id: T) -> &mut Self {
+        self.inner.chat_id = chat_id.into();
+        self.missing.retain(|f| *f != "chat_id");
         self
     }
 
-    pub fn message_id(&mut self, message_id: i64) -> &mut Self {
-        self.inner.message_id = message_id;
+    pub fn message_id<T: Into<MessageId>>(&mut self, message_id: T) -> &mut Self {
+        self.inner.message_id = message_id.into();
+        self.missing.retain(|f| *f != "message_id");
         self
     }
 }
@@ -3537,19 +5691,20 @@ impl AsRef<UpdateMessageContentOpened> for RTDUpdateMessageContentOpenedBuilder
 
 /// A message was edited. Changes in the message content will come in a separate updateMessageContent
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateMessageEdited")]
 pub struct UpdateMessageEdited {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Chat identifier
-    chat_id: i64,
+    chat_id: ChatId,
     /// Message identifier
-    message_id: i64,
+    message_id: MessageId,
     /// Point in time (Unix timestamp) when the message was edited
-    edit_date: i64,
+    edit_date: i32,
     /// New message reply markup; may be null
     reply_markup: Option<ReplyMarkup>,
 }
@@ -3568,6 +5723,26 @@ impl RObject for UpdateMessageEdited {
     }
 }
 
+impl PartialEq for UpdateMessageEdited {
+    fn eq(&self, other: &Self) -> bool {
+        self.chat_id == other.chat_id
+            && self.message_id == other.message_id
+            && self.edit_date == other.edit_date
+            && self.reply_markup == other.reply_markup
+    }
+}
+
+impl Eq for UpdateMessageEdited {}
+
+impl std::hash::Hash for UpdateMessageEdited {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.chat_id, state);
+        std::hash::Hash::hash(&self.message_id, state);
+        std::hash::Hash::hash(&self.edit_date, state);
+        std::hash::Hash::hash(&self.reply_markup, state);
+    }
+}
+
 impl TDUpdate for UpdateMessageEdited {}
 
 impl UpdateMessageEdited {
@@ -3575,51 +5750,105 @@ impl UpdateMessageEdited {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateMessageEditedBuilder {
-        let mut inner = UpdateMessageEdited::default();
-        inner.td_name = "updateMessageEdited".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateMessageEditedBuilder { inner }
+        RTDUpdateMessageEditedBuilder {
+            inner: UpdateMessageEdited::default(),
+            missing: vec!["chat_id", "message_id", "edit_date"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateMessageEditedBuilder {
+        RTDUpdateMessageEditedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateMessageEditedBuilder {
+        RTDUpdateMessageEditedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn chat_id(&self) -> i64 {
+    pub fn chat_id(&self) -> ChatId {
         self.chat_id
     }
 
-    pub fn message_id(&self) -> i64 {
+    pub fn message_id(&self) -> MessageId {
         self.message_id
     }
 
-    pub fn edit_date(&self) -> i64 {
+    pub fn edit_date(&self) -> i32 {
         self.edit_date
     }
 
     pub fn reply_markup(&self) -> &Option<ReplyMarkup> {
         &self.reply_markup
     }
+
+    pub fn into_reply_markup(self) -> Option<ReplyMarkup> {
+        self.reply_markup
+    }
+
+    pub fn with_chat_id<T: Into<ChatId>>(mut self, chat_id: T) -> Self {
+        self.chat_id = chat_id.into();
+        self
+    }
+
+    pub fn with_message_id<T: Into<MessageId>>(mut self, message_id: T) -> Self {
+        self.message_id = message_id.into();
+        self
+    }
+
+    pub fn with_edit_date(mut self, edit_date: i32) -> Self {
+        self.edit_date = edit_date;
+        self
+    }
+
+    pub fn with_reply_markup<T: AsRef<ReplyMarkup>>(mut self, reply_markup: T) -> Self {
+        self.reply_markup = Some(reply_markup.as_ref().clone());
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateMessageEditedBuilder {
     inner: UpdateMessageEdited,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateMessageEditedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateMessageEdited {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateMessageEdited> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateMessageEdited",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
-        self.inner.chat_id = chat_id;
+    pub fn chat_id<T: Into<ChatId>>(&mut self, chat_id: T) -> &mut Self {
+        self.inner.chat_id = chat_id.into();
+        self.missing.retain(|f| *f != "chat_id");
         self
     }
 
-    pub fn message_id(&mut self, message_id: i64) -> &mut Self {
-        self.inner.message_id = message_id;
+    pub fn message_id<T: Into<MessageId>>(&mut self, message_id: T) -> &mut Self {
+        self.inner.message_id = message_id.into();
+        self.missing.retain(|f| *f != "message_id");
         self
     }
 
-    pub fn edit_date(&mut self, edit_date: i64) -> &mut Self {
+    pub fn edit_date(&mut self, edit_date: i32) -> &mut Self {
         self.inner.edit_date = edit_date;
+        self.missing.retain(|f| *f != "edit_date");
         self
     }
 
@@ -3643,17 +5872,18 @@ impl AsRef<UpdateMessageEdited> for RTDUpdateMessageEditedBuilder {
 
 /// A message with a live location was viewed. When the update is received, the client is supposed to update the live location
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateMessageLiveLocationViewed")]
 pub struct UpdateMessageLiveLocationViewed {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Identifier of the chat with the live location message
-    chat_id: i64,
+    chat_id: ChatId,
     /// Identifier of the message with live location
-    message_id: i64,
+    message_id: MessageId,
 }
 
 impl RObject for UpdateMessageLiveLocationViewed {
@@ -3670,6 +5900,21 @@ impl RObject for UpdateMessageLiveLocationViewed {
     }
 }
 
+impl PartialEq for UpdateMessageLiveLocationViewed {
+    fn eq(&self, other: &Self) -> bool {
+        self.chat_id == other.chat_id && self.message_id == other.message_id
+    }
+}
+
+impl Eq for UpdateMessageLiveLocationViewed {}
+
+impl std::hash::Hash for UpdateMessageLiveLocationViewed {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.chat_id, state);
+        std::hash::Hash::hash(&self.message_id, state);
+    }
+}
+
 impl TDUpdate for UpdateMessageLiveLocationViewed {}
 
 impl UpdateMessageLiveLocationViewed {
@@ -3677,38 +5922,77 @@ impl UpdateMessageLiveLocationViewed {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateMessageLiveLocationViewedBuilder {
-        let mut inner = UpdateMessageLiveLocationViewed::default();
-        inner.td_name = "updateMessageLiveLocationViewed".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateMessageLiveLocationViewedBuilder { inner }
+        RTDUpdateMessageLiveLocationViewedBuilder {
+            inner: UpdateMessageLiveLocationViewed::default(),
+            missing: vec!["chat_id", "message_id"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateMessageLiveLocationViewedBuilder {
+        RTDUpdateMessageLiveLocationViewedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateMessageLiveLocationViewedBuilder {
+        RTDUpdateMessageLiveLocationViewedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn chat_id(&self) -> i64 {
+    pub fn chat_id(&self) -> ChatId {
         self.chat_id
     }
 
-    pub fn message_id(&self) -> i64 {
+    pub fn message_id(&self) -> MessageId {
         self.message_id
     }
+
+    pub fn with_chat_id<T: Into<ChatId>>(mut self, chat_id: T) -> Self {
+        self.chat_id = chat_id.into();
+        self
+    }
+
+    pub fn with_message_id<T: Into<MessageId>>(mut self, message_id: T) -> Self {
+        self.message_id = message_id.into();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateMessageLiveLocationViewedBuilder {
     inner: UpdateMessageLiveLocationViewed,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateMessageLiveLocationViewedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateMessageLiveLocationViewed {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateMessageLiveLocationViewed> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateMessageLiveLocationViewed",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
-        self.inner.chat_id = chat_id;
+    pub fn chat_id<T: Into<ChatId>>(&mut self, chat_id: T) -> &mut Self {
+        self.inner.chat_id = chat_id.into();
+        self.missing.retain(|f| *f != "chat_id");
         self
     }
 
-    pub fn message_id(&mut self, message_id: i64) -> &mut Self {
-        self.inner.message_id = message_id;
+    pub fn message_id<T: Into<MessageId>>(&mut self, message_id: T) -> &mut Self {
+        self.inner.message_id = message_id.into();
+        self.missing.retain(|f| *f != "message_id");
         self
     }
 }
@@ -3727,19 +6011,20 @@ impl AsRef<UpdateMessageLiveLocationViewed> for RTDUpdateMessageLiveLocationView
 
 /// A message with an unread mention was read
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateMessageMentionRead")]
 pub struct UpdateMessageMentionRead {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Chat identifier
-    chat_id: i64,
+    chat_id: ChatId,
     /// Message identifier
-    message_id: i64,
+    message_id: MessageId,
     /// The new number of unread mention messages left in the chat
-    unread_mention_count: i64,
+    unread_mention_count: i32,
 }
 
 impl RObject for UpdateMessageMentionRead {
@@ -3756,6 +6041,24 @@ impl RObject for UpdateMessageMentionRead {
     }
 }
 
+impl PartialEq for UpdateMessageMentionRead {
+    fn eq(&self, other: &Self) -> bool {
+        self.chat_id == other.chat_id
+            && self.message_id == other.message_id
+            && self.unread_mention_count == other.unread_mention_count
+    }
+}
+
+impl Eq for UpdateMessageMentionRead {}
+
+impl std::hash::Hash for UpdateMessageMentionRead {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.chat_id, state);
+        std::hash::Hash::hash(&self.message_id, state);
+        std::hash::Hash::hash(&self.unread_mention_count, state);
+    }
+}
+
 impl TDUpdate for UpdateMessageMentionRead {}
 
 impl UpdateMessageMentionRead {
@@ -3763,47 +6066,92 @@ impl UpdateMessageMentionRead {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateMessageMentionReadBuilder {
-        let mut inner = UpdateMessageMentionRead::default();
-        inner.td_name = "updateMessageMentionRead".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateMessageMentionReadBuilder { inner }
+        RTDUpdateMessageMentionReadBuilder {
+            inner: UpdateMessageMentionRead::default(),
+            missing: vec!["chat_id", "message_id", "unread_mention_count"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateMessageMentionReadBuilder {
+        RTDUpdateMessageMentionReadBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateMessageMentionReadBuilder {
+        RTDUpdateMessageMentionReadBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn chat_id(&self) -> i64 {
+    pub fn chat_id(&self) -> ChatId {
         self.chat_id
     }
 
-    pub fn message_id(&self) -> i64 {
+    pub fn message_id(&self) -> MessageId {
         self.message_id
     }
 
-    pub fn unread_mention_count(&self) -> i64 {
+    pub fn unread_mention_count(&self) -> i32 {
         self.unread_mention_count
     }
+
+    pub fn with_chat_id<T: Into<ChatId>>(mut self, chat_id: T) -> Self {
+        self.chat_id = chat_id.into();
+        self
+    }
+
+    pub fn with_message_id<T: Into<MessageId>>(mut self, message_id: T) -> Self {
+        self.message_id = message_id.into();
+        self
+    }
+
+    pub fn with_unread_mention_count(mut self, unread_mention_count: i32) -> Self {
+        self.unread_mention_count = unread_mention_count;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateMessageMentionReadBuilder {
     inner: UpdateMessageMentionRead,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateMessageMentionReadBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateMessageMentionRead {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateMessageMentionRead> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateMessageMentionRead",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
-        self.inner.chat_id = chat_id;
+    pub fn chat_id<T: Into<ChatId>>(&mut self, chat_id: T) -> &mut Self {
+        self.inner.chat_id = chat_id.into();
+        self.missing.retain(|f| *f != "chat_id");
         self
     }
 
-    pub fn message_id(&mut self, message_id: i64) -> &mut Self {
-        self.inner.message_id = message_id;
+    pub fn message_id<T: Into<MessageId>>(&mut self, message_id: T) -> &mut Self {
+        self.inner.message_id = message_id.into();
+        self.missing.retain(|f| *f != "message_id");
         self
     }
 
-    pub fn unread_mention_count(&mut self, unread_mention_count: i64) -> &mut Self {
+    pub fn unread_mention_count(&mut self, unread_mention_count: i32) -> &mut Self {
         self.inner.unread_mention_count = unread_mention_count;
+        self.missing.retain(|f| *f != "unread_mention_count");
         self
     }
 }
@@ -3822,17 +6170,18 @@ impl AsRef<UpdateMessageMentionRead> for RTDUpdateMessageMentionReadBuilder {
 
 /// A request to send a message has reached the Telegram server. This doesn't mean that the message will be sent successfully or even that the send message request will be processed. This update will be sent only if the option "use_quick_ack" is set to true. This update may be sent multiple times for the same message
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateMessageSendAcknowledged")]
 pub struct UpdateMessageSendAcknowledged {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The chat identifier of the sent message
-    chat_id: i64,
+    chat_id: ChatId,
     /// A temporary message identifier
-    message_id: i64,
+    message_id: MessageId,
 }
 
 impl RObject for UpdateMessageSendAcknowledged {
@@ -3849,6 +6198,21 @@ impl RObject for UpdateMessageSendAcknowledged {
     }
 }
 
+impl PartialEq for UpdateMessageSendAcknowledged {
+    fn eq(&self, other: &Self) -> bool {
+        self.chat_id == other.chat_id && self.message_id == other.message_id
+    }
+}
+
+impl Eq for UpdateMessageSendAcknowledged {}
+
+impl std::hash::Hash for UpdateMessageSendAcknowledged {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.chat_id, state);
+        std::hash::Hash::hash(&self.message_id, state);
+    }
+}
+
 impl TDUpdate for UpdateMessageSendAcknowledged {}
 
 impl UpdateMessageSendAcknowledged {
@@ -3856,38 +6220,77 @@ impl UpdateMessageSendAcknowledged {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateMessageSendAcknowledgedBuilder {
-        let mut inner = UpdateMessageSendAcknowledged::default();
-        inner.td_name = "updateMessageSendAcknowledged".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateMessageSendAcknowledgedBuilder { inner }
+        RTDUpdateMessageSendAcknowledgedBuilder {
+            inner: UpdateMessageSendAcknowledged::default(),
+            missing: vec!["chat_id", "message_id"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateMessageSendAcknowledgedBuilder {
+        RTDUpdateMessageSendAcknowledgedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateMessageSendAcknowledgedBuilder {
+        RTDUpdateMessageSendAcknowledgedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn chat_id(&self) -> i64 {
+    pub fn chat_id(&self) -> ChatId {
         self.chat_id
     }
 
-    pub fn message_id(&self) -> i64 {
+    pub fn message_id(&self) -> MessageId {
         self.message_id
     }
+
+    pub fn with_chat_id<T: Into<ChatId>>(mut self, chat_id: T) -> Self {
+        self.chat_id = chat_id.into();
+        self
+    }
+
+    pub fn with_message_id<T: Into<MessageId>>(mut self, message_id: T) -> Self {
+        self.message_id = message_id.into();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateMessageSendAcknowledgedBuilder {
     inner: UpdateMessageSendAcknowledged,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateMessageSendAcknowledgedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateMessageSendAcknowledged {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateMessageSendAcknowledged> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateMessageSendAcknowledged",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
-        self.inner.chat_id = chat_id;
+    pub fn chat_id<T: Into<ChatId>>(&mut self, chat_id: T) -> &mut Self {
+        self.inner.chat_id = chat_id.into();
+        self.missing.retain(|f| *f != "chat_id");
         self
     }
 
-    pub fn message_id(&mut self, message_id: i64) -> &mut Self {
-        self.inner.message_id = message_id;
+    pub fn message_id<T: Into<MessageId>>(&mut self, message_id: T) -> &mut Self {
+        self.inner.message_id = message_id.into();
+        self.missing.retain(|f| *f != "message_id");
         self
     }
 }
@@ -3906,19 +6309,20 @@ impl AsRef<UpdateMessageSendAcknowledged> for RTDUpdateMessageSendAcknowledgedBu
 
 /// A message failed to send. Be aware that some messages being sent can be irrecoverably deleted, in which case updateDeleteMessages will be received instead of this update
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateMessageSendFailed")]
 pub struct UpdateMessageSendFailed {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Contains information about the message which failed to send
     message: Message,
     /// The previous temporary message identifier
-    old_message_id: i64,
+    old_message_id: MessageId,
     /// An error code
-    error_code: i64,
+    error_code: i32,
     /// Error message
     error_message: String,
 }
@@ -3937,6 +6341,15 @@ impl RObject for UpdateMessageSendFailed {
     }
 }
 
+impl PartialEq for UpdateMessageSendFailed {
+    fn eq(&self, other: &Self) -> bool {
+        self.message == other.message
+            && self.old_message_id == other.old_message_id
+            && self.error_code == other.error_code
+            && self.error_message == other.error_message
+    }
+}
+
 impl TDUpdate for UpdateMessageSendFailed {}
 
 impl UpdateMessageSendFailed {
@@ -3944,56 +6357,115 @@ impl UpdateMessageSendFailed {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateMessageSendFailedBuilder {
-        let mut inner = UpdateMessageSendFailed::default();
-        inner.td_name = "updateMessageSendFailed".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateMessageSendFailedBuilder { inner }
+        RTDUpdateMessageSendFailedBuilder {
+            inner: UpdateMessageSendFailed::default(),
+            missing: vec!["message", "old_message_id", "error_code", "error_message"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateMessageSendFailedBuilder {
+        RTDUpdateMessageSendFailedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateMessageSendFailedBuilder {
+        RTDUpdateMessageSendFailedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn message(&self) -> &Message {
         &self.message
     }
 
-    pub fn old_message_id(&self) -> i64 {
+    pub fn old_message_id(&self) -> MessageId {
         self.old_message_id
     }
 
-    pub fn error_code(&self) -> i64 {
+    pub fn error_code(&self) -> i32 {
         self.error_code
     }
 
     pub fn error_message(&self) -> &String {
         &self.error_message
     }
+
+    pub fn into_message(self) -> Message {
+        self.message
+    }
+
+    pub fn into_error_message(self) -> String {
+        self.error_message
+    }
+
+    pub fn with_message<T: AsRef<Message>>(mut self, message: T) -> Self {
+        self.message = message.as_ref().clone();
+        self
+    }
+
+    pub fn with_old_message_id<T: Into<MessageId>>(mut self, old_message_id: T) -> Self {
+        self.old_message_id = old_message_id.into();
+        self
+    }
+
+    pub fn with_error_code(mut self, error_code: i32) -> Self {
+        self.error_code = error_code;
+        self
+    }
+
+    pub fn with_error_message<T: AsRef<str>>(mut self, error_message: T) -> Self {
+        self.error_message = error_message.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateMessageSendFailedBuilder {
     inner: UpdateMessageSendFailed,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateMessageSendFailedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateMessageSendFailed {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateMessageSendFailed> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateMessageSendFailed",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn message<T: AsRef<Message>>(&mut self, message: T) -> &mut Self {
         self.inner.message = message.as_ref().clone();
+        self.missing.retain(|f| *f != "message");
         self
     }
 
-    pub fn old_message_id(&mut self, old_message_id: i64) -> &mut Self {
-        self.inner.old_message_id = old_message_id;
+    pub fn old_message_id<T: Into<MessageId>>(&mut self, old_message_id: T) -> &mut Self {
+        self.inner.old_message_id = old_message_id.into();
+        self.missing.retain(|f| *f != "old_message_id");
         self
     }
 
-    pub fn error_code(&mut self, error_code: i64) -> &mut Self {
+    pub fn error_code(&mut self, error_code: i32) -> &mut Self {
         self.inner.error_code = error_code;
+        self.missing.retain(|f| *f != "error_code");
         self
     }
 
     pub fn error_message<T: AsRef<str>>(&mut self, error_message: T) -> &mut Self {
         self.inner.error_message = error_message.as_ref().to_string();
+        self.missing.retain(|f| *f != "error_message");
         self
     }
 }
@@ -4012,17 +6484,18 @@ impl AsRef<UpdateMessageSendFailed> for RTDUpdateMessageSendFailedBuilder {
 
 /// A message has been successfully sent
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateMessageSendSucceeded")]
 pub struct UpdateMessageSendSucceeded {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Information about the sent message. Usually only the message identifier, date, and content are changed, but almost all other fields can also change
     message: Message,
     /// The previous temporary message identifier
-    old_message_id: i64,
+    old_message_id: MessageId,
 }
 
 impl RObject for UpdateMessageSendSucceeded {
@@ -4039,6 +6512,12 @@ impl RObject for UpdateMessageSendSucceeded {
     }
 }
 
+impl PartialEq for UpdateMessageSendSucceeded {
+    fn eq(&self, other: &Self) -> bool {
+        self.message == other.message && self.old_message_id == other.old_message_id
+    }
+}
+
 impl TDUpdate for UpdateMessageSendSucceeded {}
 
 impl UpdateMessageSendSucceeded {
@@ -4046,38 +6525,81 @@ impl UpdateMessageSendSucceeded {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateMessageSendSucceededBuilder {
-        let mut inner = UpdateMessageSendSucceeded::default();
-        inner.td_name = "updateMessageSendSucceeded".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateMessageSendSucceededBuilder { inner }
+        RTDUpdateMessageSendSucceededBuilder {
+            inner: UpdateMessageSendSucceeded::default(),
+            missing: vec!["message", "old_message_id"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateMessageSendSucceededBuilder {
+        RTDUpdateMessageSendSucceededBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateMessageSendSucceededBuilder {
+        RTDUpdateMessageSendSucceededBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn message(&self) -> &Message {
         &self.message
     }
 
-    pub fn old_message_id(&self) -> i64 {
+    pub fn old_message_id(&self) -> MessageId {
         self.old_message_id
     }
+
+    pub fn into_message(self) -> Message {
+        self.message
+    }
+
+    pub fn with_message<T: AsRef<Message>>(mut self, message: T) -> Self {
+        self.message = message.as_ref().clone();
+        self
+    }
+
+    pub fn with_old_message_id<T: Into<MessageId>>(mut self, old_message_id: T) -> Self {
+        self.old_message_id = old_message_id.into();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateMessageSendSucceededBuilder {
     inner: UpdateMessageSendSucceeded,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateMessageSendSucceededBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateMessageSendSucceeded {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateMessageSendSucceeded> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateMessageSendSucceeded",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn message<T: AsRef<Message>>(&mut self, message: T) -> &mut Self {
         self.inner.message = message.as_ref().clone();
+        self.missing.retain(|f| *f != "message");
         self
     }
 
-    pub fn old_message_id(&mut self, old_message_id: i64) -> &mut Self {
-        self.inner.old_message_id = old_message_id;
+    pub fn old_message_id<T: Into<MessageId>>(&mut self, old_message_id: T) -> &mut Self {
+        self.inner.old_message_id = old_message_id.into();
+        self.missing.retain(|f| *f != "old_message_id");
         self
     }
 }
@@ -4096,19 +6618,20 @@ impl AsRef<UpdateMessageSendSucceeded> for RTDUpdateMessageSendSucceededBuilder
 
 /// The view count of the message has changed
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateMessageViews")]
 pub struct UpdateMessageViews {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Chat identifier
-    chat_id: i64,
+    chat_id: ChatId,
     /// Message identifier
-    message_id: i64,
+    message_id: MessageId,
     /// New value of the view count
-    views: i64,
+    views: i32,
 }
 
 impl RObject for UpdateMessageViews {
@@ -4125,6 +6648,24 @@ impl RObject for UpdateMessageViews {
     }
 }
 
+impl PartialEq for UpdateMessageViews {
+    fn eq(&self, other: &Self) -> bool {
+        self.chat_id == other.chat_id
+            && self.message_id == other.message_id
+            && self.views == other.views
+    }
+}
+
+impl Eq for UpdateMessageViews {}
+
+impl std::hash::Hash for UpdateMessageViews {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.chat_id, state);
+        std::hash::Hash::hash(&self.message_id, state);
+        std::hash::Hash::hash(&self.views, state);
+    }
+}
+
 impl TDUpdate for UpdateMessageViews {}
 
 impl UpdateMessageViews {
@@ -4132,47 +6673,92 @@ impl UpdateMessageViews {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateMessageViewsBuilder {
-        let mut inner = UpdateMessageViews::default();
-        inner.td_name = "updateMessageViews".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateMessageViewsBuilder { inner }
+        RTDUpdateMessageViewsBuilder {
+            inner: UpdateMessageViews::default(),
+            missing: vec!["chat_id", "message_id", "views"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateMessageViewsBuilder {
+        RTDUpdateMessageViewsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateMessageViewsBuilder {
+        RTDUpdateMessageViewsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn chat_id(&self) -> i64 {
+    pub fn chat_id(&self) -> ChatId {
         self.chat_id
     }
 
-    pub fn message_id(&self) -> i64 {
+    pub fn message_id(&self) -> MessageId {
         self.message_id
     }
 
-    pub fn views(&self) -> i64 {
+    pub fn views(&self) -> i32 {
         self.views
     }
+
+    pub fn with_chat_id<T: Into<ChatId>>(mut self, chat_id: T) -> Self {
+        self.chat_id = chat_id.into();
+        self
+    }
+
+    pub fn with_message_id<T: Into<MessageId>>(mut self, message_id: T) -> Self {
+        self.message_id = message_id.into();
+        self
+    }
+
+    pub fn with_views(mut self, views: i32) -> Self {
+        self.views = views;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateMessageViewsBuilder {
     inner: UpdateMessageViews,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateMessageViewsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateMessageViews {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateMessageViews> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateMessageViews",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
-        self.inner.chat_id = chat_id;
+    pub fn chat_id<T: Into<ChatId>>(&mut self, chat_id: T) -> &mut Self {
+        self.inner.chat_id = chat_id.into();
+        self.missing.retain(|f| *f != "chat_id");
         self
     }
 
-    pub fn message_id(&mut self, message_id: i64) -> &mut Self {
-        self.inner.message_id = message_id;
+    pub fn message_id<T: Into<MessageId>>(&mut self, message_id: T) -> &mut Self {
+        self.inner.message_id = message_id.into();
+        self.missing.retain(|f| *f != "message_id");
         self
     }
 
-    pub fn views(&mut self, views: i64) -> &mut Self {
+    pub fn views(&mut self, views: i32) -> &mut Self {
         self.inner.views = views;
+        self.missing.retain(|f| *f != "views");
         self
     }
 }
@@ -4191,23 +6777,26 @@ impl AsRef<UpdateMessageViews> for RTDUpdateMessageViewsBuilder {
 
 /// A new incoming callback query; for bots only
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateNewCallbackQuery")]
 pub struct UpdateNewCallbackQuery {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Unique query identifier
-    id: isize,
+    #[serde(with = "super::_common::int64")]
+    id: i64,
     /// Identifier of the user who sent the query
-    sender_user_id: i64,
+    sender_user_id: UserId,
     /// Identifier of the chat where the query was sent
-    chat_id: i64,
+    chat_id: ChatId,
     /// Identifier of the message, from which the query originated
-    message_id: i64,
+    message_id: MessageId,
     /// Identifier that uniquely corresponds to the chat to which the message was sent
-    chat_instance: isize,
+    #[serde(with = "super::_common::int64")]
+    chat_instance: i64,
     /// Query payload
     payload: CallbackQueryPayload,
 }
@@ -4226,6 +6815,30 @@ impl RObject for UpdateNewCallbackQuery {
     }
 }
 
+impl PartialEq for UpdateNewCallbackQuery {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.sender_user_id == other.sender_user_id
+            && self.chat_id == other.chat_id
+            && self.message_id == other.message_id
+            && self.chat_instance == other.chat_instance
+            && self.payload == other.payload
+    }
+}
+
+impl Eq for UpdateNewCallbackQuery {}
+
+impl std::hash::Hash for UpdateNewCallbackQuery {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.id, state);
+        std::hash::Hash::hash(&self.sender_user_id, state);
+        std::hash::Hash::hash(&self.chat_id, state);
+        std::hash::Hash::hash(&self.message_id, state);
+        std::hash::Hash::hash(&self.chat_instance, state);
+        std::hash::Hash::hash(&self.payload, state);
+    }
+}
+
 impl TDUpdate for UpdateNewCallbackQuery {}
 
 impl UpdateNewCallbackQuery {
@@ -4233,79 +6846,153 @@ impl UpdateNewCallbackQuery {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateNewCallbackQueryBuilder {
-        let mut inner = UpdateNewCallbackQuery::default();
-        inner.td_name = "updateNewCallbackQuery".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateNewCallbackQueryBuilder { inner }
+        RTDUpdateNewCallbackQueryBuilder {
+            inner: UpdateNewCallbackQuery::default(),
+            missing: vec![
+                "id",
+                "sender_user_id",
+                "chat_id",
+                "message_id",
+                "chat_instance",
+                "payload",
+            ],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateNewCallbackQueryBuilder {
+        RTDUpdateNewCallbackQueryBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateNewCallbackQueryBuilder {
+        RTDUpdateNewCallbackQueryBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn id(&self) -> isize {
+    pub fn id(&self) -> i64 {
         self.id
     }
 
-    pub fn sender_user_id(&self) -> i64 {
+    pub fn sender_user_id(&self) -> UserId {
         self.sender_user_id
     }
 
-    pub fn chat_id(&self) -> i64 {
+    pub fn chat_id(&self) -> ChatId {
         self.chat_id
     }
 
-    pub fn message_id(&self) -> i64 {
+    pub fn message_id(&self) -> MessageId {
         self.message_id
     }
 
-    pub fn chat_instance(&self) -> isize {
+    pub fn chat_instance(&self) -> i64 {
         self.chat_instance
     }
 
     pub fn payload(&self) -> &CallbackQueryPayload {
         &self.payload
     }
-}
-
-#[doc(hidden)]
-pub struct RTDUpdateNewCallbackQueryBuilder {
-    inner: UpdateNewCallbackQuery,
-}
 
-impl RTDUpdateNewCallbackQueryBuilder {
-    pub fn build(&self) -> UpdateNewCallbackQuery {
-        self.inner.clone()
+    pub fn into_payload(self) -> CallbackQueryPayload {
+        self.payload
     }
 
-    pub fn id(&mut self, id: isize) -> &mut Self {
-        self.inner.id = id;
+    pub fn with_id(mut self, id: i64) -> Self {
+        self.id = id;
         self
     }
 
-    pub fn sender_user_id(&mut self, sender_user_id: i64) -> &mut Self {
-        self.inner.sender_user_id = sender_user_id;
+    pub fn with_sender_user_id<T: Into<UserId>>(mut self, sender_user_id: T) -> Self {
+        self.sender_user_id = sender_user_id.into();
         self
     }
 
-    pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
-        self.inner.chat_id = chat_id;
+    pub fn with_chat_id<T: Into<ChatId>>(mut self, chat_id: T) -> Self {
+        self.chat_id = chat_id.into();
         self
     }
 
-    pub fn message_id(&mut self, message_id: i64) -> &mut Self {
-        self.inner.message_id = message_id;
+    pub fn with_message_id<T: Into<MessageId>>(mut self, message_id: T) -> Self {
+        self.message_id = message_id.into();
         self
     }
 
-    pub fn chat_instance(&mut self, chat_instance: isize) -> &mut Self {
-        self.inner.chat_instance = chat_instance;
+    pub fn with_chat_instance(mut self, chat_instance: i64) -> Self {
+        self.chat_instance = chat_instance;
         self
     }
 
-    pub fn payload<T: AsRef<CallbackQueryPayload>>(&mut self, payload: T) -> &mut Self {
-        self.inner.payload = payload.as_ref().clone();
+    pub fn with_payload<T: AsRef<CallbackQueryPayload>>(mut self, payload: T) -> Self {
+        self.payload = payload.as_ref().clone();
         self
     }
 }
 
-impl AsRef<UpdateNewCallbackQuery> for UpdateNewCallbackQuery {
+#[doc(hidden)]
+pub struct RTDUpdateNewCallbackQueryBuilder {
+    inner: UpdateNewCallbackQuery,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
+}
+
+impl RTDUpdateNewCallbackQueryBuilder {
+    /// Builds the object, fields which are not set have default values
+    pub fn build(&self) -> UpdateNewCallbackQuery {
+        self.inner.clone()
+    }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateNewCallbackQuery> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateNewCallbackQuery",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
+
+    pub fn id(&mut self, id: i64) -> &mut Self {
+        self.inner.id = id;
+        self.missing.retain(|f| *f != "id");
+        self
+    }
+
+    pub fn sender_user_id<T: Into<UserId>>(&mut self, sender_user_id: T) -> &mut Self {
+        self.inner.sender_user_id = sender_user_id.into();
+        self.missing.retain(|f| *f != "sender_user_id");
+        self
+    }
+
+    pub fn chat_id<T: Into<ChatId>>(&mut self, chat_id: T) -> &mut Self {
+        self.inner.chat_id = chat_id.into();
+        self.missing.retain(|f| *f != "chat_id");
+        self
+    }
+
+    pub fn message_id<T: Into<MessageId>>(&mut self, message_id: T) -> &mut Self {
+        self.inner.message_id = message_id.into();
+        self.missing.retain(|f| *f != "message_id");
+        self
+    }
+
+    pub fn chat_instance(&mut self, chat_instance: i64) -> &mut Self {
+        self.inner.chat_instance = chat_instance;
+        self.missing.retain(|f| *f != "chat_instance");
+        self
+    }
+
+    pub fn payload<T: AsRef<CallbackQueryPayload>>(&mut self, payload: T) -> &mut Self {
+        self.inner.payload = payload.as_ref().clone();
+        self.missing.retain(|f| *f != "payload");
+        self
+    }
+}
+
+impl AsRef<UpdateNewCallbackQuery> for UpdateNewCallbackQuery {
     fn as_ref(&self) -> &UpdateNewCallbackQuery {
         self
     }
@@ -4319,12 +7006,13 @@ impl AsRef<UpdateNewCallbackQuery> for RTDUpdateNewCallbackQueryBuilder {
 
 /// A new chat has been loaded/created. This update is guaranteed to come before the chat identifier is returned to the client. The chat field changes will be reported through separate updates
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateNewChat")]
 pub struct UpdateNewChat {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The chat
     chat: Chat,
@@ -4344,6 +7032,12 @@ impl RObject for UpdateNewChat {
     }
 }
 
+impl PartialEq for UpdateNewChat {
+    fn eq(&self, other: &Self) -> bool {
+        self.chat == other.chat
+    }
+}
+
 impl TDUpdate for UpdateNewChat {}
 
 impl UpdateNewChat {
@@ -4351,29 +7045,66 @@ impl UpdateNewChat {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateNewChatBuilder {
-        let mut inner = UpdateNewChat::default();
-        inner.td_name = "updateNewChat".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateNewChatBuilder { inner }
+        RTDUpdateNewChatBuilder {
+            inner: UpdateNewChat::default(),
+            missing: vec!["chat"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateNewChatBuilder {
+        RTDUpdateNewChatBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateNewChatBuilder {
+        RTDUpdateNewChatBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn chat(&self) -> &Chat {
         &self.chat
     }
+
+    pub fn into_chat(self) -> Chat {
+        self.chat
+    }
+
+    pub fn with_chat<T: AsRef<Chat>>(mut self, chat: T) -> Self {
+        self.chat = chat.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateNewChatBuilder {
     inner: UpdateNewChat,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateNewChatBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateNewChat {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateNewChat> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateNewChat",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn chat<T: AsRef<Chat>>(&mut self, chat: T) -> &mut Self {
         self.inner.chat = chat.as_ref().clone();
+        self.missing.retain(|f| *f != "chat");
         self
     }
 }
@@ -4392,15 +7123,16 @@ impl AsRef<UpdateNewChat> for RTDUpdateNewChatBuilder {
 
 /// The user has chosen a result of an inline query; for bots only
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateNewChosenInlineResult")]
 pub struct UpdateNewChosenInlineResult {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Identifier of the user who sent the query
-    sender_user_id: i64,
+    sender_user_id: UserId,
     /// User location, provided by the client; may be null
     user_location: Option<Location>,
     /// Text of the query
@@ -4425,6 +7157,16 @@ impl RObject for UpdateNewChosenInlineResult {
     }
 }
 
+impl PartialEq for UpdateNewChosenInlineResult {
+    fn eq(&self, other: &Self) -> bool {
+        self.sender_user_id == other.sender_user_id
+            && self.user_location == other.user_location
+            && self.query == other.query
+            && self.result_id == other.result_id
+            && self.inline_message_id == other.inline_message_id
+    }
+}
+
 impl TDUpdate for UpdateNewChosenInlineResult {}
 
 impl UpdateNewChosenInlineResult {
@@ -4432,13 +7174,27 @@ impl UpdateNewChosenInlineResult {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateNewChosenInlineResultBuilder {
-        let mut inner = UpdateNewChosenInlineResult::default();
-        inner.td_name = "updateNewChosenInlineResult".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateNewChosenInlineResultBuilder { inner }
+        RTDUpdateNewChosenInlineResultBuilder {
+            inner: UpdateNewChosenInlineResult::default(),
+            missing: vec!["sender_user_id", "query", "result_id"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateNewChosenInlineResultBuilder {
+        RTDUpdateNewChosenInlineResultBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateNewChosenInlineResultBuilder {
+        RTDUpdateNewChosenInlineResultBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn sender_user_id(&self) -> i64 {
+    pub fn sender_user_id(&self) -> UserId {
         self.sender_user_id
     }
 
@@ -4457,20 +7213,75 @@ impl UpdateNewChosenInlineResult {
     pub fn inline_message_id(&self) -> &String {
         &self.inline_message_id
     }
+
+    pub fn into_user_location(self) -> Option<Location> {
+        self.user_location
+    }
+
+    pub fn into_query(self) -> String {
+        self.query
+    }
+
+    pub fn into_result_id(self) -> String {
+        self.result_id
+    }
+
+    pub fn into_inline_message_id(self) -> String {
+        self.inline_message_id
+    }
+
+    pub fn with_sender_user_id<T: Into<UserId>>(mut self, sender_user_id: T) -> Self {
+        self.sender_user_id = sender_user_id.into();
+        self
+    }
+
+    pub fn with_user_location<T: AsRef<Location>>(mut self, user_location: T) -> Self {
+        self.user_location = Some(user_location.as_ref().clone());
+        self
+    }
+
+    pub fn with_query<T: AsRef<str>>(mut self, query: T) -> Self {
+        self.query = query.as_ref().to_string();
+        self
+    }
+
+    pub fn with_result_id<T: AsRef<str>>(mut self, result_id: T) -> Self {
+        self.result_id = result_id.as_ref().to_string();
+        self
+    }
+
+    pub fn with_inline_message_id<T: AsRef<str>>(mut self, inline_message_id: T) -> Self {
+        self.inline_message_id = inline_message_id.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateNewChosenInlineResultBuilder {
     inner: UpdateNewChosenInlineResult,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateNewChosenInlineResultBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateNewChosenInlineResult {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateNewChosenInlineResult> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateNewChosenInlineResult",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn sender_user_id(&mut self, sender_user_id: i64) -> &mut Self {
-        self.inner.sender_user_id = sender_user_id;
+    pub fn sender_user_id<T: Into<UserId>>(&mut self, sender_user_id: T) -> &mut Self {
+        self.inner.sender_user_id = sender_user_id.into();
+        self.missing.retain(|f| *f != "sender_user_id");
         self
     }
 
@@ -4481,11 +7292,13 @@ impl RTDUpdateNewChosenInlineResultBuilder {
 
     pub fn query<T: AsRef<str>>(&mut self, query: T) -> &mut Self {
         self.inner.query = query.as_ref().to_string();
+        self.missing.retain(|f| *f != "query");
         self
     }
 
     pub fn result_id<T: AsRef<str>>(&mut self, result_id: T) -> &mut Self {
         self.inner.result_id = result_id.as_ref().to_string();
+        self.missing.retain(|f| *f != "result_id");
         self
     }
 
@@ -4509,12 +7322,13 @@ impl AsRef<UpdateNewChosenInlineResult> for RTDUpdateNewChosenInlineResultBuilde
 
 /// A new incoming event; for bots only
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateNewCustomEvent")]
 pub struct UpdateNewCustomEvent {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// A JSON-serialized event
     event: String,
@@ -4534,6 +7348,20 @@ impl RObject for UpdateNewCustomEvent {
     }
 }
 
+impl PartialEq for UpdateNewCustomEvent {
+    fn eq(&self, other: &Self) -> bool {
+        self.event == other.event
+    }
+}
+
+impl Eq for UpdateNewCustomEvent {}
+
+impl std::hash::Hash for UpdateNewCustomEvent {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.event, state);
+    }
+}
+
 impl TDUpdate for UpdateNewCustomEvent {}
 
 impl UpdateNewCustomEvent {
@@ -4541,29 +7369,66 @@ impl UpdateNewCustomEvent {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateNewCustomEventBuilder {
-        let mut inner = UpdateNewCustomEvent::default();
-        inner.td_name = "updateNewCustomEvent".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateNewCustomEventBuilder { inner }
+        RTDUpdateNewCustomEventBuilder {
+            inner: UpdateNewCustomEvent::default(),
+            missing: vec!["event"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateNewCustomEventBuilder {
+        RTDUpdateNewCustomEventBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateNewCustomEventBuilder {
+        RTDUpdateNewCustomEventBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn event(&self) -> &String {
         &self.event
     }
+
+    pub fn into_event(self) -> String {
+        self.event
+    }
+
+    pub fn with_event<T: AsRef<str>>(mut self, event: T) -> Self {
+        self.event = event.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateNewCustomEventBuilder {
     inner: UpdateNewCustomEvent,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateNewCustomEventBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateNewCustomEvent {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateNewCustomEvent> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateNewCustomEvent",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn event<T: AsRef<str>>(&mut self, event: T) -> &mut Self {
         self.inner.event = event.as_ref().to_string();
+        self.missing.retain(|f| *f != "event");
         self
     }
 }
@@ -4582,19 +7447,21 @@ impl AsRef<UpdateNewCustomEvent> for RTDUpdateNewCustomEventBuilder {
 
 /// A new incoming query; for bots only
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateNewCustomQuery")]
 pub struct UpdateNewCustomQuery {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The query identifier
-    id: isize,
+    #[serde(with = "super::_common::int64")]
+    id: i64,
     /// JSON-serialized query data
     data: String,
     /// Query timeout
-    timeout: i64,
+    timeout: i32,
 }
 
 impl RObject for UpdateNewCustomQuery {
@@ -4611,6 +7478,22 @@ impl RObject for UpdateNewCustomQuery {
     }
 }
 
+impl PartialEq for UpdateNewCustomQuery {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id && self.data == other.data && self.timeout == other.timeout
+    }
+}
+
+impl Eq for UpdateNewCustomQuery {}
+
+impl std::hash::Hash for UpdateNewCustomQuery {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.id, state);
+        std::hash::Hash::hash(&self.data, state);
+        std::hash::Hash::hash(&self.timeout, state);
+    }
+}
+
 impl TDUpdate for UpdateNewCustomQuery {}
 
 impl UpdateNewCustomQuery {
@@ -4618,13 +7501,27 @@ impl UpdateNewCustomQuery {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateNewCustomQueryBuilder {
-        let mut inner = UpdateNewCustomQuery::default();
-        inner.td_name = "updateNewCustomQuery".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateNewCustomQueryBuilder { inner }
+        RTDUpdateNewCustomQueryBuilder {
+            inner: UpdateNewCustomQuery::default(),
+            missing: vec!["id", "data", "timeout"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateNewCustomQueryBuilder {
+        RTDUpdateNewCustomQueryBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateNewCustomQueryBuilder {
+        RTDUpdateNewCustomQueryBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn id(&self) -> isize {
+    pub fn id(&self) -> i64 {
         self.id
     }
 
@@ -4632,33 +7529,68 @@ impl UpdateNewCustomQuery {
         &self.data
     }
 
-    pub fn timeout(&self) -> i64 {
+    pub fn timeout(&self) -> i32 {
         self.timeout
     }
+
+    pub fn into_data(self) -> String {
+        self.data
+    }
+
+    pub fn with_id(mut self, id: i64) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn with_data<T: AsRef<str>>(mut self, data: T) -> Self {
+        self.data = data.as_ref().to_string();
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: i32) -> Self {
+        self.timeout = timeout;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateNewCustomQueryBuilder {
     inner: UpdateNewCustomQuery,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateNewCustomQueryBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateNewCustomQuery {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateNewCustomQuery> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateNewCustomQuery",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn id(&mut self, id: isize) -> &mut Self {
+    pub fn id(&mut self, id: i64) -> &mut Self {
         self.inner.id = id;
+        self.missing.retain(|f| *f != "id");
         self
     }
 
     pub fn data<T: AsRef<str>>(&mut self, data: T) -> &mut Self {
         self.inner.data = data.as_ref().to_string();
+        self.missing.retain(|f| *f != "data");
         self
     }
 
-    pub fn timeout(&mut self, timeout: i64) -> &mut Self {
+    pub fn timeout(&mut self, timeout: i32) -> &mut Self {
         self.inner.timeout = timeout;
+        self.missing.retain(|f| *f != "timeout");
         self
     }
 }
@@ -4677,21 +7609,24 @@ impl AsRef<UpdateNewCustomQuery> for RTDUpdateNewCustomQueryBuilder {
 
 /// A new incoming callback query from a message sent via a bot; for bots only
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateNewInlineCallbackQuery")]
 pub struct UpdateNewInlineCallbackQuery {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Unique query identifier
-    id: isize,
+    #[serde(with = "super::_common::int64")]
+    id: i64,
     /// Identifier of the user who sent the query
-    sender_user_id: i64,
+    sender_user_id: UserId,
     /// Identifier of the inline message, from which the query originated
     inline_message_id: String,
     /// An identifier uniquely corresponding to the chat a message was sent to
-    chat_instance: isize,
+    #[serde(with = "super::_common::int64")]
+    chat_instance: i64,
     /// Query payload
     payload: CallbackQueryPayload,
 }
@@ -4710,6 +7645,28 @@ impl RObject for UpdateNewInlineCallbackQuery {
     }
 }
 
+impl PartialEq for UpdateNewInlineCallbackQuery {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.sender_user_id == other.sender_user_id
+            && self.inline_message_id == other.inline_message_id
+            && self.chat_instance == other.chat_instance
+            && self.payload == other.payload
+    }
+}
+
+impl Eq for UpdateNewInlineCallbackQuery {}
+
+impl std::hash::Hash for UpdateNewInlineCallbackQuery {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.id, state);
+        std::hash::Hash::hash(&self.sender_user_id, state);
+        std::hash::Hash::hash(&self.inline_message_id, state);
+        std::hash::Hash::hash(&self.chat_instance, state);
+        std::hash::Hash::hash(&self.payload, state);
+    }
+}
+
 impl TDUpdate for UpdateNewInlineCallbackQuery {}
 
 impl UpdateNewInlineCallbackQuery {
@@ -4717,17 +7674,37 @@ impl UpdateNewInlineCallbackQuery {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateNewInlineCallbackQueryBuilder {
-        let mut inner = UpdateNewInlineCallbackQuery::default();
-        inner.td_name = "updateNewInlineCallbackQuery".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateNewInlineCallbackQueryBuilder { inner }
+        RTDUpdateNewInlineCallbackQueryBuilder {
+            inner: UpdateNewInlineCallbackQuery::default(),
+            missing: vec![
+                "id",
+                "sender_user_id",
+                "inline_message_id",
+                "chat_instance",
+                "payload",
+            ],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateNewInlineCallbackQueryBuilder {
+        RTDUpdateNewInlineCallbackQueryBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateNewInlineCallbackQueryBuilder {
+        RTDUpdateNewInlineCallbackQueryBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn id(&self) -> isize {
+    pub fn id(&self) -> i64 {
         self.id
     }
 
-    pub fn sender_user_id(&self) -> i64 {
+    pub fn sender_user_id(&self) -> UserId {
         self.sender_user_id
     }
 
@@ -4735,47 +7712,98 @@ impl UpdateNewInlineCallbackQuery {
         &self.inline_message_id
     }
 
-    pub fn chat_instance(&self) -> isize {
+    pub fn chat_instance(&self) -> i64 {
         self.chat_instance
     }
 
     pub fn payload(&self) -> &CallbackQueryPayload {
         &self.payload
     }
+
+    pub fn into_inline_message_id(self) -> String {
+        self.inline_message_id
+    }
+
+    pub fn into_payload(self) -> CallbackQueryPayload {
+        self.payload
+    }
+
+    pub fn with_id(mut self, id: i64) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn with_sender_user_id<T: Into<UserId>>(mut self, sender_user_id: T) -> Self {
+        self.sender_user_id = sender_user_id.into();
+        self
+    }
+
+    pub fn with_inline_message_id<T: AsRef<str>>(mut self, inline_message_id: T) -> Self {
+        self.inline_message_id = inline_message_id.as_ref().to_string();
+        self
+    }
+
+    pub fn with_chat_instance(mut self, chat_instance: i64) -> Self {
+        self.chat_instance = chat_instance;
+        self
+    }
+
+    pub fn with_payload<T: AsRef<CallbackQueryPayload>>(mut self, payload: T) -> Self {
+        self.payload = payload.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateNewInlineCallbackQueryBuilder {
     inner: UpdateNewInlineCallbackQuery,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateNewInlineCallbackQueryBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateNewInlineCallbackQuery {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateNewInlineCallbackQuery> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateNewInlineCallbackQuery",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn id(&mut self, id: isize) -> &mut Self {
+    pub fn id(&mut self, id: i64) -> &mut Self {
         self.inner.id = id;
+        self.missing.retain(|f| *f != "id");
         self
     }
 
-    pub fn sender_user_id(&mut self, sender_user_id: i64) -> &mut Self {
-        self.inner.sender_user_id = sender_user_id;
+    pub fn sender_user_id<T: Into<UserId>>(&mut self, sender_user_id: T) -> &mut Self {
+        self.inner.sender_user_id = sender_user_id.into();
+        self.missing.retain(|f| *f != "sender_user_id");
         self
     }
 
     pub fn inline_message_id<T: AsRef<str>>(&mut self, inline_message_id: T) -> &mut Self {
         self.inner.inline_message_id = inline_message_id.as_ref().to_string();
+        self.missing.retain(|f| *f != "inline_message_id");
         self
     }
 
-    pub fn chat_instance(&mut self, chat_instance: isize) -> &mut Self {
+    pub fn chat_instance(&mut self, chat_instance: i64) -> &mut Self {
         self.inner.chat_instance = chat_instance;
+        self.missing.retain(|f| *f != "chat_instance");
         self
     }
 
     pub fn payload<T: AsRef<CallbackQueryPayload>>(&mut self, payload: T) -> &mut Self {
         self.inner.payload = payload.as_ref().clone();
+        self.missing.retain(|f| *f != "payload");
         self
     }
 }
@@ -4794,17 +7822,19 @@ impl AsRef<UpdateNewInlineCallbackQuery> for RTDUpdateNewInlineCallbackQueryBuil
 
 /// A new incoming inline query; for bots only
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateNewInlineQuery")]
 pub struct UpdateNewInlineQuery {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Unique query identifier
-    id: isize,
+    #[serde(with = "super::_common::int64")]
+    id: i64,
     /// Identifier of the user who sent the query
-    sender_user_id: i64,
+    sender_user_id: UserId,
     /// User location, provided by the client; may be null
     user_location: Option<Location>,
     /// Text of the query
@@ -4827,6 +7857,16 @@ impl RObject for UpdateNewInlineQuery {
     }
 }
 
+impl PartialEq for UpdateNewInlineQuery {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.sender_user_id == other.sender_user_id
+            && self.user_location == other.user_location
+            && self.query == other.query
+            && self.offset == other.offset
+    }
+}
+
 impl TDUpdate for UpdateNewInlineQuery {}
 
 impl UpdateNewInlineQuery {
@@ -4834,17 +7874,31 @@ impl UpdateNewInlineQuery {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateNewInlineQueryBuilder {
-        let mut inner = UpdateNewInlineQuery::default();
-        inner.td_name = "updateNewInlineQuery".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateNewInlineQueryBuilder { inner }
+        RTDUpdateNewInlineQueryBuilder {
+            inner: UpdateNewInlineQuery::default(),
+            missing: vec!["id", "sender_user_id", "query", "offset"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateNewInlineQueryBuilder {
+        RTDUpdateNewInlineQueryBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateNewInlineQueryBuilder {
+        RTDUpdateNewInlineQueryBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn id(&self) -> isize {
+    pub fn id(&self) -> i64 {
         self.id
     }
 
-    pub fn sender_user_id(&self) -> i64 {
+    pub fn sender_user_id(&self) -> UserId {
         self.sender_user_id
     }
 
@@ -4859,25 +7913,77 @@ impl UpdateNewInlineQuery {
     pub fn offset(&self) -> &String {
         &self.offset
     }
+
+    pub fn into_user_location(self) -> Option<Location> {
+        self.user_location
+    }
+
+    pub fn into_query(self) -> String {
+        self.query
+    }
+
+    pub fn into_offset(self) -> String {
+        self.offset
+    }
+
+    pub fn with_id(mut self, id: i64) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn with_sender_user_id<T: Into<UserId>>(mut self, sender_user_id: T) -> Self {
+        self.sender_user_id = sender_user_id.into();
+        self
+    }
+
+    pub fn with_user_location<T: AsRef<Location>>(mut self, user_location: T) -> Self {
+        self.user_location = Some(user_location.as_ref().clone());
+        self
+    }
+
+    pub fn with_query<T: AsRef<str>>(mut self, query: T) -> Self {
+        self.query = query.as_ref().to_string();
+        self
+    }
+
+    pub fn with_offset<T: AsRef<str>>(mut self, offset: T) -> Self {
+        self.offset = offset.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateNewInlineQueryBuilder {
     inner: UpdateNewInlineQuery,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateNewInlineQueryBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateNewInlineQuery {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateNewInlineQuery> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateNewInlineQuery",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn id(&mut self, id: isize) -> &mut Self {
+    pub fn id(&mut self, id: i64) -> &mut Self {
         self.inner.id = id;
+        self.missing.retain(|f| *f != "id");
         self
     }
 
-    pub fn sender_user_id(&mut self, sender_user_id: i64) -> &mut Self {
-        self.inner.sender_user_id = sender_user_id;
+    pub fn sender_user_id<T: Into<UserId>>(&mut self, sender_user_id: T) -> &mut Self {
+        self.inner.sender_user_id = sender_user_id.into();
+        self.missing.retain(|f| *f != "sender_user_id");
         self
     }
 
@@ -4888,11 +7994,13 @@ impl RTDUpdateNewInlineQueryBuilder {
 
     pub fn query<T: AsRef<str>>(&mut self, query: T) -> &mut Self {
         self.inner.query = query.as_ref().to_string();
+        self.missing.retain(|f| *f != "query");
         self
     }
 
     pub fn offset<T: AsRef<str>>(&mut self, offset: T) -> &mut Self {
         self.inner.offset = offset.as_ref().to_string();
+        self.missing.retain(|f| *f != "offset");
         self
     }
 }
@@ -4911,12 +8019,13 @@ impl AsRef<UpdateNewInlineQuery> for RTDUpdateNewInlineQueryBuilder {
 
 /// A new message was received; can also be an outgoing message
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateNewMessage")]
 pub struct UpdateNewMessage {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The new message
     message: Message,
@@ -4936,6 +8045,12 @@ impl RObject for UpdateNewMessage {
     }
 }
 
+impl PartialEq for UpdateNewMessage {
+    fn eq(&self, other: &Self) -> bool {
+        self.message == other.message
+    }
+}
+
 impl TDUpdate for UpdateNewMessage {}
 
 impl UpdateNewMessage {
@@ -4943,29 +8058,66 @@ impl UpdateNewMessage {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateNewMessageBuilder {
-        let mut inner = UpdateNewMessage::default();
-        inner.td_name = "updateNewMessage".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateNewMessageBuilder { inner }
+        RTDUpdateNewMessageBuilder {
+            inner: UpdateNewMessage::default(),
+            missing: vec!["message"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateNewMessageBuilder {
+        RTDUpdateNewMessageBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateNewMessageBuilder {
+        RTDUpdateNewMessageBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn message(&self) -> &Message {
         &self.message
     }
+
+    pub fn into_message(self) -> Message {
+        self.message
+    }
+
+    pub fn with_message<T: AsRef<Message>>(mut self, message: T) -> Self {
+        self.message = message.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateNewMessageBuilder {
     inner: UpdateNewMessage,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateNewMessageBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateNewMessage {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateNewMessage> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateNewMessage",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn message<T: AsRef<Message>>(&mut self, message: T) -> &mut Self {
         self.inner.message = message.as_ref().clone();
+        self.missing.retain(|f| *f != "message");
         self
     }
 }
@@ -4984,23 +8136,26 @@ impl AsRef<UpdateNewMessage> for RTDUpdateNewMessageBuilder {
 
 /// A new incoming pre-checkout query; for bots only. Contains full information about a checkout
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateNewPreCheckoutQuery")]
 pub struct UpdateNewPreCheckoutQuery {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Unique query identifier
-    id: isize,
+    #[serde(with = "super::_common::int64")]
+    id: i64,
     /// Identifier of the user who sent the query
-    sender_user_id: i64,
+    sender_user_id: UserId,
     /// Currency for the product price
     currency: String,
     /// Total price for the product, in the minimal quantity of the currency
     total_amount: i64,
     /// Invoice payload
-    invoice_payload: String,
+    #[serde(with = "super::_common::bytes")]
+    invoice_payload: Vec<u8>,
     /// Identifier of a shipping option chosen by the user; may be empty if not applicable
     shipping_option_id: String,
     /// Information about the order; may be null
@@ -5021,6 +8176,32 @@ impl RObject for UpdateNewPreCheckoutQuery {
     }
 }
 
+impl PartialEq for UpdateNewPreCheckoutQuery {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.sender_user_id == other.sender_user_id
+            && self.currency == other.currency
+            && self.total_amount == other.total_amount
+            && self.invoice_payload == other.invoice_payload
+            && self.shipping_option_id == other.shipping_option_id
+            && self.order_info == other.order_info
+    }
+}
+
+impl Eq for UpdateNewPreCheckoutQuery {}
+
+impl std::hash::Hash for UpdateNewPreCheckoutQuery {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.id, state);
+        std::hash::Hash::hash(&self.sender_user_id, state);
+        std::hash::Hash::hash(&self.currency, state);
+        std::hash::Hash::hash(&self.total_amount, state);
+        std::hash::Hash::hash(&self.invoice_payload, state);
+        std::hash::Hash::hash(&self.shipping_option_id, state);
+        std::hash::Hash::hash(&self.order_info, state);
+    }
+}
+
 impl TDUpdate for UpdateNewPreCheckoutQuery {}
 
 impl UpdateNewPreCheckoutQuery {
@@ -5028,17 +8209,37 @@ impl UpdateNewPreCheckoutQuery {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateNewPreCheckoutQueryBuilder {
-        let mut inner = UpdateNewPreCheckoutQuery::default();
-        inner.td_name = "updateNewPreCheckoutQuery".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateNewPreCheckoutQueryBuilder { inner }
+        RTDUpdateNewPreCheckoutQueryBuilder {
+            inner: UpdateNewPreCheckoutQuery::default(),
+            missing: vec![
+                "id",
+                "sender_user_id",
+                "currency",
+                "total_amount",
+                "invoice_payload",
+            ],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateNewPreCheckoutQueryBuilder {
+        RTDUpdateNewPreCheckoutQueryBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateNewPreCheckoutQueryBuilder {
+        RTDUpdateNewPreCheckoutQueryBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn id(&self) -> isize {
+    pub fn id(&self) -> i64 {
         self.id
     }
 
-    pub fn sender_user_id(&self) -> i64 {
+    pub fn sender_user_id(&self) -> UserId {
         self.sender_user_id
     }
 
@@ -5050,7 +8251,7 @@ impl UpdateNewPreCheckoutQuery {
         self.total_amount
     }
 
-    pub fn invoice_payload(&self) -> &String {
+    pub fn invoice_payload(&self) -> &[u8] {
         &self.invoice_payload
     }
 
@@ -5061,40 +8262,109 @@ impl UpdateNewPreCheckoutQuery {
     pub fn order_info(&self) -> &Option<OrderInfo> {
         &self.order_info
     }
+
+    pub fn into_currency(self) -> String {
+        self.currency
+    }
+
+    pub fn into_invoice_payload(self) -> Vec<u8> {
+        self.invoice_payload
+    }
+
+    pub fn into_shipping_option_id(self) -> String {
+        self.shipping_option_id
+    }
+
+    pub fn into_order_info(self) -> Option<OrderInfo> {
+        self.order_info
+    }
+
+    pub fn with_id(mut self, id: i64) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn with_sender_user_id<T: Into<UserId>>(mut self, sender_user_id: T) -> Self {
+        self.sender_user_id = sender_user_id.into();
+        self
+    }
+
+    pub fn with_currency<T: AsRef<str>>(mut self, currency: T) -> Self {
+        self.currency = currency.as_ref().to_string();
+        self
+    }
+
+    pub fn with_total_amount(mut self, total_amount: i64) -> Self {
+        self.total_amount = total_amount;
+        self
+    }
+
+    pub fn with_invoice_payload<T: AsRef<[u8]>>(mut self, invoice_payload: T) -> Self {
+        self.invoice_payload = invoice_payload.as_ref().to_vec();
+        self
+    }
+
+    pub fn with_shipping_option_id<T: AsRef<str>>(mut self, shipping_option_id: T) -> Self {
+        self.shipping_option_id = shipping_option_id.as_ref().to_string();
+        self
+    }
+
+    pub fn with_order_info<T: AsRef<OrderInfo>>(mut self, order_info: T) -> Self {
+        self.order_info = Some(order_info.as_ref().clone());
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateNewPreCheckoutQueryBuilder {
     inner: UpdateNewPreCheckoutQuery,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateNewPreCheckoutQueryBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateNewPreCheckoutQuery {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateNewPreCheckoutQuery> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateNewPreCheckoutQuery",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn id(&mut self, id: isize) -> &mut Self {
+    pub fn id(&mut self, id: i64) -> &mut Self {
         self.inner.id = id;
+        self.missing.retain(|f| *f != "id");
         self
     }
 
-    pub fn sender_user_id(&mut self, sender_user_id: i64) -> &mut Self {
-        self.inner.sender_user_id = sender_user_id;
+    pub fn sender_user_id<T: Into<UserId>>(&mut self, sender_user_id: T) -> &mut Self {
+        self.inner.sender_user_id = sender_user_id.into();
+        self.missing.retain(|f| *f != "sender_user_id");
         self
     }
 
     pub fn currency<T: AsRef<str>>(&mut self, currency: T) -> &mut Self {
         self.inner.currency = currency.as_ref().to_string();
+        self.missing.retain(|f| *f != "currency");
         self
     }
 
     pub fn total_amount(&mut self, total_amount: i64) -> &mut Self {
         self.inner.total_amount = total_amount;
+        self.missing.retain(|f| *f != "total_amount");
         self
     }
 
-    pub fn invoice_payload<T: AsRef<str>>(&mut self, invoice_payload: T) -> &mut Self {
-        self.inner.invoice_payload = invoice_payload.as_ref().to_string();
+    pub fn invoice_payload<T: AsRef<[u8]>>(&mut self, invoice_payload: T) -> &mut Self {
+        self.inner.invoice_payload = invoice_payload.as_ref().to_vec();
+        self.missing.retain(|f| *f != "invoice_payload");
         self
     }
 
@@ -5123,19 +8393,22 @@ impl AsRef<UpdateNewPreCheckoutQuery> for RTDUpdateNewPreCheckoutQueryBuilder {
 
 /// A new incoming shipping query; for bots only. Only for invoices with flexible price
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateNewShippingQuery")]
 pub struct UpdateNewShippingQuery {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Unique query identifier
-    id: isize,
+    #[serde(with = "super::_common::int64")]
+    id: i64,
     /// Identifier of the user who sent the query
-    sender_user_id: i64,
+    sender_user_id: UserId,
     /// Invoice payload
-    invoice_payload: String,
+    #[serde(with = "super::_common::bytes")]
+    invoice_payload: Vec<u8>,
     /// User shipping address
     shipping_address: Address,
 }
@@ -5154,6 +8427,26 @@ impl RObject for UpdateNewShippingQuery {
     }
 }
 
+impl PartialEq for UpdateNewShippingQuery {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.sender_user_id == other.sender_user_id
+            && self.invoice_payload == other.invoice_payload
+            && self.shipping_address == other.shipping_address
+    }
+}
+
+impl Eq for UpdateNewShippingQuery {}
+
+impl std::hash::Hash for UpdateNewShippingQuery {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.id, state);
+        std::hash::Hash::hash(&self.sender_user_id, state);
+        std::hash::Hash::hash(&self.invoice_payload, state);
+        std::hash::Hash::hash(&self.shipping_address, state);
+    }
+}
+
 impl TDUpdate for UpdateNewShippingQuery {}
 
 impl UpdateNewShippingQuery {
@@ -5161,56 +8454,120 @@ impl UpdateNewShippingQuery {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateNewShippingQueryBuilder {
-        let mut inner = UpdateNewShippingQuery::default();
-        inner.td_name = "updateNewShippingQuery".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateNewShippingQueryBuilder { inner }
+        RTDUpdateNewShippingQueryBuilder {
+            inner: UpdateNewShippingQuery::default(),
+            missing: vec![
+                "id",
+                "sender_user_id",
+                "invoice_payload",
+                "shipping_address",
+            ],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateNewShippingQueryBuilder {
+        RTDUpdateNewShippingQueryBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateNewShippingQueryBuilder {
+        RTDUpdateNewShippingQueryBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn id(&self) -> isize {
+    pub fn id(&self) -> i64 {
         self.id
     }
 
-    pub fn sender_user_id(&self) -> i64 {
+    pub fn sender_user_id(&self) -> UserId {
         self.sender_user_id
     }
 
-    pub fn invoice_payload(&self) -> &String {
+    pub fn invoice_payload(&self) -> &[u8] {
         &self.invoice_payload
     }
 
     pub fn shipping_address(&self) -> &Address {
         &self.shipping_address
     }
+
+    pub fn into_invoice_payload(self) -> Vec<u8> {
+        self.invoice_payload
+    }
+
+    pub fn into_shipping_address(self) -> Address {
+        self.shipping_address
+    }
+
+    pub fn with_id(mut self, id: i64) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn with_sender_user_id<T: Into<UserId>>(mut self, sender_user_id: T) -> Self {
+        self.sender_user_id = sender_user_id.into();
+        self
+    }
+
+    pub fn with_invoice_payload<T: AsRef<[u8]>>(mut self, invoice_payload: T) -> Self {
+        self.invoice_payload = invoice_payload.as_ref().to_vec();
+        self
+    }
+
+    pub fn with_shipping_address<T: AsRef<Address>>(mut self, shipping_address: T) -> Self {
+        self.shipping_address = shipping_address.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateNewShippingQueryBuilder {
     inner: UpdateNewShippingQuery,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateNewShippingQueryBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateNewShippingQuery {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateNewShippingQuery> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateNewShippingQuery",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn id(&mut self, id: isize) -> &mut Self {
+    pub fn id(&mut self, id: i64) -> &mut Self {
         self.inner.id = id;
+        self.missing.retain(|f| *f != "id");
         self
     }
 
-    pub fn sender_user_id(&mut self, sender_user_id: i64) -> &mut Self {
-        self.inner.sender_user_id = sender_user_id;
+    pub fn sender_user_id<T: Into<UserId>>(&mut self, sender_user_id: T) -> &mut Self {
+        self.inner.sender_user_id = sender_user_id.into();
+        self.missing.retain(|f| *f != "sender_user_id");
         self
     }
 
-    pub fn invoice_payload<T: AsRef<str>>(&mut self, invoice_payload: T) -> &mut Self {
-        self.inner.invoice_payload = invoice_payload.as_ref().to_string();
+    pub fn invoice_payload<T: AsRef<[u8]>>(&mut self, invoice_payload: T) -> &mut Self {
+        self.inner.invoice_payload = invoice_payload.as_ref().to_vec();
+        self.missing.retain(|f| *f != "invoice_payload");
         self
     }
 
     pub fn shipping_address<T: AsRef<Address>>(&mut self, shipping_address: T) -> &mut Self {
         self.inner.shipping_address = shipping_address.as_ref().clone();
+        self.missing.retain(|f| *f != "shipping_address");
         self
     }
 }
@@ -5229,15 +8586,16 @@ impl AsRef<UpdateNewShippingQuery> for RTDUpdateNewShippingQueryBuilder {
 
 /// A notification was changed
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateNotification")]
 pub struct UpdateNotification {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Unique notification group identifier
-    notification_group_id: i64,
+    notification_group_id: i32,
     /// Changed notification
     notification: Notification,
 }
@@ -5256,6 +8614,13 @@ impl RObject for UpdateNotification {
     }
 }
 
+impl PartialEq for UpdateNotification {
+    fn eq(&self, other: &Self) -> bool {
+        self.notification_group_id == other.notification_group_id
+            && self.notification == other.notification
+    }
+}
+
 impl TDUpdate for UpdateNotification {}
 
 impl UpdateNotification {
@@ -5263,38 +8628,81 @@ impl UpdateNotification {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateNotificationBuilder {
-        let mut inner = UpdateNotification::default();
-        inner.td_name = "updateNotification".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateNotificationBuilder { inner }
+        RTDUpdateNotificationBuilder {
+            inner: UpdateNotification::default(),
+            missing: vec!["notification_group_id", "notification"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateNotificationBuilder {
+        RTDUpdateNotificationBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateNotificationBuilder {
+        RTDUpdateNotificationBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn notification_group_id(&self) -> i64 {
+    pub fn notification_group_id(&self) -> i32 {
         self.notification_group_id
     }
 
     pub fn notification(&self) -> &Notification {
         &self.notification
     }
+
+    pub fn into_notification(self) -> Notification {
+        self.notification
+    }
+
+    pub fn with_notification_group_id(mut self, notification_group_id: i32) -> Self {
+        self.notification_group_id = notification_group_id;
+        self
+    }
+
+    pub fn with_notification<T: AsRef<Notification>>(mut self, notification: T) -> Self {
+        self.notification = notification.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateNotificationBuilder {
     inner: UpdateNotification,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateNotificationBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateNotification {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateNotification> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateNotification",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn notification_group_id(&mut self, notification_group_id: i64) -> &mut Self {
+    pub fn notification_group_id(&mut self, notification_group_id: i32) -> &mut Self {
         self.inner.notification_group_id = notification_group_id;
+        self.missing.retain(|f| *f != "notification_group_id");
         self
     }
 
     pub fn notification<T: AsRef<Notification>>(&mut self, notification: T) -> &mut Self {
         self.inner.notification = notification.as_ref().clone();
+        self.missing.retain(|f| *f != "notification");
         self
     }
 }
@@ -5313,30 +8721,31 @@ impl AsRef<UpdateNotification> for RTDUpdateNotificationBuilder {
 
 /// A list of active notifications in a notification group has changed
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateNotificationGroup")]
 pub struct UpdateNotificationGroup {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Unique notification group identifier
-    notification_group_id: i64,
+    notification_group_id: i32,
     /// New type of the notification group
     #[serde(rename(serialize = "type", deserialize = "type"))]
     type_: NotificationGroupType,
     /// Identifier of a chat to which all notifications in the group belong
-    chat_id: i64,
+    chat_id: ChatId,
     /// Chat identifier, which notification settings must be applied to the added notifications
-    notification_settings_chat_id: i64,
+    notification_settings_chat_id: ChatId,
     /// True, if the notifications should be shown without sound
     is_silent: bool,
     /// Total number of unread notifications in the group, can be bigger than number of active notifications
-    total_count: i64,
+    total_count: i32,
     /// List of added group notifications, sorted by notification ID
     added_notifications: Vec<Notification>,
     /// Identifiers of removed group notifications, sorted by notification ID
-    removed_notification_ids: Vec<i64>,
+    removed_notification_ids: Vec<i32>,
 }
 
 impl RObject for UpdateNotificationGroup {
@@ -5353,82 +8762,190 @@ impl RObject for UpdateNotificationGroup {
     }
 }
 
+impl PartialEq for UpdateNotificationGroup {
+    fn eq(&self, other: &Self) -> bool {
+        self.notification_group_id == other.notification_group_id
+            && self.type_ == other.type_
+            && self.chat_id == other.chat_id
+            && self.notification_settings_chat_id == other.notification_settings_chat_id
+            && self.is_silent == other.is_silent
+            && self.total_count == other.total_count
+            && self.added_notifications == other.added_notifications
+            && self.removed_notification_ids == other.removed_notification_ids
+    }
+}
+
 impl TDUpdate for UpdateNotificationGroup {}
 
 impl UpdateNotificationGroup {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    pub fn builder() -> RTDUpdateNotificationGroupBuilder {
-        let mut inner = UpdateNotificationGroup::default();
-        inner.td_name = "updateNotificationGroup".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateNotificationGroupBuilder { inner }
+    pub fn builder() -> RTDUpdateNotificationGroupBuilder {
+        RTDUpdateNotificationGroupBuilder {
+            inner: UpdateNotificationGroup::default(),
+            missing: vec![
+                "notification_group_id",
+                "type_",
+                "chat_id",
+                "notification_settings_chat_id",
+                "total_count",
+                "added_notifications",
+                "removed_notification_ids",
+            ],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateNotificationGroupBuilder {
+        RTDUpdateNotificationGroupBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateNotificationGroupBuilder {
+        RTDUpdateNotificationGroupBuilder {
+            inner: self,
+            missing: vec![],
+        }
+    }
+
+    pub fn notification_group_id(&self) -> i32 {
+        self.notification_group_id
+    }
+
+    pub fn type_(&self) -> &NotificationGroupType {
+        &self.type_
+    }
+
+    pub fn chat_id(&self) -> ChatId {
+        self.chat_id
+    }
+
+    pub fn notification_settings_chat_id(&self) -> ChatId {
+        self.notification_settings_chat_id
+    }
+
+    pub fn is_silent(&self) -> bool {
+        self.is_silent
+    }
+
+    pub fn total_count(&self) -> i32 {
+        self.total_count
+    }
+
+    pub fn added_notifications(&self) -> &Vec<Notification> {
+        &self.added_notifications
+    }
+
+    pub fn removed_notification_ids(&self) -> &Vec<i32> {
+        &self.removed_notification_ids
+    }
+
+    pub fn into_type(self) -> NotificationGroupType {
+        self.type_
+    }
+
+    pub fn into_added_notifications(self) -> Vec<Notification> {
+        self.added_notifications
+    }
+
+    pub fn into_removed_notification_ids(self) -> Vec<i32> {
+        self.removed_notification_ids
     }
 
-    pub fn notification_group_id(&self) -> i64 {
-        self.notification_group_id
+    pub fn with_notification_group_id(mut self, notification_group_id: i32) -> Self {
+        self.notification_group_id = notification_group_id;
+        self
     }
 
-    pub fn type_(&self) -> &NotificationGroupType {
-        &self.type_
+    pub fn with_type<T: AsRef<NotificationGroupType>>(mut self, type_: T) -> Self {
+        self.type_ = type_.as_ref().clone();
+        self
     }
 
-    pub fn chat_id(&self) -> i64 {
-        self.chat_id
+    pub fn with_chat_id<T: Into<ChatId>>(mut self, chat_id: T) -> Self {
+        self.chat_id = chat_id.into();
+        self
     }
 
-    pub fn notification_settings_chat_id(&self) -> i64 {
-        self.notification_settings_chat_id
+    pub fn with_notification_settings_chat_id<T: Into<ChatId>>(
+        mut self,
+        notification_settings_chat_id: T,
+    ) -> Self {
+        self.notification_settings_chat_id = notification_settings_chat_id.into();
+        self
     }
 
-    pub fn is_silent(&self) -> bool {
-        self.is_silent
+    pub fn with_is_silent(mut self, is_silent: bool) -> Self {
+        self.is_silent = is_silent;
+        self
     }
 
-    pub fn total_count(&self) -> i64 {
-        self.total_count
+    pub fn with_total_count(mut self, total_count: i32) -> Self {
+        self.total_count = total_count;
+        self
     }
 
-    pub fn added_notifications(&self) -> &Vec<Notification> {
-        &self.added_notifications
+    pub fn with_added_notifications(mut self, added_notifications: Vec<Notification>) -> Self {
+        self.added_notifications = added_notifications;
+        self
     }
 
-    pub fn removed_notification_ids(&self) -> &Vec<i64> {
-        &self.removed_notification_ids
+    pub fn with_removed_notification_ids(mut self, removed_notification_ids: Vec<i32>) -> Self {
+        self.removed_notification_ids = removed_notification_ids;
+        self
     }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateNotificationGroupBuilder {
     inner: UpdateNotificationGroup,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateNotificationGroupBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateNotificationGroup {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateNotificationGroup> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateNotificationGroup",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn notification_group_id(&mut self, notification_group_id: i64) -> &mut Self {
+    pub fn notification_group_id(&mut self, notification_group_id: i32) -> &mut Self {
         self.inner.notification_group_id = notification_group_id;
+        self.missing.retain(|f| *f != "notification_group_id");
         self
     }
 
     pub fn type_<T: AsRef<NotificationGroupType>>(&mut self, type_: T) -> &mut Self {
         self.inner.type_ = type_.as_ref().clone();
+        self.missing.retain(|f| *f != "type_");
         self
     }
 
-    pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
-        self.inner.chat_id = chat_id;
+    pub fn chat_id<T: Into<ChatId>>(&mut self, chat_id: T) -> &mut Self {
+        self.inner.chat_id = chat_id.into();
+        self.missing.retain(|f| *f != "chat_id");
         self
     }
 
-    pub fn notification_settings_chat_id(
+    pub fn notification_settings_chat_id<T: Into<ChatId>>(
         &mut self,
-        notification_settings_chat_id: i64,
+        notification_settings_chat_id: T,
     ) -> &mut Self {
-        self.inner.notification_settings_chat_id = notification_settings_chat_id;
+        self.inner.notification_settings_chat_id = notification_settings_chat_id.into();
+        self.missing
+            .retain(|f| *f != "notification_settings_chat_id");
         self
     }
 
@@ -5437,18 +8954,21 @@ impl RTDUpdateNotificationGroupBuilder {
         self
     }
 
-    pub fn total_count(&mut self, total_count: i64) -> &mut Self {
+    pub fn total_count(&mut self, total_count: i32) -> &mut Self {
         self.inner.total_count = total_count;
+        self.missing.retain(|f| *f != "total_count");
         self
     }
 
     pub fn added_notifications(&mut self, added_notifications: Vec<Notification>) -> &mut Self {
         self.inner.added_notifications = added_notifications;
+        self.missing.retain(|f| *f != "added_notifications");
         self
     }
 
-    pub fn removed_notification_ids(&mut self, removed_notification_ids: Vec<i64>) -> &mut Self {
+    pub fn removed_notification_ids(&mut self, removed_notification_ids: Vec<i32>) -> &mut Self {
         self.inner.removed_notification_ids = removed_notification_ids;
+        self.missing.retain(|f| *f != "removed_notification_ids");
         self
     }
 }
@@ -5467,12 +8987,13 @@ impl AsRef<UpdateNotificationGroup> for RTDUpdateNotificationGroupBuilder {
 
 /// An option changed its value
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateOption")]
 pub struct UpdateOption {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The option name
     name: String,
@@ -5494,6 +9015,21 @@ impl RObject for UpdateOption {
     }
 }
 
+impl PartialEq for UpdateOption {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.value == other.value
+    }
+}
+
+impl Eq for UpdateOption {}
+
+impl std::hash::Hash for UpdateOption {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.name, state);
+        std::hash::Hash::hash(&self.value, state);
+    }
+}
+
 impl TDUpdate for UpdateOption {}
 
 impl UpdateOption {
@@ -5501,10 +9037,24 @@ impl UpdateOption {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateOptionBuilder {
-        let mut inner = UpdateOption::default();
-        inner.td_name = "updateOption".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateOptionBuilder { inner }
+        RTDUpdateOptionBuilder {
+            inner: UpdateOption::default(),
+            missing: vec!["name", "value"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateOptionBuilder {
+        RTDUpdateOptionBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateOptionBuilder {
+        RTDUpdateOptionBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn name(&self) -> &String {
@@ -5514,25 +9064,58 @@ impl UpdateOption {
     pub fn value(&self) -> &OptionValue {
         &self.value
     }
+
+    pub fn into_name(self) -> String {
+        self.name
+    }
+
+    pub fn into_value(self) -> OptionValue {
+        self.value
+    }
+
+    pub fn with_name<T: AsRef<str>>(mut self, name: T) -> Self {
+        self.name = name.as_ref().to_string();
+        self
+    }
+
+    pub fn with_value<T: AsRef<OptionValue>>(mut self, value: T) -> Self {
+        self.value = value.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateOptionBuilder {
     inner: UpdateOption,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateOptionBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateOption {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateOption> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateOption",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn name<T: AsRef<str>>(&mut self, name: T) -> &mut Self {
         self.inner.name = name.as_ref().to_string();
+        self.missing.retain(|f| *f != "name");
         self
     }
 
     pub fn value<T: AsRef<OptionValue>>(&mut self, value: T) -> &mut Self {
         self.inner.value = value.as_ref().clone();
+        self.missing.retain(|f| *f != "value");
         self
     }
 }
@@ -5551,12 +9134,13 @@ impl AsRef<UpdateOption> for RTDUpdateOptionBuilder {
 
 /// A poll was updated; for bots only
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updatePoll")]
 pub struct UpdatePoll {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// New data about the poll
     poll: Poll,
@@ -5576,6 +9160,20 @@ impl RObject for UpdatePoll {
     }
 }
 
+impl PartialEq for UpdatePoll {
+    fn eq(&self, other: &Self) -> bool {
+        self.poll == other.poll
+    }
+}
+
+impl Eq for UpdatePoll {}
+
+impl std::hash::Hash for UpdatePoll {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.poll, state);
+    }
+}
+
 impl TDUpdate for UpdatePoll {}
 
 impl UpdatePoll {
@@ -5583,29 +9181,63 @@ impl UpdatePoll {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdatePollBuilder {
-        let mut inner = UpdatePoll::default();
-        inner.td_name = "updatePoll".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdatePollBuilder { inner }
+        RTDUpdatePollBuilder {
+            inner: UpdatePoll::default(),
+            missing: vec!["poll"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdatePollBuilder {
+        RTDUpdatePollBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdatePollBuilder {
+        RTDUpdatePollBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn poll(&self) -> &Poll {
         &self.poll
     }
+
+    pub fn into_poll(self) -> Poll {
+        self.poll
+    }
+
+    pub fn with_poll<T: AsRef<Poll>>(mut self, poll: T) -> Self {
+        self.poll = poll.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdatePollBuilder {
     inner: UpdatePoll,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdatePollBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdatePoll {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdatePoll> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("UpdatePoll", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn poll<T: AsRef<Poll>>(&mut self, poll: T) -> &mut Self {
         self.inner.poll = poll.as_ref().clone();
+        self.missing.retain(|f| *f != "poll");
         self
     }
 }
@@ -5624,19 +9256,21 @@ impl AsRef<UpdatePoll> for RTDUpdatePollBuilder {
 
 /// A user changed the answer to a poll; for bots only
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updatePollAnswer")]
 pub struct UpdatePollAnswer {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Unique poll identifier
-    poll_id: isize,
+    #[serde(with = "super::_common::int64")]
+    poll_id: i64,
     /// The user, who changed the answer to the poll
-    user_id: i64,
+    user_id: UserId,
     /// 0-based identifiers of answer options, chosen by the user
-    option_ids: Vec<i64>,
+    option_ids: Vec<i32>,
 }
 
 impl RObject for UpdatePollAnswer {
@@ -5653,6 +9287,24 @@ impl RObject for UpdatePollAnswer {
     }
 }
 
+impl PartialEq for UpdatePollAnswer {
+    fn eq(&self, other: &Self) -> bool {
+        self.poll_id == other.poll_id
+            && self.user_id == other.user_id
+            && self.option_ids == other.option_ids
+    }
+}
+
+impl Eq for UpdatePollAnswer {}
+
+impl std::hash::Hash for UpdatePollAnswer {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.poll_id, state);
+        std::hash::Hash::hash(&self.user_id, state);
+        std::hash::Hash::hash(&self.option_ids, state);
+    }
+}
+
 impl TDUpdate for UpdatePollAnswer {}
 
 impl UpdatePollAnswer {
@@ -5660,46 +9312,94 @@ impl UpdatePollAnswer {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdatePollAnswerBuilder {
-        let mut inner = UpdatePollAnswer::default();
-        inner.td_name = "updatePollAnswer".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdatePollAnswerBuilder { inner }
+        RTDUpdatePollAnswerBuilder {
+            inner: UpdatePollAnswer::default(),
+            missing: vec!["poll_id", "user_id"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdatePollAnswerBuilder {
+        RTDUpdatePollAnswerBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdatePollAnswerBuilder {
+        RTDUpdatePollAnswerBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn poll_id(&self) -> isize {
+    pub fn poll_id(&self) -> i64 {
         self.poll_id
     }
 
-    pub fn user_id(&self) -> i64 {
+    pub fn user_id(&self) -> UserId {
         self.user_id
     }
 
-    pub fn option_ids(&self) -> &Vec<i64> {
+    pub fn option_ids(&self) -> &Vec<i32> {
         &self.option_ids
     }
+
+    pub fn into_option_ids(self) -> Vec<i32> {
+        self.option_ids
+    }
+
+    pub fn with_poll_id(mut self, poll_id: i64) -> Self {
+        self.poll_id = poll_id;
+        self
+    }
+
+    pub fn with_user_id<T: Into<UserId>>(mut self, user_id: T) -> Self {
+        self.user_id = user_id.into();
+        self
+    }
+
+    pub fn with_option_ids(mut self, option_ids: Vec<i32>) -> Self {
+        self.option_ids = option_ids;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdatePollAnswerBuilder {
     inner: UpdatePollAnswer,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdatePollAnswerBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdatePollAnswer {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdatePollAnswer> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdatePollAnswer",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn poll_id(&mut self, poll_id: isize) -> &mut Self {
+    pub fn poll_id(&mut self, poll_id: i64) -> &mut Self {
         self.inner.poll_id = poll_id;
+        self.missing.retain(|f| *f != "poll_id");
         self
     }
 
-    pub fn user_id(&mut self, user_id: i64) -> &mut Self {
-        self.inner.user_id = user_id;
+    pub fn user_id<T: Into<UserId>>(&mut self, user_id: T) -> &mut Self {
+        self.inner.user_id = user_id.into();
+        self.missing.retain(|f| *f != "user_id");
         self
     }
 
-    pub fn option_ids(&mut self, option_ids: Vec<i64>) -> &mut Self {
+    pub fn option_ids(&mut self, option_ids: Vec<i32>) -> &mut Self {
         self.inner.option_ids = option_ids;
         self
     }
@@ -5719,17 +9419,18 @@ impl AsRef<UpdatePollAnswer> for RTDUpdatePollAnswerBuilder {
 
 /// The list of recently used stickers was updated
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateRecentStickers")]
 pub struct UpdateRecentStickers {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// True, if the list of stickers attached to photo or video files was updated, otherwise the list of sent stickers is updated
     is_attached: bool,
     /// The new list of file identifiers of recently used stickers
-    sticker_ids: Vec<i64>,
+    sticker_ids: Vec<i32>,
 }
 
 impl RObject for UpdateRecentStickers {
@@ -5746,6 +9447,21 @@ impl RObject for UpdateRecentStickers {
     }
 }
 
+impl PartialEq for UpdateRecentStickers {
+    fn eq(&self, other: &Self) -> bool {
+        self.is_attached == other.is_attached && self.sticker_ids == other.sticker_ids
+    }
+}
+
+impl Eq for UpdateRecentStickers {}
+
+impl std::hash::Hash for UpdateRecentStickers {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.is_attached, state);
+        std::hash::Hash::hash(&self.sticker_ids, state);
+    }
+}
+
 impl TDUpdate for UpdateRecentStickers {}
 
 impl UpdateRecentStickers {
@@ -5753,38 +9469,80 @@ impl UpdateRecentStickers {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateRecentStickersBuilder {
-        let mut inner = UpdateRecentStickers::default();
-        inner.td_name = "updateRecentStickers".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateRecentStickersBuilder { inner }
+        RTDUpdateRecentStickersBuilder {
+            inner: UpdateRecentStickers::default(),
+            missing: vec!["sticker_ids"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateRecentStickersBuilder {
+        RTDUpdateRecentStickersBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateRecentStickersBuilder {
+        RTDUpdateRecentStickersBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn is_attached(&self) -> bool {
         self.is_attached
     }
 
-    pub fn sticker_ids(&self) -> &Vec<i64> {
+    pub fn sticker_ids(&self) -> &Vec<i32> {
         &self.sticker_ids
     }
+
+    pub fn into_sticker_ids(self) -> Vec<i32> {
+        self.sticker_ids
+    }
+
+    pub fn with_is_attached(mut self, is_attached: bool) -> Self {
+        self.is_attached = is_attached;
+        self
+    }
+
+    pub fn with_sticker_ids(mut self, sticker_ids: Vec<i32>) -> Self {
+        self.sticker_ids = sticker_ids;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateRecentStickersBuilder {
     inner: UpdateRecentStickers,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateRecentStickersBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateRecentStickers {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateRecentStickers> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateRecentStickers",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn is_attached(&mut self, is_attached: bool) -> &mut Self {
         self.inner.is_attached = is_attached;
         self
     }
 
-    pub fn sticker_ids(&mut self, sticker_ids: Vec<i64>) -> &mut Self {
+    pub fn sticker_ids(&mut self, sticker_ids: Vec<i32>) -> &mut Self {
         self.inner.sticker_ids = sticker_ids;
+        self.missing.retain(|f| *f != "sticker_ids");
         self
     }
 }
@@ -5803,15 +9561,16 @@ impl AsRef<UpdateRecentStickers> for RTDUpdateRecentStickersBuilder {
 
 /// The list of saved animations was updated
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateSavedAnimations")]
 pub struct UpdateSavedAnimations {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The new list of file identifiers of saved animations
-    animation_ids: Vec<i64>,
+    animation_ids: Vec<i32>,
 }
 
 impl RObject for UpdateSavedAnimations {
@@ -5828,6 +9587,20 @@ impl RObject for UpdateSavedAnimations {
     }
 }
 
+impl PartialEq for UpdateSavedAnimations {
+    fn eq(&self, other: &Self) -> bool {
+        self.animation_ids == other.animation_ids
+    }
+}
+
+impl Eq for UpdateSavedAnimations {}
+
+impl std::hash::Hash for UpdateSavedAnimations {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.animation_ids, state);
+    }
+}
+
 impl TDUpdate for UpdateSavedAnimations {}
 
 impl UpdateSavedAnimations {
@@ -5835,29 +9608,66 @@ impl UpdateSavedAnimations {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateSavedAnimationsBuilder {
-        let mut inner = UpdateSavedAnimations::default();
-        inner.td_name = "updateSavedAnimations".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateSavedAnimationsBuilder { inner }
+        RTDUpdateSavedAnimationsBuilder {
+            inner: UpdateSavedAnimations::default(),
+            missing: vec!["animation_ids"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateSavedAnimationsBuilder {
+        RTDUpdateSavedAnimationsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateSavedAnimationsBuilder {
+        RTDUpdateSavedAnimationsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn animation_ids(&self) -> &Vec<i64> {
+    pub fn animation_ids(&self) -> &Vec<i32> {
         &self.animation_ids
     }
+
+    pub fn into_animation_ids(self) -> Vec<i32> {
+        self.animation_ids
+    }
+
+    pub fn with_animation_ids(mut self, animation_ids: Vec<i32>) -> Self {
+        self.animation_ids = animation_ids;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateSavedAnimationsBuilder {
     inner: UpdateSavedAnimations,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateSavedAnimationsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateSavedAnimations {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateSavedAnimations> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateSavedAnimations",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn animation_ids(&mut self, animation_ids: Vec<i64>) -> &mut Self {
+    pub fn animation_ids(&mut self, animation_ids: Vec<i32>) -> &mut Self {
         self.inner.animation_ids = animation_ids;
+        self.missing.retain(|f| *f != "animation_ids");
         self
     }
 }
@@ -5876,12 +9686,13 @@ impl AsRef<UpdateSavedAnimations> for RTDUpdateSavedAnimationsBuilder {
 
 /// Notification settings for some type of chats were updated
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateScopeNotificationSettings")]
 pub struct UpdateScopeNotificationSettings {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Types of chats for which notification settings were updated
     scope: NotificationSettingsScope,
@@ -5903,6 +9714,21 @@ impl RObject for UpdateScopeNotificationSettings {
     }
 }
 
+impl PartialEq for UpdateScopeNotificationSettings {
+    fn eq(&self, other: &Self) -> bool {
+        self.scope == other.scope && self.notification_settings == other.notification_settings
+    }
+}
+
+impl Eq for UpdateScopeNotificationSettings {}
+
+impl std::hash::Hash for UpdateScopeNotificationSettings {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.scope, state);
+        std::hash::Hash::hash(&self.notification_settings, state);
+    }
+}
+
 impl TDUpdate for UpdateScopeNotificationSettings {}
 
 impl UpdateScopeNotificationSettings {
@@ -5910,10 +9736,24 @@ impl UpdateScopeNotificationSettings {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateScopeNotificationSettingsBuilder {
-        let mut inner = UpdateScopeNotificationSettings::default();
-        inner.td_name = "updateScopeNotificationSettings".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateScopeNotificationSettingsBuilder { inner }
+        RTDUpdateScopeNotificationSettingsBuilder {
+            inner: UpdateScopeNotificationSettings::default(),
+            missing: vec!["scope", "notification_settings"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateScopeNotificationSettingsBuilder {
+        RTDUpdateScopeNotificationSettingsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateScopeNotificationSettingsBuilder {
+        RTDUpdateScopeNotificationSettingsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn scope(&self) -> &NotificationSettingsScope {
@@ -5923,20 +9763,55 @@ impl UpdateScopeNotificationSettings {
     pub fn notification_settings(&self) -> &ScopeNotificationSettings {
         &self.notification_settings
     }
+
+    pub fn into_scope(self) -> NotificationSettingsScope {
+        self.scope
+    }
+
+    pub fn into_notification_settings(self) -> ScopeNotificationSettings {
+        self.notification_settings
+    }
+
+    pub fn with_scope<T: AsRef<NotificationSettingsScope>>(mut self, scope: T) -> Self {
+        self.scope = scope.as_ref().clone();
+        self
+    }
+
+    pub fn with_notification_settings<T: AsRef<ScopeNotificationSettings>>(
+        mut self,
+        notification_settings: T,
+    ) -> Self {
+        self.notification_settings = notification_settings.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateScopeNotificationSettingsBuilder {
     inner: UpdateScopeNotificationSettings,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateScopeNotificationSettingsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateScopeNotificationSettings {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateScopeNotificationSettings> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateScopeNotificationSettings",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn scope<T: AsRef<NotificationSettingsScope>>(&mut self, scope: T) -> &mut Self {
         self.inner.scope = scope.as_ref().clone();
+        self.missing.retain(|f| *f != "scope");
         self
     }
 
@@ -5945,6 +9820,7 @@ impl RTDUpdateScopeNotificationSettingsBuilder {
         notification_settings: T,
     ) -> &mut Self {
         self.inner.notification_settings = notification_settings.as_ref().clone();
+        self.missing.retain(|f| *f != "notification_settings");
         self
     }
 }
@@ -5963,12 +9839,13 @@ impl AsRef<UpdateScopeNotificationSettings> for RTDUpdateScopeNotificationSettin
 
 /// Some data of a secret chat has changed. This update is guaranteed to come before the secret chat identifier is returned to the client
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateSecretChat")]
 pub struct UpdateSecretChat {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// New data about the secret chat
     secret_chat: SecretChat,
@@ -5988,6 +9865,20 @@ impl RObject for UpdateSecretChat {
     }
 }
 
+impl PartialEq for UpdateSecretChat {
+    fn eq(&self, other: &Self) -> bool {
+        self.secret_chat == other.secret_chat
+    }
+}
+
+impl Eq for UpdateSecretChat {}
+
+impl std::hash::Hash for UpdateSecretChat {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.secret_chat, state);
+    }
+}
+
 impl TDUpdate for UpdateSecretChat {}
 
 impl UpdateSecretChat {
@@ -5995,29 +9886,66 @@ impl UpdateSecretChat {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateSecretChatBuilder {
-        let mut inner = UpdateSecretChat::default();
-        inner.td_name = "updateSecretChat".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateSecretChatBuilder { inner }
+        RTDUpdateSecretChatBuilder {
+            inner: UpdateSecretChat::default(),
+            missing: vec!["secret_chat"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateSecretChatBuilder {
+        RTDUpdateSecretChatBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateSecretChatBuilder {
+        RTDUpdateSecretChatBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn secret_chat(&self) -> &SecretChat {
         &self.secret_chat
     }
+
+    pub fn into_secret_chat(self) -> SecretChat {
+        self.secret_chat
+    }
+
+    pub fn with_secret_chat<T: AsRef<SecretChat>>(mut self, secret_chat: T) -> Self {
+        self.secret_chat = secret_chat.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateSecretChatBuilder {
     inner: UpdateSecretChat,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateSecretChatBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateSecretChat {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateSecretChat> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateSecretChat",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn secret_chat<T: AsRef<SecretChat>>(&mut self, secret_chat: T) -> &mut Self {
         self.inner.secret_chat = secret_chat.as_ref().clone();
+        self.missing.retain(|f| *f != "secret_chat");
         self
     }
 }
@@ -6036,12 +9964,13 @@ impl AsRef<UpdateSecretChat> for RTDUpdateSecretChatBuilder {
 
 /// The selected background has changed
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateSelectedBackground")]
 pub struct UpdateSelectedBackground {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// True, if background for dark theme has changed
     for_dark_theme: bool,
@@ -6063,6 +9992,21 @@ impl RObject for UpdateSelectedBackground {
     }
 }
 
+impl PartialEq for UpdateSelectedBackground {
+    fn eq(&self, other: &Self) -> bool {
+        self.for_dark_theme == other.for_dark_theme && self.background == other.background
+    }
+}
+
+impl Eq for UpdateSelectedBackground {}
+
+impl std::hash::Hash for UpdateSelectedBackground {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.for_dark_theme, state);
+        std::hash::Hash::hash(&self.background, state);
+    }
+}
+
 impl TDUpdate for UpdateSelectedBackground {}
 
 impl UpdateSelectedBackground {
@@ -6070,10 +10014,24 @@ impl UpdateSelectedBackground {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateSelectedBackgroundBuilder {
-        let mut inner = UpdateSelectedBackground::default();
-        inner.td_name = "updateSelectedBackground".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateSelectedBackgroundBuilder { inner }
+        RTDUpdateSelectedBackgroundBuilder {
+            inner: UpdateSelectedBackground::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateSelectedBackgroundBuilder {
+        RTDUpdateSelectedBackgroundBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateSelectedBackgroundBuilder {
+        RTDUpdateSelectedBackgroundBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn for_dark_theme(&self) -> bool {
@@ -6083,17 +10041,44 @@ impl UpdateSelectedBackground {
     pub fn background(&self) -> &Option<Background> {
         &self.background
     }
+
+    pub fn into_background(self) -> Option<Background> {
+        self.background
+    }
+
+    pub fn with_for_dark_theme(mut self, for_dark_theme: bool) -> Self {
+        self.for_dark_theme = for_dark_theme;
+        self
+    }
+
+    pub fn with_background<T: AsRef<Background>>(mut self, background: T) -> Self {
+        self.background = Some(background.as_ref().clone());
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateSelectedBackgroundBuilder {
     inner: UpdateSelectedBackground,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateSelectedBackgroundBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateSelectedBackground {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateSelectedBackground> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateSelectedBackground",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn for_dark_theme(&mut self, for_dark_theme: bool) -> &mut Self {
         self.inner.for_dark_theme = for_dark_theme;
@@ -6120,12 +10105,13 @@ impl AsRef<UpdateSelectedBackground> for RTDUpdateSelectedBackgroundBuilder {
 
 /// Service notification from the server. Upon receiving this the client must show a popup with the content of the notification
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateServiceNotification")]
 pub struct UpdateServiceNotification {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Notification type. If type begins with "AUTH_KEY_DROP_", then two buttons "Cancel" and "Log out" should be shown under notification; if user presses the second, all local data should be destroyed using Destroy method
     #[serde(rename(serialize = "type", deserialize = "type"))]
@@ -6148,6 +10134,12 @@ impl RObject for UpdateServiceNotification {
     }
 }
 
+impl PartialEq for UpdateServiceNotification {
+    fn eq(&self, other: &Self) -> bool {
+        self.type_ == other.type_ && self.content == other.content
+    }
+}
+
 impl TDUpdate for UpdateServiceNotification {}
 
 impl UpdateServiceNotification {
@@ -6155,10 +10147,24 @@ impl UpdateServiceNotification {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateServiceNotificationBuilder {
-        let mut inner = UpdateServiceNotification::default();
-        inner.td_name = "updateServiceNotification".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateServiceNotificationBuilder { inner }
+        RTDUpdateServiceNotificationBuilder {
+            inner: UpdateServiceNotification::default(),
+            missing: vec!["type_", "content"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateServiceNotificationBuilder {
+        RTDUpdateServiceNotificationBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateServiceNotificationBuilder {
+        RTDUpdateServiceNotificationBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn type_(&self) -> &String {
@@ -6168,25 +10174,58 @@ impl UpdateServiceNotification {
     pub fn content(&self) -> &MessageContent {
         &self.content
     }
+
+    pub fn into_type(self) -> String {
+        self.type_
+    }
+
+    pub fn into_content(self) -> MessageContent {
+        self.content
+    }
+
+    pub fn with_type<T: AsRef<str>>(mut self, type_: T) -> Self {
+        self.type_ = type_.as_ref().to_string();
+        self
+    }
+
+    pub fn with_content<T: AsRef<MessageContent>>(mut self, content: T) -> Self {
+        self.content = content.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateServiceNotificationBuilder {
     inner: UpdateServiceNotification,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateServiceNotificationBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateServiceNotification {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateServiceNotification> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateServiceNotification",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn type_<T: AsRef<str>>(&mut self, type_: T) -> &mut Self {
         self.inner.type_ = type_.as_ref().to_string();
+        self.missing.retain(|f| *f != "type_");
         self
     }
 
     pub fn content<T: AsRef<MessageContent>>(&mut self, content: T) -> &mut Self {
         self.inner.content = content.as_ref().clone();
+        self.missing.retain(|f| *f != "content");
         self
     }
 }
@@ -6205,12 +10244,13 @@ impl AsRef<UpdateServiceNotification> for RTDUpdateServiceNotificationBuilder {
 
 /// Some data of a supergroup or a channel has changed. This update is guaranteed to come before the supergroup identifier is returned to the client
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateSupergroup")]
 pub struct UpdateSupergroup {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// New data about the supergroup
     supergroup: Supergroup,
@@ -6230,6 +10270,20 @@ impl RObject for UpdateSupergroup {
     }
 }
 
+impl PartialEq for UpdateSupergroup {
+    fn eq(&self, other: &Self) -> bool {
+        self.supergroup == other.supergroup
+    }
+}
+
+impl Eq for UpdateSupergroup {}
+
+impl std::hash::Hash for UpdateSupergroup {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.supergroup, state);
+    }
+}
+
 impl TDUpdate for UpdateSupergroup {}
 
 impl UpdateSupergroup {
@@ -6237,29 +10291,66 @@ impl UpdateSupergroup {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateSupergroupBuilder {
-        let mut inner = UpdateSupergroup::default();
-        inner.td_name = "updateSupergroup".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateSupergroupBuilder { inner }
+        RTDUpdateSupergroupBuilder {
+            inner: UpdateSupergroup::default(),
+            missing: vec!["supergroup"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateSupergroupBuilder {
+        RTDUpdateSupergroupBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateSupergroupBuilder {
+        RTDUpdateSupergroupBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn supergroup(&self) -> &Supergroup {
         &self.supergroup
     }
+
+    pub fn into_supergroup(self) -> Supergroup {
+        self.supergroup
+    }
+
+    pub fn with_supergroup<T: AsRef<Supergroup>>(mut self, supergroup: T) -> Self {
+        self.supergroup = supergroup.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateSupergroupBuilder {
     inner: UpdateSupergroup,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateSupergroupBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateSupergroup {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateSupergroup> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateSupergroup",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn supergroup<T: AsRef<Supergroup>>(&mut self, supergroup: T) -> &mut Self {
         self.inner.supergroup = supergroup.as_ref().clone();
+        self.missing.retain(|f| *f != "supergroup");
         self
     }
 }
@@ -6278,15 +10369,16 @@ impl AsRef<UpdateSupergroup> for RTDUpdateSupergroupBuilder {
 
 /// Some data from supergroupFullInfo has been changed
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateSupergroupFullInfo")]
 pub struct UpdateSupergroupFullInfo {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Identifier of the supergroup or channel
-    supergroup_id: i64,
+    supergroup_id: SupergroupId,
     /// New full information about the supergroup
     supergroup_full_info: SupergroupFullInfo,
 }
@@ -6305,40 +10397,92 @@ impl RObject for UpdateSupergroupFullInfo {
     }
 }
 
+impl PartialEq for UpdateSupergroupFullInfo {
+    fn eq(&self, other: &Self) -> bool {
+        self.supergroup_id == other.supergroup_id
+            && self.supergroup_full_info == other.supergroup_full_info
+    }
+}
+
 impl TDUpdate for UpdateSupergroupFullInfo {}
 
 impl UpdateSupergroupFullInfo {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    pub fn builder() -> RTDUpdateSupergroupFullInfoBuilder {
-        let mut inner = UpdateSupergroupFullInfo::default();
-        inner.td_name = "updateSupergroupFullInfo".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateSupergroupFullInfoBuilder { inner }
+    pub fn builder() -> RTDUpdateSupergroupFullInfoBuilder {
+        RTDUpdateSupergroupFullInfoBuilder {
+            inner: UpdateSupergroupFullInfo::default(),
+            missing: vec!["supergroup_id", "supergroup_full_info"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateSupergroupFullInfoBuilder {
+        RTDUpdateSupergroupFullInfoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateSupergroupFullInfoBuilder {
+        RTDUpdateSupergroupFullInfoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn supergroup_id(&self) -> i64 {
+    pub fn supergroup_id(&self) -> SupergroupId {
         self.supergroup_id
     }
 
     pub fn supergroup_full_info(&self) -> &SupergroupFullInfo {
         &self.supergroup_full_info
     }
+
+    pub fn into_supergroup_full_info(self) -> SupergroupFullInfo {
+        self.supergroup_full_info
+    }
+
+    pub fn with_supergroup_id<T: Into<SupergroupId>>(mut self, supergroup_id: T) -> Self {
+        self.supergroup_id = supergroup_id.into();
+        self
+    }
+
+    pub fn with_supergroup_full_info<T: AsRef<SupergroupFullInfo>>(
+        mut self,
+        supergroup_full_info: T,
+    ) -> Self {
+        self.supergroup_full_info = supergroup_full_info.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateSupergroupFullInfoBuilder {
     inner: UpdateSupergroupFullInfo,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateSupergroupFullInfoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateSupergroupFullInfo {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateSupergroupFullInfo> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateSupergroupFullInfo",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn supergroup_id(&mut self, supergroup_id: i64) -> &mut Self {
-        self.inner.supergroup_id = supergroup_id;
+    pub fn supergroup_id<T: Into<SupergroupId>>(&mut self, supergroup_id: T) -> &mut Self {
+        self.inner.supergroup_id = supergroup_id.into();
+        self.missing.retain(|f| *f != "supergroup_id");
         self
     }
 
@@ -6347,6 +10491,7 @@ impl RTDUpdateSupergroupFullInfoBuilder {
         supergroup_full_info: T,
     ) -> &mut Self {
         self.inner.supergroup_full_info = supergroup_full_info.as_ref().clone();
+        self.missing.retain(|f| *f != "supergroup_full_info");
         self
     }
 }
@@ -6365,12 +10510,13 @@ impl AsRef<UpdateSupergroupFullInfo> for RTDUpdateSupergroupFullInfoBuilder {
 
 /// New terms of service must be accepted by the user. If the terms of service are declined, then the deleteAccount method should be called with the reason "Decline ToS update"
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateTermsOfService")]
 pub struct UpdateTermsOfService {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Identifier of the terms of service
     terms_of_service_id: String,
@@ -6392,6 +10538,22 @@ impl RObject for UpdateTermsOfService {
     }
 }
 
+impl PartialEq for UpdateTermsOfService {
+    fn eq(&self, other: &Self) -> bool {
+        self.terms_of_service_id == other.terms_of_service_id
+            && self.terms_of_service == other.terms_of_service
+    }
+}
+
+impl Eq for UpdateTermsOfService {}
+
+impl std::hash::Hash for UpdateTermsOfService {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.terms_of_service_id, state);
+        std::hash::Hash::hash(&self.terms_of_service, state);
+    }
+}
+
 impl TDUpdate for UpdateTermsOfService {}
 
 impl UpdateTermsOfService {
@@ -6399,10 +10561,24 @@ impl UpdateTermsOfService {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateTermsOfServiceBuilder {
-        let mut inner = UpdateTermsOfService::default();
-        inner.td_name = "updateTermsOfService".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateTermsOfServiceBuilder { inner }
+        RTDUpdateTermsOfServiceBuilder {
+            inner: UpdateTermsOfService::default(),
+            missing: vec!["terms_of_service_id", "terms_of_service"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateTermsOfServiceBuilder {
+        RTDUpdateTermsOfServiceBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateTermsOfServiceBuilder {
+        RTDUpdateTermsOfServiceBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn terms_of_service_id(&self) -> &String {
@@ -6412,25 +10588,58 @@ impl UpdateTermsOfService {
     pub fn terms_of_service(&self) -> &TermsOfService {
         &self.terms_of_service
     }
+
+    pub fn into_terms_of_service_id(self) -> String {
+        self.terms_of_service_id
+    }
+
+    pub fn into_terms_of_service(self) -> TermsOfService {
+        self.terms_of_service
+    }
+
+    pub fn with_terms_of_service_id<T: AsRef<str>>(mut self, terms_of_service_id: T) -> Self {
+        self.terms_of_service_id = terms_of_service_id.as_ref().to_string();
+        self
+    }
+
+    pub fn with_terms_of_service<T: AsRef<TermsOfService>>(mut self, terms_of_service: T) -> Self {
+        self.terms_of_service = terms_of_service.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateTermsOfServiceBuilder {
     inner: UpdateTermsOfService,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateTermsOfServiceBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateTermsOfService {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateTermsOfService> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateTermsOfService",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn terms_of_service_id<T: AsRef<str>>(&mut self, terms_of_service_id: T) -> &mut Self {
         self.inner.terms_of_service_id = terms_of_service_id.as_ref().to_string();
+        self.missing.retain(|f| *f != "terms_of_service_id");
         self
     }
 
     pub fn terms_of_service<T: AsRef<TermsOfService>>(&mut self, terms_of_service: T) -> &mut Self {
         self.inner.terms_of_service = terms_of_service.as_ref().clone();
+        self.missing.retain(|f| *f != "terms_of_service");
         self
     }
 }
@@ -6449,12 +10658,13 @@ impl AsRef<UpdateTermsOfService> for RTDUpdateTermsOfServiceBuilder {
 
 /// The list of trending sticker sets was updated or some of them were viewed
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateTrendingStickerSets")]
 pub struct UpdateTrendingStickerSets {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The new list of trending sticker sets
     sticker_sets: StickerSets,
@@ -6474,6 +10684,12 @@ impl RObject for UpdateTrendingStickerSets {
     }
 }
 
+impl PartialEq for UpdateTrendingStickerSets {
+    fn eq(&self, other: &Self) -> bool {
+        self.sticker_sets == other.sticker_sets
+    }
+}
+
 impl TDUpdate for UpdateTrendingStickerSets {}
 
 impl UpdateTrendingStickerSets {
@@ -6481,29 +10697,66 @@ impl UpdateTrendingStickerSets {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateTrendingStickerSetsBuilder {
-        let mut inner = UpdateTrendingStickerSets::default();
-        inner.td_name = "updateTrendingStickerSets".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateTrendingStickerSetsBuilder { inner }
+        RTDUpdateTrendingStickerSetsBuilder {
+            inner: UpdateTrendingStickerSets::default(),
+            missing: vec!["sticker_sets"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateTrendingStickerSetsBuilder {
+        RTDUpdateTrendingStickerSetsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateTrendingStickerSetsBuilder {
+        RTDUpdateTrendingStickerSetsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn sticker_sets(&self) -> &StickerSets {
         &self.sticker_sets
     }
+
+    pub fn into_sticker_sets(self) -> StickerSets {
+        self.sticker_sets
+    }
+
+    pub fn with_sticker_sets<T: AsRef<StickerSets>>(mut self, sticker_sets: T) -> Self {
+        self.sticker_sets = sticker_sets.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateTrendingStickerSetsBuilder {
     inner: UpdateTrendingStickerSets,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateTrendingStickerSetsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateTrendingStickerSets {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateTrendingStickerSets> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateTrendingStickerSets",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn sticker_sets<T: AsRef<StickerSets>>(&mut self, sticker_sets: T) -> &mut Self {
         self.inner.sticker_sets = sticker_sets.as_ref().clone();
+        self.missing.retain(|f| *f != "sticker_sets");
         self
     }
 }
@@ -6522,25 +10775,26 @@ impl AsRef<UpdateTrendingStickerSets> for RTDUpdateTrendingStickerSetsBuilder {
 
 /// Number of unread chats, i.e. with unread messages or marked as unread, has changed. This update is sent only if the message database is used
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateUnreadChatCount")]
 pub struct UpdateUnreadChatCount {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The chat list with changed number of unread messages
     chat_list: ChatList,
     /// Approximate total number of chats in the chat list
-    total_count: i64,
+    total_count: i32,
     /// Total number of unread chats
-    unread_count: i64,
+    unread_count: i32,
     /// Total number of unread unmuted chats
-    unread_unmuted_count: i64,
+    unread_unmuted_count: i32,
     /// Total number of chats marked as unread
-    marked_as_unread_count: i64,
+    marked_as_unread_count: i32,
     /// Total number of unmuted chats marked as unread
-    marked_as_unread_unmuted_count: i64,
+    marked_as_unread_unmuted_count: i32,
 }
 
 impl RObject for UpdateUnreadChatCount {
@@ -6557,6 +10811,30 @@ impl RObject for UpdateUnreadChatCount {
     }
 }
 
+impl PartialEq for UpdateUnreadChatCount {
+    fn eq(&self, other: &Self) -> bool {
+        self.chat_list == other.chat_list
+            && self.total_count == other.total_count
+            && self.unread_count == other.unread_count
+            && self.unread_unmuted_count == other.unread_unmuted_count
+            && self.marked_as_unread_count == other.marked_as_unread_count
+            && self.marked_as_unread_unmuted_count == other.marked_as_unread_unmuted_count
+    }
+}
+
+impl Eq for UpdateUnreadChatCount {}
+
+impl std::hash::Hash for UpdateUnreadChatCount {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.chat_list, state);
+        std::hash::Hash::hash(&self.total_count, state);
+        std::hash::Hash::hash(&self.unread_count, state);
+        std::hash::Hash::hash(&self.unread_unmuted_count, state);
+        std::hash::Hash::hash(&self.marked_as_unread_count, state);
+        std::hash::Hash::hash(&self.marked_as_unread_unmuted_count, state);
+    }
+}
+
 impl TDUpdate for UpdateUnreadChatCount {}
 
 impl UpdateUnreadChatCount {
@@ -6564,77 +10842,155 @@ impl UpdateUnreadChatCount {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateUnreadChatCountBuilder {
-        let mut inner = UpdateUnreadChatCount::default();
-        inner.td_name = "updateUnreadChatCount".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateUnreadChatCountBuilder { inner }
+        RTDUpdateUnreadChatCountBuilder {
+            inner: UpdateUnreadChatCount::default(),
+            missing: vec![
+                "chat_list",
+                "total_count",
+                "unread_count",
+                "unread_unmuted_count",
+                "marked_as_unread_count",
+                "marked_as_unread_unmuted_count",
+            ],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateUnreadChatCountBuilder {
+        RTDUpdateUnreadChatCountBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateUnreadChatCountBuilder {
+        RTDUpdateUnreadChatCountBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn chat_list(&self) -> &ChatList {
         &self.chat_list
     }
 
-    pub fn total_count(&self) -> i64 {
+    pub fn total_count(&self) -> i32 {
         self.total_count
     }
 
-    pub fn unread_count(&self) -> i64 {
+    pub fn unread_count(&self) -> i32 {
         self.unread_count
     }
 
-    pub fn unread_unmuted_count(&self) -> i64 {
+    pub fn unread_unmuted_count(&self) -> i32 {
         self.unread_unmuted_count
     }
 
-    pub fn marked_as_unread_count(&self) -> i64 {
+    pub fn marked_as_unread_count(&self) -> i32 {
         self.marked_as_unread_count
     }
 
-    pub fn marked_as_unread_unmuted_count(&self) -> i64 {
+    pub fn marked_as_unread_unmuted_count(&self) -> i32 {
         self.marked_as_unread_unmuted_count
     }
+
+    pub fn into_chat_list(self) -> ChatList {
+        self.chat_list
+    }
+
+    pub fn with_chat_list<T: AsRef<ChatList>>(mut self, chat_list: T) -> Self {
+        self.chat_list = chat_list.as_ref().clone();
+        self
+    }
+
+    pub fn with_total_count(mut self, total_count: i32) -> Self {
+        self.total_count = total_count;
+        self
+    }
+
+    pub fn with_unread_count(mut self, unread_count: i32) -> Self {
+        self.unread_count = unread_count;
+        self
+    }
+
+    pub fn with_unread_unmuted_count(mut self, unread_unmuted_count: i32) -> Self {
+        self.unread_unmuted_count = unread_unmuted_count;
+        self
+    }
+
+    pub fn with_marked_as_unread_count(mut self, marked_as_unread_count: i32) -> Self {
+        self.marked_as_unread_count = marked_as_unread_count;
+        self
+    }
+
+    pub fn with_marked_as_unread_unmuted_count(
+        mut self,
+        marked_as_unread_unmuted_count: i32,
+    ) -> Self {
+        self.marked_as_unread_unmuted_count = marked_as_unread_unmuted_count;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateUnreadChatCountBuilder {
     inner: UpdateUnreadChatCount,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateUnreadChatCountBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateUnreadChatCount {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateUnreadChatCount> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateUnreadChatCount",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn chat_list<T: AsRef<ChatList>>(&mut self, chat_list: T) -> &mut Self {
         self.inner.chat_list = chat_list.as_ref().clone();
+        self.missing.retain(|f| *f != "chat_list");
         self
     }
 
-    pub fn total_count(&mut self, total_count: i64) -> &mut Self {
+    pub fn total_count(&mut self, total_count: i32) -> &mut Self {
         self.inner.total_count = total_count;
+        self.missing.retain(|f| *f != "total_count");
         self
     }
 
-    pub fn unread_count(&mut self, unread_count: i64) -> &mut Self {
+    pub fn unread_count(&mut self, unread_count: i32) -> &mut Self {
         self.inner.unread_count = unread_count;
+        self.missing.retain(|f| *f != "unread_count");
         self
     }
 
-    pub fn unread_unmuted_count(&mut self, unread_unmuted_count: i64) -> &mut Self {
+    pub fn unread_unmuted_count(&mut self, unread_unmuted_count: i32) -> &mut Self {
         self.inner.unread_unmuted_count = unread_unmuted_count;
+        self.missing.retain(|f| *f != "unread_unmuted_count");
         self
     }
 
-    pub fn marked_as_unread_count(&mut self, marked_as_unread_count: i64) -> &mut Self {
+    pub fn marked_as_unread_count(&mut self, marked_as_unread_count: i32) -> &mut Self {
         self.inner.marked_as_unread_count = marked_as_unread_count;
+        self.missing.retain(|f| *f != "marked_as_unread_count");
         self
     }
 
     pub fn marked_as_unread_unmuted_count(
         &mut self,
-        marked_as_unread_unmuted_count: i64,
+        marked_as_unread_unmuted_count: i32,
     ) -> &mut Self {
         self.inner.marked_as_unread_unmuted_count = marked_as_unread_unmuted_count;
+        self.missing
+            .retain(|f| *f != "marked_as_unread_unmuted_count");
         self
     }
 }
@@ -6653,19 +11009,20 @@ impl AsRef<UpdateUnreadChatCount> for RTDUpdateUnreadChatCountBuilder {
 
 /// Number of unread messages in a chat list has changed. This update is sent only if the message database is used
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateUnreadMessageCount")]
 pub struct UpdateUnreadMessageCount {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The chat list with changed number of unread messages
     chat_list: ChatList,
     /// Total number of unread messages
-    unread_count: i64,
+    unread_count: i32,
     /// Total number of unread messages in unmuted chats
-    unread_unmuted_count: i64,
+    unread_unmuted_count: i32,
 }
 
 impl RObject for UpdateUnreadMessageCount {
@@ -6682,6 +11039,24 @@ impl RObject for UpdateUnreadMessageCount {
     }
 }
 
+impl PartialEq for UpdateUnreadMessageCount {
+    fn eq(&self, other: &Self) -> bool {
+        self.chat_list == other.chat_list
+            && self.unread_count == other.unread_count
+            && self.unread_unmuted_count == other.unread_unmuted_count
+    }
+}
+
+impl Eq for UpdateUnreadMessageCount {}
+
+impl std::hash::Hash for UpdateUnreadMessageCount {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.chat_list, state);
+        std::hash::Hash::hash(&self.unread_count, state);
+        std::hash::Hash::hash(&self.unread_unmuted_count, state);
+    }
+}
+
 impl TDUpdate for UpdateUnreadMessageCount {}
 
 impl UpdateUnreadMessageCount {
@@ -6689,47 +11064,96 @@ impl UpdateUnreadMessageCount {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateUnreadMessageCountBuilder {
-        let mut inner = UpdateUnreadMessageCount::default();
-        inner.td_name = "updateUnreadMessageCount".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateUnreadMessageCountBuilder { inner }
+        RTDUpdateUnreadMessageCountBuilder {
+            inner: UpdateUnreadMessageCount::default(),
+            missing: vec!["chat_list", "unread_count", "unread_unmuted_count"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateUnreadMessageCountBuilder {
+        RTDUpdateUnreadMessageCountBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateUnreadMessageCountBuilder {
+        RTDUpdateUnreadMessageCountBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn chat_list(&self) -> &ChatList {
         &self.chat_list
     }
 
-    pub fn unread_count(&self) -> i64 {
+    pub fn unread_count(&self) -> i32 {
         self.unread_count
     }
 
-    pub fn unread_unmuted_count(&self) -> i64 {
+    pub fn unread_unmuted_count(&self) -> i32 {
         self.unread_unmuted_count
     }
+
+    pub fn into_chat_list(self) -> ChatList {
+        self.chat_list
+    }
+
+    pub fn with_chat_list<T: AsRef<ChatList>>(mut self, chat_list: T) -> Self {
+        self.chat_list = chat_list.as_ref().clone();
+        self
+    }
+
+    pub fn with_unread_count(mut self, unread_count: i32) -> Self {
+        self.unread_count = unread_count;
+        self
+    }
+
+    pub fn with_unread_unmuted_count(mut self, unread_unmuted_count: i32) -> Self {
+        self.unread_unmuted_count = unread_unmuted_count;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateUnreadMessageCountBuilder {
     inner: UpdateUnreadMessageCount,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateUnreadMessageCountBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateUnreadMessageCount {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateUnreadMessageCount> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateUnreadMessageCount",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn chat_list<T: AsRef<ChatList>>(&mut self, chat_list: T) -> &mut Self {
         self.inner.chat_list = chat_list.as_ref().clone();
+        self.missing.retain(|f| *f != "chat_list");
         self
     }
 
-    pub fn unread_count(&mut self, unread_count: i64) -> &mut Self {
+    pub fn unread_count(&mut self, unread_count: i32) -> &mut Self {
         self.inner.unread_count = unread_count;
+        self.missing.retain(|f| *f != "unread_count");
         self
     }
 
-    pub fn unread_unmuted_count(&mut self, unread_unmuted_count: i64) -> &mut Self {
+    pub fn unread_unmuted_count(&mut self, unread_unmuted_count: i32) -> &mut Self {
         self.inner.unread_unmuted_count = unread_unmuted_count;
+        self.missing.retain(|f| *f != "unread_unmuted_count");
         self
     }
 }
@@ -6748,12 +11172,13 @@ impl AsRef<UpdateUnreadMessageCount> for RTDUpdateUnreadMessageCountBuilder {
 
 /// Some data of a user has changed. This update is guaranteed to come before the user identifier is returned to the client
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateUser")]
 pub struct UpdateUser {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// New data about the user
     user: User,
@@ -6773,6 +11198,20 @@ impl RObject for UpdateUser {
     }
 }
 
+impl PartialEq for UpdateUser {
+    fn eq(&self, other: &Self) -> bool {
+        self.user == other.user
+    }
+}
+
+impl Eq for UpdateUser {}
+
+impl std::hash::Hash for UpdateUser {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.user, state);
+    }
+}
+
 impl TDUpdate for UpdateUser {}
 
 impl UpdateUser {
@@ -6780,29 +11219,63 @@ impl UpdateUser {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateUserBuilder {
-        let mut inner = UpdateUser::default();
-        inner.td_name = "updateUser".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateUserBuilder { inner }
+        RTDUpdateUserBuilder {
+            inner: UpdateUser::default(),
+            missing: vec!["user"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateUserBuilder {
+        RTDUpdateUserBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateUserBuilder {
+        RTDUpdateUserBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn user(&self) -> &User {
         &self.user
     }
+
+    pub fn into_user(self) -> User {
+        self.user
+    }
+
+    pub fn with_user<T: AsRef<User>>(mut self, user: T) -> Self {
+        self.user = user.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateUserBuilder {
     inner: UpdateUser,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateUserBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateUser {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateUser> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("UpdateUser", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn user<T: AsRef<User>>(&mut self, user: T) -> &mut Self {
         self.inner.user = user.as_ref().clone();
+        self.missing.retain(|f| *f != "user");
         self
     }
 }
@@ -6821,17 +11294,18 @@ impl AsRef<UpdateUser> for RTDUpdateUserBuilder {
 
 /// User activity in the chat has changed
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateUserChatAction")]
 pub struct UpdateUserChatAction {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Chat identifier
-    chat_id: i64,
+    chat_id: ChatId,
     /// Identifier of a user performing an action
-    user_id: i64,
+    user_id: UserId,
     /// The action description
     action: ChatAction,
 }
@@ -6850,6 +11324,24 @@ impl RObject for UpdateUserChatAction {
     }
 }
 
+impl PartialEq for UpdateUserChatAction {
+    fn eq(&self, other: &Self) -> bool {
+        self.chat_id == other.chat_id
+            && self.user_id == other.user_id
+            && self.action == other.action
+    }
+}
+
+impl Eq for UpdateUserChatAction {}
+
+impl std::hash::Hash for UpdateUserChatAction {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.chat_id, state);
+        std::hash::Hash::hash(&self.user_id, state);
+        std::hash::Hash::hash(&self.action, state);
+    }
+}
+
 impl TDUpdate for UpdateUserChatAction {}
 
 impl UpdateUserChatAction {
@@ -6857,47 +11349,96 @@ impl UpdateUserChatAction {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateUserChatActionBuilder {
-        let mut inner = UpdateUserChatAction::default();
-        inner.td_name = "updateUserChatAction".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateUserChatActionBuilder { inner }
+        RTDUpdateUserChatActionBuilder {
+            inner: UpdateUserChatAction::default(),
+            missing: vec!["chat_id", "user_id", "action"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateUserChatActionBuilder {
+        RTDUpdateUserChatActionBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateUserChatActionBuilder {
+        RTDUpdateUserChatActionBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn chat_id(&self) -> i64 {
+    pub fn chat_id(&self) -> ChatId {
         self.chat_id
     }
 
-    pub fn user_id(&self) -> i64 {
+    pub fn user_id(&self) -> UserId {
         self.user_id
     }
 
     pub fn action(&self) -> &ChatAction {
         &self.action
     }
+
+    pub fn into_action(self) -> ChatAction {
+        self.action
+    }
+
+    pub fn with_chat_id<T: Into<ChatId>>(mut self, chat_id: T) -> Self {
+        self.chat_id = chat_id.into();
+        self
+    }
+
+    pub fn with_user_id<T: Into<UserId>>(mut self, user_id: T) -> Self {
+        self.user_id = user_id.into();
+        self
+    }
+
+    pub fn with_action<T: AsRef<ChatAction>>(mut self, action: T) -> Self {
+        self.action = action.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateUserChatActionBuilder {
     inner: UpdateUserChatAction,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateUserChatActionBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateUserChatAction {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateUserChatAction> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateUserChatAction",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
-        self.inner.chat_id = chat_id;
+    pub fn chat_id<T: Into<ChatId>>(&mut self, chat_id: T) -> &mut Self {
+        self.inner.chat_id = chat_id.into();
+        self.missing.retain(|f| *f != "chat_id");
         self
     }
 
-    pub fn user_id(&mut self, user_id: i64) -> &mut Self {
-        self.inner.user_id = user_id;
+    pub fn user_id<T: Into<UserId>>(&mut self, user_id: T) -> &mut Self {
+        self.inner.user_id = user_id.into();
+        self.missing.retain(|f| *f != "user_id");
         self
     }
 
     pub fn action<T: AsRef<ChatAction>>(&mut self, action: T) -> &mut Self {
         self.inner.action = action.as_ref().clone();
+        self.missing.retain(|f| *f != "action");
         self
     }
 }
@@ -6916,15 +11457,16 @@ impl AsRef<UpdateUserChatAction> for RTDUpdateUserChatActionBuilder {
 
 /// Some data from userFullInfo has been changed
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateUserFullInfo")]
 pub struct UpdateUserFullInfo {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// User identifier
-    user_id: i64,
+    user_id: UserId,
     /// New full information about the user
     user_full_info: UserFullInfo,
 }
@@ -6943,6 +11485,21 @@ impl RObject for UpdateUserFullInfo {
     }
 }
 
+impl PartialEq for UpdateUserFullInfo {
+    fn eq(&self, other: &Self) -> bool {
+        self.user_id == other.user_id && self.user_full_info == other.user_full_info
+    }
+}
+
+impl Eq for UpdateUserFullInfo {}
+
+impl std::hash::Hash for UpdateUserFullInfo {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.user_id, state);
+        std::hash::Hash::hash(&self.user_full_info, state);
+    }
+}
+
 impl TDUpdate for UpdateUserFullInfo {}
 
 impl UpdateUserFullInfo {
@@ -6950,38 +11507,81 @@ impl UpdateUserFullInfo {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateUserFullInfoBuilder {
-        let mut inner = UpdateUserFullInfo::default();
-        inner.td_name = "updateUserFullInfo".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateUserFullInfoBuilder { inner }
+        RTDUpdateUserFullInfoBuilder {
+            inner: UpdateUserFullInfo::default(),
+            missing: vec!["user_id", "user_full_info"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateUserFullInfoBuilder {
+        RTDUpdateUserFullInfoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateUserFullInfoBuilder {
+        RTDUpdateUserFullInfoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn user_id(&self) -> i64 {
+    pub fn user_id(&self) -> UserId {
         self.user_id
     }
 
     pub fn user_full_info(&self) -> &UserFullInfo {
         &self.user_full_info
     }
+
+    pub fn into_user_full_info(self) -> UserFullInfo {
+        self.user_full_info
+    }
+
+    pub fn with_user_id<T: Into<UserId>>(mut self, user_id: T) -> Self {
+        self.user_id = user_id.into();
+        self
+    }
+
+    pub fn with_user_full_info<T: AsRef<UserFullInfo>>(mut self, user_full_info: T) -> Self {
+        self.user_full_info = user_full_info.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateUserFullInfoBuilder {
     inner: UpdateUserFullInfo,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateUserFullInfoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateUserFullInfo {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateUserFullInfo> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateUserFullInfo",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn user_id(&mut self, user_id: i64) -> &mut Self {
-        self.inner.user_id = user_id;
+    pub fn user_id<T: Into<UserId>>(&mut self, user_id: T) -> &mut Self {
+        self.inner.user_id = user_id.into();
+        self.missing.retain(|f| *f != "user_id");
         self
     }
 
     pub fn user_full_info<T: AsRef<UserFullInfo>>(&mut self, user_full_info: T) -> &mut Self {
         self.inner.user_full_info = user_full_info.as_ref().clone();
+        self.missing.retain(|f| *f != "user_full_info");
         self
     }
 }
@@ -7000,12 +11600,13 @@ impl AsRef<UpdateUserFullInfo> for RTDUpdateUserFullInfoBuilder {
 
 /// Some privacy setting rules have been changed
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateUserPrivacySettingRules")]
 pub struct UpdateUserPrivacySettingRules {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The privacy setting
     setting: UserPrivacySetting,
@@ -7027,6 +11628,21 @@ impl RObject for UpdateUserPrivacySettingRules {
     }
 }
 
+impl PartialEq for UpdateUserPrivacySettingRules {
+    fn eq(&self, other: &Self) -> bool {
+        self.setting == other.setting && self.rules == other.rules
+    }
+}
+
+impl Eq for UpdateUserPrivacySettingRules {}
+
+impl std::hash::Hash for UpdateUserPrivacySettingRules {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.setting, state);
+        std::hash::Hash::hash(&self.rules, state);
+    }
+}
+
 impl TDUpdate for UpdateUserPrivacySettingRules {}
 
 impl UpdateUserPrivacySettingRules {
@@ -7034,10 +11650,24 @@ impl UpdateUserPrivacySettingRules {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateUserPrivacySettingRulesBuilder {
-        let mut inner = UpdateUserPrivacySettingRules::default();
-        inner.td_name = "updateUserPrivacySettingRules".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateUserPrivacySettingRulesBuilder { inner }
+        RTDUpdateUserPrivacySettingRulesBuilder {
+            inner: UpdateUserPrivacySettingRules::default(),
+            missing: vec!["setting", "rules"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateUserPrivacySettingRulesBuilder {
+        RTDUpdateUserPrivacySettingRulesBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateUserPrivacySettingRulesBuilder {
+        RTDUpdateUserPrivacySettingRulesBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn setting(&self) -> &UserPrivacySetting {
@@ -7047,25 +11677,58 @@ impl UpdateUserPrivacySettingRules {
     pub fn rules(&self) -> &UserPrivacySettingRules {
         &self.rules
     }
+
+    pub fn into_setting(self) -> UserPrivacySetting {
+        self.setting
+    }
+
+    pub fn into_rules(self) -> UserPrivacySettingRules {
+        self.rules
+    }
+
+    pub fn with_setting<T: AsRef<UserPrivacySetting>>(mut self, setting: T) -> Self {
+        self.setting = setting.as_ref().clone();
+        self
+    }
+
+    pub fn with_rules<T: AsRef<UserPrivacySettingRules>>(mut self, rules: T) -> Self {
+        self.rules = rules.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateUserPrivacySettingRulesBuilder {
     inner: UpdateUserPrivacySettingRules,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateUserPrivacySettingRulesBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateUserPrivacySettingRules {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateUserPrivacySettingRules> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateUserPrivacySettingRules",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn setting<T: AsRef<UserPrivacySetting>>(&mut self, setting: T) -> &mut Self {
         self.inner.setting = setting.as_ref().clone();
+        self.missing.retain(|f| *f != "setting");
         self
     }
 
     pub fn rules<T: AsRef<UserPrivacySettingRules>>(&mut self, rules: T) -> &mut Self {
         self.inner.rules = rules.as_ref().clone();
+        self.missing.retain(|f| *f != "rules");
         self
     }
 }
@@ -7084,15 +11747,16 @@ impl AsRef<UpdateUserPrivacySettingRules> for RTDUpdateUserPrivacySettingRulesBu
 
 /// The user went online or offline
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateUserStatus")]
 pub struct UpdateUserStatus {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// User identifier
-    user_id: i64,
+    user_id: UserId,
     /// New status of the user
     status: UserStatus,
 }
@@ -7111,6 +11775,21 @@ impl RObject for UpdateUserStatus {
     }
 }
 
+impl PartialEq for UpdateUserStatus {
+    fn eq(&self, other: &Self) -> bool {
+        self.user_id == other.user_id && self.status == other.status
+    }
+}
+
+impl Eq for UpdateUserStatus {}
+
+impl std::hash::Hash for UpdateUserStatus {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.user_id, state);
+        std::hash::Hash::hash(&self.status, state);
+    }
+}
+
 impl TDUpdate for UpdateUserStatus {}
 
 impl UpdateUserStatus {
@@ -7118,38 +11797,81 @@ impl UpdateUserStatus {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateUserStatusBuilder {
-        let mut inner = UpdateUserStatus::default();
-        inner.td_name = "updateUserStatus".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateUserStatusBuilder { inner }
+        RTDUpdateUserStatusBuilder {
+            inner: UpdateUserStatus::default(),
+            missing: vec!["user_id", "status"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateUserStatusBuilder {
+        RTDUpdateUserStatusBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateUserStatusBuilder {
+        RTDUpdateUserStatusBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn user_id(&self) -> i64 {
+    pub fn user_id(&self) -> UserId {
         self.user_id
     }
 
     pub fn status(&self) -> &UserStatus {
         &self.status
     }
+
+    pub fn into_status(self) -> UserStatus {
+        self.status
+    }
+
+    pub fn with_user_id<T: Into<UserId>>(mut self, user_id: T) -> Self {
+        self.user_id = user_id.into();
+        self
+    }
+
+    pub fn with_status<T: AsRef<UserStatus>>(mut self, status: T) -> Self {
+        self.status = status.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateUserStatusBuilder {
     inner: UpdateUserStatus,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateUserStatusBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateUserStatus {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateUserStatus> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateUserStatus",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn user_id(&mut self, user_id: i64) -> &mut Self {
-        self.inner.user_id = user_id;
+    pub fn user_id<T: Into<UserId>>(&mut self, user_id: T) -> &mut Self {
+        self.inner.user_id = user_id.into();
+        self.missing.retain(|f| *f != "user_id");
         self
     }
 
     pub fn status<T: AsRef<UserStatus>>(&mut self, status: T) -> &mut Self {
         self.inner.status = status.as_ref().clone();
+        self.missing.retain(|f| *f != "status");
         self
     }
 }
@@ -7168,12 +11890,13 @@ impl AsRef<UpdateUserStatus> for RTDUpdateUserStatusBuilder {
 
 /// List of users nearby has changed. The update is sent only 60 seconds after a successful searchChatsNearby request
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updateUsersNearby")]
 pub struct UpdateUsersNearby {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The new list of users nearby
     users_nearby: Vec<ChatNearby>,
@@ -7193,6 +11916,20 @@ impl RObject for UpdateUsersNearby {
     }
 }
 
+impl PartialEq for UpdateUsersNearby {
+    fn eq(&self, other: &Self) -> bool {
+        self.users_nearby == other.users_nearby
+    }
+}
+
+impl Eq for UpdateUsersNearby {}
+
+impl std::hash::Hash for UpdateUsersNearby {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.users_nearby, state);
+    }
+}
+
 impl TDUpdate for UpdateUsersNearby {}
 
 impl UpdateUsersNearby {
@@ -7200,29 +11937,66 @@ impl UpdateUsersNearby {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdateUsersNearbyBuilder {
-        let mut inner = UpdateUsersNearby::default();
-        inner.td_name = "updateUsersNearby".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdateUsersNearbyBuilder { inner }
+        RTDUpdateUsersNearbyBuilder {
+            inner: UpdateUsersNearby::default(),
+            missing: vec!["users_nearby"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdateUsersNearbyBuilder {
+        RTDUpdateUsersNearbyBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdateUsersNearbyBuilder {
+        RTDUpdateUsersNearbyBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn users_nearby(&self) -> &Vec<ChatNearby> {
         &self.users_nearby
     }
+
+    pub fn into_users_nearby(self) -> Vec<ChatNearby> {
+        self.users_nearby
+    }
+
+    pub fn with_users_nearby(mut self, users_nearby: Vec<ChatNearby>) -> Self {
+        self.users_nearby = users_nearby;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateUsersNearbyBuilder {
     inner: UpdateUsersNearby,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdateUsersNearbyBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UpdateUsersNearby {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UpdateUsersNearby> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UpdateUsersNearby",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn users_nearby(&mut self, users_nearby: Vec<ChatNearby>) -> &mut Self {
         self.inner.users_nearby = users_nearby;
+        self.missing.retain(|f| *f != "users_nearby");
         self
     }
 }
@@ -7238,3 +12012,896 @@ impl AsRef<UpdateUsersNearby> for RTDUpdateUsersNearbyBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(Update::ActiveNotifications(
+            UpdateActiveNotifications::builder().groups(vec![]).build(),
+        ));
+        assert_round_trip(Update::AuthorizationState(
+            UpdateAuthorizationState::builder()
+                .authorization_state(AuthorizationState::Closed(
+                    AuthorizationStateClosed::builder().build(),
+                ))
+                .build(),
+        ));
+        assert_round_trip(Update::BasicGroup(
+            UpdateBasicGroup::builder()
+                .basic_group(
+                    BasicGroup::builder()
+                        .id(i32::MAX)
+                        .member_count(i32::MAX)
+                        .status(ChatMemberStatus::Administrator(
+                            ChatMemberStatusAdministrator::builder()
+                                .custom_title("text")
+                                .can_be_edited(true)
+                                .can_change_info(true)
+                                .can_post_messages(true)
+                                .can_edit_messages(true)
+                                .can_delete_messages(true)
+                                .can_invite_users(true)
+                                .can_restrict_members(true)
+                                .can_pin_messages(true)
+                                .can_promote_members(true)
+                                .build(),
+                        ))
+                        .is_active(true)
+                        .upgraded_to_supergroup_id(i32::MAX)
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(Update::BasicGroupFullInfo(
+            UpdateBasicGroupFullInfo::builder()
+                .basic_group_id(i32::MAX)
+                .basic_group_full_info(
+                    BasicGroupFullInfo::builder()
+                        .description("text")
+                        .creator_user_id(i32::MAX)
+                        .members(vec![])
+                        .invite_link("text")
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(Update::Call(
+            UpdateCall::builder()
+                .call(
+                    Call::builder()
+                        .id(i32::MAX)
+                        .user_id(i32::MAX)
+                        .is_outgoing(true)
+                        .state(CallState::ExchangingKeys(
+                            CallStateExchangingKeys::builder().build(),
+                        ))
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(Update::ChatActionBar(
+            UpdateChatActionBar::builder()
+                .chat_id(9007199254740991i64)
+                .build(),
+        ));
+        assert_round_trip(Update::ChatChatList(
+            UpdateChatChatList::builder()
+                .chat_id(9007199254740991i64)
+                .build(),
+        ));
+        assert_round_trip(Update::ChatDefaultDisableNotification(
+            UpdateChatDefaultDisableNotification::builder()
+                .chat_id(9007199254740991i64)
+                .default_disable_notification(true)
+                .build(),
+        ));
+        assert_round_trip(Update::ChatDraftMessage(
+            UpdateChatDraftMessage::builder()
+                .chat_id(9007199254740991i64)
+                .order(i64::MAX)
+                .build(),
+        ));
+        assert_round_trip(Update::ChatHasScheduledMessages(
+            UpdateChatHasScheduledMessages::builder()
+                .chat_id(9007199254740991i64)
+                .has_scheduled_messages(true)
+                .build(),
+        ));
+        assert_round_trip(Update::ChatIsMarkedAsUnread(
+            UpdateChatIsMarkedAsUnread::builder()
+                .chat_id(9007199254740991i64)
+                .is_marked_as_unread(true)
+                .build(),
+        ));
+        assert_round_trip(Update::ChatIsPinned(
+            UpdateChatIsPinned::builder()
+                .chat_id(9007199254740991i64)
+                .is_pinned(true)
+                .order(i64::MAX)
+                .build(),
+        ));
+        assert_round_trip(Update::ChatIsSponsored(
+            UpdateChatIsSponsored::builder()
+                .chat_id(9007199254740991i64)
+                .is_sponsored(true)
+                .order(i64::MAX)
+                .build(),
+        ));
+        assert_round_trip(Update::ChatLastMessage(
+            UpdateChatLastMessage::builder()
+                .chat_id(9007199254740991i64)
+                .order(i64::MAX)
+                .build(),
+        ));
+        assert_round_trip(Update::ChatNotificationSettings(
+            UpdateChatNotificationSettings::builder()
+                .chat_id(9007199254740991i64)
+                .notification_settings(
+                    ChatNotificationSettings::builder()
+                        .use_default_mute_for(true)
+                        .mute_for(i32::MAX)
+                        .use_default_sound(true)
+                        .sound("text")
+                        .use_default_show_preview(true)
+                        .show_preview(true)
+                        .use_default_disable_pinned_message_notifications(true)
+                        .disable_pinned_message_notifications(true)
+                        .use_default_disable_mention_notifications(true)
+                        .disable_mention_notifications(true)
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(Update::ChatOnlineMemberCount(
+            UpdateChatOnlineMemberCount::builder()
+                .chat_id(9007199254740991i64)
+                .online_member_count(i32::MAX)
+                .build(),
+        ));
+        assert_round_trip(Update::ChatOrder(
+            UpdateChatOrder::builder()
+                .chat_id(9007199254740991i64)
+                .order(i64::MAX)
+                .build(),
+        ));
+        assert_round_trip(Update::ChatPermissions(
+            UpdateChatPermissions::builder()
+                .chat_id(9007199254740991i64)
+                .permissions(
+                    ChatPermissions::builder()
+                        .can_send_messages(true)
+                        .can_send_media_messages(true)
+                        .can_send_polls(true)
+                        .can_send_other_messages(true)
+                        .can_add_web_page_previews(true)
+                        .can_change_info(true)
+                        .can_invite_users(true)
+                        .can_pin_messages(true)
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(Update::ChatPhoto(
+            UpdateChatPhoto::builder()
+                .chat_id(9007199254740991i64)
+                .build(),
+        ));
+        assert_round_trip(Update::ChatPinnedMessage(
+            UpdateChatPinnedMessage::builder()
+                .chat_id(9007199254740991i64)
+                .pinned_message_id(9007199254740991i64)
+                .build(),
+        ));
+        assert_round_trip(Update::ChatReadInbox(
+            UpdateChatReadInbox::builder()
+                .chat_id(9007199254740991i64)
+                .last_read_inbox_message_id(9007199254740991i64)
+                .unread_count(i32::MAX)
+                .build(),
+        ));
+        assert_round_trip(Update::ChatReadOutbox(
+            UpdateChatReadOutbox::builder()
+                .chat_id(9007199254740991i64)
+                .last_read_outbox_message_id(9007199254740991i64)
+                .build(),
+        ));
+        assert_round_trip(Update::ChatReplyMarkup(
+            UpdateChatReplyMarkup::builder()
+                .chat_id(9007199254740991i64)
+                .reply_markup_message_id(9007199254740991i64)
+                .build(),
+        ));
+        assert_round_trip(Update::ChatTitle(
+            UpdateChatTitle::builder()
+                .chat_id(9007199254740991i64)
+                .title("text")
+                .build(),
+        ));
+        assert_round_trip(Update::ChatUnreadMentionCount(
+            UpdateChatUnreadMentionCount::builder()
+                .chat_id(9007199254740991i64)
+                .unread_mention_count(i32::MAX)
+                .build(),
+        ));
+        assert_round_trip(Update::ConnectionState(
+            UpdateConnectionState::builder()
+                .state(ConnectionState::Connecting(
+                    ConnectionStateConnecting::builder().build(),
+                ))
+                .build(),
+        ));
+        assert_round_trip(Update::DeleteMessages(
+            UpdateDeleteMessages::builder()
+                .chat_id(9007199254740991i64)
+                .message_ids(vec![Default::default()])
+                .is_permanent(true)
+                .from_cache(true)
+                .build(),
+        ));
+        assert_round_trip(Update::FavoriteStickers(
+            UpdateFavoriteStickers::builder()
+                .sticker_ids(vec![i32::MAX])
+                .build(),
+        ));
+        assert_round_trip(Update::File(
+            UpdateFile::builder()
+                .file(
+                    File::builder()
+                        .id(i32::MAX)
+                        .size(i32::MAX)
+                        .expected_size(i32::MAX)
+                        .local(
+                            LocalFile::builder()
+                                .path("text")
+                                .can_be_downloaded(true)
+                                .can_be_deleted(true)
+                                .is_downloading_active(true)
+                                .is_downloading_completed(true)
+                                .download_offset(i32::MAX)
+                                .downloaded_prefix_size(i32::MAX)
+                                .downloaded_size(i32::MAX)
+                                .build(),
+                        )
+                        .remote(
+                            RemoteFile::builder()
+                                .id("text")
+                                .unique_id("text")
+                                .is_uploading_active(true)
+                                .is_uploading_completed(true)
+                                .uploaded_size(i32::MAX)
+                                .build(),
+                        )
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(Update::FileGenerationStart(
+            UpdateFileGenerationStart::builder()
+                .generation_id(i64::MAX)
+                .original_path("text")
+                .destination_path("text")
+                .conversion("text")
+                .build(),
+        ));
+        assert_round_trip(Update::FileGenerationStop(
+            UpdateFileGenerationStop::builder()
+                .generation_id(i64::MAX)
+                .build(),
+        ));
+        assert_round_trip(Update::HavePendingNotifications(
+            UpdateHavePendingNotifications::builder()
+                .have_delayed_notifications(true)
+                .have_unreceived_notifications(true)
+                .build(),
+        ));
+        assert_round_trip(Update::InstalledStickerSets(
+            UpdateInstalledStickerSets::builder()
+                .is_masks(true)
+                .sticker_set_ids(vec![i64::MAX])
+                .build(),
+        ));
+        assert_round_trip(Update::LanguagePackStrings(
+            UpdateLanguagePackStrings::builder()
+                .localization_target("text")
+                .language_pack_id("text")
+                .strings(vec![])
+                .build(),
+        ));
+        assert_round_trip(Update::MessageContent(
+            UpdateMessageContent::builder()
+                .chat_id(9007199254740991i64)
+                .message_id(9007199254740991i64)
+                .new_content(MessageContent::MessageBasicGroupChatCreate(
+                    MessageBasicGroupChatCreate::builder()
+                        .title("text")
+                        .member_user_ids(vec![Default::default()])
+                        .build(),
+                ))
+                .build(),
+        ));
+        assert_round_trip(Update::MessageContentOpened(
+            UpdateMessageContentOpened::builder()
+                .chat_id(9007199254740991i64)
+                .message_id(9007199254740991i64)
+                .build(),
+        ));
+        assert_round_trip(Update::MessageEdited(
+            UpdateMessageEdited::builder()
+                .chat_id(9007199254740991i64)
+                .message_id(9007199254740991i64)
+                .edit_date(i32::MAX)
+                .build(),
+        ));
+        assert_round_trip(Update::MessageLiveLocationViewed(
+            UpdateMessageLiveLocationViewed::builder()
+                .chat_id(9007199254740991i64)
+                .message_id(9007199254740991i64)
+                .build(),
+        ));
+        assert_round_trip(Update::MessageMentionRead(
+            UpdateMessageMentionRead::builder()
+                .chat_id(9007199254740991i64)
+                .message_id(9007199254740991i64)
+                .unread_mention_count(i32::MAX)
+                .build(),
+        ));
+        assert_round_trip(Update::MessageSendAcknowledged(
+            UpdateMessageSendAcknowledged::builder()
+                .chat_id(9007199254740991i64)
+                .message_id(9007199254740991i64)
+                .build(),
+        ));
+        assert_round_trip(Update::MessageSendFailed(
+            UpdateMessageSendFailed::builder()
+                .message(
+                    Message::builder()
+                        .id(9007199254740991i64)
+                        .sender_user_id(i32::MAX)
+                        .chat_id(9007199254740991i64)
+                        .is_outgoing(true)
+                        .can_be_edited(true)
+                        .can_be_forwarded(true)
+                        .can_be_deleted_only_for_self(true)
+                        .can_be_deleted_for_all_users(true)
+                        .is_channel_post(true)
+                        .contains_unread_mention(true)
+                        .date(i32::MAX)
+                        .edit_date(i32::MAX)
+                        .reply_to_message_id(9007199254740991i64)
+                        .ttl(i32::MAX)
+                        .ttl_expires_in(1.5)
+                        .via_bot_user_id(i32::MAX)
+                        .author_signature("text")
+                        .views(i32::MAX)
+                        .media_album_id(i64::MAX)
+                        .restriction_reason("text")
+                        .content(MessageContent::MessageBasicGroupChatCreate(
+                            MessageBasicGroupChatCreate::builder()
+                                .title("text")
+                                .member_user_ids(vec![Default::default()])
+                                .build(),
+                        ))
+                        .build(),
+                )
+                .old_message_id(9007199254740991i64)
+                .error_code(i32::MAX)
+                .error_message("text")
+                .build(),
+        ));
+        assert_round_trip(Update::MessageSendSucceeded(
+            UpdateMessageSendSucceeded::builder()
+                .message(
+                    Message::builder()
+                        .id(9007199254740991i64)
+                        .sender_user_id(i32::MAX)
+                        .chat_id(9007199254740991i64)
+                        .is_outgoing(true)
+                        .can_be_edited(true)
+                        .can_be_forwarded(true)
+                        .can_be_deleted_only_for_self(true)
+                        .can_be_deleted_for_all_users(true)
+                        .is_channel_post(true)
+                        .contains_unread_mention(true)
+                        .date(i32::MAX)
+                        .edit_date(i32::MAX)
+                        .reply_to_message_id(9007199254740991i64)
+                        .ttl(i32::MAX)
+                        .ttl_expires_in(1.5)
+                        .via_bot_user_id(i32::MAX)
+                        .author_signature("text")
+                        .views(i32::MAX)
+                        .media_album_id(i64::MAX)
+                        .restriction_reason("text")
+                        .content(MessageContent::MessageBasicGroupChatCreate(
+                            MessageBasicGroupChatCreate::builder()
+                                .title("text")
+                                .member_user_ids(vec![Default::default()])
+                                .build(),
+                        ))
+                        .build(),
+                )
+                .old_message_id(9007199254740991i64)
+                .build(),
+        ));
+        assert_round_trip(Update::MessageViews(
+            UpdateMessageViews::builder()
+                .chat_id(9007199254740991i64)
+                .message_id(9007199254740991i64)
+                .views(i32::MAX)
+                .build(),
+        ));
+        assert_round_trip(Update::NewCallbackQuery(
+            UpdateNewCallbackQuery::builder()
+                .id(i64::MAX)
+                .sender_user_id(i32::MAX)
+                .chat_id(9007199254740991i64)
+                .message_id(9007199254740991i64)
+                .chat_instance(i64::MAX)
+                .payload(CallbackQueryPayload::Data(
+                    CallbackQueryPayloadData::builder()
+                        .data(b"\x00\x01\xfe\xff")
+                        .build(),
+                ))
+                .build(),
+        ));
+        assert_round_trip(Update::NewChat(
+            UpdateNewChat::builder()
+                .chat(
+                    Chat::builder()
+                        .id(9007199254740991i64)
+                        .type_(ChatType::BasicGroup(
+                            ChatTypeBasicGroup::builder()
+                                .basic_group_id(i32::MAX)
+                                .build(),
+                        ))
+                        .title("text")
+                        .permissions(
+                            ChatPermissions::builder()
+                                .can_send_messages(true)
+                                .can_send_media_messages(true)
+                                .can_send_polls(true)
+                                .can_send_other_messages(true)
+                                .can_add_web_page_previews(true)
+                                .can_change_info(true)
+                                .can_invite_users(true)
+                                .can_pin_messages(true)
+                                .build(),
+                        )
+                        .order(i64::MAX)
+                        .is_pinned(true)
+                        .is_marked_as_unread(true)
+                        .is_sponsored(true)
+                        .has_scheduled_messages(true)
+                        .can_be_deleted_only_for_self(true)
+                        .can_be_deleted_for_all_users(true)
+                        .can_be_reported(true)
+                        .default_disable_notification(true)
+                        .unread_count(i32::MAX)
+                        .last_read_inbox_message_id(9007199254740991i64)
+                        .last_read_outbox_message_id(9007199254740991i64)
+                        .unread_mention_count(i32::MAX)
+                        .notification_settings(
+                            ChatNotificationSettings::builder()
+                                .use_default_mute_for(true)
+                                .mute_for(i32::MAX)
+                                .use_default_sound(true)
+                                .sound("text")
+                                .use_default_show_preview(true)
+                                .show_preview(true)
+                                .use_default_disable_pinned_message_notifications(true)
+                                .disable_pinned_message_notifications(true)
+                                .use_default_disable_mention_notifications(true)
+                                .disable_mention_notifications(true)
+                                .build(),
+                        )
+                        .pinned_message_id(9007199254740991i64)
+                        .reply_markup_message_id(9007199254740991i64)
+                        .client_data("text")
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(Update::NewChosenInlineResult(
+            UpdateNewChosenInlineResult::builder()
+                .sender_user_id(i32::MAX)
+                .query("text")
+                .result_id("text")
+                .inline_message_id("text")
+                .build(),
+        ));
+        assert_round_trip(Update::NewCustomEvent(
+            UpdateNewCustomEvent::builder().event("text").build(),
+        ));
+        assert_round_trip(Update::NewCustomQuery(
+            UpdateNewCustomQuery::builder()
+                .id(i64::MAX)
+                .data("text")
+                .timeout(i32::MAX)
+                .build(),
+        ));
+        assert_round_trip(Update::NewInlineCallbackQuery(
+            UpdateNewInlineCallbackQuery::builder()
+                .id(i64::MAX)
+                .sender_user_id(i32::MAX)
+                .inline_message_id("text")
+                .chat_instance(i64::MAX)
+                .payload(CallbackQueryPayload::Data(
+                    CallbackQueryPayloadData::builder()
+                        .data(b"\x00\x01\xfe\xff")
+                        .build(),
+                ))
+                .build(),
+        ));
+        assert_round_trip(Update::NewInlineQuery(
+            UpdateNewInlineQuery::builder()
+                .id(i64::MAX)
+                .sender_user_id(i32::MAX)
+                .query("text")
+                .offset("text")
+                .build(),
+        ));
+        assert_round_trip(Update::NewMessage(
+            UpdateNewMessage::builder()
+                .message(
+                    Message::builder()
+                        .id(9007199254740991i64)
+                        .sender_user_id(i32::MAX)
+                        .chat_id(9007199254740991i64)
+                        .is_outgoing(true)
+                        .can_be_edited(true)
+                        .can_be_forwarded(true)
+                        .can_be_deleted_only_for_self(true)
+                        .can_be_deleted_for_all_users(true)
+                        .is_channel_post(true)
+                        .contains_unread_mention(true)
+                        .date(i32::MAX)
+                        .edit_date(i32::MAX)
+                        .reply_to_message_id(9007199254740991i64)
+                        .ttl(i32::MAX)
+                        .ttl_expires_in(1.5)
+                        .via_bot_user_id(i32::MAX)
+                        .author_signature("text")
+                        .views(i32::MAX)
+                        .media_album_id(i64::MAX)
+                        .restriction_reason("text")
+                        .content(MessageContent::MessageBasicGroupChatCreate(
+                            MessageBasicGroupChatCreate::builder()
+                                .title("text")
+                                .member_user_ids(vec![Default::default()])
+                                .build(),
+                        ))
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(Update::NewPreCheckoutQuery(
+            UpdateNewPreCheckoutQuery::builder()
+                .id(i64::MAX)
+                .sender_user_id(i32::MAX)
+                .currency("text")
+                .total_amount(9007199254740991i64)
+                .invoice_payload(b"\x00\x01\xfe\xff")
+                .shipping_option_id("text")
+                .build(),
+        ));
+        assert_round_trip(Update::NewShippingQuery(
+            UpdateNewShippingQuery::builder()
+                .id(i64::MAX)
+                .sender_user_id(i32::MAX)
+                .invoice_payload(b"\x00\x01\xfe\xff")
+                .shipping_address(
+                    Address::builder()
+                        .country_code("text")
+                        .state("text")
+                        .city("text")
+                        .street_line1("text")
+                        .street_line2("text")
+                        .postal_code("text")
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(Update::Notification(
+            UpdateNotification::builder()
+                .notification_group_id(i32::MAX)
+                .notification(
+                    Notification::builder()
+                        .id(i32::MAX)
+                        .date(i32::MAX)
+                        .is_silent(true)
+                        .type_(NotificationType::NewCall(
+                            NotificationTypeNewCall::builder().call_id(i32::MAX).build(),
+                        ))
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(Update::NotificationGroup(
+            UpdateNotificationGroup::builder()
+                .notification_group_id(i32::MAX)
+                .type_(NotificationGroupType::Calls(
+                    NotificationGroupTypeCalls::builder().build(),
+                ))
+                .chat_id(9007199254740991i64)
+                .notification_settings_chat_id(9007199254740991i64)
+                .is_silent(true)
+                .total_count(i32::MAX)
+                .added_notifications(vec![])
+                .removed_notification_ids(vec![i32::MAX])
+                .build(),
+        ));
+        assert_round_trip(Update::Option(
+            UpdateOption::builder()
+                .name("text")
+                .value(OptionValue::Boolean(
+                    OptionValueBoolean::builder().value(true).build(),
+                ))
+                .build(),
+        ));
+        assert_round_trip(Update::Poll(
+            UpdatePoll::builder()
+                .poll(
+                    Poll::builder()
+                        .id(i64::MAX)
+                        .question("text")
+                        .options(vec![])
+                        .total_voter_count(i32::MAX)
+                        .recent_voter_user_ids(vec![Default::default()])
+                        .is_anonymous(true)
+                        .type_(PollType::Quiz(
+                            PollTypeQuiz::builder().correct_option_id(i32::MAX).build(),
+                        ))
+                        .is_closed(true)
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(Update::PollAnswer(
+            UpdatePollAnswer::builder()
+                .poll_id(i64::MAX)
+                .user_id(i32::MAX)
+                .option_ids(vec![i32::MAX])
+                .build(),
+        ));
+        assert_round_trip(Update::RecentStickers(
+            UpdateRecentStickers::builder()
+                .is_attached(true)
+                .sticker_ids(vec![i32::MAX])
+                .build(),
+        ));
+        assert_round_trip(Update::SavedAnimations(
+            UpdateSavedAnimations::builder()
+                .animation_ids(vec![i32::MAX])
+                .build(),
+        ));
+        assert_round_trip(Update::ScopeNotificationSettings(
+            UpdateScopeNotificationSettings::builder()
+                .scope(NotificationSettingsScope::ChannelChats(
+                    NotificationSettingsScopeChannelChats::builder().build(),
+                ))
+                .notification_settings(
+                    ScopeNotificationSettings::builder()
+                        .mute_for(i32::MAX)
+                        .sound("text")
+                        .show_preview(true)
+                        .disable_pinned_message_notifications(true)
+                        .disable_mention_notifications(true)
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(Update::SecretChat(
+            UpdateSecretChat::builder()
+                .secret_chat(
+                    SecretChat::builder()
+                        .id(i32::MAX)
+                        .user_id(i32::MAX)
+                        .state(SecretChatState::Closed(
+                            SecretChatStateClosed::builder().build(),
+                        ))
+                        .is_outbound(true)
+                        .ttl(i32::MAX)
+                        .key_hash(b"\x00\x01\xfe\xff")
+                        .layer(i32::MAX)
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(Update::SelectedBackground(
+            UpdateSelectedBackground::builder()
+                .for_dark_theme(true)
+                .build(),
+        ));
+        assert_round_trip(Update::ServiceNotification(
+            UpdateServiceNotification::builder()
+                .type_("text")
+                .content(MessageContent::MessageBasicGroupChatCreate(
+                    MessageBasicGroupChatCreate::builder()
+                        .title("text")
+                        .member_user_ids(vec![Default::default()])
+                        .build(),
+                ))
+                .build(),
+        ));
+        assert_round_trip(Update::Supergroup(
+            UpdateSupergroup::builder()
+                .supergroup(
+                    Supergroup::builder()
+                        .id(i32::MAX)
+                        .username("text")
+                        .date(i32::MAX)
+                        .status(ChatMemberStatus::Administrator(
+                            ChatMemberStatusAdministrator::builder()
+                                .custom_title("text")
+                                .can_be_edited(true)
+                                .can_change_info(true)
+                                .can_post_messages(true)
+                                .can_edit_messages(true)
+                                .can_delete_messages(true)
+                                .can_invite_users(true)
+                                .can_restrict_members(true)
+                                .can_pin_messages(true)
+                                .can_promote_members(true)
+                                .build(),
+                        ))
+                        .member_count(i32::MAX)
+                        .has_linked_chat(true)
+                        .has_location(true)
+                        .sign_messages(true)
+                        .is_slow_mode_enabled(true)
+                        .is_channel(true)
+                        .is_verified(true)
+                        .restriction_reason("text")
+                        .is_scam(true)
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(Update::SupergroupFullInfo(
+            UpdateSupergroupFullInfo::builder()
+                .supergroup_id(i32::MAX)
+                .supergroup_full_info(
+                    SupergroupFullInfo::builder()
+                        .description("text")
+                        .member_count(i32::MAX)
+                        .administrator_count(i32::MAX)
+                        .restricted_count(i32::MAX)
+                        .banned_count(i32::MAX)
+                        .linked_chat_id(9007199254740991i64)
+                        .slow_mode_delay(i32::MAX)
+                        .slow_mode_delay_expires_in(1.5)
+                        .can_get_members(true)
+                        .can_set_username(true)
+                        .can_set_sticker_set(true)
+                        .can_set_location(true)
+                        .can_view_statistics(true)
+                        .is_all_history_available(true)
+                        .sticker_set_id(i64::MAX)
+                        .invite_link("text")
+                        .upgraded_from_basic_group_id(i32::MAX)
+                        .upgraded_from_max_message_id(9007199254740991i64)
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(Update::TermsOfService(
+            UpdateTermsOfService::builder()
+                .terms_of_service_id("text")
+                .terms_of_service(
+                    TermsOfService::builder()
+                        .text(
+                            FormattedText::builder()
+                                .text("text")
+                                .entities(vec![])
+                                .build(),
+                        )
+                        .min_user_age(i32::MAX)
+                        .show_popup(true)
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(Update::TrendingStickerSets(
+            UpdateTrendingStickerSets::builder()
+                .sticker_sets(
+                    StickerSets::builder()
+                        .total_count(i32::MAX)
+                        .sets(vec![])
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(Update::UnreadChatCount(
+            UpdateUnreadChatCount::builder()
+                .chat_list(ChatList::Archive(ChatListArchive::builder().build()))
+                .total_count(i32::MAX)
+                .unread_count(i32::MAX)
+                .unread_unmuted_count(i32::MAX)
+                .marked_as_unread_count(i32::MAX)
+                .marked_as_unread_unmuted_count(i32::MAX)
+                .build(),
+        ));
+        assert_round_trip(Update::UnreadMessageCount(
+            UpdateUnreadMessageCount::builder()
+                .chat_list(ChatList::Archive(ChatListArchive::builder().build()))
+                .unread_count(i32::MAX)
+                .unread_unmuted_count(i32::MAX)
+                .build(),
+        ));
+        assert_round_trip(Update::User(
+            UpdateUser::builder()
+                .user(
+                    User::builder()
+                        .id(i32::MAX)
+                        .first_name("text")
+                        .last_name("text")
+                        .username("text")
+                        .phone_number("text")
+                        .status(UserStatus::Empty(UserStatusEmpty::builder().build()))
+                        .is_contact(true)
+                        .is_mutual_contact(true)
+                        .is_verified(true)
+                        .is_support(true)
+                        .restriction_reason("text")
+                        .is_scam(true)
+                        .have_access(true)
+                        .type_(UserType::Bot(
+                            UserTypeBot::builder()
+                                .can_join_groups(true)
+                                .can_read_all_group_messages(true)
+                                .is_inline(true)
+                                .inline_query_placeholder("text")
+                                .need_location(true)
+                                .build(),
+                        ))
+                        .language_code("text")
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(Update::UserChatAction(
+            UpdateUserChatAction::builder()
+                .chat_id(9007199254740991i64)
+                .user_id(i32::MAX)
+                .action(ChatAction::Cancel(ChatActionCancel::builder().build()))
+                .build(),
+        ));
+        assert_round_trip(Update::UserFullInfo(
+            UpdateUserFullInfo::builder()
+                .user_id(i32::MAX)
+                .user_full_info(
+                    UserFullInfo::builder()
+                        .is_blocked(true)
+                        .can_be_called(true)
+                        .has_private_calls(true)
+                        .need_phone_number_privacy_exception(true)
+                        .bio("text")
+                        .share_text("text")
+                        .group_in_common_count(i32::MAX)
+                        .build(),
+                )
+                .build(),
+        ));
+        assert_round_trip(Update::UserPrivacySettingRules(
+            UpdateUserPrivacySettingRules::builder()
+                .setting(UserPrivacySetting::AllowCalls(
+                    UserPrivacySettingAllowCalls::builder().build(),
+                ))
+                .rules(UserPrivacySettingRules::builder().rules(vec![]).build())
+                .build(),
+        ));
+        assert_round_trip(Update::UserStatus(
+            UpdateUserStatus::builder()
+                .user_id(i32::MAX)
+                .status(UserStatus::Empty(UserStatusEmpty::builder().build()))
+                .build(),
+        ));
+        assert_round_trip(Update::UsersNearby(
+            UpdateUsersNearby::builder().users_nearby(vec![]).build(),
+        ));
+    }
+}
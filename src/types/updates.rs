@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains a list of updates
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "updates")]
 pub struct Updates {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// List of updates
     updates: Vec<Update>,
@@ -29,34 +29,74 @@ impl RObject for Updates {
     }
 }
 
+impl PartialEq for Updates {
+    fn eq(&self, other: &Self) -> bool {
+        self.updates == other.updates
+    }
+}
+
 impl Updates {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUpdatesBuilder {
-        let mut inner = Updates::default();
-        inner.td_name = "updates".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUpdatesBuilder { inner }
+        RTDUpdatesBuilder {
+            inner: Updates::default(),
+            missing: vec!["updates"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUpdatesBuilder {
+        RTDUpdatesBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUpdatesBuilder {
+        RTDUpdatesBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn updates(&self) -> &Vec<Update> {
         &self.updates
     }
+
+    pub fn into_updates(self) -> Vec<Update> {
+        self.updates
+    }
+
+    pub fn with_updates(mut self, updates: Vec<Update>) -> Self {
+        self.updates = updates;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUpdatesBuilder {
     inner: Updates,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUpdatesBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> Updates {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<Updates> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("Updates", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn updates(&mut self, updates: Vec<Update>) -> &mut Self {
         self.inner.updates = updates;
+        self.missing.retain(|f| *f != "updates");
         self
     }
 }
@@ -72,3 +112,14 @@ impl AsRef<Updates> for RTDUpdatesBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(Updates::builder().updates(vec![]).build());
+    }
+}
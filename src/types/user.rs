@@ -1,18 +1,18 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Represents a user
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "user")]
 pub struct User {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// User identifier
-    id: i64,
+    id: UserId,
     /// First name of the user
     first_name: String,
     /// Last name of the user
@@ -60,18 +60,85 @@ impl RObject for User {
     }
 }
 
+impl PartialEq for User {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.first_name == other.first_name
+            && self.last_name == other.last_name
+            && self.username == other.username
+            && self.phone_number == other.phone_number
+            && self.status == other.status
+            && self.profile_photo == other.profile_photo
+            && self.is_contact == other.is_contact
+            && self.is_mutual_contact == other.is_mutual_contact
+            && self.is_verified == other.is_verified
+            && self.is_support == other.is_support
+            && self.restriction_reason == other.restriction_reason
+            && self.is_scam == other.is_scam
+            && self.have_access == other.have_access
+            && self.type_ == other.type_
+            && self.language_code == other.language_code
+    }
+}
+
+impl Eq for User {}
+
+impl std::hash::Hash for User {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.id, state);
+        std::hash::Hash::hash(&self.first_name, state);
+        std::hash::Hash::hash(&self.last_name, state);
+        std::hash::Hash::hash(&self.username, state);
+        std::hash::Hash::hash(&self.phone_number, state);
+        std::hash::Hash::hash(&self.status, state);
+        std::hash::Hash::hash(&self.profile_photo, state);
+        std::hash::Hash::hash(&self.is_contact, state);
+        std::hash::Hash::hash(&self.is_mutual_contact, state);
+        std::hash::Hash::hash(&self.is_verified, state);
+        std::hash::Hash::hash(&self.is_support, state);
+        std::hash::Hash::hash(&self.restriction_reason, state);
+        std::hash::Hash::hash(&self.is_scam, state);
+        std::hash::Hash::hash(&self.have_access, state);
+        std::hash::Hash::hash(&self.type_, state);
+        std::hash::Hash::hash(&self.language_code, state);
+    }
+}
+
 impl User {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUserBuilder {
-        let mut inner = User::default();
-        inner.td_name = "user".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUserBuilder { inner }
-    }
-
-    pub fn id(&self) -> i64 {
+        RTDUserBuilder {
+            inner: User::default(),
+            missing: vec![
+                "id",
+                "first_name",
+                "last_name",
+                "username",
+                "phone_number",
+                "status",
+                "type_",
+                "language_code",
+            ],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUserBuilder {
+        RTDUserBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUserBuilder {
+        RTDUserBuilder {
+            inner: self,
+            missing: vec![],
+        }
+    }
+
+    pub fn id(&self) -> UserId {
         self.id
     }
 
@@ -134,45 +201,177 @@ impl User {
     pub fn language_code(&self) -> &String {
         &self.language_code
     }
+
+    pub fn into_first_name(self) -> String {
+        self.first_name
+    }
+
+    pub fn into_last_name(self) -> String {
+        self.last_name
+    }
+
+    pub fn into_username(self) -> String {
+        self.username
+    }
+
+    pub fn into_phone_number(self) -> String {
+        self.phone_number
+    }
+
+    pub fn into_status(self) -> UserStatus {
+        self.status
+    }
+
+    pub fn into_profile_photo(self) -> Option<ProfilePhoto> {
+        self.profile_photo
+    }
+
+    pub fn into_restriction_reason(self) -> String {
+        self.restriction_reason
+    }
+
+    pub fn into_type(self) -> UserType {
+        self.type_
+    }
+
+    pub fn into_language_code(self) -> String {
+        self.language_code
+    }
+
+    pub fn with_id<T: Into<UserId>>(mut self, id: T) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    pub fn with_first_name<T: AsRef<str>>(mut self, first_name: T) -> Self {
+        self.first_name = first_name.as_ref().to_string();
+        self
+    }
+
+    pub fn with_last_name<T: AsRef<str>>(mut self, last_name: T) -> Self {
+        self.last_name = last_name.as_ref().to_string();
+        self
+    }
+
+    pub fn with_username<T: AsRef<str>>(mut self, username: T) -> Self {
+        self.username = username.as_ref().to_string();
+        self
+    }
+
+    pub fn with_phone_number<T: AsRef<str>>(mut self, phone_number: T) -> Self {
+        self.phone_number = phone_number.as_ref().to_string();
+        self
+    }
+
+    pub fn with_status<T: AsRef<UserStatus>>(mut self, status: T) -> Self {
+        self.status = status.as_ref().clone();
+        self
+    }
+
+    pub fn with_profile_photo<T: AsRef<ProfilePhoto>>(mut self, profile_photo: T) -> Self {
+        self.profile_photo = Some(profile_photo.as_ref().clone());
+        self
+    }
+
+    pub fn with_is_contact(mut self, is_contact: bool) -> Self {
+        self.is_contact = is_contact;
+        self
+    }
+
+    pub fn with_is_mutual_contact(mut self, is_mutual_contact: bool) -> Self {
+        self.is_mutual_contact = is_mutual_contact;
+        self
+    }
+
+    pub fn with_is_verified(mut self, is_verified: bool) -> Self {
+        self.is_verified = is_verified;
+        self
+    }
+
+    pub fn with_is_support(mut self, is_support: bool) -> Self {
+        self.is_support = is_support;
+        self
+    }
+
+    pub fn with_restriction_reason<T: AsRef<str>>(mut self, restriction_reason: T) -> Self {
+        self.restriction_reason = restriction_reason.as_ref().to_string();
+        self
+    }
+
+    pub fn with_is_scam(mut self, is_scam: bool) -> Self {
+        self.is_scam = is_scam;
+        self
+    }
+
+    pub fn with_have_access(mut self, have_access: bool) -> Self {
+        self.have_access = have_access;
+        self
+    }
+
+    pub fn with_type<T: AsRef<UserType>>(mut self, type_: T) -> Self {
+        self.type_ = type_.as_ref().clone();
+        self
+    }
+
+    pub fn with_language_code<T: AsRef<str>>(mut self, language_code: T) -> Self {
+        self.language_code = language_code.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUserBuilder {
     inner: User,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUserBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> User {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<User> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("User", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn id(&mut self, id: i64) -> &mut Self {
-        self.inner.id = id;
+    pub fn id<T: Into<UserId>>(&mut self, id: T) -> &mut Self {
+        self.inner.id = id.into();
+        self.missing.retain(|f| *f != "id");
         self
     }
 
     pub fn first_name<T: AsRef<str>>(&mut self, first_name: T) -> &mut Self {
         self.inner.first_name = first_name.as_ref().to_string();
+        self.missing.retain(|f| *f != "first_name");
         self
     }
 
     pub fn last_name<T: AsRef<str>>(&mut self, last_name: T) -> &mut Self {
         self.inner.last_name = last_name.as_ref().to_string();
+        self.missing.retain(|f| *f != "last_name");
         self
     }
 
     pub fn username<T: AsRef<str>>(&mut self, username: T) -> &mut Self {
         self.inner.username = username.as_ref().to_string();
+        self.missing.retain(|f| *f != "username");
         self
     }
 
     pub fn phone_number<T: AsRef<str>>(&mut self, phone_number: T) -> &mut Self {
         self.inner.phone_number = phone_number.as_ref().to_string();
+        self.missing.retain(|f| *f != "phone_number");
         self
     }
 
     pub fn status<T: AsRef<UserStatus>>(&mut self, status: T) -> &mut Self {
         self.inner.status = status.as_ref().clone();
+        self.missing.retain(|f| *f != "status");
         self
     }
 
@@ -218,11 +417,13 @@ impl RTDUserBuilder {
 
     pub fn type_<T: AsRef<UserType>>(&mut self, type_: T) -> &mut Self {
         self.inner.type_ = type_.as_ref().clone();
+        self.missing.retain(|f| *f != "type_");
         self
     }
 
     pub fn language_code<T: AsRef<str>>(&mut self, language_code: T) -> &mut Self {
         self.inner.language_code = language_code.as_ref().to_string();
+        self.missing.retain(|f| *f != "language_code");
         self
     }
 }
@@ -238,3 +439,40 @@ impl AsRef<User> for RTDUserBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            User::builder()
+                .id(i32::MAX)
+                .first_name("text")
+                .last_name("text")
+                .username("text")
+                .phone_number("text")
+                .status(UserStatus::Empty(UserStatusEmpty::builder().build()))
+                .is_contact(true)
+                .is_mutual_contact(true)
+                .is_verified(true)
+                .is_support(true)
+                .restriction_reason("text")
+                .is_scam(true)
+                .have_access(true)
+                .type_(UserType::Bot(
+                    UserTypeBot::builder()
+                        .can_join_groups(true)
+                        .can_read_all_group_messages(true)
+                        .is_inline(true)
+                        .inline_query_placeholder("text")
+                        .need_location(true)
+                        .build(),
+                ))
+                .language_code("text")
+                .build(),
+        );
+    }
+}
@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains full information about a user (except the full list of profile photos)
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "userFullInfo")]
 pub struct UserFullInfo {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// True, if the user is blacklisted by the current user
     is_blocked: bool,
@@ -24,7 +24,7 @@ pub struct UserFullInfo {
     /// For bots, the text that is included with the link when users share the bot
     share_text: String,
     /// Number of group chats where both the other user and the current user are a member; 0 for the current user
-    group_in_common_count: i64,
+    group_in_common_count: i32,
     /// If the user is a bot, information about the bot; may be null
     bot_info: Option<BotInfo>,
 }
@@ -43,15 +43,57 @@ impl RObject for UserFullInfo {
     }
 }
 
+impl PartialEq for UserFullInfo {
+    fn eq(&self, other: &Self) -> bool {
+        self.is_blocked == other.is_blocked
+            && self.can_be_called == other.can_be_called
+            && self.has_private_calls == other.has_private_calls
+            && self.need_phone_number_privacy_exception == other.need_phone_number_privacy_exception
+            && self.bio == other.bio
+            && self.share_text == other.share_text
+            && self.group_in_common_count == other.group_in_common_count
+            && self.bot_info == other.bot_info
+    }
+}
+
+impl Eq for UserFullInfo {}
+
+impl std::hash::Hash for UserFullInfo {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.is_blocked, state);
+        std::hash::Hash::hash(&self.can_be_called, state);
+        std::hash::Hash::hash(&self.has_private_calls, state);
+        std::hash::Hash::hash(&self.need_phone_number_privacy_exception, state);
+        std::hash::Hash::hash(&self.bio, state);
+        std::hash::Hash::hash(&self.share_text, state);
+        std::hash::Hash::hash(&self.group_in_common_count, state);
+        std::hash::Hash::hash(&self.bot_info, state);
+    }
+}
+
 impl UserFullInfo {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUserFullInfoBuilder {
-        let mut inner = UserFullInfo::default();
-        inner.td_name = "userFullInfo".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUserFullInfoBuilder { inner }
+        RTDUserFullInfoBuilder {
+            inner: UserFullInfo::default(),
+            missing: vec!["bio", "share_text"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUserFullInfoBuilder {
+        RTDUserFullInfoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUserFullInfoBuilder {
+        RTDUserFullInfoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn is_blocked(&self) -> bool {
@@ -78,24 +120,92 @@ impl UserFullInfo {
         &self.share_text
     }
 
-    pub fn group_in_common_count(&self) -> i64 {
+    pub fn group_in_common_count(&self) -> i32 {
         self.group_in_common_count
     }
 
     pub fn bot_info(&self) -> &Option<BotInfo> {
         &self.bot_info
     }
+
+    pub fn into_bio(self) -> String {
+        self.bio
+    }
+
+    pub fn into_share_text(self) -> String {
+        self.share_text
+    }
+
+    pub fn into_bot_info(self) -> Option<BotInfo> {
+        self.bot_info
+    }
+
+    pub fn with_is_blocked(mut self, is_blocked: bool) -> Self {
+        self.is_blocked = is_blocked;
+        self
+    }
+
+    pub fn with_can_be_called(mut self, can_be_called: bool) -> Self {
+        self.can_be_called = can_be_called;
+        self
+    }
+
+    pub fn with_has_private_calls(mut self, has_private_calls: bool) -> Self {
+        self.has_private_calls = has_private_calls;
+        self
+    }
+
+    pub fn with_need_phone_number_privacy_exception(
+        mut self,
+        need_phone_number_privacy_exception: bool,
+    ) -> Self {
+        self.need_phone_number_privacy_exception = need_phone_number_privacy_exception;
+        self
+    }
+
+    pub fn with_bio<T: AsRef<str>>(mut self, bio: T) -> Self {
+        self.bio = bio.as_ref().to_string();
+        self
+    }
+
+    pub fn with_share_text<T: AsRef<str>>(mut self, share_text: T) -> Self {
+        self.share_text = share_text.as_ref().to_string();
+        self
+    }
+
+    pub fn with_group_in_common_count(mut self, group_in_common_count: i32) -> Self {
+        self.group_in_common_count = group_in_common_count;
+        self
+    }
+
+    pub fn with_bot_info<T: AsRef<BotInfo>>(mut self, bot_info: T) -> Self {
+        self.bot_info = Some(bot_info.as_ref().clone());
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUserFullInfoBuilder {
     inner: UserFullInfo,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUserFullInfoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UserFullInfo {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UserFullInfo> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UserFullInfo",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn is_blocked(&mut self, is_blocked: bool) -> &mut Self {
         self.inner.is_blocked = is_blocked;
@@ -122,15 +232,17 @@ impl RTDUserFullInfoBuilder {
 
     pub fn bio<T: AsRef<str>>(&mut self, bio: T) -> &mut Self {
         self.inner.bio = bio.as_ref().to_string();
+        self.missing.retain(|f| *f != "bio");
         self
     }
 
     pub fn share_text<T: AsRef<str>>(&mut self, share_text: T) -> &mut Self {
         self.inner.share_text = share_text.as_ref().to_string();
+        self.missing.retain(|f| *f != "share_text");
         self
     }
 
-    pub fn group_in_common_count(&mut self, group_in_common_count: i64) -> &mut Self {
+    pub fn group_in_common_count(&mut self, group_in_common_count: i32) -> &mut Self {
         self.inner.group_in_common_count = group_in_common_count;
         self
     }
@@ -152,3 +264,24 @@ impl AsRef<UserFullInfo> for RTDUserFullInfoBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            UserFullInfo::builder()
+                .is_blocked(true)
+                .can_be_called(true)
+                .has_private_calls(true)
+                .need_phone_number_privacy_exception(true)
+                .bio("text")
+                .share_text("text")
+                .group_in_common_count(i32::MAX)
+                .build(),
+        );
+    }
+}
@@ -1,61 +1,64 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Describes available user privacy settings
 pub trait TDUserPrivacySetting: Debug + RObject {}
 
 /// Describes available user privacy settings
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum UserPrivacySetting {
-    #[doc(hidden)]
-    _Default(()),
     /// A privacy setting for managing whether the user can be called
+    #[serde(rename = "userPrivacySettingAllowCalls")]
     AllowCalls(UserPrivacySettingAllowCalls),
     /// A privacy setting for managing whether the user can be invited to chats
+    #[serde(rename = "userPrivacySettingAllowChatInvites")]
     AllowChatInvites(UserPrivacySettingAllowChatInvites),
     /// A privacy setting for managing whether the user can be found by their phone number. Checked only if the phone number is not known to the other user. Can be set only to "Allow contacts" or "Allow all"
+    #[serde(rename = "userPrivacySettingAllowFindingByPhoneNumber")]
     AllowFindingByPhoneNumber(UserPrivacySettingAllowFindingByPhoneNumber),
     /// A privacy setting for managing whether peer-to-peer connections can be used for calls
+    #[serde(rename = "userPrivacySettingAllowPeerToPeerCalls")]
     AllowPeerToPeerCalls(UserPrivacySettingAllowPeerToPeerCalls),
     /// A privacy setting for managing whether a link to the user's account is included in forwarded messages
+    #[serde(rename = "userPrivacySettingShowLinkInForwardedMessages")]
     ShowLinkInForwardedMessages(UserPrivacySettingShowLinkInForwardedMessages),
     /// A privacy setting for managing whether the user's phone number is visible
+    #[serde(rename = "userPrivacySettingShowPhoneNumber")]
     ShowPhoneNumber(UserPrivacySettingShowPhoneNumber),
     /// A privacy setting for managing whether the user's profile photo is visible
+    #[serde(rename = "userPrivacySettingShowProfilePhoto")]
     ShowProfilePhoto(UserPrivacySettingShowProfilePhoto),
     /// A privacy setting for managing whether the user's online status is visible
+    #[serde(rename = "userPrivacySettingShowStatus")]
     ShowStatus(UserPrivacySettingShowStatus),
 }
 
+/// Default value is default of [UserPrivacySettingAllowCalls], builders still require the field to be set
 impl Default for UserPrivacySetting {
     fn default() -> Self {
-        UserPrivacySetting::_Default(())
+        UserPrivacySetting::AllowCalls(UserPrivacySettingAllowCalls::default())
     }
 }
 
-impl<'de> Deserialize<'de> for UserPrivacySetting {
-    fn deserialize<D>(deserializer: D) -> Result<UserPrivacySetting, D::Error>
+impl Serialize for UserPrivacySetting {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          UserPrivacySetting,
-          (userPrivacySettingAllowCalls, AllowCalls);
-          (userPrivacySettingAllowChatInvites, AllowChatInvites);
-          (userPrivacySettingAllowFindingByPhoneNumber, AllowFindingByPhoneNumber);
-          (userPrivacySettingAllowPeerToPeerCalls, AllowPeerToPeerCalls);
-          (userPrivacySettingShowLinkInForwardedMessages, ShowLinkInForwardedMessages);
-          (userPrivacySettingShowPhoneNumber, ShowPhoneNumber);
-          (userPrivacySettingShowProfilePhoto, ShowProfilePhoto);
-          (userPrivacySettingShowStatus, ShowStatus);
-
-        )(deserializer)
+        match self {
+            UserPrivacySetting::AllowCalls(t) => t.serialize(serializer),
+            UserPrivacySetting::AllowChatInvites(t) => t.serialize(serializer),
+            UserPrivacySetting::AllowFindingByPhoneNumber(t) => t.serialize(serializer),
+            UserPrivacySetting::AllowPeerToPeerCalls(t) => t.serialize(serializer),
+            UserPrivacySetting::ShowLinkInForwardedMessages(t) => t.serialize(serializer),
+            UserPrivacySetting::ShowPhoneNumber(t) => t.serialize(serializer),
+            UserPrivacySetting::ShowProfilePhoto(t) => t.serialize(serializer),
+            UserPrivacySetting::ShowStatus(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -71,8 +74,6 @@ impl RObject for UserPrivacySetting {
             UserPrivacySetting::ShowPhoneNumber(t) => t.td_name(),
             UserPrivacySetting::ShowProfilePhoto(t) => t.td_name(),
             UserPrivacySetting::ShowStatus(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -86,8 +87,6 @@ impl RObject for UserPrivacySetting {
             UserPrivacySetting::ShowPhoneNumber(t) => t.extra(),
             UserPrivacySetting::ShowProfilePhoto(t) => t.extra(),
             UserPrivacySetting::ShowStatus(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -99,10 +98,6 @@ impl UserPrivacySetting {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, UserPrivacySetting::_Default(_))
-    }
 }
 
 impl AsRef<UserPrivacySetting> for UserPrivacySetting {
@@ -113,12 +108,13 @@ impl AsRef<UserPrivacySetting> for UserPrivacySetting {
 
 /// A privacy setting for managing whether the user can be called
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "userPrivacySettingAllowCalls")]
 pub struct UserPrivacySettingAllowCalls {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -136,6 +132,18 @@ impl RObject for UserPrivacySettingAllowCalls {
     }
 }
 
+impl PartialEq for UserPrivacySettingAllowCalls {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for UserPrivacySettingAllowCalls {}
+
+impl std::hash::Hash for UserPrivacySettingAllowCalls {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDUserPrivacySetting for UserPrivacySettingAllowCalls {}
 
 impl UserPrivacySettingAllowCalls {
@@ -143,22 +151,49 @@ impl UserPrivacySettingAllowCalls {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUserPrivacySettingAllowCallsBuilder {
-        let mut inner = UserPrivacySettingAllowCalls::default();
-        inner.td_name = "userPrivacySettingAllowCalls".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUserPrivacySettingAllowCallsBuilder { inner }
+        RTDUserPrivacySettingAllowCallsBuilder {
+            inner: UserPrivacySettingAllowCalls::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUserPrivacySettingAllowCallsBuilder {
+        RTDUserPrivacySettingAllowCallsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUserPrivacySettingAllowCallsBuilder {
+        RTDUserPrivacySettingAllowCallsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDUserPrivacySettingAllowCallsBuilder {
     inner: UserPrivacySettingAllowCalls,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUserPrivacySettingAllowCallsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UserPrivacySettingAllowCalls {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UserPrivacySettingAllowCalls> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UserPrivacySettingAllowCalls",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<UserPrivacySettingAllowCalls> for UserPrivacySettingAllowCalls {
@@ -175,12 +210,13 @@ impl AsRef<UserPrivacySettingAllowCalls> for RTDUserPrivacySettingAllowCallsBuil
 
 /// A privacy setting for managing whether the user can be invited to chats
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "userPrivacySettingAllowChatInvites")]
 pub struct UserPrivacySettingAllowChatInvites {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -198,6 +234,18 @@ impl RObject for UserPrivacySettingAllowChatInvites {
     }
 }
 
+impl PartialEq for UserPrivacySettingAllowChatInvites {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for UserPrivacySettingAllowChatInvites {}
+
+impl std::hash::Hash for UserPrivacySettingAllowChatInvites {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDUserPrivacySetting for UserPrivacySettingAllowChatInvites {}
 
 impl UserPrivacySettingAllowChatInvites {
@@ -205,22 +253,49 @@ impl UserPrivacySettingAllowChatInvites {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUserPrivacySettingAllowChatInvitesBuilder {
-        let mut inner = UserPrivacySettingAllowChatInvites::default();
-        inner.td_name = "userPrivacySettingAllowChatInvites".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUserPrivacySettingAllowChatInvitesBuilder { inner }
+        RTDUserPrivacySettingAllowChatInvitesBuilder {
+            inner: UserPrivacySettingAllowChatInvites::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUserPrivacySettingAllowChatInvitesBuilder {
+        RTDUserPrivacySettingAllowChatInvitesBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUserPrivacySettingAllowChatInvitesBuilder {
+        RTDUserPrivacySettingAllowChatInvitesBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDUserPrivacySettingAllowChatInvitesBuilder {
     inner: UserPrivacySettingAllowChatInvites,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUserPrivacySettingAllowChatInvitesBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UserPrivacySettingAllowChatInvites {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UserPrivacySettingAllowChatInvites> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UserPrivacySettingAllowChatInvites",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<UserPrivacySettingAllowChatInvites> for UserPrivacySettingAllowChatInvites {
@@ -237,12 +312,13 @@ impl AsRef<UserPrivacySettingAllowChatInvites> for RTDUserPrivacySettingAllowCha
 
 /// A privacy setting for managing whether the user can be found by their phone number. Checked only if the phone number is not known to the other user. Can be set only to "Allow contacts" or "Allow all"
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "userPrivacySettingAllowFindingByPhoneNumber")]
 pub struct UserPrivacySettingAllowFindingByPhoneNumber {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -260,6 +336,18 @@ impl RObject for UserPrivacySettingAllowFindingByPhoneNumber {
     }
 }
 
+impl PartialEq for UserPrivacySettingAllowFindingByPhoneNumber {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for UserPrivacySettingAllowFindingByPhoneNumber {}
+
+impl std::hash::Hash for UserPrivacySettingAllowFindingByPhoneNumber {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDUserPrivacySetting for UserPrivacySettingAllowFindingByPhoneNumber {}
 
 impl UserPrivacySettingAllowFindingByPhoneNumber {
@@ -267,22 +355,49 @@ impl UserPrivacySettingAllowFindingByPhoneNumber {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUserPrivacySettingAllowFindingByPhoneNumberBuilder {
-        let mut inner = UserPrivacySettingAllowFindingByPhoneNumber::default();
-        inner.td_name = "userPrivacySettingAllowFindingByPhoneNumber".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUserPrivacySettingAllowFindingByPhoneNumberBuilder { inner }
+        RTDUserPrivacySettingAllowFindingByPhoneNumberBuilder {
+            inner: UserPrivacySettingAllowFindingByPhoneNumber::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUserPrivacySettingAllowFindingByPhoneNumberBuilder {
+        RTDUserPrivacySettingAllowFindingByPhoneNumberBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUserPrivacySettingAllowFindingByPhoneNumberBuilder {
+        RTDUserPrivacySettingAllowFindingByPhoneNumberBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDUserPrivacySettingAllowFindingByPhoneNumberBuilder {
     inner: UserPrivacySettingAllowFindingByPhoneNumber,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUserPrivacySettingAllowFindingByPhoneNumberBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UserPrivacySettingAllowFindingByPhoneNumber {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UserPrivacySettingAllowFindingByPhoneNumber> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UserPrivacySettingAllowFindingByPhoneNumber",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<UserPrivacySettingAllowFindingByPhoneNumber>
@@ -303,12 +418,13 @@ impl AsRef<UserPrivacySettingAllowFindingByPhoneNumber>
 
 /// A privacy setting for managing whether peer-to-peer connections can be used for calls
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "userPrivacySettingAllowPeerToPeerCalls")]
 pub struct UserPrivacySettingAllowPeerToPeerCalls {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -326,6 +442,18 @@ impl RObject for UserPrivacySettingAllowPeerToPeerCalls {
     }
 }
 
+impl PartialEq for UserPrivacySettingAllowPeerToPeerCalls {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for UserPrivacySettingAllowPeerToPeerCalls {}
+
+impl std::hash::Hash for UserPrivacySettingAllowPeerToPeerCalls {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDUserPrivacySetting for UserPrivacySettingAllowPeerToPeerCalls {}
 
 impl UserPrivacySettingAllowPeerToPeerCalls {
@@ -333,22 +461,49 @@ impl UserPrivacySettingAllowPeerToPeerCalls {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUserPrivacySettingAllowPeerToPeerCallsBuilder {
-        let mut inner = UserPrivacySettingAllowPeerToPeerCalls::default();
-        inner.td_name = "userPrivacySettingAllowPeerToPeerCalls".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUserPrivacySettingAllowPeerToPeerCallsBuilder { inner }
+        RTDUserPrivacySettingAllowPeerToPeerCallsBuilder {
+            inner: UserPrivacySettingAllowPeerToPeerCalls::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUserPrivacySettingAllowPeerToPeerCallsBuilder {
+        RTDUserPrivacySettingAllowPeerToPeerCallsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUserPrivacySettingAllowPeerToPeerCallsBuilder {
+        RTDUserPrivacySettingAllowPeerToPeerCallsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDUserPrivacySettingAllowPeerToPeerCallsBuilder {
     inner: UserPrivacySettingAllowPeerToPeerCalls,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUserPrivacySettingAllowPeerToPeerCallsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UserPrivacySettingAllowPeerToPeerCalls {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UserPrivacySettingAllowPeerToPeerCalls> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UserPrivacySettingAllowPeerToPeerCalls",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<UserPrivacySettingAllowPeerToPeerCalls> for UserPrivacySettingAllowPeerToPeerCalls {
@@ -367,12 +522,16 @@ impl AsRef<UserPrivacySettingAllowPeerToPeerCalls>
 
 /// A privacy setting for managing whether a link to the user's account is included in forwarded messages
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(
+    tag = "@type",
+    rename = "userPrivacySettingShowLinkInForwardedMessages"
+)]
 pub struct UserPrivacySettingShowLinkInForwardedMessages {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -390,6 +549,18 @@ impl RObject for UserPrivacySettingShowLinkInForwardedMessages {
     }
 }
 
+impl PartialEq for UserPrivacySettingShowLinkInForwardedMessages {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for UserPrivacySettingShowLinkInForwardedMessages {}
+
+impl std::hash::Hash for UserPrivacySettingShowLinkInForwardedMessages {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDUserPrivacySetting for UserPrivacySettingShowLinkInForwardedMessages {}
 
 impl UserPrivacySettingShowLinkInForwardedMessages {
@@ -397,22 +568,49 @@ impl UserPrivacySettingShowLinkInForwardedMessages {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUserPrivacySettingShowLinkInForwardedMessagesBuilder {
-        let mut inner = UserPrivacySettingShowLinkInForwardedMessages::default();
-        inner.td_name = "userPrivacySettingShowLinkInForwardedMessages".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUserPrivacySettingShowLinkInForwardedMessagesBuilder { inner }
+        RTDUserPrivacySettingShowLinkInForwardedMessagesBuilder {
+            inner: UserPrivacySettingShowLinkInForwardedMessages::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUserPrivacySettingShowLinkInForwardedMessagesBuilder {
+        RTDUserPrivacySettingShowLinkInForwardedMessagesBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUserPrivacySettingShowLinkInForwardedMessagesBuilder {
+        RTDUserPrivacySettingShowLinkInForwardedMessagesBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDUserPrivacySettingShowLinkInForwardedMessagesBuilder {
     inner: UserPrivacySettingShowLinkInForwardedMessages,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUserPrivacySettingShowLinkInForwardedMessagesBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UserPrivacySettingShowLinkInForwardedMessages {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UserPrivacySettingShowLinkInForwardedMessages> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UserPrivacySettingShowLinkInForwardedMessages",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<UserPrivacySettingShowLinkInForwardedMessages>
@@ -433,12 +631,13 @@ impl AsRef<UserPrivacySettingShowLinkInForwardedMessages>
 
 /// A privacy setting for managing whether the user's phone number is visible
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "userPrivacySettingShowPhoneNumber")]
 pub struct UserPrivacySettingShowPhoneNumber {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -456,6 +655,18 @@ impl RObject for UserPrivacySettingShowPhoneNumber {
     }
 }
 
+impl PartialEq for UserPrivacySettingShowPhoneNumber {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for UserPrivacySettingShowPhoneNumber {}
+
+impl std::hash::Hash for UserPrivacySettingShowPhoneNumber {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDUserPrivacySetting for UserPrivacySettingShowPhoneNumber {}
 
 impl UserPrivacySettingShowPhoneNumber {
@@ -463,22 +674,49 @@ impl UserPrivacySettingShowPhoneNumber {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUserPrivacySettingShowPhoneNumberBuilder {
-        let mut inner = UserPrivacySettingShowPhoneNumber::default();
-        inner.td_name = "userPrivacySettingShowPhoneNumber".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUserPrivacySettingShowPhoneNumberBuilder { inner }
+        RTDUserPrivacySettingShowPhoneNumberBuilder {
+            inner: UserPrivacySettingShowPhoneNumber::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUserPrivacySettingShowPhoneNumberBuilder {
+        RTDUserPrivacySettingShowPhoneNumberBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUserPrivacySettingShowPhoneNumberBuilder {
+        RTDUserPrivacySettingShowPhoneNumberBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDUserPrivacySettingShowPhoneNumberBuilder {
     inner: UserPrivacySettingShowPhoneNumber,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUserPrivacySettingShowPhoneNumberBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UserPrivacySettingShowPhoneNumber {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UserPrivacySettingShowPhoneNumber> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UserPrivacySettingShowPhoneNumber",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<UserPrivacySettingShowPhoneNumber> for UserPrivacySettingShowPhoneNumber {
@@ -495,12 +733,13 @@ impl AsRef<UserPrivacySettingShowPhoneNumber> for RTDUserPrivacySettingShowPhone
 
 /// A privacy setting for managing whether the user's profile photo is visible
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "userPrivacySettingShowProfilePhoto")]
 pub struct UserPrivacySettingShowProfilePhoto {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -518,6 +757,18 @@ impl RObject for UserPrivacySettingShowProfilePhoto {
     }
 }
 
+impl PartialEq for UserPrivacySettingShowProfilePhoto {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for UserPrivacySettingShowProfilePhoto {}
+
+impl std::hash::Hash for UserPrivacySettingShowProfilePhoto {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDUserPrivacySetting for UserPrivacySettingShowProfilePhoto {}
 
 impl UserPrivacySettingShowProfilePhoto {
@@ -525,22 +776,49 @@ impl UserPrivacySettingShowProfilePhoto {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUserPrivacySettingShowProfilePhotoBuilder {
-        let mut inner = UserPrivacySettingShowProfilePhoto::default();
-        inner.td_name = "userPrivacySettingShowProfilePhoto".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUserPrivacySettingShowProfilePhotoBuilder { inner }
+        RTDUserPrivacySettingShowProfilePhotoBuilder {
+            inner: UserPrivacySettingShowProfilePhoto::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUserPrivacySettingShowProfilePhotoBuilder {
+        RTDUserPrivacySettingShowProfilePhotoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUserPrivacySettingShowProfilePhotoBuilder {
+        RTDUserPrivacySettingShowProfilePhotoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDUserPrivacySettingShowProfilePhotoBuilder {
     inner: UserPrivacySettingShowProfilePhoto,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUserPrivacySettingShowProfilePhotoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UserPrivacySettingShowProfilePhoto {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UserPrivacySettingShowProfilePhoto> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UserPrivacySettingShowProfilePhoto",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<UserPrivacySettingShowProfilePhoto> for UserPrivacySettingShowProfilePhoto {
@@ -557,12 +835,13 @@ impl AsRef<UserPrivacySettingShowProfilePhoto> for RTDUserPrivacySettingShowProf
 
 /// A privacy setting for managing whether the user's online status is visible
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "userPrivacySettingShowStatus")]
 pub struct UserPrivacySettingShowStatus {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -580,6 +859,18 @@ impl RObject for UserPrivacySettingShowStatus {
     }
 }
 
+impl PartialEq for UserPrivacySettingShowStatus {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for UserPrivacySettingShowStatus {}
+
+impl std::hash::Hash for UserPrivacySettingShowStatus {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDUserPrivacySetting for UserPrivacySettingShowStatus {}
 
 impl UserPrivacySettingShowStatus {
@@ -587,22 +878,49 @@ impl UserPrivacySettingShowStatus {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUserPrivacySettingShowStatusBuilder {
-        let mut inner = UserPrivacySettingShowStatus::default();
-        inner.td_name = "userPrivacySettingShowStatus".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUserPrivacySettingShowStatusBuilder { inner }
+        RTDUserPrivacySettingShowStatusBuilder {
+            inner: UserPrivacySettingShowStatus::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUserPrivacySettingShowStatusBuilder {
+        RTDUserPrivacySettingShowStatusBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUserPrivacySettingShowStatusBuilder {
+        RTDUserPrivacySettingShowStatusBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDUserPrivacySettingShowStatusBuilder {
     inner: UserPrivacySettingShowStatus,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUserPrivacySettingShowStatusBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UserPrivacySettingShowStatus {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UserPrivacySettingShowStatus> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UserPrivacySettingShowStatus",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<UserPrivacySettingShowStatus> for UserPrivacySettingShowStatus {
@@ -616,3 +934,37 @@ impl AsRef<UserPrivacySettingShowStatus> for RTDUserPrivacySettingShowStatusBuil
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(UserPrivacySetting::AllowCalls(
+            UserPrivacySettingAllowCalls::builder().build(),
+        ));
+        assert_round_trip(UserPrivacySetting::AllowChatInvites(
+            UserPrivacySettingAllowChatInvites::builder().build(),
+        ));
+        assert_round_trip(UserPrivacySetting::AllowFindingByPhoneNumber(
+            UserPrivacySettingAllowFindingByPhoneNumber::builder().build(),
+        ));
+        assert_round_trip(UserPrivacySetting::AllowPeerToPeerCalls(
+            UserPrivacySettingAllowPeerToPeerCalls::builder().build(),
+        ));
+        assert_round_trip(UserPrivacySetting::ShowLinkInForwardedMessages(
+            UserPrivacySettingShowLinkInForwardedMessages::builder().build(),
+        ));
+        assert_round_trip(UserPrivacySetting::ShowPhoneNumber(
+            UserPrivacySettingShowPhoneNumber::builder().build(),
+        ));
+        assert_round_trip(UserPrivacySetting::ShowProfilePhoto(
+            UserPrivacySettingShowProfilePhoto::builder().build(),
+        ));
+        assert_round_trip(UserPrivacySetting::ShowStatus(
+            UserPrivacySettingShowStatus::builder().build(),
+        ));
+    }
+}
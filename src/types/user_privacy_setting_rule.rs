@@ -1,61 +1,64 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Represents a single rule for managing privacy settings
 pub trait TDUserPrivacySettingRule: Debug + RObject {}
 
 /// Represents a single rule for managing privacy settings
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum UserPrivacySettingRule {
-    #[doc(hidden)]
-    _Default(()),
     /// A rule to allow all users to do something
+    #[serde(rename = "userPrivacySettingRuleAllowAll")]
     AllowAll(UserPrivacySettingRuleAllowAll),
     /// A rule to allow all members of certain specified basic groups and supergroups to doing something
+    #[serde(rename = "userPrivacySettingRuleAllowChatMembers")]
     AllowChatMembers(UserPrivacySettingRuleAllowChatMembers),
     /// A rule to allow all of a user's contacts to do something
+    #[serde(rename = "userPrivacySettingRuleAllowContacts")]
     AllowContacts(UserPrivacySettingRuleAllowContacts),
     /// A rule to allow certain specified users to do something
+    #[serde(rename = "userPrivacySettingRuleAllowUsers")]
     AllowUsers(UserPrivacySettingRuleAllowUsers),
     /// A rule to restrict all users from doing something
+    #[serde(rename = "userPrivacySettingRuleRestrictAll")]
     RestrictAll(UserPrivacySettingRuleRestrictAll),
     /// A rule to restrict all members of specified basic groups and supergroups from doing something
+    #[serde(rename = "userPrivacySettingRuleRestrictChatMembers")]
     RestrictChatMembers(UserPrivacySettingRuleRestrictChatMembers),
     /// A rule to restrict all contacts of a user from doing something
+    #[serde(rename = "userPrivacySettingRuleRestrictContacts")]
     RestrictContacts(UserPrivacySettingRuleRestrictContacts),
     /// A rule to restrict all specified users from doing something
+    #[serde(rename = "userPrivacySettingRuleRestrictUsers")]
     RestrictUsers(UserPrivacySettingRuleRestrictUsers),
 }
 
+/// Default value is default of [UserPrivacySettingRuleAllowAll], builders still require the field to be set
 impl Default for UserPrivacySettingRule {
     fn default() -> Self {
-        UserPrivacySettingRule::_Default(())
+        UserPrivacySettingRule::AllowAll(UserPrivacySettingRuleAllowAll::default())
     }
 }
 
-impl<'de> Deserialize<'de> for UserPrivacySettingRule {
-    fn deserialize<D>(deserializer: D) -> Result<UserPrivacySettingRule, D::Error>
+impl Serialize for UserPrivacySettingRule {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          UserPrivacySettingRule,
-          (userPrivacySettingRuleAllowAll, AllowAll);
-          (userPrivacySettingRuleAllowChatMembers, AllowChatMembers);
-          (userPrivacySettingRuleAllowContacts, AllowContacts);
-          (userPrivacySettingRuleAllowUsers, AllowUsers);
-          (userPrivacySettingRuleRestrictAll, RestrictAll);
-          (userPrivacySettingRuleRestrictChatMembers, RestrictChatMembers);
-          (userPrivacySettingRuleRestrictContacts, RestrictContacts);
-          (userPrivacySettingRuleRestrictUsers, RestrictUsers);
-
-        )(deserializer)
+        match self {
+            UserPrivacySettingRule::AllowAll(t) => t.serialize(serializer),
+            UserPrivacySettingRule::AllowChatMembers(t) => t.serialize(serializer),
+            UserPrivacySettingRule::AllowContacts(t) => t.serialize(serializer),
+            UserPrivacySettingRule::AllowUsers(t) => t.serialize(serializer),
+            UserPrivacySettingRule::RestrictAll(t) => t.serialize(serializer),
+            UserPrivacySettingRule::RestrictChatMembers(t) => t.serialize(serializer),
+            UserPrivacySettingRule::RestrictContacts(t) => t.serialize(serializer),
+            UserPrivacySettingRule::RestrictUsers(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -71,8 +74,6 @@ impl RObject for UserPrivacySettingRule {
             UserPrivacySettingRule::RestrictChatMembers(t) => t.td_name(),
             UserPrivacySettingRule::RestrictContacts(t) => t.td_name(),
             UserPrivacySettingRule::RestrictUsers(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -86,8 +87,6 @@ impl RObject for UserPrivacySettingRule {
             UserPrivacySettingRule::RestrictChatMembers(t) => t.extra(),
             UserPrivacySettingRule::RestrictContacts(t) => t.extra(),
             UserPrivacySettingRule::RestrictUsers(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -99,10 +98,6 @@ impl UserPrivacySettingRule {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, UserPrivacySettingRule::_Default(_))
-    }
 }
 
 impl AsRef<UserPrivacySettingRule> for UserPrivacySettingRule {
@@ -113,12 +108,13 @@ impl AsRef<UserPrivacySettingRule> for UserPrivacySettingRule {
 
 /// A rule to allow all users to do something
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "userPrivacySettingRuleAllowAll")]
 pub struct UserPrivacySettingRuleAllowAll {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -136,6 +132,18 @@ impl RObject for UserPrivacySettingRuleAllowAll {
     }
 }
 
+impl PartialEq for UserPrivacySettingRuleAllowAll {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for UserPrivacySettingRuleAllowAll {}
+
+impl std::hash::Hash for UserPrivacySettingRuleAllowAll {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDUserPrivacySettingRule for UserPrivacySettingRuleAllowAll {}
 
 impl UserPrivacySettingRuleAllowAll {
@@ -143,22 +151,49 @@ impl UserPrivacySettingRuleAllowAll {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUserPrivacySettingRuleAllowAllBuilder {
-        let mut inner = UserPrivacySettingRuleAllowAll::default();
-        inner.td_name = "userPrivacySettingRuleAllowAll".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUserPrivacySettingRuleAllowAllBuilder { inner }
+        RTDUserPrivacySettingRuleAllowAllBuilder {
+            inner: UserPrivacySettingRuleAllowAll::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUserPrivacySettingRuleAllowAllBuilder {
+        RTDUserPrivacySettingRuleAllowAllBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUserPrivacySettingRuleAllowAllBuilder {
+        RTDUserPrivacySettingRuleAllowAllBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDUserPrivacySettingRuleAllowAllBuilder {
     inner: UserPrivacySettingRuleAllowAll,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUserPrivacySettingRuleAllowAllBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UserPrivacySettingRuleAllowAll {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UserPrivacySettingRuleAllowAll> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UserPrivacySettingRuleAllowAll",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<UserPrivacySettingRuleAllowAll> for UserPrivacySettingRuleAllowAll {
@@ -175,15 +210,16 @@ impl AsRef<UserPrivacySettingRuleAllowAll> for RTDUserPrivacySettingRuleAllowAll
 
 /// A rule to allow all members of certain specified basic groups and supergroups to doing something
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "userPrivacySettingRuleAllowChatMembers")]
 pub struct UserPrivacySettingRuleAllowChatMembers {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The chat identifiers, total number of chats in all rules must not exceed 20
-    chat_ids: Vec<i64>,
+    chat_ids: Vec<ChatId>,
 }
 
 impl RObject for UserPrivacySettingRuleAllowChatMembers {
@@ -200,6 +236,20 @@ impl RObject for UserPrivacySettingRuleAllowChatMembers {
     }
 }
 
+impl PartialEq for UserPrivacySettingRuleAllowChatMembers {
+    fn eq(&self, other: &Self) -> bool {
+        self.chat_ids == other.chat_ids
+    }
+}
+
+impl Eq for UserPrivacySettingRuleAllowChatMembers {}
+
+impl std::hash::Hash for UserPrivacySettingRuleAllowChatMembers {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.chat_ids, state);
+    }
+}
+
 impl TDUserPrivacySettingRule for UserPrivacySettingRuleAllowChatMembers {}
 
 impl UserPrivacySettingRuleAllowChatMembers {
@@ -207,29 +257,66 @@ impl UserPrivacySettingRuleAllowChatMembers {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUserPrivacySettingRuleAllowChatMembersBuilder {
-        let mut inner = UserPrivacySettingRuleAllowChatMembers::default();
-        inner.td_name = "userPrivacySettingRuleAllowChatMembers".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUserPrivacySettingRuleAllowChatMembersBuilder { inner }
+        RTDUserPrivacySettingRuleAllowChatMembersBuilder {
+            inner: UserPrivacySettingRuleAllowChatMembers::default(),
+            missing: vec!["chat_ids"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUserPrivacySettingRuleAllowChatMembersBuilder {
+        RTDUserPrivacySettingRuleAllowChatMembersBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUserPrivacySettingRuleAllowChatMembersBuilder {
+        RTDUserPrivacySettingRuleAllowChatMembersBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn chat_ids(&self) -> &Vec<i64> {
+    pub fn chat_ids(&self) -> &Vec<ChatId> {
         &self.chat_ids
     }
+
+    pub fn into_chat_ids(self) -> Vec<ChatId> {
+        self.chat_ids
+    }
+
+    pub fn with_chat_ids(mut self, chat_ids: Vec<ChatId>) -> Self {
+        self.chat_ids = chat_ids;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUserPrivacySettingRuleAllowChatMembersBuilder {
     inner: UserPrivacySettingRuleAllowChatMembers,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUserPrivacySettingRuleAllowChatMembersBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UserPrivacySettingRuleAllowChatMembers {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UserPrivacySettingRuleAllowChatMembers> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UserPrivacySettingRuleAllowChatMembers",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn chat_ids(&mut self, chat_ids: Vec<i64>) -> &mut Self {
+    pub fn chat_ids(&mut self, chat_ids: Vec<ChatId>) -> &mut Self {
         self.inner.chat_ids = chat_ids;
+        self.missing.retain(|f| *f != "chat_ids");
         self
     }
 }
@@ -250,12 +337,13 @@ impl AsRef<UserPrivacySettingRuleAllowChatMembers>
 
 /// A rule to allow all of a user's contacts to do something
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "userPrivacySettingRuleAllowContacts")]
 pub struct UserPrivacySettingRuleAllowContacts {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -273,6 +361,18 @@ impl RObject for UserPrivacySettingRuleAllowContacts {
     }
 }
 
+impl PartialEq for UserPrivacySettingRuleAllowContacts {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for UserPrivacySettingRuleAllowContacts {}
+
+impl std::hash::Hash for UserPrivacySettingRuleAllowContacts {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDUserPrivacySettingRule for UserPrivacySettingRuleAllowContacts {}
 
 impl UserPrivacySettingRuleAllowContacts {
@@ -280,22 +380,49 @@ impl UserPrivacySettingRuleAllowContacts {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUserPrivacySettingRuleAllowContactsBuilder {
-        let mut inner = UserPrivacySettingRuleAllowContacts::default();
-        inner.td_name = "userPrivacySettingRuleAllowContacts".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUserPrivacySettingRuleAllowContactsBuilder { inner }
+        RTDUserPrivacySettingRuleAllowContactsBuilder {
+            inner: UserPrivacySettingRuleAllowContacts::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUserPrivacySettingRuleAllowContactsBuilder {
+        RTDUserPrivacySettingRuleAllowContactsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUserPrivacySettingRuleAllowContactsBuilder {
+        RTDUserPrivacySettingRuleAllowContactsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDUserPrivacySettingRuleAllowContactsBuilder {
     inner: UserPrivacySettingRuleAllowContacts,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUserPrivacySettingRuleAllowContactsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UserPrivacySettingRuleAllowContacts {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UserPrivacySettingRuleAllowContacts> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UserPrivacySettingRuleAllowContacts",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<UserPrivacySettingRuleAllowContacts> for UserPrivacySettingRuleAllowContacts {
@@ -312,15 +439,16 @@ impl AsRef<UserPrivacySettingRuleAllowContacts> for RTDUserPrivacySettingRuleAll
 
 /// A rule to allow certain specified users to do something
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "userPrivacySettingRuleAllowUsers")]
 pub struct UserPrivacySettingRuleAllowUsers {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The user identifiers, total number of users in all rules must not exceed 1000
-    user_ids: Vec<i64>,
+    user_ids: Vec<UserId>,
 }
 
 impl RObject for UserPrivacySettingRuleAllowUsers {
@@ -337,6 +465,20 @@ impl RObject for UserPrivacySettingRuleAllowUsers {
     }
 }
 
+impl PartialEq for UserPrivacySettingRuleAllowUsers {
+    fn eq(&self, other: &Self) -> bool {
+        self.user_ids == other.user_ids
+    }
+}
+
+impl Eq for UserPrivacySettingRuleAllowUsers {}
+
+impl std::hash::Hash for UserPrivacySettingRuleAllowUsers {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.user_ids, state);
+    }
+}
+
 impl TDUserPrivacySettingRule for UserPrivacySettingRuleAllowUsers {}
 
 impl UserPrivacySettingRuleAllowUsers {
@@ -344,29 +486,66 @@ impl UserPrivacySettingRuleAllowUsers {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUserPrivacySettingRuleAllowUsersBuilder {
-        let mut inner = UserPrivacySettingRuleAllowUsers::default();
-        inner.td_name = "userPrivacySettingRuleAllowUsers".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUserPrivacySettingRuleAllowUsersBuilder { inner }
+        RTDUserPrivacySettingRuleAllowUsersBuilder {
+            inner: UserPrivacySettingRuleAllowUsers::default(),
+            missing: vec!["user_ids"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUserPrivacySettingRuleAllowUsersBuilder {
+        RTDUserPrivacySettingRuleAllowUsersBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUserPrivacySettingRuleAllowUsersBuilder {
+        RTDUserPrivacySettingRuleAllowUsersBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn user_ids(&self) -> &Vec<i64> {
+    pub fn user_ids(&self) -> &Vec<UserId> {
         &self.user_ids
     }
+
+    pub fn into_user_ids(self) -> Vec<UserId> {
+        self.user_ids
+    }
+
+    pub fn with_user_ids(mut self, user_ids: Vec<UserId>) -> Self {
+        self.user_ids = user_ids;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUserPrivacySettingRuleAllowUsersBuilder {
     inner: UserPrivacySettingRuleAllowUsers,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUserPrivacySettingRuleAllowUsersBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UserPrivacySettingRuleAllowUsers {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UserPrivacySettingRuleAllowUsers> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UserPrivacySettingRuleAllowUsers",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn user_ids(&mut self, user_ids: Vec<i64>) -> &mut Self {
+    pub fn user_ids(&mut self, user_ids: Vec<UserId>) -> &mut Self {
         self.inner.user_ids = user_ids;
+        self.missing.retain(|f| *f != "user_ids");
         self
     }
 }
@@ -385,12 +564,13 @@ impl AsRef<UserPrivacySettingRuleAllowUsers> for RTDUserPrivacySettingRuleAllowU
 
 /// A rule to restrict all users from doing something
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "userPrivacySettingRuleRestrictAll")]
 pub struct UserPrivacySettingRuleRestrictAll {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -408,6 +588,18 @@ impl RObject for UserPrivacySettingRuleRestrictAll {
     }
 }
 
+impl PartialEq for UserPrivacySettingRuleRestrictAll {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for UserPrivacySettingRuleRestrictAll {}
+
+impl std::hash::Hash for UserPrivacySettingRuleRestrictAll {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDUserPrivacySettingRule for UserPrivacySettingRuleRestrictAll {}
 
 impl UserPrivacySettingRuleRestrictAll {
@@ -415,22 +607,49 @@ impl UserPrivacySettingRuleRestrictAll {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUserPrivacySettingRuleRestrictAllBuilder {
-        let mut inner = UserPrivacySettingRuleRestrictAll::default();
-        inner.td_name = "userPrivacySettingRuleRestrictAll".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUserPrivacySettingRuleRestrictAllBuilder { inner }
+        RTDUserPrivacySettingRuleRestrictAllBuilder {
+            inner: UserPrivacySettingRuleRestrictAll::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUserPrivacySettingRuleRestrictAllBuilder {
+        RTDUserPrivacySettingRuleRestrictAllBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUserPrivacySettingRuleRestrictAllBuilder {
+        RTDUserPrivacySettingRuleRestrictAllBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDUserPrivacySettingRuleRestrictAllBuilder {
     inner: UserPrivacySettingRuleRestrictAll,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUserPrivacySettingRuleRestrictAllBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UserPrivacySettingRuleRestrictAll {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UserPrivacySettingRuleRestrictAll> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UserPrivacySettingRuleRestrictAll",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<UserPrivacySettingRuleRestrictAll> for UserPrivacySettingRuleRestrictAll {
@@ -447,15 +666,16 @@ impl AsRef<UserPrivacySettingRuleRestrictAll> for RTDUserPrivacySettingRuleRestr
 
 /// A rule to restrict all members of specified basic groups and supergroups from doing something
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "userPrivacySettingRuleRestrictChatMembers")]
 pub struct UserPrivacySettingRuleRestrictChatMembers {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The chat identifiers, total number of chats in all rules must not exceed 20
-    chat_ids: Vec<i64>,
+    chat_ids: Vec<ChatId>,
 }
 
 impl RObject for UserPrivacySettingRuleRestrictChatMembers {
@@ -472,6 +692,20 @@ impl RObject for UserPrivacySettingRuleRestrictChatMembers {
     }
 }
 
+impl PartialEq for UserPrivacySettingRuleRestrictChatMembers {
+    fn eq(&self, other: &Self) -> bool {
+        self.chat_ids == other.chat_ids
+    }
+}
+
+impl Eq for UserPrivacySettingRuleRestrictChatMembers {}
+
+impl std::hash::Hash for UserPrivacySettingRuleRestrictChatMembers {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.chat_ids, state);
+    }
+}
+
 impl TDUserPrivacySettingRule for UserPrivacySettingRuleRestrictChatMembers {}
 
 impl UserPrivacySettingRuleRestrictChatMembers {
@@ -479,29 +713,66 @@ impl UserPrivacySettingRuleRestrictChatMembers {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUserPrivacySettingRuleRestrictChatMembersBuilder {
-        let mut inner = UserPrivacySettingRuleRestrictChatMembers::default();
-        inner.td_name = "userPrivacySettingRuleRestrictChatMembers".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUserPrivacySettingRuleRestrictChatMembersBuilder { inner }
+        RTDUserPrivacySettingRuleRestrictChatMembersBuilder {
+            inner: UserPrivacySettingRuleRestrictChatMembers::default(),
+            missing: vec!["chat_ids"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUserPrivacySettingRuleRestrictChatMembersBuilder {
+        RTDUserPrivacySettingRuleRestrictChatMembersBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUserPrivacySettingRuleRestrictChatMembersBuilder {
+        RTDUserPrivacySettingRuleRestrictChatMembersBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn chat_ids(&self) -> &Vec<i64> {
+    pub fn chat_ids(&self) -> &Vec<ChatId> {
         &self.chat_ids
     }
+
+    pub fn into_chat_ids(self) -> Vec<ChatId> {
+        self.chat_ids
+    }
+
+    pub fn with_chat_ids(mut self, chat_ids: Vec<ChatId>) -> Self {
+        self.chat_ids = chat_ids;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUserPrivacySettingRuleRestrictChatMembersBuilder {
     inner: UserPrivacySettingRuleRestrictChatMembers,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUserPrivacySettingRuleRestrictChatMembersBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UserPrivacySettingRuleRestrictChatMembers {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UserPrivacySettingRuleRestrictChatMembers> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UserPrivacySettingRuleRestrictChatMembers",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn chat_ids(&mut self, chat_ids: Vec<i64>) -> &mut Self {
+    pub fn chat_ids(&mut self, chat_ids: Vec<ChatId>) -> &mut Self {
         self.inner.chat_ids = chat_ids;
+        self.missing.retain(|f| *f != "chat_ids");
         self
     }
 }
@@ -524,12 +795,13 @@ impl AsRef<UserPrivacySettingRuleRestrictChatMembers>
 
 /// A rule to restrict all contacts of a user from doing something
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "userPrivacySettingRuleRestrictContacts")]
 pub struct UserPrivacySettingRuleRestrictContacts {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -547,6 +819,18 @@ impl RObject for UserPrivacySettingRuleRestrictContacts {
     }
 }
 
+impl PartialEq for UserPrivacySettingRuleRestrictContacts {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for UserPrivacySettingRuleRestrictContacts {}
+
+impl std::hash::Hash for UserPrivacySettingRuleRestrictContacts {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDUserPrivacySettingRule for UserPrivacySettingRuleRestrictContacts {}
 
 impl UserPrivacySettingRuleRestrictContacts {
@@ -554,22 +838,49 @@ impl UserPrivacySettingRuleRestrictContacts {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUserPrivacySettingRuleRestrictContactsBuilder {
-        let mut inner = UserPrivacySettingRuleRestrictContacts::default();
-        inner.td_name = "userPrivacySettingRuleRestrictContacts".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUserPrivacySettingRuleRestrictContactsBuilder { inner }
+        RTDUserPrivacySettingRuleRestrictContactsBuilder {
+            inner: UserPrivacySettingRuleRestrictContacts::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUserPrivacySettingRuleRestrictContactsBuilder {
+        RTDUserPrivacySettingRuleRestrictContactsBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUserPrivacySettingRuleRestrictContactsBuilder {
+        RTDUserPrivacySettingRuleRestrictContactsBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDUserPrivacySettingRuleRestrictContactsBuilder {
     inner: UserPrivacySettingRuleRestrictContacts,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUserPrivacySettingRuleRestrictContactsBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UserPrivacySettingRuleRestrictContacts {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UserPrivacySettingRuleRestrictContacts> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UserPrivacySettingRuleRestrictContacts",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<UserPrivacySettingRuleRestrictContacts> for UserPrivacySettingRuleRestrictContacts {
@@ -588,15 +899,16 @@ impl AsRef<UserPrivacySettingRuleRestrictContacts>
 
 /// A rule to restrict all specified users from doing something
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "userPrivacySettingRuleRestrictUsers")]
 pub struct UserPrivacySettingRuleRestrictUsers {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// The user identifiers, total number of users in all rules must not exceed 1000
-    user_ids: Vec<i64>,
+    user_ids: Vec<UserId>,
 }
 
 impl RObject for UserPrivacySettingRuleRestrictUsers {
@@ -613,6 +925,20 @@ impl RObject for UserPrivacySettingRuleRestrictUsers {
     }
 }
 
+impl PartialEq for UserPrivacySettingRuleRestrictUsers {
+    fn eq(&self, other: &Self) -> bool {
+        self.user_ids == other.user_ids
+    }
+}
+
+impl Eq for UserPrivacySettingRuleRestrictUsers {}
+
+impl std::hash::Hash for UserPrivacySettingRuleRestrictUsers {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.user_ids, state);
+    }
+}
+
 impl TDUserPrivacySettingRule for UserPrivacySettingRuleRestrictUsers {}
 
 impl UserPrivacySettingRuleRestrictUsers {
@@ -620,29 +946,66 @@ impl UserPrivacySettingRuleRestrictUsers {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUserPrivacySettingRuleRestrictUsersBuilder {
-        let mut inner = UserPrivacySettingRuleRestrictUsers::default();
-        inner.td_name = "userPrivacySettingRuleRestrictUsers".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUserPrivacySettingRuleRestrictUsersBuilder { inner }
+        RTDUserPrivacySettingRuleRestrictUsersBuilder {
+            inner: UserPrivacySettingRuleRestrictUsers::default(),
+            missing: vec!["user_ids"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUserPrivacySettingRuleRestrictUsersBuilder {
+        RTDUserPrivacySettingRuleRestrictUsersBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUserPrivacySettingRuleRestrictUsersBuilder {
+        RTDUserPrivacySettingRuleRestrictUsersBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn user_ids(&self) -> &Vec<i64> {
+    pub fn user_ids(&self) -> &Vec<UserId> {
         &self.user_ids
     }
+
+    pub fn into_user_ids(self) -> Vec<UserId> {
+        self.user_ids
+    }
+
+    pub fn with_user_ids(mut self, user_ids: Vec<UserId>) -> Self {
+        self.user_ids = user_ids;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUserPrivacySettingRuleRestrictUsersBuilder {
     inner: UserPrivacySettingRuleRestrictUsers,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUserPrivacySettingRuleRestrictUsersBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UserPrivacySettingRuleRestrictUsers {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UserPrivacySettingRuleRestrictUsers> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UserPrivacySettingRuleRestrictUsers",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn user_ids(&mut self, user_ids: Vec<i64>) -> &mut Self {
+    pub fn user_ids(&mut self, user_ids: Vec<UserId>) -> &mut Self {
         self.inner.user_ids = user_ids;
+        self.missing.retain(|f| *f != "user_ids");
         self
     }
 }
@@ -658,3 +1021,45 @@ impl AsRef<UserPrivacySettingRuleRestrictUsers> for RTDUserPrivacySettingRuleRes
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(UserPrivacySettingRule::AllowAll(
+            UserPrivacySettingRuleAllowAll::builder().build(),
+        ));
+        assert_round_trip(UserPrivacySettingRule::AllowChatMembers(
+            UserPrivacySettingRuleAllowChatMembers::builder()
+                .chat_ids(vec![Default::default()])
+                .build(),
+        ));
+        assert_round_trip(UserPrivacySettingRule::AllowContacts(
+            UserPrivacySettingRuleAllowContacts::builder().build(),
+        ));
+        assert_round_trip(UserPrivacySettingRule::AllowUsers(
+            UserPrivacySettingRuleAllowUsers::builder()
+                .user_ids(vec![Default::default()])
+                .build(),
+        ));
+        assert_round_trip(UserPrivacySettingRule::RestrictAll(
+            UserPrivacySettingRuleRestrictAll::builder().build(),
+        ));
+        assert_round_trip(UserPrivacySettingRule::RestrictChatMembers(
+            UserPrivacySettingRuleRestrictChatMembers::builder()
+                .chat_ids(vec![Default::default()])
+                .build(),
+        ));
+        assert_round_trip(UserPrivacySettingRule::RestrictContacts(
+            UserPrivacySettingRuleRestrictContacts::builder().build(),
+        ));
+        assert_round_trip(UserPrivacySettingRule::RestrictUsers(
+            UserPrivacySettingRuleRestrictUsers::builder()
+                .user_ids(vec![Default::default()])
+                .build(),
+        ));
+    }
+}
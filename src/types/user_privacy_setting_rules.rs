@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// A list of privacy rules. Rules are matched in the specified order. The first matched rule defines the privacy setting for a given user. If no rule matches, the action is not allowed
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "userPrivacySettingRules")]
 pub struct UserPrivacySettingRules {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// A list of rules
     rules: Vec<UserPrivacySettingRule>,
@@ -29,34 +29,85 @@ impl RObject for UserPrivacySettingRules {
     }
 }
 
+impl PartialEq for UserPrivacySettingRules {
+    fn eq(&self, other: &Self) -> bool {
+        self.rules == other.rules
+    }
+}
+
+impl Eq for UserPrivacySettingRules {}
+
+impl std::hash::Hash for UserPrivacySettingRules {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.rules, state);
+    }
+}
+
 impl UserPrivacySettingRules {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUserPrivacySettingRulesBuilder {
-        let mut inner = UserPrivacySettingRules::default();
-        inner.td_name = "userPrivacySettingRules".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUserPrivacySettingRulesBuilder { inner }
+        RTDUserPrivacySettingRulesBuilder {
+            inner: UserPrivacySettingRules::default(),
+            missing: vec!["rules"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUserPrivacySettingRulesBuilder {
+        RTDUserPrivacySettingRulesBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUserPrivacySettingRulesBuilder {
+        RTDUserPrivacySettingRulesBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn rules(&self) -> &Vec<UserPrivacySettingRule> {
         &self.rules
     }
+
+    pub fn into_rules(self) -> Vec<UserPrivacySettingRule> {
+        self.rules
+    }
+
+    pub fn with_rules(mut self, rules: Vec<UserPrivacySettingRule>) -> Self {
+        self.rules = rules;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUserPrivacySettingRulesBuilder {
     inner: UserPrivacySettingRules,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUserPrivacySettingRulesBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UserPrivacySettingRules {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UserPrivacySettingRules> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UserPrivacySettingRules",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn rules(&mut self, rules: Vec<UserPrivacySettingRule>) -> &mut Self {
         self.inner.rules = rules;
+        self.missing.retain(|f| *f != "rules");
         self
     }
 }
@@ -72,3 +123,14 @@ impl AsRef<UserPrivacySettingRules> for RTDUserPrivacySettingRulesBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(UserPrivacySettingRules::builder().rules(vec![]).build());
+    }
+}
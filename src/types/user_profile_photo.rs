@@ -1,20 +1,21 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains full information about a user profile photo
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "userProfilePhoto")]
 pub struct UserProfilePhoto {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Unique user profile photo identifier
-    id: isize,
+    #[serde(with = "super::_common::int64")]
+    id: i64,
     /// Point in time (Unix timestamp) when the photo has been added
-    added_date: i64,
+    added_date: i32,
     /// Available variants of the user photo, in different sizes
     sizes: Vec<PhotoSize>,
 }
@@ -33,52 +34,117 @@ impl RObject for UserProfilePhoto {
     }
 }
 
+impl PartialEq for UserProfilePhoto {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id && self.added_date == other.added_date && self.sizes == other.sizes
+    }
+}
+
+impl Eq for UserProfilePhoto {}
+
+impl std::hash::Hash for UserProfilePhoto {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.id, state);
+        std::hash::Hash::hash(&self.added_date, state);
+        std::hash::Hash::hash(&self.sizes, state);
+    }
+}
+
 impl UserProfilePhoto {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUserProfilePhotoBuilder {
-        let mut inner = UserProfilePhoto::default();
-        inner.td_name = "userProfilePhoto".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUserProfilePhotoBuilder { inner }
+        RTDUserProfilePhotoBuilder {
+            inner: UserProfilePhoto::default(),
+            missing: vec!["id", "added_date", "sizes"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUserProfilePhotoBuilder {
+        RTDUserProfilePhotoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUserProfilePhotoBuilder {
+        RTDUserProfilePhotoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn id(&self) -> isize {
+    pub fn id(&self) -> i64 {
         self.id
     }
 
-    pub fn added_date(&self) -> i64 {
+    pub fn added_date(&self) -> i32 {
         self.added_date
     }
 
     pub fn sizes(&self) -> &Vec<PhotoSize> {
         &self.sizes
     }
+
+    pub fn into_sizes(self) -> Vec<PhotoSize> {
+        self.sizes
+    }
+
+    pub fn with_id(mut self, id: i64) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn with_added_date(mut self, added_date: i32) -> Self {
+        self.added_date = added_date;
+        self
+    }
+
+    pub fn with_sizes(mut self, sizes: Vec<PhotoSize>) -> Self {
+        self.sizes = sizes;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUserProfilePhotoBuilder {
     inner: UserProfilePhoto,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUserProfilePhotoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UserProfilePhoto {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UserProfilePhoto> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UserProfilePhoto",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn id(&mut self, id: isize) -> &mut Self {
+    pub fn id(&mut self, id: i64) -> &mut Self {
         self.inner.id = id;
+        self.missing.retain(|f| *f != "id");
         self
     }
 
-    pub fn added_date(&mut self, added_date: i64) -> &mut Self {
+    pub fn added_date(&mut self, added_date: i32) -> &mut Self {
         self.inner.added_date = added_date;
+        self.missing.retain(|f| *f != "added_date");
         self
     }
 
     pub fn sizes(&mut self, sizes: Vec<PhotoSize>) -> &mut Self {
         self.inner.sizes = sizes;
+        self.missing.retain(|f| *f != "sizes");
         self
     }
 }
@@ -94,3 +160,20 @@ impl AsRef<UserProfilePhoto> for RTDUserProfilePhotoBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            UserProfilePhoto::builder()
+                .id(i64::MAX)
+                .added_date(i32::MAX)
+                .sizes(vec![])
+                .build(),
+        );
+    }
+}
@@ -1,18 +1,18 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains part of the list of user photos
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "userProfilePhotos")]
 pub struct UserProfilePhotos {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Total number of user profile photos
-    total_count: i64,
+    total_count: i32,
     /// A list of photos
     photos: Vec<UserProfilePhoto>,
 }
@@ -31,43 +31,101 @@ impl RObject for UserProfilePhotos {
     }
 }
 
+impl PartialEq for UserProfilePhotos {
+    fn eq(&self, other: &Self) -> bool {
+        self.total_count == other.total_count && self.photos == other.photos
+    }
+}
+
+impl Eq for UserProfilePhotos {}
+
+impl std::hash::Hash for UserProfilePhotos {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.total_count, state);
+        std::hash::Hash::hash(&self.photos, state);
+    }
+}
+
 impl UserProfilePhotos {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUserProfilePhotosBuilder {
-        let mut inner = UserProfilePhotos::default();
-        inner.td_name = "userProfilePhotos".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUserProfilePhotosBuilder { inner }
+        RTDUserProfilePhotosBuilder {
+            inner: UserProfilePhotos::default(),
+            missing: vec!["total_count", "photos"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUserProfilePhotosBuilder {
+        RTDUserProfilePhotosBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUserProfilePhotosBuilder {
+        RTDUserProfilePhotosBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn total_count(&self) -> i64 {
+    pub fn total_count(&self) -> i32 {
         self.total_count
     }
 
     pub fn photos(&self) -> &Vec<UserProfilePhoto> {
         &self.photos
     }
+
+    pub fn into_photos(self) -> Vec<UserProfilePhoto> {
+        self.photos
+    }
+
+    pub fn with_total_count(mut self, total_count: i32) -> Self {
+        self.total_count = total_count;
+        self
+    }
+
+    pub fn with_photos(mut self, photos: Vec<UserProfilePhoto>) -> Self {
+        self.photos = photos;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUserProfilePhotosBuilder {
     inner: UserProfilePhotos,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUserProfilePhotosBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UserProfilePhotos {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UserProfilePhotos> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UserProfilePhotos",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn total_count(&mut self, total_count: i64) -> &mut Self {
+    pub fn total_count(&mut self, total_count: i32) -> &mut Self {
         self.inner.total_count = total_count;
+        self.missing.retain(|f| *f != "total_count");
         self
     }
 
     pub fn photos(&mut self, photos: Vec<UserProfilePhoto>) -> &mut Self {
         self.inner.photos = photos;
+        self.missing.retain(|f| *f != "photos");
         self
     }
 }
@@ -83,3 +141,19 @@ impl AsRef<UserProfilePhotos> for RTDUserProfilePhotosBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            UserProfilePhotos::builder()
+                .total_count(i32::MAX)
+                .photos(vec![])
+                .build(),
+        );
+    }
+}
@@ -1,55 +1,56 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Describes the last time the user was online
 pub trait TDUserStatus: Debug + RObject {}
 
 /// Describes the last time the user was online
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum UserStatus {
-    #[doc(hidden)]
-    _Default(()),
     /// The user status was never changed
+    #[serde(rename = "userStatusEmpty")]
     Empty(UserStatusEmpty),
     /// The user is offline, but was online last month
+    #[serde(rename = "userStatusLastMonth")]
     LastMonth(UserStatusLastMonth),
     /// The user is offline, but was online last week
+    #[serde(rename = "userStatusLastWeek")]
     LastWeek(UserStatusLastWeek),
     /// The user is offline
+    #[serde(rename = "userStatusOffline")]
     Offline(UserStatusOffline),
     /// The user is online
+    #[serde(rename = "userStatusOnline")]
     Online(UserStatusOnline),
     /// The user was online recently
+    #[serde(rename = "userStatusRecently")]
     Recently(UserStatusRecently),
 }
 
+/// Default value is default of [UserStatusEmpty], builders still require the field to be set
 impl Default for UserStatus {
     fn default() -> Self {
-        UserStatus::_Default(())
+        UserStatus::Empty(UserStatusEmpty::default())
     }
 }
 
-impl<'de> Deserialize<'de> for UserStatus {
-    fn deserialize<D>(deserializer: D) -> Result<UserStatus, D::Error>
+impl Serialize for UserStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          UserStatus,
-          (userStatusEmpty, Empty);
-          (userStatusLastMonth, LastMonth);
-          (userStatusLastWeek, LastWeek);
-          (userStatusOffline, Offline);
-          (userStatusOnline, Online);
-          (userStatusRecently, Recently);
-
-        )(deserializer)
+        match self {
+            UserStatus::Empty(t) => t.serialize(serializer),
+            UserStatus::LastMonth(t) => t.serialize(serializer),
+            UserStatus::LastWeek(t) => t.serialize(serializer),
+            UserStatus::Offline(t) => t.serialize(serializer),
+            UserStatus::Online(t) => t.serialize(serializer),
+            UserStatus::Recently(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -63,8 +64,6 @@ impl RObject for UserStatus {
             UserStatus::Offline(t) => t.td_name(),
             UserStatus::Online(t) => t.td_name(),
             UserStatus::Recently(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -76,8 +75,6 @@ impl RObject for UserStatus {
             UserStatus::Offline(t) => t.extra(),
             UserStatus::Online(t) => t.extra(),
             UserStatus::Recently(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -89,10 +86,6 @@ impl UserStatus {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, UserStatus::_Default(_))
-    }
 }
 
 impl AsRef<UserStatus> for UserStatus {
@@ -103,12 +96,13 @@ impl AsRef<UserStatus> for UserStatus {
 
 /// The user status was never changed
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "userStatusEmpty")]
 pub struct UserStatusEmpty {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -126,6 +120,18 @@ impl RObject for UserStatusEmpty {
     }
 }
 
+impl PartialEq for UserStatusEmpty {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for UserStatusEmpty {}
+
+impl std::hash::Hash for UserStatusEmpty {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDUserStatus for UserStatusEmpty {}
 
 impl UserStatusEmpty {
@@ -133,22 +139,49 @@ impl UserStatusEmpty {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUserStatusEmptyBuilder {
-        let mut inner = UserStatusEmpty::default();
-        inner.td_name = "userStatusEmpty".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUserStatusEmptyBuilder { inner }
+        RTDUserStatusEmptyBuilder {
+            inner: UserStatusEmpty::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUserStatusEmptyBuilder {
+        RTDUserStatusEmptyBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUserStatusEmptyBuilder {
+        RTDUserStatusEmptyBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDUserStatusEmptyBuilder {
     inner: UserStatusEmpty,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUserStatusEmptyBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UserStatusEmpty {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UserStatusEmpty> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UserStatusEmpty",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<UserStatusEmpty> for UserStatusEmpty {
@@ -165,12 +198,13 @@ impl AsRef<UserStatusEmpty> for RTDUserStatusEmptyBuilder {
 
 /// The user is offline, but was online last month
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "userStatusLastMonth")]
 pub struct UserStatusLastMonth {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -188,6 +222,18 @@ impl RObject for UserStatusLastMonth {
     }
 }
 
+impl PartialEq for UserStatusLastMonth {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for UserStatusLastMonth {}
+
+impl std::hash::Hash for UserStatusLastMonth {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDUserStatus for UserStatusLastMonth {}
 
 impl UserStatusLastMonth {
@@ -195,22 +241,49 @@ impl UserStatusLastMonth {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUserStatusLastMonthBuilder {
-        let mut inner = UserStatusLastMonth::default();
-        inner.td_name = "userStatusLastMonth".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUserStatusLastMonthBuilder { inner }
+        RTDUserStatusLastMonthBuilder {
+            inner: UserStatusLastMonth::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUserStatusLastMonthBuilder {
+        RTDUserStatusLastMonthBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUserStatusLastMonthBuilder {
+        RTDUserStatusLastMonthBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDUserStatusLastMonthBuilder {
     inner: UserStatusLastMonth,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUserStatusLastMonthBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UserStatusLastMonth {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UserStatusLastMonth> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UserStatusLastMonth",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<UserStatusLastMonth> for UserStatusLastMonth {
@@ -227,12 +300,13 @@ impl AsRef<UserStatusLastMonth> for RTDUserStatusLastMonthBuilder {
 
 /// The user is offline, but was online last week
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "userStatusLastWeek")]
 pub struct UserStatusLastWeek {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -250,6 +324,18 @@ impl RObject for UserStatusLastWeek {
     }
 }
 
+impl PartialEq for UserStatusLastWeek {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for UserStatusLastWeek {}
+
+impl std::hash::Hash for UserStatusLastWeek {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDUserStatus for UserStatusLastWeek {}
 
 impl UserStatusLastWeek {
@@ -257,22 +343,49 @@ impl UserStatusLastWeek {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUserStatusLastWeekBuilder {
-        let mut inner = UserStatusLastWeek::default();
-        inner.td_name = "userStatusLastWeek".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUserStatusLastWeekBuilder { inner }
+        RTDUserStatusLastWeekBuilder {
+            inner: UserStatusLastWeek::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUserStatusLastWeekBuilder {
+        RTDUserStatusLastWeekBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUserStatusLastWeekBuilder {
+        RTDUserStatusLastWeekBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDUserStatusLastWeekBuilder {
     inner: UserStatusLastWeek,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUserStatusLastWeekBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UserStatusLastWeek {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UserStatusLastWeek> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UserStatusLastWeek",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<UserStatusLastWeek> for UserStatusLastWeek {
@@ -289,15 +402,16 @@ impl AsRef<UserStatusLastWeek> for RTDUserStatusLastWeekBuilder {
 
 /// The user is offline
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "userStatusOffline")]
 pub struct UserStatusOffline {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Point in time (Unix timestamp) when the user was last online
-    was_online: i64,
+    was_online: i32,
 }
 
 impl RObject for UserStatusOffline {
@@ -314,6 +428,20 @@ impl RObject for UserStatusOffline {
     }
 }
 
+impl PartialEq for UserStatusOffline {
+    fn eq(&self, other: &Self) -> bool {
+        self.was_online == other.was_online
+    }
+}
+
+impl Eq for UserStatusOffline {}
+
+impl std::hash::Hash for UserStatusOffline {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.was_online, state);
+    }
+}
+
 impl TDUserStatus for UserStatusOffline {}
 
 impl UserStatusOffline {
@@ -321,29 +449,62 @@ impl UserStatusOffline {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUserStatusOfflineBuilder {
-        let mut inner = UserStatusOffline::default();
-        inner.td_name = "userStatusOffline".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUserStatusOfflineBuilder { inner }
+        RTDUserStatusOfflineBuilder {
+            inner: UserStatusOffline::default(),
+            missing: vec!["was_online"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUserStatusOfflineBuilder {
+        RTDUserStatusOfflineBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUserStatusOfflineBuilder {
+        RTDUserStatusOfflineBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn was_online(&self) -> i64 {
+    pub fn was_online(&self) -> i32 {
         self.was_online
     }
+
+    pub fn with_was_online(mut self, was_online: i32) -> Self {
+        self.was_online = was_online;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUserStatusOfflineBuilder {
     inner: UserStatusOffline,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUserStatusOfflineBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UserStatusOffline {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UserStatusOffline> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UserStatusOffline",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn was_online(&mut self, was_online: i64) -> &mut Self {
+    pub fn was_online(&mut self, was_online: i32) -> &mut Self {
         self.inner.was_online = was_online;
+        self.missing.retain(|f| *f != "was_online");
         self
     }
 }
@@ -362,15 +523,16 @@ impl AsRef<UserStatusOffline> for RTDUserStatusOfflineBuilder {
 
 /// The user is online
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "userStatusOnline")]
 pub struct UserStatusOnline {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Point in time (Unix timestamp) when the user's online status will expire
-    expires: i64,
+    expires: i32,
 }
 
 impl RObject for UserStatusOnline {
@@ -387,6 +549,20 @@ impl RObject for UserStatusOnline {
     }
 }
 
+impl PartialEq for UserStatusOnline {
+    fn eq(&self, other: &Self) -> bool {
+        self.expires == other.expires
+    }
+}
+
+impl Eq for UserStatusOnline {}
+
+impl std::hash::Hash for UserStatusOnline {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.expires, state);
+    }
+}
+
 impl TDUserStatus for UserStatusOnline {}
 
 impl UserStatusOnline {
@@ -394,29 +570,62 @@ impl UserStatusOnline {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUserStatusOnlineBuilder {
-        let mut inner = UserStatusOnline::default();
-        inner.td_name = "userStatusOnline".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUserStatusOnlineBuilder { inner }
+        RTDUserStatusOnlineBuilder {
+            inner: UserStatusOnline::default(),
+            missing: vec!["expires"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUserStatusOnlineBuilder {
+        RTDUserStatusOnlineBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUserStatusOnlineBuilder {
+        RTDUserStatusOnlineBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn expires(&self) -> i64 {
+    pub fn expires(&self) -> i32 {
         self.expires
     }
+
+    pub fn with_expires(mut self, expires: i32) -> Self {
+        self.expires = expires;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUserStatusOnlineBuilder {
     inner: UserStatusOnline,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUserStatusOnlineBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UserStatusOnline {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UserStatusOnline> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UserStatusOnline",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn expires(&mut self, expires: i64) -> &mut Self {
+    pub fn expires(&mut self, expires: i32) -> &mut Self {
         self.inner.expires = expires;
+        self.missing.retain(|f| *f != "expires");
         self
     }
 }
@@ -435,12 +644,13 @@ impl AsRef<UserStatusOnline> for RTDUserStatusOnlineBuilder {
 
 /// The user was online recently
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "userStatusRecently")]
 pub struct UserStatusRecently {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -458,6 +668,18 @@ impl RObject for UserStatusRecently {
     }
 }
 
+impl PartialEq for UserStatusRecently {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for UserStatusRecently {}
+
+impl std::hash::Hash for UserStatusRecently {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDUserStatus for UserStatusRecently {}
 
 impl UserStatusRecently {
@@ -465,22 +687,49 @@ impl UserStatusRecently {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUserStatusRecentlyBuilder {
-        let mut inner = UserStatusRecently::default();
-        inner.td_name = "userStatusRecently".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUserStatusRecentlyBuilder { inner }
+        RTDUserStatusRecentlyBuilder {
+            inner: UserStatusRecently::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUserStatusRecentlyBuilder {
+        RTDUserStatusRecentlyBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUserStatusRecentlyBuilder {
+        RTDUserStatusRecentlyBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDUserStatusRecentlyBuilder {
     inner: UserStatusRecently,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUserStatusRecentlyBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UserStatusRecently {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UserStatusRecently> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UserStatusRecently",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<UserStatusRecently> for UserStatusRecently {
@@ -494,3 +743,25 @@ impl AsRef<UserStatusRecently> for RTDUserStatusRecentlyBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(UserStatus::Empty(UserStatusEmpty::builder().build()));
+        assert_round_trip(UserStatus::LastMonth(
+            UserStatusLastMonth::builder().build(),
+        ));
+        assert_round_trip(UserStatus::LastWeek(UserStatusLastWeek::builder().build()));
+        assert_round_trip(UserStatus::Offline(
+            UserStatusOffline::builder().was_online(i32::MAX).build(),
+        ));
+        assert_round_trip(UserStatus::Online(
+            UserStatusOnline::builder().expires(i32::MAX).build(),
+        ));
+        assert_round_trip(UserStatus::Recently(UserStatusRecently::builder().build()));
+    }
+}
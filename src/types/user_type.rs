@@ -1,49 +1,48 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt::Debug;
 
 /// TRAIT | Represents the type of a user. The following types are possible: regular users, deleted users and bots
 pub trait TDUserType: Debug + RObject {}
 
 /// Represents the type of a user. The following types are possible: regular users, deleted users and bots
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(tag = "@type")]
 pub enum UserType {
-    #[doc(hidden)]
-    _Default(()),
     /// A bot (see https://core.telegram.org/bots)
+    #[serde(rename = "userTypeBot")]
     Bot(UserTypeBot),
     /// A deleted user or deleted bot. No information on the user besides the user identifier is available. It is not possible to perform any active actions on this type of user
+    #[serde(rename = "userTypeDeleted")]
     Deleted(UserTypeDeleted),
     /// A regular user
+    #[serde(rename = "userTypeRegular")]
     Regular(UserTypeRegular),
     /// No information on the user besides the user identifier is available, yet this user has not been deleted. This object is extremely rare and must be handled like a deleted user. It is not possible to perform any actions on users of this type
+    #[serde(rename = "userTypeUnknown")]
     Unknown(UserTypeUnknown),
 }
 
+/// Default value is default of [UserTypeBot], builders still require the field to be set
 impl Default for UserType {
     fn default() -> Self {
-        UserType::_Default(())
+        UserType::Bot(UserTypeBot::default())
     }
 }
 
-impl<'de> Deserialize<'de> for UserType {
-    fn deserialize<D>(deserializer: D) -> Result<UserType, D::Error>
+impl Serialize for UserType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        D: Deserializer<'de>,
+        S: Serializer,
     {
-        use serde::de::Error;
-        rtd_enum_deserialize!(
-          UserType,
-          (userTypeBot, Bot);
-          (userTypeDeleted, Deleted);
-          (userTypeRegular, Regular);
-          (userTypeUnknown, Unknown);
-
-        )(deserializer)
+        match self {
+            UserType::Bot(t) => t.serialize(serializer),
+            UserType::Deleted(t) => t.serialize(serializer),
+            UserType::Regular(t) => t.serialize(serializer),
+            UserType::Unknown(t) => t.serialize(serializer),
+        }
     }
 }
 
@@ -55,8 +54,6 @@ impl RObject for UserType {
             UserType::Deleted(t) => t.td_name(),
             UserType::Regular(t) => t.td_name(),
             UserType::Unknown(t) => t.td_name(),
-
-            _ => "-1",
         }
     }
     #[doc(hidden)]
@@ -66,8 +63,6 @@ impl RObject for UserType {
             UserType::Deleted(t) => t.extra(),
             UserType::Regular(t) => t.extra(),
             UserType::Unknown(t) => t.extra(),
-
-            _ => None,
         }
     }
     fn to_json(&self) -> RTDResult<String> {
@@ -79,10 +74,6 @@ impl UserType {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
-    #[doc(hidden)]
-    pub fn _is_default(&self) -> bool {
-        matches!(self, UserType::_Default(_))
-    }
 }
 
 impl AsRef<UserType> for UserType {
@@ -93,12 +84,13 @@ impl AsRef<UserType> for UserType {
 
 /// A bot (see https://core.telegram.org/bots)
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "userTypeBot")]
 pub struct UserTypeBot {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// True, if the bot can be invited to basic group and supergroup chats
     can_join_groups: bool,
@@ -126,6 +118,28 @@ impl RObject for UserTypeBot {
     }
 }
 
+impl PartialEq for UserTypeBot {
+    fn eq(&self, other: &Self) -> bool {
+        self.can_join_groups == other.can_join_groups
+            && self.can_read_all_group_messages == other.can_read_all_group_messages
+            && self.is_inline == other.is_inline
+            && self.inline_query_placeholder == other.inline_query_placeholder
+            && self.need_location == other.need_location
+    }
+}
+
+impl Eq for UserTypeBot {}
+
+impl std::hash::Hash for UserTypeBot {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.can_join_groups, state);
+        std::hash::Hash::hash(&self.can_read_all_group_messages, state);
+        std::hash::Hash::hash(&self.is_inline, state);
+        std::hash::Hash::hash(&self.inline_query_placeholder, state);
+        std::hash::Hash::hash(&self.need_location, state);
+    }
+}
+
 impl TDUserType for UserTypeBot {}
 
 impl UserTypeBot {
@@ -133,10 +147,24 @@ impl UserTypeBot {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUserTypeBotBuilder {
-        let mut inner = UserTypeBot::default();
-        inner.td_name = "userTypeBot".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUserTypeBotBuilder { inner }
+        RTDUserTypeBotBuilder {
+            inner: UserTypeBot::default(),
+            missing: vec!["inline_query_placeholder"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUserTypeBotBuilder {
+        RTDUserTypeBotBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUserTypeBotBuilder {
+        RTDUserTypeBotBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn can_join_groups(&self) -> bool {
@@ -158,17 +186,59 @@ impl UserTypeBot {
     pub fn need_location(&self) -> bool {
         self.need_location
     }
+
+    pub fn into_inline_query_placeholder(self) -> String {
+        self.inline_query_placeholder
+    }
+
+    pub fn with_can_join_groups(mut self, can_join_groups: bool) -> Self {
+        self.can_join_groups = can_join_groups;
+        self
+    }
+
+    pub fn with_can_read_all_group_messages(mut self, can_read_all_group_messages: bool) -> Self {
+        self.can_read_all_group_messages = can_read_all_group_messages;
+        self
+    }
+
+    pub fn with_is_inline(mut self, is_inline: bool) -> Self {
+        self.is_inline = is_inline;
+        self
+    }
+
+    pub fn with_inline_query_placeholder<T: AsRef<str>>(
+        mut self,
+        inline_query_placeholder: T,
+    ) -> Self {
+        self.inline_query_placeholder = inline_query_placeholder.as_ref().to_string();
+        self
+    }
+
+    pub fn with_need_location(mut self, need_location: bool) -> Self {
+        self.need_location = need_location;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUserTypeBotBuilder {
     inner: UserTypeBot,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUserTypeBotBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UserTypeBot {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UserTypeBot> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("UserTypeBot", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn can_join_groups(&mut self, can_join_groups: bool) -> &mut Self {
         self.inner.can_join_groups = can_join_groups;
@@ -190,6 +260,7 @@ impl RTDUserTypeBotBuilder {
         inline_query_placeholder: T,
     ) -> &mut Self {
         self.inner.inline_query_placeholder = inline_query_placeholder.as_ref().to_string();
+        self.missing.retain(|f| *f != "inline_query_placeholder");
         self
     }
 
@@ -213,12 +284,13 @@ impl AsRef<UserTypeBot> for RTDUserTypeBotBuilder {
 
 /// A deleted user or deleted bot. No information on the user besides the user identifier is available. It is not possible to perform any active actions on this type of user
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "userTypeDeleted")]
 pub struct UserTypeDeleted {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -236,6 +308,18 @@ impl RObject for UserTypeDeleted {
     }
 }
 
+impl PartialEq for UserTypeDeleted {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for UserTypeDeleted {}
+
+impl std::hash::Hash for UserTypeDeleted {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDUserType for UserTypeDeleted {}
 
 impl UserTypeDeleted {
@@ -243,22 +327,49 @@ impl UserTypeDeleted {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUserTypeDeletedBuilder {
-        let mut inner = UserTypeDeleted::default();
-        inner.td_name = "userTypeDeleted".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUserTypeDeletedBuilder { inner }
+        RTDUserTypeDeletedBuilder {
+            inner: UserTypeDeleted::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUserTypeDeletedBuilder {
+        RTDUserTypeDeletedBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUserTypeDeletedBuilder {
+        RTDUserTypeDeletedBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDUserTypeDeletedBuilder {
     inner: UserTypeDeleted,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUserTypeDeletedBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UserTypeDeleted {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UserTypeDeleted> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UserTypeDeleted",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<UserTypeDeleted> for UserTypeDeleted {
@@ -275,12 +386,13 @@ impl AsRef<UserTypeDeleted> for RTDUserTypeDeletedBuilder {
 
 /// A regular user
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "userTypeRegular")]
 pub struct UserTypeRegular {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -298,6 +410,18 @@ impl RObject for UserTypeRegular {
     }
 }
 
+impl PartialEq for UserTypeRegular {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for UserTypeRegular {}
+
+impl std::hash::Hash for UserTypeRegular {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDUserType for UserTypeRegular {}
 
 impl UserTypeRegular {
@@ -305,22 +429,49 @@ impl UserTypeRegular {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUserTypeRegularBuilder {
-        let mut inner = UserTypeRegular::default();
-        inner.td_name = "userTypeRegular".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUserTypeRegularBuilder { inner }
+        RTDUserTypeRegularBuilder {
+            inner: UserTypeRegular::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUserTypeRegularBuilder {
+        RTDUserTypeRegularBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUserTypeRegularBuilder {
+        RTDUserTypeRegularBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDUserTypeRegularBuilder {
     inner: UserTypeRegular,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUserTypeRegularBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UserTypeRegular {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UserTypeRegular> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UserTypeRegular",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<UserTypeRegular> for UserTypeRegular {
@@ -337,12 +488,13 @@ impl AsRef<UserTypeRegular> for RTDUserTypeRegularBuilder {
 
 /// No information on the user besides the user identifier is available, yet this user has not been deleted. This object is extremely rare and must be handled like a deleted user. It is not possible to perform any actions on users of this type
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "userTypeUnknown")]
 pub struct UserTypeUnknown {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
 }
 
@@ -360,6 +512,18 @@ impl RObject for UserTypeUnknown {
     }
 }
 
+impl PartialEq for UserTypeUnknown {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for UserTypeUnknown {}
+
+impl std::hash::Hash for UserTypeUnknown {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
 impl TDUserType for UserTypeUnknown {}
 
 impl UserTypeUnknown {
@@ -367,22 +531,49 @@ impl UserTypeUnknown {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUserTypeUnknownBuilder {
-        let mut inner = UserTypeUnknown::default();
-        inner.td_name = "userTypeUnknown".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUserTypeUnknownBuilder { inner }
+        RTDUserTypeUnknownBuilder {
+            inner: UserTypeUnknown::default(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUserTypeUnknownBuilder {
+        RTDUserTypeUnknownBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUserTypeUnknownBuilder {
+        RTDUserTypeUnknownBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 }
 
 #[doc(hidden)]
 pub struct RTDUserTypeUnknownBuilder {
     inner: UserTypeUnknown,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUserTypeUnknownBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> UserTypeUnknown {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<UserTypeUnknown> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "UserTypeUnknown",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 }
 
 impl AsRef<UserTypeUnknown> for UserTypeUnknown {
@@ -396,3 +587,25 @@ impl AsRef<UserTypeUnknown> for RTDUserTypeUnknownBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(UserType::Bot(
+            UserTypeBot::builder()
+                .can_join_groups(true)
+                .can_read_all_group_messages(true)
+                .is_inline(true)
+                .inline_query_placeholder("text")
+                .need_location(true)
+                .build(),
+        ));
+        assert_round_trip(UserType::Deleted(UserTypeDeleted::builder().build()));
+        assert_round_trip(UserType::Regular(UserTypeRegular::builder().build()));
+        assert_round_trip(UserType::Unknown(UserTypeUnknown::builder().build()));
+    }
+}
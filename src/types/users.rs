@@ -1,20 +1,20 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Represents a list of users
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "users")]
 pub struct Users {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Approximate total count of users found
-    total_count: i64,
+    total_count: i32,
     /// A list of user identifiers
-    user_ids: Vec<i64>,
+    user_ids: Vec<UserId>,
 }
 
 impl RObject for Users {
@@ -31,43 +31,98 @@ impl RObject for Users {
     }
 }
 
+impl PartialEq for Users {
+    fn eq(&self, other: &Self) -> bool {
+        self.total_count == other.total_count && self.user_ids == other.user_ids
+    }
+}
+
+impl Eq for Users {}
+
+impl std::hash::Hash for Users {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.total_count, state);
+        std::hash::Hash::hash(&self.user_ids, state);
+    }
+}
+
 impl Users {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDUsersBuilder {
-        let mut inner = Users::default();
-        inner.td_name = "users".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDUsersBuilder { inner }
+        RTDUsersBuilder {
+            inner: Users::default(),
+            missing: vec!["total_count", "user_ids"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDUsersBuilder {
+        RTDUsersBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDUsersBuilder {
+        RTDUsersBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn total_count(&self) -> i64 {
+    pub fn total_count(&self) -> i32 {
         self.total_count
     }
 
-    pub fn user_ids(&self) -> &Vec<i64> {
+    pub fn user_ids(&self) -> &Vec<UserId> {
         &self.user_ids
     }
+
+    pub fn into_user_ids(self) -> Vec<UserId> {
+        self.user_ids
+    }
+
+    pub fn with_total_count(mut self, total_count: i32) -> Self {
+        self.total_count = total_count;
+        self
+    }
+
+    pub fn with_user_ids(mut self, user_ids: Vec<UserId>) -> Self {
+        self.user_ids = user_ids;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDUsersBuilder {
     inner: Users,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDUsersBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> Users {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<Users> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("Users", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn total_count(&mut self, total_count: i64) -> &mut Self {
+    pub fn total_count(&mut self, total_count: i32) -> &mut Self {
         self.inner.total_count = total_count;
+        self.missing.retain(|f| *f != "total_count");
         self
     }
 
-    pub fn user_ids(&mut self, user_ids: Vec<i64>) -> &mut Self {
+    pub fn user_ids(&mut self, user_ids: Vec<UserId>) -> &mut Self {
         self.inner.user_ids = user_ids;
+        self.missing.retain(|f| *f != "user_ids");
         self
     }
 }
@@ -83,3 +138,19 @@ impl AsRef<Users> for RTDUsersBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            Users::builder()
+                .total_count(i32::MAX)
+                .user_ids(vec![Default::default()])
+                .build(),
+        );
+    }
+}
@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Contains a temporary identifier of validated order information, which is stored for one hour. Also contains the available shipping options
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "validatedOrderInfo")]
 pub struct ValidatedOrderInfo {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Temporary identifier of the order information
     order_info_id: String,
@@ -31,15 +31,44 @@ impl RObject for ValidatedOrderInfo {
     }
 }
 
+impl PartialEq for ValidatedOrderInfo {
+    fn eq(&self, other: &Self) -> bool {
+        self.order_info_id == other.order_info_id && self.shipping_options == other.shipping_options
+    }
+}
+
+impl Eq for ValidatedOrderInfo {}
+
+impl std::hash::Hash for ValidatedOrderInfo {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.order_info_id, state);
+        std::hash::Hash::hash(&self.shipping_options, state);
+    }
+}
+
 impl ValidatedOrderInfo {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDValidatedOrderInfoBuilder {
-        let mut inner = ValidatedOrderInfo::default();
-        inner.td_name = "validatedOrderInfo".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDValidatedOrderInfoBuilder { inner }
+        RTDValidatedOrderInfoBuilder {
+            inner: ValidatedOrderInfo::default(),
+            missing: vec!["order_info_id", "shipping_options"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDValidatedOrderInfoBuilder {
+        RTDValidatedOrderInfoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDValidatedOrderInfoBuilder {
+        RTDValidatedOrderInfoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn order_info_id(&self) -> &String {
@@ -49,25 +78,58 @@ impl ValidatedOrderInfo {
     pub fn shipping_options(&self) -> &Vec<ShippingOption> {
         &self.shipping_options
     }
+
+    pub fn into_order_info_id(self) -> String {
+        self.order_info_id
+    }
+
+    pub fn into_shipping_options(self) -> Vec<ShippingOption> {
+        self.shipping_options
+    }
+
+    pub fn with_order_info_id<T: AsRef<str>>(mut self, order_info_id: T) -> Self {
+        self.order_info_id = order_info_id.as_ref().to_string();
+        self
+    }
+
+    pub fn with_shipping_options(mut self, shipping_options: Vec<ShippingOption>) -> Self {
+        self.shipping_options = shipping_options;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDValidatedOrderInfoBuilder {
     inner: ValidatedOrderInfo,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDValidatedOrderInfoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> ValidatedOrderInfo {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<ValidatedOrderInfo> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "ValidatedOrderInfo",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn order_info_id<T: AsRef<str>>(&mut self, order_info_id: T) -> &mut Self {
         self.inner.order_info_id = order_info_id.as_ref().to_string();
+        self.missing.retain(|f| *f != "order_info_id");
         self
     }
 
     pub fn shipping_options(&mut self, shipping_options: Vec<ShippingOption>) -> &mut Self {
         self.inner.shipping_options = shipping_options;
+        self.missing.retain(|f| *f != "shipping_options");
         self
     }
 }
@@ -83,3 +145,19 @@ impl AsRef<ValidatedOrderInfo> for RTDValidatedOrderInfoBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            ValidatedOrderInfo::builder()
+                .order_info_id("text")
+                .shipping_options(vec![])
+                .build(),
+        );
+    }
+}
@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Describes a venue
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "venue")]
 pub struct Venue {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Venue location; as defined by the sender
     location: Location,
@@ -40,15 +40,40 @@ impl RObject for Venue {
     }
 }
 
+impl PartialEq for Venue {
+    fn eq(&self, other: &Self) -> bool {
+        self.location == other.location
+            && self.title == other.title
+            && self.address == other.address
+            && self.provider == other.provider
+            && self.id == other.id
+            && self.type_ == other.type_
+    }
+}
+
 impl Venue {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDVenueBuilder {
-        let mut inner = Venue::default();
-        inner.td_name = "venue".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDVenueBuilder { inner }
+        RTDVenueBuilder {
+            inner: Venue::default(),
+            missing: vec!["location", "title", "address", "provider", "id", "type_"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDVenueBuilder {
+        RTDVenueBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDVenueBuilder {
+        RTDVenueBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn location(&self) -> &Location {
@@ -74,45 +99,115 @@ impl Venue {
     pub fn type_(&self) -> &String {
         &self.type_
     }
+
+    pub fn into_location(self) -> Location {
+        self.location
+    }
+
+    pub fn into_title(self) -> String {
+        self.title
+    }
+
+    pub fn into_address(self) -> String {
+        self.address
+    }
+
+    pub fn into_provider(self) -> String {
+        self.provider
+    }
+
+    pub fn into_id(self) -> String {
+        self.id
+    }
+
+    pub fn into_type(self) -> String {
+        self.type_
+    }
+
+    pub fn with_location<T: AsRef<Location>>(mut self, location: T) -> Self {
+        self.location = location.as_ref().clone();
+        self
+    }
+
+    pub fn with_title<T: AsRef<str>>(mut self, title: T) -> Self {
+        self.title = title.as_ref().to_string();
+        self
+    }
+
+    pub fn with_address<T: AsRef<str>>(mut self, address: T) -> Self {
+        self.address = address.as_ref().to_string();
+        self
+    }
+
+    pub fn with_provider<T: AsRef<str>>(mut self, provider: T) -> Self {
+        self.provider = provider.as_ref().to_string();
+        self
+    }
+
+    pub fn with_id<T: AsRef<str>>(mut self, id: T) -> Self {
+        self.id = id.as_ref().to_string();
+        self
+    }
+
+    pub fn with_type<T: AsRef<str>>(mut self, type_: T) -> Self {
+        self.type_ = type_.as_ref().to_string();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDVenueBuilder {
     inner: Venue,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDVenueBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> Venue {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<Venue> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("Venue", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn location<T: AsRef<Location>>(&mut self, location: T) -> &mut Self {
         self.inner.location = location.as_ref().clone();
+        self.missing.retain(|f| *f != "location");
         self
     }
 
     pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
         self.inner.title = title.as_ref().to_string();
+        self.missing.retain(|f| *f != "title");
         self
     }
 
     pub fn address<T: AsRef<str>>(&mut self, address: T) -> &mut Self {
         self.inner.address = address.as_ref().to_string();
+        self.missing.retain(|f| *f != "address");
         self
     }
 
     pub fn provider<T: AsRef<str>>(&mut self, provider: T) -> &mut Self {
         self.inner.provider = provider.as_ref().to_string();
+        self.missing.retain(|f| *f != "provider");
         self
     }
 
     pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
         self.inner.id = id.as_ref().to_string();
+        self.missing.retain(|f| *f != "id");
         self
     }
 
     pub fn type_<T: AsRef<str>>(&mut self, type_: T) -> &mut Self {
         self.inner.type_ = type_.as_ref().to_string();
+        self.missing.retain(|f| *f != "type_");
         self
     }
 }
@@ -128,3 +223,23 @@ impl AsRef<Venue> for RTDVenueBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            Venue::builder()
+                .location(Location::builder().latitude(1.5).longitude(1.5).build())
+                .title("text")
+                .address("text")
+                .provider("text")
+                .id("text")
+                .type_("text")
+                .build(),
+        );
+    }
+}
@@ -1,22 +1,22 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Describes a video file
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "video")]
 pub struct Video {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Duration of the video, in seconds; as defined by the sender
-    duration: i64,
+    duration: i32,
     /// Video width; as defined by the sender
-    width: i64,
+    width: i32,
     /// Video height; as defined by the sender
-    height: i64,
+    height: i32,
     /// Original name of the file; as defined by the sender
     file_name: String,
     /// MIME type of the file; as defined by the sender
@@ -47,26 +47,79 @@ impl RObject for Video {
     }
 }
 
+impl PartialEq for Video {
+    fn eq(&self, other: &Self) -> bool {
+        self.duration == other.duration
+            && self.width == other.width
+            && self.height == other.height
+            && self.file_name == other.file_name
+            && self.mime_type == other.mime_type
+            && self.has_stickers == other.has_stickers
+            && self.supports_streaming == other.supports_streaming
+            && self.minithumbnail == other.minithumbnail
+            && self.thumbnail == other.thumbnail
+            && self.video == other.video
+    }
+}
+
+impl Eq for Video {}
+
+impl std::hash::Hash for Video {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.duration, state);
+        std::hash::Hash::hash(&self.width, state);
+        std::hash::Hash::hash(&self.height, state);
+        std::hash::Hash::hash(&self.file_name, state);
+        std::hash::Hash::hash(&self.mime_type, state);
+        std::hash::Hash::hash(&self.has_stickers, state);
+        std::hash::Hash::hash(&self.supports_streaming, state);
+        std::hash::Hash::hash(&self.minithumbnail, state);
+        std::hash::Hash::hash(&self.thumbnail, state);
+        std::hash::Hash::hash(&self.video, state);
+    }
+}
+
 impl Video {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDVideoBuilder {
-        let mut inner = Video::default();
-        inner.td_name = "video".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDVideoBuilder { inner }
+        RTDVideoBuilder {
+            inner: Video::default(),
+            missing: vec![
+                "duration",
+                "width",
+                "height",
+                "file_name",
+                "mime_type",
+                "video",
+            ],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDVideoBuilder {
+        RTDVideoBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDVideoBuilder {
+        RTDVideoBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn duration(&self) -> i64 {
+    pub fn duration(&self) -> i32 {
         self.duration
     }
 
-    pub fn width(&self) -> i64 {
+    pub fn width(&self) -> i32 {
         self.width
     }
 
-    pub fn height(&self) -> i64 {
+    pub fn height(&self) -> i32 {
         self.height
     }
 
@@ -97,40 +150,125 @@ impl Video {
     pub fn video(&self) -> &File {
         &self.video
     }
+
+    pub fn into_file_name(self) -> String {
+        self.file_name
+    }
+
+    pub fn into_mime_type(self) -> String {
+        self.mime_type
+    }
+
+    pub fn into_minithumbnail(self) -> Option<Minithumbnail> {
+        self.minithumbnail
+    }
+
+    pub fn into_thumbnail(self) -> Option<PhotoSize> {
+        self.thumbnail
+    }
+
+    pub fn into_video(self) -> File {
+        self.video
+    }
+
+    pub fn with_duration(mut self, duration: i32) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    pub fn with_width(mut self, width: i32) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn with_height(mut self, height: i32) -> Self {
+        self.height = height;
+        self
+    }
+
+    pub fn with_file_name<T: AsRef<str>>(mut self, file_name: T) -> Self {
+        self.file_name = file_name.as_ref().to_string();
+        self
+    }
+
+    pub fn with_mime_type<T: AsRef<str>>(mut self, mime_type: T) -> Self {
+        self.mime_type = mime_type.as_ref().to_string();
+        self
+    }
+
+    pub fn with_has_stickers(mut self, has_stickers: bool) -> Self {
+        self.has_stickers = has_stickers;
+        self
+    }
+
+    pub fn with_supports_streaming(mut self, supports_streaming: bool) -> Self {
+        self.supports_streaming = supports_streaming;
+        self
+    }
+
+    pub fn with_minithumbnail<T: AsRef<Minithumbnail>>(mut self, minithumbnail: T) -> Self {
+        self.minithumbnail = Some(minithumbnail.as_ref().clone());
+        self
+    }
+
+    pub fn with_thumbnail<T: AsRef<PhotoSize>>(mut self, thumbnail: T) -> Self {
+        self.thumbnail = Some(thumbnail.as_ref().clone());
+        self
+    }
+
+    pub fn with_video<T: AsRef<File>>(mut self, video: T) -> Self {
+        self.video = video.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDVideoBuilder {
     inner: Video,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDVideoBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> Video {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<Video> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("Video", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn duration(&mut self, duration: i64) -> &mut Self {
+    pub fn duration(&mut self, duration: i32) -> &mut Self {
         self.inner.duration = duration;
+        self.missing.retain(|f| *f != "duration");
         self
     }
 
-    pub fn width(&mut self, width: i64) -> &mut Self {
+    pub fn width(&mut self, width: i32) -> &mut Self {
         self.inner.width = width;
+        self.missing.retain(|f| *f != "width");
         self
     }
 
-    pub fn height(&mut self, height: i64) -> &mut Self {
+    pub fn height(&mut self, height: i32) -> &mut Self {
         self.inner.height = height;
+        self.missing.retain(|f| *f != "height");
         self
     }
 
     pub fn file_name<T: AsRef<str>>(&mut self, file_name: T) -> &mut Self {
         self.inner.file_name = file_name.as_ref().to_string();
+        self.missing.retain(|f| *f != "file_name");
         self
     }
 
     pub fn mime_type<T: AsRef<str>>(&mut self, mime_type: T) -> &mut Self {
         self.inner.mime_type = mime_type.as_ref().to_string();
+        self.missing.retain(|f| *f != "mime_type");
         self
     }
 
@@ -156,6 +294,7 @@ impl RTDVideoBuilder {
 
     pub fn video<T: AsRef<File>>(&mut self, video: T) -> &mut Self {
         self.inner.video = video.as_ref().clone();
+        self.missing.retain(|f| *f != "video");
         self
     }
 }
@@ -171,3 +310,52 @@ impl AsRef<Video> for RTDVideoBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            Video::builder()
+                .duration(i32::MAX)
+                .width(i32::MAX)
+                .height(i32::MAX)
+                .file_name("text")
+                .mime_type("text")
+                .has_stickers(true)
+                .supports_streaming(true)
+                .video(
+                    File::builder()
+                        .id(i32::MAX)
+                        .size(i32::MAX)
+                        .expected_size(i32::MAX)
+                        .local(
+                            LocalFile::builder()
+                                .path("text")
+                                .can_be_downloaded(true)
+                                .can_be_deleted(true)
+                                .is_downloading_active(true)
+                                .is_downloading_completed(true)
+                                .download_offset(i32::MAX)
+                                .downloaded_prefix_size(i32::MAX)
+                                .downloaded_size(i32::MAX)
+                                .build(),
+                        )
+                        .remote(
+                            RemoteFile::builder()
+                                .id("text")
+                                .unique_id("text")
+                                .is_uploading_active(true)
+                                .is_uploading_completed(true)
+                                .uploaded_size(i32::MAX)
+                                .build(),
+                        )
+                        .build(),
+                )
+                .build(),
+        );
+    }
+}
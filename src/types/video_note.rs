@@ -1,20 +1,20 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Describes a video note. The video must be equal in width and height, cropped to a circle, and stored in MPEG4 format
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "videoNote")]
 pub struct VideoNote {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Duration of the video, in seconds; as defined by the sender
-    duration: i64,
+    duration: i32,
     /// Video width and height; as defined by the sender
-    length: i64,
+    length: i32,
     /// Video minithumbnail; may be null
     minithumbnail: Option<Minithumbnail>,
     /// Video thumbnail; as defined by the sender; may be null
@@ -37,22 +37,58 @@ impl RObject for VideoNote {
     }
 }
 
+impl PartialEq for VideoNote {
+    fn eq(&self, other: &Self) -> bool {
+        self.duration == other.duration
+            && self.length == other.length
+            && self.minithumbnail == other.minithumbnail
+            && self.thumbnail == other.thumbnail
+            && self.video == other.video
+    }
+}
+
+impl Eq for VideoNote {}
+
+impl std::hash::Hash for VideoNote {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.duration, state);
+        std::hash::Hash::hash(&self.length, state);
+        std::hash::Hash::hash(&self.minithumbnail, state);
+        std::hash::Hash::hash(&self.thumbnail, state);
+        std::hash::Hash::hash(&self.video, state);
+    }
+}
+
 impl VideoNote {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDVideoNoteBuilder {
-        let mut inner = VideoNote::default();
-        inner.td_name = "videoNote".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDVideoNoteBuilder { inner }
-    }
-
-    pub fn duration(&self) -> i64 {
+        RTDVideoNoteBuilder {
+            inner: VideoNote::default(),
+            missing: vec!["duration", "length", "video"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDVideoNoteBuilder {
+        RTDVideoNoteBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDVideoNoteBuilder {
+        RTDVideoNoteBuilder {
+            inner: self,
+            missing: vec![],
+        }
+    }
+
+    pub fn duration(&self) -> i32 {
         self.duration
     }
 
-    pub fn length(&self) -> i64 {
+    pub fn length(&self) -> i32 {
         self.length
     }
 
@@ -67,25 +103,74 @@ impl VideoNote {
     pub fn video(&self) -> &File {
         &self.video
     }
+
+    pub fn into_minithumbnail(self) -> Option<Minithumbnail> {
+        self.minithumbnail
+    }
+
+    pub fn into_thumbnail(self) -> Option<PhotoSize> {
+        self.thumbnail
+    }
+
+    pub fn into_video(self) -> File {
+        self.video
+    }
+
+    pub fn with_duration(mut self, duration: i32) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    pub fn with_length(mut self, length: i32) -> Self {
+        self.length = length;
+        self
+    }
+
+    pub fn with_minithumbnail<T: AsRef<Minithumbnail>>(mut self, minithumbnail: T) -> Self {
+        self.minithumbnail = Some(minithumbnail.as_ref().clone());
+        self
+    }
+
+    pub fn with_thumbnail<T: AsRef<PhotoSize>>(mut self, thumbnail: T) -> Self {
+        self.thumbnail = Some(thumbnail.as_ref().clone());
+        self
+    }
+
+    pub fn with_video<T: AsRef<File>>(mut self, video: T) -> Self {
+        self.video = video.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDVideoNoteBuilder {
     inner: VideoNote,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDVideoNoteBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> VideoNote {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<VideoNote> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("VideoNote", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn duration(&mut self, duration: i64) -> &mut Self {
+    pub fn duration(&mut self, duration: i32) -> &mut Self {
         self.inner.duration = duration;
+        self.missing.retain(|f| *f != "duration");
         self
     }
 
-    pub fn length(&mut self, length: i64) -> &mut Self {
+    pub fn length(&mut self, length: i32) -> &mut Self {
         self.inner.length = length;
+        self.missing.retain(|f| *f != "length");
         self
     }
 
@@ -101,6 +186,7 @@ impl RTDVideoNoteBuilder {
 
     pub fn video<T: AsRef<File>>(&mut self, video: T) -> &mut Self {
         self.inner.video = video.as_ref().clone();
+        self.missing.retain(|f| *f != "video");
         self
     }
 }
@@ -116,3 +202,47 @@ impl AsRef<VideoNote> for RTDVideoNoteBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            VideoNote::builder()
+                .duration(i32::MAX)
+                .length(i32::MAX)
+                .video(
+                    File::builder()
+                        .id(i32::MAX)
+                        .size(i32::MAX)
+                        .expected_size(i32::MAX)
+                        .local(
+                            LocalFile::builder()
+                                .path("text")
+                                .can_be_downloaded(true)
+                                .can_be_deleted(true)
+                                .is_downloading_active(true)
+                                .is_downloading_completed(true)
+                                .download_offset(i32::MAX)
+                                .downloaded_prefix_size(i32::MAX)
+                                .downloaded_size(i32::MAX)
+                                .build(),
+                        )
+                        .remote(
+                            RemoteFile::builder()
+                                .id("text")
+                                .unique_id("text")
+                                .is_uploading_active(true)
+                                .is_uploading_completed(true)
+                                .uploaded_size(i32::MAX)
+                                .build(),
+                        )
+                        .build(),
+                )
+                .build(),
+        );
+    }
+}
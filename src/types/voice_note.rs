@@ -1,20 +1,21 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Describes a voice note. The voice note must be encoded with the Opus codec, and stored inside an OGG container. Voice notes can have only a single audio channel
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "voiceNote")]
 pub struct VoiceNote {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Duration of the voice note, in seconds; as defined by the sender
-    duration: i64,
+    duration: i32,
     /// A waveform representation of the voice note in 5-bit format
-    waveform: String,
+    #[serde(with = "super::_common::bytes")]
+    waveform: Vec<u8>,
     /// MIME type of the file; as defined by the sender
     mime_type: String,
     /// File containing the voice note
@@ -35,22 +36,56 @@ impl RObject for VoiceNote {
     }
 }
 
+impl PartialEq for VoiceNote {
+    fn eq(&self, other: &Self) -> bool {
+        self.duration == other.duration
+            && self.waveform == other.waveform
+            && self.mime_type == other.mime_type
+            && self.voice == other.voice
+    }
+}
+
+impl Eq for VoiceNote {}
+
+impl std::hash::Hash for VoiceNote {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.duration, state);
+        std::hash::Hash::hash(&self.waveform, state);
+        std::hash::Hash::hash(&self.mime_type, state);
+        std::hash::Hash::hash(&self.voice, state);
+    }
+}
+
 impl VoiceNote {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDVoiceNoteBuilder {
-        let mut inner = VoiceNote::default();
-        inner.td_name = "voiceNote".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDVoiceNoteBuilder { inner }
+        RTDVoiceNoteBuilder {
+            inner: VoiceNote::default(),
+            missing: vec!["duration", "waveform", "mime_type", "voice"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDVoiceNoteBuilder {
+        RTDVoiceNoteBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDVoiceNoteBuilder {
+        RTDVoiceNoteBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
-    pub fn duration(&self) -> i64 {
+    pub fn duration(&self) -> i32 {
         self.duration
     }
 
-    pub fn waveform(&self) -> &String {
+    pub fn waveform(&self) -> &[u8] {
         &self.waveform
     }
 
@@ -61,35 +96,81 @@ impl VoiceNote {
     pub fn voice(&self) -> &File {
         &self.voice
     }
+
+    pub fn into_waveform(self) -> Vec<u8> {
+        self.waveform
+    }
+
+    pub fn into_mime_type(self) -> String {
+        self.mime_type
+    }
+
+    pub fn into_voice(self) -> File {
+        self.voice
+    }
+
+    pub fn with_duration(mut self, duration: i32) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    pub fn with_waveform<T: AsRef<[u8]>>(mut self, waveform: T) -> Self {
+        self.waveform = waveform.as_ref().to_vec();
+        self
+    }
+
+    pub fn with_mime_type<T: AsRef<str>>(mut self, mime_type: T) -> Self {
+        self.mime_type = mime_type.as_ref().to_string();
+        self
+    }
+
+    pub fn with_voice<T: AsRef<File>>(mut self, voice: T) -> Self {
+        self.voice = voice.as_ref().clone();
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDVoiceNoteBuilder {
     inner: VoiceNote,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDVoiceNoteBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> VoiceNote {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<VoiceNote> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("VoiceNote", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
-    pub fn duration(&mut self, duration: i64) -> &mut Self {
+    pub fn duration(&mut self, duration: i32) -> &mut Self {
         self.inner.duration = duration;
+        self.missing.retain(|f| *f != "duration");
         self
     }
 
-    pub fn waveform<T: AsRef<str>>(&mut self, waveform: T) -> &mut Self {
-        self.inner.waveform = waveform.as_ref().to_string();
+    pub fn waveform<T: AsRef<[u8]>>(&mut self, waveform: T) -> &mut Self {
+        self.inner.waveform = waveform.as_ref().to_vec();
+        self.missing.retain(|f| *f != "waveform");
         self
     }
 
     pub fn mime_type<T: AsRef<str>>(&mut self, mime_type: T) -> &mut Self {
         self.inner.mime_type = mime_type.as_ref().to_string();
+        self.missing.retain(|f| *f != "mime_type");
         self
     }
 
     pub fn voice<T: AsRef<File>>(&mut self, voice: T) -> &mut Self {
         self.inner.voice = voice.as_ref().clone();
+        self.missing.retain(|f| *f != "voice");
         self
     }
 }
@@ -105,3 +186,48 @@ impl AsRef<VoiceNote> for RTDVoiceNoteBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            VoiceNote::builder()
+                .duration(i32::MAX)
+                .waveform(b"\x00\x01\xfe\xff")
+                .mime_type("text")
+                .voice(
+                    File::builder()
+                        .id(i32::MAX)
+                        .size(i32::MAX)
+                        .expected_size(i32::MAX)
+                        .local(
+                            LocalFile::builder()
+                                .path("text")
+                                .can_be_downloaded(true)
+                                .can_be_deleted(true)
+                                .is_downloading_active(true)
+                                .is_downloading_completed(true)
+                                .download_offset(i32::MAX)
+                                .downloaded_prefix_size(i32::MAX)
+                                .downloaded_size(i32::MAX)
+                                .build(),
+                        )
+                        .remote(
+                            RemoteFile::builder()
+                                .id("text")
+                                .unique_id("text")
+                                .is_uploading_active(true)
+                                .is_uploading_completed(true)
+                                .uploaded_size(i32::MAX)
+                                .build(),
+                        )
+                        .build(),
+                )
+                .build(),
+        );
+    }
+}
@@ -1,15 +1,15 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Describes a web page preview
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "webPage")]
 pub struct WebPage {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Original URL of the link
     url: String,
@@ -31,11 +31,11 @@ pub struct WebPage {
     /// MIME type of the embedded preview, (e.g., text/html or video/mp4)
     embed_type: String,
     /// Width of the embedded preview
-    embed_width: i64,
+    embed_width: i32,
     /// Height of the embedded preview
-    embed_height: i64,
+    embed_height: i32,
     /// Duration of the content, in seconds
-    duration: i64,
+    duration: i32,
     /// Author of the content
     author: String,
     /// Preview of the content as an animation, if available; may be null
@@ -53,7 +53,7 @@ pub struct WebPage {
     /// Preview of the content as a voice note, if available; may be null
     voice_note: Option<VoiceNote>,
     /// Version of instant view, available for the web page (currently can be 1 or 2), 0 if none
-    instant_view_version: i64,
+    instant_view_version: i32,
 }
 
 impl RObject for WebPage {
@@ -70,15 +70,68 @@ impl RObject for WebPage {
     }
 }
 
+impl PartialEq for WebPage {
+    fn eq(&self, other: &Self) -> bool {
+        self.url == other.url
+            && self.display_url == other.display_url
+            && self.type_ == other.type_
+            && self.site_name == other.site_name
+            && self.title == other.title
+            && self.description == other.description
+            && self.photo == other.photo
+            && self.embed_url == other.embed_url
+            && self.embed_type == other.embed_type
+            && self.embed_width == other.embed_width
+            && self.embed_height == other.embed_height
+            && self.duration == other.duration
+            && self.author == other.author
+            && self.animation == other.animation
+            && self.audio == other.audio
+            && self.document == other.document
+            && self.sticker == other.sticker
+            && self.video == other.video
+            && self.video_note == other.video_note
+            && self.voice_note == other.voice_note
+            && self.instant_view_version == other.instant_view_version
+    }
+}
+
 impl WebPage {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDWebPageBuilder {
-        let mut inner = WebPage::default();
-        inner.td_name = "webPage".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDWebPageBuilder { inner }
+        RTDWebPageBuilder {
+            inner: WebPage::default(),
+            missing: vec![
+                "url",
+                "display_url",
+                "type_",
+                "site_name",
+                "title",
+                "description",
+                "embed_url",
+                "embed_type",
+                "embed_width",
+                "embed_height",
+                "duration",
+                "author",
+            ],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDWebPageBuilder {
+        RTDWebPageBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDWebPageBuilder {
+        RTDWebPageBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn url(&self) -> &String {
@@ -117,15 +170,15 @@ impl WebPage {
         &self.embed_type
     }
 
-    pub fn embed_width(&self) -> i64 {
+    pub fn embed_width(&self) -> i32 {
         self.embed_width
     }
 
-    pub fn embed_height(&self) -> i64 {
+    pub fn embed_height(&self) -> i32 {
         self.embed_height
     }
 
-    pub fn duration(&self) -> i64 {
+    pub fn duration(&self) -> i32 {
         self.duration
     }
 
@@ -161,48 +214,237 @@ impl WebPage {
         &self.voice_note
     }
 
-    pub fn instant_view_version(&self) -> i64 {
+    pub fn instant_view_version(&self) -> i32 {
         self.instant_view_version
     }
+
+    pub fn into_url(self) -> String {
+        self.url
+    }
+
+    pub fn into_display_url(self) -> String {
+        self.display_url
+    }
+
+    pub fn into_type(self) -> String {
+        self.type_
+    }
+
+    pub fn into_site_name(self) -> String {
+        self.site_name
+    }
+
+    pub fn into_title(self) -> String {
+        self.title
+    }
+
+    pub fn into_description(self) -> String {
+        self.description
+    }
+
+    pub fn into_photo(self) -> Option<Photo> {
+        self.photo
+    }
+
+    pub fn into_embed_url(self) -> String {
+        self.embed_url
+    }
+
+    pub fn into_embed_type(self) -> String {
+        self.embed_type
+    }
+
+    pub fn into_author(self) -> String {
+        self.author
+    }
+
+    pub fn into_animation(self) -> Option<Animation> {
+        self.animation
+    }
+
+    pub fn into_audio(self) -> Option<Audio> {
+        self.audio
+    }
+
+    pub fn into_document(self) -> Option<Document> {
+        self.document
+    }
+
+    pub fn into_sticker(self) -> Option<Sticker> {
+        self.sticker
+    }
+
+    pub fn into_video(self) -> Option<Video> {
+        self.video
+    }
+
+    pub fn into_video_note(self) -> Option<VideoNote> {
+        self.video_note
+    }
+
+    pub fn into_voice_note(self) -> Option<VoiceNote> {
+        self.voice_note
+    }
+
+    pub fn with_url<T: AsRef<str>>(mut self, url: T) -> Self {
+        self.url = url.as_ref().to_string();
+        self
+    }
+
+    pub fn with_display_url<T: AsRef<str>>(mut self, display_url: T) -> Self {
+        self.display_url = display_url.as_ref().to_string();
+        self
+    }
+
+    pub fn with_type<T: AsRef<str>>(mut self, type_: T) -> Self {
+        self.type_ = type_.as_ref().to_string();
+        self
+    }
+
+    pub fn with_site_name<T: AsRef<str>>(mut self, site_name: T) -> Self {
+        self.site_name = site_name.as_ref().to_string();
+        self
+    }
+
+    pub fn with_title<T: AsRef<str>>(mut self, title: T) -> Self {
+        self.title = title.as_ref().to_string();
+        self
+    }
+
+    pub fn with_description<T: AsRef<str>>(mut self, description: T) -> Self {
+        self.description = description.as_ref().to_string();
+        self
+    }
+
+    pub fn with_photo<T: AsRef<Photo>>(mut self, photo: T) -> Self {
+        self.photo = Some(photo.as_ref().clone());
+        self
+    }
+
+    pub fn with_embed_url<T: AsRef<str>>(mut self, embed_url: T) -> Self {
+        self.embed_url = embed_url.as_ref().to_string();
+        self
+    }
+
+    pub fn with_embed_type<T: AsRef<str>>(mut self, embed_type: T) -> Self {
+        self.embed_type = embed_type.as_ref().to_string();
+        self
+    }
+
+    pub fn with_embed_width(mut self, embed_width: i32) -> Self {
+        self.embed_width = embed_width;
+        self
+    }
+
+    pub fn with_embed_height(mut self, embed_height: i32) -> Self {
+        self.embed_height = embed_height;
+        self
+    }
+
+    pub fn with_duration(mut self, duration: i32) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    pub fn with_author<T: AsRef<str>>(mut self, author: T) -> Self {
+        self.author = author.as_ref().to_string();
+        self
+    }
+
+    pub fn with_animation<T: AsRef<Animation>>(mut self, animation: T) -> Self {
+        self.animation = Some(animation.as_ref().clone());
+        self
+    }
+
+    pub fn with_audio<T: AsRef<Audio>>(mut self, audio: T) -> Self {
+        self.audio = Some(audio.as_ref().clone());
+        self
+    }
+
+    pub fn with_document<T: AsRef<Document>>(mut self, document: T) -> Self {
+        self.document = Some(document.as_ref().clone());
+        self
+    }
+
+    pub fn with_sticker<T: AsRef<Sticker>>(mut self, sticker: T) -> Self {
+        self.sticker = Some(sticker.as_ref().clone());
+        self
+    }
+
+    pub fn with_video<T: AsRef<Video>>(mut self, video: T) -> Self {
+        self.video = Some(video.as_ref().clone());
+        self
+    }
+
+    pub fn with_video_note<T: AsRef<VideoNote>>(mut self, video_note: T) -> Self {
+        self.video_note = Some(video_note.as_ref().clone());
+        self
+    }
+
+    pub fn with_voice_note<T: AsRef<VoiceNote>>(mut self, voice_note: T) -> Self {
+        self.voice_note = Some(voice_note.as_ref().clone());
+        self
+    }
+
+    pub fn with_instant_view_version(mut self, instant_view_version: i32) -> Self {
+        self.instant_view_version = instant_view_version;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDWebPageBuilder {
     inner: WebPage,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDWebPageBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> WebPage {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<WebPage> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields("WebPage", self.missing.clone()));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
         self.inner.url = url.as_ref().to_string();
+        self.missing.retain(|f| *f != "url");
         self
     }
 
     pub fn display_url<T: AsRef<str>>(&mut self, display_url: T) -> &mut Self {
         self.inner.display_url = display_url.as_ref().to_string();
+        self.missing.retain(|f| *f != "display_url");
         self
     }
 
     pub fn type_<T: AsRef<str>>(&mut self, type_: T) -> &mut Self {
         self.inner.type_ = type_.as_ref().to_string();
+        self.missing.retain(|f| *f != "type_");
         self
     }
 
     pub fn site_name<T: AsRef<str>>(&mut self, site_name: T) -> &mut Self {
         self.inner.site_name = site_name.as_ref().to_string();
+        self.missing.retain(|f| *f != "site_name");
         self
     }
 
     pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
         self.inner.title = title.as_ref().to_string();
+        self.missing.retain(|f| *f != "title");
         self
     }
 
     pub fn description<T: AsRef<str>>(&mut self, description: T) -> &mut Self {
         self.inner.description = description.as_ref().to_string();
+        self.missing.retain(|f| *f != "description");
         self
     }
 
@@ -213,31 +455,37 @@ impl RTDWebPageBuilder {
 
     pub fn embed_url<T: AsRef<str>>(&mut self, embed_url: T) -> &mut Self {
         self.inner.embed_url = embed_url.as_ref().to_string();
+        self.missing.retain(|f| *f != "embed_url");
         self
     }
 
     pub fn embed_type<T: AsRef<str>>(&mut self, embed_type: T) -> &mut Self {
         self.inner.embed_type = embed_type.as_ref().to_string();
+        self.missing.retain(|f| *f != "embed_type");
         self
     }
 
-    pub fn embed_width(&mut self, embed_width: i64) -> &mut Self {
+    pub fn embed_width(&mut self, embed_width: i32) -> &mut Self {
         self.inner.embed_width = embed_width;
+        self.missing.retain(|f| *f != "embed_width");
         self
     }
 
-    pub fn embed_height(&mut self, embed_height: i64) -> &mut Self {
+    pub fn embed_height(&mut self, embed_height: i32) -> &mut Self {
         self.inner.embed_height = embed_height;
+        self.missing.retain(|f| *f != "embed_height");
         self
     }
 
-    pub fn duration(&mut self, duration: i64) -> &mut Self {
+    pub fn duration(&mut self, duration: i32) -> &mut Self {
         self.inner.duration = duration;
+        self.missing.retain(|f| *f != "duration");
         self
     }
 
     pub fn author<T: AsRef<str>>(&mut self, author: T) -> &mut Self {
         self.inner.author = author.as_ref().to_string();
+        self.missing.retain(|f| *f != "author");
         self
     }
 
@@ -276,7 +524,7 @@ impl RTDWebPageBuilder {
         self
     }
 
-    pub fn instant_view_version(&mut self, instant_view_version: i64) -> &mut Self {
+    pub fn instant_view_version(&mut self, instant_view_version: i32) -> &mut Self {
         self.inner.instant_view_version = instant_view_version;
         self
     }
@@ -293,3 +541,30 @@ impl AsRef<WebPage> for RTDWebPageBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            WebPage::builder()
+                .url("text")
+                .display_url("text")
+                .type_("text")
+                .site_name("text")
+                .title("text")
+                .description("text")
+                .embed_url("text")
+                .embed_type("text")
+                .embed_width(i32::MAX)
+                .embed_height(i32::MAX)
+                .duration(i32::MAX)
+                .author("text")
+                .instant_view_version(i32::MAX)
+                .build(),
+        );
+    }
+}
@@ -1,20 +1,20 @@
 use crate::errors::*;
 use crate::types::*;
-use uuid::Uuid;
 
 /// Describes an instant view page for a web page
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "@type", rename = "webPageInstantView")]
 pub struct WebPageInstantView {
     #[doc(hidden)]
-    #[serde(rename(serialize = "@type", deserialize = "@type"))]
-    td_name: String,
-    #[doc(hidden)]
-    #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+    #[serde(
+        rename(serialize = "@extra", deserialize = "@extra"),
+        skip_serializing_if = "Option::is_none"
+    )]
     extra: Option<String>,
     /// Content of the web page
     page_blocks: Vec<PageBlock>,
     /// Version of the instant view, currently can be 1 or 2
-    version: i64,
+    version: i32,
     /// Instant view URL; may be different from WebPage.url and must be used for the correct anchors handling
     url: String,
     /// True, if the instant view must be shown from right to left
@@ -37,22 +37,46 @@ impl RObject for WebPageInstantView {
     }
 }
 
+impl PartialEq for WebPageInstantView {
+    fn eq(&self, other: &Self) -> bool {
+        self.page_blocks == other.page_blocks
+            && self.version == other.version
+            && self.url == other.url
+            && self.is_rtl == other.is_rtl
+            && self.is_full == other.is_full
+    }
+}
+
 impl WebPageInstantView {
     pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> {
         Ok(serde_json::from_str(json.as_ref())?)
     }
     pub fn builder() -> RTDWebPageInstantViewBuilder {
-        let mut inner = WebPageInstantView::default();
-        inner.td_name = "webPageInstantView".to_string();
-        inner.extra = Some(Uuid::new_v4().to_string());
-        RTDWebPageInstantViewBuilder { inner }
+        RTDWebPageInstantViewBuilder {
+            inner: WebPageInstantView::default(),
+            missing: vec!["page_blocks", "version", "url"],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn to_builder(&self) -> RTDWebPageInstantViewBuilder {
+        RTDWebPageInstantViewBuilder {
+            inner: self.clone(),
+            missing: vec![],
+        }
+    }
+    /// Builder, initialized with fields of this object
+    pub fn into_builder(self) -> RTDWebPageInstantViewBuilder {
+        RTDWebPageInstantViewBuilder {
+            inner: self,
+            missing: vec![],
+        }
     }
 
     pub fn page_blocks(&self) -> &Vec<PageBlock> {
         &self.page_blocks
     }
 
-    pub fn version(&self) -> i64 {
+    pub fn version(&self) -> i32 {
         self.version
     }
 
@@ -67,30 +91,79 @@ impl WebPageInstantView {
     pub fn is_full(&self) -> bool {
         self.is_full
     }
+
+    pub fn into_page_blocks(self) -> Vec<PageBlock> {
+        self.page_blocks
+    }
+
+    pub fn into_url(self) -> String {
+        self.url
+    }
+
+    pub fn with_page_blocks(mut self, page_blocks: Vec<PageBlock>) -> Self {
+        self.page_blocks = page_blocks;
+        self
+    }
+
+    pub fn with_version(mut self, version: i32) -> Self {
+        self.version = version;
+        self
+    }
+
+    pub fn with_url<T: AsRef<str>>(mut self, url: T) -> Self {
+        self.url = url.as_ref().to_string();
+        self
+    }
+
+    pub fn with_is_rtl(mut self, is_rtl: bool) -> Self {
+        self.is_rtl = is_rtl;
+        self
+    }
+
+    pub fn with_is_full(mut self, is_full: bool) -> Self {
+        self.is_full = is_full;
+        self
+    }
 }
 
 #[doc(hidden)]
 pub struct RTDWebPageInstantViewBuilder {
     inner: WebPageInstantView,
+    // required fields, which are not set yet
+    missing: Vec<&'static str>,
 }
 
 impl RTDWebPageInstantViewBuilder {
+    /// Builds the object, fields which are not set have default values
     pub fn build(&self) -> WebPageInstantView {
         self.inner.clone()
     }
+    /// Builds the object, returns [RTDError::MissingFields] if some of required fields are not set
+    pub fn try_build(&self) -> RTDResult<WebPageInstantView> {
+        if !self.missing.is_empty() {
+            return Err(RTDError::MissingFields(
+                "WebPageInstantView",
+                self.missing.clone(),
+            ));
+        }
+        Ok(self.inner.clone())
+    }
 
     pub fn page_blocks(&mut self, page_blocks: Vec<PageBlock>) -> &mut Self {
         self.inner.page_blocks = page_blocks;
+        self.missing.retain(|f| *f != "page_blocks");
         self
     }
 
-    pub fn version(&mut self, version: i64) -> &mut Self {
+    pub fn version(&mut self, version: i32) -> &mut Self {
         self.inner.version = version;
+        self.missing.retain(|f| *f != "version");
         self
     }
 
     pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
         self.inner.url = url.as_ref().to_string();
+        self.missing.retain(|f| *f != "url");
         self
     }
 
@@ -116,3 +189,22 @@ impl AsRef<WebPageInstantView> for RTDWebPageInstantViewBuilder {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::_common::assert_round_trip;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trip(
+            WebPageInstantView::builder()
+                .page_blocks(vec![])
+                .version(i32::MAX)
+                .url("text")
+                .is_rtl(true)
+                .is_full(true)
+                .build(),
+        );
+    }
+}